@@ -2,6 +2,7 @@
 
 import datetime
 import itertools
+import json
 import os
 import platform
 import shutil
@@ -31,6 +32,8 @@ PARALLEL_BUILD = os.getenv("PARALLEL_BUILD", "true") == "true"
 COPY_TO_SOURCE = os.getenv("COPY_TO_SOURCE", "true") == "true"
 # If PyO3 only then don't build C extensions to reduce compilation time
 PYO3_ONLY = os.getenv("PYO3_ONLY", "") != ""
+# If HIGH_PRECISION is enabled, use 128-bit raw values with 16 decimal places for fixed-point types
+HIGH_PRECISION = os.getenv("HIGH_PRECISION", "false").lower() == "true"
 
 if PROFILE_MODE:
     # For subsequent debugging, the C source needs to be in the same tree as
@@ -78,6 +81,24 @@ RUST_LIB_PATHS: list[Path] = [
 RUST_LIBS: list[str] = [str(path) for path in RUST_LIB_PATHS]
 
 
+def _cargo_features() -> list[str]:
+    # All workspace features, except `high-precision` unless enabled for the build
+    # (the Cython extensions must be compiled against the same raw value width)
+    metadata = subprocess.run(
+        ["cargo", "metadata", "--no-deps", "--format-version", "1"],  # noqa
+        cwd="nautilus_core",
+        check=True,
+        capture_output=True,
+        text=True,
+    )
+    return [
+        f"{package['name']}/{feature}"
+        for package in json.loads(metadata.stdout)["packages"]
+        for feature in package["features"]
+        if HIGH_PRECISION or feature != "high-precision"
+    ]
+
+
 def _build_rust_libs() -> None:
     try:
         # Build the Rust libraries using Cargo
@@ -88,7 +109,8 @@ def _build_rust_libs() -> None:
             "cargo",
             "build",
             *build_options.split(),
-            "--all-features",
+            "--features",
+            ",".join(_cargo_features()),
         ]
         print(" ".join(cmd_args))
 
@@ -139,6 +161,9 @@ def _build_extensions() -> list[Extension]:
     if PROFILE_MODE or ANNOTATION_MODE:
         # Profiling requires special macro directives
         define_macros.append(("CYTHON_TRACE", "1"))
+    if HIGH_PRECISION:
+        # Selects the 128-bit raw value definitions in the Rust headers
+        define_macros.append(("HIGH_PRECISION", None))
 
     extra_compile_args = []
     extra_link_args = RUST_LIBS
@@ -347,7 +372,8 @@ if __name__ == "__main__":
     print(f"ANNOTATION_MODE={ANNOTATION_MODE}")
     print(f"PARALLEL_BUILD={PARALLEL_BUILD}")
     print(f"COPY_TO_SOURCE={COPY_TO_SOURCE}")
-    print(f"PYO3_ONLY={PYO3_ONLY}\n")
+    print(f"PYO3_ONLY={PYO3_ONLY}")
+    print(f"HIGH_PRECISION={HIGH_PRECISION}\n")
 
     print("Starting build...")
     ts_start = datetime.datetime.now(datetime.timezone.utc)
@@ -23,7 +23,8 @@ use nautilus_model::{
     instruments::Instrument,
     position::Position,
     types::{
-        balance::AccountBalance, currency::Currency, money::Money, price::Price, quantity::Quantity,
+        balance::AccountBalance, currency::Currency, money::Money,
+        multi_currency_money::MultiCurrencyMoney, price::Price, quantity::Quantity,
     },
 };
 use rust_decimal::prelude::ToPrimitive;
@@ -179,7 +180,7 @@ impl BaseAccount {
         fill: OrderFilled,
         position: Option<Position>,
     ) -> Result<Vec<Money>> {
-        let mut pnls: HashMap<Currency, Money> = HashMap::new();
+        let mut pnls = MultiCurrencyMoney::new();
         let quote_currency = instrument.quote_currency();
         let base_currency = instrument.base_currency();
 
@@ -189,30 +190,18 @@ impl BaseAccount {
         });
         if fill.order_side == OrderSide::Buy {
             if let (Some(base_currency_value), None) = (base_currency, self.base_currency) {
-                pnls.insert(
-                    base_currency_value,
-                    Money::new(fill_qty, base_currency_value).unwrap(),
-                );
+                pnls += Money::new(fill_qty, base_currency_value).unwrap();
             }
-            pnls.insert(
-                quote_currency,
-                Money::new(-(fill_qty * fill_px), quote_currency).unwrap(),
-            );
+            pnls += Money::new(-(fill_qty * fill_px), quote_currency).unwrap();
         } else if fill.order_side == OrderSide::Sell {
             if let (Some(base_currency_value), None) = (base_currency, self.base_currency) {
-                pnls.insert(
-                    base_currency_value,
-                    Money::new(-fill_qty, base_currency_value).unwrap(),
-                );
+                pnls += Money::new(-fill_qty, base_currency_value).unwrap();
             }
-            pnls.insert(
-                quote_currency,
-                Money::new(fill_qty * fill_px, quote_currency).unwrap(),
-            );
+            pnls += Money::new(fill_qty * fill_px, quote_currency).unwrap();
         } else {
             panic!("Invalid order side in   base_calculate_pnls")
         }
-        Ok(pnls.into_values().collect())
+        Ok(pnls.monies())
     }
 
     pub fn base_calculate_commission<T: Instrument>(
@@ -23,6 +23,7 @@ use std::{
 };
 
 use anyhow::Result;
+use nautilus_core::time::UnixNanos;
 use nautilus_model::{
     enums::{AccountType, LiquiditySide, OrderSide},
     events::{account::state::AccountState, order::filled::OrderFilled},
@@ -39,7 +40,10 @@ use nautilus_model::{
 };
 use rust_decimal::prelude::ToPrimitive;
 
-use crate::account::{base::BaseAccount, Account};
+use crate::{
+    account::{base::BaseAccount, Account},
+    margin_model::{MarginCall, MarginConfig, PositionMargin},
+};
 
 #[derive(Debug)]
 #[cfg_attr(
@@ -51,6 +55,7 @@ pub struct MarginAccount {
     pub leverages: HashMap<InstrumentId, f64>,
     pub margins: HashMap<InstrumentId, MarginBalance>,
     pub default_leverage: f64,
+    pub margin_config: MarginConfig,
 }
 
 impl MarginAccount {
@@ -60,6 +65,7 @@ impl MarginAccount {
             leverages: HashMap::new(),
             margins: HashMap::new(),
             default_leverage: 1.0,
+            margin_config: MarginConfig::default(),
         })
     }
 
@@ -67,6 +73,10 @@ impl MarginAccount {
         self.default_leverage = leverage;
     }
 
+    pub fn set_margin_config(&mut self, margin_config: MarginConfig) {
+        self.margin_config = margin_config;
+    }
+
     pub fn set_leverage(&mut self, instrument_id: InstrumentId, leverage: f64) {
         self.leverages.insert(instrument_id, leverage);
     }
@@ -229,6 +239,22 @@ impl MarginAccount {
         }
     }
 
+    /// Returns the margin calls for the accounts open `positions`, according to the
+    /// configured margin mode.
+    pub fn margin_calls(
+        &self,
+        positions: &[PositionMargin],
+        ts_event: UnixNanos,
+    ) -> Result<Vec<MarginCall>> {
+        let balances: HashMap<Currency, Money> = self
+            .balances
+            .iter()
+            .map(|(currency, balance)| (*currency, balance.total))
+            .collect();
+        self.margin_config
+            .check_margin_calls(self.id, &balances, positions, ts_event)
+    }
+
     pub fn recalculate_balance(&mut self, currency: Currency) {
         let current_balance = match self.balances.get(&currency) {
             Some(balance) => balance,
@@ -0,0 +1,266 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Foreign exchange (FX) conversion of PnL into the account currency.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::quote::QuoteTick,
+    enums::PriceType,
+    identifiers::{instrument_id::InstrumentId, position_id::PositionId},
+    position::Position,
+    types::{currency::Currency, money::Money, price::Price},
+};
+
+/// Provides a table of the latest FX rates from currency pair quotes, for converting
+/// amounts between currencies (directly, inversely or via a single intermediate currency).
+#[derive(Clone, Debug, Default)]
+pub struct FxRateTable {
+    rates: HashMap<(Currency, Currency), (f64, f64)>,
+}
+
+/// Represents a snapshot of a positions PnL converted into the account currency.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PnlSnapshot {
+    pub position_id: PositionId,
+    pub instrument_id: InstrumentId,
+    /// The realized PnL in the account currency.
+    pub realized_pnl: Money,
+    /// The unrealized PnL in the account currency.
+    pub unrealized_pnl: Money,
+    /// The exchange rate applied from the positions settlement currency.
+    pub xrate: f64,
+    pub ts_event: UnixNanos,
+}
+
+impl FxRateTable {
+    /// Creates a new empty [`FxRateTable`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the rate for the `base`/`quote` currency pair from the given `quote`.
+    pub fn update(&mut self, base: Currency, quote: Currency, tick: &QuoteTick) {
+        self.rates.insert(
+            (base, quote),
+            (tick.bid_price.as_f64(), tick.ask_price.as_f64()),
+        );
+    }
+
+    /// Returns the exchange rate to convert an amount in `from` into `to` (if available).
+    ///
+    /// Returns `None` for a `price_type` of `Last`, as rates are derived from quotes.
+    #[must_use]
+    pub fn rate(&self, from: Currency, to: Currency, price_type: PriceType) -> Option<f64> {
+        if from == to {
+            return Some(1.0);
+        }
+        if let Some(rate) = self.direct_rate(from, to, price_type) {
+            return Some(rate);
+        }
+
+        // Triangulate via an intermediate currency
+        self.rates
+            .keys()
+            .flat_map(|(base, quote)| [*base, *quote])
+            .filter(|via| *via != from && *via != to)
+            .find_map(|via| {
+                let first = self.direct_rate(from, via, price_type)?;
+                let second = self.direct_rate(via, to, price_type)?;
+                Some(first * second)
+            })
+    }
+
+    /// Converts the `amount` into the `to` currency (if a rate is available).
+    #[must_use]
+    pub fn convert(&self, amount: Money, to: Currency, price_type: PriceType) -> Option<Money> {
+        let rate = self.rate(amount.currency, to, price_type)?;
+        Money::new(amount.as_f64() * rate, to).ok()
+    }
+
+    /// Returns a snapshot of the realized and unrealized PnL for the `position` (valued at
+    /// the `last` price), converted into the `account_currency`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no exchange rate is available for the conversion.
+    pub fn pnl_snapshot(
+        &self,
+        position: &Position,
+        last: Price,
+        account_currency: Currency,
+        price_type: PriceType,
+        ts_event: UnixNanos,
+    ) -> Result<PnlSnapshot> {
+        let currency = position.settlement_currency;
+        let xrate = self
+            .rate(currency, account_currency, price_type)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No exchange rate for {}/{}",
+                    currency.code,
+                    account_currency.code
+                )
+            })?;
+
+        let realized_pnl = position.realized_pnl.map_or(0.0, |pnl| pnl.as_f64());
+        let unrealized_pnl = position.unrealized_pnl(last).as_f64();
+
+        Ok(PnlSnapshot {
+            position_id: position.id,
+            instrument_id: position.instrument_id,
+            realized_pnl: Money::new(realized_pnl * xrate, account_currency)?,
+            unrealized_pnl: Money::new(unrealized_pnl * xrate, account_currency)?,
+            xrate,
+            ts_event,
+        })
+    }
+
+    fn direct_rate(&self, from: Currency, to: Currency, price_type: PriceType) -> Option<f64> {
+        if let Some((bid, ask)) = self.rates.get(&(from, to)) {
+            return side_rate(*bid, *ask, price_type);
+        }
+        if let Some((bid, ask)) = self.rates.get(&(to, from)) {
+            return side_rate(*bid, *ask, price_type).map(|rate| 1.0 / rate);
+        }
+        None
+    }
+}
+
+fn side_rate(bid: f64, ask: f64, price_type: PriceType) -> Option<f64> {
+    match price_type {
+        PriceType::Bid => Some(bid),
+        PriceType::Ask => Some(ask),
+        PriceType::Mid => Some((bid + ask) / 2.0),
+        PriceType::Last => None,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{position::Position, types::quantity::Quantity};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::*;
+
+    fn quote(instrument_id: &str, bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from(instrument_id),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            0,
+            0,
+        )
+        .unwrap()
+    }
+
+    fn rate_table() -> FxRateTable {
+        let mut table = FxRateTable::new();
+        table.update(
+            Currency::from("EUR"),
+            Currency::from("USD"),
+            &quote("EUR/USD.SIM", "1.2000", "1.3000"),
+        );
+        table.update(
+            Currency::from("USD"),
+            Currency::from("JPY"),
+            &quote("USD/JPY.SIM", "150.00", "150.00"),
+        );
+        table
+    }
+
+    #[rstest]
+    #[case("EUR", "USD", PriceType::Bid, Some(1.2))]
+    #[case("EUR", "USD", PriceType::Mid, Some(1.25))]
+    #[case("USD", "EUR", PriceType::Mid, Some(0.8))]
+    #[case("EUR", "JPY", PriceType::Mid, Some(187.5))]
+    #[case("EUR", "EUR", PriceType::Mid, Some(1.0))]
+    #[case("EUR", "USD", PriceType::Last, None)]
+    #[case("EUR", "GBP", PriceType::Mid, None)]
+    fn test_rate(
+        #[case] from: &str,
+        #[case] to: &str,
+        #[case] price_type: PriceType,
+        #[case] expected: Option<f64>,
+    ) {
+        let rate = rate_table().rate(Currency::from(from), Currency::from(to), price_type);
+
+        match expected {
+            Some(expected) => assert!((rate.unwrap() - expected).abs() < 1e-9),
+            None => assert!(rate.is_none()),
+        }
+    }
+
+    #[rstest]
+    fn test_convert() {
+        let amount = Money::new(100.0, Currency::from("USD")).unwrap();
+
+        let converted = rate_table().convert(amount, Currency::from("EUR"), PriceType::Mid);
+
+        assert_eq!(
+            converted,
+            Some(Money::new(80.0, Currency::from("EUR")).unwrap())
+        );
+    }
+
+    #[rstest]
+    fn test_pnl_snapshot_converts_to_account_currency(test_position_long: Position) {
+        let snapshot = rate_table()
+            .pnl_snapshot(
+                &test_position_long,
+                Price::from("2.0002"),
+                Currency::from("EUR"),
+                PriceType::Mid,
+                1,
+            )
+            .unwrap();
+        let realized_pnl = test_position_long.realized_pnl.unwrap().as_f64() * 0.8;
+
+        assert_eq!(snapshot.position_id, test_position_long.id);
+        assert!((snapshot.xrate - 0.8).abs() < 1e-9);
+        assert_eq!(
+            snapshot.unrealized_pnl,
+            Money::new(0.8, Currency::from("EUR")).unwrap()
+        );
+        assert_eq!(
+            snapshot.realized_pnl,
+            Money::new(realized_pnl, Currency::from("EUR")).unwrap()
+        );
+        assert_eq!(snapshot.ts_event, 1);
+    }
+
+    #[rstest]
+    fn test_pnl_snapshot_without_rate_returns_error(test_position_long: Position) {
+        let result = FxRateTable::new().pnl_snapshot(
+            &test_position_long,
+            Price::from("2.0002"),
+            Currency::from("EUR"),
+            PriceType::Mid,
+            1,
+        );
+
+        assert!(result.is_err());
+    }
+}
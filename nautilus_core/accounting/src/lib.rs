@@ -14,6 +14,8 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod account;
+pub mod fx;
+pub mod margin_model;
 #[cfg(test)]
 pub mod stubs;
 
@@ -0,0 +1,323 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Margin models for cross-margin and (simplified) portfolio-margin accounts.
+//!
+//! Under `Isolated` margin each position is margined against its own allocated initial margin.
+//! Under `Cross` margin all positions share the account equity per currency, and under
+//! `Portfolio` margin offsetting long and short exposures are netted per currency, subject to
+//! a haircut on the hedged exposure.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    enums::PositionSide,
+    identifiers::{account_id::AccountId, instrument_id::InstrumentId},
+    types::{currency::Currency, money::Money},
+};
+
+/// The margin mode for a venue account.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum MarginMode {
+    /// Each position is margined independently against its allocated initial margin.
+    #[default]
+    Isolated,
+    /// All positions are margined against the account equity per currency.
+    Cross,
+    /// Long and short exposures are netted per currency, with a haircut on the offset.
+    Portfolio,
+}
+
+/// The margin configuration for a venue account.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MarginConfig {
+    /// The margin mode for the account.
+    pub mode: MarginMode,
+    /// The haircut applied to offsetting exposures per currency under portfolio margin,
+    /// where 0.0 nets offsetting exposure in full and 1.0 applies no offset.
+    pub haircuts: HashMap<Currency, f64>,
+    /// The haircut for currencies without a specific haircut.
+    pub default_haircut: f64,
+}
+
+/// The margin state of an open position.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PositionMargin {
+    pub instrument_id: InstrumentId,
+    pub side: PositionSide,
+    pub initial: Money,
+    pub maintenance: Money,
+    pub unrealized_pnl: Money,
+}
+
+/// Represents a margin call, raised when account (or isolated position) equity falls below
+/// the maintenance margin requirement.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MarginCall {
+    pub account_id: AccountId,
+    /// The instrument for an isolated margin call, otherwise `None` for the whole account.
+    pub instrument_id: Option<InstrumentId>,
+    pub equity: Money,
+    pub maintenance_margin: Money,
+    pub ts_event: UnixNanos,
+}
+
+impl MarginConfig {
+    /// Returns the portfolio margin haircut for the given `currency`.
+    #[must_use]
+    pub fn haircut(&self, currency: &Currency) -> f64 {
+        *self.haircuts.get(currency).unwrap_or(&self.default_haircut)
+    }
+
+    /// Returns the maintenance margin requirement per currency for the given `positions`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a portfolio margin requirement is not a valid `Money` amount.
+    pub fn maintenance_requirements(
+        &self,
+        positions: &[PositionMargin],
+    ) -> Result<HashMap<Currency, Money>> {
+        let mut requirements: HashMap<Currency, Money> = HashMap::new();
+        if self.mode != MarginMode::Portfolio {
+            for position in positions {
+                *requirements
+                    .entry(position.maintenance.currency)
+                    .or_insert_with(|| Money::from_raw(0, position.maintenance.currency)) +=
+                    position.maintenance;
+            }
+            return Ok(requirements);
+        }
+
+        let mut exposures: HashMap<Currency, (f64, f64)> = HashMap::new();
+        for position in positions {
+            let (long, short) = exposures
+                .entry(position.maintenance.currency)
+                .or_insert((0.0, 0.0));
+            match position.side {
+                PositionSide::Long => *long += position.maintenance.as_f64(),
+                PositionSide::Short => *short += position.maintenance.as_f64(),
+                _ => {}
+            }
+        }
+
+        for (currency, (long, short)) in exposures {
+            let offset = long.min(short);
+            let requirement = (long - short).abs() + 2.0 * offset * self.haircut(&currency);
+            requirements.insert(currency, Money::new(requirement, currency)?);
+        }
+        Ok(requirements)
+    }
+
+    /// Returns the margin calls for an account with the given total `balances` and open
+    /// `positions`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the maintenance margin requirements cannot be calculated.
+    pub fn check_margin_calls(
+        &self,
+        account_id: AccountId,
+        balances: &HashMap<Currency, Money>,
+        positions: &[PositionMargin],
+        ts_event: UnixNanos,
+    ) -> Result<Vec<MarginCall>> {
+        if self.mode == MarginMode::Isolated {
+            return Ok(positions
+                .iter()
+                .filter_map(|position| {
+                    let equity = position.initial + position.unrealized_pnl;
+                    (equity < position.maintenance).then_some(MarginCall {
+                        account_id,
+                        instrument_id: Some(position.instrument_id),
+                        equity,
+                        maintenance_margin: position.maintenance,
+                        ts_event,
+                    })
+                })
+                .collect());
+        }
+
+        let mut equities: HashMap<Currency, Money> = balances.clone();
+        for position in positions {
+            *equities
+                .entry(position.unrealized_pnl.currency)
+                .or_insert_with(|| Money::from_raw(0, position.unrealized_pnl.currency)) +=
+                position.unrealized_pnl;
+        }
+
+        let mut margin_calls: Vec<MarginCall> = self
+            .maintenance_requirements(positions)?
+            .into_iter()
+            .filter_map(|(currency, maintenance_margin)| {
+                let equity = equities
+                    .get(&currency)
+                    .copied()
+                    .unwrap_or_else(|| Money::from_raw(0, currency));
+                (equity < maintenance_margin).then_some(MarginCall {
+                    account_id,
+                    instrument_id: None,
+                    equity,
+                    maintenance_margin,
+                    ts_event,
+                })
+            })
+            .collect();
+        margin_calls.sort_by(|a, b| {
+            let (a, b) = (a.maintenance_margin.currency, b.maintenance_margin.currency);
+            a.code.as_str().cmp(b.code.as_str())
+        });
+        Ok(margin_calls)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn usd(amount: f64) -> Money {
+        Money::new(amount, Currency::from("USD")).unwrap()
+    }
+
+    fn position(
+        instrument: &str,
+        side: PositionSide,
+        maintenance: f64,
+        pnl: f64,
+    ) -> PositionMargin {
+        PositionMargin {
+            instrument_id: InstrumentId::from(instrument),
+            side,
+            initial: usd(maintenance * 2.0),
+            maintenance: usd(maintenance),
+            unrealized_pnl: usd(pnl),
+        }
+    }
+
+    fn config(mode: MarginMode, haircut: f64) -> MarginConfig {
+        MarginConfig {
+            mode,
+            haircuts: HashMap::new(),
+            default_haircut: haircut,
+        }
+    }
+
+    #[rstest]
+    #[case(MarginMode::Isolated, 0.0, 1_500.0)]
+    #[case(MarginMode::Cross, 0.0, 1_500.0)]
+    #[case(MarginMode::Portfolio, 0.0, 500.0)]
+    #[case(MarginMode::Portfolio, 0.5, 1_000.0)]
+    #[case(MarginMode::Portfolio, 1.0, 1_500.0)]
+    fn test_maintenance_requirements(
+        #[case] mode: MarginMode,
+        #[case] haircut: f64,
+        #[case] expected: f64,
+    ) {
+        let positions = [
+            position("BTCUSDT-PERP.BINANCE", PositionSide::Long, 1_000.0, 0.0),
+            position("ETHUSDT-PERP.BINANCE", PositionSide::Short, 500.0, 0.0),
+        ];
+
+        let requirements = config(mode, haircut)
+            .maintenance_requirements(&positions)
+            .unwrap();
+
+        assert_eq!(requirements[&Currency::from("USD")], usd(expected));
+    }
+
+    #[rstest]
+    fn test_haircut_per_currency() {
+        let mut config = config(MarginMode::Portfolio, 1.0);
+        config.haircuts.insert(Currency::from("USD"), 0.25);
+
+        assert_eq!(config.haircut(&Currency::from("USD")), 0.25);
+        assert_eq!(config.haircut(&Currency::from("EUR")), 1.0);
+    }
+
+    #[rstest]
+    fn test_isolated_margin_call_for_position() {
+        let positions = [
+            position(
+                "BTCUSDT-PERP.BINANCE",
+                PositionSide::Long,
+                1_000.0,
+                -1_500.0,
+            ),
+            position("ETHUSDT-PERP.BINANCE", PositionSide::Short, 500.0, 0.0),
+        ];
+        let balances = HashMap::from([(Currency::from("USD"), usd(100_000.0))]);
+
+        let calls = config(MarginMode::Isolated, 0.0)
+            .check_margin_calls(AccountId::from("SIM-001"), &balances, &positions, 1)
+            .unwrap();
+
+        assert_eq!(calls.len(), 1);
+        assert_eq!(
+            calls[0].instrument_id,
+            Some(InstrumentId::from("BTCUSDT-PERP.BINANCE"))
+        );
+        assert_eq!(calls[0].equity, usd(500.0));
+        assert_eq!(calls[0].maintenance_margin, usd(1_000.0));
+    }
+
+    #[rstest]
+    fn test_cross_margin_uses_account_equity() {
+        let positions = [position(
+            "BTCUSDT-PERP.BINANCE",
+            PositionSide::Long,
+            1_000.0,
+            -1_500.0,
+        )];
+        let balances = HashMap::from([(Currency::from("USD"), usd(10_000.0))]);
+
+        let calls = config(MarginMode::Cross, 0.0)
+            .check_margin_calls(AccountId::from("SIM-001"), &balances, &positions, 1)
+            .unwrap();
+
+        assert!(calls.is_empty());
+    }
+
+    #[rstest]
+    #[case(MarginMode::Cross, 1)]
+    #[case(MarginMode::Portfolio, 0)]
+    fn test_margin_call_when_equity_below_maintenance(
+        #[case] mode: MarginMode,
+        #[case] expected_calls: usize,
+    ) {
+        let positions = [
+            position("BTCUSDT-PERP.BINANCE", PositionSide::Long, 1_000.0, -200.0),
+            position("ETHUSDT-PERP.BINANCE", PositionSide::Short, 1_000.0, 0.0),
+        ];
+        let balances = HashMap::from([(Currency::from("USD"), usd(1_500.0))]);
+
+        let calls = config(mode, 0.0)
+            .check_margin_calls(AccountId::from("SIM-001"), &balances, &positions, 1)
+            .unwrap();
+
+        assert_eq!(calls.len(), expected_calls);
+        if let Some(call) = calls.first() {
+            assert_eq!(call.instrument_id, None);
+            assert_eq!(call.equity, usd(1_300.0));
+            assert_eq!(call.maintenance_margin, usd(2_000.0));
+        }
+    }
+}
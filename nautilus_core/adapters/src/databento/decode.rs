@@ -41,7 +41,12 @@ use nautilus_model::{
         equity::Equity, futures_contract::FuturesContract, futures_spread::FuturesSpread,
         options_contract::OptionsContract, options_spread::OptionsSpread, InstrumentType,
     },
-    types::{currency::Currency, fixed::FIXED_SCALAR, price::Price, quantity::Quantity},
+    types::{
+        currency::Currency,
+        fixed::{FIXED_PRECISION, FIXED_SCALAR},
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 use ustr::Ustr;
 
@@ -145,17 +150,26 @@ pub fn parse_cfi_iso10926(value: &str) -> Result<(Option<AssetClass>, Option<Ins
     Ok((asset_class, instrument_class))
 }
 
+/// Databento prices are fixed-point integers with 9 decimal places.
+const DATABENTO_FIXED_PRECISION: u8 = 9;
+
+/// Converts a Databento fixed-point price to the raw value of a [`Price`].
+#[must_use]
+pub fn decode_price_raw(value: i64) -> PriceRaw {
+    value as PriceRaw * PriceRaw::pow(10, u32::from(FIXED_PRECISION - DATABENTO_FIXED_PRECISION))
+}
+
 pub fn decode_price(value: i64, precision: u8) -> Result<Price> {
     match value {
         0 | i64::MAX => Price::new(10f64.powi(-i32::from(precision)), precision),
-        _ => Price::from_raw(value, precision),
+        _ => Price::from_raw(decode_price_raw(value), precision),
     }
 }
 
 pub fn decode_optional_price(value: i64, precision: u8) -> Result<Option<Price>> {
     match value {
         i64::MAX => Ok(None),
-        _ => Ok(Some(Price::from_raw(value, precision)?)),
+        _ => Ok(Some(Price::from_raw(decode_price_raw(value), precision)?)),
     }
 }
 
@@ -307,7 +321,7 @@ pub fn decode_options_contract_v1(
         parse_option_kind(msg.instrument_class)?,
         msg.activation,
         msg.expiration,
-        Price::from_raw(msg.strike_price, currency.precision)?,
+        Price::from_raw(decode_price_raw(msg.strike_price), currency.precision)?,
         currency,
         currency.precision,
         decode_price(msg.min_price_increment, currency.precision)?,
@@ -381,8 +395,8 @@ pub fn decode_mbo_msg(
         if include_trades {
             let trade = TradeTick::new(
                 instrument_id,
-                Price::from_raw(msg.price, price_precision)?,
-                Quantity::from_raw(u64::from(msg.size) * FIXED_SCALAR as u64, 0)?,
+                Price::from_raw(decode_price_raw(msg.price), price_precision)?,
+                Quantity::from_raw(QuantityRaw::from(msg.size) * FIXED_SCALAR as QuantityRaw, 0)?,
                 parse_aggressor_side(msg.side),
                 TradeId::new(itoa::Buffer::new().format(msg.sequence))?,
                 msg.ts_recv,
@@ -396,8 +410,8 @@ pub fn decode_mbo_msg(
 
     let order = BookOrder::new(
         side,
-        Price::from_raw(msg.price, price_precision)?,
-        Quantity::from_raw(u64::from(msg.size) * FIXED_SCALAR as u64, 0)?,
+        Price::from_raw(decode_price_raw(msg.price), price_precision)?,
+        Quantity::from_raw(QuantityRaw::from(msg.size) * FIXED_SCALAR as QuantityRaw, 0)?,
         msg.order_id,
     );
 
@@ -422,8 +436,8 @@ pub fn decode_trade_msg(
 ) -> anyhow::Result<TradeTick> {
     let trade = TradeTick::new(
         instrument_id,
-        Price::from_raw(msg.price, price_precision)?,
-        Quantity::from_raw(u64::from(msg.size) * FIXED_SCALAR as u64, 0)?,
+        Price::from_raw(decode_price_raw(msg.price), price_precision)?,
+        Quantity::from_raw(QuantityRaw::from(msg.size) * FIXED_SCALAR as QuantityRaw, 0)?,
         parse_aggressor_side(msg.side),
         TradeId::new(itoa::Buffer::new().format(msg.sequence))?,
         msg.ts_recv,
@@ -443,10 +457,16 @@ pub fn decode_mbp1_msg(
     let top_level = &msg.levels[0];
     let quote = QuoteTick::new(
         instrument_id,
-        Price::from_raw(top_level.bid_px, price_precision)?,
-        Price::from_raw(top_level.ask_px, price_precision)?,
-        Quantity::from_raw(u64::from(top_level.bid_sz) * FIXED_SCALAR as u64, 0)?,
-        Quantity::from_raw(u64::from(top_level.ask_sz) * FIXED_SCALAR as u64, 0)?,
+        Price::from_raw(decode_price_raw(top_level.bid_px), price_precision)?,
+        Price::from_raw(decode_price_raw(top_level.ask_px), price_precision)?,
+        Quantity::from_raw(
+            QuantityRaw::from(top_level.bid_sz) * FIXED_SCALAR as QuantityRaw,
+            0,
+        )?,
+        Quantity::from_raw(
+            QuantityRaw::from(top_level.ask_sz) * FIXED_SCALAR as QuantityRaw,
+            0,
+        )?,
         msg.ts_recv,
         ts_init,
     )?;
@@ -454,8 +474,8 @@ pub fn decode_mbp1_msg(
     let maybe_trade = if include_trades && msg.action as u8 as char == 'T' {
         Some(TradeTick::new(
             instrument_id,
-            Price::from_raw(msg.price, price_precision)?,
-            Quantity::from_raw(u64::from(msg.size) * FIXED_SCALAR as u64, 0)?,
+            Price::from_raw(decode_price_raw(msg.price), price_precision)?,
+            Quantity::from_raw(QuantityRaw::from(msg.size) * FIXED_SCALAR as QuantityRaw, 0)?,
             parse_aggressor_side(msg.side),
             TradeId::new(itoa::Buffer::new().format(msg.sequence))?,
             msg.ts_recv,
@@ -482,15 +502,21 @@ pub fn decode_mbp10_msg(
     for level in &msg.levels {
         let bid_order = BookOrder::new(
             OrderSide::Buy,
-            Price::from_raw(level.bid_px, price_precision)?,
-            Quantity::from_raw(u64::from(level.bid_sz) * FIXED_SCALAR as u64, 0)?,
+            Price::from_raw(decode_price_raw(level.bid_px), price_precision)?,
+            Quantity::from_raw(
+                QuantityRaw::from(level.bid_sz) * FIXED_SCALAR as QuantityRaw,
+                0,
+            )?,
             0,
         );
 
         let ask_order = BookOrder::new(
             OrderSide::Sell,
-            Price::from_raw(level.ask_px, price_precision)?,
-            Quantity::from_raw(u64::from(level.ask_sz) * FIXED_SCALAR as u64, 0)?,
+            Price::from_raw(decode_price_raw(level.ask_px), price_precision)?,
+            Quantity::from_raw(
+                QuantityRaw::from(level.ask_sz) * FIXED_SCALAR as QuantityRaw,
+                0,
+            )?,
             0,
         );
 
@@ -592,11 +618,11 @@ pub fn decode_ohlcv_msg(
 
     let bar = Bar::new(
         bar_type,
-        Price::from_raw(msg.open / 100, price_precision)?, // TODO(adjust for display factor)
-        Price::from_raw(msg.high / 100, price_precision)?, // TODO(adjust for display factor)
-        Price::from_raw(msg.low / 100, price_precision)?,  // TODO(adjust for display factor)
-        Price::from_raw(msg.close / 100, price_precision)?, // TODO(adjust for display factor)
-        Quantity::from_raw(msg.volume * FIXED_SCALAR as u64, 0)?, // TODO(adjust for display factor)
+        Price::from_raw(decode_price_raw(msg.open / 100), price_precision)?, // TODO(adjust for display factor)
+        Price::from_raw(decode_price_raw(msg.high / 100), price_precision)?, // TODO(adjust for display factor)
+        Price::from_raw(decode_price_raw(msg.low / 100), price_precision)?, // TODO(adjust for display factor)
+        Price::from_raw(decode_price_raw(msg.close / 100), price_precision)?, // TODO(adjust for display factor)
+        Quantity::from_raw(msg.volume as QuantityRaw * FIXED_SCALAR as QuantityRaw, 0)?, // TODO(adjust for display factor)
         ts_event,
         ts_init,
     );
@@ -856,7 +882,7 @@ pub fn decode_options_contract(
         parse_option_kind(msg.instrument_class)?,
         msg.activation,
         msg.expiration,
-        Price::from_raw(msg.strike_price, currency.precision)?,
+        Price::from_raw(decode_price_raw(msg.strike_price), currency.precision)?,
         currency,
         currency.precision,
         decode_price(msg.min_price_increment, currency.precision)?,
@@ -921,9 +947,12 @@ pub fn decode_imbalance_msg(
 ) -> anyhow::Result<DatabentoImbalance> {
     DatabentoImbalance::new(
         instrument_id,
-        Price::from_raw(msg.ref_price, price_precision)?,
-        Price::from_raw(msg.cont_book_clr_price, price_precision)?,
-        Price::from_raw(msg.auct_interest_clr_price, price_precision)?,
+        Price::from_raw(decode_price_raw(msg.ref_price), price_precision)?,
+        Price::from_raw(decode_price_raw(msg.cont_book_clr_price), price_precision)?,
+        Price::from_raw(
+            decode_price_raw(msg.auct_interest_clr_price),
+            price_precision,
+        )?,
         Quantity::new(f64::from(msg.paired_qty), 0)?,
         Quantity::new(f64::from(msg.total_imbalance_qty), 0)?,
         parse_order_side(msg.side),
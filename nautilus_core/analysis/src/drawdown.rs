@@ -0,0 +1,153 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{events::account::state::AccountState, types::currency::Currency};
+
+use crate::statistic::StreamingStatistic;
+
+/// Calculates the maximum drawdown of account equity.
+///
+/// Drawdowns are expressed as a non-positive fraction of the running peak equity, so a fall
+/// from a peak of 100 to 75 is a drawdown of -0.25.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct MaxDrawdown {
+    /// The currency of the account balances tracked.
+    pub currency: Currency,
+    pub peak: f64,
+    pub drawdown: f64,
+    pub max_drawdown: f64,
+    pub count: usize,
+}
+
+impl Display for MaxDrawdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.currency.code)
+    }
+}
+
+impl StreamingStatistic for MaxDrawdown {
+    fn name(&self) -> String {
+        stringify!(MaxDrawdown).to_string()
+    }
+
+    fn value(&self) -> f64 {
+        if self.count == 0 {
+            return f64::NAN;
+        }
+        self.max_drawdown
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        self.count += 1;
+        if value > self.peak || self.count == 1 {
+            self.peak = value;
+        }
+        self.drawdown = if self.peak > 0.0 {
+            (value - self.peak) / self.peak
+        } else {
+            0.0
+        };
+        self.max_drawdown = self.max_drawdown.min(self.drawdown);
+    }
+
+    fn reset(&mut self) {
+        self.peak = 0.0;
+        self.drawdown = 0.0;
+        self.max_drawdown = 0.0;
+        self.count = 0;
+    }
+}
+
+impl MaxDrawdown {
+    #[must_use]
+    pub fn new(currency: Currency) -> Self {
+        Self {
+            currency,
+            peak: 0.0,
+            drawdown: 0.0,
+            max_drawdown: 0.0,
+            count: 0,
+        }
+    }
+
+    /// Updates the statistic with the total balance in the tracked currency from the
+    /// account `state` (states without a balance in the currency are ignored).
+    pub fn handle_account_state(&mut self, state: &AccountState) {
+        if let Some(balance) = state
+            .balances
+            .iter()
+            .find(|balance| balance.currency == self.currency)
+        {
+            self.update_raw(balance.total.as_f64());
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::events::account::stubs::cash_account_state;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_value_with_no_inputs_is_nan() {
+        let drawdown = MaxDrawdown::new(Currency::USD());
+        assert!(drawdown.value().is_nan());
+    }
+
+    #[rstest]
+    fn test_max_drawdown() {
+        let mut drawdown = MaxDrawdown::new(Currency::USD());
+        for equity in [100.0, 120.0, 90.0, 110.0, 130.0, 117.0] {
+            drawdown.update_raw(equity);
+        }
+        assert_eq!(drawdown.peak, 130.0);
+        assert!((drawdown.drawdown - -0.1).abs() < 1e-12);
+        assert!((drawdown.value() - -0.25).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_handle_account_state(cash_account_state: AccountState) {
+        let mut drawdown = MaxDrawdown::new(Currency::USD());
+        drawdown.handle_account_state(&cash_account_state);
+        assert_eq!(drawdown.count, 1);
+        assert_eq!(drawdown.value(), 0.0);
+
+        let mut drawdown = MaxDrawdown::new(Currency::JPY());
+        drawdown.handle_account_state(&cash_account_state);
+        assert_eq!(drawdown.count, 0);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut drawdown = MaxDrawdown::new(Currency::USD());
+        drawdown.update_raw(100.0);
+        drawdown.update_raw(50.0);
+        drawdown.reset();
+        assert_eq!(drawdown.count, 0);
+        assert_eq!(drawdown.max_drawdown, 0.0);
+    }
+}
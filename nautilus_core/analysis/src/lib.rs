@@ -0,0 +1,28 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Streaming portfolio statistics.
+//!
+//! The statistics are updated incrementally on each position or account event, so they can be
+//! read at any point during a live session without recomputing over the full history.
+
+pub mod drawdown;
+pub mod returns;
+pub mod sharpe_ratio;
+pub mod sortino_ratio;
+pub mod statistic;
+
+#[cfg(feature = "python")]
+pub mod python;
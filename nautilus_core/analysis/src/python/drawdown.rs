@@ -0,0 +1,82 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{events::account::state::AccountState, types::currency::Currency};
+use pyo3::prelude::*;
+
+use crate::{drawdown::MaxDrawdown, statistic::StreamingStatistic};
+
+#[pymethods]
+impl MaxDrawdown {
+    #[new]
+    fn py_new(currency: Currency) -> Self {
+        Self::new(currency)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self}")
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "currency")]
+    fn py_currency(&self) -> Currency {
+        self.currency
+    }
+
+    #[getter]
+    #[pyo3(name = "peak")]
+    fn py_peak(&self) -> f64 {
+        self.peak
+    }
+
+    #[getter]
+    #[pyo3(name = "drawdown")]
+    fn py_drawdown(&self) -> f64 {
+        self.drawdown
+    }
+
+    #[getter]
+    #[pyo3(name = "count")]
+    fn py_count(&self) -> usize {
+        self.count
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    fn py_value(&self) -> f64 {
+        self.value()
+    }
+
+    #[pyo3(name = "update_raw")]
+    fn py_update_raw(&mut self, value: f64) {
+        self.update_raw(value);
+    }
+
+    #[pyo3(name = "handle_account_state")]
+    fn py_handle_account_state(&mut self, state: &AccountState) {
+        self.handle_account_state(state);
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+}
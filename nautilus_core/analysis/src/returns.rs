@@ -0,0 +1,201 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use nautilus_core::time::UnixNanos;
+
+const NANOSECONDS_IN_DAY: u64 = 86_400_000_000_000;
+
+/// Provides a rolling window of daily returns with running sums.
+///
+/// Returns are summed into daily (UTC) bins, with days without any returns counted as zero,
+/// equivalent to resampling the returns series into `1D` bins.
+#[derive(Clone, Debug)]
+pub struct DailyReturnsWindow {
+    pub window: usize,
+    values: VecDeque<f64>,
+    sum: f64,
+    sum_sq: f64,
+    downside_sum_sq: f64,
+    day: Option<u64>,
+}
+
+impl DailyReturnsWindow {
+    #[must_use]
+    pub fn new(window: usize) -> Self {
+        Self {
+            window,
+            values: VecDeque::with_capacity(window),
+            sum: 0.0,
+            sum_sq: 0.0,
+            downside_sum_sq: 0.0,
+            day: None,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Adds the return `value` to the daily bin for `ts`.
+    ///
+    /// Returns for a day earlier than the current bin are added to the current bin.
+    pub fn add(&mut self, ts: UnixNanos, value: f64) {
+        let day = ts / NANOSECONDS_IN_DAY;
+        match self.day {
+            Some(current) if day <= current => {
+                let last = self.values.pop_back().unwrap_or_default();
+                self.remove_contribution(last);
+                self.push(last + value);
+            }
+            Some(current) => {
+                let skipped = (day - current - 1).min(self.window as u64);
+                for _ in 0..skipped {
+                    self.push(0.0);
+                }
+                self.push(value);
+                self.day = Some(day);
+            }
+            None => {
+                self.push(value);
+                self.day = Some(day);
+            }
+        }
+    }
+
+    /// Adds a complete bin `value` to the window.
+    pub fn push(&mut self, value: f64) {
+        self.values.push_back(value);
+        self.add_contribution(value);
+        if self.values.len() > self.window {
+            if let Some(front) = self.values.pop_front() {
+                self.remove_contribution(front);
+            }
+        }
+    }
+
+    /// Returns the mean of the returns in the window.
+    #[must_use]
+    pub fn mean(&self) -> f64 {
+        if self.values.is_empty() {
+            return f64::NAN;
+        }
+        self.sum / self.values.len() as f64
+    }
+
+    /// Returns the sample standard deviation (one delta degree of freedom) of the window.
+    #[must_use]
+    pub fn std(&self) -> f64 {
+        let n = self.values.len() as f64;
+        if n < 2.0 {
+            return f64::NAN;
+        }
+        let variance = (self.sum_sq - self.sum * self.sum / n) / (n - 1.0);
+        variance.max(0.0).sqrt()
+    }
+
+    /// Returns the downside deviation (root mean square of the negative returns) of the window.
+    #[must_use]
+    pub fn downside_deviation(&self) -> f64 {
+        if self.values.is_empty() {
+            return f64::NAN;
+        }
+        (self.downside_sum_sq / self.values.len() as f64).sqrt()
+    }
+
+    pub fn reset(&mut self) {
+        self.values.clear();
+        self.sum = 0.0;
+        self.sum_sq = 0.0;
+        self.downside_sum_sq = 0.0;
+        self.day = None;
+    }
+
+    fn add_contribution(&mut self, value: f64) {
+        self.sum += value;
+        self.sum_sq += value * value;
+        if value < 0.0 {
+            self.downside_sum_sq += value * value;
+        }
+    }
+
+    fn remove_contribution(&mut self, value: f64) {
+        self.sum -= value;
+        self.sum_sq -= value * value;
+        if value < 0.0 {
+            self.downside_sum_sq -= value * value;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_returns_binned_daily() {
+        let mut window = DailyReturnsWindow::new(10);
+        window.add(0, 0.01);
+        window.add(NANOSECONDS_IN_DAY / 2, 0.02);
+        window.add(NANOSECONDS_IN_DAY, -0.01);
+
+        assert_eq!(window.len(), 2);
+        assert!((window.mean() - 0.01).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_skipped_days_count_as_zero() {
+        let mut window = DailyReturnsWindow::new(10);
+        window.add(0, 0.03);
+        window.add(3 * NANOSECONDS_IN_DAY, 0.03);
+
+        assert_eq!(window.len(), 4);
+        assert!((window.mean() - 0.015).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_window_drops_oldest_returns() {
+        let mut window = DailyReturnsWindow::new(2);
+        window.push(1.0);
+        window.push(2.0);
+        window.push(4.0);
+
+        assert_eq!(window.len(), 2);
+        assert_eq!(window.mean(), 3.0);
+        assert!((window.std() - 2.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_downside_deviation() {
+        let mut window = DailyReturnsWindow::new(4);
+        for value in [0.02, -0.02, 0.01, -0.04] {
+            window.push(value);
+        }
+
+        assert!((window.downside_deviation() - 0.0005_f64.sqrt()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,163 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::position::Position;
+
+use crate::{returns::DailyReturnsWindow, statistic::StreamingStatistic};
+
+/// Calculates the annualized Sharpe Ratio over a rolling window of daily returns.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct RollingSharpeRatio {
+    /// The trading period in days (used for annualization).
+    pub period: usize,
+    returns: DailyReturnsWindow,
+}
+
+impl Display for RollingSharpeRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({}, {})",
+            stringify!(RollingSharpeRatio),
+            self.window(),
+            self.period
+        )
+    }
+}
+
+impl StreamingStatistic for RollingSharpeRatio {
+    fn name(&self) -> String {
+        format!("Sharpe Ratio ({} days)", self.period)
+    }
+
+    fn value(&self) -> f64 {
+        let std = self.returns.std();
+        if std.is_nan() || std == 0.0 {
+            return f64::NAN;
+        }
+        self.returns.mean() / std * (self.period as f64).sqrt()
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        self.returns.push(value);
+    }
+
+    fn reset(&mut self) {
+        self.returns.reset();
+    }
+}
+
+impl RollingSharpeRatio {
+    /// Creates a new statistic over the last `window` daily returns, annualized using
+    /// `period` trading days (default 252).
+    #[must_use]
+    pub fn new(window: usize, period: Option<usize>) -> Self {
+        Self {
+            period: period.unwrap_or(252),
+            returns: DailyReturnsWindow::new(window),
+        }
+    }
+
+    #[must_use]
+    pub fn window(&self) -> usize {
+        self.returns.window
+    }
+
+    /// Returns the number of daily returns in the window.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.returns.len()
+    }
+
+    /// Adds the return `value` realized at `ts` to its daily bin.
+    pub fn handle_return(&mut self, ts: UnixNanos, value: f64) {
+        self.returns.add(ts, value);
+    }
+
+    /// Adds the realized return of the `position` once closed.
+    pub fn handle_position(&mut self, position: &Position) {
+        if let Some(ts_closed) = position.ts_closed {
+            self.handle_return(ts_closed, position.realized_return);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::stubs::test_position_long;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_name_and_display() {
+        let sharpe = RollingSharpeRatio::new(30, None);
+        assert_eq!(sharpe.name(), "Sharpe Ratio (252 days)");
+        assert_eq!(format!("{sharpe}"), "RollingSharpeRatio(30, 252)");
+    }
+
+    #[rstest]
+    fn test_value_with_insufficient_returns_is_nan() {
+        let mut sharpe = RollingSharpeRatio::new(30, None);
+        assert!(sharpe.value().is_nan());
+        sharpe.update_raw(0.01);
+        assert!(sharpe.value().is_nan());
+    }
+
+    #[rstest]
+    fn test_value() {
+        let mut sharpe = RollingSharpeRatio::new(30, Some(365));
+        for value in [0.01, 0.02, 0.03] {
+            sharpe.update_raw(value);
+        }
+        assert!((sharpe.value() - 2.0 * 365.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_value_over_rolling_window() {
+        let mut sharpe = RollingSharpeRatio::new(3, None);
+        for value in [-0.5, 0.01, 0.02, 0.03] {
+            sharpe.update_raw(value);
+        }
+        assert_eq!(sharpe.count(), 3);
+        assert!((sharpe.value() - 2.0 * 252.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_handle_position_when_open_does_nothing(test_position_long: Position) {
+        let mut sharpe = RollingSharpeRatio::new(30, None);
+        sharpe.handle_position(&test_position_long);
+        assert_eq!(sharpe.count(), 0);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut sharpe = RollingSharpeRatio::new(30, None);
+        sharpe.handle_return(0, 0.01);
+        sharpe.reset();
+        assert_eq!(sharpe.count(), 0);
+    }
+}
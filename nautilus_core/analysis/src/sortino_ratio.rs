@@ -0,0 +1,150 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::position::Position;
+
+use crate::{returns::DailyReturnsWindow, statistic::StreamingStatistic};
+
+/// Calculates the annualized Sortino Ratio over a rolling window of daily returns.
+///
+/// The downside deviation is the root mean square of the negative returns over all returns
+/// in the window.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct RollingSortinoRatio {
+    /// The trading period in days (used for annualization).
+    pub period: usize,
+    returns: DailyReturnsWindow,
+}
+
+impl Display for RollingSortinoRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({}, {})",
+            stringify!(RollingSortinoRatio),
+            self.window(),
+            self.period
+        )
+    }
+}
+
+impl StreamingStatistic for RollingSortinoRatio {
+    fn name(&self) -> String {
+        format!("Sortino Ratio ({} days)", self.period)
+    }
+
+    fn value(&self) -> f64 {
+        let downside = self.returns.downside_deviation();
+        if downside.is_nan() || downside == 0.0 {
+            return f64::NAN;
+        }
+        self.returns.mean() / downside * (self.period as f64).sqrt()
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        self.returns.push(value);
+    }
+
+    fn reset(&mut self) {
+        self.returns.reset();
+    }
+}
+
+impl RollingSortinoRatio {
+    /// Creates a new statistic over the last `window` daily returns, annualized using
+    /// `period` trading days (default 252).
+    #[must_use]
+    pub fn new(window: usize, period: Option<usize>) -> Self {
+        Self {
+            period: period.unwrap_or(252),
+            returns: DailyReturnsWindow::new(window),
+        }
+    }
+
+    #[must_use]
+    pub fn window(&self) -> usize {
+        self.returns.window
+    }
+
+    /// Returns the number of daily returns in the window.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.returns.len()
+    }
+
+    /// Adds the return `value` realized at `ts` to its daily bin.
+    pub fn handle_return(&mut self, ts: UnixNanos, value: f64) {
+        self.returns.add(ts, value);
+    }
+
+    /// Adds the realized return of the `position` once closed.
+    pub fn handle_position(&mut self, position: &Position) {
+        if let Some(ts_closed) = position.ts_closed {
+            self.handle_return(ts_closed, position.realized_return);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_name() {
+        let sortino = RollingSortinoRatio::new(30, None);
+        assert_eq!(sortino.name(), "Sortino Ratio (252 days)");
+    }
+
+    #[rstest]
+    fn test_value_without_negative_returns_is_nan() {
+        let mut sortino = RollingSortinoRatio::new(30, None);
+        sortino.update_raw(0.01);
+        sortino.update_raw(0.02);
+        assert!(sortino.value().is_nan());
+    }
+
+    #[rstest]
+    fn test_value() {
+        let mut sortino = RollingSortinoRatio::new(30, None);
+        for value in [0.03, -0.01, 0.02, -0.02] {
+            sortino.update_raw(value);
+        }
+        // mean = 0.005, downside = sqrt((0.0001 + 0.0004) / 4)
+        let expected = 0.005 / (0.0005_f64 / 4.0).sqrt() * 252.0_f64.sqrt();
+        assert!((sortino.value() - expected).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_returns_on_the_same_day_are_binned() {
+        let mut sortino = RollingSortinoRatio::new(30, None);
+        sortino.handle_return(1, 0.01);
+        sortino.handle_return(2, -0.03);
+        assert_eq!(sortino.count(), 1);
+        assert!((sortino.value() - (-0.02 / 0.02) * 252.0_f64.sqrt()).abs() < 1e-9);
+    }
+}
@@ -42,6 +42,29 @@ impl TimeEventAccumulator {
         self.event_handlers.extend(handlers);
     }
 
+    /// Advance each of the given clocks to the `to_time_ns`.
+    ///
+    /// Clocks with no timers due at or before `to_time_ns` are skipped (other than setting
+    /// their time when `set_time` is true), so the cost is proportional to the number of
+    /// clocks with events due rather than the total number of timers.
+    pub fn advance_clocks(
+        &mut self,
+        clocks: &mut [&mut TestClock],
+        to_time_ns: UnixNanos,
+        set_time: bool,
+    ) {
+        for clock in clocks.iter_mut() {
+            if clock
+                .next_timer_ns()
+                .is_some_and(|next_time_ns| next_time_ns <= to_time_ns)
+            {
+                self.advance_clock(clock, to_time_ns, set_time);
+            } else if set_time {
+                clock.set_time(to_time_ns);
+            }
+        }
+    }
+
     /// Drain the accumulated time event handlers in sorted order (by the events `ts_event`).
     pub fn drain(&mut self) -> Vec<TimeEventHandler> {
         // stable sort is not necessary since there is no relation between
@@ -0,0 +1,218 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Iceberg (display quantity) order simulation.
+//!
+//! An iceberg order only exposes its display quantity to the book. Once the visible portion
+//! is completely filled, it is refreshed from the hidden quantity and re-enters the queue at
+//! the back of its price level, matching typical venue behavior.
+
+use std::cmp::min;
+
+use nautilus_model::{
+    data::order::{BookOrder, OrderId},
+    enums::OrderSide,
+    identifiers::client_order_id::ClientOrderId,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents the visible and hidden state of a resting iceberg order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IcebergOrder {
+    /// The client order ID of the iceberg order.
+    pub client_order_id: ClientOrderId,
+    /// The order ID of the visible portion in the simulated book.
+    pub book_order_id: OrderId,
+    /// The order side.
+    pub side: OrderSide,
+    /// The order (limit) price.
+    pub price: Price,
+    /// The maximum quantity displayed at any one time.
+    pub display_qty: Quantity,
+    /// The total quantity remaining to be filled (visible and hidden).
+    pub leaves_qty: Quantity,
+    /// The currently displayed quantity.
+    pub visible_qty: Quantity,
+}
+
+impl IcebergOrder {
+    /// Creates a new [`IcebergOrder`] instance, displaying up to `display_qty` of `leaves_qty`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `display_qty` is not positive or is greater than `leaves_qty`.
+    pub fn new(
+        client_order_id: ClientOrderId,
+        book_order_id: OrderId,
+        side: OrderSide,
+        price: Price,
+        display_qty: Quantity,
+        leaves_qty: Quantity,
+    ) -> anyhow::Result<Self> {
+        if !display_qty.is_positive() {
+            anyhow::bail!("Invalid `display_qty` for {client_order_id}, was {display_qty}");
+        }
+        if display_qty > leaves_qty {
+            anyhow::bail!(
+                "Invalid `display_qty` for {client_order_id}, was {display_qty} \
+                which is greater than the leaves quantity {leaves_qty}"
+            );
+        }
+
+        Ok(Self {
+            client_order_id,
+            book_order_id,
+            side,
+            price,
+            display_qty,
+            leaves_qty,
+            visible_qty: display_qty,
+        })
+    }
+
+    /// Returns the quantity not currently displayed.
+    #[must_use]
+    pub fn hidden_qty(&self) -> Quantity {
+        self.leaves_qty - self.visible_qty
+    }
+
+    /// Returns the visible portion of the order as a book order.
+    #[must_use]
+    pub fn book_order(&self) -> BookOrder {
+        BookOrder::new(self.side, self.price, self.visible_qty, self.book_order_id)
+    }
+
+    /// Applies a fill of `last_qty` against the visible portion of the order.
+    ///
+    /// Returns `true` if the visible portion was exhausted and refreshed from the hidden
+    /// quantity, in which case the order loses its queue priority.
+    pub fn apply_fill(&mut self, last_qty: Quantity) -> bool {
+        let fill_qty = min(last_qty, self.leaves_qty);
+        self.leaves_qty -= fill_qty;
+        self.visible_qty -= min(fill_qty, self.visible_qty);
+
+        if self.visible_qty.is_zero() && self.leaves_qty.is_positive() {
+            self.visible_qty = min(self.display_qty, self.leaves_qty);
+            return true;
+        }
+        false
+    }
+
+    /// Returns whether the order has been completely filled.
+    #[must_use]
+    pub fn is_filled(&self) -> bool {
+        self.leaves_qty.is_zero()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        identifiers::instrument_id::InstrumentId, orderbook::book_mbo::OrderBookMbo,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn iceberg(display_qty: i64, leaves_qty: i64) -> IcebergOrder {
+        IcebergOrder::new(
+            ClientOrderId::from("O-1"),
+            100,
+            OrderSide::Buy,
+            Price::from("10.00"),
+            Quantity::from(display_qty),
+            Quantity::from(leaves_qty),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case(0, 100)]
+    #[case(200, 100)]
+    fn test_new_with_invalid_display_qty(#[case] display_qty: i64, #[case] leaves_qty: i64) {
+        let result = IcebergOrder::new(
+            ClientOrderId::from("O-1"),
+            100,
+            OrderSide::Buy,
+            Price::from("10.00"),
+            Quantity::from(display_qty),
+            Quantity::from(leaves_qty),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_new_displays_only_display_qty() {
+        let order = iceberg(10, 100);
+
+        assert_eq!(order.visible_qty, Quantity::from(10));
+        assert_eq!(order.hidden_qty(), Quantity::from(90));
+        assert_eq!(order.book_order().size, Quantity::from(10));
+    }
+
+    #[rstest]
+    fn test_partial_fill_of_visible_does_not_refresh() {
+        let mut order = iceberg(10, 100);
+
+        let refreshed = order.apply_fill(Quantity::from(4));
+
+        assert!(!refreshed);
+        assert_eq!(order.visible_qty, Quantity::from(6));
+        assert_eq!(order.leaves_qty, Quantity::from(96));
+    }
+
+    #[rstest]
+    fn test_fill_of_visible_refreshes_from_hidden() {
+        let mut order = iceberg(10, 25);
+
+        assert!(order.apply_fill(Quantity::from(10)));
+        assert_eq!(order.visible_qty, Quantity::from(10));
+        assert_eq!(order.leaves_qty, Quantity::from(15));
+
+        assert!(order.apply_fill(Quantity::from(10)));
+        assert_eq!(order.visible_qty, Quantity::from(5)); // Only the remainder is displayed
+        assert_eq!(order.hidden_qty(), Quantity::from(0));
+
+        assert!(!order.apply_fill(Quantity::from(5)));
+        assert!(order.is_filled());
+    }
+
+    #[rstest]
+    fn test_refresh_loses_queue_priority() {
+        let mut book = OrderBookMbo::new(InstrumentId::from("AAPL.XNAS"));
+        let mut order = iceberg(10, 100);
+        let other = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(50), 1);
+        book.add(order.book_order(), 0, 1);
+        book.add(other, 0, 2);
+
+        assert_eq!(
+            book.get_queue_position(order.book_order_id),
+            Some((Price::from("10.00"), 0, Quantity::from(0)))
+        );
+
+        book.delete(order.book_order(), 0, 3);
+        assert!(order.apply_fill(Quantity::from(10)));
+        book.add(order.book_order(), 0, 4);
+
+        assert_eq!(
+            book.get_queue_position(order.book_order_id),
+            Some((Price::from("10.00"), 1, Quantity::from(50)))
+        );
+    }
+}
@@ -14,4 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod engine;
+pub mod iceberg;
 pub mod matching_engine;
+pub mod models;
+pub mod self_trade;
@@ -18,15 +18,35 @@
 use std::collections::HashMap;
 
 use nautilus_common::msgbus::MessageBus;
-use nautilus_core::time::AtomicTime;
-use nautilus_execution::matching_core::OrderMatchingCore;
+use nautilus_core::{
+    calendar::TradingCalendar,
+    time::{AtomicTime, UnixNanos},
+};
+use nautilus_execution::{
+    contingency::{resolve_contingencies, resolve_contingencies_update, ContingencyAction},
+    matching_core::OrderMatchingCore,
+    trailing::TrailingStopUpdate,
+};
 use nautilus_model::{
-    data::bar::Bar,
-    enums::{AccountType, BookType, MarketStatus, OmsType},
-    identifiers::{account_id::AccountId, trader_id::TraderId, venue::Venue},
-    instruments::Instrument,
+    data::{bar::Bar, order::OrderId, quote::QuoteTick},
+    enums::{AccountType, BookType, LiquiditySide, MarketStatus, OmsType, OrderSide},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        trader_id::TraderId, venue::Venue,
+    },
+    instruments::{
+        combo::{ComboInstrument, ComboLeg},
+        Instrument,
+    },
     orderbook::{book_mbo::OrderBookMbo, book_mbp::OrderBookMbp},
-    types::price::Price,
+    orders::base::{LimitOrderType, Order, PassiveOrderType},
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+use crate::{
+    iceberg::IcebergOrder,
+    models::fee::FeeModel,
+    self_trade::{resolve_self_trades, SelfTradeAction, SelfTradePreventionMode},
 };
 
 pub struct OrderMatchingEngineConfig {
@@ -37,6 +57,7 @@ pub struct OrderMatchingEngineConfig {
     pub use_position_ids: bool,
     pub use_random_ids: bool,
     pub use_reduce_only: bool,
+    pub self_trade_prevention: SelfTradePreventionMode,
 }
 
 pub struct OrderMatchingEngine {
@@ -48,6 +69,10 @@ pub struct OrderMatchingEngine {
     pub account_type: AccountType,
     pub market_status: MarketStatus,
     pub config: OrderMatchingEngineConfig,
+    /// The optional trading calendar used to gate fills outside of venue sessions.
+    pub trading_calendar: Option<TradingCalendar>,
+    /// The fee model used to calculate commissions for fills.
+    pub fee_model: Box<dyn FeeModel>,
     // pub cache: Cache  // TODO!
     clock: &'static AtomicTime,
     msgbus: &'static MessageBus,
@@ -63,4 +88,230 @@ pub struct OrderMatchingEngine {
     position_count: usize,
     order_count: usize,
     execution_count: usize,
+    icebergs: HashMap<ClientOrderId, IcebergOrder>,
+    iceberg_count: u64,
+}
+
+/// The base order ID for simulated iceberg orders in the L3 book, chosen to avoid colliding
+/// with venue order IDs from market data.
+const ICEBERG_ORDER_ID_BASE: OrderId = 1 << 63;
+
+impl OrderMatchingEngine {
+    /// Returns whether orders may be filled at the given UNIX timestamp (nanoseconds).
+    ///
+    /// Always returns `true` when no trading calendar is configured.
+    #[must_use]
+    pub fn is_session_open(&self, ts: UnixNanos) -> bool {
+        self.trading_calendar
+            .as_ref()
+            .map_or(true, |calendar| calendar.is_open(ts))
+    }
+
+    /// Returns the legs of the engine's instrument if it is a combo, otherwise `None`.
+    #[must_use]
+    pub fn combo_legs(&self) -> Option<&[ComboLeg]> {
+        self.instrument
+            .as_any()
+            .downcast_ref::<ComboInstrument>()
+            .map(|combo| combo.legs.as_slice())
+    }
+
+    /// Updates the synthetic top-of-book for a combo instrument from the latest
+    /// quotes of its legs.
+    ///
+    /// Returns an error if the engine's instrument is not a combo, or if a quote
+    /// for any leg is missing.
+    pub fn process_leg_quotes(
+        &mut self,
+        leg_quotes: &HashMap<InstrumentId, QuoteTick>,
+    ) -> anyhow::Result<()> {
+        let combo = self
+            .instrument
+            .as_any()
+            .downcast_ref::<ComboInstrument>()
+            .ok_or_else(|| anyhow::anyhow!("Instrument {} is not a combo", self.instrument.id()))?;
+        let (bid, ask) = combo.calculate_quote(leg_quotes)?;
+        self.core.bid = Some(bid);
+        self.core.ask = Some(ask);
+        Ok(())
+    }
+
+    /// Returns the actions to apply to the orders linked to `order` after it has been
+    /// filled, canceled, expired or rejected.
+    ///
+    /// Returns no actions when contingent orders are not supported by the venue.
+    pub fn contingency_actions<'a, F>(
+        &self,
+        order: &dyn Order,
+        lookup: F,
+    ) -> anyhow::Result<Vec<ContingencyAction>>
+    where
+        F: Fn(&ClientOrderId) -> Option<&'a dyn Order>,
+    {
+        if !self.config.support_contingent_orders {
+            return Ok(Vec::new());
+        }
+        resolve_contingencies(order, lookup)
+    }
+
+    /// Returns the actions to apply to the orders linked to `order` after it has been
+    /// modified.
+    ///
+    /// Returns no actions when contingent orders are not supported by the venue.
+    pub fn contingency_update_actions<'a, F>(
+        &self,
+        order: &dyn Order,
+        lookup: F,
+    ) -> anyhow::Result<Vec<ContingencyAction>>
+    where
+        F: Fn(&ClientOrderId) -> Option<&'a dyn Order>,
+    {
+        if !self.config.support_contingent_orders {
+            return Ok(Vec::new());
+        }
+        resolve_contingencies_update(order, lookup)
+    }
+
+    /// Trails the open trailing stop orders from the current market prices, returning the
+    /// updates applied (from which `OrderUpdated` events are generated).
+    pub fn update_trailing_stop_orders(&mut self) -> anyhow::Result<Vec<TrailingStopUpdate>> {
+        self.core.update_trailing_stop_orders()
+    }
+
+    /// Registers a resting limit `order` with a display quantity as an iceberg order, adding
+    /// its visible portion to the L3 book (if any) for queue position modeling.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order has no price or display quantity, or if the display
+    /// quantity is invalid.
+    pub fn add_iceberg_order(&mut self, order: &dyn Order, ts_event: u64) -> anyhow::Result<()> {
+        let client_order_id = order.client_order_id();
+        let price = order
+            .price()
+            .ok_or_else(|| anyhow::anyhow!("No `price` for iceberg order {client_order_id}"))?;
+        let display_qty = order.display_qty().ok_or_else(|| {
+            anyhow::anyhow!("No `display_qty` for iceberg order {client_order_id}")
+        })?;
+
+        self.iceberg_count += 1;
+        let iceberg = IcebergOrder::new(
+            client_order_id,
+            ICEBERG_ORDER_ID_BASE + self.iceberg_count,
+            order.side(),
+            price,
+            display_qty,
+            order.leaves_qty(),
+        )?;
+
+        if let Some(book) = self.book_mbo.as_mut() {
+            book.add(iceberg.book_order(), ts_event, 0);
+        }
+        self.icebergs.insert(client_order_id, iceberg);
+        Ok(())
+    }
+
+    /// Returns the currently displayed quantity of the iceberg order (if found).
+    #[must_use]
+    pub fn iceberg_visible_qty(&self, client_order_id: &ClientOrderId) -> Option<Quantity> {
+        self.icebergs.get(client_order_id).map(|o| o.visible_qty)
+    }
+
+    /// Returns the queue position of the visible portion of the iceberg order in the L3
+    /// book, as the level price, zero-based position and size ahead (if found).
+    #[must_use]
+    pub fn iceberg_queue_position(
+        &self,
+        client_order_id: &ClientOrderId,
+    ) -> Option<(Price, usize, Quantity)> {
+        let iceberg = self.icebergs.get(client_order_id)?;
+        self.book_mbo
+            .as_ref()?
+            .get_queue_position(iceberg.book_order_id)
+    }
+
+    /// Applies a fill of `last_qty` to the iceberg order, refreshing its visible portion from
+    /// the hidden quantity once exhausted.
+    ///
+    /// Returns the refreshed visible quantity when a refresh occurred. A refreshed order
+    /// re-enters the book at the back of the queue, while a partially filled visible
+    /// portion keeps its queue priority.
+    pub fn process_iceberg_fill(
+        &mut self,
+        client_order_id: &ClientOrderId,
+        last_qty: Quantity,
+        ts_event: u64,
+    ) -> Option<Quantity> {
+        let iceberg = self.icebergs.get_mut(client_order_id)?;
+        let book_order_before = iceberg.book_order();
+        let refreshed = iceberg.apply_fill(last_qty);
+        let book_order = iceberg.book_order();
+        let is_filled = iceberg.is_filled();
+
+        if let Some(book) = self.book_mbo.as_mut() {
+            if refreshed || is_filled {
+                book.delete(book_order_before, ts_event, 0);
+            }
+            if refreshed {
+                book.add(book_order, ts_event, 0);
+            } else if !is_filled {
+                book.update(book_order, ts_event, 0);
+            }
+        }
+
+        if is_filled {
+            self.icebergs.remove(client_order_id);
+        }
+
+        refreshed.then_some(book_order.size)
+    }
+
+    /// Returns the commission for a fill of the engines instrument, as determined by the
+    /// configured fee model.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the fee model fails to calculate the commission.
+    pub fn calculate_commission(
+        &mut self,
+        liquidity_side: LiquiditySide,
+        last_qty: Quantity,
+        last_px: Price,
+    ) -> anyhow::Result<Money> {
+        self.fee_model
+            .get_commission(self.instrument.as_ref(), liquidity_side, last_qty, last_px)
+    }
+
+    /// Returns the actions required to prevent the `incoming` order trading against resting
+    /// orders of the same trader, according to the configured self-trade prevention mode.
+    #[must_use]
+    pub fn self_trade_actions(&self, incoming: &dyn Order) -> Vec<SelfTradeAction> {
+        let resting = match incoming.side() {
+            OrderSide::Buy => self.core.get_orders_ask(),
+            OrderSide::Sell => self.core.get_orders_bid(),
+            OrderSide::NoOrderSide => return Vec::new(),
+        };
+        let resting: Vec<&dyn Order> = resting.iter().filter_map(resting_limit_order).collect();
+        resolve_self_trades(self.config.self_trade_prevention, incoming, &resting)
+    }
+
+    /// Removes the iceberg order (when canceled or expired), deleting its visible portion
+    /// from the L3 book.
+    pub fn remove_iceberg_order(&mut self, client_order_id: &ClientOrderId, ts_event: u64) {
+        if let Some(iceberg) = self.icebergs.remove(client_order_id) {
+            if let Some(book) = self.book_mbo.as_mut() {
+                book.delete(iceberg.book_order(), ts_event, 0);
+            }
+        }
+    }
+}
+
+fn resting_limit_order(order: &PassiveOrderType) -> Option<&dyn Order> {
+    match order {
+        PassiveOrderType::Limit(LimitOrderType::Limit(o)) => Some(o),
+        PassiveOrderType::Limit(LimitOrderType::MarketToLimit(o)) => Some(o),
+        PassiveOrderType::Limit(LimitOrderType::StopLimit(o)) => Some(o),
+        PassiveOrderType::Limit(LimitOrderType::TrailingStopLimit(o)) => Some(o),
+        PassiveOrderType::Stop(_) => None,
+    }
 }
@@ -0,0 +1,397 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Commission (fee) models for simulated fills.
+//!
+//! The maker/taker rate applied to a fill is selected from its liquidity side, where
+//! `MAKER` fills rested on the book and `TAKER` fills removed liquidity.
+
+use nautilus_model::{
+    enums::LiquiditySide,
+    instruments::Instrument,
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+/// Provides commission calculations for simulated fills.
+pub trait FeeModel {
+    /// Returns the commission for a fill of `fill_qty` at `fill_px` with the given `liquidity_side`.
+    ///
+    /// Models may be stateful (for example tracking traded volume), so every call is
+    /// assumed to correspond to an actual fill.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `liquidity_side` is `NoLiquiditySide`, or the commission is invalid.
+    fn get_commission(
+        &mut self,
+        instrument: &dyn Instrument,
+        liquidity_side: LiquiditySide,
+        fill_qty: Quantity,
+        fill_px: Price,
+    ) -> anyhow::Result<Money>;
+}
+
+/// Applies the instruments maker and taker fee rates to the notional value of each fill.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MakerTakerFeeModel;
+
+impl FeeModel for MakerTakerFeeModel {
+    fn get_commission(
+        &mut self,
+        instrument: &dyn Instrument,
+        liquidity_side: LiquiditySide,
+        fill_qty: Quantity,
+        fill_px: Price,
+    ) -> anyhow::Result<Money> {
+        let rate = fee_rate(
+            liquidity_side,
+            instrument.maker_fee(),
+            instrument.taker_fee(),
+        )?;
+        notional_commission(instrument, fill_qty, fill_px, rate)
+    }
+}
+
+/// Represents a single tier of a volume based fee schedule.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FeeTier {
+    /// The cumulative notional volume from which the tier applies.
+    pub min_volume: f64,
+    /// The fee rate applied to `MAKER` fills.
+    pub maker_fee: Decimal,
+    /// The fee rate applied to `TAKER` fills.
+    pub taker_fee: Decimal,
+}
+
+/// Applies maker and taker fee rates from a tiered volume schedule.
+///
+/// The tier is selected from the cumulative notional volume traded *prior* to each fill,
+/// so a fill which crosses a tier boundary is charged entirely at the lower tier.
+#[derive(Clone, Debug)]
+pub struct TieredFeeModel {
+    tiers: Vec<FeeTier>,
+    cumulative_volume: f64,
+}
+
+impl TieredFeeModel {
+    /// Creates a new [`TieredFeeModel`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `tiers` is empty, the first tier does not start from zero volume,
+    /// or the tier volumes are not strictly increasing.
+    pub fn new(tiers: Vec<FeeTier>) -> anyhow::Result<Self> {
+        let Some(first) = tiers.first() else {
+            anyhow::bail!("Invalid `tiers`, was empty");
+        };
+        if first.min_volume != 0.0 {
+            anyhow::bail!(
+                "Invalid `tiers`, first tier `min_volume` must be 0, was {}",
+                first.min_volume
+            );
+        }
+        if tiers.windows(2).any(|w| w[0].min_volume >= w[1].min_volume) {
+            anyhow::bail!("Invalid `tiers`, `min_volume` must be strictly increasing");
+        }
+
+        Ok(Self {
+            tiers,
+            cumulative_volume: 0.0,
+        })
+    }
+
+    /// Returns the cumulative notional volume charged by the model.
+    #[must_use]
+    pub fn cumulative_volume(&self) -> f64 {
+        self.cumulative_volume
+    }
+
+    /// Returns the tier applicable for the current cumulative volume.
+    #[must_use]
+    pub fn current_tier(&self) -> &FeeTier {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| self.cumulative_volume >= tier.min_volume)
+            .unwrap_or(&self.tiers[0])
+    }
+
+    /// Resets the cumulative volume (for example at the start of a new fee period).
+    pub fn reset(&mut self) {
+        self.cumulative_volume = 0.0;
+    }
+}
+
+impl FeeModel for TieredFeeModel {
+    fn get_commission(
+        &mut self,
+        instrument: &dyn Instrument,
+        liquidity_side: LiquiditySide,
+        fill_qty: Quantity,
+        fill_px: Price,
+    ) -> anyhow::Result<Money> {
+        let tier = self.current_tier();
+        let rate = fee_rate(liquidity_side, tier.maker_fee, tier.taker_fee)?;
+        let commission = notional_commission(instrument, fill_qty, fill_px, rate)?;
+        self.cumulative_volume += instrument
+            .calculate_notional_value(fill_qty, fill_px, None)
+            .as_f64();
+        Ok(commission)
+    }
+}
+
+/// Charges a fixed commission per contract (or share), subject to an optional minimum per fill.
+#[derive(Clone, Copy, Debug)]
+pub struct PerContractFeeModel {
+    /// The commission charged per contract for `MAKER` fills.
+    pub maker_commission: Money,
+    /// The commission charged per contract for `TAKER` fills.
+    pub taker_commission: Money,
+    /// The minimum commission charged per fill.
+    pub min_commission: Option<Money>,
+}
+
+impl PerContractFeeModel {
+    /// Creates a new [`PerContractFeeModel`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the commissions are not all denominated in the same currency.
+    pub fn new(
+        maker_commission: Money,
+        taker_commission: Money,
+        min_commission: Option<Money>,
+    ) -> anyhow::Result<Self> {
+        let currency = maker_commission.currency;
+        if taker_commission.currency != currency
+            || min_commission.is_some_and(|min| min.currency != currency)
+        {
+            anyhow::bail!(
+                "Invalid commissions, currencies must match {}",
+                currency.code
+            );
+        }
+
+        Ok(Self {
+            maker_commission,
+            taker_commission,
+            min_commission,
+        })
+    }
+}
+
+impl FeeModel for PerContractFeeModel {
+    fn get_commission(
+        &mut self,
+        _instrument: &dyn Instrument,
+        liquidity_side: LiquiditySide,
+        fill_qty: Quantity,
+        _fill_px: Price,
+    ) -> anyhow::Result<Money> {
+        let per_contract = match liquidity_side {
+            LiquiditySide::Maker => self.maker_commission,
+            LiquiditySide::Taker => self.taker_commission,
+            LiquiditySide::NoLiquiditySide => {
+                anyhow::bail!("Invalid `liquidity_side`, was {liquidity_side}")
+            }
+        };
+        let mut amount = per_contract.as_f64() * fill_qty.as_f64();
+        if let Some(min_commission) = self.min_commission {
+            amount = amount.max(min_commission.as_f64());
+        }
+        Money::new(amount, per_contract.currency)
+    }
+}
+
+fn fee_rate(
+    liquidity_side: LiquiditySide,
+    maker_fee: Decimal,
+    taker_fee: Decimal,
+) -> anyhow::Result<f64> {
+    let rate = match liquidity_side {
+        LiquiditySide::Maker => maker_fee,
+        LiquiditySide::Taker => taker_fee,
+        LiquiditySide::NoLiquiditySide => {
+            anyhow::bail!("Invalid `liquidity_side`, was {liquidity_side}")
+        }
+    };
+    rate.to_f64()
+        .ok_or_else(|| anyhow::anyhow!("Invalid fee rate, was {rate}"))
+}
+
+fn notional_commission(
+    instrument: &dyn Instrument,
+    fill_qty: Quantity,
+    fill_px: Price,
+    rate: f64,
+) -> anyhow::Result<Money> {
+    // Inverse instruments are charged in the base currency of the notional value
+    let notional = instrument.calculate_notional_value(fill_qty, fill_px, None);
+    Money::new(notional.as_f64() * rate, notional.currency)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        instruments::{
+            currency_pair::CurrencyPair,
+            equity::Equity,
+            stubs::{audusd_sim, equity_aapl},
+        },
+        types::currency::Currency,
+    };
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn tiers() -> Vec<FeeTier> {
+        vec![
+            FeeTier {
+                min_volume: 0.0,
+                maker_fee: dec!(0.0002),
+                taker_fee: dec!(0.0004),
+            },
+            FeeTier {
+                min_volume: 1_000_000.0,
+                maker_fee: dec!(0.0001),
+                taker_fee: dec!(0.0002),
+            },
+        ]
+    }
+
+    #[rstest]
+    #[case(LiquiditySide::Maker)]
+    #[case(LiquiditySide::Taker)]
+    fn test_maker_taker_fee_model(audusd_sim: CurrencyPair, #[case] liquidity_side: LiquiditySide) {
+        let mut model = MakerTakerFeeModel;
+
+        let commission = model
+            .get_commission(
+                &audusd_sim,
+                liquidity_side,
+                Quantity::from(100_000),
+                Price::from("1.00000"),
+            )
+            .unwrap();
+
+        assert_eq!(commission, Money::new(2.0, Currency::USD()).unwrap());
+    }
+
+    #[rstest]
+    fn test_maker_taker_fee_model_no_liquidity_side_errors(audusd_sim: CurrencyPair) {
+        let mut model = MakerTakerFeeModel;
+
+        let result = model.get_commission(
+            &audusd_sim,
+            LiquiditySide::NoLiquiditySide,
+            Quantity::from(100_000),
+            Price::from("1.00000"),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_tiered_fee_model_new_validates_tiers() {
+        assert!(TieredFeeModel::new(vec![]).is_err());
+        assert!(TieredFeeModel::new(tiers().into_iter().rev().collect()).is_err());
+        assert!(TieredFeeModel::new(tiers()).is_ok());
+    }
+
+    #[rstest]
+    fn test_tiered_fee_model_moves_up_tiers_with_volume(audusd_sim: CurrencyPair) {
+        let mut model = TieredFeeModel::new(tiers()).unwrap();
+        let qty = Quantity::from(1_000_000);
+        let px = Price::from("1.00000");
+
+        let first = model
+            .get_commission(&audusd_sim, LiquiditySide::Taker, qty, px)
+            .unwrap();
+        let second = model
+            .get_commission(&audusd_sim, LiquiditySide::Taker, qty, px)
+            .unwrap();
+        let maker = model
+            .get_commission(&audusd_sim, LiquiditySide::Maker, qty, px)
+            .unwrap();
+
+        assert_eq!(first, Money::new(400.0, Currency::USD()).unwrap());
+        assert_eq!(second, Money::new(200.0, Currency::USD()).unwrap());
+        assert_eq!(maker, Money::new(100.0, Currency::USD()).unwrap());
+        assert_eq!(model.cumulative_volume(), 3_000_000.0);
+    }
+
+    #[rstest]
+    fn test_tiered_fee_model_reset(audusd_sim: CurrencyPair) {
+        let mut model = TieredFeeModel::new(tiers()).unwrap();
+        model
+            .get_commission(
+                &audusd_sim,
+                LiquiditySide::Taker,
+                Quantity::from(2_000_000),
+                Price::from("1.00000"),
+            )
+            .unwrap();
+
+        model.reset();
+
+        assert_eq!(model.cumulative_volume(), 0.0);
+        assert_eq!(model.current_tier().min_volume, 0.0);
+    }
+
+    #[rstest]
+    #[case(LiquiditySide::Maker, 100, 1.0)]
+    #[case(LiquiditySide::Taker, 100, 2.0)]
+    #[case(LiquiditySide::Taker, 10, 1.0)]
+    fn test_per_contract_fee_model(
+        equity_aapl: Equity,
+        #[case] liquidity_side: LiquiditySide,
+        #[case] fill_qty: i64,
+        #[case] expected: f64,
+    ) {
+        let mut model = PerContractFeeModel::new(
+            Money::new(0.01, Currency::USD()).unwrap(),
+            Money::new(0.02, Currency::USD()).unwrap(),
+            Some(Money::new(1.0, Currency::USD()).unwrap()),
+        )
+        .unwrap();
+
+        let commission = model
+            .get_commission(
+                &equity_aapl,
+                liquidity_side,
+                Quantity::from(fill_qty),
+                Price::from("150.00"),
+            )
+            .unwrap();
+
+        assert_eq!(commission, Money::new(expected, Currency::USD()).unwrap());
+    }
+
+    #[rstest]
+    fn test_per_contract_fee_model_mismatched_currencies_errors() {
+        let result = PerContractFeeModel::new(
+            Money::new(0.01, Currency::USD()).unwrap(),
+            Money::new(0.01, Currency::EUR()).unwrap(),
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,348 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Simulated venue self-trade prevention (STP).
+//!
+//! A self-trade occurs when an incoming order from a trader would match against one of the
+//! same traders resting orders. Venues prevent this by canceling (or decrementing) one or both
+//! of the orders instead of filling them.
+
+use std::cmp::{min, Reverse};
+
+use nautilus_model::{
+    enums::OrderSide, identifiers::client_order_id::ClientOrderId, orders::base::Order,
+    types::quantity::Quantity,
+};
+
+/// The self-trade prevention mode applied by a simulated venue.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum SelfTradePreventionMode {
+    /// Self-trades are allowed and will fill.
+    #[default]
+    None,
+    /// The incoming (newest) order is canceled.
+    CancelNewest,
+    /// The resting (oldest) orders are canceled, and the incoming order continues to match.
+    CancelOldest,
+    /// Both the incoming order and the first matching resting order are canceled.
+    CancelBoth,
+    /// The smaller order is canceled and the larger order is decremented by its quantity
+    /// (both are canceled when equal).
+    DecrementAndCancel,
+}
+
+/// Represents an action a venue takes to prevent a self-trade.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelfTradeAction {
+    /// Cancel the order.
+    Cancel(ClientOrderId),
+    /// Reduce the order quantity to the given quantity.
+    ModifyQuantity(ClientOrderId, Quantity),
+}
+
+/// Returns whether the `incoming` order would trade against the `resting` order of the same trader.
+#[must_use]
+pub fn is_self_trade(incoming: &dyn Order, resting: &dyn Order) -> bool {
+    if incoming.trader_id() != resting.trader_id() || incoming.side() == resting.side() {
+        return false;
+    }
+
+    let Some(resting_px) = resting.price() else {
+        return false; // Not resting on the book
+    };
+
+    match (incoming.side(), incoming.price()) {
+        (_, None) => true, // Marketable at any price
+        (OrderSide::Buy, Some(px)) => resting_px <= px,
+        (OrderSide::Sell, Some(px)) => resting_px >= px,
+        (OrderSide::NoOrderSide, _) => false,
+    }
+}
+
+/// Returns the actions required to prevent the `incoming` order trading against any of the
+/// `resting` orders, according to the given `mode`.
+///
+/// Resting orders are considered in price-time priority, where the given `resting` slice is
+/// assumed to be in time priority.
+#[must_use]
+pub fn resolve_self_trades(
+    mode: SelfTradePreventionMode,
+    incoming: &dyn Order,
+    resting: &[&dyn Order],
+) -> Vec<SelfTradeAction> {
+    if mode == SelfTradePreventionMode::None {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<&dyn Order> = resting
+        .iter()
+        .copied()
+        .filter(|order| is_self_trade(incoming, *order))
+        .collect();
+    if matches.is_empty() {
+        return Vec::new();
+    }
+
+    // Stable sorts preserve time priority within each price level
+    match incoming.side() {
+        OrderSide::Buy => matches.sort_by_key(|order| order.price()),
+        _ => matches.sort_by_key(|order| Reverse(order.price())),
+    }
+
+    let incoming_id = incoming.client_order_id();
+    match mode {
+        SelfTradePreventionMode::None => Vec::new(),
+        SelfTradePreventionMode::CancelNewest => vec![SelfTradeAction::Cancel(incoming_id)],
+        SelfTradePreventionMode::CancelOldest => matches
+            .iter()
+            .map(|order| SelfTradeAction::Cancel(order.client_order_id()))
+            .collect(),
+        SelfTradePreventionMode::CancelBoth => vec![
+            SelfTradeAction::Cancel(matches[0].client_order_id()),
+            SelfTradeAction::Cancel(incoming_id),
+        ],
+        SelfTradePreventionMode::DecrementAndCancel => decrement_and_cancel(incoming, &matches),
+    }
+}
+
+fn decrement_and_cancel(incoming: &dyn Order, matches: &[&dyn Order]) -> Vec<SelfTradeAction> {
+    let mut actions = Vec::new();
+    let mut incoming_leaves = incoming.leaves_qty();
+
+    for resting in matches {
+        let resting_leaves = resting.leaves_qty();
+        let decrement = min(incoming_leaves, resting_leaves);
+        incoming_leaves -= decrement;
+
+        if decrement == resting_leaves {
+            actions.push(SelfTradeAction::Cancel(resting.client_order_id()));
+        } else {
+            actions.push(SelfTradeAction::ModifyQuantity(
+                resting.client_order_id(),
+                resting.quantity() - decrement,
+            ));
+        }
+
+        if incoming_leaves.is_zero() {
+            actions.push(SelfTradeAction::Cancel(incoming.client_order_id()));
+            return actions;
+        }
+    }
+
+    let decremented = incoming.leaves_qty() - incoming_leaves;
+    actions.push(SelfTradeAction::ModifyQuantity(
+        incoming.client_order_id(),
+        incoming.quantity() - decremented,
+    ));
+    actions
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        identifiers::{instrument_id::InstrumentId, trader_id::TraderId},
+        orders::{limit::LimitOrder, stubs::TestOrderStubs},
+        types::price::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn limit_order(side: OrderSide, price: &str, quantity: i64, id: &str) -> LimitOrder {
+        TestOrderStubs::limit_order(
+            InstrumentId::from("AAPL.XNAS"),
+            side,
+            Price::from(price),
+            Quantity::from(quantity),
+            Some(ClientOrderId::from(id)),
+            None,
+        )
+    }
+
+    #[rstest]
+    fn test_is_self_trade_when_crossing() {
+        let incoming = limit_order(OrderSide::Buy, "100.00", 100, "O-1");
+        let resting = limit_order(OrderSide::Sell, "99.00", 100, "O-2");
+
+        assert!(is_self_trade(&incoming, &resting));
+    }
+
+    #[rstest]
+    fn test_is_self_trade_when_not_crossing() {
+        let incoming = limit_order(OrderSide::Buy, "100.00", 100, "O-1");
+        let resting = limit_order(OrderSide::Sell, "101.00", 100, "O-2");
+
+        assert!(!is_self_trade(&incoming, &resting));
+    }
+
+    #[rstest]
+    fn test_is_self_trade_for_different_traders() {
+        let incoming = limit_order(OrderSide::Buy, "100.00", 100, "O-1");
+        let mut resting = limit_order(OrderSide::Sell, "99.00", 100, "O-2");
+        resting.trader_id = TraderId::from("TRADER-002");
+
+        assert!(!is_self_trade(&incoming, &resting));
+    }
+
+    #[rstest]
+    fn test_is_self_trade_for_market_order() {
+        let incoming = TestOrderStubs::market_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Sell,
+            Quantity::from(100),
+            Some(ClientOrderId::from("O-1")),
+            None,
+        );
+        let resting = limit_order(OrderSide::Buy, "1.00", 100, "O-2");
+
+        assert!(is_self_trade(&incoming, &resting));
+    }
+
+    #[rstest]
+    fn test_resolve_when_mode_none() {
+        let incoming = limit_order(OrderSide::Buy, "100.00", 100, "O-1");
+        let resting = limit_order(OrderSide::Sell, "99.00", 100, "O-2");
+
+        let actions = resolve_self_trades(SelfTradePreventionMode::None, &incoming, &[&resting]);
+
+        assert!(actions.is_empty());
+    }
+
+    #[rstest]
+    fn test_resolve_cancel_newest() {
+        let incoming = limit_order(OrderSide::Buy, "100.00", 100, "O-1");
+        let resting = limit_order(OrderSide::Sell, "99.00", 100, "O-2");
+
+        let actions = resolve_self_trades(
+            SelfTradePreventionMode::CancelNewest,
+            &incoming,
+            &[&resting],
+        );
+
+        assert_eq!(
+            actions,
+            vec![SelfTradeAction::Cancel(ClientOrderId::from("O-1"))]
+        );
+    }
+
+    #[rstest]
+    fn test_resolve_cancel_oldest_only_crossing_orders() {
+        let incoming = limit_order(OrderSide::Buy, "100.00", 100, "O-1");
+        let resting1 = limit_order(OrderSide::Sell, "100.00", 100, "O-2");
+        let resting2 = limit_order(OrderSide::Sell, "101.00", 100, "O-3");
+        let resting3 = limit_order(OrderSide::Sell, "99.00", 100, "O-4");
+
+        let actions = resolve_self_trades(
+            SelfTradePreventionMode::CancelOldest,
+            &incoming,
+            &[&resting1, &resting2, &resting3],
+        );
+
+        assert_eq!(
+            actions,
+            vec![
+                SelfTradeAction::Cancel(ClientOrderId::from("O-4")),
+                SelfTradeAction::Cancel(ClientOrderId::from("O-2")),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_resolve_cancel_both() {
+        let incoming = limit_order(OrderSide::Sell, "100.00", 100, "O-1");
+        let resting1 = limit_order(OrderSide::Buy, "100.00", 100, "O-2");
+        let resting2 = limit_order(OrderSide::Buy, "101.00", 100, "O-3");
+
+        let actions = resolve_self_trades(
+            SelfTradePreventionMode::CancelBoth,
+            &incoming,
+            &[&resting1, &resting2],
+        );
+
+        assert_eq!(
+            actions,
+            vec![
+                SelfTradeAction::Cancel(ClientOrderId::from("O-3")),
+                SelfTradeAction::Cancel(ClientOrderId::from("O-1")),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_resolve_no_actions_when_no_self_trade() {
+        let incoming = limit_order(OrderSide::Buy, "100.00", 100, "O-1");
+        let resting = limit_order(OrderSide::Sell, "101.00", 100, "O-2");
+
+        let actions =
+            resolve_self_trades(SelfTradePreventionMode::CancelBoth, &incoming, &[&resting]);
+
+        assert!(actions.is_empty());
+    }
+
+    #[rstest]
+    #[case(100, 100, vec![
+        SelfTradeAction::Cancel(ClientOrderId::from("O-2")),
+        SelfTradeAction::Cancel(ClientOrderId::from("O-1")),
+    ])]
+    #[case(100, 40, vec![
+        SelfTradeAction::Cancel(ClientOrderId::from("O-2")),
+        SelfTradeAction::ModifyQuantity(ClientOrderId::from("O-1"), Quantity::from(60)),
+    ])]
+    #[case(40, 100, vec![
+        SelfTradeAction::ModifyQuantity(ClientOrderId::from("O-2"), Quantity::from(60)),
+        SelfTradeAction::Cancel(ClientOrderId::from("O-1")),
+    ])]
+    fn test_resolve_decrement_and_cancel(
+        #[case] incoming_qty: i64,
+        #[case] resting_qty: i64,
+        #[case] expected: Vec<SelfTradeAction>,
+    ) {
+        let incoming = limit_order(OrderSide::Buy, "100.00", incoming_qty, "O-1");
+        let resting = limit_order(OrderSide::Sell, "100.00", resting_qty, "O-2");
+
+        let actions = resolve_self_trades(
+            SelfTradePreventionMode::DecrementAndCancel,
+            &incoming,
+            &[&resting],
+        );
+
+        assert_eq!(actions, expected);
+    }
+
+    #[rstest]
+    fn test_resolve_decrement_and_cancel_across_multiple_orders() {
+        let incoming = limit_order(OrderSide::Buy, "100.00", 100, "O-1");
+        let resting1 = limit_order(OrderSide::Sell, "99.00", 30, "O-2");
+        let resting2 = limit_order(OrderSide::Sell, "100.00", 100, "O-3");
+
+        let actions = resolve_self_trades(
+            SelfTradePreventionMode::DecrementAndCancel,
+            &incoming,
+            &[&resting2, &resting1],
+        );
+
+        assert_eq!(
+            actions,
+            vec![
+                SelfTradeAction::Cancel(ClientOrderId::from("O-2")),
+                SelfTradeAction::ModifyQuantity(ClientOrderId::from("O-3"), Quantity::from(30)),
+                SelfTradeAction::Cancel(ClientOrderId::from("O-1")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1206 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Bar aggregation from quote and trade ticks.
+//!
+//! Aggregators return the bars completed by each update, rather than invoking a handler,
+//! so that they can be driven equally from live data or a batch replay for backtests.
+
+use nautilus_core::{
+    datetime::{NANOSECONDS_IN_MILLISECOND, NANOSECONDS_IN_SECOND},
+    time::UnixNanos,
+};
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        quote::QuoteTick,
+        trade::TradeTick,
+    },
+    enums::{AggressorSide, BarAggregation},
+    instruments::Instrument,
+    types::{
+        fixed::FIXED_SCALAR,
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
+};
+
+use crate::timer::TimeEvent;
+
+/// Provides a generic bar builder for aggregation.
+#[derive(Clone, Debug)]
+pub struct BarBuilder {
+    /// The bar type for the builder.
+    pub bar_type: BarType,
+    /// The price precision for the builders instrument.
+    pub price_precision: u8,
+    /// The size precision for the builders instrument.
+    pub size_precision: u8,
+    /// If the builder has been initialized with an update or partial bar.
+    pub initialized: bool,
+    /// The UNIX timestamp (nanoseconds) of the last update.
+    pub ts_last: UnixNanos,
+    /// The count of updates since the last bar was built.
+    pub count: usize,
+    /// The accumulated volume since the last bar was built.
+    pub volume: Quantity,
+    partial_set: bool,
+    last_close: Option<Price>,
+    open: Option<Price>,
+    high: Option<Price>,
+    low: Option<Price>,
+    close: Option<Price>,
+}
+
+impl BarBuilder {
+    /// Creates a new [`BarBuilder`] instance.
+    ///
+    /// # Panics
+    ///
+    /// If `instrument.id()` is not equal to `bar_type.instrument_id`.
+    #[must_use]
+    pub fn new<I: Instrument>(instrument: &I, bar_type: BarType) -> Self {
+        assert_eq!(
+            instrument.id(),
+            bar_type.instrument_id,
+            "`instrument.id` does not match `bar_type.instrument_id`"
+        );
+
+        Self {
+            bar_type,
+            price_precision: instrument.price_precision(),
+            size_precision: instrument.size_precision(),
+            initialized: false,
+            ts_last: 0,
+            count: 0,
+            volume: Quantity::zero(instrument.size_precision()),
+            partial_set: false,
+            last_close: None,
+            open: None,
+            high: None,
+            low: None,
+            close: None,
+        }
+    }
+
+    /// Sets the initial values for a partially completed bar.
+    ///
+    /// This method only has an effect the first time it is called.
+    pub fn set_partial(&mut self, partial_bar: Bar) {
+        if self.partial_set {
+            return; // Already updated
+        }
+
+        self.open = Some(partial_bar.open);
+
+        if self.high.map_or(true, |high| partial_bar.high > high) {
+            self.high = Some(partial_bar.high);
+        }
+
+        if self.low.map_or(true, |low| partial_bar.low < low) {
+            self.low = Some(partial_bar.low);
+        }
+
+        if self.close.is_none() {
+            self.close = Some(partial_bar.close);
+        }
+
+        self.volume = partial_bar.volume;
+
+        if self.ts_last == 0 {
+            self.ts_last = partial_bar.ts_init;
+        }
+
+        self.partial_set = true;
+        self.initialized = true;
+    }
+
+    /// Updates the builder with the given price and size.
+    ///
+    /// Updates with a timestamp earlier than the last update are ignored.
+    pub fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        if ts_event < self.ts_last {
+            return; // Not applicable
+        }
+
+        match (self.open, self.high, self.low) {
+            (Some(_), Some(high), Some(low)) => {
+                if price > high {
+                    self.high = Some(price);
+                } else if price < low {
+                    self.low = Some(price);
+                }
+            }
+            _ => {
+                self.open = Some(price);
+                self.high = Some(price);
+                self.low = Some(price);
+                self.initialized = true;
+            }
+        }
+
+        self.close = Some(price);
+        self.volume.raw += size.raw;
+        self.count += 1;
+        self.ts_last = ts_event;
+    }
+
+    /// Resets the builder, ready to aggregate the next bar.
+    pub fn reset(&mut self) {
+        self.open = None;
+        self.high = None;
+        self.low = None;
+        self.volume = Quantity::zero(self.size_precision);
+        self.count = 0;
+    }
+
+    /// Returns the aggregated bar timestamped at the last update, and resets the builder.
+    pub fn build_now(&mut self) -> Option<Bar> {
+        self.build(self.ts_last, self.ts_last)
+    }
+
+    /// Returns the aggregated bar with the given timestamps, and resets the builder.
+    ///
+    /// If there were no updates since the last bar then the bar is built flat at the
+    /// last close. Returns `None` if the builder has never been updated.
+    pub fn build(&mut self, ts_event: UnixNanos, ts_init: UnixNanos) -> Option<Bar> {
+        if self.open.is_none() {
+            // No updates were received
+            self.open = self.last_close;
+            self.high = self.last_close;
+            self.low = self.last_close;
+            self.close = self.last_close;
+        }
+
+        let bar = match (self.open, self.high, self.low, self.close) {
+            (Some(open), Some(high), Some(low), Some(close)) => Some(Bar::new(
+                self.bar_type,
+                open,
+                high,
+                low,
+                close,
+                // SAFETY: Size precision is from a valid instrument
+                Quantity::from_raw(self.volume.raw, self.size_precision).unwrap(),
+                ts_event,
+                ts_init,
+            )),
+            _ => None,
+        };
+
+        self.last_close = self.close;
+        self.reset();
+        bar
+    }
+}
+
+/// Provides the state common to all bar aggregators.
+#[derive(Clone, Debug)]
+pub struct BarAggregatorCore {
+    /// The bar type for the aggregator.
+    pub bar_type: BarType,
+    /// The builder for the bar being aggregated.
+    pub builder: BarBuilder,
+    /// If the aggregator should await an initial partial bar prior to aggregating.
+    pub await_partial: bool,
+}
+
+impl BarAggregatorCore {
+    #[must_use]
+    pub fn new<I: Instrument>(instrument: &I, bar_type: BarType, await_partial: bool) -> Self {
+        Self {
+            bar_type,
+            builder: BarBuilder::new(instrument, bar_type),
+            await_partial,
+        }
+    }
+}
+
+/// Provides a means of aggregating bars from quote and trade ticks.
+///
+/// Each handler returns the bars completed by the update, in the order they closed.
+pub trait BarAggregator {
+    fn core(&self) -> &BarAggregatorCore;
+    fn core_mut(&mut self) -> &mut BarAggregatorCore;
+
+    /// Applies the given price and size update, returning any completed bars.
+    fn apply_update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) -> Vec<Bar>;
+
+    /// Returns the bar type for the aggregator.
+    fn bar_type(&self) -> BarType {
+        self.core().bar_type
+    }
+
+    fn set_await_partial(&mut self, value: bool) {
+        self.core_mut().await_partial = value;
+    }
+
+    /// Sets the initial values for a partially completed bar.
+    fn set_partial(&mut self, partial_bar: Bar) {
+        self.core_mut().builder.set_partial(partial_bar);
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) -> Vec<Bar> {
+        if self.core().await_partial {
+            return Vec::new();
+        }
+
+        let price_type = self.bar_type().spec.price_type;
+        self.apply_update(
+            quote.extract_price(price_type),
+            quote.extract_volume(price_type),
+            quote.ts_event,
+        )
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) -> Vec<Bar> {
+        if self.core().await_partial {
+            return Vec::new();
+        }
+
+        self.apply_update(trade.price, trade.size, trade.ts_event)
+    }
+
+    /// Replays the given quote ticks in order, returning all completed bars.
+    fn handle_quote_ticks(&mut self, quotes: &[QuoteTick]) -> Vec<Bar> {
+        quotes
+            .iter()
+            .flat_map(|quote| self.handle_quote_tick(quote))
+            .collect()
+    }
+
+    /// Replays the given trade ticks in order, returning all completed bars.
+    fn handle_trade_ticks(&mut self, trades: &[TradeTick]) -> Vec<Bar> {
+        trades
+            .iter()
+            .flat_map(|trade| self.handle_trade_tick(trade))
+            .collect()
+    }
+}
+
+/// Provides a means of building tick bars from ticks.
+///
+/// When the received tick count reaches the step threshold of the bar specification,
+/// then a bar is built.
+#[derive(Clone, Debug)]
+pub struct TickBarAggregator {
+    core: BarAggregatorCore,
+}
+
+impl TickBarAggregator {
+    /// Creates a new [`TickBarAggregator`] instance.
+    ///
+    /// # Panics
+    ///
+    /// If `bar_type.spec.step` is not positive (> 0).
+    #[must_use]
+    pub fn new<I: Instrument>(instrument: &I, bar_type: BarType) -> Self {
+        assert!(
+            bar_type.spec.step > 0,
+            "`bar_type.spec.step` must be positive"
+        );
+
+        Self {
+            core: BarAggregatorCore::new(instrument, bar_type, false),
+        }
+    }
+}
+
+impl BarAggregator for TickBarAggregator {
+    fn core(&self) -> &BarAggregatorCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut BarAggregatorCore {
+        &mut self.core
+    }
+
+    fn apply_update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) -> Vec<Bar> {
+        let builder = &mut self.core.builder;
+        builder.update(price, size, ts_event);
+
+        if builder.count == self.core.bar_type.spec.step {
+            return builder.build_now().into_iter().collect();
+        }
+
+        Vec::new()
+    }
+}
+
+/// Provides a means of building volume bars from ticks.
+///
+/// When the received volume reaches the step threshold of the bar specification,
+/// then a bar is built. An update which crosses the threshold is split across bars.
+#[derive(Clone, Debug)]
+pub struct VolumeBarAggregator {
+    core: BarAggregatorCore,
+}
+
+impl VolumeBarAggregator {
+    /// Creates a new [`VolumeBarAggregator`] instance.
+    ///
+    /// # Panics
+    ///
+    /// If `bar_type.spec.step` is not positive (> 0).
+    #[must_use]
+    pub fn new<I: Instrument>(instrument: &I, bar_type: BarType) -> Self {
+        assert!(
+            bar_type.spec.step > 0,
+            "`bar_type.spec.step` must be positive"
+        );
+
+        Self {
+            core: BarAggregatorCore::new(instrument, bar_type, false),
+        }
+    }
+}
+
+impl BarAggregator for VolumeBarAggregator {
+    fn core(&self) -> &BarAggregatorCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut BarAggregatorCore {
+        &mut self.core
+    }
+
+    fn apply_update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) -> Vec<Bar> {
+        let builder = &mut self.core.builder;
+        let raw_step = (self.core.bar_type.spec.step as f64 * FIXED_SCALAR) as QuantityRaw;
+        let mut raw_size_update = size.raw;
+        let mut bars = Vec::new();
+
+        while raw_size_update > 0 {
+            if builder.volume.raw + raw_size_update < raw_step {
+                builder.update(
+                    price,
+                    Quantity::from_raw(raw_size_update, size.precision).unwrap(),
+                    ts_event,
+                );
+                break;
+            }
+
+            // Update builder to the step threshold, then build (a partial bar may
+            // already have seeded a volume at or above the threshold)
+            let raw_size_diff = raw_step.saturating_sub(builder.volume.raw);
+            builder.update(
+                price,
+                Quantity::from_raw(raw_size_diff, size.precision).unwrap(),
+                ts_event,
+            );
+            bars.extend(builder.build_now());
+
+            raw_size_update -= raw_size_diff;
+        }
+
+        bars
+    }
+}
+
+/// Provides a means of building value bars from ticks.
+///
+/// When the received notional value reaches the step threshold of the bar specification,
+/// then a bar is built. An update which crosses the threshold is split across bars.
+#[derive(Clone, Debug)]
+pub struct ValueBarAggregator {
+    core: BarAggregatorCore,
+    cum_value: f64,
+}
+
+impl ValueBarAggregator {
+    /// Creates a new [`ValueBarAggregator`] instance.
+    ///
+    /// # Panics
+    ///
+    /// If `bar_type.spec.step` is not positive (> 0).
+    #[must_use]
+    pub fn new<I: Instrument>(instrument: &I, bar_type: BarType) -> Self {
+        assert!(
+            bar_type.spec.step > 0,
+            "`bar_type.spec.step` must be positive"
+        );
+
+        Self {
+            core: BarAggregatorCore::new(instrument, bar_type, false),
+            cum_value: 0.0,
+        }
+    }
+
+    /// Returns the cumulative value of the bar being aggregated.
+    #[must_use]
+    pub fn get_cumulative_value(&self) -> f64 {
+        self.cum_value
+    }
+}
+
+impl BarAggregator for ValueBarAggregator {
+    fn core(&self) -> &BarAggregatorCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut BarAggregatorCore {
+        &mut self.core
+    }
+
+    fn apply_update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) -> Vec<Bar> {
+        let builder = &mut self.core.builder;
+        let step = self.core.bar_type.spec.step as f64;
+        let mut raw_size_update = size.raw;
+        let mut bars = Vec::new();
+
+        while raw_size_update > 0 {
+            let value_update = price.as_f64() * (raw_size_update as f64 / FIXED_SCALAR);
+            if self.cum_value + value_update < step {
+                self.cum_value += value_update;
+                builder.update(
+                    price,
+                    Quantity::from_raw(raw_size_update, size.precision).unwrap(),
+                    ts_event,
+                );
+                break;
+            }
+
+            // Update builder to the step threshold, then build
+            let value_diff = step - self.cum_value;
+            let raw_size_diff = ((raw_size_update as f64 * (value_diff / value_update)).round()
+                as QuantityRaw)
+                .clamp(1, raw_size_update);
+            builder.update(
+                price,
+                Quantity::from_raw(raw_size_diff, size.precision).unwrap(),
+                ts_event,
+            );
+            bars.extend(builder.build_now());
+            self.cum_value = 0.0;
+
+            raw_size_update -= raw_size_diff;
+        }
+
+        bars
+    }
+}
+
+/// Provides a means of building Renko bars from ticks.
+///
+/// A brick is built each time the price moves a full brick size (the bar step multiplied
+/// by the instruments price increment) from the close of the last brick. A move spanning
+/// several bricks builds several bars, with the accumulated volume on the first.
+#[derive(Clone, Debug)]
+pub struct RenkoBarAggregator {
+    core: BarAggregatorCore,
+    /// The brick size for the aggregator.
+    pub brick_size: Price,
+    last_close: Option<Price>,
+}
+
+impl RenkoBarAggregator {
+    #[must_use]
+    pub fn new<I: Instrument>(instrument: &I, bar_type: BarType) -> Self {
+        let price_increment = instrument.price_increment();
+        let brick_size = Price::from_raw(
+            bar_type.spec.step as PriceRaw * price_increment.raw,
+            price_increment.precision,
+        )
+        .unwrap();
+        assert!(brick_size.raw > 0, "`bar_type.spec.step` must be positive");
+
+        Self {
+            core: BarAggregatorCore::new(instrument, bar_type, false),
+            brick_size,
+            last_close: None,
+        }
+    }
+}
+
+impl BarAggregator for RenkoBarAggregator {
+    fn core(&self) -> &BarAggregatorCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut BarAggregatorCore {
+        &mut self.core
+    }
+
+    fn apply_update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) -> Vec<Bar> {
+        let builder = &mut self.core.builder;
+        builder.update(price, size, ts_event);
+
+        let Some(last_close) = self.last_close else {
+            self.last_close = Some(price); // First update anchors the bricks
+            return Vec::new();
+        };
+
+        let moved_raw = price.raw - last_close.raw;
+        let num_bricks = moved_raw.abs() / self.brick_size.raw;
+        if num_bricks == 0 {
+            return Vec::new();
+        }
+
+        let brick_raw = moved_raw.signum() * self.brick_size.raw;
+        let mut volume = builder.volume;
+        let mut open = last_close;
+        let mut bars = Vec::with_capacity(num_bricks as usize);
+
+        for _ in 0..num_bricks {
+            let close = Price::from_raw(open.raw + brick_raw, builder.price_precision).unwrap();
+            bars.push(Bar::new(
+                self.core.bar_type,
+                open,
+                open.max(close),
+                open.min(close),
+                close,
+                volume,
+                ts_event,
+                ts_event,
+            ));
+            volume = Quantity::zero(builder.size_precision);
+            open = close;
+        }
+
+        self.last_close = Some(open);
+        builder.reset();
+        bars
+    }
+}
+
+/// Provides a means of building range bars from ticks.
+///
+/// A bar is built when an update would extend the high-low range of the current bar
+/// beyond the range size (the bar step multiplied by the instruments price increment),
+/// the update then opens the next bar.
+#[derive(Clone, Debug)]
+pub struct RangeBarAggregator {
+    core: BarAggregatorCore,
+    /// The maximum high-low range of each bar.
+    pub range_size: Price,
+    high_low_raw: Option<(PriceRaw, PriceRaw)>,
+}
+
+impl RangeBarAggregator {
+    #[must_use]
+    pub fn new<I: Instrument>(instrument: &I, bar_type: BarType) -> Self {
+        let price_increment = instrument.price_increment();
+        let range_size = Price::from_raw(
+            bar_type.spec.step as PriceRaw * price_increment.raw,
+            price_increment.precision,
+        )
+        .unwrap();
+        assert!(range_size.raw > 0, "`bar_type.spec.step` must be positive");
+
+        Self {
+            core: BarAggregatorCore::new(instrument, bar_type, false),
+            range_size,
+            high_low_raw: None,
+        }
+    }
+}
+
+impl BarAggregator for RangeBarAggregator {
+    fn core(&self) -> &BarAggregatorCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut BarAggregatorCore {
+        &mut self.core
+    }
+
+    fn apply_update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) -> Vec<Bar> {
+        let builder = &mut self.core.builder;
+        let mut bars = Vec::new();
+
+        if let Some((high, low)) = self.high_low_raw {
+            if high.max(price.raw) - low.min(price.raw) > self.range_size.raw {
+                bars.extend(builder.build_now());
+                self.high_low_raw = None;
+            }
+        }
+
+        builder.update(price, size, ts_event);
+        self.high_low_raw = Some(match self.high_low_raw {
+            Some((high, low)) => (high.max(price.raw), low.min(price.raw)),
+            None => (price.raw, price.raw),
+        });
+
+        bars
+    }
+}
+
+/// Provides a means of building information driven imbalance bars from ticks.
+///
+/// Each update is signed as buyer (+1) or seller (-1) initiated, from the trade aggressor
+/// side when known, otherwise by the tick rule (the sign of the last price change). The
+/// signed ticks, volume or notional value are accumulated (per the bar aggregation), and a
+/// bar is built when the absolute imbalance reaches the step threshold of the bar
+/// specification.
+#[derive(Clone, Debug)]
+pub struct ImbalanceBarAggregator {
+    core: BarAggregatorCore,
+    imbalance: f64,
+    last_price: Option<Price>,
+    last_sign: f64,
+}
+
+impl ImbalanceBarAggregator {
+    /// Creates a new [`ImbalanceBarAggregator`] instance.
+    ///
+    /// # Panics
+    ///
+    /// If the `bar_type` aggregation is not `TickImbalance`, `VolumeImbalance` or
+    /// `ValueImbalance`.
+    #[must_use]
+    pub fn new<I: Instrument>(instrument: &I, bar_type: BarType) -> Self {
+        assert!(
+            matches!(
+                bar_type.spec.aggregation,
+                BarAggregation::TickImbalance
+                    | BarAggregation::VolumeImbalance
+                    | BarAggregation::ValueImbalance
+            ),
+            "Aggregation {} is not an imbalance aggregation",
+            bar_type.spec.aggregation
+        );
+
+        Self {
+            core: BarAggregatorCore::new(instrument, bar_type, false),
+            imbalance: 0.0,
+            last_price: None,
+            last_sign: 1.0,
+        }
+    }
+
+    /// Returns the signed imbalance of the bar being aggregated.
+    #[must_use]
+    pub fn get_imbalance(&self) -> f64 {
+        self.imbalance
+    }
+
+    fn tick_rule_sign(&self, price: Price) -> f64 {
+        match self.last_price {
+            Some(last_price) if price > last_price => 1.0,
+            Some(last_price) if price < last_price => -1.0,
+            _ => self.last_sign,
+        }
+    }
+
+    fn apply_signed_update(
+        &mut self,
+        price: Price,
+        size: Quantity,
+        ts_event: UnixNanos,
+        sign: f64,
+    ) -> Vec<Bar> {
+        let weight = match self.core.bar_type.spec.aggregation {
+            BarAggregation::TickImbalance => 1.0,
+            BarAggregation::VolumeImbalance => size.as_f64(),
+            BarAggregation::ValueImbalance => price.as_f64() * size.as_f64(),
+            _ => unreachable!(), // Checked on construction
+        };
+
+        self.imbalance += sign * weight;
+        self.last_price = Some(price);
+        self.last_sign = sign;
+
+        let builder = &mut self.core.builder;
+        builder.update(price, size, ts_event);
+
+        if self.imbalance.abs() >= self.core.bar_type.spec.step as f64 {
+            self.imbalance = 0.0;
+            return builder.build_now().into_iter().collect();
+        }
+
+        Vec::new()
+    }
+}
+
+impl BarAggregator for ImbalanceBarAggregator {
+    fn core(&self) -> &BarAggregatorCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut BarAggregatorCore {
+        &mut self.core
+    }
+
+    fn apply_update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) -> Vec<Bar> {
+        let sign = self.tick_rule_sign(price);
+        self.apply_signed_update(price, size, ts_event, sign)
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) -> Vec<Bar> {
+        if self.core.await_partial {
+            return Vec::new();
+        }
+
+        let sign = match trade.aggressor_side {
+            AggressorSide::Buyer => 1.0,
+            AggressorSide::Seller => -1.0,
+            AggressorSide::NoAggressor => self.tick_rule_sign(trade.price),
+        };
+        self.apply_signed_update(trade.price, trade.size, trade.ts_event, sign)
+    }
+}
+
+/// Returns the fixed interval (nanoseconds) for the given time bar aggregation and step.
+///
+/// # Panics
+///
+/// If `aggregation` is not a fixed length time aggregation.
+#[must_use]
+pub fn get_time_bar_interval_ns(aggregation: BarAggregation, step: usize) -> u64 {
+    let unit_ns = match aggregation {
+        BarAggregation::Millisecond => NANOSECONDS_IN_MILLISECOND,
+        BarAggregation::Second => NANOSECONDS_IN_SECOND,
+        BarAggregation::Minute => 60 * NANOSECONDS_IN_SECOND,
+        BarAggregation::Hour => 60 * 60 * NANOSECONDS_IN_SECOND,
+        BarAggregation::Day => 24 * 60 * 60 * NANOSECONDS_IN_SECOND,
+        BarAggregation::Week => 7 * 24 * 60 * 60 * NANOSECONDS_IN_SECOND,
+        _ => panic!("Aggregation {aggregation} is not a fixed length time aggregation"),
+    };
+
+    step as u64 * unit_ns
+}
+
+/// Provides a means of building time bars from ticks.
+///
+/// Bar intervals are aligned to the UNIX epoch and are left-open, so an update exactly
+/// on an interval boundary belongs to the bar closing at that boundary. Bars are closed
+/// either by an update beyond the current interval, or by `build_until` (for example
+/// from a timer event).
+#[derive(Clone, Debug)]
+pub struct TimeBarAggregator {
+    core: BarAggregatorCore,
+    /// The bar interval (nanoseconds).
+    pub interval_ns: u64,
+    /// If bars are built for intervals with no updates, flat at the last close.
+    pub build_with_no_updates: bool,
+    /// If bar `ts_event` is the close of the interval, otherwise the open.
+    pub timestamp_on_close: bool,
+    /// The UNIX timestamp (nanoseconds) of the next bar close.
+    pub next_close_ns: Option<UnixNanos>,
+}
+
+impl TimeBarAggregator {
+    /// Creates a new [`TimeBarAggregator`] instance.
+    ///
+    /// # Panics
+    ///
+    /// If the `bar_type` aggregation is not a fixed length time aggregation.
+    #[must_use]
+    pub fn new<I: Instrument>(
+        instrument: &I,
+        bar_type: BarType,
+        build_with_no_updates: bool,
+        timestamp_on_close: bool,
+    ) -> Self {
+        let interval_ns = get_time_bar_interval_ns(bar_type.spec.aggregation, bar_type.spec.step);
+        assert!(interval_ns > 0, "`bar_type.spec.step` must be positive");
+
+        Self {
+            core: BarAggregatorCore::new(instrument, bar_type, false),
+            interval_ns,
+            build_with_no_updates,
+            timestamp_on_close,
+            next_close_ns: None,
+        }
+    }
+
+    /// Builds all bars which close at or before the given timestamp.
+    pub fn build_until(&mut self, ts_now: UnixNanos) -> Vec<Bar> {
+        let mut bars = Vec::new();
+
+        while let Some(close_ns) = self.next_close_ns {
+            if close_ns > ts_now {
+                break;
+            }
+            bars.extend(self.build_bar(close_ns));
+            self.next_close_ns = Some(close_ns + self.interval_ns);
+        }
+
+        bars
+    }
+
+    /// Handles the given timer event, building all bars which close at or before the
+    /// event timestamp.
+    pub fn handle_time_event(&mut self, event: &TimeEvent) -> Vec<Bar> {
+        self.build_until(event.ts_event)
+    }
+
+    fn build_bar(&mut self, close_ns: UnixNanos) -> Option<Bar> {
+        let builder = &mut self.core.builder;
+        if builder.count == 0 && !self.build_with_no_updates {
+            return None;
+        }
+
+        let ts_event = if self.timestamp_on_close {
+            close_ns
+        } else {
+            close_ns - self.interval_ns
+        };
+
+        builder.build(ts_event, close_ns)
+    }
+}
+
+impl BarAggregator for TimeBarAggregator {
+    fn core(&self) -> &BarAggregatorCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut BarAggregatorCore {
+        &mut self.core
+    }
+
+    fn apply_update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) -> Vec<Bar> {
+        let bars = match self.next_close_ns {
+            // Close all bars whose interval ends before this update
+            Some(_) => self.build_until(ts_event.saturating_sub(1)),
+            None => {
+                self.next_close_ns = Some(ts_event.div_ceil(self.interval_ns) * self.interval_ns);
+                Vec::new()
+            }
+        };
+
+        self.core.builder.update(price, size, ts_event);
+        bars
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::bar::BarSpecification,
+        enums::{AggregationSource, AggressorSide, PriceType},
+        identifiers::trade_id::TradeId,
+        instruments::{equity::Equity, stubs::*},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn bar_type(instrument: &Equity, step: usize, aggregation: BarAggregation) -> BarType {
+        BarType::new(
+            instrument.id,
+            BarSpecification::new(step, aggregation, PriceType::Last),
+            AggregationSource::Internal,
+        )
+    }
+
+    fn trade(instrument: &Equity, ts_event: UnixNanos) -> TradeTick {
+        TradeTick::new(
+            instrument.id,
+            Price::from("100.00"),
+            Quantity::from("1"),
+            AggressorSide::Buyer,
+            TradeId::new("1").unwrap(),
+            ts_event,
+            ts_event,
+        )
+    }
+
+    fn update(
+        aggregator: &mut impl BarAggregator,
+        price: &str,
+        size: &str,
+        ts_event: UnixNanos,
+    ) -> Vec<Bar> {
+        aggregator.apply_update(Price::from(price), Quantity::from(size), ts_event)
+    }
+
+    #[rstest]
+    fn test_bar_builder_update_and_build(equity_aapl: Equity) {
+        let mut builder = BarBuilder::new(
+            &equity_aapl,
+            bar_type(&equity_aapl, 3, BarAggregation::Tick),
+        );
+
+        builder.update(Price::from("100.00"), Quantity::from("1"), 1);
+        builder.update(Price::from("101.00"), Quantity::from("2"), 2);
+        builder.update(Price::from("99.00"), Quantity::from("3"), 3);
+        builder.update(Price::from("102.00"), Quantity::from("1"), 1); // Out of order
+        let bar = builder.build_now().unwrap();
+
+        assert_eq!(bar.open, Price::from("100.00"));
+        assert_eq!(bar.high, Price::from("101.00"));
+        assert_eq!(bar.low, Price::from("99.00"));
+        assert_eq!(bar.close, Price::from("99.00"));
+        assert_eq!(bar.volume, Quantity::from("6"));
+        assert_eq!(bar.ts_event, 3);
+        assert_eq!(builder.count, 0);
+    }
+
+    #[rstest]
+    fn test_bar_builder_build_with_no_updates(equity_aapl: Equity) {
+        let mut builder = BarBuilder::new(
+            &equity_aapl,
+            bar_type(&equity_aapl, 1, BarAggregation::Tick),
+        );
+
+        assert!(builder.build(1, 1).is_none());
+
+        builder.update(Price::from("100.00"), Quantity::from("1"), 1);
+        builder.build_now();
+        let bar = builder.build(2, 2).unwrap();
+
+        assert_eq!(bar.open, Price::from("100.00"));
+        assert_eq!(bar.close, Price::from("100.00"));
+        assert_eq!(bar.volume, Quantity::from("0"));
+    }
+
+    #[rstest]
+    fn test_tick_bar_aggregator(equity_aapl: Equity) {
+        let mut aggregator = TickBarAggregator::new(
+            &equity_aapl,
+            bar_type(&equity_aapl, 3, BarAggregation::Tick),
+        );
+
+        assert!(update(&mut aggregator, "100.00", "1", 1).is_empty());
+        assert!(update(&mut aggregator, "101.00", "1", 2).is_empty());
+        let bars = update(&mut aggregator, "100.50", "1", 3);
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].high, Price::from("101.00"));
+        assert_eq!(bars[0].close, Price::from("100.50"));
+        assert_eq!(bars[0].volume, Quantity::from("3"));
+    }
+
+    #[rstest]
+    fn test_tick_bar_aggregator_awaiting_partial(equity_aapl: Equity) {
+        let mut aggregator = TickBarAggregator::new(
+            &equity_aapl,
+            bar_type(&equity_aapl, 1, BarAggregation::Tick),
+        );
+        aggregator.set_await_partial(true);
+
+        assert!(aggregator
+            .handle_trade_tick(&trade(&equity_aapl, 1))
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_volume_bar_aggregator_splits_update(equity_aapl: Equity) {
+        let mut aggregator = VolumeBarAggregator::new(
+            &equity_aapl,
+            bar_type(&equity_aapl, 10, BarAggregation::Volume),
+        );
+
+        assert!(update(&mut aggregator, "100.00", "4", 1).is_empty());
+        let bars = update(&mut aggregator, "101.00", "25", 2);
+
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].volume, Quantity::from("10"));
+        assert_eq!(bars[0].open, Price::from("100.00"));
+        assert_eq!(bars[1].volume, Quantity::from("10"));
+        assert_eq!(bars[1].open, Price::from("101.00"));
+        assert_eq!(aggregator.core().builder.volume, Quantity::from("9"));
+    }
+
+    #[rstest]
+    fn test_volume_bar_aggregator_when_partial_at_step(equity_aapl: Equity) {
+        let bar_type = bar_type(&equity_aapl, 10, BarAggregation::Volume);
+        let mut aggregator = VolumeBarAggregator::new(&equity_aapl, bar_type);
+        aggregator.set_partial(Bar::new(
+            bar_type,
+            Price::from("100.00"),
+            Price::from("101.00"),
+            Price::from("99.00"),
+            Price::from("100.00"),
+            Quantity::from("15"),
+            1,
+            1,
+        ));
+
+        let bars = update(&mut aggregator, "100.00", "1", 2);
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].volume, Quantity::from("15"));
+        assert_eq!(aggregator.core().builder.volume, Quantity::from("1"));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`bar_type.spec.step` must be positive")]
+    fn test_volume_bar_aggregator_when_step_zero(equity_aapl: Equity) {
+        let _ = VolumeBarAggregator::new(
+            &equity_aapl,
+            bar_type(&equity_aapl, 0, BarAggregation::Volume),
+        );
+    }
+
+    #[rstest]
+    fn test_value_bar_aggregator_splits_update(equity_aapl: Equity) {
+        let mut aggregator = ValueBarAggregator::new(
+            &equity_aapl,
+            bar_type(&equity_aapl, 1000, BarAggregation::Value),
+        );
+
+        assert!(update(&mut aggregator, "100.00", "5", 1).is_empty());
+        assert_eq!(aggregator.get_cumulative_value(), 500.0);
+        let bars = update(&mut aggregator, "100.00", "20", 2);
+
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].volume, Quantity::from("10"));
+        assert_eq!(bars[1].volume, Quantity::from("10"));
+        assert_eq!(aggregator.core().builder.volume, Quantity::from("5"));
+        assert_eq!(aggregator.get_cumulative_value(), 500.0);
+    }
+
+    #[rstest]
+    fn test_renko_bar_aggregator(equity_aapl: Equity) {
+        let mut aggregator = RenkoBarAggregator::new(
+            &equity_aapl,
+            bar_type(&equity_aapl, 50, BarAggregation::Renko),
+        );
+
+        assert_eq!(aggregator.brick_size, Price::from("0.50"));
+        assert!(update(&mut aggregator, "100.00", "1", 1).is_empty());
+        assert!(update(&mut aggregator, "100.40", "1", 2).is_empty());
+        let bars = update(&mut aggregator, "101.20", "1", 3);
+
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].open, Price::from("100.00"));
+        assert_eq!(bars[0].close, Price::from("100.50"));
+        assert_eq!(bars[0].volume, Quantity::from("3"));
+        assert_eq!(bars[1].open, Price::from("100.50"));
+        assert_eq!(bars[1].close, Price::from("101.00"));
+        assert_eq!(bars[1].volume, Quantity::from("0"));
+
+        let bars = update(&mut aggregator, "100.40", "1", 4);
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].open, Price::from("101.00"));
+        assert_eq!(bars[0].high, Price::from("101.00"));
+        assert_eq!(bars[0].low, Price::from("100.50"));
+        assert_eq!(bars[0].close, Price::from("100.50"));
+    }
+
+    #[rstest]
+    fn test_range_bar_aggregator(equity_aapl: Equity) {
+        let mut aggregator = RangeBarAggregator::new(
+            &equity_aapl,
+            bar_type(&equity_aapl, 10, BarAggregation::Range),
+        );
+
+        assert!(update(&mut aggregator, "100.00", "1", 1).is_empty());
+        assert!(update(&mut aggregator, "100.10", "1", 2).is_empty());
+        assert!(update(&mut aggregator, "100.05", "1", 3).is_empty());
+        let bars = update(&mut aggregator, "100.11", "1", 4);
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].open, Price::from("100.00"));
+        assert_eq!(bars[0].high, Price::from("100.10"));
+        assert_eq!(bars[0].low, Price::from("100.00"));
+        assert_eq!(bars[0].close, Price::from("100.05"));
+        assert_eq!(bars[0].volume, Quantity::from("3"));
+        assert_eq!(aggregator.core().builder.count, 1);
+    }
+
+    #[rstest]
+    fn test_tick_imbalance_bar_aggregator_with_tick_rule(equity_aapl: Equity) {
+        let bar_type = bar_type(&equity_aapl, 3, BarAggregation::TickImbalance);
+        let mut aggregator = ImbalanceBarAggregator::new(&equity_aapl, bar_type);
+
+        assert!(update(&mut aggregator, "100.00", "1", 1).is_empty()); // +1 (initial)
+        assert!(update(&mut aggregator, "99.99", "1", 2).is_empty()); // -1
+        assert!(update(&mut aggregator, "100.01", "1", 3).is_empty()); // +1
+        assert!(update(&mut aggregator, "100.01", "1", 4).is_empty()); // +1 (unchanged)
+        assert_eq!(aggregator.get_imbalance(), 2.0);
+        let bars = update(&mut aggregator, "100.02", "1", 5); // +1
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].volume, Quantity::from("5"));
+        assert_eq!(aggregator.get_imbalance(), 0.0);
+    }
+
+    #[rstest]
+    fn test_volume_imbalance_bar_aggregator_with_aggressor(equity_aapl: Equity) {
+        let bar_type = bar_type(&equity_aapl, 10, BarAggregation::VolumeImbalance);
+        let mut aggregator = ImbalanceBarAggregator::new(&equity_aapl, bar_type);
+        let trade = |size: &str, aggressor_side, ts_event| {
+            TradeTick::new(
+                equity_aapl.id,
+                Price::from("100.00"),
+                Quantity::from(size),
+                aggressor_side,
+                TradeId::new("1").unwrap(),
+                ts_event,
+                ts_event,
+            )
+        };
+
+        assert!(aggregator
+            .handle_trade_tick(&trade("6", AggressorSide::Seller, 1))
+            .is_empty());
+        assert!(aggregator
+            .handle_trade_tick(&trade("4", AggressorSide::Buyer, 2))
+            .is_empty());
+        assert_eq!(aggregator.get_imbalance(), -2.0);
+        let bars = aggregator.handle_trade_tick(&trade("8", AggressorSide::Seller, 3));
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].volume, Quantity::from("18"));
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator(equity_aapl: Equity) {
+        let bar_type = bar_type(&equity_aapl, 1, BarAggregation::Second);
+        let mut aggregator = TimeBarAggregator::new(&equity_aapl, bar_type, true, true);
+        let second = NANOSECONDS_IN_SECOND;
+
+        assert!(update(&mut aggregator, "100.00", "1", second / 2).is_empty());
+        assert!(update(&mut aggregator, "101.00", "1", second).is_empty()); // Left-open
+        let bars = update(&mut aggregator, "102.00", "1", 3 * second + 1);
+
+        assert_eq!(bars.len(), 3);
+        assert_eq!(bars[0].ts_event, second);
+        assert_eq!(bars[0].close, Price::from("101.00"));
+        assert_eq!(bars[0].volume, Quantity::from("2"));
+        assert_eq!(bars[1].ts_event, 2 * second);
+        assert_eq!(bars[1].open, Price::from("101.00")); // Flat at last close
+        assert_eq!(bars[2].ts_event, 3 * second);
+
+        let bars = aggregator.build_until(4 * second);
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].close, Price::from("102.00"));
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_without_empty_bars(equity_aapl: Equity) {
+        let bar_type = bar_type(&equity_aapl, 1, BarAggregation::Second);
+        let mut aggregator = TimeBarAggregator::new(&equity_aapl, bar_type, false, false);
+        let second = NANOSECONDS_IN_SECOND;
+
+        update(&mut aggregator, "100.00", "1", second / 2);
+        let bars = update(&mut aggregator, "102.00", "1", 3 * second + 1);
+
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].ts_event, 0); // Timestamped on open
+        assert_eq!(bars[0].ts_init, second);
+    }
+
+    #[rstest]
+    fn test_handle_trade_ticks_replay(equity_aapl: Equity) {
+        let mut aggregator = TickBarAggregator::new(
+            &equity_aapl,
+            bar_type(&equity_aapl, 2, BarAggregation::Tick),
+        );
+        let trades: Vec<TradeTick> = (0..5).map(|i| trade(&equity_aapl, i)).collect();
+
+        let bars = aggregator.handle_trade_ticks(&trades);
+
+        assert_eq!(bars.len(), 2);
+        assert_eq!(aggregator.core().builder.count, 1);
+    }
+}
@@ -13,17 +13,25 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, ops::Deref};
+use std::{
+    collections::{HashMap, VecDeque},
+    ops::Deref,
+    sync::atomic::{AtomicI64, AtomicU64, Ordering},
+    time::Instant,
+};
 
 use nautilus_core::{
     correctness::check_valid_string,
-    time::{get_atomic_clock_realtime, AtomicTime, UnixNanos},
+    datetime::{NANOSECONDS_IN_MICROSECOND, NANOSECONDS_IN_MILLISECOND, NANOSECONDS_IN_SECOND},
+    time::{duration_since_unix_epoch, get_atomic_clock_realtime, AtomicTime, UnixNanos},
 };
+use nautilus_model::identifiers::venue::Venue;
 use ustr::Ustr;
 
 use crate::{
     handlers::EventHandler,
-    timer::{LiveTimer, TestTimer, TimeEvent, TimeEventHandler},
+    schedule::Schedule,
+    timer::{LiveTimer, TestTimer, TimeEvent, TimeEventHandler, TimerWheel},
 };
 
 /// Represents a type of clock.
@@ -62,6 +70,18 @@ pub trait Clock {
         callback: Option<EventHandler>,
     );
 
+    /// Set a `Timer` to alert at every time of the given `schedule`
+    /// after the start time (or now if zero) until the optional stop
+    /// time. Optional callback gets used to handle generated events.
+    fn set_timer_schedule(
+        &mut self,
+        name: &str,
+        schedule: Schedule,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+        callback: Option<EventHandler>,
+    );
+
     fn next_time_ns(&self, name: &str) -> UnixNanos;
     fn cancel_timer(&mut self, name: &str);
     fn cancel_timers(&mut self);
@@ -70,6 +90,10 @@ pub trait Clock {
 pub struct TestClock {
     time: AtomicTime,
     timers: HashMap<Ustr, TestTimer>,
+    /// Schedules the next time of each active timer (keyed by name and timer ID).
+    wheel: TimerWheel<(Ustr, u64)>,
+    timer_ids: HashMap<Ustr, u64>,
+    next_timer_id: u64,
     default_callback: Option<EventHandler>,
     callbacks: HashMap<Ustr, EventHandler>,
 }
@@ -80,6 +104,9 @@ impl TestClock {
         Self {
             time: AtomicTime::new(false, 0),
             timers: HashMap::new(),
+            wheel: TimerWheel::new(0),
+            timer_ids: HashMap::new(),
+            next_timer_id: 0,
             default_callback: None,
             callbacks: HashMap::new(),
         }
@@ -90,6 +117,15 @@ impl TestClock {
         &self.timers
     }
 
+    /// Returns the earliest time any timer is scheduled to alert.
+    ///
+    /// Canceled timers are removed lazily, so this is a lower bound which allows callers
+    /// to skip advancing the clock when no timers can be due.
+    #[must_use]
+    pub fn next_timer_ns(&self) -> Option<UnixNanos> {
+        self.wheel.peek_next()
+    }
+
     pub fn advance_time(&mut self, to_time_ns: UnixNanos, set_time: bool) -> Vec<TimeEvent> {
         // Time should increase monotonically
         assert!(
@@ -101,15 +137,36 @@ impl TestClock {
             self.time.set_time(to_time_ns);
         }
 
-        let mut timers: Vec<TimeEvent> = self
-            .timers
-            .iter_mut()
-            .filter(|(_, timer)| !timer.is_expired)
-            .flat_map(|(_, timer)| timer.advance(to_time_ns))
-            .collect();
+        // Events are popped from the wheel in time order
+        let mut events = Vec::new();
+        while let Some((time_ns, (name, timer_id))) = self.wheel.pop_next(to_time_ns) {
+            if self.timer_ids.get(&name) != Some(&timer_id) {
+                continue; // Timer was canceled or replaced
+            }
+            let Some(timer) = self.timers.get_mut(&name) else {
+                continue;
+            };
+            if timer.is_expired || timer.next_time_ns != time_ns {
+                continue;
+            }
+            if let Some((event, _)) = timer.next() {
+                events.push(event);
+            }
+            if !timer.is_expired {
+                self.wheel.insert(timer.next_time_ns, (name, timer_id));
+            }
+        }
+        events
+    }
 
-        timers.sort_by(|a, b| a.ts_event.cmp(&b.ts_event));
-        timers
+    fn insert_timer(&mut self, name: Ustr, timer: TestTimer) {
+        self.next_timer_id += 1;
+        if !timer.is_expired {
+            self.wheel
+                .insert(timer.next_time_ns, (name, self.next_timer_id));
+        }
+        self.timer_ids.insert(name, self.next_timer_id);
+        self.timers.insert(name, timer);
     }
 
     /// Assumes time events are sorted by their `ts_event`.
@@ -200,7 +257,7 @@ impl Clock for TestClock {
         // currently share timestamp nanoseconds
         let time_ns = self.time.get_time_ns();
         let timer = TestTimer::new(name, alert_time_ns - time_ns, time_ns, Some(alert_time_ns));
-        self.timers.insert(name_ustr, timer);
+        self.insert_timer(name_ustr, timer);
     }
 
     fn set_timer_ns(
@@ -224,7 +281,35 @@ impl Clock for TestClock {
         };
 
         let timer = TestTimer::new(name, interval_ns, start_time_ns, stop_time_ns);
-        self.timers.insert(name_ustr, timer);
+        self.insert_timer(name_ustr, timer);
+    }
+
+    fn set_timer_schedule(
+        &mut self,
+        name: &str,
+        schedule: Schedule,
+        mut start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+        callback: Option<EventHandler>,
+    ) {
+        check_valid_string(name, "`Timer` name").unwrap();
+        assert!(
+            callback.is_some() | self.default_callback.is_some(),
+            "All Python callbacks were `None`"
+        );
+
+        let name_ustr = Ustr::from(name);
+        match callback {
+            Some(callback_py) => self.callbacks.insert(name_ustr, callback_py),
+            None => None,
+        };
+
+        if start_time_ns == 0 {
+            start_time_ns = self.time.get_time_ns();
+        }
+
+        let timer = TestTimer::new_scheduled(name, schedule, start_time_ns, stop_time_ns);
+        self.insert_timer(name_ustr, timer);
     }
 
     fn next_time_ns(&self, name: &str) -> UnixNanos {
@@ -236,7 +321,9 @@ impl Clock for TestClock {
     }
 
     fn cancel_timer(&mut self, name: &str) {
-        let timer = self.timers.remove(&Ustr::from(name));
+        let name = Ustr::from(name);
+        self.timer_ids.remove(&name);
+        let timer = self.timers.remove(&name);
         match timer {
             None => {}
             Some(mut timer) => timer.cancel(),
@@ -248,11 +335,227 @@ impl Clock for TestClock {
             timer.cancel();
         }
         self.timers = HashMap::new();
+        self.timer_ids.clear();
+        self.wheel.clear();
+    }
+}
+
+/// The default interval (nanoseconds) between checks of the monotonic time against the wall clock.
+pub const DEFAULT_RESYNC_INTERVAL_NS: u64 = NANOSECONDS_IN_SECOND;
+
+/// The default maximum correction (nanoseconds) applied per resync, bounding the slew rate.
+pub const DEFAULT_MAX_SLEW_NS: u64 = 500 * NANOSECONDS_IN_MICROSECOND;
+
+/// The drift (nanoseconds) beyond which the wall clock is stepped to rather than slewed towards.
+pub const DEFAULT_STEP_THRESHOLD_NS: u64 = NANOSECONDS_IN_SECOND;
+
+/// The default number of samples retained per venue for clock offset estimation.
+pub const DEFAULT_VENUE_SAMPLES: usize = 16;
+
+/// Provides UNIX time derived from a monotonic source anchored to the wall clock.
+///
+/// Intervals are measured with [`Instant`] so they are immune to wall clock adjustments,
+/// while the result is periodically compared against the system wall clock. Any drift is
+/// corrected gradually (slewed) by at most `max_slew_ns` per resync, unless it exceeds
+/// `step_threshold_ns` in which case the correction is applied at once. Returned times
+/// are always strictly increasing.
+#[derive(Debug)]
+pub struct HybridTime {
+    anchor: Instant,
+    anchor_ns: UnixNanos,
+    resync_interval_ns: u64,
+    max_slew_ns: u64,
+    step_threshold_ns: u64,
+    correction_ns: AtomicI64,
+    last_resync_ns: AtomicU64,
+    last_ns: AtomicU64,
+}
+
+impl HybridTime {
+    #[must_use]
+    pub fn new(resync_interval_ns: u64, max_slew_ns: u64, step_threshold_ns: u64) -> Self {
+        let anchor = Instant::now();
+        let anchor_ns = duration_since_unix_epoch().as_nanos() as UnixNanos;
+        Self {
+            anchor,
+            anchor_ns,
+            resync_interval_ns,
+            max_slew_ns,
+            step_threshold_ns,
+            correction_ns: AtomicI64::new(0),
+            last_resync_ns: AtomicU64::new(0),
+            last_ns: AtomicU64::new(anchor_ns),
+        }
+    }
+
+    /// Returns the current UNIX time (nanoseconds), resyncing with the wall clock if due.
+    #[must_use]
+    pub fn get_time_ns(&self) -> UnixNanos {
+        let elapsed_ns = self.anchor.elapsed().as_nanos() as u64;
+        let last_resync_ns = self.last_resync_ns.load(Ordering::Relaxed);
+        if elapsed_ns.saturating_sub(last_resync_ns) >= self.resync_interval_ns
+            && self
+                .last_resync_ns
+                .compare_exchange(
+                    last_resync_ns,
+                    elapsed_ns,
+                    Ordering::AcqRel,
+                    Ordering::Relaxed,
+                )
+                .is_ok()
+        {
+            self.resync(elapsed_ns);
+        }
+
+        let now = self.monotonic_ns(elapsed_ns);
+        let prev = self
+            .last_ns
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |last| {
+                Some(now.max(last + 1))
+            })
+            .expect("Update function always returns `Some`");
+        now.max(prev + 1)
+    }
+
+    /// Returns the accumulated correction (nanoseconds) applied to the monotonic time.
+    #[must_use]
+    pub fn correction_ns(&self) -> i64 {
+        self.correction_ns.load(Ordering::Relaxed)
+    }
+
+    /// Returns the current drift (nanoseconds) of the wall clock relative to this clock.
+    #[must_use]
+    pub fn drift_ns(&self) -> i64 {
+        let elapsed_ns = self.anchor.elapsed().as_nanos() as u64;
+        let wall_ns = duration_since_unix_epoch().as_nanos() as i64;
+        wall_ns - self.monotonic_ns(elapsed_ns) as i64
+    }
+
+    fn monotonic_ns(&self, elapsed_ns: u64) -> UnixNanos {
+        let correction_ns = self.correction_ns.load(Ordering::Relaxed);
+        (self.anchor_ns + elapsed_ns).saturating_add_signed(correction_ns)
+    }
+
+    fn resync(&self, elapsed_ns: u64) {
+        let wall_ns = duration_since_unix_epoch().as_nanos() as i64;
+        let drift_ns = wall_ns - self.monotonic_ns(elapsed_ns) as i64;
+        let adjustment_ns = if drift_ns.unsigned_abs() > self.step_threshold_ns {
+            drift_ns
+        } else {
+            drift_ns.clamp(-(self.max_slew_ns as i64), self.max_slew_ns as i64)
+        };
+        self.correction_ns
+            .fetch_add(adjustment_ns, Ordering::Relaxed);
+    }
+}
+
+impl Default for HybridTime {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_RESYNC_INTERVAL_NS,
+            DEFAULT_MAX_SLEW_NS,
+            DEFAULT_STEP_THRESHOLD_NS,
+        )
+    }
+}
+
+/// Represents a single clock offset measurement against a venue server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClockOffsetSample {
+    /// The estimated offset (nanoseconds) of the venue clock relative to the local clock.
+    pub offset_ns: i64,
+    /// The round-trip time (nanoseconds) of the request used for the measurement.
+    pub rtt_ns: u64,
+    /// The local UNIX timestamp (nanoseconds) when the response was received.
+    pub ts_recv: UnixNanos,
+}
+
+impl ClockOffsetSample {
+    /// Creates a new sample from an NTP-style request/response exchange, where `ts_server`
+    /// is the venue server time reported in the response.
+    #[must_use]
+    pub fn new(ts_sent: UnixNanos, ts_server: UnixNanos, ts_recv: UnixNanos) -> Self {
+        let rtt_ns = ts_recv.saturating_sub(ts_sent);
+        let midpoint = ts_sent + rtt_ns / 2;
+        Self {
+            offset_ns: ts_server as i64 - midpoint as i64,
+            rtt_ns,
+            ts_recv,
+        }
+    }
+}
+
+/// Estimates the offset between the local clock and a venue clock.
+///
+/// Retains a window of the most recent samples and, as with the NTP clock filter, takes the
+/// sample with the lowest round-trip time as the best estimate (the one least affected by
+/// asymmetric network delay).
+#[derive(Clone, Debug)]
+pub struct VenueClockOffset {
+    samples: VecDeque<ClockOffsetSample>,
+    capacity: usize,
+}
+
+impl VenueClockOffset {
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "`capacity` must be positive");
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Adds the given sample, evicting the oldest if at capacity.
+    pub fn add_sample(&mut self, sample: ClockOffsetSample) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// Returns the sample with the lowest round-trip time.
+    #[must_use]
+    pub fn best_sample(&self) -> Option<&ClockOffsetSample> {
+        self.samples.iter().min_by_key(|s| s.rtt_ns)
+    }
+
+    /// Returns the estimated venue clock offset (nanoseconds).
+    #[must_use]
+    pub fn offset_ns(&self) -> Option<i64> {
+        self.best_sample().map(|s| s.offset_ns)
+    }
+
+    /// Returns the estimated one-way latency (nanoseconds) to the venue.
+    #[must_use]
+    pub fn latency_ns(&self) -> Option<u64> {
+        self.best_sample().map(|s| s.rtt_ns / 2)
+    }
+
+    /// Returns the offset jitter (nanoseconds), the spread of the retained offset estimates.
+    #[must_use]
+    pub fn jitter_ns(&self) -> Option<u64> {
+        let min = self.samples.iter().map(|s| s.offset_ns).min()?;
+        let max = self.samples.iter().map(|s| s.offset_ns).max()?;
+        Some(max.abs_diff(min))
+    }
+
+    #[must_use]
+    pub fn sample_count(&self) -> usize {
+        self.samples.len()
+    }
+}
+
+impl Default for VenueClockOffset {
+    fn default() -> Self {
+        Self::new(DEFAULT_VENUE_SAMPLES)
     }
 }
 
 pub struct LiveClock {
     time: &'static AtomicTime,
+    hybrid: HybridTime,
+    venue_offsets: HashMap<Venue, VenueClockOffset>,
     timers: HashMap<Ustr, LiveTimer>,
     default_callback: Option<EventHandler>,
 }
@@ -262,6 +565,8 @@ impl LiveClock {
     pub fn new() -> Self {
         Self {
             time: get_atomic_clock_realtime(),
+            hybrid: HybridTime::default(),
+            venue_offsets: HashMap::new(),
             timers: HashMap::new(),
             default_callback: None,
         }
@@ -271,6 +576,80 @@ impl LiveClock {
     pub fn get_timers(&self) -> &HashMap<Ustr, LiveTimer> {
         &self.timers
     }
+
+    /// Get time in nanoseconds (monotonic, anchored to and corrected against the wall clock).
+    #[must_use]
+    pub fn get_time_ns(&self) -> UnixNanos {
+        self.hybrid.get_time_ns()
+    }
+
+    /// Get time as microseconds.
+    #[must_use]
+    pub fn get_time_us(&self) -> u64 {
+        self.get_time_ns() / NANOSECONDS_IN_MICROSECOND
+    }
+
+    /// Get time as milliseconds.
+    #[must_use]
+    pub fn get_time_ms(&self) -> u64 {
+        self.get_time_ns() / NANOSECONDS_IN_MILLISECOND
+    }
+
+    /// Get time as seconds.
+    #[must_use]
+    pub fn get_time(&self) -> f64 {
+        self.get_time_ns() as f64 / (NANOSECONDS_IN_SECOND as f64)
+    }
+
+    /// Returns the current drift (nanoseconds) of the wall clock relative to this clock.
+    #[must_use]
+    pub fn drift_ns(&self) -> i64 {
+        self.hybrid.drift_ns()
+    }
+
+    /// Records a venue server time observation from a request/response exchange.
+    ///
+    /// Adapters should call this with the local time the request was sent, the server time
+    /// reported by the venue, and the local time the response was received.
+    pub fn record_venue_time(
+        &mut self,
+        venue: Venue,
+        ts_sent: UnixNanos,
+        ts_server: UnixNanos,
+        ts_recv: UnixNanos,
+    ) {
+        self.venue_offsets
+            .entry(venue)
+            .or_default()
+            .add_sample(ClockOffsetSample::new(ts_sent, ts_server, ts_recv));
+    }
+
+    /// Returns the clock offset estimator for the given `venue` (if observations recorded).
+    #[must_use]
+    pub fn venue_clock(&self, venue: &Venue) -> Option<&VenueClockOffset> {
+        self.venue_offsets.get(venue)
+    }
+
+    /// Returns the estimated offset (nanoseconds) of the `venue` clock relative to this clock.
+    #[must_use]
+    pub fn venue_offset_ns(&self, venue: &Venue) -> Option<i64> {
+        self.venue_clock(venue)
+            .and_then(VenueClockOffset::offset_ns)
+    }
+
+    /// Returns the estimated one-way latency (nanoseconds) to the `venue`.
+    #[must_use]
+    pub fn venue_latency_ns(&self, venue: &Venue) -> Option<u64> {
+        self.venue_clock(venue)
+            .and_then(VenueClockOffset::latency_ns)
+    }
+
+    /// Returns the estimated current time (nanoseconds) on the `venue` clock.
+    #[must_use]
+    pub fn venue_time_ns(&self, venue: &Venue) -> Option<UnixNanos> {
+        self.venue_offset_ns(venue)
+            .map(|offset_ns| self.get_time_ns().saturating_add_signed(offset_ns))
+    }
 }
 
 impl Default for LiveClock {
@@ -361,6 +740,35 @@ impl Clock for LiveClock {
         self.timers.insert(Ustr::from(name), timer);
     }
 
+    fn set_timer_schedule(
+        &mut self,
+        name: &str,
+        schedule: Schedule,
+        mut start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+        callback: Option<EventHandler>,
+    ) {
+        check_valid_string(name, "`Timer` name").unwrap();
+        assert!(
+            callback.is_some() | self.default_callback.is_some(),
+            "All Python callbacks were `None`"
+        );
+
+        let callback = match callback {
+            Some(callback) => callback,
+            None => self.default_callback.clone().unwrap(),
+        };
+
+        if start_time_ns == 0 {
+            start_time_ns = self.get_time_ns();
+        }
+
+        let mut timer =
+            LiveTimer::new_scheduled(name, schedule, start_time_ns, stop_time_ns, callback);
+        timer.start();
+        self.timers.insert(Ustr::from(name), timer);
+    }
+
     fn next_time_ns(&self, name: &str) -> UnixNanos {
         let timer = self.timers.get(&Ustr::from(name));
         match timer {
@@ -385,4 +793,93 @@ impl Clock for LiveClock {
     }
 }
 
-// TODO: Rust specific clock tests
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_hybrid_time_is_strictly_increasing() {
+        let time = HybridTime::default();
+        let mut last = time.get_time_ns();
+        for _ in 0..1_000 {
+            let now = time.get_time_ns();
+            assert!(now > last);
+            last = now;
+        }
+    }
+
+    #[rstest]
+    fn test_hybrid_time_tracks_wall_clock() {
+        let time = HybridTime::new(0, DEFAULT_MAX_SLEW_NS, DEFAULT_STEP_THRESHOLD_NS);
+        let wall_ns = duration_since_unix_epoch().as_nanos() as i64;
+        let diff_ns = time.get_time_ns() as i64 - wall_ns;
+        assert!(diff_ns.unsigned_abs() < NANOSECONDS_IN_SECOND);
+    }
+
+    #[rstest]
+    fn test_hybrid_time_slews_correction() {
+        let time = HybridTime::new(0, 10, DEFAULT_STEP_THRESHOLD_NS);
+        time.correction_ns.store(-1_000, Ordering::Relaxed);
+        time.resync(time.anchor.elapsed().as_nanos() as u64);
+        // Drift of ~1µs is corrected by at most the max slew per resync
+        let correction_ns = time.correction_ns();
+        assert!((-1_000..=-990).contains(&correction_ns));
+    }
+
+    #[rstest]
+    fn test_hybrid_time_steps_large_drift() {
+        let time = HybridTime::new(0, 10, NANOSECONDS_IN_SECOND);
+        time.correction_ns
+            .store(-10 * NANOSECONDS_IN_SECOND as i64, Ordering::Relaxed);
+        time.resync(time.anchor.elapsed().as_nanos() as u64);
+        assert!(time.correction_ns().unsigned_abs() < NANOSECONDS_IN_SECOND);
+    }
+
+    #[rstest]
+    fn test_clock_offset_sample() {
+        let sample = ClockOffsetSample::new(1_000, 1_600, 1_200);
+        assert_eq!(sample.rtt_ns, 200);
+        assert_eq!(sample.offset_ns, 500);
+        assert_eq!(sample.ts_recv, 1_200);
+    }
+
+    #[rstest]
+    fn test_venue_clock_offset_uses_lowest_rtt_sample() {
+        let mut offsets = VenueClockOffset::new(3);
+        assert_eq!(offsets.offset_ns(), None);
+
+        offsets.add_sample(ClockOffsetSample::new(0, 1_000, 400)); // rtt 400, offset 800
+        offsets.add_sample(ClockOffsetSample::new(1_000, 1_550, 1_100)); // rtt 100, offset 500
+        offsets.add_sample(ClockOffsetSample::new(2_000, 2_700, 2_200)); // rtt 200, offset 600
+
+        assert_eq!(offsets.offset_ns(), Some(500));
+        assert_eq!(offsets.latency_ns(), Some(50));
+        assert_eq!(offsets.jitter_ns(), Some(300));
+
+        // Evicts the lowest rtt sample
+        offsets.add_sample(ClockOffsetSample::new(3_000, 3_900, 3_300)); // rtt 300, offset 750
+        offsets.add_sample(ClockOffsetSample::new(4_000, 4_900, 4_300)); // rtt 300, offset 750
+        assert_eq!(offsets.sample_count(), 3);
+        assert_eq!(offsets.offset_ns(), Some(600));
+    }
+
+    #[rstest]
+    fn test_live_clock_venue_offset() {
+        let mut clock = LiveClock::new();
+        let venue = Venue::from("BINANCE");
+        assert_eq!(clock.venue_offset_ns(&venue), None);
+        assert_eq!(clock.venue_time_ns(&venue), None);
+
+        clock.record_venue_time(venue, 1_000, 1_600, 1_200);
+
+        assert_eq!(clock.venue_offset_ns(&venue), Some(500));
+        assert_eq!(clock.venue_latency_ns(&venue), Some(100));
+        let now = clock.get_time_ns();
+        assert!(clock.venue_time_ns(&venue).unwrap() > now + 500);
+    }
+}
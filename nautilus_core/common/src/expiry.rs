@@ -0,0 +1,510 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tracks instrument activation and expiration, emitting roll and close events.
+
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::{close::InstrumentClose, trade::TradeTick},
+    enums::InstrumentCloseType,
+    identifiers::instrument_id::InstrumentId,
+    instruments::Instrument,
+    types::price::Price,
+};
+
+/// The number of nanoseconds in one day.
+const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+
+/// Configuration for an [`ExpiryTracker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExpiryTrackerConfig {
+    /// The offset (nanoseconds) before expiration at which a roll notification is emitted.
+    pub roll_offset_ns: u64,
+}
+
+impl ExpiryTrackerConfig {
+    #[must_use]
+    pub fn new(roll_offset_ns: u64) -> Self {
+        Self { roll_offset_ns }
+    }
+
+    /// Creates a new config which emits roll notifications the given number of `days`
+    /// before expiration.
+    #[must_use]
+    pub fn with_roll_days(days: u64) -> Self {
+        Self::new(days * NANOS_PER_DAY)
+    }
+}
+
+/// Represents an event emitted by an [`ExpiryTracker`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpiryEvent {
+    /// An instrument entered its roll window ahead of expiration.
+    Roll {
+        instrument_id: InstrumentId,
+        expiration_ns: UnixNanos,
+        ts_event: UnixNanos,
+    },
+    /// A continuous contract alias was mapped to a new front month.
+    FrontMonthChanged {
+        alias: InstrumentId,
+        previous: Option<InstrumentId>,
+        current: InstrumentId,
+        ts_event: UnixNanos,
+    },
+    /// An instrument reached expiration.
+    Close(InstrumentClose),
+}
+
+#[derive(Clone, Debug)]
+struct TrackedInstrument {
+    activation_ns: UnixNanos,
+    expiration_ns: UnixNanos,
+    last_price: Option<Price>,
+    rolled: bool,
+    expired: bool,
+}
+
+#[derive(Clone, Debug)]
+struct ContinuousContract {
+    members: Vec<InstrumentId>,
+    front: Option<InstrumentId>,
+}
+
+/// Monitors the expiration of instruments such as futures, emitting roll notifications
+/// and [`InstrumentClose`] events, and mapping continuous contract aliases to the
+/// active front month.
+///
+/// The tracker is driven by calling [`ExpiryTracker::advance`] with the current time,
+/// for example from a clock time alert scheduled at [`ExpiryTracker::next_event_ns`],
+/// so the same logic applies in both backtests and live trading.
+#[derive(Clone, Debug)]
+pub struct ExpiryTracker {
+    pub config: ExpiryTrackerConfig,
+    instruments: IndexMap<InstrumentId, TrackedInstrument>,
+    continuous: IndexMap<InstrumentId, ContinuousContract>,
+}
+
+impl ExpiryTracker {
+    #[must_use]
+    pub fn new(config: ExpiryTrackerConfig) -> Self {
+        Self {
+            config,
+            instruments: IndexMap::new(),
+            continuous: IndexMap::new(),
+        }
+    }
+
+    /// Adds the given `instrument` to the tracker.
+    ///
+    /// Returns an error if the instrument does not expire.
+    pub fn add_instrument(&mut self, instrument: &dyn Instrument) -> Result<()> {
+        let (Some(activation_ns), Some(expiration_ns)) =
+            (instrument.activation_ns(), instrument.expiration_ns())
+        else {
+            bail!("Instrument {} does not expire", instrument.id());
+        };
+        self.add(instrument.id(), activation_ns, expiration_ns)
+    }
+
+    /// Adds an instrument to the tracker with the given activation and expiration times.
+    pub fn add(
+        &mut self,
+        instrument_id: InstrumentId,
+        activation_ns: UnixNanos,
+        expiration_ns: UnixNanos,
+    ) -> Result<()> {
+        if expiration_ns < activation_ns {
+            bail!(
+                "Condition failed: `expiration_ns` {expiration_ns} was before `activation_ns` {activation_ns}"
+            );
+        }
+        self.instruments.insert(
+            instrument_id,
+            TrackedInstrument {
+                activation_ns,
+                expiration_ns,
+                last_price: None,
+                rolled: false,
+                expired: false,
+            },
+        );
+        Ok(())
+    }
+
+    /// Removes the given instrument from the tracker, including from any continuous contracts.
+    pub fn remove(&mut self, instrument_id: &InstrumentId) {
+        self.instruments.shift_remove(instrument_id);
+        for contract in self.continuous.values_mut() {
+            contract.members.retain(|id| id != instrument_id);
+            if contract.front.as_ref() == Some(instrument_id) {
+                contract.front = None;
+            }
+        }
+    }
+
+    /// Registers a continuous contract `alias` over the given `members`, which must
+    /// already be tracked.
+    ///
+    /// The alias is mapped to a front month on the next call to [`ExpiryTracker::advance`].
+    pub fn add_continuous(
+        &mut self,
+        alias: InstrumentId,
+        members: Vec<InstrumentId>,
+    ) -> Result<()> {
+        if members.is_empty() {
+            bail!("Condition failed: `members` for {alias} was empty");
+        }
+        if let Some(member) = members
+            .iter()
+            .find(|id| !self.instruments.contains_key(*id))
+        {
+            bail!("Continuous contract member {member} is not tracked");
+        }
+        self.continuous.insert(
+            alias,
+            ContinuousContract {
+                members,
+                front: None,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns whether the given instrument is tracked.
+    #[must_use]
+    pub fn is_tracked(&self, instrument_id: &InstrumentId) -> bool {
+        self.instruments.contains_key(instrument_id)
+    }
+
+    /// Returns whether the given instrument has reached expiration.
+    #[must_use]
+    pub fn is_expired(&self, instrument_id: &InstrumentId) -> bool {
+        self.instruments
+            .get(instrument_id)
+            .is_some_and(|tracked| tracked.expired)
+    }
+
+    /// Returns the current front month for the given continuous contract `alias`.
+    #[must_use]
+    pub fn front_month(&self, alias: &InstrumentId) -> Option<InstrumentId> {
+        self.continuous.get(alias).and_then(|c| c.front)
+    }
+
+    /// Resolves the given `instrument_id` to its front month if it is a continuous contract
+    /// alias, otherwise returns it unchanged.
+    #[must_use]
+    pub fn resolve(&self, instrument_id: &InstrumentId) -> InstrumentId {
+        self.front_month(instrument_id).unwrap_or(*instrument_id)
+    }
+
+    /// Updates the last price for the given instrument, used as the close price at expiration.
+    pub fn update_price(&mut self, instrument_id: &InstrumentId, price: Price) {
+        if let Some(tracked) = self.instruments.get_mut(instrument_id) {
+            tracked.last_price = Some(price);
+        }
+    }
+
+    /// Updates the last price for the instrument of the given `trade`.
+    pub fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_price(&trade.instrument_id, trade.price);
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) of the next pending roll or expiration,
+    /// which can be used to schedule a time alert for the next call to [`ExpiryTracker::advance`].
+    #[must_use]
+    pub fn next_event_ns(&self) -> Option<UnixNanos> {
+        self.instruments
+            .values()
+            .filter_map(|tracked| {
+                if !tracked.rolled {
+                    Some(self.roll_ns(tracked))
+                } else if !tracked.expired {
+                    Some(tracked.expiration_ns)
+                } else {
+                    None
+                }
+            })
+            .min()
+    }
+
+    /// Advances the tracker to `ts_now`, returning any roll, front month and close events
+    /// which occurred since the last call, ordered by instrument expiration.
+    pub fn advance(&mut self, ts_now: UnixNanos) -> Vec<ExpiryEvent> {
+        let mut events = Vec::new();
+        let roll_offset_ns = self.config.roll_offset_ns;
+
+        let mut pending: Vec<(&InstrumentId, &mut TrackedInstrument)> = self
+            .instruments
+            .iter_mut()
+            .filter(|(_, tracked)| !tracked.expired)
+            .collect();
+        pending.sort_by_key(|(_, tracked)| tracked.expiration_ns);
+
+        for (instrument_id, tracked) in pending {
+            let roll_ns = tracked.expiration_ns.saturating_sub(roll_offset_ns);
+            if !tracked.rolled && ts_now >= roll_ns {
+                tracked.rolled = true;
+                events.push(ExpiryEvent::Roll {
+                    instrument_id: *instrument_id,
+                    expiration_ns: tracked.expiration_ns,
+                    ts_event: roll_ns,
+                });
+            }
+            if ts_now >= tracked.expiration_ns {
+                tracked.expired = true;
+                match tracked.last_price {
+                    Some(close_price) => events.push(ExpiryEvent::Close(InstrumentClose::new(
+                        *instrument_id,
+                        close_price,
+                        InstrumentCloseType::ContractExpired,
+                        tracked.expiration_ns,
+                        ts_now,
+                    ))),
+                    None => log::warn!(
+                        "Cannot emit close for expired instrument {instrument_id}, no price received"
+                    ),
+                }
+            }
+        }
+
+        for (alias, contract) in &mut self.continuous {
+            let front = select_front_month(&self.instruments, &contract.members, ts_now);
+            if let Some(current) = front {
+                if contract.front != front {
+                    events.push(ExpiryEvent::FrontMonthChanged {
+                        alias: *alias,
+                        previous: contract.front,
+                        current,
+                        ts_event: ts_now,
+                    });
+                }
+            }
+            contract.front = front;
+        }
+
+        events
+    }
+
+    fn roll_ns(&self, tracked: &TrackedInstrument) -> UnixNanos {
+        tracked
+            .expiration_ns
+            .saturating_sub(self.config.roll_offset_ns)
+    }
+}
+
+/// Selects the active member with the nearest expiration which has not yet rolled,
+/// falling back to the nearest unexpired member once all have rolled.
+fn select_front_month(
+    instruments: &IndexMap<InstrumentId, TrackedInstrument>,
+    members: &[InstrumentId],
+    ts_now: UnixNanos,
+) -> Option<InstrumentId> {
+    let active = || {
+        members.iter().filter_map(|id| {
+            instruments
+                .get(id)
+                .filter(|tracked| tracked.activation_ns <= ts_now && !tracked.expired)
+                .map(|tracked| (id, tracked))
+        })
+    };
+
+    active()
+        .filter(|(_, tracked)| !tracked.rolled)
+        .min_by_key(|(_, tracked)| tracked.expiration_ns)
+        .or_else(|| active().min_by_key(|(_, tracked)| tracked.expiration_ns))
+        .map(|(id, _)| *id)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::AssetClass,
+        identifiers::symbol::Symbol,
+        instruments::{equity::Equity, futures_contract::FuturesContract},
+        types::{currency::Currency, quantity::Quantity},
+    };
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::*;
+
+    const DAY: u64 = NANOS_PER_DAY;
+
+    fn future(symbol: &str, activation_ns: UnixNanos, expiration_ns: UnixNanos) -> FuturesContract {
+        FuturesContract::new(
+            InstrumentId::from(format!("{symbol}.GLBX").as_str()),
+            Symbol::from(symbol),
+            AssetClass::Index,
+            Some(Ustr::from("XCME")),
+            Ustr::from("ES"),
+            activation_ns,
+            expiration_ns,
+            Currency::USD(),
+            2,
+            Price::from("0.25"),
+            Quantity::from(50),
+            Quantity::from(1),
+            None,
+            None,
+            None,
+            None,
+            0,
+            0,
+        )
+        .unwrap()
+    }
+
+    #[fixture]
+    fn tracker() -> ExpiryTracker {
+        let mut tracker = ExpiryTracker::new(ExpiryTrackerConfig::with_roll_days(5));
+        tracker
+            .add_instrument(&future("ESH4", 0, 90 * DAY))
+            .unwrap();
+        tracker
+            .add_instrument(&future("ESM4", 0, 180 * DAY))
+            .unwrap();
+        tracker
+            .add_continuous(
+                InstrumentId::from("ES.GLBX"),
+                vec![
+                    InstrumentId::from("ESH4.GLBX"),
+                    InstrumentId::from("ESM4.GLBX"),
+                ],
+            )
+            .unwrap();
+        tracker
+    }
+
+    #[rstest]
+    fn test_add_instrument_which_does_not_expire() {
+        let mut tracker = ExpiryTracker::new(ExpiryTrackerConfig::new(0));
+        let equity = Equity::new(
+            InstrumentId::from("AAPL.XNAS"),
+            Symbol::from("AAPL"),
+            None,
+            Currency::USD(),
+            2,
+            Price::from("0.01"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            0,
+            0,
+        )
+        .unwrap();
+
+        assert!(tracker.add_instrument(&equity).is_err());
+    }
+
+    #[rstest]
+    fn test_add_continuous_with_untracked_member(mut tracker: ExpiryTracker) {
+        let result = tracker.add_continuous(
+            InstrumentId::from("NQ.GLBX"),
+            vec![InstrumentId::from("NQH4.GLBX")],
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_front_month_maps_to_nearest_expiry(mut tracker: ExpiryTracker) {
+        let alias = InstrumentId::from("ES.GLBX");
+        assert_eq!(tracker.front_month(&alias), None);
+
+        let events = tracker.advance(DAY);
+
+        assert_eq!(
+            events,
+            vec![ExpiryEvent::FrontMonthChanged {
+                alias,
+                previous: None,
+                current: InstrumentId::from("ESH4.GLBX"),
+                ts_event: DAY,
+            }]
+        );
+        assert_eq!(tracker.resolve(&alias), InstrumentId::from("ESH4.GLBX"));
+        assert!(tracker.advance(2 * DAY).is_empty());
+    }
+
+    #[rstest]
+    fn test_roll_notification_switches_front_month(mut tracker: ExpiryTracker) {
+        let alias = InstrumentId::from("ES.GLBX");
+        tracker.advance(DAY);
+
+        let events = tracker.advance(85 * DAY);
+
+        assert_eq!(
+            events,
+            vec![
+                ExpiryEvent::Roll {
+                    instrument_id: InstrumentId::from("ESH4.GLBX"),
+                    expiration_ns: 90 * DAY,
+                    ts_event: 85 * DAY,
+                },
+                ExpiryEvent::FrontMonthChanged {
+                    alias,
+                    previous: Some(InstrumentId::from("ESH4.GLBX")),
+                    current: InstrumentId::from("ESM4.GLBX"),
+                    ts_event: 85 * DAY,
+                },
+            ]
+        );
+        assert_eq!(tracker.next_event_ns(), Some(90 * DAY));
+    }
+
+    #[rstest]
+    fn test_expiration_emits_instrument_close(mut tracker: ExpiryTracker) {
+        let instrument_id = InstrumentId::from("ESH4.GLBX");
+        tracker.advance(86 * DAY);
+        tracker.update_price(&instrument_id, Price::from("5230.25"));
+
+        let events = tracker.advance(90 * DAY + 1);
+
+        assert_eq!(
+            events,
+            vec![ExpiryEvent::Close(InstrumentClose::new(
+                instrument_id,
+                Price::from("5230.25"),
+                InstrumentCloseType::ContractExpired,
+                90 * DAY,
+                90 * DAY + 1,
+            ))]
+        );
+        assert!(tracker.is_expired(&instrument_id));
+        assert_eq!(tracker.next_event_ns(), Some(175 * DAY));
+    }
+
+    #[rstest]
+    fn test_expiration_without_price_emits_no_close(mut tracker: ExpiryTracker) {
+        let events = tracker.advance(90 * DAY);
+
+        assert_eq!(events.len(), 2); // Roll for ESH4 and front month change only
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, ExpiryEvent::Close(_))));
+        assert!(tracker.is_expired(&InstrumentId::from("ESH4.GLBX")));
+    }
+}
@@ -19,7 +19,7 @@ use nautilus_core::{time::AtomicTime, uuid::UUID4};
 use nautilus_model::{
     enums::{ContingencyType, OrderSide, TimeInForce},
     identifiers::{
-        client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
+        client_id::ClientId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
         instrument_id::InstrumentId, order_list_id::OrderListId, strategy_id::StrategyId,
         trader_id::TraderId,
     },
@@ -29,7 +29,8 @@ use nautilus_model::{
 use ustr::Ustr;
 
 use crate::generators::{
-    client_order_id::ClientOrderIdGenerator, order_list_id::OrderListIdGenerator,
+    client_order_id::{ClientOrderIdConfig, ClientOrderIdGenerator},
+    order_list_id::OrderListIdGenerator,
 };
 
 #[repr(C)]
@@ -39,6 +40,7 @@ pub struct OrderFactory {
     strategy_id: StrategyId,
     order_id_generator: ClientOrderIdGenerator,
     order_list_id_generator: OrderListIdGenerator,
+    client_order_id_configs: HashMap<ClientId, ClientOrderIdConfig>,
 }
 
 impl OrderFactory {
@@ -67,9 +69,25 @@ impl OrderFactory {
             strategy_id,
             order_id_generator,
             order_list_id_generator,
+            client_order_id_configs: HashMap::new(),
         }
     }
 
+    /// Sets the default configuration for generating client order IDs.
+    pub fn set_client_order_id_config(&mut self, config: ClientOrderIdConfig) {
+        self.order_id_generator.set_config(config);
+    }
+
+    /// Sets the configuration for generating client order IDs for orders routed to the
+    /// execution client with the given `client_id`.
+    pub fn set_client_order_id_config_for_client(
+        &mut self,
+        client_id: ClientId,
+        config: ClientOrderIdConfig,
+    ) {
+        self.client_order_id_configs.insert(client_id, config);
+    }
+
     pub fn set_client_order_id_count(&mut self, count: usize) {
         self.order_id_generator.set_count(count);
     }
@@ -82,6 +100,15 @@ impl OrderFactory {
         self.order_id_generator.generate()
     }
 
+    /// Generates a client order ID for an order to be routed to the execution client with the
+    /// given `client_id`, using its configuration if set (otherwise the default).
+    pub fn generate_client_order_id_for_client(&mut self, client_id: &ClientId) -> ClientOrderId {
+        match self.client_order_id_configs.get(client_id) {
+            Some(config) => self.order_id_generator.generate_with(*config),
+            None => self.order_id_generator.generate(),
+        }
+    }
+
     pub fn generate_order_list_id(&mut self) -> OrderListId {
         self.order_list_id_generator.generate()
     }
@@ -143,12 +170,19 @@ pub mod tests {
     use nautilus_model::{
         enums::{OrderSide, TimeInForce},
         identifiers::{
-            client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+            client_id::ClientId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+            order_list_id::OrderListId,
         },
     };
     use rstest::rstest;
 
-    use crate::{factories::OrderFactory, stubs::order_factory};
+    use crate::{
+        factories::OrderFactory,
+        generators::client_order_id::{
+            ClientOrderIdCharset, ClientOrderIdConfig, ClientOrderIdStrategy,
+        },
+        stubs::order_factory,
+    };
 
     #[rstest]
     fn test_generate_client_order_id(mut order_factory: OrderFactory) {
@@ -159,6 +193,28 @@ pub mod tests {
         );
     }
 
+    #[rstest]
+    fn test_generate_client_order_id_for_client(mut order_factory: OrderFactory) {
+        order_factory.set_client_order_id_config_for_client(
+            ClientId::from("VENUE"),
+            ClientOrderIdConfig {
+                strategy: ClientOrderIdStrategy::Deterministic,
+                charset: ClientOrderIdCharset::Alphanumeric,
+                max_length: None,
+            },
+        );
+
+        let for_client =
+            order_factory.generate_client_order_id_for_client(&ClientId::from("VENUE"));
+        let default = order_factory.generate_client_order_id_for_client(&ClientId::from("OTHER"));
+
+        assert_eq!(for_client, ClientOrderId::from("O0010011"));
+        assert_eq!(
+            default,
+            ClientOrderId::new("O-19700101-0000-001-001-2").unwrap()
+        );
+    }
+
     #[rstest]
     fn test_generate_order_list_id(mut order_factory: OrderFactory) {
         let order_list_id = order_factory.generate_order_list_id();
@@ -28,6 +28,8 @@ use pyo3::{
     types::{PyList, PyString},
 };
 
+use nautilus_model::identifiers::venue::Venue;
+
 use crate::{
     clock::{Clock, LiveClock, TestClock},
     handlers::EventHandler,
@@ -130,6 +132,12 @@ pub extern "C" fn test_clock_timer_count(clock: &mut TestClock_API) -> usize {
     clock.timer_count()
 }
 
+/// Returns the earliest time any timer is scheduled to alert (zero if no timers).
+#[no_mangle]
+pub extern "C" fn test_clock_next_timer_ns(clock: &TestClock_API) -> UnixNanos {
+    clock.next_timer_ns().unwrap_or_default()
+}
+
 /// # Safety
 ///
 /// - Assumes `name_ptr` is a valid C string pointer.
@@ -416,3 +424,58 @@ pub unsafe extern "C" fn live_clock_cancel_timer(
 pub extern "C" fn live_clock_cancel_timers(clock: &mut LiveClock_API) {
     clock.cancel_timers();
 }
+
+/// # Safety
+///
+/// - Assumes `venue_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn live_clock_record_venue_time(
+    clock: &mut LiveClock_API,
+    venue_ptr: *const c_char,
+    ts_sent: UnixNanos,
+    ts_server: UnixNanos,
+    ts_recv: UnixNanos,
+) {
+    let venue = Venue::from(cstr_to_str(venue_ptr));
+    clock.record_venue_time(venue, ts_sent, ts_server, ts_recv);
+}
+
+/// # Safety
+///
+/// - Assumes `venue_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn live_clock_has_venue_offset(
+    clock: &LiveClock_API,
+    venue_ptr: *const c_char,
+) -> u8 {
+    let venue = Venue::from(cstr_to_str(venue_ptr));
+    u8::from(clock.venue_offset_ns(&venue).is_some())
+}
+
+/// Returns the estimated venue clock offset (nanoseconds), or zero if no observations recorded.
+///
+/// # Safety
+///
+/// - Assumes `venue_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn live_clock_venue_offset_ns(
+    clock: &LiveClock_API,
+    venue_ptr: *const c_char,
+) -> i64 {
+    let venue = Venue::from(cstr_to_str(venue_ptr));
+    clock.venue_offset_ns(&venue).unwrap_or_default()
+}
+
+/// Returns the estimated one-way venue latency (nanoseconds), or zero if no observations recorded.
+///
+/// # Safety
+///
+/// - Assumes `venue_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn live_clock_venue_latency_ns(
+    clock: &LiveClock_API,
+    venue_ptr: *const c_char,
+) -> u64 {
+    let venue = Venue::from(cstr_to_str(venue_ptr));
+    clock.venue_latency_ns(&venue).unwrap_or_default()
+}
@@ -30,7 +30,7 @@ use crate::{
         self, headers,
         logger::{self, LoggerConfig},
         logging_set_bypass, map_log_level_to_filter, parse_component_levels,
-        writer::FileWriterConfig,
+        writer::{FileRotationConfig, FileWriterConfig},
     },
 };
 
@@ -50,6 +50,7 @@ use crate::{
 /// - Assume `file_name_ptr` is either NULL or a valid C string pointer.
 /// - Assume `file_format_ptr` is either NULL or a valid C string pointer.
 /// - Assume `component_level_ptr` is either NULL or a valid C string pointer.
+/// - Assume `file_rotation_ptr` is either NULL or a valid C string pointer.
 #[no_mangle]
 pub unsafe extern "C" fn logging_init(
     trader_id: TraderId,
@@ -59,8 +60,10 @@ pub unsafe extern "C" fn logging_init(
     directory_ptr: *const c_char,
     file_name_ptr: *const c_char,
     file_format_ptr: *const c_char,
+    file_rotation_ptr: *const c_char,
     component_levels_ptr: *const c_char,
     is_colored: u8,
+    is_json: u8,
     is_bypassed: u8,
     print_config: u8,
 ) {
@@ -75,13 +78,22 @@ pub unsafe extern "C" fn logging_init(
         level_file,
         component_levels,
         u8_as_bool(is_colored),
+        u8_as_bool(is_json),
         u8_as_bool(print_config),
     );
 
     let directory = optional_cstr_to_str(directory_ptr).map(|s| s.to_string());
     let file_name = optional_cstr_to_str(file_name_ptr).map(|s| s.to_string());
     let file_format = optional_cstr_to_str(file_format_ptr).map(|s| s.to_string());
-    let file_config = FileWriterConfig::new(directory, file_name, file_format);
+    let file_rotation = optional_bytes_to_json(file_rotation_ptr)
+        .map(|map| {
+            serde_json::from_value::<FileRotationConfig>(serde_json::Value::Object(
+                map.into_iter().collect(),
+            ))
+            .expect("Invalid `FileRotationConfig`")
+        })
+        .unwrap_or_default();
+    let file_config = FileWriterConfig::new(directory, file_name, file_format, file_rotation);
 
     if u8_as_bool(is_bypassed) {
         logging_set_bypass();
@@ -138,6 +150,31 @@ pub unsafe extern "C" fn logging_log_sysinfo(component_ptr: *const c_char) {
     headers::log_sysinfo(component)
 }
 
+/// Sets the runtime log level override for the given component.
+///
+/// # Safety
+///
+/// - Assumes `component_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn logging_set_component_level(
+    component_ptr: *const c_char,
+    level: LogLevel,
+) {
+    let component = cstr_to_ustr(component_ptr);
+    logger::set_component_level(component, Some(map_log_level_to_filter(level)));
+}
+
+/// Clears any runtime log level override for the given component.
+///
+/// # Safety
+///
+/// - Assumes `component_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn logging_clear_component_level(component_ptr: *const c_char) {
+    let component = cstr_to_ustr(component_ptr);
+    logger::set_component_level(component, None);
+}
+
 /// Flushes global logger buffers.
 #[no_mangle]
 pub extern "C" fn logger_flush() {
@@ -0,0 +1,63 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ffi::c_char;
+
+use nautilus_core::ffi::string::cstr_to_str;
+
+use crate::metrics;
+
+/// Returns whether metrics collection is enabled.
+#[no_mangle]
+pub extern "C" fn metrics_is_enabled() -> u8 {
+    u8::from(metrics::metrics_enabled())
+}
+
+/// Increments the count of messages of `kind` processed by `component`.
+///
+/// # Safety
+///
+/// - Assumes `component_ptr` is a valid C string pointer.
+/// - Assumes `kind_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn metrics_inc_messages_processed(
+    component_ptr: *const c_char,
+    kind_ptr: *const c_char,
+) {
+    metrics::inc_messages_processed(cstr_to_str(component_ptr), cstr_to_str(kind_ptr));
+}
+
+/// Records the time from order initialization to the venue response `event`.
+///
+/// # Safety
+///
+/// - Assumes `event_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn metrics_observe_order_latency(event_ptr: *const c_char, latency_ns: u64) {
+    metrics::observe_order_latency(cstr_to_str(event_ptr), latency_ns);
+}
+
+/// Records the time taken to apply an update to an order book of `book_type`.
+///
+/// # Safety
+///
+/// - Assumes `book_type_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn metrics_observe_book_apply_time(
+    book_type_ptr: *const c_char,
+    duration_ns: u64,
+) {
+    metrics::observe_book_apply_time(cstr_to_str(book_type_ptr), duration_ns);
+}
@@ -16,5 +16,7 @@
 pub mod clock;
 pub mod enums;
 pub mod logging;
+pub mod metrics;
 pub mod msgbus;
+pub mod random;
 pub mod timer;
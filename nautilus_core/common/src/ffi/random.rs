@@ -0,0 +1,119 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::ffi::string::cstr_to_str;
+
+use crate::random::RandomGenerator;
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`RandomGenerator`].
+///
+/// This struct wraps `RandomGenerator` in a way that makes it compatible with C function
+/// calls, enabling interaction with `RandomGenerator` in a C environment.
+///
+/// It implements the `Deref` and `DerefMut` traits, allowing instances of `RandomGenerator_API`
+/// to be dereferenced to `RandomGenerator`, providing access to `RandomGenerator`'s methods
+/// without having to manually access the underlying `RandomGenerator` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct RandomGenerator_API(Box<RandomGenerator>);
+
+impl Deref for RandomGenerator_API {
+    type Target = RandomGenerator;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for RandomGenerator_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn random_generator_new(seed: u64) -> RandomGenerator_API {
+    RandomGenerator_API(Box::new(RandomGenerator::new(seed)))
+}
+
+#[no_mangle]
+pub extern "C" fn random_generator_from_entropy() -> RandomGenerator_API {
+    RandomGenerator_API(Box::new(RandomGenerator::from_entropy()))
+}
+
+#[no_mangle]
+pub extern "C" fn random_generator_drop(rng: RandomGenerator_API) {
+    drop(rng); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn random_generator_seed(rng: &RandomGenerator_API) -> u64 {
+    rng.seed()
+}
+
+#[no_mangle]
+pub extern "C" fn random_generator_reseed(rng: &mut RandomGenerator_API, seed: u64) {
+    rng.reseed(seed);
+}
+
+/// # Safety
+///
+/// - Assumes `stream_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn random_generator_fork(
+    rng: &RandomGenerator_API,
+    stream_ptr: *const c_char,
+) -> RandomGenerator_API {
+    let stream = cstr_to_str(stream_ptr);
+    RandomGenerator_API(Box::new(rng.fork(stream)))
+}
+
+#[no_mangle]
+pub extern "C" fn random_generator_next_u64(rng: &mut RandomGenerator_API) -> u64 {
+    rng.next_u64()
+}
+
+#[no_mangle]
+pub extern "C" fn random_generator_next_f64(rng: &mut RandomGenerator_API) -> f64 {
+    rng.next_f64()
+}
+
+#[no_mangle]
+pub extern "C" fn random_generator_gen_bool(rng: &mut RandomGenerator_API, probability: f64) -> u8 {
+    u8::from(rng.gen_bool(probability))
+}
+
+#[no_mangle]
+pub extern "C" fn random_generator_gen_range(
+    rng: &mut RandomGenerator_API,
+    low: u64,
+    high: u64,
+) -> u64 {
+    rng.gen_range(low, high)
+}
+
+#[no_mangle]
+pub extern "C" fn random_generator_gen_normal(
+    rng: &mut RandomGenerator_API,
+    mean: f64,
+    std_dev: f64,
+) -> f64 {
+    rng.gen_normal(mean, std_dev)
+}
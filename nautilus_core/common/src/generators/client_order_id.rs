@@ -20,12 +20,84 @@ use nautilus_model::identifiers::{
 
 use super::get_datetime_tag;
 
+/// The custom epoch for snowflake IDs (2024-01-01T00:00:00Z) in UNIX milliseconds.
+pub const SNOWFLAKE_EPOCH_MS: u64 = 1_704_067_200_000;
+
+const SNOWFLAKE_NODE_BITS: u64 = 10;
+const SNOWFLAKE_SEQUENCE_BITS: u64 = 12;
+const SNOWFLAKE_SEQUENCE_MASK: u64 = (1 << SNOWFLAKE_SEQUENCE_BITS) - 1;
+const SNOWFLAKE_NODE_MASK: u64 = (1 << SNOWFLAKE_NODE_BITS) - 1;
+
+/// The strategy used to generate client order IDs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClientOrderIdStrategy {
+    /// IDs of the form `O-{datetime}-{trader}-{strategy}-{count}`.
+    #[default]
+    Default,
+    /// Time-sortable numeric snowflake IDs composed of the milliseconds since
+    /// [`SNOWFLAKE_EPOCH_MS`], the `node_id` (10 bits) and a sequence (12 bits).
+    Snowflake { node_id: u16 },
+    /// Clock independent IDs of the form `O-{trader}-{strategy}-{count}`, for
+    /// reproducible backtests.
+    Deterministic,
+}
+
+/// The characters permitted in client order IDs by a venue.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClientOrderIdCharset {
+    /// Any valid identifier characters.
+    #[default]
+    Any,
+    /// ASCII letters, digits, hyphens and underscores only.
+    AlphanumericDash,
+    /// ASCII letters and digits only.
+    Alphanumeric,
+    /// ASCII digits only.
+    Numeric,
+}
+
+impl ClientOrderIdCharset {
+    #[must_use]
+    pub fn allows(&self, c: char) -> bool {
+        match self {
+            Self::Any => true,
+            Self::AlphanumericDash => c.is_ascii_alphanumeric() || c == '-' || c == '_',
+            Self::Alphanumeric => c.is_ascii_alphanumeric(),
+            Self::Numeric => c.is_ascii_digit(),
+        }
+    }
+}
+
+/// The configuration for generating client order IDs, including any venue constraints.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClientOrderIdConfig {
+    pub strategy: ClientOrderIdStrategy,
+    pub charset: ClientOrderIdCharset,
+    /// The maximum ID length, IDs exceeding this keep their trailing (most unique) characters.
+    pub max_length: Option<usize>,
+}
+
+impl ClientOrderIdConfig {
+    /// Applies the charset and length constraints to the given `id`.
+    #[must_use]
+    pub fn constrain(&self, id: &str) -> String {
+        let id: String = id.chars().filter(|c| self.charset.allows(*c)).collect();
+        match self.max_length {
+            Some(max_length) if id.len() > max_length => id[id.len() - max_length..].to_string(),
+            _ => id,
+        }
+    }
+}
+
 #[repr(C)]
 pub struct ClientOrderIdGenerator {
     clock: &'static AtomicTime,
     trader_id: TraderId,
     strategy_id: StrategyId,
     count: usize,
+    config: ClientOrderIdConfig,
+    snowflake_last_ms: u64,
+    snowflake_sequence: u64,
 }
 
 impl ClientOrderIdGenerator {
@@ -41,15 +113,29 @@ impl ClientOrderIdGenerator {
             strategy_id,
             count: initial_count,
             clock,
+            config: ClientOrderIdConfig::default(),
+            snowflake_last_ms: 0,
+            snowflake_sequence: 0,
         }
     }
 
+    #[must_use]
+    pub fn config(&self) -> ClientOrderIdConfig {
+        self.config
+    }
+
+    pub fn set_config(&mut self, config: ClientOrderIdConfig) {
+        self.config = config;
+    }
+
     pub fn set_count(&mut self, count: usize) {
         self.count = count;
     }
 
     pub fn reset(&mut self) {
         self.count = 0;
+        self.snowflake_last_ms = 0;
+        self.snowflake_sequence = 0;
     }
 
     #[must_use]
@@ -58,15 +144,56 @@ impl ClientOrderIdGenerator {
     }
 
     pub fn generate(&mut self) -> ClientOrderId {
-        let datetime_tag = get_datetime_tag(self.clock.get_time_ms());
-        let trader_tag = self.trader_id.get_tag();
-        let strategy_tag = self.strategy_id.get_tag();
+        self.generate_with(self.config)
+    }
+
+    /// Generates a client order ID with the given `config` (sharing the count and snowflake
+    /// sequence with the generators default configuration).
+    pub fn generate_with(&mut self, config: ClientOrderIdConfig) -> ClientOrderId {
         self.count += 1;
-        let id = format!(
-            "O-{}-{}-{}-{}",
-            datetime_tag, trader_tag, strategy_tag, self.count
-        );
-        ClientOrderId::from(id.as_str())
+        let id = match config.strategy {
+            ClientOrderIdStrategy::Default => {
+                let datetime_tag = get_datetime_tag(self.clock.get_time_ms());
+                let trader_tag = self.trader_id.get_tag();
+                let strategy_tag = self.strategy_id.get_tag();
+                format!(
+                    "O-{}-{}-{}-{}",
+                    datetime_tag, trader_tag, strategy_tag, self.count
+                )
+            }
+            ClientOrderIdStrategy::Snowflake { node_id } => {
+                self.next_snowflake(node_id).to_string()
+            }
+            ClientOrderIdStrategy::Deterministic => format!(
+                "O-{}-{}-{}",
+                self.trader_id.get_tag(),
+                self.strategy_id.get_tag(),
+                self.count
+            ),
+        };
+        ClientOrderId::from(config.constrain(&id).as_str())
+    }
+
+    fn next_snowflake(&mut self, node_id: u16) -> u64 {
+        let mut elapsed_ms = self
+            .clock
+            .get_time_ms()
+            .saturating_sub(SNOWFLAKE_EPOCH_MS)
+            .max(self.snowflake_last_ms);
+        if elapsed_ms == self.snowflake_last_ms {
+            self.snowflake_sequence = (self.snowflake_sequence + 1) & SNOWFLAKE_SEQUENCE_MASK;
+            if self.snowflake_sequence == 0 {
+                // Sequence exhausted for this millisecond, borrow the next one
+                elapsed_ms += 1;
+            }
+        } else {
+            self.snowflake_sequence = 0;
+        }
+        self.snowflake_last_ms = elapsed_ms;
+
+        (elapsed_ms << (SNOWFLAKE_NODE_BITS + SNOWFLAKE_SEQUENCE_BITS))
+            | ((u64::from(node_id) & SNOWFLAKE_NODE_MASK) << SNOWFLAKE_SEQUENCE_BITS)
+            | self.snowflake_sequence
     }
 }
 
@@ -76,12 +203,9 @@ impl ClientOrderIdGenerator {
 #[cfg(test)]
 mod tests {
     use nautilus_core::time::get_atomic_clock_static;
-    use nautilus_model::identifiers::{
-        client_order_id::ClientOrderId, strategy_id::StrategyId, trader_id::TraderId,
-    };
     use rstest::rstest;
 
-    use crate::generators::client_order_id::ClientOrderIdGenerator;
+    use super::*;
 
     fn get_client_order_id_generator(initial_count: Option<usize>) -> ClientOrderIdGenerator {
         let trader_id = TraderId::from("TRADER-001");
@@ -161,4 +285,59 @@ mod tests {
             ClientOrderId::new("O-19700101-0000-001-001-1").unwrap()
         );
     }
+
+    #[rstest]
+    fn test_generate_deterministic_client_order_id() {
+        let mut generator = get_client_order_id_generator(None);
+        generator.set_config(ClientOrderIdConfig {
+            strategy: ClientOrderIdStrategy::Deterministic,
+            ..Default::default()
+        });
+
+        assert_eq!(generator.generate(), ClientOrderId::from("O-001-001-1"));
+        assert_eq!(generator.generate(), ClientOrderId::from("O-001-001-2"));
+    }
+
+    #[rstest]
+    fn test_generate_snowflake_client_order_ids_are_time_sortable() {
+        let clock: &'static AtomicTime = Box::leak(Box::new(AtomicTime::new(false, 0)));
+        let mut generator = ClientOrderIdGenerator::new(
+            TraderId::from("TRADER-001"),
+            StrategyId::from("EMACross-001"),
+            0,
+            clock,
+        );
+        generator.set_config(ClientOrderIdConfig {
+            strategy: ClientOrderIdStrategy::Snowflake { node_id: 7 },
+            charset: ClientOrderIdCharset::Numeric,
+            max_length: None,
+        });
+        clock.set_time((SNOWFLAKE_EPOCH_MS + 1) * 1_000_000);
+
+        let ids: Vec<u64> = (0..3)
+            .map(|_| generator.generate().to_string().parse().unwrap())
+            .collect();
+        clock.set_time((SNOWFLAKE_EPOCH_MS + 2) * 1_000_000);
+        let later: u64 = generator.generate().to_string().parse().unwrap();
+
+        assert_eq!(ids[0], (1 << 22) | (7 << 12));
+        assert_eq!(ids[1], ids[0] + 1);
+        assert_eq!(ids[2], ids[0] + 2);
+        assert_eq!(later, (2 << 22) | (7 << 12));
+    }
+
+    #[rstest]
+    fn test_generate_with_venue_constraints() {
+        let mut generator = get_client_order_id_generator(Some(122));
+        let config = ClientOrderIdConfig {
+            strategy: ClientOrderIdStrategy::Default,
+            charset: ClientOrderIdCharset::Alphanumeric,
+            max_length: Some(12),
+        };
+
+        let result = generator.generate_with(config);
+
+        assert_eq!(result, ClientOrderId::from("000001001123"));
+        assert_eq!(generator.count(), 123);
+    }
 }
@@ -13,14 +13,19 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod aggregation;
 pub mod clock;
 pub mod enums;
+pub mod expiry;
 pub mod factories;
 pub mod generators;
 pub mod handlers;
 pub mod logging;
+pub mod metrics;
 pub mod msgbus;
+pub mod random;
 pub mod runtime;
+pub mod schedule;
 pub mod testing;
 pub mod timer;
 
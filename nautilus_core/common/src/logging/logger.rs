@@ -18,8 +18,9 @@ use std::{
     env, fmt,
     str::FromStr,
     sync::{
-        atomic::Ordering,
+        atomic::{AtomicUsize, Ordering},
         mpsc::{channel, Receiver, SendError, Sender},
+        OnceLock,
     },
     thread,
 };
@@ -45,6 +46,11 @@ use crate::{
     logging::writer::{FileWriter, FileWriterConfig, LogWriter, StderrWriter, StdoutWriter},
 };
 
+/// The sender for log events to the logging thread (set at initialization).
+static LOGGER_TX: OnceLock<Sender<LogEvent>> = OnceLock::new();
+/// The most verbose component level override, as a `LevelFilter` discriminant.
+static COMPONENT_MAX_LEVEL: AtomicUsize = AtomicUsize::new(0);
+
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.common")
@@ -59,6 +65,8 @@ pub struct LoggerConfig {
     component_level: HashMap<Ustr, LevelFilter>,
     /// If logger is using ANSI color codes.
     pub is_colored: bool,
+    /// If logger writes structured JSON lines to stdout and stderr.
+    pub is_json: bool,
     /// If the configuration should be printed to stdout at initialization.
     pub print_config: bool,
 }
@@ -70,6 +78,7 @@ impl Default for LoggerConfig {
             fileout_level: LevelFilter::Off,
             component_level: HashMap::new(),
             is_colored: false,
+            is_json: false,
             print_config: false,
         }
     }
@@ -81,6 +90,7 @@ impl LoggerConfig {
         fileout_level: LevelFilter,
         component_level: HashMap<Ustr, LevelFilter>,
         is_colored: bool,
+        is_json: bool,
         print_config: bool,
     ) -> Self {
         Self {
@@ -88,6 +98,7 @@ impl LoggerConfig {
             fileout_level,
             component_level,
             is_colored,
+            is_json,
             print_config,
         }
     }
@@ -98,11 +109,14 @@ impl LoggerConfig {
             mut fileout_level,
             mut component_level,
             mut is_colored,
+            mut is_json,
             mut print_config,
         } = Self::default();
         spec.split(';').for_each(|kv| {
             if kv == "is_colored" {
                 is_colored = true;
+            } else if kv == "is_json" {
+                is_json = true;
             } else if kv == "print_config" {
                 print_config = true;
             } else {
@@ -125,6 +139,7 @@ impl LoggerConfig {
             fileout_level,
             component_level,
             is_colored,
+            is_json,
             print_config,
         }
     }
@@ -161,6 +176,8 @@ pub enum LogEvent {
     Log(LogLine),
     /// A command to flush all logger buffers.
     Flush,
+    /// A command to set (or clear with `None`) the level override for a component.
+    SetComponentLevel(Ustr, Option<LevelFilter>),
 }
 
 /// Represents a log event which includes a message.
@@ -172,6 +189,9 @@ pub struct LogLine {
     pub color: LogColor,
     /// The Nautilus system component the log event originated from.
     pub component: Ustr,
+    /// The instrument ID the log event relates to (if any).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instrument_id: Option<Ustr>,
     /// The log message content.
     pub message: String,
 }
@@ -246,6 +266,9 @@ impl Serialize for LogLineWrapper {
         json_obj.insert("level".to_string(), self.line.level.to_string());
         json_obj.insert("color".to_string(), self.line.color.to_string());
         json_obj.insert("component".to_string(), self.line.component.to_string());
+        if let Some(instrument_id) = self.line.instrument_id {
+            json_obj.insert("instrument_id".to_string(), instrument_id.to_string());
+        }
         json_obj.insert("message".to_string(), self.line.message.to_string());
 
         json_obj.serialize(serializer)
@@ -257,7 +280,8 @@ impl Log for Logger {
         !LOGGING_BYPASSED.load(Ordering::Relaxed)
             && (metadata.level() == Level::Error
                 || metadata.level() <= self.config.stdout_level
-                || metadata.level() <= self.config.fileout_level)
+                || metadata.level() <= self.config.fileout_level
+                || metadata.level() as usize <= COMPONENT_MAX_LEVEL.load(Ordering::Relaxed))
     }
 
     fn log(&self, record: &log::Record) {
@@ -271,11 +295,15 @@ impl Log for Logger {
                 .get("component".into())
                 .map(|v| Ustr::from(&v.to_string()))
                 .unwrap_or_else(|| Ustr::from(record.metadata().target()));
+            let instrument_id = key_values
+                .get("instrument_id".into())
+                .map(|v| Ustr::from(&v.to_string()));
 
             let line = LogLine {
                 level: record.level(),
                 color,
                 component,
+                instrument_id,
                 message: format!("{}", record.args()).to_string(),
             };
             if let Err(SendError(LogEvent::Log(line))) = self.tx.send(LogEvent::Log(line)) {
@@ -315,8 +343,16 @@ impl Logger {
             println!("Logger initialized with {:?} {:?}", config, file_config);
         }
 
+        let tx = logger.tx.clone();
+        let component_max_level = config.component_level.values().max().copied();
+
         match set_boxed_logger(Box::new(logger)) {
             Ok(_) => {
+                let _ = LOGGER_TX.set(tx);
+                if let Some(level) = component_max_level {
+                    COMPONENT_MAX_LEVEL.fetch_max(level as usize, Ordering::Relaxed);
+                }
+
                 let _join_handle = thread::Builder::new()
                     .name("logging".to_string())
                     .spawn(move || {
@@ -356,8 +392,9 @@ impl Logger {
         let LoggerConfig {
             stdout_level,
             fileout_level,
-            ref component_level,
+            mut component_level,
             is_colored,
+            is_json,
             print_config: _,
         } = config;
 
@@ -380,26 +417,39 @@ impl Logger {
                 LogEvent::Flush => {
                     break;
                 }
+                LogEvent::SetComponentLevel(component, level) => match level {
+                    Some(level) => {
+                        component_level.insert(component, level);
+                    }
+                    None => {
+                        component_level.remove(&component);
+                    }
+                },
                 LogEvent::Log(line) => {
                     let timestamp = match LOGGING_REALTIME.load(Ordering::Relaxed) {
                         true => get_atomic_clock_realtime().get_time_ns(),
                         false => get_atomic_clock_static().get_time_ns(),
                     };
 
-                    let component_level = component_level.get(&line.component);
+                    let component_level = component_level.get(&line.component).copied();
 
                     // Check if the component exists in level_filters,
                     // and if its level is greater than event.level.
-                    if let Some(&filter_level) = component_level {
+                    if let Some(filter_level) = component_level {
                         if line.level > filter_level {
                             continue;
                         }
                     }
 
+                    // A component level override takes precedence over the writer levels
+                    let is_override = component_level.is_some();
+
                     let mut wrapper = LogLineWrapper::new(line, trader_id_cache, timestamp);
 
                     if stderr_writer.enabled(&wrapper.line) {
-                        if is_colored {
+                        if is_json {
+                            stderr_writer.write(&wrapper.get_json());
+                        } else if is_colored {
                             stderr_writer.write(wrapper.get_colored());
                         } else {
                             stderr_writer.write(wrapper.get_string());
@@ -408,8 +458,14 @@ impl Logger {
                         stderr_writer.flush();
                     }
 
-                    if stdout_writer.enabled(&wrapper.line) {
-                        if is_colored {
+                    if stdout_writer.enabled(&wrapper.line)
+                        || (is_override
+                            && stdout_level != LevelFilter::Off
+                            && wrapper.line.level > LevelFilter::Error)
+                    {
+                        if is_json {
+                            stdout_writer.write(&wrapper.get_json());
+                        } else if is_colored {
                             stdout_writer.write(wrapper.get_colored());
                         } else {
                             stdout_writer.write(wrapper.get_string());
@@ -418,7 +474,7 @@ impl Logger {
                     }
 
                     if let Some(ref mut writer) = file_writer_opt {
-                        if writer.enabled(&wrapper.line) {
+                        if writer.enabled(&wrapper.line) || is_override {
                             if writer.json_format {
                                 writer.write(&wrapper.get_json());
                             } else {
@@ -433,6 +489,26 @@ impl Logger {
     }
 }
 
+/// Sets the runtime log level override for the given `component`, or clears any
+/// override if `level` is `None`.
+///
+/// Lines from the component are then written up to the override level, regardless
+/// of the stdout and file levels (an override of `Off` silences the component).
+pub fn set_component_level(component: Ustr, level: Option<LevelFilter>) {
+    if let Some(level) = level {
+        COMPONENT_MAX_LEVEL.fetch_max(level as usize, Ordering::Relaxed);
+    }
+
+    match LOGGER_TX.get() {
+        Some(tx) => {
+            if let Err(e) = tx.send(LogEvent::SetComponentLevel(component, level)) {
+                eprintln!("Error sending component level: {e}");
+            }
+        }
+        None => eprintln!("Cannot set component level: logging not initialized"),
+    }
+}
+
 pub fn log(level: LogLevel, color: LogColor, component: Ustr, message: &str) {
     let color = Value::from(color as u8);
 
@@ -481,6 +557,7 @@ mod tests {
             level: log::Level::Info,
             color: LogColor::Normal,
             component: Ustr::from("Portfolio"),
+            instrument_id: None,
             message: "This is a log message".to_string(),
         };
 
@@ -490,6 +567,25 @@ mod tests {
         assert_eq!(deserialized_value["level"], "INFO");
         assert_eq!(deserialized_value["component"], "Portfolio");
         assert_eq!(deserialized_value["message"], "This is a log message");
+        assert!(deserialized_value.get("instrument_id").is_none());
+    }
+
+    #[rstest]
+    fn log_line_wrapper_json_with_instrument_id() {
+        let line = LogLine {
+            level: log::Level::Warn,
+            color: LogColor::Yellow,
+            component: Ustr::from("DataClient-BINANCE"),
+            instrument_id: Some(Ustr::from("ETHUSDT.BINANCE")),
+            message: "Book sequence gap".to_string(),
+        };
+
+        let wrapper = LogLineWrapper::new(line, Ustr::from("TRADER-001"), 0);
+
+        assert_eq!(
+            wrapper.get_json(),
+            "{\"timestamp\":\"1970-01-01T00:00:00.000000000Z\",\"trader_id\":\"TRADER-001\",\"level\":\"WARN\",\"color\":\"YELLOW\",\"component\":\"DataClient-BINANCE\",\"instrument_id\":\"ETHUSDT.BINANCE\",\"message\":\"Book sequence gap\"}\n"
+        );
     }
 
     #[rstest]
@@ -506,6 +602,7 @@ mod tests {
                     LevelFilter::Error
                 )]),
                 is_colored: true,
+                is_json: false,
                 print_config: false,
             }
         )
@@ -521,11 +618,31 @@ mod tests {
                 fileout_level: LevelFilter::Error,
                 component_level: HashMap::new(),
                 is_colored: false,
+                is_json: false,
                 print_config: true,
             }
         )
     }
 
+    #[rstest]
+    fn log_config_parsing_json() {
+        let config = LoggerConfig::from_spec("stdout=Info;is_json;BinanceDataClient=Debug");
+        assert_eq!(
+            config,
+            LoggerConfig {
+                stdout_level: LevelFilter::Info,
+                fileout_level: LevelFilter::Off,
+                component_level: HashMap::from_iter(vec![(
+                    Ustr::from("BinanceDataClient"),
+                    LevelFilter::Debug
+                )]),
+                is_colored: false,
+                is_json: true,
+                print_config: false,
+            }
+        )
+    }
+
     #[rstest]
     fn test_logging_to_file() {
         let config = LoggerConfig {
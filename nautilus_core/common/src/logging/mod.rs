@@ -23,7 +23,7 @@ use std::{
 use log::LevelFilter;
 use nautilus_core::{time::get_atomic_clock_static, uuid::UUID4};
 use nautilus_model::identifiers::trader_id::TraderId;
-use tracing_subscriber::EnvFilter;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 use ustr::Ustr;
 
 use self::{
@@ -34,6 +34,8 @@ use crate::enums::LogLevel;
 
 pub mod headers;
 pub mod logger;
+#[cfg(feature = "otlp")]
+pub mod otlp;
 pub mod writer;
 
 static LOGGING_INITIALIZED: AtomicBool = AtomicBool::new(false);
@@ -84,22 +86,68 @@ pub extern "C" fn logging_clock_set_static_time(time_ns: u64) {
     clock.set_time(time_ns);
 }
 
+/// Initialize tracing.
+///
+/// Tracing is meant to be used to trace/debug async Rust code. It can be
+/// configured to filter modules and write up to a specific level only
+/// by passing a configuration using the `RUST_LOG` environment variable.
+///
+/// When built with the `otlp` feature and `OTEL_EXPORTER_OTLP_ENDPOINT` is set,
+/// spans are also exported to the OTLP collector at that endpoint (filtered by
+/// `RUST_LOG`, or at `info` level if not set).
 ///
 /// # Safety
 ///
 /// Should only be called once during an applications run, ideally at the
 /// beginning of the run.
 pub fn init_tracing() {
-    // Skip tracing initialization if `RUST_LOG` is not set
-    if let Ok(v) = env::var("RUST_LOG") {
-        tracing_subscriber::fmt()
-            .with_env_filter(EnvFilter::new(v.clone()))
-            .try_init()
-            .unwrap_or_else(|e| eprintln!("Cannot set tracing subscriber because of error: {e}"));
-        println!("Initialized tracing logs with RUST_LOG={v}");
+    #[cfg(feature = "otlp")]
+    let otlp_endpoint = otlp::get_otlp_endpoint();
+    #[cfg(not(feature = "otlp"))]
+    let otlp_endpoint: Option<String> = None;
+
+    // Skip tracing initialization if neither `RUST_LOG` nor an OTLP endpoint is set
+    let filter = match (env::var("RUST_LOG"), &otlp_endpoint) {
+        (Ok(v), _) => v,
+        (Err(_), Some(_)) => "info".to_string(),
+        (Err(_), None) => return,
+    };
+
+    let registry = tracing_subscriber::registry()
+        .with(EnvFilter::new(filter.clone()))
+        .with(tracing_subscriber::fmt::layer());
+
+    #[cfg(feature = "otlp")]
+    let otlp_layer = otlp_endpoint.as_deref().and_then(|endpoint| {
+        otlp::otlp_layer(endpoint)
+            .map_err(|e| eprintln!("Cannot initialize OTLP exporter because of error: {e}"))
+            .ok()
+    });
+    #[cfg(feature = "otlp")]
+    let is_exporting = otlp_layer.is_some();
+    #[cfg(feature = "otlp")]
+    let registry = registry.with(otlp_layer);
+    #[cfg(not(feature = "otlp"))]
+    let is_exporting = false;
+
+    registry
+        .try_init()
+        .unwrap_or_else(|e| eprintln!("Cannot set tracing subscriber because of error: {e}"));
+    println!("Initialized tracing logs with RUST_LOG={filter}");
+
+    if is_exporting {
+        if let Some(endpoint) = otlp_endpoint {
+            println!("Initialized tracing OTLP exporter with endpoint={endpoint}");
+        }
     }
 }
 
+/// Shuts down tracing, flushing any spans pending export.
+pub fn shutdown_tracing() {
+    #[cfg(feature = "otlp")]
+    otlp::shutdown_otlp();
+}
+
 /// Initialize logging.
 ///
 /// Logging should be used for Python and sync Rust logic which is most of
@@ -0,0 +1,101 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! OpenTelemetry (OTLP) export of `tracing` spans.
+//!
+//! The exporter is configured with the standard OpenTelemetry environment variables, so
+//! spans can be shipped to any OTLP compatible collector (e.g. Jaeger or Tempo):
+//!
+//! - `OTEL_EXPORTER_OTLP_ENDPOINT`: the collector gRPC endpoint (enables the exporter).
+//! - `OTEL_SERVICE_NAME`: the service name for the exported spans.
+
+use std::env;
+
+use opentelemetry::{global, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{runtime, trace, Resource};
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::runtime::get_runtime;
+
+pub const OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+pub const OTLP_SERVICE_NAME_ENV: &str = "OTEL_SERVICE_NAME";
+pub const DEFAULT_SERVICE_NAME: &str = "nautilus_trader";
+
+/// Returns the OTLP collector endpoint from the environment (if set).
+#[must_use]
+pub fn get_otlp_endpoint() -> Option<String> {
+    env::var(OTLP_ENDPOINT_ENV).ok().filter(|v| !v.is_empty())
+}
+
+/// Returns the service name for exported spans from the environment, or the default.
+#[must_use]
+pub fn get_service_name() -> String {
+    env::var(OTLP_SERVICE_NAME_ENV)
+        .ok()
+        .filter(|v| !v.is_empty())
+        .unwrap_or_else(|| DEFAULT_SERVICE_NAME.to_string())
+}
+
+/// Creates a `tracing` layer which exports spans to the OTLP collector at `endpoint`.
+///
+/// Spans are exported in batches from the shared Nautilus tokio runtime.
+pub fn otlp_layer<S>(endpoint: &str) -> anyhow::Result<OpenTelemetryLayer<S, trace::Tracer>>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    // The batch span processor spawns its export task onto the current runtime
+    let _guard = get_runtime().enter();
+
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(endpoint),
+        )
+        .with_trace_config(
+            trace::config().with_resource(Resource::new(vec![KeyValue::new(
+                "service.name",
+                get_service_name(),
+            )])),
+        )
+        .install_batch(runtime::Tokio)?;
+
+    Ok(tracing_opentelemetry::layer().with_tracer(tracer))
+}
+
+/// Flushes any pending spans and shuts down the global tracer provider.
+pub fn shutdown_otlp() {
+    global::shutdown_tracer_provider();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_service_name_default() {
+        env::remove_var(OTLP_SERVICE_NAME_ENV);
+        assert_eq!(get_service_name(), DEFAULT_SERVICE_NAME);
+    }
+}
@@ -14,13 +14,17 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
-    fs::{create_dir_all, File},
-    io::{self, BufWriter, Stderr, Stdout, Write},
-    path::PathBuf,
+    fs::{self, create_dir_all, File},
+    io::{self, BufReader, BufWriter, Stderr, Stdout, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
 };
 
 use chrono::{DateTime, Utc};
+use flate2::{write::GzEncoder, Compression};
 use log::LevelFilter;
+use serde::Deserialize;
 
 use crate::logging::logger::LogLine;
 
@@ -115,6 +119,7 @@ pub struct FileWriterConfig {
     pub directory: Option<String>,
     pub file_name: Option<String>,
     pub file_format: Option<String>,
+    pub rotation: FileRotationConfig,
 }
 
 impl FileWriterConfig {
@@ -122,15 +127,61 @@ impl FileWriterConfig {
         directory: Option<String>,
         file_name: Option<String>,
         file_format: Option<String>,
+        rotation: FileRotationConfig,
     ) -> Self {
         Self {
             directory,
             file_name,
             file_format,
+            rotation,
         }
     }
 }
 
+/// The compression applied to rotated log files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileCompression {
+    Gzip,
+    Zstd,
+}
+
+impl FileCompression {
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Gzip => "gz",
+            Self::Zstd => "zst",
+        }
+    }
+}
+
+/// Configuration for log file rotation and the retention of rotated (backup) files.
+///
+/// When a limit is reached the current log file is renamed with a UTC timestamp
+/// (e.g. `{file_name}.20240101T000000123Z.log`), optionally compressed, and a new
+/// file is started with the original name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct FileRotationConfig {
+    /// The maximum size (bytes) of a log file before it is rotated.
+    pub max_file_size: Option<u64>,
+    /// The maximum age (seconds) of a log file before it is rotated.
+    pub max_file_age_secs: Option<u64>,
+    /// The compression for rotated log files.
+    pub compression: Option<FileCompression>,
+    /// The maximum number of rotated log files to retain.
+    pub max_backup_count: Option<usize>,
+    /// The maximum age (days) of rotated log files to retain.
+    pub max_backup_age_days: Option<u64>,
+}
+
+impl FileRotationConfig {
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.max_file_size.is_some() || self.max_file_age_secs.is_some()
+    }
+}
+
 #[derive(Debug)]
 pub struct FileWriter {
     pub json_format: bool,
@@ -140,6 +191,8 @@ pub struct FileWriter {
     trader_id: String,
     instance_id: String,
     level: LevelFilter,
+    file_size: u64,
+    opened_at: SystemTime,
 }
 
 impl FileWriter {
@@ -171,12 +224,14 @@ impl FileWriter {
         {
             Ok(file) => Some(Self {
                 json_format,
+                file_size: file.metadata().map_or(0, |m| m.len()),
                 buf: BufWriter::new(file),
                 path: file_path,
                 file_config,
                 trader_id,
                 instance_id,
                 level: fileout_level,
+                opened_at: SystemTime::now(),
             }),
             Err(e) => {
                 eprintln!("Error creating log file: {}", e);
@@ -227,6 +282,177 @@ impl FileWriter {
 
         current_date_utc != creation_date_utc
     }
+
+    /// Returns whether writing `len` more bytes should first roll over the log file,
+    /// based on the configured size and age limits.
+    pub fn should_roll_over(&self, len: usize) -> bool {
+        let rotation = &self.file_config.rotation;
+
+        if let Some(max_file_size) = rotation.max_file_size {
+            if self.file_size > 0 && self.file_size + len as u64 > max_file_size {
+                return true;
+            }
+        }
+
+        if let Some(max_file_age_secs) = rotation.max_file_age_secs {
+            let age = self.opened_at.elapsed().unwrap_or_default();
+            if self.file_size > 0 && age >= Duration::from_secs(max_file_age_secs) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Rolls over the log file by renaming it to a timestamped backup and starting a new file.
+    ///
+    /// Compression of the backup and the retention policy are applied on a separate thread.
+    fn roll_over(&mut self) {
+        self.flush();
+
+        let backup_path = create_backup_path(&self.path);
+        if let Err(e) = fs::rename(&self.path, &backup_path) {
+            eprintln!("Error rotating log file: {e}");
+            return;
+        }
+
+        match File::options().create(true).append(true).open(&self.path) {
+            Ok(file) => {
+                self.buf = BufWriter::new(file);
+                self.file_size = 0;
+                self.opened_at = SystemTime::now();
+            }
+            Err(e) => eprintln!("Error creating log file: {e}"),
+        }
+
+        let path = self.path.clone();
+        let rotation = self.file_config.rotation.clone();
+        let spawned = thread::Builder::new()
+            .name("logging-rotation".to_string())
+            .spawn(move || {
+                if let Some(compression) = rotation.compression {
+                    if let Err(e) = compress_file(&backup_path, compression) {
+                        eprintln!("Error compressing log file: {e}");
+                    }
+                }
+                apply_retention(&path, &rotation, SystemTime::now());
+            });
+        if let Err(e) = spawned {
+            eprintln!("Error spawning `logging-rotation` thread: {e}");
+        }
+    }
+}
+
+// Returns a unique backup path `{stem}.{timestamp}.{ext}` for the given log file `path`.
+fn create_backup_path(path: &Path) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = path.extension().unwrap_or_default().to_string_lossy();
+    let timestamp = Utc::now().format("%Y%m%dT%H%M%S%3fZ");
+
+    let mut backup_path = path.with_file_name(format!("{stem}.{timestamp}.{extension}"));
+    let mut count = 1;
+    while backup_path.exists() {
+        backup_path = path.with_file_name(format!("{stem}.{timestamp}-{count}.{extension}"));
+        count += 1;
+    }
+    backup_path
+}
+
+// Compresses the file at `path` alongside it, then removes the original.
+fn compress_file(path: &Path, compression: FileCompression) -> io::Result<PathBuf> {
+    let mut compressed_path = path.as_os_str().to_owned();
+    compressed_path.push(".");
+    compressed_path.push(compression.extension());
+    let compressed_path = PathBuf::from(compressed_path);
+
+    let mut reader = BufReader::new(File::open(path)?);
+    let writer = BufWriter::new(File::create(&compressed_path)?);
+
+    match compression {
+        FileCompression::Gzip => {
+            let mut encoder = GzEncoder::new(writer, Compression::default());
+            io::copy(&mut reader, &mut encoder)?;
+            encoder.finish()?.flush()?;
+        }
+        FileCompression::Zstd => {
+            let mut encoder = zstd::Encoder::new(writer, 0)?;
+            io::copy(&mut reader, &mut encoder)?;
+            encoder.finish()?.flush()?;
+        }
+    }
+
+    fs::remove_file(path)?;
+    Ok(compressed_path)
+}
+
+/// Returns the rotated (backup) files for the given log file `path`, oldest first.
+#[must_use]
+pub fn list_backup_files(path: &Path) -> Vec<PathBuf> {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = path.extension().unwrap_or_default().to_string_lossy();
+    let prefix = format!("{stem}.");
+    let suffix = format!(".{extension}");
+
+    let directory = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let Ok(entries) = fs::read_dir(directory) else {
+        return Vec::new();
+    };
+
+    let mut backups: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|backup| {
+            let Some(name) = backup.file_name().map(|n| n.to_string_lossy().into_owned()) else {
+                return false;
+            };
+            // Strip any compression extension before matching the backup name
+            let name = name
+                .strip_suffix(".gz")
+                .or_else(|| name.strip_suffix(".zst"))
+                .unwrap_or(&name);
+            name.len() > prefix.len() + suffix.len()
+                && name.starts_with(&prefix)
+                && name.ends_with(&suffix)
+        })
+        .collect();
+
+    // Backup names embed a sortable UTC timestamp
+    backups.sort();
+    backups
+}
+
+// Removes backup files beyond the configured retention count and age.
+fn apply_retention(path: &Path, rotation: &FileRotationConfig, now: SystemTime) {
+    let mut backups = list_backup_files(path);
+
+    if let Some(max_backup_age_days) = rotation.max_backup_age_days {
+        let max_age = Duration::from_secs(max_backup_age_days * 24 * 60 * 60);
+        backups.retain(|backup| {
+            let modified = fs::metadata(backup).and_then(|m| m.modified());
+            let is_expired = modified
+                .is_ok_and(|modified| now.duration_since(modified).unwrap_or_default() > max_age);
+            if is_expired {
+                remove_backup_file(backup);
+            }
+            !is_expired
+        });
+    }
+
+    if let Some(max_backup_count) = rotation.max_backup_count {
+        let excess = backups.len().saturating_sub(max_backup_count);
+        for backup in backups.drain(..excess) {
+            remove_backup_file(&backup);
+        }
+    }
+}
+
+fn remove_backup_file(path: &Path) {
+    if let Err(e) = fs::remove_file(path) {
+        eprintln!("Error removing rotated log file: {e}");
+    }
 }
 
 impl LogWriter for FileWriter {
@@ -247,15 +473,19 @@ impl LogWriter for FileWriter {
                 .open(file_path.clone())
             {
                 Ok(file) => {
+                    self.file_size = file.metadata().map_or(0, |m| m.len());
                     self.buf = BufWriter::new(file);
                     self.path = file_path;
+                    self.opened_at = SystemTime::now();
                 }
                 Err(e) => eprintln!("Error creating log file: {}", e),
             }
+        } else if self.should_roll_over(line.len()) {
+            self.roll_over();
         }
 
         match self.buf.write_all(line.as_bytes()) {
-            Ok(()) => {}
+            Ok(()) => self.file_size += line.len() as u64,
             Err(e) => eprintln!("Error writing to file: {e:?}"),
         }
     }
@@ -271,3 +501,186 @@ impl LogWriter for FileWriter {
         line.level <= self.level
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::GzDecoder;
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn stub_file_writer(directory: &Path, rotation: FileRotationConfig) -> FileWriter {
+        let file_config = FileWriterConfig::new(
+            Some(directory.to_str().unwrap().to_string()),
+            Some("test".to_string()),
+            None,
+            rotation,
+        );
+        FileWriter::new(
+            "TRADER-001".to_string(),
+            "instance".to_string(),
+            file_config,
+            LevelFilter::Info,
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_rotation_config_deserialization() {
+        let json = r#"{"max_file_size": 1024, "compression": "zstd", "max_backup_count": 5}"#;
+
+        let rotation: FileRotationConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            rotation,
+            FileRotationConfig {
+                max_file_size: Some(1024),
+                compression: Some(FileCompression::Zstd),
+                max_backup_count: Some(5),
+                ..Default::default()
+            }
+        );
+        assert!(rotation.is_enabled());
+        assert!(!FileRotationConfig::default().is_enabled());
+    }
+
+    #[rstest]
+    fn test_write_rolls_over_when_max_file_size_exceeded() {
+        let temp_dir = tempdir().unwrap();
+        let rotation = FileRotationConfig {
+            max_file_size: Some(16),
+            ..Default::default()
+        };
+        let mut writer = stub_file_writer(temp_dir.path(), rotation);
+
+        writer.write("first log line\n");
+        writer.write("second log line\n");
+        writer.flush();
+
+        let backups = list_backup_files(&writer.path);
+        assert_eq!(backups.len(), 1);
+        assert_eq!(fs::read_to_string(&backups[0]).unwrap(), "first log line\n");
+        assert_eq!(
+            fs::read_to_string(&writer.path).unwrap(),
+            "second log line\n"
+        );
+    }
+
+    #[rstest]
+    fn test_should_roll_over_when_max_file_age_exceeded() {
+        let temp_dir = tempdir().unwrap();
+        let rotation = FileRotationConfig {
+            max_file_age_secs: Some(60),
+            ..Default::default()
+        };
+        let mut writer = stub_file_writer(temp_dir.path(), rotation);
+
+        writer.write("first log line\n");
+        assert!(!writer.should_roll_over(1));
+
+        writer.opened_at -= Duration::from_secs(61);
+        assert!(writer.should_roll_over(1));
+    }
+
+    #[rstest]
+    fn test_compress_file_with_gzip() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("test.20240101T000000000Z.log");
+        fs::write(&path, "log line\n").unwrap();
+
+        let compressed_path = compress_file(&path, FileCompression::Gzip).unwrap();
+
+        let mut decoder = GzDecoder::new(File::open(&compressed_path).unwrap());
+        let mut contents = String::new();
+        decoder.read_to_string(&mut contents).unwrap();
+        assert_eq!(contents, "log line\n");
+        assert_eq!(compressed_path.extension().unwrap(), "gz");
+        assert!(!path.exists());
+    }
+
+    #[rstest]
+    fn test_compress_file_with_zstd() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("test.20240101T000000000Z.log");
+        fs::write(&path, "log line\n").unwrap();
+
+        let compressed_path = compress_file(&path, FileCompression::Zstd).unwrap();
+
+        let contents = zstd::decode_all(File::open(&compressed_path).unwrap()).unwrap();
+        assert_eq!(contents, b"log line\n");
+        assert_eq!(compressed_path.extension().unwrap(), "zst");
+    }
+
+    #[rstest]
+    fn test_list_backup_files_matches_only_backups() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("test.log");
+        for name in [
+            "test.log",
+            "test.20240102T000000000Z.log.gz",
+            "test.20240101T000000000Z.log",
+            "other.20240101T000000000Z.log",
+            "test.20240101T000000000Z.json",
+        ] {
+            fs::write(temp_dir.path().join(name), "").unwrap();
+        }
+
+        let backups = list_backup_files(&path);
+
+        assert_eq!(
+            backups,
+            vec![
+                temp_dir.path().join("test.20240101T000000000Z.log"),
+                temp_dir.path().join("test.20240102T000000000Z.log.gz"),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_apply_retention_with_max_backup_count_removes_oldest() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("test.log");
+        for day in 1..=4 {
+            let name = format!("test.2024010{day}T000000000Z.log");
+            fs::write(temp_dir.path().join(name), "").unwrap();
+        }
+        let rotation = FileRotationConfig {
+            max_backup_count: Some(2),
+            ..Default::default()
+        };
+
+        apply_retention(&path, &rotation, SystemTime::now());
+
+        assert_eq!(
+            list_backup_files(&path),
+            vec![
+                temp_dir.path().join("test.20240103T000000000Z.log"),
+                temp_dir.path().join("test.20240104T000000000Z.log"),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_apply_retention_with_max_backup_age_removes_expired() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("test.log");
+        fs::write(temp_dir.path().join("test.20240101T000000000Z.log"), "").unwrap();
+        let rotation = FileRotationConfig {
+            max_backup_age_days: Some(1),
+            ..Default::default()
+        };
+
+        apply_retention(&path, &rotation, SystemTime::now());
+        assert_eq!(list_backup_files(&path).len(), 1);
+
+        let later = SystemTime::now() + Duration::from_secs(2 * 24 * 60 * 60);
+        apply_retention(&path, &rotation, later);
+        assert!(list_backup_files(&path).is_empty());
+    }
+}
@@ -0,0 +1,346 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Prometheus metrics for live nodes.
+//!
+//! Metrics are collected into a process wide registry and exposed in the Prometheus text
+//! format over HTTP at `/metrics` once [`init_metrics`] has been called. Until then, all
+//! recording functions are no-ops so instrumented code paths carry negligible cost.
+
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        OnceLock,
+    },
+};
+
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+use crate::runtime::get_runtime;
+
+pub const METRICS_PATH: &str = "/metrics";
+pub const METRICS_NAMESPACE: &str = "nautilus";
+
+const MAX_REQUEST_HEAD_BYTES: usize = 8 * 1024;
+
+static METRICS_ENABLED: AtomicBool = AtomicBool::new(false);
+static METRICS: OnceLock<Metrics> = OnceLock::new();
+
+/// Latency buckets (seconds) for order round trips to the venue.
+const ORDER_LATENCY_BUCKETS: &[f64] = &[
+    0.000_5, 0.001, 0.002_5, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0,
+];
+
+/// Duration buckets (seconds) for applying updates to an order book.
+const BOOK_APPLY_BUCKETS: &[f64] = &[
+    0.000_001,
+    0.000_002_5,
+    0.000_005,
+    0.000_01,
+    0.000_025,
+    0.000_05,
+    0.000_1,
+    0.000_25,
+    0.000_5,
+    0.001,
+    0.005,
+];
+
+/// Provides the metrics collected for a node.
+pub struct Metrics {
+    registry: Registry,
+    /// The count of messages processed, labelled by `component` and `kind`.
+    pub messages_processed: IntCounterVec,
+    /// The time from order initialization to a venue response, labelled by `event`.
+    pub order_latency: HistogramVec,
+    /// The time to apply an update to an order book, labelled by `book_type`.
+    pub book_apply_time: HistogramVec,
+    /// The count of WebSocket reconnects, labelled by `url`.
+    pub ws_reconnects: IntCounterVec,
+}
+
+impl Metrics {
+    /// Creates a new [`Metrics`] instance with all collectors registered.
+    pub fn new() -> anyhow::Result<Self> {
+        let registry = Registry::new_custom(Some(METRICS_NAMESPACE.to_string()), None)?;
+
+        let messages_processed = IntCounterVec::new(
+            Opts::new(
+                "messages_processed_total",
+                "Messages processed by component",
+            ),
+            &["component", "kind"],
+        )?;
+        let order_latency = HistogramVec::new(
+            HistogramOpts::new(
+                "order_latency_seconds",
+                "Time from order initialization to a venue response",
+            )
+            .buckets(ORDER_LATENCY_BUCKETS.to_vec()),
+            &["event"],
+        )?;
+        let book_apply_time = HistogramVec::new(
+            HistogramOpts::new(
+                "book_apply_seconds",
+                "Time to apply an update to an order book",
+            )
+            .buckets(BOOK_APPLY_BUCKETS.to_vec()),
+            &["book_type"],
+        )?;
+        let ws_reconnects = IntCounterVec::new(
+            Opts::new("ws_reconnects_total", "WebSocket client reconnects"),
+            &["url"],
+        )?;
+
+        registry.register(Box::new(messages_processed.clone()))?;
+        registry.register(Box::new(order_latency.clone()))?;
+        registry.register(Box::new(book_apply_time.clone()))?;
+        registry.register(Box::new(ws_reconnects.clone()))?;
+
+        Ok(Self {
+            registry,
+            messages_processed,
+            order_latency,
+            book_apply_time,
+            ws_reconnects,
+        })
+    }
+
+    /// Encodes all collected metrics in the Prometheus text format.
+    pub fn encode(&self) -> anyhow::Result<String> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+}
+
+/// Returns the global metrics (initializing the collectors if necessary).
+pub fn get_metrics() -> &'static Metrics {
+    METRICS.get_or_init(|| Metrics::new().expect("Failed to create metrics"))
+}
+
+/// Returns whether metrics collection is enabled.
+#[must_use]
+pub fn metrics_enabled() -> bool {
+    METRICS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Enables metrics collection and starts serving `/metrics` on the given address.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If metrics have already been initialized.
+/// - If the listener cannot be bound to `addr`.
+pub fn init_metrics(addr: SocketAddr) -> anyhow::Result<()> {
+    if metrics_enabled() {
+        anyhow::bail!("Metrics have already been initialized");
+    }
+
+    let listener = get_runtime().block_on(TcpListener::bind(addr))?;
+    let local_addr = listener.local_addr()?;
+
+    get_metrics();
+    METRICS_ENABLED.store(true, Ordering::Relaxed);
+
+    get_runtime().spawn(serve(listener));
+    log::info!("Serving metrics on http://{local_addr}{METRICS_PATH}");
+
+    Ok(())
+}
+
+/// Increments the count of messages of `kind` processed by `component`.
+pub fn inc_messages_processed(component: &str, kind: &str) {
+    if metrics_enabled() {
+        get_metrics()
+            .messages_processed
+            .with_label_values(&[component, kind])
+            .inc();
+    }
+}
+
+/// Records the time from order initialization to the venue response `event`.
+pub fn observe_order_latency(event: &str, latency_ns: u64) {
+    if metrics_enabled() {
+        get_metrics()
+            .order_latency
+            .with_label_values(&[event])
+            .observe(nanos_to_secs(latency_ns));
+    }
+}
+
+/// Records the time taken to apply an update to an order book of `book_type`.
+pub fn observe_book_apply_time(book_type: &str, duration_ns: u64) {
+    if metrics_enabled() {
+        get_metrics()
+            .book_apply_time
+            .with_label_values(&[book_type])
+            .observe(nanos_to_secs(duration_ns));
+    }
+}
+
+/// Increments the count of reconnects for the WebSocket client at `url`.
+pub fn inc_ws_reconnects(url: &str) {
+    if metrics_enabled() {
+        get_metrics().ws_reconnects.with_label_values(&[url]).inc();
+    }
+}
+
+fn nanos_to_secs(nanos: u64) -> f64 {
+    nanos as f64 / 1_000_000_000.0
+}
+
+async fn serve(listener: TcpListener) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                tokio::spawn(async move {
+                    if let Err(e) = handle_connection(stream).await {
+                        log::debug!("Error serving metrics request: {e}");
+                    }
+                });
+            }
+            Err(e) => log::error!("Error accepting metrics connection: {e}"),
+        }
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream) -> anyhow::Result<()> {
+    let mut buffer = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+
+    // Read the request head only, bodies are not expected for scrapes
+    while !buffer.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        buffer.extend_from_slice(&chunk[..n]);
+        if buffer.len() > MAX_REQUEST_HEAD_BYTES {
+            anyhow::bail!("Request head too large");
+        }
+    }
+
+    let response = build_response(&buffer);
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+
+    Ok(())
+}
+
+fn build_response(request: &[u8]) -> String {
+    let request_line = request
+        .split(|b| *b == b'\n')
+        .next()
+        .map(|line| String::from_utf8_lossy(line).trim().to_string())
+        .unwrap_or_default();
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or_default();
+
+    let (status, content_type, body) = match (method, path) {
+        ("GET", METRICS_PATH) => match get_metrics().encode() {
+            Ok(body) => ("200 OK", TextEncoder::new().format_type().to_string(), body),
+            Err(e) => (
+                "500 Internal Server Error",
+                "text/plain".to_string(),
+                e.to_string(),
+            ),
+        },
+        ("GET", _) => ("404 Not Found", "text/plain".to_string(), String::new()),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain".to_string(),
+            String::new(),
+        ),
+    };
+
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_recording_is_noop_when_disabled() {
+        inc_messages_processed("DataEngine", "data");
+
+        assert!(!metrics_enabled());
+        assert_eq!(
+            get_metrics()
+                .messages_processed
+                .with_label_values(&["DataEngine", "data"])
+                .get(),
+            0
+        );
+    }
+
+    #[rstest]
+    fn test_encode_includes_recorded_metrics() {
+        let metrics = Metrics::new().unwrap();
+        metrics
+            .messages_processed
+            .with_label_values(&["ExecEngine", "event"])
+            .inc();
+        metrics
+            .order_latency
+            .with_label_values(&["accepted"])
+            .observe(0.002);
+        metrics.ws_reconnects.with_label_values(&["wss://a"]).inc();
+
+        let text = metrics.encode().unwrap();
+
+        assert!(text.contains(
+            "nautilus_messages_processed_total{component=\"ExecEngine\",kind=\"event\"} 1"
+        ));
+        assert!(text.contains("nautilus_order_latency_seconds_count{event=\"accepted\"} 1"));
+        assert!(text.contains("nautilus_ws_reconnects_total{url=\"wss://a\"} 1"));
+    }
+
+    #[rstest]
+    fn test_build_response_for_unknown_path() {
+        let response = build_response(b"GET /unknown HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 404 Not Found"));
+    }
+
+    #[rstest]
+    fn test_build_response_for_invalid_method() {
+        let response = build_response(b"POST /metrics HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 405 Method Not Allowed"));
+    }
+
+    #[rstest]
+    fn test_build_response_for_metrics() {
+        let response = build_response(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("Content-Type: text/plain; version=0.0.4"));
+    }
+}
@@ -17,13 +17,17 @@ use std::{
     collections::HashMap,
     fmt,
     hash::{Hash, Hasher},
+    str::Split,
     sync::mpsc::{channel, Receiver, SendError, Sender},
     thread,
 };
 
 use indexmap::IndexMap;
 use nautilus_core::uuid::UUID4;
-use nautilus_model::identifiers::trader_id::TraderId;
+use nautilus_model::{
+    data::{custom::CustomData, Data},
+    identifiers::{instrument_id::InstrumentId, trader_id::TraderId},
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use ustr::Ustr;
@@ -36,10 +40,14 @@ use crate::redis::handle_messages_with_redis;
 //
 // This is an internal class intended to be used by the message bus to organize
 // topics and their subscribers.
+//
+// Subscriptions are delivered in descending `priority` order, with ties broken
+// by ascending `sequence` (the order in which they were subscribed).
 #[derive(Clone, Debug)]
 pub struct Subscription {
     pub handler: MessageHandler,
     pub topic: Ustr,
+    pub pattern: TopicPattern,
     pub sequence: usize,
     pub priority: u8,
 }
@@ -55,6 +63,7 @@ impl Subscription {
         Self {
             topic,
             handler,
+            pattern: TopicPattern::new(topic),
             sequence,
             priority: priority.unwrap_or(0),
         }
@@ -119,6 +128,8 @@ impl fmt::Display for BusMessage {
 /// Pub/Sub wildcard patterns for hierarchical topics are possible:
 ///  - `*` asterisk represents one or more characters in a pattern.
 ///  - `?` question mark represents a single character in a pattern.
+///  - `+` as a whole level represents exactly one topic level.
+///  - `#` as a whole level represents zero or more topic levels.
 ///
 /// Given a topic and pattern potentially containing wildcard characters, i.e.
 /// `*` and `?`, where `?` can match any single character in the topic, and `*`
@@ -131,6 +142,15 @@ impl fmt::Display for BusMessage {
 /// A question mark matches a single character once. For example, `c?mp` matches
 /// `camp` and `comp`. The question mark can also be used more than once.
 /// For example, `c??p` would match both of the above examples and `coop`.
+///
+/// Topic levels are separated by `.` and the MQTT-style level wildcards never
+/// match across a level boundary except as described. For example,
+/// `data.quotes.+` matches `data.quotes.BINANCE` but not `data.quotes.BINANCE.ETHUSDT`,
+/// whereas `data.quotes.#` matches both (as well as `data.quotes` itself).
+/// When a pattern contains a level wildcard, `*` and `?` only match within a single level.
+///
+/// Subscribers are delivered messages in descending priority order, and subscribers
+/// with equal priority are delivered in the order they subscribed.
 #[derive(Clone)]
 pub struct MessageBus {
     /// The trader ID associated with the message bus.
@@ -154,10 +174,14 @@ pub struct MessageBus {
     /// a topic can be a string with wildcards
     /// * '?' - any character
     /// * '*' - any number of any characters
+    /// * '+' - any single level
+    /// * '#' - any number of levels
     subscriptions: IndexMap<Subscription, Vec<Ustr>>,
-    /// maps a pattern to all the handlers registered for it
-    /// this is updated whenever a new subscription is created.
+    /// maps a published topic to all the subscriptions matching it (in delivery order)
+    /// this is updated whenever a subscription is created or removed.
     patterns: IndexMap<Ustr, Vec<Subscription>>,
+    /// The sequence number assigned to the next subscription.
+    next_sequence: usize,
     /// handles a message or a request destined for a specific endpoint.
     endpoints: IndexMap<Ustr, MessageHandler>,
     /// Relates a request with a response
@@ -178,7 +202,14 @@ impl MessageBus {
         let has_backing = config
             .get("database")
             .map_or(false, |v| v != &serde_json::Value::Null);
-        let tx = if has_backing {
+        // Only the built-in backing is handled on the `msgbus` thread, other backing types
+        // (such as `nats`) are handled by external adapters in `nautilus_infrastructure`
+        let is_builtin_backing = config
+            .get("database")
+            .and_then(|database| database.get("type"))
+            .and_then(Value::as_str)
+            .map_or(true, |backing_type| backing_type == "redis");
+        let tx = if has_backing && is_builtin_backing {
             let (tx, rx) = channel::<BusMessage>();
             let _join_handler = thread::Builder::new()
                 .name("msgbus".to_string())
@@ -202,6 +233,7 @@ impl MessageBus {
             patterns: IndexMap::new(),
             endpoints: IndexMap::new(),
             correlation_index: IndexMap::new(),
+            next_sequence: 0,
             has_backing,
         })
     }
@@ -230,9 +262,9 @@ impl MessageBus {
     /// Returns whether there are subscribers for the given `pattern`.
     #[must_use]
     pub fn has_subscribers(&self, pattern: &str) -> bool {
-        self.matching_handlers(&Ustr::from(pattern))
-            .next()
-            .is_some()
+        let pattern = TopicPattern::new(Ustr::from(pattern));
+        let mut handlers = self.matching_handlers(&pattern);
+        handlers.next().is_some()
     }
 
     /// Returns whether there are subscribers for the given `pattern`.
@@ -284,7 +316,7 @@ impl MessageBus {
     /// Subscribes the given `handler` to the `topic`.
     pub fn subscribe(&mut self, topic: &str, handler: MessageHandler, priority: Option<u8>) {
         let topic = Ustr::from(topic);
-        let sub = Subscription::new(topic, handler, self.subscriptions.len(), priority);
+        let sub = Subscription::new(topic, handler, self.next_sequence, priority);
 
         if self.subscriptions.contains_key(&sub) {
             // TODO: Implement proper logging
@@ -292,14 +324,16 @@ impl MessageBus {
             return;
         }
 
-        // Find existing patterns which match this topic
+        self.next_sequence += 1;
+
+        // Find existing published topics which match this pattern
         let mut matches = Vec::new();
-        for (pattern, subs) in &mut self.patterns {
-            if is_matching(&topic, pattern) {
-                subs.push(sub.clone());
-                subs.sort();
-                // subs.sort_by(|a, b| a.priority.cmp(&b.priority).then_with(|| a.cmp(b)));
-                matches.push(*pattern);
+        for (published, subs) in &mut self.patterns {
+            if sub.pattern.matches(published) {
+                // Sequence numbers are unique, so the position is never an existing entry
+                let idx = subs.binary_search(&sub).unwrap_or_else(|idx| idx);
+                subs.insert(idx, sub.clone());
+                matches.push(*published);
             }
         }
 
@@ -310,8 +344,14 @@ impl MessageBus {
 
     /// Unsubscribes the given `handler` from the `topic`.
     pub fn unsubscribe(&mut self, topic: &str, handler: MessageHandler) {
-        let sub = Subscription::new(Ustr::from(topic), handler, self.subscriptions.len(), None);
-        self.subscriptions.shift_remove(&sub);
+        let sub = Subscription::new(Ustr::from(topic), handler, self.next_sequence, None);
+        if let Some(matches) = self.subscriptions.shift_remove(&sub) {
+            for published in matches {
+                if let Some(subs) = self.patterns.get_mut(&published) {
+                    subs.retain(|s| s != &sub);
+                }
+            }
+        }
     }
 
     /// Returns the handler for the given `endpoint`.
@@ -350,42 +390,52 @@ impl MessageBus {
         self.correlation_index.shift_remove(correlation_id)
     }
 
+    /// Returns the subscriptions matching the given published `topic` in delivery order.
     #[must_use]
-    pub fn matching_subscriptions<'a>(&'a self, pattern: &'a Ustr) -> Vec<&'a Subscription> {
-        let mut matching_subs: Vec<&'a Subscription> = Vec::new();
-
-        // Collect matching subscriptions from direct subscriptions
-        matching_subs.extend(self.subscriptions.iter().filter_map(|(sub, _)| {
-            if is_matching(&sub.topic, pattern) {
-                Some(sub)
-            } else {
-                None
-            }
-        }));
-
-        // Collect matching subscriptions from pattern-based subscriptions
-        // TODO: Improve efficiency of this
-        for subs in self.patterns.values() {
-            let filtered_subs: Vec<&Subscription> = subs
-                .iter()
-                // .filter(|sub| is_matching(&sub.topic, pattern))
-                // .filter(|sub| !matching_subs.contains(sub) && is_matching(&sub.topic, pattern))
-                .collect();
-
-            matching_subs.extend(filtered_subs);
+    pub fn matching_subscriptions<'a>(&'a self, topic: &'a Ustr) -> Vec<&'a Subscription> {
+        if let Some(subs) = self.patterns.get(topic) {
+            return subs.iter().collect();
         }
 
+        let mut matching_subs: Vec<&'a Subscription> = self
+            .subscriptions
+            .keys()
+            .filter(|sub| sub.pattern.matches(topic))
+            .collect();
+
         // Sort into priority order
         matching_subs.sort();
         matching_subs
     }
 
+    /// Resolves the subscriptions matching the given published `topic` in delivery order.
+    ///
+    /// The result is cached, and kept up to date as subscriptions are added and removed,
+    /// so repeated publishing on the same topic does not need to re-evaluate any patterns.
+    pub fn resolve_subscriptions(&mut self, topic: &Ustr) -> &[Subscription] {
+        if !self.patterns.contains_key(topic) {
+            let mut subs = Vec::new();
+            for (sub, matches) in &mut self.subscriptions {
+                if sub.pattern.matches(topic) {
+                    subs.push(sub.clone());
+                    if let Err(idx) = matches.binary_search(topic) {
+                        matches.insert(idx, *topic);
+                    }
+                }
+            }
+            subs.sort();
+            self.patterns.insert(*topic, subs);
+        }
+
+        &self.patterns[topic]
+    }
+
     fn matching_handlers<'a>(
         &'a self,
-        pattern: &'a Ustr,
+        pattern: &'a TopicPattern,
     ) -> impl Iterator<Item = &'a MessageHandler> {
         self.subscriptions.iter().filter_map(move |(sub, _)| {
-            if is_matching(&sub.topic, pattern) {
+            if pattern.matches(&sub.topic) {
                 Some(&sub.handler)
             } else {
                 None
@@ -448,36 +498,225 @@ fn handle_messages_with_redis_if_enabled(
     panic!("`redis` feature is not enabled");
 }
 
+/// Represents a compiled message bus topic pattern.
+///
+/// Patterns are compiled once on creation, so that matching a topic does not need
+/// to re-parse the pattern or allocate. See [`MessageBus`] for the wildcard syntax.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TopicPattern {
+    pattern: Ustr,
+    matcher: Matcher,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Matcher {
+    /// The pattern contains no wildcards.
+    Exact,
+    /// The pattern contains only character wildcards (which may span levels).
+    Glob,
+    /// The pattern contains level wildcards.
+    Levels(Vec<Level>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Level {
+    Literal(Ustr),
+    Glob(Ustr),
+    Single,
+    Multi,
+}
+
+impl TopicPattern {
+    /// Compiles a new [`TopicPattern`] from the given `pattern`.
+    #[must_use]
+    pub fn new(pattern: Ustr) -> Self {
+        let has_level_wildcard = pattern.split('.').any(|level| level == "+" || level == "#");
+        let has_char_wildcard = |s: &str| s.contains(['*', '?']);
+
+        let matcher = if has_level_wildcard {
+            let levels = pattern
+                .split('.')
+                .map(|level| match level {
+                    "+" => Level::Single,
+                    "#" => Level::Multi,
+                    _ if has_char_wildcard(level) => Level::Glob(Ustr::from(level)),
+                    _ => Level::Literal(Ustr::from(level)),
+                })
+                .collect();
+            Matcher::Levels(levels)
+        } else if has_char_wildcard(pattern.as_str()) {
+            Matcher::Glob
+        } else {
+            Matcher::Exact
+        };
+
+        Self { pattern, matcher }
+    }
+
+    /// Returns the pattern string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.pattern.as_str()
+    }
+
+    /// Returns whether the given `topic` matches the pattern.
+    #[must_use]
+    pub fn matches(&self, topic: &str) -> bool {
+        match &self.matcher {
+            Matcher::Exact => self.pattern.as_str() == topic,
+            Matcher::Glob => is_glob_match(topic.as_bytes(), self.pattern.as_bytes()),
+            Matcher::Levels(levels) => is_levels_match(levels, topic.split('.')),
+        }
+    }
+}
+
+impl fmt::Display for TopicPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+// Matches `topic` against a character glob `pattern` where `*` matches zero or more
+// characters and `?` matches exactly one character.
+fn is_glob_match(topic: &[u8], pattern: &[u8]) -> bool {
+    let (mut t, mut p) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < topic.len() {
+        if p < pattern.len() && (pattern[p] == b'?' || pattern[p] == topic[t]) {
+            t += 1;
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            // Let the last `*` absorb one more character and retry
+            backtrack = Some((star_p, star_t + 1));
+            p = star_p + 1;
+            t = star_t + 1;
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+fn is_levels_match(levels: &[Level], mut parts: Split<'_, char>) -> bool {
+    for (i, level) in levels.iter().enumerate() {
+        match level {
+            Level::Multi => {
+                let rest = &levels[i + 1..];
+                if rest.is_empty() {
+                    return true;
+                }
+                // Try every split point for the zero or more levels consumed by `#`
+                loop {
+                    if is_levels_match(rest, parts.clone()) {
+                        return true;
+                    }
+                    if parts.next().is_none() {
+                        return false;
+                    }
+                }
+            }
+            Level::Single => {
+                if parts.next().is_none() {
+                    return false;
+                }
+            }
+            Level::Literal(literal) => {
+                if parts.next() != Some(literal.as_str()) {
+                    return false;
+                }
+            }
+            Level::Glob(glob) => match parts.next() {
+                Some(part) if is_glob_match(part.as_bytes(), glob.as_bytes()) => {}
+                _ => return false,
+            },
+        }
+    }
+
+    parts.next().is_none()
+}
+
 /// Match a topic and a string pattern
 /// pattern can contains -
 /// '*' - match 0 or more characters after this
 /// '?' - match any character once
+/// '+' - match any single level
+/// '#' - match 0 or more levels
 /// 'a-z' - match the specific character
 #[must_use]
 pub fn is_matching(topic: &Ustr, pattern: &Ustr) -> bool {
-    let mut table = [[false; 256]; 256];
-    table[0][0] = true;
+    TopicPattern::new(*pattern).matches(topic)
+}
 
-    let m = pattern.len();
-    let n = topic.len();
+fn get_instrument_topic(prefix: &str, instrument_id: &InstrumentId) -> Ustr {
+    Ustr::from(&format!(
+        "{prefix}.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    ))
+}
 
-    pattern.chars().enumerate().for_each(|(j, c)| {
-        if c == '*' {
-            table[0][j + 1] = table[0][j];
-        }
-    });
-
-    topic.chars().enumerate().for_each(|(i, tc)| {
-        pattern.chars().enumerate().for_each(|(j, pc)| {
-            if pc == '*' {
-                table[i + 1][j + 1] = table[i][j + 1] || table[i + 1][j];
-            } else if pc == '?' || tc == pc {
-                table[i + 1][j + 1] = table[i][j];
-            }
-        });
-    });
+/// Returns the message bus topic for mark price updates of the given instrument.
+#[must_use]
+pub fn get_mark_price_topic(instrument_id: &InstrumentId) -> Ustr {
+    get_instrument_topic("data.mark_prices", instrument_id)
+}
+
+/// Returns the message bus topic for index price updates of the given instrument.
+#[must_use]
+pub fn get_index_price_topic(instrument_id: &InstrumentId) -> Ustr {
+    get_instrument_topic("data.index_prices", instrument_id)
+}
+
+/// Returns the message bus topic for open interest updates of the given instrument.
+#[must_use]
+pub fn get_open_interest_topic(instrument_id: &InstrumentId) -> Ustr {
+    get_instrument_topic("data.open_interest", instrument_id)
+}
+
+/// Returns the message bus topic for funding rate updates of the given instrument.
+#[must_use]
+pub fn get_funding_rate_topic(instrument_id: &InstrumentId) -> Ustr {
+    get_instrument_topic("data.funding_rates", instrument_id)
+}
 
-    table[n][m]
+/// Returns the message bus topic for liquidation ticks of the given instrument.
+#[must_use]
+pub fn get_liquidation_topic(instrument_id: &InstrumentId) -> Ustr {
+    get_instrument_topic("data.liquidations", instrument_id)
+}
+
+/// Returns the message bus topic for option greeks of the given instrument.
+#[must_use]
+pub fn get_greeks_topic(instrument_id: &InstrumentId) -> Ustr {
+    get_instrument_topic("data.greeks", instrument_id)
+}
+
+/// Returns the message bus topic for the given user-defined custom data.
+#[must_use]
+pub fn get_custom_data_topic(data: &CustomData) -> Ustr {
+    Ustr::from(&format!("data.{}", data.data_type))
+}
+
+/// Returns the message bus topic the given `data` should be published on.
+#[must_use]
+pub fn get_data_topic(data: &Data) -> Ustr {
+    match data {
+        Data::Delta(delta) => get_instrument_topic("data.book.deltas", &delta.instrument_id),
+        Data::Deltas(deltas) => get_instrument_topic("data.book.deltas", &deltas.instrument_id),
+        Data::Depth10(depth) => get_instrument_topic("data.book.depth", &depth.instrument_id),
+        Data::Depth25(depth) => get_instrument_topic("data.book.depth25", &depth.instrument_id),
+        Data::Quote(quote) => get_instrument_topic("data.quotes", &quote.instrument_id),
+        Data::Trade(trade) => get_instrument_topic("data.trades", &trade.instrument_id),
+        Data::Trades(trades) => get_instrument_topic("data.trades", &trades.instrument_id),
+        Data::Bar(bar) => Ustr::from(&format!("data.bars.{}", bar.bar_type)),
+        Data::MarkPrice(update) => get_mark_price_topic(&update.instrument_id),
+        Data::IndexPrice(update) => get_index_price_topic(&update.instrument_id),
+        Data::OpenInterest(update) => get_open_interest_topic(&update.instrument_id),
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -489,6 +728,13 @@ mod tests {
     use std::sync::Arc;
 
     use nautilus_core::{message::Message, uuid::UUID4};
+    use nautilus_model::{
+        data::{
+            depth::{stubs::stub_depth25, OrderBookDepth25},
+            prices::{IndexPriceUpdate, MarkPriceUpdate},
+        },
+        types::price::Price,
+    };
     use rstest::*;
 
     use super::*;
@@ -696,10 +942,137 @@ mod tests {
     #[case("data.quotes.BINANCE", "data.*.BINANCE", true)]
     #[case("data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.*", true)]
     #[case("data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.ETH*", true)]
+    #[case("data.quotes.BINANCE", "data.quotes.+", true)]
+    #[case("data.quotes.BINANCE.ETHUSDT", "data.quotes.+", false)]
+    #[case("data.quotes.BINANCE.ETHUSDT", "data.+.BINANCE.+", true)]
+    #[case("data.quotes.BINANCE.ETHUSDT", "data.+.+", false)]
+    #[case("data.quotes", "data.quotes.+", false)]
+    #[case("data.quotes", "data.quotes.#", true)]
+    #[case("data.quotes.BINANCE.ETHUSDT", "data.quotes.#", true)]
+    #[case("data.quotes.BINANCE.ETHUSDT", "#", true)]
+    #[case("data.quotes.BINANCE.ETHUSDT", "data.#.ETHUSDT", true)]
+    #[case("data.quotes.BINANCE.ETHUSDT", "data.#.BTCUSDT", false)]
+    #[case("data.quotes.BINANCE.ETHUSDT", "data.+.BIN*.#", true)]
+    #[case("data.quotes.BINANCE.ETHUSDT", "data.+.*.ETHUSDT", false)]
+    #[case("data.quotesX", "data.quotes.#", false)]
     fn test_is_matching(#[case] topic: &str, #[case] pattern: &str, #[case] expected: bool) {
         assert_eq!(
             is_matching(&Ustr::from(topic), &Ustr::from(pattern)),
             expected
         );
     }
+
+    #[rstest]
+    fn test_get_data_topic_for_depth25(stub_depth25: OrderBookDepth25) {
+        let topic = get_data_topic(&Data::from(stub_depth25));
+
+        assert_eq!(topic.as_str(), "data.book.depth25.XNAS.AAPL");
+        assert!(!is_matching(&topic, &Ustr::from("data.book.depth.*")));
+    }
+
+    #[rstest]
+    fn test_get_data_topic_for_price_updates() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let mark = MarkPriceUpdate::new(instrument_id, Price::from("50200.00"), 1, 2);
+        let index = IndexPriceUpdate::new(instrument_id, Price::from("50100.00"), 1, 2);
+
+        let mark_topic = get_data_topic(&Data::from(mark));
+        let index_topic = get_data_topic(&Data::from(index));
+
+        assert_eq!(mark_topic, get_mark_price_topic(&instrument_id));
+        assert_eq!(mark_topic.as_str(), "data.mark_prices.BINANCE.BTCUSDT-PERP");
+        assert_eq!(
+            index_topic.as_str(),
+            "data.index_prices.BINANCE.BTCUSDT-PERP"
+        );
+        assert!(is_matching(&mark_topic, &Ustr::from("data.mark_prices.*")));
+        assert!(!is_matching(
+            &index_topic,
+            &Ustr::from("data.mark_prices.*")
+        ));
+    }
+
+    #[rstest]
+    fn test_topic_pattern_display() {
+        let pattern = TopicPattern::new(Ustr::from("data.quotes.#"));
+
+        assert_eq!(pattern.as_str(), "data.quotes.#");
+        assert_eq!(pattern.to_string(), "data.quotes.#");
+    }
+
+    #[rstest]
+    fn test_matching_subscriptions_with_level_wildcards() {
+        let mut msgbus = stub_msgbus();
+        let callback = stub_rust_callback();
+
+        let handler1 = MessageHandler::new(Ustr::from("1"), Some(callback.clone()));
+        let handler2 = MessageHandler::new(Ustr::from("2"), Some(callback.clone()));
+        let handler3 = MessageHandler::new(Ustr::from("3"), Some(callback));
+
+        msgbus.subscribe("data.quotes.+", handler1, None);
+        msgbus.subscribe("data.#", handler2, Some(5));
+        msgbus.subscribe("data.trades.+", handler3, None);
+
+        let topic = Ustr::from("data.quotes.BINANCE");
+        let subs = msgbus.matching_subscriptions(&topic);
+
+        assert_eq!(subs.len(), 2);
+        assert_eq!(subs[0].handler.handler_id, Ustr::from("2"));
+        assert_eq!(subs[1].handler.handler_id, Ustr::from("1"));
+    }
+
+    #[rstest]
+    fn test_resolve_subscriptions_updates_on_subscribe_and_unsubscribe() {
+        let mut msgbus = stub_msgbus();
+        let callback = stub_rust_callback();
+        let topic = Ustr::from("data.quotes.BINANCE");
+
+        let handler1 = MessageHandler::new(Ustr::from("1"), Some(callback.clone()));
+        let handler2 = MessageHandler::new(Ustr::from("2"), Some(callback.clone()));
+        let handler3 = MessageHandler::new(Ustr::from("3"), Some(callback));
+
+        msgbus.subscribe("data.quotes.+", handler1.clone(), None);
+        assert_eq!(msgbus.resolve_subscriptions(&topic).len(), 1);
+
+        // New subscriptions are inserted into the cached topic in delivery order
+        msgbus.subscribe("data.#", handler2.clone(), Some(1));
+        msgbus.subscribe("data.quotes.BINANCE", handler3, None);
+        let ids: Vec<Ustr> = msgbus
+            .resolve_subscriptions(&topic)
+            .iter()
+            .map(|s| s.handler.handler_id)
+            .collect();
+        assert_eq!(ids, vec![Ustr::from("2"), Ustr::from("1"), Ustr::from("3")]);
+
+        msgbus.unsubscribe("data.#", handler2);
+        let ids: Vec<Ustr> = msgbus
+            .resolve_subscriptions(&topic)
+            .iter()
+            .map(|s| s.handler.handler_id)
+            .collect();
+        assert_eq!(ids, vec![Ustr::from("1"), Ustr::from("3")]);
+    }
+
+    #[rstest]
+    fn test_subscription_sequence_is_unique_after_unsubscribe() {
+        let mut msgbus = stub_msgbus();
+        let callback = stub_rust_callback();
+        let topic = Ustr::from("my-topic");
+
+        let handler1 = MessageHandler::new(Ustr::from("1"), Some(callback.clone()));
+        let handler2 = MessageHandler::new(Ustr::from("2"), Some(callback.clone()));
+        let handler3 = MessageHandler::new(Ustr::from("3"), Some(callback));
+
+        msgbus.subscribe("my-topic", handler1.clone(), None);
+        msgbus.subscribe("my-topic", handler2, None);
+        msgbus.unsubscribe("my-topic", handler1);
+        msgbus.subscribe("my-topic", handler3, None);
+
+        let ids: Vec<Ustr> = msgbus
+            .matching_subscriptions(&topic)
+            .iter()
+            .map(|s| s.handler.handler_id)
+            .collect();
+        assert_eq!(ids, vec![Ustr::from("2"), Ustr::from("3")]);
+    }
 }
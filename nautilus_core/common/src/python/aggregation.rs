@@ -0,0 +1,233 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{python::to_pyvalue_err, time::UnixNanos};
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        quote::QuoteTick,
+        trade::TradeTick,
+    },
+    enums::BarAggregation,
+    instruments::{
+        crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual,
+        currency_pair::CurrencyPair, equity::Equity, futures_contract::FuturesContract,
+        options_contract::OptionsContract, Instrument,
+    },
+};
+use pyo3::prelude::*;
+
+use crate::{
+    aggregation::{
+        BarAggregator, ImbalanceBarAggregator, RangeBarAggregator, RenkoBarAggregator,
+        TickBarAggregator, TimeBarAggregator, ValueBarAggregator, VolumeBarAggregator,
+    },
+};
+
+enum AnyBarAggregator {
+    Time(Box<TimeBarAggregator>),
+    Other(Box<dyn BarAggregator + Send>),
+}
+
+impl AnyBarAggregator {
+    fn new<I: Instrument>(
+        instrument: &I,
+        bar_type: BarType,
+        build_with_no_updates: bool,
+        timestamp_on_close: bool,
+    ) -> PyResult<Self> {
+        if instrument.id() != bar_type.instrument_id {
+            return Err(to_pyvalue_err(format!(
+                "`instrument.id` {} does not match `bar_type.instrument_id` {}",
+                instrument.id(),
+                bar_type.instrument_id,
+            )));
+        }
+        if bar_type.spec.step == 0 {
+            return Err(to_pyvalue_err("`bar_type.spec.step` must be positive"));
+        }
+
+        let aggregator: Box<dyn BarAggregator + Send> = match bar_type.spec.aggregation {
+            BarAggregation::Tick => Box::new(TickBarAggregator::new(instrument, bar_type)),
+            BarAggregation::Volume => Box::new(VolumeBarAggregator::new(instrument, bar_type)),
+            BarAggregation::Value => Box::new(ValueBarAggregator::new(instrument, bar_type)),
+            BarAggregation::TickImbalance
+            | BarAggregation::VolumeImbalance
+            | BarAggregation::ValueImbalance => {
+                Box::new(ImbalanceBarAggregator::new(instrument, bar_type))
+            }
+            BarAggregation::Renko => Box::new(RenkoBarAggregator::new(instrument, bar_type)),
+            BarAggregation::Range => Box::new(RangeBarAggregator::new(instrument, bar_type)),
+            BarAggregation::Millisecond
+            | BarAggregation::Second
+            | BarAggregation::Minute
+            | BarAggregation::Hour
+            | BarAggregation::Day
+            | BarAggregation::Week => {
+                return Ok(Self::Time(Box::new(TimeBarAggregator::new(
+                    instrument,
+                    bar_type,
+                    build_with_no_updates,
+                    timestamp_on_close,
+                ))))
+            }
+            aggregation => {
+                return Err(to_pyvalue_err(format!(
+                    "Aggregation {aggregation} is not currently implemented in Rust"
+                )))
+            }
+        };
+
+        Ok(Self::Other(aggregator))
+    }
+
+    fn as_aggregator(&self) -> &dyn BarAggregator {
+        match self {
+            Self::Time(aggregator) => aggregator.as_ref(),
+            Self::Other(aggregator) => aggregator.as_ref(),
+        }
+    }
+
+    fn as_aggregator_mut(&mut self) -> &mut dyn BarAggregator {
+        match self {
+            Self::Time(aggregator) => aggregator.as_mut(),
+            Self::Other(aggregator) => aggregator.as_mut(),
+        }
+    }
+}
+
+/// Python wrapper over the Rust bar aggregators, constructing the aggregator for the
+/// aggregation of the given bar type.
+#[pyclass(
+    name = "BarAggregator",
+    module = "nautilus_trader.core.nautilus_pyo3.common"
+)]
+pub struct PyBarAggregator {
+    aggregator: AnyBarAggregator,
+}
+
+#[pymethods]
+impl PyBarAggregator {
+    #[new]
+    #[pyo3(signature = (instrument, bar_type, build_with_no_updates=true, timestamp_on_close=true))]
+    fn py_new(
+        py: Python<'_>,
+        instrument: PyObject,
+        bar_type: BarType,
+        build_with_no_updates: bool,
+        timestamp_on_close: bool,
+    ) -> PyResult<Self> {
+        let instrument_type = instrument
+            .getattr(py, "instrument_type")?
+            .extract::<String>(py)?;
+        let aggregator = match instrument_type.as_str() {
+            "CryptoFuture" => AnyBarAggregator::new(
+                &instrument.extract::<CryptoFuture>(py)?,
+                bar_type,
+                build_with_no_updates,
+                timestamp_on_close,
+            ),
+            "CryptoPerpetual" => AnyBarAggregator::new(
+                &instrument.extract::<CryptoPerpetual>(py)?,
+                bar_type,
+                build_with_no_updates,
+                timestamp_on_close,
+            ),
+            "CurrencyPair" => AnyBarAggregator::new(
+                &instrument.extract::<CurrencyPair>(py)?,
+                bar_type,
+                build_with_no_updates,
+                timestamp_on_close,
+            ),
+            "Equity" => AnyBarAggregator::new(
+                &instrument.extract::<Equity>(py)?,
+                bar_type,
+                build_with_no_updates,
+                timestamp_on_close,
+            ),
+            "FuturesContract" => AnyBarAggregator::new(
+                &instrument.extract::<FuturesContract>(py)?,
+                bar_type,
+                build_with_no_updates,
+                timestamp_on_close,
+            ),
+            "OptionsContract" => AnyBarAggregator::new(
+                &instrument.extract::<OptionsContract>(py)?,
+                bar_type,
+                build_with_no_updates,
+                timestamp_on_close,
+            ),
+            _ => Err(to_pyvalue_err(format!(
+                "Unsupported instrument type {instrument_type}"
+            ))),
+        }?;
+
+        Ok(Self { aggregator })
+    }
+
+    #[getter]
+    #[pyo3(name = "bar_type")]
+    fn py_bar_type(&self) -> BarType {
+        self.aggregator.as_aggregator().bar_type()
+    }
+
+    #[pyo3(name = "set_await_partial")]
+    fn py_set_await_partial(&mut self, value: bool) {
+        self.aggregator.as_aggregator_mut().set_await_partial(value);
+    }
+
+    #[pyo3(name = "set_partial")]
+    fn py_set_partial(&mut self, partial_bar: Bar) {
+        self.aggregator.as_aggregator_mut().set_partial(partial_bar);
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn py_handle_quote_tick(&mut self, quote: QuoteTick) -> Vec<Bar> {
+        self.aggregator
+            .as_aggregator_mut()
+            .handle_quote_tick(&quote)
+    }
+
+    #[pyo3(name = "handle_trade_tick")]
+    fn py_handle_trade_tick(&mut self, trade: TradeTick) -> Vec<Bar> {
+        self.aggregator
+            .as_aggregator_mut()
+            .handle_trade_tick(&trade)
+    }
+
+    #[pyo3(name = "handle_quote_ticks")]
+    fn py_handle_quote_ticks(&mut self, quotes: Vec<QuoteTick>) -> Vec<Bar> {
+        self.aggregator
+            .as_aggregator_mut()
+            .handle_quote_ticks(&quotes)
+    }
+
+    #[pyo3(name = "handle_trade_ticks")]
+    fn py_handle_trade_ticks(&mut self, trades: Vec<TradeTick>) -> Vec<Bar> {
+        self.aggregator
+            .as_aggregator_mut()
+            .handle_trade_ticks(&trades)
+    }
+
+    #[pyo3(name = "build_until")]
+    fn py_build_until(&mut self, ts_now: UnixNanos) -> PyResult<Vec<Bar>> {
+        match &mut self.aggregator {
+            AnyBarAggregator::Time(aggregator) => Ok(aggregator.build_until(ts_now)),
+            AnyBarAggregator::Other(_) => Err(to_pyvalue_err(
+                "`build_until` is only supported for time bar aggregations",
+            )),
+        }
+    }
+}
@@ -155,4 +155,30 @@ mod tests {
             assert_eq!(test_clock.get_time_ns(), 0);
         });
     }
+
+    #[rstest]
+    fn test_advance_time_with_many_timers_in_time_order(mut test_clock: TestClock) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let py_list = PyList::empty(py);
+            let py_append = Py::from(py_list.getattr("append").unwrap());
+            let handler = EventHandler::new(py_append);
+            test_clock.register_default_handler(handler);
+
+            for i in 1..=1_000 {
+                test_clock.set_timer_ns(&format!("TIMER_{i}"), i * 7, 0, None, None);
+            }
+            test_clock.cancel_timer("TIMER_1");
+            assert_eq!(test_clock.next_timer_ns(), Some(7)); // Lower bound includes canceled
+
+            let events = test_clock.advance_time(1_000, true);
+
+            assert!(events.windows(2).all(|w| w[0].ts_event <= w[1].ts_event));
+            assert!(events.iter().all(|e| e.name.as_str() != "TIMER_1"));
+            let expected: u64 = (2..=1_000).map(|i| 1_000 / (i * 7)).sum();
+            assert_eq!(events.len() as u64, expected);
+            assert_eq!(test_clock.next_timer_ns(), Some(1_001));
+        });
+    }
 }
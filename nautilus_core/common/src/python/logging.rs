@@ -16,7 +16,7 @@
 use std::collections::HashMap;
 
 use log::LevelFilter;
-use nautilus_core::uuid::UUID4;
+use nautilus_core::{python::to_pyvalue_err, uuid::UUID4};
 use nautilus_model::identifiers::trader_id::TraderId;
 use pyo3::prelude::*;
 use ustr::Ustr;
@@ -27,7 +27,7 @@ use crate::{
         self, headers,
         logger::{self, LoggerConfig},
         logging_set_bypass, map_log_level_to_filter, parse_level_filter_str,
-        writer::FileWriterConfig,
+        writer::{FileCompression, FileRotationConfig, FileWriterConfig},
     },
 };
 
@@ -39,7 +39,12 @@ impl FileWriterConfig {
         file_name: Option<String>,
         file_format: Option<String>,
     ) -> Self {
-        Self::new(directory, file_name, file_format)
+        Self::new(
+            directory,
+            file_name,
+            file_format,
+            FileRotationConfig::default(),
+        )
     }
 }
 
@@ -68,6 +73,13 @@ pub fn py_init_tracing() {
     logging::init_tracing();
 }
 
+/// Shut down tracing, flushing any spans pending export.
+#[pyfunction()]
+#[pyo3(name = "shutdown_tracing")]
+pub fn py_shutdown_tracing() {
+    logging::shutdown_tracing();
+}
+
 /// Initialize logging.
 ///
 /// Logging should be used for Python and sync Rust logic which is most of
@@ -91,10 +103,16 @@ pub fn py_init_logging(
     directory: Option<String>,
     file_name: Option<String>,
     file_format: Option<String>,
+    max_file_size: Option<u64>,
+    max_file_age_secs: Option<u64>,
+    compression: Option<String>,
+    max_backup_count: Option<usize>,
+    max_backup_age_days: Option<u64>,
     is_colored: Option<bool>,
+    is_json: Option<bool>,
     is_bypassed: Option<bool>,
     print_config: Option<bool>,
-) {
+) -> PyResult<()> {
     let level_file = level_file
         .map(map_log_level_to_filter)
         .unwrap_or(LevelFilter::Off);
@@ -104,16 +122,31 @@ pub fn py_init_logging(
         level_file,
         parse_component_levels(component_levels),
         is_colored.unwrap_or(true),
+        is_json.unwrap_or(false),
         print_config.unwrap_or(false),
     );
 
-    let file_config = FileWriterConfig::new(directory, file_name, file_format);
+    let compression = match compression.map(|c| c.to_lowercase()).as_deref() {
+        None => None,
+        Some("gzip") => Some(FileCompression::Gzip),
+        Some("zstd") => Some(FileCompression::Zstd),
+        Some(other) => return Err(to_pyvalue_err(format!("Invalid compression '{other}'"))),
+    };
+    let rotation = FileRotationConfig {
+        max_file_size,
+        max_file_age_secs,
+        compression,
+        max_backup_count,
+        max_backup_age_days,
+    };
+    let file_config = FileWriterConfig::new(directory, file_name, file_format, rotation);
 
     if is_bypassed.unwrap_or(false) {
         logging_set_bypass();
     }
 
     logging::init_logging(trader_id, instance_id, config, file_config);
+    Ok(())
 }
 
 fn parse_component_levels(
@@ -140,6 +173,20 @@ pub fn py_logger_log(level: LogLevel, color: LogColor, component: String, messag
     logger::log(level, color, Ustr::from(&component), message.as_str());
 }
 
+/// Sets the runtime log level override for the given component.
+#[pyfunction]
+#[pyo3(name = "logging_set_component_level")]
+pub fn py_logging_set_component_level(component: &str, level: LogLevel) {
+    logger::set_component_level(Ustr::from(component), Some(map_log_level_to_filter(level)));
+}
+
+/// Clears any runtime log level override for the given component.
+#[pyfunction]
+#[pyo3(name = "logging_clear_component_level")]
+pub fn py_logging_clear_component_level(component: &str) {
+    logger::set_component_level(Ustr::from(component), None);
+}
+
 /// Logs the standard Nautilus system header.
 #[pyfunction]
 #[pyo3(name = "log_header")]
@@ -0,0 +1,44 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::net::{IpAddr, SocketAddr};
+
+use nautilus_core::python::to_pyvalue_err;
+use pyo3::prelude::*;
+
+use crate::metrics;
+
+/// Enables metrics collection and starts serving `/metrics` over HTTP.
+#[pyfunction]
+#[pyo3(name = "init_metrics")]
+pub fn py_init_metrics(host: &str, port: u16) -> PyResult<()> {
+    let ip: IpAddr = host.parse().map_err(to_pyvalue_err)?;
+    metrics::init_metrics(SocketAddr::new(ip, port)).map_err(to_pyvalue_err)
+}
+
+/// Returns whether metrics collection is enabled.
+#[pyfunction]
+#[pyo3(name = "metrics_is_enabled")]
+#[must_use]
+pub fn py_metrics_is_enabled() -> bool {
+    metrics::metrics_enabled()
+}
+
+/// Returns all collected metrics in the Prometheus text format.
+#[pyfunction]
+#[pyo3(name = "encode_metrics")]
+pub fn py_encode_metrics() -> PyResult<String> {
+    metrics::get_metrics().encode().map_err(to_pyvalue_err)
+}
@@ -13,9 +13,11 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod aggregation;
 pub mod clock;
 pub mod enums;
 pub mod logging;
+pub mod metrics;
 pub mod timer;
 pub mod versioning;
 
@@ -33,11 +35,24 @@ pub fn common(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<crate::enums::LogFormat>()?;
     m.add_class::<LoggerConfig>()?;
     m.add_class::<FileWriterConfig>()?;
+    m.add_class::<aggregation::PyBarAggregator>()?;
     m.add_function(wrap_pyfunction!(logging::py_init_tracing, m)?)?;
+    m.add_function(wrap_pyfunction!(logging::py_shutdown_tracing, m)?)?;
     m.add_function(wrap_pyfunction!(logging::py_init_logging, m)?)?;
     m.add_function(wrap_pyfunction!(logging::py_logger_log, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        logging::py_logging_set_component_level,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(
+        logging::py_logging_clear_component_level,
+        m
+    )?)?;
     m.add_function(wrap_pyfunction!(logging::py_log_header, m)?)?;
     m.add_function(wrap_pyfunction!(logging::py_log_sysinfo, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::py_init_metrics, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::py_metrics_is_enabled, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::py_encode_metrics, m)?)?;
 
     Ok(())
 }
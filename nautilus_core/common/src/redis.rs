@@ -32,6 +32,9 @@ const DELIMITER: char = ':';
 const XTRIM: &str = "XTRIM";
 const MINID: &str = "MINID";
 
+/// The default maximum number of buffered commands written per pipeline.
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 1_000;
+
 pub fn handle_messages_with_redis(
     rx: Receiver<BusMessage>,
     trader_id: TraderId,
@@ -190,6 +193,13 @@ pub fn get_buffer_interval(config: &HashMap<String, Value>) -> Duration {
     Duration::from_millis(buffer_interval_ms.unwrap_or(0))
 }
 
+pub fn get_max_batch_size(config: &HashMap<String, Value>) -> usize {
+    config
+        .get("max_batch_size")
+        .and_then(|v| v.as_u64())
+        .map_or(DEFAULT_MAX_BATCH_SIZE, |v| v as usize)
+}
+
 fn get_stream_name(
     trader_id: TraderId,
     instance_id: UUID4,
@@ -274,4 +284,20 @@ mod tests {
         let buffer_interval = get_buffer_interval(&config);
         assert_eq!(buffer_interval, Duration::from_millis(100));
     }
+
+    #[rstest]
+    fn test_get_max_batch_size_default() {
+        let config = HashMap::new();
+        let max_batch_size = get_max_batch_size(&config);
+        assert_eq!(max_batch_size, DEFAULT_MAX_BATCH_SIZE);
+    }
+
+    #[rstest]
+    fn test_get_max_batch_size() {
+        let mut config = HashMap::new();
+        config.insert("max_batch_size".to_string(), json!(50));
+
+        let max_batch_size = get_max_batch_size(&config);
+        assert_eq!(max_batch_size, 50);
+    }
 }
@@ -0,0 +1,496 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Cron-style and calendar-based schedules for clock timers.
+//!
+//! Two expression forms are supported:
+//!
+//! - Cron: `[second] minute hour day-of-month month day-of-week [time-zone]`, e.g.
+//!   `30 9 * * MON-FRI America/New_York`. Fields accept `*`, values, ranges (`a-b`),
+//!   steps (`*/n`, `a-b/n`) and comma separated lists, with month and weekday names.
+//! - Calendar: `every <days> [at HH:MM[:SS]] [time-zone]`, e.g.
+//!   `every weekday at 09:30:00 America/New_York`, where days is `day`, `weekday`,
+//!   `weekend`, or a list or range of weekday names (`mon,wed,fri`, `monday-thursday`).
+//!
+//! Times without a time zone are interpreted as UTC.
+
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Timelike};
+use chrono_tz::Tz;
+use nautilus_core::time::UnixNanos;
+
+/// The maximum number of days searched for the next scheduled time (covers leap day schedules).
+const MAX_LOOKAHEAD_DAYS: u32 = 366 * 8;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+const WEEKDAY_FULL_NAMES: [&str; 7] = [
+    "SUNDAY",
+    "MONDAY",
+    "TUESDAY",
+    "WEDNESDAY",
+    "THURSDAY",
+    "FRIDAY",
+    "SATURDAY",
+];
+
+/// Represents a recurring schedule of times, evaluated in a time zone.
+///
+/// Each field is held as a bitmask of matching values. As with cron, when both the day of
+/// month and day of week are restricted a date matches if either matches.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    seconds: u64,
+    minutes: u64,
+    hours: u32,
+    days_of_month: u32,
+    months: u16,
+    days_of_week: u8,
+    dom_restricted: bool,
+    dow_restricted: bool,
+    pub tz: Tz,
+}
+
+impl Schedule {
+    /// Parses the given cron or calendar schedule expression.
+    pub fn parse(expr: &str) -> Result<Self> {
+        let tokens: Vec<&str> = expr.split_whitespace().collect();
+        match tokens.first() {
+            None => bail!("Empty schedule expression"),
+            Some(first) if first.eq_ignore_ascii_case("every") => {
+                Self::parse_calendar(&tokens[1..])
+            }
+            Some(_) => Self::parse_cron(&tokens),
+        }
+        .map_err(|e| anyhow!("Invalid schedule '{expr}': {e}"))
+    }
+
+    /// Returns a schedule firing on each of the given weekdays at `time` in the time zone `tz`.
+    #[must_use]
+    pub fn weekly(weekdays: &[chrono::Weekday], time: NaiveTime, tz: Tz) -> Self {
+        let days_of_week = weekdays
+            .iter()
+            .fold(0, |mask, day| mask | 1 << day.num_days_from_sunday());
+        Self {
+            seconds: 1 << time.second(),
+            minutes: 1 << time.minute(),
+            hours: 1 << time.hour(),
+            days_of_month: bits(1, 31) as u32,
+            months: bits(1, 12) as u16,
+            days_of_week,
+            dom_restricted: false,
+            dow_restricted: true,
+            tz,
+        }
+    }
+
+    /// Returns the first scheduled UNIX timestamp (nanoseconds) strictly after `ts`.
+    #[must_use]
+    pub fn next_after(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        let local = self.tz.timestamp_nanos(ts as i64).naive_local();
+        let mut date = local.date();
+        let mut from = local.time().with_nanosecond(0)?;
+        match from.overflowing_add_signed(TimeDelta::seconds(1)) {
+            (time, 0) => from = time,
+            _ => {
+                date = date.succ_opt()?;
+                from = NaiveTime::MIN;
+            }
+        }
+
+        for _ in 0..=MAX_LOOKAHEAD_DAYS {
+            if self.matches_date(date) {
+                let mut search_from = from;
+                while let Some(time) = self.next_time_of_day(search_from) {
+                    if let Some(next) = self.to_unix_nanos(date.and_time(time)) {
+                        if next > ts {
+                            return Some(next);
+                        }
+                    }
+                    match time.overflowing_add_signed(TimeDelta::seconds(1)) {
+                        (next_time, 0) => search_from = next_time,
+                        _ => break,
+                    }
+                }
+            }
+            date = date.succ_opt()?;
+            from = NaiveTime::MIN;
+        }
+
+        None
+    }
+
+    /// Returns whether the given local `date` matches the schedule.
+    #[must_use]
+    pub fn matches_date(&self, date: NaiveDate) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+        let dom = self.days_of_month & (1 << date.day()) != 0;
+        let dow = self.days_of_week & (1 << date.weekday().num_days_from_sunday()) != 0;
+        if self.dom_restricted && self.dow_restricted {
+            dom || dow
+        } else {
+            dom && dow
+        }
+    }
+
+    fn next_time_of_day(&self, from: NaiveTime) -> Option<NaiveTime> {
+        let (from_hour, from_minute, from_second) = (from.hour(), from.minute(), from.second());
+        let mut hour = next_bit(u64::from(self.hours), from_hour)?;
+        loop {
+            let mut minute_from = if hour == from_hour { from_minute } else { 0 };
+            while let Some(minute) = next_bit(self.minutes, minute_from) {
+                let second_from = if hour == from_hour && minute == from_minute {
+                    from_second
+                } else {
+                    0
+                };
+                if let Some(second) = next_bit(self.seconds, second_from) {
+                    return NaiveTime::from_hms_opt(hour, minute, second);
+                }
+                minute_from = minute + 1;
+            }
+            hour = next_bit(u64::from(self.hours), hour + 1)?;
+        }
+    }
+
+    fn to_unix_nanos(self, local: NaiveDateTime) -> Option<UnixNanos> {
+        // Times falling in a daylight saving gap are shifted forward by the gap
+        let datetime = self.tz.from_local_datetime(&local).earliest().or_else(|| {
+            self.tz
+                .from_local_datetime(&(local + TimeDelta::hours(1)))
+                .earliest()
+        })?;
+        datetime.timestamp_nanos_opt().map(|ns| ns as UnixNanos)
+    }
+
+    fn parse_cron(tokens: &[&str]) -> Result<Self> {
+        let (fields, tz) = match tokens {
+            [fields @ .., tz] if is_time_zone(tz) => (fields, parse_tz(tz)?),
+            fields => (fields, Tz::UTC),
+        };
+        let (seconds, fields) = match fields.len() {
+            5 => (1, fields),
+            6 => (parse_field(fields[0], 0, 59, &[])?, &fields[1..]),
+            n => bail!("expected 5 or 6 fields, was {n}"),
+        };
+
+        Ok(Self {
+            seconds,
+            minutes: parse_field(fields[0], 0, 59, &[])?,
+            hours: parse_field(fields[1], 0, 23, &[])? as u32,
+            days_of_month: parse_field(fields[2], 1, 31, &[])? as u32,
+            months: parse_field(fields[3], 1, 12, &MONTH_NAMES)? as u16,
+            days_of_week: parse_day_of_week(fields[4])?,
+            dom_restricted: fields[2] != "*",
+            dow_restricted: fields[4] != "*",
+            tz,
+        })
+    }
+
+    fn parse_calendar(tokens: &[&str]) -> Result<Self> {
+        let at = tokens.iter().position(|t| t.eq_ignore_ascii_case("at"));
+        let (day_tokens, rest) = match at {
+            Some(pos) => (&tokens[..pos], &tokens[pos + 1..]),
+            None => match tokens {
+                [days @ .., tz] if is_time_zone(tz) => (days, std::slice::from_ref(tz)),
+                days => (days, &[][..]),
+            },
+        };
+
+        let days = day_tokens.concat().to_ascii_uppercase();
+        let days_of_week = match days.trim_end_matches('S') {
+            "" => bail!("missing days"),
+            "DAY" => bits(0, 6) as u8,
+            "WEEKDAY" => bits(1, 5) as u8,
+            "WEEKEND" => (1 << 0) | (1 << 6),
+            _ => parse_day_of_week(&days)?,
+        };
+
+        let (time, tz) = match (at, rest) {
+            (None, []) => (NaiveTime::MIN, Tz::UTC),
+            (None, [tz]) => (NaiveTime::MIN, parse_tz(tz)?),
+            (Some(_), [time]) => (parse_time(time)?, Tz::UTC),
+            (Some(_), [time, tz]) => (parse_time(time)?, parse_tz(tz)?),
+            _ => bail!("expected 'at HH:MM[:SS] [time-zone]'"),
+        };
+
+        let mut schedule = Self::weekly(&[], time, tz);
+        schedule.days_of_week = days_of_week;
+        schedule.dow_restricted = days_of_week != bits(0, 6) as u8;
+        Ok(schedule)
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+fn bits(min: u32, max: u32) -> u64 {
+    (min..=max).fold(0, |mask, i| mask | 1 << i)
+}
+
+fn next_bit(mask: u64, from: u32) -> Option<u32> {
+    if from >= 64 {
+        return None;
+    }
+    match mask >> from {
+        0 => None,
+        shifted => Some(from + shifted.trailing_zeros()),
+    }
+}
+
+fn is_time_zone(token: &str) -> bool {
+    token.parse::<Tz>().is_ok()
+        || (token.contains('/') && token.starts_with(|c: char| c.is_ascii_alphabetic()))
+}
+
+fn parse_tz(token: &str) -> Result<Tz> {
+    token
+        .parse::<Tz>()
+        .map_err(|e| anyhow!("invalid time zone '{token}': {e}"))
+}
+
+fn parse_time(token: &str) -> Result<NaiveTime> {
+    NaiveTime::parse_from_str(token, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(token, "%H:%M"))
+        .map_err(|_| anyhow!("invalid time '{token}'"))
+}
+
+fn parse_day_of_week(field: &str) -> Result<u8> {
+    let mut normalized = field.to_ascii_uppercase().replace("DAYS", "DAY");
+    for (i, name) in WEEKDAY_FULL_NAMES.iter().enumerate() {
+        normalized = normalized.replace(name, WEEKDAY_NAMES[i]);
+    }
+    // Sunday may be given as either 0 or 7
+    let mask = parse_field(&normalized, 0, 7, &WEEKDAY_NAMES)?;
+    Ok((mask | (mask >> 7)) as u8 & 0x7F)
+}
+
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str]) -> Result<u64> {
+    let parse_value = |s: &str| -> Result<u32> {
+        let value = match names.iter().position(|n| n.eq_ignore_ascii_case(s)) {
+            Some(i) => min + i as u32,
+            None => s
+                .parse::<u32>()
+                .map_err(|_| anyhow!("invalid value '{s}'"))?,
+        };
+        if value < min || value > max {
+            bail!("value {value} out of range [{min}, {max}]");
+        }
+        Ok(value)
+    };
+
+    let mut mask = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step = step
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .ok_or_else(|| anyhow!("invalid step '{step}'"))?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (parse_value(start)?, parse_value(end)?),
+                // A single value with a step runs to the maximum
+                None if step > 1 => (parse_value(range)?, max),
+                None => {
+                    let value = parse_value(range)?;
+                    (value, value)
+                }
+            },
+        };
+        if start > end {
+            bail!("invalid range '{range}'");
+        }
+        mask |= (start..=end)
+            .step_by(step as usize)
+            .fold(0, |m, i| m | 1 << i);
+    }
+    Ok(mask)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc, Weekday};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn utc_nanos(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> UnixNanos {
+        Utc.with_ymd_and_hms(y, m, d, h, min, s)
+            .unwrap()
+            .timestamp_nanos_opt()
+            .unwrap() as UnixNanos
+    }
+
+    #[rstest]
+    #[case("every weekday at 09:30:00 America/New_York")]
+    #[case("every mon-fri at 09:30 America/New_York")]
+    #[case("every monday,tuesday,wednesday,thursday,friday at 09:30 America/New_York")]
+    #[case("30 9 * * MON-FRI America/New_York")]
+    #[case("0 30 9 * * 1-5 America/New_York")]
+    fn test_equivalent_expressions(#[case] expr: &str) {
+        let expected = Schedule::weekly(
+            &[
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+            Tz::America__New_York,
+        );
+        assert_eq!(Schedule::parse(expr).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("every")]
+    #[case("every weekday at 25:00")]
+    #[case("every funday at 09:30")]
+    #[case("every day at 09:30 Mars/Olympus")]
+    #[case("* * * *")]
+    #[case("60 * * * *")]
+    #[case("* * * 13 *")]
+    #[case("*/0 * * * *")]
+    #[case("5-1 * * * *")]
+    fn test_parse_invalid(#[case] expr: &str) {
+        assert!(Schedule::parse(expr).is_err());
+    }
+
+    #[rstest]
+    fn test_next_after_weekday_session_open() {
+        let schedule: Schedule = "every weekday at 09:30:00 America/New_York"
+            .parse()
+            .unwrap();
+
+        // Friday 2024-01-05 before the open (EST, UTC-5)
+        let friday = utc_nanos(2024, 1, 5, 12, 0, 0);
+        assert_eq!(
+            schedule.next_after(friday),
+            Some(utc_nanos(2024, 1, 5, 14, 30, 0))
+        );
+
+        // Exactly at the open skips to the following Monday
+        let open = utc_nanos(2024, 1, 5, 14, 30, 0);
+        assert_eq!(
+            schedule.next_after(open),
+            Some(utc_nanos(2024, 1, 8, 14, 30, 0))
+        );
+    }
+
+    #[rstest]
+    fn test_next_after_across_daylight_saving() {
+        let schedule: Schedule = "every day at 09:30 America/New_York".parse().unwrap();
+
+        // Clocks spring forward on 2024-03-10, moving the open from 14:30 to 13:30 UTC
+        let saturday = utc_nanos(2024, 3, 9, 15, 0, 0);
+        assert_eq!(
+            schedule.next_after(saturday),
+            Some(utc_nanos(2024, 3, 10, 13, 30, 0))
+        );
+    }
+
+    #[rstest]
+    fn test_next_after_within_daylight_saving_gap() {
+        // 02:30 does not exist on 2024-03-10 in New York, so is shifted forward to 03:30
+        let schedule: Schedule = "every day at 02:30 America/New_York".parse().unwrap();
+        let ts = utc_nanos(2024, 3, 9, 12, 0, 0);
+        assert_eq!(
+            schedule.next_after(ts),
+            Some(utc_nanos(2024, 3, 10, 7, 30, 0))
+        );
+    }
+
+    #[rstest]
+    fn test_next_after_cron_steps() {
+        let schedule: Schedule = "*/15 * * * *".parse().unwrap();
+        let ts = utc_nanos(2024, 1, 1, 10, 7, 30);
+        assert_eq!(
+            schedule.next_after(ts),
+            Some(utc_nanos(2024, 1, 1, 10, 15, 0))
+        );
+
+        let ts = utc_nanos(2024, 1, 1, 23, 50, 0);
+        assert_eq!(
+            schedule.next_after(ts),
+            Some(utc_nanos(2024, 1, 2, 0, 0, 0))
+        );
+    }
+
+    #[rstest]
+    fn test_next_after_with_seconds_and_sub_second_timestamp() {
+        let schedule: Schedule = "*/10 * * * * *".parse().unwrap();
+        let ts = utc_nanos(2024, 1, 1, 23, 59, 59) + 500_000_000;
+        assert_eq!(
+            schedule.next_after(ts),
+            Some(utc_nanos(2024, 1, 2, 0, 0, 0))
+        );
+    }
+
+    #[rstest]
+    fn test_next_after_day_of_month_or_day_of_week() {
+        // Cron semantics: the 1st of the month or any Friday
+        let schedule: Schedule = "0 0 1 * FRI".parse().unwrap();
+        let ts = utc_nanos(2024, 1, 1, 12, 0, 0); // Monday
+        assert_eq!(
+            schedule.next_after(ts),
+            Some(utc_nanos(2024, 1, 5, 0, 0, 0))
+        );
+        let ts = utc_nanos(2024, 1, 26, 12, 0, 0); // Friday
+        assert_eq!(
+            schedule.next_after(ts),
+            Some(utc_nanos(2024, 2, 1, 0, 0, 0))
+        );
+    }
+
+    #[rstest]
+    fn test_next_after_leap_day() {
+        let schedule: Schedule = "0 12 29 FEB *".parse().unwrap();
+        let ts = utc_nanos(2024, 3, 1, 0, 0, 0);
+        assert_eq!(
+            schedule.next_after(ts),
+            Some(utc_nanos(2028, 2, 29, 12, 0, 0))
+        );
+    }
+
+    #[rstest]
+    fn test_sunday_as_seven() {
+        assert_eq!(
+            Schedule::parse("0 0 * * 7").unwrap(),
+            Schedule::parse("0 0 * * SUN").unwrap()
+        );
+    }
+}
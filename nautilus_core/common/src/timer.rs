@@ -15,6 +15,7 @@
 
 use std::{
     cmp::Ordering,
+    collections::BTreeMap,
     ffi::c_char,
     fmt::{Display, Formatter},
     time::Duration,
@@ -30,7 +31,7 @@ use pyo3::{types::PyCapsule, IntoPy, PyObject, Python};
 use tokio::sync::oneshot;
 use ustr::Ustr;
 
-use crate::{handlers::EventHandler, runtime::get_runtime};
+use crate::{handlers::EventHandler, runtime::get_runtime, schedule::Schedule};
 
 #[repr(C)]
 #[derive(Clone, Debug)]
@@ -121,6 +122,19 @@ pub trait Timer {
     fn cancel(&mut self);
 }
 
+/// Returns the next time after `time_ns` for a timer with the given `schedule`, or at the
+/// fixed `interval_ns` if no schedule.
+fn next_timer_time(
+    schedule: Option<&Schedule>,
+    interval_ns: u64,
+    time_ns: UnixNanos,
+) -> Option<UnixNanos> {
+    match schedule {
+        Some(schedule) => schedule.next_after(time_ns),
+        None => Some(time_ns + interval_ns),
+    }
+}
+
 /// Provides a test timer for user with a `TestClock`.
 #[derive(Clone, Copy, Debug)]
 pub struct TestTimer {
@@ -130,6 +144,8 @@ pub struct TestTimer {
     pub stop_time_ns: Option<UnixNanos>,
     pub next_time_ns: UnixNanos,
     pub is_expired: bool,
+    /// The schedule for the timer (if `None` then alerts at every `interval_ns`).
+    pub schedule: Option<Schedule>,
 }
 
 impl TestTimer {
@@ -149,6 +165,33 @@ impl TestTimer {
             stop_time_ns,
             next_time_ns: start_time_ns + interval_ns,
             is_expired: false,
+            schedule: None,
+        }
+    }
+
+    /// Creates a new timer alerting at each time of the `schedule` after the start time.
+    #[must_use]
+    pub fn new_scheduled(
+        name: &str,
+        schedule: Schedule,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+    ) -> Self {
+        check_valid_string(name, "`TestTimer` name").unwrap();
+
+        let next_time_ns = schedule.next_after(start_time_ns);
+        Self {
+            name: Ustr::from(name),
+            interval_ns: 0,
+            start_time_ns,
+            stop_time_ns,
+            next_time_ns: next_time_ns.unwrap_or(UnixNanos::MAX),
+            is_expired: match (next_time_ns, stop_time_ns) {
+                (Some(next_time_ns), Some(stop_time_ns)) => next_time_ns > stop_time_ns,
+                (Some(_), None) => false,
+                (None, _) => true,
+            },
+            schedule: Some(schedule),
         }
     }
 
@@ -166,9 +209,13 @@ impl TestTimer {
     /// of events. A [`TimeEvent`] is appended for each time a next event is
     /// <= the given `to_time_ns`.
     pub fn advance(&mut self, to_time_ns: UnixNanos) -> impl Iterator<Item = TimeEvent> + '_ {
-        let advances =
-            to_time_ns.saturating_sub(self.next_time_ns - self.interval_ns) / self.interval_ns;
-        self.take(advances as usize).map(|(event, _)| event)
+        std::iter::from_fn(move || {
+            if self.next_time_ns <= to_time_ns {
+                self.next().map(|(event, _)| event)
+            } else {
+                None
+            }
+        })
     }
 
     /// Cancels the timer (the timer will not generate an event).
@@ -201,13 +248,215 @@ impl Iterator for TestTimer {
                 }
             }
 
-            self.next_time_ns += self.interval_ns;
+            match next_timer_time(self.schedule.as_ref(), self.interval_ns, self.next_time_ns) {
+                // A scheduled time beyond the stop time will never alert
+                Some(next_time_ns)
+                    if self.schedule.is_some()
+                        && self.stop_time_ns.is_some_and(|stop| next_time_ns > stop) =>
+                {
+                    self.next_time_ns = next_time_ns;
+                    self.is_expired = true;
+                }
+                Some(next_time_ns) => self.next_time_ns = next_time_ns,
+                None => self.is_expired = true,
+            }
 
             Some(item)
         }
     }
 }
 
+/// The number of bits of time resolved by each level of a [`TimerWheel`].
+const WHEEL_SLOT_BITS: u32 = 6;
+
+/// The number of slots in each level of a [`TimerWheel`].
+const WHEEL_SLOTS: usize = 1 << WHEEL_SLOT_BITS;
+
+/// The number of levels in a [`TimerWheel`] (covering the full `u64` nanosecond range).
+const WHEEL_LEVELS: usize = 11;
+
+/// Provides a hierarchical timer wheel for scheduling items at UNIX nanosecond times.
+///
+/// Each level has 64 slots, with level `n` slots spanning `64^n` nanoseconds. Items are
+/// placed on the level of the highest bit in which their time differs from the current
+/// wheel time, and cascade down to lower levels as the wheel advances. This makes
+/// insertion `O(1)` and advancing proportional to the number of items due (plus the
+/// number of levels), independent of the total number of items scheduled.
+///
+/// Items due at the same time are returned in insertion order.
+#[derive(Debug)]
+pub struct TimerWheel<T> {
+    elapsed: UnixNanos,
+    levels: Vec<WheelLevel<T>>,
+    ready: BTreeMap<(UnixNanos, u64), T>,
+    sequence: u64,
+    len: usize,
+}
+
+#[derive(Debug)]
+struct WheelLevel<T> {
+    occupied: u64,
+    slots: Vec<Vec<(UnixNanos, T)>>,
+}
+
+impl<T> WheelLevel<T> {
+    fn new() -> Self {
+        Self {
+            occupied: 0,
+            slots: (0..WHEEL_SLOTS).map(|_| Vec::new()).collect(),
+        }
+    }
+}
+
+impl<T> TimerWheel<T> {
+    /// Creates a new timer wheel starting at the given `elapsed` time.
+    #[must_use]
+    pub fn new(elapsed: UnixNanos) -> Self {
+        Self {
+            elapsed,
+            levels: (0..WHEEL_LEVELS).map(|_| WheelLevel::new()).collect(),
+            ready: BTreeMap::new(),
+            sequence: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the time the wheel has been advanced to.
+    #[must_use]
+    pub fn elapsed(&self) -> UnixNanos {
+        self.elapsed
+    }
+
+    /// Returns the number of items scheduled.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Schedules the `item` at the given time. Items scheduled before the current wheel
+    /// time are due immediately.
+    pub fn insert(&mut self, time_ns: UnixNanos, item: T) {
+        self.len += 1;
+        self.place(time_ns, item);
+    }
+
+    /// Removes all scheduled items.
+    pub fn clear(&mut self) {
+        for level in &mut self.levels {
+            for slot in &mut level.slots {
+                slot.clear();
+            }
+            level.occupied = 0;
+        }
+        self.ready.clear();
+        self.len = 0;
+    }
+
+    /// Returns the time of the next scheduled item.
+    #[must_use]
+    pub fn peek_next(&self) -> Option<UnixNanos> {
+        if let Some(((time_ns, _), _)) = self.ready.first_key_value() {
+            return Some(*time_ns);
+        }
+        let (level, slot, _) = self.next_expiration()?;
+        self.levels[level].slots[slot]
+            .iter()
+            .map(|(time_ns, _)| *time_ns)
+            .min()
+    }
+
+    /// Removes and returns the next item scheduled at or before `to_time_ns`.
+    ///
+    /// When no further items are due the wheel time is advanced to `to_time_ns`.
+    pub fn pop_next(&mut self, to_time_ns: UnixNanos) -> Option<(UnixNanos, T)> {
+        loop {
+            if let Some(entry) = self.ready.first_entry() {
+                if entry.key().0 <= to_time_ns {
+                    let ((time_ns, _), item) = entry.remove_entry();
+                    self.len -= 1;
+                    return Some((time_ns, item));
+                }
+                return None; // Ready items precede all items still on the wheel
+            }
+
+            match self.next_expiration() {
+                Some((level, slot, deadline)) if deadline <= to_time_ns => {
+                    self.elapsed = self.elapsed.max(deadline);
+                    self.levels[level].occupied &= !(1 << slot);
+                    let items = std::mem::take(&mut self.levels[level].slots[slot]);
+                    for (time_ns, item) in items {
+                        self.place(time_ns, item); // Cascades to a lower level (or ready)
+                    }
+                }
+                _ => {
+                    self.elapsed = self.elapsed.max(to_time_ns);
+                    return None;
+                }
+            }
+        }
+    }
+
+    /// Advances the wheel to `to_time_ns`, returning all items due in time order.
+    pub fn advance(&mut self, to_time_ns: UnixNanos) -> Vec<(UnixNanos, T)> {
+        std::iter::from_fn(|| self.pop_next(to_time_ns)).collect()
+    }
+
+    fn place(&mut self, time_ns: UnixNanos, item: T) {
+        if time_ns <= self.elapsed {
+            self.ready.insert((time_ns, self.sequence), item);
+            self.sequence += 1;
+            return;
+        }
+
+        let level = level_for(self.elapsed, time_ns);
+        let slot = slot_for(time_ns, level);
+        self.levels[level].slots[slot].push((time_ns, item));
+        self.levels[level].occupied |= 1 << slot;
+    }
+
+    /// Returns the level, slot and start time of the next occupied slot.
+    fn next_expiration(&self) -> Option<(usize, usize, UnixNanos)> {
+        // Items on lower levels are always due before items on higher levels
+        let (level, occupied) = self
+            .levels
+            .iter()
+            .enumerate()
+            .find(|(_, level)| level.occupied != 0)
+            .map(|(i, level)| (i, level.occupied))?;
+
+        let slot_range = 1_u128 << (WHEEL_SLOT_BITS as usize * level);
+        let level_range = slot_range << WHEEL_SLOT_BITS;
+        let now = u128::from(self.elapsed);
+        let now_slot = ((now / slot_range) % WHEEL_SLOTS as u128) as u32;
+        let slot =
+            (occupied.rotate_right(now_slot).trailing_zeros() + now_slot) as usize % WHEEL_SLOTS;
+        let level_start = now & !(level_range - 1);
+        let deadline = (level_start + slot as u128 * slot_range).max(now);
+        Some((level, slot, deadline as UnixNanos))
+    }
+}
+
+impl<T> Default for TimerWheel<T> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+fn level_for(elapsed: UnixNanos, time_ns: UnixNanos) -> usize {
+    let masked = (elapsed ^ time_ns) | (WHEEL_SLOTS as u64 - 1);
+    let significant = 63 - masked.leading_zeros();
+    (significant / WHEEL_SLOT_BITS) as usize
+}
+
+fn slot_for(time_ns: UnixNanos, level: usize) -> usize {
+    ((time_ns >> (WHEEL_SLOT_BITS as usize * level)) as usize) & (WHEEL_SLOTS - 1)
+}
+
 /// Provides a live timer for use with a `LiveClock`.
 ///
 /// Note: `next_time_ns` is only accurate when initially starting the timer
@@ -219,6 +468,8 @@ pub struct LiveTimer {
     pub stop_time_ns: Option<UnixNanos>,
     pub next_time_ns: UnixNanos,
     pub is_expired: bool,
+    /// The schedule for the timer (if `None` then alerts at every `interval_ns`).
+    pub schedule: Option<Schedule>,
     callback: EventHandler,
     canceler: Option<oneshot::Sender<()>>,
 }
@@ -241,6 +492,31 @@ impl LiveTimer {
             stop_time_ns,
             next_time_ns: start_time_ns + interval_ns,
             is_expired: false,
+            schedule: None,
+            callback,
+            canceler: None,
+        }
+    }
+
+    /// Creates a new timer alerting at each time of the `schedule` after the start time.
+    #[must_use]
+    pub fn new_scheduled(
+        name: &str,
+        schedule: Schedule,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+        callback: EventHandler,
+    ) -> Self {
+        check_valid_string(name, "`LiveTimer` name").unwrap();
+
+        Self {
+            name: Ustr::from(name),
+            interval_ns: 0,
+            start_time_ns,
+            stop_time_ns,
+            next_time_ns: schedule.next_after(start_time_ns).unwrap_or_default(),
+            is_expired: false,
+            schedule: Some(schedule),
             callback,
             canceler: None,
         }
@@ -251,6 +527,7 @@ impl LiveTimer {
         let mut start_time_ns = self.start_time_ns;
         let stop_time_ns = self.stop_time_ns;
         let interval_ns = self.interval_ns;
+        let schedule = self.schedule;
 
         let callback = self.callback.clone();
 
@@ -264,7 +541,11 @@ impl LiveTimer {
                 start_time_ns = clock.get_time_ns();
             }
 
-            let mut next_time_ns = start_time_ns + interval_ns;
+            let Some(mut next_time_ns) =
+                next_timer_time(schedule.as_ref(), interval_ns, start_time_ns)
+            else {
+                return Ok(()); // Schedule has no further times
+            };
 
             loop {
                 tokio::select! {
@@ -274,7 +555,10 @@ impl LiveTimer {
                         call_python_with_time_event(event_name, next_time_ns, clock.get_time_ns(), callback);
 
                         // Prepare next time interval
-                        next_time_ns += interval_ns;
+                        match next_timer_time(schedule.as_ref(), interval_ns, next_time_ns) {
+                            Some(time_ns) => next_time_ns = time_ns,
+                            None => break, // Schedule has no further times
+                        }
 
                         // Check if expired
                         if let Some(stop_time_ns) = stop_time_ns {
@@ -340,9 +624,11 @@ fn call_python_with_time_event(
 #[cfg(not(feature = "python"))]
 #[cfg(test)]
 mod tests {
+    use nautilus_core::time::UnixNanos;
     use rstest::*;
 
-    use super::{TestTimer, TimeEvent};
+    use super::{TestTimer, TimeEvent, TimerWheel};
+    use crate::schedule::Schedule;
 
     #[rstest]
     fn test_test_timer_pop_event() {
@@ -386,10 +672,128 @@ mod tests {
         assert!(timer.is_expired);
     }
 
+    #[rstest]
+    fn test_test_timer_scheduled() {
+        let schedule: Schedule = "0 * * * *".parse().unwrap(); // Every hour
+        let hour_ns = 3_600 * 1_000_000_000;
+        let mut timer = TestTimer::new_scheduled("test_timer", schedule, 0, None);
+        assert_eq!(timer.next_time_ns, hour_ns);
+
+        let events: Vec<TimeEvent> = timer.advance(hour_ns * 3 + 1).collect();
+        let ts_events: Vec<UnixNanos> = events.iter().map(|e| e.ts_event).collect();
+        assert_eq!(ts_events, vec![hour_ns, hour_ns * 2, hour_ns * 3]);
+        assert_eq!(timer.next_time_ns, hour_ns * 4);
+        assert!(!timer.is_expired);
+    }
+
+    #[rstest]
+    fn test_test_timer_scheduled_with_stop_time() {
+        let schedule: Schedule = "0 * * * *".parse().unwrap(); // Every hour
+        let hour_ns = 3_600 * 1_000_000_000;
+        let mut timer = TestTimer::new_scheduled("test_timer", schedule, 0, Some(hour_ns * 2 + 1));
+        assert_eq!(timer.advance(hour_ns * 10).count(), 2);
+        assert!(timer.is_expired);
+    }
+
     #[rstest]
     fn test_test_timer_advance_beyond_stop_time() {
         let mut timer = TestTimer::new("test_timer", 1, 0, Some(5));
         assert_eq!(timer.advance(10).count(), 5);
         assert!(timer.is_expired);
     }
+
+    #[rstest]
+    fn test_timer_wheel_pops_in_time_order() {
+        let mut wheel = TimerWheel::new(0);
+        for time_ns in [500, 3, 70, 4_096, 1 << 40, 70, 64] {
+            wheel.insert(time_ns, time_ns);
+        }
+        assert_eq!(wheel.len(), 7);
+        assert_eq!(wheel.peek_next(), Some(3));
+
+        let times: Vec<UnixNanos> = wheel.advance(u64::MAX).iter().map(|(t, _)| *t).collect();
+        assert_eq!(times, vec![3, 64, 70, 70, 500, 4_096, 1 << 40]);
+        assert!(wheel.is_empty());
+    }
+
+    #[rstest]
+    fn test_timer_wheel_advance_stops_at_time() {
+        let mut wheel = TimerWheel::new(0);
+        wheel.insert(10, "a");
+        wheel.insert(100, "b");
+        wheel.insert(10_000, "c");
+
+        assert_eq!(wheel.advance(99), vec![(10, "a")]);
+        assert_eq!(wheel.elapsed(), 99);
+        assert_eq!(wheel.peek_next(), Some(100));
+        assert_eq!(wheel.advance(100), vec![(100, "b")]);
+        assert!(wheel.advance(9_999).is_empty());
+        assert_eq!(wheel.advance(10_000), vec![(10_000, "c")]);
+    }
+
+    #[rstest]
+    fn test_timer_wheel_insert_past_time_is_due_immediately() {
+        let mut wheel = TimerWheel::new(1_000);
+        wheel.insert(500, "late");
+        wheel.insert(1_000, "now");
+        assert_eq!(wheel.pop_next(1_000), Some((500, "late")));
+        assert_eq!(wheel.pop_next(1_000), Some((1_000, "now")));
+        assert_eq!(wheel.pop_next(1_000), None);
+    }
+
+    #[rstest]
+    fn test_timer_wheel_same_time_in_insertion_order() {
+        let mut wheel = TimerWheel::new(0);
+        for i in 0..5 {
+            wheel.insert(1_000_000, i);
+        }
+        let items: Vec<i32> = wheel
+            .advance(1_000_000)
+            .into_iter()
+            .map(|(_, i)| i)
+            .collect();
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[rstest]
+    fn test_timer_wheel_clear() {
+        let mut wheel = TimerWheel::new(0);
+        wheel.insert(10, ());
+        wheel.insert(1_000, ());
+        wheel.clear();
+        assert!(wheel.is_empty());
+        assert_eq!(wheel.peek_next(), None);
+        assert!(wheel.advance(2_000).is_empty());
+    }
+
+    #[rstest]
+    fn test_timer_wheel_matches_sorted_order_with_reinsertion() {
+        // Periodic items with co-prime intervals, re-inserted as they fire
+        let intervals: Vec<u64> = vec![7, 13, 1_000, 4_099, 65_537, 1_000_003];
+        let mut wheel = TimerWheel::new(0);
+        for (i, interval) in intervals.iter().enumerate() {
+            wheel.insert(*interval, i);
+        }
+
+        let mut fired = Vec::new();
+        let mut to_time_ns = 0;
+        while to_time_ns < 5_000_000 {
+            to_time_ns += 9_973;
+            while let Some((time_ns, i)) = wheel.pop_next(to_time_ns) {
+                fired.push(time_ns);
+                wheel.insert(time_ns + intervals[i], i);
+            }
+        }
+
+        let mut expected: Vec<UnixNanos> = intervals
+            .iter()
+            .flat_map(|interval| {
+                (1..)
+                    .map(move |k| k * interval)
+                    .take_while(|t| *t <= to_time_ns)
+            })
+            .collect();
+        expected.sort_unstable();
+        assert_eq!(fired, expected);
+    }
 }
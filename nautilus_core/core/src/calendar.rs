@@ -0,0 +1,316 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trading calendars modelling venue sessions, half days and holidays.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{bail, Result};
+use chrono::{
+    DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Weekday,
+};
+use chrono_tz::Tz;
+
+use crate::time::UnixNanos;
+
+/// The maximum number of days searched when finding the next session open or close.
+const MAX_LOOKAHEAD_DAYS: i64 = 366;
+
+/// Represents a regular trading session in venue local time.
+///
+/// A session with a `close` at or before its `open` is an overnight session, closing on the
+/// following calendar day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TradingSession {
+    pub open: NaiveTime,
+    pub close: NaiveTime,
+}
+
+impl TradingSession {
+    #[must_use]
+    pub fn new(open: NaiveTime, close: NaiveTime) -> Self {
+        Self { open, close }
+    }
+
+    /// Returns whether the session closes on the calendar day after it opens.
+    #[must_use]
+    pub fn is_overnight(&self) -> bool {
+        self.close <= self.open
+    }
+}
+
+/// Represents the trading calendar for a venue, including regular weekly sessions,
+/// holidays and half days (early closes).
+///
+/// Session times are defined in the venue time zone, so daylight saving transitions are
+/// handled when converting to UNIX timestamps.
+#[derive(Clone, Debug)]
+pub struct TradingCalendar {
+    pub name: String,
+    pub tz: Tz,
+    sessions: [Vec<TradingSession>; 7],
+    holidays: BTreeSet<NaiveDate>,
+    half_days: BTreeMap<NaiveDate, NaiveTime>,
+}
+
+impl TradingCalendar {
+    #[must_use]
+    pub fn new(name: &str, tz: Tz) -> Self {
+        Self {
+            name: name.to_string(),
+            tz,
+            sessions: Default::default(),
+            holidays: BTreeSet::new(),
+            half_days: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a regular session opening on each of the given `weekdays`.
+    pub fn add_session(&mut self, weekdays: &[Weekday], session: TradingSession) -> Result<()> {
+        if session.open == session.close {
+            bail!(
+                "Condition failed: session `open` and `close` were both {}",
+                session.open
+            );
+        }
+        for weekday in weekdays {
+            self.sessions[weekday.num_days_from_monday() as usize].push(session);
+            self.sessions[weekday.num_days_from_monday() as usize].sort_by_key(|s| s.open);
+        }
+        Ok(())
+    }
+
+    /// Adds a holiday, on which no sessions open.
+    pub fn add_holiday(&mut self, date: NaiveDate) {
+        self.holidays.insert(date);
+    }
+
+    /// Adds a half day, on which sessions opening that day close no later than `close`.
+    pub fn add_half_day(&mut self, date: NaiveDate, close: NaiveTime) {
+        self.half_days.insert(date, close);
+    }
+
+    /// Returns whether the given date is a holiday.
+    #[must_use]
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date)
+    }
+
+    /// Returns whether the given date is a half day.
+    #[must_use]
+    pub fn is_half_day(&self, date: NaiveDate) -> bool {
+        self.half_days.contains_key(&date)
+    }
+
+    /// Returns the session intervals `(open, close)` as UNIX timestamps (nanoseconds) for
+    /// sessions opening on the given local `date`.
+    #[must_use]
+    pub fn sessions_for_date(&self, date: NaiveDate) -> Vec<(UnixNanos, UnixNanos)> {
+        if self.is_holiday(date) {
+            return Vec::new();
+        }
+
+        let early_close = self.half_days.get(&date).map(|close| date.and_time(*close));
+
+        self.sessions[date.weekday().num_days_from_monday() as usize]
+            .iter()
+            .filter_map(|session| {
+                let open = date.and_time(session.open);
+                let mut close = if session.is_overnight() {
+                    date.succ_opt()?.and_time(session.close)
+                } else {
+                    date.and_time(session.close)
+                };
+                if let Some(early_close) = early_close {
+                    close = close.min(early_close);
+                }
+                if close <= open {
+                    return None;
+                }
+                Some((self.to_unix_nanos(open)?, self.to_unix_nanos(close)?))
+            })
+            .collect()
+    }
+
+    /// Returns whether the venue is open at the given UNIX timestamp (nanoseconds).
+    #[must_use]
+    pub fn is_open(&self, ts: UnixNanos) -> bool {
+        let date = self.local_date(ts);
+        // Check the previous day for overnight sessions still in progress
+        [date.pred_opt(), Some(date)]
+            .into_iter()
+            .flatten()
+            .flat_map(|date| self.sessions_for_date(date))
+            .any(|(open, close)| open <= ts && ts < close)
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) of the next session open after `ts`.
+    #[must_use]
+    pub fn next_open(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        self.sessions_from(ts)
+            .map(|(open, _)| open)
+            .find(|open| *open > ts)
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) of the next session close after `ts`.
+    #[must_use]
+    pub fn next_close(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        self.sessions_from(ts)
+            .map(|(_, close)| close)
+            .find(|close| *close > ts)
+    }
+
+    fn sessions_from(&self, ts: UnixNanos) -> impl Iterator<Item = (UnixNanos, UnixNanos)> + '_ {
+        let start = self.local_date(ts) - TimeDelta::days(1);
+        (0..=MAX_LOOKAHEAD_DAYS)
+            .filter_map(move |days| start.checked_add_signed(TimeDelta::days(days)))
+            .flat_map(|date| self.sessions_for_date(date))
+    }
+
+    fn local_date(&self, ts: UnixNanos) -> NaiveDate {
+        self.tz.timestamp_nanos(ts as i64).date_naive()
+    }
+
+    fn to_unix_nanos(&self, local: NaiveDateTime) -> Option<UnixNanos> {
+        // Times falling in a daylight saving gap are shifted forward by the gap
+        let datetime: DateTime<Tz> =
+            self.tz.from_local_datetime(&local).earliest().or_else(|| {
+                self.tz
+                    .from_local_datetime(&(local + TimeDelta::hours(1)))
+                    .earliest()
+            })?;
+        datetime.timestamp_nanos_opt().map(|ns| ns as UnixNanos)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::datetime::WEEKDAYS;
+
+    fn utc_nanos(y: i32, m: u32, d: u32, h: u32, min: u32) -> UnixNanos {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0)
+            .unwrap()
+            .timestamp_nanos_opt()
+            .unwrap() as UnixNanos
+    }
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[fixture]
+    fn nyse() -> TradingCalendar {
+        let mut calendar = TradingCalendar::new("XNYS", chrono_tz::America::New_York);
+        calendar
+            .add_session(&WEEKDAYS, TradingSession::new(time(9, 30), time(16, 0)))
+            .unwrap();
+        calendar.add_holiday(date(2024, 7, 4));
+        calendar.add_half_day(date(2024, 7, 3), time(13, 0));
+        calendar
+    }
+
+    #[rstest]
+    fn test_is_open_regular_session(nyse: TradingCalendar) {
+        // Monday 2024-06-03, EDT (UTC-4)
+        assert!(!nyse.is_open(utc_nanos(2024, 6, 3, 13, 29)));
+        assert!(nyse.is_open(utc_nanos(2024, 6, 3, 13, 30)));
+        assert!(nyse.is_open(utc_nanos(2024, 6, 3, 19, 59)));
+        assert!(!nyse.is_open(utc_nanos(2024, 6, 3, 20, 0)));
+    }
+
+    #[rstest]
+    fn test_is_open_weekend(nyse: TradingCalendar) {
+        assert!(!nyse.is_open(utc_nanos(2024, 6, 1, 15, 0)));
+    }
+
+    #[rstest]
+    fn test_holiday_and_half_day(nyse: TradingCalendar) {
+        assert!(nyse.is_holiday(date(2024, 7, 4)));
+        assert!(!nyse.is_open(utc_nanos(2024, 7, 4, 15, 0)));
+        assert!(nyse.is_half_day(date(2024, 7, 3)));
+        assert!(nyse.is_open(utc_nanos(2024, 7, 3, 16, 59)));
+        assert!(!nyse.is_open(utc_nanos(2024, 7, 3, 17, 0)));
+    }
+
+    #[rstest]
+    fn test_daylight_saving_transition(nyse: TradingCalendar) {
+        // Friday 2024-03-08 is EST (UTC-5), Monday 2024-03-11 is EDT (UTC-4)
+        assert_eq!(
+            nyse.sessions_for_date(date(2024, 3, 8)),
+            vec![(utc_nanos(2024, 3, 8, 14, 30), utc_nanos(2024, 3, 8, 21, 0))]
+        );
+        assert_eq!(
+            nyse.sessions_for_date(date(2024, 3, 11)),
+            vec![(
+                utc_nanos(2024, 3, 11, 13, 30),
+                utc_nanos(2024, 3, 11, 20, 0)
+            )]
+        );
+    }
+
+    #[rstest]
+    fn test_next_open_and_close(nyse: TradingCalendar) {
+        // Wednesday 2024-07-03 during the half day session
+        let ts = utc_nanos(2024, 7, 3, 15, 0);
+        assert_eq!(nyse.next_close(ts), Some(utc_nanos(2024, 7, 3, 17, 0)));
+        // Skips the 2024-07-04 holiday
+        assert_eq!(nyse.next_open(ts), Some(utc_nanos(2024, 7, 5, 13, 30)));
+    }
+
+    #[rstest]
+    fn test_overnight_session() {
+        let mut calendar = TradingCalendar::new("GLBX", chrono_tz::America::Chicago);
+        calendar
+            .add_session(
+                &[
+                    Weekday::Sun,
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                ],
+                TradingSession::new(time(17, 0), time(16, 0)),
+            )
+            .unwrap();
+
+        // Sunday 2024-06-02 17:00 CDT (UTC-5) opens the session through Monday 16:00
+        assert!(!calendar.is_open(utc_nanos(2024, 6, 2, 21, 59)));
+        assert!(calendar.is_open(utc_nanos(2024, 6, 2, 22, 0)));
+        assert!(calendar.is_open(utc_nanos(2024, 6, 3, 20, 59)));
+        assert!(!calendar.is_open(utc_nanos(2024, 6, 3, 21, 30)));
+        assert_eq!(
+            calendar.next_open(utc_nanos(2024, 6, 3, 21, 30)),
+            Some(utc_nanos(2024, 6, 3, 22, 0))
+        );
+    }
+
+    #[rstest]
+    fn test_add_session_with_equal_open_and_close() {
+        let mut calendar = TradingCalendar::new("TEST", chrono_tz::UTC);
+        let result = calendar.add_session(&WEEKDAYS, TradingSession::new(time(9, 0), time(9, 0)));
+        assert!(result.is_err());
+    }
+}
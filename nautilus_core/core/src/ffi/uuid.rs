@@ -19,13 +19,24 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use crate::uuid::UUID4;
+use crate::uuid::{set_uuid_version, UuidVersion, UUID4};
 
 #[no_mangle]
 pub extern "C" fn uuid4_new() -> UUID4 {
     UUID4::new()
 }
 
+/// Sets the UUID version generated by `uuid4_new` for the process.
+///
+/// # Panics
+///
+/// - If `version` is not a supported UUID version (4 or 7).
+#[no_mangle]
+pub extern "C" fn uuid4_set_version(version: u8) {
+    let version = UuidVersion::try_from(version).unwrap_or_else(|e| panic!("{e}"));
+    set_uuid_version(version);
+}
+
 /// Returns a [`UUID4`] from C string pointer.
 ///
 /// # Safety
@@ -13,6 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod calendar;
 pub mod correctness;
 pub mod datetime;
 pub mod message;
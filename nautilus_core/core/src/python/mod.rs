@@ -25,6 +25,7 @@ use crate::uuid::UUID4;
 pub mod casing;
 pub mod datetime;
 pub mod serialization;
+pub mod time;
 pub mod uuid;
 
 /// Gets the type name for the given Python `obj`.
@@ -62,5 +63,9 @@ pub fn core(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(datetime::py_unix_nanos_to_iso8601, m)?)?;
     m.add_function(wrap_pyfunction!(datetime::py_last_weekday_nanos, m)?)?;
     m.add_function(wrap_pyfunction!(datetime::py_is_within_last_24_hours, m)?)?;
+    m.add_function(wrap_pyfunction!(time::py_iso8601_to_unix_nanos, m)?)?;
+    m.add_function(wrap_pyfunction!(time::py_local_iso8601_to_unix_nanos, m)?)?;
+    m.add_function(wrap_pyfunction!(time::py_format_iso8601, m)?)?;
+    m.add_function(wrap_pyfunction!(time::py_format_iso8601_local, m)?)?;
     Ok(())
 }
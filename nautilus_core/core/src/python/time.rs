@@ -0,0 +1,49 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use pyo3::prelude::*;
+
+use super::to_pyvalue_err;
+use crate::time::{
+    format_iso8601, format_iso8601_local, iso8601_to_unix_nanos, local_iso8601_to_unix_nanos,
+    UnixNanos,
+};
+
+#[pyfunction(name = "iso8601_to_unix_nanos")]
+pub fn py_iso8601_to_unix_nanos(value: &str) -> PyResult<UnixNanos> {
+    iso8601_to_unix_nanos(value).map_err(to_pyvalue_err)
+}
+
+#[pyfunction(name = "local_iso8601_to_unix_nanos")]
+pub fn py_local_iso8601_to_unix_nanos(value: &str, tz: &str) -> PyResult<UnixNanos> {
+    local_iso8601_to_unix_nanos(value, tz).map_err(to_pyvalue_err)
+}
+
+#[must_use]
+#[pyfunction(name = "format_iso8601")]
+#[pyo3(signature = (timestamp_ns, precision=9))]
+pub fn py_format_iso8601(timestamp_ns: UnixNanos, precision: u8) -> String {
+    format_iso8601(timestamp_ns, precision)
+}
+
+#[pyfunction(name = "format_iso8601_local")]
+#[pyo3(signature = (timestamp_ns, tz, precision=9))]
+pub fn py_format_iso8601_local(
+    timestamp_ns: UnixNanos,
+    tz: &str,
+    precision: u8,
+) -> PyResult<String> {
+    format_iso8601_local(timestamp_ns, tz, precision).map_err(to_pyvalue_err)
+}
@@ -14,6 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
+    fmt::Write,
     ops::Deref,
     sync::{
         atomic::{AtomicBool, AtomicU64, Ordering},
@@ -22,6 +23,10 @@ use std::{
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
+use anyhow::{anyhow, Result};
+use chrono::{NaiveDate, Offset, TimeZone};
+use chrono_tz::Tz;
+
 use crate::datetime::{
     NANOSECONDS_IN_MICROSECOND, NANOSECONDS_IN_MILLISECOND, NANOSECONDS_IN_SECOND,
 };
@@ -162,6 +167,268 @@ impl AtomicTime {
     }
 }
 
+/// The number of nanoseconds in one day.
+const NANOSECONDS_IN_DAY: i64 = 86_400 * NANOSECONDS_IN_SECOND as i64;
+
+/// Represents the components of a parsed ISO 8601 timestamp.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ParsedTimestamp {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanos: u32,
+    offset_secs: Option<i32>,
+}
+
+impl ParsedTimestamp {
+    fn to_utc_nanos(self, offset_secs: i32) -> Result<UnixNanos> {
+        let days = days_from_civil(self.year, self.month, self.day);
+        let secs =
+            i64::from(self.hour * 3_600 + self.minute * 60 + self.second) - i64::from(offset_secs);
+        let nanos = i128::from(days) * i128::from(NANOSECONDS_IN_DAY)
+            + i128::from(secs) * i128::from(NANOSECONDS_IN_SECOND)
+            + i128::from(self.nanos);
+        UnixNanos::try_from(nanos).map_err(|_| anyhow!("Timestamp out of range for `UnixNanos`"))
+    }
+}
+
+/// Parses the given ISO 8601 / RFC 3339 string into UNIX nanoseconds.
+///
+/// Accepts a date (`2024-01-02`), or a date and time separated by `T` or a space, with
+/// optional seconds, an optional fractional second of up to nanosecond precision, and an
+/// optional `Z` or `±HH:MM` offset. Timestamps without an offset are interpreted as UTC.
+pub fn iso8601_to_unix_nanos(s: &str) -> Result<UnixNanos> {
+    let parsed = parse_iso8601(s)?;
+    parsed.to_utc_nanos(parsed.offset_secs.unwrap_or(0))
+}
+
+/// Parses the given ISO 8601 string into UNIX nanoseconds, interpreting timestamps without
+/// an offset as local time in the given IANA time zone `tz` (e.g. `America/New_York`).
+pub fn local_iso8601_to_unix_nanos(s: &str, tz: &str) -> Result<UnixNanos> {
+    let parsed = parse_iso8601(s)?;
+    if let Some(offset_secs) = parsed.offset_secs {
+        return parsed.to_utc_nanos(offset_secs);
+    }
+
+    let tz = parse_tz(tz)?;
+    let local = NaiveDate::from_ymd_opt(parsed.year as i32, parsed.month, parsed.day)
+        .and_then(|date| {
+            date.and_hms_nano_opt(parsed.hour, parsed.minute, parsed.second, parsed.nanos)
+        })
+        .ok_or_else(|| anyhow!("Invalid timestamp '{s}'"))?;
+    let datetime = tz
+        .from_local_datetime(&local)
+        .earliest()
+        .ok_or_else(|| anyhow!("Local time '{s}' does not exist in time zone {tz}"))?;
+    let offset_secs = datetime.offset().fix().local_minus_utc();
+    parsed.to_utc_nanos(offset_secs)
+}
+
+/// Formats the given UNIX nanoseconds timestamp as an ISO 8601 UTC string, with `precision`
+/// fractional second digits (clamped to 9).
+#[must_use]
+pub fn format_iso8601(timestamp_ns: UnixNanos, precision: u8) -> String {
+    let mut s = String::with_capacity(30);
+    write_iso8601(&mut s, timestamp_ns as i128, precision);
+    s.push('Z');
+    s
+}
+
+/// Formats the given UNIX nanoseconds timestamp as an ISO 8601 string in the given IANA
+/// time zone `tz`, with `precision` fractional second digits (clamped to 9) and a `±HH:MM`
+/// offset.
+pub fn format_iso8601_local(timestamp_ns: UnixNanos, tz: &str, precision: u8) -> Result<String> {
+    let tz = parse_tz(tz)?;
+    let offset_secs = tz
+        .timestamp_nanos(timestamp_ns as i64)
+        .offset()
+        .fix()
+        .local_minus_utc();
+    let local_ns =
+        timestamp_ns as i128 + i128::from(offset_secs) * i128::from(NANOSECONDS_IN_SECOND);
+
+    let mut s = String::with_capacity(35);
+    write_iso8601(&mut s, local_ns, precision);
+    let sign = if offset_secs < 0 { '-' } else { '+' };
+    let offset_mins = offset_secs.unsigned_abs() / 60;
+    write!(s, "{sign}{:02}:{:02}", offset_mins / 60, offset_mins % 60)
+        .expect("Writing to a `String` cannot fail");
+    Ok(s)
+}
+
+fn parse_tz(tz: &str) -> Result<Tz> {
+    tz.parse::<Tz>()
+        .map_err(|e| anyhow!("Invalid time zone '{tz}': {e}"))
+}
+
+fn write_iso8601(s: &mut String, nanos: i128, precision: u8) {
+    let days = nanos.div_euclid(i128::from(NANOSECONDS_IN_DAY)) as i64;
+    let nanos_of_day = nanos.rem_euclid(i128::from(NANOSECONDS_IN_DAY)) as i64;
+    let (year, month, day) = civil_from_days(days);
+    let secs_of_day = nanos_of_day / NANOSECONDS_IN_SECOND as i64;
+    let subsec_nanos = nanos_of_day % NANOSECONDS_IN_SECOND as i64;
+
+    write!(
+        s,
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        secs_of_day / 3_600,
+        (secs_of_day % 3_600) / 60,
+        secs_of_day % 60,
+    )
+    .expect("Writing to a `String` cannot fail");
+
+    let precision = u32::from(precision.min(9));
+    if precision > 0 {
+        let fraction = subsec_nanos / 10_i64.pow(9 - precision);
+        write!(s, ".{fraction:0width$}", width = precision as usize)
+            .expect("Writing to a `String` cannot fail");
+    }
+}
+
+fn parse_iso8601(s: &str) -> Result<ParsedTimestamp> {
+    let invalid = || anyhow!("Invalid ISO 8601 timestamp '{s}'");
+    let bytes = s.as_bytes();
+
+    let digits = |start: usize, len: usize| -> Result<u32> {
+        let slice = bytes.get(start..start + len).ok_or_else(invalid)?;
+        slice.iter().try_fold(0_u32, |acc, b| {
+            if b.is_ascii_digit() {
+                Ok(acc * 10 + u32::from(b - b'0'))
+            } else {
+                Err(invalid())
+            }
+        })
+    };
+    let expect = |pos: usize, expected: &[u8]| -> Result<()> {
+        match bytes.get(pos) {
+            Some(b) if expected.contains(b) => Ok(()),
+            _ => Err(invalid()),
+        }
+    };
+
+    let year = i64::from(digits(0, 4)?);
+    expect(4, b"-")?;
+    let month = digits(5, 2)?;
+    expect(7, b"-")?;
+    let day = digits(8, 2)?;
+
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return Err(invalid());
+    }
+
+    let mut parsed = ParsedTimestamp {
+        year,
+        month,
+        day,
+        hour: 0,
+        minute: 0,
+        second: 0,
+        nanos: 0,
+        offset_secs: None,
+    };
+
+    let mut pos = 10;
+    if pos == bytes.len() {
+        return Ok(parsed);
+    }
+
+    expect(pos, b"T ")?;
+    parsed.hour = digits(pos + 1, 2)?;
+    expect(pos + 3, b":")?;
+    parsed.minute = digits(pos + 4, 2)?;
+    pos += 6;
+
+    if bytes.get(pos) == Some(&b':') {
+        parsed.second = digits(pos + 1, 2)?;
+        pos += 3;
+
+        if matches!(bytes.get(pos), Some(b'.' | b',')) {
+            pos += 1;
+            let start = pos;
+            while bytes.get(pos).is_some_and(u8::is_ascii_digit) {
+                pos += 1;
+            }
+            let len = pos - start;
+            if len == 0 {
+                return Err(invalid());
+            }
+            // Digits beyond nanosecond precision are truncated
+            let used = len.min(9);
+            parsed.nanos = digits(start, used)? * 10_u32.pow(9 - used as u32);
+        }
+    }
+
+    if parsed.hour > 23 || parsed.minute > 59 || parsed.second > 59 {
+        return Err(invalid());
+    }
+
+    match bytes.get(pos) {
+        None => {}
+        Some(b'Z' | b'z') if pos + 1 == bytes.len() => parsed.offset_secs = Some(0),
+        Some(sign @ (b'+' | b'-')) => {
+            let hours = digits(pos + 1, 2)?;
+            let minutes = match bytes.len() - (pos + 3) {
+                0 => 0,
+                2 => digits(pos + 3, 2)?,
+                3 => {
+                    expect(pos + 3, b":")?;
+                    digits(pos + 4, 2)?
+                }
+                _ => return Err(invalid()),
+            };
+            if hours > 23 || minutes > 59 {
+                return Err(invalid());
+            }
+            let offset = (hours * 3_600 + minutes * 60) as i32;
+            parsed.offset_secs = Some(if *sign == b'-' { -offset } else { offset });
+        }
+        Some(_) => return Err(invalid()),
+    }
+
+    Ok(parsed)
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days since 1970-01-01 for the given proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = i64::from(month);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Returns the proleptic Gregorian date for the given number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -253,4 +520,99 @@ mod tests {
         assert!(result5 >= result4);
         assert!(result1 > 1_650_000_000_000_000_000);
     }
+
+    #[rstest]
+    #[case("1970-01-01", 0)]
+    #[case("1970-01-01T00:00:00Z", 0)]
+    #[case("2024-01-02T03:04:05Z", 1_704_164_645_000_000_000)]
+    #[case("2024-01-02 03:04:05", 1_704_164_645_000_000_000)]
+    #[case("2024-01-02T03:04", 1_704_164_640_000_000_000)]
+    #[case("2024-01-02T03:04:05.1Z", 1_704_164_645_100_000_000)]
+    #[case("2024-01-02T03:04:05.123456789Z", 1_704_164_645_123_456_789)]
+    #[case("2024-01-02T03:04:05.1234567891Z", 1_704_164_645_123_456_789)]
+    #[case("2024-01-02T03:04:05+01:00", 1_704_161_045_000_000_000)]
+    #[case("2024-01-02T03:04:05-0530", 1_704_184_445_000_000_000)]
+    #[case("2024-02-29T00:00:00Z", 1_709_164_800_000_000_000)]
+    fn test_iso8601_to_unix_nanos(#[case] value: &str, #[case] expected: UnixNanos) {
+        assert_eq!(iso8601_to_unix_nanos(value).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("2024-1-02")]
+    #[case("2024-13-01")]
+    #[case("2023-02-29")]
+    #[case("2024-01-02T24:00:00")]
+    #[case("2024-01-02T03:04:05.")]
+    #[case("2024-01-02T03:04:05+1")]
+    #[case("2024-01-02T03:04:05Zjunk")]
+    #[case("1969-12-31T23:59:59Z")]
+    fn test_iso8601_to_unix_nanos_invalid(#[case] value: &str) {
+        assert!(iso8601_to_unix_nanos(value).is_err());
+    }
+
+    #[rstest]
+    fn test_iso8601_matches_chrono() {
+        for value in [
+            "2000-02-29T12:00:00.5Z",
+            "2038-01-19T03:14:07.999Z",
+            "2100-03-01T00:00:00Z",
+        ] {
+            let expected = chrono::DateTime::parse_from_rfc3339(value)
+                .unwrap()
+                .timestamp_nanos_opt()
+                .unwrap() as UnixNanos;
+            assert_eq!(iso8601_to_unix_nanos(value).unwrap(), expected);
+        }
+    }
+
+    #[rstest]
+    #[case(0, "2024-01-02T03:04:05Z")]
+    #[case(3, "2024-01-02T03:04:05.123Z")]
+    #[case(6, "2024-01-02T03:04:05.123456Z")]
+    #[case(9, "2024-01-02T03:04:05.123456789Z")]
+    #[case(12, "2024-01-02T03:04:05.123456789Z")]
+    fn test_format_iso8601(#[case] precision: u8, #[case] expected: &str) {
+        assert_eq!(
+            format_iso8601(1_704_164_645_123_456_789, precision),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_format_iso8601_round_trip() {
+        let timestamp_ns = 1_704_164_645_123_456_789;
+        let formatted = format_iso8601(timestamp_ns, 9);
+        assert_eq!(iso8601_to_unix_nanos(&formatted).unwrap(), timestamp_ns);
+    }
+
+    #[rstest]
+    #[case(1_704_164_645_000_000_000, "2024-01-01T22:04:05.000-05:00")] // EST
+    #[case(1_719_889_445_000_000_000, "2024-07-01T23:04:05.000-04:00")] // EDT
+    fn test_format_iso8601_local(#[case] timestamp_ns: UnixNanos, #[case] expected: &str) {
+        let result = format_iso8601_local(timestamp_ns, "America/New_York", 3).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn test_local_iso8601_to_unix_nanos() {
+        let result = local_iso8601_to_unix_nanos("2024-07-01T09:30:00", "America/New_York");
+        assert_eq!(
+            result.unwrap(),
+            iso8601_to_unix_nanos("2024-07-01T13:30:00Z").unwrap()
+        );
+
+        // Explicit offsets take precedence over the time zone
+        let result = local_iso8601_to_unix_nanos("2024-07-01T09:30:00Z", "America/New_York");
+        assert_eq!(
+            result.unwrap(),
+            iso8601_to_unix_nanos("2024-07-01T09:30:00Z").unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_local_iso8601_with_invalid_time_zone() {
+        assert!(local_iso8601_to_unix_nanos("2024-07-01T09:30:00", "Mars/Olympus").is_err());
+        assert!(format_iso8601_local(0, "Mars/Olympus", 0).is_err());
+    }
 }
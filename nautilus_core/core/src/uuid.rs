@@ -18,11 +18,62 @@ use std::{
     fmt::{Debug, Display, Formatter},
     hash::Hash,
     str::FromStr,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Mutex,
+    },
 };
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use uuid::Uuid;
 
+use crate::time::duration_since_unix_epoch;
+
+/// The UUID version generated by [`UUID4::new`].
+static UUID_VERSION: AtomicU8 = AtomicU8::new(UuidVersion::V4 as u8);
+
+/// The state of the UUID v7 generator, as (last UNIX milliseconds, counter).
+static UUID_V7_STATE: Mutex<(u64, u16)> = Mutex::new((0, 0));
+
+/// The maximum value of the 12-bit UUID v7 counter.
+const UUID_V7_COUNTER_MAX: u16 = 0x0FFF;
+
+/// Represents a UUID version which can be generated.
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UuidVersion {
+    /// Random (RFC 4122 version 4).
+    V4 = 4,
+    /// Unix epoch time-ordered (RFC 9562 version 7).
+    V7 = 7,
+}
+
+impl TryFrom<u8> for UuidVersion {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            4 => Ok(Self::V4),
+            7 => Ok(Self::V7),
+            _ => Err(format!("Unsupported UUID version {value}, expected 4 or 7")),
+        }
+    }
+}
+
+/// Sets the UUID version generated by [`UUID4::new`] for the process.
+pub fn set_uuid_version(version: UuidVersion) {
+    UUID_VERSION.store(version as u8, Ordering::Relaxed);
+}
+
+/// Returns the UUID version generated by [`UUID4::new`].
+#[must_use]
+pub fn uuid_version() -> UuidVersion {
+    match UUID_VERSION.load(Ordering::Relaxed) {
+        7 => UuidVersion::V7,
+        _ => UuidVersion::V4,
+    }
+}
+
 /// Represents a pseudo-random UUID (universally unique identifier)
 /// version 4 based on a 128-bit label as specified in RFC 4122.
 #[repr(C)]
@@ -37,9 +88,71 @@ pub struct UUID4 {
 }
 
 impl UUID4 {
+    /// Creates a new UUID of the configured version (see [`set_uuid_version`]).
     #[must_use]
     pub fn new() -> Self {
-        let uuid = Uuid::new_v4();
+        match uuid_version() {
+            UuidVersion::V4 => Self::new_v4(),
+            UuidVersion::V7 => Self::new_v7(),
+        }
+    }
+
+    /// Creates a new random (version 4) UUID.
+    #[must_use]
+    pub fn new_v4() -> Self {
+        Self::from_uuid(Uuid::new_v4())
+    }
+
+    /// Creates a new time-ordered (version 7) UUID.
+    ///
+    /// The first 48 bits hold the UNIX timestamp (milliseconds), followed by a 12-bit
+    /// counter which orders UUIDs generated within the same millisecond, so values
+    /// generated by this process sort chronologically (both as bytes and strings).
+    #[must_use]
+    pub fn new_v7() -> Self {
+        let now_ms = duration_since_unix_epoch().as_millis() as u64;
+        let (unix_ms, counter) = {
+            let mut state = UUID_V7_STATE.lock().expect("UUID v7 state lock poisoned");
+            let (last_ms, last_counter) = *state;
+            *state = if now_ms > last_ms {
+                (now_ms, 0)
+            } else if last_counter < UUID_V7_COUNTER_MAX {
+                (last_ms, last_counter + 1)
+            } else {
+                // Counter exhausted, borrow from the next millisecond
+                (last_ms + 1, 0)
+            };
+            *state
+        };
+
+        // Random bits are taken from a v4 UUID
+        let mut bytes = *Uuid::new_v4().as_bytes();
+        bytes[..6].copy_from_slice(&unix_ms.to_be_bytes()[2..]);
+        bytes[6] = 0x70 | ((counter >> 8) as u8 & 0x0F);
+        bytes[7] = counter as u8;
+        bytes[8] = 0x80 | (bytes[8] & 0x3F); // RFC 4122 variant
+        Self::from_uuid(Uuid::from_bytes(bytes))
+    }
+
+    /// Returns the version number of the UUID.
+    #[must_use]
+    pub fn version(&self) -> usize {
+        self.as_uuid().get_version_num()
+    }
+
+    /// Returns the UNIX timestamp (milliseconds) embedded in a version 7 UUID.
+    #[must_use]
+    pub fn timestamp_ms(&self) -> Option<u64> {
+        let uuid = self.as_uuid();
+        if uuid.get_version_num() != 7 {
+            return None;
+        }
+        let mut ms_bytes = [0; 8];
+        ms_bytes[2..].copy_from_slice(&uuid.as_bytes()[..6]);
+        Some(u64::from_be_bytes(ms_bytes))
+    }
+
+    fn from_uuid(uuid: Uuid) -> Self {
         let c_string = CString::new(uuid.to_string()).expect("`CString` conversion failed");
         let bytes = c_string.as_bytes_with_nul();
         let mut value = [0; 37];
@@ -48,6 +161,11 @@ impl UUID4 {
         Self { value }
     }
 
+    fn as_uuid(&self) -> Uuid {
+        // SAFETY: Unwrap safe as we always store valid UUID strings
+        Uuid::parse_str(&self.to_cstr().to_string_lossy()).unwrap()
+    }
+
     #[must_use]
     pub fn to_cstr(&self) -> &CStr {
         // SAFETY: Unwrap safe as we always store valid C strings
@@ -60,12 +178,7 @@ impl FromStr for UUID4 {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let uuid = Uuid::parse_str(s).map_err(|_| "Invalid UUID string")?;
-        let c_string = CString::new(uuid.to_string()).expect("`CString` conversion failed");
-        let bytes = c_string.as_bytes_with_nul();
-        let mut value = [0; 37];
-        value[..bytes.len()].copy_from_slice(bytes);
-
-        Ok(Self { value })
+        Ok(Self::from_uuid(uuid))
     }
 }
 
@@ -159,4 +272,37 @@ mod tests {
         let result_string = format!("{uuid}");
         assert_eq!(result_string, uuid_string);
     }
+
+    #[rstest]
+    fn test_uuid_v4_new() {
+        let uuid = UUID4::new_v4();
+        assert_eq!(uuid.version(), 4);
+        assert_eq!(uuid.timestamp_ms(), None);
+    }
+
+    #[rstest]
+    fn test_uuid_v7_new() {
+        let before_ms = duration_since_unix_epoch().as_millis() as u64;
+        let uuid = UUID4::new_v7();
+        let uuid_parsed = Uuid::parse_str(&uuid.to_string()).expect("Uuid::parse_str failed");
+
+        assert_eq!(uuid.version(), 7);
+        assert_eq!(uuid_parsed.get_variant(), uuid::Variant::RFC4122);
+        assert!(uuid.timestamp_ms().unwrap() >= before_ms);
+    }
+
+    #[rstest]
+    fn test_uuid_v7_sorts_chronologically() {
+        let uuids: Vec<String> = (0..10_000).map(|_| UUID4::new_v7().to_string()).collect();
+        let mut sorted = uuids.clone();
+        sorted.sort();
+        assert_eq!(uuids, sorted);
+    }
+
+    #[rstest]
+    fn test_uuid_version_try_from() {
+        assert_eq!(UuidVersion::try_from(4), Ok(UuidVersion::V4));
+        assert_eq!(UuidVersion::try_from(7), Ok(UuidVersion::V7));
+        assert!(UuidVersion::try_from(1).is_err());
+    }
 }
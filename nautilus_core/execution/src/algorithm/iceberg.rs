@@ -0,0 +1,296 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Iceberg execution algorithm with randomized clip sizes.
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    events::order::filled::OrderFilled,
+    identifiers::client_order_id::ClientOrderId,
+    orders::base::Order,
+    types::{
+        fixed::FIXED_PRECISION,
+        quantity::{Quantity, QuantityRaw},
+    },
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{get_exec_param, ChildOrder, ExecAlgorithm, PrimaryOrder};
+
+const NANOS_IN_MILLISECOND: u64 = 1_000_000;
+
+/// Configuration for an [`Iceberg`] algorithm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IcebergConfig {
+    /// The minimum visible clip size.
+    pub min_clip: Quantity,
+    /// The maximum visible clip size.
+    pub max_clip: Quantity,
+    /// The minimum delay (nanoseconds) before replenishing a filled clip.
+    pub min_delay_ns: u64,
+    /// The maximum delay (nanoseconds) before replenishing a filled clip.
+    pub max_delay_ns: u64,
+}
+
+/// Provides an iceberg execution algorithm.
+///
+/// Only one visible clip of the primary order is working at a time. Each clip size is drawn
+/// uniformly from `[min_clip, max_clip]` (rounded to the quantity precision), and once a clip
+/// is completely filled the next clip is spawned after a delay drawn uniformly from
+/// `[min_delay_ns, max_delay_ns]`. The random generator is seeded so that a backtest is
+/// reproducible.
+#[derive(Clone, Debug)]
+pub struct Iceberg {
+    primary: PrimaryOrder,
+    config: IcebergConfig,
+    rng: StdRng,
+    active_clip: Option<(ClientOrderId, Quantity)>,
+    ts_next_clip: Option<UnixNanos>,
+}
+
+impl Iceberg {
+    /// Creates a new [`Iceberg`] instance with the first clip due at `ts_start`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clip or delay ranges are invalid.
+    pub fn new(
+        order: &dyn Order,
+        ts_start: UnixNanos,
+        config: IcebergConfig,
+        seed: u64,
+    ) -> anyhow::Result<Self> {
+        if config.min_clip.is_zero() || config.min_clip > config.max_clip {
+            anyhow::bail!(
+                "Invalid iceberg clips: min_clip={}, max_clip={}",
+                config.min_clip,
+                config.max_clip
+            );
+        }
+        if config.min_delay_ns > config.max_delay_ns {
+            anyhow::bail!(
+                "Invalid iceberg delays: min_delay_ns={}, max_delay_ns={}",
+                config.min_delay_ns,
+                config.max_delay_ns
+            );
+        }
+
+        Ok(Self {
+            primary: PrimaryOrder::new(order),
+            config,
+            rng: StdRng::seed_from_u64(seed),
+            active_clip: None,
+            ts_next_clip: Some(ts_start),
+        })
+    }
+
+    /// Creates a new [`Iceberg`] instance from the `min_clip`, `max_clip`, `min_delay_ms`,
+    /// `max_delay_ms` and optional `seed` in the orders `exec_algorithm_params`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameters are missing or invalid.
+    pub fn from_params(order: &dyn Order, ts_start: UnixNanos) -> anyhow::Result<Self> {
+        let precision = order.quantity().precision;
+        let min_clip: f64 = get_exec_param(order, "min_clip")?;
+        let max_clip: f64 = get_exec_param(order, "max_clip")?;
+        let min_delay_ms: u64 = get_exec_param(order, "min_delay_ms")?;
+        let max_delay_ms: u64 = get_exec_param(order, "max_delay_ms")?;
+        let seed: u64 = get_exec_param(order, "seed").unwrap_or(ts_start);
+        let config = IcebergConfig {
+            min_clip: Quantity::new(min_clip, precision)?,
+            max_clip: Quantity::new(max_clip, precision)?,
+            min_delay_ns: min_delay_ms * NANOS_IN_MILLISECOND,
+            max_delay_ns: max_delay_ms * NANOS_IN_MILLISECOND,
+        };
+        Self::new(order, ts_start, config, seed)
+    }
+
+    /// Returns the currently working clip and its leaves quantity (if any).
+    #[must_use]
+    pub fn active_clip(&self) -> Option<(ClientOrderId, Quantity)> {
+        self.active_clip
+    }
+
+    fn next_clip_qty(&mut self) -> Quantity {
+        let precision = self.primary.quantity.precision;
+        let step = QuantityRaw::pow(10, u32::from(FIXED_PRECISION - precision));
+        let min_units = self.config.min_clip.raw / step;
+        let max_units = self.config.max_clip.raw / step;
+        let units = self.rng.gen_range(min_units..=max_units);
+        Quantity::from_raw(units * step, precision).unwrap()
+    }
+}
+
+impl ExecAlgorithm for Iceberg {
+    fn primary(&self) -> &PrimaryOrder {
+        &self.primary
+    }
+
+    fn on_time(&mut self, ts_now: UnixNanos) -> Vec<ChildOrder> {
+        match self.ts_next_clip {
+            Some(ts_due) if ts_due <= ts_now => {}
+            _ => return Vec::new(),
+        }
+
+        self.ts_next_clip = None;
+        let quantity = self.next_clip_qty();
+        let child = self.primary.spawn(quantity, ts_now);
+        self.active_clip = child.map(|c| (c.client_order_id, c.quantity));
+        child.into_iter().collect()
+    }
+
+    fn on_fill(&mut self, fill: &OrderFilled) -> Vec<ChildOrder> {
+        let Some((client_order_id, leaves_qty)) = self.active_clip else {
+            return Vec::new();
+        };
+        if fill.client_order_id != client_order_id {
+            return Vec::new();
+        }
+
+        let leaves_qty = leaves_qty - fill.last_qty.min(leaves_qty);
+        if leaves_qty.is_positive() {
+            self.active_clip = Some((client_order_id, leaves_qty));
+            return Vec::new();
+        }
+
+        self.active_clip = None;
+        if !self.primary.is_complete() {
+            let delay = self
+                .rng
+                .gen_range(self.config.min_delay_ns..=self.config.max_delay_ns);
+            self.ts_next_clip = Some(fill.ts_event + delay);
+        }
+        self.on_time(fill.ts_event)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::events::order::filled::OrderFilledBuilder;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::algorithm::stubs::primary_order;
+
+    fn config() -> IcebergConfig {
+        IcebergConfig {
+            min_clip: Quantity::from(10),
+            max_clip: Quantity::from(20),
+            min_delay_ns: 100,
+            max_delay_ns: 200,
+        }
+    }
+
+    fn fill(
+        client_order_id: ClientOrderId,
+        last_qty: Quantity,
+        ts_event: UnixNanos,
+    ) -> OrderFilled {
+        OrderFilledBuilder::default()
+            .client_order_id(client_order_id)
+            .last_qty(last_qty)
+            .ts_event(ts_event)
+            .build()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_invalid_config_returns_error() {
+        let order = primary_order("100", &[]);
+        let mut config = config();
+        config.min_clip = Quantity::from(30);
+
+        assert!(Iceberg::new(&order, 0, config, 1).is_err());
+    }
+
+    #[rstest]
+    fn test_from_params() {
+        let order = primary_order(
+            "100",
+            &[
+                ("min_clip", "10"),
+                ("max_clip", "20"),
+                ("min_delay_ms", "1"),
+                ("max_delay_ms", "2"),
+            ],
+        );
+
+        let iceberg = Iceberg::from_params(&order, 0).unwrap();
+
+        assert_eq!(iceberg.config.min_delay_ns, 1_000_000);
+        assert_eq!(iceberg.config.max_clip, Quantity::from(20));
+    }
+
+    #[rstest]
+    fn test_clip_replenished_after_random_delay_once_filled() {
+        let order = primary_order("100", &[]);
+        let mut iceberg = Iceberg::new(&order, 0, config(), 42).unwrap();
+
+        let clip = iceberg.on_time(0)[0];
+        let partial = iceberg.on_fill(&fill(clip.client_order_id, Quantity::from(1), 5));
+        let filled = iceberg.on_fill(&fill(
+            clip.client_order_id,
+            clip.quantity - Quantity::from(1),
+            10,
+        ));
+        let next_clip = (10..=210)
+            .find_map(|ts| iceberg.on_time(ts).first().copied())
+            .unwrap();
+
+        assert!((Quantity::from(10)..=Quantity::from(20)).contains(&clip.quantity));
+        assert!(partial.is_empty());
+        assert!(filled.is_empty());
+        assert!((110..=210).contains(&next_clip.ts_init));
+        assert_eq!(next_clip.client_order_id, ClientOrderId::from("O-1-E2"));
+    }
+
+    #[rstest]
+    fn test_clips_sum_to_primary_quantity() {
+        let order = primary_order("100", &[]);
+        let config = IcebergConfig {
+            min_delay_ns: 0,
+            max_delay_ns: 0,
+            ..config()
+        };
+        let mut iceberg = Iceberg::new(&order, 0, config, 42).unwrap();
+
+        let mut clips = iceberg.on_time(0);
+        while let Some(clip) = clips.last().copied() {
+            let next = iceberg.on_fill(&fill(clip.client_order_id, clip.quantity, 0));
+            if next.is_empty() {
+                break;
+            }
+            clips.extend(next);
+        }
+
+        let total: f64 = clips.iter().map(|clip| clip.quantity.as_f64()).sum();
+        assert_eq!(total, 100.0);
+        assert!(iceberg.is_complete());
+        assert!(iceberg.active_clip().is_none());
+    }
+
+    #[rstest]
+    fn test_same_seed_gives_same_clips() {
+        let order = primary_order("100", &[]);
+        let mut iceberg1 = Iceberg::new(&order, 0, config(), 7).unwrap();
+        let mut iceberg2 = Iceberg::new(&order, 0, config(), 7).unwrap();
+
+        assert_eq!(iceberg1.on_time(0), iceberg2.on_time(0));
+    }
+}
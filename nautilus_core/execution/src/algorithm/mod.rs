@@ -0,0 +1,363 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Execution algorithms which slice a primary order into child orders.
+//!
+//! Algorithms are pure state machines driven by the caller with the clock and market data,
+//! so the same slicing applies identically in backtest and live. Each child order is spawned
+//! from the primary order with a client order ID of the form `{primary}-E{sequence}`.
+
+pub mod iceberg;
+pub mod pov;
+pub mod twap;
+pub mod vwap;
+
+use std::str::FromStr;
+
+use anyhow::Context;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::trade::TradeTick,
+    enums::OrderSide,
+    events::order::filled::OrderFilled,
+    identifiers::{
+        client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
+        instrument_id::InstrumentId, strategy_id::StrategyId, trader_id::TraderId,
+    },
+    orders::base::Order,
+    types::{
+        fixed::FIXED_PRECISION,
+        price::Price,
+        quantity::{Quantity, QuantityRaw},
+    },
+};
+use ustr::Ustr;
+
+/// Represents a child order spawned from a primary order.
+///
+/// The child order is a `MARKET` order if `price` is `None`, otherwise a `LIMIT` order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChildOrder {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub exec_algorithm_id: Option<ExecAlgorithmId>,
+    pub exec_spawn_id: ClientOrderId,
+    pub order_side: OrderSide,
+    pub quantity: Quantity,
+    pub price: Option<Price>,
+    pub ts_init: UnixNanos,
+}
+
+/// Represents the primary order being executed by an algorithm, and tracks the quantity
+/// spawned from it so far.
+#[derive(Clone, Debug)]
+pub struct PrimaryOrder {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub exec_algorithm_id: Option<ExecAlgorithmId>,
+    pub order_side: OrderSide,
+    pub quantity: Quantity,
+    pub price: Option<Price>,
+    spawned_qty: Quantity,
+    spawn_sequence: usize,
+}
+
+impl PrimaryOrder {
+    #[must_use]
+    pub fn new(order: &dyn Order) -> Self {
+        let quantity = order.quantity();
+        Self {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            exec_algorithm_id: order.exec_algorithm_id(),
+            order_side: order.side(),
+            quantity,
+            price: order.price(),
+            spawned_qty: Quantity::zero(quantity.precision),
+            spawn_sequence: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn spawned_qty(&self) -> Quantity {
+        self.spawned_qty
+    }
+
+    #[must_use]
+    pub fn leaves_qty(&self) -> Quantity {
+        self.quantity - self.spawned_qty
+    }
+
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.spawned_qty >= self.quantity
+    }
+
+    /// Spawns a child order for the given `quantity` (capped to the leaves quantity).
+    ///
+    /// Returns `None` if the capped quantity is zero.
+    pub fn spawn(&mut self, quantity: Quantity, ts_init: UnixNanos) -> Option<ChildOrder> {
+        let quantity = quantity.min(self.leaves_qty());
+        if quantity.is_zero() {
+            return None;
+        }
+
+        self.spawn_sequence += 1;
+        self.spawned_qty += quantity;
+
+        Some(ChildOrder {
+            trader_id: self.trader_id,
+            strategy_id: self.strategy_id,
+            instrument_id: self.instrument_id,
+            client_order_id: ClientOrderId::from(
+                format!("{}-E{}", self.client_order_id, self.spawn_sequence).as_str(),
+            ),
+            exec_algorithm_id: self.exec_algorithm_id,
+            exec_spawn_id: self.client_order_id,
+            order_side: self.order_side,
+            quantity,
+            price: self.price,
+            ts_init,
+        })
+    }
+}
+
+/// The abstract execution algorithm trait.
+pub trait ExecAlgorithm {
+    /// Returns the primary order being executed.
+    fn primary(&self) -> &PrimaryOrder;
+
+    /// Handles the clock advancing to `ts_now`, returning the child orders now due.
+    fn on_time(&mut self, _ts_now: UnixNanos) -> Vec<ChildOrder> {
+        Vec::new()
+    }
+
+    /// Handles a market `trade`, returning the child orders now due.
+    fn on_trade(&mut self, _trade: &TradeTick) -> Vec<ChildOrder> {
+        Vec::new()
+    }
+
+    /// Handles a `fill` of a child order, returning the child orders now due.
+    fn on_fill(&mut self, _fill: &OrderFilled) -> Vec<ChildOrder> {
+        Vec::new()
+    }
+
+    /// Returns whether the entire primary order quantity has been spawned.
+    fn is_complete(&self) -> bool {
+        self.primary().is_complete()
+    }
+}
+
+/// Creates the built-in execution algorithm for the `exec_algorithm_id` of the `order`
+/// (`TWAP`, `VWAP`, `POV` or `ICEBERG`) from its `exec_algorithm_params`.
+///
+/// # Errors
+///
+/// Returns an error if the algorithm is not recognized, or its parameters are invalid.
+pub fn create_exec_algorithm(
+    order: &dyn Order,
+    ts_start: UnixNanos,
+    volume_curves: &vwap::VolumeCurveCache,
+) -> anyhow::Result<Box<dyn ExecAlgorithm>> {
+    let exec_algorithm_id = order
+        .exec_algorithm_id()
+        .context("no `exec_algorithm_id` for order")?;
+    Ok(match exec_algorithm_id.value.as_str() {
+        "TWAP" => Box::new(twap::Twap::from_params(order, ts_start)?),
+        "VWAP" => Box::new(vwap::Vwap::from_params(order, ts_start, volume_curves)?),
+        "POV" => Box::new(pov::Pov::from_params(order, ts_start)?),
+        "ICEBERG" => Box::new(iceberg::Iceberg::from_params(order, ts_start)?),
+        _ => anyhow::bail!("Unrecognized execution algorithm {exec_algorithm_id}"),
+    })
+}
+
+/// Splits the `quantity` into slices proportional to the given `weights`.
+///
+/// Each slice is rounded down to the quantity precision, with the cumulative rounding
+/// remainder carried into the following slices so that the slices always sum to `quantity`.
+/// Slices may be zero.
+///
+/// # Panics
+///
+/// Panics if `weights` is empty or does not sum to a positive value.
+#[must_use]
+pub fn split_quantity(quantity: Quantity, weights: &[f64]) -> Vec<Quantity> {
+    assert!(!weights.is_empty(), "`weights` was empty");
+    let total: f64 = weights.iter().sum();
+    assert!(total > 0.0, "`weights` did not sum to a positive value");
+
+    let step = QuantityRaw::pow(10, u32::from(FIXED_PRECISION - quantity.precision));
+    let units = quantity.raw / step;
+
+    let mut slices = Vec::with_capacity(weights.len());
+    let mut cumulative_weight = 0.0;
+    let mut allocated: QuantityRaw = 0;
+    for (i, weight) in weights.iter().enumerate() {
+        cumulative_weight += weight;
+        let target = if i == weights.len() - 1 {
+            units
+        } else {
+            ((units as f64) * cumulative_weight / total).floor() as QuantityRaw
+        };
+        let target = target.clamp(allocated, units);
+        slices.push(Quantity::from_raw((target - allocated) * step, quantity.precision).unwrap());
+        allocated = target;
+    }
+    slices
+}
+
+/// Returns the parsed `key` from the `exec_algorithm_params` of the `order`.
+///
+/// # Errors
+///
+/// Returns an error if the parameter is not found or cannot be parsed.
+pub fn get_exec_param<T: FromStr>(order: &dyn Order, key: &str) -> anyhow::Result<T> {
+    let value = order
+        .exec_algorithm_params()
+        .and_then(|params| params.get(&Ustr::from(key)).copied())
+        .with_context(|| format!("`{key}` not found in `exec_algorithm_params`"))?;
+    value
+        .as_str()
+        .parse::<T>()
+        .map_err(|_| anyhow::anyhow!("invalid `{key}` in `exec_algorithm_params`: {value}"))
+}
+
+#[cfg(test)]
+pub(crate) mod stubs {
+    use std::collections::HashMap;
+
+    use nautilus_model::{
+        enums::AggressorSide, events::order::initialized::OrderInitializedBuilder,
+        identifiers::trade_id::TradeId, orders::market::MarketOrder,
+    };
+    use ustr::Ustr;
+
+    use super::*;
+
+    pub fn primary_order(quantity: &str, params: &[(&str, &str)]) -> MarketOrder {
+        algo_order("ALGO", quantity, params)
+    }
+
+    pub fn algo_order(
+        exec_algorithm_id: &str,
+        quantity: &str,
+        params: &[(&str, &str)],
+    ) -> MarketOrder {
+        let params: HashMap<Ustr, Ustr> = params
+            .iter()
+            .map(|(k, v)| (Ustr::from(k), Ustr::from(v)))
+            .collect();
+        OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::from("O-1"))
+            .quantity(Quantity::from(quantity))
+            .exec_algorithm_id(Some(ExecAlgorithmId::from(exec_algorithm_id)))
+            .exec_algorithm_params(Some(params))
+            .build()
+            .unwrap()
+            .into()
+    }
+
+    pub fn trade(instrument_id: InstrumentId, size: &str, ts_event: UnixNanos) -> TradeTick {
+        TradeTick::new(
+            instrument_id,
+            Price::from("1.00000"),
+            Quantity::from(size),
+            AggressorSide::Buyer,
+            TradeId::from("T-1"),
+            ts_event,
+            ts_event,
+        )
+    }
+
+    pub fn quantities(children: &[ChildOrder]) -> Vec<Quantity> {
+        children.iter().map(|child| child.quantity).collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{
+        stubs::{algo_order, primary_order},
+        *,
+    };
+
+    #[rstest]
+    #[case("100", &[1.0, 1.0, 1.0], &["33", "33", "34"])]
+    #[case("10.5", &[1.0, 2.0, 1.0], &["2.6", "5.2", "2.7"])]
+    #[case("2", &[1.0, 1.0, 1.0, 1.0], &["0", "1", "0", "1"])]
+    fn test_split_quantity(
+        #[case] quantity: &str,
+        #[case] weights: &[f64],
+        #[case] expected: &[&str],
+    ) {
+        let slices = split_quantity(Quantity::from(quantity), weights);
+
+        let expected: Vec<Quantity> = expected.iter().map(|q| Quantity::from(*q)).collect();
+        assert_eq!(slices, expected);
+    }
+
+    #[rstest]
+    fn test_primary_order_spawn_increments_sequence_and_caps_quantity() {
+        let order = primary_order("100", &[]);
+        let mut primary = PrimaryOrder::new(&order);
+
+        let child1 = primary.spawn(Quantity::from(60), 1).unwrap();
+        let child2 = primary.spawn(Quantity::from(60), 2).unwrap();
+
+        assert_eq!(child1.client_order_id, ClientOrderId::from("O-1-E1"));
+        assert_eq!(child2.client_order_id, ClientOrderId::from("O-1-E2"));
+        assert_eq!(child2.exec_spawn_id, order.client_order_id());
+        assert_eq!(child2.quantity, Quantity::from(40));
+        assert!(primary.is_complete());
+        assert!(primary.spawn(Quantity::from(1), 3).is_none());
+    }
+
+    #[rstest]
+    #[case("TWAP", &[("horizon_secs", "2"), ("interval_secs", "1")], true)]
+    #[case("POV", &[("participation_rate", "0.2")], true)]
+    #[case("POV", &[], false)]
+    #[case("VWAP", &[("interval_secs", "1")], false)]
+    #[case("UNKNOWN", &[], false)]
+    fn test_create_exec_algorithm(
+        #[case] exec_algorithm_id: &str,
+        #[case] params: &[(&str, &str)],
+        #[case] expected_ok: bool,
+    ) {
+        let order = algo_order(exec_algorithm_id, "100", params);
+
+        let result = create_exec_algorithm(&order, 0, &vwap::VolumeCurveCache::new());
+
+        assert_eq!(result.is_ok(), expected_ok);
+    }
+
+    #[rstest]
+    fn test_get_exec_param() {
+        let order = primary_order("100", &[("horizon_secs", "60")]);
+
+        assert_eq!(get_exec_param::<u64>(&order, "horizon_secs").unwrap(), 60);
+        assert!(get_exec_param::<u64>(&order, "interval_secs").is_err());
+    }
+}
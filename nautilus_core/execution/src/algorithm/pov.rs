@@ -0,0 +1,175 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Percentage-of-Volume (POV) execution algorithm.
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::trade::TradeTick,
+    orders::base::Order,
+    types::quantity::{Quantity, QuantityRaw},
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+use rust_decimal_macros::dec;
+
+use super::{get_exec_param, ChildOrder, ExecAlgorithm, PrimaryOrder};
+
+/// The default participation rate for a [`Pov`] algorithm (10%).
+pub const DEFAULT_PARTICIPATION_RATE: Decimal = dec!(0.1);
+
+/// Provides a POV execution algorithm.
+///
+/// Child orders are spawned as market trades are observed for the instrument (from
+/// `ts_start`), such that the cumulative spawned quantity tracks the `participation_rate`
+/// of the cumulative market volume, rounded down to the quantity precision.
+#[derive(Clone, Debug)]
+pub struct Pov {
+    primary: PrimaryOrder,
+    participation_rate: Decimal,
+    ts_start: UnixNanos,
+    market_volume: Decimal,
+}
+
+impl Pov {
+    /// Creates a new [`Pov`] instance starting at `ts_start`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `participation_rate` is not in the range (0, 1].
+    pub fn new(
+        order: &dyn Order,
+        ts_start: UnixNanos,
+        participation_rate: Decimal,
+    ) -> anyhow::Result<Self> {
+        if participation_rate <= Decimal::ZERO || participation_rate > Decimal::ONE {
+            anyhow::bail!("Invalid POV participation_rate: {participation_rate}");
+        }
+
+        Ok(Self {
+            primary: PrimaryOrder::new(order),
+            participation_rate,
+            ts_start,
+            market_volume: Decimal::ZERO,
+        })
+    }
+
+    /// Creates a new [`Pov`] instance from the `participation_rate` in the orders
+    /// `exec_algorithm_params`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter is missing or invalid.
+    pub fn from_params(order: &dyn Order, ts_start: UnixNanos) -> anyhow::Result<Self> {
+        let participation_rate: Decimal = get_exec_param(order, "participation_rate")?;
+        Self::new(order, ts_start, participation_rate)
+    }
+
+    /// Returns the cumulative market volume observed.
+    #[must_use]
+    pub fn market_volume(&self) -> Decimal {
+        self.market_volume
+    }
+
+    fn target_qty(&self) -> Quantity {
+        let precision = self.primary.quantity.precision;
+        let target = (self.market_volume * self.participation_rate)
+            .round_dp_with_strategy(u32::from(precision), RoundingStrategy::ToZero)
+            .min(self.primary.quantity.as_decimal());
+        Quantity::new(target.to_f64().unwrap_or(0.0), precision).unwrap()
+    }
+}
+
+impl ExecAlgorithm for Pov {
+    fn primary(&self) -> &PrimaryOrder {
+        &self.primary
+    }
+
+    fn on_trade(&mut self, trade: &TradeTick) -> Vec<ChildOrder> {
+        if trade.instrument_id != self.primary.instrument_id
+            || trade.ts_event < self.ts_start
+            || self.primary.is_complete()
+        {
+            return Vec::new();
+        }
+
+        self.market_volume += trade.size.as_decimal();
+
+        let target = self.target_qty();
+        let spawned: QuantityRaw = self.primary.spawned_qty().raw;
+        if target.raw <= spawned {
+            return Vec::new();
+        }
+
+        let quantity = Quantity::from_raw(target.raw - spawned, target.precision).unwrap();
+        self.primary
+            .spawn(quantity, trade.ts_init)
+            .into_iter()
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::identifiers::instrument_id::InstrumentId;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::algorithm::stubs::{primary_order, trade};
+
+    #[rstest]
+    #[case(dec!(0))]
+    #[case(dec!(1.5))]
+    fn test_invalid_participation_rate_returns_error(#[case] participation_rate: Decimal) {
+        let order = primary_order("100", &[]);
+
+        assert!(Pov::new(&order, 0, participation_rate).is_err());
+    }
+
+    #[rstest]
+    fn test_on_trade_tracks_participation_of_market_volume() {
+        let order = primary_order("100", &[("participation_rate", "0.1")]);
+        let instrument_id = order.instrument_id();
+        let mut pov = Pov::from_params(&order, 10).unwrap();
+
+        let before_start = pov.on_trade(&trade(instrument_id, "500", 5));
+        let below_lot = pov.on_trade(&trade(instrument_id, "5", 10));
+        let first = pov.on_trade(&trade(instrument_id, "300", 11));
+        let other = pov.on_trade(&trade(InstrumentId::from("ETHUSDT.BINANCE"), "1000", 12));
+        let second = pov.on_trade(&trade(instrument_id, "195", 13));
+
+        assert!(before_start.is_empty());
+        assert!(below_lot.is_empty());
+        assert_eq!(first[0].quantity, Quantity::from(30));
+        assert!(other.is_empty());
+        assert_eq!(second[0].quantity, Quantity::from(20));
+        assert_eq!(pov.market_volume(), dec!(500));
+    }
+
+    #[rstest]
+    fn test_on_trade_caps_at_primary_quantity() {
+        let order = primary_order("100", &[]);
+        let instrument_id = order.instrument_id();
+        let mut pov = Pov::new(&order, 0, DEFAULT_PARTICIPATION_RATE).unwrap();
+
+        let children = pov.on_trade(&trade(instrument_id, "5000", 1));
+
+        assert_eq!(children[0].quantity, Quantity::from(100));
+        assert!(pov.is_complete());
+        assert!(pov.on_trade(&trade(instrument_id, "5000", 2)).is_empty());
+    }
+}
@@ -0,0 +1,165 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Time-Weighted Average Price (TWAP) execution algorithm.
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{orders::base::Order, types::quantity::Quantity};
+
+use super::{get_exec_param, split_quantity, ChildOrder, ExecAlgorithm, PrimaryOrder};
+
+const NANOS_IN_SECOND: u64 = 1_000_000_000;
+
+/// Provides a TWAP execution algorithm.
+///
+/// The primary order quantity is split evenly over the `horizon`, with a child order due
+/// at the start of each `interval` (the first immediately). Any remainder from rounding to
+/// the quantity precision is carried into the later slices.
+#[derive(Clone, Debug)]
+pub struct Twap {
+    primary: PrimaryOrder,
+    schedule: Vec<(UnixNanos, Quantity)>,
+    next_slice: usize,
+}
+
+impl Twap {
+    /// Creates a new [`Twap`] instance starting at `ts_start`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `interval_ns` is zero or greater than `horizon_ns`.
+    pub fn new(
+        order: &dyn Order,
+        ts_start: UnixNanos,
+        horizon_ns: u64,
+        interval_ns: u64,
+    ) -> anyhow::Result<Self> {
+        if interval_ns == 0 || horizon_ns < interval_ns {
+            anyhow::bail!(
+                "Invalid TWAP schedule: horizon_ns={horizon_ns}, interval_ns={interval_ns}"
+            );
+        }
+
+        let num_intervals = (horizon_ns / interval_ns) as usize;
+        let schedule = split_quantity(order.quantity(), &vec![1.0; num_intervals])
+            .into_iter()
+            .enumerate()
+            .map(|(i, quantity)| (ts_start + i as u64 * interval_ns, quantity))
+            .collect();
+
+        Ok(Self {
+            primary: PrimaryOrder::new(order),
+            schedule,
+            next_slice: 0,
+        })
+    }
+
+    /// Creates a new [`Twap`] instance from the `horizon_secs` and `interval_secs` in the
+    /// orders `exec_algorithm_params`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameters are missing or invalid.
+    pub fn from_params(order: &dyn Order, ts_start: UnixNanos) -> anyhow::Result<Self> {
+        let horizon_secs: f64 = get_exec_param(order, "horizon_secs")?;
+        let interval_secs: f64 = get_exec_param(order, "interval_secs")?;
+        Self::new(
+            order,
+            ts_start,
+            (horizon_secs * NANOS_IN_SECOND as f64) as u64,
+            (interval_secs * NANOS_IN_SECOND as f64) as u64,
+        )
+    }
+
+    /// Returns the scheduled (time, quantity) slices.
+    #[must_use]
+    pub fn schedule(&self) -> &[(UnixNanos, Quantity)] {
+        &self.schedule
+    }
+}
+
+impl ExecAlgorithm for Twap {
+    fn primary(&self) -> &PrimaryOrder {
+        &self.primary
+    }
+
+    fn on_time(&mut self, ts_now: UnixNanos) -> Vec<ChildOrder> {
+        let mut children = Vec::new();
+        while let Some((ts_due, quantity)) = self.schedule.get(self.next_slice).copied() {
+            if ts_due > ts_now {
+                break;
+            }
+            self.next_slice += 1;
+            children.extend(self.primary.spawn(quantity, ts_now));
+        }
+        children
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::algorithm::stubs::{primary_order, quantities};
+
+    #[rstest]
+    fn test_invalid_schedule_returns_error() {
+        let order = primary_order("100", &[]);
+
+        assert!(Twap::new(&order, 0, 10, 0).is_err());
+        assert!(Twap::new(&order, 0, 10, 20).is_err());
+    }
+
+    #[rstest]
+    fn test_from_params_builds_even_schedule() {
+        let order = primary_order("100", &[("horizon_secs", "3"), ("interval_secs", "1")]);
+
+        let twap = Twap::from_params(&order, 1_000).unwrap();
+
+        assert_eq!(
+            twap.schedule(),
+            &[
+                (1_000, Quantity::from(33)),
+                (1_000_001_000, Quantity::from(33)),
+                (2_000_001_000, Quantity::from(34)),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_on_time_spawns_due_slices_until_complete() {
+        let order = primary_order("100", &[]);
+        let mut twap = Twap::new(&order, 0, 40, 10).unwrap();
+
+        let first = twap.on_time(0);
+        let none = twap.on_time(9);
+        let caught_up = twap.on_time(25);
+        let last = twap.on_time(30);
+
+        assert_eq!(quantities(&first), vec![Quantity::from(25)]);
+        assert!(none.is_empty());
+        assert_eq!(
+            quantities(&caught_up),
+            vec![Quantity::from(25), Quantity::from(25)]
+        );
+        assert_eq!(last[0].client_order_id.to_string(), "O-1-E4");
+        assert!(twap.is_complete());
+        assert!(twap.on_time(100).is_empty());
+    }
+}
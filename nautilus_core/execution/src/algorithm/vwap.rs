@@ -0,0 +1,249 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Volume-Weighted Average Price (VWAP) execution algorithm.
+
+use std::collections::HashMap;
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    identifiers::instrument_id::InstrumentId, orders::base::Order, types::quantity::Quantity,
+};
+
+use super::{get_exec_param, split_quantity, ChildOrder, ExecAlgorithm, PrimaryOrder};
+
+const NANOS_IN_SECOND: u64 = 1_000_000_000;
+
+/// Represents an expected volume curve, as the fraction of volume traded in each bucket of
+/// an execution horizon.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VolumeCurve {
+    weights: Vec<f64>,
+}
+
+impl VolumeCurve {
+    /// Creates a new [`VolumeCurve`] from the (historical) `volumes` traded in each bucket.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `volumes` is empty, contains a negative value or sums to zero.
+    pub fn from_volumes(volumes: &[f64]) -> anyhow::Result<Self> {
+        if volumes.iter().any(|v| *v < 0.0) {
+            anyhow::bail!("Invalid volume curve: negative volume in {volumes:?}");
+        }
+        let total: f64 = volumes.iter().sum();
+        if total <= 0.0 {
+            anyhow::bail!("Invalid volume curve: no volume in {volumes:?}");
+        }
+
+        Ok(Self {
+            weights: volumes.iter().map(|v| v / total).collect(),
+        })
+    }
+
+    /// Returns the fraction of volume for each bucket (summing to one).
+    #[must_use]
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+}
+
+/// Provides a cache of volume curves per instrument.
+#[derive(Clone, Debug, Default)]
+pub struct VolumeCurveCache {
+    curves: HashMap<InstrumentId, VolumeCurve>,
+}
+
+impl VolumeCurveCache {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_curve(&mut self, instrument_id: InstrumentId, curve: VolumeCurve) {
+        self.curves.insert(instrument_id, curve);
+    }
+
+    #[must_use]
+    pub fn curve(&self, instrument_id: &InstrumentId) -> Option<&VolumeCurve> {
+        self.curves.get(instrument_id)
+    }
+}
+
+/// Provides a VWAP execution algorithm.
+///
+/// The primary order quantity is split over consecutive buckets of `interval_ns` in
+/// proportion to the expected volume curve, with a child order due at the start of each
+/// bucket (the first immediately). Buckets with a zero quantity after rounding are skipped.
+#[derive(Clone, Debug)]
+pub struct Vwap {
+    primary: PrimaryOrder,
+    schedule: Vec<(UnixNanos, Quantity)>,
+    next_slice: usize,
+}
+
+impl Vwap {
+    /// Creates a new [`Vwap`] instance starting at `ts_start` following the `curve`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `interval_ns` is zero.
+    pub fn new(
+        order: &dyn Order,
+        ts_start: UnixNanos,
+        interval_ns: u64,
+        curve: &VolumeCurve,
+    ) -> anyhow::Result<Self> {
+        if interval_ns == 0 {
+            anyhow::bail!("Invalid VWAP schedule: interval_ns={interval_ns}");
+        }
+
+        let schedule = split_quantity(order.quantity(), curve.weights())
+            .into_iter()
+            .enumerate()
+            .filter(|(_, quantity)| quantity.is_positive())
+            .map(|(i, quantity)| (ts_start + i as u64 * interval_ns, quantity))
+            .collect();
+
+        Ok(Self {
+            primary: PrimaryOrder::new(order),
+            schedule,
+            next_slice: 0,
+        })
+    }
+
+    /// Creates a new [`Vwap`] instance using the cached volume curve for the orders instrument.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no volume curve is cached for the instrument, or `interval_ns` is zero.
+    pub fn from_cache(
+        order: &dyn Order,
+        ts_start: UnixNanos,
+        interval_ns: u64,
+        cache: &VolumeCurveCache,
+    ) -> anyhow::Result<Self> {
+        let instrument_id = order.instrument_id();
+        let curve = cache
+            .curve(&instrument_id)
+            .ok_or_else(|| anyhow::anyhow!("No volume curve for {instrument_id}"))?;
+        Self::new(order, ts_start, interval_ns, curve)
+    }
+
+    /// Creates a new [`Vwap`] instance using the cached volume curve for the orders instrument,
+    /// with buckets of the `interval_secs` in the orders `exec_algorithm_params`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parameter is missing or invalid, or no volume curve is cached.
+    pub fn from_params(
+        order: &dyn Order,
+        ts_start: UnixNanos,
+        cache: &VolumeCurveCache,
+    ) -> anyhow::Result<Self> {
+        let interval_secs: f64 = get_exec_param(order, "interval_secs")?;
+        Self::from_cache(
+            order,
+            ts_start,
+            (interval_secs * NANOS_IN_SECOND as f64) as u64,
+            cache,
+        )
+    }
+
+    /// Returns the scheduled (time, quantity) slices.
+    #[must_use]
+    pub fn schedule(&self) -> &[(UnixNanos, Quantity)] {
+        &self.schedule
+    }
+}
+
+impl ExecAlgorithm for Vwap {
+    fn primary(&self) -> &PrimaryOrder {
+        &self.primary
+    }
+
+    fn on_time(&mut self, ts_now: UnixNanos) -> Vec<ChildOrder> {
+        let mut children = Vec::new();
+        while let Some((ts_due, quantity)) = self.schedule.get(self.next_slice).copied() {
+            if ts_due > ts_now {
+                break;
+            }
+            self.next_slice += 1;
+            children.extend(self.primary.spawn(quantity, ts_now));
+        }
+        children
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::algorithm::stubs::{primary_order, quantities};
+
+    #[rstest]
+    #[case(&[])]
+    #[case(&[0.0, 0.0])]
+    #[case(&[1.0, -1.0])]
+    fn test_invalid_volume_curve_returns_error(#[case] volumes: &[f64]) {
+        assert!(VolumeCurve::from_volumes(volumes).is_err());
+    }
+
+    #[rstest]
+    fn test_from_cache_when_no_curve_returns_error() {
+        let order = primary_order("100", &[]);
+
+        assert!(Vwap::from_cache(&order, 0, 10, &VolumeCurveCache::new()).is_err());
+    }
+
+    #[rstest]
+    fn test_schedule_follows_volume_curve() {
+        let order = primary_order("1000", &[]);
+        let mut cache = VolumeCurveCache::new();
+        cache.add_curve(
+            order.instrument_id(),
+            VolumeCurve::from_volumes(&[500.0, 250.0, 0.0, 250.0]).unwrap(),
+        );
+
+        let vwap = Vwap::from_cache(&order, 0, 10, &cache).unwrap();
+
+        assert_eq!(
+            vwap.schedule(),
+            &[
+                (0, Quantity::from(500)),
+                (10, Quantity::from(250)),
+                (30, Quantity::from(250)),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_on_time_spawns_due_slices_until_complete() {
+        let order = primary_order("100", &[]);
+        let curve = VolumeCurve::from_volumes(&[3.0, 1.0]).unwrap();
+        let mut vwap = Vwap::new(&order, 0, 10, &curve).unwrap();
+
+        let first = vwap.on_time(0);
+        let second = vwap.on_time(10);
+
+        assert_eq!(quantities(&first), vec![Quantity::from(75)]);
+        assert_eq!(quantities(&second), vec![Quantity::from(25)]);
+        assert!(vwap.is_complete());
+    }
+}
@@ -0,0 +1,433 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Contingent order (OTO, OCO, OUO) resolution.
+//!
+//! Given an order which has just been updated by an event, the resolver determines which
+//! actions should be taken on its linked orders. The resolution is pure (orders are looked
+//! up through a caller provided function), so the same rules apply to both the backtest
+//! matching engine and live execution emulation.
+
+use nautilus_model::{
+    enums::ContingencyType, identifiers::client_order_id::ClientOrderId, orders::base::Order,
+    types::quantity::Quantity,
+};
+
+/// Represents an action to apply to a contingent order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContingencyAction {
+    /// Submit (release) the contingent order to the venue.
+    Submit(ClientOrderId),
+    /// Cancel the contingent order.
+    Cancel(ClientOrderId),
+    /// Modify the contingent order to the given quantity.
+    ModifyQuantity(ClientOrderId, Quantity),
+}
+
+/// Resolves the actions to apply to the orders linked to `order` following a fill,
+/// cancel, expiry or rejection of `order`.
+///
+/// - OTO: once the parent has filled, child orders are sized to the parent filled quantity
+///   and submitted. If the parent closes without any fill then the children are canceled.
+/// - OCO: once an order has filled or closed, all other open linked orders are canceled.
+/// - OUO: once an order closes, all other open linked orders are canceled, otherwise the
+///   linked orders are reduced to the leaves quantity of the order.
+///
+/// Linked orders which are already closed, or still active locally (not yet at the venue)
+/// for OCO and OUO, are skipped.
+///
+/// # Errors
+///
+/// Returns an error if a linked order cannot be found using `lookup`.
+pub fn resolve_contingencies<'a, F>(
+    order: &dyn Order,
+    lookup: F,
+) -> anyhow::Result<Vec<ContingencyAction>>
+where
+    F: Fn(&ClientOrderId) -> Option<&'a dyn Order>,
+{
+    let mut actions = Vec::new();
+    let Some(contingency_type) = order.contingency_type() else {
+        return Ok(actions);
+    };
+
+    let filled_qty = order.filled_qty();
+    let leaves_qty = order.leaves_qty();
+
+    for client_order_id in order.linked_order_ids().unwrap_or_default() {
+        if client_order_id == order.client_order_id() {
+            continue; // Already being handled
+        }
+        let contingent_order = lookup(&client_order_id).ok_or_else(|| {
+            anyhow::anyhow!("Cannot find {contingency_type} contingent order {client_order_id}")
+        })?;
+        if contingent_order.is_closed() {
+            continue; // Already completed
+        }
+
+        match contingency_type {
+            ContingencyType::Oto => {
+                if filled_qty.is_positive() {
+                    if contingent_order.leaves_qty() != filled_qty {
+                        actions.push(ContingencyAction::ModifyQuantity(
+                            client_order_id,
+                            filled_qty,
+                        ));
+                    }
+                    if !contingent_order.is_open() && !contingent_order.is_inflight() {
+                        actions.push(ContingencyAction::Submit(client_order_id));
+                    }
+                } else if order.is_closed() {
+                    actions.push(ContingencyAction::Cancel(client_order_id));
+                }
+            }
+            ContingencyType::Oco => {
+                if contingent_order.is_active_local() {
+                    continue; // Order is not at the venue yet
+                }
+                if filled_qty.is_positive() || order.is_closed() {
+                    actions.push(ContingencyAction::Cancel(client_order_id));
+                }
+            }
+            ContingencyType::Ouo => {
+                if contingent_order.is_active_local() {
+                    continue; // Order is not at the venue yet
+                }
+                if order.is_closed() || leaves_qty.is_zero() {
+                    actions.push(ContingencyAction::Cancel(client_order_id));
+                } else if contingent_order.leaves_qty() != leaves_qty {
+                    actions.push(ContingencyAction::ModifyQuantity(
+                        client_order_id,
+                        leaves_qty,
+                    ));
+                }
+            }
+            ContingencyType::NoContingency => return Ok(actions),
+        }
+    }
+
+    Ok(actions)
+}
+
+/// Resolves the quantity modifications to apply to the orders linked to `order` following
+/// a modification of `order`.
+///
+/// Only OTO children and OUO linked orders follow the quantity of the modified order.
+///
+/// # Errors
+///
+/// Returns an error if a linked order cannot be found using `lookup`.
+pub fn resolve_contingencies_update<'a, F>(
+    order: &dyn Order,
+    lookup: F,
+) -> anyhow::Result<Vec<ContingencyAction>>
+where
+    F: Fn(&ClientOrderId) -> Option<&'a dyn Order>,
+{
+    let mut actions = Vec::new();
+    if !matches!(
+        order.contingency_type(),
+        Some(ContingencyType::Oto | ContingencyType::Ouo)
+    ) {
+        return Ok(actions);
+    }
+
+    let quantity = order.quantity();
+    if quantity.is_zero() {
+        return Ok(actions);
+    }
+
+    for client_order_id in order.linked_order_ids().unwrap_or_default() {
+        if client_order_id == order.client_order_id() {
+            continue; // Already being handled
+        }
+        let contingent_order = lookup(&client_order_id)
+            .ok_or_else(|| anyhow::anyhow!("Cannot find contingent order {client_order_id}"))?;
+        if contingent_order.is_closed() {
+            continue; // Already completed
+        }
+        if contingent_order.quantity() != quantity {
+            actions.push(ContingencyAction::ModifyQuantity(client_order_id, quantity));
+        }
+    }
+
+    Ok(actions)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use nautilus_model::{
+        events::order::{
+            accepted::OrderAcceptedBuilder, canceled::OrderCanceledBuilder, event::OrderEvent,
+            filled::OrderFilledBuilder, initialized::OrderInitializedBuilder,
+            submitted::OrderSubmittedBuilder,
+        },
+        orders::market::MarketOrder,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn contingent_order(
+        client_order_id: &str,
+        contingency_type: ContingencyType,
+        linked_order_ids: &[&str],
+    ) -> MarketOrder {
+        OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .contingency_type(Some(contingency_type))
+            .linked_order_ids(Some(
+                linked_order_ids
+                    .iter()
+                    .map(|id| ClientOrderId::from(*id))
+                    .collect(),
+            ))
+            .build()
+            .unwrap()
+            .into()
+    }
+
+    fn accept(order: &mut MarketOrder) {
+        let client_order_id = order.client_order_id();
+        order
+            .apply(OrderEvent::OrderSubmitted(
+                OrderSubmittedBuilder::default()
+                    .client_order_id(client_order_id)
+                    .build()
+                    .unwrap(),
+            ))
+            .unwrap();
+        order
+            .apply(OrderEvent::OrderAccepted(
+                OrderAcceptedBuilder::default()
+                    .client_order_id(client_order_id)
+                    .build()
+                    .unwrap(),
+            ))
+            .unwrap();
+    }
+
+    fn fill(order: &mut MarketOrder, last_qty: Quantity) {
+        let filled = OrderFilledBuilder::default()
+            .client_order_id(order.client_order_id())
+            .last_qty(last_qty)
+            .build()
+            .unwrap();
+        let event = if last_qty == order.leaves_qty() {
+            OrderEvent::OrderFilled(filled)
+        } else {
+            OrderEvent::OrderPartiallyFilled(filled)
+        };
+        order.apply(event).unwrap();
+    }
+
+    fn cancel(order: &mut MarketOrder) {
+        let canceled = OrderCanceledBuilder::default()
+            .client_order_id(order.client_order_id())
+            .build()
+            .unwrap();
+        order.apply(OrderEvent::OrderCanceled(canceled)).unwrap();
+    }
+
+    fn resolve(order: &MarketOrder, orders: &[&MarketOrder]) -> Vec<ContingencyAction> {
+        let orders: HashMap<ClientOrderId, &dyn Order> = orders
+            .iter()
+            .map(|o| (o.client_order_id(), *o as &dyn Order))
+            .collect();
+        resolve_contingencies(order, |id| orders.get(id).copied()).unwrap()
+    }
+
+    #[rstest]
+    fn test_no_contingency_returns_no_actions() {
+        let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
+        accept(&mut order);
+        fill(&mut order, Quantity::from(100_000));
+
+        assert!(resolve(&order, &[]).is_empty());
+    }
+
+    #[rstest]
+    fn test_missing_linked_order_returns_error() {
+        let order = contingent_order("O-1", ContingencyType::Oco, &["O-2"]);
+
+        let result = resolve_contingencies(&order, |_| None);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_oto_parent_filled_submits_children() {
+        let mut parent = contingent_order("O-1", ContingencyType::Oto, &["O-2"]);
+        let child: MarketOrder = OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::from("O-2"))
+            .parent_order_id(Some(ClientOrderId::from("O-1")))
+            .build()
+            .unwrap()
+            .into();
+        accept(&mut parent);
+        fill(&mut parent, Quantity::from(100_000));
+
+        let actions = resolve(&parent, &[&parent, &child]);
+
+        assert_eq!(
+            actions,
+            vec![ContingencyAction::Submit(ClientOrderId::from("O-2"))]
+        );
+    }
+
+    #[rstest]
+    fn test_oto_parent_partially_filled_resizes_and_submits_children() {
+        let mut parent = contingent_order("O-1", ContingencyType::Oto, &["O-2"]);
+        let child: MarketOrder = OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::from("O-2"))
+            .parent_order_id(Some(ClientOrderId::from("O-1")))
+            .build()
+            .unwrap()
+            .into();
+        accept(&mut parent);
+        fill(&mut parent, Quantity::from(40_000));
+
+        let actions = resolve(&parent, &[&parent, &child]);
+
+        assert_eq!(
+            actions,
+            vec![
+                ContingencyAction::ModifyQuantity(
+                    ClientOrderId::from("O-2"),
+                    Quantity::from(40_000)
+                ),
+                ContingencyAction::Submit(ClientOrderId::from("O-2")),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_oto_parent_canceled_without_fill_cancels_children() {
+        let mut parent = contingent_order("O-1", ContingencyType::Oto, &["O-2"]);
+        let child: MarketOrder = OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::from("O-2"))
+            .parent_order_id(Some(ClientOrderId::from("O-1")))
+            .build()
+            .unwrap()
+            .into();
+        accept(&mut parent);
+        cancel(&mut parent);
+
+        let actions = resolve(&parent, &[&parent, &child]);
+
+        assert_eq!(
+            actions,
+            vec![ContingencyAction::Cancel(ClientOrderId::from("O-2"))]
+        );
+    }
+
+    #[rstest]
+    fn test_oco_filled_cancels_open_siblings() {
+        let mut order1 = contingent_order("O-1", ContingencyType::Oco, &["O-1", "O-2", "O-3"]);
+        let mut order2 = contingent_order("O-2", ContingencyType::Oco, &["O-1", "O-2", "O-3"]);
+        let mut order3 = contingent_order("O-3", ContingencyType::Oco, &["O-1", "O-2", "O-3"]);
+        accept(&mut order1);
+        accept(&mut order2);
+        accept(&mut order3);
+        cancel(&mut order3);
+        fill(&mut order1, Quantity::from(100_000));
+
+        let actions = resolve(&order1, &[&order1, &order2, &order3]);
+
+        assert_eq!(
+            actions,
+            vec![ContingencyAction::Cancel(ClientOrderId::from("O-2"))]
+        );
+    }
+
+    #[rstest]
+    fn test_oco_skips_orders_not_yet_at_venue() {
+        let mut order1 = contingent_order("O-1", ContingencyType::Oco, &["O-2"]);
+        let order2 = contingent_order("O-2", ContingencyType::Oco, &["O-1"]);
+        accept(&mut order1);
+        fill(&mut order1, Quantity::from(100_000));
+
+        let actions = resolve(&order1, &[&order1, &order2]);
+
+        assert!(actions.is_empty());
+    }
+
+    #[rstest]
+    fn test_ouo_partially_filled_reduces_siblings() {
+        let mut order1 = contingent_order("O-1", ContingencyType::Ouo, &["O-2"]);
+        let mut order2 = contingent_order("O-2", ContingencyType::Ouo, &["O-1"]);
+        accept(&mut order1);
+        accept(&mut order2);
+        fill(&mut order1, Quantity::from(30_000));
+
+        let actions = resolve(&order1, &[&order1, &order2]);
+
+        assert_eq!(
+            actions,
+            vec![ContingencyAction::ModifyQuantity(
+                ClientOrderId::from("O-2"),
+                Quantity::from(70_000)
+            )]
+        );
+    }
+
+    #[rstest]
+    fn test_ouo_canceled_cancels_siblings() {
+        let mut order1 = contingent_order("O-1", ContingencyType::Ouo, &["O-2"]);
+        let mut order2 = contingent_order("O-2", ContingencyType::Ouo, &["O-1"]);
+        accept(&mut order1);
+        accept(&mut order2);
+        cancel(&mut order1);
+
+        let actions = resolve(&order1, &[&order1, &order2]);
+
+        assert_eq!(
+            actions,
+            vec![ContingencyAction::Cancel(ClientOrderId::from("O-2"))]
+        );
+    }
+
+    #[rstest]
+    fn test_update_propagates_quantity_to_oto_children() {
+        let parent = contingent_order("O-1", ContingencyType::Oto, &["O-2"]);
+        let child: MarketOrder = OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::from("O-2"))
+            .quantity(Quantity::from(50_000))
+            .build()
+            .unwrap()
+            .into();
+        let orders: HashMap<ClientOrderId, &dyn Order> = [
+            (parent.client_order_id(), &parent as &dyn Order),
+            (child.client_order_id(), &child as &dyn Order),
+        ]
+        .into_iter()
+        .collect();
+
+        let actions = resolve_contingencies_update(&parent, |id| orders.get(id).copied()).unwrap();
+
+        assert_eq!(
+            actions,
+            vec![ContingencyAction::ModifyQuantity(
+                ClientOrderId::from("O-2"),
+                Quantity::from(100_000)
+            )]
+        );
+    }
+}
@@ -0,0 +1,534 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Order emulation for trigger-based order types.
+//!
+//! Emulated orders are held locally and monitored against market data from the
+//! configured emulation trigger source. Once triggered, an order is released to the
+//! venue as a `MARKET` order (for `STOP_MARKET` and `MARKET_IF_TOUCHED`) or as a
+//! `LIMIT` order (for `STOP_LIMIT` and `LIMIT_IF_TOUCHED`).
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::{quote::QuoteTick, trade::TradeTick},
+    enums::{OrderSide, OrderType, TriggerType},
+    identifiers::{client_order_id::ClientOrderId, instrument_id::InstrumentId},
+    orders::base::Order,
+    types::{fixed::FIXED_PRECISION, price::Price, quantity::Quantity},
+};
+use serde::{Deserialize, Serialize};
+
+/// The cache key under which the emulated order state is persisted.
+pub const EMULATED_ORDERS_KEY: &str = "emulator:orders";
+
+/// The trigger sources supported for order emulation.
+pub const SUPPORTED_TRIGGERS: [TriggerType; 5] = [
+    TriggerType::Default,
+    TriggerType::BidAsk,
+    TriggerType::LastTrade,
+    TriggerType::MidPoint,
+    TriggerType::MarkPrice,
+];
+
+/// The latest market prices for an instrument which emulated orders trigger from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TriggerPrices {
+    pub bid: Option<Price>,
+    pub ask: Option<Price>,
+    pub last: Option<Price>,
+    pub mark: Option<Price>,
+}
+
+impl TriggerPrices {
+    /// Returns the mid-point of the current bid and ask (with one extra digit of precision).
+    #[must_use]
+    pub fn mid(&self) -> Option<Price> {
+        let (bid, ask) = (self.bid?, self.ask?);
+        let precision = (bid.precision + 1).min(FIXED_PRECISION);
+        Price::from_raw((bid.raw + ask.raw) / 2, precision).ok()
+    }
+
+    /// Returns the market price to compare against for the given trigger source and order side.
+    ///
+    /// For quote based triggers a buy order is compared against the ask, and a sell order
+    /// against the bid.
+    #[must_use]
+    pub fn trigger_price(&self, trigger_type: TriggerType, side: OrderSide) -> Option<Price> {
+        match trigger_type {
+            TriggerType::Default | TriggerType::BidAsk => match side {
+                OrderSide::Buy => self.ask,
+                OrderSide::Sell => self.bid,
+                OrderSide::NoOrderSide => None,
+            },
+            TriggerType::LastTrade => self.last,
+            TriggerType::MidPoint => self.mid(),
+            TriggerType::MarkPrice => self.mark,
+            _ => None,
+        }
+    }
+}
+
+/// Represents the persisted state of an emulated order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EmulatedOrder {
+    pub client_order_id: ClientOrderId,
+    pub instrument_id: InstrumentId,
+    pub trigger_instrument_id: InstrumentId,
+    pub order_type: OrderType,
+    pub side: OrderSide,
+    pub quantity: Quantity,
+    pub price: Option<Price>,
+    pub trigger_price: Price,
+    pub emulation_trigger: TriggerType,
+    pub ts_init: UnixNanos,
+}
+
+impl EmulatedOrder {
+    /// Creates the emulated state for the given `order`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The order has no emulation trigger, or the trigger source is not supported.
+    /// - The order type cannot be emulated.
+    /// - The order is missing its trigger price (or limit price for limit types).
+    pub fn from_order(order: &dyn Order) -> Result<Self> {
+        let client_order_id = order.client_order_id();
+        let Some(emulation_trigger) = order.emulation_trigger() else {
+            bail!("Order {client_order_id} has no `emulation_trigger`")
+        };
+        if !SUPPORTED_TRIGGERS.contains(&emulation_trigger) {
+            bail!("Emulation trigger {emulation_trigger} not supported for {client_order_id}");
+        }
+
+        let order_type = order.order_type();
+        let price = match order_type {
+            OrderType::StopMarket | OrderType::MarketIfTouched => None,
+            OrderType::StopLimit | OrderType::LimitIfTouched => match order.price() {
+                Some(price) => Some(price),
+                None => bail!("Order {client_order_id} has no `price`"),
+            },
+            _ => bail!("Order type {order_type} cannot be emulated for {client_order_id}"),
+        };
+        let Some(trigger_price) = order.trigger_price() else {
+            bail!("Order {client_order_id} has no `trigger_price`")
+        };
+
+        Ok(Self {
+            client_order_id,
+            instrument_id: order.instrument_id(),
+            trigger_instrument_id: order
+                .trigger_instrument_id()
+                .unwrap_or(order.instrument_id()),
+            order_type,
+            side: order.side(),
+            quantity: order.leaves_qty(),
+            price,
+            trigger_price,
+            emulation_trigger,
+            ts_init: order.ts_init(),
+        })
+    }
+
+    /// Returns the order type the order is released as once triggered.
+    #[must_use]
+    pub fn released_order_type(&self) -> OrderType {
+        match self.order_type {
+            OrderType::StopLimit | OrderType::LimitIfTouched => OrderType::Limit,
+            _ => OrderType::Market,
+        }
+    }
+
+    /// Returns whether the order is triggered by the given market `prices`.
+    ///
+    /// Stop orders trigger when the market moves through the trigger price against the
+    /// order side, whereas if-touched orders trigger when the market touches the trigger
+    /// price in favor of the order side.
+    #[must_use]
+    pub fn is_triggered(&self, prices: &TriggerPrices) -> bool {
+        let Some(market) = prices.trigger_price(self.emulation_trigger, self.side) else {
+            return false;
+        };
+        let is_stop = matches!(
+            self.order_type,
+            OrderType::StopMarket | OrderType::StopLimit
+        );
+        match (self.side, is_stop) {
+            (OrderSide::Buy, true) | (OrderSide::Sell, false) => market >= self.trigger_price,
+            (OrderSide::Sell, true) | (OrderSide::Buy, false) => market <= self.trigger_price,
+            (OrderSide::NoOrderSide, _) => false,
+        }
+    }
+}
+
+/// Represents an emulated order which has triggered and should be released to the venue.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReleasedOrder {
+    pub order: EmulatedOrder,
+    /// The order type to release the order as.
+    pub order_type: OrderType,
+    /// The market price which triggered the release.
+    pub released_price: Price,
+    pub ts_event: UnixNanos,
+}
+
+/// Provides the core state and trigger logic for emulating orders locally.
+#[derive(Debug, Default)]
+pub struct OrderEmulatorCore {
+    orders: IndexMap<ClientOrderId, EmulatedOrder>,
+    prices: HashMap<InstrumentId, TriggerPrices>,
+}
+
+impl OrderEmulatorCore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restores the emulator from the persisted state in `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the bytes cannot be deserialized.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let orders: Vec<EmulatedOrder> = serde_json::from_slice(bytes)?;
+        Ok(Self {
+            orders: orders
+                .into_iter()
+                .map(|order| (order.client_order_id, order))
+                .collect(),
+            prices: HashMap::new(),
+        })
+    }
+
+    /// Serializes the emulated orders for persistence to the cache.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the orders cannot be serialized.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let orders: Vec<&EmulatedOrder> = self.orders.values().collect();
+        Ok(serde_json::to_vec(&orders)?)
+    }
+
+    #[must_use]
+    pub fn orders(&self) -> Vec<&EmulatedOrder> {
+        self.orders.values().collect()
+    }
+
+    #[must_use]
+    pub fn order(&self, client_order_id: &ClientOrderId) -> Option<&EmulatedOrder> {
+        self.orders.get(client_order_id)
+    }
+
+    #[must_use]
+    pub fn trigger_prices(&self, instrument_id: &InstrumentId) -> Option<&TriggerPrices> {
+        self.prices.get(instrument_id)
+    }
+
+    /// Returns the instrument IDs (and trigger sources) market data is required for.
+    #[must_use]
+    pub fn subscriptions(&self) -> Vec<(InstrumentId, TriggerType)> {
+        let mut subscriptions: Vec<(InstrumentId, TriggerType)> = Vec::new();
+        for order in self.orders.values() {
+            let subscription = (order.trigger_instrument_id, order.emulation_trigger);
+            if !subscriptions.contains(&subscription) {
+                subscriptions.push(subscription);
+            }
+        }
+        subscriptions
+    }
+
+    /// Adds the given `order` for emulation.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order cannot be emulated.
+    pub fn add_order(&mut self, order: &dyn Order) -> Result<()> {
+        let emulated = EmulatedOrder::from_order(order)?;
+        self.orders.insert(emulated.client_order_id, emulated);
+        Ok(())
+    }
+
+    /// Updates the trigger price (and price) of an emulated order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order is not being emulated.
+    pub fn modify_order(
+        &mut self,
+        client_order_id: &ClientOrderId,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+    ) -> Result<()> {
+        let Some(order) = self.orders.get_mut(client_order_id) else {
+            bail!("Order {client_order_id} not found for emulation")
+        };
+        if order.price.is_some() {
+            order.price = price.or(order.price);
+        }
+        order.trigger_price = trigger_price.unwrap_or(order.trigger_price);
+        Ok(())
+    }
+
+    /// Removes the order from emulation (for instance when canceled).
+    pub fn cancel_order(&mut self, client_order_id: &ClientOrderId) -> Option<EmulatedOrder> {
+        self.orders.shift_remove(client_order_id)
+    }
+
+    pub fn on_quote(&mut self, quote: &QuoteTick) -> Vec<ReleasedOrder> {
+        let prices = self.prices.entry(quote.instrument_id).or_default();
+        prices.bid = Some(quote.bid_price);
+        prices.ask = Some(quote.ask_price);
+        self.iterate(quote.instrument_id, quote.ts_event)
+    }
+
+    pub fn on_trade(&mut self, trade: &TradeTick) -> Vec<ReleasedOrder> {
+        self.prices.entry(trade.instrument_id).or_default().last = Some(trade.price);
+        self.iterate(trade.instrument_id, trade.ts_event)
+    }
+
+    pub fn on_mark_price(
+        &mut self,
+        instrument_id: InstrumentId,
+        mark_price: Price,
+        ts_event: UnixNanos,
+    ) -> Vec<ReleasedOrder> {
+        self.prices.entry(instrument_id).or_default().mark = Some(mark_price);
+        self.iterate(instrument_id, ts_event)
+    }
+
+    fn iterate(&mut self, instrument_id: InstrumentId, ts_event: UnixNanos) -> Vec<ReleasedOrder> {
+        let Some(prices) = self.prices.get(&instrument_id).copied() else {
+            return Vec::new();
+        };
+
+        let triggered: Vec<ClientOrderId> = self
+            .orders
+            .values()
+            .filter(|order| order.trigger_instrument_id == instrument_id)
+            .filter(|order| order.is_triggered(&prices))
+            .map(|order| order.client_order_id)
+            .collect();
+
+        triggered
+            .iter()
+            .filter_map(|client_order_id| self.orders.shift_remove(client_order_id))
+            .map(|order| ReleasedOrder {
+                order_type: order.released_order_type(),
+                // SAFETY: Order was triggered so the trigger source has a price
+                released_price: prices
+                    .trigger_price(order.emulation_trigger, order.side)
+                    .unwrap(),
+                order,
+                ts_event,
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::AggressorSide, events::order::initialized::OrderInitializedBuilder,
+        identifiers::trade_id::TradeId, orders::limit_if_touched::LimitIfTouchedOrder,
+        orders::market::MarketOrder, orders::market_if_touched::MarketIfTouchedOrder,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("AUD/USD.SIM")
+    }
+
+    fn mit_order(
+        client_order_id: &str,
+        side: OrderSide,
+        trigger_price: &str,
+        emulation_trigger: TriggerType,
+    ) -> MarketIfTouchedOrder {
+        OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .instrument_id(instrument_id())
+            .order_type(OrderType::MarketIfTouched)
+            .order_side(side)
+            .trigger_price(Some(Price::from(trigger_price)))
+            .trigger_type(Some(TriggerType::Default))
+            .emulation_trigger(Some(emulation_trigger))
+            .build()
+            .unwrap()
+            .into()
+    }
+
+    fn lit_order(side: OrderSide, price: &str, trigger_price: &str) -> LimitIfTouchedOrder {
+        OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::from("O-LIT"))
+            .instrument_id(instrument_id())
+            .order_type(OrderType::LimitIfTouched)
+            .order_side(side)
+            .price(Some(Price::from(price)))
+            .trigger_price(Some(Price::from(trigger_price)))
+            .trigger_type(Some(TriggerType::Default))
+            .emulation_trigger(Some(TriggerType::BidAsk))
+            .build()
+            .unwrap()
+            .into()
+    }
+
+    fn quote(bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id(),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            1,
+            1,
+        )
+        .unwrap()
+    }
+
+    fn trade(price: &str) -> TradeTick {
+        TradeTick::new(
+            instrument_id(),
+            Price::from(price),
+            Quantity::from(100_000),
+            AggressorSide::Buyer,
+            TradeId::from("T-1"),
+            1,
+            1,
+        )
+    }
+
+    #[rstest]
+    fn test_mid_point_adds_precision() {
+        let prices = TriggerPrices {
+            bid: Some(Price::from("1.00000")),
+            ask: Some(Price::from("1.00001")),
+            ..Default::default()
+        };
+
+        assert_eq!(prices.mid(), Some(Price::from("1.000005")));
+    }
+
+    #[rstest]
+    fn test_add_order_rejects_unsupported_orders() {
+        let mut emulator = OrderEmulatorCore::new();
+        let market: MarketOrder = OrderInitializedBuilder::default()
+            .emulation_trigger(Some(TriggerType::BidAsk))
+            .build()
+            .unwrap()
+            .into();
+        let index_triggered = mit_order("O-1", OrderSide::Buy, "1.0", TriggerType::IndexPrice);
+
+        assert!(emulator.add_order(&market).is_err());
+        assert!(emulator.add_order(&index_triggered).is_err());
+        assert!(emulator.orders().is_empty());
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "0.99999", "1.00001", false)]
+    #[case(OrderSide::Buy, "0.99998", "1.00000", true)]
+    #[case(OrderSide::Sell, "0.99999", "1.00001", false)]
+    #[case(OrderSide::Sell, "1.00000", "1.00002", true)]
+    fn test_mit_triggers_on_touch_of_bid_ask(
+        #[case] side: OrderSide,
+        #[case] bid: &str,
+        #[case] ask: &str,
+        #[case] expected: bool,
+    ) {
+        let mut emulator = OrderEmulatorCore::new();
+        let order = mit_order("O-1", side, "1.00000", TriggerType::BidAsk);
+        emulator.add_order(&order).unwrap();
+
+        let released = emulator.on_quote(&quote(bid, ask));
+
+        assert_eq!(released.len(), usize::from(expected));
+        assert_eq!(emulator.orders().len(), usize::from(!expected));
+        if let Some(released) = released.first() {
+            assert_eq!(released.order_type, OrderType::Market);
+            assert_eq!(released.released_price, Price::from("1.00000"));
+        }
+    }
+
+    #[rstest]
+    fn test_lit_releases_limit_order_at_price() {
+        let mut emulator = OrderEmulatorCore::new();
+        let order = lit_order(OrderSide::Sell, "1.00010", "1.00005");
+        emulator.add_order(&order).unwrap();
+
+        let released = emulator.on_quote(&quote("1.00005", "1.00007"));
+
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].order_type, OrderType::Limit);
+        assert_eq!(released[0].order.price, Some(Price::from("1.00010")));
+    }
+
+    #[rstest]
+    fn test_trigger_sources_only_react_to_their_own_data() {
+        let mut emulator = OrderEmulatorCore::new();
+        let last = mit_order("O-LAST", OrderSide::Buy, "1.00000", TriggerType::LastTrade);
+        let mark = mit_order("O-MARK", OrderSide::Buy, "1.00000", TriggerType::MarkPrice);
+        let mid = mit_order("O-MID", OrderSide::Buy, "1.00000", TriggerType::MidPoint);
+        emulator.add_order(&last).unwrap();
+        emulator.add_order(&mark).unwrap();
+        emulator.add_order(&mid).unwrap();
+
+        let on_quote = emulator.on_quote(&quote("0.99999", "1.00001"));
+        let on_trade = emulator.on_trade(&trade("0.99990"));
+        let on_mark = emulator.on_mark_price(instrument_id(), Price::from("0.99995"), 2);
+
+        let ids = |released: &[ReleasedOrder]| -> Vec<ClientOrderId> {
+            released.iter().map(|r| r.order.client_order_id).collect()
+        };
+        assert_eq!(ids(&on_quote), vec![ClientOrderId::from("O-MID")]);
+        assert_eq!(ids(&on_trade), vec![ClientOrderId::from("O-LAST")]);
+        assert_eq!(ids(&on_mark), vec![ClientOrderId::from("O-MARK")]);
+        assert!(emulator.orders().is_empty());
+    }
+
+    #[rstest]
+    fn test_persisted_state_survives_restart() {
+        let mut emulator = OrderEmulatorCore::new();
+        let order = lit_order(OrderSide::Buy, "0.99990", "0.99995");
+        emulator.add_order(&order).unwrap();
+        emulator
+            .modify_order(
+                &ClientOrderId::from("O-LIT"),
+                None,
+                Some(Price::from("0.99980")),
+            )
+            .unwrap();
+
+        let bytes = emulator.to_bytes().unwrap();
+        let mut restored = OrderEmulatorCore::from_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.orders(), emulator.orders());
+        assert_eq!(
+            restored.subscriptions(),
+            vec![(instrument_id(), TriggerType::BidAsk)]
+        );
+        assert!(restored.on_quote(&quote("0.99985", "0.99990")).is_empty());
+        assert_eq!(restored.on_quote(&quote("0.99975", "0.99980")).len(), 1);
+    }
+}
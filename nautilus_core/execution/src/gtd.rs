@@ -0,0 +1,256 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Local expiry management for `GTD` (good-till-date) orders on venues which do not
+//! support the time in force natively.
+
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    enums::TimeInForce,
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, strategy_id::StrategyId,
+        venue::Venue,
+    },
+    orders::base::Order,
+};
+
+/// The prefix for the names of GTD expiry timers.
+pub const GTD_EXPIRY_TIMER_PREFIX: &str = "GTD-EXPIRY:";
+
+/// Returns the clock timer name for the GTD expiry of the given order.
+#[must_use]
+pub fn gtd_expiry_timer_name(client_order_id: &ClientOrderId) -> String {
+    format!("{GTD_EXPIRY_TIMER_PREFIX}{client_order_id}")
+}
+
+/// Represents a managed GTD order which has reached its expire time and should be
+/// canceled locally.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GtdExpired {
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub expire_time_ns: UnixNanos,
+}
+
+/// Manages the expiry of `GTD` orders for venues lacking native GTD support.
+///
+/// Managed orders should be sent to the venue as `GTC` (see
+/// [`GtdExpiryManager::venue_time_in_force`]), with the manager driven by calling
+/// [`GtdExpiryManager::advance`] with the current time, for example from a clock time
+/// alert scheduled at [`GtdExpiryManager::next_expiry_ns`], so the same logic applies in
+/// both backtests and live trading.
+#[derive(Clone, Debug, Default)]
+pub struct GtdExpiryManager {
+    native_gtd_venues: HashSet<Venue>,
+    orders: IndexMap<ClientOrderId, GtdExpired>,
+}
+
+impl GtdExpiryManager {
+    #[must_use]
+    pub fn new(native_gtd_venues: HashSet<Venue>) -> Self {
+        Self {
+            native_gtd_venues,
+            orders: IndexMap::new(),
+        }
+    }
+
+    /// Returns whether the given venue supports `GTD` orders natively.
+    #[must_use]
+    pub fn is_native(&self, venue: &Venue) -> bool {
+        self.native_gtd_venues.contains(venue)
+    }
+
+    /// Returns whether the expiry of the given `order` must be managed locally.
+    #[must_use]
+    pub fn requires_management(&self, order: &dyn Order) -> bool {
+        order.time_in_force() == TimeInForce::Gtd
+            && order.expire_time().is_some()
+            && !self.is_native(&order.venue())
+    }
+
+    /// Returns the time in force the given `order` should be sent to the venue with.
+    #[must_use]
+    pub fn venue_time_in_force(&self, order: &dyn Order) -> TimeInForce {
+        if self.requires_management(order) {
+            TimeInForce::Gtc
+        } else {
+            order.time_in_force()
+        }
+    }
+
+    /// Starts managing the expiry of the given `order`, returning the expire time if the
+    /// order requires management.
+    pub fn track(&mut self, order: &dyn Order) -> Option<UnixNanos> {
+        if !self.requires_management(order) || order.is_closed() {
+            return None;
+        }
+        let expire_time_ns = order.expire_time()?;
+        self.orders.insert(
+            order.client_order_id(),
+            GtdExpired {
+                strategy_id: order.strategy_id(),
+                instrument_id: order.instrument_id(),
+                client_order_id: order.client_order_id(),
+                expire_time_ns,
+            },
+        );
+        Some(expire_time_ns)
+    }
+
+    /// Stops managing the expiry of the given order (for instance once it has closed).
+    pub fn untrack(&mut self, client_order_id: &ClientOrderId) -> bool {
+        self.orders.shift_remove(client_order_id).is_some()
+    }
+
+    /// Returns whether the expiry of the given order is being managed.
+    #[must_use]
+    pub fn is_tracked(&self, client_order_id: &ClientOrderId) -> bool {
+        self.orders.contains_key(client_order_id)
+    }
+
+    /// Returns the expire time of the given order if it is being managed.
+    #[must_use]
+    pub fn expire_time_ns(&self, client_order_id: &ClientOrderId) -> Option<UnixNanos> {
+        self.orders
+            .get(client_order_id)
+            .map(|tracked| tracked.expire_time_ns)
+    }
+
+    /// Returns the earliest expire time of all managed orders.
+    #[must_use]
+    pub fn next_expiry_ns(&self) -> Option<UnixNanos> {
+        self.orders
+            .values()
+            .map(|tracked| tracked.expire_time_ns)
+            .min()
+    }
+
+    /// Advances the manager to `ts_now`, returning the orders which have expired (in
+    /// expire time order) and should be canceled.
+    ///
+    /// Expired orders are no longer managed once returned.
+    pub fn advance(&mut self, ts_now: UnixNanos) -> Vec<GtdExpired> {
+        let mut expired: Vec<GtdExpired> = self
+            .orders
+            .values()
+            .filter(|tracked| tracked.expire_time_ns <= ts_now)
+            .copied()
+            .collect();
+        expired.sort_by_key(|tracked| tracked.expire_time_ns);
+        for tracked in &expired {
+            self.orders.shift_remove(&tracked.client_order_id);
+        }
+        expired
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderType, events::order::initialized::OrderInitializedBuilder,
+        orders::limit::LimitOrder, types::price::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn limit_order(
+        client_order_id: &str,
+        instrument_id: &str,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+    ) -> LimitOrder {
+        OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .instrument_id(InstrumentId::from(instrument_id))
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00000")))
+            .time_in_force(time_in_force)
+            .expire_time(expire_time)
+            .build()
+            .unwrap()
+            .into()
+    }
+
+    fn manager() -> GtdExpiryManager {
+        GtdExpiryManager::new(HashSet::from([Venue::from("NATIVE")]))
+    }
+
+    #[rstest]
+    fn test_gtd_expiry_timer_name() {
+        assert_eq!(
+            gtd_expiry_timer_name(&ClientOrderId::from("O-1")),
+            "GTD-EXPIRY:O-1"
+        );
+    }
+
+    #[rstest]
+    fn test_track_only_manages_gtd_orders_on_unsupported_venues() {
+        let mut manager = manager();
+        let managed = limit_order("O-1", "AUD/USD.SIM", TimeInForce::Gtd, Some(1_000));
+        let native = limit_order("O-2", "AUD/USD.NATIVE", TimeInForce::Gtd, Some(1_000));
+        let gtc = limit_order("O-3", "AUD/USD.SIM", TimeInForce::Gtc, None);
+
+        assert_eq!(manager.track(&managed), Some(1_000));
+        assert_eq!(manager.track(&native), None);
+        assert_eq!(manager.track(&gtc), None);
+        assert_eq!(manager.venue_time_in_force(&managed), TimeInForce::Gtc);
+        assert_eq!(manager.venue_time_in_force(&native), TimeInForce::Gtd);
+        assert!(manager.is_tracked(&ClientOrderId::from("O-1")));
+        assert!(!manager.is_tracked(&ClientOrderId::from("O-2")));
+    }
+
+    #[rstest]
+    fn test_advance_returns_expired_orders_in_expiry_order() {
+        let mut manager = manager();
+        let later = limit_order("O-1", "AUD/USD.SIM", TimeInForce::Gtd, Some(2_000));
+        let earlier = limit_order("O-2", "AUD/USD.SIM", TimeInForce::Gtd, Some(1_000));
+        let pending = limit_order("O-3", "AUD/USD.SIM", TimeInForce::Gtd, Some(3_000));
+        manager.track(&later);
+        manager.track(&earlier);
+        manager.track(&pending);
+
+        assert_eq!(manager.next_expiry_ns(), Some(1_000));
+        assert!(manager.advance(999).is_empty());
+
+        let expired = manager.advance(2_000);
+
+        let ids: Vec<ClientOrderId> = expired.iter().map(|e| e.client_order_id).collect();
+        assert_eq!(
+            ids,
+            vec![ClientOrderId::from("O-2"), ClientOrderId::from("O-1")]
+        );
+        assert_eq!(manager.next_expiry_ns(), Some(3_000));
+    }
+
+    #[rstest]
+    fn test_untrack_removes_order() {
+        let mut manager = manager();
+        let order = limit_order("O-1", "AUD/USD.SIM", TimeInForce::Gtd, Some(1_000));
+        manager.track(&order);
+
+        assert!(manager.untrack(&ClientOrderId::from("O-1")));
+        assert!(!manager.untrack(&ClientOrderId::from("O-1")));
+        assert!(manager.advance(1_000).is_empty());
+        assert_eq!(manager.next_expiry_ns(), None);
+    }
+}
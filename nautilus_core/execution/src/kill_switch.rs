@@ -0,0 +1,497 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Kill switch to cancel all open orders and optionally flatten all positions.
+//!
+//! The [`KillSwitch`] is triggered on a drawdown limit breach, a lost venue heartbeat or an
+//! external command. On trigger it determines the cancel and close actions to take across all
+//! venues, and records every step in an audit trail of [`KillSwitchEvent`]s. Once triggered
+//! the kill switch remains latched until explicitly reset.
+
+use std::fmt::Display;
+
+use indexmap::IndexMap;
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{OrderSide, PositionSide, TradingState},
+    identifiers::{
+        client_id::ClientId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, strategy_id::StrategyId, trader_id::TraderId,
+        venue_order_id::VenueOrderId,
+    },
+    orders::base::Order,
+    position::Position,
+    types::quantity::Quantity,
+};
+use ustr::Ustr;
+
+/// Configuration for a [`KillSwitch`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct KillSwitchConfig {
+    /// If all open positions should be flattened (in addition to canceling all open orders).
+    pub flatten_positions: bool,
+    /// The maximum drawdown from peak equity as a fraction (e.g. 0.1 for 10%), if any.
+    pub max_drawdown: Option<f64>,
+    /// The maximum duration (nanoseconds) between heartbeats from an execution client, if any.
+    pub heartbeat_timeout_ns: Option<u64>,
+}
+
+/// Represents the reason a [`KillSwitch`] was triggered.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KillSwitchTrigger {
+    /// The drawdown from peak equity breached the configured maximum.
+    DrawdownLimit { drawdown: f64, max_drawdown: f64 },
+    /// No heartbeat was received from an execution client within the configured timeout.
+    HeartbeatLost {
+        client_id: ClientId,
+        last_heartbeat_ns: UnixNanos,
+        timeout_ns: u64,
+    },
+    /// An external command (e.g. from an API or operator).
+    Command { reason: Ustr },
+}
+
+impl Display for KillSwitchTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DrawdownLimit {
+                drawdown,
+                max_drawdown,
+            } => write!(
+                f,
+                "DRAWDOWN_LIMIT: drawdown={drawdown:.4}, max_drawdown={max_drawdown:.4}"
+            ),
+            Self::HeartbeatLost {
+                client_id,
+                last_heartbeat_ns,
+                timeout_ns,
+            } => write!(
+                f,
+                "HEARTBEAT_LOST: client_id={client_id}, last_heartbeat_ns={last_heartbeat_ns}, timeout_ns={timeout_ns}"
+            ),
+            Self::Command { reason } => write!(f, "COMMAND: {reason}"),
+        }
+    }
+}
+
+/// Represents an action to take following a [`KillSwitch`] trigger.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KillSwitchAction {
+    /// Cancel the open order.
+    CancelOrder {
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: Option<VenueOrderId>,
+    },
+    /// Close the open position with a reduce-only market order.
+    ClosePosition {
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        position_id: PositionId,
+        order_side: OrderSide,
+        quantity: Quantity,
+    },
+}
+
+/// Represents the kind of a [`KillSwitchEvent`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KillSwitchEventKind {
+    Triggered(KillSwitchTrigger),
+    ActionIssued(KillSwitchAction),
+    Reset,
+}
+
+/// Represents an entry in the kill switch audit trail.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KillSwitchEvent {
+    pub trader_id: TraderId,
+    pub kind: KillSwitchEventKind,
+    pub event_id: UUID4,
+    pub ts_event: UnixNanos,
+}
+
+/// Provides a latching kill switch which cancels all open orders and optionally flattens all
+/// open positions across venues.
+#[derive(Clone, Debug)]
+pub struct KillSwitch {
+    pub trader_id: TraderId,
+    pub config: KillSwitchConfig,
+    trigger: Option<KillSwitchTrigger>,
+    peak_equity: Option<f64>,
+    heartbeats: IndexMap<ClientId, UnixNanos>,
+    audit_trail: Vec<KillSwitchEvent>,
+}
+
+impl KillSwitch {
+    #[must_use]
+    pub fn new(trader_id: TraderId, config: KillSwitchConfig) -> Self {
+        Self {
+            trader_id,
+            config,
+            trigger: None,
+            peak_equity: None,
+            heartbeats: IndexMap::new(),
+            audit_trail: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_triggered(&self) -> bool {
+        self.trigger.is_some()
+    }
+
+    /// Returns the trigger which latched the kill switch, if triggered.
+    #[must_use]
+    pub fn trigger(&self) -> Option<&KillSwitchTrigger> {
+        self.trigger.as_ref()
+    }
+
+    /// Returns the trading state to apply to the risk engine.
+    ///
+    /// When triggered with `flatten_positions` the state is `REDUCING` so that the closing
+    /// orders are still permitted, otherwise `HALTED`.
+    #[must_use]
+    pub fn trading_state(&self) -> TradingState {
+        match (self.is_triggered(), self.config.flatten_positions) {
+            (false, _) => TradingState::Active,
+            (true, true) => TradingState::Reducing,
+            (true, false) => TradingState::Halted,
+        }
+    }
+
+    #[must_use]
+    pub fn audit_trail(&self) -> &[KillSwitchEvent] {
+        &self.audit_trail
+    }
+
+    /// Records a heartbeat received from the execution client with the given `client_id`.
+    pub fn record_heartbeat(&mut self, client_id: ClientId, ts_event: UnixNanos) {
+        self.heartbeats.insert(client_id, ts_event);
+    }
+
+    /// Updates the account equity and returns a trigger if the drawdown from peak equity
+    /// breaches the configured maximum.
+    pub fn update_equity(&mut self, equity: f64) -> Option<KillSwitchTrigger> {
+        let peak = self.peak_equity.map_or(equity, |peak| peak.max(equity));
+        self.peak_equity = Some(peak);
+
+        let max_drawdown = self.config.max_drawdown?;
+        if peak <= 0.0 {
+            return None;
+        }
+
+        let drawdown = (peak - equity) / peak;
+        (drawdown > max_drawdown).then_some(KillSwitchTrigger::DrawdownLimit {
+            drawdown,
+            max_drawdown,
+        })
+    }
+
+    /// Returns a trigger for the first execution client whose last heartbeat is older than
+    /// the configured timeout as at `ts_now`.
+    #[must_use]
+    pub fn check_heartbeats(&self, ts_now: UnixNanos) -> Option<KillSwitchTrigger> {
+        let timeout_ns = self.config.heartbeat_timeout_ns?;
+        self.heartbeats
+            .iter()
+            .find(|(_, last)| ts_now.saturating_sub(**last) > timeout_ns)
+            .map(|(client_id, last)| KillSwitchTrigger::HeartbeatLost {
+                client_id: *client_id,
+                last_heartbeat_ns: *last,
+                timeout_ns,
+            })
+    }
+
+    /// Triggers the kill switch and returns the actions to take for the given open `orders`
+    /// and `positions`.
+    ///
+    /// If the kill switch is already triggered then no actions are returned (the original
+    /// trigger is retained). Closed orders and positions are skipped, and positions are only
+    /// closed if `flatten_positions` is configured.
+    pub fn fire(
+        &mut self,
+        trigger: KillSwitchTrigger,
+        orders: &[&dyn Order],
+        positions: &[&Position],
+        ts_event: UnixNanos,
+    ) -> Vec<KillSwitchAction> {
+        if self.is_triggered() {
+            return Vec::new();
+        }
+
+        self.trigger = Some(trigger);
+        self.record(KillSwitchEventKind::Triggered(trigger), ts_event);
+
+        let mut actions: Vec<KillSwitchAction> = orders
+            .iter()
+            .filter(|order| order.is_open() || order.is_inflight())
+            .map(|order| KillSwitchAction::CancelOrder {
+                strategy_id: order.strategy_id(),
+                instrument_id: order.instrument_id(),
+                client_order_id: order.client_order_id(),
+                venue_order_id: order.venue_order_id(),
+            })
+            .collect();
+
+        if self.config.flatten_positions {
+            actions.extend(positions.iter().filter_map(|position| {
+                let order_side = match position.side {
+                    PositionSide::Long => OrderSide::Sell,
+                    PositionSide::Short => OrderSide::Buy,
+                    _ => return None,
+                };
+                Some(KillSwitchAction::ClosePosition {
+                    strategy_id: position.strategy_id,
+                    instrument_id: position.instrument_id,
+                    position_id: position.id,
+                    order_side,
+                    quantity: position.quantity,
+                })
+            }));
+        }
+
+        for action in &actions {
+            self.record(KillSwitchEventKind::ActionIssued(*action), ts_event);
+        }
+
+        actions
+    }
+
+    /// Resets the kill switch (re-enabling trading) and clears the peak equity and heartbeats.
+    pub fn reset(&mut self, ts_event: UnixNanos) {
+        self.trigger = None;
+        self.peak_equity = None;
+        self.heartbeats.clear();
+        self.record(KillSwitchEventKind::Reset, ts_event);
+    }
+
+    fn record(&mut self, kind: KillSwitchEventKind, ts_event: UnixNanos) {
+        self.audit_trail.push(KillSwitchEvent {
+            trader_id: self.trader_id,
+            kind,
+            event_id: UUID4::new(),
+            ts_event,
+        });
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        events::order::{
+            accepted::OrderAcceptedBuilder, event::OrderEvent, filled::OrderFilledBuilder,
+            initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+        },
+        identifiers::stubs::trader_id,
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim, Instrument},
+        orders::market::MarketOrder,
+        types::{currency::Currency, money::Money, price::Price},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn accepted_order(client_order_id: &str) -> MarketOrder {
+        let client_order_id = ClientOrderId::from(client_order_id);
+        let mut order: MarketOrder = OrderInitializedBuilder::default()
+            .client_order_id(client_order_id)
+            .build()
+            .unwrap()
+            .into();
+        order
+            .apply(OrderEvent::OrderSubmitted(
+                OrderSubmittedBuilder::default()
+                    .client_order_id(client_order_id)
+                    .build()
+                    .unwrap(),
+            ))
+            .unwrap();
+        order
+            .apply(OrderEvent::OrderAccepted(
+                OrderAcceptedBuilder::default()
+                    .client_order_id(client_order_id)
+                    .build()
+                    .unwrap(),
+            ))
+            .unwrap();
+        order
+    }
+
+    fn position(instrument: CurrencyPair, side: OrderSide) -> Position {
+        let fill = OrderFilledBuilder::default()
+            .instrument_id(instrument.id())
+            .position_id(Some(PositionId::from("P-1")))
+            .order_side(side)
+            .last_qty(Quantity::from(100_000))
+            .last_px(Price::from("1.00000"))
+            .commission(Some(Money::new(0.0, Currency::USD()).unwrap()))
+            .build()
+            .unwrap();
+        Position::new(instrument, fill).unwrap()
+    }
+
+    fn kill_switch(config: KillSwitchConfig) -> KillSwitch {
+        KillSwitch::new(trader_id(), config)
+    }
+
+    #[rstest]
+    fn test_fire_cancels_open_orders_only() {
+        let mut kill_switch = kill_switch(KillSwitchConfig::default());
+        let open = accepted_order("O-1");
+        let initialized: MarketOrder = OrderInitializedBuilder::default()
+            .client_order_id(ClientOrderId::from("O-2"))
+            .build()
+            .unwrap()
+            .into();
+
+        let actions = kill_switch.fire(
+            KillSwitchTrigger::Command {
+                reason: Ustr::from("operator"),
+            },
+            &[&open, &initialized],
+            &[],
+            1,
+        );
+
+        assert_eq!(actions.len(), 1);
+        assert!(matches!(
+            actions[0],
+            KillSwitchAction::CancelOrder { client_order_id, .. }
+                if client_order_id == open.client_order_id()
+        ));
+        assert!(kill_switch.is_triggered());
+        assert_eq!(kill_switch.trading_state(), TradingState::Halted);
+    }
+
+    #[rstest]
+    fn test_fire_flattens_positions_when_configured(audusd_sim: CurrencyPair) {
+        let mut kill_switch = kill_switch(KillSwitchConfig {
+            flatten_positions: true,
+            ..Default::default()
+        });
+        let long = position(audusd_sim, OrderSide::Buy);
+        let short = position(audusd_sim, OrderSide::Sell);
+
+        let actions = kill_switch.fire(
+            KillSwitchTrigger::Command {
+                reason: Ustr::from("operator"),
+            },
+            &[],
+            &[&long, &short],
+            1,
+        );
+
+        let sides: Vec<OrderSide> = actions
+            .iter()
+            .map(|action| match action {
+                KillSwitchAction::ClosePosition { order_side, .. } => *order_side,
+                KillSwitchAction::CancelOrder { .. } => panic!("unexpected cancel"),
+            })
+            .collect();
+        assert_eq!(sides, vec![OrderSide::Sell, OrderSide::Buy]);
+        assert_eq!(kill_switch.trading_state(), TradingState::Reducing);
+    }
+
+    #[rstest]
+    fn test_fire_when_already_triggered_returns_no_actions() {
+        let mut kill_switch = kill_switch(KillSwitchConfig::default());
+        let order = accepted_order("O-1");
+        let first = KillSwitchTrigger::Command {
+            reason: Ustr::from("first"),
+        };
+        kill_switch.fire(first, &[&order], &[], 1);
+
+        let actions = kill_switch.fire(
+            KillSwitchTrigger::Command {
+                reason: Ustr::from("second"),
+            },
+            &[&order],
+            &[],
+            2,
+        );
+
+        assert!(actions.is_empty());
+        assert_eq!(kill_switch.trigger(), Some(&first));
+    }
+
+    #[rstest]
+    fn test_update_equity_triggers_on_drawdown_breach() {
+        let mut kill_switch = kill_switch(KillSwitchConfig {
+            max_drawdown: Some(0.1),
+            ..Default::default()
+        });
+
+        assert!(kill_switch.update_equity(100_000.0).is_none());
+        assert!(kill_switch.update_equity(120_000.0).is_none());
+        assert!(kill_switch.update_equity(110_000.0).is_none());
+        let trigger = kill_switch.update_equity(100_000.0).unwrap();
+
+        assert_eq!(
+            trigger.to_string(),
+            "DRAWDOWN_LIMIT: drawdown=0.1667, max_drawdown=0.1000"
+        );
+    }
+
+    #[rstest]
+    fn test_check_heartbeats_triggers_on_timeout() {
+        let mut kill_switch = kill_switch(KillSwitchConfig {
+            heartbeat_timeout_ns: Some(5_000),
+            ..Default::default()
+        });
+        kill_switch.record_heartbeat(ClientId::from("BINANCE"), 1_000);
+        kill_switch.record_heartbeat(ClientId::from("DYDX"), 4_000);
+
+        assert!(kill_switch.check_heartbeats(6_000).is_none());
+        assert_eq!(
+            kill_switch.check_heartbeats(6_001),
+            Some(KillSwitchTrigger::HeartbeatLost {
+                client_id: ClientId::from("BINANCE"),
+                last_heartbeat_ns: 1_000,
+                timeout_ns: 5_000,
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_audit_trail_records_trigger_actions_and_reset() {
+        let mut kill_switch = kill_switch(KillSwitchConfig::default());
+        let order = accepted_order("O-1");
+        let trigger = KillSwitchTrigger::Command {
+            reason: Ustr::from("operator"),
+        };
+        let actions = kill_switch.fire(trigger, &[&order], &[], 1);
+        kill_switch.reset(2);
+
+        let kinds: Vec<KillSwitchEventKind> = kill_switch
+            .audit_trail()
+            .iter()
+            .map(|event| event.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                KillSwitchEventKind::Triggered(trigger),
+                KillSwitchEventKind::ActionIssued(actions[0]),
+                KillSwitchEventKind::Reset,
+            ]
+        );
+        assert!(!kill_switch.is_triggered());
+        assert_eq!(kill_switch.trading_state(), TradingState::Active);
+    }
+}
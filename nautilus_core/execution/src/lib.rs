@@ -13,4 +13,15 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod algorithm;
+pub mod contingency;
+pub mod emulator;
+pub mod gtd;
+pub mod kill_switch;
 pub mod matching_core;
+pub mod oms;
+pub mod reconciliation;
+pub mod reports;
+pub mod sequencer;
+pub mod sor;
+pub mod trailing;
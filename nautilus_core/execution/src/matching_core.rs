@@ -26,8 +26,14 @@ use nautilus_model::{
     },
     types::price::Price,
 };
+use tracing::{debug, instrument};
+
+use crate::trailing::{trailing_stop_calculate, TrailingStopUpdate};
 
 /// Provides a generic order matching core.
+///
+/// Order operations are recorded as `tracing` spans carrying the `client_order_id`, so
+/// an order can be followed from submission through to fill by an exporting subscriber.
 pub struct OrderMatchingCore {
     /// The instrument ID for the matching core.
     pub instrument_id: InstrumentId,
@@ -96,6 +102,11 @@ impl OrderMatchingCore {
         self.orders_ask.clear();
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(instrument_id = %self.instrument_id, client_order_id = %order.get_client_order_id()),
+    )]
     pub fn add_order(&mut self, order: PassiveOrderType) -> Result<(), OrderError> {
         match order.get_order_side() {
             OrderSideFixed::Buy => {
@@ -109,6 +120,11 @@ impl OrderMatchingCore {
         }
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(instrument_id = %self.instrument_id, client_order_id = %order.get_client_order_id()),
+    )]
     pub fn delete_order(&mut self, order: &PassiveOrderType) -> Result<(), OrderError> {
         match order.get_order_side() {
             OrderSideFixed::Buy => {
@@ -151,6 +167,55 @@ impl OrderMatchingCore {
         }
     }
 
+    /// Trails the trigger and limit prices of all trailing stop orders from the current
+    /// bid, ask and last prices, returning the updates which were applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a market price required by an order trigger type is not available.
+    pub fn update_trailing_stop_orders(&mut self) -> anyhow::Result<Vec<TrailingStopUpdate>> {
+        let (bid, ask, last) = (self.bid, self.ask, self.last);
+        let price_increment = self.price_increment;
+        let mut updates = Vec::new();
+
+        for order in self.orders_bid.iter_mut().chain(self.orders_ask.iter_mut()) {
+            let (client_order_id, trigger_price, price) = match order {
+                PassiveOrderType::Stop(StopOrderType::TrailingStopMarket(o)) => {
+                    let (trigger_price, _) =
+                        trailing_stop_calculate(price_increment, &*o, bid, ask, last)?;
+                    if let Some(trigger_price) = trigger_price {
+                        o.trigger_price = trigger_price;
+                    }
+                    (o.client_order_id, trigger_price, None)
+                }
+                PassiveOrderType::Stop(StopOrderType::TrailingStopLimit(o))
+                | PassiveOrderType::Limit(LimitOrderType::TrailingStopLimit(o)) => {
+                    let (trigger_price, price) =
+                        trailing_stop_calculate(price_increment, &*o, bid, ask, last)?;
+                    if let Some(trigger_price) = trigger_price {
+                        o.trigger_price = trigger_price;
+                    }
+                    if let Some(price) = price {
+                        o.price = price;
+                    }
+                    (o.client_order_id, trigger_price, price)
+                }
+                _ => continue,
+            };
+
+            if trigger_price.is_some() || price.is_some() {
+                debug!("Trailing stop order {client_order_id} updated");
+                updates.push(TrailingStopUpdate {
+                    client_order_id,
+                    trigger_price,
+                    price,
+                });
+            }
+        }
+
+        Ok(updates)
+    }
+
     // -- MATCHING --------------------------------------------------------------------------------
 
     fn match_order(&self, order: &PassiveOrderType, _initial: bool) {
@@ -160,17 +225,29 @@ impl OrderMatchingCore {
         }
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(instrument_id = %self.instrument_id, client_order_id = %order.get_client_order_id()),
+    )]
     pub fn match_limit_order(&self, order: &LimitOrderType) {
         if self.is_limit_matched(order) {
             if let Some(func) = self.fill_limit_order {
+                debug!("Limit order matched");
                 func(order.clone()); // TODO: Remove this clone (will need a lifetime)
             }
         }
     }
 
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(instrument_id = %self.instrument_id, client_order_id = %order.get_client_order_id()),
+    )]
     pub fn match_stop_order(&self, order: &StopOrderType) {
         if self.is_stop_matched(order) {
             if let Some(func) = self.trigger_stop_order {
+                debug!("Stop order triggered");
                 func(order.clone()); // TODO: Remove this clone (will need a lifetime)
             }
         }
@@ -201,7 +278,10 @@ mod tests {
     use std::sync::Mutex;
 
     use nautilus_model::{
-        enums::OrderSide, orders::stubs::TestOrderStubs, types::quantity::Quantity,
+        enums::{OrderSide, OrderType, TrailingOffsetType, TriggerType},
+        events::order::initialized::OrderInitializedBuilder,
+        orders::{stubs::TestOrderStubs, trailing_stop_market::TrailingStopMarketOrder},
+        types::quantity::Quantity,
     };
     use rstest::rstest;
 
@@ -556,4 +636,50 @@ mod tests {
         assert_eq!(filled_limits.len(), 1);
         assert_eq!(filled_limits[0], LimitOrderType::Limit(order));
     }
+
+    #[rstest]
+    fn test_update_trailing_stop_orders() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut matching_core = create_matching_core(instrument_id, Price::from("0.01"));
+
+        let order: TrailingStopMarketOrder = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .order_side(OrderSide::Sell)
+            .order_type(OrderType::TrailingStopMarket)
+            .trigger_price(Some(Price::from("90.00")))
+            .trigger_type(Some(TriggerType::LastTrade))
+            .trailing_offset(Some(Price::from("1.00")))
+            .trailing_offset_type(Some(TrailingOffsetType::Price))
+            .build()
+            .unwrap()
+            .into();
+        let client_order_id = order.client_order_id;
+        matching_core
+            .add_order(PassiveOrderType::Stop(StopOrderType::TrailingStopMarket(
+                order,
+            )))
+            .unwrap();
+        matching_core.last = Some(Price::from("100.00"));
+
+        let updates = matching_core.update_trailing_stop_orders().unwrap();
+
+        assert_eq!(
+            updates,
+            vec![TrailingStopUpdate {
+                client_order_id,
+                trigger_price: Some(Price::from("99.00")),
+                price: None,
+            }]
+        );
+        match &matching_core.get_orders_ask()[0] {
+            PassiveOrderType::Stop(order) => {
+                assert_eq!(order.get_stop_px(), Price::from("99.00"));
+            }
+            PassiveOrderType::Limit(_) => panic!("Expected stop order"),
+        }
+        assert!(matching_core
+            .update_trailing_stop_orders()
+            .unwrap()
+            .is_empty());
+    }
 }
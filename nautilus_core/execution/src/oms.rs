@@ -0,0 +1,311 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Position ID routing for `NETTING` and `HEDGING` order management systems.
+//!
+//! The router determines which position a fill applies to. Under `NETTING` there is a single
+//! position per instrument and strategy, whereas under `HEDGING` each position has its own ID,
+//! either assigned by the venue or generated as a virtual position ID by Nautilus.
+
+use std::collections::HashMap;
+
+use nautilus_common::generators::position_id::PositionIdGenerator;
+use nautilus_model::{
+    enums::OmsType,
+    events::order::filled::OrderFilled,
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, position_id::PositionId,
+        strategy_id::StrategyId,
+    },
+};
+use tracing::{debug, error};
+
+/// Provides position ID assignment for order fills.
+pub struct PositionIdRouter {
+    generator: PositionIdGenerator,
+    order_position_ids: HashMap<ClientOrderId, PositionId>,
+    spawn_position_ids: HashMap<ClientOrderId, PositionId>,
+}
+
+impl PositionIdRouter {
+    #[must_use]
+    pub fn new(generator: PositionIdGenerator) -> Self {
+        Self {
+            generator,
+            order_position_ids: HashMap::new(),
+            spawn_position_ids: HashMap::new(),
+        }
+    }
+
+    /// Returns the position ID assigned to the order with the given `client_order_id`.
+    #[must_use]
+    pub fn position_id(&self, client_order_id: &ClientOrderId) -> Option<PositionId> {
+        self.order_position_ids.get(client_order_id).copied()
+    }
+
+    /// Indexes the `position_id` for the order (and its execution spawn, if any), e.g. when
+    /// loading state from the cache or when a position ID was specified on order submission.
+    pub fn add_position_id(
+        &mut self,
+        client_order_id: ClientOrderId,
+        position_id: PositionId,
+        exec_spawn_id: Option<ClientOrderId>,
+    ) {
+        self.order_position_ids.insert(client_order_id, position_id);
+        if let Some(exec_spawn_id) = exec_spawn_id {
+            self.spawn_position_ids
+                .entry(exec_spawn_id)
+                .or_insert(position_id);
+        }
+    }
+
+    /// Determines the position ID for the `fill` based on the `oms_type`, and indexes it
+    /// against the order.
+    ///
+    /// A position ID already indexed for the order takes precedence over any position ID
+    /// assigned to the fill, except when a `HEDGING` venue reports its own position ID for an
+    /// order indexed against a virtual position, in which case the venue position ID is
+    /// reconciled into the index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `oms_type` is `Unspecified`.
+    pub fn determine_position_id(
+        &mut self,
+        fill: &OrderFilled,
+        oms_type: OmsType,
+        exec_spawn_id: Option<ClientOrderId>,
+    ) -> anyhow::Result<PositionId> {
+        if let Some(position_id) = self.position_id(&fill.client_order_id) {
+            match fill.position_id {
+                Some(venue_position_id)
+                    if oms_type == OmsType::Hedging
+                        && position_id.is_virtual()
+                        && !venue_position_id.is_virtual() =>
+                {
+                    debug!("Reconciled {position_id} to venue {venue_position_id}");
+                    self.reconcile(position_id, venue_position_id);
+                    return Ok(venue_position_id);
+                }
+                Some(assigned) if assigned != position_id => {
+                    error!(
+                        "Incorrect position ID assigned to fill: cached={position_id}, assigned={assigned}, re-assigning from cache"
+                    );
+                }
+                _ => {}
+            }
+            return Ok(position_id);
+        }
+
+        let position_id = match oms_type {
+            OmsType::Hedging => self.hedging_position_id(fill, exec_spawn_id),
+            OmsType::Netting => netting_position_id(&fill.instrument_id, &fill.strategy_id),
+            OmsType::Unspecified => anyhow::bail!("Invalid `OmsType`, was {oms_type}"),
+        };
+
+        self.add_position_id(fill.client_order_id, position_id, exec_spawn_id);
+        Ok(position_id)
+    }
+
+    /// Returns the position ID for the remainder of a `fill` which flips a position.
+    ///
+    /// Virtual `HEDGING` positions are flipped into a newly generated position ID, otherwise
+    /// the position ID of the fill is retained (or the netted position ID if none).
+    pub fn flip_position_id(&mut self, fill: &OrderFilled, oms_type: OmsType) -> PositionId {
+        match (oms_type, fill.position_id) {
+            (OmsType::Hedging, Some(position_id)) if !position_id.is_virtual() => position_id,
+            (OmsType::Hedging, _) => self.generator.generate(fill.strategy_id, true),
+            _ => fill
+                .position_id
+                .unwrap_or_else(|| netting_position_id(&fill.instrument_id, &fill.strategy_id)),
+        }
+    }
+
+    /// Resets the router to its initial state.
+    pub fn reset(&mut self) {
+        self.generator.reset();
+        self.order_position_ids.clear();
+        self.spawn_position_ids.clear();
+    }
+
+    fn hedging_position_id(
+        &mut self,
+        fill: &OrderFilled,
+        exec_spawn_id: Option<ClientOrderId>,
+    ) -> PositionId {
+        if let Some(position_id) = fill.position_id {
+            return position_id; // Already assigned
+        }
+
+        if let Some(position_id) = exec_spawn_id.and_then(|id| self.spawn_position_ids.get(&id)) {
+            return *position_id;
+        }
+
+        self.generator.generate(fill.strategy_id, false)
+    }
+
+    fn reconcile(&mut self, virtual_id: PositionId, venue_id: PositionId) {
+        self.order_position_ids
+            .values_mut()
+            .chain(self.spawn_position_ids.values_mut())
+            .filter(|position_id| **position_id == virtual_id)
+            .for_each(|position_id| *position_id = venue_id);
+    }
+}
+
+/// Returns the netted position ID for the given instrument and strategy.
+#[must_use]
+pub fn netting_position_id(instrument_id: &InstrumentId, strategy_id: &StrategyId) -> PositionId {
+    PositionId::from(format!("{instrument_id}-{strategy_id}").as_str())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::time::get_atomic_clock_static;
+    use nautilus_model::{
+        events::order::filled::OrderFilledBuilder, identifiers::trader_id::TraderId,
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn router() -> PositionIdRouter {
+        let generator =
+            PositionIdGenerator::new(TraderId::from("TRADER-001"), get_atomic_clock_static());
+        PositionIdRouter::new(generator)
+    }
+
+    fn fill(client_order_id: &str, position_id: Option<&str>) -> OrderFilled {
+        OrderFilledBuilder::default()
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .strategy_id(StrategyId::from("S-001"))
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .position_id(position_id.map(PositionId::from))
+            .build()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_netting_assigns_instrument_strategy_position_id(mut router: PositionIdRouter) {
+        let position_id1 = router
+            .determine_position_id(&fill("O-1", None), OmsType::Netting, None)
+            .unwrap();
+        let position_id2 = router
+            .determine_position_id(&fill("O-2", None), OmsType::Netting, None)
+            .unwrap();
+
+        assert_eq!(position_id1, PositionId::from("AUD/USD.SIM-S-001"));
+        assert_eq!(position_id2, position_id1);
+        assert_eq!(
+            router.position_id(&ClientOrderId::from("O-2")),
+            Some(position_id1)
+        );
+    }
+
+    #[rstest]
+    fn test_hedging_generates_position_id_per_order(mut router: PositionIdRouter) {
+        let position_id1 = router
+            .determine_position_id(&fill("O-1", None), OmsType::Hedging, None)
+            .unwrap();
+        let position_id2 = router
+            .determine_position_id(&fill("O-2", None), OmsType::Hedging, None)
+            .unwrap();
+
+        assert_eq!(position_id1, PositionId::from("P-19700101-0000-001-001-1"));
+        assert_eq!(position_id2, PositionId::from("P-19700101-0000-001-001-2"));
+    }
+
+    #[rstest]
+    fn test_hedging_uses_venue_position_id(mut router: PositionIdRouter) {
+        let position_id = router
+            .determine_position_id(&fill("O-1", Some("SIM-001")), OmsType::Hedging, None)
+            .unwrap();
+
+        assert_eq!(position_id, PositionId::from("SIM-001"));
+    }
+
+    #[rstest]
+    fn test_subsequent_fills_use_indexed_position_id(mut router: PositionIdRouter) {
+        let position_id1 = router
+            .determine_position_id(&fill("O-1", None), OmsType::Hedging, None)
+            .unwrap();
+        let position_id2 = router
+            .determine_position_id(&fill("O-1", Some("P-OTHER")), OmsType::Hedging, None)
+            .unwrap();
+
+        assert_eq!(position_id2, position_id1);
+    }
+
+    #[rstest]
+    fn test_hedging_spawned_orders_share_position_id(mut router: PositionIdRouter) {
+        let primary_id = ClientOrderId::from("O-1");
+        let position_id1 = router
+            .determine_position_id(&fill("O-1-E1", None), OmsType::Hedging, Some(primary_id))
+            .unwrap();
+        let position_id2 = router
+            .determine_position_id(&fill("O-1-E2", None), OmsType::Hedging, Some(primary_id))
+            .unwrap();
+
+        assert_eq!(position_id2, position_id1);
+    }
+
+    #[rstest]
+    fn test_hedging_reconciles_virtual_to_venue_position_id(mut router: PositionIdRouter) {
+        let virtual_id = router
+            .determine_position_id(&fill("O-1", None), OmsType::Hedging, None)
+            .unwrap();
+        router.add_position_id(ClientOrderId::from("O-2"), virtual_id, None);
+
+        let position_id = router
+            .determine_position_id(&fill("O-1", Some("SIM-001")), OmsType::Hedging, None)
+            .unwrap();
+
+        assert_eq!(position_id, PositionId::from("SIM-001"));
+        assert_eq!(
+            router.position_id(&ClientOrderId::from("O-2")),
+            Some(PositionId::from("SIM-001"))
+        );
+    }
+
+    #[rstest]
+    fn test_unspecified_oms_type_returns_error(mut router: PositionIdRouter) {
+        assert!(router
+            .determine_position_id(&fill("O-1", None), OmsType::Unspecified, None)
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_flip_position_id(mut router: PositionIdRouter) {
+        let virtual_fill = fill("O-1", Some("P-19700101-0000-001-001-1"));
+        let venue_fill = fill("O-2", Some("SIM-001"));
+
+        assert_eq!(
+            router.flip_position_id(&virtual_fill, OmsType::Hedging),
+            PositionId::from("P-19700101-0000-001-001-1F")
+        );
+        assert_eq!(
+            router.flip_position_id(&venue_fill, OmsType::Hedging),
+            PositionId::from("SIM-001")
+        );
+        assert_eq!(
+            router.flip_position_id(&fill("O-3", None), OmsType::Netting),
+            PositionId::from("AUD/USD.SIM-S-001")
+        );
+    }
+}
@@ -0,0 +1,905 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Reconciliation of cached execution state against venue execution reports.
+//!
+//! The reconciler diffs an [`ExecutionMassStatus`] against the cached orders and
+//! positions, generating the inferred order events required to bring the cache in line
+//! with the venue, and flagging any discrepancies which cannot be reconciled.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderStatus, OrderType},
+    events::order::{
+        accepted::OrderAccepted, canceled::OrderCanceled, event::OrderEvent, expired::OrderExpired,
+        filled::OrderFilled, rejected::OrderRejected, triggered::OrderTriggered,
+        updated::OrderUpdated,
+    },
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, position_id::PositionId,
+        trade_id::TradeId, trader_id::TraderId, venue_order_id::VenueOrderId,
+    },
+    instruments::Instrument,
+    orders::base::Order,
+    position::Position,
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+use ustr::Ustr;
+
+use crate::reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport};
+
+/// The tolerance when comparing reported and cached position quantities.
+const QTY_TOLERANCE: f64 = 1e-9;
+
+/// Represents a discrepancy between the venue and the cache which cannot be reconciled
+/// automatically.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReconciliationDiscrepancy {
+    /// A reported order was not found in the cache (external order).
+    OrderNotFound {
+        venue_order_id: VenueOrderId,
+        client_order_id: Option<ClientOrderId>,
+    },
+    /// More than one order report was received for the same client order ID.
+    DuplicateClientOrderId { client_order_id: ClientOrderId },
+    /// More than one fill report was received for the same trade ID.
+    DuplicateTradeId { trade_id: TradeId },
+    /// The instrument for a reported order has not been added to the reconciler.
+    InstrumentNotFound { instrument_id: InstrumentId },
+    /// The cached filled quantity of an order exceeds the reported filled quantity.
+    FilledQtyExceedsReported {
+        client_order_id: ClientOrderId,
+        filled_qty: Quantity,
+        reported_filled_qty: Quantity,
+    },
+    /// A fill must be inferred but the report has no price to infer it from.
+    MissingFillPrice { client_order_id: ClientOrderId },
+    /// A fill was reported for an order which was not found in the cache.
+    FillOrderNotFound {
+        venue_order_id: VenueOrderId,
+        trade_id: TradeId,
+    },
+    /// The net quantity of a cached position differs from the reported position.
+    PositionMismatch {
+        instrument_id: InstrumentId,
+        venue_position_id: Option<PositionId>,
+        cached_qty: f64,
+        reported_qty: f64,
+    },
+}
+
+/// The result of reconciling an [`ExecutionMassStatus`].
+#[derive(Clone, Debug, Default)]
+pub struct ReconciliationResult {
+    /// The inferred events to apply, in order.
+    pub events: Vec<OrderEvent>,
+    /// The discrepancies which could not be reconciled.
+    pub discrepancies: Vec<ReconciliationDiscrepancy>,
+}
+
+impl ReconciliationResult {
+    /// Returns whether the cache was fully reconciled with the venue.
+    #[must_use]
+    pub fn is_reconciled(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+/// The local view of an order while its inferred events are generated.
+struct OrderState<'a> {
+    order: &'a dyn Order,
+    status: OrderStatus,
+    filled_qty: Quantity,
+    notional: f64,
+    trade_ids: HashSet<TradeId>,
+}
+
+impl<'a> OrderState<'a> {
+    fn new(order: &'a dyn Order) -> Self {
+        Self {
+            order,
+            status: order.status(),
+            filled_qty: order.filled_qty(),
+            notional: order.avg_px().unwrap_or(0.0) * order.filled_qty().as_f64(),
+            trade_ids: order.trade_ids().into_iter().copied().collect(),
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        matches!(
+            self.status,
+            OrderStatus::Accepted
+                | OrderStatus::Triggered
+                | OrderStatus::PendingCancel
+                | OrderStatus::PendingUpdate
+                | OrderStatus::PartiallyFilled
+        )
+    }
+}
+
+/// Provides execution state reconciliation between the cache and trading venues.
+///
+/// Reconciliation should be performed at startup, and then periodically while
+/// [`ExecutionReconciler::is_due`] (if an interval is configured).
+#[derive(Clone, Debug)]
+pub struct ExecutionReconciler {
+    pub trader_id: TraderId,
+    /// The interval (nanoseconds) between periodic reconciliations.
+    pub interval_ns: Option<u64>,
+    instruments: HashMap<InstrumentId, (Currency, u8)>,
+    last_reconciled_ns: Option<UnixNanos>,
+}
+
+impl ExecutionReconciler {
+    #[must_use]
+    pub fn new(trader_id: TraderId, interval_ns: Option<u64>) -> Self {
+        Self {
+            trader_id,
+            interval_ns,
+            instruments: HashMap::new(),
+            last_reconciled_ns: None,
+        }
+    }
+
+    /// Adds the given `instrument` for pricing inferred fills.
+    pub fn add_instrument(&mut self, instrument: &dyn Instrument) {
+        self.instruments.insert(
+            instrument.id(),
+            (instrument.quote_currency(), instrument.price_precision()),
+        );
+    }
+
+    /// Returns whether a reconciliation is due at `ts_now`.
+    #[must_use]
+    pub fn is_due(&self, ts_now: UnixNanos) -> bool {
+        match (self.last_reconciled_ns, self.interval_ns) {
+            (None, _) => true,
+            (Some(last), Some(interval_ns)) => ts_now >= last + interval_ns,
+            (Some(_), None) => false,
+        }
+    }
+
+    /// Reconciles the given `mass_status` against the cached `orders` and open `positions`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if an inferred event fails validation.
+    pub fn reconcile_mass_status(
+        &mut self,
+        mass_status: &ExecutionMassStatus,
+        orders: &[&dyn Order],
+        positions: &[&Position],
+    ) -> Result<ReconciliationResult> {
+        self.last_reconciled_ns = Some(mass_status.ts_init);
+
+        let mut result = ReconciliationResult::default();
+        let orders_by_id: HashMap<ClientOrderId, &dyn Order> = orders
+            .iter()
+            .map(|order| (order.client_order_id(), *order))
+            .collect();
+        let client_order_ids: HashMap<VenueOrderId, ClientOrderId> = orders
+            .iter()
+            .filter_map(|order| Some((order.venue_order_id()?, order.client_order_id())))
+            .collect();
+
+        let mut reconciled_orders: HashSet<ClientOrderId> = HashSet::new();
+        let mut reconciled_trades: HashSet<TradeId> = HashSet::new();
+
+        for (venue_order_id, report) in mass_status.order_reports() {
+            let fills = mass_status
+                .fill_reports()
+                .get(venue_order_id)
+                .map_or(&[][..], Vec::as_slice);
+            for fill in fills {
+                if !reconciled_trades.insert(fill.trade_id) {
+                    result
+                        .discrepancies
+                        .push(ReconciliationDiscrepancy::DuplicateTradeId {
+                            trade_id: fill.trade_id,
+                        });
+                }
+            }
+
+            let client_order_id = report
+                .client_order_id
+                .or_else(|| client_order_ids.get(venue_order_id).copied());
+            let Some(order) = client_order_id.and_then(|id| orders_by_id.get(&id)) else {
+                result
+                    .discrepancies
+                    .push(ReconciliationDiscrepancy::OrderNotFound {
+                        venue_order_id: *venue_order_id,
+                        client_order_id,
+                    });
+                continue;
+            };
+            if !reconciled_orders.insert(order.client_order_id()) {
+                result
+                    .discrepancies
+                    .push(ReconciliationDiscrepancy::DuplicateClientOrderId {
+                        client_order_id: order.client_order_id(),
+                    });
+                continue;
+            }
+
+            self.reconcile_order_report(*order, report, fills, mass_status.ts_init, &mut result)?;
+        }
+
+        // Fills for orders without a status report
+        for (venue_order_id, fills) in mass_status.fill_reports() {
+            if mass_status.order_reports().contains_key(venue_order_id) {
+                continue;
+            }
+            let client_order_id = client_order_ids.get(venue_order_id).copied();
+            for fill in fills {
+                let order = fill
+                    .client_order_id
+                    .or(client_order_id)
+                    .and_then(|id| orders_by_id.get(&id));
+                let Some(order) = order else {
+                    result
+                        .discrepancies
+                        .push(ReconciliationDiscrepancy::FillOrderNotFound {
+                            venue_order_id: *venue_order_id,
+                            trade_id: fill.trade_id,
+                        });
+                    continue;
+                };
+                let mut state = OrderState::new(*order);
+                self.reconcile_fill_report(&mut state, fill, mass_status.ts_init, &mut result)?;
+            }
+        }
+
+        for reports in mass_status.position_reports().values() {
+            for report in reports {
+                Self::reconcile_position_report(report, positions, &mut result);
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn reconcile_order_report(
+        &self,
+        order: &dyn Order,
+        report: &OrderStatusReport,
+        fills: &[FillReport],
+        ts_init: UnixNanos,
+        result: &mut ReconciliationResult,
+    ) -> Result<()> {
+        if !self.instruments.contains_key(&order.instrument_id()) {
+            result
+                .discrepancies
+                .push(ReconciliationDiscrepancy::InstrumentNotFound {
+                    instrument_id: order.instrument_id(),
+                });
+            return Ok(());
+        }
+
+        let mut state = OrderState::new(order);
+        let trader_id = self.trader_id;
+        let strategy_id = order.strategy_id();
+        let instrument_id = order.instrument_id();
+        let client_order_id = order.client_order_id();
+
+        match report.order_status {
+            OrderStatus::Rejected => {
+                if state.status != OrderStatus::Rejected {
+                    result
+                        .events
+                        .push(OrderEvent::OrderRejected(OrderRejected::new(
+                            trader_id,
+                            strategy_id,
+                            instrument_id,
+                            client_order_id,
+                            report.account_id,
+                            Ustr::from("UNKNOWN"),
+                            UUID4::new(),
+                            report.ts_last,
+                            ts_init,
+                            true,
+                        )?));
+                }
+                return Ok(());
+            }
+            OrderStatus::Accepted => {
+                if state.status != OrderStatus::Accepted {
+                    self.accept(&mut state, report, ts_init, result)?;
+                }
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        // Order must have been accepted from this point
+        if matches!(
+            state.status,
+            OrderStatus::Initialized | OrderStatus::Submitted
+        ) {
+            self.accept(&mut state, report, ts_init, result)?;
+        }
+
+        if report.quantity != order.quantity()
+            || (report.price.is_some() && report.price != order.price())
+            || (report.trigger_price.is_some() && report.trigger_price != order.trigger_price())
+        {
+            result
+                .events
+                .push(OrderEvent::OrderUpdated(OrderUpdated::new(
+                    trader_id,
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    report.quantity,
+                    UUID4::new(),
+                    report.ts_last,
+                    ts_init,
+                    true,
+                    Some(report.venue_order_id),
+                    Some(report.account_id),
+                    report.price,
+                    report.trigger_price,
+                )?));
+        }
+
+        match report.order_status {
+            OrderStatus::Triggered => {
+                if state.status != OrderStatus::Triggered {
+                    result.events.push(self.triggered(&state, report, ts_init)?);
+                }
+            }
+            OrderStatus::Canceled => {
+                if state.status != OrderStatus::Canceled && state.is_open() {
+                    if report.ts_triggered > 0 {
+                        result.events.push(self.triggered(&state, report, ts_init)?);
+                    }
+                    for fill in fills {
+                        self.reconcile_fill_report(&mut state, fill, ts_init, result)?;
+                    }
+                    result
+                        .events
+                        .push(OrderEvent::OrderCanceled(OrderCanceled::new(
+                            trader_id,
+                            strategy_id,
+                            instrument_id,
+                            client_order_id,
+                            UUID4::new(),
+                            report.ts_last,
+                            ts_init,
+                            true,
+                            Some(report.venue_order_id),
+                            Some(report.account_id),
+                        )?));
+                }
+            }
+            OrderStatus::Expired => {
+                if state.status != OrderStatus::Expired && state.is_open() {
+                    if report.ts_triggered > 0 {
+                        result.events.push(self.triggered(&state, report, ts_init)?);
+                    }
+                    result
+                        .events
+                        .push(OrderEvent::OrderExpired(OrderExpired::new(
+                            trader_id,
+                            strategy_id,
+                            instrument_id,
+                            client_order_id,
+                            UUID4::new(),
+                            report.ts_last,
+                            ts_init,
+                            true,
+                            Some(report.venue_order_id),
+                            Some(report.account_id),
+                        )?));
+                }
+            }
+            _ => {
+                // Order has some fills from this point
+                for fill in fills {
+                    self.reconcile_fill_report(&mut state, fill, ts_init, result)?;
+                }
+                self.reconcile_filled_qty(&mut state, report, ts_init, result)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn reconcile_fill_report(
+        &self,
+        state: &mut OrderState,
+        report: &FillReport,
+        ts_init: UnixNanos,
+        result: &mut ReconciliationResult,
+    ) -> Result<()> {
+        if state.trade_ids.contains(&report.trade_id) {
+            return Ok(()); // Fill already applied (assumes consistent trades)
+        }
+        let Some((currency, _)) = self.instruments.get(&state.order.instrument_id()) else {
+            result
+                .discrepancies
+                .push(ReconciliationDiscrepancy::InstrumentNotFound {
+                    instrument_id: state.order.instrument_id(),
+                });
+            return Ok(());
+        };
+
+        let filled = OrderFilled::new(
+            self.trader_id,
+            state.order.strategy_id(),
+            state.order.instrument_id(),
+            state.order.client_order_id(),
+            report.venue_order_id,
+            report.account_id,
+            report.trade_id,
+            state.order.side(),
+            state.order.order_type(),
+            report.last_qty,
+            report.last_px,
+            *currency,
+            report.liquidity_side,
+            UUID4::new(),
+            report.ts_event,
+            ts_init,
+            true,
+            report.venue_position_id,
+            report.commission,
+        )?;
+        result.events.push(Self::apply_fill(state, filled));
+        Ok(())
+    }
+
+    fn reconcile_filled_qty(
+        &self,
+        state: &mut OrderState,
+        report: &OrderStatusReport,
+        ts_init: UnixNanos,
+        result: &mut ReconciliationResult,
+    ) -> Result<()> {
+        let client_order_id = state.order.client_order_id();
+        if report.filled_qty == state.filled_qty {
+            return Ok(());
+        }
+        if report.filled_qty < state.filled_qty {
+            result
+                .discrepancies
+                .push(ReconciliationDiscrepancy::FilledQtyExceedsReported {
+                    client_order_id,
+                    filled_qty: state.filled_qty,
+                    reported_filled_qty: report.filled_qty,
+                });
+            return Ok(());
+        }
+
+        // Infer a fill for the missing quantity. There may be some information loss here
+        // if multiple fills occurred to reach the reported state.
+        let (currency, price_precision) = self.instruments[&state.order.instrument_id()];
+        let last_qty = report.filled_qty - state.filled_qty;
+        let last_px = match (report.avg_px, report.price) {
+            (Some(avg_px), _) => {
+                (avg_px * report.filled_qty.as_f64() - state.notional) / last_qty.as_f64()
+            }
+            (None, Some(price)) => price.as_f64(),
+            (None, None) => {
+                result
+                    .discrepancies
+                    .push(ReconciliationDiscrepancy::MissingFillPrice { client_order_id });
+                return Ok(());
+            }
+        };
+        let liquidity_side = match state.order.order_type() {
+            OrderType::Market | OrderType::StopMarket | OrderType::TrailingStopMarket => {
+                LiquiditySide::Taker
+            }
+            _ if report.post_only => LiquiditySide::Maker,
+            _ => LiquiditySide::NoLiquiditySide,
+        };
+
+        let filled = OrderFilled::new(
+            self.trader_id,
+            state.order.strategy_id(),
+            state.order.instrument_id(),
+            client_order_id,
+            report.venue_order_id,
+            report.account_id,
+            TradeId::new(&UUID4::new().to_string())?,
+            state.order.side(),
+            state.order.order_type(),
+            last_qty,
+            Price::new(last_px, price_precision)?,
+            currency,
+            liquidity_side,
+            UUID4::new(),
+            report.ts_last,
+            ts_init,
+            true,
+            None,
+            None,
+        )?;
+        result.events.push(Self::apply_fill(state, filled));
+        Ok(())
+    }
+
+    fn reconcile_position_report(
+        report: &PositionStatusReport,
+        positions: &[&Position],
+        result: &mut ReconciliationResult,
+    ) {
+        let cached_qty: f64 = positions
+            .iter()
+            .filter(|position| position.instrument_id == report.instrument_id)
+            .filter(|position| {
+                report
+                    .venue_position_id
+                    .map_or(true, |position_id| position.id == position_id)
+            })
+            .filter(|position| position.is_open())
+            .map(|position| position.signed_qty)
+            .sum();
+        let reported_qty = report.signed_qty();
+        if (cached_qty - reported_qty).abs() > QTY_TOLERANCE {
+            result
+                .discrepancies
+                .push(ReconciliationDiscrepancy::PositionMismatch {
+                    instrument_id: report.instrument_id,
+                    venue_position_id: report.venue_position_id,
+                    cached_qty,
+                    reported_qty,
+                });
+        }
+    }
+
+    fn accept(
+        &self,
+        state: &mut OrderState,
+        report: &OrderStatusReport,
+        ts_init: UnixNanos,
+        result: &mut ReconciliationResult,
+    ) -> Result<()> {
+        result
+            .events
+            .push(OrderEvent::OrderAccepted(OrderAccepted::new(
+                self.trader_id,
+                state.order.strategy_id(),
+                state.order.instrument_id(),
+                state.order.client_order_id(),
+                report.venue_order_id,
+                report.account_id,
+                UUID4::new(),
+                report.ts_accepted,
+                ts_init,
+                true,
+            )?));
+        state.status = OrderStatus::Accepted;
+        Ok(())
+    }
+
+    fn triggered(
+        &self,
+        state: &OrderState,
+        report: &OrderStatusReport,
+        ts_init: UnixNanos,
+    ) -> Result<OrderEvent> {
+        Ok(OrderEvent::OrderTriggered(OrderTriggered::new(
+            self.trader_id,
+            state.order.strategy_id(),
+            state.order.instrument_id(),
+            state.order.client_order_id(),
+            UUID4::new(),
+            report.ts_triggered,
+            ts_init,
+            true,
+            Some(report.venue_order_id),
+            Some(report.account_id),
+        )?))
+    }
+
+    fn apply_fill(state: &mut OrderState, filled: OrderFilled) -> OrderEvent {
+        state.trade_ids.insert(filled.trade_id);
+        state.filled_qty += filled.last_qty;
+        state.notional += filled.last_px.as_f64() * filled.last_qty.as_f64();
+        if state.filled_qty >= state.order.quantity() {
+            state.status = OrderStatus::Filled;
+            OrderEvent::OrderFilled(filled)
+        } else {
+            state.status = OrderStatus::PartiallyFilled;
+            OrderEvent::OrderPartiallyFilled(filled)
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, PositionSide, TimeInForce},
+        events::order::{
+            accepted::OrderAcceptedBuilder, initialized::OrderInitializedBuilder,
+            submitted::OrderSubmittedBuilder,
+        },
+        identifiers::{account_id::AccountId, client_id::ClientId, stubs::trader_id, venue::Venue},
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::market::MarketOrder,
+        types::money::Money,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn market_order(instrument: &CurrencyPair, accepted: bool) -> MarketOrder {
+        let client_order_id = ClientOrderId::from("O-1");
+        let mut order: MarketOrder = OrderInitializedBuilder::default()
+            .instrument_id(instrument.id)
+            .client_order_id(client_order_id)
+            .build()
+            .unwrap()
+            .into();
+        order
+            .apply(OrderEvent::OrderSubmitted(
+                OrderSubmittedBuilder::default()
+                    .instrument_id(instrument.id)
+                    .client_order_id(client_order_id)
+                    .build()
+                    .unwrap(),
+            ))
+            .unwrap();
+        if accepted {
+            order
+                .apply(OrderEvent::OrderAccepted(
+                    OrderAcceptedBuilder::default()
+                        .instrument_id(instrument.id)
+                        .client_order_id(client_order_id)
+                        .venue_order_id(VenueOrderId::from("V-1"))
+                        .build()
+                        .unwrap(),
+                ))
+                .unwrap();
+        }
+        order
+    }
+
+    fn order_report(
+        instrument: &CurrencyPair,
+        order_status: OrderStatus,
+        filled_qty: i64,
+        avg_px: Option<f64>,
+    ) -> OrderStatusReport {
+        OrderStatusReport {
+            account_id: AccountId::from("SIM-001"),
+            instrument_id: instrument.id,
+            client_order_id: Some(ClientOrderId::from("O-1")),
+            venue_order_id: VenueOrderId::from("V-1"),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::Day,
+            order_status,
+            quantity: Quantity::from(100_000),
+            filled_qty: Quantity::from(filled_qty),
+            price: None,
+            trigger_price: None,
+            avg_px,
+            post_only: false,
+            report_id: UUID4::new(),
+            ts_accepted: 1,
+            ts_triggered: 0,
+            ts_last: 2,
+            ts_init: 3,
+        }
+    }
+
+    fn fill_report(
+        instrument: &CurrencyPair,
+        venue_order_id: &str,
+        trade_id: &str,
+        last_qty: i64,
+    ) -> FillReport {
+        FillReport {
+            account_id: AccountId::from("SIM-001"),
+            instrument_id: instrument.id,
+            client_order_id: None,
+            venue_order_id: VenueOrderId::from(venue_order_id),
+            venue_position_id: None,
+            trade_id: TradeId::from(trade_id),
+            order_side: OrderSide::Buy,
+            last_qty: Quantity::from(last_qty),
+            last_px: Price::from("1.00000"),
+            commission: Some(Money::new(0.0, Currency::USD()).unwrap()),
+            liquidity_side: LiquiditySide::Taker,
+            report_id: UUID4::new(),
+            ts_event: 2,
+            ts_init: 3,
+        }
+    }
+
+    fn mass_status() -> ExecutionMassStatus {
+        ExecutionMassStatus::new(
+            ClientId::from("SIM"),
+            AccountId::from("SIM-001"),
+            Venue::from("SIM"),
+            UUID4::new(),
+            3,
+        )
+    }
+
+    fn reconciler(instrument: &CurrencyPair) -> ExecutionReconciler {
+        let mut reconciler = ExecutionReconciler::new(trader_id(), Some(10));
+        reconciler.add_instrument(instrument);
+        reconciler
+    }
+
+    #[rstest]
+    fn test_reconcile_infers_missing_fill(audusd_sim: CurrencyPair) {
+        let order = market_order(&audusd_sim, true);
+        let mut mass_status = mass_status();
+        mass_status.add_order_reports(vec![order_report(
+            &audusd_sim,
+            OrderStatus::Filled,
+            100_000,
+            Some(1.00006),
+        )]);
+        mass_status.add_fill_reports(vec![fill_report(&audusd_sim, "V-1", "T-1", 40_000)]);
+
+        let result = reconciler(&audusd_sim)
+            .reconcile_mass_status(&mass_status, &[&order], &[])
+            .unwrap();
+
+        assert!(result.is_reconciled());
+        assert_eq!(result.events.len(), 2);
+        let OrderEvent::OrderPartiallyFilled(reported) = &result.events[0] else {
+            panic!("Expected partial fill, was {:?}", result.events[0]);
+        };
+        assert_eq!(reported.trade_id, TradeId::from("T-1"));
+        let OrderEvent::OrderFilled(inferred) = &result.events[1] else {
+            panic!("Expected fill, was {:?}", result.events[1]);
+        };
+        assert_eq!(inferred.last_qty, Quantity::from(60_000));
+        assert_eq!(inferred.last_px, Price::from("1.00010"));
+        assert_eq!(inferred.liquidity_side, LiquiditySide::Taker);
+        assert!(inferred.reconciliation);
+    }
+
+    #[rstest]
+    fn test_reconcile_canceled_order_infers_accepted_then_canceled(audusd_sim: CurrencyPair) {
+        let order = market_order(&audusd_sim, false);
+        let mut mass_status = mass_status();
+        mass_status.add_order_reports(vec![order_report(
+            &audusd_sim,
+            OrderStatus::Canceled,
+            0,
+            None,
+        )]);
+
+        let result = reconciler(&audusd_sim)
+            .reconcile_mass_status(&mass_status, &[&order], &[])
+            .unwrap();
+
+        assert!(result.is_reconciled());
+        assert!(matches!(
+            result.events.as_slice(),
+            [OrderEvent::OrderAccepted(_), OrderEvent::OrderCanceled(_)]
+        ));
+    }
+
+    #[rstest]
+    fn test_reconcile_flags_unknown_orders_and_fills(audusd_sim: CurrencyPair) {
+        let mut mass_status = mass_status();
+        let mut report = order_report(&audusd_sim, OrderStatus::Accepted, 0, None);
+        report.client_order_id = None;
+        mass_status.add_order_reports(vec![report]);
+        mass_status.add_fill_reports(vec![
+            fill_report(&audusd_sim, "V-1", "T-1", 10_000),
+            fill_report(&audusd_sim, "V-1", "T-1", 10_000),
+            fill_report(&audusd_sim, "V-2", "T-2", 10_000),
+        ]);
+
+        let result = reconciler(&audusd_sim)
+            .reconcile_mass_status(&mass_status, &[], &[])
+            .unwrap();
+
+        assert!(result.events.is_empty());
+        assert_eq!(
+            result.discrepancies,
+            vec![
+                ReconciliationDiscrepancy::DuplicateTradeId {
+                    trade_id: TradeId::from("T-1")
+                },
+                ReconciliationDiscrepancy::OrderNotFound {
+                    venue_order_id: VenueOrderId::from("V-1"),
+                    client_order_id: None,
+                },
+                ReconciliationDiscrepancy::FillOrderNotFound {
+                    venue_order_id: VenueOrderId::from("V-2"),
+                    trade_id: TradeId::from("T-2"),
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, None)]
+    #[case(PositionSide::Short, Some(-100_000.0))]
+    fn test_reconcile_netting_position(
+        audusd_sim: CurrencyPair,
+        #[case] position_side: PositionSide,
+        #[case] expected_reported_qty: Option<f64>,
+    ) {
+        let fill = OrderFilled::new(
+            trader_id(),
+            Default::default(),
+            audusd_sim.id,
+            ClientOrderId::from("O-1"),
+            VenueOrderId::from("V-1"),
+            AccountId::from("SIM-001"),
+            TradeId::from("T-1"),
+            OrderSide::Buy,
+            OrderType::Market,
+            Quantity::from(100_000),
+            Price::from("1.00000"),
+            Currency::USD(),
+            LiquiditySide::Taker,
+            UUID4::new(),
+            1,
+            1,
+            false,
+            Some(PositionId::from("P-1")),
+            Some(Money::new(0.0, Currency::USD()).unwrap()),
+        )
+        .unwrap();
+        let position = Position::new(audusd_sim, fill).unwrap();
+        let mut mass_status = mass_status();
+        mass_status.add_position_reports(vec![PositionStatusReport {
+            account_id: AccountId::from("SIM-001"),
+            instrument_id: audusd_sim.id,
+            venue_position_id: None,
+            position_side,
+            quantity: Quantity::from(100_000),
+            report_id: UUID4::new(),
+            ts_last: 2,
+            ts_init: 3,
+        }]);
+
+        let result = reconciler(&audusd_sim)
+            .reconcile_mass_status(&mass_status, &[], &[&position])
+            .unwrap();
+
+        let expected: Vec<ReconciliationDiscrepancy> = expected_reported_qty
+            .map(|reported_qty| ReconciliationDiscrepancy::PositionMismatch {
+                instrument_id: audusd_sim.id,
+                venue_position_id: None,
+                cached_qty: 100_000.0,
+                reported_qty,
+            })
+            .into_iter()
+            .collect();
+        assert_eq!(result.discrepancies, expected);
+    }
+
+    #[rstest]
+    fn test_is_due_after_interval(audusd_sim: CurrencyPair) {
+        let mut reconciler = reconciler(&audusd_sim);
+        assert!(reconciler.is_due(0));
+
+        reconciler
+            .reconcile_mass_status(&mass_status(), &[], &[])
+            .unwrap();
+
+        assert!(!reconciler.is_due(12));
+        assert!(reconciler.is_due(13));
+    }
+}
@@ -0,0 +1,174 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Execution state reports received from trading venues.
+
+use indexmap::IndexMap;
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide, OrderStatus, OrderType, PositionSide, TimeInForce},
+    identifiers::{
+        account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+        instrument_id::InstrumentId, position_id::PositionId, trade_id::TradeId, venue::Venue,
+        venue_order_id::VenueOrderId,
+    },
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+/// Represents an order status at a point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderStatusReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    /// The client order ID (`None` for external orders).
+    pub client_order_id: Option<ClientOrderId>,
+    pub venue_order_id: VenueOrderId,
+    pub order_side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    pub order_status: OrderStatus,
+    pub quantity: Quantity,
+    pub filled_qty: Quantity,
+    pub price: Option<Price>,
+    pub trigger_price: Option<Price>,
+    pub avg_px: Option<f64>,
+    pub post_only: bool,
+    pub report_id: UUID4,
+    pub ts_accepted: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the order was triggered (zero if not triggered).
+    pub ts_triggered: UnixNanos,
+    pub ts_last: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+/// Represents a report of a single order fill.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FillReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    /// The client order ID (`None` for external orders).
+    pub client_order_id: Option<ClientOrderId>,
+    pub venue_order_id: VenueOrderId,
+    pub venue_position_id: Option<PositionId>,
+    pub trade_id: TradeId,
+    pub order_side: OrderSide,
+    pub last_qty: Quantity,
+    pub last_px: Price,
+    pub commission: Option<Money>,
+    pub liquidity_side: LiquiditySide,
+    pub report_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+/// Represents a position status at a point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionStatusReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    /// The venue position ID (`None` for `NETTING` venues).
+    pub venue_position_id: Option<PositionId>,
+    pub position_side: PositionSide,
+    pub quantity: Quantity,
+    pub report_id: UUID4,
+    pub ts_last: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl PositionStatusReport {
+    /// Returns the signed quantity of the position (negative for `SHORT`).
+    #[must_use]
+    pub fn signed_qty(&self) -> f64 {
+        match self.position_side {
+            PositionSide::Short => -self.quantity.as_f64(),
+            _ => self.quantity.as_f64(),
+        }
+    }
+}
+
+/// Represents an execution mass status report for an execution client, including
+/// the status of all orders, fills and positions at the venue.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecutionMassStatus {
+    pub client_id: ClientId,
+    pub account_id: AccountId,
+    pub venue: Venue,
+    pub report_id: UUID4,
+    pub ts_init: UnixNanos,
+    order_reports: IndexMap<VenueOrderId, OrderStatusReport>,
+    fill_reports: IndexMap<VenueOrderId, Vec<FillReport>>,
+    position_reports: IndexMap<InstrumentId, Vec<PositionStatusReport>>,
+}
+
+impl ExecutionMassStatus {
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        account_id: AccountId,
+        venue: Venue,
+        report_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            client_id,
+            account_id,
+            venue,
+            report_id,
+            ts_init,
+            order_reports: IndexMap::new(),
+            fill_reports: IndexMap::new(),
+            position_reports: IndexMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn order_reports(&self) -> &IndexMap<VenueOrderId, OrderStatusReport> {
+        &self.order_reports
+    }
+
+    #[must_use]
+    pub fn fill_reports(&self) -> &IndexMap<VenueOrderId, Vec<FillReport>> {
+        &self.fill_reports
+    }
+
+    #[must_use]
+    pub fn position_reports(&self) -> &IndexMap<InstrumentId, Vec<PositionStatusReport>> {
+        &self.position_reports
+    }
+
+    pub fn add_order_reports(&mut self, reports: Vec<OrderStatusReport>) {
+        for report in reports {
+            self.order_reports.insert(report.venue_order_id, report);
+        }
+    }
+
+    pub fn add_fill_reports(&mut self, reports: Vec<FillReport>) {
+        for report in reports {
+            self.fill_reports
+                .entry(report.venue_order_id)
+                .or_default()
+                .push(report);
+        }
+    }
+
+    pub fn add_position_reports(&mut self, reports: Vec<PositionStatusReport>) {
+        for report in reports {
+            self.position_reports
+                .entry(report.instrument_id)
+                .or_default()
+                .push(report);
+        }
+    }
+}
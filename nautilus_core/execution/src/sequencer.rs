@@ -0,0 +1,313 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Deduplication and sequencing of order events received from execution clients.
+//!
+//! Adapters can replay fills on reconnect, and venues can deliver status updates out of
+//! order (for instance a fill ahead of the acceptance). The [`ExecEventSequencer`] drops
+//! duplicate fills keyed on `(venue_order_id, trade_id)`, and holds back events which are
+//! not yet a valid transition for the order FSM until the events preceding them arrive.
+
+use std::collections::{HashMap, HashSet};
+
+use nautilus_model::{
+    enums::OrderStatus,
+    events::order::event::OrderEvent,
+    identifiers::{
+        client_order_id::ClientOrderId, trade_id::TradeId, venue_order_id::VenueOrderId,
+    },
+    orders::{base::Order, fsm::transition},
+};
+use tracing::{debug, warn};
+
+/// The default maximum number of events held back per order.
+pub const DEFAULT_MAX_PENDING: usize = 32;
+
+#[derive(Clone, Copy, Debug)]
+struct SequencedOrder {
+    status: OrderStatus,
+    previous_status: Option<OrderStatus>,
+}
+
+/// Provides a deduplication and sequencing layer ahead of the order FSM.
+#[derive(Debug)]
+pub struct ExecEventSequencer {
+    max_pending: usize,
+    fills: HashSet<(VenueOrderId, TradeId)>,
+    orders: HashMap<ClientOrderId, SequencedOrder>,
+    pending: HashMap<ClientOrderId, Vec<OrderEvent>>,
+    duplicate_count: usize,
+    stale_count: usize,
+}
+
+impl Default for ExecEventSequencer {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_PENDING)
+    }
+}
+
+impl ExecEventSequencer {
+    #[must_use]
+    pub fn new(max_pending: usize) -> Self {
+        Self {
+            max_pending,
+            fills: HashSet::new(),
+            orders: HashMap::new(),
+            pending: HashMap::new(),
+            duplicate_count: 0,
+            stale_count: 0,
+        }
+    }
+
+    /// Returns the count of duplicate fills dropped.
+    #[must_use]
+    pub fn duplicate_count(&self) -> usize {
+        self.duplicate_count
+    }
+
+    /// Returns the count of held back events discarded once their order closed.
+    #[must_use]
+    pub fn stale_count(&self) -> usize {
+        self.stale_count
+    }
+
+    /// Returns the count of events currently held back for the given order.
+    #[must_use]
+    pub fn pending_count(&self, client_order_id: &ClientOrderId) -> usize {
+        self.pending.get(client_order_id).map_or(0, Vec::len)
+    }
+
+    /// Registers the given `order` (with its current status and applied fills) for sequencing.
+    pub fn register_order(&mut self, order: &dyn Order) {
+        self.orders.insert(
+            order.client_order_id(),
+            SequencedOrder {
+                status: order.status(),
+                previous_status: None,
+            },
+        );
+        if let Some(venue_order_id) = order.venue_order_id() {
+            for trade_id in order.trade_ids() {
+                self.fills.insert((venue_order_id, *trade_id));
+            }
+        }
+    }
+
+    /// Processes the given `event`, returning the events (in order) which are ready to be
+    /// applied to the order.
+    ///
+    /// Duplicate fills are dropped, and events for orders which have not been registered
+    /// are passed straight through.
+    pub fn process(&mut self, event: OrderEvent) -> Vec<OrderEvent> {
+        if let OrderEvent::OrderPartiallyFilled(fill) | OrderEvent::OrderFilled(fill) = &event {
+            if !self.fills.insert((fill.venue_order_id, fill.trade_id)) {
+                self.duplicate_count += 1;
+                warn!(
+                    "Dropping duplicate fill {} for {}",
+                    fill.trade_id, fill.venue_order_id
+                );
+                return Vec::new();
+            }
+        }
+
+        let client_order_id = event.client_order_id();
+        let Some(order) = self.orders.get_mut(&client_order_id) else {
+            return vec![event];
+        };
+
+        if !Self::advance(order, &event) {
+            if is_closed(order.status) {
+                // Events behind a closed order can never be applied
+                self.stale_count += 1;
+                return Vec::new();
+            }
+            let pending = self.pending.entry(client_order_id).or_default();
+            if pending.len() >= self.max_pending {
+                // Release the oldest event for the engine to handle as an invalid transition
+                let oldest = pending.remove(0);
+                pending.push(event);
+                return vec![oldest];
+            }
+            debug!("Holding back out-of-order event for {client_order_id}");
+            pending.push(event);
+            return Vec::new();
+        }
+
+        let mut released = vec![event];
+        if let Some(mut pending) = self.pending.remove(&client_order_id) {
+            // Replay held back events until no further event can be applied
+            while let Some(index) = pending
+                .iter()
+                .position(|event| transition(order.status, order.previous_status, event).is_ok())
+            {
+                let event = pending.remove(index);
+                Self::advance(order, &event);
+                released.push(event);
+            }
+            if is_closed(order.status) {
+                self.stale_count += pending.len();
+            } else if !pending.is_empty() {
+                self.pending.insert(client_order_id, pending);
+            }
+        }
+        released
+    }
+
+    /// Deregisters the given order, discarding any events held back for it.
+    pub fn deregister_order(&mut self, client_order_id: &ClientOrderId) {
+        self.orders.remove(client_order_id);
+        self.pending.remove(client_order_id);
+    }
+
+    fn advance(order: &mut SequencedOrder, event: &OrderEvent) -> bool {
+        let Ok(next) = transition(order.status, order.previous_status, event) else {
+            return false;
+        };
+        if !matches!(
+            order.status,
+            OrderStatus::PendingUpdate | OrderStatus::PendingCancel
+        ) {
+            order.previous_status = Some(order.status);
+        }
+        order.status = next;
+        true
+    }
+}
+
+fn is_closed(status: OrderStatus) -> bool {
+    matches!(
+        status,
+        OrderStatus::Denied
+            | OrderStatus::Rejected
+            | OrderStatus::Canceled
+            | OrderStatus::Expired
+            | OrderStatus::Filled
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        events::order::{
+            accepted::OrderAcceptedBuilder, filled::OrderFilledBuilder,
+            initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+            triggered::OrderTriggeredBuilder,
+        },
+        orders::market::MarketOrder,
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn submitted_order() -> MarketOrder {
+        let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
+        order
+            .apply(OrderEvent::OrderSubmitted(
+                OrderSubmittedBuilder::default().build().unwrap(),
+            ))
+            .unwrap();
+        order
+    }
+
+    fn accepted() -> OrderEvent {
+        OrderEvent::OrderAccepted(OrderAcceptedBuilder::default().build().unwrap())
+    }
+
+    fn triggered() -> OrderEvent {
+        OrderEvent::OrderTriggered(OrderTriggeredBuilder::default().build().unwrap())
+    }
+
+    fn partial_fill(trade_id: &str) -> OrderEvent {
+        OrderEvent::OrderPartiallyFilled(
+            OrderFilledBuilder::default()
+                .trade_id(TradeId::from(trade_id))
+                .last_qty(Quantity::from(10_000))
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn sequencer() -> ExecEventSequencer {
+        let mut sequencer = ExecEventSequencer::default();
+        sequencer.register_order(&submitted_order());
+        sequencer
+    }
+
+    #[rstest]
+    fn test_duplicate_fills_are_dropped() {
+        let mut sequencer = sequencer();
+
+        assert_eq!(sequencer.process(accepted()).len(), 1);
+        assert_eq!(sequencer.process(partial_fill("T-1")).len(), 1);
+        assert!(sequencer.process(partial_fill("T-1")).is_empty());
+        assert_eq!(sequencer.process(partial_fill("T-2")).len(), 1);
+        assert_eq!(sequencer.duplicate_count(), 1);
+    }
+
+    #[rstest]
+    fn test_out_of_order_events_are_held_back_until_valid() {
+        let mut sequencer = sequencer();
+        let client_order_id = ClientOrderId::default();
+
+        let released = sequencer.process(triggered());
+        assert!(released.is_empty());
+        assert_eq!(sequencer.pending_count(&client_order_id), 1);
+
+        let released = sequencer.process(accepted());
+
+        assert!(matches!(
+            released.as_slice(),
+            [OrderEvent::OrderAccepted(_), OrderEvent::OrderTriggered(_)]
+        ));
+        assert_eq!(sequencer.pending_count(&client_order_id), 0);
+    }
+
+    #[rstest]
+    fn test_stale_events_for_closed_orders_are_discarded() {
+        let mut sequencer = sequencer();
+        let filled = OrderEvent::OrderFilled(OrderFilledBuilder::default().build().unwrap());
+
+        assert_eq!(sequencer.process(filled).len(), 1);
+        assert!(sequencer.process(accepted()).is_empty());
+        assert_eq!(sequencer.stale_count(), 1);
+        assert_eq!(sequencer.pending_count(&ClientOrderId::default()), 0);
+    }
+
+    #[rstest]
+    fn test_oldest_event_released_when_pending_is_full() {
+        let mut sequencer = ExecEventSequencer::new(1);
+        sequencer.register_order(&submitted_order());
+
+        assert!(sequencer.process(triggered()).is_empty());
+        let released = sequencer.process(triggered());
+
+        assert!(matches!(
+            released.as_slice(),
+            [OrderEvent::OrderTriggered(_)]
+        ));
+        assert_eq!(sequencer.pending_count(&ClientOrderId::default()), 1);
+    }
+
+    #[rstest]
+    fn test_unregistered_orders_pass_through() {
+        let mut sequencer = ExecEventSequencer::default();
+
+        assert_eq!(sequencer.process(triggered()).len(), 1);
+    }
+}
@@ -0,0 +1,357 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Smart order routing across venues for a fungible instrument.
+//!
+//! The [`SmartOrderRouter`] splits a parent order across the venues listing the same
+//! (fungible) instrument, taking the best available liquidity from the cached order books
+//! after fees. Each venue allocation is rounded down to the venues size increment, and venues
+//! whose allocation would fall below their minimum quantity are excluded and the quantity
+//! re-routed.
+
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use indexmap::IndexMap;
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::instrument_id::InstrumentId,
+    instruments::Instrument,
+    orderbook::book_mbp::OrderBookMbp,
+    types::{price::Price, quantity::Quantity},
+};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+
+/// Represents a venue listing of a fungible instrument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VenueListing {
+    pub instrument_id: InstrumentId,
+    pub size_increment: Quantity,
+    pub min_quantity: Option<Quantity>,
+    /// The taker fee rate for the listing (e.g. 0.001 for 10 bps).
+    pub taker_fee: Decimal,
+}
+
+impl VenueListing {
+    #[must_use]
+    pub fn from_instrument<T: Instrument>(instrument: &T) -> Self {
+        Self {
+            instrument_id: instrument.id(),
+            size_increment: instrument.size_increment(),
+            min_quantity: instrument.min_quantity(),
+            taker_fee: instrument.taker_fee(),
+        }
+    }
+}
+
+/// Represents the quantity routed to a single venue listing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RouteAllocation {
+    pub instrument_id: InstrumentId,
+    pub quantity: Quantity,
+    /// The expected average price (before fees) for the allocation.
+    pub avg_px: Decimal,
+    /// The expected average price after fees for the allocation.
+    pub fee_adjusted_px: Decimal,
+}
+
+/// Represents the routing of a parent order across venue listings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoutePlan {
+    /// The allocations, in order of best fee adjusted price.
+    pub allocations: Vec<RouteAllocation>,
+    /// The quantity which could not be routed (from insufficient liquidity or rounding).
+    pub unallocated: Quantity,
+}
+
+/// Provides a smart order router for a fungible instrument listed on multiple venues.
+#[derive(Clone, Debug, Default)]
+pub struct SmartOrderRouter {
+    listings: Vec<VenueListing>,
+}
+
+impl SmartOrderRouter {
+    #[must_use]
+    pub fn new(listings: Vec<VenueListing>) -> Self {
+        Self { listings }
+    }
+
+    #[must_use]
+    pub fn listings(&self) -> &[VenueListing] {
+        &self.listings
+    }
+
+    /// Routes the `quantity` for the `order_side` across the venue listings using the given
+    /// order `books`, not crossing the `limit_px` (if any).
+    #[must_use]
+    pub fn route(
+        &self,
+        order_side: OrderSide,
+        quantity: Quantity,
+        limit_px: Option<Price>,
+        books: &HashMap<InstrumentId, OrderBookMbp>,
+    ) -> RoutePlan {
+        let mut excluded = HashSet::new();
+        loop {
+            let plan = self.allocate(order_side, quantity, limit_px, books, &excluded);
+            let below_min: Vec<InstrumentId> = plan
+                .allocations
+                .iter()
+                .filter(|allocation| {
+                    self.listing(&allocation.instrument_id)
+                        .and_then(|listing| listing.min_quantity)
+                        .is_some_and(|min_quantity| allocation.quantity < min_quantity)
+                })
+                .map(|allocation| allocation.instrument_id)
+                .collect();
+
+            if below_min.is_empty() {
+                return plan;
+            }
+            excluded.extend(below_min);
+        }
+    }
+
+    fn listing(&self, instrument_id: &InstrumentId) -> Option<&VenueListing> {
+        self.listings
+            .iter()
+            .find(|listing| listing.instrument_id == *instrument_id)
+    }
+
+    fn allocate(
+        &self,
+        order_side: OrderSide,
+        quantity: Quantity,
+        limit_px: Option<Price>,
+        books: &HashMap<InstrumentId, OrderBookMbp>,
+        excluded: &HashSet<InstrumentId>,
+    ) -> RoutePlan {
+        // Collect (fee adjusted price, listing index, price, size) for all eligible levels
+        let mut levels: Vec<(Decimal, usize, Decimal, Decimal)> = Vec::new();
+        for (i, listing) in self.listings.iter().enumerate() {
+            if excluded.contains(&listing.instrument_id) {
+                continue;
+            }
+            let Some(book) = books.get(&listing.instrument_id) else {
+                continue;
+            };
+
+            let book_levels: Vec<_> = match order_side {
+                OrderSide::Buy => book.asks().collect(),
+                OrderSide::Sell => book.bids().collect(),
+                OrderSide::NoOrderSide => Vec::new(),
+            };
+            for level in book_levels {
+                let price = level.price.value;
+                let crosses_limit = limit_px.is_some_and(|limit| match order_side {
+                    OrderSide::Buy => price > limit,
+                    _ => price < limit,
+                });
+                if crosses_limit {
+                    break; // Levels are ordered from best price
+                }
+                let px = price.as_decimal();
+                let fee_adjusted_px = match order_side {
+                    OrderSide::Buy => px * (Decimal::ONE + listing.taker_fee),
+                    _ => px * (Decimal::ONE - listing.taker_fee),
+                };
+                let size = Decimal::from_f64(level.size()).unwrap_or_default();
+                levels.push((fee_adjusted_px, i, px, size));
+            }
+        }
+
+        match order_side {
+            OrderSide::Buy => levels.sort_by_key(|level| level.0),
+            _ => levels.sort_by_key(|level| Reverse(level.0)),
+        }
+
+        // Take the best levels until the quantity is filled
+        let mut remaining = quantity.as_decimal();
+        let mut taken: IndexMap<usize, (Decimal, Decimal, Decimal)> = IndexMap::new();
+        for (fee_adjusted_px, i, px, size) in levels {
+            if remaining <= Decimal::ZERO {
+                break;
+            }
+            let take = remaining.min(size);
+            let entry = taken.entry(i).or_default();
+            entry.0 += take;
+            entry.1 += take * px;
+            entry.2 += take * fee_adjusted_px;
+            remaining -= take;
+        }
+
+        // Round each allocation down to the listings size increment
+        let mut allocations = Vec::with_capacity(taken.len());
+        let mut unallocated = remaining;
+        for (i, (qty, notional, fee_adjusted_notional)) in taken {
+            let listing = &self.listings[i];
+            let increment = listing.size_increment.as_decimal();
+            let rounded = (qty / increment).floor() * increment;
+            unallocated += qty - rounded;
+            if rounded.is_zero() {
+                continue;
+            }
+            allocations.push(RouteAllocation {
+                instrument_id: listing.instrument_id,
+                quantity: Quantity::new(
+                    rounded.to_f64().unwrap(),
+                    listing.size_increment.precision,
+                )
+                .unwrap(),
+                avg_px: notional / qty,
+                fee_adjusted_px: fee_adjusted_notional / qty,
+            });
+        }
+
+        RoutePlan {
+            allocations,
+            unallocated: Quantity::new(unallocated.to_f64().unwrap(), quantity.precision).unwrap(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::order::BookOrder;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn binance() -> InstrumentId {
+        InstrumentId::from("BTCUSDT.BINANCE")
+    }
+
+    fn bybit() -> InstrumentId {
+        InstrumentId::from("BTCUSDT.BYBIT")
+    }
+
+    fn listing(
+        instrument_id: InstrumentId,
+        min_quantity: &str,
+        taker_fee: Decimal,
+    ) -> VenueListing {
+        VenueListing {
+            instrument_id,
+            size_increment: Quantity::from("0.001"),
+            min_quantity: Some(Quantity::from(min_quantity)),
+            taker_fee,
+        }
+    }
+
+    fn book(instrument_id: InstrumentId, asks: &[(&str, &str)]) -> OrderBookMbp {
+        let mut book = OrderBookMbp::new(instrument_id, false);
+        for (i, (price, size)) in asks.iter().enumerate() {
+            let order = BookOrder::new(
+                OrderSide::Sell,
+                Price::from(*price),
+                Quantity::from(*size),
+                i as u64,
+            );
+            book.add(order, 0, i as u64);
+        }
+        book
+    }
+
+    fn books() -> HashMap<InstrumentId, OrderBookMbp> {
+        HashMap::from([
+            (
+                binance(),
+                book(binance(), &[("50000.00", "1.000"), ("50010.00", "2.000")]),
+            ),
+            (
+                bybit(),
+                book(bybit(), &[("49990.00", "0.500"), ("50020.00", "2.000")]),
+            ),
+        ])
+    }
+
+    #[rstest]
+    fn test_route_takes_best_liquidity_across_venues() {
+        let router = SmartOrderRouter::new(vec![
+            listing(binance(), "0.001", dec!(0)),
+            listing(bybit(), "0.001", dec!(0)),
+        ]);
+
+        let plan = router.route(OrderSide::Buy, Quantity::from("2.000"), None, &books());
+
+        assert_eq!(plan.allocations.len(), 2);
+        assert_eq!(plan.allocations[0].instrument_id, bybit());
+        assert_eq!(plan.allocations[0].quantity, Quantity::from("0.500"));
+        assert_eq!(plan.allocations[1].instrument_id, binance());
+        assert_eq!(plan.allocations[1].quantity, Quantity::from("1.500"));
+        assert_eq!(plan.allocations[1].avg_px.round_dp(2), dec!(50003.33));
+        assert!(plan.unallocated.is_zero());
+    }
+
+    #[rstest]
+    fn test_route_accounts_for_fee_differences() {
+        // 10 bps on 49,990 is worse than the 50,000 on a zero fee venue
+        let router = SmartOrderRouter::new(vec![
+            listing(binance(), "0.001", dec!(0)),
+            listing(bybit(), "0.001", dec!(0.001)),
+        ]);
+
+        let plan = router.route(OrderSide::Buy, Quantity::from("1.000"), None, &books());
+
+        assert_eq!(plan.allocations.len(), 1);
+        assert_eq!(plan.allocations[0].instrument_id, binance());
+        assert_eq!(plan.allocations[0].quantity, Quantity::from("1.000"));
+    }
+
+    #[rstest]
+    fn test_route_reroutes_allocations_below_venue_min_quantity() {
+        let router = SmartOrderRouter::new(vec![
+            listing(binance(), "0.001", dec!(0)),
+            listing(bybit(), "1.000", dec!(0)),
+        ]);
+
+        let plan = router.route(OrderSide::Buy, Quantity::from("2.000"), None, &books());
+
+        assert_eq!(plan.allocations.len(), 1);
+        assert_eq!(plan.allocations[0].instrument_id, binance());
+        assert_eq!(plan.allocations[0].quantity, Quantity::from("2.000"));
+    }
+
+    #[rstest]
+    fn test_route_respects_limit_price_and_reports_unallocated() {
+        let router = SmartOrderRouter::new(vec![
+            listing(binance(), "0.001", dec!(0)),
+            listing(bybit(), "0.001", dec!(0)),
+        ]);
+
+        let plan = router.route(
+            OrderSide::Buy,
+            Quantity::from("2.000"),
+            Some(Price::from("50000.00")),
+            &books(),
+        );
+
+        let routed: Vec<Quantity> = plan.allocations.iter().map(|a| a.quantity).collect();
+        assert_eq!(
+            routed,
+            vec![Quantity::from("0.500"), Quantity::from("1.000")]
+        );
+        assert_eq!(plan.unallocated, Quantity::from("0.500"));
+    }
+}
@@ -0,0 +1,401 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trailing stop order price calculations.
+
+use nautilus_model::{
+    enums::{OrderSide, OrderType, TrailingOffsetType, TriggerType},
+    identifiers::client_order_id::ClientOrderId,
+    orders::base::Order,
+    types::price::Price,
+};
+
+/// Represents the updated prices of a trailing stop order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TrailingStopUpdate {
+    /// The client order ID of the updated order.
+    pub client_order_id: ClientOrderId,
+    /// The new trigger price, if the trigger price moved.
+    pub trigger_price: Option<Price>,
+    /// The new limit price, if the limit price moved.
+    pub price: Option<Price>,
+}
+
+/// Calculates the new trigger price and limit price (for trailing stop limit orders) of a
+/// trailing stop `order` from the given market prices.
+///
+/// Prices only ever trail in the favorable direction: down for BUY orders and up for SELL
+/// orders. A `None` is returned for each price which should not move.
+///
+/// The market price the offset is applied to depends on the order trigger type:
+/// - `DEFAULT`, `LAST_TRADE` and `MARK_PRICE` use the `last` price.
+/// - `BID_ASK` uses the `ask` for BUY orders and the `bid` for SELL orders.
+/// - `MID_POINT` uses the mid-point of the `bid` and `ask`.
+/// - `LAST_OR_BID_ASK` uses the `last` price, then the `bid`/`ask`.
+///
+/// # Errors
+///
+/// This function returns an error if:
+/// - The `order` is not a trailing stop order.
+/// - A market price required by the trigger type is `None`.
+/// - The trigger type or trailing offset type is not supported.
+pub fn trailing_stop_calculate(
+    price_increment: Price,
+    order: &dyn Order,
+    bid: Option<Price>,
+    ask: Option<Price>,
+    last: Option<Price>,
+) -> anyhow::Result<(Option<Price>, Option<Price>)> {
+    let order_type = order.order_type();
+    if !matches!(
+        order_type,
+        OrderType::TrailingStopMarket | OrderType::TrailingStopLimit
+    ) {
+        anyhow::bail!("Invalid `OrderType` for trailing stop calculation, was {order_type}");
+    }
+
+    let side = order.side();
+    let offset_type = order
+        .trailing_offset_type()
+        .ok_or_else(|| anyhow::anyhow!("No `trailing_offset_type` for {order_type} order"))?;
+    let trailing_offset = order
+        .trailing_offset()
+        .ok_or_else(|| anyhow::anyhow!("No `trailing_offset` for {order_type} order"))?
+        .as_f64();
+    let limit_offset = match order_type {
+        OrderType::TrailingStopLimit => Some(
+            order
+                .limit_offset()
+                .ok_or_else(|| anyhow::anyhow!("No `limit_offset` for {order_type} order"))?
+                .as_f64(),
+        ),
+        _ => None,
+    };
+
+    let instrument_id = order.instrument_id();
+    let last_price = || {
+        last.map(|p| p.as_f64()).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Cannot process trailing stop, no LAST price for {instrument_id} \
+                (add trade ticks or use bars)"
+            )
+        })
+    };
+    let bid_ask = || match (bid, ask) {
+        (Some(bid), Some(ask)) => Ok((bid.as_f64(), ask.as_f64())),
+        _ => Err(anyhow::anyhow!(
+            "Cannot process trailing stop, no BID or ASK price for {instrument_id} \
+            (add quote ticks or use bars)"
+        )),
+    };
+    let bid_ask_price = || {
+        bid_ask().and_then(|(bid, ask)| match side {
+            OrderSide::Buy => Ok(ask),
+            OrderSide::Sell => Ok(bid),
+            OrderSide::NoOrderSide => anyhow::bail!("Invalid `OrderSide`, was {side}"),
+        })
+    };
+
+    let references = match order.trigger_type().unwrap_or(TriggerType::Default) {
+        TriggerType::Default | TriggerType::LastTrade | TriggerType::MarkPrice => {
+            vec![last_price()?]
+        }
+        TriggerType::BidAsk => vec![bid_ask_price()?],
+        TriggerType::MidPoint => {
+            let (bid, ask) = bid_ask()?;
+            vec![(bid + ask) / 2.0]
+        }
+        TriggerType::LastOrBidAsk => vec![last_price()?, bid_ask_price()?],
+        trigger_type => anyhow::bail!(
+            "Cannot process trailing stop, `TriggerType.{trigger_type}` not currently supported"
+        ),
+    };
+
+    let mut trigger_price = order.trigger_price();
+    let mut price = order.price();
+    let mut new_trigger_price = None;
+    let mut new_price = None;
+
+    for reference in references {
+        let temp_trigger_price = calculate_with_reference(
+            price_increment,
+            offset_type,
+            side,
+            trailing_offset,
+            reference,
+        )?;
+        if is_trailed(side, trigger_price, temp_trigger_price) {
+            trigger_price = Some(temp_trigger_price);
+            new_trigger_price = Some(temp_trigger_price);
+        }
+
+        if let Some(limit_offset) = limit_offset {
+            let temp_price = calculate_with_reference(
+                price_increment,
+                offset_type,
+                side,
+                limit_offset,
+                reference,
+            )?;
+            if is_trailed(side, price, temp_price) {
+                price = Some(temp_price);
+                new_price = Some(temp_price);
+            }
+        }
+    }
+
+    Ok((new_trigger_price, new_price))
+}
+
+fn calculate_with_reference(
+    price_increment: Price,
+    offset_type: TrailingOffsetType,
+    side: OrderSide,
+    offset: f64,
+    reference: f64,
+) -> anyhow::Result<Price> {
+    let offset = match offset_type {
+        TrailingOffsetType::Price => offset,
+        TrailingOffsetType::BasisPoints => reference * (offset / 100.0) / 100.0,
+        TrailingOffsetType::Ticks => offset * price_increment.as_f64(),
+        _ => anyhow::bail!(
+            "Cannot process trailing stop, `TrailingOffsetType` {offset_type} not currently supported"
+        ),
+    };
+
+    match side {
+        OrderSide::Buy => Price::new(reference + offset, price_increment.precision),
+        OrderSide::Sell => Price::new(reference - offset, price_increment.precision),
+        OrderSide::NoOrderSide => anyhow::bail!("Invalid `OrderSide`, was {side}"),
+    }
+}
+
+fn is_trailed(side: OrderSide, current: Option<Price>, candidate: Price) -> bool {
+    match side {
+        OrderSide::Buy => current.map_or(true, |p| p > candidate),
+        OrderSide::Sell => current.map_or(true, |p| p < candidate),
+        OrderSide::NoOrderSide => false,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        events::order::initialized::OrderInitializedBuilder,
+        orders::{
+            market::MarketOrder, trailing_stop_limit::TrailingStopLimitOrder,
+            trailing_stop_market::TrailingStopMarketOrder,
+        },
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn trailing_stop_market(
+        side: OrderSide,
+        trigger_price: &str,
+        trigger_type: TriggerType,
+        trailing_offset: &str,
+        trailing_offset_type: TrailingOffsetType,
+    ) -> TrailingStopMarketOrder {
+        OrderInitializedBuilder::default()
+            .order_side(side)
+            .order_type(OrderType::TrailingStopMarket)
+            .trigger_price(Some(Price::from(trigger_price)))
+            .trigger_type(Some(trigger_type))
+            .trailing_offset(Some(Price::from(trailing_offset)))
+            .trailing_offset_type(Some(trailing_offset_type))
+            .build()
+            .unwrap()
+            .into()
+    }
+
+    #[rstest]
+    fn test_calculate_with_invalid_order_type() {
+        let order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
+
+        let result = trailing_stop_calculate(Price::from("0.01"), &order, None, None, None);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_calculate_with_missing_last_price() {
+        let order = trailing_stop_market(
+            OrderSide::Sell,
+            "90.00",
+            TriggerType::LastTrade,
+            "1.00",
+            TrailingOffsetType::Price,
+        );
+
+        let result = trailing_stop_calculate(Price::from("0.01"), &order, None, None, None);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, TrailingOffsetType::Price, "1.00", Some("99.00"))]
+    #[case(OrderSide::Sell, TrailingOffsetType::BasisPoints, "50", Some("99.50"))]
+    #[case(OrderSide::Sell, TrailingOffsetType::Ticks, "25", Some("99.75"))]
+    #[case(OrderSide::Buy, TrailingOffsetType::Price, "1.00", Some("101.00"))]
+    #[case(OrderSide::Buy, TrailingOffsetType::BasisPoints, "50", Some("100.50"))]
+    #[case(OrderSide::Buy, TrailingOffsetType::Ticks, "25", Some("100.25"))]
+    fn test_calculate_with_last_trade_offset_types(
+        #[case] side: OrderSide,
+        #[case] trailing_offset_type: TrailingOffsetType,
+        #[case] trailing_offset: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let trigger_price = match side {
+            OrderSide::Buy => "110.00",
+            _ => "90.00",
+        };
+        let order = trailing_stop_market(
+            side,
+            trigger_price,
+            TriggerType::LastTrade,
+            trailing_offset,
+            trailing_offset_type,
+        );
+
+        let (trigger_price, price) = trailing_stop_calculate(
+            Price::from("0.01"),
+            &order,
+            None,
+            None,
+            Some(Price::from("100.00")),
+        )
+        .unwrap();
+
+        assert_eq!(trigger_price, expected.map(Price::from));
+        assert_eq!(price, None);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, "99.50")] // Already trailing closer than the new trigger
+    #[case(OrderSide::Buy, "100.50")]
+    fn test_calculate_does_not_move_trigger_price_away(
+        #[case] side: OrderSide,
+        #[case] trigger_price: &str,
+    ) {
+        let order = trailing_stop_market(
+            side,
+            trigger_price,
+            TriggerType::LastTrade,
+            "1.00",
+            TrailingOffsetType::Price,
+        );
+
+        let (trigger_price, price) = trailing_stop_calculate(
+            Price::from("0.01"),
+            &order,
+            None,
+            None,
+            Some(Price::from("100.00")),
+        )
+        .unwrap();
+
+        assert_eq!(trigger_price, None);
+        assert_eq!(price, None);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, TriggerType::BidAsk, "98.00")]
+    #[case(OrderSide::Buy, TriggerType::BidAsk, "103.00")]
+    #[case(OrderSide::Sell, TriggerType::MidPoint, "99.50")]
+    #[case(OrderSide::Buy, TriggerType::MidPoint, "101.50")]
+    fn test_calculate_with_quote_trigger_types(
+        #[case] side: OrderSide,
+        #[case] trigger_type: TriggerType,
+        #[case] expected: &str,
+    ) {
+        let trigger_price = match side {
+            OrderSide::Buy => "110.00",
+            _ => "90.00",
+        };
+        let order = trailing_stop_market(
+            side,
+            trigger_price,
+            trigger_type,
+            "1.00",
+            TrailingOffsetType::Price,
+        );
+
+        let (trigger_price, _) = trailing_stop_calculate(
+            Price::from("0.01"),
+            &order,
+            Some(Price::from("99.00")),
+            Some(Price::from("102.00")),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(trigger_price, Some(Price::from(expected)));
+    }
+
+    #[rstest]
+    fn test_calculate_with_last_or_bid_ask_uses_tightest() {
+        let order = trailing_stop_market(
+            OrderSide::Sell,
+            "90.00",
+            TriggerType::LastOrBidAsk,
+            "1.00",
+            TrailingOffsetType::Price,
+        );
+
+        let (trigger_price, _) = trailing_stop_calculate(
+            Price::from("0.01"),
+            &order,
+            Some(Price::from("100.50")),
+            Some(Price::from("101.00")),
+            Some(Price::from("100.00")),
+        )
+        .unwrap();
+
+        assert_eq!(trigger_price, Some(Price::from("99.50")));
+    }
+
+    #[rstest]
+    fn test_calculate_trailing_stop_limit_trails_price() {
+        let order: TrailingStopLimitOrder = OrderInitializedBuilder::default()
+            .order_side(OrderSide::Sell)
+            .order_type(OrderType::TrailingStopLimit)
+            .price(Some(Price::from("89.00")))
+            .trigger_price(Some(Price::from("90.00")))
+            .trigger_type(Some(TriggerType::LastTrade))
+            .limit_offset(Some(Price::from("2.00")))
+            .trailing_offset(Some(Price::from("1.00")))
+            .trailing_offset_type(Some(TrailingOffsetType::Price))
+            .build()
+            .unwrap()
+            .into();
+
+        let (trigger_price, price) = trailing_stop_calculate(
+            Price::from("0.01"),
+            &order,
+            None,
+            None,
+            Some(Price::from("100.00")),
+        )
+        .unwrap();
+
+        assert_eq!(trigger_price, Some(Price::from("99.00")));
+        assert_eq!(price, Some(Price::from("98.00")));
+    }
+}
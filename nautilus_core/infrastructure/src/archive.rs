@@ -0,0 +1,320 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Snapshot and restore of the entire cache database state as a single compressed archive.
+//!
+//! An archive holds every entry of the cache collections (accounts, orders, positions,
+//! instruments, etc.) and indexes as read from a [`CacheDatabase`], so it can be restored into
+//! any backend. This allows fast warm-starts, and migrating state between Redis and Postgres.
+//!
+//! The archive is a zstd compressed stream of length-prefixed (little-endian) records:
+//!
+//! - header: `ARCHIVE_MAGIC`, version (`u32`), entry count (`u32`).
+//! - each entry: key length (`u32`), key, value count (`u32`), then each value length (`u32`), value.
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use anyhow::{bail, Result};
+
+use crate::cache::{get_collection_key, CacheDatabase, DELIMITER};
+
+/// The leading bytes identifying a cache archive.
+pub const ARCHIVE_MAGIC: &[u8; 8] = b"NTCACHE\0";
+
+/// The current cache archive format version.
+pub const ARCHIVE_VERSION: u32 = 1;
+
+const INDEX: &str = "index";
+
+// Snapshots and health are history rather than state, so are not archived
+const ARCHIVE_COLLECTIONS: [&str; 9] = [
+    "general",
+    "currencies",
+    "instruments",
+    "synthetics",
+    "accounts",
+    "orders",
+    "positions",
+    "actors",
+    "strategies",
+];
+
+const ARCHIVE_INDEX_SETS: [&str; 9] = [
+    "index:order_ids",
+    "index:orders",
+    "index:orders_open",
+    "index:orders_closed",
+    "index:orders_emulated",
+    "index:orders_inflight",
+    "index:positions",
+    "index:positions_open",
+    "index:positions_closed",
+];
+
+// Map indexes are read as a single JSON object of fields to values
+const ARCHIVE_INDEX_MAPS: [&str; 2] = ["index:order_position", "index:order_client"];
+
+/// Represents a single cache database entry within an archive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchiveEntry {
+    /// The entry key as `{collection}:{id}` (without the trader key).
+    pub key: String,
+    /// The entry values as read from the database (e.g. the ordered events of an order).
+    pub values: Vec<Vec<u8>>,
+}
+
+impl ArchiveEntry {
+    #[must_use]
+    pub fn new(key: String, values: Vec<Vec<u8>>) -> Self {
+        Self { key, values }
+    }
+}
+
+/// Exports the entire state for the `trader_key` in the given database to an archive at `path`.
+///
+/// Returns the number of entries archived.
+pub fn export_archive<D: CacheDatabase>(
+    db: &mut D,
+    trader_key: &str,
+    path: &Path,
+) -> Result<usize> {
+    let entries = read_entries(db, trader_key)?;
+    let writer = BufWriter::new(File::create(path)?);
+    write_archive(writer, &entries)?;
+    Ok(entries.len())
+}
+
+/// Restores the state from the archive at `path` into the given database.
+///
+/// The database should be empty (see `flushdb`), as events are appended to any existing orders,
+/// positions and accounts. Writes are queued to the databases background thread as usual.
+///
+/// Returns the number of entries restored.
+pub fn restore_archive<D: CacheDatabase>(db: &mut D, path: &Path) -> Result<usize> {
+    let reader = BufReader::new(File::open(path)?);
+    let entries = read_archive(reader)?;
+    write_entries(db, &entries)?;
+    Ok(entries.len())
+}
+
+/// Reads all archivable entries for the `trader_key` from the given database.
+pub fn read_entries<D: CacheDatabase>(db: &mut D, trader_key: &str) -> Result<Vec<ArchiveEntry>> {
+    let mut entries = Vec::new();
+    let prefix = format!("{trader_key}{DELIMITER}");
+
+    for collection in ARCHIVE_COLLECTIONS {
+        let mut keys = db.keys(&format!("{prefix}{collection}{DELIMITER}*"))?;
+        keys.sort();
+
+        for full_key in keys {
+            let Some(key) = full_key.strip_prefix(&prefix) else {
+                continue; // Belongs to another trader
+            };
+            let values = db.read(key)?;
+            if !values.is_empty() {
+                entries.push(ArchiveEntry::new(key.to_string(), values));
+            }
+        }
+    }
+
+    for key in ARCHIVE_INDEX_SETS.iter().chain(ARCHIVE_INDEX_MAPS.iter()) {
+        let values = db.read(key)?;
+        if !values.is_empty() {
+            entries.push(ArchiveEntry::new(key.to_string(), values));
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Writes the given archive entries into the database.
+pub fn write_entries<D: CacheDatabase>(db: &mut D, entries: &[ArchiveEntry]) -> Result<()> {
+    for entry in entries {
+        let key = &entry.key;
+        if get_collection_key(key)? == INDEX && ARCHIVE_INDEX_MAPS.contains(&key.as_str()) {
+            for value in &entry.values {
+                let map: HashMap<String, String> = serde_json::from_slice(value)?;
+                for (field, value) in map {
+                    db.insert(
+                        key.clone(),
+                        Some(vec![field.into_bytes(), value.into_bytes()]),
+                    )?;
+                }
+            }
+        } else {
+            // Event lists and index sets are inserted value by value, in order
+            for value in &entry.values {
+                db.insert(key.clone(), Some(vec![value.clone()]))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the given entries as a compressed archive.
+pub fn write_archive<W: Write>(writer: W, entries: &[ArchiveEntry]) -> Result<()> {
+    let mut encoder = zstd::Encoder::new(writer, 0)?;
+    encoder.write_all(ARCHIVE_MAGIC)?;
+    encoder.write_all(&ARCHIVE_VERSION.to_le_bytes())?;
+    write_len(&mut encoder, entries.len())?;
+
+    for entry in entries {
+        write_bytes(&mut encoder, entry.key.as_bytes())?;
+        write_len(&mut encoder, entry.values.len())?;
+        for value in &entry.values {
+            write_bytes(&mut encoder, value)?;
+        }
+    }
+
+    encoder.finish()?.flush()?;
+    Ok(())
+}
+
+/// Reads the entries from a compressed archive.
+pub fn read_archive<R: Read>(reader: R) -> Result<Vec<ArchiveEntry>> {
+    let mut decoder = zstd::Decoder::new(reader)?;
+
+    let mut magic = [0u8; 8];
+    decoder.read_exact(&mut magic)?;
+    if &magic != ARCHIVE_MAGIC {
+        bail!("Invalid cache archive, bad magic bytes");
+    }
+
+    let version = read_u32(&mut decoder)?;
+    if version != ARCHIVE_VERSION {
+        bail!("Unsupported cache archive version {version}, expected {ARCHIVE_VERSION}");
+    }
+
+    let count = read_u32(&mut decoder)? as usize;
+    let mut entries = Vec::with_capacity(count);
+
+    for _ in 0..count {
+        let key = String::from_utf8(read_bytes(&mut decoder)?)?;
+        let value_count = read_u32(&mut decoder)? as usize;
+        let values = (0..value_count)
+            .map(|_| read_bytes(&mut decoder))
+            .collect::<Result<Vec<_>>>()?;
+        entries.push(ArchiveEntry::new(key, values));
+    }
+
+    Ok(entries)
+}
+
+fn write_len<W: Write>(writer: &mut W, len: usize) -> Result<()> {
+    let len = u32::try_from(len)?;
+    writer.write_all(&len.to_le_bytes())?;
+    Ok(())
+}
+
+fn write_bytes<W: Write>(writer: &mut W, bytes: &[u8]) -> Result<()> {
+    write_len(writer, bytes.len())?;
+    writer.write_all(bytes)?;
+    Ok(())
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> Result<u32> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_bytes<R: Read>(reader: &mut R) -> Result<Vec<u8>> {
+    let len = read_u32(reader)? as usize;
+    let mut buf = vec![0u8; len];
+    reader.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn stub_entries() -> Vec<ArchiveEntry> {
+        vec![
+            ArchiveEntry::new("instruments:AUD/USD.SIM".to_string(), vec![b"{}".to_vec()]),
+            ArchiveEntry::new(
+                "orders:O-123456".to_string(),
+                vec![b"initialized".to_vec(), b"submitted".to_vec(), vec![]],
+            ),
+            ArchiveEntry::new("index:orders_open".to_string(), vec![b"O-123456".to_vec()]),
+            ArchiveEntry::new(
+                "index:order_position".to_string(),
+                vec![br#"{"O-123456":"P-1"}"#.to_vec()],
+            ),
+        ]
+    }
+
+    #[rstest]
+    fn test_archive_round_trip() {
+        let entries = stub_entries();
+        let mut buf = Vec::new();
+
+        write_archive(&mut buf, &entries).unwrap();
+        let restored = read_archive(buf.as_slice()).unwrap();
+
+        assert_eq!(restored, entries);
+    }
+
+    #[rstest]
+    fn test_archive_round_trip_empty() {
+        let mut buf = Vec::new();
+
+        write_archive(&mut buf, &[]).unwrap();
+        let restored = read_archive(buf.as_slice()).unwrap();
+
+        assert!(restored.is_empty());
+    }
+
+    #[rstest]
+    fn test_read_archive_with_bad_magic() {
+        let buf = zstd::encode_all(b"NOTANARCHIVE".as_slice(), 0).unwrap();
+        assert!(read_archive(buf.as_slice()).is_err());
+    }
+
+    #[rstest]
+    fn test_read_archive_with_unsupported_version() {
+        let mut data = ARCHIVE_MAGIC.to_vec();
+        data.extend_from_slice(&(ARCHIVE_VERSION + 1).to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        let buf = zstd::encode_all(data.as_slice(), 0).unwrap();
+
+        let result = read_archive(buf.as_slice());
+
+        assert!(result.unwrap_err().to_string().contains("version"));
+    }
+
+    #[rstest]
+    fn test_read_archive_truncated() {
+        let mut buf = Vec::new();
+        write_archive(&mut buf, &stub_entries()).unwrap();
+
+        let mut data = zstd::decode_all(buf.as_slice()).unwrap();
+        data.truncate(data.len() - 4);
+        let buf = zstd::encode_all(data.as_slice(), 0).unwrap();
+
+        assert!(read_archive(buf.as_slice()).is_err());
+    }
+}
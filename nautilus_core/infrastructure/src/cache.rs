@@ -13,11 +13,24 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, sync::mpsc::Receiver};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::mpsc::Receiver,
+};
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
 use nautilus_core::uuid::UUID4;
 use nautilus_model::identifiers::trader_id::TraderId;
+use serde_json::json;
+
+/// The delimiter between the parts of a cache database key.
+pub const DELIMITER: char = ':';
+
+/// The state index flags for an atomic order or position update.
+pub const INDEX_FLAG_INFLIGHT: u8 = 1 << 0;
+pub const INDEX_FLAG_OPEN: u8 = 1 << 1;
+pub const INDEX_FLAG_CLOSED: u8 = 1 << 2;
+pub const INDEX_FLAG_EMULATED: u8 = 1 << 3;
 
 /// A type of database operation.
 #[derive(Clone, Debug)]
@@ -25,6 +38,14 @@ pub enum DatabaseOperation {
     Insert,
     Update,
     Delete,
+    /// Appends an order event and updates the order state indexes atomically.
+    ///
+    /// The `payload` is `[event, [flags]]` where `flags` is a combination of `INDEX_FLAG_*`.
+    UpdateOrder,
+    /// Appends a position event and updates the position state indexes atomically.
+    ///
+    /// The `payload` is `[event, [flags]]` where `flags` is a combination of `INDEX_FLAG_*`.
+    UpdatePosition,
 }
 
 /// Represents a database command to be performed which may be executed 'remotely' across a thread.
@@ -68,9 +89,72 @@ pub trait CacheDatabase {
     fn insert(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()>;
     fn update(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()>;
     fn delete(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()>;
+    fn update_order(&mut self, key: String, event: Vec<u8>, flags: u8) -> Result<()>;
+    fn update_position(&mut self, key: String, event: Vec<u8>, flags: u8) -> Result<()>;
     fn handle_messages(
         rx: Receiver<DatabaseCommand>,
         trader_key: String,
         config: HashMap<String, serde_json::Value>,
     );
 }
+
+/// Returns the trader key which prefixes all keys for the given trader instance.
+#[must_use]
+pub fn get_trader_key(
+    trader_id: TraderId,
+    instance_id: UUID4,
+    config: &HashMap<String, serde_json::Value>,
+) -> String {
+    let mut key = String::new();
+
+    if let Some(json!(true)) = config.get("use_trader_prefix") {
+        key.push_str("trader-");
+    }
+
+    key.push_str(trader_id.value.as_str());
+
+    if let Some(json!(true)) = config.get("use_instance_id") {
+        key.push(DELIMITER);
+        key.push_str(&format!("{instance_id}"));
+    }
+
+    key
+}
+
+/// Receives all pending commands from `rx` into the `buffer`, up to the `max_batch_size`.
+///
+/// This allows the commands to be written as a single batch, saving round trips.
+pub fn receive_pending(
+    rx: &Receiver<DatabaseCommand>,
+    buffer: &mut VecDeque<DatabaseCommand>,
+    max_batch_size: usize,
+) {
+    while buffer.len() < max_batch_size {
+        match rx.try_recv() {
+            Ok(msg) => buffer.push_back(msg),
+            Err(_) => break, // Empty or hung up, handled by the receive loop
+        }
+    }
+}
+
+/// Returns the event and state index flags from an atomic update `payload`.
+pub fn get_update_payload(payload: Option<&Vec<Vec<u8>>>) -> Result<(&[u8], u8)> {
+    match payload.map(Vec::as_slice) {
+        Some([event, flags]) if flags.len() == 1 => Ok((event, flags[0])),
+        _ => bail!("Invalid `payload` for atomic update, expected event and flags"),
+    }
+}
+
+/// Returns the collection part of the given `key`.
+pub fn get_collection_key(key: &str) -> Result<&str> {
+    key.split_once(DELIMITER)
+        .map(|(collection, _)| collection)
+        .ok_or_else(|| anyhow!("Invalid `key`, missing a '{DELIMITER}' delimiter, was {key}"))
+}
+
+/// Returns the part of the given `key` following the collection.
+pub fn get_index_key(key: &str) -> Result<&str> {
+    key.split_once(DELIMITER)
+        .map(|(_, index_key)| index_key)
+        .ok_or_else(|| anyhow!("Invalid `key`, missing a '{DELIMITER}' delimiter, was {key}"))
+}
@@ -0,0 +1,579 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An [Apache Kafka](https://kafka.apache.org) backend for the external message bus.
+//!
+//! Messages are routed to a Kafka topic per message type, named `{prefix}.{type}` where the
+//! type is the leading levels of the message bus topic (e.g. `events.order` or `data.quotes`).
+//! Records are keyed by instrument ID where one is available, so that all messages for an
+//! instrument land on the same partition and are consumed in order. The full message bus topic
+//! and the payload content type (from the configured `encoding`) are carried as record headers.
+
+use std::{
+    collections::HashMap,
+    sync::mpsc::{channel, Receiver, Sender, TryRecvError},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Result};
+use nautilus_common::{msgbus::BusMessage, redis::get_buffer_interval};
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::trader_id::TraderId;
+use rdkafka::{
+    config::ClientConfig,
+    consumer::{BaseConsumer, Consumer},
+    error::{KafkaError, RDKafkaErrorCode},
+    message::{Header, Headers, Message, OwnedHeaders},
+    producer::{BaseProducer, BaseRecord, Producer},
+};
+use serde_json::{json, Value};
+use tracing::{debug, error};
+
+use crate::msgbus::MessageBusDatabase;
+
+// Error constants
+const CHANNEL_TX_FAILED: &str = "Failed to send to channel";
+
+// Kafka constants
+const DEFAULT_HOST: &str = "127.0.0.1";
+const DEFAULT_PORT: u64 = 9092;
+const DEFAULT_TIMEOUT_SECS: u64 = 20;
+const TOPIC_LEVELS: usize = 2; // The leading levels of a topic which identify its type
+const DELIMITER: char = '.';
+const QUEUE_FULL_BACKOFF: Duration = Duration::from_millis(100);
+
+/// The record header carrying the original message bus topic.
+pub const HEADER_TOPIC: &str = "nautilus-topic";
+/// The record header carrying the payload content type.
+pub const HEADER_CONTENT_TYPE: &str = "content-type";
+
+struct KafkaMessage {
+    topic: String,
+    key: Option<String>,
+    payload: Vec<u8>,
+}
+
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.infrastructure")
+)]
+pub struct KafkaMessageBusDatabase {
+    pub trader_id: TraderId,
+    tx: Option<Sender<KafkaMessage>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MessageBusDatabase for KafkaMessageBusDatabase {
+    type DatabaseType = KafkaMessageBusDatabase;
+
+    fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: HashMap<String, Value>,
+    ) -> Result<KafkaMessageBusDatabase> {
+        debug!("Initializing trader_id={trader_id}, instance_id={instance_id}, config={config:?}");
+        let topic_prefix = get_topic_prefix(trader_id, instance_id, &config);
+        let content_type = get_content_type(&config)?;
+
+        // Create the producer up front so configuration errors surface immediately
+        let producer: BaseProducer = get_client_config(&config).create()?;
+        debug!("Created producer");
+
+        let (tx, rx) = channel::<KafkaMessage>();
+        let handle = thread::Builder::new()
+            .name("msgbus-kafka".to_string())
+            .spawn(move || {
+                if let Err(e) = handle_messages(rx, producer, topic_prefix, content_type, config) {
+                    error!("Error handling Kafka messages: {e}");
+                }
+            })
+            .expect("Error spawning `msgbus-kafka` thread");
+
+        Ok(KafkaMessageBusDatabase {
+            trader_id,
+            tx: Some(tx),
+            handle: Some(handle),
+        })
+    }
+
+    fn is_closed(&self) -> bool {
+        self.tx.is_none()
+    }
+
+    fn publish(&self, topic: String, payload: Vec<u8>) -> Result<()> {
+        self.publish_keyed(topic, payload, None)
+    }
+
+    fn close(&mut self) {
+        // Hanging up the channel drains any buffered messages before the thread exits
+        self.tx.take();
+
+        if let Some(handle) = self.handle.take() {
+            if handle.join().is_err() {
+                error!("Error joining `msgbus-kafka` thread");
+            }
+        }
+    }
+}
+
+impl KafkaMessageBusDatabase {
+    /// Publishes the `payload` for the given message bus `topic` with a partition `key`.
+    ///
+    /// If `key` is `None` then the key is derived from the topic levels following the
+    /// message type (see [`get_partition_key`]).
+    pub fn publish_keyed(
+        &self,
+        topic: String,
+        payload: Vec<u8>,
+        key: Option<String>,
+    ) -> Result<()> {
+        let Some(tx) = &self.tx else {
+            bail!("Cannot publish: database is closed");
+        };
+
+        let msg = KafkaMessage {
+            topic,
+            key,
+            payload,
+        };
+        match tx.send(msg) {
+            Ok(()) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+}
+
+impl Drop for KafkaMessageBusDatabase {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+fn handle_messages(
+    rx: Receiver<KafkaMessage>,
+    producer: BaseProducer,
+    topic_prefix: String,
+    content_type: &'static str,
+    config: HashMap<String, Value>,
+) -> Result<()> {
+    let flush_timeout = get_timeout(&config);
+
+    // Buffering
+    let mut buffer: Vec<KafkaMessage> = Vec::new();
+    let mut last_drain = Instant::now();
+    let recv_interval = Duration::from_millis(1);
+    let buffer_interval = get_buffer_interval(&config);
+
+    loop {
+        if last_drain.elapsed() >= buffer_interval && !buffer.is_empty() {
+            for msg in buffer.drain(..) {
+                send_message(&producer, &topic_prefix, content_type, msg)?;
+            }
+            producer.poll(Duration::ZERO); // Serve delivery callbacks
+            last_drain = Instant::now();
+        } else {
+            // Continue to receive and handle messages until channel is hung up
+            match rx.try_recv() {
+                Ok(msg) => buffer.push(msg),
+                Err(TryRecvError::Empty) => thread::sleep(recv_interval),
+                Err(TryRecvError::Disconnected) => break, // Channel hung up
+            }
+        }
+    }
+
+    // Send any remaining messages and wait for outstanding deliveries
+    for msg in buffer.drain(..) {
+        send_message(&producer, &topic_prefix, content_type, msg)?;
+    }
+    producer.flush(flush_timeout)?;
+
+    Ok(())
+}
+
+fn send_message(
+    producer: &BaseProducer,
+    topic_prefix: &str,
+    content_type: &str,
+    msg: KafkaMessage,
+) -> Result<()> {
+    let kafka_topic = get_kafka_topic(topic_prefix, &msg.topic, TOPIC_LEVELS);
+    let key = msg
+        .key
+        .unwrap_or_else(|| get_partition_key(&msg.topic, TOPIC_LEVELS));
+
+    loop {
+        let headers = OwnedHeaders::new()
+            .insert(Header {
+                key: HEADER_TOPIC,
+                value: Some(msg.topic.as_str()),
+            })
+            .insert(Header {
+                key: HEADER_CONTENT_TYPE,
+                value: Some(content_type),
+            });
+        let mut record = BaseRecord::to(&kafka_topic)
+            .payload(&msg.payload)
+            .headers(headers);
+        if !key.is_empty() {
+            record = record.key(key.as_str());
+        }
+
+        match producer.send(record) {
+            Ok(()) => return Ok(()),
+            Err((KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull), _)) => {
+                // Wait for in-flight deliveries to free space in the producer queue
+                producer.poll(QUEUE_FULL_BACKOFF);
+            }
+            Err((e, _)) => return Err(e.into()),
+        }
+    }
+}
+
+/// Provides a consumer group member for messages streamed by a [`KafkaMessageBusDatabase`].
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.infrastructure")
+)]
+pub struct KafkaMessageBusConsumer {
+    topic_prefix: String,
+    consumer: BaseConsumer,
+}
+
+impl KafkaMessageBusConsumer {
+    /// Creates a new [`KafkaMessageBusConsumer`] for the topics of the given trader.
+    ///
+    /// The `types` are message types such as `events.order` or `data.quotes`, and default to
+    /// all types for the trader. Consumers with the same `group_id` share the partitions
+    /// between them and their committed offsets across restarts.
+    pub fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: HashMap<String, Value>,
+        group_id: String,
+        types: Option<Vec<String>>,
+    ) -> Result<Self> {
+        let topic_prefix = get_topic_prefix(trader_id, instance_id, &config);
+
+        let consumer: BaseConsumer = get_client_config(&config)
+            .set("group.id", group_id)
+            .set("auto.offset.reset", "earliest")
+            .create()?;
+
+        let topics: Vec<String> = match types {
+            Some(types) => types
+                .iter()
+                .map(|message_type| format!("{topic_prefix}{DELIMITER}{message_type}"))
+                .collect(),
+            // A leading `^` subscribes to all topics matching the regex
+            None => vec![format!("^{}\\..+", topic_prefix.replace('.', "\\."))],
+        };
+        let topics: Vec<&str> = topics.iter().map(String::as_str).collect();
+        consumer.subscribe(&topics)?;
+
+        Ok(Self {
+            topic_prefix,
+            consumer,
+        })
+    }
+
+    /// Polls for up to `max_messages` messages, waiting at most `timeout` for each to arrive.
+    ///
+    /// Returned messages carry their original message bus topic.
+    pub fn poll(&self, max_messages: usize, timeout: Duration) -> Result<Vec<BusMessage>> {
+        let mut messages = Vec::new();
+        while messages.len() < max_messages {
+            let Some(result) = self.consumer.poll(timeout) else {
+                break; // Timed out
+            };
+            let msg = result?;
+
+            let topic = msg
+                .headers()
+                .and_then(|headers| {
+                    headers
+                        .iter()
+                        .find(|header| header.key == HEADER_TOPIC)
+                        .and_then(|header| header.value)
+                })
+                .map_or_else(
+                    || {
+                        msg.topic()
+                            .strip_prefix(&self.topic_prefix)
+                            .and_then(|s| s.strip_prefix(DELIMITER))
+                            .unwrap_or(msg.topic())
+                            .to_string()
+                    },
+                    |value| String::from_utf8_lossy(value).into_owned(),
+                );
+
+            messages.push(BusMessage {
+                topic,
+                payload: msg.payload().unwrap_or_default().to_vec(),
+            });
+        }
+
+        Ok(messages)
+    }
+}
+
+fn get_client_config(config: &HashMap<String, Value>) -> ClientConfig {
+    let empty = json!({});
+    let database = config.get("database").unwrap_or(&empty);
+
+    let mut client_config = ClientConfig::new();
+    client_config
+        .set("bootstrap.servers", get_bootstrap_servers(config))
+        .set(
+            "socket.connection.setup.timeout.ms",
+            get_timeout(config).as_millis().to_string(),
+        );
+
+    let ssl = database
+        .get("ssl")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let username = database.get("username").and_then(Value::as_str);
+    let password = database.get("password").and_then(Value::as_str);
+
+    if let (Some(username), Some(password)) = (username, password) {
+        let protocol = if ssl { "SASL_SSL" } else { "SASL_PLAINTEXT" };
+        client_config
+            .set("security.protocol", protocol)
+            .set("sasl.mechanisms", "PLAIN")
+            .set("sasl.username", username)
+            .set("sasl.password", password);
+    } else if ssl {
+        client_config.set("security.protocol", "SSL");
+    }
+
+    client_config
+}
+
+fn get_timeout(config: &HashMap<String, Value>) -> Duration {
+    let timeout_secs = config
+        .get("database")
+        .and_then(|database| database.get("timeout"))
+        .and_then(Value::as_u64)
+        .unwrap_or(DEFAULT_TIMEOUT_SECS);
+    Duration::from_secs(timeout_secs)
+}
+
+/// Returns the Kafka bootstrap servers for the given message bus `config`.
+#[must_use]
+pub fn get_bootstrap_servers(config: &HashMap<String, Value>) -> String {
+    let empty = json!({});
+    let database = config.get("database").unwrap_or(&empty);
+
+    let host = database
+        .get("host")
+        .and_then(Value::as_str)
+        .unwrap_or(DEFAULT_HOST);
+    let port = database
+        .get("port")
+        .and_then(Value::as_u64)
+        .unwrap_or(DEFAULT_PORT);
+
+    format!("{host}:{port}")
+}
+
+/// Returns the payload content type for the configured message bus `encoding`.
+pub fn get_content_type(config: &HashMap<String, Value>) -> Result<&'static str> {
+    match config.get("encoding").and_then(Value::as_str) {
+        Some("msgpack") | None => Ok("application/msgpack"),
+        Some("json") => Ok("application/json"),
+        Some(encoding) => bail!("Unsupported encoding '{encoding}' for Kafka message bus"),
+    }
+}
+
+/// Returns the topic prefix for the given trader and message bus `config`.
+#[must_use]
+pub fn get_topic_prefix(
+    trader_id: TraderId,
+    instance_id: UUID4,
+    config: &HashMap<String, Value>,
+) -> String {
+    let mut tokens: Vec<String> = Vec::new();
+
+    let use_trader_prefix = config.get("use_trader_prefix") == Some(&json!(true));
+    let use_trader_id = config.get("use_trader_id") == Some(&json!(true));
+
+    match (use_trader_prefix, use_trader_id) {
+        (true, true) => tokens.push(format!("trader-{}", trader_id.value)),
+        (true, false) => tokens.push("trader".to_string()),
+        (false, true) => tokens.push(trader_id.value.to_string()),
+        (false, false) => {}
+    }
+
+    if config.get("use_instance_id") == Some(&json!(true)) {
+        tokens.push(instance_id.to_string());
+    }
+
+    let streams_prefix = config
+        .get("streams_prefix")
+        .and_then(Value::as_str)
+        .unwrap_or("streams");
+    tokens.push(streams_prefix.to_string());
+
+    sanitize_topic(&tokens.join(&DELIMITER.to_string()))
+}
+
+/// Returns the Kafka topic for the given message bus `topic`, made up of the prefix
+/// and the leading `topic_levels` levels of the topic.
+#[must_use]
+pub fn get_kafka_topic(topic_prefix: &str, topic: &str, topic_levels: usize) -> String {
+    let message_type: Vec<&str> = topic.split(DELIMITER).take(topic_levels).collect();
+    format!(
+        "{topic_prefix}{DELIMITER}{}",
+        sanitize_topic(&message_type.join(&DELIMITER.to_string()))
+    )
+}
+
+/// Returns the partition key for the given message bus `topic`, being the levels
+/// following the leading `topic_levels` (empty if there are none).
+#[must_use]
+pub fn get_partition_key(topic: &str, topic_levels: usize) -> String {
+    let levels: Vec<&str> = topic.split(DELIMITER).skip(topic_levels).collect();
+    levels.join(&DELIMITER.to_string())
+}
+
+// Replaces characters which are not valid within Kafka topic names.
+fn sanitize_topic(topic: &str) -> String {
+    topic
+        .chars()
+        .map(|c| match c {
+            c if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn stub_config(use_trader_prefix: bool, use_instance_id: bool) -> HashMap<String, Value> {
+        let mut config = HashMap::new();
+        config.insert("use_trader_prefix".to_string(), json!(use_trader_prefix));
+        config.insert("use_trader_id".to_string(), json!(true));
+        config.insert("use_instance_id".to_string(), json!(use_instance_id));
+        config.insert("streams_prefix".to_string(), json!("streams"));
+        config
+    }
+
+    #[rstest]
+    fn test_get_bootstrap_servers_defaults() {
+        let mut config = HashMap::new();
+        config.insert("database".to_string(), json!({"type": "kafka"}));
+
+        assert_eq!(get_bootstrap_servers(&config), "127.0.0.1:9092");
+    }
+
+    #[rstest]
+    fn test_get_bootstrap_servers() {
+        let mut config = HashMap::new();
+        config.insert(
+            "database".to_string(),
+            json!({"type": "kafka", "host": "kafka.local", "port": 9093}),
+        );
+
+        assert_eq!(get_bootstrap_servers(&config), "kafka.local:9093");
+    }
+
+    #[rstest]
+    #[case(None, "application/msgpack")]
+    #[case(Some("msgpack"), "application/msgpack")]
+    #[case(Some("json"), "application/json")]
+    fn test_get_content_type(#[case] encoding: Option<&str>, #[case] expected: &str) {
+        let mut config = HashMap::new();
+        if let Some(encoding) = encoding {
+            config.insert("encoding".to_string(), json!(encoding));
+        }
+
+        assert_eq!(get_content_type(&config).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_get_content_type_with_unsupported_encoding() {
+        let mut config = HashMap::new();
+        config.insert("encoding".to_string(), json!("xml"));
+
+        assert!(get_content_type(&config).is_err());
+    }
+
+    #[rstest]
+    fn test_get_topic_prefix_with_trader_prefix_and_instance_id() {
+        let trader_id = TraderId::from("tester-123");
+        let instance_id = UUID4::new();
+        let config = stub_config(true, true);
+
+        let prefix = get_topic_prefix(trader_id, instance_id, &config);
+
+        assert_eq!(prefix, format!("trader-tester-123.{instance_id}.streams"));
+    }
+
+    #[rstest]
+    fn test_get_topic_prefix_without_trader_prefix_or_instance_id() {
+        let trader_id = TraderId::from("tester-123");
+        let config = stub_config(false, false);
+
+        let prefix = get_topic_prefix(trader_id, UUID4::new(), &config);
+
+        assert_eq!(prefix, "tester-123.streams");
+    }
+
+    #[rstest]
+    #[case("events.order.S-001", 2, "streams.events.order")]
+    #[case("data.quotes.BINANCE.ETHUSDT", 2, "streams.data.quotes")]
+    #[case("data.quotes.BINANCE.ETHUSDT", 3, "streams.data.quotes.BINANCE")]
+    #[case("data.bars.AUD/USD.SIM-1-MINUTE-BID-EXTERNAL", 2, "streams.data.bars")]
+    #[case("events.system", 3, "streams.events.system")]
+    #[case("data.custom:type", 2, "streams.data.custom_type")]
+    fn test_get_kafka_topic(#[case] topic: &str, #[case] levels: usize, #[case] expected: &str) {
+        assert_eq!(get_kafka_topic("streams", topic, levels), expected);
+    }
+
+    #[rstest]
+    #[case("events.order.S-001", 2, "S-001")]
+    #[case("data.quotes.BINANCE.ETHUSDT", 2, "BINANCE.ETHUSDT")]
+    #[case("data.quotes.BINANCE.ETHUSDT", 3, "ETHUSDT")]
+    #[case("events.system", 2, "")]
+    fn test_get_partition_key(#[case] topic: &str, #[case] levels: usize, #[case] expected: &str) {
+        assert_eq!(get_partition_key(topic, levels), expected);
+    }
+
+    #[rstest]
+    fn test_get_client_config_with_sasl_ssl() {
+        let mut config = HashMap::new();
+        config.insert(
+            "database".to_string(),
+            json!({"type": "kafka", "username": "user", "password": "pass", "ssl": true}),
+        );
+
+        let client_config = get_client_config(&config);
+
+        assert_eq!(client_config.get("security.protocol"), Some("SASL_SSL"));
+        assert_eq!(client_config.get("sasl.username"), Some("user"));
+        assert_eq!(
+            client_config.get("socket.connection.setup.timeout.ms"),
+            Some("20000")
+        );
+    }
+}
@@ -13,10 +13,24 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod archive;
 pub mod cache;
+pub mod msgbus;
 
 #[cfg(feature = "python")]
 pub mod python;
 
 #[cfg(feature = "redis")]
 pub mod redis;
+
+#[cfg(feature = "nats")]
+pub mod nats;
+
+#[cfg(feature = "kafka")]
+pub mod kafka;
+
+#[cfg(feature = "postgres")]
+pub mod postgres;
+
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
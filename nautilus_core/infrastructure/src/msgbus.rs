@@ -0,0 +1,37 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::trader_id::TraderId;
+
+/// Provides a generic message bus database facade for streaming messages externally.
+///
+/// The main operation takes a `topic` and serialized `payload` which should provide enough
+/// information to implement the message bus database in many different technologies.
+pub trait MessageBusDatabase {
+    type DatabaseType;
+
+    fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: HashMap<String, serde_json::Value>,
+    ) -> Result<Self::DatabaseType>;
+    fn is_closed(&self) -> bool;
+    fn publish(&self, topic: String, payload: Vec<u8>) -> Result<()>;
+    fn close(&mut self);
+}
@@ -0,0 +1,520 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A [NATS JetStream](https://docs.nats.io/nats-concepts/jetstream) backend for the external
+//! message bus.
+//!
+//! Messages are published on the subject `{prefix}.{topic}`, where the prefix is built from
+//! the `MessageBusConfig` in the same way as Redis stream names (using `.` as the delimiter),
+//! and all subjects for the prefix are captured by a single JetStream stream.
+
+use std::{
+    collections::HashMap,
+    sync::mpsc::{channel, Receiver, Sender, TryRecvError},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Result};
+use async_nats::jetstream::{
+    self,
+    consumer::{pull, AckPolicy, PullConsumer},
+    context::PublishAckFuture,
+    stream,
+};
+use futures::StreamExt;
+use nautilus_common::{msgbus::BusMessage, redis::get_buffer_interval, runtime::get_runtime};
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::trader_id::TraderId;
+use serde_json::{json, Value};
+use tracing::{debug, error};
+
+use crate::msgbus::MessageBusDatabase;
+
+// Error constants
+const CHANNEL_TX_FAILED: &str = "Failed to send to channel";
+
+// NATS constants
+const DEFAULT_HOST: &str = "127.0.0.1";
+const DEFAULT_PORT: u64 = 4222;
+const DEFAULT_TIMEOUT_SECS: u64 = 20;
+const DELIMITER: char = '.';
+
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.infrastructure")
+)]
+pub struct NatsMessageBusDatabase {
+    pub trader_id: TraderId,
+    tx: Option<Sender<BusMessage>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MessageBusDatabase for NatsMessageBusDatabase {
+    type DatabaseType = NatsMessageBusDatabase;
+
+    fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: HashMap<String, Value>,
+    ) -> Result<NatsMessageBusDatabase> {
+        debug!("Initializing trader_id={trader_id}, instance_id={instance_id}, config={config:?}");
+        let subject_prefix = get_subject_prefix(trader_id, instance_id, &config);
+
+        // Connect and create the stream up front so configuration errors surface immediately
+        let context = get_runtime().block_on(connect(&config))?;
+        get_runtime().block_on(get_or_create_stream(&context, &subject_prefix, &config))?;
+        debug!("Connected");
+
+        let (tx, rx) = channel::<BusMessage>();
+        let handle = thread::Builder::new()
+            .name("msgbus-nats".to_string())
+            .spawn(move || {
+                if let Err(e) = handle_messages(rx, context, subject_prefix, config) {
+                    error!("Error handling NATS messages: {e}");
+                }
+            })
+            .expect("Error spawning `msgbus-nats` thread");
+
+        Ok(NatsMessageBusDatabase {
+            trader_id,
+            tx: Some(tx),
+            handle: Some(handle),
+        })
+    }
+
+    fn is_closed(&self) -> bool {
+        self.tx.is_none()
+    }
+
+    fn publish(&self, topic: String, payload: Vec<u8>) -> Result<()> {
+        let Some(tx) = &self.tx else {
+            bail!("Cannot publish: database is closed");
+        };
+
+        let msg = BusMessage { topic, payload };
+        match tx.send(msg) {
+            Ok(()) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+
+    fn close(&mut self) {
+        // Hanging up the channel drains any buffered messages before the thread exits
+        self.tx.take();
+
+        if let Some(handle) = self.handle.take() {
+            if handle.join().is_err() {
+                error!("Error joining `msgbus-nats` thread");
+            }
+        }
+    }
+}
+
+impl Drop for NatsMessageBusDatabase {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+fn handle_messages(
+    rx: Receiver<BusMessage>,
+    context: jetstream::Context,
+    subject_prefix: String,
+    config: HashMap<String, Value>,
+) -> Result<()> {
+    // Buffering
+    let mut buffer: Vec<BusMessage> = Vec::new();
+    let mut last_drain = Instant::now();
+    let recv_interval = Duration::from_millis(1);
+    let buffer_interval = get_buffer_interval(&config);
+
+    loop {
+        if last_drain.elapsed() >= buffer_interval && !buffer.is_empty() {
+            drain_buffer(&context, &subject_prefix, &mut buffer)?;
+            last_drain = Instant::now();
+        } else {
+            // Continue to receive and handle messages until channel is hung up
+            match rx.try_recv() {
+                Ok(msg) => buffer.push(msg),
+                Err(TryRecvError::Empty) => thread::sleep(recv_interval),
+                Err(TryRecvError::Disconnected) => break, // Channel hung up
+            }
+        }
+    }
+
+    // Drain any remaining messages
+    if !buffer.is_empty() {
+        drain_buffer(&context, &subject_prefix, &mut buffer)?;
+    }
+
+    Ok(())
+}
+
+fn drain_buffer(
+    context: &jetstream::Context,
+    subject_prefix: &str,
+    buffer: &mut Vec<BusMessage>,
+) -> Result<()> {
+    get_runtime().block_on(publish_batch(context, subject_prefix, buffer.drain(..)))
+}
+
+async fn publish_batch(
+    context: &jetstream::Context,
+    subject_prefix: &str,
+    messages: impl Iterator<Item = BusMessage>,
+) -> Result<()> {
+    // Pipeline the publishes, then await all acknowledgements
+    let mut acks: Vec<PublishAckFuture> = Vec::new();
+    for msg in messages {
+        let subject = get_subject(subject_prefix, &msg.topic);
+        acks.push(context.publish(subject, msg.payload.into()).await?);
+    }
+
+    for ack in acks {
+        ack.await?;
+    }
+
+    Ok(())
+}
+
+/// Provides a durable pull consumer for messages streamed by a [`NatsMessageBusDatabase`].
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.infrastructure")
+)]
+pub struct NatsMessageBusConsumer {
+    subject_prefix: String,
+    consumer: PullConsumer,
+}
+
+impl NatsMessageBusConsumer {
+    /// Creates a new [`NatsMessageBusConsumer`] for the stream of the given trader.
+    ///
+    /// The `topic_filter` may contain message bus level wildcards (`+` and `#`), and
+    /// defaults to all topics. Consumers with the same `durable_name` share their position
+    /// in the stream across restarts.
+    pub fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: HashMap<String, Value>,
+        durable_name: String,
+        topic_filter: Option<&str>,
+    ) -> Result<Self> {
+        let subject_prefix = get_subject_prefix(trader_id, instance_id, &config);
+        let filter_subject = get_filter_subject(&subject_prefix, topic_filter.unwrap_or("#"));
+
+        let consumer = get_runtime().block_on(create_consumer(
+            &config,
+            &subject_prefix,
+            durable_name,
+            filter_subject,
+        ))?;
+
+        Ok(Self {
+            subject_prefix,
+            consumer,
+        })
+    }
+
+    /// Fetches up to `max_messages` messages, waiting at most `timeout` for them to arrive.
+    ///
+    /// Returned messages are acknowledged and have the subject prefix removed from the topic.
+    pub fn fetch(&self, max_messages: usize, timeout: Duration) -> Result<Vec<BusMessage>> {
+        get_runtime().block_on(self.fetch_batch(max_messages, timeout))
+    }
+
+    async fn fetch_batch(&self, max_messages: usize, timeout: Duration) -> Result<Vec<BusMessage>> {
+        let mut batch = self
+            .consumer
+            .fetch()
+            .max_messages(max_messages)
+            .expires(timeout)
+            .messages()
+            .await
+            .map_err(|e| anyhow!(e))?;
+
+        let mut messages = Vec::new();
+        while let Some(msg) = batch.next().await {
+            let msg = msg.map_err(|e| anyhow!(e))?;
+            msg.ack().await.map_err(|e| anyhow!(e))?;
+
+            let subject = msg.subject.as_str();
+            let topic = subject
+                .strip_prefix(&self.subject_prefix)
+                .and_then(|s| s.strip_prefix(DELIMITER))
+                .unwrap_or(subject)
+                .to_string();
+            messages.push(BusMessage {
+                topic,
+                payload: msg.payload.to_vec(),
+            });
+        }
+
+        Ok(messages)
+    }
+}
+
+async fn create_consumer(
+    config: &HashMap<String, Value>,
+    subject_prefix: &str,
+    durable_name: String,
+    filter_subject: String,
+) -> Result<PullConsumer> {
+    let context = connect(config).await?;
+    let stream = get_or_create_stream(&context, subject_prefix, config).await?;
+    stream
+        .get_or_create_consumer(
+            &durable_name,
+            pull::Config {
+                durable_name: Some(durable_name.clone()),
+                filter_subject,
+                ack_policy: AckPolicy::Explicit,
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(|e| anyhow!(e))
+}
+
+async fn connect(config: &HashMap<String, Value>) -> Result<jetstream::Context> {
+    let empty = json!({});
+    let database = config.get("database").unwrap_or(&empty);
+
+    let mut options = async_nats::ConnectOptions::new().connection_timeout(Duration::from_secs(
+        database
+            .get("timeout")
+            .and_then(Value::as_u64)
+            .unwrap_or(DEFAULT_TIMEOUT_SECS),
+    ));
+
+    let username = database.get("username").and_then(Value::as_str);
+    let password = database.get("password").and_then(Value::as_str);
+    if let (Some(username), Some(password)) = (username, password) {
+        options = options.user_and_password(username.to_string(), password.to_string());
+    }
+
+    if database
+        .get("ssl")
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+    {
+        options = options.require_tls(true);
+    }
+
+    let client = options.connect(get_nats_url(config)).await?;
+    Ok(jetstream::new(client))
+}
+
+async fn get_or_create_stream(
+    context: &jetstream::Context,
+    subject_prefix: &str,
+    config: &HashMap<String, Value>,
+) -> Result<stream::Stream> {
+    // Autotrimming maps directly onto the JetStream message age limit
+    let autotrim_mins = config
+        .get("autotrim_mins")
+        .and_then(Value::as_u64)
+        .unwrap_or(0);
+
+    context
+        .get_or_create_stream(stream::Config {
+            name: get_stream_name(subject_prefix),
+            subjects: vec![format!("{subject_prefix}{DELIMITER}>")],
+            max_age: Duration::from_secs(autotrim_mins * 60),
+            ..Default::default()
+        })
+        .await
+        .map_err(|e| anyhow!(e))
+}
+
+/// Returns the NATS server URL for the given message bus `config`.
+#[must_use]
+pub fn get_nats_url(config: &HashMap<String, Value>) -> String {
+    let empty = json!({});
+    let database = config.get("database").unwrap_or(&empty);
+
+    let host = database
+        .get("host")
+        .and_then(Value::as_str)
+        .unwrap_or(DEFAULT_HOST);
+    let port = database
+        .get("port")
+        .and_then(Value::as_u64)
+        .unwrap_or(DEFAULT_PORT);
+    let scheme = if database
+        .get("ssl")
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+    {
+        "tls"
+    } else {
+        "nats"
+    };
+
+    format!("{scheme}://{host}:{port}")
+}
+
+/// Returns the subject prefix for the given trader and message bus `config`.
+#[must_use]
+pub fn get_subject_prefix(
+    trader_id: TraderId,
+    instance_id: UUID4,
+    config: &HashMap<String, Value>,
+) -> String {
+    let mut tokens: Vec<String> = Vec::new();
+
+    let use_trader_prefix = config.get("use_trader_prefix") == Some(&json!(true));
+    let use_trader_id = config.get("use_trader_id") == Some(&json!(true));
+
+    match (use_trader_prefix, use_trader_id) {
+        (true, true) => tokens.push(format!("trader-{}", trader_id.value)),
+        (true, false) => tokens.push("trader".to_string()),
+        (false, true) => tokens.push(trader_id.value.to_string()),
+        (false, false) => {}
+    }
+
+    if config.get("use_instance_id") == Some(&json!(true)) {
+        tokens.push(instance_id.to_string());
+    }
+
+    let streams_prefix = config
+        .get("streams_prefix")
+        .and_then(Value::as_str)
+        .unwrap_or("streams");
+    tokens.push(streams_prefix.to_string());
+
+    sanitize_subject(&tokens.join(&DELIMITER.to_string()))
+}
+
+/// Returns the JetStream stream name for the given `subject_prefix`.
+///
+/// Stream names may not contain `.`, so levels are joined with `_` instead.
+#[must_use]
+pub fn get_stream_name(subject_prefix: &str) -> String {
+    subject_prefix.replace(DELIMITER, "_")
+}
+
+/// Returns the subject for the given message bus `topic`.
+#[must_use]
+pub fn get_subject(subject_prefix: &str, topic: &str) -> String {
+    format!("{subject_prefix}{DELIMITER}{}", sanitize_subject(topic))
+}
+
+/// Returns the subject filter for the given message bus `topic_filter`, translating
+/// the level wildcards `+` and `#` to their NATS equivalents `*` and `>`.
+#[must_use]
+pub fn get_filter_subject(subject_prefix: &str, topic_filter: &str) -> String {
+    let levels: Vec<&str> = topic_filter
+        .split(DELIMITER)
+        .map(|level| match level {
+            "+" => "*",
+            "#" => ">",
+            _ => level,
+        })
+        .collect();
+    format!(
+        "{subject_prefix}{DELIMITER}{}",
+        levels.join(&DELIMITER.to_string())
+    )
+}
+
+// Replaces characters which are not valid within NATS subject tokens.
+fn sanitize_subject(subject: &str) -> String {
+    subject
+        .chars()
+        .map(|c| match c {
+            '*' | '>' => '_',
+            c if c.is_whitespace() => '_',
+            c => c,
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn stub_config(use_trader_prefix: bool, use_instance_id: bool) -> HashMap<String, Value> {
+        let mut config = HashMap::new();
+        config.insert("use_trader_prefix".to_string(), json!(use_trader_prefix));
+        config.insert("use_trader_id".to_string(), json!(true));
+        config.insert("use_instance_id".to_string(), json!(use_instance_id));
+        config.insert("streams_prefix".to_string(), json!("streams"));
+        config
+    }
+
+    #[rstest]
+    fn test_get_nats_url_defaults() {
+        let mut config = HashMap::new();
+        config.insert("database".to_string(), json!({"type": "nats"}));
+
+        assert_eq!(get_nats_url(&config), "nats://127.0.0.1:4222");
+    }
+
+    #[rstest]
+    fn test_get_nats_url_with_ssl() {
+        let mut config = HashMap::new();
+        config.insert(
+            "database".to_string(),
+            json!({"type": "nats", "host": "nats.local", "port": 4333, "ssl": true}),
+        );
+
+        assert_eq!(get_nats_url(&config), "tls://nats.local:4333");
+    }
+
+    #[rstest]
+    fn test_get_subject_prefix_with_trader_prefix_and_instance_id() {
+        let trader_id = TraderId::from("tester-123");
+        let instance_id = UUID4::new();
+        let config = stub_config(true, true);
+
+        let prefix = get_subject_prefix(trader_id, instance_id, &config);
+
+        assert_eq!(prefix, format!("trader-tester-123.{instance_id}.streams"));
+    }
+
+    #[rstest]
+    fn test_get_subject_prefix_without_trader_prefix_or_instance_id() {
+        let trader_id = TraderId::from("tester-123");
+        let config = stub_config(false, false);
+
+        let prefix = get_subject_prefix(trader_id, UUID4::new(), &config);
+
+        assert_eq!(prefix, "tester-123.streams");
+        assert_eq!(get_stream_name(&prefix), "tester-123_streams");
+    }
+
+    #[rstest]
+    fn test_get_subject_sanitizes_topic() {
+        let subject = get_subject("streams", "data.bars.AUD/USD.SIM-1 MINUTE*");
+
+        assert_eq!(subject, "streams.data.bars.AUD/USD.SIM-1_MINUTE_");
+    }
+
+    #[rstest]
+    #[case("#", "streams.>")]
+    #[case("data.quotes.+", "streams.data.quotes.*")]
+    #[case("events.order.#", "streams.events.order.>")]
+    #[case("data.quotes.BINANCE", "streams.data.quotes.BINANCE")]
+    fn test_get_filter_subject(#[case] topic_filter: &str, #[case] expected: &str) {
+        assert_eq!(get_filter_subject("streams", topic_filter), expected);
+    }
+}
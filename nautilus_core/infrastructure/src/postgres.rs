@@ -0,0 +1,912 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A [PostgreSQL](https://www.postgresql.org) backend for the cache database.
+//!
+//! Keys follow the same `{collection}:{id}` scheme as the Redis backend, mapped onto tables:
+//!
+//! - `cache_objects`: single value collections (general, currencies, instruments, etc.).
+//! - `accounts`, `orders`, `positions`: one row per entity holding its ordered event payloads.
+//! - `snapshots`: append-only order and position state snapshots.
+//! - `index_sets` and `index_maps`: the cache indexes.
+//!
+//! All rows are scoped by the trader key, so many trader instances can share a database.
+//! The schema is created and upgraded on connection by applying any pending [`MIGRATIONS`].
+
+use std::{
+    collections::{HashMap, VecDeque},
+    path::Path,
+    sync::mpsc::{channel, Receiver, Sender, TryRecvError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Result};
+use nautilus_common::{
+    redis::{get_buffer_interval, get_timeout_duration},
+    runtime::get_runtime,
+};
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::trader_id::TraderId;
+use serde_json::{json, Value};
+use sqlx::{
+    postgres::{PgConnectOptions, PgPoolOptions, PgSslMode},
+    PgConnection, PgPool,
+};
+use tracing::{debug, error};
+
+use crate::{
+    archive,
+    cache::{
+        get_collection_key, get_index_key, get_trader_key, get_update_payload, CacheDatabase,
+        DatabaseCommand, DatabaseOperation, DELIMITER, INDEX_FLAG_CLOSED, INDEX_FLAG_EMULATED,
+        INDEX_FLAG_INFLIGHT, INDEX_FLAG_OPEN,
+    },
+};
+
+// Error constants
+const CHANNEL_TX_FAILED: &str = "Failed to send to channel";
+
+// Connection defaults
+const DEFAULT_HOST: &str = "127.0.0.1";
+const DEFAULT_PORT: u16 = 5432;
+const DEFAULT_USERNAME: &str = "postgres";
+const DEFAULT_DATABASE_NAME: &str = "nautilus";
+const DEFAULT_TIMEOUT_SECS: u64 = 20;
+
+// Collection keys
+const INDEX: &str = "index";
+const GENERAL: &str = "general";
+const CURRENCIES: &str = "currencies";
+const INSTRUMENTS: &str = "instruments";
+const SYNTHETICS: &str = "synthetics";
+const ACCOUNTS: &str = "accounts";
+const ORDERS: &str = "orders";
+const POSITIONS: &str = "positions";
+const ACTORS: &str = "actors";
+const STRATEGIES: &str = "strategies";
+const SNAPSHOTS: &str = "snapshots";
+const HEALTH: &str = "health";
+
+// Index keys
+const INDEX_ORDER_IDS: &str = "order_ids";
+const INDEX_ORDER_POSITION: &str = "order_position";
+const INDEX_ORDER_CLIENT: &str = "order_client";
+const INDEX_ORDERS: &str = "orders";
+const INDEX_ORDERS_OPEN: &str = "orders_open";
+const INDEX_ORDERS_CLOSED: &str = "orders_closed";
+const INDEX_ORDERS_EMULATED: &str = "orders_emulated";
+const INDEX_ORDERS_INFLIGHT: &str = "orders_inflight";
+const INDEX_POSITIONS: &str = "positions";
+const INDEX_POSITIONS_OPEN: &str = "positions_open";
+const INDEX_POSITIONS_CLOSED: &str = "positions_closed";
+
+/// The collections held in the `cache_objects` table (one value per key).
+const OBJECT_COLLECTIONS: [&str; 7] = [
+    GENERAL,
+    CURRENCIES,
+    INSTRUMENTS,
+    SYNTHETICS,
+    ACTORS,
+    STRATEGIES,
+    HEALTH,
+];
+
+/// The ordered schema migrations as `(version, SQL)`, applied once each on connection.
+pub const MIGRATIONS: &[(i32, &str)] = &[(
+    1,
+    r"
+CREATE TABLE IF NOT EXISTS cache_objects (
+    trader_key TEXT NOT NULL,
+    collection TEXT NOT NULL,
+    id TEXT NOT NULL,
+    value BYTEA NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (trader_key, collection, id)
+);
+CREATE TABLE IF NOT EXISTS accounts (
+    trader_key TEXT NOT NULL,
+    id TEXT NOT NULL,
+    events BYTEA[] NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (trader_key, id)
+);
+CREATE TABLE IF NOT EXISTS orders (
+    trader_key TEXT NOT NULL,
+    id TEXT NOT NULL,
+    events BYTEA[] NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (trader_key, id)
+);
+CREATE TABLE IF NOT EXISTS positions (
+    trader_key TEXT NOT NULL,
+    id TEXT NOT NULL,
+    events BYTEA[] NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (trader_key, id)
+);
+CREATE TABLE IF NOT EXISTS snapshots (
+    seq BIGSERIAL PRIMARY KEY,
+    trader_key TEXT NOT NULL,
+    id TEXT NOT NULL,
+    value BYTEA NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+CREATE INDEX IF NOT EXISTS snapshots_trader_key_id_idx ON snapshots (trader_key, id);
+CREATE TABLE IF NOT EXISTS index_sets (
+    trader_key TEXT NOT NULL,
+    name TEXT NOT NULL,
+    member BYTEA NOT NULL,
+    PRIMARY KEY (trader_key, name, member)
+);
+CREATE TABLE IF NOT EXISTS index_maps (
+    trader_key TEXT NOT NULL,
+    name TEXT NOT NULL,
+    field TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (trader_key, name, field)
+);
+",
+)];
+
+// Arbitrary key for the advisory lock held while migrating (so concurrent nodes serialize)
+const MIGRATIONS_LOCK_KEY: i64 = 0x6E61_7574_696C_7573;
+
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.infrastructure")
+)]
+pub struct PostgresCacheDatabase {
+    pub trader_id: TraderId,
+    trader_key: String,
+    pool: PgPool,
+    tx: Sender<DatabaseCommand>,
+}
+
+impl CacheDatabase for PostgresCacheDatabase {
+    type DatabaseType = PostgresCacheDatabase;
+
+    fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: HashMap<String, Value>,
+    ) -> Result<PostgresCacheDatabase> {
+        debug!("Initializing trader_id={trader_id}, instance_id={instance_id}");
+        let pool = get_runtime().block_on(connect(&config))?;
+        debug!("Connected");
+
+        get_runtime().block_on(run_migrations(&pool))?;
+
+        let (tx, rx) = channel::<DatabaseCommand>();
+        let trader_key = get_trader_key(trader_id, instance_id, &config);
+        let trader_key_clone = trader_key.clone();
+
+        let _join_handle = thread::Builder::new()
+            .name("cache-postgres".to_string())
+            .spawn(move || {
+                Self::handle_messages(rx, trader_key_clone, config);
+            })
+            .expect("Error spawning `cache-postgres` thread");
+
+        Ok(PostgresCacheDatabase {
+            trader_id,
+            trader_key,
+            pool,
+            tx,
+        })
+    }
+
+    /// Deletes all data for this trader key (data for other traders is unaffected).
+    fn flushdb(&mut self) -> Result<()> {
+        get_runtime().block_on(flush(&self.pool, &self.trader_key))
+    }
+
+    /// Returns the full keys (including the trader key) matching the glob-style `pattern`.
+    ///
+    /// Index and snapshot keys are not returned, as these are not loaded by key.
+    fn keys(&mut self, pattern: &str) -> Result<Vec<String>> {
+        get_runtime().block_on(read_keys(&self.pool, &self.trader_key, pattern))
+    }
+
+    fn read(&mut self, key: &str) -> Result<Vec<Vec<u8>>> {
+        get_runtime().block_on(read(&self.pool, &self.trader_key, key))
+    }
+
+    fn insert(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+        let op = DatabaseCommand::new(DatabaseOperation::Insert, key, payload);
+        match self.tx.send(op) {
+            Ok(_) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+
+    fn update(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+        let op = DatabaseCommand::new(DatabaseOperation::Update, key, payload);
+        match self.tx.send(op) {
+            Ok(_) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+
+    fn delete(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+        let op = DatabaseCommand::new(DatabaseOperation::Delete, key, payload);
+        match self.tx.send(op) {
+            Ok(_) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+
+    fn update_order(&mut self, key: String, event: Vec<u8>, flags: u8) -> Result<()> {
+        let payload = Some(vec![event, vec![flags]]);
+        let op = DatabaseCommand::new(DatabaseOperation::UpdateOrder, key, payload);
+        match self.tx.send(op) {
+            Ok(_) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+
+    fn update_position(&mut self, key: String, event: Vec<u8>, flags: u8) -> Result<()> {
+        let payload = Some(vec![event, vec![flags]]);
+        let op = DatabaseCommand::new(DatabaseOperation::UpdatePosition, key, payload);
+        match self.tx.send(op) {
+            Ok(_) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+
+    fn handle_messages(
+        rx: Receiver<DatabaseCommand>,
+        trader_key: String,
+        config: HashMap<String, Value>,
+    ) {
+        let pool = get_runtime()
+            .block_on(connect(&config))
+            .expect("Failed to connect to Postgres");
+
+        // Buffering
+        let mut buffer: VecDeque<DatabaseCommand> = VecDeque::new();
+        let mut last_drain = Instant::now();
+        let recv_interval = Duration::from_millis(1);
+        let buffer_interval = get_buffer_interval(&config);
+
+        loop {
+            if last_drain.elapsed() >= buffer_interval && !buffer.is_empty() {
+                get_runtime().block_on(drain_buffer(&pool, &trader_key, &mut buffer));
+                last_drain = Instant::now();
+            } else {
+                // Continue to receive and handle messages until channel is hung up
+                match rx.try_recv() {
+                    Ok(msg) => buffer.push_back(msg),
+                    Err(TryRecvError::Empty) => thread::sleep(recv_interval),
+                    Err(TryRecvError::Disconnected) => break, // Channel hung up
+                }
+            }
+        }
+
+        // Drain any remaining messages
+        if !buffer.is_empty() {
+            get_runtime().block_on(drain_buffer(&pool, &trader_key, &mut buffer));
+        }
+    }
+}
+
+impl PostgresCacheDatabase {
+    /// Exports the entire cache state for this trader to a compressed archive at `path`.
+    ///
+    /// Returns the number of entries archived.
+    pub fn export_archive(&mut self, path: &Path) -> Result<usize> {
+        let trader_key = self.trader_key.clone();
+        archive::export_archive(self, &trader_key, path)
+    }
+
+    /// Restores the cache state from the compressed archive at `path`.
+    ///
+    /// Returns the number of entries restored.
+    pub fn restore_archive(&mut self, path: &Path) -> Result<usize> {
+        archive::restore_archive(self, path)
+    }
+
+    /// Loads all entries of the given `collection` in a single query.
+    ///
+    /// Returns a map of `{collection}:{id}` keys to their payloads (the ordered events for
+    /// accounts, orders and positions, otherwise a single value), for bulk loading on startup.
+    pub fn load_collection(&mut self, collection: &str) -> Result<HashMap<String, Vec<Vec<u8>>>> {
+        get_runtime().block_on(read_collection(&self.pool, &self.trader_key, collection))
+    }
+}
+
+async fn flush(pool: &PgPool, trader_key: &str) -> Result<()> {
+    let mut transaction = pool.begin().await?;
+    for table in [
+        "cache_objects",
+        "accounts",
+        "orders",
+        "positions",
+        "snapshots",
+        "index_sets",
+        "index_maps",
+    ] {
+        sqlx::query(&format!("DELETE FROM {table} WHERE trader_key = $1"))
+            .bind(trader_key)
+            .execute(&mut *transaction)
+            .await?;
+    }
+    transaction.commit().await?;
+    Ok(())
+}
+
+async fn drain_buffer(pool: &PgPool, trader_key: &str, buffer: &mut VecDeque<DatabaseCommand>) {
+    // All buffered commands are applied atomically, as with the Redis pipeline
+    let mut transaction = match pool.begin().await {
+        Ok(transaction) => transaction,
+        Err(e) => {
+            error!("Failed to begin transaction: {e}");
+            return; // Buffered commands are retained to retry on next drain
+        }
+    };
+
+    for msg in buffer.drain(..) {
+        match apply_command(&mut transaction, trader_key, &msg).await {
+            Ok(()) => {}
+            Err(e) if e.is::<sqlx::Error>() => {
+                // The transaction is aborted, so is rolled back when dropped
+                error!(
+                    "Failed to apply command for '{}', rolled back: {e}",
+                    msg.key
+                );
+                return;
+            }
+            Err(e) => error!("{e}"),
+        }
+    }
+
+    if let Err(e) = transaction.commit().await {
+        error!("Failed to commit transaction: {e}");
+    }
+}
+
+async fn apply_command(
+    conn: &mut PgConnection,
+    trader_key: &str,
+    msg: &DatabaseCommand,
+) -> Result<()> {
+    let collection = get_collection_key(&msg.key)?;
+    let id = get_index_key(&msg.key)?;
+
+    match msg.op_type {
+        DatabaseOperation::Insert => {
+            let payload = get_payload(msg, "insert")?;
+            match collection {
+                INDEX => insert_index(conn, trader_key, id, payload).await?,
+                ACCOUNTS | ORDERS | POSITIONS => {
+                    insert_event(conn, trader_key, collection, id, &payload[0]).await?;
+                }
+                SNAPSHOTS => {
+                    sqlx::query(
+                        "INSERT INTO snapshots (trader_key, id, value) VALUES ($1, $2, $3)",
+                    )
+                    .bind(trader_key)
+                    .bind(id)
+                    .bind(payload[0].as_slice())
+                    .execute(&mut *conn)
+                    .await?;
+                }
+                c if OBJECT_COLLECTIONS.contains(&c) => {
+                    sqlx::query(
+                        "INSERT INTO cache_objects (trader_key, collection, id, value) \
+                         VALUES ($1, $2, $3, $4) \
+                         ON CONFLICT (trader_key, collection, id) DO UPDATE \
+                         SET value = EXCLUDED.value, updated_at = now()",
+                    )
+                    .bind(trader_key)
+                    .bind(collection)
+                    .bind(id)
+                    .bind(payload[0].as_slice())
+                    .execute(&mut *conn)
+                    .await?;
+                }
+                _ => bail!("Unsupported operation: `insert` for collection '{collection}'"),
+            }
+        }
+        DatabaseOperation::Update => {
+            let payload = get_payload(msg, "update")?;
+            match collection {
+                ACCOUNTS | ORDERS | POSITIONS => {
+                    append_event(conn, trader_key, collection, id, &payload[0]).await?;
+                }
+                _ => bail!("Unsupported operation: `update` for collection '{collection}'"),
+            }
+        }
+        DatabaseOperation::Delete => match collection {
+            INDEX => {
+                // `payload` is required to target the index member to remove
+                let payload = get_payload(msg, "delete")?;
+                remove_index(conn, trader_key, id, payload).await?;
+            }
+            ACTORS | STRATEGIES => {
+                sqlx::query(
+                    "DELETE FROM cache_objects \
+                     WHERE trader_key = $1 AND collection = $2 AND id = $3",
+                )
+                .bind(trader_key)
+                .bind(collection)
+                .bind(id)
+                .execute(&mut *conn)
+                .await?;
+            }
+            _ => bail!("Unsupported operation: `delete` for collection '{collection}'"),
+        },
+        // The event and index updates are atomic within the drain transaction
+        DatabaseOperation::UpdateOrder => {
+            let (event, flags) = get_update_payload(msg.payload.as_ref())?;
+            append_event(conn, trader_key, ORDERS, id, event).await?;
+
+            let member = id.as_bytes();
+            let is_inflight = flags & INDEX_FLAG_INFLIGHT != 0;
+            set_index(conn, trader_key, INDEX_ORDERS_INFLIGHT, member, is_inflight).await?;
+            if flags & INDEX_FLAG_OPEN != 0 {
+                set_index(conn, trader_key, INDEX_ORDERS_CLOSED, member, false).await?;
+                set_index(conn, trader_key, INDEX_ORDERS_OPEN, member, true).await?;
+            } else if flags & INDEX_FLAG_CLOSED != 0 {
+                set_index(conn, trader_key, INDEX_ORDERS_OPEN, member, false).await?;
+                set_index(conn, trader_key, INDEX_ORDERS_CLOSED, member, true).await?;
+            }
+            let is_emulated = flags & INDEX_FLAG_EMULATED != 0;
+            set_index(conn, trader_key, INDEX_ORDERS_EMULATED, member, is_emulated).await?;
+        }
+        DatabaseOperation::UpdatePosition => {
+            let (event, flags) = get_update_payload(msg.payload.as_ref())?;
+            append_event(conn, trader_key, POSITIONS, id, event).await?;
+
+            let member = id.as_bytes();
+            if flags & INDEX_FLAG_OPEN != 0 {
+                set_index(conn, trader_key, INDEX_POSITIONS_CLOSED, member, false).await?;
+                set_index(conn, trader_key, INDEX_POSITIONS_OPEN, member, true).await?;
+            } else if flags & INDEX_FLAG_CLOSED != 0 {
+                set_index(conn, trader_key, INDEX_POSITIONS_OPEN, member, false).await?;
+                set_index(conn, trader_key, INDEX_POSITIONS_CLOSED, member, true).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn get_payload<'a>(msg: &'a DatabaseCommand, op: &str) -> Result<&'a [Vec<u8>]> {
+    match &msg.payload {
+        Some(payload) if !payload.is_empty() => Ok(payload),
+        Some(_) => bail!("Empty `payload` for `{op}` '{}'", msg.key),
+        None => bail!("Null `payload` for `{op}` '{}'", msg.key),
+    }
+}
+
+/// Inserts the `event` for an account, order or position, appending to any existing events.
+async fn insert_event(
+    conn: &mut PgConnection,
+    trader_key: &str,
+    collection: &str,
+    id: &str,
+    event: &[u8],
+) -> Result<()> {
+    sqlx::query(&format!(
+        "INSERT INTO {collection} (trader_key, id, events) VALUES ($1, $2, ARRAY[$3::BYTEA]) \
+         ON CONFLICT (trader_key, id) DO UPDATE \
+         SET events = {collection}.events || EXCLUDED.events, updated_at = now()"
+    ))
+    .bind(trader_key)
+    .bind(id)
+    .bind(event)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// Appends the `event` to an existing account, order or position (otherwise does nothing).
+async fn append_event(
+    conn: &mut PgConnection,
+    trader_key: &str,
+    collection: &str,
+    id: &str,
+    event: &[u8],
+) -> Result<()> {
+    sqlx::query(&format!(
+        "UPDATE {collection} SET events = array_append(events, $3::BYTEA), updated_at = now() \
+         WHERE trader_key = $1 AND id = $2"
+    ))
+    .bind(trader_key)
+    .bind(id)
+    .bind(event)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+async fn set_index(
+    conn: &mut PgConnection,
+    trader_key: &str,
+    index_key: &str,
+    member: &[u8],
+    is_member: bool,
+) -> Result<()> {
+    let sql = if is_member {
+        "INSERT INTO index_sets (trader_key, name, member) VALUES ($1, $2, $3) \
+         ON CONFLICT DO NOTHING"
+    } else {
+        "DELETE FROM index_sets WHERE trader_key = $1 AND name = $2 AND member = $3"
+    };
+    sqlx::query(sql)
+        .bind(trader_key)
+        .bind(index_key)
+        .bind(member)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+async fn insert_index(
+    conn: &mut PgConnection,
+    trader_key: &str,
+    index_key: &str,
+    payload: &[Vec<u8>],
+) -> Result<()> {
+    match index_key {
+        INDEX_ORDER_POSITION | INDEX_ORDER_CLIENT => {
+            if payload.len() < 2 {
+                bail!("Invalid `payload` for index '{index_key}', expected field and value");
+            }
+            let field = String::from_utf8(payload[0].clone())?;
+            let value = String::from_utf8(payload[1].clone())?;
+            sqlx::query(
+                "INSERT INTO index_maps (trader_key, name, field, value) VALUES ($1, $2, $3, $4) \
+                 ON CONFLICT (trader_key, name, field) DO UPDATE SET value = EXCLUDED.value",
+            )
+            .bind(trader_key)
+            .bind(index_key)
+            .bind(field)
+            .bind(value)
+            .execute(&mut *conn)
+            .await?;
+        }
+        INDEX_ORDER_IDS
+        | INDEX_ORDERS
+        | INDEX_ORDERS_OPEN
+        | INDEX_ORDERS_CLOSED
+        | INDEX_ORDERS_EMULATED
+        | INDEX_ORDERS_INFLIGHT
+        | INDEX_POSITIONS
+        | INDEX_POSITIONS_OPEN
+        | INDEX_POSITIONS_CLOSED => {
+            set_index(conn, trader_key, index_key, &payload[0], true).await?;
+        }
+        _ => bail!("Index unknown '{index_key}' on insert"),
+    }
+    Ok(())
+}
+
+async fn remove_index(
+    conn: &mut PgConnection,
+    trader_key: &str,
+    index_key: &str,
+    payload: &[Vec<u8>],
+) -> Result<()> {
+    match index_key {
+        INDEX_ORDERS_OPEN
+        | INDEX_ORDERS_CLOSED
+        | INDEX_ORDERS_EMULATED
+        | INDEX_ORDERS_INFLIGHT
+        | INDEX_POSITIONS_OPEN
+        | INDEX_POSITIONS_CLOSED => {
+            set_index(conn, trader_key, index_key, &payload[0], false).await
+        }
+        _ => bail!("Unsupported index operation: remove from '{index_key}'"),
+    }
+}
+
+async fn read_keys(pool: &PgPool, trader_key: &str, pattern: &str) -> Result<Vec<String>> {
+    let keys = sqlx::query_scalar(
+        "SELECT key FROM (
+            SELECT $1 || ':' || collection || ':' || id AS key
+            FROM cache_objects WHERE trader_key = $1
+            UNION ALL SELECT $1 || ':accounts:' || id FROM accounts WHERE trader_key = $1
+            UNION ALL SELECT $1 || ':orders:' || id FROM orders WHERE trader_key = $1
+            UNION ALL SELECT $1 || ':positions:' || id FROM positions WHERE trader_key = $1
+        ) AS keys WHERE key LIKE $2 ESCAPE '\\'",
+    )
+    .bind(trader_key)
+    .bind(glob_to_like(pattern))
+    .fetch_all(pool)
+    .await?;
+    Ok(keys)
+}
+
+async fn read(pool: &PgPool, trader_key: &str, key: &str) -> Result<Vec<Vec<u8>>> {
+    let collection = get_collection_key(key)?;
+    let id = get_index_key(key)?;
+
+    match collection {
+        INDEX => read_index(pool, trader_key, id).await,
+        ACCOUNTS | ORDERS | POSITIONS => {
+            let events: Option<Vec<Vec<u8>>> = sqlx::query_scalar(&format!(
+                "SELECT events FROM {collection} WHERE trader_key = $1 AND id = $2"
+            ))
+            .bind(trader_key)
+            .bind(id)
+            .fetch_optional(pool)
+            .await?;
+            Ok(events.unwrap_or_default())
+        }
+        c if OBJECT_COLLECTIONS.contains(&c) && c != HEALTH => {
+            let value: Option<Vec<u8>> = sqlx::query_scalar(
+                "SELECT value FROM cache_objects \
+                 WHERE trader_key = $1 AND collection = $2 AND id = $3",
+            )
+            .bind(trader_key)
+            .bind(collection)
+            .bind(id)
+            .fetch_optional(pool)
+            .await?;
+            Ok(value.into_iter().collect())
+        }
+        _ => bail!("Unsupported operation: `read` for collection '{collection}'"),
+    }
+}
+
+async fn read_index(pool: &PgPool, trader_key: &str, index_key: &str) -> Result<Vec<Vec<u8>>> {
+    match index_key {
+        INDEX_ORDER_POSITION | INDEX_ORDER_CLIENT => {
+            let rows: Vec<(String, String)> = sqlx::query_as(
+                "SELECT field, value FROM index_maps WHERE trader_key = $1 AND name = $2",
+            )
+            .bind(trader_key)
+            .bind(index_key)
+            .fetch_all(pool)
+            .await?;
+            let map: HashMap<String, String> = rows.into_iter().collect();
+            Ok(vec![serde_json::to_string(&map)?.into_bytes()])
+        }
+        INDEX_ORDER_IDS
+        | INDEX_ORDERS
+        | INDEX_ORDERS_OPEN
+        | INDEX_ORDERS_CLOSED
+        | INDEX_ORDERS_EMULATED
+        | INDEX_ORDERS_INFLIGHT
+        | INDEX_POSITIONS
+        | INDEX_POSITIONS_OPEN
+        | INDEX_POSITIONS_CLOSED => {
+            let members = sqlx::query_scalar(
+                "SELECT member FROM index_sets WHERE trader_key = $1 AND name = $2",
+            )
+            .bind(trader_key)
+            .bind(index_key)
+            .fetch_all(pool)
+            .await?;
+            Ok(members)
+        }
+        _ => bail!("Index unknown '{index_key}' on read"),
+    }
+}
+
+async fn read_collection(
+    pool: &PgPool,
+    trader_key: &str,
+    collection: &str,
+) -> Result<HashMap<String, Vec<Vec<u8>>>> {
+    let rows: Vec<(String, Vec<Vec<u8>>)> = match collection {
+        ACCOUNTS | ORDERS | POSITIONS => {
+            sqlx::query_as(&format!(
+                "SELECT id, events FROM {collection} WHERE trader_key = $1"
+            ))
+            .bind(trader_key)
+            .fetch_all(pool)
+            .await?
+        }
+        c if OBJECT_COLLECTIONS.contains(&c) => {
+            sqlx::query_as(
+                "SELECT id, ARRAY[value] FROM cache_objects \
+                 WHERE trader_key = $1 AND collection = $2",
+            )
+            .bind(trader_key)
+            .bind(collection)
+            .fetch_all(pool)
+            .await?
+        }
+        _ => bail!("Unsupported operation: `load_collection` for collection '{collection}'"),
+    };
+
+    Ok(rows
+        .into_iter()
+        .map(|(id, payloads)| (format!("{collection}{DELIMITER}{id}"), payloads))
+        .collect())
+}
+
+/// Applies any pending [`MIGRATIONS`] in order, recording each applied version.
+pub async fn run_migrations(pool: &PgPool) -> Result<()> {
+    let mut transaction = pool.begin().await?;
+    sqlx::query("SELECT pg_advisory_xact_lock($1)")
+        .bind(MIGRATIONS_LOCK_KEY)
+        .execute(&mut *transaction)
+        .await?;
+    sqlx::raw_sql(
+        "CREATE TABLE IF NOT EXISTS cache_migrations (
+            version INTEGER PRIMARY KEY,
+            applied_at TIMESTAMPTZ NOT NULL DEFAULT now()
+        )",
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    let current: i32 = sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) FROM cache_migrations")
+        .fetch_one(&mut *transaction)
+        .await?;
+
+    for (version, sql) in MIGRATIONS.iter().filter(|(v, _)| *v > current) {
+        debug!("Applying cache schema migration {version}");
+        sqlx::raw_sql(sql)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| anyhow!("Failed to apply cache schema migration {version}: {e}"))?;
+        sqlx::query("INSERT INTO cache_migrations (version) VALUES ($1)")
+            .bind(version)
+            .execute(&mut *transaction)
+            .await?;
+    }
+
+    transaction.commit().await?;
+    Ok(())
+}
+
+async fn connect(config: &HashMap<String, Value>) -> Result<PgPool> {
+    let pool = PgPoolOptions::new()
+        .acquire_timeout(get_timeout_duration(config, DEFAULT_TIMEOUT_SECS))
+        .connect_with(get_postgres_options(config))
+        .await?;
+    Ok(pool)
+}
+
+fn get_use_ssl(config: &HashMap<String, Value>) -> bool {
+    config
+        .get("database")
+        .and_then(|database| database.get("ssl"))
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+/// Returns the Postgres connection options for the given cache `config`.
+#[must_use]
+pub fn get_postgres_options(config: &HashMap<String, Value>) -> PgConnectOptions {
+    let empty = json!({});
+    let database = config.get("database").unwrap_or(&empty);
+
+    let host = database
+        .get("host")
+        .and_then(Value::as_str)
+        .unwrap_or(DEFAULT_HOST);
+    let port = database
+        .get("port")
+        .and_then(Value::as_u64)
+        .and_then(|v| u16::try_from(v).ok())
+        .unwrap_or(DEFAULT_PORT);
+    let username = database
+        .get("username")
+        .and_then(Value::as_str)
+        .unwrap_or(DEFAULT_USERNAME);
+    let dbname = database
+        .get("name")
+        .and_then(Value::as_str)
+        .unwrap_or(DEFAULT_DATABASE_NAME);
+    let ssl_mode = if get_use_ssl(config) {
+        PgSslMode::Require
+    } else {
+        PgSslMode::Disable
+    };
+
+    let options = PgConnectOptions::new_without_pgpass()
+        .host(host)
+        .port(port)
+        .username(username)
+        .database(dbname)
+        .application_name("nautilus_trader")
+        .ssl_mode(ssl_mode);
+
+    match database.get("password").and_then(Value::as_str) {
+        Some(password) => options.password(password),
+        None => options,
+    }
+}
+
+/// Converts a glob-style `pattern` (as used for Redis keys) into a SQL `LIKE` pattern.
+fn glob_to_like(pattern: &str) -> String {
+    let mut like = String::with_capacity(pattern.len());
+    for c in pattern.chars() {
+        match c {
+            '*' => like.push('%'),
+            '?' => like.push('_'),
+            '%' | '_' | '\\' => {
+                like.push('\\');
+                like.push(c);
+            }
+            _ => like.push(c),
+        }
+    }
+    like
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_postgres_options_defaults() {
+        let config = HashMap::new();
+
+        let options = get_postgres_options(&config);
+
+        assert_eq!(options.get_host(), DEFAULT_HOST);
+        assert_eq!(options.get_port(), DEFAULT_PORT);
+        assert_eq!(options.get_username(), DEFAULT_USERNAME);
+        assert_eq!(options.get_database(), Some(DEFAULT_DATABASE_NAME));
+        assert_eq!(options.get_application_name(), Some("nautilus_trader"));
+        assert!(matches!(options.get_ssl_mode(), PgSslMode::Disable));
+    }
+
+    #[rstest]
+    fn test_get_postgres_options_from_database_config() {
+        let mut config = HashMap::new();
+        config.insert(
+            "database".to_string(),
+            json!({
+                "type": "postgres",
+                "host": "db.local",
+                "port": 6543,
+                "username": "trader",
+                "password": "secret",
+                "name": "live",
+                "ssl": true,
+            }),
+        );
+
+        let options = get_postgres_options(&config);
+
+        assert_eq!(options.get_host(), "db.local");
+        assert_eq!(options.get_port(), 6543);
+        assert_eq!(options.get_username(), "trader");
+        assert_eq!(options.get_database(), Some("live"));
+        assert!(matches!(options.get_ssl_mode(), PgSslMode::Require));
+    }
+
+    #[rstest]
+    #[case("*:orders*", "%:orders%")]
+    #[case("trader-001:general:?", "trader-001:general:_")]
+    #[case("*:index:order_ids", "%:index:order\\_ids")]
+    #[case("100%", "100\\%")]
+    fn test_glob_to_like(#[case] pattern: &str, #[case] expected: &str) {
+        assert_eq!(glob_to_like(pattern), expected);
+    }
+
+    #[rstest]
+    fn test_migrations_are_ordered_and_unique() {
+        let versions: Vec<i32> = MIGRATIONS.iter().map(|(v, _)| *v).collect();
+        let mut sorted = versions.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(versions, sorted);
+        assert_eq!(versions.first(), Some(&1));
+    }
+}
@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
 
 use nautilus_core::{
     python::{to_pyruntime_err, to_pyvalue_err},
@@ -90,4 +90,36 @@ impl RedisCacheDatabase {
             Err(e) => Err(to_pyvalue_err(e)),
         }
     }
+
+    #[pyo3(name = "update_order")]
+    fn py_update_order(&mut self, key: String, event: Vec<u8>, flags: u8) -> PyResult<()> {
+        match self.update_order(key, event, flags) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(to_pyvalue_err(e)),
+        }
+    }
+
+    #[pyo3(name = "update_position")]
+    fn py_update_position(&mut self, key: String, event: Vec<u8>, flags: u8) -> PyResult<()> {
+        match self.update_position(key, event, flags) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(to_pyvalue_err(e)),
+        }
+    }
+
+    #[pyo3(name = "export_archive")]
+    fn py_export_archive(&mut self, path: PathBuf) -> PyResult<usize> {
+        match self.export_archive(&path) {
+            Ok(count) => Ok(count),
+            Err(e) => Err(to_pyruntime_err(e)),
+        }
+    }
+
+    #[pyo3(name = "restore_archive")]
+    fn py_restore_archive(&mut self, path: PathBuf) -> PyResult<usize> {
+        match self.restore_archive(&path) {
+            Ok(count) => Ok(count),
+            Err(e) => Err(to_pyruntime_err(e)),
+        }
+    }
 }
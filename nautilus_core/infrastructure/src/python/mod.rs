@@ -16,9 +16,29 @@
 use pyo3::{prelude::*, pymodule};
 
 pub mod cache;
+#[cfg(feature = "kafka")]
+pub mod kafka;
+#[cfg(feature = "nats")]
+pub mod nats;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 
 #[pymodule]
 pub fn infrastructure(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<crate::redis::RedisCacheDatabase>()?;
+    #[cfg(feature = "nats")]
+    m.add_class::<crate::nats::NatsMessageBusDatabase>()?;
+    #[cfg(feature = "nats")]
+    m.add_class::<crate::nats::NatsMessageBusConsumer>()?;
+    #[cfg(feature = "kafka")]
+    m.add_class::<crate::kafka::KafkaMessageBusDatabase>()?;
+    #[cfg(feature = "kafka")]
+    m.add_class::<crate::kafka::KafkaMessageBusConsumer>()?;
+    #[cfg(feature = "postgres")]
+    m.add_class::<crate::postgres::PostgresCacheDatabase>()?;
+    #[cfg(feature = "sqlite")]
+    m.add_class::<crate::sqlite::SqliteCacheDatabase>()?;
     Ok(())
 }
@@ -0,0 +1,110 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, path::PathBuf};
+
+use nautilus_core::{
+    python::{to_pyruntime_err, to_pyvalue_err},
+    uuid::UUID4,
+};
+use nautilus_model::identifiers::trader_id::TraderId;
+use pyo3::{prelude::*, types::PyBytes};
+
+use crate::{cache::CacheDatabase, sqlite::SqliteCacheDatabase};
+
+#[pymethods]
+impl SqliteCacheDatabase {
+    #[new]
+    fn py_new(trader_id: TraderId, instance_id: UUID4, config_json: Vec<u8>) -> PyResult<Self> {
+        let config: HashMap<String, serde_json::Value> =
+            serde_json::from_slice(&config_json).map_err(to_pyvalue_err)?;
+
+        Self::new(trader_id, instance_id, config).map_err(to_pyruntime_err)
+    }
+
+    #[pyo3(name = "flushdb")]
+    fn py_flushdb(&mut self) -> PyResult<()> {
+        self.flushdb().map_err(to_pyruntime_err)
+    }
+
+    #[pyo3(name = "keys")]
+    fn py_keys(&mut self, pattern: &str) -> PyResult<Vec<String>> {
+        self.keys(pattern).map_err(to_pyruntime_err)
+    }
+
+    #[pyo3(name = "read")]
+    fn py_read(&mut self, py: Python, key: &str) -> PyResult<Vec<PyObject>> {
+        let result = self.read(key).map_err(to_pyruntime_err)?;
+        Ok(result
+            .into_iter()
+            .map(|r| PyBytes::new(py, &r).into())
+            .collect())
+    }
+
+    #[pyo3(name = "load_collection")]
+    fn py_load_collection(
+        &mut self,
+        py: Python,
+        collection: &str,
+    ) -> PyResult<HashMap<String, Vec<PyObject>>> {
+        let result = self.load_collection(collection).map_err(to_pyruntime_err)?;
+        Ok(result
+            .into_iter()
+            .map(|(key, payloads)| {
+                let payloads = payloads
+                    .into_iter()
+                    .map(|r| PyBytes::new(py, &r).into())
+                    .collect();
+                (key, payloads)
+            })
+            .collect())
+    }
+
+    #[pyo3(name = "insert")]
+    fn py_insert(&mut self, key: String, payload: Vec<Vec<u8>>) -> PyResult<()> {
+        self.insert(key, Some(payload)).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "update")]
+    fn py_update(&mut self, key: String, payload: Vec<Vec<u8>>) -> PyResult<()> {
+        self.update(key, Some(payload)).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "delete")]
+    fn py_delete(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> PyResult<()> {
+        self.delete(key, payload).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "update_order")]
+    fn py_update_order(&mut self, key: String, event: Vec<u8>, flags: u8) -> PyResult<()> {
+        self.update_order(key, event, flags).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "update_position")]
+    fn py_update_position(&mut self, key: String, event: Vec<u8>, flags: u8) -> PyResult<()> {
+        self.update_position(key, event, flags)
+            .map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "export_archive")]
+    fn py_export_archive(&mut self, path: PathBuf) -> PyResult<usize> {
+        self.export_archive(&path).map_err(to_pyruntime_err)
+    }
+
+    #[pyo3(name = "restore_archive")]
+    fn py_restore_archive(&mut self, path: PathBuf) -> PyResult<usize> {
+        self.restore_archive(&path).map_err(to_pyruntime_err)
+    }
+}
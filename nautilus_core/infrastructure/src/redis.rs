@@ -15,27 +15,36 @@
 
 use std::{
     collections::{HashMap, VecDeque},
+    path::Path,
     sync::mpsc::{channel, Receiver, Sender, TryRecvError},
     thread,
     time::{Duration, Instant},
 };
 
 use anyhow::{anyhow, bail, Result};
-use nautilus_common::redis::{get_buffer_interval, get_redis_url, get_timeout_duration};
+use nautilus_common::redis::{
+    get_buffer_interval, get_max_batch_size, get_redis_url, get_timeout_duration,
+};
 use nautilus_core::uuid::UUID4;
 use nautilus_model::identifiers::trader_id::TraderId;
 use redis::{Commands, Connection, Pipeline};
-use serde_json::json;
 use tracing::debug;
 
-use crate::cache::{CacheDatabase, DatabaseCommand, DatabaseOperation};
+use crate::{
+    archive,
+    cache::{
+        get_collection_key, get_index_key, get_trader_key, get_update_payload, receive_pending,
+        CacheDatabase, DatabaseCommand, DatabaseOperation, DELIMITER, INDEX_FLAG_CLOSED,
+        INDEX_FLAG_EMULATED, INDEX_FLAG_INFLIGHT, INDEX_FLAG_OPEN,
+    },
+};
 
 // Error constants
 const CHANNEL_TX_FAILED: &str = "Failed to send to channel";
 
 // Redis constants
 const FLUSHDB: &str = "FLUSHDB";
-const DELIMITER: char = ':';
+const EVAL: &str = "EVAL";
 
 // Collection keys
 const INDEX: &str = "index";
@@ -64,6 +73,48 @@ const INDEX_POSITIONS: &str = "index:positions";
 const INDEX_POSITIONS_OPEN: &str = "index:positions_open";
 const INDEX_POSITIONS_CLOSED: &str = "index:positions_closed";
 
+// Appends an order event (if the order exists) and moves the order between the state indexes.
+// KEYS: order, orders_inflight, orders_open, orders_closed, orders_emulated
+// ARGV: event, client_order_id, inflight, open, closed, emulated
+const UPDATE_ORDER_SCRIPT: &str = r"
+if redis.call('EXISTS', KEYS[1]) == 1 then
+    redis.call('RPUSH', KEYS[1], ARGV[1])
+end
+if ARGV[3] == '1' then
+    redis.call('SADD', KEYS[2], ARGV[2])
+else
+    redis.call('SREM', KEYS[2], ARGV[2])
+end
+if ARGV[4] == '1' then
+    redis.call('SREM', KEYS[4], ARGV[2])
+    redis.call('SADD', KEYS[3], ARGV[2])
+elseif ARGV[5] == '1' then
+    redis.call('SREM', KEYS[3], ARGV[2])
+    redis.call('SADD', KEYS[4], ARGV[2])
+end
+if ARGV[6] == '1' then
+    redis.call('SADD', KEYS[5], ARGV[2])
+else
+    redis.call('SREM', KEYS[5], ARGV[2])
+end
+";
+
+// Appends a position event (if the position exists) and moves the position between the state indexes.
+// KEYS: position, positions_open, positions_closed
+// ARGV: event, position_id, open, closed
+const UPDATE_POSITION_SCRIPT: &str = r"
+if redis.call('EXISTS', KEYS[1]) == 1 then
+    redis.call('RPUSH', KEYS[1], ARGV[1])
+end
+if ARGV[3] == '1' then
+    redis.call('SREM', KEYS[3], ARGV[2])
+    redis.call('SADD', KEYS[2], ARGV[2])
+elseif ARGV[4] == '1' then
+    redis.call('SREM', KEYS[2], ARGV[2])
+    redis.call('SADD', KEYS[3], ARGV[2])
+end
+";
+
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.infrastructure")
@@ -167,6 +218,24 @@ impl CacheDatabase for RedisCacheDatabase {
         }
     }
 
+    fn update_order(&mut self, key: String, event: Vec<u8>, flags: u8) -> Result<()> {
+        let payload = Some(vec![event, vec![flags]]);
+        let op = DatabaseCommand::new(DatabaseOperation::UpdateOrder, key, payload);
+        match self.tx.send(op) {
+            Ok(_) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+
+    fn update_position(&mut self, key: String, event: Vec<u8>, flags: u8) -> Result<()> {
+        let payload = Some(vec![event, vec![flags]]);
+        let op = DatabaseCommand::new(DatabaseOperation::UpdatePosition, key, payload);
+        match self.tx.send(op) {
+            Ok(_) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+
     fn handle_messages(
         rx: Receiver<DatabaseCommand>,
         trader_key: String,
@@ -181,15 +250,21 @@ impl CacheDatabase for RedisCacheDatabase {
         let mut last_drain = Instant::now();
         let recv_interval = Duration::from_millis(1);
         let buffer_interval = get_buffer_interval(&config);
+        let max_batch_size = get_max_batch_size(&config);
 
         loop {
-            if last_drain.elapsed() >= buffer_interval && !buffer.is_empty() {
+            let is_due = last_drain.elapsed() >= buffer_interval || buffer.len() >= max_batch_size;
+            if is_due && !buffer.is_empty() {
                 drain_buffer(&mut conn, &trader_key, &mut buffer);
                 last_drain = Instant::now();
             } else {
                 // Continue to receive and handle messages until channel is hung up
                 match rx.try_recv() {
-                    Ok(msg) => buffer.push_back(msg),
+                    Ok(msg) => {
+                        buffer.push_back(msg);
+                        // Batch all pending messages into the same pipeline to save round trips
+                        receive_pending(&rx, &mut buffer, max_batch_size);
+                    }
                     Err(TryRecvError::Empty) => thread::sleep(recv_interval),
                     Err(TryRecvError::Disconnected) => break, // Channel hung up
                 }
@@ -203,6 +278,23 @@ impl CacheDatabase for RedisCacheDatabase {
     }
 }
 
+impl RedisCacheDatabase {
+    /// Exports the entire cache state for this trader to a compressed archive at `path`.
+    ///
+    /// Returns the number of entries archived.
+    pub fn export_archive(&mut self, path: &Path) -> Result<usize> {
+        let trader_key = self.trader_key.clone();
+        archive::export_archive(self, &trader_key, path)
+    }
+
+    /// Restores the cache state from the compressed archive at `path`.
+    ///
+    /// Returns the number of entries restored.
+    pub fn restore_archive(&mut self, path: &Path) -> Result<usize> {
+        archive::restore_archive(self, path)
+    }
+}
+
 fn drain_buffer(conn: &mut Connection, trader_key: &str, buffer: &mut VecDeque<DatabaseCommand>) {
     let mut pipe = redis::pipe();
     pipe.atomic();
@@ -266,6 +358,16 @@ fn drain_buffer(conn: &mut Connection, trader_key: &str, buffer: &mut VecDeque<D
                     eprintln!("{e}");
                 }
             }
+            DatabaseOperation::UpdateOrder => {
+                if let Err(e) = update_order(&mut pipe, trader_key, &msg) {
+                    eprintln!("{e}");
+                }
+            }
+            DatabaseOperation::UpdatePosition => {
+                if let Err(e) = update_position(&mut pipe, trader_key, &msg) {
+                    eprintln!("{e}");
+                }
+            }
         }
     }
 
@@ -466,6 +568,54 @@ fn update_list(pipe: &mut Pipeline, key: &str, value: &[u8]) {
     pipe.rpush_exists(key, value);
 }
 
+fn update_order(pipe: &mut Pipeline, trader_key: &str, msg: &DatabaseCommand) -> Result<()> {
+    let (event, flags) = get_update_payload(msg.payload.as_ref())?;
+    let client_order_id = get_index_key(&msg.key)?;
+
+    // Scripts are sent in full with `EVAL` (cached server side by digest), so that no
+    // pipeline fails with `NOSCRIPT` following a script cache flush or server restart
+    pipe.cmd(EVAL)
+        .arg(UPDATE_ORDER_SCRIPT)
+        .arg(5)
+        .arg(format!("{trader_key}{DELIMITER}{}", msg.key))
+        .arg(format!("{trader_key}{DELIMITER}{INDEX_ORDERS_INFLIGHT}"))
+        .arg(format!("{trader_key}{DELIMITER}{INDEX_ORDERS_OPEN}"))
+        .arg(format!("{trader_key}{DELIMITER}{INDEX_ORDERS_CLOSED}"))
+        .arg(format!("{trader_key}{DELIMITER}{INDEX_ORDERS_EMULATED}"))
+        .arg(event)
+        .arg(client_order_id)
+        .arg(flag_arg(flags, INDEX_FLAG_INFLIGHT))
+        .arg(flag_arg(flags, INDEX_FLAG_OPEN))
+        .arg(flag_arg(flags, INDEX_FLAG_CLOSED))
+        .arg(flag_arg(flags, INDEX_FLAG_EMULATED));
+    Ok(())
+}
+
+fn update_position(pipe: &mut Pipeline, trader_key: &str, msg: &DatabaseCommand) -> Result<()> {
+    let (event, flags) = get_update_payload(msg.payload.as_ref())?;
+    let position_id = get_index_key(&msg.key)?;
+
+    pipe.cmd(EVAL)
+        .arg(UPDATE_POSITION_SCRIPT)
+        .arg(3)
+        .arg(format!("{trader_key}{DELIMITER}{}", msg.key))
+        .arg(format!("{trader_key}{DELIMITER}{INDEX_POSITIONS_OPEN}"))
+        .arg(format!("{trader_key}{DELIMITER}{INDEX_POSITIONS_CLOSED}"))
+        .arg(event)
+        .arg(position_id)
+        .arg(flag_arg(flags, INDEX_FLAG_OPEN))
+        .arg(flag_arg(flags, INDEX_FLAG_CLOSED));
+    Ok(())
+}
+
+fn flag_arg(flags: u8, flag: u8) -> &'static str {
+    if flags & flag == flag {
+        "1"
+    } else {
+        "0"
+    }
+}
+
 fn delete(
     pipe: &mut Pipeline,
     collection: &str,
@@ -527,39 +677,6 @@ fn delete_string(pipe: &mut Pipeline, key: &str) {
     pipe.del(key);
 }
 
-fn get_trader_key(
-    trader_id: TraderId,
-    instance_id: UUID4,
-    config: &HashMap<String, serde_json::Value>,
-) -> String {
-    let mut key = String::new();
-
-    if let Some(json!(true)) = config.get("use_trader_prefix") {
-        key.push_str("trader-");
-    }
-
-    key.push_str(trader_id.value.as_str());
-
-    if let Some(json!(true)) = config.get("use_instance_id") {
-        key.push(DELIMITER);
-        key.push_str(&format!("{instance_id}"));
-    }
-
-    key
-}
-
-fn get_collection_key(key: &str) -> Result<&str> {
-    key.split_once(DELIMITER)
-        .map(|(collection, _)| collection)
-        .ok_or_else(|| anyhow!("Invalid `key`, missing a '{DELIMITER}' delimiter, was {key}"))
-}
-
-fn get_index_key(key: &str) -> Result<&str> {
-    key.split_once(DELIMITER)
-        .map(|(_, index_key)| index_key)
-        .ok_or_else(|| anyhow!("Invalid `key`, missing a '{DELIMITER}' delimiter, was {key}"))
-}
-
 // This function can be used when we handle cache serialization in Rust
 #[allow(dead_code)]
 fn get_encoding(config: &HashMap<String, serde_json::Value>) -> String {
@@ -630,4 +747,58 @@ mod tests {
         let key = "no_delimiter";
         assert!(get_index_key(key).is_err());
     }
+
+    #[rstest]
+    fn test_get_update_payload() {
+        let payload = vec![b"event".to_vec(), vec![INDEX_FLAG_OPEN]];
+        let (event, flags) = get_update_payload(Some(&payload)).unwrap();
+        assert_eq!(event, b"event");
+        assert_eq!(flags, INDEX_FLAG_OPEN);
+    }
+
+    #[rstest]
+    fn test_get_update_payload_invalid() {
+        assert!(get_update_payload(None).is_err());
+        assert!(get_update_payload(Some(&vec![b"event".to_vec()])).is_err());
+    }
+
+    #[rstest]
+    #[case(INDEX_FLAG_INFLIGHT | INDEX_FLAG_OPEN, INDEX_FLAG_INFLIGHT, "1")]
+    #[case(INDEX_FLAG_INFLIGHT | INDEX_FLAG_OPEN, INDEX_FLAG_OPEN, "1")]
+    #[case(INDEX_FLAG_INFLIGHT | INDEX_FLAG_OPEN, INDEX_FLAG_CLOSED, "0")]
+    #[case(0, INDEX_FLAG_EMULATED, "0")]
+    fn test_flag_arg(#[case] flags: u8, #[case] flag: u8, #[case] expected: &str) {
+        assert_eq!(flag_arg(flags, flag), expected);
+    }
+
+    #[rstest]
+    fn test_update_order_single_script_command() {
+        let mut pipe = redis::pipe();
+        let msg = DatabaseCommand::new(
+            DatabaseOperation::UpdateOrder,
+            "orders:O-123".to_string(),
+            Some(vec![b"event".to_vec(), vec![INDEX_FLAG_OPEN]]),
+        );
+
+        update_order(&mut pipe, "trader-TESTER-001", &msg).unwrap();
+
+        assert_eq!(pipe.cmd_iter().count(), 1);
+    }
+
+    #[rstest]
+    fn test_receive_pending_batches_up_to_max_batch_size() {
+        let (tx, rx) = channel::<DatabaseCommand>();
+        for i in 0..5 {
+            let key = format!("orders:O-{i}");
+            tx.send(DatabaseCommand::new(DatabaseOperation::Insert, key, None))
+                .unwrap();
+        }
+
+        let mut buffer = VecDeque::new();
+        receive_pending(&rx, &mut buffer, 3);
+        assert_eq!(buffer.len(), 3);
+
+        receive_pending(&rx, &mut buffer, 10);
+        assert_eq!(buffer.len(), 5);
+    }
 }
@@ -0,0 +1,1019 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An embedded [SQLite](https://www.sqlite.org) backend for the cache database.
+//!
+//! Requires no external services, so is suitable for a single live node on one machine.
+//! Keys follow the same `{collection}:{id}` scheme (with the same serialized payloads) as the
+//! Redis backend, mapped onto tables:
+//!
+//! - `cache_objects`: single value collections (general, currencies, instruments, etc.).
+//! - `events`: the ordered event payloads of accounts, orders and positions.
+//! - `snapshots`: append-only order and position state snapshots.
+//! - `index_sets` and `index_maps`: the cache indexes.
+//!
+//! The database is opened in WAL mode, so reads are not blocked by the background writer.
+//! The schema is created and upgraded on open by applying any pending [`MIGRATIONS`].
+
+use std::{
+    collections::{HashMap, VecDeque},
+    path::{Path, PathBuf},
+    sync::mpsc::{channel, Receiver, Sender, TryRecvError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Result};
+use nautilus_common::{
+    redis::{get_buffer_interval, get_max_batch_size, get_timeout_duration},
+    runtime::get_runtime,
+};
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::trader_id::TraderId;
+use serde_json::Value;
+use sqlx::{
+    sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous},
+    SqliteConnection, SqlitePool,
+};
+use tracing::{debug, error};
+
+use crate::{
+    archive,
+    cache::{
+        get_collection_key, get_index_key, get_trader_key, get_update_payload, receive_pending,
+        CacheDatabase, DatabaseCommand, DatabaseOperation, DELIMITER, INDEX_FLAG_CLOSED,
+        INDEX_FLAG_EMULATED, INDEX_FLAG_INFLIGHT, INDEX_FLAG_OPEN,
+    },
+};
+
+// Error constants
+const CHANNEL_TX_FAILED: &str = "Failed to send to channel";
+
+// Connection defaults
+const DEFAULT_PATH: &str = "nautilus.db";
+const DEFAULT_TIMEOUT_SECS: u64 = 20;
+
+// Collection keys
+const INDEX: &str = "index";
+const GENERAL: &str = "general";
+const CURRENCIES: &str = "currencies";
+const INSTRUMENTS: &str = "instruments";
+const SYNTHETICS: &str = "synthetics";
+const ACCOUNTS: &str = "accounts";
+const ORDERS: &str = "orders";
+const POSITIONS: &str = "positions";
+const ACTORS: &str = "actors";
+const STRATEGIES: &str = "strategies";
+const SNAPSHOTS: &str = "snapshots";
+const HEALTH: &str = "health";
+
+// Index keys (following the `index` collection)
+const INDEX_ORDER_IDS: &str = "order_ids";
+const INDEX_ORDER_POSITION: &str = "order_position";
+const INDEX_ORDER_CLIENT: &str = "order_client";
+const INDEX_ORDERS: &str = "orders";
+const INDEX_ORDERS_OPEN: &str = "orders_open";
+const INDEX_ORDERS_CLOSED: &str = "orders_closed";
+const INDEX_ORDERS_EMULATED: &str = "orders_emulated";
+const INDEX_ORDERS_INFLIGHT: &str = "orders_inflight";
+const INDEX_POSITIONS: &str = "positions";
+const INDEX_POSITIONS_OPEN: &str = "positions_open";
+const INDEX_POSITIONS_CLOSED: &str = "positions_closed";
+
+// Collections stored as a single value in `cache_objects`
+const OBJECT_COLLECTIONS: [&str; 7] = [
+    GENERAL,
+    CURRENCIES,
+    INSTRUMENTS,
+    SYNTHETICS,
+    ACTORS,
+    STRATEGIES,
+    HEALTH,
+];
+
+/// The ordered schema migrations as `(version, SQL)`, tracked with the `user_version` pragma.
+pub const MIGRATIONS: &[(i32, &str)] = &[(
+    1,
+    r"
+CREATE TABLE IF NOT EXISTS cache_objects (
+    trader_key TEXT NOT NULL,
+    collection TEXT NOT NULL,
+    id TEXT NOT NULL,
+    value BLOB NOT NULL,
+    PRIMARY KEY (trader_key, collection, id)
+) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS events (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+    trader_key TEXT NOT NULL,
+    collection TEXT NOT NULL,
+    id TEXT NOT NULL,
+    value BLOB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS events_key_idx ON events (trader_key, collection, id, seq);
+CREATE TABLE IF NOT EXISTS snapshots (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+    trader_key TEXT NOT NULL,
+    id TEXT NOT NULL,
+    value BLOB NOT NULL
+);
+CREATE INDEX IF NOT EXISTS snapshots_trader_key_id_idx ON snapshots (trader_key, id);
+CREATE TABLE IF NOT EXISTS index_sets (
+    trader_key TEXT NOT NULL,
+    name TEXT NOT NULL,
+    member BLOB NOT NULL,
+    PRIMARY KEY (trader_key, name, member)
+) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS index_maps (
+    trader_key TEXT NOT NULL,
+    name TEXT NOT NULL,
+    field TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (trader_key, name, field)
+) WITHOUT ROWID;
+",
+)];
+
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.infrastructure")
+)]
+pub struct SqliteCacheDatabase {
+    pub trader_id: TraderId,
+    trader_key: String,
+    pool: SqlitePool,
+    tx: Sender<DatabaseCommand>,
+}
+
+impl CacheDatabase for SqliteCacheDatabase {
+    type DatabaseType = SqliteCacheDatabase;
+
+    fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: HashMap<String, Value>,
+    ) -> Result<SqliteCacheDatabase> {
+        debug!("Initializing trader_id={trader_id}, instance_id={instance_id}");
+        let pool = get_runtime().block_on(connect(&config))?;
+        debug!("Opened {:?}", get_sqlite_path(&config));
+
+        get_runtime().block_on(run_migrations(&pool))?;
+
+        let (tx, rx) = channel::<DatabaseCommand>();
+        let trader_key = get_trader_key(trader_id, instance_id, &config);
+        let trader_key_clone = trader_key.clone();
+
+        let _join_handle = thread::Builder::new()
+            .name("cache-sqlite".to_string())
+            .spawn(move || {
+                Self::handle_messages(rx, trader_key_clone, config);
+            })
+            .expect("Error spawning `cache-sqlite` thread");
+
+        Ok(SqliteCacheDatabase {
+            trader_id,
+            trader_key,
+            pool,
+            tx,
+        })
+    }
+
+    /// Deletes all data for this trader key (data for other traders is unaffected).
+    fn flushdb(&mut self) -> Result<()> {
+        get_runtime().block_on(flush(&self.pool, &self.trader_key))
+    }
+
+    /// Returns the full keys (including the trader key) matching the glob-style `pattern`.
+    ///
+    /// Index and snapshot keys are not returned, as these are not loaded by key.
+    fn keys(&mut self, pattern: &str) -> Result<Vec<String>> {
+        get_runtime().block_on(read_keys(&self.pool, &self.trader_key, pattern))
+    }
+
+    fn read(&mut self, key: &str) -> Result<Vec<Vec<u8>>> {
+        get_runtime().block_on(read(&self.pool, &self.trader_key, key))
+    }
+
+    fn insert(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+        let op = DatabaseCommand::new(DatabaseOperation::Insert, key, payload);
+        match self.tx.send(op) {
+            Ok(_) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+
+    fn update(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+        let op = DatabaseCommand::new(DatabaseOperation::Update, key, payload);
+        match self.tx.send(op) {
+            Ok(_) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+
+    fn delete(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+        let op = DatabaseCommand::new(DatabaseOperation::Delete, key, payload);
+        match self.tx.send(op) {
+            Ok(_) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+
+    fn update_order(&mut self, key: String, event: Vec<u8>, flags: u8) -> Result<()> {
+        let payload = Some(vec![event, vec![flags]]);
+        let op = DatabaseCommand::new(DatabaseOperation::UpdateOrder, key, payload);
+        match self.tx.send(op) {
+            Ok(_) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+
+    fn update_position(&mut self, key: String, event: Vec<u8>, flags: u8) -> Result<()> {
+        let payload = Some(vec![event, vec![flags]]);
+        let op = DatabaseCommand::new(DatabaseOperation::UpdatePosition, key, payload);
+        match self.tx.send(op) {
+            Ok(_) => Ok(()),
+            Err(e) => bail!("{CHANNEL_TX_FAILED}: {e}"),
+        }
+    }
+
+    fn handle_messages(
+        rx: Receiver<DatabaseCommand>,
+        trader_key: String,
+        config: HashMap<String, Value>,
+    ) {
+        let pool = get_runtime()
+            .block_on(connect(&config))
+            .expect("Failed to open SQLite database");
+
+        // Buffering
+        let mut buffer: VecDeque<DatabaseCommand> = VecDeque::new();
+        let mut last_drain = Instant::now();
+        let recv_interval = Duration::from_millis(1);
+        let buffer_interval = get_buffer_interval(&config);
+        let max_batch_size = get_max_batch_size(&config);
+
+        loop {
+            let is_due = last_drain.elapsed() >= buffer_interval || buffer.len() >= max_batch_size;
+            if is_due && !buffer.is_empty() {
+                get_runtime().block_on(drain_buffer(&pool, &trader_key, &mut buffer));
+                last_drain = Instant::now();
+            } else {
+                // Continue to receive and handle messages until channel is hung up
+                match rx.try_recv() {
+                    Ok(msg) => {
+                        buffer.push_back(msg);
+                        // Batch all pending messages into the same transaction to save commits
+                        receive_pending(&rx, &mut buffer, max_batch_size);
+                    }
+                    Err(TryRecvError::Empty) => thread::sleep(recv_interval),
+                    Err(TryRecvError::Disconnected) => break, // Channel hung up
+                }
+            }
+        }
+
+        // Drain any remaining messages
+        if !buffer.is_empty() {
+            get_runtime().block_on(drain_buffer(&pool, &trader_key, &mut buffer));
+        }
+    }
+}
+
+impl SqliteCacheDatabase {
+    /// Exports the entire cache state for this trader to a compressed archive at `path`.
+    ///
+    /// Returns the number of entries archived.
+    pub fn export_archive(&mut self, path: &Path) -> Result<usize> {
+        let trader_key = self.trader_key.clone();
+        archive::export_archive(self, &trader_key, path)
+    }
+
+    /// Restores the cache state from the compressed archive at `path`.
+    ///
+    /// Returns the number of entries restored.
+    pub fn restore_archive(&mut self, path: &Path) -> Result<usize> {
+        archive::restore_archive(self, path)
+    }
+
+    /// Loads all entries of the given `collection` in a single query.
+    ///
+    /// Returns a map of `{collection}:{id}` keys to their payloads (the ordered events for
+    /// accounts, orders and positions, otherwise a single value), for bulk loading on startup.
+    pub fn load_collection(&mut self, collection: &str) -> Result<HashMap<String, Vec<Vec<u8>>>> {
+        get_runtime().block_on(read_collection(&self.pool, &self.trader_key, collection))
+    }
+}
+
+async fn flush(pool: &SqlitePool, trader_key: &str) -> Result<()> {
+    let mut transaction = pool.begin().await?;
+    for table in [
+        "cache_objects",
+        "events",
+        "snapshots",
+        "index_sets",
+        "index_maps",
+    ] {
+        sqlx::query(&format!("DELETE FROM {table} WHERE trader_key = ?1"))
+            .bind(trader_key)
+            .execute(&mut *transaction)
+            .await?;
+    }
+    transaction.commit().await?;
+    Ok(())
+}
+
+async fn drain_buffer(pool: &SqlitePool, trader_key: &str, buffer: &mut VecDeque<DatabaseCommand>) {
+    // All buffered commands are applied atomically, as with the Redis pipeline (every command
+    // writes, so the transaction takes the write lock on its first statement)
+    let mut transaction = match pool.begin().await {
+        Ok(transaction) => transaction,
+        Err(e) => {
+            error!("Failed to begin transaction: {e}");
+            return; // Buffered commands are retained to retry on next drain
+        }
+    };
+
+    for msg in buffer.drain(..) {
+        match apply_command(&mut transaction, trader_key, &msg).await {
+            Ok(()) => {}
+            Err(e) if e.is::<sqlx::Error>() => {
+                // The transaction is rolled back when dropped
+                error!(
+                    "Failed to apply command for '{}', rolled back: {e}",
+                    msg.key
+                );
+                return;
+            }
+            Err(e) => error!("{e}"),
+        }
+    }
+
+    if let Err(e) = transaction.commit().await {
+        error!("Failed to commit transaction: {e}");
+    }
+}
+
+async fn apply_command(
+    conn: &mut SqliteConnection,
+    trader_key: &str,
+    msg: &DatabaseCommand,
+) -> Result<()> {
+    let collection = get_collection_key(&msg.key)?;
+    let id = get_index_key(&msg.key)?;
+
+    match msg.op_type {
+        DatabaseOperation::Insert => {
+            let payload = get_payload(msg, "insert")?;
+            match collection {
+                INDEX => insert_index(conn, trader_key, id, payload).await?,
+                ACCOUNTS | ORDERS | POSITIONS => {
+                    sqlx::query(
+                        "INSERT INTO events (trader_key, collection, id, value) \
+                         VALUES (?1, ?2, ?3, ?4)",
+                    )
+                    .bind(trader_key)
+                    .bind(collection)
+                    .bind(id)
+                    .bind(payload[0].as_slice())
+                    .execute(&mut *conn)
+                    .await?;
+                }
+                SNAPSHOTS => {
+                    sqlx::query("INSERT INTO snapshots (trader_key, id, value) VALUES (?1, ?2, ?3)")
+                        .bind(trader_key)
+                        .bind(id)
+                        .bind(payload[0].as_slice())
+                        .execute(&mut *conn)
+                        .await?;
+                }
+                c if OBJECT_COLLECTIONS.contains(&c) => {
+                    sqlx::query(
+                        "INSERT INTO cache_objects (trader_key, collection, id, value) \
+                         VALUES (?1, ?2, ?3, ?4) \
+                         ON CONFLICT (trader_key, collection, id) \
+                         DO UPDATE SET value = excluded.value",
+                    )
+                    .bind(trader_key)
+                    .bind(collection)
+                    .bind(id)
+                    .bind(payload[0].as_slice())
+                    .execute(&mut *conn)
+                    .await?;
+                }
+                _ => bail!("Unsupported operation: `insert` for collection '{collection}'"),
+            }
+        }
+        DatabaseOperation::Update => {
+            let payload = get_payload(msg, "update")?;
+            match collection {
+                ACCOUNTS | ORDERS | POSITIONS => {
+                    append_event(conn, trader_key, collection, id, &payload[0]).await?;
+                }
+                _ => bail!("Unsupported operation: `update` for collection '{collection}'"),
+            }
+        }
+        DatabaseOperation::Delete => match collection {
+            INDEX => {
+                // `payload` is required to target the index member to remove
+                let payload = get_payload(msg, "delete")?;
+                remove_index(conn, trader_key, id, payload).await?;
+            }
+            ACTORS | STRATEGIES => {
+                sqlx::query(
+                    "DELETE FROM cache_objects \
+                     WHERE trader_key = ?1 AND collection = ?2 AND id = ?3",
+                )
+                .bind(trader_key)
+                .bind(collection)
+                .bind(id)
+                .execute(&mut *conn)
+                .await?;
+            }
+            _ => bail!("Unsupported operation: `delete` for collection '{collection}'"),
+        },
+        // The event and index updates are atomic within the drain transaction
+        DatabaseOperation::UpdateOrder => {
+            let (event, flags) = get_update_payload(msg.payload.as_ref())?;
+            append_event(conn, trader_key, ORDERS, id, event).await?;
+
+            let member = id.as_bytes();
+            let is_inflight = flags & INDEX_FLAG_INFLIGHT != 0;
+            set_index(conn, trader_key, INDEX_ORDERS_INFLIGHT, member, is_inflight).await?;
+            if flags & INDEX_FLAG_OPEN != 0 {
+                set_index(conn, trader_key, INDEX_ORDERS_CLOSED, member, false).await?;
+                set_index(conn, trader_key, INDEX_ORDERS_OPEN, member, true).await?;
+            } else if flags & INDEX_FLAG_CLOSED != 0 {
+                set_index(conn, trader_key, INDEX_ORDERS_OPEN, member, false).await?;
+                set_index(conn, trader_key, INDEX_ORDERS_CLOSED, member, true).await?;
+            }
+            let is_emulated = flags & INDEX_FLAG_EMULATED != 0;
+            set_index(conn, trader_key, INDEX_ORDERS_EMULATED, member, is_emulated).await?;
+        }
+        DatabaseOperation::UpdatePosition => {
+            let (event, flags) = get_update_payload(msg.payload.as_ref())?;
+            append_event(conn, trader_key, POSITIONS, id, event).await?;
+
+            let member = id.as_bytes();
+            if flags & INDEX_FLAG_OPEN != 0 {
+                set_index(conn, trader_key, INDEX_POSITIONS_CLOSED, member, false).await?;
+                set_index(conn, trader_key, INDEX_POSITIONS_OPEN, member, true).await?;
+            } else if flags & INDEX_FLAG_CLOSED != 0 {
+                set_index(conn, trader_key, INDEX_POSITIONS_OPEN, member, false).await?;
+                set_index(conn, trader_key, INDEX_POSITIONS_CLOSED, member, true).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn get_payload<'a>(msg: &'a DatabaseCommand, op: &str) -> Result<&'a [Vec<u8>]> {
+    match &msg.payload {
+        Some(payload) if !payload.is_empty() => Ok(payload),
+        Some(_) => bail!("Empty `payload` for `{op}` '{}'", msg.key),
+        None => bail!("Null `payload` for `{op}` '{}'", msg.key),
+    }
+}
+
+/// Appends the `event` to an existing account, order or position (otherwise does nothing).
+async fn append_event(
+    conn: &mut SqliteConnection,
+    trader_key: &str,
+    collection: &str,
+    id: &str,
+    event: &[u8],
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO events (trader_key, collection, id, value) \
+         SELECT ?1, ?2, ?3, ?4 WHERE EXISTS ( \
+             SELECT 1 FROM events WHERE trader_key = ?1 AND collection = ?2 AND id = ?3 \
+         )",
+    )
+    .bind(trader_key)
+    .bind(collection)
+    .bind(id)
+    .bind(event)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+async fn set_index(
+    conn: &mut SqliteConnection,
+    trader_key: &str,
+    index_key: &str,
+    member: &[u8],
+    is_member: bool,
+) -> Result<()> {
+    let sql = if is_member {
+        "INSERT OR IGNORE INTO index_sets (trader_key, name, member) VALUES (?1, ?2, ?3)"
+    } else {
+        "DELETE FROM index_sets WHERE trader_key = ?1 AND name = ?2 AND member = ?3"
+    };
+    sqlx::query(sql)
+        .bind(trader_key)
+        .bind(index_key)
+        .bind(member)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+async fn insert_index(
+    conn: &mut SqliteConnection,
+    trader_key: &str,
+    index_key: &str,
+    payload: &[Vec<u8>],
+) -> Result<()> {
+    match index_key {
+        INDEX_ORDER_POSITION | INDEX_ORDER_CLIENT => {
+            if payload.len() < 2 {
+                bail!("Invalid `payload` for index '{index_key}', expected field and value");
+            }
+            let field = String::from_utf8(payload[0].clone())?;
+            let value = String::from_utf8(payload[1].clone())?;
+            sqlx::query(
+                "INSERT INTO index_maps (trader_key, name, field, value) \
+                 VALUES (?1, ?2, ?3, ?4) \
+                 ON CONFLICT (trader_key, name, field) DO UPDATE SET value = excluded.value",
+            )
+            .bind(trader_key)
+            .bind(index_key)
+            .bind(field)
+            .bind(value)
+            .execute(&mut *conn)
+            .await?;
+        }
+        INDEX_ORDER_IDS
+        | INDEX_ORDERS
+        | INDEX_ORDERS_OPEN
+        | INDEX_ORDERS_CLOSED
+        | INDEX_ORDERS_EMULATED
+        | INDEX_ORDERS_INFLIGHT
+        | INDEX_POSITIONS
+        | INDEX_POSITIONS_OPEN
+        | INDEX_POSITIONS_CLOSED => {
+            set_index(conn, trader_key, index_key, &payload[0], true).await?;
+        }
+        _ => bail!("Index unknown '{index_key}' on insert"),
+    }
+    Ok(())
+}
+
+async fn remove_index(
+    conn: &mut SqliteConnection,
+    trader_key: &str,
+    index_key: &str,
+    payload: &[Vec<u8>],
+) -> Result<()> {
+    match index_key {
+        INDEX_ORDERS_OPEN
+        | INDEX_ORDERS_CLOSED
+        | INDEX_ORDERS_EMULATED
+        | INDEX_ORDERS_INFLIGHT
+        | INDEX_POSITIONS_OPEN
+        | INDEX_POSITIONS_CLOSED => {
+            set_index(conn, trader_key, index_key, &payload[0], false).await
+        }
+        _ => bail!("Unsupported index operation: remove from '{index_key}'"),
+    }
+}
+
+async fn read_keys(pool: &SqlitePool, trader_key: &str, pattern: &str) -> Result<Vec<String>> {
+    // SQLite `GLOB` matching has the same (case sensitive) syntax as Redis key patterns
+    let keys = sqlx::query_scalar(
+        "SELECT key FROM (
+            SELECT ?1 || ':' || collection || ':' || id AS key
+            FROM cache_objects WHERE trader_key = ?1
+            UNION SELECT ?1 || ':' || collection || ':' || id FROM events WHERE trader_key = ?1
+        ) WHERE key GLOB ?2",
+    )
+    .bind(trader_key)
+    .bind(pattern)
+    .fetch_all(pool)
+    .await?;
+    Ok(keys)
+}
+
+async fn read(pool: &SqlitePool, trader_key: &str, key: &str) -> Result<Vec<Vec<u8>>> {
+    let collection = get_collection_key(key)?;
+    let id = get_index_key(key)?;
+
+    match collection {
+        INDEX => read_index(pool, trader_key, id).await,
+        ACCOUNTS | ORDERS | POSITIONS => {
+            let events = sqlx::query_scalar(
+                "SELECT value FROM events \
+                 WHERE trader_key = ?1 AND collection = ?2 AND id = ?3 ORDER BY seq",
+            )
+            .bind(trader_key)
+            .bind(collection)
+            .bind(id)
+            .fetch_all(pool)
+            .await?;
+            Ok(events)
+        }
+        c if OBJECT_COLLECTIONS.contains(&c) && c != HEALTH => {
+            let value: Option<Vec<u8>> = sqlx::query_scalar(
+                "SELECT value FROM cache_objects \
+                 WHERE trader_key = ?1 AND collection = ?2 AND id = ?3",
+            )
+            .bind(trader_key)
+            .bind(collection)
+            .bind(id)
+            .fetch_optional(pool)
+            .await?;
+            Ok(value.into_iter().collect())
+        }
+        _ => bail!("Unsupported operation: `read` for collection '{collection}'"),
+    }
+}
+
+async fn read_index(pool: &SqlitePool, trader_key: &str, index_key: &str) -> Result<Vec<Vec<u8>>> {
+    match index_key {
+        INDEX_ORDER_POSITION | INDEX_ORDER_CLIENT => {
+            let rows: Vec<(String, String)> = sqlx::query_as(
+                "SELECT field, value FROM index_maps WHERE trader_key = ?1 AND name = ?2",
+            )
+            .bind(trader_key)
+            .bind(index_key)
+            .fetch_all(pool)
+            .await?;
+            let map: HashMap<String, String> = rows.into_iter().collect();
+            Ok(vec![serde_json::to_string(&map)?.into_bytes()])
+        }
+        INDEX_ORDER_IDS
+        | INDEX_ORDERS
+        | INDEX_ORDERS_OPEN
+        | INDEX_ORDERS_CLOSED
+        | INDEX_ORDERS_EMULATED
+        | INDEX_ORDERS_INFLIGHT
+        | INDEX_POSITIONS
+        | INDEX_POSITIONS_OPEN
+        | INDEX_POSITIONS_CLOSED => {
+            let members = sqlx::query_scalar(
+                "SELECT member FROM index_sets WHERE trader_key = ?1 AND name = ?2",
+            )
+            .bind(trader_key)
+            .bind(index_key)
+            .fetch_all(pool)
+            .await?;
+            Ok(members)
+        }
+        _ => bail!("Index unknown '{index_key}' on read"),
+    }
+}
+
+async fn read_collection(
+    pool: &SqlitePool,
+    trader_key: &str,
+    collection: &str,
+) -> Result<HashMap<String, Vec<Vec<u8>>>> {
+    let sql = match collection {
+        ACCOUNTS | ORDERS | POSITIONS => {
+            "SELECT id, value FROM events \
+             WHERE trader_key = ?1 AND collection = ?2 ORDER BY seq"
+        }
+        c if OBJECT_COLLECTIONS.contains(&c) => {
+            "SELECT id, value FROM cache_objects WHERE trader_key = ?1 AND collection = ?2"
+        }
+        _ => bail!("Unsupported operation: `load_collection` for collection '{collection}'"),
+    };
+
+    let rows: Vec<(String, Vec<u8>)> = sqlx::query_as(sql)
+        .bind(trader_key)
+        .bind(collection)
+        .fetch_all(pool)
+        .await?;
+
+    let mut result: HashMap<String, Vec<Vec<u8>>> = HashMap::new();
+    for (id, value) in rows {
+        result
+            .entry(format!("{collection}{DELIMITER}{id}"))
+            .or_default()
+            .push(value);
+    }
+
+    Ok(result)
+}
+
+/// Applies any pending [`MIGRATIONS`] in order, recording the applied version.
+pub async fn run_migrations(pool: &SqlitePool) -> Result<()> {
+    // An immediate transaction takes the write lock, so concurrent nodes serialize
+    let mut conn = pool.acquire().await?;
+    sqlx::raw_sql("BEGIN IMMEDIATE").execute(&mut *conn).await?;
+
+    match apply_migrations(&mut conn).await {
+        Ok(()) => {
+            sqlx::raw_sql("COMMIT").execute(&mut *conn).await?;
+            Ok(())
+        }
+        Err(e) => {
+            sqlx::raw_sql("ROLLBACK").execute(&mut *conn).await?;
+            Err(e)
+        }
+    }
+}
+
+async fn apply_migrations(conn: &mut SqliteConnection) -> Result<()> {
+    let current: i32 = sqlx::query_scalar("PRAGMA user_version")
+        .fetch_one(&mut *conn)
+        .await?;
+
+    for (version, sql) in MIGRATIONS.iter().filter(|(v, _)| *v > current) {
+        debug!("Applying cache schema migration {version}");
+        sqlx::raw_sql(sql)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| anyhow!("Failed to apply cache schema migration {version}: {e}"))?;
+        sqlx::raw_sql(&format!("PRAGMA user_version = {version}"))
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    Ok(())
+}
+
+async fn connect(config: &HashMap<String, Value>) -> Result<SqlitePool> {
+    let pool = SqlitePoolOptions::new()
+        .connect_with(get_sqlite_options(config))
+        .await?;
+    Ok(pool)
+}
+
+/// Returns the SQLite connection options for the given cache `config`.
+///
+/// WAL allows reads concurrent with the background writer, with `NORMAL` sync being durable
+/// across application crashes (a power loss may roll back the most recent transactions).
+#[must_use]
+pub fn get_sqlite_options(config: &HashMap<String, Value>) -> SqliteConnectOptions {
+    SqliteConnectOptions::new()
+        .filename(get_sqlite_path(config))
+        .create_if_missing(true)
+        .busy_timeout(get_timeout_duration(config, DEFAULT_TIMEOUT_SECS))
+        .journal_mode(SqliteJournalMode::Wal)
+        .synchronous(SqliteSynchronous::Normal)
+}
+
+/// Returns the SQLite database file path for the given cache `config`.
+#[must_use]
+pub fn get_sqlite_path(config: &HashMap<String, Value>) -> PathBuf {
+    config
+        .get("database")
+        .and_then(|database| database.get("name"))
+        .and_then(Value::as_str)
+        .unwrap_or(DEFAULT_PATH)
+        .into()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+
+    const TRADER_KEY: &str = "trader-TESTER-001";
+
+    async fn pool() -> SqlitePool {
+        // A single connection which is never closed, as each in-memory connection has its own database
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(SqliteConnectOptions::from_str("sqlite::memory:").unwrap())
+            .await
+            .unwrap();
+        run_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    async fn apply(pool: &SqlitePool, commands: Vec<DatabaseCommand>) {
+        let mut buffer = VecDeque::from(commands);
+        drain_buffer(pool, TRADER_KEY, &mut buffer).await;
+    }
+
+    fn insert(key: &str, payload: &[&str]) -> DatabaseCommand {
+        let payload = payload.iter().map(|v| v.as_bytes().to_vec()).collect();
+        DatabaseCommand::new(DatabaseOperation::Insert, key.to_string(), Some(payload))
+    }
+
+    #[rstest]
+    fn test_get_sqlite_path_default() {
+        let config = HashMap::new();
+        assert_eq!(get_sqlite_path(&config), PathBuf::from(DEFAULT_PATH));
+    }
+
+    #[rstest]
+    fn test_get_sqlite_path_from_database_config() {
+        let mut config = HashMap::new();
+        config.insert(
+            "database".to_string(),
+            json!({"type": "sqlite", "name": "/var/lib/nautilus/cache.db"}),
+        );
+        assert_eq!(
+            get_sqlite_path(&config),
+            PathBuf::from("/var/lib/nautilus/cache.db")
+        );
+        assert_eq!(
+            get_sqlite_options(&config).get_filename(),
+            Path::new("/var/lib/nautilus/cache.db")
+        );
+    }
+
+    #[rstest]
+    fn test_migrations_are_ordered_and_unique() {
+        let versions: Vec<i32> = MIGRATIONS.iter().map(|(v, _)| *v).collect();
+        let mut sorted = versions.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(versions, sorted);
+        assert_eq!(versions.first(), Some(&1));
+    }
+
+    #[tokio::test]
+    async fn test_run_migrations_is_idempotent() {
+        let pool = pool().await;
+
+        run_migrations(&pool).await.unwrap();
+
+        let version: i32 = sqlx::query_scalar("PRAGMA user_version")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(version, MIGRATIONS.last().unwrap().0);
+    }
+
+    #[tokio::test]
+    async fn test_insert_and_read_object() {
+        let pool = pool().await;
+        apply(
+            &pool,
+            vec![
+                insert("instruments:AUD/USD.SIM", &["v1"]),
+                insert("instruments:AUD/USD.SIM", &["v2"]),
+            ],
+        )
+        .await;
+
+        let result = read(&pool, TRADER_KEY, "instruments:AUD/USD.SIM")
+            .await
+            .unwrap();
+        assert_eq!(result, &[b"v2".to_vec()]);
+        assert!(read(&pool, TRADER_KEY, "instruments:ETH/USD.SIM")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_appends_events_to_existing_only() {
+        let pool = pool().await;
+        let update = |key: &str, event: &str| {
+            DatabaseCommand::new(
+                DatabaseOperation::Update,
+                key.to_string(),
+                Some(vec![event.as_bytes().to_vec()]),
+            )
+        };
+        apply(
+            &pool,
+            vec![
+                insert("orders:O-1", &["initialized"]),
+                update("orders:O-1", "submitted"),
+                update("orders:O-2", "submitted"),
+            ],
+        )
+        .await;
+
+        let result = read(&pool, TRADER_KEY, "orders:O-1").await.unwrap();
+        assert_eq!(result, &[b"initialized".to_vec(), b"submitted".to_vec()]);
+        assert!(read(&pool, TRADER_KEY, "orders:O-2")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_order_moves_indexes() {
+        let pool = pool().await;
+        let update_order = |event: &str, flags: u8| {
+            DatabaseCommand::new(
+                DatabaseOperation::UpdateOrder,
+                "orders:O-1".to_string(),
+                Some(vec![event.as_bytes().to_vec(), vec![flags]]),
+            )
+        };
+        apply(
+            &pool,
+            vec![
+                insert("orders:O-1", &["initialized"]),
+                update_order("accepted", INDEX_FLAG_OPEN | INDEX_FLAG_INFLIGHT),
+            ],
+        )
+        .await;
+        assert_eq!(
+            read(&pool, TRADER_KEY, "index:orders_open").await.unwrap(),
+            vec![b"O-1".to_vec()]
+        );
+        assert_eq!(
+            read(&pool, TRADER_KEY, "index:orders_inflight")
+                .await
+                .unwrap(),
+            vec![b"O-1".to_vec()]
+        );
+
+        apply(&pool, vec![update_order("filled", INDEX_FLAG_CLOSED)]).await;
+
+        assert!(read(&pool, TRADER_KEY, "index:orders_open")
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(read(&pool, TRADER_KEY, "index:orders_inflight")
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            read(&pool, TRADER_KEY, "index:orders_closed")
+                .await
+                .unwrap(),
+            vec![b"O-1".to_vec()]
+        );
+        assert_eq!(
+            read(&pool, TRADER_KEY, "orders:O-1").await.unwrap().len(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn test_index_map_reads_as_json() {
+        let pool = pool().await;
+        apply(&pool, vec![insert("index:order_position", &["O-1", "P-1"])]).await;
+
+        let result = read(&pool, TRADER_KEY, "index:order_position")
+            .await
+            .unwrap();
+        let map: HashMap<String, String> = serde_json::from_slice(&result[0]).unwrap();
+        assert_eq!(map.get("O-1").map(String::as_str), Some("P-1"));
+    }
+
+    #[tokio::test]
+    async fn test_keys_with_glob_pattern() {
+        let pool = pool().await;
+        apply(
+            &pool,
+            vec![
+                insert("orders:O-1", &["initialized"]),
+                insert("orders:O-1", &["submitted"]),
+                insert("positions:P-1", &["opened"]),
+                insert("general:flag", &["1"]),
+            ],
+        )
+        .await;
+
+        let keys = read_keys(&pool, TRADER_KEY, "*:orders:*").await.unwrap();
+        assert_eq!(keys, vec![format!("{TRADER_KEY}:orders:O-1")]);
+        assert_eq!(read_keys(&pool, TRADER_KEY, "*").await.unwrap().len(), 3);
+        assert!(read_keys(&pool, "trader-OTHER", "*")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_load_collection_groups_events_in_order() {
+        let pool = pool().await;
+        apply(
+            &pool,
+            vec![
+                insert("orders:O-1", &["initialized"]),
+                insert("orders:O-2", &["initialized"]),
+                insert("orders:O-1", &["submitted"]),
+            ],
+        )
+        .await;
+
+        let result = read_collection(&pool, TRADER_KEY, ORDERS).await.unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result["orders:O-1"],
+            vec![b"initialized".to_vec(), b"submitted".to_vec()]
+        );
+    }
+}
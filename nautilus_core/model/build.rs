@@ -53,7 +53,7 @@ fn main() {
             .expect("invalid UTF-8 in stream");
 
         // Run the replace operation in memory
-        let new_data = data.replace("cdef enum", "cpdef enum");
+        let new_data = fold_high_precision_blocks(&data.replace("cdef enum", "cpdef enum"));
 
         // Recreate the file and dump the processed contents to it
         let mut dst = File::create(cython_path).expect("`File::create` failed");
@@ -61,3 +61,41 @@ fn main() {
             .expect("I/O error on `dist.write`");
     }
 }
+
+/// Replaces the `IF HIGH_PRECISION` compile-time blocks (deprecated in Cython 3) with the
+/// standard precision declarations. Extern declarations only need the general kind of each
+/// type, the C header defines the actual width and values for the build.
+#[cfg(feature = "ffi")]
+fn fold_high_precision_blocks(data: &str) -> String {
+    let mut output = String::with_capacity(data.len());
+    let mut block: Option<(usize, bool)> = None; // (indent, keep)
+
+    for line in data.lines() {
+        let indent = line.len() - line.trim_start().len();
+        if let Some((block_indent, keep)) = block {
+            if line.trim().is_empty() {
+                if keep {
+                    output.push('\n');
+                }
+                continue;
+            }
+            if indent > block_indent {
+                if keep {
+                    output.push_str(&line[4..]);
+                    output.push('\n');
+                }
+                continue;
+            }
+            block = None;
+        }
+        match line.trim() {
+            "IF not HIGH_PRECISION:" => block = Some((indent, true)),
+            "IF HIGH_PRECISION:" => block = Some((indent, false)),
+            _ => {
+                output.push_str(line);
+                output.push('\n');
+            }
+        }
+    }
+    output
+}
@@ -287,6 +287,8 @@ impl Bar {
     pub fn from_pyobject(obj: &PyAny) -> PyResult<Self> {
         use nautilus_core::python::to_pyvalue_err;
 
+        use crate::types::{price::PriceRaw, quantity::QuantityRaw};
+
         let bar_type_obj: &PyAny = obj.getattr("bar_type")?.extract()?;
         let bar_type_str = bar_type_obj.call_method0("__str__")?.extract()?;
         let bar_type = BarType::from_str(bar_type_str)
@@ -295,23 +297,23 @@ impl Bar {
 
         let open_py: &PyAny = obj.getattr("open")?;
         let price_prec: u8 = open_py.getattr("precision")?.extract()?;
-        let open_raw: i64 = open_py.getattr("raw")?.extract()?;
+        let open_raw: PriceRaw = open_py.getattr("raw")?.extract()?;
         let open = Price::from_raw(open_raw, price_prec).map_err(to_pyvalue_err)?;
 
         let high_py: &PyAny = obj.getattr("high")?;
-        let high_raw: i64 = high_py.getattr("raw")?.extract()?;
+        let high_raw: PriceRaw = high_py.getattr("raw")?.extract()?;
         let high = Price::from_raw(high_raw, price_prec).map_err(to_pyvalue_err)?;
 
         let low_py: &PyAny = obj.getattr("low")?;
-        let low_raw: i64 = low_py.getattr("raw")?.extract()?;
+        let low_raw: PriceRaw = low_py.getattr("raw")?.extract()?;
         let low = Price::from_raw(low_raw, price_prec).map_err(to_pyvalue_err)?;
 
         let close_py: &PyAny = obj.getattr("close")?;
-        let close_raw: i64 = close_py.getattr("raw")?.extract()?;
+        let close_raw: PriceRaw = close_py.getattr("raw")?.extract()?;
         let close = Price::from_raw(close_raw, price_prec).map_err(to_pyvalue_err)?;
 
         let volume_py: &PyAny = obj.getattr("volume")?;
-        let volume_raw: u64 = volume_py.getattr("raw")?.extract()?;
+        let volume_raw: QuantityRaw = volume_py.getattr("raw")?.extract()?;
         let volume_prec: u8 = volume_py.getattr("precision")?.extract()?;
         let volume = Quantity::from_raw(volume_raw, volume_prec).map_err(to_pyvalue_err)?;
 
@@ -424,6 +426,15 @@ mod tests {
         assert_eq!(bar_type, BarType::from(input));
     }
 
+    #[rstest]
+    fn test_bar_type_parse_renko() {
+        let bar_type = BarType::from_str("AAPL.XNAS-10-RENKO-LAST-INTERNAL").unwrap();
+
+        assert_eq!(bar_type.spec.step, 10);
+        assert_eq!(bar_type.spec.aggregation, BarAggregation::Renko);
+        assert_eq!(bar_type.to_string(), "AAPL.XNAS-10-RENKO-LAST-INTERNAL");
+    }
+
     #[rstest]
     fn test_bar_type_parse_invalid_token_pos_0() {
         let input = "BTCUSDT-PERP-1-MINUTE-LAST-INTERNAL";
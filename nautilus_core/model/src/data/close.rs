@@ -0,0 +1,115 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::InstrumentCloseType, identifiers::instrument_id::InstrumentId, types::price::Price,
+};
+
+/// Represents an instrument close at a venue, such as the end of a session or contract expiry.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct InstrumentClose {
+    /// The instrument ID for the close.
+    pub instrument_id: InstrumentId,
+    /// The closing price for the instrument.
+    pub close_price: Price,
+    /// The type of closing price.
+    pub close_type: InstrumentCloseType,
+    /// The UNIX timestamp (nanoseconds) when the close event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl InstrumentClose {
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        close_price: Price,
+        close_type: InstrumentCloseType,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            close_price,
+            close_type,
+            ts_event,
+            ts_init,
+        }
+    }
+}
+
+impl Display for InstrumentClose {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            self.instrument_id, self.close_price, self.close_type, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl Serializable for InstrumentClose {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn instrument_close() -> InstrumentClose {
+        InstrumentClose::new(
+            InstrumentId::from("ESM4.GLBX"),
+            Price::from("5230.25"),
+            InstrumentCloseType::ContractExpired,
+            1,
+            2,
+        )
+    }
+
+    #[rstest]
+    fn test_display(instrument_close: InstrumentClose) {
+        assert_eq!(
+            instrument_close.to_string(),
+            "ESM4.GLBX,5230.25,CONTRACT_EXPIRED,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_json_serialization(instrument_close: InstrumentClose) {
+        let serialized = instrument_close.as_json_bytes().unwrap();
+        let deserialized = InstrumentClose::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, instrument_close);
+    }
+}
@@ -0,0 +1,396 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    sync::Mutex,
+};
+
+use anyhow::{anyhow, bail, Result};
+use indexmap::IndexMap;
+use nautilus_core::{
+    correctness::check_valid_string, serialization::Serializable, time::UnixNanos,
+};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use ustr::Ustr;
+
+/// The field names reserved for the timestamps of every custom data type.
+const RESERVED_FIELDS: [&str; 2] = ["ts_event", "ts_init"];
+
+/// The Arrow field types supported for user-defined custom data.
+#[derive(Copy, Clone, Debug, Display, Hash, PartialEq, Eq, EnumString, Serialize, Deserialize)]
+pub enum CustomFieldType {
+    Boolean,
+    Int64,
+    UInt64,
+    Float64,
+    Utf8,
+}
+
+/// Represents a single field value of a custom data object.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum CustomValue {
+    Boolean(bool),
+    Int64(i64),
+    UInt64(u64),
+    Float64(f64),
+    Utf8(String),
+}
+
+impl CustomValue {
+    /// Returns the field type for the value.
+    #[must_use]
+    pub fn field_type(&self) -> CustomFieldType {
+        match self {
+            Self::Boolean(_) => CustomFieldType::Boolean,
+            Self::Int64(_) => CustomFieldType::Int64,
+            Self::UInt64(_) => CustomFieldType::UInt64,
+            Self::Float64(_) => CustomFieldType::Float64,
+            Self::Utf8(_) => CustomFieldType::Utf8,
+        }
+    }
+}
+
+impl Display for CustomValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Boolean(value) => write!(f, "{value}"),
+            Self::Int64(value) => write!(f, "{value}"),
+            Self::UInt64(value) => write!(f, "{value}"),
+            Self::Float64(value) => write!(f, "{value}"),
+            Self::Utf8(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The ordered field names and types which make up a custom data schema.
+pub type CustomDataSchema = IndexMap<String, CustomFieldType>;
+
+static CUSTOM_DATA_SCHEMAS: Lazy<Mutex<HashMap<Ustr, CustomDataSchema>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Registers the given `schema` for the custom `data_type`.
+///
+/// Registering an identical schema for an existing data type is a no-op.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If `data_type` is not a valid string.
+/// - If `schema` is empty or contains a reserved field name (`ts_event` or `ts_init`).
+/// - If a different schema is already registered for `data_type`.
+pub fn register_custom_data_type(data_type: &str, schema: CustomDataSchema) -> Result<()> {
+    check_valid_string(data_type, stringify!(data_type))?;
+
+    if schema.is_empty() {
+        bail!("Schema for custom data type `{data_type}` was empty");
+    }
+
+    if let Some(name) = schema
+        .keys()
+        .find(|name| RESERVED_FIELDS.contains(&name.as_str()))
+    {
+        bail!("Schema for custom data type `{data_type}` contained reserved field `{name}`");
+    }
+
+    let mut schemas = CUSTOM_DATA_SCHEMAS
+        .lock()
+        .map_err(|e| anyhow!("Failed to acquire lock on `CUSTOM_DATA_SCHEMAS`: {e}"))?;
+
+    match schemas.get(&Ustr::from(data_type)) {
+        Some(existing) if *existing == schema => Ok(()),
+        Some(_) => {
+            bail!("A different schema is already registered for custom data type `{data_type}`")
+        }
+        None => {
+            schemas.insert(Ustr::from(data_type), schema);
+            Ok(())
+        }
+    }
+}
+
+/// Returns the registered schema for the custom `data_type` (if found).
+#[must_use]
+pub fn get_custom_data_schema(data_type: &str) -> Option<CustomDataSchema> {
+    CUSTOM_DATA_SCHEMAS
+        .lock()
+        .ok()
+        .and_then(|schemas| schemas.get(&Ustr::from(data_type)).cloned())
+}
+
+/// Returns the names of all registered custom data types.
+#[must_use]
+pub fn get_custom_data_types() -> Vec<Ustr> {
+    CUSTOM_DATA_SCHEMAS
+        .lock()
+        .map(|schemas| schemas.keys().copied().collect())
+        .unwrap_or_default()
+}
+
+/// Represents a user-defined data object conforming to a registered custom data schema.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct CustomData {
+    /// The registered custom data type name.
+    pub data_type: Ustr,
+    /// The field values, in schema order.
+    pub values: IndexMap<String, CustomValue>,
+    /// The UNIX timestamp (nanoseconds) when the data event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl CustomData {
+    /// Creates a new [`CustomData`] instance, validating `values` against the registered schema.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If no schema is registered for `data_type`.
+    /// - If `values` does not contain exactly the schema fields with matching types.
+    pub fn new(
+        data_type: &str,
+        values: IndexMap<String, CustomValue>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Result<Self> {
+        let schema = get_custom_data_schema(data_type)
+            .ok_or_else(|| anyhow!("No schema registered for custom data type `{data_type}`"))?;
+
+        if values.len() != schema.len() {
+            bail!(
+                "Expected {} values for custom data type `{data_type}`, was {}",
+                schema.len(),
+                values.len()
+            );
+        }
+
+        let mut ordered = IndexMap::with_capacity(schema.len());
+        for (name, field_type) in &schema {
+            let value = values
+                .get(name)
+                .ok_or_else(|| anyhow!("Missing value for field `{name}` of `{data_type}`"))?;
+            if value.field_type() != *field_type {
+                bail!(
+                    "Invalid value type for field `{name}` of `{data_type}`: expected {field_type}, was {}",
+                    value.field_type()
+                );
+            }
+            ordered.insert(name.clone(), value.clone());
+        }
+
+        Ok(Self {
+            data_type: Ustr::from(data_type),
+            values: ordered,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Returns the value for the given field `name` (if found).
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&CustomValue> {
+        self.values.get(name)
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(data_type: &str) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("data_type".to_string(), data_type.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the registered `data_type`, for use with Arrow schemas.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no schema is registered for `data_type`.
+    pub fn get_fields(data_type: &str) -> Result<IndexMap<String, String>> {
+        let schema = get_custom_data_schema(data_type)
+            .ok_or_else(|| anyhow!("No schema registered for custom data type `{data_type}`"))?;
+
+        let mut metadata = IndexMap::new();
+        for (name, field_type) in schema {
+            metadata.insert(name, field_type.to_string());
+        }
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        Ok(metadata)
+    }
+}
+
+impl Display for CustomData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values = self
+            .values
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<String>>()
+            .join(",");
+        write!(
+            f,
+            "{},{},{},{}",
+            self.data_type, values, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl Serializable for CustomData {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Stubs
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "stubs")]
+pub mod stubs {
+    use indexmap::IndexMap;
+    use rstest::fixture;
+
+    use super::{register_custom_data_type, CustomData, CustomFieldType, CustomValue};
+
+    pub const STUB_CUSTOM_DATA_TYPE: &str = "OnChainFlow";
+
+    pub fn register_stub_custom_data_type() {
+        let mut schema = IndexMap::new();
+        schema.insert("token".to_string(), CustomFieldType::Utf8);
+        schema.insert("inflow".to_string(), CustomFieldType::Float64);
+        schema.insert("transfers".to_string(), CustomFieldType::UInt64);
+        schema.insert("is_exchange".to_string(), CustomFieldType::Boolean);
+        register_custom_data_type(STUB_CUSTOM_DATA_TYPE, schema).unwrap();
+    }
+
+    #[fixture]
+    pub fn stub_custom_data() -> CustomData {
+        register_stub_custom_data_type();
+
+        let mut values = IndexMap::new();
+        values.insert("token".to_string(), CustomValue::Utf8("ETH".to_string()));
+        values.insert("inflow".to_string(), CustomValue::Float64(1250.5));
+        values.insert("transfers".to_string(), CustomValue::UInt64(42));
+        values.insert("is_exchange".to_string(), CustomValue::Boolean(true));
+        CustomData::new(STUB_CUSTOM_DATA_TYPE, values, 1, 2).unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{stubs::*, *};
+
+    #[rstest]
+    fn test_new_orders_values_by_schema(stub_custom_data: CustomData) {
+        let data = stub_custom_data;
+        let names: Vec<&str> = data.values.keys().map(String::as_str).collect();
+
+        assert_eq!(data.data_type.as_str(), STUB_CUSTOM_DATA_TYPE);
+        assert_eq!(names, vec!["token", "inflow", "transfers", "is_exchange"]);
+        assert_eq!(data.get("inflow"), Some(&CustomValue::Float64(1250.5)));
+    }
+
+    #[rstest]
+    fn test_new_when_unregistered_type() {
+        let result = CustomData::new("UnregisteredType", IndexMap::new(), 1, 2);
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_new_when_value_type_mismatch() {
+        register_stub_custom_data_type();
+
+        let mut values = IndexMap::new();
+        values.insert("token".to_string(), CustomValue::Utf8("ETH".to_string()));
+        values.insert("inflow".to_string(), CustomValue::Int64(1250));
+        values.insert("transfers".to_string(), CustomValue::UInt64(42));
+        values.insert("is_exchange".to_string(), CustomValue::Boolean(true));
+
+        let result = CustomData::new(STUB_CUSTOM_DATA_TYPE, values, 1, 2);
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_register_when_reserved_field() {
+        let mut schema = IndexMap::new();
+        schema.insert("ts_event".to_string(), CustomFieldType::UInt64);
+
+        let result = register_custom_data_type("ReservedFieldType", schema);
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_register_when_different_schema() {
+        register_stub_custom_data_type();
+
+        let mut schema = IndexMap::new();
+        schema.insert("token".to_string(), CustomFieldType::Utf8);
+
+        let result = register_custom_data_type(STUB_CUSTOM_DATA_TYPE, schema);
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_get_fields(stub_custom_data: CustomData) {
+        let fields = CustomData::get_fields(stub_custom_data.data_type.as_str()).unwrap();
+        let expected: Vec<(&str, &str)> = vec![
+            ("token", "Utf8"),
+            ("inflow", "Float64"),
+            ("transfers", "UInt64"),
+            ("is_exchange", "Boolean"),
+            ("ts_event", "UInt64"),
+            ("ts_init", "UInt64"),
+        ];
+
+        let fields: Vec<(&str, &str)> = fields
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        assert_eq!(fields, expected);
+    }
+
+    #[rstest]
+    fn test_display(stub_custom_data: CustomData) {
+        assert_eq!(
+            stub_custom_data.to_string(),
+            "OnChainFlow,token=ETH,inflow=1250.5,transfers=42,is_exchange=true,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_custom_data: CustomData) {
+        let data = stub_custom_data;
+        let serialized = data.as_json_bytes().unwrap();
+        let deserialized = CustomData::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, data);
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization(stub_custom_data: CustomData) {
+        let data = stub_custom_data;
+        let serialized = data.as_msgpack_bytes().unwrap();
+        let deserialized = CustomData::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized, data);
+    }
+}
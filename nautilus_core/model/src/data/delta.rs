@@ -24,7 +24,10 @@ use nautilus_core::{serialization::Serializable, time::UnixNanos};
 use serde::{Deserialize, Serialize};
 
 use super::order::{BookOrder, NULL_ORDER};
-use crate::{enums::BookAction, identifiers::instrument_id::InstrumentId};
+use crate::{
+    enums::{BookAction, RecordFlag},
+    identifiers::instrument_id::InstrumentId,
+};
 
 /// Represents a single change/delta in an order book.
 #[repr(C)]
@@ -86,7 +89,7 @@ impl OrderBookDelta {
             instrument_id,
             action: BookAction::Clear,
             order: NULL_ORDER,
-            flags: 32, // TODO: Flags constants
+            flags: RecordFlag::F_SNAPSHOT as u8,
             sequence,
             ts_event,
             ts_init,
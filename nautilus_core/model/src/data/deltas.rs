@@ -14,20 +14,25 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
+    collections::HashMap,
     fmt::{Display, Formatter},
     hash::{Hash, Hasher},
     ops::{Deref, DerefMut},
 };
 
-use nautilus_core::time::UnixNanos;
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
+use serde::{Deserialize, Serialize};
 
 use super::delta::OrderBookDelta;
-use crate::identifiers::instrument_id::InstrumentId;
+use crate::{enums::RecordFlag, identifiers::instrument_id::InstrumentId};
 
 /// Represents a grouped batch of `OrderBookDelta` updates for an `OrderBook`.
 ///
 /// This type cannot be `repr(C)` due to the `deltas` vec.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -67,6 +72,75 @@ impl OrderBookDeltas {
             ts_init,
         }
     }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> HashMap<String, String> {
+        OrderBookDelta::get_metadata(instrument_id, price_precision, size_precision)
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    ///
+    /// This is the [`OrderBookDelta`] field map, as batch boundaries are carried by the
+    /// [`RecordFlag::F_LAST`] flag on the final delta of each batch.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        OrderBookDelta::get_fields()
+    }
+
+    /// Flattens the given batches into a single [`OrderBookDeltas`], preserving delta order.
+    ///
+    /// Returns an error if `batches` is empty or contains deltas for more than one instrument.
+    pub fn flatten(batches: Vec<Self>) -> Result<Self> {
+        let Some(first) = batches.first() else {
+            bail!("`batches` cannot be empty");
+        };
+        let instrument_id = first.instrument_id;
+
+        let len = batches.iter().map(|batch| batch.deltas.len()).sum();
+        let mut deltas = Vec::with_capacity(len);
+        for batch in batches {
+            if batch.instrument_id != instrument_id {
+                bail!(
+                    "Cannot flatten deltas for different instruments, {} and {}",
+                    instrument_id,
+                    batch.instrument_id
+                );
+            }
+            deltas.extend(batch.deltas);
+        }
+
+        Ok(Self::new(instrument_id, deltas))
+    }
+
+    /// Splits the deltas into batches ending on each [`RecordFlag::F_LAST`] boundary.
+    ///
+    /// Any trailing deltas after the last `F_LAST` flag are returned as a final batch.
+    #[must_use]
+    pub fn split_by_flags(&self) -> Vec<Self> {
+        self.deltas
+            .split_inclusive(|delta| RecordFlag::F_LAST.matches(delta.flags))
+            .map(|chunk| Self::new(self.instrument_id, chunk.to_vec()))
+            .collect()
+    }
+
+    /// Re-chunks the deltas into batches of at most `max_size` deltas each.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `max_size` is zero.
+    #[must_use]
+    pub fn split_by_size(&self, max_size: usize) -> Vec<Self> {
+        assert!(max_size > 0, "`max_size` must be positive");
+        self.deltas
+            .chunks(max_size)
+            .map(|chunk| Self::new(self.instrument_id, chunk.to_vec()))
+            .collect()
+    }
 }
 
 impl PartialEq<Self> for OrderBookDeltas {
@@ -84,8 +158,7 @@ impl Hash for OrderBookDeltas {
     }
 }
 
-// TODO: Implement
-// impl Serializable for OrderBookDeltas {}
+impl Serializable for OrderBookDeltas {}
 
 // TODO: Exact format for Debug and Display TBD
 impl Display for OrderBookDeltas {
@@ -382,4 +455,80 @@ mod tests {
             "AAPL.XNAS,len=7,flags=32,sequence=0,ts_event=1,ts_init=2".to_string()
         );
     }
+
+    #[rstest]
+    fn test_json_serialization(stub_deltas: OrderBookDeltas) {
+        let deltas = stub_deltas;
+        let serialized = deltas.as_json_bytes().unwrap();
+        let deserialized = OrderBookDeltas::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, deltas);
+        assert_eq!(deserialized.deltas, deltas.deltas);
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization(stub_deltas: OrderBookDeltas) {
+        let deltas = stub_deltas;
+        let serialized = deltas.as_msgpack_bytes().unwrap();
+        let deserialized = OrderBookDeltas::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized, deltas);
+        assert_eq!(deserialized.deltas, deltas.deltas);
+    }
+
+    #[rstest]
+    fn test_flatten(stub_deltas: OrderBookDeltas) {
+        let batches = stub_deltas.split_by_size(3);
+        let flattened = OrderBookDeltas::flatten(batches).unwrap();
+
+        assert_eq!(flattened.deltas, stub_deltas.deltas);
+        assert_eq!(flattened.sequence, stub_deltas.sequence);
+        assert_eq!(flattened.ts_event, stub_deltas.ts_event);
+    }
+
+    #[rstest]
+    fn test_flatten_when_empty() {
+        assert!(OrderBookDeltas::flatten(vec![]).is_err());
+    }
+
+    #[rstest]
+    fn test_flatten_when_different_instruments(stub_deltas: OrderBookDeltas) {
+        let mut other = stub_deltas.clone();
+        other.instrument_id = InstrumentId::from("MSFT.XNAS");
+
+        assert!(OrderBookDeltas::flatten(vec![stub_deltas, other]).is_err());
+    }
+
+    #[rstest]
+    fn test_split_by_flags(stub_deltas: OrderBookDeltas) {
+        let mut deltas = stub_deltas;
+        deltas.deltas[2].flags |= RecordFlag::F_LAST as u8;
+        deltas.deltas[4].flags |= RecordFlag::F_LAST as u8;
+
+        let batches = deltas.split_by_flags();
+
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[0].deltas.len(), 3);
+        assert_eq!(batches[1].deltas.len(), 2);
+        assert_eq!(batches[2].deltas.len(), 2);
+        assert!(RecordFlag::F_LAST.matches(batches[0].flags));
+        assert!(RecordFlag::F_LAST.matches(batches[1].flags));
+        assert!(!RecordFlag::F_LAST.matches(batches[2].flags));
+    }
+
+    #[rstest]
+    fn test_split_by_size(stub_deltas: OrderBookDeltas) {
+        let batches = stub_deltas.split_by_size(3);
+
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[0].deltas.len(), 3);
+        assert_eq!(batches[1].deltas.len(), 3);
+        assert_eq!(batches[2].deltas.len(), 1);
+        assert_eq!(batches[0].deltas[0], stub_deltas.deltas[0]);
+        assert_eq!(batches[2].deltas[0], stub_deltas.deltas[6]);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`max_size` must be positive")]
+    fn test_split_by_size_when_zero(stub_deltas: OrderBookDeltas) {
+        let _ = stub_deltas.split_by_size(0);
+    }
 }
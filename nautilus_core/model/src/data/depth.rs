@@ -26,6 +26,7 @@ use super::order::BookOrder;
 use crate::identifiers::instrument_id::InstrumentId;
 
 pub const DEPTH10_LEN: usize = 10;
+pub const DEPTH25_LEN: usize = 25;
 
 /// Represents a self-contained order book update with a fixed depth of 10 levels per side.
 ///
@@ -190,6 +191,117 @@ impl Display for OrderBookDepth10 {
 
 impl Serializable for OrderBookDepth10 {}
 
+/// Represents a self-contained order book update with a fixed depth of 25 levels per side.
+///
+/// This is the 25 level equivalent of `OrderBookDepth10`, for venues which publish deeper
+/// fixed-depth books (e.g. OKX and Kraken). Unused levels are padded with default (zero size)
+/// orders.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct OrderBookDepth25 {
+    /// The instrument ID for the book.
+    pub instrument_id: InstrumentId,
+    /// The bid orders for the depth update.
+    pub bids: [BookOrder; DEPTH25_LEN],
+    /// The ask orders for the depth update.
+    pub asks: [BookOrder; DEPTH25_LEN],
+    /// The count of bid orders per level for the depth update.
+    pub bid_counts: [u32; DEPTH25_LEN],
+    /// The count of ask orders per level for the depth update.
+    pub ask_counts: [u32; DEPTH25_LEN],
+    /// A combination of packet end with matching engine status.
+    pub flags: u8,
+    /// The message sequence number assigned at the venue.
+    pub sequence: u64,
+    /// The UNIX timestamp (nanoseconds) when the data event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl OrderBookDepth25 {
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        bids: [BookOrder; DEPTH25_LEN],
+        asks: [BookOrder; DEPTH25_LEN],
+        bid_counts: [u32; DEPTH25_LEN],
+        ask_counts: [u32; DEPTH25_LEN],
+        flags: u8,
+        sequence: u64,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            bids,
+            asks,
+            bid_counts,
+            ask_counts,
+            flags,
+            sequence,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("price_precision".to_string(), price_precision.to_string());
+        metadata.insert("size_precision".to_string(), size_precision.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        for (prefix, data_type) in [
+            ("bid_price", "Int64"),
+            ("ask_price", "Int64"),
+            ("bid_size", "UInt64"),
+            ("ask_size", "UInt64"),
+            ("bid_count", "UInt32"),
+            ("ask_count", "UInt32"),
+        ] {
+            for i in 0..DEPTH25_LEN {
+                metadata.insert(format!("{prefix}_{i}"), data_type.to_string());
+            }
+        }
+        metadata.insert("flags".to_string(), "UInt8".to_string());
+        metadata.insert("sequence".to_string(), "UInt64".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+// TODO: Exact format for Debug and Display TBD
+impl Display for OrderBookDepth25 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},flags={},sequence={},ts_event={},ts_init={}",
+            self.instrument_id, self.flags, self.sequence, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl Serializable for OrderBookDepth25 {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Stubs
 ////////////////////////////////////////////////////////////////////////////////
@@ -198,7 +310,7 @@ impl Serializable for OrderBookDepth10 {}
 pub mod stubs {
     use rstest::fixture;
 
-    use super::{OrderBookDepth10, DEPTH10_LEN};
+    use super::{OrderBookDepth10, OrderBookDepth25, DEPTH10_LEN, DEPTH25_LEN};
     use crate::{
         data::order::BookOrder,
         enums::OrderSide,
@@ -272,6 +384,73 @@ pub mod stubs {
             ts_init,
         )
     }
+
+    #[fixture]
+    pub fn stub_depth25() -> OrderBookDepth25 {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let flags = 0;
+        let sequence = 0;
+        let ts_event = 1;
+        let ts_init = 2;
+
+        let mut bids: [BookOrder; DEPTH25_LEN] = [BookOrder::default(); DEPTH25_LEN];
+        let mut asks: [BookOrder; DEPTH25_LEN] = [BookOrder::default(); DEPTH25_LEN];
+
+        // Create bids
+        let mut price = 99.00;
+        let mut quantity = 100.0;
+        let mut order_id = 1;
+
+        for i in 0..DEPTH25_LEN {
+            let order = BookOrder::new(
+                OrderSide::Buy,
+                Price::new(price, 2).unwrap(),
+                Quantity::new(quantity, 0).unwrap(),
+                order_id,
+            );
+
+            bids[i] = order;
+
+            price -= 1.0;
+            quantity += 100.0;
+            order_id += 1;
+        }
+
+        // Create asks
+        let mut price = 100.00;
+        let mut quantity = 100.0;
+        let mut order_id = 26;
+
+        for i in 0..DEPTH25_LEN {
+            let order = BookOrder::new(
+                OrderSide::Sell,
+                Price::new(price, 2).unwrap(),
+                Quantity::new(quantity, 0).unwrap(),
+                order_id,
+            );
+
+            asks[i] = order;
+
+            price += 1.0;
+            quantity += 100.0;
+            order_id += 1;
+        }
+
+        let bid_counts: [u32; DEPTH25_LEN] = [1; DEPTH25_LEN];
+        let ask_counts: [u32; DEPTH25_LEN] = [1; DEPTH25_LEN];
+
+        OrderBookDepth25::new(
+            instrument_id,
+            bids,
+            asks,
+            bid_counts,
+            ask_counts,
+            flags,
+            sequence,
+            ts_event,
+            ts_init,
+        )
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -318,4 +497,29 @@ mod tests {
             "AAPL.XNAS,flags=0,sequence=0,ts_event=1,ts_init=2".to_string()
         );
     }
+
+    #[rstest]
+    fn test_new_depth25(stub_depth25: OrderBookDepth25) {
+        let depth = stub_depth25;
+
+        assert_eq!(depth.instrument_id, InstrumentId::from("AAPL.XNAS"));
+        assert_eq!(depth.bids.len(), 25);
+        assert_eq!(depth.asks.len(), 25);
+        assert_eq!(depth.asks[24].price.as_f64(), 124.0);
+        assert_eq!(depth.asks[0].price.as_f64(), 100.0);
+        assert_eq!(depth.bids[0].price.as_f64(), 99.0);
+        assert_eq!(depth.bids[24].price.as_f64(), 75.0);
+        assert_eq!(depth.bid_counts[24], 1);
+        assert_eq!(depth.ask_counts[24], 1);
+    }
+
+    #[rstest]
+    fn test_depth25_get_fields() {
+        let fields = OrderBookDepth25::get_fields();
+
+        assert_eq!(fields.len(), 6 * DEPTH25_LEN + 4);
+        assert_eq!(fields.get_index(0).unwrap().0, "bid_price_0");
+        assert_eq!(fields.get_index(DEPTH25_LEN).unwrap().0, "ask_price_0");
+        assert_eq!(fields.get_index(6 * DEPTH25_LEN).unwrap().0, "flags");
+    }
 }
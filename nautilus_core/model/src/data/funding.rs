@@ -0,0 +1,195 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::identifiers::instrument_id::InstrumentId;
+
+/// Represents a funding rate update for a perpetual swap instrument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct FundingRateUpdate {
+    /// The instrument ID for the funding rate.
+    pub instrument_id: InstrumentId,
+    /// The current funding rate.
+    pub rate: Decimal,
+    /// The UNIX timestamp (nanoseconds) of the next scheduled funding event, if known.
+    pub next_funding_ns: Option<UnixNanos>,
+    /// The UNIX timestamp (nanoseconds) when the funding rate event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl FundingRateUpdate {
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        rate: Decimal,
+        next_funding_ns: Option<UnixNanos>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            rate,
+            next_funding_ns,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the duration (nanoseconds) from `ts_now` until the next scheduled funding event.
+    ///
+    /// Returns `None` if the next funding time is unknown, or zero if it has already passed.
+    #[must_use]
+    pub fn time_until_next_funding(&self, ts_now: UnixNanos) -> Option<u64> {
+        self.next_funding_ns
+            .map(|next_funding_ns| next_funding_ns.saturating_sub(ts_now))
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(instrument_id: &InstrumentId) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("rate".to_string(), "Utf8".to_string());
+        metadata.insert("next_funding_ns".to_string(), "UInt64".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for FundingRateUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            self.instrument_id,
+            self.rate,
+            self.next_funding_ns
+                .map_or_else(|| "None".to_string(), |ns| ns.to_string()),
+            self.ts_event,
+            self.ts_init
+        )
+    }
+}
+
+impl Serializable for FundingRateUpdate {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Stubs
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "stubs")]
+pub mod stubs {
+    use rstest::fixture;
+    use rust_decimal_macros::dec;
+
+    use super::FundingRateUpdate;
+    use crate::identifiers::instrument_id::InstrumentId;
+
+    #[fixture]
+    pub fn stub_funding_rate_update() -> FundingRateUpdate {
+        FundingRateUpdate::new(
+            InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+            dec!(0.0001),
+            Some(28_800_000_000_000),
+            1,
+            2,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::{stubs::*, *};
+
+    #[rstest]
+    fn test_display(stub_funding_rate_update: FundingRateUpdate) {
+        assert_eq!(
+            stub_funding_rate_update.to_string(),
+            "BTCUSDT-PERP.BINANCE,0.0001,28800000000000,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_display_when_next_funding_unknown(mut stub_funding_rate_update: FundingRateUpdate) {
+        stub_funding_rate_update.next_funding_ns = None;
+        assert_eq!(
+            stub_funding_rate_update.to_string(),
+            "BTCUSDT-PERP.BINANCE,0.0001,None,1,2"
+        );
+    }
+
+    #[rstest]
+    #[case(Some(100), 40, Some(60))]
+    #[case(Some(100), 150, Some(0))]
+    #[case(None, 40, None)]
+    fn test_time_until_next_funding(
+        mut stub_funding_rate_update: FundingRateUpdate,
+        #[case] next_funding_ns: Option<UnixNanos>,
+        #[case] ts_now: UnixNanos,
+        #[case] expected: Option<u64>,
+    ) {
+        stub_funding_rate_update.next_funding_ns = next_funding_ns;
+        assert_eq!(
+            stub_funding_rate_update.time_until_next_funding(ts_now),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_funding_rate_update: FundingRateUpdate) {
+        let update = stub_funding_rate_update;
+        let serialized = update.as_json_bytes().unwrap();
+        let deserialized = FundingRateUpdate::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, update);
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization(stub_funding_rate_update: FundingRateUpdate) {
+        let update = stub_funding_rate_update;
+        let serialized = update.as_msgpack_bytes().unwrap();
+        let deserialized = FundingRateUpdate::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized, update);
+    }
+}
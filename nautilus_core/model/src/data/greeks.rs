@@ -0,0 +1,224 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use crate::identifiers::instrument_id::InstrumentId;
+
+/// Represents the option greeks and implied volatility for an instrument.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct GreeksData {
+    /// The instrument ID for the greeks.
+    pub instrument_id: InstrumentId,
+    /// The sensitivity of the option price to a change in the underlying price.
+    pub delta: f64,
+    /// The sensitivity of delta to a change in the underlying price.
+    pub gamma: f64,
+    /// The sensitivity of the option price to a change in implied volatility.
+    pub vega: f64,
+    /// The sensitivity of the option price to the passage of time.
+    pub theta: f64,
+    /// The implied volatility of the option.
+    pub implied_vol: f64,
+    /// The UNIX timestamp (nanoseconds) when the greeks were calculated.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl GreeksData {
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        delta: f64,
+        gamma: f64,
+        vega: f64,
+        theta: f64,
+        implied_vol: f64,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            delta,
+            gamma,
+            vega,
+            theta,
+            implied_vol,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the greeks scaled by the given `factor`, leaving implied volatility unchanged.
+    ///
+    /// Typically the factor is a signed position quantity multiplied by the contract
+    /// multiplier, giving the position-level exposures for aggregation.
+    #[must_use]
+    pub fn scaled(&self, factor: f64) -> Self {
+        Self {
+            delta: self.delta * factor,
+            gamma: self.gamma * factor,
+            vega: self.vega * factor,
+            theta: self.theta * factor,
+            ..*self
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(instrument_id: &InstrumentId) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("delta".to_string(), "Float64".to_string());
+        metadata.insert("gamma".to_string(), "Float64".to_string());
+        metadata.insert("vega".to_string(), "Float64".to_string());
+        metadata.insert("theta".to_string(), "Float64".to_string());
+        metadata.insert("implied_vol".to_string(), "Float64".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for GreeksData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{}",
+            self.instrument_id,
+            self.delta,
+            self.gamma,
+            self.vega,
+            self.theta,
+            self.implied_vol,
+            self.ts_event,
+            self.ts_init
+        )
+    }
+}
+
+impl Serializable for GreeksData {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Stubs
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "stubs")]
+pub mod stubs {
+    use rstest::fixture;
+
+    use super::GreeksData;
+    use crate::identifiers::instrument_id::InstrumentId;
+
+    #[fixture]
+    pub fn stub_greeks_data() -> GreeksData {
+        GreeksData::new(
+            InstrumentId::from("BTC-28JUN24-70000-C.DERIBIT"),
+            0.5,
+            0.25,
+            12.5,
+            -3.75,
+            0.625,
+            1,
+            2,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::{stubs::*, *};
+
+    #[rstest]
+    fn test_display(stub_greeks_data: GreeksData) {
+        assert_eq!(
+            stub_greeks_data.to_string(),
+            "BTC-28JUN24-70000-C.DERIBIT,0.5,0.25,12.5,-3.75,0.625,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_scaled(stub_greeks_data: GreeksData) {
+        let scaled = stub_greeks_data.scaled(-2.0);
+        assert_eq!(scaled.instrument_id, stub_greeks_data.instrument_id);
+        assert_eq!(scaled.delta, -1.0);
+        assert_eq!(scaled.gamma, -0.5);
+        assert_eq!(scaled.vega, -25.0);
+        assert_eq!(scaled.theta, 7.5);
+        assert_eq!(scaled.implied_vol, 0.625);
+        assert_eq!(scaled.ts_event, stub_greeks_data.ts_event);
+        assert_eq!(scaled.ts_init, stub_greeks_data.ts_init);
+    }
+
+    #[rstest]
+    fn test_get_fields() {
+        let fields = GreeksData::get_fields();
+        let keys: Vec<&str> = fields.keys().map(String::as_str).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "delta",
+                "gamma",
+                "vega",
+                "theta",
+                "implied_vol",
+                "ts_event",
+                "ts_init"
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_greeks_data: GreeksData) {
+        let greeks = stub_greeks_data;
+        let serialized = greeks.as_json_bytes().unwrap();
+        let deserialized = GreeksData::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, greeks);
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization(stub_greeks_data: GreeksData) {
+        let greeks = stub_greeks_data;
+        let serialized = greeks.as_msgpack_bytes().unwrap();
+        let deserialized = GreeksData::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized, greeks);
+    }
+}
@@ -0,0 +1,174 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::OrderSide,
+    identifiers::instrument_id::InstrumentId,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents a forced liquidation order executed by the venue for a derivatives instrument.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct LiquidationTick {
+    /// The instrument ID for the liquidation.
+    pub instrument_id: InstrumentId,
+    /// The side of the liquidation order (a `SELL` liquidates a long position).
+    pub side: OrderSide,
+    /// The price of the liquidation order.
+    pub price: Price,
+    /// The size of the liquidation order.
+    pub size: Quantity,
+    /// The UNIX timestamp (nanoseconds) when the liquidation event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl LiquidationTick {
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        side: OrderSide,
+        price: Price,
+        size: Quantity,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            side,
+            price,
+            size,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("price_precision".to_string(), price_precision.to_string());
+        metadata.insert("size_precision".to_string(), size_precision.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("side".to_string(), "UInt8".to_string());
+        metadata.insert("price".to_string(), "Int64".to_string());
+        metadata.insert("size".to_string(), "UInt64".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for LiquidationTick {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{}",
+            self.instrument_id, self.side, self.price, self.size, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl Serializable for LiquidationTick {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Stubs
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "stubs")]
+pub mod stubs {
+    use rstest::fixture;
+
+    use super::LiquidationTick;
+    use crate::{
+        enums::OrderSide,
+        identifiers::instrument_id::InstrumentId,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    #[fixture]
+    pub fn stub_liquidation_tick() -> LiquidationTick {
+        LiquidationTick::new(
+            InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+            OrderSide::Sell,
+            Price::from("50100.10"),
+            Quantity::from("0.014"),
+            1,
+            2,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::{stubs::*, *};
+
+    #[rstest]
+    fn test_display(stub_liquidation_tick: LiquidationTick) {
+        assert_eq!(
+            stub_liquidation_tick.to_string(),
+            "BTCUSDT-PERP.BINANCE,SELL,50100.10,0.014,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_liquidation_tick: LiquidationTick) {
+        let tick = stub_liquidation_tick;
+        let serialized = tick.as_json_bytes().unwrap();
+        let deserialized = LiquidationTick::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, tick);
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization(stub_liquidation_tick: LiquidationTick) {
+        let tick = stub_liquidation_tick;
+        let serialized = tick.as_msgpack_bytes().unwrap();
+        let deserialized = LiquidationTick::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized, tick);
+    }
+}
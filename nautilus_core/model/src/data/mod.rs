@@ -14,24 +14,36 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod bar;
+pub mod close;
+pub mod custom;
 pub mod delta;
 pub mod deltas;
 pub mod depth;
+pub mod funding;
+pub mod greeks;
+pub mod liquidation;
+pub mod open_interest;
 pub mod order;
+pub mod prices;
 pub mod quote;
 #[cfg(feature = "stubs")]
 pub mod stubs;
 pub mod trade;
+pub mod trades;
 
 use nautilus_core::time::UnixNanos;
 
 use self::{
     bar::Bar,
+    custom::CustomData,
     delta::OrderBookDelta,
     deltas::{OrderBookDeltas, OrderBookDeltas_API},
-    depth::OrderBookDepth10,
+    depth::{OrderBookDepth10, OrderBookDepth25},
+    open_interest::OpenInterestUpdate,
+    prices::{IndexPriceUpdate, MarkPriceUpdate},
     quote::QuoteTick,
     trade::TradeTick,
+    trades::{TradeTicks, TradeTicks_API},
 };
 
 #[repr(C)]
@@ -41,9 +53,14 @@ pub enum Data {
     Delta(OrderBookDelta),
     Deltas(OrderBookDeltas_API),
     Depth10(OrderBookDepth10),
+    Depth25(Box<OrderBookDepth25>), // Boxed to avoid increasing the size of every variant
     Quote(QuoteTick),
     Trade(TradeTick),
+    Trades(TradeTicks_API),
     Bar(Bar),
+    MarkPrice(MarkPriceUpdate),
+    IndexPrice(IndexPriceUpdate),
+    OpenInterest(OpenInterestUpdate),
 }
 
 pub trait HasTsInit {
@@ -56,9 +73,14 @@ impl HasTsInit for Data {
             Self::Delta(d) => d.ts_init,
             Self::Deltas(d) => d.ts_init,
             Self::Depth10(d) => d.ts_init,
+            Self::Depth25(d) => d.ts_init,
             Self::Quote(q) => q.ts_init,
             Self::Trade(t) => t.ts_init,
+            Self::Trades(t) => t.ts_init,
             Self::Bar(b) => b.ts_init,
+            Self::MarkPrice(p) => p.ts_init,
+            Self::IndexPrice(p) => p.ts_init,
+            Self::OpenInterest(o) => o.ts_init,
         }
     }
 }
@@ -81,6 +103,12 @@ impl HasTsInit for OrderBookDepth10 {
     }
 }
 
+impl HasTsInit for OrderBookDepth25 {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
 impl HasTsInit for QuoteTick {
     fn get_ts_init(&self) -> UnixNanos {
         self.ts_init
@@ -93,12 +121,42 @@ impl HasTsInit for TradeTick {
     }
 }
 
+impl HasTsInit for TradeTicks {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
 impl HasTsInit for Bar {
     fn get_ts_init(&self) -> UnixNanos {
         self.ts_init
     }
 }
 
+impl HasTsInit for MarkPriceUpdate {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl HasTsInit for IndexPriceUpdate {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl HasTsInit for OpenInterestUpdate {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl HasTsInit for CustomData {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
 pub fn is_monotonically_increasing_by_init<T: HasTsInit>(data: &[T]) -> bool {
     data.windows(2)
         .all(|window| window[0].get_ts_init() <= window[1].get_ts_init())
@@ -116,12 +174,24 @@ impl From<OrderBookDeltas_API> for Data {
     }
 }
 
+impl From<OrderBookDeltas> for Data {
+    fn from(value: OrderBookDeltas) -> Self {
+        Self::Deltas(OrderBookDeltas_API::new(value))
+    }
+}
+
 impl From<OrderBookDepth10> for Data {
     fn from(value: OrderBookDepth10) -> Self {
         Self::Depth10(value)
     }
 }
 
+impl From<OrderBookDepth25> for Data {
+    fn from(value: OrderBookDepth25) -> Self {
+        Self::Depth25(Box::new(value))
+    }
+}
+
 impl From<QuoteTick> for Data {
     fn from(value: QuoteTick) -> Self {
         Self::Quote(value)
@@ -134,12 +204,42 @@ impl From<TradeTick> for Data {
     }
 }
 
+impl From<TradeTicks_API> for Data {
+    fn from(value: TradeTicks_API) -> Self {
+        Self::Trades(value)
+    }
+}
+
+impl From<TradeTicks> for Data {
+    fn from(value: TradeTicks) -> Self {
+        Self::Trades(TradeTicks_API::new(value))
+    }
+}
+
 impl From<Bar> for Data {
     fn from(value: Bar) -> Self {
         Self::Bar(value)
     }
 }
 
+impl From<MarkPriceUpdate> for Data {
+    fn from(value: MarkPriceUpdate) -> Self {
+        Self::MarkPrice(value)
+    }
+}
+
+impl From<IndexPriceUpdate> for Data {
+    fn from(value: IndexPriceUpdate) -> Self {
+        Self::IndexPrice(value)
+    }
+}
+
+impl From<OpenInterestUpdate> for Data {
+    fn from(value: OpenInterestUpdate) -> Self {
+        Self::OpenInterest(value)
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn data_clone(data: &Data) -> Data {
     data.clone()
@@ -0,0 +1,159 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use crate::{identifiers::instrument_id::InstrumentId, types::quantity::Quantity};
+
+/// Represents an open interest update for a futures or perpetual swap instrument.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct OpenInterestUpdate {
+    /// The instrument ID for the open interest.
+    pub instrument_id: InstrumentId,
+    /// The total number of outstanding contracts.
+    pub value: Quantity,
+    /// The UNIX timestamp (nanoseconds) when the open interest event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl OpenInterestUpdate {
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        value: Quantity,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            value,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        size_precision: u8,
+    ) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("size_precision".to_string(), size_precision.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("value".to_string(), "UInt64".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for OpenInterestUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.instrument_id, self.value, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl Serializable for OpenInterestUpdate {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Stubs
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "stubs")]
+pub mod stubs {
+    use rstest::fixture;
+
+    use super::OpenInterestUpdate;
+    use crate::{identifiers::instrument_id::InstrumentId, types::quantity::Quantity};
+
+    #[fixture]
+    pub fn stub_open_interest_update() -> OpenInterestUpdate {
+        OpenInterestUpdate::new(
+            InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+            Quantity::from("84125.317"),
+            1,
+            2,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::{stubs::*, *};
+
+    #[rstest]
+    fn test_display(stub_open_interest_update: OpenInterestUpdate) {
+        assert_eq!(
+            stub_open_interest_update.to_string(),
+            "BTCUSDT-PERP.BINANCE,84125.317,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_get_fields() {
+        let fields = OpenInterestUpdate::get_fields();
+        let keys: Vec<&str> = fields.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["value", "ts_event", "ts_init"]);
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_open_interest_update: OpenInterestUpdate) {
+        let update = stub_open_interest_update;
+        let serialized = update.as_json_bytes().unwrap();
+        let deserialized = OpenInterestUpdate::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, update);
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization(stub_open_interest_update: OpenInterestUpdate) {
+        let update = stub_open_interest_update;
+        let serialized = update.as_msgpack_bytes().unwrap();
+        let deserialized = OpenInterestUpdate::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized, update);
+    }
+}
@@ -0,0 +1,254 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use crate::{identifiers::instrument_id::InstrumentId, types::price::Price};
+
+/// Represents a mark price update for a derivatives instrument, as published by the venue.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct MarkPriceUpdate {
+    /// The instrument ID for the mark price.
+    pub instrument_id: InstrumentId,
+    /// The mark price value.
+    pub value: Price,
+    /// The UNIX timestamp (nanoseconds) when the price event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl MarkPriceUpdate {
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        value: Price,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            value,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+    ) -> HashMap<String, String> {
+        get_price_metadata(instrument_id, price_precision)
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        get_price_fields()
+    }
+}
+
+impl Display for MarkPriceUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.instrument_id, self.value, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl Serializable for MarkPriceUpdate {}
+
+/// Represents an index price update for a derivatives instrument, as published by the venue.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct IndexPriceUpdate {
+    /// The instrument ID for the index price.
+    pub instrument_id: InstrumentId,
+    /// The index price value.
+    pub value: Price,
+    /// The UNIX timestamp (nanoseconds) when the price event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl IndexPriceUpdate {
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        value: Price,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            value,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+    ) -> HashMap<String, String> {
+        get_price_metadata(instrument_id, price_precision)
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        get_price_fields()
+    }
+}
+
+impl Display for IndexPriceUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.instrument_id, self.value, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl Serializable for IndexPriceUpdate {}
+
+fn get_price_metadata(
+    instrument_id: &InstrumentId,
+    price_precision: u8,
+) -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+    metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+    metadata.insert("price_precision".to_string(), price_precision.to_string());
+    metadata
+}
+
+fn get_price_fields() -> IndexMap<String, String> {
+    let mut metadata = IndexMap::new();
+    metadata.insert("value".to_string(), "Int64".to_string());
+    metadata.insert("ts_event".to_string(), "UInt64".to_string());
+    metadata.insert("ts_init".to_string(), "UInt64".to_string());
+    metadata
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Stubs
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "stubs")]
+pub mod stubs {
+    use rstest::fixture;
+
+    use super::{IndexPriceUpdate, MarkPriceUpdate};
+    use crate::{identifiers::instrument_id::InstrumentId, types::price::Price};
+
+    #[fixture]
+    pub fn stub_mark_price_update() -> MarkPriceUpdate {
+        MarkPriceUpdate::new(
+            InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+            Price::from("50200.00"),
+            1,
+            2,
+        )
+    }
+
+    #[fixture]
+    pub fn stub_index_price_update() -> IndexPriceUpdate {
+        IndexPriceUpdate::new(
+            InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+            Price::from("50100.00"),
+            1,
+            2,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::{stubs::*, *};
+
+    #[rstest]
+    fn test_mark_price_display(stub_mark_price_update: MarkPriceUpdate) {
+        assert_eq!(
+            stub_mark_price_update.to_string(),
+            "BTCUSDT-PERP.BINANCE,50200.00,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_index_price_display(stub_index_price_update: IndexPriceUpdate) {
+        assert_eq!(
+            stub_index_price_update.to_string(),
+            "BTCUSDT-PERP.BINANCE,50100.00,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_get_fields() {
+        let fields = MarkPriceUpdate::get_fields();
+        assert_eq!(
+            fields.keys().collect::<Vec<_>>(),
+            vec!["value", "ts_event", "ts_init"]
+        );
+        assert_eq!(fields, IndexPriceUpdate::get_fields());
+    }
+
+    #[rstest]
+    fn test_mark_price_json_serialization(stub_mark_price_update: MarkPriceUpdate) {
+        let update = stub_mark_price_update;
+        let serialized = update.as_json_bytes().unwrap();
+        let deserialized = MarkPriceUpdate::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, update);
+    }
+
+    #[rstest]
+    fn test_index_price_msgpack_serialization(stub_index_price_update: IndexPriceUpdate) {
+        let update = stub_index_price_update;
+        let serialized = update.as_msgpack_bytes().unwrap();
+        let deserialized = IndexPriceUpdate::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized, update);
+    }
+}
@@ -200,7 +200,7 @@ mod tests {
     use rstest::rstest;
 
     use super::stubs::*;
-    use crate::{data::quote::QuoteTick, enums::PriceType};
+    use crate::{data::quote::QuoteTick, enums::PriceType, types::price::PriceRaw};
 
     #[rstest]
     fn test_to_string(quote_tick_ethusdt_binance: QuoteTick) {
@@ -217,7 +217,7 @@ mod tests {
     #[case(PriceType::Mid, 10_000_500_000_000)]
     fn test_extract_price(
         #[case] input: PriceType,
-        #[case] expected: i64,
+        #[case] expected: PriceRaw,
         quote_tick_ethusdt_binance: QuoteTick,
     ) {
         let tick = quote_tick_ethusdt_binance;
@@ -0,0 +1,290 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use super::trade::TradeTick;
+use crate::identifiers::instrument_id::InstrumentId;
+
+/// Represents a grouped batch of `TradeTick` events for a single instrument, such as a
+/// burst of trades delivered in one venue message.
+///
+/// This type cannot be `repr(C)` due to the `trades` vec.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct TradeTicks {
+    /// The instrument ID for the trades.
+    pub instrument_id: InstrumentId,
+    /// The trade ticks.
+    pub trades: Vec<TradeTick>,
+    /// A combination of packet end with matching engine status.
+    pub flags: u8,
+    /// The message sequence number assigned at the venue.
+    pub sequence: u64,
+    /// The UNIX timestamp (nanoseconds) when the data event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl TradeTicks {
+    /// Creates a new [`TradeTicks`] instance, with timestamps taken from the last trade.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `trades` is empty, or contains trades for another instrument.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        trades: Vec<TradeTick>,
+        flags: u8,
+        sequence: u64,
+    ) -> Self {
+        assert!(!trades.is_empty(), "`trades` cannot be empty");
+        assert!(
+            trades
+                .iter()
+                .all(|trade| trade.instrument_id == instrument_id),
+            "`trades` must all be for {instrument_id}"
+        );
+        // SAFETY: We asserted `trades` is not empty
+        let last = trades.last().unwrap();
+        let ts_event = last.ts_event;
+        let ts_init = last.ts_init;
+        Self {
+            instrument_id,
+            trades,
+            flags,
+            sequence,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> HashMap<String, String> {
+        TradeTick::get_metadata(instrument_id, price_precision, size_precision)
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    ///
+    /// This is the [`TradeTick`] field map with trailing `flags`, `sequence` and `batch_id`
+    /// columns, which group rows back into their original batches on decode.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = TradeTick::get_fields();
+        metadata.insert("flags".to_string(), "UInt8".to_string());
+        metadata.insert("sequence".to_string(), "UInt64".to_string());
+        metadata.insert("batch_id".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl PartialEq<Self> for TradeTicks {
+    fn eq(&self, other: &Self) -> bool {
+        self.instrument_id == other.instrument_id
+            && self.sequence == other.sequence
+            && self.trades == other.trades
+    }
+}
+
+impl Eq for TradeTicks {}
+
+impl Hash for TradeTicks {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instrument_id.hash(state);
+        self.sequence.hash(state);
+        self.ts_event.hash(state);
+    }
+}
+
+impl Serializable for TradeTicks {}
+
+impl Display for TradeTicks {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},len={},flags={},sequence={},ts_event={},ts_init={}",
+            self.instrument_id,
+            self.trades.len(),
+            self.flags,
+            self.sequence,
+            self.ts_event,
+            self.ts_init
+        )
+    }
+}
+
+/// Provides a C compatible Foreign Function Interface (FFI) for an underlying [`TradeTicks`].
+///
+/// This struct wraps `TradeTicks` in a way that makes it compatible with C function
+/// calls, enabling interaction with `TradeTicks` in a C environment.
+#[repr(C)]
+#[derive(Debug, Clone)]
+#[allow(non_camel_case_types)]
+pub struct TradeTicks_API(Box<TradeTicks>);
+
+impl TradeTicks_API {
+    #[must_use]
+    pub fn new(trades: TradeTicks) -> Self {
+        Self(Box::new(trades))
+    }
+
+    /// Consumes the wrapper, returning the underlying `TradeTicks`.
+    #[must_use]
+    pub fn into_inner(self) -> TradeTicks {
+        *self.0
+    }
+}
+
+impl Deref for TradeTicks_API {
+    type Target = TradeTicks;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for TradeTicks_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Stubs
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "stubs")]
+pub mod stubs {
+    use rstest::fixture;
+
+    use super::TradeTicks;
+    use crate::{
+        data::trade::TradeTick,
+        enums::AggressorSide,
+        identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+        types::{price::Price, quantity::Quantity},
+    };
+
+    #[fixture]
+    pub fn stub_trade_ticks() -> TradeTicks {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let trades = [
+            ("10000.0000", "1.00000000", AggressorSide::Buyer, "1"),
+            ("10000.5000", "2.00000000", AggressorSide::Buyer, "2"),
+            ("9999.5000", "0.50000000", AggressorSide::Seller, "3"),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, (price, size, aggressor_side, trade_id))| {
+            TradeTick::new(
+                instrument_id,
+                Price::from(price),
+                Quantity::from(size),
+                aggressor_side,
+                TradeId::new(trade_id).unwrap(),
+                i as u64,
+                i as u64 + 1,
+            )
+        })
+        .collect();
+
+        TradeTicks::new(instrument_id, trades, 0, 1)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{stubs::*, *};
+
+    #[rstest]
+    fn test_new(stub_trade_ticks: TradeTicks) {
+        let trades = stub_trade_ticks;
+
+        assert_eq!(
+            trades.instrument_id,
+            InstrumentId::from("ETHUSDT-PERP.BINANCE")
+        );
+        assert_eq!(trades.trades.len(), 3);
+        assert_eq!(trades.flags, 0);
+        assert_eq!(trades.sequence, 1);
+        assert_eq!(trades.ts_event, 2);
+        assert_eq!(trades.ts_init, 3);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`trades` cannot be empty")]
+    fn test_new_when_empty() {
+        let _ = TradeTicks::new(InstrumentId::from("AAPL.XNAS"), vec![], 0, 0);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`trades` must all be for AAPL.XNAS")]
+    fn test_new_with_mixed_instruments(stub_trade_ticks: TradeTicks) {
+        let _ = TradeTicks::new(
+            InstrumentId::from("AAPL.XNAS"),
+            stub_trade_ticks.trades,
+            0,
+            0,
+        );
+    }
+
+    #[rstest]
+    fn test_display(stub_trade_ticks: TradeTicks) {
+        assert_eq!(
+            stub_trade_ticks.to_string(),
+            "ETHUSDT-PERP.BINANCE,len=3,flags=0,sequence=1,ts_event=2,ts_init=3"
+        );
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_trade_ticks: TradeTicks) {
+        let trades = stub_trade_ticks;
+        let serialized = trades.as_json_bytes().unwrap();
+        let deserialized = TradeTicks::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, trades);
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization(stub_trade_ticks: TradeTicks) {
+        let trades = stub_trade_ticks;
+        let serialized = trades.as_msgpack_bytes().unwrap();
+        let deserialized = TradeTicks::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized, trades);
+    }
+}
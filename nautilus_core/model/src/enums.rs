@@ -220,6 +220,8 @@ pub enum InstrumentClass {
     Warrant = 10,
     /// A warrant instrument class. A derivative that gives the holder the right, but not the obligation, to buy or sell a security—most commonly an equity—at a certain price before expiration.
     SportsBetting = 11,
+    /// A binary option instrument class. A contract which settles at either a fixed amount or nothing depending on the outcome of an event, such as a prediction market contract.
+    BinaryOption = 12,
 }
 
 /// The aggregation method through which a bar is generated and closed.
@@ -278,6 +280,10 @@ pub enum BarAggregation {
     Week = 15,
     /// Based on time intervals with month granularity.
     Month = 16,
+    /// Based on fixed size price movements (bricks), with the brick size in price increments.
+    Renko = 17,
+    /// Based on the high-low price range, with the range in price increments.
+    Range = 18,
 }
 
 /// The type of order book action for an order book event.
@@ -539,6 +545,8 @@ pub enum MarketStatus {
     PreClose = 6,
     /// The market session is closed.
     Closed = 7,
+    /// The market session is in an auction call phase.
+    Auction = 8,
 }
 
 /// The reason for a venue or market halt.
@@ -571,6 +579,46 @@ pub enum HaltReason {
     General = 2,
     /// Trading halt is imposed by the venue to protect against extreme volatility.
     Volatility = 3,
+    /// Trading halt is imposed after the price reached a limit-up or limit-down band.
+    LimitUpLimitDown = 4,
+    /// Trading halt is imposed by a market-wide circuit breaker.
+    CircuitBreaker = 5,
+}
+
+/// The auction call phase for a market session.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum AuctionPhase {
+    /// The market session is not in an auction call phase.
+    NoAuction = 1,
+    /// The opening auction call phase before continuous trading.
+    Opening = 2,
+    /// The closing auction call phase after continuous trading.
+    Closing = 3,
+    /// A scheduled intraday auction call phase.
+    Intraday = 4,
+    /// An unscheduled auction call phase triggered by a price breaching the volatility bands.
+    VolatilityInterruption = 5,
 }
 
 /// The order management system (OMS) type for a trading venue or trading strategy.
@@ -799,6 +847,38 @@ pub enum OrderType {
     TrailingStopLimit = 9,
 }
 
+/// The reference price which the limit price of a pegged order is pegged to.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum PegPriceType {
+    /// No peg is specified (the order is not pegged).
+    NoPeg = 0, // Will be replaced by `Option`
+    /// Pegged to the mid-point of the best bid and ask, offset away from the market.
+    MidPoint = 1,
+    /// Pegged to the same side of the market (best bid for BUY, best ask for SELL), offset towards the market.
+    Primary = 2,
+}
+
 /// The market side for a specific position, or action related to positions.
 #[repr(C)]
 #[derive(
@@ -868,6 +948,89 @@ pub enum PriceType {
     Last = 4,
 }
 
+/// The rounding mode for fixed-point division and rescaling of value types.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum RoundingMode {
+    /// Rounds away from zero.
+    Up = 1,
+    /// Rounds towards zero (truncation).
+    Down = 2,
+    /// Rounds towards positive infinity.
+    Ceiling = 3,
+    /// Rounds towards negative infinity.
+    Floor = 4,
+    /// Rounds to the nearest value, with ties rounded away from zero.
+    HalfUp = 5,
+    /// Rounds to the nearest value, with ties rounded towards zero.
+    HalfDown = 6,
+    /// Rounds to the nearest value, with ties rounded to the nearest even value (banker's rounding).
+    HalfEven = 7,
+}
+
+/// A record flag bit field, indicating packet end and data information.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[allow(non_camel_case_types)]
+pub enum RecordFlag {
+    /// Last message in the packet from the venue for a given `instrument_id`.
+    F_LAST = 1 << 7, // 128
+    /// Top-of-book message, not an individual order.
+    F_TOB = 1 << 6, // 64
+    /// Message sourced from a replay, such as a snapshot server.
+    F_SNAPSHOT = 1 << 5, // 32
+    /// Aggregated price level message, not an individual order.
+    F_MBP = 1 << 4, // 16
+    /// Reserved for future use.
+    RESERVED_2 = 1 << 3, // 8
+    /// Reserved for future use.
+    RESERVED_1 = 1 << 2, // 4
+}
+
+impl RecordFlag {
+    /// Checks if the flag matches a given value.
+    #[must_use]
+    pub fn matches(self, value: u8) -> bool {
+        (self as u8) & value != 0
+    }
+}
+
 /// The 'Time in Force' instruction for an order in the financial market.
 #[repr(C)]
 #[derive(
@@ -1026,6 +1189,7 @@ enum_strum_serde!(AccountType);
 enum_strum_serde!(AggregationSource);
 enum_strum_serde!(AggressorSide);
 enum_strum_serde!(AssetClass);
+enum_strum_serde!(AuctionPhase);
 enum_strum_serde!(InstrumentClass);
 enum_strum_serde!(BarAggregation);
 enum_strum_serde!(BookAction);
@@ -1040,8 +1204,11 @@ enum_strum_serde!(OptionKind);
 enum_strum_serde!(OrderSide);
 enum_strum_serde!(OrderStatus);
 enum_strum_serde!(OrderType);
+enum_strum_serde!(PegPriceType);
 enum_strum_serde!(PositionSide);
 enum_strum_serde!(PriceType);
+enum_strum_serde!(RecordFlag);
+enum_strum_serde!(RoundingMode);
 enum_strum_serde!(TimeInForce);
 enum_strum_serde!(TradingState);
 enum_strum_serde!(TrailingOffsetType);
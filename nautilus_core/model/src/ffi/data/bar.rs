@@ -29,7 +29,10 @@ use crate::{
     data::bar::{Bar, BarSpecification, BarType},
     enums::{AggregationSource, BarAggregation, PriceType},
     identifiers::instrument_id::InstrumentId,
-    types::{price::Price, quantity::Quantity},
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 #[no_mangle]
@@ -188,12 +191,12 @@ pub extern "C" fn bar_new(
 #[no_mangle]
 pub extern "C" fn bar_new_from_raw(
     bar_type: BarType,
-    open: i64,
-    high: i64,
-    low: i64,
-    close: i64,
+    open: PriceRaw,
+    high: PriceRaw,
+    low: PriceRaw,
+    close: PriceRaw,
     price_prec: u8,
-    volume: u64,
+    volume: QuantityRaw,
     size_prec: u8,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
@@ -20,3 +20,4 @@ pub mod depth;
 pub mod order;
 pub mod quote;
 pub mod trade;
+pub mod trades;
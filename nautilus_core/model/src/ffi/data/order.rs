@@ -24,15 +24,18 @@ use nautilus_core::ffi::string::str_to_cstr;
 use crate::{
     data::order::BookOrder,
     enums::OrderSide,
-    types::{price::Price, quantity::Quantity},
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 #[no_mangle]
 pub extern "C" fn book_order_from_raw(
     order_side: OrderSide,
-    price_raw: i64,
+    price_raw: PriceRaw,
     price_prec: u8,
-    size_raw: u64,
+    size_raw: QuantityRaw,
     size_prec: u8,
     order_id: u64,
 ) -> BookOrder {
@@ -24,18 +24,21 @@ use nautilus_core::{ffi::string::str_to_cstr, time::UnixNanos};
 use crate::{
     data::quote::QuoteTick,
     identifiers::instrument_id::InstrumentId,
-    types::{price::Price, quantity::Quantity},
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 #[no_mangle]
 pub extern "C" fn quote_tick_new(
     instrument_id: InstrumentId,
-    bid_price_raw: i64,
-    ask_price_raw: i64,
+    bid_price_raw: PriceRaw,
+    ask_price_raw: PriceRaw,
     bid_price_prec: u8,
     ask_price_prec: u8,
-    bid_size_raw: u64,
-    ask_size_raw: u64,
+    bid_size_raw: QuantityRaw,
+    ask_size_raw: QuantityRaw,
     bid_size_prec: u8,
     ask_size_prec: u8,
     ts_event: UnixNanos,
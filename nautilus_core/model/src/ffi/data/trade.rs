@@ -25,15 +25,18 @@ use crate::{
     data::trade::TradeTick,
     enums::AggressorSide,
     identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
-    types::{price::Price, quantity::Quantity},
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 #[no_mangle]
 pub extern "C" fn trade_tick_new(
     instrument_id: InstrumentId,
-    price_raw: i64,
+    price_raw: PriceRaw,
     price_prec: u8,
-    size_raw: u64,
+    size_raw: QuantityRaw,
     size_prec: u8,
     aggressor_side: AggressorSide,
     trade_id: TradeId,
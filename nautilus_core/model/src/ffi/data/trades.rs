@@ -0,0 +1,97 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{ffi::cvec::CVec, time::UnixNanos};
+
+use crate::{
+    data::{
+        trade::TradeTick,
+        trades::{TradeTicks, TradeTicks_API},
+    },
+    identifiers::instrument_id::InstrumentId,
+};
+
+/// Creates a new `TradeTicks` object from a `CVec` of `TradeTick`.
+///
+/// # Safety
+/// - The `trades` must be a valid pointer to a `CVec` containing `TradeTick` objects
+/// - This function clones the data pointed to by `trades` into Rust-managed memory, then forgets the original `Vec` to prevent Rust from auto-deallocating it
+/// - The caller is responsible for managing the memory of `trades` (including its deallocation) to avoid memory leaks
+#[no_mangle]
+pub extern "C" fn trade_ticks_new(
+    instrument_id: InstrumentId,
+    trades: &CVec,
+    flags: u8,
+    sequence: u64,
+) -> TradeTicks_API {
+    let CVec { ptr, len, cap } = *trades;
+    let trades: Vec<TradeTick> = unsafe { Vec::from_raw_parts(ptr.cast::<TradeTick>(), len, cap) };
+    let cloned_trades = trades.clone();
+    std::mem::forget(trades); // Prevents Rust from dropping `trades`
+    TradeTicks_API::new(TradeTicks::new(
+        instrument_id,
+        cloned_trades,
+        flags,
+        sequence,
+    ))
+}
+
+#[no_mangle]
+pub extern "C" fn trade_ticks_drop(trades: TradeTicks_API) {
+    drop(trades); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn trade_ticks_clone(trades: &TradeTicks_API) -> TradeTicks_API {
+    trades.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn trade_ticks_instrument_id(trades: &TradeTicks_API) -> InstrumentId {
+    trades.instrument_id
+}
+
+#[no_mangle]
+pub extern "C" fn trade_ticks_vec_trades(trades: &TradeTicks_API) -> CVec {
+    trades.trades.clone().into()
+}
+
+#[no_mangle]
+pub extern "C" fn trade_ticks_flags(trades: &TradeTicks_API) -> u8 {
+    trades.flags
+}
+
+#[no_mangle]
+pub extern "C" fn trade_ticks_sequence(trades: &TradeTicks_API) -> u64 {
+    trades.sequence
+}
+
+#[no_mangle]
+pub extern "C" fn trade_ticks_ts_event(trades: &TradeTicks_API) -> UnixNanos {
+    trades.ts_event
+}
+
+#[no_mangle]
+pub extern "C" fn trade_ticks_ts_init(trades: &TradeTicks_API) -> UnixNanos {
+    trades.ts_init
+}
+
+#[allow(clippy::drop_non_drop)]
+#[no_mangle]
+pub extern "C" fn trade_ticks_vec_drop(v: CVec) {
+    let CVec { ptr, len, cap } = v;
+    let trades: Vec<TradeTick> = unsafe { Vec::from_raw_parts(ptr.cast::<TradeTick>(), len, cap) };
+    drop(trades); // Memory freed here
+}
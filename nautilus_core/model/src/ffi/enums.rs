@@ -18,10 +18,11 @@ use std::{ffi::c_char, str::FromStr};
 use nautilus_core::ffi::string::{cstr_to_str, str_to_cstr};
 
 use crate::enums::{
-    AccountType, AggregationSource, AggressorSide, AssetClass, BarAggregation, BookAction,
-    BookType, ContingencyType, CurrencyType, HaltReason, InstrumentClass, InstrumentCloseType,
-    LiquiditySide, MarketStatus, OmsType, OptionKind, OrderSide, OrderStatus, OrderType,
-    PositionSide, PriceType, TimeInForce, TradingState, TrailingOffsetType, TriggerType,
+    AccountType, AggregationSource, AggressorSide, AssetClass, AuctionPhase, BarAggregation,
+    BookAction, BookType, ContingencyType, CurrencyType, HaltReason, InstrumentClass,
+    InstrumentCloseType, LiquiditySide, MarketStatus, OmsType, OptionKind, OrderSide, OrderStatus,
+    OrderType, PegPriceType, PositionSide, PriceType, RoundingMode, TimeInForce, TradingState,
+    TrailingOffsetType, TriggerType,
 };
 
 #[no_mangle]
@@ -265,6 +266,23 @@ pub unsafe extern "C" fn halt_reason_from_cstr(ptr: *const c_char) -> HaltReason
         .unwrap_or_else(|_| panic!("invalid `HaltReason` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn auction_phase_to_cstr(value: AuctionPhase) -> *const c_char {
+    str_to_cstr(value.as_ref())
+}
+
+/// Returns an enum from a Python string.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn auction_phase_from_cstr(ptr: *const c_char) -> AuctionPhase {
+    let value = cstr_to_str(ptr);
+    AuctionPhase::from_str(value)
+        .unwrap_or_else(|_| panic!("invalid `AuctionPhase` enum string value, was '{value}'"))
+}
+
 #[no_mangle]
 pub extern "C" fn oms_type_to_cstr(value: OmsType) -> *const c_char {
     str_to_cstr(value.as_ref())
@@ -350,6 +368,23 @@ pub unsafe extern "C" fn order_type_from_cstr(ptr: *const c_char) -> OrderType {
         .unwrap_or_else(|_| panic!("invalid `OrderType` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn peg_price_type_to_cstr(value: PegPriceType) -> *const c_char {
+    str_to_cstr(value.as_ref())
+}
+
+/// Returns an enum from a Python string.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn peg_price_type_from_cstr(ptr: *const c_char) -> PegPriceType {
+    let value = cstr_to_str(ptr);
+    PegPriceType::from_str(value)
+        .unwrap_or_else(|_| panic!("invalid `PegPriceType` enum string value, was '{value}'"))
+}
+
 #[no_mangle]
 pub extern "C" fn position_side_to_cstr(value: PositionSide) -> *const c_char {
     str_to_cstr(value.as_ref())
@@ -384,6 +419,23 @@ pub unsafe extern "C" fn price_type_from_cstr(ptr: *const c_char) -> PriceType {
         .unwrap_or_else(|_| panic!("invalid `PriceType` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn rounding_mode_to_cstr(value: RoundingMode) -> *const c_char {
+    str_to_cstr(value.as_ref())
+}
+
+/// Returns an enum from a Python string.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rounding_mode_from_cstr(ptr: *const c_char) -> RoundingMode {
+    let value = cstr_to_str(ptr);
+    RoundingMode::from_str(value)
+        .unwrap_or_else(|_| panic!("invalid `RoundingMode` enum string value, was '{value}'"))
+}
+
 #[no_mangle]
 pub extern "C" fn time_in_force_to_cstr(value: TimeInForce) -> *const c_char {
     str_to_cstr(value.as_ref())
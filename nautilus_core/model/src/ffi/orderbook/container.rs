@@ -13,15 +13,25 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use indexmap::IndexMap;
+
 use crate::{
     data::{
-        delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, order::BookOrder,
-        quote::QuoteTick, trade::TradeTick,
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::{OrderBookDepth10, OrderBookDepth25},
+        order::{BookOrder, OrderId},
+        quote::QuoteTick,
+        trade::TradeTick,
     },
     enums::{BookType, OrderSide},
     identifiers::instrument_id::InstrumentId,
     orderbook::{
-        book::BookIntegrityError, book_mbo::OrderBookMbo, book_mbp::OrderBookMbp, level::Level,
+        book::{BookIntegrityError, BookSweep, TopOfBookUpdate},
+        book_mbo::OrderBookMbo,
+        book_mbp::OrderBookMbp,
+        integrity::BookIntegrityConfig,
+        level::Level,
     },
     types::{price::Price, quantity::Quantity},
 };
@@ -179,6 +189,14 @@ impl OrderBookContainer {
         };
     }
 
+    pub fn apply_deltas_with_quote(&mut self, deltas: OrderBookDeltas) -> TopOfBookUpdate {
+        match self.book_type {
+            BookType::L3_MBO => self.get_mbo_mut().apply_deltas_with_quote(deltas),
+            BookType::L2_MBP => self.get_mbp_mut().apply_deltas_with_quote(deltas),
+            BookType::L1_MBP => self.get_mbp_mut().apply_deltas_with_quote(deltas),
+        }
+    }
+
     pub fn apply_depth(&mut self, depth: OrderBookDepth10) {
         match self.book_type {
             BookType::L3_MBO => self.get_mbo_mut().apply_depth(depth),
@@ -187,6 +205,26 @@ impl OrderBookContainer {
         };
     }
 
+    pub fn apply_depth25(&mut self, depth: OrderBookDepth25) {
+        match self.book_type {
+            BookType::L3_MBO => self.get_mbo_mut().apply_depth25(depth),
+            BookType::L2_MBP => self.get_mbp_mut().apply_depth25(depth),
+            BookType::L1_MBP => panic!("Invalid operation for L1_MBP book: `apply_depth25`"),
+        };
+    }
+
+    pub fn apply_depth25_incremental(&mut self, depth: OrderBookDepth25) -> Vec<OrderBookDelta> {
+        match self.book_type {
+            BookType::L2_MBP => self.get_mbp_mut().apply_depth25_incremental(depth),
+            BookType::L3_MBO => {
+                panic!("Invalid operation for L3_MBO book: `apply_depth25_incremental`")
+            }
+            BookType::L1_MBP => {
+                panic!("Invalid operation for L1_MBP book: `apply_depth25_incremental`")
+            }
+        }
+    }
+
     #[must_use]
     pub fn bids(&self) -> Vec<&Level> {
         match self.book_type {
@@ -277,6 +315,51 @@ impl OrderBookContainer {
         }
     }
 
+    #[must_use]
+    pub fn spread_ticks(&self, tick_size: Price) -> Option<f64> {
+        match self.book_type {
+            BookType::L3_MBO => self.get_mbo().spread_ticks(tick_size),
+            BookType::L2_MBP => self.get_mbp().spread_ticks(tick_size),
+            BookType::L1_MBP => self.get_mbp().spread_ticks(tick_size),
+        }
+    }
+
+    #[must_use]
+    pub fn volume_imbalance(&self, depth: usize) -> Option<f64> {
+        match self.book_type {
+            BookType::L3_MBO => self.get_mbo().volume_imbalance(depth),
+            BookType::L2_MBP => self.get_mbp().volume_imbalance(depth),
+            BookType::L1_MBP => self.get_mbp().volume_imbalance(depth),
+        }
+    }
+
+    #[must_use]
+    pub fn microprice(&self) -> Option<f64> {
+        match self.book_type {
+            BookType::L3_MBO => self.get_mbo().microprice(),
+            BookType::L2_MBP => self.get_mbp().microprice(),
+            BookType::L1_MBP => self.get_mbp().microprice(),
+        }
+    }
+
+    #[must_use]
+    pub fn get_cumulative_depth(&self, side: OrderSide, offset: Price) -> f64 {
+        match self.book_type {
+            BookType::L3_MBO => self.get_mbo().get_cumulative_depth(side, offset),
+            BookType::L2_MBP => self.get_mbp().get_cumulative_depth(side, offset),
+            BookType::L1_MBP => self.get_mbp().get_cumulative_depth(side, offset),
+        }
+    }
+
+    #[must_use]
+    pub fn group_levels(&self, side: OrderSide, tick_interval: Price) -> IndexMap<Price, f64> {
+        match self.book_type {
+            BookType::L3_MBO => self.get_mbo().group_levels(side, tick_interval),
+            BookType::L2_MBP => self.get_mbp().group_levels(side, tick_interval),
+            BookType::L1_MBP => self.get_mbp().group_levels(side, tick_interval),
+        }
+    }
+
     #[must_use]
     pub fn get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
         match self.book_type {
@@ -286,6 +369,15 @@ impl OrderBookContainer {
         }
     }
 
+    #[must_use]
+    pub fn get_sweep_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> BookSweep {
+        match self.book_type {
+            BookType::L3_MBO => self.get_mbo().get_sweep_for_quantity(qty, order_side),
+            BookType::L2_MBP => self.get_mbp().get_sweep_for_quantity(qty, order_side),
+            BookType::L1_MBP => self.get_mbp().get_sweep_for_quantity(qty, order_side),
+        }
+    }
+
     #[must_use]
     pub fn get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> f64 {
         match self.book_type {
@@ -295,6 +387,15 @@ impl OrderBookContainer {
         }
     }
 
+    #[must_use]
+    pub fn get_queue_position(&self, order_id: OrderId) -> Option<(Price, usize, Quantity)> {
+        match self.book_type {
+            BookType::L3_MBO => self.get_mbo().get_queue_position(order_id),
+            BookType::L2_MBP => panic!("Invalid operation for L2_MBP book: `get_queue_position`"),
+            BookType::L1_MBP => panic!("Invalid operation for L1_MBP book: `get_queue_position`"),
+        }
+    }
+
     #[must_use]
     pub fn simulate_fills(&self, order: &BookOrder) -> Vec<(Price, Quantity)> {
         match self.book_type {
@@ -312,6 +413,17 @@ impl OrderBookContainer {
         }
     }
 
+    pub fn enforce_integrity(
+        &mut self,
+        config: &BookIntegrityConfig,
+    ) -> Result<(), BookIntegrityError> {
+        match self.book_type {
+            BookType::L3_MBO => self.get_mbo_mut().enforce_integrity(config),
+            BookType::L2_MBP => self.get_mbp_mut().enforce_integrity(config),
+            BookType::L1_MBP => self.get_mbp_mut().enforce_integrity(config),
+        }
+    }
+
     #[must_use]
     pub fn pprint(&self, num_levels: usize) -> String {
         match self.book_type {
@@ -15,7 +15,10 @@
 
 use std::ops::{AddAssign, SubAssign};
 
-use crate::types::{currency::Currency, money::Money};
+use crate::types::{
+    currency::Currency,
+    money::{Money, MoneyRaw},
+};
 
 // TODO: Document panic
 #[no_mangle]
@@ -25,7 +28,7 @@ pub extern "C" fn money_new(amount: f64, currency: Currency) -> Money {
 }
 
 #[no_mangle]
-pub extern "C" fn money_from_raw(raw: i64, currency: Currency) -> Money {
+pub extern "C" fn money_from_raw(raw: MoneyRaw, currency: Currency) -> Money {
     Money::from_raw(raw, currency)
 }
 
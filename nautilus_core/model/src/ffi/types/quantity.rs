@@ -15,7 +15,7 @@
 
 use std::ops::{AddAssign, SubAssign};
 
-use crate::types::quantity::Quantity;
+use crate::types::quantity::{Quantity, QuantityRaw};
 
 // TODO: Document panic
 #[no_mangle]
@@ -25,7 +25,7 @@ pub extern "C" fn quantity_new(value: f64, precision: u8) -> Quantity {
 }
 
 #[no_mangle]
-pub extern "C" fn quantity_from_raw(raw: u64, precision: u8) -> Quantity {
+pub extern "C" fn quantity_from_raw(raw: QuantityRaw, precision: u8) -> Quantity {
     Quantity::from_raw(raw, precision).unwrap()
 }
 
@@ -42,6 +42,13 @@ impl PositionId {
             value: Ustr::from(s),
         })
     }
+
+    /// Returns whether the position ID was generated by Nautilus (rather than assigned by
+    /// the venue), as used for virtual positions in `HEDGING` mode.
+    #[must_use]
+    pub fn is_virtual(&self) -> bool {
+        self.value.starts_with("P-")
+    }
 }
 
 impl Default for PositionId {
@@ -84,4 +91,10 @@ mod tests {
         assert_eq!(position_id_test.to_string(), "P-123456789");
         assert_eq!(format!("{position_id_test}"), "P-123456789");
     }
+
+    #[rstest]
+    fn test_is_virtual(position_id_test: PositionId) {
+        assert!(position_id_test.is_virtual());
+        assert!(!PositionId::from("BINANCE-123456").is_virtual());
+    }
 }
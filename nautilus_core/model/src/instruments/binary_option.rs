@@ -0,0 +1,423 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    any::Any,
+    hash::{Hash, Hasher},
+};
+
+use anyhow::{bail, Result};
+use nautilus_core::time::UnixNanos;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::Instrument;
+use crate::{
+    enums::{AssetClass, InstrumentClass},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// Represents a binary (all-or-nothing) option, such as a prediction market or
+/// event contract.
+///
+/// Each contract settles at exactly 1 unit of `currency` if the `outcome` occurs,
+/// otherwise at 0. Prices are therefore bounded to the open interval (0, 1) and
+/// may be read as the implied probability of the outcome.
+#[repr(C)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct BinaryOption {
+    pub id: InstrumentId,
+    pub raw_symbol: Symbol,
+    pub asset_class: AssetClass,
+    pub currency: Currency,
+    pub activation_ns: UnixNanos,
+    pub expiration_ns: UnixNanos,
+    pub price_precision: u8,
+    pub size_precision: u8,
+    pub price_increment: Price,
+    pub size_increment: Quantity,
+    /// The outcome this contract pays out on (e.g. "Yes").
+    pub outcome: Option<Ustr>,
+    /// The description of the event being traded.
+    pub description: Option<Ustr>,
+    pub max_quantity: Option<Quantity>,
+    pub min_quantity: Option<Quantity>,
+    pub max_price: Option<Price>,
+    pub min_price: Option<Price>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl BinaryOption {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        asset_class: AssetClass,
+        currency: Currency,
+        activation_ns: UnixNanos,
+        expiration_ns: UnixNanos,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        outcome: Option<Ustr>,
+        description: Option<Ustr>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Result<Self> {
+        if price_increment.precision != price_precision {
+            bail!(
+                "Condition failed: `price_increment` precision {} did not match `price_precision` {price_precision}",
+                price_increment.precision
+            );
+        }
+        if size_increment.precision != size_precision {
+            bail!(
+                "Condition failed: `size_increment` precision {} did not match `size_precision` {size_precision}",
+                size_increment.precision
+            );
+        }
+        let one = Price::new(1.0, price_precision)?;
+        if price_increment.raw <= 0 || price_increment >= one {
+            bail!(
+                "Condition failed: `price_increment` must be in the interval (0, 1), was {price_increment}"
+            );
+        }
+        if expiration_ns < activation_ns {
+            bail!(
+                "Condition failed: `expiration_ns` {expiration_ns} was before `activation_ns` {activation_ns}"
+            );
+        }
+
+        Ok(Self {
+            id,
+            raw_symbol,
+            asset_class,
+            currency,
+            activation_ns,
+            expiration_ns,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            outcome,
+            description,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Returns the price at which the contract settles, being 1 if the outcome
+    /// occurred, otherwise 0.
+    #[must_use]
+    pub fn settlement_price(&self, outcome_occurred: bool) -> Price {
+        if outcome_occurred {
+            Price::new(1.0, self.price_precision).expect("Valid price precision")
+        } else {
+            Price::zero(self.price_precision)
+        }
+    }
+
+    /// Returns the settlement value of `quantity` contracts.
+    #[must_use]
+    pub fn settlement_value(&self, quantity: Quantity, outcome_occurred: bool) -> Money {
+        let value = if outcome_occurred {
+            quantity.as_f64()
+        } else {
+            0.0
+        };
+        Money::new(value, self.currency).expect("Valid settlement value")
+    }
+
+    /// Returns whether the contract is tradable at the given UNIX timestamp (nanoseconds).
+    #[must_use]
+    pub fn is_active(&self, ts: UnixNanos) -> bool {
+        ts >= self.activation_ns && ts < self.expiration_ns
+    }
+
+    /// Checks that `price` is a valid limit price for the contract.
+    ///
+    /// The price must lie on the tick grid, within the open interval (0, 1),
+    /// and within any `min_price`/`max_price` bounds.
+    pub fn check_price(&self, price: Price) -> Result<()> {
+        if price.precision != self.price_precision {
+            bail!(
+                "Invalid price precision {} for {}, expected {}",
+                price.precision,
+                self.id,
+                self.price_precision
+            );
+        }
+        if price.raw <= 0 || price >= self.settlement_price(true) {
+            bail!(
+                "Invalid price {price} for {}, must be in the interval (0, 1)",
+                self.id
+            );
+        }
+        if price.raw % self.price_increment.raw != 0 {
+            bail!(
+                "Invalid price {price} for {}, not a multiple of the tick size {}",
+                self.id,
+                self.price_increment
+            );
+        }
+        if let Some(min_price) = self.min_price {
+            if price < min_price {
+                bail!(
+                    "Invalid price {price} for {}, below the minimum {min_price}",
+                    self.id
+                );
+            }
+        }
+        if let Some(max_price) = self.max_price {
+            if price > max_price {
+                bail!(
+                    "Invalid price {price} for {}, above the maximum {max_price}",
+                    self.id
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq<Self> for BinaryOption {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for BinaryOption {}
+
+impl Hash for BinaryOption {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for BinaryOption {
+    fn id(&self) -> InstrumentId {
+        self.id
+    }
+
+    fn raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        self.asset_class
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        InstrumentClass::BinaryOption
+    }
+
+    fn quote_currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        None
+    }
+
+    fn settlement_currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn is_inverse(&self) -> bool {
+        false
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    fn multiplier(&self) -> Quantity {
+        Quantity::from(1)
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        None
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        Some(self.activation_ns)
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        Some(self.expiration_ns)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        instruments::{binary_option::BinaryOption, stubs::*, Instrument},
+        types::{money::Money, price::Price, quantity::Quantity},
+    };
+
+    #[rstest]
+    fn test_equality(binary_option: BinaryOption) {
+        let cloned = binary_option;
+        assert_eq!(binary_option, cloned);
+    }
+
+    #[rstest]
+    fn test_settlement_price(binary_option: BinaryOption) {
+        assert_eq!(binary_option.settlement_price(true), Price::from("1.000"));
+        assert_eq!(binary_option.settlement_price(false), Price::from("0.000"));
+    }
+
+    #[rstest]
+    fn test_settlement_value(binary_option: BinaryOption) {
+        let quantity = Quantity::from("250.00");
+        assert_eq!(
+            binary_option.settlement_value(quantity, true),
+            Money::from("250.00000000 USDC")
+        );
+        assert_eq!(
+            binary_option.settlement_value(quantity, false),
+            Money::from("0.00000000 USDC")
+        );
+    }
+
+    #[rstest]
+    fn test_is_active(binary_option: BinaryOption) {
+        assert!(!binary_option.is_active(binary_option.activation_ns - 1));
+        assert!(binary_option.is_active(binary_option.activation_ns));
+        assert!(!binary_option.is_active(binary_option.expiration_ns));
+    }
+
+    #[rstest]
+    #[case("0.001", true)]
+    #[case("0.555", true)]
+    #[case("0.999", true)]
+    #[case("0.000", false)]
+    #[case("1.000", false)]
+    fn test_check_price_bounds(
+        binary_option: BinaryOption,
+        #[case] price: &str,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            binary_option.check_price(Price::from(price)).is_ok(),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_check_price_off_tick(mut binary_option: BinaryOption) {
+        binary_option.price_increment = Price::from("0.010");
+        assert!(binary_option.check_price(Price::from("0.550")).is_ok());
+        assert!(binary_option.check_price(Price::from("0.555")).is_err());
+    }
+
+    #[rstest]
+    fn test_check_price_wrong_precision(binary_option: BinaryOption) {
+        assert!(binary_option.check_price(Price::from("0.55")).is_err());
+    }
+
+    #[rstest]
+    fn test_new_with_invalid_price_increment(binary_option: BinaryOption) {
+        let result = BinaryOption::new(
+            binary_option.id,
+            binary_option.raw_symbol,
+            binary_option.asset_class,
+            binary_option.currency,
+            binary_option.activation_ns,
+            binary_option.expiration_ns,
+            binary_option.price_precision,
+            binary_option.size_precision,
+            Price::from("1.000"),
+            binary_option.size_increment,
+            binary_option.outcome,
+            binary_option.description,
+            None,
+            None,
+            None,
+            None,
+            0,
+            0,
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_instrument_trait(binary_option: BinaryOption) {
+        assert_eq!(binary_option.multiplier(), Quantity::from(1));
+        assert_eq!(binary_option.size_precision(), 2);
+        assert!(binary_option.lot_size().is_none());
+    }
+}
@@ -0,0 +1,546 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+    hash::{Hash, Hasher},
+};
+
+use anyhow::{anyhow, bail, Result};
+use nautilus_core::time::UnixNanos;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::Instrument;
+use crate::{
+    data::quote::QuoteTick,
+    enums::{AssetClass, InstrumentClass, OrderSide},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+
+/// Represents a single leg of a multi-leg combo instrument.
+///
+/// A positive `ratio` means the leg is bought when the combo is bought, a negative
+/// `ratio` means the leg is sold when the combo is bought.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct ComboLeg {
+    /// The instrument ID for the leg.
+    pub instrument_id: InstrumentId,
+    /// The signed number of leg units per unit of the combo.
+    pub ratio: i64,
+}
+
+impl ComboLeg {
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, ratio: i64) -> Self {
+        Self {
+            instrument_id,
+            ratio,
+        }
+    }
+
+    /// Returns the side of the leg order when the combo is traded on `side`.
+    #[must_use]
+    pub fn side_for(&self, side: OrderSide) -> OrderSide {
+        match (side, self.ratio > 0) {
+            (OrderSide::Buy, true) | (OrderSide::Sell, false) => OrderSide::Buy,
+            (OrderSide::Buy, false) | (OrderSide::Sell, true) => OrderSide::Sell,
+            _ => panic!("Invalid `OrderSide` {side}"),
+        }
+    }
+}
+
+/// Represents a multi-leg combo instrument, such as an options spread or an exchange-listed
+/// calendar spread, priced as the ratio weighted sum of its leg prices.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct ComboInstrument {
+    pub id: InstrumentId,
+    pub raw_symbol: Symbol,
+    pub asset_class: AssetClass,
+    pub instrument_class: InstrumentClass,
+    /// The exchange ISO 10383 Market Identifier Code (MIC) where the instrument trades.
+    pub exchange: Option<Ustr>,
+    pub legs: Vec<ComboLeg>,
+    pub currency: Currency,
+    pub price_precision: u8,
+    pub price_increment: Price,
+    pub multiplier: Quantity,
+    pub lot_size: Quantity,
+    pub max_quantity: Option<Quantity>,
+    pub min_quantity: Option<Quantity>,
+    pub max_price: Option<Price>,
+    pub min_price: Option<Price>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl ComboInstrument {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        asset_class: AssetClass,
+        instrument_class: InstrumentClass,
+        exchange: Option<Ustr>,
+        legs: Vec<ComboLeg>,
+        currency: Currency,
+        price_precision: u8,
+        price_increment: Price,
+        multiplier: Quantity,
+        lot_size: Quantity,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Result<Self> {
+        if legs.len() < 2 {
+            bail!(
+                "Condition failed: `legs` must contain at least 2 legs, was {}",
+                legs.len()
+            );
+        }
+        let mut seen = HashSet::new();
+        for leg in &legs {
+            if leg.ratio == 0 {
+                bail!(
+                    "Condition failed: `ratio` for leg {} was zero",
+                    leg.instrument_id
+                );
+            }
+            if leg.instrument_id == id {
+                bail!("Condition failed: combo {id} cannot contain itself as a leg");
+            }
+            if !seen.insert(leg.instrument_id) {
+                bail!(
+                    "Condition failed: duplicate leg {} in `legs`",
+                    leg.instrument_id
+                );
+            }
+        }
+
+        Ok(Self {
+            id,
+            raw_symbol,
+            asset_class,
+            instrument_class,
+            exchange,
+            legs,
+            currency,
+            price_precision,
+            price_increment,
+            multiplier,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Returns the instrument IDs of the legs, in leg order.
+    #[must_use]
+    pub fn leg_ids(&self) -> Vec<InstrumentId> {
+        self.legs.iter().map(|leg| leg.instrument_id).collect()
+    }
+
+    /// Returns the leg for the given `instrument_id`, if it is part of the combo.
+    #[must_use]
+    pub fn leg(&self, instrument_id: &InstrumentId) -> Option<&ComboLeg> {
+        self.legs
+            .iter()
+            .find(|leg| leg.instrument_id == *instrument_id)
+    }
+
+    /// Calculates the combo price as the ratio weighted sum of the given leg prices.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a price is missing for any leg.
+    pub fn calculate_price(&self, leg_prices: &HashMap<InstrumentId, Price>) -> Result<Price> {
+        let mut value = 0.0;
+        for leg in &self.legs {
+            let price = leg_prices
+                .get(&leg.instrument_id)
+                .ok_or_else(|| anyhow!("No price for leg {}", leg.instrument_id))?;
+            value += leg.ratio as f64 * price.as_f64();
+        }
+        self.make_price(value)
+    }
+
+    /// Calculates the combo bid and ask prices from the given leg quotes.
+    ///
+    /// The combo bid is the price at which the legs can be sold as a package (bought legs
+    /// hit their bids, sold legs lift their asks) and the combo ask the reverse.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a quote is missing for any leg.
+    pub fn calculate_quote(
+        &self,
+        leg_quotes: &HashMap<InstrumentId, QuoteTick>,
+    ) -> Result<(Price, Price)> {
+        let mut bid = 0.0;
+        let mut ask = 0.0;
+        for leg in &self.legs {
+            let quote = leg_quotes
+                .get(&leg.instrument_id)
+                .ok_or_else(|| anyhow!("No quote for leg {}", leg.instrument_id))?;
+            let ratio = leg.ratio as f64;
+            if leg.ratio > 0 {
+                bid += ratio * quote.bid_price.as_f64();
+                ask += ratio * quote.ask_price.as_f64();
+            } else {
+                bid += ratio * quote.ask_price.as_f64();
+                ask += ratio * quote.bid_price.as_f64();
+            }
+        }
+        Ok((self.make_price(bid)?, self.make_price(ask)?))
+    }
+
+    /// Returns the leg orders (instrument ID, side and quantity) equivalent to trading
+    /// `quantity` of the combo on `side`.
+    #[must_use]
+    pub fn leg_orders(
+        &self,
+        side: OrderSide,
+        quantity: Quantity,
+    ) -> Vec<(InstrumentId, OrderSide, Quantity)> {
+        self.legs
+            .iter()
+            .map(|leg| {
+                let leg_qty = Quantity::new(
+                    quantity.as_f64() * leg.ratio.unsigned_abs() as f64,
+                    quantity.precision,
+                )
+                .unwrap(); // SAFETY: Scaled from a valid quantity
+                (leg.instrument_id, leg.side_for(side), leg_qty)
+            })
+            .collect()
+    }
+}
+
+impl PartialEq<Self> for ComboInstrument {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for ComboInstrument {}
+
+impl Hash for ComboInstrument {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for ComboInstrument {
+    fn id(&self) -> InstrumentId {
+        self.id
+    }
+
+    fn raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        self.asset_class
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        self.instrument_class
+    }
+
+    fn quote_currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        None
+    }
+
+    fn settlement_currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn is_inverse(&self) -> bool {
+        false
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        0
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        Quantity::from(1)
+    }
+
+    fn multiplier(&self) -> Quantity {
+        self.multiplier
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        Some(self.lot_size)
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::instruments::stubs::*;
+
+    fn quote(instrument_id: &str, bid: &str, ask: &str) -> (InstrumentId, QuoteTick) {
+        let instrument_id = InstrumentId::from(instrument_id);
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(10),
+            Quantity::from(10),
+            0,
+            0,
+        )
+        .unwrap();
+        (instrument_id, quote)
+    }
+
+    #[rstest]
+    fn test_equality(combo_vertical_spread: ComboInstrument) {
+        assert_eq!(combo_vertical_spread, combo_vertical_spread.clone());
+        assert_eq!(
+            combo_vertical_spread.instrument_class(),
+            InstrumentClass::OptionSpread
+        );
+    }
+
+    #[rstest]
+    fn test_new_with_single_leg_errors(combo_vertical_spread: ComboInstrument) {
+        let mut legs = combo_vertical_spread.legs.clone();
+        legs.truncate(1);
+        let result = ComboInstrument::new(
+            combo_vertical_spread.id,
+            combo_vertical_spread.raw_symbol,
+            combo_vertical_spread.asset_class,
+            combo_vertical_spread.instrument_class,
+            None,
+            legs,
+            Currency::USD(),
+            2,
+            Price::from("0.01"),
+            Quantity::from(100),
+            Quantity::from(1),
+            None,
+            None,
+            None,
+            None,
+            0,
+            0,
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_new_with_zero_ratio_errors(combo_vertical_spread: ComboInstrument) {
+        let mut legs = combo_vertical_spread.legs.clone();
+        legs[1].ratio = 0;
+        let result = ComboInstrument::new(
+            combo_vertical_spread.id,
+            combo_vertical_spread.raw_symbol,
+            combo_vertical_spread.asset_class,
+            combo_vertical_spread.instrument_class,
+            None,
+            legs,
+            Currency::USD(),
+            2,
+            Price::from("0.01"),
+            Quantity::from(100),
+            Quantity::from(1),
+            None,
+            None,
+            None,
+            None,
+            0,
+            0,
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_new_with_duplicate_leg_errors(combo_vertical_spread: ComboInstrument) {
+        let mut legs = combo_vertical_spread.legs.clone();
+        legs[1].instrument_id = legs[0].instrument_id;
+        let result = ComboInstrument::new(
+            combo_vertical_spread.id,
+            combo_vertical_spread.raw_symbol,
+            combo_vertical_spread.asset_class,
+            combo_vertical_spread.instrument_class,
+            None,
+            legs,
+            Currency::USD(),
+            2,
+            Price::from("0.01"),
+            Quantity::from(100),
+            Quantity::from(1),
+            None,
+            None,
+            None,
+            None,
+            0,
+            0,
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_leg_lookup(combo_vertical_spread: ComboInstrument) {
+        let leg_ids = combo_vertical_spread.leg_ids();
+        assert_eq!(leg_ids.len(), 2);
+        assert_eq!(combo_vertical_spread.leg(&leg_ids[1]).unwrap().ratio, -1);
+        assert!(combo_vertical_spread
+            .leg(&InstrumentId::from("AAPL.XNAS"))
+            .is_none());
+    }
+
+    #[rstest]
+    fn test_calculate_price(combo_vertical_spread: ComboInstrument) {
+        let leg_ids = combo_vertical_spread.leg_ids();
+        let mut prices = HashMap::new();
+        prices.insert(leg_ids[0], Price::from("5.20"));
+        prices.insert(leg_ids[1], Price::from("3.05"));
+
+        let price = combo_vertical_spread.calculate_price(&prices).unwrap();
+
+        assert_eq!(price, Price::from("2.15"));
+    }
+
+    #[rstest]
+    fn test_calculate_price_can_be_negative(combo_vertical_spread: ComboInstrument) {
+        let leg_ids = combo_vertical_spread.leg_ids();
+        let mut prices = HashMap::new();
+        prices.insert(leg_ids[0], Price::from("1.00"));
+        prices.insert(leg_ids[1], Price::from("1.50"));
+
+        let price = combo_vertical_spread.calculate_price(&prices).unwrap();
+
+        assert_eq!(price, Price::from("-0.50"));
+    }
+
+    #[rstest]
+    fn test_calculate_price_missing_leg_errors(combo_vertical_spread: ComboInstrument) {
+        let leg_ids = combo_vertical_spread.leg_ids();
+        let mut prices = HashMap::new();
+        prices.insert(leg_ids[0], Price::from("5.20"));
+
+        assert!(combo_vertical_spread.calculate_price(&prices).is_err());
+    }
+
+    #[rstest]
+    fn test_calculate_quote(combo_vertical_spread: ComboInstrument) {
+        let leg_ids = combo_vertical_spread.leg_ids();
+        let quotes: HashMap<InstrumentId, QuoteTick> = vec![
+            quote(&leg_ids[0].to_string(), "5.10", "5.30"),
+            quote(&leg_ids[1].to_string(), "3.00", "3.10"),
+        ]
+        .into_iter()
+        .collect();
+
+        let (bid, ask) = combo_vertical_spread.calculate_quote(&quotes).unwrap();
+
+        assert_eq!(bid, Price::from("2.00"));
+        assert_eq!(ask, Price::from("2.30"));
+    }
+
+    #[rstest]
+    fn test_leg_orders(combo_vertical_spread: ComboInstrument) {
+        let leg_ids = combo_vertical_spread.leg_ids();
+
+        let buy = combo_vertical_spread.leg_orders(OrderSide::Buy, Quantity::from(3));
+        let sell = combo_vertical_spread.leg_orders(OrderSide::Sell, Quantity::from(3));
+
+        assert_eq!(
+            buy,
+            vec![
+                (leg_ids[0], OrderSide::Buy, Quantity::from(3)),
+                (leg_ids[1], OrderSide::Sell, Quantity::from(3)),
+            ]
+        );
+        assert_eq!(
+            sell,
+            vec![
+                (leg_ids[0], OrderSide::Sell, Quantity::from(3)),
+                (leg_ids[1], OrderSide::Buy, Quantity::from(3)),
+            ]
+        );
+    }
+}
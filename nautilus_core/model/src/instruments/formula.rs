@@ -0,0 +1,303 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A compiled evaluator for synthetic instrument price formulas.
+//!
+//! Formulas are parsed with `evalexpr` once, then lowered into a flat stack program where
+//! component variables are resolved to input slot indices. Evaluating a tick is then a
+//! single pass over the program with no string lookups, hashing or allocation.
+
+use anyhow::{anyhow, bail, Result};
+use evalexpr::{Node, Operator, Value};
+
+#[derive(Clone, Copy, Debug)]
+enum Instruction {
+    Const(f64),
+    Input(usize),
+    Unary(fn(f64) -> f64),
+    Binary(fn(f64, f64) -> f64),
+    Min(usize),
+    Max(usize),
+}
+
+/// Represents a formula compiled into a flat stack program over indexed `f64` inputs.
+#[derive(Clone, Debug)]
+pub struct CompiledFormula {
+    program: Vec<Instruction>,
+    stack: Vec<f64>,
+    num_inputs: usize,
+}
+
+impl CompiledFormula {
+    /// Compiles the given `evalexpr` operator tree, resolving each variable to its index
+    /// in `variables`.
+    ///
+    /// Returns an error if the tree references an unknown variable, or contains an operator
+    /// or function which has no compiled equivalent.
+    pub fn compile(node: &Node, variables: &[String]) -> Result<Self> {
+        let mut program = Vec::new();
+        compile_node(node, variables, &mut program)?;
+
+        let mut depth: usize = 0;
+        let mut max_depth: usize = 0;
+        for instruction in &program {
+            depth = match instruction {
+                Instruction::Const(_) | Instruction::Input(_) => depth + 1,
+                Instruction::Unary(_) => depth,
+                Instruction::Binary(_) => depth - 1,
+                Instruction::Min(n) | Instruction::Max(n) => depth + 1 - n,
+            };
+            max_depth = max_depth.max(depth);
+        }
+        if depth != 1 {
+            bail!("Formula did not evaluate to a single value");
+        }
+
+        Ok(Self {
+            program,
+            stack: Vec::with_capacity(max_depth),
+            num_inputs: variables.len(),
+        })
+    }
+
+    /// Returns the number of inputs expected by the formula.
+    #[must_use]
+    pub fn num_inputs(&self) -> usize {
+        self.num_inputs
+    }
+
+    /// Evaluates the formula for the given `inputs`, which must be ordered as the
+    /// `variables` the formula was compiled with.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `inputs` is shorter than the number of compiled variables.
+    pub fn evaluate(&mut self, inputs: &[f64]) -> f64 {
+        let stack = &mut self.stack;
+        stack.clear();
+
+        for instruction in &self.program {
+            match *instruction {
+                Instruction::Const(value) => stack.push(value),
+                Instruction::Input(index) => stack.push(inputs[index]),
+                Instruction::Unary(f) => {
+                    let value = stack.last_mut().expect("Compiled program is valid");
+                    *value = f(*value);
+                }
+                Instruction::Binary(f) => {
+                    let rhs = stack.pop().expect("Compiled program is valid");
+                    let lhs = stack.last_mut().expect("Compiled program is valid");
+                    *lhs = f(*lhs, rhs);
+                }
+                Instruction::Min(n) => {
+                    let start = stack.len() - n;
+                    let value = stack[start..].iter().copied().fold(f64::INFINITY, f64::min);
+                    stack.truncate(start);
+                    stack.push(value);
+                }
+                Instruction::Max(n) => {
+                    let start = stack.len() - n;
+                    let value = stack[start..]
+                        .iter()
+                        .copied()
+                        .fold(f64::NEG_INFINITY, f64::max);
+                    stack.truncate(start);
+                    stack.push(value);
+                }
+            }
+        }
+
+        stack.pop().unwrap_or(f64::NAN)
+    }
+}
+
+fn compile_node(node: &Node, variables: &[String], program: &mut Vec<Instruction>) -> Result<()> {
+    // Fold any subtree which does not read a variable into a single constant,
+    // using `evalexpr` itself so that integer semantics are preserved (the node iterator
+    // only visits descendants, so the node itself must also be checked)
+    let reads_variable = matches!(node.operator(), Operator::VariableIdentifierRead { .. })
+        || node.iter_variable_identifiers().next().is_some();
+    if !reads_variable {
+        let value = match node.eval()? {
+            Value::Float(value) => value,
+            Value::Int(value) => value as f64,
+            value => bail!("Formula constant `{value}` is not a number"),
+        };
+        program.push(Instruction::Const(value));
+        return Ok(());
+    }
+
+    let children = node.children();
+    match node.operator() {
+        Operator::RootNode => match children {
+            [child] => compile_node(child, variables, program)?,
+            _ => bail!("Formula contains an unsupported expression chain"),
+        },
+        Operator::VariableIdentifierRead { identifier } => {
+            let index = variables
+                .iter()
+                .position(|v| v == identifier)
+                .ok_or_else(|| anyhow!("Formula references unknown component `{identifier}`"))?;
+            program.push(Instruction::Input(index));
+        }
+        Operator::Neg => {
+            compile_children(children, 1, variables, program)?;
+            program.push(Instruction::Unary(|x| -x));
+        }
+        Operator::Add => compile_binary(children, |a, b| a + b, variables, program)?,
+        Operator::Sub => compile_binary(children, |a, b| a - b, variables, program)?,
+        Operator::Mul => compile_binary(children, |a, b| a * b, variables, program)?,
+        Operator::Div => compile_binary(children, |a, b| a / b, variables, program)?,
+        Operator::Mod => compile_binary(children, |a, b| a % b, variables, program)?,
+        Operator::Exp => compile_binary(children, f64::powf, variables, program)?,
+        Operator::FunctionIdentifier { identifier } => {
+            let args = function_args(children);
+            match identifier.as_str() {
+                "min" | "max" if !args.is_empty() => {
+                    compile_children(args, args.len(), variables, program)?;
+                    program.push(if identifier == "min" {
+                        Instruction::Min(args.len())
+                    } else {
+                        Instruction::Max(args.len())
+                    });
+                }
+                "math::pow" => compile_binary(args, f64::powf, variables, program)?,
+                name => {
+                    let f: fn(f64) -> f64 = match name {
+                        "floor" => f64::floor,
+                        "ceil" => f64::ceil,
+                        "round" => f64::round,
+                        "math::abs" => f64::abs,
+                        "math::sqrt" => f64::sqrt,
+                        "math::ln" => f64::ln,
+                        "math::log10" => f64::log10,
+                        "math::exp" => f64::exp,
+                        _ => bail!("Formula function `{name}` is not supported"),
+                    };
+                    compile_children(args, 1, variables, program)?;
+                    program.push(Instruction::Unary(f));
+                }
+            }
+        }
+        operator => bail!("Formula operator `{operator:?}` is not supported"),
+    }
+
+    Ok(())
+}
+
+fn compile_children(
+    children: &[Node],
+    expected: usize,
+    variables: &[String],
+    program: &mut Vec<Instruction>,
+) -> Result<()> {
+    if children.len() != expected {
+        bail!(
+            "Invalid number of operands, expected {expected}, was {}",
+            children.len()
+        );
+    }
+    for child in children {
+        compile_node(child, variables, program)?;
+    }
+    Ok(())
+}
+
+fn compile_binary(
+    children: &[Node],
+    f: fn(f64, f64) -> f64,
+    variables: &[String],
+    program: &mut Vec<Instruction>,
+) -> Result<()> {
+    compile_children(children, 2, variables, program)?;
+    program.push(Instruction::Binary(f));
+    Ok(())
+}
+
+/// Returns the argument nodes of a function call, flattening a single tuple argument.
+fn function_args(children: &[Node]) -> &[Node] {
+    match children {
+        [arg] => match arg.operator() {
+            Operator::Tuple => arg.children(),
+            Operator::RootNode => function_args(arg.children()),
+            _ => children,
+        },
+        _ => children,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use evalexpr::{ContextWithMutableVariables, HashMapContext};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn variables() -> Vec<String> {
+        vec!["BTC.BINANCE".to_string(), "LTC.BINANCE".to_string()]
+    }
+
+    fn interpret(formula: &str, inputs: &[f64]) -> f64 {
+        let mut context = HashMapContext::new();
+        for (variable, input) in variables().iter().zip(inputs) {
+            context
+                .set_value(variable.clone(), Value::from(*input))
+                .unwrap();
+        }
+        evalexpr::eval_number_with_context(formula, &context).unwrap()
+    }
+
+    #[rstest]
+    #[case("(BTC.BINANCE + LTC.BINANCE) / 2")]
+    #[case("(BTC.BINANCE + LTC.BINANCE) / 2.0")]
+    #[case("BTC.BINANCE - LTC.BINANCE * 3")]
+    #[case("-BTC.BINANCE + 1 / 2 * LTC.BINANCE")]
+    #[case("BTC.BINANCE % 7 + LTC.BINANCE ^ 2")]
+    #[case("min(BTC.BINANCE, LTC.BINANCE, 150.0)")]
+    #[case("max(BTC.BINANCE, LTC.BINANCE)")]
+    #[case("floor(BTC.BINANCE / 3) + round(LTC.BINANCE / 3)")]
+    fn test_matches_interpreter(#[case] formula: &str) {
+        let inputs = [100.5, 200.25];
+        let node = evalexpr::build_operator_tree(formula).unwrap();
+        let mut compiled = CompiledFormula::compile(&node, &variables()).unwrap();
+
+        assert_eq!(compiled.num_inputs(), 2);
+        assert_eq!(compiled.evaluate(&inputs), interpret(formula, &inputs));
+    }
+
+    #[rstest]
+    fn test_reevaluate_with_new_inputs() {
+        let node = evalexpr::build_operator_tree("(BTC.BINANCE + LTC.BINANCE) / 2").unwrap();
+        let mut compiled = CompiledFormula::compile(&node, &variables()).unwrap();
+
+        assert_eq!(compiled.evaluate(&[100.0, 200.0]), 150.0);
+        assert_eq!(compiled.evaluate(&[300.0, 200.0]), 250.0);
+    }
+
+    #[rstest]
+    fn test_compile_with_unknown_variable() {
+        let node = evalexpr::build_operator_tree("BTC.BINANCE + ETH.BINANCE").unwrap();
+        assert!(CompiledFormula::compile(&node, &variables()).is_err());
+    }
+
+    #[rstest]
+    fn test_compile_with_unsupported_operator() {
+        let node = evalexpr::build_operator_tree("BTC.BINANCE > LTC.BINANCE").unwrap();
+        assert!(CompiledFormula::compile(&node, &variables()).is_err());
+    }
+}
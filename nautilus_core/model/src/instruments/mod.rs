@@ -14,10 +14,13 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::any::Any;
+pub mod binary_option;
+pub mod combo;
 pub mod crypto_future;
 pub mod crypto_perpetual;
 pub mod currency_pair;
 pub mod equity;
+pub mod formula;
 pub mod futures_contract;
 pub mod futures_spread;
 pub mod options_contract;
@@ -33,8 +36,9 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
 use self::{
-    crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
-    equity::Equity, futures_contract::FuturesContract, futures_spread::FuturesSpread,
+    binary_option::BinaryOption, combo::ComboInstrument, crypto_future::CryptoFuture,
+    crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair, equity::Equity,
+    futures_contract::FuturesContract, futures_spread::FuturesSpread,
     options_contract::OptionsContract, options_spread::OptionsSpread,
 };
 use crate::{
@@ -45,6 +49,8 @@ use crate::{
 
 #[derive(Debug)]
 pub enum InstrumentType {
+    BinaryOption(BinaryOption),
+    Combo(ComboInstrument),
     CryptoFuture(CryptoFuture),
     CryptoPerpetual(CryptoPerpetual),
     CurrencyPair(CurrencyPair),
@@ -98,6 +104,16 @@ pub trait Instrument: Any + 'static + Send {
     fn ts_event(&self) -> UnixNanos;
     fn ts_init(&self) -> UnixNanos;
 
+    /// Returns the UNIX timestamp (nanoseconds) when the instrument became tradable, if it expires.
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        None
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) when the instrument expires, if it expires.
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        None
+    }
+
     /// Creates a new price from the given `value` with the correct price precision for the instrument.
     fn make_price(&self, value: f64) -> Result<Price> {
         Price::new(value, self.price_precision())
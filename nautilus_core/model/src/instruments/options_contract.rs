@@ -205,6 +205,14 @@ impl Instrument for OptionsContract {
         self.ts_init
     }
 
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        Some(self.activation_ns)
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        Some(self.expiration_ns)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
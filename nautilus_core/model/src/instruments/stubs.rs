@@ -19,9 +19,14 @@ use rstest::fixture;
 use rust_decimal_macros::dec;
 use ustr::Ustr;
 
-use super::{futures_spread::FuturesSpread, options_spread::OptionsSpread};
+use super::{
+    binary_option::BinaryOption,
+    combo::{ComboInstrument, ComboLeg},
+    futures_spread::FuturesSpread,
+    options_spread::OptionsSpread,
+};
 use crate::{
-    enums::{AssetClass, OptionKind},
+    enums::{AssetClass, InstrumentClass, OptionKind},
     identifiers::{instrument_id::InstrumentId, symbol::Symbol, venue::Venue},
     instruments::{
         crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual,
@@ -420,3 +425,65 @@ pub fn options_spread() -> OptionsSpread {
     )
     .unwrap()
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// ComboInstrument
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn combo_vertical_spread() -> ComboInstrument {
+    ComboInstrument::new(
+        InstrumentId::from("AAPL240621C190-195.OPRA"),
+        Symbol::from("AAPL240621C190-195"),
+        AssetClass::Equity,
+        InstrumentClass::OptionSpread,
+        Some(Ustr::from("OPRA")),
+        vec![
+            ComboLeg::new(InstrumentId::from("AAPL240621C00190000.OPRA"), 1),
+            ComboLeg::new(InstrumentId::from("AAPL240621C00195000.OPRA"), -1),
+        ],
+        Currency::USD(),
+        2,
+        Price::from("0.01"),
+        Quantity::from(100),
+        Quantity::from(1),
+        None,
+        None,
+        None,
+        None,
+        0,
+        0,
+    )
+    .unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// BinaryOption
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn binary_option() -> BinaryOption {
+    let activation = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let expiration = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    BinaryOption::new(
+        InstrumentId::from("BTC-100K-2024-YES.POLYMARKET"),
+        Symbol::from("BTC-100K-2024-YES"),
+        AssetClass::Cryptocurrency,
+        Currency::USDC(),
+        activation.timestamp_nanos_opt().unwrap() as UnixNanos,
+        expiration.timestamp_nanos_opt().unwrap() as UnixNanos,
+        3,
+        2,
+        Price::from("0.001"),
+        Quantity::from("0.01"),
+        Some(Ustr::from("Yes")),
+        Some(Ustr::from("Will BTC trade above 100,000 USD before 2025?")),
+        None,
+        None,
+        None,
+        None,
+        0,
+        0,
+    )
+    .unwrap()
+}
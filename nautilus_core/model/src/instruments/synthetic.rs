@@ -22,9 +22,12 @@ use anyhow::{anyhow, Result};
 use evalexpr::{ContextWithMutableVariables, HashMapContext, Node, Value};
 use nautilus_core::time::UnixNanos;
 
+use super::formula::CompiledFormula;
 use crate::{
+    data::{quote::QuoteTick, trade::TradeTick},
+    enums::AggressorSide,
     identifiers::{instrument_id::InstrumentId, symbol::Symbol, venue::Venue},
-    types::price::Price,
+    types::{price::Price, quantity::Quantity},
 };
 
 /// Represents a synthetic instrument with prices derived from component instruments using a
@@ -45,6 +48,10 @@ pub struct SyntheticInstrument {
     context: HashMapContext,
     variables: Vec<String>,
     operator_tree: Node,
+    compiled: Option<CompiledFormula>,
+    bid_inputs: Vec<Option<f64>>,
+    ask_inputs: Vec<Option<f64>>,
+    last_inputs: Vec<Option<f64>>,
 }
 
 impl SyntheticInstrument {
@@ -65,6 +72,8 @@ impl SyntheticInstrument {
             .collect();
 
         let operator_tree = evalexpr::build_operator_tree(&formula)?;
+        let compiled = CompiledFormula::compile(&operator_tree, &variables).ok();
+        let num_components = components.len();
 
         Ok(Self {
             id: InstrumentId::new(symbol, Venue::synthetic()),
@@ -75,6 +84,10 @@ impl SyntheticInstrument {
             context: HashMapContext::new(),
             variables,
             operator_tree,
+            compiled,
+            bid_inputs: vec![None; num_components],
+            ask_inputs: vec![None; num_components],
+            last_inputs: vec![None; num_components],
             ts_event,
             ts_init,
         })
@@ -87,11 +100,19 @@ impl SyntheticInstrument {
 
     pub fn change_formula(&mut self, formula: String) -> Result<(), anyhow::Error> {
         let operator_tree = evalexpr::build_operator_tree(&formula)?;
+        self.compiled = CompiledFormula::compile(&operator_tree, &self.variables).ok();
         self.formula = formula;
         self.operator_tree = operator_tree;
         Ok(())
     }
 
+    /// Returns whether the formula is evaluated by the compiled evaluator, rather than
+    /// falling back to interpreting the operator tree.
+    #[must_use]
+    pub fn is_compiled(&self) -> bool {
+        self.compiled.is_some()
+    }
+
     /// Calculates the price of the synthetic instrument based on the given component input prices
     /// provided as a map.
     #[allow(dead_code)]
@@ -118,6 +139,10 @@ impl SyntheticInstrument {
             return Err(anyhow!("Invalid number of input values"));
         }
 
+        if let Some(compiled) = &mut self.compiled {
+            return Price::new(compiled.evaluate(inputs), self.price_precision);
+        }
+
         for (variable, input) in self.variables.iter().zip(inputs) {
             self.context
                 .set_value(variable.clone(), Value::from(*input))?;
@@ -132,6 +157,80 @@ impl SyntheticInstrument {
             )),
         }
     }
+
+    /// Updates the synthetic instrument with the given component `quote`, returning a derived
+    /// quote once prices are available for all components.
+    ///
+    /// The derived bid and ask are calculated from the latest component bids and asks
+    /// respectively, with placeholder sizes of 1.
+    pub fn handle_quote(
+        &mut self,
+        quote: &QuoteTick,
+        ts_init: UnixNanos,
+    ) -> Result<Option<QuoteTick>> {
+        let Some(index) = self.component_index(&quote.instrument_id) else {
+            return Ok(None);
+        };
+        self.bid_inputs[index] = Some(quote.bid_price.as_f64());
+        self.ask_inputs[index] = Some(quote.ask_price.as_f64());
+
+        let (Some(bid_inputs), Some(ask_inputs)) = (
+            collect_inputs(&self.bid_inputs),
+            collect_inputs(&self.ask_inputs),
+        ) else {
+            return Ok(None);
+        };
+        let bid_price = self.calculate(&bid_inputs)?;
+        let ask_price = self.calculate(&ask_inputs)?;
+        let size_one = Quantity::new(1.0, 0)?;
+
+        QuoteTick::new(
+            self.id,
+            bid_price,
+            ask_price,
+            size_one,
+            size_one,
+            quote.ts_event,
+            ts_init,
+        )
+        .map(Some)
+    }
+
+    /// Updates the synthetic instrument with the given component `trade`, returning a derived
+    /// trade once last prices are available for all components.
+    pub fn handle_trade(
+        &mut self,
+        trade: &TradeTick,
+        ts_init: UnixNanos,
+    ) -> Result<Option<TradeTick>> {
+        let Some(index) = self.component_index(&trade.instrument_id) else {
+            return Ok(None);
+        };
+        self.last_inputs[index] = Some(trade.price.as_f64());
+
+        let Some(last_inputs) = collect_inputs(&self.last_inputs) else {
+            return Ok(None);
+        };
+        let price = self.calculate(&last_inputs)?;
+
+        Ok(Some(TradeTick::new(
+            self.id,
+            price,
+            Quantity::new(1.0, 0)?,
+            AggressorSide::NoAggressor,
+            trade.trade_id,
+            trade.ts_event,
+            ts_init,
+        )))
+    }
+
+    fn component_index(&self, instrument_id: &InstrumentId) -> Option<usize> {
+        self.components.iter().position(|id| id == instrument_id)
+    }
+}
+
+fn collect_inputs(inputs: &[Option<f64>]) -> Option<Vec<f64>> {
+    inputs.iter().copied().collect()
 }
 
 impl PartialEq<Self> for SyntheticInstrument {
@@ -153,7 +252,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
-    use crate::identifiers::{instrument_id::InstrumentId, symbol::Symbol};
+    use crate::identifiers::{instrument_id::InstrumentId, symbol::Symbol, trade_id::TradeId};
 
     #[rstest]
     fn test_calculate_from_map() {
@@ -229,4 +328,133 @@ mod tests {
         assert_eq!(price.as_f64(), 75.0);
         assert_eq!(synth.formula, new_formula);
     }
+
+    #[rstest]
+    fn test_formula_is_compiled() {
+        let synth = SyntheticInstrument::new(
+            Symbol::new("BTC-LTC").unwrap(),
+            2,
+            vec![
+                InstrumentId::from("BTC.BINANCE"),
+                InstrumentId::from("LTC.BINANCE"),
+            ],
+            "(BTC.BINANCE + LTC.BINANCE) / 2".to_string(),
+            0,
+            0,
+        )
+        .unwrap();
+
+        assert!(synth.is_compiled());
+    }
+
+    #[rstest]
+    fn test_handle_quote() {
+        let btc_binance = InstrumentId::from("BTC.BINANCE");
+        let ltc_binance = InstrumentId::from("LTC.BINANCE");
+        let mut synth = SyntheticInstrument::new(
+            Symbol::new("BTC-LTC").unwrap(),
+            2,
+            vec![btc_binance, ltc_binance],
+            "(BTC.BINANCE + LTC.BINANCE) / 2.0".to_string(),
+            0,
+            0,
+        )
+        .unwrap();
+
+        let btc_quote = QuoteTick::new(
+            btc_binance,
+            Price::from("100.00"),
+            Price::from("101.00"),
+            Quantity::from(5),
+            Quantity::from(5),
+            1,
+            1,
+        )
+        .unwrap();
+        let ltc_quote = QuoteTick::new(
+            ltc_binance,
+            Price::from("200.00"),
+            Price::from("203.00"),
+            Quantity::from(5),
+            Quantity::from(5),
+            2,
+            2,
+        )
+        .unwrap();
+
+        assert!(synth.handle_quote(&btc_quote, 3).unwrap().is_none());
+        let quote = synth.handle_quote(&ltc_quote, 4).unwrap().unwrap();
+
+        assert_eq!(quote.instrument_id, synth.id);
+        assert_eq!(quote.bid_price, Price::from("150.00"));
+        assert_eq!(quote.ask_price, Price::from("152.00"));
+        assert_eq!(quote.bid_size, Quantity::from(1));
+        assert_eq!(quote.ts_event, 2);
+        assert_eq!(quote.ts_init, 4);
+    }
+
+    #[rstest]
+    fn test_handle_trade() {
+        let btc_binance = InstrumentId::from("BTC.BINANCE");
+        let ltc_binance = InstrumentId::from("LTC.BINANCE");
+        let mut synth = SyntheticInstrument::new(
+            Symbol::new("BTC-LTC").unwrap(),
+            2,
+            vec![btc_binance, ltc_binance],
+            "BTC.BINANCE - LTC.BINANCE".to_string(),
+            0,
+            0,
+        )
+        .unwrap();
+
+        let mut trade = TradeTick::new(
+            btc_binance,
+            Price::from("300.00"),
+            Quantity::from(2),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            1,
+            1,
+        );
+        assert!(synth.handle_trade(&trade, 1).unwrap().is_none());
+
+        trade.instrument_id = ltc_binance;
+        trade.price = Price::from("120.50");
+        trade.trade_id = TradeId::from("2");
+        let synth_trade = synth.handle_trade(&trade, 2).unwrap().unwrap();
+
+        assert_eq!(synth_trade.instrument_id, synth.id);
+        assert_eq!(synth_trade.price, Price::from("179.50"));
+        assert_eq!(synth_trade.aggressor_side, AggressorSide::NoAggressor);
+        assert_eq!(synth_trade.trade_id, TradeId::from("2"));
+    }
+
+    #[rstest]
+    fn test_handle_quote_for_non_component() {
+        let mut synth = SyntheticInstrument::new(
+            Symbol::new("BTC-LTC").unwrap(),
+            2,
+            vec![
+                InstrumentId::from("BTC.BINANCE"),
+                InstrumentId::from("LTC.BINANCE"),
+            ],
+            "(BTC.BINANCE + LTC.BINANCE) / 2".to_string(),
+            0,
+            0,
+        )
+        .unwrap();
+
+        let quote = QuoteTick::new(
+            InstrumentId::from("ETH.BINANCE"),
+            Price::from("100.00"),
+            Price::from("101.00"),
+            Quantity::from(5),
+            Quantity::from(5),
+            1,
+            1,
+        )
+        .unwrap();
+
+        assert!(synth.handle_quote(&quote, 1).unwrap().is_none());
+    }
 }
@@ -23,6 +23,7 @@ pub mod macros;
 pub mod orderbook;
 pub mod orders;
 pub mod position;
+pub mod tax_lots;
 pub mod types;
 pub mod venues;
 
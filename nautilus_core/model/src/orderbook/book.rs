@@ -15,12 +15,19 @@
 
 use std::collections::BTreeMap;
 
+use indexmap::IndexMap;
+use nautilus_core::time::UnixNanos;
 use thiserror::Error;
 
 use super::{ladder::BookPrice, level::Level};
 use crate::{
+    data::quote::QuoteTick,
     enums::{BookType, OrderSide},
-    types::{price::Price, quantity::Quantity},
+    identifiers::instrument_id::InstrumentId,
+    types::{
+        price::Price,
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 #[derive(thiserror::Error, Debug)]
@@ -39,6 +46,12 @@ pub enum BookIntegrityError {
     NoOrderSide,
     #[error("Integrity error: orders in cross [{0} {1}]")]
     OrdersCrossed(BookPrice, BookPrice),
+    #[error("Integrity error: orders locked [{0} {1}]")]
+    OrdersLocked(BookPrice, BookPrice),
+    #[error("Integrity error: invalid {0} order size: order_id={1}, size={2}")]
+    InvalidOrderSize(OrderSide, u64, Quantity),
+    #[error("Integrity error: duplicate order ID in book: order_id={0}")]
+    DuplicateOrderId(u64),
     #[error("Integrity error: number of {0} orders at level > 1 for L2_MBP book, was {1}")]
     TooManyOrders(OrderSide, usize),
     #[error("Integrity error: number of {0} levels > 1 for L1_MBP book, was {1}")]
@@ -49,7 +62,7 @@ pub enum BookIntegrityError {
 /// order book levels.
 #[must_use]
 pub fn get_avg_px_for_quantity(qty: Quantity, levels: &BTreeMap<BookPrice, Level>) -> f64 {
-    let mut cumulative_size_raw = 0u64;
+    let mut cumulative_size_raw: QuantityRaw = 0;
     let mut cumulative_value = 0.0;
 
     for (book_price, level) in levels {
@@ -69,6 +82,64 @@ pub fn get_avg_px_for_quantity(qty: Quantity, levels: &BTreeMap<BookPrice, Level
     }
 }
 
+/// Represents the result of sweeping order book levels for a specified quantity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BookSweep {
+    /// The size weighted average price of the filled quantity (zero if nothing filled).
+    pub avg_px: f64,
+    /// The total quantity which could be filled from the available levels.
+    pub filled_qty: Quantity,
+    /// The price and quantity filled at each level swept, in the order walked.
+    pub fills: Vec<(Price, Quantity)>,
+}
+
+impl BookSweep {
+    /// Returns whether the full requested quantity was filled.
+    #[must_use]
+    pub fn is_complete(&self, qty: Quantity) -> bool {
+        self.filled_qty.raw >= qty.raw
+    }
+}
+
+/// Sweeps a set of order book levels for a specified quantity, returning the average
+/// price, total filled quantity and the fill at each level.
+#[must_use]
+pub fn get_sweep_for_quantity(qty: Quantity, levels: &BTreeMap<BookPrice, Level>) -> BookSweep {
+    let mut cumulative_size_raw: QuantityRaw = 0;
+    let mut cumulative_value = 0.0;
+    let mut fills = Vec::new();
+
+    for (book_price, level) in levels {
+        if cumulative_size_raw >= qty.raw {
+            break;
+        }
+
+        let size_this_level = level.size_raw().min(qty.raw - cumulative_size_raw);
+        if size_this_level == 0 {
+            continue;
+        }
+
+        cumulative_size_raw += size_this_level;
+        cumulative_value += book_price.value.as_f64() * size_this_level as f64;
+        fills.push((
+            book_price.value,
+            Quantity::from_raw(size_this_level, qty.precision).unwrap(),
+        ));
+    }
+
+    let avg_px = if cumulative_size_raw == 0 {
+        0.0
+    } else {
+        cumulative_value / cumulative_size_raw as f64
+    };
+
+    BookSweep {
+        avg_px,
+        filled_qty: Quantity::from_raw(cumulative_size_raw, qty.precision).unwrap(),
+        fills,
+    }
+}
+
 /// Calculates the estimated fill quantity for a specified price from a set of
 /// order book levels and order side.
 #[must_use]
@@ -99,6 +170,169 @@ pub fn get_quantity_for_price(
     matched_size
 }
 
+/// Returns a `QuoteTick` built from the top level of each side of the book, with the
+/// size of each level aggregated across its orders.
+///
+/// Returns `None` if either side of the book is empty.
+#[must_use]
+pub fn get_top_of_book_quote(
+    instrument_id: InstrumentId,
+    bids: &BTreeMap<BookPrice, Level>,
+    asks: &BTreeMap<BookPrice, Level>,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> Option<QuoteTick> {
+    let top_bid = bids.values().next()?;
+    let top_ask = asks.values().next()?;
+    let bid_size = Quantity::from_raw(top_bid.size_raw(), top_bid.first()?.size.precision).ok()?;
+    let ask_size = Quantity::from_raw(top_ask.size_raw(), top_ask.first()?.size.precision).ok()?;
+
+    QuoteTick::new(
+        instrument_id,
+        top_bid.price.value,
+        top_ask.price.value,
+        bid_size,
+        ask_size,
+        ts_event,
+        ts_init,
+    )
+    .ok()
+}
+
+/// The change to the top of book after applying deltas.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TopOfBookUpdate {
+    /// The best bid and ask prices and sizes are unchanged.
+    Unchanged,
+    /// The top of book changed, with a quote for the new best bid and ask.
+    Changed(QuoteTick),
+    /// A side of the book became empty, so there is no top of book quote.
+    Cleared,
+}
+
+impl TopOfBookUpdate {
+    /// Returns the update from the `previous` to the `current` top of book quotes.
+    #[must_use]
+    pub fn from_quotes(previous: Option<&QuoteTick>, current: Option<QuoteTick>) -> Self {
+        match (previous, current) {
+            (_, Some(current)) if is_top_of_book_changed(previous, &current) => {
+                Self::Changed(current)
+            }
+            (Some(_), None) => Self::Cleared,
+            _ => Self::Unchanged,
+        }
+    }
+}
+
+/// Returns whether the top of book differs between the `previous` and `current` quotes.
+#[must_use]
+pub fn is_top_of_book_changed(previous: Option<&QuoteTick>, current: &QuoteTick) -> bool {
+    match previous {
+        Some(previous) => {
+            previous.bid_price != current.bid_price
+                || previous.ask_price != current.ask_price
+                || previous.bid_size != current.bid_size
+                || previous.ask_size != current.ask_size
+        }
+        None => true,
+    }
+}
+
+/// Calculates the volume imbalance across the top `depth` levels of each side of the book.
+///
+/// The result is in the range [-1, 1], where positive values indicate more bid volume.
+/// Returns `None` if there is no volume within `depth` on either side.
+#[must_use]
+pub fn get_volume_imbalance(
+    bids: &BTreeMap<BookPrice, Level>,
+    asks: &BTreeMap<BookPrice, Level>,
+    depth: usize,
+) -> Option<f64> {
+    let bid_volume: f64 = bids.values().take(depth).map(Level::size).sum();
+    let ask_volume: f64 = asks.values().take(depth).map(Level::size).sum();
+    let total_volume = bid_volume + ask_volume;
+
+    if total_volume == 0.0 {
+        return None;
+    }
+
+    Some((bid_volume - ask_volume) / total_volume)
+}
+
+/// Calculates the size weighted microprice from the top levels of each side of the book.
+///
+/// Each top price is weighted by the size on the opposite side, so the microprice leans
+/// towards the side with less resting volume.
+#[must_use]
+pub fn get_microprice(
+    bids: &BTreeMap<BookPrice, Level>,
+    asks: &BTreeMap<BookPrice, Level>,
+) -> Option<f64> {
+    let top_bid = bids.values().next()?;
+    let top_ask = asks.values().next()?;
+    let bid_size = top_bid.size();
+    let ask_size = top_ask.size();
+    let total_size = bid_size + ask_size;
+
+    if total_size == 0.0 {
+        return None;
+    }
+
+    Some(
+        (top_bid.price.value.as_f64() * ask_size + top_ask.price.value.as_f64() * bid_size)
+            / total_size,
+    )
+}
+
+/// Calculates the cumulative size of all levels priced within `offset` of the best price.
+#[must_use]
+pub fn get_cumulative_depth(levels: &BTreeMap<BookPrice, Level>, offset: Price) -> f64 {
+    let Some(best_price) = levels.keys().next() else {
+        return 0.0;
+    };
+
+    levels
+        .values()
+        .take_while(|level| (level.price.value.raw - best_price.value.raw).abs() <= offset.raw)
+        .map(Level::size)
+        .sum()
+}
+
+/// Aggregates the levels into coarser price bands of `tick_interval` width.
+///
+/// Bid prices are rounded down and ask prices rounded up to the band boundary, so that
+/// each band is never more aggressive than the levels it contains. The bands are
+/// returned in book order, from the best price outwards.
+///
+/// Returns an empty grouping if `tick_interval` is not positive.
+#[must_use]
+pub fn group_levels(
+    levels: &BTreeMap<BookPrice, Level>,
+    side: OrderSide,
+    tick_interval: Price,
+) -> IndexMap<Price, f64> {
+    let interval = tick_interval.raw;
+    let mut groups: IndexMap<Price, f64> = IndexMap::new();
+    if interval <= 0 {
+        return groups;
+    }
+
+    for level in levels.values() {
+        let raw = level.price.value.raw;
+        let band_raw = match side {
+            OrderSide::Buy => raw.div_euclid(interval) * interval,
+            OrderSide::Sell => -((-raw).div_euclid(interval) * interval),
+            _ => panic!("Invalid `OrderSide` {side}"),
+        };
+        let precision = level.price.value.precision.max(tick_interval.precision);
+        // SAFETY: Precision is taken from valid prices
+        let band = Price::from_raw(band_raw, precision).unwrap();
+        *groups.entry(band).or_insert(0.0) += level.size();
+    }
+
+    groups
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -108,10 +342,13 @@ mod tests {
 
     use crate::{
         data::{
-            depth::{stubs::stub_depth10, OrderBookDepth10},
+            depth::{
+                stubs::{stub_depth10, stub_depth25},
+                OrderBookDepth10, OrderBookDepth25,
+            },
             order::BookOrder,
         },
-        enums::OrderSide,
+        enums::{BookAction, OrderSide},
         identifiers::instrument_id::InstrumentId,
         orderbook::{book_mbo::OrderBookMbo, book_mbp::OrderBookMbp},
         types::{price::Price, quantity::Quantity},
@@ -349,6 +586,169 @@ mod tests {
         );
     }
 
+    fn analytics_book() -> OrderBookMbp {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBookMbp::new(instrument_id, false);
+        let orders = [
+            (OrderSide::Buy, "1.00", "3.0"),
+            (OrderSide::Buy, "0.99", "2.0"),
+            (OrderSide::Buy, "0.97", "5.0"),
+            (OrderSide::Sell, "1.02", "1.0"),
+            (OrderSide::Sell, "1.03", "4.0"),
+        ];
+        for (side, price, size) in orders {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+            book.add(order, 0, 1);
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_volume_imbalance() {
+        let book = analytics_book();
+
+        assert_eq!(book.volume_imbalance(1), Some(0.5));
+        assert_eq!(book.volume_imbalance(2), Some(0.0));
+        assert_eq!(book.volume_imbalance(10), Some(5.0 / 15.0));
+    }
+
+    #[rstest]
+    fn test_volume_imbalance_when_empty() {
+        let book = OrderBookMbo::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+
+        assert_eq!(book.volume_imbalance(5), None);
+    }
+
+    #[rstest]
+    fn test_microprice() {
+        let book = analytics_book();
+
+        // (1.00 * 1.0 + 1.02 * 3.0) / 4.0
+        assert!((book.microprice().unwrap() - 1.015).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_microprice_when_one_sided() {
+        let mut book = analytics_book();
+        book.clear_asks(0, 2);
+
+        assert_eq!(book.microprice(), None);
+    }
+
+    #[rstest]
+    fn test_get_cumulative_depth() {
+        let book = analytics_book();
+
+        assert_eq!(
+            book.get_cumulative_depth(OrderSide::Buy, Price::from("0.00")),
+            3.0
+        );
+        assert_eq!(
+            book.get_cumulative_depth(OrderSide::Buy, Price::from("0.01")),
+            5.0
+        );
+        assert_eq!(
+            book.get_cumulative_depth(OrderSide::Buy, Price::from("0.05")),
+            10.0
+        );
+        assert_eq!(
+            book.get_cumulative_depth(OrderSide::Sell, Price::from("0.01")),
+            5.0
+        );
+    }
+
+    #[rstest]
+    fn test_group_levels() {
+        let book = analytics_book();
+
+        let bids = book.group_levels(OrderSide::Buy, Price::from("0.05"));
+        let asks = book.group_levels(OrderSide::Sell, Price::from("0.05"));
+
+        assert_eq!(
+            bids.into_iter().collect::<Vec<_>>(),
+            vec![(Price::from("1.00"), 3.0), (Price::from("0.95"), 7.0)]
+        );
+        assert_eq!(
+            asks.into_iter().collect::<Vec<_>>(),
+            vec![(Price::from("1.05"), 5.0)]
+        );
+    }
+
+    #[rstest]
+    fn test_group_levels_when_empty() {
+        let book = OrderBookMbo::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+
+        assert!(book
+            .group_levels(OrderSide::Buy, Price::from("0.05"))
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_group_levels_when_tick_interval_not_positive() {
+        let book = analytics_book();
+
+        assert!(book
+            .group_levels(OrderSide::Buy, Price::from("0.00"))
+            .is_empty());
+        assert!(book
+            .group_levels(OrderSide::Sell, Price::from("-0.05"))
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_spread_ticks() {
+        let book = analytics_book();
+
+        assert_eq!(book.spread_ticks(Price::from("0.01")), Some(2.0));
+        assert_eq!(book.spread_ticks(Price::from("0.005")), Some(4.0));
+    }
+
+    #[rstest]
+    fn test_get_sweep_for_quantity() {
+        let book = analytics_book();
+
+        let sweep = book.get_sweep_for_quantity(Quantity::from("3.0"), OrderSide::Buy);
+
+        assert!(sweep.is_complete(Quantity::from("3.0")));
+        assert_eq!(sweep.filled_qty, Quantity::from("3.0"));
+        assert_eq!(
+            sweep.fills,
+            vec![
+                (Price::from("1.02"), Quantity::from("1.0")),
+                (Price::from("1.03"), Quantity::from("2.0")),
+            ]
+        );
+        assert!((sweep.avg_px - (1.02 + 2.0 * 1.03) / 3.0).abs() < 1e-9);
+        assert!(
+            (sweep.avg_px - book.get_avg_px_for_quantity(Quantity::from("3.0"), OrderSide::Buy))
+                .abs()
+                < 1e-9
+        );
+    }
+
+    #[rstest]
+    fn test_get_sweep_for_quantity_when_partial() {
+        let book = analytics_book();
+
+        let sweep = book.get_sweep_for_quantity(Quantity::from("20.0"), OrderSide::Sell);
+
+        assert!(!sweep.is_complete(Quantity::from("20.0")));
+        assert_eq!(sweep.filled_qty, Quantity::from("10.0"));
+        assert_eq!(sweep.fills.len(), 3);
+        assert_eq!(sweep.fills[2], (Price::from("0.97"), Quantity::from("5.0")));
+    }
+
+    #[rstest]
+    fn test_get_sweep_for_quantity_when_empty() {
+        let book = OrderBookMbo::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+
+        let sweep = book.get_sweep_for_quantity(Quantity::from("1.0"), OrderSide::Buy);
+
+        assert_eq!(sweep.avg_px, 0.0);
+        assert_eq!(sweep.filled_qty, Quantity::from("0.0"));
+        assert!(sweep.fills.is_empty());
+    }
+
     #[rstest]
     fn test_apply_depth(stub_depth10: OrderBookDepth10) {
         let depth = stub_depth10;
@@ -363,6 +763,73 @@ mod tests {
         assert_eq!(book.best_ask_size().unwrap().as_f64(), 100.0);
     }
 
+    #[rstest]
+    fn test_apply_depth25(stub_depth25: OrderBookDepth25) {
+        let mut depth = stub_depth25;
+        depth.asks[24] = BookOrder::default(); // Padding level
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBookMbp::new(instrument_id, false);
+
+        book.apply_depth25(depth);
+
+        assert_eq!(book.best_bid_price().unwrap().as_f64(), 99.00);
+        assert_eq!(book.best_ask_price().unwrap().as_f64(), 100.00);
+        assert_eq!(book.bids().count(), 25);
+        assert_eq!(book.asks().count(), 24);
+    }
+
+    #[rstest]
+    fn test_apply_depth25_incremental(stub_depth25: OrderBookDepth25) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBookMbp::new(instrument_id, false);
+        book.apply_depth25(stub_depth25);
+
+        let mut depth = stub_depth25;
+        depth.bids[0].size = Quantity::from(150);
+        depth.asks.rotate_left(1); // Best ask level removed
+        depth.asks[24] = BookOrder::new(
+            OrderSide::Sell,
+            Price::new(125.00, 2).unwrap(),
+            Quantity::from(100),
+            0,
+        );
+        depth.sequence = 1;
+
+        let deltas = book.apply_depth25_incremental(depth);
+
+        let actions: Vec<(BookAction, OrderSide, f64)> = deltas
+            .iter()
+            .map(|delta| (delta.action, delta.order.side, delta.order.price.as_f64()))
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                (BookAction::Update, OrderSide::Buy, 99.00),
+                (BookAction::Delete, OrderSide::Sell, 100.00),
+                (BookAction::Add, OrderSide::Sell, 125.00),
+            ]
+        );
+        assert!(deltas.iter().all(|delta| delta.sequence == 1));
+        assert_eq!(book.best_bid_size().unwrap().as_f64(), 150.0);
+        assert_eq!(book.best_ask_price().unwrap().as_f64(), 101.00);
+        assert_eq!(book.bids().count(), 25);
+        assert_eq!(book.asks().count(), 25);
+        assert_eq!(book.sequence, 1);
+    }
+
+    #[rstest]
+    fn test_apply_depth25_incremental_when_unchanged(stub_depth25: OrderBookDepth25) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBookMbp::new(instrument_id, false);
+        book.apply_depth25(stub_depth25);
+
+        let deltas = book.apply_depth25_incremental(stub_depth25);
+
+        assert!(deltas.is_empty());
+        assert_eq!(book.bids().count(), 25);
+        assert_eq!(book.asks().count(), 25);
+    }
+
     #[rstest]
     fn test_pprint() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
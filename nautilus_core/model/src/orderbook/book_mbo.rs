@@ -13,20 +13,33 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use indexmap::IndexMap;
 use nautilus_core::time::UnixNanos;
 
 use super::{
-    book::{get_avg_px_for_quantity, get_quantity_for_price},
+    book::{
+        get_avg_px_for_quantity, get_cumulative_depth, get_microprice, get_quantity_for_price,
+        get_sweep_for_quantity, get_top_of_book_quote, get_volume_imbalance, group_levels,
+        BookSweep, TopOfBookUpdate,
+    },
     display::pprint_book,
     level::Level,
 };
 use crate::{
     data::{
-        delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, order::BookOrder,
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::{OrderBookDepth10, OrderBookDepth25},
+        order::{BookOrder, OrderId},
+        quote::QuoteTick,
     },
     enums::{BookAction, OrderSide},
     identifiers::instrument_id::InstrumentId,
-    orderbook::{book::BookIntegrityError, ladder::Ladder},
+    orderbook::{
+        book::BookIntegrityError,
+        integrity::{enforce_integrity, BookIntegrityConfig},
+        ladder::Ladder,
+    },
     types::{price::Price, quantity::Quantity},
 };
 
@@ -132,6 +145,29 @@ impl OrderBookMbo {
         }
     }
 
+    /// Applies the given deltas, returning how the top of book changed, with a `QuoteTick`
+    /// for the new top of book if the best bid or ask price or size changed.
+    pub fn apply_deltas_with_quote(&mut self, deltas: OrderBookDeltas) -> TopOfBookUpdate {
+        let ts_init = deltas.ts_init;
+        let previous = self.top_of_book_quote(ts_init);
+        self.apply_deltas(deltas);
+        let current = self.top_of_book_quote(ts_init);
+
+        TopOfBookUpdate::from_quotes(previous.as_ref(), current)
+    }
+
+    /// Returns a `QuoteTick` for the current top of book, or `None` if either side is empty.
+    #[must_use]
+    pub fn top_of_book_quote(&self, ts_init: UnixNanos) -> Option<QuoteTick> {
+        get_top_of_book_quote(
+            self.instrument_id,
+            &self.bids.levels,
+            &self.asks.levels,
+            self.ts_last,
+            ts_init,
+        )
+    }
+
     pub fn apply_depth(&mut self, depth: OrderBookDepth10) {
         self.bids.clear();
         self.asks.clear();
@@ -145,6 +181,21 @@ impl OrderBookMbo {
         }
     }
 
+    /// Applies the given depth as a snapshot, replacing the current state of the book.
+    ///
+    /// Padding levels (with zero size) are skipped.
+    pub fn apply_depth25(&mut self, depth: OrderBookDepth25) {
+        self.bids.clear();
+        self.asks.clear();
+
+        for order in depth.bids.into_iter().chain(depth.asks) {
+            if order.size.raw == 0 {
+                continue;
+            }
+            self.add(order, depth.ts_event, depth.sequence);
+        }
+    }
+
     pub fn bids(&self) -> impl Iterator<Item = &Level> {
         self.bids.levels.values()
     }
@@ -211,6 +262,51 @@ impl OrderBookMbo {
         }
     }
 
+    /// Returns the spread expressed as a number of `tick_size` increments.
+    #[must_use]
+    pub fn spread_ticks(&self, tick_size: Price) -> Option<f64> {
+        match (self.best_ask_price(), self.best_bid_price()) {
+            (Some(ask), Some(bid)) => Some((ask.raw - bid.raw) as f64 / tick_size.raw as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the volume imbalance across the top `depth` levels of each side.
+    #[must_use]
+    pub fn volume_imbalance(&self, depth: usize) -> Option<f64> {
+        get_volume_imbalance(&self.bids.levels, &self.asks.levels, depth)
+    }
+
+    /// Returns the size weighted microprice from the top level of each side.
+    #[must_use]
+    pub fn microprice(&self) -> Option<f64> {
+        get_microprice(&self.bids.levels, &self.asks.levels)
+    }
+
+    /// Returns the cumulative size on the given side within `offset` of the best price.
+    #[must_use]
+    pub fn get_cumulative_depth(&self, side: OrderSide, offset: Price) -> f64 {
+        let levels = match side {
+            OrderSide::Buy => &self.bids.levels,
+            OrderSide::Sell => &self.asks.levels,
+            _ => panic!("Invalid `OrderSide` {side}"),
+        };
+
+        get_cumulative_depth(levels, offset)
+    }
+
+    /// Returns the levels on the given side aggregated into `tick_interval` price bands.
+    #[must_use]
+    pub fn group_levels(&self, side: OrderSide, tick_interval: Price) -> IndexMap<Price, f64> {
+        let levels = match side {
+            OrderSide::Buy => &self.bids.levels,
+            OrderSide::Sell => &self.asks.levels,
+            _ => panic!("Invalid `OrderSide` {side}"),
+        };
+
+        group_levels(levels, side, tick_interval)
+    }
+
     #[must_use]
     pub fn get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
         let levels = match order_side {
@@ -222,6 +318,17 @@ impl OrderBookMbo {
         get_avg_px_for_quantity(qty, levels)
     }
 
+    #[must_use]
+    pub fn get_sweep_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> BookSweep {
+        let levels = match order_side {
+            OrderSide::Buy => &self.asks.levels,
+            OrderSide::Sell => &self.bids.levels,
+            _ => panic!("Invalid `OrderSide` {order_side}"),
+        };
+
+        get_sweep_for_quantity(qty, levels)
+    }
+
     #[must_use]
     pub fn get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> f64 {
         let levels = match order_side {
@@ -233,6 +340,17 @@ impl OrderBookMbo {
         get_quantity_for_price(price, order_side, levels)
     }
 
+    /// Returns the estimated queue position for the given order ID.
+    ///
+    /// The result is the price of the order's level, its zero-based position in the queue
+    /// and the total size of the orders ahead of it.
+    #[must_use]
+    pub fn get_queue_position(&self, order_id: OrderId) -> Option<(Price, usize, Quantity)> {
+        self.bids
+            .get_queue_position(order_id)
+            .or_else(|| self.asks.get_queue_position(order_id))
+    }
+
     #[must_use]
     pub fn simulate_fills(&self, order: &BookOrder) -> Vec<(Price, Quantity)> {
         match order.side {
@@ -267,6 +385,15 @@ impl OrderBookMbo {
         Ok(())
     }
 
+    /// Checks the book for crossed or locked markets and invalid orders, applying the
+    /// configured action for each violation found.
+    pub fn enforce_integrity(
+        &mut self,
+        config: &BookIntegrityConfig,
+    ) -> Result<(), BookIntegrityError> {
+        enforce_integrity(&mut self.bids, &mut self.asks, true, config)
+    }
+
     fn increment(&mut self, ts_event: u64, sequence: u64) {
         self.ts_last = ts_event;
         self.sequence = sequence;
@@ -282,7 +409,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
-    use crate::identifiers::instrument_id::InstrumentId;
+    use crate::{identifiers::instrument_id::InstrumentId, orderbook::integrity::IntegrityAction};
 
     #[rstest]
     fn test_orderbook_creation() {
@@ -332,4 +459,69 @@ mod tests {
 
         assert!(book.check_integrity().is_err());
     }
+
+    #[rstest]
+    fn test_get_queue_position() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBookMbo::new(instrument_id);
+
+        let bid1 = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(100), 1);
+        let bid2 = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(200), 2);
+        let bid3 = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(300), 3);
+        let ask1 = BookOrder::new(OrderSide::Sell, Price::from("10.01"), Quantity::from(50), 4);
+        book.add(bid1, 0, 1);
+        book.add(bid2, 0, 2);
+        book.add(bid3, 0, 3);
+        book.add(ask1, 0, 4);
+
+        assert_eq!(
+            book.get_queue_position(3),
+            Some((Price::from("10.00"), 2, Quantity::from(300)))
+        );
+        assert_eq!(
+            book.get_queue_position(4),
+            Some((Price::from("10.01"), 0, Quantity::from(0)))
+        );
+
+        book.delete(bid1, 0, 5);
+
+        assert_eq!(
+            book.get_queue_position(3),
+            Some((Price::from("10.00"), 1, Quantity::from(200)))
+        );
+        assert_eq!(book.get_queue_position(1), None);
+    }
+
+    #[rstest]
+    fn test_enforce_integrity_when_crossed_clears_side() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBookMbo::new(instrument_id);
+
+        let ask1 = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1.000"),
+            Quantity::from("1.0"),
+            1,
+        );
+        let bid1 = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("2.000"),
+            Quantity::from("1.0"),
+            2,
+        );
+        book.add(bid1, 0, 1);
+        book.add(ask1, 0, 1);
+
+        let config = BookIntegrityConfig::new(
+            IntegrityAction::ClearSide(OrderSide::Sell),
+            IntegrityAction::Raise,
+            IntegrityAction::Raise,
+        )
+        .unwrap();
+
+        assert!(book.enforce_integrity(&config).is_ok());
+        assert!(book.has_bid());
+        assert!(!book.has_ask());
+        assert!(book.check_integrity().is_ok());
+    }
 }
@@ -13,21 +13,34 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use indexmap::IndexMap;
 use nautilus_core::time::UnixNanos;
 
 use super::{
-    book::{get_avg_px_for_quantity, get_quantity_for_price},
+    book::{
+        get_avg_px_for_quantity, get_cumulative_depth, get_microprice, get_quantity_for_price,
+        get_sweep_for_quantity, get_top_of_book_quote, get_volume_imbalance, group_levels,
+        BookSweep, TopOfBookUpdate,
+    },
     display::pprint_book,
     level::Level,
 };
 use crate::{
     data::{
-        delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, order::BookOrder,
-        quote::QuoteTick, trade::TradeTick,
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::{OrderBookDepth10, OrderBookDepth25, DEPTH25_LEN},
+        order::BookOrder,
+        quote::QuoteTick,
+        trade::TradeTick,
     },
     enums::{BookAction, OrderSide},
     identifiers::instrument_id::InstrumentId,
-    orderbook::{book::BookIntegrityError, ladder::Ladder},
+    orderbook::{
+        book::BookIntegrityError,
+        integrity::{enforce_integrity, BookIntegrityConfig},
+        ladder::Ladder,
+    },
     types::{price::Price, quantity::Quantity},
 };
 
@@ -172,6 +185,29 @@ impl OrderBookMbp {
         }
     }
 
+    /// Applies the given deltas, returning how the top of book changed, with a `QuoteTick`
+    /// for the new top of book if the best bid or ask price or size changed.
+    pub fn apply_deltas_with_quote(&mut self, deltas: OrderBookDeltas) -> TopOfBookUpdate {
+        let ts_init = deltas.ts_init;
+        let previous = self.top_of_book_quote(ts_init);
+        self.apply_deltas(deltas);
+        let current = self.top_of_book_quote(ts_init);
+
+        TopOfBookUpdate::from_quotes(previous.as_ref(), current)
+    }
+
+    /// Returns a `QuoteTick` for the current top of book, or `None` if either side is empty.
+    #[must_use]
+    pub fn top_of_book_quote(&self, ts_init: UnixNanos) -> Option<QuoteTick> {
+        get_top_of_book_quote(
+            self.instrument_id,
+            &self.bids.levels,
+            &self.asks.levels,
+            self.ts_last,
+            ts_init,
+        )
+    }
+
     pub fn apply_depth(&mut self, depth: OrderBookDepth10) {
         self.bids.clear();
         self.asks.clear();
@@ -185,6 +221,21 @@ impl OrderBookMbp {
         }
     }
 
+    /// Applies the given depth as a snapshot, replacing the current state of the book.
+    ///
+    /// Padding levels (with zero size) are skipped.
+    pub fn apply_depth25(&mut self, depth: OrderBookDepth25) {
+        self.bids.clear();
+        self.asks.clear();
+
+        for order in depth.bids.into_iter().chain(depth.asks) {
+            if order.size.raw == 0 {
+                continue;
+            }
+            self.add(order, depth.ts_event, depth.sequence);
+        }
+    }
+
     pub fn bids(&self) -> impl Iterator<Item = &Level> {
         self.bids.levels.values()
     }
@@ -251,6 +302,51 @@ impl OrderBookMbp {
         }
     }
 
+    /// Returns the spread expressed as a number of `tick_size` increments.
+    #[must_use]
+    pub fn spread_ticks(&self, tick_size: Price) -> Option<f64> {
+        match (self.best_ask_price(), self.best_bid_price()) {
+            (Some(ask), Some(bid)) => Some((ask.raw - bid.raw) as f64 / tick_size.raw as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the volume imbalance across the top `depth` levels of each side.
+    #[must_use]
+    pub fn volume_imbalance(&self, depth: usize) -> Option<f64> {
+        get_volume_imbalance(&self.bids.levels, &self.asks.levels, depth)
+    }
+
+    /// Returns the size weighted microprice from the top level of each side.
+    #[must_use]
+    pub fn microprice(&self) -> Option<f64> {
+        get_microprice(&self.bids.levels, &self.asks.levels)
+    }
+
+    /// Returns the cumulative size on the given side within `offset` of the best price.
+    #[must_use]
+    pub fn get_cumulative_depth(&self, side: OrderSide, offset: Price) -> f64 {
+        let levels = match side {
+            OrderSide::Buy => &self.bids.levels,
+            OrderSide::Sell => &self.asks.levels,
+            _ => panic!("Invalid `OrderSide` {side}"),
+        };
+
+        get_cumulative_depth(levels, offset)
+    }
+
+    /// Returns the levels on the given side aggregated into `tick_interval` price bands.
+    #[must_use]
+    pub fn group_levels(&self, side: OrderSide, tick_interval: Price) -> IndexMap<Price, f64> {
+        let levels = match side {
+            OrderSide::Buy => &self.bids.levels,
+            OrderSide::Sell => &self.asks.levels,
+            _ => panic!("Invalid `OrderSide` {side}"),
+        };
+
+        group_levels(levels, side, tick_interval)
+    }
+
     #[must_use]
     pub fn get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
         let levels = match order_side {
@@ -262,6 +358,17 @@ impl OrderBookMbp {
         get_avg_px_for_quantity(qty, levels)
     }
 
+    #[must_use]
+    pub fn get_sweep_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> BookSweep {
+        let levels = match order_side {
+            OrderSide::Buy => &self.asks.levels,
+            OrderSide::Sell => &self.bids.levels,
+            _ => panic!("Invalid `OrderSide` {order_side}"),
+        };
+
+        get_sweep_for_quantity(qty, levels)
+    }
+
     #[must_use]
     pub fn get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> f64 {
         let levels = match order_side {
@@ -345,6 +452,33 @@ impl OrderBookMbp {
         Ok(())
     }
 
+    /// Checks the book for crossed or locked markets and invalid orders, applying the
+    /// configured action for each violation found.
+    pub fn enforce_integrity(
+        &mut self,
+        config: &BookIntegrityConfig,
+    ) -> Result<(), BookIntegrityError> {
+        enforce_integrity(&mut self.bids, &mut self.asks, false, config)
+    }
+
+    /// Applies the given depth as an incremental update, changing only the levels which differ
+    /// from the current state of the book, and returns the deltas which were applied.
+    ///
+    /// Levels in the depth are added, or updated if their size changed. Existing levels within
+    /// the price range covered by the depth which are not present in it are deleted (when a side
+    /// of the depth is full, levels beyond its worst price are retained). Padding levels (with
+    /// zero size) are skipped.
+    pub fn apply_depth25_incremental(&mut self, depth: OrderBookDepth25) -> Vec<OrderBookDelta> {
+        let mut deltas = get_depth_side_deltas(&self.bids, &depth.bids, &depth);
+        deltas.extend(get_depth_side_deltas(&self.asks, &depth.asks, &depth));
+
+        for delta in &deltas {
+            self.apply_delta(*delta);
+        }
+
+        deltas
+    }
+
     fn increment(&mut self, ts_event: u64, sequence: u64) {
         self.ts_last = ts_event;
         self.sequence = sequence;
@@ -421,6 +555,50 @@ impl OrderBookMbp {
     }
 }
 
+fn get_depth_side_deltas(
+    ladder: &Ladder,
+    orders: &[BookOrder],
+    depth: &OrderBookDepth25,
+) -> Vec<OrderBookDelta> {
+    let new_delta = |action: BookAction, order: BookOrder| {
+        OrderBookDelta::new(
+            depth.instrument_id,
+            action,
+            order,
+            depth.flags,
+            depth.sequence,
+            depth.ts_event,
+            depth.ts_init,
+        )
+    };
+
+    let orders: Vec<&BookOrder> = orders.iter().filter(|order| order.size.raw != 0).collect();
+    let worst_price = match orders.last() {
+        Some(order) if orders.len() == DEPTH25_LEN => Some(order.to_book_price()),
+        _ => None,
+    };
+
+    // Levels are ordered best price first
+    let mut deltas: Vec<OrderBookDelta> = ladder
+        .levels
+        .iter()
+        .take_while(|(price, _)| worst_price.map_or(true, |worst| **price <= worst))
+        .filter(|(price, _)| !orders.iter().any(|order| order.to_book_price() == **price))
+        .filter_map(|(_, level)| level.first())
+        .map(|order| new_delta(BookAction::Delete, *order))
+        .collect();
+
+    for order in orders {
+        match ladder.levels.get(&order.to_book_price()) {
+            Some(level) if level.size_raw() == order.size.raw => {} // Unchanged
+            Some(_) => deltas.push(new_delta(BookAction::Update, *order)),
+            None => deltas.push(new_delta(BookAction::Add, *order)),
+        }
+    }
+
+    deltas
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -532,4 +710,66 @@ mod tests {
 
         assert!(book.check_integrity().is_err());
     }
+
+    #[rstest]
+    fn test_apply_deltas_with_quote() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBookMbp::new(instrument_id, false);
+        let delta = |side, price: &str, size: &str, sequence| {
+            OrderBookDelta::new(
+                instrument_id,
+                BookAction::Add,
+                BookOrder::new(side, Price::from(price), Quantity::from(size), 0),
+                0,
+                sequence,
+                sequence,
+                sequence,
+            )
+        };
+
+        let update = book.apply_deltas_with_quote(OrderBookDeltas::new(
+            instrument_id,
+            vec![
+                delta(OrderSide::Buy, "100.00", "1", 1),
+                delta(OrderSide::Sell, "101.00", "2", 2),
+            ],
+        ));
+
+        let TopOfBookUpdate::Changed(quote) = update else {
+            panic!("expected `Changed`, was {update:?}");
+        };
+        assert_eq!(quote.bid_price, Price::from("100.00"));
+        assert_eq!(quote.ask_price, Price::from("101.00"));
+        assert_eq!(quote.bid_size, Quantity::from("1"));
+        assert_eq!(quote.ask_size, Quantity::from("2"));
+        assert_eq!(quote.ts_event, 2);
+
+        // Below the top of book, so no change
+        let update = book.apply_deltas_with_quote(OrderBookDeltas::new(
+            instrument_id,
+            vec![delta(OrderSide::Buy, "99.00", "5", 3)],
+        ));
+        assert_eq!(update, TopOfBookUpdate::Unchanged);
+
+        let update = book.apply_deltas_with_quote(OrderBookDeltas::new(
+            instrument_id,
+            vec![delta(OrderSide::Sell, "100.50", "3", 4)],
+        ));
+        assert!(
+            matches!(update, TopOfBookUpdate::Changed(quote) if quote.ask_price == Price::from("100.50"))
+        );
+
+        let update = book.apply_deltas_with_quote(OrderBookDeltas::new(
+            instrument_id,
+            vec![OrderBookDelta::clear(instrument_id, 5, 5, 5)],
+        ));
+        assert_eq!(update, TopOfBookUpdate::Cleared);
+
+        // Still empty on the ask side, so no change
+        let update = book.apply_deltas_with_quote(OrderBookDeltas::new(
+            instrument_id,
+            vec![delta(OrderSide::Buy, "100.00", "1", 6)],
+        ));
+        assert_eq!(update, TopOfBookUpdate::Unchanged);
+    }
 }
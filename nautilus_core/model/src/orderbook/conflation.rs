@@ -0,0 +1,310 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use indexmap::IndexMap;
+use nautilus_core::time::UnixNanos;
+
+use crate::{
+    data::{delta::OrderBookDelta, deltas::OrderBookDeltas},
+    enums::{BookAction, BookType, OrderSide, RecordFlag},
+    identifiers::instrument_id::InstrumentId,
+};
+
+/// Conflates a stream of `OrderBookDelta` updates into batches emitted at a fixed interval.
+///
+/// Within each interval only the latest delta per price level (or per order for `L3_MBO`
+/// books) is retained, and a `Clear` action discards everything pending before it. Intervals
+/// are aligned to multiples of `interval_ns` based on `ts_event`.
+#[derive(Clone, Debug)]
+pub struct DeltaConflator {
+    /// The instrument ID for the conflated deltas.
+    pub instrument_id: InstrumentId,
+    /// The order book type which determines how deltas are keyed.
+    pub book_type: BookType,
+    /// The conflation interval (nanoseconds).
+    pub interval_ns: u64,
+    clear: Option<OrderBookDelta>,
+    pending: IndexMap<(OrderSide, u64), OrderBookDelta>,
+    next_emit_ns: Option<UnixNanos>,
+}
+
+impl DeltaConflator {
+    /// Creates a new [`DeltaConflator`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `interval_ns` is zero.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, book_type: BookType, interval_ns: u64) -> Self {
+        assert!(interval_ns > 0, "`interval_ns` must be positive");
+        Self {
+            instrument_id,
+            book_type,
+            interval_ns,
+            clear: None,
+            pending: IndexMap::new(),
+            next_emit_ns: None,
+        }
+    }
+
+    /// Returns the number of deltas currently pending emission.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pending.len() + usize::from(self.clear.is_some())
+    }
+
+    /// Returns whether there are no deltas pending emission.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.clear.is_none() && self.pending.is_empty()
+    }
+
+    /// Handles the given delta, returning the conflated batch for the previous interval
+    /// if the delta falls beyond it.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the delta is for a different instrument.
+    pub fn handle_delta(&mut self, delta: OrderBookDelta) -> Option<OrderBookDeltas> {
+        assert_eq!(
+            delta.instrument_id, self.instrument_id,
+            "`delta.instrument_id` does not match conflator"
+        );
+
+        let mut batch = None;
+        match self.next_emit_ns {
+            Some(next_emit_ns) if delta.ts_event >= next_emit_ns => {
+                batch = self.flush();
+                self.next_emit_ns = Some(self.interval_end(delta.ts_event));
+            }
+            None => self.next_emit_ns = Some(self.interval_end(delta.ts_event)),
+            _ => {}
+        }
+
+        if delta.action == BookAction::Clear {
+            self.pending.clear();
+            self.clear = Some(delta);
+        } else {
+            let key = match self.book_type {
+                BookType::L3_MBO => (delta.order.side, delta.order.order_id),
+                BookType::L2_MBP | BookType::L1_MBP => {
+                    (delta.order.side, delta.order.price.raw as u64)
+                }
+            };
+            self.pending.insert(key, delta);
+        }
+
+        batch
+    }
+
+    /// Handles each delta in the given batch, returning any conflated batches emitted.
+    pub fn handle_deltas(&mut self, deltas: OrderBookDeltas) -> Vec<OrderBookDeltas> {
+        deltas
+            .deltas
+            .into_iter()
+            .filter_map(|delta| self.handle_delta(delta))
+            .collect()
+    }
+
+    /// Emits all pending deltas as a single batch, with `F_LAST` set on the final delta.
+    ///
+    /// Returns `None` if there are no pending deltas.
+    pub fn flush(&mut self) -> Option<OrderBookDeltas> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut deltas: Vec<OrderBookDelta> = Vec::with_capacity(self.len());
+        deltas.extend(self.clear.take());
+        deltas.extend(self.pending.drain(..).map(|(_, delta)| delta));
+
+        // SAFETY: Checked not empty above
+        let last = deltas.last_mut().unwrap();
+        last.flags |= RecordFlag::F_LAST as u8;
+
+        Some(OrderBookDeltas::new(self.instrument_id, deltas))
+    }
+
+    /// Clears all pending deltas and resets the interval.
+    pub fn reset(&mut self) {
+        self.clear = None;
+        self.pending.clear();
+        self.next_emit_ns = None;
+    }
+
+    fn interval_end(&self, ts_event: UnixNanos) -> UnixNanos {
+        (ts_event / self.interval_ns + 1) * self.interval_ns
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::order::BookOrder,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    const INTERVAL_NS: u64 = 100_000_000; // 100ms
+
+    fn delta(
+        action: BookAction,
+        side: OrderSide,
+        price: &str,
+        size: &str,
+        order_id: u64,
+        ts_event: UnixNanos,
+    ) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from("AAPL.XNAS"),
+            action,
+            BookOrder::new(side, Price::from(price), Quantity::from(size), order_id),
+            0,
+            0,
+            ts_event,
+            ts_event,
+        )
+    }
+
+    #[rstest]
+    fn test_collapses_updates_per_price_level() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut conflator = DeltaConflator::new(instrument_id, BookType::L2_MBP, INTERVAL_NS);
+
+        let updates = [
+            delta(BookAction::Add, OrderSide::Buy, "10.00", "100", 0, 1),
+            delta(BookAction::Add, OrderSide::Sell, "10.01", "100", 0, 2),
+            delta(BookAction::Update, OrderSide::Buy, "10.00", "200", 0, 3),
+            delta(BookAction::Update, OrderSide::Buy, "10.00", "300", 0, 4),
+        ];
+        for update in updates {
+            assert!(conflator.handle_delta(update).is_none());
+        }
+
+        assert_eq!(conflator.len(), 2);
+
+        let batch = conflator
+            .handle_delta(delta(
+                BookAction::Add,
+                OrderSide::Buy,
+                "9.99",
+                "100",
+                0,
+                INTERVAL_NS,
+            ))
+            .unwrap();
+
+        assert_eq!(batch.deltas.len(), 2);
+        assert_eq!(batch.deltas[0].order.size, Quantity::from("300"));
+        assert_eq!(batch.deltas[1].order.side, OrderSide::Sell);
+        assert!(RecordFlag::F_LAST.matches(batch.flags));
+        assert_eq!(conflator.len(), 1);
+    }
+
+    #[rstest]
+    fn test_keys_by_order_id_for_mbo() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut conflator = DeltaConflator::new(instrument_id, BookType::L3_MBO, INTERVAL_NS);
+
+        conflator.handle_delta(delta(BookAction::Add, OrderSide::Buy, "10.00", "100", 1, 1));
+        conflator.handle_delta(delta(BookAction::Add, OrderSide::Buy, "10.00", "200", 2, 2));
+        conflator.handle_delta(delta(
+            BookAction::Delete,
+            OrderSide::Buy,
+            "10.00",
+            "100",
+            1,
+            3,
+        ));
+
+        let batch = conflator.flush().unwrap();
+
+        assert_eq!(batch.deltas.len(), 2);
+        assert_eq!(batch.deltas[0].action, BookAction::Delete);
+        assert_eq!(batch.deltas[1].order.order_id, 2);
+    }
+
+    #[rstest]
+    fn test_clear_discards_pending() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut conflator = DeltaConflator::new(instrument_id, BookType::L2_MBP, INTERVAL_NS);
+
+        conflator.handle_delta(delta(BookAction::Add, OrderSide::Buy, "10.00", "100", 0, 1));
+        conflator.handle_delta(OrderBookDelta::clear(instrument_id, 0, 2, 2));
+        conflator.handle_delta(delta(
+            BookAction::Add,
+            OrderSide::Sell,
+            "10.01",
+            "100",
+            0,
+            3,
+        ));
+
+        let batch = conflator.flush().unwrap();
+
+        assert_eq!(batch.deltas.len(), 2);
+        assert_eq!(batch.deltas[0].action, BookAction::Clear);
+        assert_eq!(batch.deltas[1].order.side, OrderSide::Sell);
+        assert!(conflator.is_empty());
+        assert!(conflator.flush().is_none());
+    }
+
+    #[rstest]
+    fn test_handle_deltas_emits_per_interval() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut conflator = DeltaConflator::new(instrument_id, BookType::L2_MBP, INTERVAL_NS);
+
+        let deltas = OrderBookDeltas::new(
+            instrument_id,
+            vec![
+                delta(BookAction::Add, OrderSide::Buy, "10.00", "100", 0, 1),
+                delta(
+                    BookAction::Add,
+                    OrderSide::Buy,
+                    "10.00",
+                    "200",
+                    0,
+                    INTERVAL_NS + 1,
+                ),
+                delta(
+                    BookAction::Add,
+                    OrderSide::Buy,
+                    "10.00",
+                    "300",
+                    0,
+                    3 * INTERVAL_NS,
+                ),
+            ],
+        );
+
+        let batches = conflator.handle_deltas(deltas);
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].deltas[0].order.size, Quantity::from("100"));
+        assert_eq!(batches[1].deltas[0].order.size, Quantity::from("200"));
+        assert_eq!(conflator.len(), 1);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`interval_ns` must be positive")]
+    fn test_new_when_zero_interval() {
+        let _ = DeltaConflator::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP, 0);
+    }
+}
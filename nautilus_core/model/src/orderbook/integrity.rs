@@ -0,0 +1,347 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashSet, str::FromStr};
+
+use super::{book::BookIntegrityError, ladder::Ladder};
+use crate::enums::OrderSide;
+
+/// The action to take when an order book integrity violation is detected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntegrityAction {
+    /// Return the violation as an error.
+    Raise,
+    /// Log the violation as a warning and continue.
+    Log,
+    /// Clear the given side of the book and continue.
+    ClearSide(OrderSide),
+}
+
+impl FromStr for IntegrityAction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_uppercase().as_str() {
+            "RAISE" => Ok(Self::Raise),
+            "LOG" => Ok(Self::Log),
+            "CLEAR_BIDS" => Ok(Self::ClearSide(OrderSide::Buy)),
+            "CLEAR_ASKS" => Ok(Self::ClearSide(OrderSide::Sell)),
+            _ => anyhow::bail!("Invalid `IntegrityAction`, was '{s}'"),
+        }
+    }
+}
+
+/// Configuration for how an order book responds to integrity violations.
+///
+/// The default configuration raises on every violation, which matches the behavior
+/// of `check_integrity`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct BookIntegrityConfig {
+    /// The action when the best bid is above the best ask.
+    on_crossed: IntegrityAction,
+    /// The action when the best bid equals the best ask.
+    on_locked: IntegrityAction,
+    /// The action when an order has a zero size or a duplicate order ID.
+    on_invalid_order: IntegrityAction,
+}
+
+impl Default for BookIntegrityConfig {
+    fn default() -> Self {
+        Self {
+            on_crossed: IntegrityAction::Raise,
+            on_locked: IntegrityAction::Raise,
+            on_invalid_order: IntegrityAction::Raise,
+        }
+    }
+}
+
+impl BookIntegrityConfig {
+    /// Creates a new [`BookIntegrityConfig`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any action clears `OrderSide::NoOrderSide`.
+    pub fn new(
+        on_crossed: IntegrityAction,
+        on_locked: IntegrityAction,
+        on_invalid_order: IntegrityAction,
+    ) -> anyhow::Result<Self> {
+        for action in [on_crossed, on_locked, on_invalid_order] {
+            if action == IntegrityAction::ClearSide(OrderSide::NoOrderSide) {
+                anyhow::bail!("Invalid `IntegrityAction::ClearSide`, was `OrderSide::NoOrderSide`");
+            }
+        }
+
+        Ok(Self {
+            on_crossed,
+            on_locked,
+            on_invalid_order,
+        })
+    }
+
+    /// Returns the configured action for the given violation.
+    #[must_use]
+    pub fn action_for(&self, violation: &BookIntegrityError) -> IntegrityAction {
+        match violation {
+            BookIntegrityError::OrdersCrossed(..) => self.on_crossed,
+            BookIntegrityError::OrdersLocked(..) => self.on_locked,
+            BookIntegrityError::InvalidOrderSize(..) | BookIntegrityError::DuplicateOrderId(..) => {
+                self.on_invalid_order
+            }
+            _ => IntegrityAction::Raise,
+        }
+    }
+}
+
+/// Returns all integrity violations found in the given ladders.
+///
+/// Order IDs are only checked for uniqueness when `check_order_ids` is true, as MBP
+/// books derive synthetic order IDs from prices which may legitimately repeat across sides.
+#[must_use]
+pub fn find_violations(
+    bids: &Ladder,
+    asks: &Ladder,
+    check_order_ids: bool,
+) -> Vec<BookIntegrityError> {
+    let mut violations = Vec::new();
+
+    if let (Some(top_bid), Some(top_ask)) = (bids.top(), asks.top()) {
+        if top_bid.price.value > top_ask.price.value {
+            violations.push(BookIntegrityError::OrdersCrossed(
+                top_bid.price,
+                top_ask.price,
+            ));
+        } else if top_bid.price.value == top_ask.price.value {
+            violations.push(BookIntegrityError::OrdersLocked(
+                top_bid.price,
+                top_ask.price,
+            ));
+        }
+    }
+
+    let mut order_ids = HashSet::new();
+    for level in bids.levels.values().chain(asks.levels.values()) {
+        for order in level.orders.values() {
+            if order.size.raw == 0 {
+                violations.push(BookIntegrityError::InvalidOrderSize(
+                    order.side,
+                    order.order_id,
+                    order.size,
+                ));
+            }
+            if check_order_ids && !order_ids.insert(order.order_id) {
+                violations.push(BookIntegrityError::DuplicateOrderId(order.order_id));
+            }
+        }
+    }
+
+    violations
+}
+
+/// Applies the configured action for each violation found in the given ladders.
+///
+/// Returns the first violation configured to raise, any violations found before it will
+/// already have been handled.
+pub fn enforce_integrity(
+    bids: &mut Ladder,
+    asks: &mut Ladder,
+    check_order_ids: bool,
+    config: &BookIntegrityConfig,
+) -> Result<(), BookIntegrityError> {
+    for violation in find_violations(bids, asks, check_order_ids) {
+        match config.action_for(&violation) {
+            IntegrityAction::Raise => return Err(violation),
+            IntegrityAction::Log => log::warn!("{violation}"),
+            IntegrityAction::ClearSide(side) => match side {
+                OrderSide::Buy => bids.clear(),
+                OrderSide::Sell => asks.clear(),
+                // Rejected when the config is built
+                OrderSide::NoOrderSide => return Err(BookIntegrityError::NoOrderSide),
+            },
+        }
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::order::BookOrder,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    fn ladders(bid_px: &str, ask_px: &str) -> (Ladder, Ladder) {
+        let mut bids = Ladder::new(OrderSide::Buy);
+        let mut asks = Ladder::new(OrderSide::Sell);
+        bids.add(BookOrder::new(
+            OrderSide::Buy,
+            Price::from(bid_px),
+            Quantity::from("1.0"),
+            1,
+        ));
+        asks.add(BookOrder::new(
+            OrderSide::Sell,
+            Price::from(ask_px),
+            Quantity::from("1.0"),
+            2,
+        ));
+        (bids, asks)
+    }
+
+    #[rstest]
+    fn test_find_violations_when_valid() {
+        let (bids, asks) = ladders("1.00", "1.01");
+
+        assert!(find_violations(&bids, &asks, true).is_empty());
+    }
+
+    #[rstest]
+    fn test_find_violations_when_crossed() {
+        let (bids, asks) = ladders("1.02", "1.01");
+
+        let violations = find_violations(&bids, &asks, true);
+
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(
+            violations[0],
+            BookIntegrityError::OrdersCrossed(..)
+        ));
+    }
+
+    #[rstest]
+    fn test_find_violations_when_locked() {
+        let (bids, asks) = ladders("1.01", "1.01");
+
+        let violations = find_violations(&bids, &asks, true);
+
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(
+            violations[0],
+            BookIntegrityError::OrdersLocked(..)
+        ));
+    }
+
+    #[rstest]
+    fn test_find_violations_when_zero_size_and_duplicate_order_id() {
+        let (mut bids, asks) = ladders("1.00", "1.01");
+        bids.add(BookOrder::new(
+            OrderSide::Buy,
+            Price::from("0.99"),
+            Quantity::from("0.0"),
+            2,
+        ));
+
+        let violations = find_violations(&bids, &asks, true);
+
+        assert_eq!(violations.len(), 2);
+        assert!(matches!(
+            violations[0],
+            BookIntegrityError::InvalidOrderSize(OrderSide::Buy, 2, _)
+        ));
+        assert!(matches!(
+            violations[1],
+            BookIntegrityError::DuplicateOrderId(2)
+        ));
+        assert_eq!(find_violations(&bids, &asks, false).len(), 1);
+    }
+
+    #[rstest]
+    fn test_enforce_integrity_raises_by_default() {
+        let (mut bids, mut asks) = ladders("1.02", "1.01");
+        let config = BookIntegrityConfig::default();
+
+        let result = enforce_integrity(&mut bids, &mut asks, true, &config);
+
+        assert!(matches!(result, Err(BookIntegrityError::OrdersCrossed(..))));
+        assert_eq!(bids.len(), 1);
+        assert_eq!(asks.len(), 1);
+    }
+
+    #[rstest]
+    fn test_enforce_integrity_when_log() {
+        let (mut bids, mut asks) = ladders("1.01", "1.01");
+        let config = BookIntegrityConfig::new(
+            IntegrityAction::Raise,
+            IntegrityAction::Log,
+            IntegrityAction::Raise,
+        )
+        .unwrap();
+
+        let result = enforce_integrity(&mut bids, &mut asks, true, &config);
+
+        assert!(result.is_ok());
+        assert_eq!(bids.len(), 1);
+        assert_eq!(asks.len(), 1);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, 0, 1)]
+    #[case(OrderSide::Sell, 1, 0)]
+    fn test_enforce_integrity_when_clear_side(
+        #[case] side: OrderSide,
+        #[case] expected_bids: usize,
+        #[case] expected_asks: usize,
+    ) {
+        let (mut bids, mut asks) = ladders("1.02", "1.01");
+        let config = BookIntegrityConfig::new(
+            IntegrityAction::ClearSide(side),
+            IntegrityAction::Raise,
+            IntegrityAction::Raise,
+        )
+        .unwrap();
+
+        let result = enforce_integrity(&mut bids, &mut asks, true, &config);
+
+        assert!(result.is_ok());
+        assert_eq!(bids.len(), expected_bids);
+        assert_eq!(asks.len(), expected_asks);
+    }
+
+    #[rstest]
+    fn test_config_new_when_clear_no_order_side() {
+        let result = BookIntegrityConfig::new(
+            IntegrityAction::ClearSide(OrderSide::NoOrderSide),
+            IntegrityAction::Raise,
+            IntegrityAction::Raise,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case("RAISE", IntegrityAction::Raise)]
+    #[case("log", IntegrityAction::Log)]
+    #[case("CLEAR_BIDS", IntegrityAction::ClearSide(OrderSide::Buy))]
+    #[case("CLEAR_ASKS", IntegrityAction::ClearSide(OrderSide::Sell))]
+    fn test_integrity_action_from_str(#[case] input: &str, #[case] expected: IntegrityAction) {
+        assert_eq!(IntegrityAction::from_str(input).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_integrity_action_from_str_when_invalid() {
+        assert!(IntegrityAction::from_str("CLEAR_NONE").is_err());
+    }
+}
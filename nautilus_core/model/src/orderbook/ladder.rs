@@ -167,6 +167,15 @@ impl Ladder {
         }
     }
 
+    /// Returns the price, queue position and size ahead for the given order (if found).
+    #[must_use]
+    pub fn get_queue_position(&self, order_id: OrderId) -> Option<(Price, usize, Quantity)> {
+        let book_price = self.cache.get(&order_id)?;
+        let level = self.levels.get(book_price)?;
+        let (position, size_ahead) = level.get_queue_position(order_id)?;
+        Some((book_price.value, position, size_ahead))
+    }
+
     #[must_use]
     pub fn sizes(&self) -> f64 {
         self.levels.values().map(super::level::Level::size).sum()
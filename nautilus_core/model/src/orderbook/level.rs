@@ -18,7 +18,10 @@ use std::{cmp::Ordering, collections::BTreeMap};
 use crate::{
     data::order::{BookOrder, OrderId},
     orderbook::{book::BookIntegrityError, ladder::BookPrice},
-    types::fixed::FIXED_SCALAR,
+    types::{
+        fixed::FIXED_SCALAR,
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 /// Represents a discrete price level in an order book.
@@ -84,13 +87,30 @@ impl Level {
             .collect()
     }
 
+    /// Returns the zero-based queue position of the given order, along with the total
+    /// size of the orders ahead of it at this level.
+    #[must_use]
+    pub fn get_queue_position(&self, order_id: OrderId) -> Option<(usize, Quantity)> {
+        let order = self.orders.get(&order_id)?;
+        let position = self.insertion_order.iter().position(|&id| id == order_id)?;
+        let size_ahead_raw = self.insertion_order[..position]
+            .iter()
+            .filter_map(|id| self.orders.get(id))
+            .map(|o| o.size.raw)
+            .sum();
+
+        // SAFETY: Precision is taken from a valid order
+        let size_ahead = Quantity::from_raw(size_ahead_raw, order.size.precision).unwrap();
+        Some((position, size_ahead))
+    }
+
     #[must_use]
     pub fn size(&self) -> f64 {
         self.orders.values().map(|o| o.size.as_f64()).sum()
     }
 
     #[must_use]
-    pub fn size_raw(&self) -> u64 {
+    pub fn size_raw(&self) -> QuantityRaw {
         self.orders.values().map(|o| o.size.raw).sum()
     }
 
@@ -103,10 +123,10 @@ impl Level {
     }
 
     #[must_use]
-    pub fn exposure_raw(&self) -> u64 {
+    pub fn exposure_raw(&self) -> QuantityRaw {
         self.orders
             .values()
-            .map(|o| ((o.price.as_f64() * o.size.as_f64()) * FIXED_SCALAR) as u64)
+            .map(|o| ((o.price.as_f64() * o.size.as_f64()) * FIXED_SCALAR) as QuantityRaw)
             .sum()
     }
 
@@ -127,14 +147,20 @@ impl Level {
         self.insertion_order.push(order.order_id);
     }
 
+    /// Updates the given order at this level.
+    ///
+    /// An increase in size loses queue priority, moving the order to the back of the queue.
     pub fn update(&mut self, order: BookOrder) {
         self.check_order_for_this_level(&order);
 
         if order.size.raw == 0 {
             self.orders.remove(&order.order_id);
             self.update_insertion_order();
-        } else {
-            self.orders.insert(order.order_id, order);
+        } else if let Some(existing) = self.orders.insert(order.order_id, order) {
+            if order.size.raw > existing.size.raw {
+                self.insertion_order.retain(|&id| id != order.order_id);
+                self.insertion_order.push(order.order_id);
+            }
         }
     }
 
@@ -415,4 +441,52 @@ mod tests {
         level.add(order2);
         assert_eq!(level.exposure_raw(), 60_000_000_000);
     }
+
+    #[rstest]
+    fn test_update_order_with_increased_size_loses_priority() {
+        let mut level = Level::new(BookPrice::new(Price::from("1.00"), OrderSide::Buy));
+        let order1 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(10), 0);
+        let order2 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(20), 1);
+        let order1_increased =
+            BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(15), 0);
+
+        level.add(order1);
+        level.add(order2);
+        level.update(order1_increased);
+
+        assert_eq!(level.first().unwrap(), &order2);
+        assert_eq!(level.get_queue_position(0), Some((1, Quantity::from(20))));
+    }
+
+    #[rstest]
+    fn test_update_order_with_decreased_size_keeps_priority() {
+        let mut level = Level::new(BookPrice::new(Price::from("1.00"), OrderSide::Buy));
+        let order1 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(10), 0);
+        let order2 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(20), 1);
+        let order1_decreased =
+            BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(5), 0);
+
+        level.add(order1);
+        level.add(order2);
+        level.update(order1_decreased);
+
+        assert_eq!(level.first().unwrap(), &order1_decreased);
+        assert_eq!(level.get_queue_position(1), Some((1, Quantity::from(5))));
+    }
+
+    #[rstest]
+    fn test_get_queue_position() {
+        let mut level = Level::new(BookPrice::new(Price::from("1.00"), OrderSide::Sell));
+        let order1 = BookOrder::new(OrderSide::Sell, Price::from("1.00"), Quantity::from(10), 0);
+        let order2 = BookOrder::new(OrderSide::Sell, Price::from("1.00"), Quantity::from(20), 1);
+        let order3 = BookOrder::new(OrderSide::Sell, Price::from("1.00"), Quantity::from(30), 2);
+
+        level.add(order1);
+        level.add(order2);
+        level.add(order3);
+
+        assert_eq!(level.get_queue_position(0), Some((0, Quantity::from(0))));
+        assert_eq!(level.get_queue_position(2), Some((2, Quantity::from(30))));
+        assert_eq!(level.get_queue_position(3), None);
+    }
 }
@@ -16,6 +16,9 @@
 pub mod book;
 pub mod book_mbo;
 pub mod book_mbp;
+pub mod conflation;
 pub mod display;
+pub mod integrity;
 pub mod ladder;
 pub mod level;
+pub mod rebuilder;
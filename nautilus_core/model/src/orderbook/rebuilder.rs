@@ -0,0 +1,428 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::time::UnixNanos;
+use thiserror::Error;
+
+use super::{book_mbo::OrderBookMbo, book_mbp::OrderBookMbp, level::Level};
+use crate::{
+    data::{
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::{OrderBookDepth10, DEPTH10_LEN},
+        order::BookOrder,
+    },
+    enums::{BookAction, BookType, OrderSide, RecordFlag},
+    identifiers::instrument_id::InstrumentId,
+    types::quantity::Quantity,
+};
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceError {
+    #[error("Sequence gap: expected {0}, received {1}")]
+    Gap(u64, u64),
+    #[error("Sequence not monotonic: last {0}, received {1}")]
+    NotMonotonic(u64, u64),
+    #[error("Book is stale after a sequence gap, awaiting snapshot")]
+    Stale,
+    #[error("Instrument ID mismatch: expected {0}, received {1}")]
+    InstrumentMismatch(InstrumentId, InstrumentId),
+}
+
+#[derive(Clone, Debug)]
+enum RebuilderBook {
+    Mbo(OrderBookMbo),
+    Mbp(OrderBookMbp),
+}
+
+/// Rebuilds an order book from a stream of `OrderBookDeltas` while validating sequencing.
+///
+/// Each batch must continue from the last applied sequence number. When a gap is detected
+/// the batch is rejected and the book is marked stale, until a snapshot batch (one starting
+/// with a `Clear` action) resynchronizes it.
+#[derive(Clone, Debug)]
+pub struct BookRebuilder {
+    /// The instrument ID for the book.
+    pub instrument_id: InstrumentId,
+    /// The order book type being rebuilt.
+    pub book_type: BookType,
+    /// The last applied sequence number (if any).
+    pub last_sequence: Option<u64>,
+    /// The count of sequence gaps detected.
+    pub gap_count: u64,
+    is_stale: bool,
+    book: RebuilderBook,
+}
+
+impl BookRebuilder {
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, book_type: BookType) -> Self {
+        let book = match book_type {
+            BookType::L3_MBO => RebuilderBook::Mbo(OrderBookMbo::new(instrument_id)),
+            BookType::L2_MBP => RebuilderBook::Mbp(OrderBookMbp::new(instrument_id, false)),
+            BookType::L1_MBP => RebuilderBook::Mbp(OrderBookMbp::new(instrument_id, true)),
+        };
+
+        Self {
+            instrument_id,
+            book_type,
+            last_sequence: None,
+            gap_count: 0,
+            is_stale: false,
+            book,
+        }
+    }
+
+    /// Returns whether the book is stale, awaiting a snapshot after a sequence gap.
+    #[must_use]
+    pub fn is_stale(&self) -> bool {
+        self.is_stale
+    }
+
+    /// Resets the book and sequencing state.
+    pub fn reset(&mut self) {
+        match &mut self.book {
+            RebuilderBook::Mbo(book) => book.reset(),
+            RebuilderBook::Mbp(book) => book.reset(),
+        }
+        self.last_sequence = None;
+        self.is_stale = false;
+    }
+
+    /// Validates the sequencing of the given deltas and applies them to the book.
+    ///
+    /// A batch starting with a `Clear` action is treated as a snapshot, and is always
+    /// applied regardless of its sequence number. Otherwise every delta must continue the
+    /// sequence, deltas sharing a sequence number must be consecutive within the batch.
+    pub fn apply_deltas(&mut self, deltas: OrderBookDeltas) -> Result<(), SequenceError> {
+        if let Some(delta) = deltas
+            .deltas
+            .iter()
+            .find(|delta| delta.instrument_id != self.instrument_id)
+        {
+            return Err(SequenceError::InstrumentMismatch(
+                self.instrument_id,
+                delta.instrument_id,
+            ));
+        }
+
+        // SAFETY: `OrderBookDeltas` cannot be empty
+        let first = deltas.deltas.first().unwrap();
+        let is_snapshot = first.action == BookAction::Clear;
+
+        if !is_snapshot {
+            self.validate_sequence(&deltas)?;
+        }
+
+        self.last_sequence = Some(deltas.sequence);
+        self.is_stale = false;
+
+        match &mut self.book {
+            RebuilderBook::Mbo(book) => book.apply_deltas(deltas),
+            RebuilderBook::Mbp(book) => book.apply_deltas(deltas),
+        }
+
+        Ok(())
+    }
+
+    /// Returns the current book state as a snapshot batch, a `Clear` followed by an `Add`
+    /// for each order, flagged with `F_SNAPSHOT` and `F_LAST` on the final delta.
+    #[must_use]
+    pub fn snapshot_deltas(&self, ts_init: UnixNanos) -> OrderBookDeltas {
+        let (sequence, ts_event) = self.sequence_and_ts_last();
+        let flags = RecordFlag::F_SNAPSHOT as u8;
+
+        let mut deltas = vec![OrderBookDelta::clear(
+            self.instrument_id,
+            sequence,
+            ts_event,
+            ts_init,
+        )];
+        for level in self.bids().chain(self.asks()) {
+            for order in level.get_orders() {
+                deltas.push(OrderBookDelta::new(
+                    self.instrument_id,
+                    BookAction::Add,
+                    order,
+                    flags,
+                    sequence,
+                    ts_event,
+                    ts_init,
+                ));
+            }
+        }
+
+        // SAFETY: Always contains at least the `Clear` delta
+        deltas.last_mut().unwrap().flags |= RecordFlag::F_LAST as u8;
+
+        OrderBookDeltas::new(self.instrument_id, deltas)
+    }
+
+    /// Returns the top 10 levels of the current book state as an `OrderBookDepth10`.
+    ///
+    /// Levels are aggregated, with the order count per level, and unused levels are padded
+    /// with default (zero size) orders.
+    #[must_use]
+    pub fn snapshot_depth10(&self, ts_init: UnixNanos) -> OrderBookDepth10 {
+        let (sequence, ts_event) = self.sequence_and_ts_last();
+
+        let mut bids = [BookOrder::default(); DEPTH10_LEN];
+        let mut asks = [BookOrder::default(); DEPTH10_LEN];
+        let mut bid_counts = [0u32; DEPTH10_LEN];
+        let mut ask_counts = [0u32; DEPTH10_LEN];
+
+        for (i, level) in self.bids().take(DEPTH10_LEN).enumerate() {
+            bids[i] = aggregate_level(level, OrderSide::Buy);
+            bid_counts[i] = level.len() as u32;
+        }
+
+        for (i, level) in self.asks().take(DEPTH10_LEN).enumerate() {
+            asks[i] = aggregate_level(level, OrderSide::Sell);
+            ask_counts[i] = level.len() as u32;
+        }
+
+        OrderBookDepth10::new(
+            self.instrument_id,
+            bids,
+            asks,
+            bid_counts,
+            ask_counts,
+            RecordFlag::F_SNAPSHOT as u8,
+            sequence,
+            ts_event,
+            ts_init,
+        )
+    }
+
+    fn validate_sequence(&mut self, deltas: &OrderBookDeltas) -> Result<(), SequenceError> {
+        if self.is_stale {
+            return Err(SequenceError::Stale);
+        }
+
+        let Some(last_sequence) = self.last_sequence else {
+            return Ok(()); // First batch establishes the sequence
+        };
+
+        let mut previous = last_sequence;
+        for (i, delta) in deltas.deltas.iter().enumerate() {
+            if delta.sequence > previous + 1 {
+                self.gap_count += 1;
+                self.is_stale = true;
+                return Err(SequenceError::Gap(previous + 1, delta.sequence));
+            }
+            if delta.sequence < previous || (i == 0 && delta.sequence == previous) {
+                return Err(SequenceError::NotMonotonic(previous, delta.sequence));
+            }
+            previous = delta.sequence;
+        }
+
+        Ok(())
+    }
+
+    fn sequence_and_ts_last(&self) -> (u64, UnixNanos) {
+        match &self.book {
+            RebuilderBook::Mbo(book) => (book.sequence, book.ts_last),
+            RebuilderBook::Mbp(book) => (book.sequence, book.ts_last),
+        }
+    }
+
+    fn bids(&self) -> Box<dyn Iterator<Item = &Level> + '_> {
+        match &self.book {
+            RebuilderBook::Mbo(book) => Box::new(book.bids()),
+            RebuilderBook::Mbp(book) => Box::new(book.bids()),
+        }
+    }
+
+    fn asks(&self) -> Box<dyn Iterator<Item = &Level> + '_> {
+        match &self.book {
+            RebuilderBook::Mbo(book) => Box::new(book.asks()),
+            RebuilderBook::Mbp(book) => Box::new(book.asks()),
+        }
+    }
+}
+
+fn aggregate_level(level: &Level, side: OrderSide) -> BookOrder {
+    let precision = level.first().map_or(0, |order| order.size.precision);
+    // SAFETY: Precision is taken from a valid order
+    let size = Quantity::from_raw(level.size_raw(), precision).unwrap();
+    BookOrder::new(side, level.price.value, size, 0)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::types::price::Price;
+
+    fn add(
+        side: OrderSide,
+        price: &str,
+        size: &str,
+        order_id: u64,
+        sequence: u64,
+    ) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from("AAPL.XNAS"),
+            BookAction::Add,
+            BookOrder::new(side, Price::from(price), Quantity::from(size), order_id),
+            0,
+            sequence,
+            sequence,
+            sequence,
+        )
+    }
+
+    fn batch(deltas: Vec<OrderBookDelta>) -> OrderBookDeltas {
+        OrderBookDeltas::new(InstrumentId::from("AAPL.XNAS"), deltas)
+    }
+
+    fn rebuilder_with_book() -> BookRebuilder {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut rebuilder = BookRebuilder::new(instrument_id, BookType::L3_MBO);
+        rebuilder
+            .apply_deltas(batch(vec![
+                OrderBookDelta::clear(instrument_id, 1, 1, 1),
+                add(OrderSide::Buy, "10.00", "100", 1, 1),
+                add(OrderSide::Buy, "10.00", "200", 2, 1),
+                add(OrderSide::Sell, "10.01", "50", 3, 1),
+            ]))
+            .unwrap();
+        rebuilder
+    }
+
+    #[rstest]
+    fn test_apply_deltas_in_sequence() {
+        let mut rebuilder = rebuilder_with_book();
+
+        let result = rebuilder.apply_deltas(batch(vec![
+            add(OrderSide::Buy, "9.99", "100", 4, 2),
+            add(OrderSide::Buy, "9.98", "100", 5, 3),
+        ]));
+
+        assert!(result.is_ok());
+        assert_eq!(rebuilder.last_sequence, Some(3));
+        assert!(!rebuilder.is_stale());
+    }
+
+    #[rstest]
+    fn test_apply_deltas_when_gap() {
+        let mut rebuilder = rebuilder_with_book();
+
+        let result = rebuilder.apply_deltas(batch(vec![add(OrderSide::Buy, "9.99", "100", 4, 3)]));
+
+        assert_eq!(result, Err(SequenceError::Gap(2, 3)));
+        assert_eq!(rebuilder.gap_count, 1);
+        assert!(rebuilder.is_stale());
+        assert_eq!(
+            rebuilder.apply_deltas(batch(vec![add(OrderSide::Buy, "9.99", "100", 4, 4)])),
+            Err(SequenceError::Stale)
+        );
+    }
+
+    #[rstest]
+    fn test_apply_deltas_when_gap_within_batch() {
+        let mut rebuilder = rebuilder_with_book();
+
+        let result = rebuilder.apply_deltas(batch(vec![
+            add(OrderSide::Buy, "9.99", "100", 4, 2),
+            add(OrderSide::Buy, "9.98", "100", 5, 4),
+        ]));
+
+        assert_eq!(result, Err(SequenceError::Gap(3, 4)));
+        assert_eq!(rebuilder.gap_count, 1);
+        assert!(rebuilder.is_stale());
+        assert_eq!(rebuilder.last_sequence, Some(1));
+    }
+
+    #[rstest]
+    fn test_apply_deltas_when_instrument_mismatch() {
+        let mut rebuilder = rebuilder_with_book();
+        let instrument_id = InstrumentId::from("MSFT.XNAS");
+        let mut delta = add(OrderSide::Buy, "9.99", "100", 4, 2);
+        delta.instrument_id = instrument_id;
+
+        let result = rebuilder.apply_deltas(OrderBookDeltas::new(instrument_id, vec![delta]));
+
+        assert_eq!(
+            result,
+            Err(SequenceError::InstrumentMismatch(
+                InstrumentId::from("AAPL.XNAS"),
+                instrument_id,
+            ))
+        );
+        assert_eq!(rebuilder.last_sequence, Some(1));
+    }
+
+    #[rstest]
+    fn test_apply_deltas_when_not_monotonic() {
+        let mut rebuilder = rebuilder_with_book();
+
+        let result = rebuilder.apply_deltas(batch(vec![add(OrderSide::Buy, "9.99", "100", 4, 1)]));
+
+        assert_eq!(result, Err(SequenceError::NotMonotonic(1, 1)));
+        assert!(!rebuilder.is_stale());
+    }
+
+    #[rstest]
+    fn test_snapshot_resyncs_after_gap() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut rebuilder = rebuilder_with_book();
+        let _ = rebuilder.apply_deltas(batch(vec![add(OrderSide::Buy, "9.99", "100", 4, 5)]));
+
+        let result = rebuilder.apply_deltas(batch(vec![
+            OrderBookDelta::clear(instrument_id, 10, 10, 10),
+            add(OrderSide::Buy, "10.00", "100", 1, 10),
+        ]));
+
+        assert!(result.is_ok());
+        assert!(!rebuilder.is_stale());
+        assert_eq!(rebuilder.last_sequence, Some(10));
+    }
+
+    #[rstest]
+    fn test_snapshot_deltas() {
+        let rebuilder = rebuilder_with_book();
+
+        let snapshot = rebuilder.snapshot_deltas(99);
+
+        assert_eq!(snapshot.deltas.len(), 4);
+        assert_eq!(snapshot.deltas[0].action, BookAction::Clear);
+        assert_eq!(snapshot.deltas[1].order.order_id, 1);
+        assert_eq!(snapshot.deltas[2].order.order_id, 2);
+        assert_eq!(snapshot.deltas[3].order.order_id, 3);
+        assert!(RecordFlag::F_SNAPSHOT.matches(snapshot.deltas[1].flags));
+        assert!(RecordFlag::F_LAST.matches(snapshot.flags));
+        assert_eq!(snapshot.ts_init, 99);
+    }
+
+    #[rstest]
+    fn test_snapshot_depth10() {
+        let rebuilder = rebuilder_with_book();
+
+        let depth = rebuilder.snapshot_depth10(99);
+
+        assert_eq!(depth.bids[0].price, Price::from("10.00"));
+        assert_eq!(depth.bids[0].size, Quantity::from("300"));
+        assert_eq!(depth.bid_counts[0], 2);
+        assert_eq!(depth.asks[0].price, Price::from("10.01"));
+        assert_eq!(depth.ask_counts[0], 1);
+        assert_eq!(depth.bids[1], BookOrder::default());
+        assert_eq!(depth.ask_counts[1], 0);
+    }
+}
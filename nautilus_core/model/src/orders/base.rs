@@ -21,7 +21,7 @@ use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
-    limit::LimitOrder, limit_if_touched::LimitIfTouchedOrder,
+    fsm::transition, limit::LimitOrder, limit_if_touched::LimitIfTouchedOrder,
     market_if_touched::MarketIfTouchedOrder, market_to_limit::MarketToLimitOrder,
     stop_limit::StopLimitOrder, stop_market::StopMarketOrder,
     trailing_stop_limit::TrailingStopLimitOrder, trailing_stop_market::TrailingStopMarketOrder,
@@ -70,8 +70,12 @@ pub enum OrderError {
     NoOrderSide,
     #[error("Invalid event for order type")]
     InvalidOrderEvent,
-    #[error("Invalid order state transition")]
-    InvalidStateTransition,
+    #[error("Invalid order state transition from {status} on trigger {trigger}, event {event}")]
+    InvalidStateTransition {
+        status: OrderStatus,
+        trigger: OrderStatus,
+        event: String,
+    },
     #[error("Order was already initialized")]
     AlreadyInitialized,
     #[error("Order had no previous state")]
@@ -270,77 +274,6 @@ pub fn str_hashmap_to_ustr(h: HashMap<String, String>) -> HashMap<Ustr, Ustr> {
         .collect()
 }
 
-impl OrderStatus {
-    #[rustfmt::skip]
-    pub fn transition(&mut self, event: &OrderEvent) -> Result<Self, OrderError> {
-        let new_state = match (self, event) {
-            (Self::Initialized, OrderEvent::OrderDenied(_)) => Self::Denied,
-            (Self::Initialized, OrderEvent::OrderEmulated(_)) => Self::Emulated,  // Emulated orders
-            (Self::Initialized, OrderEvent::OrderReleased(_)) => Self::Released,  // Emulated orders
-            (Self::Initialized, OrderEvent::OrderSubmitted(_)) => Self::Submitted,
-            (Self::Initialized, OrderEvent::OrderRejected(_)) => Self::Rejected,  // External orders
-            (Self::Initialized, OrderEvent::OrderAccepted(_)) => Self::Accepted,  // External orders
-            (Self::Initialized, OrderEvent::OrderCanceled(_)) => Self::Canceled,  // External orders
-            (Self::Initialized, OrderEvent::OrderExpired(_)) => Self::Expired,  // External orders
-            (Self::Initialized, OrderEvent::OrderTriggered(_)) => Self::Triggered, // External orders
-            (Self::Emulated, OrderEvent::OrderCanceled(_)) => Self::Canceled,  // Emulated orders
-            (Self::Emulated, OrderEvent::OrderExpired(_)) => Self::Expired,  // Emulated orders
-            (Self::Emulated, OrderEvent::OrderReleased(_)) => Self::Released,  // Emulated orders
-            (Self::Released, OrderEvent::OrderSubmitted(_)) => Self::Submitted,  // Emulated orders
-            (Self::Released, OrderEvent::OrderDenied(_)) => Self::Denied,  // Emulated orders
-            (Self::Released, OrderEvent::OrderCanceled(_)) => Self::Canceled,  // Execution algo
-            (Self::Submitted, OrderEvent::OrderPendingUpdate(_)) => Self::PendingUpdate,
-            (Self::Submitted, OrderEvent::OrderPendingCancel(_)) => Self::PendingCancel,
-            (Self::Submitted, OrderEvent::OrderRejected(_)) => Self::Rejected,
-            (Self::Submitted, OrderEvent::OrderCanceled(_)) => Self::Canceled,  // FOK and IOC cases
-            (Self::Submitted, OrderEvent::OrderAccepted(_)) => Self::Accepted,
-            (Self::Submitted, OrderEvent::OrderPartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::Submitted, OrderEvent::OrderFilled(_)) => Self::Filled,
-            (Self::Accepted, OrderEvent::OrderRejected(_)) => Self::Rejected,  // StopLimit order
-            (Self::Accepted, OrderEvent::OrderPendingUpdate(_)) => Self::PendingUpdate,
-            (Self::Accepted, OrderEvent::OrderPendingCancel(_)) => Self::PendingCancel,
-            (Self::Accepted, OrderEvent::OrderCanceled(_)) => Self::Canceled,
-            (Self::Accepted, OrderEvent::OrderTriggered(_)) => Self::Triggered,
-            (Self::Accepted, OrderEvent::OrderExpired(_)) => Self::Expired,
-            (Self::Accepted, OrderEvent::OrderPartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::Accepted, OrderEvent::OrderFilled(_)) => Self::Filled,
-            (Self::Canceled, OrderEvent::OrderPartiallyFilled(_)) => Self::PartiallyFilled,  // Real world possibility
-            (Self::Canceled, OrderEvent::OrderFilled(_)) => Self::Filled,  // Real world possibility
-            (Self::PendingUpdate, OrderEvent::OrderRejected(_)) => Self::Rejected,
-            (Self::PendingUpdate, OrderEvent::OrderAccepted(_)) => Self::Accepted,
-            (Self::PendingUpdate, OrderEvent::OrderCanceled(_)) => Self::Canceled,
-            (Self::PendingUpdate, OrderEvent::OrderExpired(_)) => Self::Expired,
-            (Self::PendingUpdate, OrderEvent::OrderTriggered(_)) => Self::Triggered,
-            (Self::PendingUpdate, OrderEvent::OrderPendingUpdate(_)) => Self::PendingUpdate,  // Allow multiple requests
-            (Self::PendingUpdate, OrderEvent::OrderPendingCancel(_)) => Self::PendingCancel,
-            (Self::PendingUpdate, OrderEvent::OrderPartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::PendingUpdate, OrderEvent::OrderFilled(_)) => Self::Filled,
-            (Self::PendingCancel, OrderEvent::OrderRejected(_)) => Self::Rejected,
-            (Self::PendingCancel, OrderEvent::OrderPendingCancel(_)) => Self::PendingCancel,  // Allow multiple requests
-            (Self::PendingCancel, OrderEvent::OrderCanceled(_)) => Self::Canceled,
-            (Self::PendingCancel, OrderEvent::OrderExpired(_)) => Self::Expired,
-            (Self::PendingCancel, OrderEvent::OrderAccepted(_)) => Self::Accepted,  // Allow failed cancel requests
-            (Self::PendingCancel, OrderEvent::OrderPartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::PendingCancel, OrderEvent::OrderFilled(_)) => Self::Filled,
-            (Self::Triggered, OrderEvent::OrderRejected(_)) => Self::Rejected,
-            (Self::Triggered, OrderEvent::OrderPendingUpdate(_)) => Self::PendingUpdate,
-            (Self::Triggered, OrderEvent::OrderPendingCancel(_)) => Self::PendingCancel,
-            (Self::Triggered, OrderEvent::OrderCanceled(_)) => Self::Canceled,
-            (Self::Triggered, OrderEvent::OrderExpired(_)) => Self::Expired,
-            (Self::Triggered, OrderEvent::OrderPartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::Triggered, OrderEvent::OrderFilled(_)) => Self::Filled,
-            (Self::PartiallyFilled, OrderEvent::OrderPendingUpdate(_)) => Self::PendingUpdate,
-            (Self::PartiallyFilled, OrderEvent::OrderPendingCancel(_)) => Self::PendingCancel,
-            (Self::PartiallyFilled, OrderEvent::OrderCanceled(_)) => Self::Canceled,
-            (Self::PartiallyFilled, OrderEvent::OrderExpired(_)) => Self::Expired,
-            (Self::PartiallyFilled, OrderEvent::OrderPartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::PartiallyFilled, OrderEvent::OrderFilled(_)) => Self::Filled,
-            _ => return Err(OrderError::InvalidStateTransition),
-        };
-        Ok(new_state)
-    }
-}
-
 pub trait Order {
     fn status(&self) -> OrderStatus;
     fn trader_id(&self) -> TraderId;
@@ -657,9 +590,8 @@ impl OrderCore {
         assert_eq!(self.client_order_id, event.client_order_id());
         assert_eq!(self.strategy_id, event.strategy_id());
 
-        let new_status = self.status.transition(&event)?;
-        self.previous_status = Some(self.status);
-        self.status = new_status;
+        let previous_status = self.status;
+        self.status = transition(self.status, self.previous_status, &event)?;
 
         match &event {
             OrderEvent::OrderInitialized(_) => return Err(OrderError::AlreadyInitialized),
@@ -681,6 +613,15 @@ impl OrderCore {
             OrderEvent::OrderFilled(event) => self.filled(event),
         }
 
+        // Pending statuses are not recorded, so rejected or completed requests return the order
+        // to the status prior to the request
+        if !matches!(
+            previous_status,
+            OrderStatus::PendingUpdate | OrderStatus::PendingCancel
+        ) {
+            self.previous_status = Some(previous_status);
+        }
+
         self.ts_last = event.ts_event();
         self.events.push(event);
         Ok(())
@@ -718,16 +659,12 @@ impl OrderCore {
         // Do nothing else
     }
 
-    fn modify_rejected(&mut self, _event: &OrderModifyRejected) {
-        self.status = self
-            .previous_status
-            .unwrap_or_else(|| panic!("{}", OrderError::NoPreviousState));
+    fn modify_rejected(&self, _event: &OrderModifyRejected) {
+        // Do nothing else (the FSM returns the order to its previous status)
     }
 
-    fn cancel_rejected(&mut self, _event: &OrderCancelRejected) {
-        self.status = self
-            .previous_status
-            .unwrap_or_else(|| panic!("{}", OrderError::NoPreviousState));
+    fn cancel_rejected(&self, _event: &OrderCancelRejected) {
+        // Do nothing else (the FSM returns the order to its previous status)
     }
 
     fn triggered(&mut self, _event: &OrderTriggered) {}
@@ -853,7 +790,8 @@ mod tests {
         enums::{OrderSide, OrderStatus, PositionSide},
         events::order::{
             accepted::OrderAcceptedBuilder, denied::OrderDeniedBuilder, filled::OrderFilledBuilder,
-            initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+            initialized::OrderInitializedBuilder, modify_rejected::OrderModifyRejectedBuilder,
+            pending_update::OrderPendingUpdateBuilder, submitted::OrderSubmittedBuilder,
         },
         orders::market::MarketOrder,
     };
@@ -967,4 +905,47 @@ mod tests {
         assert_eq!(order.commission(&Currency::USD()), None);
         assert_eq!(order.commissions(), HashMap::new());
     }
+
+    #[rstest]
+    fn test_order_modify_rejected_returns_to_previous_status() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let pending_update = OrderPendingUpdateBuilder::default().build().unwrap();
+        let modify_rejected = OrderModifyRejectedBuilder::default().build().unwrap();
+
+        let mut order: MarketOrder = init.into();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order
+            .apply(OrderEvent::OrderPendingUpdate(pending_update))
+            .unwrap();
+        assert_eq!(order.status(), OrderStatus::PendingUpdate);
+
+        order
+            .apply(OrderEvent::OrderModifyRejected(modify_rejected))
+            .unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert!(order.is_open());
+        assert_eq!(order.event_count(), 4);
+    }
+
+    #[rstest]
+    fn test_order_invalid_state_transition() {
+        let filled = OrderFilledBuilder::default().build().unwrap();
+        let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
+
+        let result = order.apply(OrderEvent::OrderFilled(filled));
+
+        assert!(matches!(
+            result,
+            Err(OrderError::InvalidStateTransition {
+                status: OrderStatus::Initialized,
+                trigger: OrderStatus::Filled,
+                ..
+            })
+        ));
+        assert_eq!(order.status(), OrderStatus::Initialized);
+    }
 }
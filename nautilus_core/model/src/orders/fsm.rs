@@ -0,0 +1,379 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The order finite-state machine (FSM).
+//!
+//! Order events are mapped to a trigger (the order status the event moves towards), and the
+//! transition table gives the next status for each valid `(status, trigger)` pair. The table is
+//! kept in sync with the Cython `Order` state transition table.
+
+use std::fmt::Write;
+
+use crate::{enums::OrderStatus, events::order::event::OrderEvent, orders::base::OrderError};
+
+/// The valid order state transitions as `(status, trigger, next status)`.
+#[rustfmt::skip]
+pub const ORDER_STATE_TABLE: &[(OrderStatus, OrderStatus, OrderStatus)] = &[
+    (OrderStatus::Initialized, OrderStatus::Denied, OrderStatus::Denied),
+    (OrderStatus::Initialized, OrderStatus::Emulated, OrderStatus::Emulated),  // Emulated orders
+    (OrderStatus::Initialized, OrderStatus::Released, OrderStatus::Released),  // Emulated orders
+    (OrderStatus::Initialized, OrderStatus::Submitted, OrderStatus::Submitted),
+    (OrderStatus::Initialized, OrderStatus::Rejected, OrderStatus::Rejected),  // External orders
+    (OrderStatus::Initialized, OrderStatus::Accepted, OrderStatus::Accepted),  // External orders
+    (OrderStatus::Initialized, OrderStatus::Canceled, OrderStatus::Canceled),  // External orders
+    (OrderStatus::Initialized, OrderStatus::Expired, OrderStatus::Expired),  // External orders
+    (OrderStatus::Initialized, OrderStatus::Triggered, OrderStatus::Triggered),  // External orders
+    (OrderStatus::Emulated, OrderStatus::Canceled, OrderStatus::Canceled),  // Emulated orders
+    (OrderStatus::Emulated, OrderStatus::Expired, OrderStatus::Expired),  // Emulated orders
+    (OrderStatus::Emulated, OrderStatus::Released, OrderStatus::Released),  // Emulated orders
+    (OrderStatus::Released, OrderStatus::Denied, OrderStatus::Denied),  // Emulated orders
+    (OrderStatus::Released, OrderStatus::Submitted, OrderStatus::Submitted),  // Emulated orders
+    (OrderStatus::Released, OrderStatus::Canceled, OrderStatus::Canceled),  // Execution algo
+    (OrderStatus::Submitted, OrderStatus::PendingUpdate, OrderStatus::PendingUpdate),
+    (OrderStatus::Submitted, OrderStatus::PendingCancel, OrderStatus::PendingCancel),
+    (OrderStatus::Submitted, OrderStatus::Rejected, OrderStatus::Rejected),
+    (OrderStatus::Submitted, OrderStatus::Canceled, OrderStatus::Canceled),  // FOK and IOC cases
+    (OrderStatus::Submitted, OrderStatus::Accepted, OrderStatus::Accepted),
+    (OrderStatus::Submitted, OrderStatus::PartiallyFilled, OrderStatus::PartiallyFilled),
+    (OrderStatus::Submitted, OrderStatus::Filled, OrderStatus::Filled),
+    (OrderStatus::Accepted, OrderStatus::Rejected, OrderStatus::Rejected),  // StopLimit order
+    (OrderStatus::Accepted, OrderStatus::PendingUpdate, OrderStatus::PendingUpdate),
+    (OrderStatus::Accepted, OrderStatus::PendingCancel, OrderStatus::PendingCancel),
+    (OrderStatus::Accepted, OrderStatus::Canceled, OrderStatus::Canceled),
+    (OrderStatus::Accepted, OrderStatus::Triggered, OrderStatus::Triggered),
+    (OrderStatus::Accepted, OrderStatus::Expired, OrderStatus::Expired),
+    (OrderStatus::Accepted, OrderStatus::PartiallyFilled, OrderStatus::PartiallyFilled),
+    (OrderStatus::Accepted, OrderStatus::Filled, OrderStatus::Filled),
+    (OrderStatus::Canceled, OrderStatus::PartiallyFilled, OrderStatus::PartiallyFilled),  // Real world possibility
+    (OrderStatus::Canceled, OrderStatus::Filled, OrderStatus::Filled),  // Real world possibility
+    (OrderStatus::PendingUpdate, OrderStatus::Rejected, OrderStatus::Rejected),  // Real world possibility
+    (OrderStatus::PendingUpdate, OrderStatus::Accepted, OrderStatus::Accepted),
+    (OrderStatus::PendingUpdate, OrderStatus::Canceled, OrderStatus::Canceled),
+    (OrderStatus::PendingUpdate, OrderStatus::Expired, OrderStatus::Expired),
+    (OrderStatus::PendingUpdate, OrderStatus::Triggered, OrderStatus::Triggered),
+    (OrderStatus::PendingUpdate, OrderStatus::Submitted, OrderStatus::PendingUpdate),  // Real world possibility
+    (OrderStatus::PendingUpdate, OrderStatus::PendingUpdate, OrderStatus::PendingUpdate),  // Allow multiple requests
+    (OrderStatus::PendingUpdate, OrderStatus::PendingCancel, OrderStatus::PendingCancel),
+    (OrderStatus::PendingUpdate, OrderStatus::PartiallyFilled, OrderStatus::PartiallyFilled),
+    (OrderStatus::PendingUpdate, OrderStatus::Filled, OrderStatus::Filled),
+    (OrderStatus::PendingCancel, OrderStatus::Rejected, OrderStatus::Rejected),  // Real world possibility
+    (OrderStatus::PendingCancel, OrderStatus::PendingCancel, OrderStatus::PendingCancel),  // Allow multiple requests
+    (OrderStatus::PendingCancel, OrderStatus::Canceled, OrderStatus::Canceled),
+    (OrderStatus::PendingCancel, OrderStatus::Expired, OrderStatus::Expired),
+    (OrderStatus::PendingCancel, OrderStatus::Accepted, OrderStatus::Accepted),  // Allow failed cancel requests
+    (OrderStatus::PendingCancel, OrderStatus::PartiallyFilled, OrderStatus::PartiallyFilled),
+    (OrderStatus::PendingCancel, OrderStatus::Filled, OrderStatus::Filled),
+    (OrderStatus::Triggered, OrderStatus::Rejected, OrderStatus::Rejected),
+    (OrderStatus::Triggered, OrderStatus::PendingUpdate, OrderStatus::PendingUpdate),
+    (OrderStatus::Triggered, OrderStatus::PendingCancel, OrderStatus::PendingCancel),
+    (OrderStatus::Triggered, OrderStatus::Canceled, OrderStatus::Canceled),
+    (OrderStatus::Triggered, OrderStatus::Expired, OrderStatus::Expired),
+    (OrderStatus::Triggered, OrderStatus::PartiallyFilled, OrderStatus::PartiallyFilled),
+    (OrderStatus::Triggered, OrderStatus::Filled, OrderStatus::Filled),
+    (OrderStatus::PartiallyFilled, OrderStatus::PendingUpdate, OrderStatus::PendingUpdate),
+    (OrderStatus::PartiallyFilled, OrderStatus::PendingCancel, OrderStatus::PendingCancel),
+    (OrderStatus::PartiallyFilled, OrderStatus::Canceled, OrderStatus::Canceled),
+    (OrderStatus::PartiallyFilled, OrderStatus::Expired, OrderStatus::Expired),
+    (OrderStatus::PartiallyFilled, OrderStatus::PartiallyFilled, OrderStatus::PartiallyFilled),
+    (OrderStatus::PartiallyFilled, OrderStatus::Filled, OrderStatus::Filled),
+];
+
+// The closed (done) statuses, drawn as terminal nodes in the graphviz export
+const CLOSED_STATUSES: &[OrderStatus] = &[
+    OrderStatus::Denied,
+    OrderStatus::Rejected,
+    OrderStatus::Canceled,
+    OrderStatus::Expired,
+    OrderStatus::Filled,
+];
+
+impl OrderStatus {
+    /// Returns the next status following the `trigger` from this status, or `None` if the
+    /// transition is invalid.
+    #[must_use]
+    pub fn next(self, trigger: Self) -> Option<Self> {
+        ORDER_STATE_TABLE
+            .iter()
+            .find(|(status, t, _)| *status == self && *t == trigger)
+            .map(|(_, _, next)| *next)
+    }
+
+    /// Returns whether the `trigger` is a valid transition from this status.
+    #[must_use]
+    pub fn can_transition(self, trigger: Self) -> bool {
+        self.next(trigger).is_some()
+    }
+}
+
+/// Returns the FSM trigger for the given `event`, or `None` if the event does not move the order
+/// status.
+///
+/// Modify/cancel rejections and updates return a pending order to its `previous_status`.
+pub fn trigger_for_event(
+    event: &OrderEvent,
+    status: OrderStatus,
+    previous_status: Option<OrderStatus>,
+) -> Result<Option<OrderStatus>, OrderError> {
+    let trigger = match event {
+        OrderEvent::OrderInitialized(_) => return Err(OrderError::AlreadyInitialized),
+        OrderEvent::OrderDenied(_) => OrderStatus::Denied,
+        OrderEvent::OrderEmulated(_) => OrderStatus::Emulated,
+        OrderEvent::OrderReleased(_) => OrderStatus::Released,
+        OrderEvent::OrderSubmitted(_) => OrderStatus::Submitted,
+        OrderEvent::OrderAccepted(_) => OrderStatus::Accepted,
+        OrderEvent::OrderRejected(_) => OrderStatus::Rejected,
+        OrderEvent::OrderCanceled(_) => OrderStatus::Canceled,
+        OrderEvent::OrderExpired(_) => OrderStatus::Expired,
+        OrderEvent::OrderTriggered(_) => OrderStatus::Triggered,
+        OrderEvent::OrderPendingUpdate(_) => OrderStatus::PendingUpdate,
+        OrderEvent::OrderPendingCancel(_) => OrderStatus::PendingCancel,
+        OrderEvent::OrderPartiallyFilled(_) => OrderStatus::PartiallyFilled,
+        OrderEvent::OrderFilled(_) => OrderStatus::Filled,
+        OrderEvent::OrderModifyRejected(_) | OrderEvent::OrderUpdated(_) => {
+            if status != OrderStatus::PendingUpdate {
+                return Ok(None);
+            }
+            previous_status.ok_or(OrderError::NoPreviousState)?
+        }
+        OrderEvent::OrderCancelRejected(_) => {
+            if status != OrderStatus::PendingCancel {
+                return Ok(None);
+            }
+            previous_status.ok_or(OrderError::NoPreviousState)?
+        }
+    };
+    Ok(Some(trigger))
+}
+
+/// Returns the status following the `event` applied to an order with the given `status`.
+///
+/// # Errors
+///
+/// Returns [`OrderError::InvalidStateTransition`] (with the current status, attempted trigger and
+/// the event) if the event is not a valid transition from the current status.
+pub fn transition(
+    status: OrderStatus,
+    previous_status: Option<OrderStatus>,
+    event: &OrderEvent,
+) -> Result<OrderStatus, OrderError> {
+    match trigger_for_event(event, status, previous_status)? {
+        Some(trigger) => status
+            .next(trigger)
+            .ok_or_else(|| OrderError::InvalidStateTransition {
+                status,
+                trigger,
+                event: event_to_string(event),
+            }),
+        None => Ok(status),
+    }
+}
+
+/// Returns the order FSM in the graphviz `dot` language, for documentation and debugging.
+///
+/// Edges are labeled with their trigger, and closed statuses are drawn with a double border.
+#[must_use]
+pub fn order_fsm_to_dot() -> String {
+    let mut dot =
+        String::from("digraph OrderFSM {\n    rankdir=LR;\n    node [shape=box, style=rounded];\n");
+    writeln!(
+        dot,
+        "    {} [style=\"rounded,bold\"];",
+        OrderStatus::Initialized
+    )
+    .unwrap();
+    for status in CLOSED_STATUSES {
+        writeln!(dot, "    {status} [peripheries=2];").unwrap();
+    }
+    for (status, trigger, next) in ORDER_STATE_TABLE {
+        writeln!(dot, "    {status} -> {next} [label=\"{trigger}\"];").unwrap();
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+fn event_to_string(event: &OrderEvent) -> String {
+    match event {
+        OrderEvent::OrderInitialized(e) => e.to_string(),
+        OrderEvent::OrderDenied(e) => e.to_string(),
+        OrderEvent::OrderEmulated(e) => e.to_string(),
+        OrderEvent::OrderReleased(e) => e.to_string(),
+        OrderEvent::OrderSubmitted(e) => e.to_string(),
+        OrderEvent::OrderAccepted(e) => e.to_string(),
+        OrderEvent::OrderRejected(e) => e.to_string(),
+        OrderEvent::OrderCanceled(e) => e.to_string(),
+        OrderEvent::OrderExpired(e) => e.to_string(),
+        OrderEvent::OrderTriggered(e) => e.to_string(),
+        OrderEvent::OrderPendingUpdate(e) => e.to_string(),
+        OrderEvent::OrderPendingCancel(e) => e.to_string(),
+        OrderEvent::OrderModifyRejected(e) => e.to_string(),
+        OrderEvent::OrderCancelRejected(e) => e.to_string(),
+        OrderEvent::OrderUpdated(e) => e.to_string(),
+        OrderEvent::OrderPartiallyFilled(e) => e.to_string(),
+        OrderEvent::OrderFilled(e) => e.to_string(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::events::order::{
+        cancel_rejected::OrderCancelRejectedBuilder, filled::OrderFilledBuilder,
+        initialized::OrderInitializedBuilder, modify_rejected::OrderModifyRejectedBuilder,
+        submitted::OrderSubmittedBuilder, updated::OrderUpdatedBuilder,
+    };
+
+    #[rstest]
+    #[case(
+        OrderStatus::Initialized,
+        OrderStatus::Submitted,
+        Some(OrderStatus::Submitted)
+    )]
+    #[case(OrderStatus::Accepted, OrderStatus::Filled, Some(OrderStatus::Filled))]
+    #[case(OrderStatus::Canceled, OrderStatus::Filled, Some(OrderStatus::Filled))]
+    #[case(
+        OrderStatus::PendingUpdate,
+        OrderStatus::Submitted,
+        Some(OrderStatus::PendingUpdate)
+    )]
+    #[case(OrderStatus::Initialized, OrderStatus::Filled, None)]
+    #[case(OrderStatus::Filled, OrderStatus::Canceled, None)]
+    #[case(OrderStatus::Denied, OrderStatus::Submitted, None)]
+    fn test_next(
+        #[case] status: OrderStatus,
+        #[case] trigger: OrderStatus,
+        #[case] expected: Option<OrderStatus>,
+    ) {
+        assert_eq!(status.next(trigger), expected);
+        assert_eq!(status.can_transition(trigger), expected.is_some());
+    }
+
+    #[rstest]
+    fn test_state_table_has_unique_transitions() {
+        let pairs: HashSet<(OrderStatus, OrderStatus)> = ORDER_STATE_TABLE
+            .iter()
+            .map(|(status, trigger, _)| (*status, *trigger))
+            .collect();
+        assert_eq!(pairs.len(), ORDER_STATE_TABLE.len());
+    }
+
+    #[rstest]
+    fn test_closed_statuses_only_transition_on_late_fills() {
+        for (status, trigger, _) in ORDER_STATE_TABLE {
+            if CLOSED_STATUSES.contains(status) {
+                assert_eq!(*status, OrderStatus::Canceled);
+                assert!(matches!(
+                    trigger,
+                    OrderStatus::PartiallyFilled | OrderStatus::Filled
+                ));
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_transition_valid() {
+        let event = OrderEvent::OrderSubmitted(OrderSubmittedBuilder::default().build().unwrap());
+
+        let result = transition(OrderStatus::Initialized, None, &event).unwrap();
+
+        assert_eq!(result, OrderStatus::Submitted);
+    }
+
+    #[rstest]
+    fn test_transition_invalid_has_diagnostics() {
+        let filled = OrderFilledBuilder::default().build().unwrap();
+        let event = OrderEvent::OrderFilled(filled);
+
+        let error = transition(OrderStatus::Initialized, None, &event).unwrap_err();
+
+        match &error {
+            OrderError::InvalidStateTransition {
+                status,
+                trigger,
+                event,
+            } => {
+                assert_eq!(*status, OrderStatus::Initialized);
+                assert_eq!(*trigger, OrderStatus::Filled);
+                assert_eq!(event, &filled.to_string());
+            }
+            _ => panic!("Unexpected error {error:?}"),
+        }
+        let message = error.to_string();
+        assert!(message.contains("from INITIALIZED on trigger FILLED"));
+        assert!(message.contains("OrderFilled("));
+    }
+
+    #[rstest]
+    fn test_transition_initialized_event_is_error() {
+        let event =
+            OrderEvent::OrderInitialized(OrderInitializedBuilder::default().build().unwrap());
+
+        let result = transition(OrderStatus::Initialized, None, &event);
+
+        assert!(matches!(result, Err(OrderError::AlreadyInitialized)));
+    }
+
+    #[rstest]
+    fn test_modify_rejected_returns_to_previous_status() {
+        let event =
+            OrderEvent::OrderModifyRejected(OrderModifyRejectedBuilder::default().build().unwrap());
+
+        let result = transition(
+            OrderStatus::PendingUpdate,
+            Some(OrderStatus::PartiallyFilled),
+            &event,
+        )
+        .unwrap();
+
+        assert_eq!(result, OrderStatus::PartiallyFilled);
+    }
+
+    #[rstest]
+    fn test_updated_when_not_pending_does_not_change_status() {
+        let event = OrderEvent::OrderUpdated(OrderUpdatedBuilder::default().build().unwrap());
+
+        let result = transition(OrderStatus::Accepted, None, &event).unwrap();
+
+        assert_eq!(result, OrderStatus::Accepted);
+    }
+
+    #[rstest]
+    fn test_cancel_rejected_without_previous_status_is_error() {
+        let event =
+            OrderEvent::OrderCancelRejected(OrderCancelRejectedBuilder::default().build().unwrap());
+
+        let result = transition(OrderStatus::PendingCancel, None, &event);
+
+        assert!(matches!(result, Err(OrderError::NoPreviousState)));
+    }
+
+    #[rstest]
+    fn test_order_fsm_to_dot() {
+        let dot = order_fsm_to_dot();
+
+        assert!(dot.starts_with("digraph OrderFSM {"));
+        assert!(dot.trim_end().ends_with('}'));
+        assert!(dot.contains("INITIALIZED -> SUBMITTED [label=\"SUBMITTED\"];"));
+        assert!(dot.contains("PENDING_UPDATE -> PENDING_UPDATE [label=\"SUBMITTED\"];"));
+        assert!(dot.contains("FILLED [peripheries=2];"));
+        assert_eq!(dot.matches(" -> ").count(), ORDER_STATE_TABLE.len());
+    }
+}
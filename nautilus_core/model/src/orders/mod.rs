@@ -17,6 +17,7 @@
 
 pub mod base;
 pub mod default;
+pub mod fsm;
 pub mod limit;
 pub mod limit_if_touched;
 pub mod market;
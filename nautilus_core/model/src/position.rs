@@ -32,6 +32,7 @@ use crate::{
         trader_id::TraderId, venue::Venue, venue_order_id::VenueOrderId,
     },
     instruments::Instrument,
+    tax_lots::{LotMatchingMethod, TaxLotLedger},
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
@@ -480,6 +481,12 @@ impl Position {
     pub fn commissions(&self) -> Vec<Money> {
         self.commissions.values().copied().collect()
     }
+
+    /// Returns the tax lots of the position, with reducing fills matched using `method`.
+    #[must_use]
+    pub fn tax_lots(&self, method: LotMatchingMethod) -> TaxLotLedger {
+        TaxLotLedger::from_position(self, method)
+    }
 }
 
 impl PartialEq<Self> for Position {
@@ -24,7 +24,11 @@ use nautilus_core::{
     serialization::Serializable,
     time::UnixNanos,
 };
-use pyo3::{prelude::*, pyclass::CompareOp, types::PyDict};
+use pyo3::{
+    prelude::*,
+    pyclass::CompareOp,
+    types::{PyBytes, PyDict, PyTuple},
+};
 
 use super::data_to_pycapsule;
 use crate::{
@@ -146,11 +150,47 @@ impl Bar {
         Self::new(bar_type, open, high, low, close, volume, ts_event, ts_init)
     }
 
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let bytes: &PyBytes = state.extract(py)?;
+        *self = Self::from_msgpack_bytes(bytes.as_bytes().to_vec()).map_err(to_pyvalue_err)?;
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        let bytes = self.as_msgpack_bytes().map_err(to_pyvalue_err)?;
+        Ok(PyBytes::new(py, &bytes).to_object(py))
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py)?;
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> PyResult<Self> {
+        Ok(Self::new(
+            BarType::from("NULL.NULL-1-TICK-LAST-EXTERNAL"),
+            Price::zero(0),
+            Price::zero(0),
+            Price::zero(0),
+            Price::zero(0),
+            Quantity::zero(0),
+            0,
+            0,
+        )) // Safe default
+    }
+
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        let lhs = self.ts_event;
+        let rhs = other.ts_event;
         match op {
             CompareOp::Eq => self.eq(other).into_py(py),
             CompareOp::Ne => self.ne(other).into_py(py),
-            _ => py.NotImplemented(),
+            CompareOp::Ge => (lhs >= rhs).into_py(py),
+            CompareOp::Gt => (lhs > rhs).into_py(py),
+            CompareOp::Le => (lhs <= rhs).into_py(py),
+            CompareOp::Lt => (lhs < rhs).into_py(py),
         }
     }
 
@@ -359,4 +399,17 @@ mod tests {
             assert_eq!(parsed_bar, bar);
         });
     }
+
+    #[rstest]
+    fn test_pickle_state_round_trip(stub_bar: Bar) {
+        pyo3::prepare_freethreaded_python();
+        let bar = stub_bar;
+
+        Python::with_gil(|py| {
+            let state = bar.__getstate__(py).unwrap();
+            let mut restored = Bar::_safe_constructor().unwrap();
+            restored.__setstate__(py, state).unwrap();
+            assert_eq!(restored, bar);
+        });
+    }
 }
@@ -0,0 +1,283 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr};
+
+use indexmap::IndexMap;
+use nautilus_core::{
+    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+    serialization::Serializable,
+    time::UnixNanos,
+};
+use pyo3::{exceptions::PyKeyError, prelude::*, pyclass::CompareOp, types::PyDict};
+
+use crate::{
+    data::custom::{
+        get_custom_data_schema, register_custom_data_type, CustomData, CustomFieldType, CustomValue,
+    },
+    python::common::PY_MODULE_MODEL,
+};
+
+fn pyobject_to_custom_value(obj: &PyAny, field_type: CustomFieldType) -> PyResult<CustomValue> {
+    Ok(match field_type {
+        CustomFieldType::Boolean => CustomValue::Boolean(obj.extract()?),
+        CustomFieldType::Int64 => CustomValue::Int64(obj.extract()?),
+        CustomFieldType::UInt64 => CustomValue::UInt64(obj.extract()?),
+        CustomFieldType::Float64 => CustomValue::Float64(obj.extract()?),
+        CustomFieldType::Utf8 => CustomValue::Utf8(obj.extract()?),
+    })
+}
+
+fn custom_value_to_pyobject(py: Python<'_>, value: &CustomValue) -> PyObject {
+    match value {
+        CustomValue::Boolean(value) => value.into_py(py),
+        CustomValue::Int64(value) => value.into_py(py),
+        CustomValue::UInt64(value) => value.into_py(py),
+        CustomValue::Float64(value) => value.into_py(py),
+        CustomValue::Utf8(value) => value.into_py(py),
+    }
+}
+
+/// Registers the Arrow schema for a user-defined custom data type.
+///
+/// The `fields` dictionary maps field names to type names (one of `Boolean`, `Int64`,
+/// `UInt64`, `Float64`, `Utf8`), in column order.
+#[pyfunction]
+#[pyo3(name = "register_custom_data_type")]
+pub fn py_register_custom_data_type(data_type: &str, fields: &PyDict) -> PyResult<()> {
+    let mut schema = IndexMap::with_capacity(fields.len());
+    for (name, type_name) in fields {
+        let name: String = name.extract()?;
+        let type_name: &str = type_name.extract()?;
+        let field_type = CustomFieldType::from_str(type_name).map_err(to_pyvalue_err)?;
+        schema.insert(name, field_type);
+    }
+
+    register_custom_data_type(data_type, schema).map_err(to_pyvalue_err)
+}
+
+/// Returns the registered schema for the custom `data_type`, or `None` if not registered.
+#[pyfunction]
+#[pyo3(name = "custom_data_schema")]
+pub fn py_custom_data_schema(py: Python<'_>, data_type: &str) -> PyResult<Option<Py<PyDict>>> {
+    match get_custom_data_schema(data_type) {
+        Some(schema) => {
+            let py_dict = PyDict::new(py);
+            for (name, field_type) in schema {
+                py_dict.set_item(name, field_type.to_string())?;
+            }
+            Ok(Some(py_dict.into()))
+        }
+        None => Ok(None),
+    }
+}
+
+#[pymethods]
+impl CustomData {
+    #[new]
+    fn py_new(
+        data_type: &str,
+        values: &PyDict,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> PyResult<Self> {
+        let schema = get_custom_data_schema(data_type).ok_or_else(|| {
+            to_pyvalue_err(format!(
+                "No schema registered for custom data type `{data_type}`"
+            ))
+        })?;
+
+        let mut custom_values = IndexMap::with_capacity(values.len());
+        for (name, value) in values {
+            let name: String = name.extract()?;
+            let field_type = schema.get(&name).ok_or_else(|| {
+                to_pyvalue_err(format!("Unknown field `{name}` for `{data_type}`"))
+            })?;
+            custom_values.insert(name, pyobject_to_custom_value(value, *field_type)?);
+        }
+
+        Self::new(data_type, custom_values, ts_event, ts_init).map_err(to_pyvalue_err)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        let lhs = self.ts_event;
+        let rhs = other.ts_event;
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            CompareOp::Ne => self.ne(other).into_py(py),
+            CompareOp::Ge => (lhs >= rhs).into_py(py),
+            CompareOp::Gt => (lhs > rhs).into_py(py),
+            CompareOp::Le => (lhs <= rhs).into_py(py),
+            CompareOp::Lt => (lhs < rhs).into_py(py),
+        }
+    }
+
+    fn __getitem__(&self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
+        self.get(name)
+            .map(|value| custom_value_to_pyobject(py, value))
+            .ok_or_else(|| PyKeyError::new_err(name.to_string()))
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}({})", stringify!(CustomData), self)
+    }
+
+    #[getter]
+    #[pyo3(name = "data_type")]
+    fn py_data_type(&self) -> String {
+        self.data_type.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "values")]
+    fn py_values(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let py_dict = PyDict::new(py);
+        for (name, value) in &self.values {
+            py_dict.set_item(name, custom_value_to_pyobject(py, value))?;
+        }
+        Ok(py_dict.into())
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "fully_qualified_name")]
+    fn py_fully_qualified_name() -> String {
+        format!("{}:{}", PY_MODULE_MODEL, stringify!(CustomData))
+    }
+
+    /// Return a dictionary representation of the object.
+    #[pyo3(name = "as_dict")]
+    fn py_as_dict(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        // Serialize object to JSON bytes
+        let json_str = serde_json::to_string(self).map_err(to_pyvalue_err)?;
+        // Parse JSON into a Python dictionary
+        let py_dict: Py<PyDict> = PyModule::import(py, "json")?
+            .call_method("loads", (json_str,), None)?
+            .extract()?;
+        Ok(py_dict)
+    }
+
+    /// Return a new object from the given dictionary representation.
+    #[staticmethod]
+    #[pyo3(name = "from_dict")]
+    fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
+        from_dict_pyo3(py, values)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "get_metadata")]
+    fn py_get_metadata(data_type: &str) -> PyResult<HashMap<String, String>> {
+        Ok(Self::get_metadata(data_type))
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "get_fields")]
+    fn py_get_fields<'py>(py: Python<'py>, data_type: &str) -> PyResult<&'py PyDict> {
+        let py_dict = PyDict::new(py);
+        for (k, v) in Self::get_fields(data_type).map_err(to_pyvalue_err)? {
+            py_dict.set_item(k, v)?;
+        }
+
+        Ok(py_dict)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_json")]
+    fn py_from_json(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_json_bytes(data).map_err(to_pyvalue_err)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_msgpack")]
+    fn py_from_msgpack(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_msgpack_bytes(data).map_err(to_pyvalue_err)
+    }
+
+    /// Return JSON encoded bytes representation of the object.
+    #[pyo3(name = "as_json")]
+    fn py_as_json(&self, py: Python<'_>) -> Py<PyAny> {
+        // SAFETY: Unwrap safe when serializing a valid object
+        self.as_json_bytes().unwrap().into_py(py)
+    }
+
+    /// Return MsgPack encoded bytes representation of the object.
+    #[pyo3(name = "as_msgpack")]
+    fn py_as_msgpack(&self, py: Python<'_>) -> Py<PyAny> {
+        // SAFETY: Unwrap safe when serializing a valid object
+        self.as_msgpack_bytes().unwrap().into_py(py)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::custom::stubs::*;
+
+    #[rstest]
+    fn test_as_dict(stub_custom_data: CustomData) {
+        pyo3::prepare_freethreaded_python();
+        let data = stub_custom_data;
+
+        Python::with_gil(|py| {
+            let dict_string = data.py_as_dict(py).unwrap().to_string();
+            let expected_string = r#"{'type': 'CustomData', 'data_type': 'OnChainFlow', 'values': {'token': {'Utf8': 'ETH'}, 'inflow': {'Float64': 1250.5}, 'transfers': {'UInt64': 42}, 'is_exchange': {'Boolean': True}}, 'ts_event': 1, 'ts_init': 2}"#;
+            assert_eq!(dict_string, expected_string);
+        });
+    }
+
+    #[rstest]
+    fn test_from_dict(stub_custom_data: CustomData) {
+        pyo3::prepare_freethreaded_python();
+        let data = stub_custom_data;
+
+        Python::with_gil(|py| {
+            let dict = data.py_as_dict(py).unwrap();
+            let parsed = CustomData::py_from_dict(py, dict).unwrap();
+            assert_eq!(parsed, data);
+        });
+    }
+
+    #[rstest]
+    fn test_getitem(stub_custom_data: CustomData) {
+        pyo3::prepare_freethreaded_python();
+        let data = stub_custom_data;
+
+        Python::with_gil(|py| {
+            let inflow: f64 = data.__getitem__(py, "inflow").unwrap().extract(py).unwrap();
+            assert_eq!(inflow, 1250.5);
+            assert!(data.__getitem__(py, "missing").is_err());
+        });
+    }
+}
@@ -24,7 +24,11 @@ use nautilus_core::{
     serialization::Serializable,
     time::UnixNanos,
 };
-use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
+use pyo3::{
+    basic::CompareOp,
+    prelude::*,
+    types::{PyBytes, PyDict, PyTuple},
+};
 
 use super::data_to_pycapsule;
 use crate::{
@@ -36,7 +40,10 @@ use crate::{
     enums::{BookAction, FromU8, OrderSide},
     identifiers::instrument_id::InstrumentId,
     python::common::PY_MODULE_MODEL,
-    types::{price::Price, quantity::Quantity},
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 impl OrderBookDelta {
@@ -66,12 +73,12 @@ impl OrderBookDelta {
             let side = OrderSide::from_u8(side_u8).unwrap();
 
             let price_py: &PyAny = order_pyobject.getattr("price")?;
-            let price_raw: i64 = price_py.getattr("raw")?.extract()?;
+            let price_raw: PriceRaw = price_py.getattr("raw")?.extract()?;
             let price_prec: u8 = price_py.getattr("precision")?.extract()?;
             let price = Price::from_raw(price_raw, price_prec).map_err(to_pyvalue_err)?;
 
             let size_py: &PyAny = order_pyobject.getattr("size")?;
-            let size_raw: u64 = size_py.getattr("raw")?.extract()?;
+            let size_raw: QuantityRaw = size_py.getattr("raw")?.extract()?;
             let size_prec: u8 = size_py.getattr("precision")?.extract()?;
             let size = Quantity::from_raw(size_raw, size_prec).map_err(to_pyvalue_err)?;
 
@@ -119,11 +126,38 @@ impl OrderBookDelta {
         )
     }
 
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let bytes: &PyBytes = state.extract(py)?;
+        *self = Self::from_msgpack_bytes(bytes.as_bytes().to_vec()).map_err(to_pyvalue_err)?;
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        let bytes = self.as_msgpack_bytes().map_err(to_pyvalue_err)?;
+        Ok(PyBytes::new(py, &bytes).to_object(py))
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py)?;
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> PyResult<Self> {
+        Ok(Self::clear(InstrumentId::from("NULL.NULL"), 0, 0, 0)) // Safe default
+    }
+
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        let lhs = (self.ts_event, self.sequence);
+        let rhs = (other.ts_event, other.sequence);
         match op {
             CompareOp::Eq => self.eq(other).into_py(py),
             CompareOp::Ne => self.ne(other).into_py(py),
-            _ => py.NotImplemented(),
+            CompareOp::Ge => (lhs >= rhs).into_py(py),
+            CompareOp::Gt => (lhs > rhs).into_py(py),
+            CompareOp::Le => (lhs <= rhs).into_py(py),
+            CompareOp::Lt => (lhs < rhs).into_py(py),
         }
     }
 
@@ -19,8 +19,19 @@ use std::{
     ops::Deref,
 };
 
-use nautilus_core::time::UnixNanos;
-use pyo3::{prelude::*, pyclass::CompareOp, types::PyCapsule};
+#[cfg(feature = "ffi")]
+use nautilus_core::ffi::cvec::CVec;
+use nautilus_core::{
+    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+    serialization::Serializable,
+    time::UnixNanos,
+};
+use pyo3::{
+    exceptions::PyIndexError,
+    prelude::*,
+    pyclass::CompareOp,
+    types::{PyBytes, PyCapsule, PyDict, PyTuple},
+};
 
 use super::data_to_pycapsule;
 use crate::{
@@ -33,6 +44,52 @@ use crate::{
     python::common::PY_MODULE_MODEL,
 };
 
+/// Copies the `OrderBookDelta` buffer from a `PyCapsule` containing a `CVec`.
+///
+/// # Safety
+///
+/// The capsule must contain a valid pointer to a `CVec` of `OrderBookDelta` structs, which
+/// remains owned (and is deallocated) by the capsule creator.
+#[cfg(feature = "ffi")]
+fn deltas_from_cvec_pycapsule(capsule: &PyAny) -> PyResult<Vec<OrderBookDelta>> {
+    let capsule: &PyCapsule = capsule.downcast()?;
+    let cvec: &CVec = unsafe { &*(capsule.pointer() as *const CVec) };
+    if cvec.len == 0 {
+        return Ok(Vec::new());
+    }
+    let deltas: &[OrderBookDelta] =
+        unsafe { std::slice::from_raw_parts(cvec.ptr.cast::<OrderBookDelta>(), cvec.len) };
+    Ok(deltas.to_vec())
+}
+
+#[cfg(not(feature = "ffi"))]
+fn deltas_from_cvec_pycapsule(_capsule: &PyAny) -> PyResult<Vec<OrderBookDelta>> {
+    Err(nautilus_core::python::to_pyruntime_err(
+        "`ffi` feature is not enabled",
+    ))
+}
+
+/// Python iterator over the deltas of an [`OrderBookDeltas`], yielding each delta lazily
+/// rather than copying the full `deltas` vec up front.
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")]
+pub struct OrderBookDeltasIterator {
+    deltas: Py<OrderBookDeltas>,
+    index: usize,
+}
+
+#[pymethods]
+impl OrderBookDeltasIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>, py: Python<'_>) -> Option<OrderBookDelta> {
+        let delta = slf.deltas.borrow(py).deltas.get(slf.index).copied();
+        slf.index += 1;
+        delta
+    }
+}
+
 #[pymethods]
 impl OrderBookDeltas {
     #[new]
@@ -40,11 +97,40 @@ impl OrderBookDeltas {
         Self::new(instrument_id, deltas)
     }
 
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let bytes: &PyBytes = state.extract(py)?;
+        *self = Self::from_msgpack_bytes(bytes.as_bytes().to_vec()).map_err(to_pyvalue_err)?;
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        let bytes = self.as_msgpack_bytes().map_err(to_pyvalue_err)?;
+        Ok(PyBytes::new(py, &bytes).to_object(py))
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py)?;
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> PyResult<Self> {
+        let instrument_id = InstrumentId::from("NULL.NULL");
+        let deltas = vec![OrderBookDelta::clear(instrument_id, 0, 0, 0)];
+        Ok(Self::new(instrument_id, deltas)) // Safe default
+    }
+
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        let lhs = (self.ts_event, self.sequence);
+        let rhs = (other.ts_event, other.sequence);
         match op {
             CompareOp::Eq => self.eq(other).into_py(py),
             CompareOp::Ne => self.ne(other).into_py(py),
-            _ => py.NotImplemented(),
+            CompareOp::Ge => (lhs >= rhs).into_py(py),
+            CompareOp::Gt => (lhs > rhs).into_py(py),
+            CompareOp::Le => (lhs <= rhs).into_py(py),
+            CompareOp::Lt => (lhs < rhs).into_py(py),
         }
     }
 
@@ -54,6 +140,28 @@ impl OrderBookDeltas {
         h.finish() as isize
     }
 
+    fn __len__(&self) -> usize {
+        self.deltas.len()
+    }
+
+    fn __getitem__(&self, index: isize) -> PyResult<OrderBookDelta> {
+        let len = self.deltas.len() as isize;
+        let i = if index < 0 { index + len } else { index };
+        if i < 0 || i >= len {
+            return Err(PyIndexError::new_err(
+                "`OrderBookDeltas` index out of range",
+            ));
+        }
+        Ok(self.deltas[i as usize])
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> OrderBookDeltasIterator {
+        OrderBookDeltasIterator {
+            deltas: slf.into(),
+            index: 0,
+        }
+    }
+
     fn __str__(&self) -> String {
         self.to_string()
     }
@@ -75,6 +183,12 @@ impl OrderBookDeltas {
         self.deltas.clone()
     }
 
+    #[getter]
+    #[pyo3(name = "deltas_count")]
+    fn py_deltas_count(&self) -> usize {
+        self.deltas.len()
+    }
+
     #[getter]
     #[pyo3(name = "flags")]
     fn py_flags(&self) -> u8 {
@@ -99,6 +213,25 @@ impl OrderBookDeltas {
         self.ts_init
     }
 
+    #[staticmethod]
+    #[pyo3(name = "flatten")]
+    fn py_flatten(batches: Vec<Self>) -> PyResult<Self> {
+        Self::flatten(batches).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "split_by_flags")]
+    fn py_split_by_flags(&self) -> Vec<Self> {
+        self.split_by_flags()
+    }
+
+    #[pyo3(name = "split_by_size")]
+    fn py_split_by_size(&self, max_size: usize) -> PyResult<Vec<Self>> {
+        if max_size == 0 {
+            return Err(to_pyvalue_err("`max_size` must be positive"));
+        }
+        Ok(self.split_by_size(max_size))
+    }
+
     #[staticmethod]
     #[pyo3(name = "fully_qualified_name")]
     fn py_fully_qualified_name() -> String {
@@ -116,6 +249,24 @@ impl OrderBookDeltas {
         data.deref().clone()
     }
 
+    /// Creates a new [`OrderBookDeltas`] from a `PyCapsule` containing a `CVec` of raw
+    /// `OrderBookDelta` structs, without constructing intermediate Python objects.
+    #[staticmethod]
+    #[pyo3(name = "from_pycapsule_list")]
+    fn py_from_pycapsule_list(instrument_id: InstrumentId, capsule: &PyAny) -> PyResult<Self> {
+        let deltas = deltas_from_cvec_pycapsule(capsule)?;
+        if deltas.is_empty() {
+            return Err(to_pyvalue_err("`deltas` cannot be empty"));
+        }
+        if let Some(delta) = deltas.iter().find(|d| d.instrument_id != instrument_id) {
+            return Err(to_pyvalue_err(format!(
+                "`deltas` contains {} which does not match `instrument_id` {instrument_id}",
+                delta.instrument_id
+            )));
+        }
+        Ok(Self::new(instrument_id, deltas))
+    }
+
     /// Creates a `PyCapsule` containing a raw pointer to a [`Data::Deltas`] object.
     ///
     /// This function takes the current object (assumed to be of a type that can be represented as
@@ -138,5 +289,203 @@ impl OrderBookDeltas {
         data_to_pycapsule(py, Data::Deltas(deltas))
     }
 
-    // TODO: Implement `Serializable` and the other methods can be added
+    /// Return a dictionary representation of the object.
+    #[pyo3(name = "as_dict")]
+    fn py_as_dict(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        // Serialize object to JSON bytes
+        let json_str = serde_json::to_string(self).map_err(to_pyvalue_err)?;
+        // Parse JSON into a Python dictionary
+        let py_dict: Py<PyDict> = PyModule::import(py, "json")?
+            .call_method("loads", (json_str,), None)?
+            .extract()?;
+        Ok(py_dict)
+    }
+
+    /// Return a new object from the given dictionary representation.
+    #[staticmethod]
+    #[pyo3(name = "from_dict")]
+    fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
+        from_dict_pyo3(py, values)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_json")]
+    fn py_from_json(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_json_bytes(data).map_err(to_pyvalue_err)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_msgpack")]
+    fn py_from_msgpack(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_msgpack_bytes(data).map_err(to_pyvalue_err)
+    }
+
+    /// Return JSON encoded bytes representation of the object.
+    #[pyo3(name = "as_json")]
+    fn py_as_json(&self, py: Python<'_>) -> Py<PyAny> {
+        // Unwrapping is safe when serializing a valid object
+        self.as_json_bytes().unwrap().into_py(py)
+    }
+
+    /// Return MsgPack encoded bytes representation of the object.
+    #[pyo3(name = "as_msgpack")]
+    fn py_as_msgpack(&self, py: Python<'_>) -> Py<PyAny> {
+        // Unwrapping is safe when serializing a valid object
+        self.as_msgpack_bytes().unwrap().into_py(py)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::deltas::stubs::*;
+
+    #[rstest]
+    fn test_as_dict(stub_deltas: OrderBookDeltas) {
+        pyo3::prepare_freethreaded_python();
+        let deltas = stub_deltas;
+
+        Python::with_gil(|py| {
+            let dict = deltas.py_as_dict(py).unwrap();
+            let dict = dict.as_ref(py);
+            let type_str: String = dict.get_item("type").unwrap().unwrap().extract().unwrap();
+            let instrument_id: String = dict
+                .get_item("instrument_id")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(type_str, "OrderBookDeltas");
+            assert_eq!(instrument_id, "AAPL.XNAS");
+        });
+    }
+
+    #[rstest]
+    fn test_from_dict(stub_deltas: OrderBookDeltas) {
+        pyo3::prepare_freethreaded_python();
+        let deltas = stub_deltas;
+
+        Python::with_gil(|py| {
+            let dict = deltas.py_as_dict(py).unwrap();
+            let parsed = OrderBookDeltas::py_from_dict(py, dict).unwrap();
+            assert_eq!(parsed, deltas);
+            assert_eq!(parsed.deltas, deltas.deltas);
+        });
+    }
+
+    #[rstest]
+    fn test_richcmp_orders_by_ts_event_and_sequence(stub_deltas: OrderBookDeltas) {
+        pyo3::prepare_freethreaded_python();
+        let deltas1 = stub_deltas;
+        let mut deltas2 = deltas1.clone();
+        deltas2.sequence += 1;
+
+        Python::with_gil(|py| {
+            let lt: bool = deltas1
+                .__richcmp__(&deltas2, CompareOp::Lt, py)
+                .extract(py)
+                .unwrap();
+            let gt: bool = deltas2
+                .__richcmp__(&deltas1, CompareOp::Gt, py)
+                .extract(py)
+                .unwrap();
+            let ne: bool = deltas1
+                .__richcmp__(&deltas2, CompareOp::Ne, py)
+                .extract(py)
+                .unwrap();
+            assert!(lt);
+            assert!(gt);
+            assert!(ne);
+        });
+    }
+
+    #[rstest]
+    fn test_pickle_state_round_trip(stub_deltas: OrderBookDeltas) {
+        pyo3::prepare_freethreaded_python();
+        let deltas = stub_deltas;
+
+        Python::with_gil(|py| {
+            let state = deltas.__getstate__(py).unwrap();
+            let mut restored = OrderBookDeltas::_safe_constructor().unwrap();
+            restored.__setstate__(py, state).unwrap();
+            assert_eq!(restored, deltas);
+            assert_eq!(restored.deltas, deltas.deltas);
+        });
+    }
+
+    #[cfg(feature = "ffi")]
+    #[rstest]
+    fn test_from_pycapsule_list(stub_deltas: OrderBookDeltas) {
+        pyo3::prepare_freethreaded_python();
+        let deltas = stub_deltas;
+
+        Python::with_gil(|py| {
+            let cvec: CVec = deltas.deltas.clone().into();
+            let capsule = PyCapsule::new(py, cvec, None).unwrap();
+            let parsed =
+                OrderBookDeltas::py_from_pycapsule_list(deltas.instrument_id, capsule).unwrap();
+            assert_eq!(parsed, deltas);
+            assert_eq!(parsed.deltas, deltas.deltas);
+
+            let CVec { ptr, len, cap } = cvec;
+            let data: Vec<OrderBookDelta> =
+                unsafe { Vec::from_raw_parts(ptr.cast::<OrderBookDelta>(), len, cap) };
+            drop(data);
+        });
+    }
+
+    #[cfg(feature = "ffi")]
+    #[rstest]
+    fn test_from_pycapsule_list_with_mismatched_instrument_id(stub_deltas: OrderBookDeltas) {
+        pyo3::prepare_freethreaded_python();
+        let deltas = stub_deltas;
+
+        Python::with_gil(|py| {
+            let cvec: CVec = deltas.deltas.clone().into();
+            let capsule = PyCapsule::new(py, cvec, None).unwrap();
+            let result = OrderBookDeltas::py_from_pycapsule_list(
+                InstrumentId::from("ETHUSDT.BINANCE"),
+                capsule,
+            );
+            assert!(result.is_err());
+
+            let CVec { ptr, len, cap } = cvec;
+            let data: Vec<OrderBookDelta> =
+                unsafe { Vec::from_raw_parts(ptr.cast::<OrderBookDelta>(), len, cap) };
+            drop(data);
+        });
+    }
+
+    #[rstest]
+    fn test_len_and_getitem(stub_deltas: OrderBookDeltas) {
+        pyo3::prepare_freethreaded_python();
+        let deltas = stub_deltas;
+
+        assert_eq!(deltas.__len__(), 7);
+        assert_eq!(deltas.py_deltas_count(), 7);
+        assert_eq!(deltas.__getitem__(0).unwrap(), deltas.deltas[0]);
+        assert_eq!(deltas.__getitem__(-1).unwrap(), deltas.deltas[6]);
+        assert!(deltas.__getitem__(7).is_err());
+        assert!(deltas.__getitem__(-8).is_err());
+    }
+
+    #[rstest]
+    fn test_iter(stub_deltas: OrderBookDeltas) {
+        pyo3::prepare_freethreaded_python();
+        let expected = stub_deltas.deltas.clone();
+
+        Python::with_gil(|py| {
+            let deltas = Py::new(py, stub_deltas).unwrap();
+            let iter = deltas.as_ref(py).iter().unwrap();
+            let collected: Vec<OrderBookDelta> = iter
+                .map(|delta| delta.unwrap().extract().unwrap())
+                .collect();
+            assert_eq!(collected, expected);
+        });
+    }
 }
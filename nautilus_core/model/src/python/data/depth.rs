@@ -23,12 +23,16 @@ use nautilus_core::{
     serialization::Serializable,
     time::UnixNanos,
 };
-use pyo3::{prelude::*, pyclass::CompareOp, types::PyDict};
+use pyo3::{
+    prelude::*,
+    pyclass::CompareOp,
+    types::{PyBytes, PyDict, PyTuple},
+};
 
 use super::data_to_pycapsule;
 use crate::{
     data::{
-        depth::{OrderBookDepth10, DEPTH10_LEN},
+        depth::{OrderBookDepth10, OrderBookDepth25, DEPTH10_LEN, DEPTH25_LEN},
         order::BookOrder,
         Data,
     },
@@ -66,11 +70,48 @@ impl OrderBookDepth10 {
         )
     }
 
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let bytes: &PyBytes = state.extract(py)?;
+        *self = Self::from_msgpack_bytes(bytes.as_bytes().to_vec()).map_err(to_pyvalue_err)?;
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        let bytes = self.as_msgpack_bytes().map_err(to_pyvalue_err)?;
+        Ok(PyBytes::new(py, &bytes).to_object(py))
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py)?;
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> PyResult<Self> {
+        Ok(Self::new(
+            InstrumentId::from("NULL.NULL"),
+            [BookOrder::default(); DEPTH10_LEN],
+            [BookOrder::default(); DEPTH10_LEN],
+            [0; DEPTH10_LEN],
+            [0; DEPTH10_LEN],
+            0,
+            0,
+            0,
+            0,
+        )) // Safe default
+    }
+
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        let lhs = (self.ts_event, self.sequence);
+        let rhs = (other.ts_event, other.sequence);
         match op {
             CompareOp::Eq => self.eq(other).into_py(py),
             CompareOp::Ne => self.ne(other).into_py(py),
-            _ => py.NotImplemented(),
+            CompareOp::Ge => (lhs >= rhs).into_py(py),
+            CompareOp::Gt => (lhs > rhs).into_py(py),
+            CompareOp::Le => (lhs <= rhs).into_py(py),
+            CompareOp::Lt => (lhs < rhs).into_py(py),
         }
     }
 
@@ -304,3 +345,221 @@ impl OrderBookDepth10 {
         self.as_msgpack_bytes().unwrap().into_py(py)
     }
 }
+
+#[pymethods]
+impl OrderBookDepth25 {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    fn py_new(
+        instrument_id: InstrumentId,
+        bids: [BookOrder; DEPTH25_LEN],
+        asks: [BookOrder; DEPTH25_LEN],
+        bid_counts: [u32; DEPTH25_LEN],
+        ask_counts: [u32; DEPTH25_LEN],
+        flags: u8,
+        sequence: u64,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new(
+            instrument_id,
+            bids,
+            asks,
+            bid_counts,
+            ask_counts,
+            flags,
+            sequence,
+            ts_event,
+            ts_init,
+        )
+    }
+
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let bytes: &PyBytes = state.extract(py)?;
+        *self = Self::from_msgpack_bytes(bytes.as_bytes().to_vec()).map_err(to_pyvalue_err)?;
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        let bytes = self.as_msgpack_bytes().map_err(to_pyvalue_err)?;
+        Ok(PyBytes::new(py, &bytes).to_object(py))
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py)?;
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> PyResult<Self> {
+        Ok(Self::new(
+            InstrumentId::from("NULL.NULL"),
+            [BookOrder::default(); DEPTH25_LEN],
+            [BookOrder::default(); DEPTH25_LEN],
+            [0; DEPTH25_LEN],
+            [0; DEPTH25_LEN],
+            0,
+            0,
+            0,
+            0,
+        )) // Safe default
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        let lhs = (self.ts_event, self.sequence);
+        let rhs = (other.ts_event, other.sequence);
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            CompareOp::Ne => self.ne(other).into_py(py),
+            CompareOp::Ge => (lhs >= rhs).into_py(py),
+            CompareOp::Gt => (lhs > rhs).into_py(py),
+            CompareOp::Le => (lhs <= rhs).into_py(py),
+            CompareOp::Lt => (lhs < rhs).into_py(py),
+        }
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut h = DefaultHasher::new();
+        self.hash(&mut h);
+        h.finish() as isize
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "bids")]
+    fn py_bids(&self) -> [BookOrder; DEPTH25_LEN] {
+        self.bids
+    }
+
+    #[getter]
+    #[pyo3(name = "asks")]
+    fn py_asks(&self) -> [BookOrder; DEPTH25_LEN] {
+        self.asks
+    }
+
+    #[getter]
+    #[pyo3(name = "bid_counts")]
+    fn py_bid_counts(&self) -> [u32; DEPTH25_LEN] {
+        self.bid_counts
+    }
+
+    #[getter]
+    #[pyo3(name = "ask_counts")]
+    fn py_ask_counts(&self) -> [u32; DEPTH25_LEN] {
+        self.ask_counts
+    }
+
+    #[getter]
+    #[pyo3(name = "flags")]
+    fn py_flags(&self) -> u8 {
+        self.flags
+    }
+
+    #[getter]
+    #[pyo3(name = "sequence")]
+    fn py_sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "fully_qualified_name")]
+    fn py_fully_qualified_name() -> String {
+        format!("{}:{}", PY_MODULE_MODEL, stringify!(OrderBookDepth25))
+    }
+
+    /// Return a dictionary representation of the object.
+    #[pyo3(name = "as_dict")]
+    fn py_as_dict(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        // Serialize object to JSON bytes
+        let json_str = serde_json::to_string(self).map_err(to_pyvalue_err)?;
+        // Parse JSON into a Python dictionary
+        let py_dict: Py<PyDict> = PyModule::import(py, "json")?
+            .call_method("loads", (json_str,), None)?
+            .extract()?;
+        Ok(py_dict)
+    }
+
+    /// Return a new object from the given dictionary representation.
+    #[staticmethod]
+    #[pyo3(name = "from_dict")]
+    fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
+        from_dict_pyo3(py, values)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "get_metadata")]
+    fn py_get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> PyResult<HashMap<String, String>> {
+        Ok(Self::get_metadata(
+            instrument_id,
+            price_precision,
+            size_precision,
+        ))
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "get_fields")]
+    fn py_get_fields(py: Python<'_>) -> PyResult<&PyDict> {
+        let py_dict = PyDict::new(py);
+        for (k, v) in Self::get_fields() {
+            py_dict.set_item(k, v)?;
+        }
+
+        Ok(py_dict)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_json")]
+    fn py_from_json(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_json_bytes(data).map_err(to_pyvalue_err)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_msgpack")]
+    fn py_from_msgpack(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_msgpack_bytes(data).map_err(to_pyvalue_err)
+    }
+
+    /// Return JSON encoded bytes representation of the object.
+    #[pyo3(name = "as_json")]
+    fn py_as_json(&self, py: Python<'_>) -> Py<PyAny> {
+        // Unwrapping is safe when serializing a valid object
+        self.as_json_bytes().unwrap().into_py(py)
+    }
+
+    /// Return MsgPack encoded bytes representation of the object.
+    #[pyo3(name = "as_msgpack")]
+    fn py_as_msgpack(&self, py: Python<'_>) -> Py<PyAny> {
+        // Unwrapping is safe when serializing a valid object
+        self.as_msgpack_bytes().unwrap().into_py(py)
+    }
+}
@@ -0,0 +1,212 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+use nautilus_core::{
+    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+    serialization::Serializable,
+    time::UnixNanos,
+};
+use pyo3::{prelude::*, pyclass::CompareOp, types::PyDict};
+use rust_decimal::Decimal;
+
+use crate::{
+    data::funding::FundingRateUpdate, identifiers::instrument_id::InstrumentId,
+    python::common::PY_MODULE_MODEL,
+};
+
+#[pymethods]
+impl FundingRateUpdate {
+    #[new]
+    #[pyo3(signature = (instrument_id, rate, next_funding_ns, ts_event, ts_init))]
+    fn py_new(
+        instrument_id: InstrumentId,
+        rate: Decimal,
+        next_funding_ns: Option<UnixNanos>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new(instrument_id, rate, next_funding_ns, ts_event, ts_init)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        let lhs = self.ts_event;
+        let rhs = other.ts_event;
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            CompareOp::Ne => self.ne(other).into_py(py),
+            CompareOp::Ge => (lhs >= rhs).into_py(py),
+            CompareOp::Gt => (lhs > rhs).into_py(py),
+            CompareOp::Le => (lhs <= rhs).into_py(py),
+            CompareOp::Lt => (lhs < rhs).into_py(py),
+        }
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut h = DefaultHasher::new();
+        self.hash(&mut h);
+        h.finish() as isize
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}({})", stringify!(FundingRateUpdate), self)
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "rate")]
+    fn py_rate(&self) -> Decimal {
+        self.rate
+    }
+
+    #[getter]
+    #[pyo3(name = "next_funding_ns")]
+    fn py_next_funding_ns(&self) -> Option<UnixNanos> {
+        self.next_funding_ns
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    #[pyo3(name = "time_until_next_funding")]
+    fn py_time_until_next_funding(&self, ts_now: UnixNanos) -> Option<u64> {
+        self.time_until_next_funding(ts_now)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "fully_qualified_name")]
+    fn py_fully_qualified_name() -> String {
+        format!("{}:{}", PY_MODULE_MODEL, stringify!(FundingRateUpdate))
+    }
+
+    /// Return a dictionary representation of the object.
+    #[pyo3(name = "as_dict")]
+    fn py_as_dict(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        // Serialize object to JSON bytes
+        let json_str = serde_json::to_string(self).map_err(to_pyvalue_err)?;
+        // Parse JSON into a Python dictionary
+        let py_dict: Py<PyDict> = PyModule::import(py, "json")?
+            .call_method("loads", (json_str,), None)?
+            .extract()?;
+        Ok(py_dict)
+    }
+
+    /// Return a new object from the given dictionary representation.
+    #[staticmethod]
+    #[pyo3(name = "from_dict")]
+    fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
+        from_dict_pyo3(py, values)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "get_metadata")]
+    fn py_get_metadata(instrument_id: &InstrumentId) -> PyResult<HashMap<String, String>> {
+        Ok(Self::get_metadata(instrument_id))
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "get_fields")]
+    fn py_get_fields(py: Python<'_>) -> PyResult<&PyDict> {
+        let py_dict = PyDict::new(py);
+        for (k, v) in Self::get_fields() {
+            py_dict.set_item(k, v)?;
+        }
+
+        Ok(py_dict)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_json")]
+    fn py_from_json(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_json_bytes(data).map_err(to_pyvalue_err)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_msgpack")]
+    fn py_from_msgpack(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_msgpack_bytes(data).map_err(to_pyvalue_err)
+    }
+
+    /// Return JSON encoded bytes representation of the object.
+    #[pyo3(name = "as_json")]
+    fn py_as_json(&self, py: Python<'_>) -> Py<PyAny> {
+        // SAFETY: Unwrap safe when serializing a valid object
+        self.as_json_bytes().unwrap().into_py(py)
+    }
+
+    /// Return MsgPack encoded bytes representation of the object.
+    #[pyo3(name = "as_msgpack")]
+    fn py_as_msgpack(&self, py: Python<'_>) -> Py<PyAny> {
+        // SAFETY: Unwrap safe when serializing a valid object
+        self.as_msgpack_bytes().unwrap().into_py(py)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::funding::stubs::*;
+
+    #[rstest]
+    fn test_as_dict(stub_funding_rate_update: FundingRateUpdate) {
+        pyo3::prepare_freethreaded_python();
+        let update = stub_funding_rate_update;
+
+        Python::with_gil(|py| {
+            let dict_string = update.py_as_dict(py).unwrap().to_string();
+            let expected_string = r"{'type': 'FundingRateUpdate', 'instrument_id': 'BTCUSDT-PERP.BINANCE', 'rate': '0.0001', 'next_funding_ns': 28800000000000, 'ts_event': 1, 'ts_init': 2}";
+            assert_eq!(dict_string, expected_string);
+        });
+    }
+
+    #[rstest]
+    fn test_from_dict(stub_funding_rate_update: FundingRateUpdate) {
+        pyo3::prepare_freethreaded_python();
+        let update = stub_funding_rate_update;
+
+        Python::with_gil(|py| {
+            let dict = update.py_as_dict(py).unwrap();
+            let parsed = FundingRateUpdate::py_from_dict(py, dict).unwrap();
+            assert_eq!(parsed, update);
+        });
+    }
+}
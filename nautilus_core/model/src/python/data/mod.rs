@@ -14,12 +14,19 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod bar;
+pub mod custom;
 pub mod delta;
 pub mod deltas;
 pub mod depth;
+pub mod funding;
+pub mod greeks;
+pub mod liquidation;
+pub mod open_interest;
 pub mod order;
+pub mod prices;
 pub mod quote;
 pub mod trade;
+pub mod trades;
 
 #[cfg(feature = "ffi")]
 use nautilus_core::ffi::cvec::CVec;
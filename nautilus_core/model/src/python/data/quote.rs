@@ -36,7 +36,10 @@ use crate::{
     enums::PriceType,
     identifiers::instrument_id::InstrumentId,
     python::common::PY_MODULE_MODEL,
-    types::{price::Price, quantity::Quantity},
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 impl QuoteTick {
@@ -47,22 +50,22 @@ impl QuoteTick {
         let instrument_id = InstrumentId::from_str(instrument_id_str).map_err(to_pyvalue_err)?;
 
         let bid_price_py: &PyAny = obj.getattr("bid_price")?;
-        let bid_price_raw: i64 = bid_price_py.getattr("raw")?.extract()?;
+        let bid_price_raw: PriceRaw = bid_price_py.getattr("raw")?.extract()?;
         let bid_price_prec: u8 = bid_price_py.getattr("precision")?.extract()?;
         let bid_price = Price::from_raw(bid_price_raw, bid_price_prec).map_err(to_pyvalue_err)?;
 
         let ask_price_py: &PyAny = obj.getattr("ask_price")?;
-        let ask_price_raw: i64 = ask_price_py.getattr("raw")?.extract()?;
+        let ask_price_raw: PriceRaw = ask_price_py.getattr("raw")?.extract()?;
         let ask_price_prec: u8 = ask_price_py.getattr("precision")?.extract()?;
         let ask_price = Price::from_raw(ask_price_raw, ask_price_prec).map_err(to_pyvalue_err)?;
 
         let bid_size_py: &PyAny = obj.getattr("bid_size")?;
-        let bid_size_raw: u64 = bid_size_py.getattr("raw")?.extract()?;
+        let bid_size_raw: QuantityRaw = bid_size_py.getattr("raw")?.extract()?;
         let bid_size_prec: u8 = bid_size_py.getattr("precision")?.extract()?;
         let bid_size = Quantity::from_raw(bid_size_raw, bid_size_prec).map_err(to_pyvalue_err)?;
 
         let ask_size_py: &PyAny = obj.getattr("ask_size")?;
-        let ask_size_raw: u64 = ask_size_py.getattr("raw")?.extract()?;
+        let ask_size_raw: QuantityRaw = ask_size_py.getattr("raw")?.extract()?;
         let ask_size_prec: u8 = ask_size_py.getattr("precision")?.extract()?;
         let ask_size = Quantity::from_raw(ask_size_raw, ask_size_prec).map_err(to_pyvalue_err)?;
 
@@ -180,10 +183,15 @@ impl QuoteTick {
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        let lhs = self.ts_event;
+        let rhs = other.ts_event;
         match op {
             CompareOp::Eq => self.eq(other).into_py(py),
             CompareOp::Ne => self.ne(other).into_py(py),
-            _ => py.NotImplemented(),
+            CompareOp::Ge => (lhs >= rhs).into_py(py),
+            CompareOp::Gt => (lhs > rhs).into_py(py),
+            CompareOp::Le => (lhs <= rhs).into_py(py),
+            CompareOp::Lt => (lhs < rhs).into_py(py),
         }
     }
 
@@ -298,12 +306,12 @@ impl QuoteTick {
     fn py_from_raw(
         _py: Python<'_>,
         instrument_id: InstrumentId,
-        bid_price_raw: i64,
-        ask_price_raw: i64,
+        bid_price_raw: PriceRaw,
+        ask_price_raw: PriceRaw,
         bid_price_prec: u8,
         ask_price_prec: u8,
-        bid_size_raw: u64,
-        ask_size_raw: u64,
+        bid_size_raw: QuantityRaw,
+        ask_size_raw: QuantityRaw,
         bid_size_prec: u8,
         ask_size_prec: u8,
         ts_event: UnixNanos,
@@ -385,7 +393,7 @@ impl QuoteTick {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use pyo3::{IntoPy, Python};
+    use pyo3::{pyclass::CompareOp, IntoPy, Python};
     use rstest::rstest;
 
     use crate::data::quote::{stubs::*, QuoteTick};
@@ -425,4 +433,30 @@ mod tests {
             assert_eq!(parsed_tick, tick);
         });
     }
+
+    #[rstest]
+    fn test_richcmp_orders_by_ts_event(quote_tick_ethusdt_binance: QuoteTick) {
+        pyo3::prepare_freethreaded_python();
+        let tick1 = quote_tick_ethusdt_binance;
+        let mut tick2 = tick1;
+        tick2.ts_event = 1;
+
+        Python::with_gil(|py| {
+            let lt: bool = tick1
+                .__richcmp__(&tick2, CompareOp::Lt, py)
+                .extract(py)
+                .unwrap();
+            let ge: bool = tick1
+                .__richcmp__(&tick2, CompareOp::Ge, py)
+                .extract(py)
+                .unwrap();
+            let eq: bool = tick1
+                .__richcmp__(&tick1, CompareOp::Eq, py)
+                .extract(py)
+                .unwrap();
+            assert!(lt);
+            assert!(!ge);
+            assert!(eq);
+        });
+    }
 }
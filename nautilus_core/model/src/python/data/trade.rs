@@ -36,7 +36,10 @@ use crate::{
     enums::{AggressorSide, FromU8},
     identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
     python::common::PY_MODULE_MODEL,
-    types::{price::Price, quantity::Quantity},
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 impl TradeTick {
@@ -47,12 +50,12 @@ impl TradeTick {
         let instrument_id = InstrumentId::from_str(instrument_id_str).map_err(to_pyvalue_err)?;
 
         let price_py: &PyAny = obj.getattr("price")?;
-        let price_raw: i64 = price_py.getattr("raw")?.extract()?;
+        let price_raw: PriceRaw = price_py.getattr("raw")?.extract()?;
         let price_prec: u8 = price_py.getattr("precision")?.extract()?;
         let price = Price::from_raw(price_raw, price_prec).map_err(to_pyvalue_err)?;
 
         let size_py: &PyAny = obj.getattr("size")?;
-        let size_raw: u64 = size_py.getattr("raw")?.extract()?;
+        let size_raw: QuantityRaw = size_py.getattr("raw")?.extract()?;
         let size_prec: u8 = size_py.getattr("precision")?.extract()?;
         let size = Quantity::from_raw(size_raw, size_prec).map_err(to_pyvalue_err)?;
 
@@ -168,10 +171,15 @@ impl TradeTick {
         // Safe default
     }
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        let lhs = self.ts_event;
+        let rhs = other.ts_event;
         match op {
             CompareOp::Eq => self.eq(other).into_py(py),
             CompareOp::Ne => self.ne(other).into_py(py),
-            _ => py.NotImplemented(),
+            CompareOp::Ge => (lhs >= rhs).into_py(py),
+            CompareOp::Gt => (lhs > rhs).into_py(py),
+            CompareOp::Le => (lhs <= rhs).into_py(py),
+            CompareOp::Lt => (lhs < rhs).into_py(py),
         }
     }
 
@@ -0,0 +1,310 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+use nautilus_core::{
+    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+    serialization::Serializable,
+    time::UnixNanos,
+};
+use pyo3::{
+    exceptions::PyIndexError,
+    prelude::*,
+    pyclass::CompareOp,
+    types::{PyBytes, PyCapsule, PyDict, PyTuple},
+};
+
+use super::data_to_pycapsule;
+use crate::{
+    data::{
+        trade::TradeTick,
+        trades::{TradeTicks, TradeTicks_API},
+        Data,
+    },
+    enums::AggressorSide,
+    identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    python::common::PY_MODULE_MODEL,
+    types::{price::Price, quantity::Quantity},
+};
+
+#[pymethods]
+impl TradeTicks {
+    #[new]
+    fn py_new(
+        instrument_id: InstrumentId,
+        trades: Vec<TradeTick>,
+        flags: Option<u8>,
+        sequence: Option<u64>,
+    ) -> Self {
+        Self::new(
+            instrument_id,
+            trades,
+            flags.unwrap_or(0),
+            sequence.unwrap_or(0),
+        )
+    }
+
+    fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let bytes: &PyBytes = state.extract(py)?;
+        *self = Self::from_msgpack_bytes(bytes.as_bytes().to_vec()).map_err(to_pyvalue_err)?;
+        Ok(())
+    }
+
+    fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        let bytes = self.as_msgpack_bytes().map_err(to_pyvalue_err)?;
+        Ok(PyBytes::new(py, &bytes).to_object(py))
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
+        let safe_constructor = py.get_type::<Self>().getattr("_safe_constructor")?;
+        let state = self.__getstate__(py)?;
+        Ok((safe_constructor, PyTuple::empty(py), state).to_object(py))
+    }
+
+    #[staticmethod]
+    fn _safe_constructor() -> PyResult<Self> {
+        let instrument_id = InstrumentId::from("NULL.NULL");
+        let trade = TradeTick::new(
+            instrument_id,
+            Price::zero(0),
+            Quantity::zero(0),
+            AggressorSide::NoAggressor,
+            TradeId::from("NULL"),
+            0,
+            0,
+        );
+        Ok(Self::new(instrument_id, vec![trade], 0, 0)) // Safe default
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        let lhs = (self.ts_event, self.sequence);
+        let rhs = (other.ts_event, other.sequence);
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            CompareOp::Ne => self.ne(other).into_py(py),
+            CompareOp::Ge => (lhs >= rhs).into_py(py),
+            CompareOp::Gt => (lhs > rhs).into_py(py),
+            CompareOp::Le => (lhs <= rhs).into_py(py),
+            CompareOp::Lt => (lhs < rhs).into_py(py),
+        }
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut h = DefaultHasher::new();
+        self.hash(&mut h);
+        h.finish() as isize
+    }
+
+    fn __len__(&self) -> usize {
+        self.trades.len()
+    }
+
+    fn __getitem__(&self, index: isize) -> PyResult<TradeTick> {
+        let len = self.trades.len() as isize;
+        let i = if index < 0 { index + len } else { index };
+        if i < 0 || i >= len {
+            return Err(PyIndexError::new_err("`TradeTicks` index out of range"));
+        }
+        Ok(self.trades[i as usize])
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "trades")]
+    fn py_trades(&self) -> Vec<TradeTick> {
+        self.trades.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "flags")]
+    fn py_flags(&self) -> u8 {
+        self.flags
+    }
+
+    #[getter]
+    #[pyo3(name = "sequence")]
+    fn py_sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "fully_qualified_name")]
+    fn py_fully_qualified_name() -> String {
+        format!("{}:{}", PY_MODULE_MODEL, stringify!(TradeTicks))
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_pycapsule")]
+    pub fn py_from_pycapsule(capsule: &PyAny) -> Self {
+        let capsule: &PyCapsule = capsule
+            .downcast()
+            .expect("Error on downcast to `&PyCapsule`");
+        let data: &TradeTicks_API = unsafe { &*(capsule.pointer() as *const TradeTicks_API) };
+        data.deref().clone()
+    }
+
+    /// Creates a `PyCapsule` containing a raw pointer to a [`Data::Trades`] object.
+    ///
+    /// This function takes the current object (assumed to be of a type that can be represented as
+    /// `Data::Trades`), and encapsulates a raw pointer to it within a `PyCapsule`.
+    ///
+    /// # Safety
+    ///
+    /// This function is safe as long as the following conditions are met:
+    /// - The `Data::Trades` object pointed to by the capsule must remain valid for the lifetime of the capsule.
+    /// - The consumer of the capsule must ensure proper handling to avoid dereferencing a dangling pointer.
+    ///
+    /// # Panics
+    ///
+    /// The function will panic if the `PyCapsule` creation fails, which can occur if the
+    /// [`Data::Trades`] object cannot be converted into a raw pointer.
+    ///
+    #[pyo3(name = "as_pycapsule")]
+    fn py_as_pycapsule(&self, py: Python<'_>) -> PyObject {
+        let trades = TradeTicks_API::new(self.clone());
+        data_to_pycapsule(py, Data::Trades(trades))
+    }
+
+    /// Return a dictionary representation of the object.
+    #[pyo3(name = "as_dict")]
+    fn py_as_dict(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        // Serialize object to JSON bytes
+        let json_str = serde_json::to_string(self).map_err(to_pyvalue_err)?;
+        // Parse JSON into a Python dictionary
+        let py_dict: Py<PyDict> = PyModule::import(py, "json")?
+            .call_method("loads", (json_str,), None)?
+            .extract()?;
+        Ok(py_dict)
+    }
+
+    /// Return a new object from the given dictionary representation.
+    #[staticmethod]
+    #[pyo3(name = "from_dict")]
+    fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
+        from_dict_pyo3(py, values)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_json")]
+    fn py_from_json(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_json_bytes(data).map_err(to_pyvalue_err)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_msgpack")]
+    fn py_from_msgpack(data: Vec<u8>) -> PyResult<Self> {
+        Self::from_msgpack_bytes(data).map_err(to_pyvalue_err)
+    }
+
+    /// Return JSON encoded bytes representation of the object.
+    #[pyo3(name = "as_json")]
+    fn py_as_json(&self, py: Python<'_>) -> Py<PyAny> {
+        // Unwrapping is safe when serializing a valid object
+        self.as_json_bytes().unwrap().into_py(py)
+    }
+
+    /// Return MsgPack encoded bytes representation of the object.
+    #[pyo3(name = "as_msgpack")]
+    fn py_as_msgpack(&self, py: Python<'_>) -> Py<PyAny> {
+        // Unwrapping is safe when serializing a valid object
+        self.as_msgpack_bytes().unwrap().into_py(py)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::trades::stubs::*;
+
+    #[rstest]
+    fn test_as_dict(stub_trade_ticks: TradeTicks) {
+        pyo3::prepare_freethreaded_python();
+        let trades = stub_trade_ticks;
+
+        Python::with_gil(|py| {
+            let dict = trades.py_as_dict(py).unwrap();
+            let dict = dict.as_ref(py);
+            let type_str: String = dict.get_item("type").unwrap().unwrap().extract().unwrap();
+            let instrument_id: String = dict
+                .get_item("instrument_id")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(type_str, "TradeTicks");
+            assert_eq!(instrument_id, "ETHUSDT-PERP.BINANCE");
+        });
+    }
+
+    #[rstest]
+    fn test_from_dict(stub_trade_ticks: TradeTicks) {
+        pyo3::prepare_freethreaded_python();
+        let trades = stub_trade_ticks;
+
+        Python::with_gil(|py| {
+            let dict = trades.py_as_dict(py).unwrap();
+            let parsed = TradeTicks::py_from_dict(py, dict).unwrap();
+            assert_eq!(parsed, trades);
+            assert_eq!(parsed.trades, trades.trades);
+        });
+    }
+
+    #[rstest]
+    fn test_from_pycapsule(stub_trade_ticks: TradeTicks) {
+        pyo3::prepare_freethreaded_python();
+        let trades = stub_trade_ticks;
+
+        Python::with_gil(|py| {
+            let capsule = PyCapsule::new(py, TradeTicks_API::new(trades.clone()), None).unwrap();
+            let parsed = TradeTicks::py_from_pycapsule(capsule);
+            assert_eq!(parsed, trades);
+        });
+    }
+}
@@ -20,10 +20,11 @@ use pyo3::{prelude::*, types::PyType, PyTypeInfo};
 
 use crate::{
     enums::{
-        AccountType, AggregationSource, AggressorSide, AssetClass, BarAggregation, BookAction,
-        BookType, ContingencyType, CurrencyType, HaltReason, InstrumentClass, InstrumentCloseType,
-        LiquiditySide, MarketStatus, OmsType, OptionKind, OrderSide, OrderStatus, OrderType,
-        PositionSide, PriceType, TimeInForce, TradingState, TrailingOffsetType, TriggerType,
+        AccountType, AggregationSource, AggressorSide, AssetClass, AuctionPhase, BarAggregation,
+        BookAction, BookType, ContingencyType, CurrencyType, HaltReason, InstrumentClass,
+        InstrumentCloseType, LiquiditySide, MarketStatus, OmsType, OptionKind, OrderSide,
+        OrderStatus, OrderType, PegPriceType, PositionSide, PriceType, RoundingMode, TimeInForce,
+        TradingState, TrailingOffsetType, TriggerType,
     },
     python::common::EnumIterator,
 };
@@ -425,6 +426,12 @@ impl InstrumentClass {
     fn py_sports_betting() -> Self {
         Self::SportsBetting
     }
+
+    #[classattr]
+    #[pyo3(name = "BINARY_OPTION")]
+    fn py_binary_option() -> Self {
+        Self::BinaryOption
+    }
 }
 
 #[pymethods]
@@ -571,6 +578,18 @@ impl BarAggregation {
     fn py_month() -> Self {
         Self::Month
     }
+
+    #[classattr]
+    #[pyo3(name = "RENKO")]
+    fn py_renko() -> Self {
+        Self::Renko
+    }
+
+    #[classattr]
+    #[pyo3(name = "RANGE")]
+    fn py_range() -> Self {
+        Self::Range
+    }
 }
 
 #[pymethods]
@@ -1015,6 +1034,12 @@ impl MarketStatus {
     fn py_closed() -> Self {
         Self::Closed
     }
+
+    #[classattr]
+    #[pyo3(name = "AUCTION")]
+    fn py_auction() -> Self {
+        Self::Auction
+    }
 }
 
 #[pymethods]
@@ -1084,6 +1109,99 @@ impl HaltReason {
     fn py_volatility() -> Self {
         Self::Volatility
     }
+
+    #[classattr]
+    #[pyo3(name = "LIMIT_UP_LIMIT_DOWN")]
+    fn py_limit_up_limit_down() -> Self {
+        Self::LimitUpLimitDown
+    }
+
+    #[classattr]
+    #[pyo3(name = "CIRCUIT_BREAKER")]
+    fn py_circuit_breaker() -> Self {
+        Self::CircuitBreaker
+    }
+}
+
+#[pymethods]
+impl AuctionPhase {
+    #[new]
+    fn py_new(py: Python<'_>, value: &PyAny) -> PyResult<Self> {
+        let t = Self::type_object(py);
+        Self::py_from_str(t, value)
+    }
+
+    fn __hash__(&self) -> isize {
+        *self as isize
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<{}.{}: '{}'>",
+            stringify!(AuctionPhase),
+            self.name(),
+            self.value(),
+        )
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    #[classmethod]
+    fn variants(_: &PyType, py: Python<'_>) -> EnumIterator {
+        EnumIterator::new::<Self>(py)
+    }
+
+    #[classmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(_: &PyType, data: &PyAny) -> PyResult<Self> {
+        let data_str: &str = data.str().and_then(|s| s.extract())?;
+        let tokenized = data_str.to_uppercase();
+        Self::from_str(&tokenized).map_err(to_pyvalue_err)
+    }
+
+    #[classattr]
+    #[pyo3(name = "NO_AUCTION")]
+    fn py_no_auction() -> Self {
+        Self::NoAuction
+    }
+
+    #[classattr]
+    #[pyo3(name = "OPENING")]
+    fn py_opening() -> Self {
+        Self::Opening
+    }
+
+    #[classattr]
+    #[pyo3(name = "CLOSING")]
+    fn py_closing() -> Self {
+        Self::Closing
+    }
+
+    #[classattr]
+    #[pyo3(name = "INTRADAY")]
+    fn py_intraday() -> Self {
+        Self::Intraday
+    }
+
+    #[classattr]
+    #[pyo3(name = "VOLATILITY_INTERRUPTION")]
+    fn py_volatility_interruption() -> Self {
+        Self::VolatilityInterruption
+    }
 }
 
 #[pymethods]
@@ -1527,6 +1645,75 @@ impl OrderType {
     }
 }
 
+#[pymethods]
+impl PegPriceType {
+    #[new]
+    fn py_new(py: Python<'_>, value: &PyAny) -> PyResult<Self> {
+        let t = Self::type_object(py);
+        Self::py_from_str(t, value)
+    }
+
+    fn __hash__(&self) -> isize {
+        *self as isize
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<{}.{}: '{}'>",
+            stringify!(PegPriceType),
+            self.name(),
+            self.value(),
+        )
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    #[classmethod]
+    fn variants(_: &PyType, py: Python<'_>) -> EnumIterator {
+        EnumIterator::new::<Self>(py)
+    }
+
+    #[classmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(_: &PyType, data: &PyAny) -> PyResult<Self> {
+        let data_str: &str = data.str().and_then(|s| s.extract())?;
+        let tokenized = data_str.to_uppercase();
+        Self::from_str(&tokenized).map_err(to_pyvalue_err)
+    }
+
+    #[classattr]
+    #[pyo3(name = "NO_PEG")]
+    fn py_no_peg() -> Self {
+        Self::NoPeg
+    }
+
+    #[classattr]
+    #[pyo3(name = "MID_POINT")]
+    fn py_mid_point() -> Self {
+        Self::MidPoint
+    }
+
+    #[classattr]
+    #[pyo3(name = "PRIMARY")]
+    fn py_primary() -> Self {
+        Self::Primary
+    }
+}
+
 #[pymethods]
 impl PositionSide {
     #[new]
@@ -1629,6 +1816,99 @@ impl PriceType {
     }
 }
 
+#[pymethods]
+impl RoundingMode {
+    #[new]
+    fn py_new(py: Python<'_>, value: &PyAny) -> PyResult<Self> {
+        let t = Self::type_object(py);
+        Self::py_from_str(t, value)
+    }
+
+    fn __hash__(&self) -> isize {
+        *self as isize
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<{}.{}: '{}'>",
+            stringify!(RoundingMode),
+            self.name(),
+            self.value(),
+        )
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    #[classmethod]
+    fn variants(_: &PyType, py: Python<'_>) -> EnumIterator {
+        EnumIterator::new::<Self>(py)
+    }
+
+    #[classmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(_: &PyType, data: &PyAny) -> PyResult<Self> {
+        let data_str: &str = data.str().and_then(|s| s.extract())?;
+        let tokenized = data_str.to_uppercase();
+        Self::from_str(&tokenized).map_err(to_pyvalue_err)
+    }
+
+    #[classattr]
+    #[pyo3(name = "UP")]
+    fn py_up() -> Self {
+        Self::Up
+    }
+
+    #[classattr]
+    #[pyo3(name = "DOWN")]
+    fn py_down() -> Self {
+        Self::Down
+    }
+
+    #[classattr]
+    #[pyo3(name = "CEILING")]
+    fn py_ceiling() -> Self {
+        Self::Ceiling
+    }
+
+    #[classattr]
+    #[pyo3(name = "FLOOR")]
+    fn py_floor() -> Self {
+        Self::Floor
+    }
+
+    #[classattr]
+    #[pyo3(name = "HALF_UP")]
+    fn py_half_up() -> Self {
+        Self::HalfUp
+    }
+
+    #[classattr]
+    #[pyo3(name = "HALF_DOWN")]
+    fn py_half_down() -> Self {
+        Self::HalfDown
+    }
+
+    #[classattr]
+    #[pyo3(name = "HALF_EVEN")]
+    fn py_half_even() -> Self {
+        Self::HalfEven
+    }
+}
+
 #[pymethods]
 impl TimeInForce {
     #[new]
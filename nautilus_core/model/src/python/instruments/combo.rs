@@ -0,0 +1,327 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+use nautilus_core::{
+    python::{serialization::from_dict_pyo3, to_pyvalue_err},
+    time::UnixNanos,
+};
+use pyo3::{
+    basic::CompareOp,
+    prelude::*,
+    types::{PyDict, PyList},
+};
+use ustr::Ustr;
+
+use crate::{
+    data::quote::QuoteTick,
+    enums::{AssetClass, InstrumentClass, OrderSide},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    instruments::combo::{ComboInstrument, ComboLeg},
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+
+#[pymethods]
+impl ComboLeg {
+    #[new]
+    fn py_new(instrument_id: InstrumentId, ratio: i64) -> Self {
+        Self::new(instrument_id, ratio)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            CompareOp::Ne => self.ne(other).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish() as isize
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "ratio")]
+    fn py_ratio(&self) -> i64 {
+        self.ratio
+    }
+
+    #[pyo3(name = "side_for")]
+    fn py_side_for(&self, side: OrderSide) -> OrderSide {
+        self.side_for(side)
+    }
+}
+
+#[pymethods]
+impl ComboInstrument {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    fn py_new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        asset_class: AssetClass,
+        instrument_class: InstrumentClass,
+        legs: Vec<ComboLeg>,
+        currency: Currency,
+        price_precision: u8,
+        price_increment: Price,
+        multiplier: Quantity,
+        lot_size: Quantity,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        exchange: Option<String>,
+    ) -> PyResult<Self> {
+        Self::new(
+            id,
+            raw_symbol,
+            asset_class,
+            instrument_class,
+            exchange.map(|e| Ustr::from(&e)),
+            legs,
+            currency,
+            price_precision,
+            price_increment,
+            multiplier,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        )
+        .map_err(to_pyvalue_err)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            _ => panic!("Not implemented"),
+        }
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish() as isize
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_type")]
+    fn py_instrument_type(&self) -> &str {
+        stringify!(ComboInstrument)
+    }
+
+    #[getter]
+    #[pyo3(name = "id")]
+    fn py_id(&self) -> InstrumentId {
+        self.id
+    }
+
+    #[getter]
+    #[pyo3(name = "raw_symbol")]
+    fn py_raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    #[getter]
+    #[pyo3(name = "asset_class")]
+    fn py_asset_class(&self) -> AssetClass {
+        self.asset_class
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_class")]
+    fn py_instrument_class(&self) -> InstrumentClass {
+        self.instrument_class
+    }
+
+    #[getter]
+    #[pyo3(name = "exchange")]
+    fn py_exchange(&self) -> Option<String> {
+        self.exchange.map(|e| e.to_string())
+    }
+
+    #[getter]
+    #[pyo3(name = "legs")]
+    fn py_legs(&self) -> Vec<ComboLeg> {
+        self.legs.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "currency")]
+    fn py_currency(&self) -> Currency {
+        self.currency
+    }
+
+    #[getter]
+    #[pyo3(name = "price_precision")]
+    fn py_price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    #[getter]
+    #[pyo3(name = "price_increment")]
+    fn py_price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    #[getter]
+    #[pyo3(name = "multiplier")]
+    fn py_multiplier(&self) -> Quantity {
+        self.multiplier
+    }
+
+    #[getter]
+    #[pyo3(name = "lot_size")]
+    fn py_lot_size(&self) -> Quantity {
+        self.lot_size
+    }
+
+    #[getter]
+    #[pyo3(name = "max_quantity")]
+    fn py_max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "min_quantity")]
+    fn py_min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "max_price")]
+    fn py_max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    #[getter]
+    #[pyo3(name = "min_price")]
+    fn py_min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    #[pyo3(name = "leg_ids")]
+    fn py_leg_ids(&self) -> Vec<InstrumentId> {
+        self.leg_ids()
+    }
+
+    #[pyo3(name = "calculate_price")]
+    fn py_calculate_price(&self, leg_prices: HashMap<InstrumentId, Price>) -> PyResult<Price> {
+        self.calculate_price(&leg_prices).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "calculate_quote")]
+    fn py_calculate_quote(
+        &self,
+        leg_quotes: HashMap<InstrumentId, QuoteTick>,
+    ) -> PyResult<(Price, Price)> {
+        self.calculate_quote(&leg_quotes).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "leg_orders")]
+    fn py_leg_orders(
+        &self,
+        side: OrderSide,
+        quantity: Quantity,
+    ) -> Vec<(InstrumentId, OrderSide, Quantity)> {
+        self.leg_orders(side, quantity)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_dict")]
+    fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
+        from_dict_pyo3(py, values)
+    }
+
+    #[pyo3(name = "to_dict")]
+    fn py_to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("type", stringify!(ComboInstrument))?;
+        dict.set_item("id", self.id.to_string())?;
+        dict.set_item("raw_symbol", self.raw_symbol.to_string())?;
+        dict.set_item("asset_class", self.asset_class.to_string())?;
+        dict.set_item("instrument_class", self.instrument_class.to_string())?;
+        let legs = PyList::empty(py);
+        for leg in &self.legs {
+            let leg_dict = PyDict::new(py);
+            leg_dict.set_item("instrument_id", leg.instrument_id.to_string())?;
+            leg_dict.set_item("ratio", leg.ratio)?;
+            legs.append(leg_dict)?;
+        }
+        dict.set_item("legs", legs)?;
+        dict.set_item("currency", self.currency.code.to_string())?;
+        dict.set_item("price_precision", self.price_precision)?;
+        dict.set_item("price_increment", self.price_increment.to_string())?;
+        dict.set_item("multiplier", self.multiplier.to_string())?;
+        dict.set_item("lot_size", self.lot_size.to_string())?;
+        dict.set_item("ts_event", self.ts_event)?;
+        dict.set_item("ts_init", self.ts_init)?;
+        match self.max_quantity {
+            Some(value) => dict.set_item("max_quantity", value.to_string())?,
+            None => dict.set_item("max_quantity", py.None())?,
+        }
+        match self.min_quantity {
+            Some(value) => dict.set_item("min_quantity", value.to_string())?,
+            None => dict.set_item("min_quantity", py.None())?,
+        }
+        match self.max_price {
+            Some(value) => dict.set_item("max_price", value.to_string())?,
+            None => dict.set_item("max_price", py.None())?,
+        }
+        match self.min_price {
+            Some(value) => dict.set_item("min_price", value.to_string())?,
+            None => dict.set_item("min_price", py.None())?,
+        }
+        match self.exchange {
+            Some(value) => dict.set_item("exchange", value.to_string())?,
+            None => dict.set_item("exchange", py.None())?,
+        }
+        Ok(dict.into())
+    }
+}
@@ -13,6 +13,8 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod binary_option;
+pub mod combo;
 pub mod crypto_future;
 pub mod crypto_perpetual;
 pub mod currency_pair;
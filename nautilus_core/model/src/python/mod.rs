@@ -32,15 +32,30 @@ pub mod types;
 pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     // Data
     m.add_function(wrap_pyfunction!(data::drop_cvec_pycapsule, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        data::custom::py_register_custom_data_type,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(data::custom::py_custom_data_schema, m)?)?;
     m.add_class::<crate::data::bar::BarSpecification>()?;
     m.add_class::<crate::data::bar::BarType>()?;
     m.add_class::<crate::data::bar::Bar>()?;
+    m.add_class::<crate::data::custom::CustomData>()?;
     m.add_class::<crate::data::order::BookOrder>()?;
     m.add_class::<crate::data::delta::OrderBookDelta>()?;
     m.add_class::<crate::data::deltas::OrderBookDeltas>()?;
+    m.add_class::<crate::python::data::deltas::OrderBookDeltasIterator>()?;
     m.add_class::<crate::data::depth::OrderBookDepth10>()?;
+    m.add_class::<crate::data::depth::OrderBookDepth25>()?;
     m.add_class::<crate::data::quote::QuoteTick>()?;
     m.add_class::<crate::data::trade::TradeTick>()?;
+    m.add_class::<crate::data::trades::TradeTicks>()?;
+    m.add_class::<crate::data::prices::MarkPriceUpdate>()?;
+    m.add_class::<crate::data::prices::IndexPriceUpdate>()?;
+    m.add_class::<crate::data::funding::FundingRateUpdate>()?;
+    m.add_class::<crate::data::greeks::GreeksData>()?;
+    m.add_class::<crate::data::liquidation::LiquidationTick>()?;
+    m.add_class::<crate::data::open_interest::OpenInterestUpdate>()?;
     // Enums
     m.add_class::<crate::enums::AccountType>()?;
     m.add_class::<crate::enums::AggregationSource>()?;
@@ -55,13 +70,17 @@ pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<crate::enums::InstrumentCloseType>()?;
     m.add_class::<crate::enums::LiquiditySide>()?;
     m.add_class::<crate::enums::MarketStatus>()?;
+    m.add_class::<crate::enums::HaltReason>()?;
+    m.add_class::<crate::enums::AuctionPhase>()?;
     m.add_class::<crate::enums::OmsType>()?;
     m.add_class::<crate::enums::OptionKind>()?;
     m.add_class::<crate::enums::OrderSide>()?;
     m.add_class::<crate::enums::OrderStatus>()?;
     m.add_class::<crate::enums::OrderType>()?;
+    m.add_class::<crate::enums::PegPriceType>()?;
     m.add_class::<crate::enums::PositionSide>()?;
     m.add_class::<crate::enums::PriceType>()?;
+    m.add_class::<crate::enums::RoundingMode>()?;
     m.add_class::<crate::enums::TimeInForce>()?;
     m.add_class::<crate::enums::TradingState>()?;
     m.add_class::<crate::enums::TrailingOffsetType>()?;
@@ -97,6 +116,9 @@ pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<crate::types::balance::AccountBalance>()?;
     m.add_class::<crate::types::balance::MarginBalance>()?;
     // Instruments
+    m.add_class::<crate::instruments::binary_option::BinaryOption>()?;
+    m.add_class::<crate::instruments::combo::ComboInstrument>()?;
+    m.add_class::<crate::instruments::combo::ComboLeg>()?;
     m.add_class::<crate::instruments::crypto_future::CryptoFuture>()?;
     m.add_class::<crate::instruments::crypto_perpetual::CryptoPerpetual>()?;
     m.add_class::<crate::instruments::currency_pair::CurrencyPair>()?;
@@ -109,6 +131,7 @@ pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     // Order book
     m.add_class::<crate::orderbook::book_mbo::OrderBookMbo>()?;
     m.add_class::<crate::orderbook::book_mbp::OrderBookMbp>()?;
+    m.add_class::<crate::orderbook::integrity::BookIntegrityConfig>()?;
     m.add_class::<crate::orderbook::level::Level>()?;
     // Events - order
     m.add_class::<crate::events::order::denied::OrderDenied>()?;
@@ -18,12 +18,18 @@ use pyo3::prelude::*;
 
 use crate::{
     data::{
-        delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, order::BookOrder,
-        quote::QuoteTick, trade::TradeTick,
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::{OrderBookDepth10, OrderBookDepth25},
+        order::BookOrder,
+        quote::QuoteTick,
+        trade::TradeTick,
     },
     enums::{BookType, OrderSide},
     identifiers::instrument_id::InstrumentId,
-    orderbook::{book_mbp::OrderBookMbp, level::Level},
+    orderbook::{
+        book::TopOfBookUpdate, book_mbp::OrderBookMbp, integrity::BookIntegrityConfig, level::Level,
+    },
     types::{price::Price, quantity::Quantity},
 };
 
@@ -144,16 +150,45 @@ impl OrderBookMbp {
         self.apply_deltas(deltas);
     }
 
+    #[pyo3(name = "apply_deltas_with_quote")]
+    fn py_apply_deltas_with_quote(&mut self, deltas: OrderBookDeltas) -> (bool, Option<QuoteTick>) {
+        match self.apply_deltas_with_quote(deltas) {
+            TopOfBookUpdate::Unchanged => (false, None),
+            TopOfBookUpdate::Changed(quote) => (true, Some(quote)),
+            TopOfBookUpdate::Cleared => (true, None),
+        }
+    }
+
+    #[pyo3(name = "top_of_book_quote")]
+    fn py_top_of_book_quote(&self, ts_init: UnixNanos) -> Option<QuoteTick> {
+        self.top_of_book_quote(ts_init)
+    }
+
     #[pyo3(name = "apply_depth")]
     fn py_apply_depth(&mut self, depth: OrderBookDepth10) {
         self.apply_depth(depth);
     }
 
+    #[pyo3(name = "apply_depth25")]
+    fn py_apply_depth25(&mut self, depth: OrderBookDepth25) {
+        self.apply_depth25(depth);
+    }
+
+    #[pyo3(name = "apply_depth25_incremental")]
+    fn py_apply_depth25_incremental(&mut self, depth: OrderBookDepth25) -> Vec<OrderBookDelta> {
+        self.apply_depth25_incremental(depth)
+    }
+
     #[pyo3(name = "check_integrity")]
     fn py_check_integrity(&mut self) -> PyResult<()> {
         self.check_integrity().map_err(to_pyruntime_err)
     }
 
+    #[pyo3(name = "enforce_integrity")]
+    fn py_enforce_integrity(&mut self, config: &BookIntegrityConfig) -> PyResult<()> {
+        self.enforce_integrity(config).map_err(to_pyruntime_err)
+    }
+
     #[pyo3(name = "bids")]
     fn py_bids(&self) -> Vec<Level> {
         // Clone each `Level` to create owned levels for Python interop
@@ -198,11 +233,47 @@ impl OrderBookMbp {
         self.midpoint()
     }
 
+    #[pyo3(name = "spread_ticks")]
+    fn py_spread_ticks(&self, tick_size: Price) -> Option<f64> {
+        self.spread_ticks(tick_size)
+    }
+
+    #[pyo3(name = "volume_imbalance")]
+    fn py_volume_imbalance(&self, depth: usize) -> Option<f64> {
+        self.volume_imbalance(depth)
+    }
+
+    #[pyo3(name = "microprice")]
+    fn py_microprice(&self) -> Option<f64> {
+        self.microprice()
+    }
+
+    #[pyo3(name = "get_cumulative_depth")]
+    fn py_get_cumulative_depth(&self, side: OrderSide, offset: Price) -> f64 {
+        self.get_cumulative_depth(side, offset)
+    }
+
+    #[pyo3(name = "group_levels")]
+    fn py_group_levels(&self, side: OrderSide, tick_interval: Price) -> Vec<(Price, f64)> {
+        self.group_levels(side, tick_interval).into_iter().collect()
+    }
+
     #[pyo3(name = "get_avg_px_for_quantity")]
     fn py_get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
         self.get_avg_px_for_quantity(qty, order_side)
     }
 
+    /// Returns a tuple of the average price, filled quantity and per level fills.
+    #[pyo3(name = "get_sweep_for_quantity")]
+    fn py_get_sweep_for_quantity(
+        &self,
+        qty: Quantity,
+        order_side: OrderSide,
+    ) -> (f64, Quantity, Vec<(Price, Quantity)>) {
+        let sweep = self.get_sweep_for_quantity(qty, order_side);
+        (sweep.avg_px, sweep.filled_qty, sweep.fills)
+    }
+
     #[pyo3(name = "get_quantity_for_price")]
     fn py_get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> f64 {
         self.get_quantity_for_price(price, order_side)
@@ -0,0 +1,38 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::str::FromStr;
+
+use nautilus_core::python::to_pyvalue_err;
+use pyo3::prelude::*;
+
+use crate::orderbook::integrity::{BookIntegrityConfig, IntegrityAction};
+
+#[pymethods]
+impl BookIntegrityConfig {
+    #[new]
+    #[pyo3(signature = (on_crossed="RAISE", on_locked="RAISE", on_invalid_order="RAISE"))]
+    fn py_new(on_crossed: &str, on_locked: &str, on_invalid_order: &str) -> PyResult<Self> {
+        let on_crossed = IntegrityAction::from_str(on_crossed).map_err(to_pyvalue_err)?;
+        let on_locked = IntegrityAction::from_str(on_locked).map_err(to_pyvalue_err)?;
+        let on_invalid_order =
+            IntegrityAction::from_str(on_invalid_order).map_err(to_pyvalue_err)?;
+        Self::new(on_crossed, on_locked, on_invalid_order).map_err(to_pyvalue_err)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
@@ -15,4 +15,5 @@
 
 pub mod book_mbo;
 pub mod book_mbp;
+pub mod integrity;
 pub mod level;
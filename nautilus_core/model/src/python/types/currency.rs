@@ -163,4 +163,38 @@ impl Currency {
     fn py_register(currency: Self, overwrite: bool) -> PyResult<()> {
         Self::register(currency, overwrite).map_err(|e| PyRuntimeError::new_err(e.to_string()))
     }
+
+    #[staticmethod]
+    #[pyo3(name = "try_register")]
+    fn py_try_register(currency: Self) -> PyResult<()> {
+        Self::try_register(currency).map_err(to_pyvalue_err)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "register_new")]
+    #[pyo3(signature = (code, precision, currency_type = CurrencyType::Crypto, iso4217 = 0, name = None))]
+    fn py_register_new(
+        code: &str,
+        precision: u8,
+        currency_type: CurrencyType,
+        iso4217: u16,
+        name: Option<&str>,
+    ) -> PyResult<Self> {
+        let currency = Self::new(
+            code,
+            precision,
+            iso4217,
+            name.unwrap_or(code),
+            currency_type,
+        )
+        .map_err(to_pyvalue_err)?;
+        Self::try_register(currency).map_err(to_pyvalue_err)?;
+        Ok(currency)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "is_registered")]
+    fn py_is_registered(code: &str) -> PyResult<bool> {
+        Self::is_registered(code).map_err(|e| PyRuntimeError::new_err(e.to_string()))
+    }
 }
@@ -29,7 +29,10 @@ use pyo3::{
 };
 use rust_decimal::{Decimal, RoundingStrategy};
 
-use crate::types::{currency::Currency, money::Money};
+use crate::types::{
+    currency::Currency,
+    money::{Money, MoneyRaw},
+};
 
 #[pymethods]
 impl Money {
@@ -322,7 +325,7 @@ impl Money {
     }
 
     #[getter]
-    fn raw(&self) -> i64 {
+    fn raw(&self) -> MoneyRaw {
         self.raw
     }
 
@@ -339,7 +342,7 @@ impl Money {
 
     #[staticmethod]
     #[pyo3(name = "from_raw")]
-    fn py_from_raw(raw: i64, currency: Currency) -> PyResult<Self> {
+    fn py_from_raw(raw: MoneyRaw, currency: Currency) -> PyResult<Self> {
         Ok(Self::from_raw(raw, currency))
     }
 
@@ -28,7 +28,10 @@ use pyo3::{
 };
 use rust_decimal::{Decimal, RoundingStrategy};
 
-use crate::types::{fixed::fixed_i64_to_f64, price::Price};
+use crate::{
+    enums::RoundingMode,
+    types::price::{Price, PriceRaw},
+};
 
 #[pymethods]
 impl Price {
@@ -320,7 +323,7 @@ impl Price {
     }
 
     #[getter]
-    fn raw(&self) -> i64 {
+    fn raw(&self) -> PriceRaw {
         self.raw
     }
 
@@ -331,7 +334,7 @@ impl Price {
 
     #[staticmethod]
     #[pyo3(name = "from_raw")]
-    fn py_from_raw(raw: i64, precision: u8) -> PyResult<Self> {
+    fn py_from_raw(raw: PriceRaw, precision: u8) -> PyResult<Self> {
         Self::from_raw(raw, precision).map_err(to_pyvalue_err)
     }
 
@@ -366,7 +369,7 @@ impl Price {
 
     #[pyo3(name = "as_double")]
     fn py_as_double(&self) -> f64 {
-        fixed_i64_to_f64(self.raw)
+        self.as_f64()
     }
 
     #[pyo3(name = "as_decimal")]
@@ -378,4 +381,35 @@ impl Price {
     fn py_to_formatted_str(&self) -> String {
         self.to_formatted_string()
     }
+
+    #[pyo3(name = "checked_add")]
+    fn py_checked_add(&self, other: Self) -> Option<Self> {
+        self.checked_add(other)
+    }
+
+    #[pyo3(name = "checked_sub")]
+    fn py_checked_sub(&self, other: Self) -> Option<Self> {
+        self.checked_sub(other)
+    }
+
+    #[pyo3(name = "checked_mul")]
+    fn py_checked_mul(&self, other: Self) -> Option<Self> {
+        self.checked_mul(other)
+    }
+
+    #[pyo3(name = "checked_div")]
+    fn py_checked_div(&self, other: Self) -> Option<Self> {
+        self.checked_div(other)
+    }
+
+    #[pyo3(name = "checked_div_round")]
+    #[pyo3(signature = (other, precision, mode = RoundingMode::HalfEven))]
+    fn py_checked_div_round(&self, other: Self, precision: u8, mode: RoundingMode) -> Option<Self> {
+        self.checked_div_round(other, precision, mode)
+    }
+
+    #[pyo3(name = "checked_rem")]
+    fn py_checked_rem(&self, other: Self) -> Option<Self> {
+        self.checked_rem(other)
+    }
 }
@@ -0,0 +1,389 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tax-lot accounting for positions.
+//!
+//! Each opening fill of a position creates a lot, and each reducing fill is matched against the
+//! open lots using the selected [`LotMatchingMethod`]. Matched quantities are reported as
+//! [`RealizedLot`]s with their realized PnL and holding period, for tax and attribution reports.
+
+use std::fmt::Display;
+
+use nautilus_core::time::UnixNanos;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{OrderSide, PositionSide},
+    events::order::filled::OrderFilled,
+    identifiers::trade_id::TradeId,
+    position::Position,
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+/// The method used to match reducing fills against the open lots of a position.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LotMatchingMethod {
+    /// First in, first out: the earliest opened lot is closed first.
+    #[default]
+    Fifo,
+    /// Last in, first out: the most recently opened lot is closed first.
+    Lifo,
+    /// Highest in, first out: the lot with the least favorable open price is closed first
+    /// (the highest price for long lots, the lowest price for short lots).
+    Hifo,
+}
+
+impl Display for LotMatchingMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Fifo => "FIFO",
+            Self::Lifo => "LIFO",
+            Self::Hifo => "HIFO",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Represents an open lot of a position.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaxLot {
+    /// The trade ID of the fill which opened the lot.
+    pub trade_id: TradeId,
+    /// The side of the lot.
+    pub side: PositionSide,
+    /// The remaining open quantity of the lot.
+    pub quantity: Quantity,
+    /// The open price of the lot.
+    pub open_px: Price,
+    /// UNIX timestamp (nanoseconds) when the lot was opened.
+    pub ts_opened: UnixNanos,
+}
+
+/// Represents a quantity of a lot closed by a reducing fill.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RealizedLot {
+    /// The trade ID of the fill which opened the lot.
+    pub opening_trade_id: TradeId,
+    /// The trade ID of the fill which closed the lot quantity.
+    pub closing_trade_id: TradeId,
+    /// The side of the lot.
+    pub side: PositionSide,
+    /// The closed quantity.
+    pub quantity: Quantity,
+    /// The open price of the lot.
+    pub open_px: Price,
+    /// The close price of the lot quantity.
+    pub close_px: Price,
+    /// The realized PnL of the closed quantity (excluding commissions).
+    pub realized_pnl: Money,
+    /// UNIX timestamp (nanoseconds) when the lot was opened.
+    pub ts_opened: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the lot quantity was closed.
+    pub ts_closed: UnixNanos,
+    /// The holding period (nanoseconds) of the lot quantity.
+    pub holding_period_ns: u64,
+}
+
+/// Provides lot-level tracking of a position's fills.
+#[derive(Clone, Debug)]
+pub struct TaxLotLedger {
+    method: LotMatchingMethod,
+    open_lots: Vec<TaxLot>,
+    realized_lots: Vec<RealizedLot>,
+}
+
+impl TaxLotLedger {
+    #[must_use]
+    pub fn new(method: LotMatchingMethod) -> Self {
+        Self {
+            method,
+            open_lots: Vec::new(),
+            realized_lots: Vec::new(),
+        }
+    }
+
+    /// Creates a ledger by replaying the fills of the given `position`.
+    #[must_use]
+    pub fn from_position(position: &Position, method: LotMatchingMethod) -> Self {
+        let mut ledger = Self::new(method);
+        for fill in &position.events {
+            ledger.apply(fill, position);
+        }
+        ledger
+    }
+
+    #[must_use]
+    pub fn method(&self) -> LotMatchingMethod {
+        self.method
+    }
+
+    /// Returns the currently open lots, in the order they were opened.
+    #[must_use]
+    pub fn open_lots(&self) -> &[TaxLot] {
+        &self.open_lots
+    }
+
+    /// Returns the realized lots, in the order they were closed.
+    #[must_use]
+    pub fn realized_lots(&self) -> &[RealizedLot] {
+        &self.realized_lots
+    }
+
+    /// Applies the `fill` to the ledger, with PnL calculated using the specification
+    /// (multiplier, inverse and settlement currency) of the given `position`.
+    pub fn apply(&mut self, fill: &OrderFilled, position: &Position) {
+        let fill_side = match fill.order_side {
+            OrderSide::Buy => PositionSide::Long,
+            OrderSide::Sell => PositionSide::Short,
+            OrderSide::NoOrderSide => panic!("Invalid order side {}", fill.order_side),
+        };
+
+        let mut remaining = fill.last_qty;
+        while !remaining.is_zero() {
+            let Some(index) = self.next_lot_index(fill_side) else {
+                break;
+            };
+            let lot = &mut self.open_lots[index];
+            let closed_qty = remaining.min(lot.quantity);
+            lot.quantity -= closed_qty;
+            remaining -= closed_qty;
+
+            let realized_pnl = Money::new(
+                lot_pnl(position, lot.side, lot.open_px, fill.last_px, closed_qty),
+                position.settlement_currency,
+            )
+            .unwrap();
+            self.realized_lots.push(RealizedLot {
+                opening_trade_id: lot.trade_id,
+                closing_trade_id: fill.trade_id,
+                side: lot.side,
+                quantity: closed_qty,
+                open_px: lot.open_px,
+                close_px: fill.last_px,
+                realized_pnl,
+                ts_opened: lot.ts_opened,
+                ts_closed: fill.ts_event,
+                holding_period_ns: fill.ts_event.saturating_sub(lot.ts_opened),
+            });
+
+            if lot.quantity.is_zero() {
+                self.open_lots.remove(index);
+            }
+        }
+
+        if !remaining.is_zero() {
+            self.open_lots.push(TaxLot {
+                trade_id: fill.trade_id,
+                side: fill_side,
+                quantity: remaining,
+                open_px: fill.last_px,
+                ts_opened: fill.ts_event,
+            });
+        }
+    }
+
+    /// Returns the index of the next open lot to be closed by a fill on `fill_side`.
+    fn next_lot_index(&self, fill_side: PositionSide) -> Option<usize> {
+        let opposing = self
+            .open_lots
+            .iter()
+            .enumerate()
+            .filter(|(_, lot)| lot.side != fill_side);
+
+        match self.method {
+            LotMatchingMethod::Fifo => opposing.map(|(i, _)| i).next(),
+            LotMatchingMethod::Lifo => opposing.map(|(i, _)| i).next_back(),
+            LotMatchingMethod::Hifo => {
+                // Ties resolve to the earliest lot
+                opposing
+                    .min_by(|(i1, lot1), (i2, lot2)| {
+                        let ordering = lot1.open_px.cmp(&lot2.open_px);
+                        let ordering = if lot1.side == PositionSide::Long {
+                            ordering.reverse()
+                        } else {
+                            ordering
+                        };
+                        ordering.then(i1.cmp(i2))
+                    })
+                    .map(|(i, _)| i)
+            }
+        }
+    }
+}
+
+fn lot_pnl(
+    position: &Position,
+    side: PositionSide,
+    open_px: Price,
+    close_px: Price,
+    quantity: Quantity,
+) -> f64 {
+    let (open_px, close_px) = (open_px.as_f64(), close_px.as_f64());
+    let points = if position.is_inverse {
+        1.0 / open_px - 1.0 / close_px
+    } else {
+        close_px - open_px
+    };
+    let points = if side == PositionSide::Short {
+        -points
+    } else {
+        points
+    };
+    quantity.as_f64() * position.multiplier.as_f64() * points
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        events::order::filled::OrderFilledBuilder,
+        identifiers::position_id::PositionId,
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim, Instrument},
+        types::currency::Currency,
+    };
+
+    fn fill(
+        instrument: &CurrencyPair,
+        trade_id: &str,
+        side: OrderSide,
+        qty: i64,
+        px: &str,
+        ts_event: UnixNanos,
+    ) -> OrderFilled {
+        OrderFilledBuilder::default()
+            .instrument_id(instrument.id())
+            .position_id(Some(PositionId::from("P-1")))
+            .trade_id(TradeId::from(trade_id))
+            .order_side(side)
+            .last_qty(Quantity::from(qty))
+            .last_px(Price::from(px))
+            .commission(Some(Money::new(0.0, Currency::USD()).unwrap()))
+            .ts_event(ts_event)
+            .build()
+            .unwrap()
+    }
+
+    fn position(instrument: CurrencyPair, fills: &[OrderFilled]) -> Position {
+        let mut position = Position::new(instrument, fills[0]).unwrap();
+        for fill in &fills[1..] {
+            position.apply(fill);
+        }
+        position
+    }
+
+    fn long_position(audusd_sim: CurrencyPair) -> Position {
+        let fills = [
+            fill(&audusd_sim, "1", OrderSide::Buy, 100_000, "1.00000", 1),
+            fill(&audusd_sim, "2", OrderSide::Buy, 100_000, "1.20000", 2),
+            fill(&audusd_sim, "3", OrderSide::Buy, 100_000, "1.10000", 3),
+            fill(&audusd_sim, "4", OrderSide::Sell, 150_000, "1.30000", 10),
+        ];
+        position(audusd_sim, &fills)
+    }
+
+    fn opening_trade_ids(ledger: &TaxLotLedger) -> Vec<(TradeId, Quantity)> {
+        ledger
+            .realized_lots()
+            .iter()
+            .map(|lot| (lot.opening_trade_id, lot.quantity))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_fifo_closes_earliest_lots_first(audusd_sim: CurrencyPair) {
+        let position = long_position(audusd_sim);
+        let ledger = position.tax_lots(LotMatchingMethod::Fifo);
+
+        assert_eq!(
+            opening_trade_ids(&ledger),
+            vec![
+                (TradeId::from("1"), Quantity::from(100_000)),
+                (TradeId::from("2"), Quantity::from(50_000)),
+            ]
+        );
+        assert_eq!(ledger.open_lots().len(), 2);
+        assert_eq!(ledger.open_lots()[0].quantity, Quantity::from(50_000));
+        assert_eq!(
+            ledger.realized_lots()[0].realized_pnl,
+            Money::from("30000.00 USD")
+        );
+        assert_eq!(ledger.realized_lots()[0].holding_period_ns, 9);
+    }
+
+    #[rstest]
+    fn test_lifo_closes_latest_lots_first(audusd_sim: CurrencyPair) {
+        let position = long_position(audusd_sim);
+        let ledger = TaxLotLedger::from_position(&position, LotMatchingMethod::Lifo);
+
+        assert_eq!(
+            opening_trade_ids(&ledger),
+            vec![
+                (TradeId::from("3"), Quantity::from(100_000)),
+                (TradeId::from("2"), Quantity::from(50_000)),
+            ]
+        );
+        assert_eq!(ledger.realized_lots()[1].holding_period_ns, 8);
+    }
+
+    #[rstest]
+    fn test_hifo_closes_highest_cost_lots_first(audusd_sim: CurrencyPair) {
+        let position = long_position(audusd_sim);
+        let ledger = TaxLotLedger::from_position(&position, LotMatchingMethod::Hifo);
+
+        assert_eq!(
+            opening_trade_ids(&ledger),
+            vec![
+                (TradeId::from("2"), Quantity::from(100_000)),
+                (TradeId::from("3"), Quantity::from(50_000)),
+            ]
+        );
+        assert_eq!(
+            ledger.realized_lots()[0].realized_pnl,
+            Money::from("10000.00 USD")
+        );
+    }
+
+    #[rstest]
+    fn test_realized_lot_pnl_sums_to_position_realized_pnl(audusd_sim: CurrencyPair) {
+        let fills = [
+            fill(&audusd_sim, "1", OrderSide::Sell, 100_000, "1.00000", 1),
+            fill(&audusd_sim, "2", OrderSide::Sell, 100_000, "1.10000", 2),
+            fill(&audusd_sim, "3", OrderSide::Buy, 200_000, "0.90000", 3),
+        ];
+        let position = position(audusd_sim, &fills);
+        let ledger = TaxLotLedger::from_position(&position, LotMatchingMethod::Hifo);
+
+        // Short lots are closed from the lowest open price first
+        assert_eq!(
+            ledger.realized_lots()[0].opening_trade_id,
+            TradeId::from("1")
+        );
+        let total: f64 = ledger
+            .realized_lots()
+            .iter()
+            .map(|lot| lot.realized_pnl.as_f64())
+            .sum();
+        assert!(ledger.open_lots().is_empty());
+        assert_eq!(
+            Money::new(total, Currency::USD()).unwrap(),
+            position.realized_pnl.unwrap()
+        );
+    }
+}
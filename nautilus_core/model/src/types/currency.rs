@@ -18,7 +18,7 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
 use nautilus_core::correctness::check_valid_string;
 use serde::{Deserialize, Serialize, Serializer};
 use ustr::Ustr;
@@ -74,6 +74,47 @@ impl Currency {
         Ok(())
     }
 
+    /// Registers the `currency` in the internal currency map, failing if a different
+    /// currency is already registered under the same code.
+    ///
+    /// Registering a currency identical to the existing entry is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a currency with the same code but a different precision, ISO 4217 code, name
+    ///   or currency type is already registered.
+    pub fn try_register(currency: Self) -> Result<()> {
+        let mut map = CURRENCY_MAP.lock().map_err(|e| anyhow!(e.to_string()))?;
+
+        if let Some(existing) = map.get(currency.code.as_str()) {
+            if !existing.is_identical(&currency) {
+                bail!(
+                    "Conflicting currency for code {}: registered {existing:?}, was {currency:?}",
+                    currency.code
+                );
+            }
+            return Ok(());
+        }
+
+        map.insert(currency.code.to_string(), currency);
+        Ok(())
+    }
+
+    /// Returns whether the currency `code` is registered in the internal currency map.
+    pub fn is_registered(code: &str) -> Result<bool> {
+        let map = CURRENCY_MAP.lock().map_err(|e| anyhow!(e.to_string()))?;
+        Ok(map.contains_key(code))
+    }
+
+    fn is_identical(&self, other: &Self) -> bool {
+        self.code == other.code
+            && self.precision == other.precision
+            && self.iso4217 == other.iso4217
+            && self.name == other.name
+            && self.currency_type == other.currency_type
+    }
+
     pub fn is_fiat(code: &str) -> Result<bool> {
         let currency = Self::from_str(code)?;
         Ok(currency.currency_type == CurrencyType::Fiat)
@@ -146,6 +187,8 @@ impl<'de> Deserialize<'de> for Currency {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use rstest::rstest;
 
     use crate::{enums::CurrencyType, types::currency::Currency};
@@ -195,6 +238,43 @@ mod tests {
         assert_eq!(currency1, currency2);
     }
 
+    #[rstest]
+    fn test_try_register_new_currency() {
+        let currency = Currency::new("PNUT", 6, 0, "Peanut", CurrencyType::Crypto).unwrap();
+
+        Currency::try_register(currency).unwrap();
+
+        assert!(Currency::is_registered("PNUT").unwrap());
+        assert_eq!(Currency::from_str("PNUT").unwrap().precision, 6);
+    }
+
+    #[rstest]
+    fn test_try_register_identical_currency_is_noop() {
+        let currency = Currency::USD();
+
+        Currency::try_register(currency).unwrap();
+
+        assert_eq!(Currency::from_str("USD").unwrap().precision, 2);
+    }
+
+    #[rstest]
+    fn test_try_register_conflicting_currency_errors() {
+        let currency = Currency::new("USD", 8, 0, "USD", CurrencyType::Crypto).unwrap();
+
+        let result = Currency::try_register(currency);
+
+        assert!(result.is_err());
+        assert_eq!(
+            Currency::from_str("USD").unwrap().currency_type,
+            CurrencyType::Fiat
+        );
+    }
+
+    #[rstest]
+    fn test_is_registered_unknown_code() {
+        assert!(!Currency::is_registered("NOT_A_CURRENCY").unwrap());
+    }
+
     #[rstest]
     fn test_serialization_deserialization() {
         let currency = Currency::USD();
@@ -15,19 +15,31 @@
 
 use anyhow::{bail, Result};
 
+use crate::enums::RoundingMode;
+
+#[cfg(not(feature = "high-precision"))]
 pub const FIXED_PRECISION: u8 = 9;
+#[cfg(feature = "high-precision")]
+pub const FIXED_PRECISION: u8 = 16;
+
+#[cfg(not(feature = "high-precision"))]
 pub const FIXED_SCALAR: f64 = 1_000_000_000.0; // 10.0**FIXED_PRECISION
+#[cfg(feature = "high-precision")]
+pub const FIXED_SCALAR: f64 = 10_000_000_000_000_000.0; // 10.0**FIXED_PRECISION
 
 pub fn check_fixed_precision(precision: u8) -> Result<()> {
     if precision > FIXED_PRECISION {
-        bail!("Condition failed: `precision` was greater than the maximum `FIXED_PRECISION` (9), was {precision}")
+        bail!("Condition failed: `precision` was greater than the maximum `FIXED_PRECISION` ({FIXED_PRECISION}), was {precision}")
     }
     Ok(())
 }
 
 #[must_use]
 pub fn f64_to_fixed_i64(value: f64, precision: u8) -> i64 {
-    assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
+    assert!(
+        precision <= FIXED_PRECISION,
+        "precision exceeded maximum {FIXED_PRECISION}"
+    );
     let pow1 = 10_i64.pow(u32::from(precision));
     let pow2 = 10_i64.pow(u32::from(FIXED_PRECISION - precision));
     let rounded = (value * pow1 as f64).round() as i64;
@@ -36,13 +48,40 @@ pub fn f64_to_fixed_i64(value: f64, precision: u8) -> i64 {
 
 #[must_use]
 pub fn f64_to_fixed_u64(value: f64, precision: u8) -> u64 {
-    assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
+    assert!(
+        precision <= FIXED_PRECISION,
+        "precision exceeded maximum {FIXED_PRECISION}"
+    );
     let pow1 = 10_u64.pow(u32::from(precision));
     let pow2 = 10_u64.pow(u32::from(FIXED_PRECISION - precision));
     let rounded = (value * pow1 as f64).round() as u64;
     rounded * pow2
 }
 
+#[must_use]
+pub fn f64_to_fixed_i128(value: f64, precision: u8) -> i128 {
+    assert!(
+        precision <= FIXED_PRECISION,
+        "precision exceeded maximum {FIXED_PRECISION}"
+    );
+    let pow1 = 10_i128.pow(u32::from(precision));
+    let pow2 = 10_i128.pow(u32::from(FIXED_PRECISION - precision));
+    let rounded = (value * pow1 as f64).round() as i128;
+    rounded * pow2
+}
+
+#[must_use]
+pub fn f64_to_fixed_u128(value: f64, precision: u8) -> u128 {
+    assert!(
+        precision <= FIXED_PRECISION,
+        "precision exceeded maximum {FIXED_PRECISION}"
+    );
+    let pow1 = 10_u128.pow(u32::from(precision));
+    let pow2 = 10_u128.pow(u32::from(FIXED_PRECISION - precision));
+    let rounded = (value * pow1 as f64).round() as u128;
+    rounded * pow2
+}
+
 #[must_use]
 pub fn fixed_i64_to_f64(value: i64) -> f64 {
     (value as f64) / FIXED_SCALAR
@@ -53,6 +92,84 @@ pub fn fixed_u64_to_f64(value: u64) -> f64 {
     (value as f64) / FIXED_SCALAR
 }
 
+#[must_use]
+pub fn fixed_i128_to_f64(value: i128) -> f64 {
+    (value as f64) / FIXED_SCALAR
+}
+
+#[must_use]
+pub fn fixed_u128_to_f64(value: u128) -> f64 {
+    (value as f64) / FIXED_SCALAR
+}
+
+/// Divides `numerator` by `denominator`, rounding the quotient according to `mode`.
+///
+/// Returns `None` if `denominator` is zero or the result overflows.
+#[must_use]
+pub fn div_round_i128(numerator: i128, denominator: i128, mode: RoundingMode) -> Option<i128> {
+    let quotient = numerator.checked_div(denominator)?;
+    let remainder = numerator.checked_rem(denominator)?;
+    if remainder == 0 {
+        return Some(quotient);
+    }
+
+    // Direction away from zero for the exact quotient
+    let away: i128 = if (numerator < 0) == (denominator < 0) {
+        1
+    } else {
+        -1
+    };
+    let twice_remainder = remainder.unsigned_abs() * 2;
+    let divisor = denominator.unsigned_abs();
+
+    let round_away = match mode {
+        RoundingMode::Up => true,
+        RoundingMode::Down => false,
+        RoundingMode::Ceiling => away > 0,
+        RoundingMode::Floor => away < 0,
+        RoundingMode::HalfUp => twice_remainder >= divisor,
+        RoundingMode::HalfDown => twice_remainder > divisor,
+        RoundingMode::HalfEven => {
+            twice_remainder > divisor || (twice_remainder == divisor && quotient % 2 != 0)
+        }
+    };
+
+    if round_away {
+        quotient.checked_add(away)
+    } else {
+        Some(quotient)
+    }
+}
+
+/// Multiplies the fixed-point raw values `lhs` and `rhs`, returning the raw product rounded
+/// to `precision` decimal places according to `mode`.
+///
+/// Returns `None` if `precision` exceeds [`FIXED_PRECISION`] or the result overflows.
+#[must_use]
+pub fn fixed_mul_round(lhs: i128, rhs: i128, precision: u8, mode: RoundingMode) -> Option<i128> {
+    if precision > FIXED_PRECISION {
+        return None;
+    }
+    let product = lhs.checked_mul(rhs)?;
+    let divisor = 10_i128.pow(u32::from(2 * FIXED_PRECISION - precision));
+    let units = div_round_i128(product, divisor, mode)?;
+    units.checked_mul(10_i128.pow(u32::from(FIXED_PRECISION - precision)))
+}
+
+/// Divides the fixed-point raw value `lhs` by `rhs`, returning the raw quotient rounded
+/// to `precision` decimal places according to `mode`.
+///
+/// Returns `None` if `rhs` is zero, `precision` exceeds [`FIXED_PRECISION`] or the result overflows.
+#[must_use]
+pub fn fixed_div_round(lhs: i128, rhs: i128, precision: u8, mode: RoundingMode) -> Option<i128> {
+    if precision > FIXED_PRECISION {
+        return None;
+    }
+    let numerator = lhs.checked_mul(10_i128.pow(u32::from(precision)))?;
+    let units = div_round_i128(numerator, rhs, mode)?;
+    units.checked_mul(10_i128.pow(u32::from(FIXED_PRECISION - precision)))
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -161,6 +278,103 @@ mod tests {
         assert_eq!(f64_to_fixed_u64(value, precision), expected);
     }
 
+    #[rstest]
+    #[case(0, 0.0)]
+    #[case(1, 1.1)]
+    #[case(9, 0.000_000_001)]
+    #[case(1, -1.1)]
+    #[case(9, -0.000_000_001)]
+    fn test_f64_to_fixed_i128_to_fixed(#[case] precision: u8, #[case] value: f64) {
+        let fixed = f64_to_fixed_i128(value, precision);
+        let result = fixed_i128_to_f64(fixed);
+        assert_eq!(result, value);
+    }
+
+    #[rstest]
+    #[case(0, 0.0)]
+    #[case(1, 1.1)]
+    #[case(9, 0.000_000_001)]
+    fn test_f64_to_fixed_u128_to_fixed(#[case] precision: u8, #[case] value: f64) {
+        let fixed = f64_to_fixed_u128(value, precision);
+        let result = fixed_u128_to_f64(fixed);
+        assert_eq!(result, value);
+    }
+
+    #[rstest]
+    fn test_f64_to_fixed_i128_matches_i64(#[values(0, 1, 5, 9)] precision: u8) {
+        let value = -5.555_555_555_5;
+        assert_eq!(
+            f64_to_fixed_i128(value, precision),
+            i128::from(f64_to_fixed_i64(value, precision))
+        );
+    }
+
+    #[cfg(feature = "high-precision")]
+    #[rstest]
+    fn test_high_precision_fixed_scalar() {
+        assert_eq!(FIXED_PRECISION, 16);
+        assert_eq!(f64_to_fixed_i128(0.000_000_000_000_1, 13), 1_000);
+    }
+
+    #[rstest]
+    #[case(RoundingMode::Up, 3, -3)]
+    #[case(RoundingMode::Down, 2, -2)]
+    #[case(RoundingMode::Ceiling, 3, -2)]
+    #[case(RoundingMode::Floor, 2, -3)]
+    #[case(RoundingMode::HalfUp, 3, -3)]
+    #[case(RoundingMode::HalfDown, 2, -2)]
+    #[case(RoundingMode::HalfEven, 2, -2)]
+    fn test_div_round_i128_tie(
+        #[case] mode: RoundingMode,
+        #[case] expected_positive: i128,
+        #[case] expected_negative: i128,
+    ) {
+        // 2.5 and -2.5
+        assert_eq!(div_round_i128(5, 2, mode), Some(expected_positive));
+        assert_eq!(div_round_i128(-5, 2, mode), Some(expected_negative));
+    }
+
+    #[rstest]
+    #[case(RoundingMode::HalfEven, 7, 2, 4)] // 3.5 -> 4
+    #[case(RoundingMode::HalfEven, 10, 3, 3)] // 3.33 -> 3
+    #[case(RoundingMode::HalfDown, 11, 3, 4)] // 3.67 -> 4
+    #[case(RoundingMode::Up, 9, 3, 3)] // Exact
+    fn test_div_round_i128(
+        #[case] mode: RoundingMode,
+        #[case] numerator: i128,
+        #[case] denominator: i128,
+        #[case] expected: i128,
+    ) {
+        assert_eq!(div_round_i128(numerator, denominator, mode), Some(expected));
+    }
+
+    #[rstest]
+    fn test_div_round_i128_by_zero() {
+        assert_eq!(div_round_i128(1, 0, RoundingMode::HalfEven), None);
+    }
+
+    #[rstest]
+    fn test_fixed_mul_round() {
+        // 1.5 * 2.25 = 3.375 -> 3.38 (HALF_EVEN)
+        let result = fixed_mul_round(1_500_000_000, 2_250_000_000, 2, RoundingMode::HalfEven);
+        assert_eq!(result, Some(3_380_000_000));
+    }
+
+    #[rstest]
+    fn test_fixed_div_round() {
+        // 1 / 3 = 0.333... -> 0.3333 (DOWN) and 0.3334 (UP)
+        let down = fixed_div_round(1_000_000_000, 3_000_000_000, 4, RoundingMode::Down);
+        let up = fixed_div_round(1_000_000_000, 3_000_000_000, 4, RoundingMode::Up);
+        assert_eq!(down, Some(333_300_000));
+        assert_eq!(up, Some(333_400_000));
+    }
+
+    #[rstest]
+    fn test_fixed_div_round_invalid_precision() {
+        let result = fixed_div_round(1_000_000_000, 3_000_000_000, 10, RoundingMode::Down);
+        assert_eq!(result, None);
+    }
+
     #[rstest]
     fn test_fixed_i64_to_f64(
         #[values(1, -1, 2, -2, 10, -10, 100, -100, 1_000, -1_000)] value: i64,
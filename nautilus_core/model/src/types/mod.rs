@@ -17,6 +17,7 @@ pub mod balance;
 pub mod currency;
 pub mod fixed;
 pub mod money;
+pub mod multi_currency_money;
 pub mod price;
 pub mod quantity;
 #[cfg(feature = "stubs")]
@@ -28,13 +28,27 @@ use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
 use super::fixed::FIXED_PRECISION;
-use crate::types::{
-    currency::Currency,
-    fixed::{f64_to_fixed_i64, fixed_i64_to_f64},
-};
-
+#[cfg(feature = "high-precision")]
+use super::fixed::{f64_to_fixed_i128 as f64_to_fixed_raw, fixed_i128_to_f64 as fixed_raw_to_f64};
+#[cfg(not(feature = "high-precision"))]
+use super::fixed::{f64_to_fixed_i64 as f64_to_fixed_raw, fixed_i64_to_f64 as fixed_raw_to_f64};
+use crate::types::currency::Currency;
+
+/// The raw fixed-point representation of a [`Money`] amount.
+#[cfg(not(feature = "high-precision"))]
+pub type MoneyRaw = i64;
+/// The raw fixed-point representation of a [`Money`] amount.
+#[cfg(feature = "high-precision")]
+pub type MoneyRaw = i128;
+
+#[cfg(not(feature = "high-precision"))]
 pub const MONEY_MAX: f64 = 9_223_372_036.0;
+#[cfg(not(feature = "high-precision"))]
 pub const MONEY_MIN: f64 = -9_223_372_036.0;
+#[cfg(feature = "high-precision")]
+pub const MONEY_MAX: f64 = 17_014_118_346_046.0;
+#[cfg(feature = "high-precision")]
+pub const MONEY_MIN: f64 = -17_014_118_346_046.0;
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Eq)]
@@ -43,7 +57,7 @@ pub const MONEY_MIN: f64 = -9_223_372_036.0;
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
 )]
 pub struct Money {
-    pub raw: i64,
+    pub raw: MoneyRaw,
     pub currency: Currency,
 }
 
@@ -52,13 +66,13 @@ impl Money {
         check_f64_in_range_inclusive(amount, MONEY_MIN, MONEY_MAX, "`Money` amount")?;
 
         Ok(Self {
-            raw: f64_to_fixed_i64(amount, currency.precision),
+            raw: f64_to_fixed_raw(amount, currency.precision),
             currency,
         })
     }
 
     #[must_use]
-    pub fn from_raw(raw: i64, currency: Currency) -> Self {
+    pub fn from_raw(raw: MoneyRaw, currency: Currency) -> Self {
         Self { raw, currency }
     }
 
@@ -69,14 +83,15 @@ impl Money {
 
     #[must_use]
     pub fn as_f64(&self) -> f64 {
-        fixed_i64_to_f64(self.raw)
+        fixed_raw_to_f64(self.raw)
     }
 
+    #[allow(clippy::useless_conversion)] // Required for 128-bit raw values
     #[must_use]
     pub fn as_decimal(&self) -> Decimal {
         // Scale down the raw value to match the precision
         let precision = self.currency.precision;
-        let rescaled_raw = self.raw / i64::pow(10, u32::from(FIXED_PRECISION - precision));
+        let rescaled_raw = self.raw / MoneyRaw::pow(10, u32::from(FIXED_PRECISION - precision));
         Decimal::from_i128_with_scale(i128::from(rescaled_raw), u32::from(precision))
     }
 
@@ -324,11 +339,11 @@ mod tests {
         let max_money = Money::new(MONEY_MAX, Currency::USD()).unwrap();
         assert_eq!(
             min_money.raw,
-            f64_to_fixed_i64(MONEY_MIN, Currency::USD().precision)
+            f64_to_fixed_raw(MONEY_MIN, Currency::USD().precision)
         );
         assert_eq!(
             max_money.raw,
-            f64_to_fixed_i64(MONEY_MAX, Currency::USD().precision)
+            f64_to_fixed_raw(MONEY_MAX, Currency::USD().precision)
         );
     }
 
@@ -0,0 +1,370 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+};
+
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
+
+use crate::types::{currency::Currency, money::Money};
+
+/// Provides exchange rates for converting amounts between currencies.
+pub trait FxRateProvider {
+    /// Returns the rate to multiply an amount in `from` by to express it in `to`,
+    /// or `None` if no rate is available.
+    fn get_rate(&self, from: Currency, to: Currency) -> Option<f64>;
+}
+
+impl<F> FxRateProvider for F
+where
+    F: Fn(Currency, Currency) -> Option<f64>,
+{
+    fn get_rate(&self, from: Currency, to: Currency) -> Option<f64> {
+        self(from, to)
+    }
+}
+
+impl FxRateProvider for HashMap<(Currency, Currency), f64> {
+    fn get_rate(&self, from: Currency, to: Currency) -> Option<f64> {
+        self.get(&(from, to))
+            .copied()
+            .or_else(|| self.get(&(to, from)).map(|rate| 1.0 / rate))
+    }
+}
+
+/// Represents an aggregate of [`Money`] amounts across multiple currencies.
+///
+/// Amounts are kept per currency in insertion order, and entries which net to zero are
+/// retained so that the set of currencies touched is preserved.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MultiCurrencyMoney {
+    amounts: IndexMap<Currency, Money>,
+}
+
+impl MultiCurrencyMoney {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.amounts.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.amounts.len()
+    }
+
+    /// Returns the amount held in the given `currency`, if any.
+    #[must_use]
+    pub fn get(&self, currency: &Currency) -> Option<Money> {
+        self.amounts.get(currency).copied()
+    }
+
+    #[must_use]
+    pub fn currencies(&self) -> Vec<Currency> {
+        self.amounts.keys().copied().collect()
+    }
+
+    #[must_use]
+    pub fn monies(&self) -> Vec<Money> {
+        self.amounts.values().copied().collect()
+    }
+
+    /// Returns whether every amount held is zero.
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.amounts.values().all(Money::is_zero)
+    }
+
+    /// Converts the aggregate into a single amount of `currency`, using the rates supplied
+    /// by `provider` for every other currency held.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If no rate is available for a currency held.
+    /// - If the converted total is outside the valid range for `Money`.
+    pub fn convert_to<P: FxRateProvider + ?Sized>(
+        &self,
+        currency: Currency,
+        provider: &P,
+    ) -> Result<Money> {
+        let mut total = 0.0;
+        for (from, money) in &self.amounts {
+            let rate = if *from == currency {
+                1.0
+            } else {
+                provider.get_rate(*from, currency).ok_or_else(|| {
+                    anyhow!(
+                        "No exchange rate available from {} to {}",
+                        from.code,
+                        currency.code
+                    )
+                })?
+            };
+            total += money.as_f64() * rate;
+        }
+        Money::new(total, currency)
+    }
+}
+
+impl From<Money> for MultiCurrencyMoney {
+    fn from(money: Money) -> Self {
+        let mut value = Self::new();
+        value += money;
+        value
+    }
+}
+
+impl FromIterator<Money> for MultiCurrencyMoney {
+    fn from_iter<I: IntoIterator<Item = Money>>(iter: I) -> Self {
+        let mut value = Self::new();
+        for money in iter {
+            value += money;
+        }
+        value
+    }
+}
+
+impl Extend<Money> for MultiCurrencyMoney {
+    fn extend<I: IntoIterator<Item = Money>>(&mut self, iter: I) {
+        for money in iter {
+            *self += money;
+        }
+    }
+}
+
+impl From<MultiCurrencyMoney> for HashMap<Currency, Money> {
+    fn from(value: MultiCurrencyMoney) -> Self {
+        value.amounts.into_iter().collect()
+    }
+}
+
+impl From<HashMap<Currency, Money>> for MultiCurrencyMoney {
+    fn from(map: HashMap<Currency, Money>) -> Self {
+        map.into_values().collect()
+    }
+}
+
+impl AddAssign<Money> for MultiCurrencyMoney {
+    fn add_assign(&mut self, money: Money) {
+        self.amounts
+            .entry(money.currency)
+            .and_modify(|existing| *existing += money)
+            .or_insert(money);
+    }
+}
+
+impl SubAssign<Money> for MultiCurrencyMoney {
+    fn sub_assign(&mut self, money: Money) {
+        *self += -money;
+    }
+}
+
+impl AddAssign for MultiCurrencyMoney {
+    fn add_assign(&mut self, other: Self) {
+        self.extend(other.amounts.into_values());
+    }
+}
+
+impl SubAssign for MultiCurrencyMoney {
+    fn sub_assign(&mut self, other: Self) {
+        *self += -other;
+    }
+}
+
+impl Add<Money> for MultiCurrencyMoney {
+    type Output = Self;
+    fn add(mut self, rhs: Money) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl Sub<Money> for MultiCurrencyMoney {
+    type Output = Self;
+    fn sub(mut self, rhs: Money) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl Add for MultiCurrencyMoney {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl Sub for MultiCurrencyMoney {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl Neg for MultiCurrencyMoney {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            amounts: self
+                .amounts
+                .into_iter()
+                .map(|(currency, money)| (currency, -money))
+                .collect(),
+        }
+    }
+}
+
+impl Display for MultiCurrencyMoney {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let amounts: Vec<String> = self.amounts.values().map(ToString::to_string).collect();
+        write!(f, "[{}]", amounts.join(", "))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_new_is_empty() {
+        let value = MultiCurrencyMoney::new();
+        assert!(value.is_empty());
+        assert!(value.is_zero());
+        assert_eq!(value.to_string(), "[]");
+    }
+
+    #[rstest]
+    fn test_add_same_currency_accumulates() {
+        let value = MultiCurrencyMoney::from(Money::from("100 USD")) + Money::from("50.5 USD");
+        assert_eq!(value.len(), 1);
+        assert_eq!(value.get(&Currency::USD()), Some(Money::from("150.5 USD")));
+    }
+
+    #[rstest]
+    fn test_add_different_currencies_keeps_separate_amounts() {
+        let value: MultiCurrencyMoney = vec![
+            Money::from("100 USD"),
+            Money::from("1.5 BTC"),
+            Money::from("25 USD"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(value.currencies(), vec![Currency::USD(), Currency::BTC()]);
+        assert_eq!(value.get(&Currency::USD()), Some(Money::from("125 USD")));
+        assert_eq!(value.get(&Currency::BTC()), Some(Money::from("1.5 BTC")));
+        assert_eq!(value.get(&Currency::EUR()), None);
+        assert_eq!(value.to_string(), "[125.00 USD, 1.50000000 BTC]");
+    }
+
+    #[rstest]
+    fn test_sub_to_zero_retains_currency() {
+        let value = MultiCurrencyMoney::from(Money::from("100 USD")) - Money::from("100 USD");
+        assert_eq!(value.len(), 1);
+        assert!(value.is_zero());
+    }
+
+    #[rstest]
+    fn test_sub_aggregate() {
+        let lhs: MultiCurrencyMoney = vec![Money::from("100 USD"), Money::from("2 BTC")]
+            .into_iter()
+            .collect();
+        let rhs: MultiCurrencyMoney = vec![Money::from("40 USD"), Money::from("10 EUR")]
+            .into_iter()
+            .collect();
+
+        let result = lhs - rhs;
+
+        assert_eq!(result.get(&Currency::USD()), Some(Money::from("60 USD")));
+        assert_eq!(result.get(&Currency::BTC()), Some(Money::from("2 BTC")));
+        assert_eq!(result.get(&Currency::EUR()), Some(Money::from("-10 EUR")));
+    }
+
+    #[rstest]
+    fn test_equality_ignores_insertion_order() {
+        let lhs: MultiCurrencyMoney = vec![Money::from("1 USD"), Money::from("2 EUR")]
+            .into_iter()
+            .collect();
+        let rhs: MultiCurrencyMoney = vec![Money::from("2 EUR"), Money::from("1 USD")]
+            .into_iter()
+            .collect();
+        assert_eq!(lhs, rhs);
+    }
+
+    #[rstest]
+    fn test_convert_to_with_rate_map() {
+        let value: MultiCurrencyMoney = vec![Money::from("100 USD"), Money::from("50 EUR")]
+            .into_iter()
+            .collect();
+        let mut rates = HashMap::new();
+        rates.insert((Currency::EUR(), Currency::USD()), 1.10);
+
+        let usd = value.convert_to(Currency::USD(), &rates).unwrap();
+        let eur = value.convert_to(Currency::EUR(), &rates).unwrap();
+
+        assert_eq!(usd, Money::from("155 USD"));
+        assert_eq!(eur, Money::from("140.91 EUR"));
+    }
+
+    #[rstest]
+    fn test_convert_to_with_closure() {
+        let value = MultiCurrencyMoney::from(Money::from("2 BTC"));
+        let provider = |from: Currency, to: Currency| {
+            (from == Currency::BTC() && to == Currency::USDT()).then_some(60_000.0)
+        };
+
+        let result = value.convert_to(Currency::USDT(), &provider).unwrap();
+
+        assert_eq!(result, Money::from("120000 USDT"));
+    }
+
+    #[rstest]
+    fn test_convert_to_missing_rate_errors() {
+        let value = MultiCurrencyMoney::from(Money::from("2 BTC"));
+        let rates: HashMap<(Currency, Currency), f64> = HashMap::new();
+
+        let result = value.convert_to(Currency::USD(), &rates);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_into_hashmap() {
+        let value: MultiCurrencyMoney = vec![Money::from("1 USD"), Money::from("2 EUR")]
+            .into_iter()
+            .collect();
+        let map: HashMap<Currency, Money> = value.into();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&Currency::EUR()], Money::from("2 EUR"));
+    }
+}
@@ -28,14 +28,43 @@ use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
 use super::fixed::{check_fixed_precision, FIXED_PRECISION, FIXED_SCALAR};
-use crate::types::fixed::{f64_to_fixed_i64, fixed_i64_to_f64};
+#[cfg(feature = "high-precision")]
+use super::fixed::{f64_to_fixed_i128 as f64_to_fixed_raw, fixed_i128_to_f64 as fixed_raw_to_f64};
+#[cfg(not(feature = "high-precision"))]
+use super::fixed::{f64_to_fixed_i64 as f64_to_fixed_raw, fixed_i64_to_f64 as fixed_raw_to_f64};
+use crate::{
+    enums::RoundingMode,
+    types::fixed::{fixed_div_round, fixed_mul_round},
+};
+
+/// The raw fixed-point representation of a [`Price`].
+#[cfg(not(feature = "high-precision"))]
+pub type PriceRaw = i64;
+/// The raw fixed-point representation of a [`Price`].
+#[cfg(feature = "high-precision")]
+pub type PriceRaw = i128;
 
+#[cfg(not(feature = "high-precision"))]
 pub const PRICE_MAX: f64 = 9_223_372_036.0;
+#[cfg(not(feature = "high-precision"))]
 pub const PRICE_MIN: f64 = -9_223_372_036.0;
+#[cfg(feature = "high-precision")]
+pub const PRICE_MAX: f64 = 17_014_118_346_046.0;
+#[cfg(feature = "high-precision")]
+pub const PRICE_MIN: f64 = -17_014_118_346_046.0;
+
+#[cfg(not(feature = "high-precision"))]
+const PRICE_RAW_MAX: PriceRaw = 9_223_372_036_000_000_000;
+#[cfg(not(feature = "high-precision"))]
+const PRICE_RAW_MIN: PriceRaw = -9_223_372_036_000_000_000;
+#[cfg(feature = "high-precision")]
+const PRICE_RAW_MAX: PriceRaw = 170_141_183_460_460_000_000_000_000_000;
+#[cfg(feature = "high-precision")]
+const PRICE_RAW_MIN: PriceRaw = -170_141_183_460_460_000_000_000_000_000;
 
 /// Sentinel Price for errors.
 pub const ERROR_PRICE: Price = Price {
-    raw: i64::MAX,
+    raw: PriceRaw::MAX,
     precision: 0,
 };
 
@@ -46,7 +75,7 @@ pub const ERROR_PRICE: Price = Price {
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
 )]
 pub struct Price {
-    pub raw: i64,
+    pub raw: PriceRaw,
     pub precision: u8,
 }
 
@@ -56,12 +85,12 @@ impl Price {
         check_fixed_precision(precision)?;
 
         Ok(Self {
-            raw: f64_to_fixed_i64(value, precision),
+            raw: f64_to_fixed_raw(value, precision),
             precision,
         })
     }
 
-    pub fn from_raw(raw: i64, precision: u8) -> Result<Self> {
+    pub fn from_raw(raw: PriceRaw, precision: u8) -> Result<Self> {
         check_fixed_precision(precision)?;
         Ok(Self { raw, precision })
     }
@@ -70,7 +99,7 @@ impl Price {
     pub fn max(precision: u8) -> Self {
         check_fixed_precision(precision).unwrap();
         Self {
-            raw: (PRICE_MAX * FIXED_SCALAR) as i64,
+            raw: (PRICE_MAX * FIXED_SCALAR) as PriceRaw,
             precision,
         }
     }
@@ -79,7 +108,7 @@ impl Price {
     pub fn min(precision: u8) -> Self {
         check_fixed_precision(precision).unwrap();
         Self {
-            raw: (PRICE_MIN * FIXED_SCALAR) as i64,
+            raw: (PRICE_MIN * FIXED_SCALAR) as PriceRaw,
             precision,
         }
     }
@@ -97,13 +126,15 @@ impl Price {
 
     #[must_use]
     pub fn as_f64(&self) -> f64 {
-        fixed_i64_to_f64(self.raw)
+        fixed_raw_to_f64(self.raw)
     }
 
+    #[allow(clippy::useless_conversion)] // Required for 128-bit raw values
     #[must_use]
     pub fn as_decimal(&self) -> Decimal {
         // Scale down the raw value to match the precision
-        let rescaled_raw = self.raw / i64::pow(10, u32::from(FIXED_PRECISION - self.precision));
+        let rescaled_raw =
+            self.raw / PriceRaw::pow(10, u32::from(FIXED_PRECISION - self.precision));
         Decimal::from_i128_with_scale(i128::from(rescaled_raw), u32::from(self.precision))
     }
 
@@ -111,6 +142,75 @@ impl Price {
     pub fn to_formatted_string(&self) -> String {
         format!("{self}").separate_with_underscores()
     }
+
+    /// Returns `self + rhs` at the greater of both precisions, or `None` if the result
+    /// would be outside the valid price range.
+    #[allow(clippy::useless_conversion)] // Required for 128-bit raw values
+    #[must_use]
+    pub fn checked_add(&self, rhs: Self) -> Option<Self> {
+        let raw = i128::from(self.raw) + i128::from(rhs.raw);
+        Self::from_raw_checked(raw, self.precision.max(rhs.precision))
+    }
+
+    /// Returns `self - rhs` at the greater of both precisions, or `None` if the result
+    /// would be outside the valid price range.
+    #[allow(clippy::useless_conversion)] // Required for 128-bit raw values
+    #[must_use]
+    pub fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        let raw = i128::from(self.raw) - i128::from(rhs.raw);
+        Self::from_raw_checked(raw, self.precision.max(rhs.precision))
+    }
+
+    /// Returns `self * rhs` at the sum of both precisions (capped at [`FIXED_PRECISION`]),
+    /// rounded with [`RoundingMode::HalfEven`], or `None` if the result would be outside
+    /// the valid price range.
+    #[allow(clippy::useless_conversion)] // Required for 128-bit raw values
+    #[must_use]
+    pub fn checked_mul(&self, rhs: Self) -> Option<Self> {
+        let precision = (self.precision + rhs.precision).min(FIXED_PRECISION);
+        let raw = fixed_mul_round(
+            i128::from(self.raw),
+            i128::from(rhs.raw),
+            precision,
+            RoundingMode::HalfEven,
+        )?;
+        Self::from_raw_checked(raw, precision)
+    }
+
+    /// Returns `self / rhs` at the precision of `self`, rounded with [`RoundingMode::HalfEven`],
+    /// or `None` if `rhs` is zero or the result would be outside the valid price range.
+    #[must_use]
+    pub fn checked_div(&self, rhs: Self) -> Option<Self> {
+        self.checked_div_round(rhs, self.precision, RoundingMode::HalfEven)
+    }
+
+    /// Returns `self / rhs` rounded to `precision` decimal places according to `mode`,
+    /// or `None` if `rhs` is zero, `precision` is invalid or the result would be outside
+    /// the valid price range.
+    #[allow(clippy::useless_conversion)] // Required for 128-bit raw values
+    #[must_use]
+    pub fn checked_div_round(&self, rhs: Self, precision: u8, mode: RoundingMode) -> Option<Self> {
+        let raw = fixed_div_round(i128::from(self.raw), i128::from(rhs.raw), precision, mode)?;
+        Self::from_raw_checked(raw, precision)
+    }
+
+    /// Returns the remainder of `self / rhs` at the greater of both precisions,
+    /// or `None` if `rhs` is zero.
+    #[allow(clippy::useless_conversion)] // Required for 128-bit raw values
+    #[must_use]
+    pub fn checked_rem(&self, rhs: Self) -> Option<Self> {
+        let raw = self.raw.checked_rem(rhs.raw)?;
+        Self::from_raw_checked(i128::from(raw), self.precision.max(rhs.precision))
+    }
+
+    #[allow(clippy::unnecessary_fallible_conversions)] // Required for 128-bit raw values
+    fn from_raw_checked(raw: i128, precision: u8) -> Option<Self> {
+        let raw = PriceRaw::try_from(raw).ok()?;
+        if !(PRICE_RAW_MIN..=PRICE_RAW_MAX).contains(&raw) {
+            return None;
+        }
+        Some(Self { raw, precision })
+    }
 }
 
 impl FromStr for Price {
@@ -185,7 +285,7 @@ impl Ord for Price {
 }
 
 impl Deref for Price {
-    type Target = i64;
+    type Target = PriceRaw;
 
     fn deref(&self) -> &Self::Target {
         &self.raw
@@ -506,4 +606,89 @@ mod tests {
         write!(&mut res, "{price}").unwrap();
         assert_eq!(res, input_string);
     }
+
+    #[rstest]
+    fn test_checked_add() {
+        let result = Price::from("1.10")
+            .checked_add(Price::from("0.005"))
+            .unwrap();
+        assert_eq!(result, Price::from("1.105"));
+        assert_eq!(result.precision, 3);
+    }
+
+    #[rstest]
+    fn test_checked_add_overflow() {
+        let result = Price::max(0).checked_add(Price::from("1"));
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_checked_sub_overflow() {
+        let result = Price::min(0).checked_sub(Price::from("1"));
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_checked_mul() {
+        let result = Price::from("1.5").checked_mul(Price::from("2.25")).unwrap();
+        assert_eq!(result, Price::from("3.375"));
+        assert_eq!(result.precision, 3);
+    }
+
+    #[rstest]
+    fn test_checked_mul_overflow() {
+        let result = Price::from("100000").checked_mul(Price::from("100000"));
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_checked_div() {
+        let result = Price::from("10.00").checked_div(Price::from("3")).unwrap();
+        assert_eq!(result, Price::from("3.33"));
+        assert_eq!(result.precision, 2);
+    }
+
+    #[rstest]
+    fn test_checked_div_by_zero() {
+        let result = Price::from("10.00").checked_div(Price::zero(2));
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    #[case(RoundingMode::HalfEven, "0.12")]
+    #[case(RoundingMode::HalfUp, "0.13")]
+    #[case(RoundingMode::Down, "0.12")]
+    #[case(RoundingMode::Ceiling, "0.13")]
+    fn test_checked_div_round(#[case] mode: RoundingMode, #[case] expected: &str) {
+        // 0.25 / 2 = 0.125
+        let result = Price::from("0.25")
+            .checked_div_round(Price::from("2"), 2, mode)
+            .unwrap();
+        assert_eq!(result, Price::from(expected));
+    }
+
+    #[rstest]
+    fn test_checked_rem() {
+        let result = Price::from("10.25")
+            .checked_rem(Price::from("0.10"))
+            .unwrap();
+        assert_eq!(result, Price::from("0.05"));
+        assert!(Price::from("1.00").checked_rem(Price::zero(2)).is_none());
+    }
+
+    #[cfg(feature = "high-precision")]
+    #[rstest]
+    fn test_high_precision_small_price() {
+        let price = Price::new(0.000_012_345_678_9, 13).unwrap();
+        assert_eq!(price.raw, 123_456_789_000);
+        assert_eq!(price.to_string(), "0.0000123456789");
+        assert_eq!(price.as_decimal(), dec!(0.0000123456789));
+    }
+
+    #[cfg(feature = "high-precision")]
+    #[rstest]
+    fn test_high_precision_max_exceeds_standard_range() {
+        let price = Price::max(0);
+        assert!(price.raw > PriceRaw::from(i64::MAX));
+    }
 }
@@ -28,11 +28,33 @@ use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
 use super::fixed::{check_fixed_precision, FIXED_PRECISION, FIXED_SCALAR};
-use crate::types::fixed::{f64_to_fixed_u64, fixed_u64_to_f64};
+#[cfg(feature = "high-precision")]
+use super::fixed::{f64_to_fixed_u128 as f64_to_fixed_raw, fixed_u128_to_f64 as fixed_raw_to_f64};
+#[cfg(not(feature = "high-precision"))]
+use super::fixed::{f64_to_fixed_u64 as f64_to_fixed_raw, fixed_u64_to_f64 as fixed_raw_to_f64};
+use crate::{
+    enums::RoundingMode,
+    types::fixed::{fixed_div_round, fixed_mul_round},
+};
+
+/// The raw fixed-point representation of a [`Quantity`].
+#[cfg(not(feature = "high-precision"))]
+pub type QuantityRaw = u64;
+/// The raw fixed-point representation of a [`Quantity`].
+#[cfg(feature = "high-precision")]
+pub type QuantityRaw = u128;
 
+#[cfg(not(feature = "high-precision"))]
 pub const QUANTITY_MAX: f64 = 18_446_744_073.0;
+#[cfg(feature = "high-precision")]
+pub const QUANTITY_MAX: f64 = 34_028_236_692_093.0;
 pub const QUANTITY_MIN: f64 = 0.0;
 
+#[cfg(not(feature = "high-precision"))]
+const QUANTITY_RAW_MAX: QuantityRaw = 18_446_744_073_000_000_000;
+#[cfg(feature = "high-precision")]
+const QUANTITY_RAW_MAX: QuantityRaw = 340_282_366_920_930_000_000_000_000_000;
+
 #[repr(C)]
 #[derive(Clone, Copy, Default, Eq)]
 #[cfg_attr(
@@ -40,7 +62,7 @@ pub const QUANTITY_MIN: f64 = 0.0;
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
 )]
 pub struct Quantity {
-    pub raw: u64,
+    pub raw: QuantityRaw,
     pub precision: u8,
 }
 
@@ -50,12 +72,12 @@ impl Quantity {
         check_fixed_precision(precision)?;
 
         Ok(Self {
-            raw: f64_to_fixed_u64(value, precision),
+            raw: f64_to_fixed_raw(value, precision),
             precision,
         })
     }
 
-    pub fn from_raw(raw: u64, precision: u8) -> Result<Self> {
+    pub fn from_raw(raw: QuantityRaw, precision: u8) -> Result<Self> {
         check_fixed_precision(precision)?;
         Ok(Self { raw, precision })
     }
@@ -78,20 +100,94 @@ impl Quantity {
 
     #[must_use]
     pub fn as_f64(&self) -> f64 {
-        fixed_u64_to_f64(self.raw)
+        fixed_raw_to_f64(self.raw)
     }
 
     #[must_use]
     pub fn as_decimal(&self) -> Decimal {
         // Scale down the raw value to match the precision
-        let rescaled_raw = self.raw / u64::pow(10, u32::from(FIXED_PRECISION - self.precision));
-        Decimal::from_i128_with_scale(i128::from(rescaled_raw), u32::from(self.precision))
+        let rescaled_raw =
+            self.raw / QuantityRaw::pow(10, u32::from(FIXED_PRECISION - self.precision));
+        Decimal::from_i128_with_scale(rescaled_raw as i128, u32::from(self.precision))
     }
 
     #[must_use]
     pub fn to_formatted_string(&self) -> String {
         format!("{self}").separate_with_underscores()
     }
+
+    /// Returns `self + rhs` at the greater of both precisions, or `None` if the result
+    /// would be outside the valid quantity range.
+    #[must_use]
+    pub fn checked_add(&self, rhs: Self) -> Option<Self> {
+        let raw = raw_to_i128(self.raw)? + raw_to_i128(rhs.raw)?;
+        Self::from_raw_checked(raw, self.precision.max(rhs.precision))
+    }
+
+    /// Returns `self - rhs` at the greater of both precisions, or `None` if the result
+    /// would be negative.
+    #[must_use]
+    pub fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        let raw = raw_to_i128(self.raw)? - raw_to_i128(rhs.raw)?;
+        Self::from_raw_checked(raw, self.precision.max(rhs.precision))
+    }
+
+    /// Returns `self * rhs` at the sum of both precisions (capped at [`FIXED_PRECISION`]),
+    /// rounded with [`RoundingMode::HalfEven`], or `None` if the result would be outside
+    /// the valid quantity range.
+    #[must_use]
+    pub fn checked_mul(&self, rhs: Self) -> Option<Self> {
+        let precision = (self.precision + rhs.precision).min(FIXED_PRECISION);
+        let raw = fixed_mul_round(
+            raw_to_i128(self.raw)?,
+            raw_to_i128(rhs.raw)?,
+            precision,
+            RoundingMode::HalfEven,
+        )?;
+        Self::from_raw_checked(raw, precision)
+    }
+
+    /// Returns `self / rhs` at the precision of `self`, rounded with [`RoundingMode::HalfEven`],
+    /// or `None` if `rhs` is zero or the result would be outside the valid quantity range.
+    #[must_use]
+    pub fn checked_div(&self, rhs: Self) -> Option<Self> {
+        self.checked_div_round(rhs, self.precision, RoundingMode::HalfEven)
+    }
+
+    /// Returns `self / rhs` rounded to `precision` decimal places according to `mode`,
+    /// or `None` if `rhs` is zero, `precision` is invalid or the result would be outside
+    /// the valid quantity range.
+    #[must_use]
+    pub fn checked_div_round(&self, rhs: Self, precision: u8, mode: RoundingMode) -> Option<Self> {
+        let raw = fixed_div_round(
+            raw_to_i128(self.raw)?,
+            raw_to_i128(rhs.raw)?,
+            precision,
+            mode,
+        )?;
+        Self::from_raw_checked(raw, precision)
+    }
+
+    /// Returns the remainder of `self / rhs` at the greater of both precisions,
+    /// or `None` if `rhs` is zero.
+    #[must_use]
+    pub fn checked_rem(&self, rhs: Self) -> Option<Self> {
+        let raw = self.raw.checked_rem(rhs.raw)?;
+        Self::from_raw_checked(raw_to_i128(raw)?, self.precision.max(rhs.precision))
+    }
+
+    fn from_raw_checked(raw: i128, precision: u8) -> Option<Self> {
+        let raw = QuantityRaw::try_from(raw).ok()?;
+        if raw > QUANTITY_RAW_MAX {
+            return None;
+        }
+        Some(Self { raw, precision })
+    }
+}
+
+#[allow(clippy::unnecessary_fallible_conversions)] // Required for 128-bit raw values
+fn raw_to_i128(raw: QuantityRaw) -> Option<i128> {
+    i128::try_from(raw).ok()
 }
 
 impl From<Quantity> for f64 {
@@ -172,7 +268,7 @@ impl Ord for Quantity {
 }
 
 impl Deref for Quantity {
-    type Target = u64;
+    type Target = QuantityRaw;
 
     fn deref(&self) -> &Self::Target {
         &self.raw
@@ -203,7 +299,7 @@ impl Mul for Quantity {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
         Self {
-            raw: (self.raw * rhs.raw) / (FIXED_SCALAR as u64),
+            raw: (self.raw * rhs.raw) / (FIXED_SCALAR as QuantityRaw),
             precision: self.precision,
         }
     }
@@ -216,31 +312,31 @@ impl Mul<f64> for Quantity {
     }
 }
 
-impl From<Quantity> for u64 {
+impl From<Quantity> for QuantityRaw {
     fn from(value: Quantity) -> Self {
         value.raw
     }
 }
 
-impl From<&Quantity> for u64 {
+impl From<&Quantity> for QuantityRaw {
     fn from(value: &Quantity) -> Self {
         value.raw
     }
 }
 
-impl<T: Into<u64>> AddAssign<T> for Quantity {
+impl<T: Into<QuantityRaw>> AddAssign<T> for Quantity {
     fn add_assign(&mut self, other: T) {
         self.raw += other.into();
     }
 }
 
-impl<T: Into<u64>> SubAssign<T> for Quantity {
+impl<T: Into<QuantityRaw>> SubAssign<T> for Quantity {
     fn sub_assign(&mut self, other: T) {
         self.raw -= other.into();
     }
 }
 
-impl<T: Into<u64>> MulAssign<T> for Quantity {
+impl<T: Into<QuantityRaw>> MulAssign<T> for Quantity {
     fn mul_assign(&mut self, other: T) {
         self.raw *= other.into();
     }
@@ -501,4 +597,68 @@ mod tests {
         assert_eq!(res, input_string);
         assert_eq!(qty.to_string(), input_string);
     }
+
+    #[rstest]
+    fn test_checked_add() {
+        let result = Quantity::from("1.5")
+            .checked_add(Quantity::from("0.25"))
+            .unwrap();
+        assert_eq!(result, Quantity::from("1.75"));
+        assert_eq!(result.precision, 2);
+    }
+
+    #[rstest]
+    fn test_checked_add_overflow() {
+        let result = Quantity::from("18446744073").checked_add(Quantity::from("1"));
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_checked_sub_negative() {
+        let result = Quantity::from("1.0").checked_sub(Quantity::from("1.1"));
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_checked_mul() {
+        let result = Quantity::from("2.5")
+            .checked_mul(Quantity::from("4"))
+            .unwrap();
+        assert_eq!(result, Quantity::from("10.0"));
+        assert_eq!(result.precision, 1);
+    }
+
+    #[rstest]
+    #[case(RoundingMode::HalfEven, "0.7")]
+    #[case(RoundingMode::Down, "0.6")]
+    #[case(RoundingMode::Up, "0.7")]
+    fn test_checked_div_round(#[case] mode: RoundingMode, #[case] expected: &str) {
+        // 2 / 3 = 0.666...
+        let result = Quantity::from("2")
+            .checked_div_round(Quantity::from("3"), 1, mode)
+            .unwrap();
+        assert_eq!(result, Quantity::from(expected));
+    }
+
+    #[rstest]
+    fn test_checked_div_by_zero() {
+        assert!(Quantity::from("1").checked_div(Quantity::zero(0)).is_none());
+    }
+
+    #[rstest]
+    fn test_checked_rem() {
+        let result = Quantity::from("10.5")
+            .checked_rem(Quantity::from("4"))
+            .unwrap();
+        assert_eq!(result, Quantity::from("2.5"));
+    }
+
+    #[cfg(feature = "high-precision")]
+    #[rstest]
+    fn test_high_precision_large_quantity() {
+        // Exceeds the standard precision maximum of ~18.4 billion
+        let qty = Quantity::new(25_000_000_000_000.0, 0).unwrap();
+        assert_eq!(qty.as_f64(), 25_000_000_000_000.0);
+        assert!(qty.raw > QuantityRaw::from(u64::MAX));
+    }
 }
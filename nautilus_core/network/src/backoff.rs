@@ -0,0 +1,170 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Exponential backoff with jitter for reconnection policies.
+
+use std::time::Duration;
+
+use rand::Rng;
+
+/// The default initial reconnect delay (milliseconds).
+pub const DEFAULT_DELAY_INITIAL_MS: u64 = 500;
+/// The default maximum reconnect delay (milliseconds).
+pub const DEFAULT_DELAY_MAX_MS: u64 = 30_000;
+/// The default factor the reconnect delay grows by with each attempt.
+pub const DEFAULT_BACKOFF_FACTOR: f64 = 2.0;
+/// The default maximum random jitter added to each reconnect delay (milliseconds).
+pub const DEFAULT_JITTER_MS: u64 = 100;
+
+/// Provides exponentially increasing delays between reconnection attempts.
+///
+/// Each delay is the initial delay multiplied by the backoff factor for each previous
+/// attempt, capped at the maximum delay, plus a random jitter so that many clients
+/// do not reconnect in lockstep.
+#[derive(Clone, Debug)]
+pub struct ExponentialBackoff {
+    delay_initial: Duration,
+    delay_max: Duration,
+    factor: f64,
+    jitter_ms: u64,
+    max_attempts: Option<u32>,
+    attempts: u32,
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self::new(
+            Duration::from_millis(DEFAULT_DELAY_INITIAL_MS),
+            Duration::from_millis(DEFAULT_DELAY_MAX_MS),
+            DEFAULT_BACKOFF_FACTOR,
+            DEFAULT_JITTER_MS,
+            None,
+        )
+    }
+}
+
+impl ExponentialBackoff {
+    /// Creates a new backoff policy, with `None` for `max_attempts` retrying indefinitely.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `factor` is less than 1.0.
+    #[must_use]
+    pub fn new(
+        delay_initial: Duration,
+        delay_max: Duration,
+        factor: f64,
+        jitter_ms: u64,
+        max_attempts: Option<u32>,
+    ) -> Self {
+        assert!(factor >= 1.0, "`factor` was less than 1.0, was {factor}");
+        Self {
+            delay_initial,
+            delay_max: delay_max.max(delay_initial),
+            factor,
+            jitter_ms,
+            max_attempts,
+            attempts: 0,
+        }
+    }
+
+    /// Returns the number of attempts since the last reset.
+    #[must_use]
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Returns the delay before the next attempt, or `None` if the maximum number of
+    /// attempts has been reached.
+    pub fn next_delay(&mut self) -> Option<Duration> {
+        if self.max_attempts.is_some_and(|max| self.attempts >= max) {
+            return None;
+        }
+        let exponent = i32::try_from(self.attempts).unwrap_or(i32::MAX);
+        let delay = self
+            .delay_initial
+            .mul_f64(self.factor.powi(exponent))
+            .min(self.delay_max);
+        self.attempts = self.attempts.saturating_add(1);
+
+        let jitter_ms = if self.jitter_ms > 0 {
+            rand::thread_rng().gen_range(0..=self.jitter_ms)
+        } else {
+            0
+        };
+        Some(delay + Duration::from_millis(jitter_ms))
+    }
+
+    /// Resets the backoff following a successful attempt.
+    pub fn reset(&mut self) {
+        self.attempts = 0;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn backoff(jitter_ms: u64, max_attempts: Option<u32>) -> ExponentialBackoff {
+        ExponentialBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_millis(500),
+            2.0,
+            jitter_ms,
+            max_attempts,
+        )
+    }
+
+    #[rstest]
+    fn test_delays_grow_exponentially_up_to_max() {
+        let mut backoff = backoff(0, None);
+
+        let delays: Vec<u128> = (0..5)
+            .map(|_| backoff.next_delay().unwrap().as_millis())
+            .collect();
+
+        assert_eq!(delays, vec![100, 200, 400, 500, 500]);
+        assert_eq!(backoff.attempts(), 5);
+    }
+
+    #[rstest]
+    fn test_jitter_is_bounded() {
+        let mut backoff = backoff(50, None);
+
+        for _ in 0..100 {
+            backoff.reset();
+            let delay = backoff.next_delay().unwrap().as_millis();
+            assert!((100..=150).contains(&delay));
+        }
+    }
+
+    #[rstest]
+    fn test_max_attempts_and_reset() {
+        let mut backoff = backoff(0, Some(2));
+
+        assert!(backoff.next_delay().is_some());
+        assert!(backoff.next_delay().is_some());
+        assert!(backoff.next_delay().is_none());
+
+        backoff.reset();
+
+        assert_eq!(backoff.next_delay(), Some(Duration::from_millis(100)));
+    }
+}
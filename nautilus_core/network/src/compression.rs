@@ -0,0 +1,184 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Decompression of compressed WebSocket message payloads.
+//!
+//! Several venues compress the payload of binary frames at the application level
+//! (rather than using the `permessage-deflate` extension), so payloads are decompressed
+//! here before being passed to the message handler.
+
+use std::{
+    borrow::Cow,
+    io::{self, Read},
+    str::FromStr,
+};
+
+use flate2::read::{DeflateDecoder, GzDecoder, ZlibDecoder};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// The compression applied to binary message payloads.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PayloadCompression {
+    /// Payloads are not compressed.
+    #[default]
+    None,
+    /// Payloads are gzip compressed.
+    Gzip,
+    /// Payloads are raw deflate compressed (no zlib header).
+    Deflate,
+    /// Payloads are zlib compressed.
+    Zlib,
+    /// Payloads are zstd compressed.
+    Zstd,
+    /// The compression is detected from the payload magic bytes (gzip, zstd or zlib),
+    /// passing through payloads which are not recognized.
+    Auto,
+}
+
+impl FromStr for PayloadCompression {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "gzip" => Ok(Self::Gzip),
+            "deflate" => Ok(Self::Deflate),
+            "zlib" => Ok(Self::Zlib),
+            "zstd" => Ok(Self::Zstd),
+            "auto" => Ok(Self::Auto),
+            _ => Err(format!("Invalid payload compression '{s}'")),
+        }
+    }
+}
+
+impl PayloadCompression {
+    /// Decompresses the given payload `data`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the payload cannot be decompressed.
+    pub fn decompress<'a>(&self, data: &'a [u8]) -> io::Result<Cow<'a, [u8]>> {
+        let compression = match self {
+            Self::Auto => Self::detect(data),
+            other => *other,
+        };
+        let mut out = Vec::with_capacity(data.len() * 4);
+        match compression {
+            Self::None | Self::Auto => return Ok(Cow::Borrowed(data)),
+            Self::Gzip => GzDecoder::new(data).read_to_end(&mut out)?,
+            Self::Deflate => DeflateDecoder::new(data).read_to_end(&mut out)?,
+            Self::Zlib => ZlibDecoder::new(data).read_to_end(&mut out)?,
+            Self::Zstd => zstd::stream::Decoder::new(data)?.read_to_end(&mut out)?,
+        };
+        Ok(Cow::Owned(out))
+    }
+
+    fn detect(data: &[u8]) -> Self {
+        if data.starts_with(&GZIP_MAGIC) {
+            Self::Gzip
+        } else if data.starts_with(&ZSTD_MAGIC) {
+            Self::Zstd
+        } else if data.len() >= 2
+            && data[0] & 0x0f == 8
+            && ((u16::from(data[0]) << 8) | u16::from(data[1])) % 31 == 0
+        {
+            // Zlib header: deflate method with a valid header checksum
+            Self::Zlib
+        } else {
+            Self::None
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{
+        write::{DeflateEncoder, GzEncoder, ZlibEncoder},
+        Compression,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    const PAYLOAD: &[u8] = br#"{"arg":{"channel":"tickers"},"data":[{"last":"9999.99"}]}"#;
+
+    fn compress(compression: PayloadCompression) -> Vec<u8> {
+        match compression {
+            PayloadCompression::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(PAYLOAD).unwrap();
+                encoder.finish().unwrap()
+            }
+            PayloadCompression::Deflate => {
+                let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(PAYLOAD).unwrap();
+                encoder.finish().unwrap()
+            }
+            PayloadCompression::Zlib => {
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(PAYLOAD).unwrap();
+                encoder.finish().unwrap()
+            }
+            PayloadCompression::Zstd => zstd::encode_all(PAYLOAD, 0).unwrap(),
+            PayloadCompression::None | PayloadCompression::Auto => PAYLOAD.to_vec(),
+        }
+    }
+
+    #[rstest]
+    #[case(PayloadCompression::None)]
+    #[case(PayloadCompression::Gzip)]
+    #[case(PayloadCompression::Deflate)]
+    #[case(PayloadCompression::Zlib)]
+    #[case(PayloadCompression::Zstd)]
+    fn test_decompress(#[case] compression: PayloadCompression) {
+        let data = compress(compression);
+
+        assert_eq!(compression.decompress(&data).unwrap().as_ref(), PAYLOAD);
+    }
+
+    #[rstest]
+    #[case(PayloadCompression::None)]
+    #[case(PayloadCompression::Gzip)]
+    #[case(PayloadCompression::Zlib)]
+    #[case(PayloadCompression::Zstd)]
+    fn test_decompress_auto(#[case] compression: PayloadCompression) {
+        let data = compress(compression);
+
+        let result = PayloadCompression::Auto.decompress(&data).unwrap();
+
+        assert_eq!(result.as_ref(), PAYLOAD);
+    }
+
+    #[rstest]
+    fn test_decompress_invalid_payload_errors() {
+        assert!(PayloadCompression::Gzip.decompress(b"not gzip").is_err());
+    }
+
+    #[rstest]
+    fn test_from_str() {
+        assert_eq!(
+            "ZSTD".parse::<PayloadCompression>().unwrap(),
+            PayloadCompression::Zstd
+        );
+        assert!("brotli".parse::<PayloadCompression>().is_err());
+    }
+}
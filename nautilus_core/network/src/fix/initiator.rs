@@ -0,0 +1,158 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX initiator transport over TCP.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use nautilus_core::time::get_atomic_clock_realtime;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    sync::mpsc,
+    task::JoinHandle,
+};
+use tracing::{debug, error};
+
+use super::{
+    message::{FixFrameDecoder, FixMessage},
+    session::{FixSession, SessionEvent},
+    store::MessageStore,
+};
+
+/// The interval for driving the session timers (heartbeats and test requests).
+const TIMER_INTERVAL: Duration = Duration::from_secs(1);
+const READ_BUFFER_SIZE: usize = 8192;
+
+enum Command {
+    Send(FixMessage),
+    Logout(Option<String>),
+}
+
+/// Provides a FIX initiator connection, driving a [`FixSession`] over TCP.
+///
+/// Session events other than frames to send (application messages, logon and logout,
+/// and the final disconnect) are forwarded on the `events` channel given on connection.
+/// The task ends on disconnect, so the caller may reconnect with a new session over the
+/// same persistent store to resume the sequence numbers.
+pub struct FixInitiator {
+    commands: mpsc::UnboundedSender<Command>,
+    task: JoinHandle<Result<()>>,
+}
+
+impl FixInitiator {
+    /// Connects to the acceptor at `addr` and sends the logon.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection cannot be established.
+    pub async fn connect<S: MessageStore + 'static>(
+        addr: &str,
+        session: FixSession<S>,
+        events: mpsc::UnboundedSender<SessionEvent>,
+    ) -> Result<Self> {
+        let stream = TcpStream::connect(addr).await?;
+        stream.set_nodelay(true)?;
+        debug!("Connected to {addr}");
+
+        let (commands, commands_rx) = mpsc::unbounded_channel();
+        let task = tokio::spawn(run(stream, session, commands_rx, events));
+        Ok(Self { commands, task })
+    }
+
+    /// Sends an application message on the session.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection task has ended.
+    pub fn send(&self, message: FixMessage) -> Result<()> {
+        self.commands
+            .send(Command::Send(message))
+            .map_err(|_| anyhow!("FIX connection closed"))
+    }
+
+    /// Initiates a logout of the session.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection task has ended.
+    pub fn logout(&self, text: Option<String>) -> Result<()> {
+        self.commands
+            .send(Command::Logout(text))
+            .map_err(|_| anyhow!("FIX connection closed"))
+    }
+
+    #[must_use]
+    pub fn is_disconnected(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+async fn run<S: MessageStore>(
+    mut stream: TcpStream,
+    mut session: FixSession<S>,
+    mut commands: mpsc::UnboundedReceiver<Command>,
+    events: mpsc::UnboundedSender<SessionEvent>,
+) -> Result<()> {
+    let clock = get_atomic_clock_realtime();
+    let mut decoder = FixFrameDecoder::default();
+    let mut buffer = vec![0; READ_BUFFER_SIZE];
+    let mut timer = tokio::time::interval(TIMER_INTERVAL);
+
+    let mut pending = session.logon(clock.get_time_ns())?;
+    loop {
+        for event in pending.drain(..) {
+            match event {
+                SessionEvent::Send(frame) => stream.write_all(&frame).await?,
+                SessionEvent::Disconnect(reason) => {
+                    debug!("Disconnecting: {reason}");
+                    session.on_disconnect();
+                    let _ = events.send(SessionEvent::Disconnect(reason));
+                    return Ok(());
+                }
+                event => {
+                    let _ = events.send(event);
+                }
+            }
+        }
+
+        tokio::select! {
+            read = stream.read(&mut buffer) => {
+                let n = read?;
+                if n == 0 {
+                    session.on_disconnect();
+                    let _ = events.send(SessionEvent::Disconnect("Connection closed".to_string()));
+                    return Ok(());
+                }
+                decoder.extend(&buffer[..n]);
+                while let Some(frame) = decoder.next_frame()? {
+                    pending.extend(session.on_frame(&frame, clock.get_time_ns())?);
+                }
+            }
+            Some(command) = commands.recv() => match command {
+                Command::Send(message) => match session.send(&message, clock.get_time_ns()) {
+                    Ok(frame) => pending.push(SessionEvent::Send(frame)),
+                    Err(e) => error!("Error sending message: {e}"),
+                },
+                Command::Logout(text) => match session.logout(text, clock.get_time_ns()) {
+                    Ok(logout_events) => pending.extend(logout_events),
+                    Err(e) => error!("Error logging out: {e}"),
+                },
+            },
+            _ = timer.tick() => pending.extend(session.on_timer(clock.get_time_ns())?),
+        }
+    }
+}
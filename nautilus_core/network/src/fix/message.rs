@@ -0,0 +1,598 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Typed FIX tag=value message codec.
+
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, NaiveDateTime};
+use nautilus_core::time::UnixNanos;
+
+/// The FIX field delimiter (SOH).
+pub const SOH: u8 = 0x01;
+
+/// FIX field tags used by the session layer.
+pub mod tags {
+    pub const BEGIN_SEQ_NO: u32 = 7;
+    pub const BEGIN_STRING: u32 = 8;
+    pub const BODY_LENGTH: u32 = 9;
+    pub const CHECK_SUM: u32 = 10;
+    pub const END_SEQ_NO: u32 = 16;
+    pub const MSG_SEQ_NUM: u32 = 34;
+    pub const MSG_TYPE: u32 = 35;
+    pub const NEW_SEQ_NO: u32 = 36;
+    pub const POSS_DUP_FLAG: u32 = 43;
+    pub const REF_SEQ_NUM: u32 = 45;
+    pub const SENDER_COMP_ID: u32 = 49;
+    pub const SENDING_TIME: u32 = 52;
+    pub const TARGET_COMP_ID: u32 = 56;
+    pub const TEXT: u32 = 58;
+    pub const ENCRYPT_METHOD: u32 = 98;
+    pub const HEART_BT_INT: u32 = 108;
+    pub const TEST_REQ_ID: u32 = 112;
+    pub const ORIG_SENDING_TIME: u32 = 122;
+    pub const GAP_FILL_FLAG: u32 = 123;
+    pub const RESET_SEQ_NUM_FLAG: u32 = 141;
+}
+
+/// FIX message types used by the session layer.
+pub mod msg_types {
+    pub const HEARTBEAT: &str = "0";
+    pub const TEST_REQUEST: &str = "1";
+    pub const RESEND_REQUEST: &str = "2";
+    pub const REJECT: &str = "3";
+    pub const SEQUENCE_RESET: &str = "4";
+    pub const LOGOUT: &str = "5";
+    pub const LOGON: &str = "A";
+}
+
+/// Tags of the standard header which are set by the session rather than the application.
+const SESSION_HEADER_TAGS: [u32; 8] = [
+    tags::BEGIN_STRING,
+    tags::BODY_LENGTH,
+    tags::MSG_TYPE,
+    tags::SENDER_COMP_ID,
+    tags::TARGET_COMP_ID,
+    tags::MSG_SEQ_NUM,
+    tags::SENDING_TIME,
+    tags::CHECK_SUM,
+];
+
+const SENDING_TIME_FORMAT: &str = "%Y%m%d-%H:%M:%S%.3f";
+
+/// The FIX protocol version of a session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FixVersion {
+    Fix42,
+    Fix44,
+}
+
+impl FixVersion {
+    #[must_use]
+    pub fn begin_string(&self) -> &'static str {
+        match self {
+            Self::Fix42 => "FIX.4.2",
+            Self::Fix44 => "FIX.4.4",
+        }
+    }
+}
+
+impl FromStr for FixVersion {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "FIX.4.2" => Ok(Self::Fix42),
+            "FIX.4.4" => Ok(Self::Fix44),
+            _ => bail!("Unsupported FIX version '{s}'"),
+        }
+    }
+}
+
+impl Display for FixVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.begin_string())
+    }
+}
+
+/// Formats UNIX nanoseconds as a FIX UTC timestamp (`YYYYMMDD-HH:MM:SS.sss`).
+#[must_use]
+pub fn format_sending_time(ts: UnixNanos) -> String {
+    DateTime::from_timestamp_nanos(ts as i64)
+        .format(SENDING_TIME_FORMAT)
+        .to_string()
+}
+
+/// Parses a FIX UTC timestamp (with optional fractional seconds) as UNIX nanoseconds.
+pub fn parse_sending_time(value: &str) -> Result<UnixNanos> {
+    let dt = NaiveDateTime::parse_from_str(value, "%Y%m%d-%H:%M:%S%.f")?;
+    let ns = dt
+        .and_utc()
+        .timestamp_nanos_opt()
+        .ok_or_else(|| anyhow!("Timestamp out of range '{value}'"))?;
+    Ok(ns as UnixNanos)
+}
+
+/// Represents a FIX message as its message type and ordered fields.
+///
+/// The `BeginString`, `BodyLength` and `CheckSum` fields are not held, being added on
+/// encoding and validated on decoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixMessage {
+    msg_type: String,
+    fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    #[must_use]
+    pub fn new(msg_type: &str) -> Self {
+        Self {
+            msg_type: msg_type.to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn msg_type(&self) -> &str {
+        &self.msg_type
+    }
+
+    #[must_use]
+    pub fn fields(&self) -> &[(u32, String)] {
+        &self.fields
+    }
+
+    /// Returns whether the message is a session level (admin) message.
+    #[must_use]
+    pub fn is_admin(&self) -> bool {
+        matches!(
+            self.msg_type.as_str(),
+            msg_types::HEARTBEAT
+                | msg_types::TEST_REQUEST
+                | msg_types::RESEND_REQUEST
+                | msg_types::REJECT
+                | msg_types::SEQUENCE_RESET
+                | msg_types::LOGOUT
+                | msg_types::LOGON
+        )
+    }
+
+    /// Appends the field, returning the message (for chaining).
+    #[must_use]
+    pub fn with(mut self, tag: u32, value: impl ToString) -> Self {
+        self.push(tag, value);
+        self
+    }
+
+    /// Appends the field (repeating group fields may appear more than once).
+    pub fn push(&mut self, tag: u32, value: impl ToString) {
+        self.fields.push((tag, value.to_string()));
+    }
+
+    /// Sets the field, replacing the first existing value for the tag.
+    pub fn set(&mut self, tag: u32, value: impl ToString) {
+        match self.fields.iter_mut().find(|(t, _)| *t == tag) {
+            Some(field) => field.1 = value.to_string(),
+            None => self.push(tag, value),
+        }
+    }
+
+    /// Removes all values for the tag.
+    pub fn remove(&mut self, tag: u32) {
+        self.fields.retain(|(t, _)| *t != tag);
+    }
+
+    /// Returns the first value for the tag.
+    #[must_use]
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(t, _)| *t == tag)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Returns the first value for the tag parsed as `T`.
+    pub fn get_parsed<T>(&self, tag: u32) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self
+            .get(tag)
+            .ok_or_else(|| anyhow!("Missing tag {tag} in message type {}", self.msg_type))?;
+        value
+            .parse()
+            .map_err(|e| anyhow!("Invalid value '{value}' for tag {tag}: {e}"))
+    }
+
+    /// Returns the boolean (`Y`/`N`) value for the tag, being `false` if not present.
+    #[must_use]
+    pub fn get_flag(&self, tag: u32) -> bool {
+        self.get(tag) == Some("Y")
+    }
+
+    /// Returns the message sequence number.
+    #[must_use]
+    pub fn seq_num(&self) -> Option<u64> {
+        self.get_parsed(tags::MSG_SEQ_NUM).ok()
+    }
+
+    /// Returns the fields which are not set by the session header.
+    pub fn body_fields(&self) -> impl Iterator<Item = &(u32, String)> {
+        self.fields
+            .iter()
+            .filter(|(tag, _)| !SESSION_HEADER_TAGS.contains(tag))
+    }
+
+    /// Encodes the message as a FIX frame for the given `version`.
+    #[must_use]
+    pub fn encode(&self, version: FixVersion) -> Vec<u8> {
+        let mut body = Vec::with_capacity(128);
+        write_field(&mut body, tags::MSG_TYPE, &self.msg_type);
+        for (tag, value) in &self.fields {
+            write_field(&mut body, *tag, value);
+        }
+
+        let mut frame = Vec::with_capacity(body.len() + 32);
+        write_field(&mut frame, tags::BEGIN_STRING, version.begin_string());
+        write_field(&mut frame, tags::BODY_LENGTH, &body.len().to_string());
+        frame.extend_from_slice(&body);
+        let checksum = checksum(&frame);
+        write_field(&mut frame, tags::CHECK_SUM, &format!("{checksum:03}"));
+        frame
+    }
+
+    /// Decodes a complete FIX frame, validating the body length and checksum.
+    pub fn decode(frame: &[u8]) -> Result<(FixVersion, Self)> {
+        let mut fields = frame
+            .split(|b| *b == SOH)
+            .filter(|field| !field.is_empty())
+            .map(parse_field);
+
+        let (tag, begin_string) = fields.next().ok_or_else(|| anyhow!("Empty frame"))??;
+        if tag != tags::BEGIN_STRING {
+            bail!("Frame must start with BeginString, was tag {tag}");
+        }
+        let version = begin_string.parse()?;
+
+        let (tag, body_length) = fields
+            .next()
+            .ok_or_else(|| anyhow!("Missing BodyLength"))??;
+        if tag != tags::BODY_LENGTH {
+            bail!("Expected BodyLength as second field, was tag {tag}");
+        }
+        let body_length: usize = body_length.parse()?;
+
+        let checksum_start = frame
+            .len()
+            .checked_sub(7)
+            .filter(|start| frame[*start..].starts_with(b"10=") && frame.ends_with(&[SOH]))
+            .ok_or_else(|| anyhow!("Frame must end with CheckSum"))?;
+        // The body starts after the SOH terminating the BodyLength field
+        let body_start = frame
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b == SOH)
+            .nth(1)
+            .map(|(pos, _)| pos + 1)
+            .ok_or_else(|| anyhow!("Missing BodyLength"))?;
+        if checksum_start.checked_sub(body_start) != Some(body_length) {
+            bail!("BodyLength mismatch, expected {body_length}");
+        }
+        let expected_checksum: u8 =
+            std::str::from_utf8(&frame[checksum_start + 3..frame.len() - 1])?.parse()?;
+        let actual_checksum = checksum(&frame[..checksum_start]);
+        if actual_checksum != expected_checksum {
+            bail!("CheckSum mismatch, expected {expected_checksum:03} was {actual_checksum:03}");
+        }
+
+        let (tag, msg_type) = fields.next().ok_or_else(|| anyhow!("Missing MsgType"))??;
+        if tag != tags::MSG_TYPE {
+            bail!("Expected MsgType as third field, was tag {tag}");
+        }
+
+        let mut message = Self::new(&msg_type);
+        for field in fields {
+            let (tag, value) = field?;
+            if tag != tags::CHECK_SUM {
+                message.fields.push((tag, value));
+            }
+        }
+        Ok((version, message))
+    }
+}
+
+fn write_field(buf: &mut Vec<u8>, tag: u32, value: &str) {
+    buf.extend_from_slice(tag.to_string().as_bytes());
+    buf.push(b'=');
+    buf.extend_from_slice(value.as_bytes());
+    buf.push(SOH);
+}
+
+fn parse_field(field: &[u8]) -> Result<(u32, String)> {
+    let field = std::str::from_utf8(field)?;
+    let (tag, value) = field
+        .split_once('=')
+        .ok_or_else(|| anyhow!("Invalid field '{field}'"))?;
+    Ok((tag.parse()?, value.to_string()))
+}
+
+fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b))
+}
+
+/// Splits complete FIX frames from a byte stream.
+#[derive(Debug, Default)]
+pub struct FixFrameDecoder {
+    buffer: Vec<u8>,
+}
+
+impl FixFrameDecoder {
+    /// Appends received bytes to the buffer.
+    pub fn extend(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Returns the next complete frame from the buffer, or `None` if more bytes are needed.
+    pub fn next_frame(&mut self) -> Result<Option<Vec<u8>>> {
+        // Frames start `8=FIX.x.y<SOH>9=<len><SOH>`
+        let Some(begin_end) = self.buffer.iter().position(|b| *b == SOH) else {
+            return Ok(None);
+        };
+        if !self.buffer.starts_with(b"8=") {
+            bail!("Frame must start with BeginString");
+        }
+        let Some(length_end) = self.buffer[begin_end + 1..]
+            .iter()
+            .position(|b| *b == SOH)
+            .map(|pos| pos + begin_end + 1)
+        else {
+            return Ok(None);
+        };
+        let (tag, body_length) = parse_field(&self.buffer[begin_end + 1..length_end])?;
+        if tag != tags::BODY_LENGTH {
+            bail!("Expected BodyLength as second field, was tag {tag}");
+        }
+        let body_length: usize = body_length.parse()?;
+
+        // CheckSum field is always `10=nnn<SOH>`
+        let frame_len = length_end + 1 + body_length + 7;
+        if self.buffer.len() < frame_len {
+            return Ok(None);
+        }
+        Ok(Some(self.buffer.drain(..frame_len).collect()))
+    }
+}
+
+/// Represents a typed session level (admin) message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AdminMessage {
+    Logon {
+        heart_bt_int: u64,
+        reset_seq_num: bool,
+    },
+    Heartbeat {
+        test_req_id: Option<String>,
+    },
+    TestRequest {
+        test_req_id: String,
+    },
+    ResendRequest {
+        begin_seq_no: u64,
+        /// The last sequence number to resend (0 for all subsequent messages).
+        end_seq_no: u64,
+    },
+    SequenceReset {
+        new_seq_no: u64,
+        gap_fill: bool,
+    },
+    Reject {
+        ref_seq_num: u64,
+        text: Option<String>,
+    },
+    Logout {
+        text: Option<String>,
+    },
+}
+
+impl TryFrom<&FixMessage> for AdminMessage {
+    type Error = anyhow::Error;
+
+    fn try_from(message: &FixMessage) -> Result<Self> {
+        let text = message.get(tags::TEXT).map(ToString::to_string);
+        Ok(match message.msg_type() {
+            msg_types::LOGON => Self::Logon {
+                heart_bt_int: message.get_parsed(tags::HEART_BT_INT)?,
+                reset_seq_num: message.get_flag(tags::RESET_SEQ_NUM_FLAG),
+            },
+            msg_types::HEARTBEAT => Self::Heartbeat {
+                test_req_id: message.get(tags::TEST_REQ_ID).map(ToString::to_string),
+            },
+            msg_types::TEST_REQUEST => Self::TestRequest {
+                test_req_id: message.get_parsed(tags::TEST_REQ_ID)?,
+            },
+            msg_types::RESEND_REQUEST => Self::ResendRequest {
+                begin_seq_no: message.get_parsed(tags::BEGIN_SEQ_NO)?,
+                end_seq_no: message.get_parsed(tags::END_SEQ_NO)?,
+            },
+            msg_types::SEQUENCE_RESET => Self::SequenceReset {
+                new_seq_no: message.get_parsed(tags::NEW_SEQ_NO)?,
+                gap_fill: message.get_flag(tags::GAP_FILL_FLAG),
+            },
+            msg_types::REJECT => Self::Reject {
+                ref_seq_num: message.get_parsed(tags::REF_SEQ_NUM)?,
+                text,
+            },
+            msg_types::LOGOUT => Self::Logout { text },
+            msg_type => bail!("Message type '{msg_type}' is not an admin message"),
+        })
+    }
+}
+
+impl From<AdminMessage> for FixMessage {
+    fn from(message: AdminMessage) -> Self {
+        match message {
+            AdminMessage::Logon {
+                heart_bt_int,
+                reset_seq_num,
+            } => {
+                let msg = Self::new(msg_types::LOGON)
+                    .with(tags::ENCRYPT_METHOD, 0)
+                    .with(tags::HEART_BT_INT, heart_bt_int);
+                if reset_seq_num {
+                    msg.with(tags::RESET_SEQ_NUM_FLAG, "Y")
+                } else {
+                    msg
+                }
+            }
+            AdminMessage::Heartbeat { test_req_id } => {
+                let msg = Self::new(msg_types::HEARTBEAT);
+                match test_req_id {
+                    Some(id) => msg.with(tags::TEST_REQ_ID, id),
+                    None => msg,
+                }
+            }
+            AdminMessage::TestRequest { test_req_id } => {
+                Self::new(msg_types::TEST_REQUEST).with(tags::TEST_REQ_ID, test_req_id)
+            }
+            AdminMessage::ResendRequest {
+                begin_seq_no,
+                end_seq_no,
+            } => Self::new(msg_types::RESEND_REQUEST)
+                .with(tags::BEGIN_SEQ_NO, begin_seq_no)
+                .with(tags::END_SEQ_NO, end_seq_no),
+            AdminMessage::SequenceReset {
+                new_seq_no,
+                gap_fill,
+            } => Self::new(msg_types::SEQUENCE_RESET)
+                .with(tags::GAP_FILL_FLAG, if gap_fill { "Y" } else { "N" })
+                .with(tags::NEW_SEQ_NO, new_seq_no),
+            AdminMessage::Reject { ref_seq_num, text } => {
+                let msg = Self::new(msg_types::REJECT).with(tags::REF_SEQ_NUM, ref_seq_num);
+                match text {
+                    Some(text) => msg.with(tags::TEXT, text),
+                    None => msg,
+                }
+            }
+            AdminMessage::Logout { text } => {
+                let msg = Self::new(msg_types::LOGOUT);
+                match text {
+                    Some(text) => msg.with(tags::TEXT, text),
+                    None => msg,
+                }
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn to_wire(s: &str) -> Vec<u8> {
+        s.replace('|', "\u{1}").into_bytes()
+    }
+
+    #[rstest]
+    fn test_encode_matches_reference_frame() {
+        let message = FixMessage::new(msg_types::HEARTBEAT)
+            .with(tags::SENDER_COMP_ID, "CLIENT")
+            .with(tags::TARGET_COMP_ID, "VENUE")
+            .with(tags::MSG_SEQ_NUM, 1)
+            .with(tags::SENDING_TIME, "20240101-00:00:00.000");
+
+        let frame = message.encode(FixVersion::Fix44);
+
+        let body = "35=0|49=CLIENT|56=VENUE|34=1|52=20240101-00:00:00.000|";
+        let head = format!("8=FIX.4.4|9={}|", body.len());
+        let checksum = checksum(&to_wire(&format!("{head}{body}")));
+        assert_eq!(frame, to_wire(&format!("{head}{body}10={checksum:03}|")),);
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let message = FixMessage::new("D")
+            .with(tags::MSG_SEQ_NUM, 7)
+            .with(11, "O-123456789")
+            .with(55, "ESZ4")
+            .with(44, "5000.25");
+
+        let (version, decoded) = FixMessage::decode(&message.encode(FixVersion::Fix42)).unwrap();
+
+        assert_eq!(version, FixVersion::Fix42);
+        assert_eq!(decoded, message);
+        assert_eq!(decoded.seq_num(), Some(7));
+        assert_eq!(decoded.get_parsed::<f64>(44).unwrap(), 5000.25);
+        assert!(!decoded.is_admin());
+    }
+
+    #[rstest]
+    fn test_decode_rejects_bad_checksum() {
+        let mut frame = FixMessage::new(msg_types::HEARTBEAT).encode(FixVersion::Fix44);
+        let len = frame.len();
+        frame[len - 2] = if frame[len - 2] == b'0' { b'1' } else { b'0' };
+
+        assert!(FixMessage::decode(&frame).is_err());
+    }
+
+    #[rstest]
+    fn test_frame_decoder_splits_stream() {
+        let first = FixMessage::new(msg_types::HEARTBEAT).encode(FixVersion::Fix44);
+        let second = FixMessage::from(AdminMessage::TestRequest {
+            test_req_id: "TEST".to_string(),
+        })
+        .encode(FixVersion::Fix44);
+        let stream = [first.clone(), second.clone()].concat();
+
+        let split = first.len() + 5;
+
+        let mut decoder = FixFrameDecoder::default();
+        decoder.extend(&stream[..split]);
+        assert_eq!(decoder.next_frame().unwrap(), Some(first));
+        assert_eq!(decoder.next_frame().unwrap(), None);
+
+        decoder.extend(&stream[split..]);
+        assert_eq!(decoder.next_frame().unwrap(), Some(second));
+    }
+
+    #[rstest]
+    fn test_admin_message_round_trip() {
+        let admin = AdminMessage::SequenceReset {
+            new_seq_no: 10,
+            gap_fill: true,
+        };
+
+        let message = FixMessage::from(admin.clone());
+
+        assert!(message.is_admin());
+        assert_eq!(AdminMessage::try_from(&message).unwrap(), admin);
+    }
+
+    #[rstest]
+    fn test_sending_time_round_trip() {
+        let ts = 1_704_067_200_123_000_000;
+
+        let formatted = format_sending_time(ts);
+
+        assert_eq!(formatted, "20240101-00:00:00.123");
+        assert_eq!(parse_sending_time(&formatted).unwrap(), ts);
+    }
+}
@@ -0,0 +1,26 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A FIX 4.2/4.4 initiator engine.
+//!
+//! The engine is layered as a typed message codec ([`message`]), a transport independent
+//! session state machine ([`session`]) persisting its state through a [`store::MessageStore`],
+//! and a TCP transport ([`initiator`]) which drives the session. Venue adapters build on
+//! the application messages delivered in sequence by the session.
+
+pub mod initiator;
+pub mod message;
+pub mod session;
+pub mod store;
@@ -0,0 +1,774 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX initiator session state machine.
+//!
+//! The session is independent of any transport: it is driven by calling
+//! [`FixSession::on_frame`] with received frames and [`FixSession::on_timer`]
+//! periodically, and returns [`SessionEvent`]s for the transport to act on (such as
+//! frames to send). This allows the session logic to be used with any connection
+//! and tested deterministically.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use nautilus_core::time::UnixNanos;
+use tracing::{debug, info, warn};
+
+use super::{
+    message::{format_sending_time, msg_types, tags, AdminMessage, FixMessage, FixVersion},
+    store::MessageStore,
+};
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// Configuration for a FIX initiator session.
+#[derive(Clone, Debug)]
+pub struct FixSessionConfig {
+    pub version: FixVersion,
+    pub sender_comp_id: String,
+    pub target_comp_id: String,
+    /// The heartbeat interval (seconds) requested on logon.
+    pub heartbeat_interval_secs: u64,
+    /// If the sequence numbers are reset to 1 on logon.
+    pub reset_on_logon: bool,
+    /// Additional fields (such as credentials) included on the logon message.
+    pub logon_fields: Vec<(u32, String)>,
+}
+
+impl FixSessionConfig {
+    /// Returns the session ID, used to identify the session in a message store.
+    #[must_use]
+    pub fn session_id(&self) -> String {
+        format!(
+            "{}-{}-{}",
+            self.version.begin_string(),
+            self.sender_comp_id,
+            self.target_comp_id
+        )
+    }
+}
+
+/// The state of a FIX session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SessionState {
+    Disconnected,
+    LogonSent,
+    Active,
+    LogoutSent,
+}
+
+/// Represents an action for the transport or application resulting from session processing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionEvent {
+    /// Send the encoded frame to the counterparty.
+    Send(Vec<u8>),
+    /// An application message was received (in sequence).
+    Application(FixMessage),
+    /// The session is logged on.
+    LoggedOn,
+    /// The session is logged out, with the reason if any.
+    LoggedOut(Option<String>),
+    /// Close the connection for the given reason.
+    Disconnect(String),
+}
+
+/// Provides a FIX initiator session layer.
+///
+/// The session handles logon and logout, heartbeats and test requests, and sequence
+/// number management including resend requests (in both directions) and sequence resets.
+/// Messages received ahead of the expected sequence number are queued until the gap is
+/// filled, so the application always receives messages in sequence.
+pub struct FixSession<S: MessageStore> {
+    config: FixSessionConfig,
+    store: S,
+    state: SessionState,
+    last_sent_ns: UnixNanos,
+    last_received_ns: UnixNanos,
+    test_request_id: Option<String>,
+    resend_end: Option<u64>,
+    queued: BTreeMap<u64, FixMessage>,
+}
+
+impl<S: MessageStore> FixSession<S> {
+    #[must_use]
+    pub fn new(config: FixSessionConfig, store: S) -> Self {
+        Self {
+            config,
+            store,
+            state: SessionState::Disconnected,
+            last_sent_ns: 0,
+            last_received_ns: 0,
+            test_request_id: None,
+            resend_end: None,
+            queued: BTreeMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(&self) -> &FixSessionConfig {
+        &self.config
+    }
+
+    #[must_use]
+    pub fn state(&self) -> SessionState {
+        self.state
+    }
+
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.state == SessionState::Active
+    }
+
+    #[must_use]
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    fn heartbeat_interval_ns(&self) -> u64 {
+        self.config.heartbeat_interval_secs * NANOS_PER_SECOND
+    }
+
+    /// Initiates the session by sending a logon (following a new connection).
+    pub fn logon(&mut self, ts_now: UnixNanos) -> Result<Vec<SessionEvent>> {
+        if self.state != SessionState::Disconnected {
+            bail!("Cannot logon from state {:?}", self.state);
+        }
+        if self.config.reset_on_logon {
+            self.store.reset()?;
+        }
+        self.queued.clear();
+        self.resend_end = None;
+        self.test_request_id = None;
+        self.last_received_ns = ts_now;
+
+        let mut logon = FixMessage::from(AdminMessage::Logon {
+            heart_bt_int: self.config.heartbeat_interval_secs,
+            reset_seq_num: self.config.reset_on_logon,
+        });
+        for (tag, value) in &self.config.logon_fields {
+            logon.push(*tag, value);
+        }
+        let frame = self.send_message(&logon, ts_now)?;
+        self.state = SessionState::LogonSent;
+        info!("Sent logon for {}", self.config.session_id());
+        Ok(vec![SessionEvent::Send(frame)])
+    }
+
+    /// Initiates a logout, the session is logged out when the counterparty responds.
+    pub fn logout(&mut self, text: Option<String>, ts_now: UnixNanos) -> Result<Vec<SessionEvent>> {
+        if self.state != SessionState::Active {
+            bail!("Cannot logout from state {:?}", self.state);
+        }
+        let frame = self.send_message(&AdminMessage::Logout { text }.into(), ts_now)?;
+        self.state = SessionState::LogoutSent;
+        Ok(vec![SessionEvent::Send(frame)])
+    }
+
+    /// Notifies the session the connection was closed.
+    pub fn on_disconnect(&mut self) {
+        self.state = SessionState::Disconnected;
+        self.test_request_id = None;
+    }
+
+    /// Prepares an application message for sending, returning the encoded frame.
+    ///
+    /// The session header fields are set, and the frame is persisted to the store for
+    /// any later resend request.
+    pub fn send(&mut self, message: &FixMessage, ts_now: UnixNanos) -> Result<Vec<u8>> {
+        if self.state != SessionState::Active {
+            bail!(
+                "Cannot send message, session is not active ({:?})",
+                self.state
+            );
+        }
+        self.send_message(message, ts_now)
+    }
+
+    fn send_message(&mut self, message: &FixMessage, ts_now: UnixNanos) -> Result<Vec<u8>> {
+        let seq_num = self.store.next_sender_seq_num();
+        let frame = self
+            .with_header(message, seq_num, ts_now)
+            .encode(self.config.version);
+        self.store.store_sent(seq_num, &frame)?;
+        self.store.set_next_sender_seq_num(seq_num + 1)?;
+        self.last_sent_ns = ts_now;
+        Ok(frame)
+    }
+
+    fn with_header(&self, message: &FixMessage, seq_num: u64, ts_now: UnixNanos) -> FixMessage {
+        let mut out = FixMessage::new(message.msg_type())
+            .with(tags::SENDER_COMP_ID, &self.config.sender_comp_id)
+            .with(tags::TARGET_COMP_ID, &self.config.target_comp_id)
+            .with(tags::MSG_SEQ_NUM, seq_num)
+            .with(tags::SENDING_TIME, format_sending_time(ts_now));
+        for (tag, value) in message.body_fields() {
+            out.push(*tag, value);
+        }
+        out
+    }
+
+    /// Handles a received frame.
+    pub fn on_frame(&mut self, frame: &[u8], ts_now: UnixNanos) -> Result<Vec<SessionEvent>> {
+        let (version, message) = FixMessage::decode(frame)?;
+        if version != self.config.version {
+            return Ok(vec![SessionEvent::Disconnect(format!(
+                "Received {version} message on {} session",
+                self.config.version
+            ))]);
+        }
+        self.on_message(message, ts_now)
+    }
+
+    /// Handles a received (decoded) message.
+    pub fn on_message(
+        &mut self,
+        message: FixMessage,
+        ts_now: UnixNanos,
+    ) -> Result<Vec<SessionEvent>> {
+        self.last_received_ns = ts_now;
+        let mut events = Vec::new();
+
+        // A sequence reset (not gap fill) applies regardless of its sequence number
+        if message.msg_type() == msg_types::SEQUENCE_RESET && !message.get_flag(tags::GAP_FILL_FLAG)
+        {
+            let new_seq_no: u64 = message.get_parsed(tags::NEW_SEQ_NO)?;
+            self.reset_target_seq_num(new_seq_no)?;
+            self.drain_queued(&mut events, ts_now)?;
+            return Ok(events);
+        }
+
+        let Some(seq_num) = message.seq_num() else {
+            return Ok(vec![SessionEvent::Disconnect(
+                "Received message without MsgSeqNum".to_string(),
+            )]);
+        };
+        let expected = self.store.next_target_seq_num();
+
+        if seq_num < expected {
+            if message.get_flag(tags::POSS_DUP_FLAG) {
+                debug!("Ignoring possible duplicate seq_num {seq_num}");
+                return Ok(events);
+            }
+            let text = format!("MsgSeqNum too low, expected {expected} received {seq_num}");
+            return self.terminate(text, ts_now);
+        }
+
+        if seq_num > expected {
+            // A logon must be processed before the gap is recovered
+            if message.msg_type() == msg_types::LOGON {
+                self.process(&message, &mut events, ts_now)?;
+            } else {
+                self.queued.insert(seq_num, message);
+            }
+            if self.resend_end.is_none() {
+                warn!("Sequence gap detected, expected {expected} received {seq_num}");
+                let resend = AdminMessage::ResendRequest {
+                    begin_seq_no: expected,
+                    end_seq_no: 0,
+                };
+                events.push(SessionEvent::Send(
+                    self.send_message(&resend.into(), ts_now)?,
+                ));
+                self.resend_end = Some(seq_num);
+            }
+            return Ok(events);
+        }
+
+        self.process_in_sequence(message, &mut events, ts_now)?;
+        self.drain_queued(&mut events, ts_now)?;
+        Ok(events)
+    }
+
+    fn process_in_sequence(
+        &mut self,
+        message: FixMessage,
+        events: &mut Vec<SessionEvent>,
+        ts_now: UnixNanos,
+    ) -> Result<()> {
+        let seq_num = self.store.next_target_seq_num();
+        if message.msg_type() == msg_types::SEQUENCE_RESET {
+            // Gap fill, the next message will have the new sequence number
+            let new_seq_no: u64 = message.get_parsed(tags::NEW_SEQ_NO)?;
+            return self.reset_target_seq_num(new_seq_no.max(seq_num + 1));
+        }
+        self.store.set_next_target_seq_num(seq_num + 1)?;
+        self.process(&message, events, ts_now)
+    }
+
+    fn reset_target_seq_num(&mut self, new_seq_no: u64) -> Result<()> {
+        let expected = self.store.next_target_seq_num();
+        if new_seq_no < expected {
+            warn!("Ignoring sequence reset to {new_seq_no} lower than expected {expected}");
+            return Ok(());
+        }
+        self.store.set_next_target_seq_num(new_seq_no)?;
+        self.queued.retain(|seq_num, _| *seq_num >= new_seq_no);
+        Ok(())
+    }
+
+    fn drain_queued(&mut self, events: &mut Vec<SessionEvent>, ts_now: UnixNanos) -> Result<()> {
+        loop {
+            let expected = self.store.next_target_seq_num();
+            let Some(message) = self.queued.remove(&expected) else {
+                break;
+            };
+            self.process_in_sequence(message, events, ts_now)?;
+        }
+        if self.queued.is_empty() && self.resend_end.take().is_some() {
+            info!("Sequence gap recovered");
+        }
+        Ok(())
+    }
+
+    fn process(
+        &mut self,
+        message: &FixMessage,
+        events: &mut Vec<SessionEvent>,
+        ts_now: UnixNanos,
+    ) -> Result<()> {
+        if !message.is_admin() {
+            events.push(SessionEvent::Application(message.clone()));
+            return Ok(());
+        }
+
+        match AdminMessage::try_from(message)? {
+            AdminMessage::Logon { heart_bt_int, .. } => {
+                if self.state != SessionState::LogonSent {
+                    warn!("Received unexpected logon in state {:?}", self.state);
+                    return Ok(());
+                }
+                if heart_bt_int != self.config.heartbeat_interval_secs {
+                    warn!(
+                        "Counterparty HeartBtInt {heart_bt_int} differs from requested {}",
+                        self.config.heartbeat_interval_secs
+                    );
+                }
+                self.state = SessionState::Active;
+                info!("Logged on {}", self.config.session_id());
+                events.push(SessionEvent::LoggedOn);
+            }
+            AdminMessage::Heartbeat { test_req_id } => {
+                if test_req_id.is_some() && test_req_id == self.test_request_id {
+                    self.test_request_id = None;
+                }
+            }
+            AdminMessage::TestRequest { test_req_id } => {
+                let heartbeat = AdminMessage::Heartbeat {
+                    test_req_id: Some(test_req_id),
+                };
+                events.push(SessionEvent::Send(
+                    self.send_message(&heartbeat.into(), ts_now)?,
+                ));
+            }
+            AdminMessage::ResendRequest {
+                begin_seq_no,
+                end_seq_no,
+            } => self.resend(begin_seq_no, end_seq_no, events, ts_now)?,
+            AdminMessage::SequenceReset { .. } => {} // Handled on receipt
+            AdminMessage::Reject { ref_seq_num, text } => {
+                warn!(
+                    "Session reject for seq_num {ref_seq_num}: {}",
+                    text.as_deref().unwrap_or_default()
+                );
+                events.push(SessionEvent::Application(message.clone()));
+            }
+            AdminMessage::Logout { text } => {
+                if self.state != SessionState::LogoutSent {
+                    let logout = AdminMessage::Logout { text: None };
+                    events.push(SessionEvent::Send(
+                        self.send_message(&logout.into(), ts_now)?,
+                    ));
+                }
+                self.state = SessionState::Disconnected;
+                info!("Logged out {}", self.config.session_id());
+                events.push(SessionEvent::LoggedOut(text));
+                events.push(SessionEvent::Disconnect("Logged out".to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Resends the stored messages for a resend request, replacing admin messages (which
+    /// must not be resent) with gap fills.
+    fn resend(
+        &mut self,
+        begin_seq_no: u64,
+        end_seq_no: u64,
+        events: &mut Vec<SessionEvent>,
+        ts_now: UnixNanos,
+    ) -> Result<()> {
+        let next_sender = self.store.next_sender_seq_num();
+        let end_seq_no = if end_seq_no == 0 || end_seq_no >= next_sender {
+            next_sender - 1
+        } else {
+            end_seq_no
+        };
+        info!("Resending messages {begin_seq_no} to {end_seq_no}");
+
+        let mut stored: BTreeMap<u64, FixMessage> = BTreeMap::new();
+        for (seq_num, frame) in self.store.sent_messages(begin_seq_no, end_seq_no)? {
+            let (_, message) = FixMessage::decode(&frame)?;
+            if !message.is_admin() {
+                stored.insert(seq_num, message);
+            }
+        }
+
+        let mut gap_start: Option<u64> = None;
+        for seq_num in begin_seq_no..=end_seq_no {
+            match stored.remove(&seq_num) {
+                Some(mut message) => {
+                    if let Some(start) = gap_start.take() {
+                        events.push(SessionEvent::Send(self.gap_fill(start, seq_num, ts_now)));
+                    }
+                    if let Some(sending_time) = message.get(tags::SENDING_TIME) {
+                        let sending_time = sending_time.to_string();
+                        message.set(tags::ORIG_SENDING_TIME, sending_time);
+                    }
+                    message.set(tags::POSS_DUP_FLAG, "Y");
+                    message.set(tags::SENDING_TIME, format_sending_time(ts_now));
+                    events.push(SessionEvent::Send(message.encode(self.config.version)));
+                }
+                None => {
+                    gap_start.get_or_insert(seq_num);
+                }
+            }
+        }
+        if let Some(start) = gap_start {
+            events.push(SessionEvent::Send(self.gap_fill(
+                start,
+                end_seq_no + 1,
+                ts_now,
+            )));
+        }
+        self.last_sent_ns = ts_now;
+        Ok(())
+    }
+
+    fn gap_fill(&self, seq_num: u64, new_seq_no: u64, ts_now: UnixNanos) -> Vec<u8> {
+        let mut message = self.with_header(
+            &AdminMessage::SequenceReset {
+                new_seq_no,
+                gap_fill: true,
+            }
+            .into(),
+            seq_num,
+            ts_now,
+        );
+        message.set(tags::POSS_DUP_FLAG, "Y");
+        message.encode(self.config.version)
+    }
+
+    fn terminate(&mut self, text: String, ts_now: UnixNanos) -> Result<Vec<SessionEvent>> {
+        warn!("Terminating session: {text}");
+        let logout = AdminMessage::Logout {
+            text: Some(text.clone()),
+        };
+        let frame = self.send_message(&logout.into(), ts_now)?;
+        self.state = SessionState::Disconnected;
+        Ok(vec![
+            SessionEvent::Send(frame),
+            SessionEvent::LoggedOut(Some(text.clone())),
+            SessionEvent::Disconnect(text),
+        ])
+    }
+
+    /// Handles the passage of time, sending heartbeats and test requests as required and
+    /// detecting an unresponsive counterparty.
+    pub fn on_timer(&mut self, ts_now: UnixNanos) -> Result<Vec<SessionEvent>> {
+        let interval = self.heartbeat_interval_ns();
+        let since_received = ts_now.saturating_sub(self.last_received_ns);
+        match self.state {
+            SessionState::LogonSent | SessionState::LogoutSent if since_received >= interval => {
+                let text = format!("No response to {:?} within heartbeat interval", self.state);
+                self.state = SessionState::Disconnected;
+                Ok(vec![SessionEvent::Disconnect(text)])
+            }
+            SessionState::Active => {
+                let mut events = Vec::new();
+                if self.test_request_id.is_some() && since_received >= 2 * interval {
+                    self.state = SessionState::Disconnected;
+                    events.push(SessionEvent::Disconnect(
+                        "No response to test request".to_string(),
+                    ));
+                    return Ok(events);
+                }
+                // Allow some transmission delay before testing the counterparty
+                if self.test_request_id.is_none() && since_received >= interval + interval / 5 {
+                    let test_req_id = format!("TEST-{ts_now}");
+                    let test_request = AdminMessage::TestRequest {
+                        test_req_id: test_req_id.clone(),
+                    };
+                    events.push(SessionEvent::Send(
+                        self.send_message(&test_request.into(), ts_now)?,
+                    ));
+                    self.test_request_id = Some(test_req_id);
+                } else if ts_now.saturating_sub(self.last_sent_ns) >= interval {
+                    let heartbeat = AdminMessage::Heartbeat { test_req_id: None };
+                    events.push(SessionEvent::Send(
+                        self.send_message(&heartbeat.into(), ts_now)?,
+                    ));
+                }
+                Ok(events)
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::fix::store::MemoryStore;
+
+    const SECOND: u64 = NANOS_PER_SECOND;
+
+    fn config() -> FixSessionConfig {
+        FixSessionConfig {
+            version: FixVersion::Fix44,
+            sender_comp_id: "CLIENT".to_string(),
+            target_comp_id: "VENUE".to_string(),
+            heartbeat_interval_secs: 30,
+            reset_on_logon: true,
+            logon_fields: vec![(553, "user".to_string())],
+        }
+    }
+
+    fn inbound(message: FixMessage, seq_num: u64) -> FixMessage {
+        let mut out = FixMessage::new(message.msg_type())
+            .with(tags::SENDER_COMP_ID, "VENUE")
+            .with(tags::TARGET_COMP_ID, "CLIENT")
+            .with(tags::MSG_SEQ_NUM, seq_num);
+        for (tag, value) in message.fields() {
+            out.push(*tag, value);
+        }
+        out
+    }
+
+    fn execution_report(seq_num: u64) -> FixMessage {
+        inbound(FixMessage::new("8").with(11, "O-123456789"), seq_num)
+    }
+
+    fn sent(events: &[SessionEvent]) -> Vec<FixMessage> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                SessionEvent::Send(frame) => Some(FixMessage::decode(frame).unwrap().1),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn active_session() -> FixSession<MemoryStore> {
+        let mut session = FixSession::new(config(), MemoryStore::default());
+        session.logon(0).unwrap();
+        let logon = AdminMessage::Logon {
+            heart_bt_int: 30,
+            reset_seq_num: true,
+        };
+        session.on_message(inbound(logon.into(), 1), 0).unwrap();
+        session
+    }
+
+    #[rstest]
+    fn test_logon_handshake() {
+        let mut session = FixSession::new(config(), MemoryStore::default());
+
+        let events = session.logon(0).unwrap();
+        let logon = &sent(&events)[0];
+
+        assert_eq!(session.state(), SessionState::LogonSent);
+        assert_eq!(logon.msg_type(), msg_types::LOGON);
+        assert_eq!(logon.seq_num(), Some(1));
+        assert_eq!(logon.get(tags::HEART_BT_INT), Some("30"));
+        assert_eq!(logon.get(tags::RESET_SEQ_NUM_FLAG), Some("Y"));
+        assert_eq!(logon.get(553), Some("user"));
+
+        let response = AdminMessage::Logon {
+            heart_bt_int: 30,
+            reset_seq_num: true,
+        };
+        let events = session.on_message(inbound(response.into(), 1), 0).unwrap();
+
+        assert_eq!(events, vec![SessionEvent::LoggedOn]);
+        assert!(session.is_active());
+        assert_eq!(session.store().next_target_seq_num(), 2);
+    }
+
+    #[rstest]
+    fn test_test_request_answered_with_heartbeat() {
+        let mut session = active_session();
+        let test_request = AdminMessage::TestRequest {
+            test_req_id: "T1".to_string(),
+        };
+
+        let events = session
+            .on_message(inbound(test_request.into(), 2), SECOND)
+            .unwrap();
+
+        let heartbeat = &sent(&events)[0];
+        assert_eq!(heartbeat.msg_type(), msg_types::HEARTBEAT);
+        assert_eq!(heartbeat.get(tags::TEST_REQ_ID), Some("T1"));
+    }
+
+    #[rstest]
+    fn test_sequence_gap_requests_resend_and_delivers_in_order() {
+        let mut session = active_session();
+
+        let events = session.on_message(execution_report(4), SECOND).unwrap();
+
+        let resend = &sent(&events)[0];
+        assert_eq!(resend.msg_type(), msg_types::RESEND_REQUEST);
+        assert_eq!(resend.get(tags::BEGIN_SEQ_NO), Some("2"));
+        assert_eq!(resend.get(tags::END_SEQ_NO), Some("0"));
+        assert!(!events
+            .iter()
+            .any(|e| matches!(e, SessionEvent::Application(_))));
+
+        // Counterparty gap fills an admin message then resends the application message
+        let gap_fill = AdminMessage::SequenceReset {
+            new_seq_no: 3,
+            gap_fill: true,
+        };
+        let events = session
+            .on_message(
+                inbound(gap_fill.into(), 2).with(tags::POSS_DUP_FLAG, "Y"),
+                SECOND,
+            )
+            .unwrap();
+        assert!(events.is_empty());
+
+        let events = session
+            .on_message(execution_report(3).with(tags::POSS_DUP_FLAG, "Y"), SECOND)
+            .unwrap();
+        let delivered: Vec<Option<u64>> = events
+            .iter()
+            .filter_map(|e| match e {
+                SessionEvent::Application(message) => Some(message.seq_num()),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(delivered, vec![Some(3), Some(4)]);
+        assert_eq!(session.store().next_target_seq_num(), 5);
+    }
+
+    #[rstest]
+    fn test_resend_request_resends_application_messages_and_gap_fills_admin() {
+        let mut session = active_session();
+        let order = FixMessage::new("D").with(11, "O-123456789");
+        session.send(&order, SECOND).unwrap();
+        session.send(&order, SECOND).unwrap();
+        let resend_request = AdminMessage::ResendRequest {
+            begin_seq_no: 1,
+            end_seq_no: 0,
+        };
+
+        let events = session
+            .on_message(inbound(resend_request.into(), 2), 2 * SECOND)
+            .unwrap();
+        let resent = sent(&events);
+
+        assert_eq!(resent.len(), 3);
+        // The logon is replaced with a gap fill
+        assert_eq!(resent[0].msg_type(), msg_types::SEQUENCE_RESET);
+        assert_eq!(resent[0].seq_num(), Some(1));
+        assert_eq!(resent[0].get(tags::NEW_SEQ_NO), Some("2"));
+        assert_eq!(resent[0].get(tags::GAP_FILL_FLAG), Some("Y"));
+        for (message, seq_num) in resent[1..].iter().zip([2, 3]) {
+            assert_eq!(message.msg_type(), "D");
+            assert_eq!(message.seq_num(), Some(seq_num));
+            assert_eq!(message.get(tags::POSS_DUP_FLAG), Some("Y"));
+            assert_eq!(
+                message.get(tags::ORIG_SENDING_TIME),
+                Some("19700101-00:00:01.000")
+            );
+        }
+        // Resending does not consume sequence numbers
+        assert_eq!(session.store().next_sender_seq_num(), 4);
+    }
+
+    #[rstest]
+    fn test_sequence_reset_sets_next_target_seq_num() {
+        let mut session = active_session();
+        let reset = AdminMessage::SequenceReset {
+            new_seq_no: 10,
+            gap_fill: false,
+        };
+
+        session
+            .on_message(inbound(reset.into(), 99), SECOND)
+            .unwrap();
+
+        assert_eq!(session.store().next_target_seq_num(), 10);
+    }
+
+    #[rstest]
+    fn test_seq_num_too_low_terminates_session() {
+        let mut session = active_session();
+
+        let events = session.on_message(execution_report(1), SECOND).unwrap();
+
+        assert_eq!(sent(&events)[0].msg_type(), msg_types::LOGOUT);
+        assert!(matches!(events.last(), Some(SessionEvent::Disconnect(_))));
+        assert_eq!(session.state(), SessionState::Disconnected);
+
+        // Possible duplicates are ignored
+        let mut session = active_session();
+        let events = session
+            .on_message(execution_report(1).with(tags::POSS_DUP_FLAG, "Y"), SECOND)
+            .unwrap();
+        assert!(events.is_empty());
+        assert!(session.is_active());
+    }
+
+    #[rstest]
+    fn test_timer_heartbeats_and_test_requests() {
+        let mut session = active_session();
+
+        let heartbeat = sent(&session.on_timer(30 * SECOND).unwrap());
+        assert_eq!(heartbeat[0].msg_type(), msg_types::HEARTBEAT);
+
+        let test_request = sent(&session.on_timer(36 * SECOND).unwrap());
+        assert_eq!(test_request[0].msg_type(), msg_types::TEST_REQUEST);
+
+        let events = session.on_timer(60 * SECOND).unwrap();
+        assert!(matches!(events[..], [SessionEvent::Disconnect(_)]));
+        assert_eq!(session.state(), SessionState::Disconnected);
+    }
+
+    #[rstest]
+    fn test_logout_handshake() {
+        let mut session = active_session();
+
+        let events = session.logout(None, SECOND).unwrap();
+        assert_eq!(sent(&events)[0].msg_type(), msg_types::LOGOUT);
+        assert_eq!(session.state(), SessionState::LogoutSent);
+
+        let response = AdminMessage::Logout { text: None };
+        let events = session
+            .on_message(inbound(response.into(), 2), SECOND)
+            .unwrap();
+
+        assert!(sent(&events).is_empty());
+        assert_eq!(events[0], SessionEvent::LoggedOut(None));
+        assert_eq!(session.state(), SessionState::Disconnected);
+    }
+}
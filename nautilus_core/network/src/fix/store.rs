@@ -0,0 +1,280 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Message stores persisting FIX session sequence numbers and sent messages.
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Result};
+
+/// Provides persistence of the FIX session state required for recovery, being the next
+/// sequence numbers and the sent messages (for resend requests).
+pub trait MessageStore: Send {
+    fn next_sender_seq_num(&self) -> u64;
+    fn next_target_seq_num(&self) -> u64;
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> Result<()>;
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> Result<()>;
+    /// Stores the encoded frame of a sent message.
+    fn store_sent(&mut self, seq_num: u64, frame: &[u8]) -> Result<()>;
+    /// Returns the sent frames from `begin` to `end` inclusive (0 for all after `begin`).
+    fn sent_messages(&self, begin: u64, end: u64) -> Result<Vec<(u64, Vec<u8>)>>;
+    /// Resets the sequence numbers to 1 and clears the sent messages.
+    fn reset(&mut self) -> Result<()>;
+}
+
+fn sent_range(messages: &BTreeMap<u64, Vec<u8>>, begin: u64, end: u64) -> Vec<(u64, Vec<u8>)> {
+    let end = if end == 0 { u64::MAX } else { end };
+    if begin > end {
+        return Vec::new();
+    }
+    messages
+        .range(begin..=end)
+        .map(|(seq_num, frame)| (*seq_num, frame.clone()))
+        .collect()
+}
+
+/// Provides an in-memory message store (state is lost on restart).
+#[derive(Clone, Debug)]
+pub struct MemoryStore {
+    next_sender_seq_num: u64,
+    next_target_seq_num: u64,
+    messages: BTreeMap<u64, Vec<u8>>,
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self {
+            next_sender_seq_num: 1,
+            next_target_seq_num: 1,
+            messages: BTreeMap::new(),
+        }
+    }
+}
+
+impl MessageStore for MemoryStore {
+    fn next_sender_seq_num(&self) -> u64 {
+        self.next_sender_seq_num
+    }
+
+    fn next_target_seq_num(&self) -> u64 {
+        self.next_target_seq_num
+    }
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> Result<()> {
+        self.next_sender_seq_num = seq_num;
+        Ok(())
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> Result<()> {
+        self.next_target_seq_num = seq_num;
+        Ok(())
+    }
+
+    fn store_sent(&mut self, seq_num: u64, frame: &[u8]) -> Result<()> {
+        self.messages.insert(seq_num, frame.to_vec());
+        Ok(())
+    }
+
+    fn sent_messages(&self, begin: u64, end: u64) -> Result<Vec<(u64, Vec<u8>)>> {
+        Ok(sent_range(&self.messages, begin, end))
+    }
+
+    fn reset(&mut self) -> Result<()> {
+        *self = Self::default();
+        Ok(())
+    }
+}
+
+/// Provides a file backed message store for a session, so that sequence numbers and sent
+/// messages survive a restart.
+///
+/// The store writes two files in the given directory, named after the session ID:
+/// `<session_id>.seqnums` holding the next sender and target sequence numbers, and
+/// `<session_id>.body` holding the sent messages as `<seq_num> <length>` header lines
+/// followed by the raw frame bytes.
+#[derive(Debug)]
+pub struct FileStore {
+    seqnums_path: PathBuf,
+    body_path: PathBuf,
+    cache: MemoryStore,
+}
+
+impl FileStore {
+    /// Opens (or creates) the store for `session_id` in the directory `dir`.
+    pub fn open(dir: impl AsRef<Path>, session_id: &str) -> Result<Self> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        let mut store = Self {
+            seqnums_path: dir.join(format!("{session_id}.seqnums")),
+            body_path: dir.join(format!("{session_id}.body")),
+            cache: MemoryStore::default(),
+        };
+        store.load()?;
+        Ok(store)
+    }
+
+    fn load(&mut self) -> Result<()> {
+        if self.seqnums_path.exists() {
+            let content = fs::read_to_string(&self.seqnums_path)?;
+            let (sender, target) = content
+                .trim()
+                .split_once(':')
+                .ok_or_else(|| anyhow!("Invalid seqnums file '{content}'"))?;
+            self.cache.next_sender_seq_num = sender.parse()?;
+            self.cache.next_target_seq_num = target.parse()?;
+        }
+
+        if self.body_path.exists() {
+            let mut reader = BufReader::new(File::open(&self.body_path)?);
+            let mut header = String::new();
+            while reader.read_line(&mut header)? > 0 {
+                let (seq_num, len) = header
+                    .trim()
+                    .split_once(' ')
+                    .ok_or_else(|| anyhow!("Invalid body record header '{header}'"))?;
+                let seq_num: u64 = seq_num.parse()?;
+                let mut frame = vec![0; len.parse()?];
+                if let Err(e) = reader.read_exact(&mut frame) {
+                    bail!("Truncated body record for seq_num {seq_num}: {e}");
+                }
+                self.cache.messages.insert(seq_num, frame);
+                header.clear();
+            }
+        }
+        Ok(())
+    }
+
+    fn write_seqnums(&self) -> Result<()> {
+        // Write then rename so a crash cannot leave a partially written file
+        let tmp_path = self.seqnums_path.with_extension("seqnums.tmp");
+        fs::write(
+            &tmp_path,
+            format!(
+                "{}:{}",
+                self.cache.next_sender_seq_num, self.cache.next_target_seq_num
+            ),
+        )?;
+        fs::rename(tmp_path, &self.seqnums_path)?;
+        Ok(())
+    }
+}
+
+impl MessageStore for FileStore {
+    fn next_sender_seq_num(&self) -> u64 {
+        self.cache.next_sender_seq_num
+    }
+
+    fn next_target_seq_num(&self) -> u64 {
+        self.cache.next_target_seq_num
+    }
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> Result<()> {
+        self.cache.next_sender_seq_num = seq_num;
+        self.write_seqnums()
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> Result<()> {
+        self.cache.next_target_seq_num = seq_num;
+        self.write_seqnums()
+    }
+
+    fn store_sent(&mut self, seq_num: u64, frame: &[u8]) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.body_path)?;
+        file.write_all(format!("{seq_num} {}\n", frame.len()).as_bytes())?;
+        file.write_all(frame)?;
+        file.flush()?;
+        self.cache.messages.insert(seq_num, frame.to_vec());
+        Ok(())
+    }
+
+    fn sent_messages(&self, begin: u64, end: u64) -> Result<Vec<(u64, Vec<u8>)>> {
+        Ok(sent_range(&self.cache.messages, begin, end))
+    }
+
+    fn reset(&mut self) -> Result<()> {
+        self.cache.reset()?;
+        if self.body_path.exists() {
+            fs::remove_file(&self.body_path)?;
+        }
+        self.write_seqnums()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_memory_store_sent_messages_range() {
+        let mut store = MemoryStore::default();
+        for seq_num in 1..=5 {
+            store.store_sent(seq_num, &[seq_num as u8]).unwrap();
+        }
+
+        let range: Vec<u64> = store
+            .sent_messages(2, 4)
+            .unwrap()
+            .into_iter()
+            .map(|(seq_num, _)| seq_num)
+            .collect();
+        let open_ended = store.sent_messages(4, 0).unwrap();
+
+        assert_eq!(range, vec![2, 3, 4]);
+        assert_eq!(open_ended, vec![(4, vec![4]), (5, vec![5])]);
+    }
+
+    #[rstest]
+    fn test_file_store_recovers_state() {
+        let dir = std::env::temp_dir().join(format!("nautilus-fix-store-{}", std::process::id()));
+        {
+            let mut store = FileStore::open(&dir, "CLIENT-VENUE").unwrap();
+            store.set_next_sender_seq_num(3).unwrap();
+            store.set_next_target_seq_num(8).unwrap();
+            store.store_sent(1, b"8=FIX.4.4\x01first").unwrap();
+            store.store_sent(2, b"second\nwith newline").unwrap();
+        }
+
+        let mut store = FileStore::open(&dir, "CLIENT-VENUE").unwrap();
+
+        assert_eq!(store.next_sender_seq_num(), 3);
+        assert_eq!(store.next_target_seq_num(), 8);
+        assert_eq!(
+            store.sent_messages(1, 0).unwrap(),
+            vec![
+                (1, b"8=FIX.4.4\x01first".to_vec()),
+                (2, b"second\nwith newline".to_vec())
+            ]
+        );
+
+        store.reset().unwrap();
+        let store = FileStore::open(&dir, "CLIENT-VENUE").unwrap();
+        assert_eq!(store.next_sender_seq_num(), 1);
+        assert!(store.sent_messages(1, 0).unwrap().is_empty());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
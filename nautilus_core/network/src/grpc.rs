@@ -0,0 +1,521 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A gRPC client with the rate limiting and retry facilities of the HTTP client.
+//!
+//! Rust callers wrap generated `tonic` clients with [`InnerGrpcClient::call`], while
+//! Python callers make unary calls with pre-encoded protobuf messages through [`GrpcClient`].
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    num::NonZeroU32,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use bytes::{Buf, BufMut};
+use nautilus_core::python::{to_pyruntime_err, to_pyvalue_err};
+use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
+use tonic::{
+    codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder},
+    codegen::http::uri::PathAndQuery,
+    metadata::{MetadataKey, MetadataMap, MetadataValue},
+    transport::{Channel, ClientTlsConfig, Endpoint},
+    Code, Request, Response, Status,
+};
+use tracing::warn;
+
+use crate::{
+    http::{await_rate_limits, RateLimitPolicy},
+    ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter},
+    retry::RetryPolicy,
+};
+
+/// The metadata key for a server supplied retry delay (milliseconds).
+pub const GRPC_RETRY_PUSHBACK_KEY: &str = "grpc-retry-pushback-ms";
+
+/// Maps a gRPC status code to the equivalent HTTP status code.
+///
+/// This allows the status matchers of a [`RetryPolicy`] (e.g. `429`, `5xx`) to apply to
+/// gRPC calls, where `UNAVAILABLE` maps to `503` and `RESOURCE_EXHAUSTED` to `429`.
+#[must_use]
+pub fn code_to_http_status(code: Code) -> u16 {
+    match code {
+        Code::Ok => 200,
+        Code::Cancelled => 499,
+        Code::InvalidArgument | Code::FailedPrecondition | Code::OutOfRange => 400,
+        Code::Unauthenticated => 401,
+        Code::PermissionDenied => 403,
+        Code::NotFound => 404,
+        Code::AlreadyExists | Code::Aborted => 409,
+        Code::ResourceExhausted => 429,
+        Code::Unknown | Code::Internal | Code::DataLoss => 500,
+        Code::Unimplemented => 501,
+        Code::Unavailable => 503,
+        Code::DeadlineExceeded => 504,
+    }
+}
+
+/// Parses the server supplied retry delay from the `metadata` of a failed call.
+#[must_use]
+pub fn parse_retry_pushback(metadata: &MetadataMap) -> Option<Duration> {
+    let value = metadata.get(GRPC_RETRY_PUSHBACK_KEY)?.to_str().ok()?;
+    // A negative value indicates the call should not be retried
+    value.trim().parse::<u64>().ok().map(Duration::from_millis)
+}
+
+/// Provides a codec which passes pre-encoded (protobuf) messages through as bytes.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BytesCodec;
+
+impl Codec for BytesCodec {
+    type Encode = Vec<u8>;
+    type Decode = Vec<u8>;
+    type Encoder = Self;
+    type Decoder = Self;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        *self
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        *self
+    }
+}
+
+impl Encoder for BytesCodec {
+    type Item = Vec<u8>;
+    type Error = Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        dst.put_slice(&item);
+        Ok(())
+    }
+}
+
+impl Decoder for BytesCodec {
+    type Item = Vec<u8>;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        let mut item = vec![0; src.remaining()];
+        src.copy_to_slice(&mut item);
+        Ok(Some(item))
+    }
+}
+
+/// Provides a gRPC client over a shared (multiplexed) channel.
+///
+/// When a [`RetryPolicy`] is set, failed calls which are safe to repeat are retried
+/// according to the policy, honouring any server retry pushback.
+#[derive(Clone)]
+pub struct InnerGrpcClient {
+    channel: Channel,
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl InnerGrpcClient {
+    #[must_use]
+    pub fn new(channel: Channel, retry_policy: Option<RetryPolicy>) -> Self {
+        Self {
+            channel,
+            retry_policy,
+        }
+    }
+
+    /// Creates a new client connecting lazily to `url`, using TLS for `https` URLs.
+    ///
+    /// Must be called from within a Tokio runtime.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the URL is invalid or TLS cannot be configured.
+    pub fn connect_lazy(
+        url: String,
+        timeout: Option<Duration>,
+        retry_policy: Option<RetryPolicy>,
+    ) -> anyhow::Result<Self> {
+        let is_tls = url.starts_with("https://");
+        let mut endpoint = Endpoint::from_shared(url)?;
+        if is_tls {
+            endpoint = endpoint.tls_config(ClientTlsConfig::new())?;
+        }
+        if let Some(timeout) = timeout {
+            endpoint = endpoint.timeout(timeout);
+        }
+        Ok(Self::new(endpoint.connect_lazy(), retry_policy))
+    }
+
+    /// Returns a handle to the channel, for constructing generated clients.
+    #[must_use]
+    pub fn channel(&self) -> Channel {
+        self.channel.clone()
+    }
+
+    /// Makes a call with `f`, retrying according to the policy if the call is `retryable`.
+    ///
+    /// A call should only be marked as retryable if it is idempotent (or carries an
+    /// idempotency key), as a failed call may still have been processed by the server.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the status of the last attempt on failure.
+    pub async fn call<T, F, Fut>(&self, retryable: bool, mut f: F) -> Result<Response<T>, Status>
+    where
+        F: FnMut(Channel) -> Fut,
+        Fut: Future<Output = Result<Response<T>, Status>>,
+    {
+        let policy = match &self.retry_policy {
+            Some(policy) if retryable => policy,
+            _ => return f(self.channel()).await,
+        };
+
+        let start = Instant::now();
+        let mut backoff = policy.backoff();
+        loop {
+            let status = match f(self.channel()).await {
+                Ok(response) => return Ok(response),
+                Err(status) if policy.is_retryable_status(code_to_http_status(status.code())) => {
+                    status
+                }
+                Err(status) => return Err(status),
+            };
+
+            let retry_after = parse_retry_pushback(status.metadata());
+            match policy.next_delay(&mut backoff, retry_after, start.elapsed()) {
+                Some(delay) => {
+                    warn!(
+                        "Retrying gRPC call in {delay:?} (attempt {}): {status}",
+                        backoff.attempts(),
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                None => return Err(status),
+            }
+        }
+    }
+
+    /// Makes a unary call to the method at `path` (e.g. `/package.Service/Method`) with
+    /// the encoded `request` message, returning the encoded response message.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the path or metadata is invalid, or the call fails.
+    pub async fn unary(
+        &self,
+        path: &str,
+        request: Vec<u8>,
+        metadata: HashMap<String, String>,
+        idempotent: bool,
+    ) -> Result<GrpcResponse, Status> {
+        let path = PathAndQuery::try_from(path)
+            .map_err(|e| Status::invalid_argument(format!("Invalid path '{path}': {e}")))?;
+
+        let mut metadata_map = MetadataMap::new();
+        for (key, value) in &metadata {
+            let key = MetadataKey::from_bytes(key.to_lowercase().as_bytes())
+                .map_err(|e| Status::invalid_argument(format!("Invalid metadata key: {e}")))?;
+            let value = MetadataValue::try_from(value.as_str())
+                .map_err(|e| Status::invalid_argument(format!("Invalid metadata value: {e}")))?;
+            metadata_map.insert(key, value);
+        }
+
+        let retryable = idempotent
+            || self.retry_policy.as_ref().is_some_and(|policy| {
+                metadata
+                    .keys()
+                    .any(|key| key.eq_ignore_ascii_case(policy.idempotency_header()))
+            });
+
+        let response = self
+            .call(retryable, |channel| {
+                let path = path.clone();
+                let mut request = Request::new(request.clone());
+                *request.metadata_mut() = metadata_map.clone();
+                async move {
+                    let mut grpc = tonic::client::Grpc::new(channel);
+                    grpc.ready()
+                        .await
+                        .map_err(|e| Status::unavailable(format!("Channel not ready: {e}")))?;
+                    grpc.unary(request, path, BytesCodec).await
+                }
+            })
+            .await?;
+
+        let metadata = response
+            .metadata()
+            .iter()
+            .filter_map(|entry| match entry {
+                tonic::metadata::KeyAndValueRef::Ascii(key, value) => value
+                    .to_str()
+                    .ok()
+                    .map(|value| (key.to_string(), value.to_string())),
+                tonic::metadata::KeyAndValueRef::Binary(..) => None,
+            })
+            .collect();
+
+        Ok(GrpcResponse {
+            metadata,
+            body: response.into_inner(),
+        })
+    }
+}
+
+/// GrpcResponse contains the encoded message and metadata from a gRPC call.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct GrpcResponse {
+    #[pyo3(get)]
+    metadata: HashMap<String, String>,
+    body: Vec<u8>,
+}
+
+#[pymethods]
+impl GrpcResponse {
+    #[getter]
+    fn get_body(&self, py: Python) -> PyResult<Py<PyBytes>> {
+        Ok(PyBytes::new(py, &self.body).into())
+    }
+}
+
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct GrpcClient {
+    rate_limiter: Arc<RateLimiter<String, MonotonicClock>>,
+    client: InnerGrpcClient,
+}
+
+#[pymethods]
+impl GrpcClient {
+    /// Create a new GrpcClient.
+    ///
+    /// * `url` - The server URL (e.g. `https://host:443`), TLS is used for `https`.
+    /// * `keyed_quotas` - A list of string quota pairs that gives quota for specific key values.
+    /// * `default_quota` - The default rate limiting quota for any call.
+    /// * `retry_policy` - The policy for retrying failed calls (no retries if `None`).
+    /// * `timeout_secs` - The timeout for each call attempt.
+    #[new]
+    #[pyo3(signature = (
+        url,
+        keyed_quotas = Vec::new(),
+        default_quota = None,
+        retry_policy = None,
+        timeout_secs = None,
+    ))]
+    fn py_new(
+        url: String,
+        keyed_quotas: Vec<(String, Quota)>,
+        default_quota: Option<Quota>,
+        retry_policy: Option<RetryPolicy>,
+        timeout_secs: Option<u64>,
+    ) -> PyResult<Self> {
+        // The channel spawns its connection task on the runtime
+        let _guard = pyo3_asyncio::tokio::get_runtime().enter();
+        let client =
+            InnerGrpcClient::connect_lazy(url, timeout_secs.map(Duration::from_secs), retry_policy)
+                .map_err(to_pyvalue_err)?;
+
+        Ok(Self {
+            rate_limiter: Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas)),
+            client,
+        })
+    }
+
+    /// Make a unary gRPC call.
+    ///
+    /// * `path` - The method path, e.g. `/package.Service/Method`.
+    /// * `request` - The encoded protobuf request message.
+    /// * `metadata` - The metadata key value pairs for the call.
+    /// * `keys` - The keys used for rate limiting the call.
+    /// * `weight` - The weight of the call against the quota of each key (default 1).
+    /// * `policy` - The policy if the call exceeds a quota (default `QUEUE`).
+    /// * `idempotent` - If the call may be retried on failure.
+    #[pyo3(name = "unary")]
+    #[pyo3(signature = (
+        path,
+        request,
+        metadata = None,
+        keys = None,
+        weight = None,
+        policy = None,
+        idempotent = false,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn py_unary<'py>(
+        &self,
+        path: String,
+        request: &'py PyBytes,
+        metadata: Option<HashMap<String, String>>,
+        keys: Option<Vec<String>>,
+        weight: Option<u32>,
+        policy: Option<RateLimitPolicy>,
+        idempotent: bool,
+        py: Python<'py>,
+    ) -> PyResult<&'py PyAny> {
+        let request = request.as_bytes().to_vec();
+        let metadata = metadata.unwrap_or_default();
+        let keys = keys.unwrap_or_default();
+        let weight = NonZeroU32::new(weight.unwrap_or(1))
+            .ok_or_else(|| to_pyvalue_err("`weight` must be a positive integer"))?;
+        let policy = policy.unwrap_or(RateLimitPolicy::QUEUE);
+        let client = self.client.clone();
+        let rate_limiter = self.rate_limiter.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            await_rate_limits(&rate_limiter, &keys, weight, policy).await?;
+            client
+                .unary(&path, request, metadata, idempotent)
+                .await
+                .map_err(|status| match status.code() {
+                    Code::InvalidArgument => to_pyvalue_err(status.message()),
+                    Code::Unavailable | Code::DeadlineExceeded => to_pyruntime_err(status),
+                    code => PyErr::new::<PyException, _>(format!(
+                        "gRPC error {code:?}: {}",
+                        status.message()
+                    )),
+                })
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn client_with_retries(max_retries: u32) -> InnerGrpcClient {
+        let policy = RetryPolicy::new(
+            max_retries,
+            Duration::from_millis(1),
+            Duration::from_millis(5),
+            2.0,
+            0,
+            None,
+            vec!["429".parse().unwrap(), "5xx".parse().unwrap()],
+            true,
+            "idempotency-key".to_string(),
+        )
+        .unwrap();
+        let channel = Endpoint::from_static("http://127.0.0.1:1").connect_lazy();
+        InnerGrpcClient::new(channel, Some(policy))
+    }
+
+    #[rstest]
+    #[case(Code::Unavailable, 503)]
+    #[case(Code::ResourceExhausted, 429)]
+    #[case(Code::DeadlineExceeded, 504)]
+    #[case(Code::InvalidArgument, 400)]
+    #[case(Code::NotFound, 404)]
+    fn test_code_to_http_status(#[case] code: Code, #[case] expected: u16) {
+        assert_eq!(code_to_http_status(code), expected);
+    }
+
+    #[rstest]
+    fn test_parse_retry_pushback() {
+        let mut metadata = MetadataMap::new();
+        assert_eq!(parse_retry_pushback(&metadata), None);
+
+        metadata.insert(GRPC_RETRY_PUSHBACK_KEY, "250".parse().unwrap());
+        assert_eq!(
+            parse_retry_pushback(&metadata),
+            Some(Duration::from_millis(250))
+        );
+
+        metadata.insert(GRPC_RETRY_PUSHBACK_KEY, "-1".parse().unwrap());
+        assert_eq!(parse_retry_pushback(&metadata), None);
+    }
+
+    #[tokio::test]
+    async fn test_call_retries_retryable_status() {
+        let client = client_with_retries(3);
+        let attempts = AtomicUsize::new(0);
+
+        let response = client
+            .call(true, |_| {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if attempt < 2 {
+                        Err(Status::unavailable("unavailable"))
+                    } else {
+                        Ok(Response::new(attempt))
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.into_inner(), 2);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_call_exhausts_retries() {
+        let client = client_with_retries(2);
+        let attempts = AtomicUsize::new(0);
+
+        let result: Result<Response<()>, Status> = client
+            .call(true, |_| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async { Err(Status::resource_exhausted("rate limited")) }
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err().code(), Code::ResourceExhausted);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_call_does_not_retry_non_retryable_status() {
+        let client = client_with_retries(3);
+        let attempts = AtomicUsize::new(0);
+
+        let result: Result<Response<()>, Status> = client
+            .call(true, |_| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async { Err(Status::invalid_argument("invalid")) }
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err().code(), Code::InvalidArgument);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_call_does_not_retry_non_idempotent_call() {
+        let client = client_with_retries(3);
+        let attempts = AtomicUsize::new(0);
+
+        let result: Result<Response<()>, Status> = client
+            .call(false, |_| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async { Err(Status::unavailable("unavailable")) }
+            })
+            .await;
+
+        assert_eq!(result.unwrap_err().code(), Code::Unavailable);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+}
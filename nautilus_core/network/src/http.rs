@@ -16,17 +16,23 @@
 use std::{
     collections::{hash_map::DefaultHasher, HashMap},
     hash::{Hash, Hasher},
+    num::NonZeroU32,
     sync::Arc,
+    time::{Instant, SystemTime},
 };
 
-use futures_util::{stream, StreamExt};
+use nautilus_core::python::to_pyvalue_err;
 use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
 use reqwest::{
-    header::{HeaderMap, HeaderName},
+    header::{HeaderMap, HeaderName, RETRY_AFTER},
     Method, Response, Url,
 };
+use tracing::warn;
 
-use crate::ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter};
+use crate::{
+    ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter},
+    retry::{parse_retry_after, RetryPolicy},
+};
 
 /// Provides a high-performance `HttpClient` for HTTP requests.
 ///
@@ -36,10 +42,14 @@ use crate::ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter};
 ///
 /// The client returns an [`HttpResponse`]. The client filters only the key value
 /// for the give `header_keys`.
+///
+/// When a [`RetryPolicy`] is set, failed requests which are safe to repeat are
+/// retried according to the policy.
 #[derive(Clone)]
 pub struct InnerHttpClient {
     client: reqwest::Client,
     header_keys: Vec<String>,
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl InnerHttpClient {
@@ -65,8 +75,52 @@ impl InnerHttpClient {
             None => request_builder.build()?,
         };
 
-        let res = self.client.execute(request).await?;
-        self.to_response(res).await
+        let policy = match &self.retry_policy {
+            Some(policy) if policy.is_retryable_request(request.method(), &headers) => policy,
+            _ => {
+                let res = self.client.execute(request).await?;
+                return self.to_response(res).await;
+            }
+        };
+
+        let start = Instant::now();
+        let mut backoff = policy.backoff();
+        loop {
+            // SAFETY: Request bodies are held in memory so can always be cloned
+            let attempt = request
+                .try_clone()
+                .expect("request body should be cloneable");
+            let (retry_after, result) = match self.client.execute(attempt).await {
+                Ok(res) if policy.is_retryable_status(res.status().as_u16()) => {
+                    let retry_after = res
+                        .headers()
+                        .get(RETRY_AFTER)
+                        .and_then(|value| value.to_str().ok())
+                        .and_then(|value| parse_retry_after(value, SystemTime::now()));
+                    (retry_after, Ok(res))
+                }
+                Ok(res) => return self.to_response(res).await,
+                Err(e) if e.is_connect() || e.is_timeout() => (None, Err(e)),
+                Err(e) => return Err(e.into()),
+            };
+
+            match policy.next_delay(&mut backoff, retry_after, start.elapsed()) {
+                Some(delay) => {
+                    let reason = match &result {
+                        Ok(res) => res.status().to_string(),
+                        Err(e) => e.to_string(),
+                    };
+                    warn!(
+                        "Retrying {} {} in {delay:?} (attempt {}): {reason}",
+                        request.method(),
+                        request.url(),
+                        backoff.attempts(),
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                None => return self.to_response(result?).await,
+            }
+        }
     }
 
     pub async fn to_response(
@@ -126,6 +180,55 @@ impl HttpMethod {
     }
 }
 
+/// The policy for a request which exceeds the rate limit quota for one of its keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub enum RateLimitPolicy {
+    /// Wait until the request conforms to the quota.
+    QUEUE,
+    /// Fail the request immediately.
+    REJECT,
+}
+
+#[pymethods]
+impl RateLimitPolicy {
+    fn __hash__(&self) -> isize {
+        let mut h = DefaultHasher::new();
+        self.hash(&mut h);
+        h.finish() as isize
+    }
+}
+
+/// Checks each of the rate limiting `keys` for `weight` against its quota, according to `policy`.
+pub(crate) async fn await_rate_limits(
+    rate_limiter: &RateLimiter<String, MonotonicClock>,
+    keys: &[String],
+    weight: NonZeroU32,
+    policy: RateLimitPolicy,
+) -> PyResult<()> {
+    for key in keys {
+        let result = match policy {
+            RateLimitPolicy::QUEUE => rate_limiter.until_key_n_ready(key, weight).await,
+            RateLimitPolicy::REJECT => match rate_limiter.check_key_n(key, weight) {
+                Ok(Ok(())) => Ok(()),
+                Ok(Err(_)) => {
+                    return Err(PyErr::new::<PyException, _>(format!(
+                        "Rate limit exceeded for key '{key}'"
+                    )))
+                }
+                Err(e) => Err(e),
+            },
+        };
+        result.map_err(|e| {
+            PyErr::new::<PyException, _>(format!("Rate limit for key '{key}': {e}"))
+        })?;
+    }
+    Ok(())
+}
+
 /// HttpResponse contains relevant data from a HTTP request.
 #[derive(Debug, Clone)]
 #[cfg_attr(
@@ -146,6 +249,7 @@ impl Default for InnerHttpClient {
         Self {
             client,
             header_keys: Default::default(),
+            retry_policy: None,
         }
     }
 }
@@ -174,6 +278,35 @@ impl HttpResponse {
 pub struct HttpClient {
     rate_limiter: Arc<RateLimiter<String, MonotonicClock>>,
     client: InnerHttpClient,
+    used_weight_headers: Arc<Vec<(String, String)>>,
+    remaining_weight_headers: Arc<Vec<(String, String)>>,
+}
+
+impl HttpClient {
+    /// Adjusts the rate limiter state from any rate limit headers on the response.
+    fn apply_rate_limit_feedback(
+        rate_limiter: &RateLimiter<String, MonotonicClock>,
+        response: &HttpResponse,
+        used_weight_headers: &[(String, String)],
+        remaining_weight_headers: &[(String, String)],
+    ) {
+        let header_value = |header: &str| {
+            response
+                .headers
+                .get(header)
+                .and_then(|value| value.trim().parse::<u32>().ok())
+        };
+        for (header, key) in used_weight_headers {
+            if let Some(used) = header_value(header) {
+                rate_limiter.sync_key_used(key, used);
+            }
+        }
+        for (header, key) in remaining_weight_headers {
+            if let Some(remaining) = header_value(header) {
+                rate_limiter.sync_key_remaining(key, remaining);
+            }
+        }
+    }
 }
 
 #[pymethods]
@@ -184,25 +317,50 @@ impl HttpClient {
     /// * `keyed_quota` - A list of string quota pairs that gives quota for specific key values.
     /// * `default_quota` - The default rate limiting quota for any request.
     /// Default quota is optional and no quota is passthrough.
+    /// * `retry_policy` - The policy for retrying failed requests (no retries if `None`).
+    /// * `used_weight_headers` - Pairs of response header and rate limit key, where the header
+    /// reports the weight used against the key quota at the venue.
+    /// * `remaining_weight_headers` - Pairs of response header and rate limit key, where the
+    /// header reports the weight remaining of the key quota at the venue.
     #[new]
-    #[pyo3(signature = (header_keys = Vec::new(), keyed_quotas = Vec::new(), default_quota = None))]
+    #[pyo3(signature = (
+        header_keys = Vec::new(),
+        keyed_quotas = Vec::new(),
+        default_quota = None,
+        retry_policy = None,
+        used_weight_headers = Vec::new(),
+        remaining_weight_headers = Vec::new(),
+    ))]
     #[must_use]
     pub fn py_new(
-        header_keys: Vec<String>,
+        mut header_keys: Vec<String>,
         keyed_quotas: Vec<(String, Quota)>,
         default_quota: Option<Quota>,
+        retry_policy: Option<RetryPolicy>,
+        used_weight_headers: Vec<(String, String)>,
+        remaining_weight_headers: Vec<(String, String)>,
     ) -> Self {
+        // Rate limit headers must be retained from responses to be applied
+        for (header, _) in used_weight_headers.iter().chain(&remaining_weight_headers) {
+            if !header_keys.contains(header) {
+                header_keys.push(header.clone());
+            }
+        }
+
         let client = reqwest::Client::new();
         let rate_limiter = Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas));
 
         let client = InnerHttpClient {
             client,
             header_keys,
+            retry_policy,
         };
 
         Self {
             rate_limiter,
             client,
+            used_weight_headers: Arc::new(used_weight_headers),
+            remaining_weight_headers: Arc::new(remaining_weight_headers),
         }
     }
 
@@ -213,7 +371,10 @@ impl HttpClient {
     /// * `headers` - The header key value pairs in the request.
     /// * `body` - The bytes sent in the body of request.
     /// * `keys` - The keys used for rate limiting the request.
+    /// * `weight` - The weight of the request against the quota of each key (default 1).
+    /// * `policy` - The policy if the request exceeds a quota (default `QUEUE`).
     #[pyo3(name = "request")]
+    #[allow(clippy::too_many_arguments)]
     fn py_request<'py>(
         &self,
         method: HttpMethod,
@@ -221,24 +382,33 @@ impl HttpClient {
         headers: Option<HashMap<String, String>>,
         body: Option<&'py PyBytes>,
         keys: Option<Vec<String>>,
+        weight: Option<u32>,
+        policy: Option<RateLimitPolicy>,
         py: Python<'py>,
     ) -> PyResult<&'py PyAny> {
         let headers = headers.unwrap_or_default();
         let body_vec = body.map(|py_bytes| py_bytes.as_bytes().to_vec());
         let keys = keys.unwrap_or_default();
+        let weight = NonZeroU32::new(weight.unwrap_or(1))
+            .ok_or_else(|| to_pyvalue_err("`weight` must be a positive integer"))?;
+        let policy = policy.unwrap_or(RateLimitPolicy::QUEUE);
         let client = self.client.clone();
         let rate_limiter = self.rate_limiter.clone();
+        let used_weight_headers = self.used_weight_headers.clone();
+        let remaining_weight_headers = self.remaining_weight_headers.clone();
         let method = method.into();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            // Check keys for rate limiting quota
-            let tasks = keys.iter().map(|key| rate_limiter.until_key_ready(key));
-            stream::iter(tasks)
-                .for_each(|key| async move {
-                    key.await;
-                })
-                .await;
+            await_rate_limits(&rate_limiter, &keys, weight, policy).await?;
             match client.send_request(method, url, headers, body_vec).await {
-                Ok(res) => Ok(res),
+                Ok(res) => {
+                    Self::apply_rate_limit_feedback(
+                        &rate_limiter,
+                        &res,
+                        &used_weight_headers,
+                        &remaining_weight_headers,
+                    );
+                    Ok(res)
+                }
                 Err(e) => Err(PyErr::new::<PyException, _>(format!(
                     "Error handling response: {e}"
                 ))),
@@ -252,7 +422,11 @@ impl HttpClient {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::net::{SocketAddr, TcpListener};
+    use std::{
+        net::{SocketAddr, TcpListener},
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
 
     use axum::{
         routing::{delete, get, patch, post},
@@ -261,6 +435,7 @@ mod tests {
     use http::status::StatusCode;
 
     use super::*;
+    use crate::retry::{StatusMatcher, DEFAULT_IDEMPOTENCY_HEADER};
 
     fn get_unique_port() -> u16 {
         // Create a temporary TcpListener to get an available port
@@ -405,4 +580,83 @@ mod tests {
 
         assert_eq!(response.status, StatusCode::OK);
     }
+
+    async fn start_unavailable_server(hits: Arc<AtomicUsize>) -> SocketAddr {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handler = move || {
+            hits.fetch_add(1, Ordering::SeqCst);
+            async { StatusCode::SERVICE_UNAVAILABLE }
+        };
+        let router = Router::new().route("/unavailable", get(handler.clone()).post(handler));
+
+        tokio::spawn(async move {
+            serve(listener, router).await.unwrap();
+        });
+
+        addr
+    }
+
+    fn client_with_retries(max_retries: u32) -> InnerHttpClient {
+        let retry_policy = RetryPolicy::new(
+            max_retries,
+            Duration::from_millis(1),
+            Duration::from_millis(10),
+            2.0,
+            0,
+            None,
+            vec![StatusMatcher::Class(5)],
+            true,
+            DEFAULT_IDEMPOTENCY_HEADER.to_string(),
+        )
+        .unwrap();
+        InnerHttpClient {
+            retry_policy: Some(retry_policy),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_idempotent_request() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let addr = start_unavailable_server(hits.clone()).await;
+
+        let client = client_with_retries(2);
+        let response = client
+            .send_request(
+                reqwest::Method::GET,
+                format!("http://{addr}/unavailable"),
+                HashMap::new(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retries_only_idempotency_keyed_post() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let addr = start_unavailable_server(hits.clone()).await;
+        let url = format!("http://{addr}/unavailable");
+
+        let client = client_with_retries(2);
+        client
+            .send_request(reqwest::Method::POST, url.clone(), HashMap::new(), None)
+            .await
+            .unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        let headers = HashMap::from([(
+            DEFAULT_IDEMPOTENCY_HEADER.to_string(),
+            "O-123456789".to_string(),
+        )]);
+        client
+            .send_request(reqwest::Method::POST, url, headers, None)
+            .await
+            .unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 4);
+    }
 }
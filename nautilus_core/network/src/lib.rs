@@ -13,10 +13,21 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod backoff;
+pub mod compression;
+pub mod fix;
+pub mod grpc;
 pub mod http;
+pub mod multicast;
+pub mod pool;
+pub mod proxy;
 #[allow(dead_code)]
 mod ratelimiter;
+pub mod retry;
+pub mod sbe;
+pub mod server;
 pub mod socket;
+pub mod tls;
 pub mod websocket;
 
 #[cfg(feature = "python")]
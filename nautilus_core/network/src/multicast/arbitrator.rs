@@ -0,0 +1,407 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Sequence arbitration between redundant (A/B) multicast feeds.
+
+use std::collections::BTreeMap;
+
+use nautilus_core::time::UnixNanos;
+
+/// The default duration (nanoseconds) to wait for a missing packet on either feed.
+pub const DEFAULT_GAP_TIMEOUT_NS: u64 = 50_000_000;
+/// The default maximum number of out of sequence packets to buffer.
+pub const DEFAULT_MAX_BUFFERED: usize = 1_000;
+
+/// Identifies one of the redundant feeds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Feed {
+    A,
+    B,
+}
+
+/// The reason a snapshot (recovery) is required.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnapshotReason {
+    /// The first packet received was after the initial sequence number.
+    LateJoin,
+    /// Packets were lost on all feeds.
+    Gap,
+}
+
+/// A request to recover state from a snapshot before processing `next_seq`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SnapshotRequest {
+    pub reason: SnapshotReason,
+    pub next_seq: u64,
+}
+
+/// Represents an arbitrated feed event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FeedEvent {
+    /// A packet delivered in sequence order, from the feed it arrived on first.
+    Packet {
+        feed: Feed,
+        seq: u64,
+        payload: Vec<u8>,
+        ts_recv: UnixNanos,
+    },
+    /// The sequence numbers `start..=end` were lost on all feeds.
+    Gap { start: u64, end: u64 },
+    /// State must be recovered from a snapshot.
+    SnapshotRequired(SnapshotRequest),
+}
+
+/// Configuration for an [`Arbitrator`].
+#[derive(Clone, Debug)]
+pub struct ArbitratorConfig {
+    /// The sequence number of the first packet of a session.
+    pub initial_seq: u64,
+    /// The duration (nanoseconds) to wait for a missing packet before declaring a gap.
+    pub gap_timeout_ns: u64,
+    /// The maximum number of out of sequence packets to buffer before declaring a gap.
+    pub max_buffered: usize,
+}
+
+impl Default for ArbitratorConfig {
+    fn default() -> Self {
+        Self {
+            initial_seq: 1,
+            gap_timeout_ns: DEFAULT_GAP_TIMEOUT_NS,
+            max_buffered: DEFAULT_MAX_BUFFERED,
+        }
+    }
+}
+
+/// Statistics for a single feed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeedStats {
+    /// The total packets received.
+    pub received: u64,
+    /// The packets delivered from this feed (arrived first).
+    pub delivered: u64,
+    /// The packets already received on the other feed (or this one).
+    pub duplicates: u64,
+}
+
+/// Arbitrates packets from redundant feeds into a single sequenced stream.
+///
+/// Packets are delivered from whichever feed provides them first, with duplicates
+/// discarded. Out of sequence packets are buffered while the missing sequence numbers
+/// may still arrive on the other feed, after the gap timeout (or once the buffer is
+/// full) the gap is declared and a snapshot is requested.
+#[derive(Debug)]
+pub struct Arbitrator {
+    config: ArbitratorConfig,
+    next_seq: Option<u64>,
+    pending: BTreeMap<u64, (Feed, Vec<u8>, UnixNanos)>,
+    gap_since: Option<UnixNanos>,
+    stats_a: FeedStats,
+    stats_b: FeedStats,
+}
+
+impl Arbitrator {
+    #[must_use]
+    pub fn new(config: ArbitratorConfig) -> Self {
+        Self {
+            config,
+            next_seq: None,
+            pending: BTreeMap::new(),
+            gap_since: None,
+            stats_a: FeedStats::default(),
+            stats_b: FeedStats::default(),
+        }
+    }
+
+    /// Returns the next expected sequence number (if a packet has been received).
+    #[must_use]
+    pub fn next_seq(&self) -> Option<u64> {
+        self.next_seq
+    }
+
+    /// Returns the number of buffered out of sequence packets.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    #[must_use]
+    pub fn stats(&self, feed: Feed) -> FeedStats {
+        match feed {
+            Feed::A => self.stats_a,
+            Feed::B => self.stats_b,
+        }
+    }
+
+    /// Resets the arbitrator to expect `next_seq` (e.g. after a snapshot or sequence reset).
+    ///
+    /// Buffered packets from before `next_seq` are discarded.
+    pub fn reset(&mut self, next_seq: u64) -> Vec<FeedEvent> {
+        self.next_seq = Some(next_seq);
+        self.pending = self.pending.split_off(&next_seq);
+        let mut events = Vec::new();
+        self.drain(&mut events);
+        events
+    }
+
+    /// Handles a packet with sequence number `seq` received on `feed`.
+    pub fn on_packet(
+        &mut self,
+        feed: Feed,
+        seq: u64,
+        payload: &[u8],
+        ts_recv: UnixNanos,
+    ) -> Vec<FeedEvent> {
+        self.stats_mut(feed).received += 1;
+
+        let mut events = Vec::new();
+        let next_seq = *self.next_seq.get_or_insert_with(|| {
+            if seq > self.config.initial_seq {
+                events.push(FeedEvent::SnapshotRequired(SnapshotRequest {
+                    reason: SnapshotReason::LateJoin,
+                    next_seq: seq,
+                }));
+            }
+            seq
+        });
+
+        if seq < next_seq || self.pending.contains_key(&seq) {
+            self.stats_mut(feed).duplicates += 1;
+            return events;
+        }
+
+        if seq == next_seq {
+            self.deliver(feed, seq, payload.to_vec(), ts_recv, &mut events);
+            self.drain(&mut events);
+        } else {
+            self.pending.insert(seq, (feed, payload.to_vec(), ts_recv));
+            self.gap_since.get_or_insert(ts_recv);
+            if self.pending.len() > self.config.max_buffered {
+                self.skip_gap(ts_recv, &mut events);
+            }
+        }
+        events
+    }
+
+    /// Checks whether an outstanding gap has timed out.
+    pub fn on_timer(&mut self, ts_now: UnixNanos) -> Vec<FeedEvent> {
+        let mut events = Vec::new();
+        if let Some(gap_since) = self.gap_since {
+            if ts_now.saturating_sub(gap_since) >= self.config.gap_timeout_ns {
+                self.skip_gap(ts_now, &mut events);
+            }
+        }
+        events
+    }
+
+    fn stats_mut(&mut self, feed: Feed) -> &mut FeedStats {
+        match feed {
+            Feed::A => &mut self.stats_a,
+            Feed::B => &mut self.stats_b,
+        }
+    }
+
+    fn deliver(
+        &mut self,
+        feed: Feed,
+        seq: u64,
+        payload: Vec<u8>,
+        ts_recv: UnixNanos,
+        events: &mut Vec<FeedEvent>,
+    ) {
+        self.stats_mut(feed).delivered += 1;
+        self.next_seq = Some(seq + 1);
+        events.push(FeedEvent::Packet {
+            feed,
+            seq,
+            payload,
+            ts_recv,
+        });
+    }
+
+    /// Delivers buffered packets which are now in sequence.
+    fn drain(&mut self, events: &mut Vec<FeedEvent>) {
+        while let Some(entry) = self.pending.first_entry() {
+            if Some(*entry.key()) != self.next_seq {
+                break;
+            }
+            let seq = *entry.key();
+            let (feed, payload, ts_recv) = entry.remove();
+            self.deliver(feed, seq, payload, ts_recv, events);
+        }
+        if self.pending.is_empty() {
+            self.gap_since = None;
+        }
+    }
+
+    /// Declares the current gap as lost and resumes from the first buffered packet.
+    fn skip_gap(&mut self, ts_now: UnixNanos, events: &mut Vec<FeedEvent>) {
+        let (Some(next_seq), Some(&first)) = (self.next_seq, self.pending.keys().next()) else {
+            return;
+        };
+        events.push(FeedEvent::Gap {
+            start: next_seq,
+            end: first - 1,
+        });
+        events.push(FeedEvent::SnapshotRequired(SnapshotRequest {
+            reason: SnapshotReason::Gap,
+            next_seq: first,
+        }));
+        self.next_seq = Some(first);
+        self.drain(events);
+        if !self.pending.is_empty() {
+            // A further gap remains, restart its timeout
+            self.gap_since = Some(ts_now);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn delivered(events: &[FeedEvent]) -> Vec<(Feed, u64)> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                FeedEvent::Packet { feed, seq, .. } => Some((*feed, *seq)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_duplicates_from_other_feed_discarded() {
+        let mut arbitrator = Arbitrator::new(ArbitratorConfig::default());
+
+        let mut events = arbitrator.on_packet(Feed::A, 1, b"1", 0);
+        events.extend(arbitrator.on_packet(Feed::B, 1, b"1", 1));
+        events.extend(arbitrator.on_packet(Feed::B, 2, b"2", 2));
+        events.extend(arbitrator.on_packet(Feed::A, 2, b"2", 3));
+
+        assert_eq!(delivered(&events), vec![(Feed::A, 1), (Feed::B, 2)]);
+        assert_eq!(events.len(), 2);
+        assert_eq!(arbitrator.next_seq(), Some(3));
+        assert_eq!(
+            arbitrator.stats(Feed::A),
+            FeedStats {
+                received: 2,
+                delivered: 1,
+                duplicates: 1
+            }
+        );
+        assert_eq!(arbitrator.stats(Feed::B).duplicates, 1);
+    }
+
+    #[rstest]
+    fn test_loss_on_one_feed_filled_by_other() {
+        let mut arbitrator = Arbitrator::new(ArbitratorConfig::default());
+
+        let mut events = arbitrator.on_packet(Feed::A, 1, b"1", 0);
+        // Feed A loses sequence 2
+        events.extend(arbitrator.on_packet(Feed::A, 3, b"3", 1));
+        events.extend(arbitrator.on_packet(Feed::A, 4, b"4", 2));
+        assert_eq!(arbitrator.pending_count(), 2);
+        events.extend(arbitrator.on_packet(Feed::B, 2, b"2", 3));
+
+        assert_eq!(
+            delivered(&events),
+            vec![(Feed::A, 1), (Feed::B, 2), (Feed::A, 3), (Feed::A, 4)]
+        );
+        assert_eq!(arbitrator.pending_count(), 0);
+        assert!(arbitrator.on_timer(DEFAULT_GAP_TIMEOUT_NS * 2).is_empty());
+    }
+
+    #[rstest]
+    fn test_gap_declared_after_timeout() {
+        let mut arbitrator = Arbitrator::new(ArbitratorConfig::default());
+        arbitrator.on_packet(Feed::A, 1, b"1", 0);
+        arbitrator.on_packet(Feed::A, 4, b"4", 100);
+
+        assert!(arbitrator
+            .on_timer(100 + DEFAULT_GAP_TIMEOUT_NS - 1)
+            .is_empty());
+        let events = arbitrator.on_timer(100 + DEFAULT_GAP_TIMEOUT_NS);
+
+        assert_eq!(events[0], FeedEvent::Gap { start: 2, end: 3 });
+        assert_eq!(
+            events[1],
+            FeedEvent::SnapshotRequired(SnapshotRequest {
+                reason: SnapshotReason::Gap,
+                next_seq: 4,
+            })
+        );
+        assert_eq!(delivered(&events), vec![(Feed::A, 4)]);
+        assert_eq!(arbitrator.next_seq(), Some(5));
+
+        // Late arrival of a lost packet is now a duplicate
+        assert!(arbitrator.on_packet(Feed::B, 2, b"2", 200).is_empty());
+    }
+
+    #[rstest]
+    fn test_gap_declared_when_buffer_full() {
+        let config = ArbitratorConfig {
+            max_buffered: 2,
+            ..Default::default()
+        };
+        let mut arbitrator = Arbitrator::new(config);
+        arbitrator.on_packet(Feed::A, 1, b"1", 0);
+        arbitrator.on_packet(Feed::A, 3, b"3", 1);
+        arbitrator.on_packet(Feed::A, 4, b"4", 2);
+
+        let events = arbitrator.on_packet(Feed::A, 5, b"5", 3);
+
+        assert_eq!(events[0], FeedEvent::Gap { start: 2, end: 2 });
+        assert_eq!(
+            delivered(&events),
+            vec![(Feed::A, 3), (Feed::A, 4), (Feed::A, 5)]
+        );
+    }
+
+    #[rstest]
+    fn test_late_join_requests_snapshot() {
+        let mut arbitrator = Arbitrator::new(ArbitratorConfig::default());
+
+        let events = arbitrator.on_packet(Feed::B, 1_000, b"1000", 0);
+
+        assert_eq!(
+            events[0],
+            FeedEvent::SnapshotRequired(SnapshotRequest {
+                reason: SnapshotReason::LateJoin,
+                next_seq: 1_000,
+            })
+        );
+        assert_eq!(delivered(&events), vec![(Feed::B, 1_000)]);
+    }
+
+    #[rstest]
+    fn test_reset_discards_stale_packets() {
+        let mut arbitrator = Arbitrator::new(ArbitratorConfig::default());
+        arbitrator.on_packet(Feed::A, 1, b"1", 0);
+        arbitrator.on_packet(Feed::A, 3, b"3", 1);
+        arbitrator.on_packet(Feed::A, 6, b"6", 2);
+
+        let events = arbitrator.reset(5);
+
+        assert!(events.is_empty());
+        assert_eq!(arbitrator.pending_count(), 1);
+        let events = arbitrator.on_packet(Feed::B, 5, b"5", 3);
+        assert_eq!(delivered(&events), vec![(Feed::B, 5), (Feed::A, 6)]);
+    }
+}
@@ -0,0 +1,277 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! UDP multicast receiver for redundant (A/B) feeds.
+
+use std::{
+    io,
+    net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+    sync::Arc,
+    time::Duration,
+};
+
+use anyhow::Result;
+use nautilus_core::time::get_atomic_clock_realtime;
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::{
+    net::UdpSocket,
+    sync::{mpsc, Notify},
+    task::JoinHandle,
+};
+use tracing::{debug, warn};
+
+use super::arbitrator::{Arbitrator, ArbitratorConfig, Feed, FeedEvent, SnapshotRequest};
+
+/// The interval for checking gap timeouts.
+const TIMER_INTERVAL: Duration = Duration::from_millis(5);
+/// The maximum UDP datagram size.
+const MAX_DATAGRAM_SIZE: usize = 65_536;
+
+/// Extracts the packet sequence number from a datagram payload.
+pub type SequenceFn = fn(&[u8]) -> Option<u64>;
+
+/// Extracts a little-endian `u32` sequence number from the start of the payload
+/// (as per the CME MDP 3.0 packet header).
+#[must_use]
+pub fn sequence_u32_le(payload: &[u8]) -> Option<u64> {
+    let bytes = payload.get(..4)?;
+    Some(u64::from(u32::from_le_bytes(bytes.try_into().ok()?)))
+}
+
+/// Extracts a big-endian `u64` sequence number from the start of the payload.
+#[must_use]
+pub fn sequence_u64_be(payload: &[u8]) -> Option<u64> {
+    let bytes = payload.get(..8)?;
+    Some(u64::from_be_bytes(bytes.try_into().ok()?))
+}
+
+/// Provides a hook for requesting snapshots (e.g. from a TCP recovery service)
+/// on late join and on gaps lost on all feeds.
+pub trait SnapshotHook: Send + Sync {
+    fn request_snapshot(&self, request: SnapshotRequest);
+}
+
+impl<F> SnapshotHook for F
+where
+    F: Fn(SnapshotRequest) + Send + Sync,
+{
+    fn request_snapshot(&self, request: SnapshotRequest) {
+        self(request);
+    }
+}
+
+/// Configuration for a single multicast feed.
+#[derive(Clone, Debug)]
+pub struct FeedConfig {
+    /// The multicast group address.
+    pub group: Ipv4Addr,
+    pub port: u16,
+    /// The local interface address to join the group on.
+    pub interface: Ipv4Addr,
+}
+
+/// Configuration for a [`MulticastReceiver`].
+#[derive(Clone, Debug)]
+pub struct MulticastConfig {
+    pub feed_a: FeedConfig,
+    /// The redundant feed, if `None` then gaps are only detected on feed A.
+    pub feed_b: Option<FeedConfig>,
+    pub arbitrator: ArbitratorConfig,
+    pub sequence_fn: SequenceFn,
+    /// The socket receive buffer size (bytes), uses the OS default if `None`.
+    pub recv_buffer_size: Option<usize>,
+}
+
+/// Provides a receiver of redundant UDP multicast feeds.
+///
+/// Packets from both feeds are arbitrated into a single sequenced stream which is
+/// forwarded on the `events` channel, along with gaps and snapshot requests. The
+/// optional snapshot hook is also called for each snapshot request.
+pub struct MulticastReceiver {
+    shutdown: Arc<Notify>,
+    task: JoinHandle<Result<()>>,
+}
+
+impl MulticastReceiver {
+    /// Joins the configured multicast groups and starts receiving.
+    ///
+    /// Must be called from within a Tokio runtime.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a socket cannot be bound or a group joined.
+    pub fn start(
+        config: MulticastConfig,
+        events: mpsc::UnboundedSender<FeedEvent>,
+        snapshot_hook: Option<Arc<dyn SnapshotHook>>,
+    ) -> Result<Self> {
+        let socket_a = bind_multicast(&config.feed_a, config.recv_buffer_size)?;
+        let socket_b = config
+            .feed_b
+            .as_ref()
+            .map(|feed| bind_multicast(feed, config.recv_buffer_size))
+            .transpose()?;
+
+        let shutdown = Arc::new(Notify::new());
+        let task = tokio::spawn(run(
+            socket_a,
+            socket_b,
+            Arbitrator::new(config.arbitrator),
+            config.sequence_fn,
+            events,
+            snapshot_hook,
+            shutdown.clone(),
+        ));
+        Ok(Self { shutdown, task })
+    }
+
+    /// Stops receiving and leaves the multicast groups.
+    pub fn stop(&self) {
+        self.shutdown.notify_one();
+    }
+
+    #[must_use]
+    pub fn is_stopped(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+fn bind_multicast(feed: &FeedConfig, recv_buffer_size: Option<usize>) -> Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_reuse_address(true)?;
+    if let Some(size) = recv_buffer_size {
+        socket.set_recv_buffer_size(size)?;
+    }
+    socket.set_nonblocking(true)?;
+
+    // Binding to the group address filters other groups on the same port (not supported on Windows)
+    let bind_addr = if cfg!(windows) {
+        Ipv4Addr::UNSPECIFIED
+    } else {
+        feed.group
+    };
+    socket.bind(&SocketAddr::V4(SocketAddrV4::new(bind_addr, feed.port)).into())?;
+    socket.join_multicast_v4(&feed.group, &feed.interface)?;
+    debug!(
+        "Joined multicast group {}:{} on {}",
+        feed.group, feed.port, feed.interface
+    );
+
+    Ok(UdpSocket::from_std(socket.into())?)
+}
+
+async fn recv_optional(socket: Option<&UdpSocket>, buffer: &mut [u8]) -> io::Result<usize> {
+    match socket {
+        Some(socket) => socket.recv(buffer).await,
+        None => std::future::pending().await,
+    }
+}
+
+async fn run(
+    socket_a: UdpSocket,
+    socket_b: Option<UdpSocket>,
+    mut arbitrator: Arbitrator,
+    sequence_fn: SequenceFn,
+    events: mpsc::UnboundedSender<FeedEvent>,
+    snapshot_hook: Option<Arc<dyn SnapshotHook>>,
+    shutdown: Arc<Notify>,
+) -> Result<()> {
+    let clock = get_atomic_clock_realtime();
+    let mut buffer_a = vec![0; MAX_DATAGRAM_SIZE];
+    let mut buffer_b = vec![0; MAX_DATAGRAM_SIZE];
+    let mut timer = tokio::time::interval(TIMER_INTERVAL);
+
+    let dispatch = |feed_events: Vec<FeedEvent>| -> bool {
+        for event in feed_events {
+            if let (FeedEvent::SnapshotRequired(request), Some(hook)) = (&event, &snapshot_hook) {
+                hook.request_snapshot(*request);
+            }
+            if events.send(event).is_err() {
+                return false;
+            }
+        }
+        true
+    };
+
+    loop {
+        let (feed, n) = tokio::select! {
+            read = socket_a.recv(&mut buffer_a) => (Feed::A, read?),
+            read = recv_optional(socket_b.as_ref(), &mut buffer_b) => (Feed::B, read?),
+            _ = timer.tick() => {
+                if !dispatch(arbitrator.on_timer(clock.get_time_ns())) {
+                    return Ok(());
+                }
+                continue;
+            }
+            () = shutdown.notified() => {
+                debug!("Multicast receiver stopped");
+                return Ok(());
+            }
+        };
+
+        let payload = match feed {
+            Feed::A => &buffer_a[..n],
+            Feed::B => &buffer_b[..n],
+        };
+        let Some(seq) = sequence_fn(payload) else {
+            warn!("Dropping packet without sequence number on feed {feed:?}");
+            continue;
+        };
+        if !dispatch(arbitrator.on_packet(feed, seq, payload, clock.get_time_ns())) {
+            // Receiver of events dropped
+            return Ok(());
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::multicast::arbitrator::SnapshotReason;
+
+    #[rstest]
+    fn test_sequence_u32_le() {
+        assert_eq!(sequence_u32_le(&[0x01, 0x02, 0x00, 0x00, 0xFF]), Some(513));
+        assert_eq!(sequence_u32_le(&[0x01, 0x02]), None);
+    }
+
+    #[rstest]
+    fn test_sequence_u64_be() {
+        assert_eq!(sequence_u64_be(&[0, 0, 0, 0, 0, 0, 0x01, 0x02]), Some(258));
+        assert_eq!(sequence_u64_be(&[0; 7]), None);
+    }
+
+    #[rstest]
+    fn test_snapshot_hook_closure() {
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let requests_clone = requests.clone();
+        let hook: Arc<dyn SnapshotHook> = Arc::new(move |request: SnapshotRequest| {
+            requests_clone.lock().unwrap().push(request);
+        });
+        let request = SnapshotRequest {
+            reason: SnapshotReason::LateJoin,
+            next_seq: 10,
+        };
+
+        hook.request_snapshot(request);
+
+        assert_eq!(*requests.lock().unwrap(), vec![request]);
+    }
+}
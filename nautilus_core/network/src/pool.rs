@@ -0,0 +1,405 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Multiplexing of subscriptions across a pool of WebSocket connections.
+//!
+//! Venues typically cap the number of subscriptions per connection, so subscriptions
+//! are spread across shards (connections) by consistent hashing of the subscription key
+//! (such as the instrument ID). When a shard fails its subscriptions fail over to the
+//! next healthy shards on the hash ring.
+
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap, HashSet},
+    hash::{Hash, Hasher},
+};
+
+use indexmap::IndexMap;
+use tokio_tungstenite::tungstenite::Error;
+use tracing::{debug, error, warn};
+
+use crate::websocket::{WebSocketClient, WebSocketConfig};
+
+/// The default number of virtual nodes per shard on the hash ring.
+pub const DEFAULT_VIRTUAL_NODES: usize = 64;
+
+/// Represents the ID of a shard (connection) within a pool.
+pub type ShardId = usize;
+
+/// Represents a subscription moved between shards on failover.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reassignment {
+    pub key: String,
+    pub from: ShardId,
+    /// The shard the subscription moved to (`None` if no shard had capacity).
+    pub to: Option<ShardId>,
+}
+
+#[derive(Clone, Debug)]
+struct Shard {
+    healthy: bool,
+    keys: HashSet<String>,
+}
+
+/// Manages the assignment of subscription keys to shards by consistent hashing,
+/// respecting a maximum number of subscriptions per shard.
+#[derive(Clone, Debug)]
+pub struct ShardManager {
+    max_subscriptions: usize,
+    virtual_nodes: usize,
+    ring: BTreeMap<u64, ShardId>,
+    shards: Vec<Shard>,
+    assignments: IndexMap<String, ShardId>,
+}
+
+impl ShardManager {
+    /// Creates a new shard manager.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `max_subscriptions` or `virtual_nodes` is zero.
+    #[must_use]
+    pub fn new(max_subscriptions: usize, virtual_nodes: usize) -> Self {
+        assert!(max_subscriptions > 0, "`max_subscriptions` was zero");
+        assert!(virtual_nodes > 0, "`virtual_nodes` was zero");
+        Self {
+            max_subscriptions,
+            virtual_nodes,
+            ring: BTreeMap::new(),
+            shards: Vec::new(),
+            assignments: IndexMap::new(),
+        }
+    }
+
+    /// Adds a new (healthy) shard to the ring, returning its ID.
+    pub fn add_shard(&mut self) -> ShardId {
+        let shard_id = self.shards.len();
+        for node in 0..self.virtual_nodes {
+            self.ring.insert(hash(&(shard_id, node)), shard_id);
+        }
+        self.shards.push(Shard {
+            healthy: true,
+            keys: HashSet::new(),
+        });
+        shard_id
+    }
+
+    #[must_use]
+    pub fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    #[must_use]
+    pub fn is_healthy(&self, shard_id: ShardId) -> bool {
+        self.shards.get(shard_id).is_some_and(|shard| shard.healthy)
+    }
+
+    /// Returns the shard the given subscription `key` is assigned to.
+    #[must_use]
+    pub fn shard_for(&self, key: &str) -> Option<ShardId> {
+        self.assignments.get(key).copied()
+    }
+
+    /// Returns the subscription keys assigned to the given shard.
+    #[must_use]
+    pub fn subscriptions(&self, shard_id: ShardId) -> Vec<&str> {
+        self.assignments
+            .iter()
+            .filter(|(_, shard)| **shard == shard_id)
+            .map(|(key, _)| key.as_str())
+            .collect()
+    }
+
+    /// Assigns the subscription `key` to a shard, returning the shard ID (or `None` if
+    /// no healthy shard has capacity, in which case a new shard should be added).
+    pub fn subscribe(&mut self, key: &str) -> Option<ShardId> {
+        if let Some(shard_id) = self.shard_for(key) {
+            return Some(shard_id);
+        }
+        let shard_id = self.place(key)?;
+        self.assign(key.to_string(), shard_id);
+        Some(shard_id)
+    }
+
+    /// Removes the subscription `key`, returning the shard it was assigned to.
+    pub fn unsubscribe(&mut self, key: &str) -> Option<ShardId> {
+        let shard_id = self.assignments.shift_remove(key)?;
+        self.shards[shard_id].keys.remove(key);
+        Some(shard_id)
+    }
+
+    /// Marks the given shard as failed, moving its subscriptions to other healthy shards.
+    pub fn mark_down(&mut self, shard_id: ShardId) -> Vec<Reassignment> {
+        let Some(shard) = self.shards.get_mut(shard_id) else {
+            return Vec::new();
+        };
+        shard.healthy = false;
+        let mut keys: Vec<String> = shard.keys.drain().collect();
+        keys.sort();
+
+        keys.into_iter()
+            .map(|key| {
+                self.assignments.shift_remove(&key);
+                let to = self.place(&key);
+                if let Some(to) = to {
+                    self.assign(key.clone(), to);
+                }
+                Reassignment {
+                    key,
+                    from: shard_id,
+                    to,
+                }
+            })
+            .collect()
+    }
+
+    /// Marks the given shard as healthy, making it available for new subscriptions.
+    pub fn mark_up(&mut self, shard_id: ShardId) {
+        if let Some(shard) = self.shards.get_mut(shard_id) {
+            shard.healthy = true;
+        }
+    }
+
+    fn assign(&mut self, key: String, shard_id: ShardId) {
+        self.shards[shard_id].keys.insert(key.clone());
+        self.assignments.insert(key, shard_id);
+    }
+
+    /// Walks the ring clockwise from the key hash to the first healthy shard with capacity.
+    fn place(&self, key: &str) -> Option<ShardId> {
+        let start = hash(&key);
+        self.ring
+            .range(start..)
+            .chain(self.ring.range(..start))
+            .map(|(_, shard_id)| *shard_id)
+            .find(|shard_id| {
+                let shard = &self.shards[*shard_id];
+                shard.healthy && shard.keys.len() < self.max_subscriptions
+            })
+    }
+}
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Provides a pool of WebSocket connections to a venue, spreading subscriptions across
+/// the connections with a [`ShardManager`].
+///
+/// New connections are opened as existing ones reach capacity. Subscription messages are
+/// registered with their connection for replay on reconnect, and on a connection failing
+/// entirely (see [`WebSocketPool::check_health`]) they are resent on the connections
+/// they fail over to.
+pub struct WebSocketPool {
+    config: WebSocketConfig,
+    shards: ShardManager,
+    clients: Vec<WebSocketClient>,
+    messages: HashMap<String, String>,
+}
+
+impl WebSocketPool {
+    #[must_use]
+    pub fn new(config: WebSocketConfig, max_subscriptions_per_connection: usize) -> Self {
+        Self {
+            config,
+            shards: ShardManager::new(max_subscriptions_per_connection, DEFAULT_VIRTUAL_NODES),
+            clients: Vec::new(),
+            messages: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn shards(&self) -> &ShardManager {
+        &self.shards
+    }
+
+    #[must_use]
+    pub fn connection_count(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Subscribes with the given `message` on the connection assigned to `key`, opening
+    /// a new connection if all existing connections are at capacity.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a connection cannot be opened or the message
+    /// cannot be sent.
+    pub async fn subscribe(&mut self, key: &str, message: String) -> Result<ShardId, Error> {
+        let shard_id = match self.shards.subscribe(key) {
+            Some(shard_id) => shard_id,
+            None => {
+                let client =
+                    WebSocketClient::connect(self.config.clone(), None, None, None).await?;
+                self.clients.push(client);
+                let shard_id = self.shards.add_shard();
+                debug!("Opened pool connection {shard_id}");
+                // SAFETY: The new shard is empty so has capacity
+                self.shards.subscribe(key).unwrap()
+            }
+        };
+        self.send_subscription(shard_id, key, message).await?;
+        Ok(shard_id)
+    }
+
+    /// Unsubscribes `key`, sending the optional unsubscribe `message` on its connection.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the message cannot be sent.
+    pub async fn unsubscribe(&mut self, key: &str, message: Option<String>) -> Result<(), Error> {
+        let Some(shard_id) = self.shards.unsubscribe(key) else {
+            return Ok(());
+        };
+        let client = &self.clients[shard_id];
+        if let Some(subscribe_message) = self.messages.remove(key) {
+            client.remove_subscription(&subscribe_message).await;
+        }
+        if let Some(message) = message {
+            client.send_text(message).await?;
+        }
+        Ok(())
+    }
+
+    /// Checks the health of each connection, failing over the subscriptions of any
+    /// connection which has terminated.
+    pub async fn check_health(&mut self) -> Vec<Reassignment> {
+        let mut reassignments = Vec::new();
+        for shard_id in 0..self.clients.len() {
+            if !self.shards.is_healthy(shard_id) || !self.clients[shard_id].is_disconnected() {
+                continue;
+            }
+            warn!("Pool connection {shard_id} terminated, failing over subscriptions");
+            for reassignment in self.shards.mark_down(shard_id) {
+                let Some(message) = self.messages.get(&reassignment.key).cloned() else {
+                    continue;
+                };
+                match reassignment.to {
+                    Some(to) => {
+                        if let Err(e) = self.send_subscription(to, &reassignment.key, message).await
+                        {
+                            error!("Error failing over {}: {e}", reassignment.key);
+                        }
+                    }
+                    None => error!("No capacity to fail over {}", reassignment.key),
+                }
+                reassignments.push(reassignment);
+            }
+        }
+        reassignments
+    }
+
+    async fn send_subscription(
+        &mut self,
+        shard_id: ShardId,
+        key: &str,
+        message: String,
+    ) -> Result<(), Error> {
+        let client = &self.clients[shard_id];
+        client.add_subscription(message.clone()).await;
+        self.messages.insert(key.to_string(), message.clone());
+        client.send_text(message).await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn manager(shards: usize, max_subscriptions: usize) -> ShardManager {
+        let mut manager = ShardManager::new(max_subscriptions, DEFAULT_VIRTUAL_NODES);
+        for _ in 0..shards {
+            manager.add_shard();
+        }
+        manager
+    }
+
+    fn keys(n: usize) -> Vec<String> {
+        (0..n).map(|i| format!("INSTRUMENT-{i}.VENUE")).collect()
+    }
+
+    #[rstest]
+    fn test_subscribe_is_consistent() {
+        let mut first = manager(4, 100);
+        let mut second = manager(4, 100);
+
+        for key in keys(50) {
+            assert_eq!(first.subscribe(&key), second.subscribe(&key));
+            assert_eq!(first.subscribe(&key), first.shard_for(&key));
+        }
+        // Keys are spread over more than one shard
+        let used: HashSet<ShardId> = keys(50).iter().filter_map(|k| first.shard_for(k)).collect();
+        assert!(used.len() > 1);
+    }
+
+    #[rstest]
+    fn test_subscribe_respects_capacity() {
+        let mut manager = manager(2, 3);
+
+        let assigned: Vec<Option<ShardId>> = keys(7).iter().map(|k| manager.subscribe(k)).collect();
+
+        assert_eq!(assigned.iter().filter(|s| s.is_some()).count(), 6);
+        assert_eq!(assigned[6], None);
+        assert_eq!(manager.subscriptions(0).len(), 3);
+        assert_eq!(manager.subscriptions(1).len(), 3);
+
+        assert_eq!(manager.unsubscribe(&keys(1)[0]), assigned[0]);
+        assert!(manager.subscribe(&keys(7)[6]).is_some());
+    }
+
+    #[rstest]
+    fn test_mark_down_fails_over_subscriptions() {
+        let mut manager = manager(3, 100);
+        for key in keys(30) {
+            manager.subscribe(&key);
+        }
+        let moved = manager.subscriptions(1).len();
+
+        let reassignments = manager.mark_down(1);
+
+        assert_eq!(reassignments.len(), moved);
+        assert!(reassignments
+            .iter()
+            .all(|r| r.from == 1 && r.to.is_some() && r.to != Some(1)));
+        assert!(manager.subscriptions(1).is_empty());
+        assert!(!manager.is_healthy(1));
+        assert_eq!(
+            manager.subscriptions(0).len() + manager.subscriptions(2).len(),
+            30
+        );
+    }
+
+    #[rstest]
+    fn test_mark_down_without_capacity_leaves_unassigned() {
+        let mut manager = manager(2, 2);
+        for key in keys(4) {
+            manager.subscribe(&key);
+        }
+
+        let reassignments = manager.mark_down(0);
+
+        assert_eq!(reassignments.len(), 2);
+        assert!(reassignments.iter().all(|r| r.to.is_none()));
+
+        manager.mark_up(0);
+        assert_eq!(manager.subscribe(&reassignments[0].key), Some(0));
+    }
+}
@@ -15,17 +15,22 @@
 
 use pyo3::prelude::*;
 
-use crate::{http, ratelimiter, socket, websocket};
+use crate::{grpc, http, ratelimiter, retry, server, socket, websocket};
 
 /// Loaded as nautilus_pyo3.network
 #[pymodule]
 pub fn network(_: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<grpc::GrpcClient>()?;
+    m.add_class::<grpc::GrpcResponse>()?;
     m.add_class::<http::HttpClient>()?;
     m.add_class::<http::HttpMethod>()?;
     m.add_class::<http::HttpResponse>()?;
+    m.add_class::<http::RateLimitPolicy>()?;
     m.add_class::<ratelimiter::quota::Quota>()?;
+    m.add_class::<retry::RetryPolicy>()?;
     m.add_class::<websocket::WebSocketClient>()?;
     m.add_class::<websocket::WebSocketConfig>()?;
+    m.add_class::<server::WebSocketServer>()?;
     m.add_class::<socket::SocketClient>()?;
     m.add_class::<socket::SocketConfig>()?;
     Ok(())
@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{cmp, fmt, time::Duration};
+use std::{cmp, convert::Infallible, fmt, num::NonZeroU32, time::Duration};
 
 use super::{clock, nanos::Nanos, quota::Quota, StateStore};
 
@@ -114,6 +114,21 @@ impl<P: clock::Reference> fmt::Display for NotUntil<P> {
     }
 }
 
+/// Error indicating that the weight of a request exceeds the burst capacity of the quota,
+/// so the request could never conform.
+///
+/// Contains the burst capacity of the quota.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsufficientCapacity(pub u32);
+
+impl fmt::Display for InsufficientCapacity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "required weight exceeds burst capacity of {}", self.0)
+    }
+}
+
+impl std::error::Error for InsufficientCapacity {}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Gcra {
     /// The "weight" of a single packet in units of time.
@@ -160,4 +175,63 @@ impl Gcra {
             }
         })
     }
+
+    /// Returns the burst capacity (in cells) of the rate limiter.
+    pub(crate) fn burst_size(&self) -> u32 {
+        u32::try_from(self.tau / self.t).unwrap_or(u32::MAX)
+    }
+
+    /// Tests `n` cells (the weight of a request) against the rate limiter state at the
+    /// given key, updating the state if all of them conform.
+    ///
+    /// Returns [`InsufficientCapacity`] if `n` exceeds the burst capacity.
+    pub(crate) fn test_n_all_and_update<K, S: StateStore<Key = K>, P: clock::Reference>(
+        &self,
+        start: P,
+        key: &K,
+        n: NonZeroU32,
+        state: &S,
+        t0: P,
+    ) -> Result<Result<(), NotUntil<P>>, InsufficientCapacity> {
+        let t0 = t0.duration_since(start);
+        let tau = self.tau;
+        let t = self.t;
+        // The weight of the cells in addition to the first cell
+        let additional_weight = t * (u64::from(n.get()) - 1);
+        if additional_weight + t > tau {
+            return Err(InsufficientCapacity(self.burst_size()));
+        }
+        Ok(state.measure_and_replace(key, |tat| {
+            let tat = tat.unwrap_or_else(|| self.starting_state(t0));
+            let earliest_time = (tat + additional_weight).saturating_sub(tau);
+            if t0 < earliest_time {
+                Err(NotUntil::new(
+                    StateSnapshot::new(self.t, self.tau, earliest_time, earliest_time),
+                    start,
+                ))
+            } else {
+                let next = cmp::max(tat, t0) + t + additional_weight;
+                Ok(((), next))
+            }
+        }))
+    }
+
+    /// Updates the rate limiter state at the given key so that at least `used` cells of
+    /// the burst capacity are consumed as of `t0`, such as when a venue reports more usage
+    /// than tracked locally. The state is never relaxed by this method.
+    pub(crate) fn reserve_used<K, S: StateStore<Key = K>, P: clock::Reference>(
+        &self,
+        start: P,
+        key: &K,
+        used: u32,
+        state: &S,
+        t0: P,
+    ) {
+        let t0 = t0.duration_since(start);
+        let reported_tat = t0 + self.t * (u64::from(used) + 1);
+        let _ = state.measure_and_replace(key, |tat| {
+            let tat = tat.unwrap_or_else(|| self.starting_state(t0));
+            Ok::<_, Infallible>(((), cmp::max(tat, reported_tat)))
+        });
+    }
 }
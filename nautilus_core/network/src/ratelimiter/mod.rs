@@ -23,7 +23,7 @@ pub mod quota;
 
 use std::{
     hash::Hash,
-    num::NonZeroU64,
+    num::{NonZeroU32, NonZeroU64},
     sync::atomic::{AtomicU64, Ordering},
     time::Duration,
 };
@@ -33,7 +33,7 @@ use tokio::time::sleep;
 
 use self::{
     clock::{Clock, FakeRelativeClock, MonotonicClock},
-    gcra::{Gcra, NotUntil},
+    gcra::{Gcra, InsufficientCapacity, NotUntil},
     nanos::Nanos,
     quota::Quota,
 };
@@ -191,6 +191,71 @@ where
             }
         }
     }
+
+    /// Checks a request with the given `weight` (such as a venue request weight) against
+    /// the quota for the key, consuming `weight` cells if it conforms.
+    ///
+    /// Returns [`InsufficientCapacity`] if the weight exceeds the burst capacity of the quota.
+    pub fn check_key_n(
+        &self,
+        key: &K,
+        weight: NonZeroU32,
+    ) -> Result<Result<(), NotUntil<C::Instant>>, InsufficientCapacity> {
+        match self.gcra.get(key) {
+            Some(quota) => {
+                quota.test_n_all_and_update(self.start, key, weight, &self.state, self.clock.now())
+            }
+            None => self.default_gcra.as_ref().map_or(Ok(Ok(())), |gcra| {
+                gcra.test_n_all_and_update(self.start, key, weight, &self.state, self.clock.now())
+            }),
+        }
+    }
+
+    /// Waits until a request with the given `weight` conforms to the quota for the key.
+    ///
+    /// Returns [`InsufficientCapacity`] if the weight exceeds the burst capacity of the quota.
+    pub async fn until_key_n_ready(
+        &self,
+        key: &K,
+        weight: NonZeroU32,
+    ) -> Result<(), InsufficientCapacity> {
+        loop {
+            match self.check_key_n(key, weight)? {
+                Ok(()) => return Ok(()),
+                Err(neg) => {
+                    sleep(neg.wait_time_from(self.clock.now())).await;
+                }
+            }
+        }
+    }
+
+    /// Adjusts the state for the key from the `used` weight reported by a venue, so that
+    /// usage outside of this rate limiter (e.g. other clients on the same API key) is
+    /// accounted for. The state is only ever tightened.
+    pub fn sync_key_used(&self, key: &K, used: u32) {
+        match self.gcra.get(key) {
+            Some(quota) => quota.reserve_used(self.start, key, used, &self.state, self.clock.now()),
+            None => {
+                if let Some(gcra) = &self.default_gcra {
+                    gcra.reserve_used(self.start, key, used, &self.state, self.clock.now());
+                }
+            }
+        }
+    }
+
+    /// Adjusts the state for the key from the `remaining` weight reported by a venue.
+    ///
+    /// See [`RateLimiter::sync_key_used`].
+    pub fn sync_key_remaining(&self, key: &K, remaining: u32) {
+        let burst_size = match self.gcra.get(key) {
+            Some(quota) => quota.burst_size(),
+            None => match &self.default_gcra {
+                Some(gcra) => gcra.burst_size(),
+                None => return,
+            },
+        };
+        self.sync_key_used(key, burst_size.saturating_sub(remaining));
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -204,7 +269,7 @@ mod tests {
 
     use super::{
         clock::{Clock, FakeRelativeClock},
-        gcra::Gcra,
+        gcra::{Gcra, InsufficientCapacity},
         quota::Quota,
         DashMapStateStore, RateLimiter,
     };
@@ -247,4 +312,64 @@ mod tests {
         assert!(mock_limiter.check_key(&"yeet".to_string()).is_ok());
         assert!(mock_limiter.check_key(&"yeet".to_string()).is_err());
     }
+
+    #[test]
+    fn test_weighted_quota() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        mock_limiter.add_quota_for_key(
+            "weight".to_string(),
+            Quota::per_second(NonZeroU32::new(10).unwrap()),
+        );
+        let key = "weight".to_string();
+
+        assert_eq!(
+            mock_limiter.check_key_n(&key, NonZeroU32::new(11).unwrap()),
+            Err(InsufficientCapacity(10))
+        );
+        assert!(mock_limiter
+            .check_key_n(&key, NonZeroU32::new(6).unwrap())
+            .unwrap()
+            .is_ok());
+        assert!(mock_limiter
+            .check_key_n(&key, NonZeroU32::new(5).unwrap())
+            .unwrap()
+            .is_err());
+        assert!(mock_limiter
+            .check_key_n(&key, NonZeroU32::new(4).unwrap())
+            .unwrap()
+            .is_ok());
+        assert!(mock_limiter.check_key(&key).is_err());
+
+        // Half the quota replenishes in half a second
+        mock_limiter.advance_clock(Duration::from_millis(500));
+        assert!(mock_limiter
+            .check_key_n(&key, NonZeroU32::new(5).unwrap())
+            .unwrap()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_sync_key_from_venue_feedback() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        mock_limiter.add_quota_for_key(
+            "weight".to_string(),
+            Quota::per_second(NonZeroU32::new(10).unwrap()),
+        );
+        let key = "weight".to_string();
+
+        // Venue reports 8 of 10 used (by other clients), so only 2 remain
+        mock_limiter.sync_key_used(&key, 8);
+        assert!(mock_limiter
+            .check_key_n(&key, NonZeroU32::new(3).unwrap())
+            .unwrap()
+            .is_err());
+        assert!(mock_limiter
+            .check_key_n(&key, NonZeroU32::new(2).unwrap())
+            .unwrap()
+            .is_ok());
+
+        // Feedback reporting less usage than tracked does not relax the state
+        mock_limiter.sync_key_remaining(&key, 10);
+        assert!(mock_limiter.check_key(&key).is_err());
+    }
 }
@@ -0,0 +1,386 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Retry policies for HTTP (and gRPC) requests.
+//!
+//! Only requests which are safe to repeat are retried, being those with an idempotent
+//! method (`GET`, `PUT`, `DELETE`) or which carry an idempotency key header, so that a
+//! request which reached the venue is never duplicated.
+
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
+
+use nautilus_core::python::to_pyvalue_err;
+use pyo3::prelude::*;
+use reqwest::Method;
+
+use crate::backoff::{
+    ExponentialBackoff, DEFAULT_BACKOFF_FACTOR, DEFAULT_DELAY_INITIAL_MS, DEFAULT_DELAY_MAX_MS,
+    DEFAULT_JITTER_MS,
+};
+
+/// The default header carrying a client supplied idempotency key.
+pub const DEFAULT_IDEMPOTENCY_HEADER: &str = "Idempotency-Key";
+/// The default maximum number of retries for a request.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Matches a response status code, either exactly (`429`) or by class (`5xx`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatusMatcher {
+    Code(u16),
+    Class(u16),
+}
+
+impl StatusMatcher {
+    #[must_use]
+    pub fn matches(&self, status: u16) -> bool {
+        match self {
+            Self::Code(code) => status == *code,
+            Self::Class(class) => status / 100 == *class,
+        }
+    }
+}
+
+impl FromStr for StatusMatcher {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = || format!("invalid status code matcher '{s}'");
+        if s.len() == 3 && s[1..].eq_ignore_ascii_case("xx") {
+            let class = s[..1].parse::<u16>().map_err(|_| invalid())?;
+            if !(1..=5).contains(&class) {
+                return Err(invalid());
+            }
+            return Ok(Self::Class(class));
+        }
+        match s.parse::<u16>() {
+            Ok(code) if (100..600).contains(&code) => Ok(Self::Code(code)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Parses a `Retry-After` header value, being either delay seconds or an HTTP date,
+/// into the delay from `now`.
+#[must_use]
+pub fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(now).unwrap_or_default())
+}
+
+/// Represents a policy for retrying failed HTTP requests.
+///
+/// Requests are retried on connection errors and timeouts, or on responses with a
+/// status matching the policy (by default `429` and `5xx`), with exponentially
+/// increasing delays. A `Retry-After` header on the response takes precedence over
+/// the backoff delay when respected, and no retry is made once the maximum elapsed
+/// time would be exceeded.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    delay_initial: Duration,
+    delay_max: Duration,
+    backoff_factor: f64,
+    jitter_ms: u64,
+    max_elapsed: Option<Duration>,
+    retry_statuses: Vec<StatusMatcher>,
+    respect_retry_after: bool,
+    idempotency_header: String,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            delay_initial: Duration::from_millis(DEFAULT_DELAY_INITIAL_MS),
+            delay_max: Duration::from_millis(DEFAULT_DELAY_MAX_MS),
+            backoff_factor: DEFAULT_BACKOFF_FACTOR,
+            jitter_ms: DEFAULT_JITTER_MS,
+            max_elapsed: None,
+            retry_statuses: vec![StatusMatcher::Code(429), StatusMatcher::Class(5)],
+            respect_retry_after: true,
+            idempotency_header: DEFAULT_IDEMPOTENCY_HEADER.to_string(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        max_retries: u32,
+        delay_initial: Duration,
+        delay_max: Duration,
+        backoff_factor: f64,
+        jitter_ms: u64,
+        max_elapsed: Option<Duration>,
+        retry_statuses: Vec<StatusMatcher>,
+        respect_retry_after: bool,
+        idempotency_header: String,
+    ) -> anyhow::Result<Self> {
+        if backoff_factor < 1.0 {
+            anyhow::bail!("`backoff_factor` was less than 1.0, was {backoff_factor}");
+        }
+        Ok(Self {
+            max_retries,
+            delay_initial,
+            delay_max,
+            backoff_factor,
+            jitter_ms,
+            max_elapsed,
+            retry_statuses,
+            respect_retry_after,
+            idempotency_header,
+        })
+    }
+
+    #[must_use]
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    #[must_use]
+    pub fn idempotency_header(&self) -> &str {
+        &self.idempotency_header
+    }
+
+    /// Returns whether a request with the given `method` and `headers` may be retried.
+    #[must_use]
+    pub fn is_retryable_request(&self, method: &Method, headers: &HashMap<String, String>) -> bool {
+        matches!(*method, Method::GET | Method::PUT | Method::DELETE)
+            || headers
+                .keys()
+                .any(|key| key.eq_ignore_ascii_case(&self.idempotency_header))
+    }
+
+    /// Returns whether a response with the given `status` should be retried.
+    #[must_use]
+    pub fn is_retryable_status(&self, status: u16) -> bool {
+        self.retry_statuses.iter().any(|m| m.matches(status))
+    }
+
+    /// Returns a new backoff for the retries of a single request.
+    #[must_use]
+    pub fn backoff(&self) -> ExponentialBackoff {
+        ExponentialBackoff::new(
+            self.delay_initial,
+            self.delay_max,
+            self.backoff_factor,
+            self.jitter_ms,
+            Some(self.max_retries),
+        )
+    }
+
+    /// Returns the delay before the next retry, or `None` if the request should not be
+    /// retried as the retries or the maximum elapsed time would be exhausted.
+    pub fn next_delay(
+        &self,
+        backoff: &mut ExponentialBackoff,
+        retry_after: Option<Duration>,
+        elapsed: Duration,
+    ) -> Option<Duration> {
+        let backoff_delay = backoff.next_delay()?;
+        let delay = match retry_after {
+            Some(retry_after) if self.respect_retry_after => retry_after,
+            _ => backoff_delay,
+        };
+        if self
+            .max_elapsed
+            .is_some_and(|max_elapsed| elapsed + delay > max_elapsed)
+        {
+            return None;
+        }
+        Some(delay)
+    }
+}
+
+#[pymethods]
+impl RetryPolicy {
+    #[new]
+    #[pyo3(signature = (
+        max_retries = DEFAULT_MAX_RETRIES,
+        delay_initial_ms = DEFAULT_DELAY_INITIAL_MS,
+        delay_max_ms = DEFAULT_DELAY_MAX_MS,
+        backoff_factor = DEFAULT_BACKOFF_FACTOR,
+        jitter_ms = DEFAULT_JITTER_MS,
+        max_elapsed_ms = None,
+        retry_statuses = None,
+        respect_retry_after = true,
+        idempotency_header = None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn py_new(
+        max_retries: u32,
+        delay_initial_ms: u64,
+        delay_max_ms: u64,
+        backoff_factor: f64,
+        jitter_ms: u64,
+        max_elapsed_ms: Option<u64>,
+        retry_statuses: Option<Vec<String>>,
+        respect_retry_after: bool,
+        idempotency_header: Option<String>,
+    ) -> PyResult<Self> {
+        let retry_statuses = match retry_statuses {
+            Some(statuses) => statuses
+                .iter()
+                .map(|s| s.parse())
+                .collect::<Result<Vec<StatusMatcher>, String>>()
+                .map_err(to_pyvalue_err)?,
+            None => Self::default().retry_statuses,
+        };
+        Self::new(
+            max_retries,
+            Duration::from_millis(delay_initial_ms),
+            Duration::from_millis(delay_max_ms),
+            backoff_factor,
+            jitter_ms,
+            max_elapsed_ms.map(Duration::from_millis),
+            retry_statuses,
+            respect_retry_after,
+            idempotency_header.unwrap_or_else(|| DEFAULT_IDEMPOTENCY_HEADER.to_string()),
+        )
+        .map_err(to_pyvalue_err)
+    }
+
+    #[getter]
+    #[pyo3(name = "max_retries")]
+    fn py_max_retries(&self) -> u32 {
+        self.max_retries
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn policy(max_elapsed: Option<Duration>) -> RetryPolicy {
+        RetryPolicy::new(
+            3,
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+            2.0,
+            0,
+            max_elapsed,
+            vec![StatusMatcher::Code(429), StatusMatcher::Class(5)],
+            true,
+            DEFAULT_IDEMPOTENCY_HEADER.to_string(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case("429", Some(StatusMatcher::Code(429)))]
+    #[case("5xx", Some(StatusMatcher::Class(5)))]
+    #[case("4XX", Some(StatusMatcher::Class(4)))]
+    #[case("9xx", None)]
+    #[case("42", None)]
+    #[case("abc", None)]
+    fn test_status_matcher_from_str(#[case] input: &str, #[case] expected: Option<StatusMatcher>) {
+        assert_eq!(input.parse::<StatusMatcher>().ok(), expected);
+    }
+
+    #[rstest]
+    #[case(429, true)]
+    #[case(500, true)]
+    #[case(503, true)]
+    #[case(400, false)]
+    #[case(200, false)]
+    fn test_is_retryable_status(#[case] status: u16, #[case] expected: bool) {
+        assert_eq!(policy(None).is_retryable_status(status), expected);
+    }
+
+    #[rstest]
+    fn test_is_retryable_request() {
+        let policy = policy(None);
+        let mut headers = HashMap::new();
+
+        assert!(policy.is_retryable_request(&Method::GET, &headers));
+        assert!(policy.is_retryable_request(&Method::DELETE, &headers));
+        assert!(!policy.is_retryable_request(&Method::POST, &headers));
+        assert!(!policy.is_retryable_request(&Method::PATCH, &headers));
+
+        headers.insert("idempotency-key".to_string(), "abc-123".to_string());
+        assert!(policy.is_retryable_request(&Method::POST, &headers));
+    }
+
+    #[rstest]
+    fn test_parse_retry_after() {
+        let now = httpdate::parse_http_date("Wed, 21 Oct 2015 07:28:00 GMT").unwrap();
+
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:27:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[rstest]
+    fn test_next_delay_respects_retries_and_retry_after() {
+        let policy = policy(None);
+        let mut backoff = policy.backoff();
+
+        assert_eq!(
+            policy.next_delay(&mut backoff, None, Duration::ZERO),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.next_delay(&mut backoff, Some(Duration::from_secs(5)), Duration::ZERO),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            policy.next_delay(&mut backoff, None, Duration::ZERO),
+            Some(Duration::from_millis(400))
+        );
+        assert_eq!(policy.next_delay(&mut backoff, None, Duration::ZERO), None);
+    }
+
+    #[rstest]
+    fn test_next_delay_respects_max_elapsed() {
+        let policy = policy(Some(Duration::from_secs(1)));
+        let mut backoff = policy.backoff();
+
+        assert!(policy
+            .next_delay(&mut backoff, None, Duration::from_millis(500))
+            .is_some());
+        assert_eq!(
+            policy.next_delay(&mut backoff, None, Duration::from_millis(900)),
+            None
+        );
+    }
+}
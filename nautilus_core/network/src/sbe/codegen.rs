@@ -0,0 +1,199 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Build time generation of typed flyweight decoders from an SBE schema.
+//!
+//! Intended to be called from a `build.rs`, for example:
+//!
+//! ```ignore
+//! nautilus_network::sbe::codegen::generate_file("schemas/mdp3.xml", out_dir.join("mdp3.rs"))?;
+//! ```
+//!
+//! and then included with `include!(concat!(env!("OUT_DIR"), "/mdp3.rs"))`.
+//! Each message template generates a module with its `TEMPLATE_ID`, `BLOCK_LENGTH`
+//! and a zero-copy `Decoder` with an accessor per fixed size root block field.
+//! Repeating groups and variable length data are decoded with [`super::SbeDecoder`].
+
+use std::{fmt::Write, fs, path::Path};
+
+use anyhow::Result;
+
+use super::schema::{ByteOrder, FieldDef, Presence, PrimitiveType, Schema, TypeDef};
+
+/// Generates decoder source for the schema at `schema_path`, writing it to `out_path`.
+pub fn generate_file<P: AsRef<Path>, Q: AsRef<Path>>(schema_path: P, out_path: Q) -> Result<()> {
+    let xml = fs::read_to_string(schema_path)?;
+    let schema = Schema::parse(&xml)?;
+    fs::write(out_path, generate(&schema))?;
+    Ok(())
+}
+
+/// Generates decoder source for the given `schema`.
+#[must_use]
+pub fn generate(schema: &Schema) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "// Generated from SBE schema '{}' (id {}, version {}), do not edit.\n",
+        schema.package, schema.id, schema.version
+    );
+    let _ = writeln!(out, "pub const SCHEMA_ID: u16 = {};", schema.id);
+    let _ = writeln!(out, "pub const SCHEMA_VERSION: u16 = {};", schema.version);
+    let _ = writeln!(
+        out,
+        "pub const HEADER_LENGTH: usize = {};",
+        schema.header_type.size()
+    );
+
+    for message in schema.messages.values() {
+        let _ = writeln!(out, "\npub mod {} {{", to_snake_case(&message.name));
+        let _ = writeln!(out, "    pub const TEMPLATE_ID: u16 = {};", message.id);
+        let _ = writeln!(
+            out,
+            "    pub const BLOCK_LENGTH: usize = {};\n",
+            message.block_length
+        );
+        out.push_str(
+            "    #[derive(Clone, Copy, Debug)]\n    \
+             pub struct Decoder<'a> {\n        \
+             buf: &'a [u8],\n        \
+             offset: usize,\n    \
+             }\n\n    \
+             impl<'a> Decoder<'a> {\n        \
+             /// Wraps the root block of a message starting at `offset` (after the header).\n        \
+             #[must_use]\n        \
+             pub fn wrap(buf: &'a [u8], offset: usize) -> Option<Self> {\n            \
+             (buf.len() >= offset + BLOCK_LENGTH).then_some(Self { buf, offset })\n        \
+             }\n",
+        );
+        for field in &message.fields {
+            write_accessors(&mut out, schema.byte_order, field);
+        }
+        out.push_str("    }\n}\n");
+    }
+    out
+}
+
+fn write_accessors(out: &mut String, byte_order: ByteOrder, field: &FieldDef) {
+    let name = to_snake_case(&field.name);
+    match &field.type_def {
+        TypeDef::Encoded(encoded) if encoded.length == 1 => {
+            if !matches!(encoded.presence, Presence::Constant(_)) {
+                write_accessor(out, byte_order, &name, encoded.primitive, field.offset);
+            }
+        }
+        TypeDef::Enum(enum_type) => {
+            write_accessor(out, byte_order, &name, enum_type.encoding, field.offset);
+        }
+        TypeDef::Set(set_type) => {
+            write_accessor(out, byte_order, &name, set_type.encoding, field.offset);
+        }
+        TypeDef::Composite(composite) => {
+            let mut offset = field.offset;
+            for member in &composite.members {
+                if let TypeDef::Encoded(encoded) = member {
+                    if encoded.length == 1 && !matches!(encoded.presence, Presence::Constant(_)) {
+                        let member_name = format!("{name}_{}", to_snake_case(&encoded.name));
+                        write_accessor(out, byte_order, &member_name, encoded.primitive, offset);
+                    }
+                }
+                offset += member.size();
+            }
+        }
+        // Fixed length arrays and strings are left to the runtime decoder
+        TypeDef::Encoded(_) => {}
+    }
+}
+
+fn write_accessor(
+    out: &mut String,
+    byte_order: ByteOrder,
+    name: &str,
+    primitive: PrimitiveType,
+    offset: usize,
+) {
+    let rust_type = primitive.rust_type();
+    let size = primitive.size();
+    let from_bytes = match byte_order {
+        ByteOrder::LittleEndian => "from_le_bytes",
+        ByteOrder::BigEndian => "from_be_bytes",
+    };
+    let _ = write!(
+        out,
+        "\n        #[must_use]\n        \
+         pub fn {name}(&self) -> {rust_type} {{\n            \
+         let start = self.offset + {offset};\n            \
+         {rust_type}::{from_bytes}(self.buf[start..start + {size}].try_into().unwrap())\n        \
+         }}\n"
+    );
+}
+
+/// Converts a schema name (typically `PascalCase`) to `snake_case`.
+fn to_snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    let chars: Vec<char> = name.chars().collect();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev_lower =
+                i > 0 && (chars[i - 1].is_lowercase() || chars[i - 1].is_ascii_digit());
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if i > 0 && (prev_lower || (next_lower && chars[i - 1].is_uppercase())) {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(*c);
+        }
+    }
+    out
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::sbe::TEST_SCHEMA;
+
+    #[rstest]
+    #[case("MDIncrementalRefreshTrade", "md_incremental_refresh_trade")]
+    #[case("TransactTime", "transact_time")]
+    #[case("MDEntryPx", "md_entry_px")]
+    #[case("blockLength", "block_length")]
+    fn test_to_snake_case(#[case] name: &str, #[case] expected: &str) {
+        assert_eq!(to_snake_case(name), expected);
+    }
+
+    #[rstest]
+    fn test_generate() {
+        let schema = Schema::parse(TEST_SCHEMA).unwrap();
+
+        let source = generate(&schema);
+
+        assert!(source.contains("pub const SCHEMA_ID: u16 = 1;"));
+        assert!(source.contains("pub const HEADER_LENGTH: usize = 8;"));
+        assert!(source.contains("pub mod md_incremental_refresh_trade {"));
+        assert!(source.contains("pub const TEMPLATE_ID: u16 = 48;"));
+        assert!(source.contains("pub const BLOCK_LENGTH: usize = 19;"));
+        assert!(source.contains("pub fn transact_time(&self) -> u64"));
+        assert!(source.contains("pub fn match_event_indicator(&self) -> u8"));
+        assert!(source.contains("pub fn trade_count(&self) -> i32"));
+        assert!(source.contains("let start = self.offset + 15;"));
+        assert!(!source.contains("pub fn symbol("));
+    }
+}
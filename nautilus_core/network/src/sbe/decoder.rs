@@ -0,0 +1,670 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Schema driven decoding of SBE messages into generic values.
+
+use anyhow::{anyhow, bail, Result};
+use indexmap::IndexMap;
+
+use super::schema::{
+    ByteOrder, DataDef, EncodedType, FieldDef, GroupDef, Presence, PrimitiveType, Schema, TypeDef,
+};
+
+/// Represents a decoded SBE value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SbeValue {
+    Null,
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(String),
+    Enum(String),
+    Set(Vec<String>),
+    Array(Vec<SbeValue>),
+    Composite(IndexMap<String, SbeValue>),
+}
+
+impl SbeValue {
+    #[must_use]
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int(value) => Some(*value),
+            Self::UInt(value) => i64::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Int(value) => u64::try_from(*value).ok(),
+            Self::UInt(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Float(value) => Some(*value),
+            Self::Int(value) => Some(*value as f64),
+            Self::UInt(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) | Self::Enum(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the `(mantissa, exponent)` of a decimal composite value.
+    #[must_use]
+    pub fn as_decimal(&self) -> Option<(i64, i8)> {
+        match self {
+            Self::Composite(members) => {
+                let mantissa = members.get("mantissa")?.as_i64()?;
+                let exponent = members.get("exponent")?.as_i64()?;
+                Some((mantissa, i8::try_from(exponent).ok()?))
+            }
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    fn as_composite_pair(&self, first: &str, second: &str) -> Option<(u64, u64)> {
+        match self {
+            Self::Composite(members) => Some((
+                members.get(first)?.as_u64()?,
+                members.get(second)?.as_u64()?,
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Converts a decimal `mantissa * 10^exponent` to a fixed point integer with the given precision.
+///
+/// This allows decoded prices and sizes to be converted to raw Nautilus values
+/// without a lossy floating point round trip, returns `None` on overflow.
+#[must_use]
+pub fn decimal_to_fixed(mantissa: i64, exponent: i8, precision: u8) -> Option<i64> {
+    let shift = i32::from(exponent) + i32::from(precision);
+    if shift >= 0 {
+        mantissa.checked_mul(10_i64.checked_pow(shift as u32)?)
+    } else {
+        let divisor = 10_i64.checked_pow(shift.unsigned_abs())?;
+        Some(mantissa / divisor)
+    }
+}
+
+/// The decoded fields, repeating groups and variable length data of a block.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SbeBlock {
+    pub fields: IndexMap<String, SbeValue>,
+    pub groups: IndexMap<String, Vec<SbeBlock>>,
+    pub data: IndexMap<String, Vec<u8>>,
+}
+
+impl SbeBlock {
+    /// Returns the field with the given name (`Null` if absent).
+    #[must_use]
+    pub fn field(&self, name: &str) -> &SbeValue {
+        self.fields.get(name).unwrap_or(&SbeValue::Null)
+    }
+
+    /// Returns the entries of the repeating group with the given name.
+    #[must_use]
+    pub fn group(&self, name: &str) -> &[SbeBlock] {
+        self.groups.get(name).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Represents a decoded SBE message.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SbeMessage {
+    pub template_id: u16,
+    pub schema_id: u16,
+    pub version: u16,
+    pub name: String,
+    pub body: SbeBlock,
+}
+
+/// Decodes SBE messages using the templates of a schema.
+///
+/// Decoding follows the SBE extension rules, the acting block length and version
+/// from the message header are used so that messages encoded with a newer schema
+/// version can still be decoded (unknown trailing fields are skipped).
+#[derive(Clone, Debug)]
+pub struct SbeDecoder {
+    schema: Schema,
+}
+
+impl SbeDecoder {
+    #[must_use]
+    pub fn new(schema: Schema) -> Self {
+        Self { schema }
+    }
+
+    /// Creates a new decoder from the SBE XML schema format.
+    pub fn from_xml(xml: &str) -> Result<Self> {
+        Ok(Self::new(Schema::parse(xml)?))
+    }
+
+    #[must_use]
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Decodes a single message from the start of `buf`.
+    ///
+    /// Returns the message along with the number of bytes consumed.
+    pub fn decode(&self, buf: &[u8]) -> Result<(SbeMessage, usize)> {
+        let reader = Reader {
+            buf,
+            byte_order: self.schema.byte_order,
+        };
+        let header_type = &self.schema.header_type;
+        if buf.len() < header_type.size() {
+            bail!(
+                "Buffer too short for message header, expected {} bytes was {}",
+                header_type.size(),
+                buf.len()
+            );
+        }
+        let header = reader.read_type(header_type, 0)?;
+        let header_field = |name: &str| {
+            match &header {
+                SbeValue::Composite(members) => members.get(name).and_then(SbeValue::as_u64),
+                _ => None,
+            }
+            .ok_or_else(|| anyhow!("Message header missing `{name}`"))
+        };
+
+        let block_length = header_field("blockLength")? as usize;
+        let template_id = header_field("templateId")? as u16;
+        let schema_id = header_field("schemaId")? as u16;
+        let version = header_field("version")? as u16;
+
+        if schema_id != self.schema.id {
+            bail!(
+                "Schema ID mismatch, expected {} was {schema_id}",
+                self.schema.id
+            );
+        }
+        let message = self
+            .schema
+            .messages
+            .get(&template_id)
+            .ok_or_else(|| anyhow!("Unknown template ID {template_id}"))?;
+
+        let mut offset = header_type.size();
+        let body = reader.read_block(
+            &message.fields,
+            &message.groups,
+            &message.data,
+            block_length,
+            version,
+            &mut offset,
+        )?;
+
+        Ok((
+            SbeMessage {
+                template_id,
+                schema_id,
+                version,
+                name: message.name.clone(),
+                body,
+            },
+            offset,
+        ))
+    }
+
+    /// Decodes all messages from `buf`, which must contain only complete messages.
+    pub fn decode_all(&self, mut buf: &[u8]) -> Result<Vec<SbeMessage>> {
+        let mut messages = Vec::new();
+        while !buf.is_empty() {
+            let (message, consumed) = self.decode(buf)?;
+            messages.push(message);
+            buf = &buf[consumed..];
+        }
+        Ok(messages)
+    }
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    byte_order: ByteOrder,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&self, offset: usize, len: usize) -> Result<&'a [u8]> {
+        offset
+            .checked_add(len)
+            .and_then(|end| self.buf.get(offset..end))
+            .ok_or_else(|| {
+                anyhow!(
+                    "Buffer underflow reading {len} bytes at {offset} (length {})",
+                    self.buf.len()
+                )
+            })
+    }
+
+    /// Advances `offset` by `len` bytes, failing if this would pass the end of the buffer.
+    fn advance(&self, offset: &mut usize, len: usize) -> Result<()> {
+        match offset.checked_add(len) {
+            Some(end) if end <= self.buf.len() => {
+                *offset = end;
+                Ok(())
+            }
+            _ => bail!(
+                "Buffer underflow advancing {len} bytes at {offset} (length {})",
+                self.buf.len()
+            ),
+        }
+    }
+
+    fn read_block(
+        &self,
+        fields: &[FieldDef],
+        groups: &[GroupDef],
+        data: &[DataDef],
+        block_length: usize,
+        version: u16,
+        offset: &mut usize,
+    ) -> Result<SbeBlock> {
+        let mut block = SbeBlock::default();
+        for field in fields {
+            let fits = field.offset + field.type_def.size() <= block_length;
+            let value = if field.since_version > version || !fits {
+                SbeValue::Null
+            } else {
+                self.read_type(&field.type_def, *offset + field.offset)?
+            };
+            block.fields.insert(field.name.clone(), value);
+        }
+        self.advance(offset, block_length)?;
+
+        for group in groups {
+            let dimension = self.read_type(&group.dimension_type, *offset)?;
+            self.advance(offset, group.dimension_type.size())?;
+            let (entry_length, count) =
+                match dimension.as_composite_pair("blockLength", "numInGroup") {
+                    Some(pair) => pair,
+                    None => bail!("Invalid dimension type for group `{}`", group.name),
+                };
+
+            // The counts come off the wire, so check the entries can fit in the remaining
+            // buffer before decoding rather than trusting them to size allocations
+            let remaining = (self.buf.len() - *offset) as u64;
+            let total = entry_length.checked_mul(count);
+            if total.filter(|total| *total <= remaining).is_none() {
+                bail!(
+                    "Group `{}` of {count} entries exceeds remaining buffer length {remaining}",
+                    group.name
+                );
+            }
+
+            let mut entries = Vec::new();
+            for _ in 0..count {
+                entries.push(self.read_block(
+                    &group.fields,
+                    &group.groups,
+                    &group.data,
+                    entry_length as usize,
+                    version,
+                    offset,
+                )?);
+            }
+            block.groups.insert(group.name.clone(), entries);
+        }
+
+        for var_data in data {
+            let TypeDef::Composite(composite) = &var_data.type_def else {
+                bail!("Data `{}` must have a composite type", var_data.name);
+            };
+            let length_type = composite
+                .members
+                .first()
+                .ok_or_else(|| anyhow!("Data `{}` missing length", var_data.name))?;
+            let length = self
+                .read_type(length_type, *offset)?
+                .as_u64()
+                .ok_or_else(|| anyhow!("Invalid length for data `{}`", var_data.name))?
+                as usize;
+            self.advance(offset, length_type.size())?;
+            let bytes = self.bytes(*offset, length)?;
+            self.advance(offset, length)?;
+            block.data.insert(var_data.name.clone(), bytes.to_vec());
+        }
+
+        Ok(block)
+    }
+
+    fn read_type(&self, type_def: &TypeDef, offset: usize) -> Result<SbeValue> {
+        match type_def {
+            TypeDef::Encoded(encoded) => self.read_encoded(encoded, offset),
+            TypeDef::Enum(enum_type) => {
+                let raw = self.read_raw(enum_type.encoding, offset)?;
+                let value = enum_type
+                    .values
+                    .iter()
+                    .find(|(_, value)| *value == raw)
+                    .map_or(SbeValue::Null, |(name, _)| SbeValue::Enum(name.clone()));
+                Ok(value)
+            }
+            TypeDef::Set(set_type) => {
+                let raw = self.read_raw(set_type.encoding, offset)?;
+                let choices = set_type
+                    .choices
+                    .iter()
+                    .filter(|(_, bit)| raw & (1 << bit) != 0)
+                    .map(|(name, _)| name.clone())
+                    .collect();
+                Ok(SbeValue::Set(choices))
+            }
+            TypeDef::Composite(composite) => {
+                let mut members = IndexMap::with_capacity(composite.members.len());
+                let mut member_offset = offset;
+                for member in &composite.members {
+                    members.insert(
+                        member.name().to_string(),
+                        self.read_type(member, member_offset)?,
+                    );
+                    member_offset += member.size();
+                }
+                Ok(SbeValue::Composite(members))
+            }
+        }
+    }
+
+    fn read_encoded(&self, encoded: &EncodedType, offset: usize) -> Result<SbeValue> {
+        if let Presence::Constant(value) = &encoded.presence {
+            return Ok(parse_constant(encoded.primitive, value));
+        }
+
+        let size = encoded.primitive.size();
+        if encoded.primitive == PrimitiveType::Char && encoded.length > 1 {
+            let bytes = self.bytes(offset, encoded.length)?;
+            let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+            return Ok(SbeValue::String(
+                String::from_utf8_lossy(&bytes[..end]).into_owned(),
+            ));
+        }
+
+        let null = match &encoded.presence {
+            Presence::Optional(null) => Some(null.as_deref()),
+            _ => None,
+        };
+        let mut values = Vec::with_capacity(encoded.length);
+        for i in 0..encoded.length {
+            let raw = self.read_raw(encoded.primitive, offset + i * size)?;
+            let value = to_value(encoded.primitive, raw);
+            let is_null = match null {
+                Some(Some(null_value)) => value == parse_constant(encoded.primitive, null_value),
+                Some(None) => raw == default_null(encoded.primitive),
+                None => false,
+            };
+            values.push(if is_null { SbeValue::Null } else { value });
+        }
+
+        Ok(if values.len() == 1 {
+            values.pop().unwrap()
+        } else {
+            SbeValue::Array(values)
+        })
+    }
+
+    /// Reads the raw bits of a primitive value.
+    fn read_raw(&self, primitive: PrimitiveType, offset: usize) -> Result<u64> {
+        let bytes = self.bytes(offset, primitive.size())?;
+        let mut raw = [0u8; 8];
+        let raw = match self.byte_order {
+            ByteOrder::LittleEndian => {
+                raw[..bytes.len()].copy_from_slice(bytes);
+                u64::from_le_bytes(raw)
+            }
+            ByteOrder::BigEndian => {
+                raw[8 - bytes.len()..].copy_from_slice(bytes);
+                u64::from_be_bytes(raw)
+            }
+        };
+        Ok(raw)
+    }
+}
+
+/// Converts raw bits to a value, sign extending signed integers.
+fn to_value(primitive: PrimitiveType, raw: u64) -> SbeValue {
+    match primitive {
+        PrimitiveType::Char => SbeValue::String(char::from(raw as u8).to_string()),
+        PrimitiveType::Int8 => SbeValue::Int(i64::from(raw as u8 as i8)),
+        PrimitiveType::Int16 => SbeValue::Int(i64::from(raw as u16 as i16)),
+        PrimitiveType::Int32 => SbeValue::Int(i64::from(raw as u32 as i32)),
+        PrimitiveType::Int64 => SbeValue::Int(raw as i64),
+        PrimitiveType::UInt8
+        | PrimitiveType::UInt16
+        | PrimitiveType::UInt32
+        | PrimitiveType::UInt64 => SbeValue::UInt(raw),
+        PrimitiveType::Float => SbeValue::Float(f64::from(f32::from_bits(raw as u32))),
+        PrimitiveType::Double => SbeValue::Float(f64::from_bits(raw)),
+    }
+}
+
+/// Returns the raw bits of the default null value for a primitive type.
+fn default_null(primitive: PrimitiveType) -> u64 {
+    match primitive {
+        PrimitiveType::Char => 0,
+        PrimitiveType::Int8 => u64::from(i8::MIN as u8),
+        PrimitiveType::Int16 => u64::from(i16::MIN as u16),
+        PrimitiveType::Int32 => u64::from(i32::MIN as u32),
+        PrimitiveType::Int64 => i64::MIN as u64,
+        PrimitiveType::UInt8 => u64::from(u8::MAX),
+        PrimitiveType::UInt16 => u64::from(u16::MAX),
+        PrimitiveType::UInt32 => u64::from(u32::MAX),
+        PrimitiveType::UInt64 => u64::MAX,
+        PrimitiveType::Float => u64::from(f32::NAN.to_bits()),
+        PrimitiveType::Double => f64::NAN.to_bits(),
+    }
+}
+
+fn parse_constant(primitive: PrimitiveType, value: &str) -> SbeValue {
+    let value = value.trim();
+    match primitive {
+        PrimitiveType::Char => SbeValue::String(value.to_string()),
+        PrimitiveType::Int8
+        | PrimitiveType::Int16
+        | PrimitiveType::Int32
+        | PrimitiveType::Int64 => value.parse().map_or(SbeValue::Null, SbeValue::Int),
+        PrimitiveType::UInt8
+        | PrimitiveType::UInt16
+        | PrimitiveType::UInt32
+        | PrimitiveType::UInt64 => value.parse().map_or(SbeValue::Null, SbeValue::UInt),
+        PrimitiveType::Float | PrimitiveType::Double => {
+            value.parse().map_or(SbeValue::Null, SbeValue::Float)
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::sbe::TEST_SCHEMA;
+
+    fn encode_trade(version: u16, trade_count: i32) -> Vec<u8> {
+        let mut buf = Vec::new();
+        // Message header
+        buf.extend_from_slice(&19u16.to_le_bytes());
+        buf.extend_from_slice(&48u16.to_le_bytes());
+        buf.extend_from_slice(&1u16.to_le_bytes());
+        buf.extend_from_slice(&version.to_le_bytes());
+        // Root block
+        buf.extend_from_slice(&1_700_000_000_000_000_000u64.to_le_bytes());
+        buf.push(0b1000_0001);
+        buf.extend_from_slice(b"ESZ4\0\0");
+        buf.extend_from_slice(&trade_count.to_le_bytes());
+        // Repeating group
+        buf.extend_from_slice(&13u16.to_le_bytes());
+        buf.push(2);
+        for (mantissa, size, side) in [(4_500_250_000_000i64, 5i32, 1u8), (4_500_500_000_000, 2, 2)]
+        {
+            buf.extend_from_slice(&mantissa.to_le_bytes());
+            buf.extend_from_slice(&size.to_le_bytes());
+            buf.push(side);
+        }
+        // Variable length data
+        buf.extend_from_slice(&5u16.to_le_bytes());
+        buf.extend_from_slice(b"hello");
+        buf
+    }
+
+    #[rstest]
+    fn test_decode_message() {
+        let decoder = SbeDecoder::from_xml(TEST_SCHEMA).unwrap();
+        let buf = encode_trade(9, 3);
+
+        let (message, consumed) = decoder.decode(&buf).unwrap();
+
+        assert_eq!(consumed, buf.len());
+        assert_eq!(message.template_id, 48);
+        assert_eq!(message.name, "MDIncrementalRefreshTrade");
+        let body = &message.body;
+        assert_eq!(
+            body.field("TransactTime").as_u64(),
+            Some(1_700_000_000_000_000_000)
+        );
+        assert_eq!(
+            body.field("MatchEventIndicator"),
+            &SbeValue::Set(vec!["LastTradeMsg".to_string(), "EndOfEvent".to_string()])
+        );
+        assert_eq!(body.field("Symbol").as_str(), Some("ESZ4"));
+        assert_eq!(body.field("TradeCount").as_i64(), Some(3));
+
+        let entries = body.group("NoMDEntries");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[0].field("MDEntryPx").as_decimal(),
+            Some((4_500_250_000_000, -9))
+        );
+        assert_eq!(entries[0].field("MDEntrySize").as_i64(), Some(5));
+        assert_eq!(entries[0].field("AggressorSide").as_str(), Some("Buy"));
+        assert_eq!(entries[1].field("AggressorSide").as_str(), Some("Sell"));
+        assert_eq!(body.data["Text"], b"hello");
+    }
+
+    #[rstest]
+    fn test_decode_optional_null_value() {
+        let decoder = SbeDecoder::from_xml(TEST_SCHEMA).unwrap();
+        let buf = encode_trade(9, i32::MAX);
+
+        let (message, _) = decoder.decode(&buf).unwrap();
+
+        assert!(message.body.field("TradeCount").is_null());
+    }
+
+    #[rstest]
+    fn test_decode_field_since_newer_version_is_null() {
+        let decoder = SbeDecoder::from_xml(TEST_SCHEMA).unwrap();
+        let buf = encode_trade(8, 3);
+
+        let (message, _) = decoder.decode(&buf).unwrap();
+
+        assert!(message.body.field("TradeCount").is_null());
+        assert_eq!(message.body.group("NoMDEntries").len(), 2);
+    }
+
+    #[rstest]
+    fn test_decode_all() {
+        let decoder = SbeDecoder::from_xml(TEST_SCHEMA).unwrap();
+        let mut buf = encode_trade(9, 1);
+        buf.extend(encode_trade(9, 2));
+
+        let messages = decoder.decode_all(&buf).unwrap();
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[1].body.field("TradeCount").as_i64(), Some(2));
+    }
+
+    #[rstest]
+    fn test_decode_truncated_fails() {
+        let decoder = SbeDecoder::from_xml(TEST_SCHEMA).unwrap();
+        let buf = encode_trade(9, 3);
+
+        assert!(decoder.decode(&buf[..buf.len() - 1]).is_err());
+    }
+
+    #[rstest]
+    fn test_decode_unknown_template_fails() {
+        let decoder = SbeDecoder::from_xml(TEST_SCHEMA).unwrap();
+        let mut buf = encode_trade(9, 3);
+        buf[2..4].copy_from_slice(&99u16.to_le_bytes());
+
+        assert!(decoder.decode(&buf).is_err());
+    }
+
+    #[rstest]
+    fn test_decode_short_header_fails() {
+        let decoder = SbeDecoder::from_xml(TEST_SCHEMA).unwrap();
+        let buf = encode_trade(9, 3);
+
+        assert!(decoder.decode(&buf[..4]).is_err());
+    }
+
+    #[rstest]
+    fn test_decode_block_length_past_end_fails() {
+        let decoder = SbeDecoder::from_xml(TEST_SCHEMA).unwrap();
+        let mut buf = encode_trade(9, 3);
+        buf[0..2].copy_from_slice(&u16::MAX.to_le_bytes());
+
+        assert!(decoder.decode(&buf).is_err());
+        assert!(decoder.decode_all(&buf).is_err());
+    }
+
+    #[rstest]
+    fn test_decode_group_count_past_end_fails() {
+        let decoder = SbeDecoder::from_xml(TEST_SCHEMA).unwrap();
+        let mut buf = encode_trade(9, 3);
+        // numInGroup of the repeating group follows the header and root block
+        buf[8 + 19 + 2] = u8::MAX;
+
+        assert!(decoder.decode(&buf).is_err());
+    }
+
+    #[rstest]
+    #[case(4_500_250_000_000, -9, 9, Some(4_500_250_000_000))]
+    #[case(450_025, -2, 9, Some(4_500_250_000_000))]
+    #[case(5, 0, 0, Some(5))]
+    #[case(123_456_789, -9, 3, Some(123))]
+    #[case(i64::MAX, 0, 9, None)]
+    fn test_decimal_to_fixed(
+        #[case] mantissa: i64,
+        #[case] exponent: i8,
+        #[case] precision: u8,
+        #[case] expected: Option<i64>,
+    ) {
+        assert_eq!(decimal_to_fixed(mantissa, exponent, precision), expected);
+    }
+}
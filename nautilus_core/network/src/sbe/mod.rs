@@ -0,0 +1,77 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Simple Binary Encoding (SBE) decoding for market data feeds.
+//!
+//! Message templates are described by the standard SBE XML schema, which can be
+//! parsed at runtime to drive [`SbeDecoder`], or at build time with [`codegen`]
+//! to generate typed zero-copy decoders.
+
+pub mod codegen;
+pub mod decoder;
+pub mod schema;
+
+pub use decoder::{decimal_to_fixed, SbeBlock, SbeDecoder, SbeMessage, SbeValue};
+pub use schema::Schema;
+
+#[cfg(test)]
+pub(crate) const TEST_SCHEMA: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sbe:messageSchema xmlns:sbe="http://fixprotocol.io/2016/sbe"
+                   package="test.mdp" id="1" version="9" byteOrder="littleEndian">
+    <types>
+        <composite name="messageHeader">
+            <type name="blockLength" primitiveType="uint16"/>
+            <type name="templateId" primitiveType="uint16"/>
+            <type name="schemaId" primitiveType="uint16"/>
+            <type name="version" primitiveType="uint16"/>
+        </composite>
+        <composite name="groupSize">
+            <type name="blockLength" primitiveType="uint16"/>
+            <type name="numInGroup" primitiveType="uint8"/>
+        </composite>
+        <composite name="varString">
+            <type name="length" primitiveType="uint16"/>
+            <type name="varData" primitiveType="uint8" length="0"/>
+        </composite>
+        <composite name="PRICE9">
+            <type name="mantissa" primitiveType="int64"/>
+            <type name="exponent" primitiveType="int8" presence="constant">-9</type>
+        </composite>
+        <type name="Int32NULL" primitiveType="int32" presence="optional" nullValue="2147483647"/>
+        <type name="Symbol" primitiveType="char" length="6"/>
+        <enum name="AggressorSide" encodingType="uint8">
+            <validValue name="NoAggressor">0</validValue>
+            <validValue name="Buy">1</validValue>
+            <validValue name="Sell">2</validValue>
+        </enum>
+        <set name="MatchEventIndicator" encodingType="uint8">
+            <choice name="LastTradeMsg">0</choice>
+            <choice name="EndOfEvent">7</choice>
+        </set>
+    </types>
+    <sbe:message name="MDIncrementalRefreshTrade" id="48" blockLength="19">
+        <field name="TransactTime" id="60" type="uint64"/>
+        <field name="MatchEventIndicator" id="5799" type="MatchEventIndicator"/>
+        <field name="Symbol" id="55" type="Symbol" offset="9"/>
+        <field name="TradeCount" id="346" type="Int32NULL" offset="15" sinceVersion="9"/>
+        <group name="NoMDEntries" id="268" dimensionType="groupSize">
+            <field name="MDEntryPx" id="270" type="PRICE9"/>
+            <field name="MDEntrySize" id="271" type="int32"/>
+            <field name="AggressorSide" id="5797" type="AggressorSide"/>
+        </group>
+        <data name="Text" id="58" type="varString"/>
+    </sbe:message>
+</sbe:messageSchema>
+"#;
@@ -0,0 +1,552 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! SBE message schema model, parsed from the standard XML schema format.
+
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Result};
+use indexmap::IndexMap;
+use roxmltree::{Document, Node};
+
+/// The default name of the message header composite type.
+pub const MESSAGE_HEADER_TYPE: &str = "messageHeader";
+
+/// The byte order of all encoded values in a schema.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ByteOrder {
+    LittleEndian,
+    BigEndian,
+}
+
+/// The SBE primitive types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrimitiveType {
+    Char,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    Float,
+    Double,
+}
+
+impl PrimitiveType {
+    /// Returns the encoded size (bytes) of a single value.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Char | Self::Int8 | Self::UInt8 => 1,
+            Self::Int16 | Self::UInt16 => 2,
+            Self::Int32 | Self::UInt32 | Self::Float => 4,
+            Self::Int64 | Self::UInt64 | Self::Double => 8,
+        }
+    }
+
+    /// Returns the name of the equivalent Rust type.
+    #[must_use]
+    pub fn rust_type(&self) -> &'static str {
+        match self {
+            Self::Char | Self::UInt8 => "u8",
+            Self::Int8 => "i8",
+            Self::Int16 => "i16",
+            Self::Int32 => "i32",
+            Self::Int64 => "i64",
+            Self::UInt16 => "u16",
+            Self::UInt32 => "u32",
+            Self::UInt64 => "u64",
+            Self::Float => "f32",
+            Self::Double => "f64",
+        }
+    }
+}
+
+impl FromStr for PrimitiveType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "char" => Self::Char,
+            "int8" => Self::Int8,
+            "int16" => Self::Int16,
+            "int32" => Self::Int32,
+            "int64" => Self::Int64,
+            "uint8" => Self::UInt8,
+            "uint16" => Self::UInt16,
+            "uint32" => Self::UInt32,
+            "uint64" => Self::UInt64,
+            "float" => Self::Float,
+            "double" => Self::Double,
+            _ => bail!("Unknown SBE primitive type '{s}'"),
+        })
+    }
+}
+
+/// The presence of an encoded value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Presence {
+    Required,
+    /// The value may be null, encoded as the given null value (or the type default).
+    Optional(Option<String>),
+    /// The value is constant and not encoded on the wire.
+    Constant(String),
+}
+
+/// A primitive (or fixed length array of primitive) type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EncodedType {
+    pub name: String,
+    pub primitive: PrimitiveType,
+    /// The number of elements (greater than 1 for arrays and fixed length strings).
+    pub length: usize,
+    pub presence: Presence,
+}
+
+/// An enumeration of named values over a primitive encoding.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumType {
+    pub name: String,
+    pub encoding: PrimitiveType,
+    /// The valid values as (name, encoded value) pairs.
+    pub values: Vec<(String, u64)>,
+}
+
+/// A bit set of named choices over an unsigned primitive encoding.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetType {
+    pub name: String,
+    pub encoding: PrimitiveType,
+    /// The choices as (name, bit position) pairs.
+    pub choices: Vec<(String, u8)>,
+}
+
+/// A composite of types encoded contiguously.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompositeType {
+    pub name: String,
+    pub members: Vec<TypeDef>,
+}
+
+/// Represents a type definition within a schema.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypeDef {
+    Encoded(EncodedType),
+    Enum(EnumType),
+    Set(SetType),
+    Composite(CompositeType),
+}
+
+impl TypeDef {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Encoded(t) => &t.name,
+            Self::Enum(t) => &t.name,
+            Self::Set(t) => &t.name,
+            Self::Composite(t) => &t.name,
+        }
+    }
+
+    /// Returns the encoded size (bytes) of the type.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Encoded(t) => match t.presence {
+                Presence::Constant(_) => 0,
+                _ => t.primitive.size() * t.length,
+            },
+            Self::Enum(t) => t.encoding.size(),
+            Self::Set(t) => t.encoding.size(),
+            Self::Composite(t) => t.members.iter().map(Self::size).sum(),
+        }
+    }
+
+    /// Returns the composite member with the given name.
+    #[must_use]
+    pub fn member(&self, name: &str) -> Option<&TypeDef> {
+        match self {
+            Self::Composite(t) => t.members.iter().find(|m| m.name() == name),
+            _ => None,
+        }
+    }
+}
+
+/// A fixed size field within a message or group block.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldDef {
+    pub name: String,
+    pub id: u16,
+    pub type_def: TypeDef,
+    pub offset: usize,
+    /// The schema version the field was added in.
+    pub since_version: u16,
+}
+
+/// A variable length data field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataDef {
+    pub name: String,
+    pub id: u16,
+    /// The `varData` composite with `length` and `varData` members.
+    pub type_def: TypeDef,
+}
+
+/// A repeating group within a message or group.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupDef {
+    pub name: String,
+    pub id: u16,
+    pub block_length: usize,
+    /// The dimension composite with `blockLength` and `numInGroup` members.
+    pub dimension_type: TypeDef,
+    pub fields: Vec<FieldDef>,
+    pub groups: Vec<GroupDef>,
+    pub data: Vec<DataDef>,
+}
+
+/// A message template.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MessageDef {
+    pub name: String,
+    pub id: u16,
+    pub block_length: usize,
+    pub fields: Vec<FieldDef>,
+    pub groups: Vec<GroupDef>,
+    pub data: Vec<DataDef>,
+}
+
+/// Represents a parsed SBE message schema.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Schema {
+    pub package: String,
+    pub id: u16,
+    pub version: u16,
+    pub byte_order: ByteOrder,
+    pub header_type: TypeDef,
+    pub types: IndexMap<String, TypeDef>,
+    pub messages: IndexMap<u16, MessageDef>,
+}
+
+impl Schema {
+    /// Parses a schema from the SBE XML schema format.
+    pub fn parse(xml: &str) -> Result<Self> {
+        let doc = Document::parse(xml)?;
+        let root = doc.root_element();
+        if root.tag_name().name() != "messageSchema" {
+            bail!("Root element must be `messageSchema`");
+        }
+
+        let mut types = IndexMap::new();
+        for types_node in children(root, "types") {
+            for node in types_node.children().filter(Node::is_element) {
+                let type_def = parse_type(node, &types)?;
+                types.insert(type_def.name().to_string(), type_def);
+            }
+        }
+
+        let header_name = root.attribute("headerType").unwrap_or(MESSAGE_HEADER_TYPE);
+        let header_type = resolve_type(&types, header_name)?;
+
+        let mut messages = IndexMap::new();
+        for node in children(root, "message") {
+            let (fields, groups, data) = parse_block(node, &types)?;
+            let block_length = match node.attribute("blockLength") {
+                Some(value) => value.parse()?,
+                None => block_size(&fields),
+            };
+            let message = MessageDef {
+                name: required(node, "name")?.to_string(),
+                id: required(node, "id")?.parse()?,
+                block_length,
+                fields,
+                groups,
+                data,
+            };
+            messages.insert(message.id, message);
+        }
+
+        Ok(Self {
+            package: root.attribute("package").unwrap_or_default().to_string(),
+            id: root.attribute("id").unwrap_or("0").parse()?,
+            version: root.attribute("version").unwrap_or("0").parse()?,
+            byte_order: match root.attribute("byteOrder") {
+                None | Some("littleEndian") => ByteOrder::LittleEndian,
+                Some("bigEndian") => ByteOrder::BigEndian,
+                Some(other) => bail!("Invalid byteOrder '{other}'"),
+            },
+            header_type,
+            types,
+            messages,
+        })
+    }
+
+    /// Returns the message template with the given name.
+    #[must_use]
+    pub fn message_by_name(&self, name: &str) -> Option<&MessageDef> {
+        self.messages.values().find(|message| message.name == name)
+    }
+}
+
+/// Returns the child elements with the given local name (ignoring namespace prefixes).
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |child| child.is_element() && child.tag_name().name() == name)
+}
+
+fn required<'a>(node: Node<'a, '_>, attribute: &str) -> Result<&'a str> {
+    node.attribute(attribute).ok_or_else(|| {
+        anyhow!(
+            "Missing attribute `{attribute}` on `{}`",
+            node.tag_name().name()
+        )
+    })
+}
+
+fn parse_u64(value: &str) -> Result<u64> {
+    let value = value.trim();
+    // Char enum values are single characters
+    if value.len() == 1 && !value.as_bytes()[0].is_ascii_digit() {
+        return Ok(u64::from(value.as_bytes()[0]));
+    }
+    Ok(value.parse()?)
+}
+
+fn resolve_type(types: &IndexMap<String, TypeDef>, name: &str) -> Result<TypeDef> {
+    if let Some(type_def) = types.get(name) {
+        return Ok(type_def.clone());
+    }
+    let primitive = name
+        .parse::<PrimitiveType>()
+        .map_err(|_| anyhow!("Unknown type '{name}'"))?;
+    Ok(TypeDef::Encoded(EncodedType {
+        name: name.to_string(),
+        primitive,
+        length: 1,
+        presence: Presence::Required,
+    }))
+}
+
+fn parse_presence(node: Node) -> Result<Presence> {
+    Ok(match node.attribute("presence") {
+        None | Some("required") => Presence::Required,
+        Some("optional") => Presence::Optional(node.attribute("nullValue").map(String::from)),
+        Some("constant") => Presence::Constant(node.text().unwrap_or_default().trim().to_string()),
+        Some(other) => bail!("Invalid presence '{other}'"),
+    })
+}
+
+fn parse_type(node: Node, types: &IndexMap<String, TypeDef>) -> Result<TypeDef> {
+    let name = required(node, "name")?.to_string();
+    Ok(match node.tag_name().name() {
+        "type" => TypeDef::Encoded(EncodedType {
+            name,
+            primitive: required(node, "primitiveType")?.parse()?,
+            length: node.attribute("length").unwrap_or("1").parse()?,
+            presence: parse_presence(node)?,
+        }),
+        "enum" => {
+            let encoding = resolve_type(types, required(node, "encodingType")?)?;
+            let TypeDef::Encoded(encoding) = encoding else {
+                bail!("Enum `{name}` must have a primitive encoding");
+            };
+            let values = children(node, "validValue")
+                .map(|value| {
+                    Ok((
+                        required(value, "name")?.to_string(),
+                        parse_u64(value.text().unwrap_or_default())?,
+                    ))
+                })
+                .collect::<Result<_>>()?;
+            TypeDef::Enum(EnumType {
+                name,
+                encoding: encoding.primitive,
+                values,
+            })
+        }
+        "set" => {
+            let encoding = resolve_type(types, required(node, "encodingType")?)?;
+            let TypeDef::Encoded(encoding) = encoding else {
+                bail!("Set `{name}` must have a primitive encoding");
+            };
+            let choices = children(node, "choice")
+                .map(|choice| {
+                    Ok((
+                        required(choice, "name")?.to_string(),
+                        choice.text().unwrap_or_default().trim().parse()?,
+                    ))
+                })
+                .collect::<Result<_>>()?;
+            TypeDef::Set(SetType {
+                name,
+                encoding: encoding.primitive,
+                choices,
+            })
+        }
+        "composite" => {
+            let members = node
+                .children()
+                .filter(Node::is_element)
+                .map(|member| match member.tag_name().name() {
+                    "ref" => {
+                        let mut type_def = resolve_type(types, required(member, "type")?)?;
+                        rename(&mut type_def, required(member, "name")?);
+                        Ok(type_def)
+                    }
+                    _ => parse_type(member, types),
+                })
+                .collect::<Result<_>>()?;
+            TypeDef::Composite(CompositeType { name, members })
+        }
+        other => bail!("Unknown type element `{other}`"),
+    })
+}
+
+fn rename(type_def: &mut TypeDef, name: &str) {
+    let target = match type_def {
+        TypeDef::Encoded(t) => &mut t.name,
+        TypeDef::Enum(t) => &mut t.name,
+        TypeDef::Set(t) => &mut t.name,
+        TypeDef::Composite(t) => &mut t.name,
+    };
+    *target = name.to_string();
+}
+
+fn block_size(fields: &[FieldDef]) -> usize {
+    fields
+        .iter()
+        .map(|field| field.offset + field.type_def.size())
+        .max()
+        .unwrap_or(0)
+}
+
+type Block = (Vec<FieldDef>, Vec<GroupDef>, Vec<DataDef>);
+
+fn parse_block(node: Node, types: &IndexMap<String, TypeDef>) -> Result<Block> {
+    let mut fields = Vec::new();
+    let mut groups = Vec::new();
+    let mut data = Vec::new();
+    let mut offset = 0;
+
+    for child in node.children().filter(Node::is_element) {
+        let name = required(child, "name")?.to_string();
+        let id = required(child, "id")?.parse()?;
+        match child.tag_name().name() {
+            "field" => {
+                let mut type_def = resolve_type(types, required(child, "type")?)?;
+                // A field may override the presence of its type
+                if let (TypeDef::Encoded(encoded), Some(_)) =
+                    (&mut type_def, child.attribute("presence"))
+                {
+                    encoded.presence = parse_presence(child)?;
+                    if let Some(value_ref) = child.attribute("valueRef") {
+                        encoded.presence = Presence::Constant(value_ref.to_string());
+                    }
+                }
+                if let Some(field_offset) = child.attribute("offset") {
+                    offset = field_offset.parse()?;
+                }
+                let size = type_def.size();
+                fields.push(FieldDef {
+                    name,
+                    id,
+                    type_def,
+                    offset,
+                    since_version: child.attribute("sinceVersion").unwrap_or("0").parse()?,
+                });
+                offset += size;
+            }
+            "group" => {
+                let (group_fields, group_groups, group_data) = parse_block(child, types)?;
+                let block_length = match child.attribute("blockLength") {
+                    Some(value) => value.parse()?,
+                    None => block_size(&group_fields),
+                };
+                groups.push(GroupDef {
+                    name,
+                    id,
+                    block_length,
+                    dimension_type: resolve_type(
+                        types,
+                        child
+                            .attribute("dimensionType")
+                            .unwrap_or("groupSizeEncoding"),
+                    )?,
+                    fields: group_fields,
+                    groups: group_groups,
+                    data: group_data,
+                });
+            }
+            "data" => data.push(DataDef {
+                name,
+                id,
+                type_def: resolve_type(types, required(child, "type")?)?,
+            }),
+            other => bail!("Unknown block element `{other}`"),
+        }
+    }
+    Ok((fields, groups, data))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::sbe::TEST_SCHEMA;
+
+    #[rstest]
+    fn test_parse_schema() {
+        let schema = Schema::parse(TEST_SCHEMA).unwrap();
+
+        assert_eq!(schema.package, "test.mdp");
+        assert_eq!(schema.id, 1);
+        assert_eq!(schema.version, 9);
+        assert_eq!(schema.byte_order, ByteOrder::LittleEndian);
+        assert_eq!(schema.header_type.size(), 8);
+        assert_eq!(schema.types["PRICE9"].size(), 8);
+
+        let message = schema.message_by_name("MDIncrementalRefreshTrade").unwrap();
+        assert_eq!(message.id, 48);
+        assert_eq!(message.block_length, 19);
+        let offsets: Vec<usize> = message.fields.iter().map(|f| f.offset).collect();
+        assert_eq!(offsets, vec![0, 8, 9, 15]);
+        assert_eq!(message.fields[3].since_version, 9);
+
+        let group = &message.groups[0];
+        assert_eq!(group.name, "NoMDEntries");
+        assert_eq!(group.block_length, 13);
+        assert_eq!(group.dimension_type.size(), 3);
+        assert_eq!(message.data[0].name, "Text");
+    }
+
+    #[rstest]
+    fn test_parse_unknown_type_fails() {
+        let xml = r#"<messageSchema id="1">
+            <message name="Test" id="1"><field name="A" id="1" type="Missing"/></message>
+        </messageSchema>"#;
+
+        assert!(Schema::parse(xml).is_err());
+    }
+}
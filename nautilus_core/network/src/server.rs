@@ -0,0 +1,527 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A lightweight WebSocket server, for exposing internal services (such as the
+//! simulated paper venue) as network endpoints.
+//!
+//! Each connected client is assigned a [`ClientId`]. Messages can be sent to a
+//! single client, or published on a topic to all clients subscribed to it.
+
+use std::{
+    collections::HashSet,
+    io,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use dashmap::DashMap;
+use futures_util::{SinkExt, StreamExt};
+use nautilus_core::python::{to_pyruntime_err, to_pyvalue_err};
+use pyo3::prelude::*;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+    task::JoinHandle,
+};
+use tokio_tungstenite::{accept_async, tungstenite::Message};
+use tracing::{debug, error, warn};
+
+/// The identifier of a connected client.
+pub type ClientId = u64;
+
+/// Handles the connection lifecycle and incoming messages of server clients.
+pub trait ServerHandler: Send + Sync {
+    fn on_connect(&self, _server: &ServerHandle, _client_id: ClientId, _peer: SocketAddr) {}
+    fn on_message(&self, server: &ServerHandle, client_id: ClientId, message: String);
+    fn on_disconnect(&self, _server: &ServerHandle, _client_id: ClientId) {}
+}
+
+#[derive(Default)]
+struct ServerState {
+    clients: DashMap<ClientId, mpsc::UnboundedSender<Message>>,
+    topics: DashMap<String, HashSet<ClientId>>,
+    next_client_id: AtomicU64,
+}
+
+/// Provides operations on the connected clients of a [`WebSocketServer`].
+#[derive(Clone, Default)]
+pub struct ServerHandle {
+    state: Arc<ServerState>,
+}
+
+impl ServerHandle {
+    #[must_use]
+    pub fn client_count(&self) -> usize {
+        self.state.clients.len()
+    }
+
+    /// Sends the text `message` to the client, returns whether the client is connected.
+    pub fn send(&self, client_id: ClientId, message: String) -> bool {
+        self.state
+            .clients
+            .get(&client_id)
+            .is_some_and(|tx| tx.send(Message::Text(message)).is_ok())
+    }
+
+    /// Sends the text `message` to all clients, returns the number of clients sent to.
+    pub fn broadcast(&self, message: &str) -> usize {
+        self.state
+            .clients
+            .iter()
+            .filter(|entry| entry.send(Message::Text(message.to_string())).is_ok())
+            .count()
+    }
+
+    /// Subscribes the client to the `topic`.
+    pub fn subscribe(&self, client_id: ClientId, topic: &str) {
+        self.state
+            .topics
+            .entry(topic.to_string())
+            .or_default()
+            .insert(client_id);
+    }
+
+    /// Unsubscribes the client from the `topic`.
+    pub fn unsubscribe(&self, client_id: ClientId, topic: &str) {
+        if let Some(mut subscribers) = self.state.topics.get_mut(topic) {
+            subscribers.remove(&client_id);
+        }
+        self.state
+            .topics
+            .remove_if(topic, |_, subscribers| subscribers.is_empty());
+    }
+
+    /// Returns the number of clients subscribed to the `topic`.
+    #[must_use]
+    pub fn subscriber_count(&self, topic: &str) -> usize {
+        self.state.topics.get(topic).map_or(0, |s| s.len())
+    }
+
+    /// Publishes the text `message` to all clients subscribed to the `topic`,
+    /// returns the number of clients published to.
+    pub fn publish(&self, topic: &str, message: &str) -> usize {
+        let Some(subscribers) = self.state.topics.get(topic) else {
+            return 0;
+        };
+        subscribers
+            .iter()
+            .filter(|client_id| self.send(**client_id, message.to_string()))
+            .count()
+    }
+
+    /// Closes the connection of the client, returns whether the client was connected.
+    pub fn close(&self, client_id: ClientId) -> bool {
+        match self.remove_client(client_id) {
+            Some(tx) => tx.send(Message::Close(None)).is_ok(),
+            None => false,
+        }
+    }
+
+    fn add_client(&self, tx: mpsc::UnboundedSender<Message>) -> ClientId {
+        let client_id = self.state.next_client_id.fetch_add(1, Ordering::Relaxed) + 1;
+        self.state.clients.insert(client_id, tx);
+        client_id
+    }
+
+    fn remove_client(&self, client_id: ClientId) -> Option<mpsc::UnboundedSender<Message>> {
+        self.state.topics.retain(|_, subscribers| {
+            subscribers.remove(&client_id);
+            !subscribers.is_empty()
+        });
+        self.state.clients.remove(&client_id).map(|(_, tx)| tx)
+    }
+}
+
+/// Provides a WebSocket server accepting client connections on a TCP listener.
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct WebSocketServer {
+    handle: ServerHandle,
+    local_addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl WebSocketServer {
+    /// Binds the server to `addr` (e.g. `127.0.0.1:0`) and starts accepting connections.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the address cannot be bound.
+    pub async fn bind(addr: &str, handler: Arc<dyn ServerHandler>) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        debug!("WebSocket server listening on {local_addr}");
+
+        let handle = ServerHandle::default();
+        let task = tokio::spawn(accept_connections(listener, handle.clone(), handler));
+        Ok(Self {
+            handle,
+            local_addr,
+            task,
+        })
+    }
+
+    #[must_use]
+    pub fn handle(&self) -> ServerHandle {
+        self.handle.clone()
+    }
+
+    #[must_use]
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Stops accepting connections and closes all client connections.
+    pub fn shutdown(&self) {
+        self.task.abort();
+        let client_ids: Vec<ClientId> =
+            self.handle.state.clients.iter().map(|e| *e.key()).collect();
+        for client_id in client_ids {
+            self.handle.close(client_id);
+        }
+    }
+
+    #[must_use]
+    pub fn is_shutdown(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl Drop for WebSocketServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn accept_connections(
+    listener: TcpListener,
+    handle: ServerHandle,
+    handler: Arc<dyn ServerHandler>,
+) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, peer)) => {
+                tokio::spawn(handle_connection(
+                    stream,
+                    peer,
+                    handle.clone(),
+                    handler.clone(),
+                ));
+            }
+            Err(e) => error!("Error accepting connection: {e}"),
+        }
+    }
+}
+
+async fn handle_connection(
+    stream: TcpStream,
+    peer: SocketAddr,
+    handle: ServerHandle,
+    handler: Arc<dyn ServerHandler>,
+) {
+    let ws_stream = match accept_async(stream).await {
+        Ok(ws_stream) => ws_stream,
+        Err(e) => {
+            warn!("WebSocket handshake with {peer} failed: {e}");
+            return;
+        }
+    };
+    let (mut writer, mut reader) = ws_stream.split();
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let client_id = handle.add_client(tx.clone());
+    debug!("Client {client_id} connected from {peer}");
+    handler.on_connect(&handle, client_id, peer);
+
+    let write_task = tokio::spawn(async move {
+        while let Some(message) = rx.recv().await {
+            let is_close = matches!(message, Message::Close(_));
+            if writer.send(message).await.is_err() || is_close {
+                break;
+            }
+        }
+        let _ = writer.close().await;
+    });
+
+    while let Some(message) = reader.next().await {
+        match message {
+            Ok(Message::Text(text)) => handler.on_message(&handle, client_id, text),
+            Ok(Message::Binary(data)) => match String::from_utf8(data) {
+                Ok(text) => handler.on_message(&handle, client_id, text),
+                Err(_) => warn!("Client {client_id} sent non UTF-8 binary message"),
+            },
+            Ok(Message::Ping(payload)) => {
+                let _ = tx.send(Message::Pong(payload));
+            }
+            Ok(Message::Close(_)) => break,
+            Ok(_) => {}
+            Err(e) => {
+                debug!("Client {client_id} connection error: {e}");
+                break;
+            }
+        }
+    }
+
+    // Client may already have been removed by a server side close
+    if handle.remove_client(client_id).is_some() {
+        write_task.abort();
+    }
+    debug!("Client {client_id} disconnected");
+    handler.on_disconnect(&handle, client_id);
+}
+
+/// Forwards server events to Python callables on the tokio thread, it is the
+/// responsibility of the callables to hand over to the Python event loop.
+struct PyServerHandler {
+    handler: PyObject,
+    on_connect: Option<PyObject>,
+    on_disconnect: Option<PyObject>,
+}
+
+impl ServerHandler for PyServerHandler {
+    fn on_connect(&self, _server: &ServerHandle, client_id: ClientId, peer: SocketAddr) {
+        if let Some(on_connect) = &self.on_connect {
+            Python::with_gil(|py| {
+                if let Err(e) = on_connect.call1(py, (client_id, peer.to_string())) {
+                    error!("Error calling `on_connect` handler: {e}");
+                }
+            });
+        }
+    }
+
+    fn on_message(&self, _server: &ServerHandle, client_id: ClientId, message: String) {
+        Python::with_gil(|py| {
+            if let Err(e) = self.handler.call1(py, (client_id, message)) {
+                error!("Error calling handler: {e}");
+            }
+        });
+    }
+
+    fn on_disconnect(&self, _server: &ServerHandle, client_id: ClientId) {
+        if let Some(on_disconnect) = &self.on_disconnect {
+            Python::with_gil(|py| {
+                if let Err(e) = on_disconnect.call1(py, (client_id,)) {
+                    error!("Error calling `on_disconnect` handler: {e}");
+                }
+            });
+        }
+    }
+}
+
+#[pymethods]
+impl WebSocketServer {
+    /// Bind a WebSocket server to `addr` and start accepting connections.
+    ///
+    /// * `handler` - Called with `(client_id, message)` for each received message.
+    /// * `on_connect` - Called with `(client_id, peer_addr)` when a client connects.
+    /// * `on_disconnect` - Called with `(client_id)` when a client disconnects.
+    #[staticmethod]
+    #[pyo3(name = "bind")]
+    #[pyo3(signature = (addr, handler, on_connect = None, on_disconnect = None))]
+    fn py_bind(
+        addr: String,
+        handler: PyObject,
+        on_connect: Option<PyObject>,
+        on_disconnect: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<&PyAny> {
+        let handler = Arc::new(PyServerHandler {
+            handler,
+            on_connect,
+            on_disconnect,
+        });
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Self::bind(&addr, handler).await.map_err(to_pyruntime_err)
+        })
+    }
+
+    #[getter]
+    #[pyo3(name = "local_addr")]
+    fn py_local_addr(&self) -> String {
+        self.local_addr.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "client_count")]
+    fn py_client_count(&self) -> usize {
+        self.handle.client_count()
+    }
+
+    #[pyo3(name = "send")]
+    fn py_send(&self, client_id: ClientId, message: String) -> bool {
+        self.handle.send(client_id, message)
+    }
+
+    #[pyo3(name = "broadcast")]
+    fn py_broadcast(&self, message: &str) -> usize {
+        self.handle.broadcast(message)
+    }
+
+    #[pyo3(name = "subscribe")]
+    fn py_subscribe(&self, client_id: ClientId, topic: &str) -> PyResult<()> {
+        if topic.is_empty() {
+            return Err(to_pyvalue_err("`topic` was empty"));
+        }
+        self.handle.subscribe(client_id, topic);
+        Ok(())
+    }
+
+    #[pyo3(name = "unsubscribe")]
+    fn py_unsubscribe(&self, client_id: ClientId, topic: &str) {
+        self.handle.unsubscribe(client_id, topic);
+    }
+
+    #[pyo3(name = "subscriber_count")]
+    fn py_subscriber_count(&self, topic: &str) -> usize {
+        self.handle.subscriber_count(topic)
+    }
+
+    #[pyo3(name = "publish")]
+    fn py_publish(&self, topic: &str, message: &str) -> usize {
+        self.handle.publish(topic, message)
+    }
+
+    #[pyo3(name = "close_client")]
+    fn py_close_client(&self, client_id: ClientId) -> bool {
+        self.handle.close(client_id)
+    }
+
+    #[pyo3(name = "shutdown")]
+    fn py_shutdown(&self) {
+        self.shutdown();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::timeout;
+    use tokio_tungstenite::connect_async;
+
+    use super::*;
+
+    /// Echoes messages, and subscribes the client to topics sent as `sub:<topic>`.
+    struct EchoHandler;
+
+    impl ServerHandler for EchoHandler {
+        fn on_message(&self, server: &ServerHandle, client_id: ClientId, message: String) {
+            match message.strip_prefix("sub:") {
+                Some(topic) => {
+                    server.subscribe(client_id, topic);
+                    server.send(client_id, format!("subscribed:{topic}"));
+                }
+                None => {
+                    server.send(client_id, message);
+                }
+            }
+        }
+    }
+
+    async fn next_text<S>(stream: &mut S) -> String
+    where
+        S: StreamExt<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+    {
+        let message = timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        message.into_text().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_send_and_publish() {
+        let server = WebSocketServer::bind("127.0.0.1:0", Arc::new(EchoHandler))
+            .await
+            .unwrap();
+        let url = format!("ws://{}", server.local_addr());
+        let (mut client_a, _) = connect_async(url.as_str()).await.unwrap();
+        let (mut client_b, _) = connect_async(url.as_str()).await.unwrap();
+
+        client_a.send(Message::Text("hello".into())).await.unwrap();
+        assert_eq!(next_text(&mut client_a).await, "hello");
+
+        client_a
+            .send(Message::Text("sub:book".into()))
+            .await
+            .unwrap();
+        assert_eq!(next_text(&mut client_a).await, "subscribed:book");
+        client_b.send(Message::Text("ping".into())).await.unwrap();
+        assert_eq!(next_text(&mut client_b).await, "ping");
+
+        let handle = server.handle();
+        assert_eq!(handle.client_count(), 2);
+        assert_eq!(handle.subscriber_count("book"), 1);
+        assert_eq!(handle.publish("book", "update"), 1);
+        assert_eq!(next_text(&mut client_a).await, "update");
+        assert_eq!(handle.broadcast("all"), 2);
+        assert_eq!(next_text(&mut client_a).await, "all");
+        assert_eq!(next_text(&mut client_b).await, "all");
+    }
+
+    #[tokio::test]
+    async fn test_client_disconnect_removes_subscriptions() {
+        let server = WebSocketServer::bind("127.0.0.1:0", Arc::new(EchoHandler))
+            .await
+            .unwrap();
+        let url = format!("ws://{}", server.local_addr());
+        let (mut client, _) = connect_async(url.as_str()).await.unwrap();
+        client
+            .send(Message::Text("sub:trades".into()))
+            .await
+            .unwrap();
+        assert_eq!(next_text(&mut client).await, "subscribed:trades");
+
+        client.close(None).await.unwrap();
+        let handle = server.handle();
+        for _ in 0..50 {
+            if handle.client_count() == 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        assert_eq!(handle.client_count(), 0);
+        assert_eq!(handle.subscriber_count("trades"), 0);
+        assert_eq!(handle.publish("trades", "update"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_server_close_client() {
+        let server = WebSocketServer::bind("127.0.0.1:0", Arc::new(EchoHandler))
+            .await
+            .unwrap();
+        let url = format!("ws://{}", server.local_addr());
+        let (mut client, _) = connect_async(url.as_str()).await.unwrap();
+        client.send(Message::Text("hello".into())).await.unwrap();
+        assert_eq!(next_text(&mut client).await, "hello");
+
+        assert!(server.handle().close(1));
+
+        let message = timeout(Duration::from_secs(5), client.next())
+            .await
+            .unwrap();
+        assert!(matches!(message, Some(Ok(Message::Close(_))) | None));
+        assert!(!server.handle().send(1, "after".to_string()));
+    }
+}
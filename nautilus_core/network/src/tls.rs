@@ -0,0 +1,108 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! TLS client configuration, including client certificate (mutual TLS) authentication.
+
+use std::{fs::File, io::BufReader, path::Path, sync::Arc};
+
+use anyhow::{anyhow, bail, Result};
+use rustls::{
+    pki_types::{CertificateDer, PrivateKeyDer},
+    ClientConfig, RootCertStore,
+};
+use tracing::debug;
+
+/// Creates a TLS client configuration.
+///
+/// Server certificates are verified against the CA certificates in the PEM file at
+/// `ca_cert_path` if given (e.g. for a broker private CA), otherwise against the
+/// native root certificates. When `client_auth` is given as the paths of the client
+/// certificate chain and private key PEM files, the client authenticates with them.
+///
+/// # Errors
+///
+/// This function returns an error if a file cannot be read or contains no valid PEM items.
+pub fn create_tls_config(
+    ca_cert_path: Option<&Path>,
+    client_auth: Option<(&Path, &Path)>,
+) -> Result<Arc<ClientConfig>> {
+    let mut roots = RootCertStore::empty();
+    match ca_cert_path {
+        Some(path) => {
+            for cert in load_certs(path)? {
+                roots.add(cert)?;
+            }
+        }
+        None => {
+            let native_certs = rustls_native_certs::load_native_certs()?;
+            let (added, ignored) = roots.add_parsable_certificates(native_certs);
+            debug!("Added {added} native root certificates (ignored {ignored})");
+        }
+    }
+
+    let builder = ClientConfig::builder().with_root_certificates(roots);
+    let config = match client_auth {
+        Some((cert_path, key_path)) => {
+            builder.with_client_auth_cert(load_certs(cert_path)?, load_private_key(key_path)?)?
+        }
+        None => builder.with_no_client_auth(),
+    };
+    Ok(Arc::new(config))
+}
+
+fn load_certs(path: &Path) -> Result<Vec<CertificateDer<'static>>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let certs = rustls_pemfile::certs(&mut reader).collect::<Result<Vec<_>, _>>()?;
+    if certs.is_empty() {
+        bail!("No certificates found in {}", path.display());
+    }
+    Ok(certs)
+}
+
+fn load_private_key(path: &Path) -> Result<PrivateKeyDer<'static>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    rustls_pemfile::private_key(&mut reader)?
+        .ok_or_else(|| anyhow!("No private key found in {}", path.display()))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_missing_ca_cert_file_fails() {
+        let path = env::temp_dir().join("nautilus_missing_ca.pem");
+
+        assert!(create_tls_config(Some(&path), None).is_err());
+    }
+
+    #[rstest]
+    fn test_file_without_certificates_fails() {
+        let path = env::temp_dir().join("nautilus_empty_ca.pem");
+        fs::write(&path, "not a certificate").unwrap();
+
+        let result = create_tls_config(Some(&path), None);
+        fs::remove_file(&path).unwrap();
+
+        assert!(result.unwrap_err().to_string().contains("No certificates"));
+    }
+}
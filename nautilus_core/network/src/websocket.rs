@@ -20,6 +20,7 @@ use futures_util::{
     SinkExt, StreamExt,
 };
 use hyper::header::HeaderName;
+use nautilus_common::metrics::inc_ws_reconnects;
 use nautilus_core::python::{to_pyruntime_err, to_pyvalue_err};
 use pyo3::{prelude::*, types::PyBytes};
 use tokio::{net::TcpStream, sync::Mutex, task, time::sleep};
@@ -28,7 +29,15 @@ use tokio_tungstenite::{
     tungstenite::{client::IntoClientRequest, http::HeaderValue, Error, Message},
     MaybeTlsStream, WebSocketStream,
 };
-use tracing::{debug, error};
+use tracing::{debug, error, warn};
+
+use crate::{
+    backoff::{
+        ExponentialBackoff, DEFAULT_BACKOFF_FACTOR, DEFAULT_DELAY_INITIAL_MS, DEFAULT_DELAY_MAX_MS,
+        DEFAULT_JITTER_MS,
+    },
+    compression::PayloadCompression,
+};
 
 type MessageWriter = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
 type SharedMessageWriter =
@@ -47,11 +56,47 @@ pub struct WebSocketConfig {
     heartbeat: Option<u64>,
     heartbeat_msg: Option<String>,
     ping_handler: Option<PyObject>,
+    state_handler: Option<PyObject>,
+    reconnect_delay_initial_ms: Option<u64>,
+    reconnect_delay_max_ms: Option<u64>,
+    reconnect_backoff_factor: Option<f64>,
+    reconnect_jitter_ms: Option<u64>,
+    reconnect_max_attempts: Option<u32>,
+    compression: PayloadCompression,
+}
+
+impl WebSocketConfig {
+    /// Returns the reconnection backoff policy for the config.
+    fn reconnect_backoff(&self) -> ExponentialBackoff {
+        ExponentialBackoff::new(
+            Duration::from_millis(
+                self.reconnect_delay_initial_ms
+                    .unwrap_or(DEFAULT_DELAY_INITIAL_MS),
+            ),
+            Duration::from_millis(self.reconnect_delay_max_ms.unwrap_or(DEFAULT_DELAY_MAX_MS)),
+            self.reconnect_backoff_factor
+                .unwrap_or(DEFAULT_BACKOFF_FACTOR),
+            self.reconnect_jitter_ms.unwrap_or(DEFAULT_JITTER_MS),
+            self.reconnect_max_attempts,
+        )
+    }
+
+    /// Calls the state handler (if any) with the given connection `state`.
+    fn notify_state(&self, state: &str) {
+        if let Some(ref handler) = self.state_handler {
+            Python::with_gil(|py| {
+                if let Err(e) = handler.call1(py, (state,)) {
+                    error!("Error calling `state_handler` with {state}: {e}");
+                }
+            });
+        }
+    }
 }
 
 #[pymethods]
 impl WebSocketConfig {
     #[new]
+    #[allow(clippy::too_many_arguments)]
     fn py_new(
         url: String,
         handler: PyObject,
@@ -59,15 +104,38 @@ impl WebSocketConfig {
         heartbeat: Option<u64>,
         heartbeat_msg: Option<String>,
         ping_handler: Option<PyObject>,
-    ) -> Self {
-        Self {
+        state_handler: Option<PyObject>,
+        reconnect_delay_initial_ms: Option<u64>,
+        reconnect_delay_max_ms: Option<u64>,
+        reconnect_backoff_factor: Option<f64>,
+        reconnect_jitter_ms: Option<u64>,
+        reconnect_max_attempts: Option<u32>,
+        compression: Option<String>,
+    ) -> PyResult<Self> {
+        if reconnect_backoff_factor.is_some_and(|factor| factor < 1.0) {
+            return Err(to_pyvalue_err(
+                "`reconnect_backoff_factor` must be at least 1.0",
+            ));
+        }
+        let compression = match compression {
+            Some(compression) => compression.parse().map_err(to_pyvalue_err)?,
+            None => PayloadCompression::None,
+        };
+        Ok(Self {
             url,
             handler,
             headers,
             heartbeat,
             heartbeat_msg,
             ping_handler,
-        }
+            state_handler,
+            reconnect_delay_initial_ms,
+            reconnect_delay_max_ms,
+            reconnect_backoff_factor,
+            reconnect_jitter_ms,
+            reconnect_max_attempts,
+            compression,
+        })
     }
 }
 
@@ -86,6 +154,14 @@ impl WebSocketConfig {
 /// The client also maintains a heartbeat if given a duration in seconds.
 /// It's preferable to set the duration slightly lower - heartbeat more
 /// frequently - than the required amount.
+///
+/// Binary message payloads can optionally be decompressed (gzip, deflate, zlib
+/// or zstd) before being passed to the handler.
+///
+/// If the connection drops the client reconnects with exponential backoff (and
+/// jitter), replaying any registered subscription messages once reconnected.
+/// Connection state changes (`CONNECTED`, `RECONNECTING`, `RECONNECTED`,
+/// `DISCONNECTED` and `FAILED`) are passed to the optional state handler.
 struct WebSocketClientInner {
     config: WebSocketConfig,
     read_task: task::JoinHandle<()>,
@@ -103,12 +179,15 @@ impl WebSocketClientInner {
             headers,
             heartbeat_msg,
             ping_handler,
+            compression,
+            ..
         } = &config;
         let (writer, reader) = Self::connect_with_server(url, headers.clone()).await?;
         let writer = Arc::new(Mutex::new(writer));
 
         // Keep receiving messages from socket and pass them as arguments to handler
-        let read_task = Self::spawn_read_task(reader, handler.clone(), ping_handler.clone());
+        let read_task =
+            Self::spawn_read_task(reader, handler.clone(), ping_handler.clone(), *compression);
         let heartbeat_task =
             Self::spawn_heartbeat_task(*heartbeat, heartbeat_msg.clone(), writer.clone());
 
@@ -172,6 +251,7 @@ impl WebSocketClientInner {
         mut reader: MessageReader,
         handler: PyObject,
         ping_handler: Option<PyObject>,
+        compression: PayloadCompression,
     ) -> task::JoinHandle<()> {
         debug!("Started task `read`");
         task::spawn(async move {
@@ -179,6 +259,13 @@ impl WebSocketClientInner {
                 match reader.next().await {
                     Some(Ok(Message::Binary(data))) => {
                         debug!("Received message <binary>");
+                        let data = match compression.decompress(&data) {
+                            Ok(data) => data,
+                            Err(e) => {
+                                error!("Error decompressing message: {e}");
+                                continue;
+                            }
+                        };
                         if let Err(e) =
                             Python::with_gil(|py| handler.call1(py, (PyBytes::new(py, &data),)))
                         {
@@ -276,6 +363,7 @@ impl WebSocketClientInner {
             reader,
             self.config.handler.clone(),
             self.config.ping_handler.clone(),
+            self.config.compression,
         );
         self.heartbeat_task = Self::spawn_heartbeat_task(
             self.config.heartbeat,
@@ -321,6 +409,7 @@ pub struct WebSocketClient {
     writer: SharedMessageWriter,
     controller_task: task::JoinHandle<()>,
     disconnect_mode: Arc<Mutex<bool>>,
+    subscriptions: Arc<Mutex<Vec<String>>>,
 }
 
 impl WebSocketClient {
@@ -338,9 +427,12 @@ impl WebSocketClient {
         let inner = WebSocketClientInner::connect_url(config).await?;
         let writer = inner.writer.clone();
         let disconnect_mode = Arc::new(Mutex::new(false));
+        let subscriptions = Arc::new(Mutex::new(Vec::new()));
+        inner.config.notify_state("CONNECTED");
         let controller_task = Self::spawn_controller_task(
             inner,
             disconnect_mode.clone(),
+            subscriptions.clone(),
             post_reconnection,
             post_disconnection,
         );
@@ -356,9 +448,23 @@ impl WebSocketClient {
             writer,
             controller_task,
             disconnect_mode,
+            subscriptions,
         })
     }
 
+    /// Registers the subscription message `data` to be replayed after a reconnect.
+    pub async fn add_subscription(&self, data: String) {
+        let mut subscriptions = self.subscriptions.lock().await;
+        if !subscriptions.contains(&data) {
+            subscriptions.push(data);
+        }
+    }
+
+    /// Removes the subscription message `data` from replay after a reconnect.
+    pub async fn remove_subscription(&self, data: &str) {
+        self.subscriptions.lock().await.retain(|sub| sub != data);
+    }
+
     #[must_use]
     pub fn is_disconnected(&self) -> bool {
         self.controller_task.is_finished()
@@ -379,6 +485,12 @@ impl WebSocketClient {
         guard.send(Message::Binary(data)).await
     }
 
+    pub async fn send_text(&self, data: String) -> Result<(), Error> {
+        debug!("Sending text: {data}");
+        let mut guard = self.writer.lock().await;
+        guard.send(Message::Text(data)).await
+    }
+
     pub async fn send_close_message(&self) {
         let mut guard = self.writer.lock().await;
         match guard.send(Message::Close(None)).await {
@@ -390,10 +502,12 @@ impl WebSocketClient {
     fn spawn_controller_task(
         mut inner: WebSocketClientInner,
         disconnect_mode: Arc<Mutex<bool>>,
+        subscriptions: Arc<Mutex<Vec<String>>>,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
     ) -> task::JoinHandle<()> {
         task::spawn(async move {
+            let mut backoff = inner.config.reconnect_backoff();
             let mut disconnect_flag;
             loop {
                 sleep(Duration::from_secs(1)).await;
@@ -404,26 +518,42 @@ impl WebSocketClient {
                 drop(guard);
 
                 match (disconnect_flag, inner.is_alive()) {
-                    (false, false) => match inner.reconnect().await {
-                        Ok(()) => {
-                            debug!("Reconnected successfully");
-                            if let Some(ref handler) = post_reconnection {
-                                Python::with_gil(|py| match handler.call0(py) {
-                                    Ok(_) => debug!("Called `post_reconnection` handler"),
-                                    Err(e) => {
-                                        error!("Error calling `post_reconnection` handler: {e}");
-                                    }
-                                });
-                            }
-                        }
-                        Err(e) => {
-                            error!("Reconnect failed {e}");
+                    (false, false) => {
+                        let Some(delay) = backoff.next_delay() else {
+                            error!("Reconnect failed after {} attempts", backoff.attempts());
+                            inner.config.notify_state("FAILED");
                             break;
+                        };
+                        inner.config.notify_state("RECONNECTING");
+                        debug!(
+                            "Reconnect attempt {} in {}ms",
+                            backoff.attempts(),
+                            delay.as_millis()
+                        );
+                        sleep(delay).await;
+
+                        if let Err(e) = inner.reconnect().await {
+                            warn!("Reconnect attempt {} failed: {e}", backoff.attempts());
+                            continue;
                         }
-                    },
+                        debug!("Reconnected successfully");
+                        backoff.reset();
+                        inc_ws_reconnects(&inner.config.url);
+                        Self::replay_subscriptions(&inner.writer, &subscriptions).await;
+                        inner.config.notify_state("RECONNECTED");
+                        if let Some(ref handler) = post_reconnection {
+                            Python::with_gil(|py| match handler.call0(py) {
+                                Ok(_) => debug!("Called `post_reconnection` handler"),
+                                Err(e) => {
+                                    error!("Error calling `post_reconnection` handler: {e}");
+                                }
+                            });
+                        }
+                    }
                     (true, true) => {
                         debug!("Shutting down inner client");
                         inner.shutdown().await;
+                        inner.config.notify_state("DISCONNECTED");
                         if let Some(ref handler) = post_disconnection {
                             Python::with_gil(|py| match handler.call0(py) {
                                 Ok(_) => debug!("Called `post_reconnection` handler"),
@@ -434,12 +564,30 @@ impl WebSocketClient {
                         }
                         break;
                     }
-                    (true, false) => break,
+                    (true, false) => {
+                        inner.config.notify_state("DISCONNECTED");
+                        break;
+                    }
                     _ => (),
                 }
             }
         })
     }
+
+    /// Resends the registered subscription messages on the (reconnected) `writer`.
+    async fn replay_subscriptions(
+        writer: &SharedMessageWriter,
+        subscriptions: &Arc<Mutex<Vec<String>>>,
+    ) {
+        let subscriptions = subscriptions.lock().await.clone();
+        let mut guard = writer.lock().await;
+        for data in subscriptions {
+            match guard.send(Message::Text(data.clone())).await {
+                Ok(()) => debug!("Replayed subscription: {data}"),
+                Err(e) => error!("Error replaying subscription {data}: {e}"),
+            }
+        }
+    }
 }
 
 #[pymethods]
@@ -447,8 +595,8 @@ impl WebSocketClient {
     /// Check if the client is still alive.
     ///
     /// Even if the connection is disconnected the client will still be alive
-    /// and trying to reconnect. Only when reconnect fails the client will
-    /// terminate.
+    /// and trying to reconnect. Only when the reconnect attempts are exhausted
+    /// will the client terminate.
     ///
     /// This is particularly useful for checking why a `send` failed. It could
     /// because the connection disconnected and the client is still alive
@@ -521,6 +669,37 @@ impl WebSocketClient {
         })
     }
 
+    /// Register a subscription message to be replayed after any reconnect.
+    #[pyo3(name = "add_subscription")]
+    fn py_add_subscription<'py>(
+        slf: PyRef<'_, Self>,
+        data: String,
+        py: Python<'py>,
+    ) -> PyResult<&'py PyAny> {
+        let subscriptions = slf.subscriptions.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let mut subscriptions = subscriptions.lock().await;
+            if !subscriptions.contains(&data) {
+                subscriptions.push(data);
+            }
+            Ok(())
+        })
+    }
+
+    /// Remove a subscription message from replay after any reconnect.
+    #[pyo3(name = "remove_subscription")]
+    fn py_remove_subscription<'py>(
+        slf: PyRef<'_, Self>,
+        data: String,
+        py: Python<'py>,
+    ) -> PyResult<&'py PyAny> {
+        let subscriptions = slf.subscriptions.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            subscriptions.lock().await.retain(|sub| *sub != data);
+            Ok(())
+        })
+    }
+
     /// Send text data to the server.
     ///
     /// # Safety
@@ -711,7 +890,15 @@ counter = Counter()",
             None,
             None,
             None,
-        );
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
         let client = WebSocketClient::connect(config, None, None, None)
             .await
             .unwrap();
@@ -816,7 +1003,15 @@ checker = Checker()",
             Some(1),
             Some("heartbeat message".to_string()),
             None,
-        );
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
         let client = WebSocketClient::connect(config, None, None, None)
             .await
             .unwrap();
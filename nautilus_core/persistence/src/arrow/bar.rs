@@ -16,30 +16,33 @@
 use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use datafusion::arrow::{
-    array::{Int64Array, UInt64Array},
+    array::UInt64Array,
     datatypes::{DataType, Field, Schema},
     error::ArrowError,
     record_batch::RecordBatch,
 };
 use nautilus_model::{
     data::bar::{Bar, BarType},
-    types::{price::Price, quantity::Quantity},
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 use super::{
-    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_BAR_TYPE, KEY_PRICE_PRECISION,
-    KEY_SIZE_PRECISION,
+    extract_column, extract_raw_column, DecodeDataFromRecordBatch, EncodingError, FixedRaw,
+    RawBuilder, KEY_BAR_TYPE, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
 impl ArrowSchemaProvider for Bar {
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
         let fields = vec![
-            Field::new("open", DataType::Int64, false),
-            Field::new("high", DataType::Int64, false),
-            Field::new("low", DataType::Int64, false),
-            Field::new("close", DataType::Int64, false),
-            Field::new("volume", DataType::UInt64, false),
+            Field::new("open", PriceRaw::DATA_TYPE, false),
+            Field::new("high", PriceRaw::DATA_TYPE, false),
+            Field::new("low", PriceRaw::DATA_TYPE, false),
+            Field::new("close", PriceRaw::DATA_TYPE, false),
+            Field::new("volume", QuantityRaw::DATA_TYPE, false),
             Field::new("ts_event", DataType::UInt64, false),
             Field::new("ts_init", DataType::UInt64, false),
         ];
@@ -78,20 +81,20 @@ impl EncodeToRecordBatch for Bar {
         metadata: &HashMap<String, String>,
         data: &[Self],
     ) -> Result<RecordBatch, ArrowError> {
-        let mut open_builder = Int64Array::builder(data.len());
-        let mut high_builder = Int64Array::builder(data.len());
-        let mut low_builder = Int64Array::builder(data.len());
-        let mut close_builder = Int64Array::builder(data.len());
-        let mut volume_builder = UInt64Array::builder(data.len());
+        let mut open_builder = RawBuilder::<PriceRaw>::with_capacity(data.len());
+        let mut high_builder = RawBuilder::<PriceRaw>::with_capacity(data.len());
+        let mut low_builder = RawBuilder::<PriceRaw>::with_capacity(data.len());
+        let mut close_builder = RawBuilder::<PriceRaw>::with_capacity(data.len());
+        let mut volume_builder = RawBuilder::<QuantityRaw>::with_capacity(data.len());
         let mut ts_event_builder = UInt64Array::builder(data.len());
         let mut ts_init_builder = UInt64Array::builder(data.len());
 
         for bar in data {
-            open_builder.append_value(bar.open.raw);
-            high_builder.append_value(bar.high.raw);
-            low_builder.append_value(bar.low.raw);
-            close_builder.append_value(bar.close.raw);
-            volume_builder.append_value(bar.volume.raw);
+            open_builder.append_value(bar.open.raw)?;
+            high_builder.append_value(bar.high.raw)?;
+            low_builder.append_value(bar.low.raw)?;
+            close_builder.append_value(bar.close.raw)?;
+            volume_builder.append_value(bar.volume.raw)?;
             ts_event_builder.append_value(bar.ts_event);
             ts_init_builder.append_value(bar.ts_init);
         }
@@ -127,11 +130,11 @@ impl DecodeFromRecordBatch for Bar {
         let (bar_type, price_precision, size_precision) = parse_metadata(metadata)?;
         let cols = record_batch.columns();
 
-        let open_values = extract_column::<Int64Array>(cols, "open", 0, DataType::Int64)?;
-        let high_values = extract_column::<Int64Array>(cols, "high", 1, DataType::Int64)?;
-        let low_values = extract_column::<Int64Array>(cols, "low", 2, DataType::Int64)?;
-        let close_values = extract_column::<Int64Array>(cols, "close", 3, DataType::Int64)?;
-        let volume_values = extract_column::<UInt64Array>(cols, "volume", 4, DataType::UInt64)?;
+        let open_values = extract_raw_column::<PriceRaw>(cols, "open", 0)?;
+        let high_values = extract_raw_column::<PriceRaw>(cols, "high", 1)?;
+        let low_values = extract_raw_column::<PriceRaw>(cols, "low", 2)?;
+        let close_values = extract_raw_column::<PriceRaw>(cols, "close", 3)?;
+        let volume_values = extract_raw_column::<QuantityRaw>(cols, "volume", 4)?;
         let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 5, DataType::UInt64)?;
         let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 6, DataType::UInt64)?;
 
@@ -179,7 +182,7 @@ impl DecodeDataFromRecordBatch for Bar {
 mod tests {
     use std::sync::Arc;
 
-    use datafusion::arrow::record_batch::RecordBatch;
+    use datafusion::arrow::{array::Int64Array, record_batch::RecordBatch};
     use rstest::rstest;
 
     use super::*;
@@ -308,4 +311,28 @@ mod tests {
         let decoded_data = Bar::decode_batch(&metadata, record_batch).unwrap();
         assert_eq!(decoded_data.len(), 2);
     }
+
+    #[rstest]
+    fn test_decode_batch_renko() {
+        let bar_type = BarType::from_str("AAPL.XNAS-50-RENKO-LAST-INTERNAL").unwrap();
+        let metadata = Bar::get_metadata(&bar_type, 2, 0);
+
+        let record_batch = RecordBatch::try_new(
+            Bar::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(Int64Array::from(vec![100_000_000_000])),
+                Arc::new(Int64Array::from(vec![100_500_000_000])),
+                Arc::new(Int64Array::from(vec![100_000_000_000])),
+                Arc::new(Int64Array::from(vec![100_500_000_000])),
+                Arc::new(UInt64Array::from(vec![3_000_000_000])),
+                Arc::new(UInt64Array::from(vec![1])),
+                Arc::new(UInt64Array::from(vec![1])),
+            ],
+        )
+        .unwrap();
+
+        let decoded_data = Bar::decode_batch(&metadata, record_batch).unwrap();
+        assert_eq!(decoded_data.len(), 1);
+        assert_eq!(decoded_data[0].bar_type, bar_type);
+    }
 }
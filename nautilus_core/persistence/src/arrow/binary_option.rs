@@ -0,0 +1,289 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use datafusion::arrow::{
+    array::{Array, StringArray, StringBuilder, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    enums::AssetClass,
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    instruments::binary_option::BinaryOption,
+    types::{
+        currency::Currency,
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
+};
+use ustr::Ustr;
+
+use super::{extract_column, extract_raw_column, EncodingError, FixedRaw, RawBuilder};
+use crate::arrow::{ArrowSchemaProvider, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for BinaryOption {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("id", DataType::Utf8, false),
+            Field::new("raw_symbol", DataType::Utf8, false),
+            Field::new("asset_class", DataType::UInt8, false),
+            Field::new("currency", DataType::Utf8, false),
+            Field::new("activation_ns", DataType::UInt64, false),
+            Field::new("expiration_ns", DataType::UInt64, false),
+            Field::new("price_precision", DataType::UInt8, false),
+            Field::new("size_precision", DataType::UInt8, false),
+            Field::new("price_increment", PriceRaw::DATA_TYPE, false),
+            Field::new("size_increment", QuantityRaw::DATA_TYPE, false),
+            Field::new("outcome", DataType::Utf8, true),
+            Field::new("description", DataType::Utf8, true),
+            Field::new("max_quantity", QuantityRaw::DATA_TYPE, true),
+            Field::new("min_quantity", QuantityRaw::DATA_TYPE, true),
+            Field::new("max_price", PriceRaw::DATA_TYPE, true),
+            Field::new("min_price", PriceRaw::DATA_TYPE, true),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for BinaryOption {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut id_builder = StringBuilder::new();
+        let mut raw_symbol_builder = StringBuilder::new();
+        let mut asset_class_builder = UInt8Array::builder(data.len());
+        let mut currency_builder = StringBuilder::new();
+        let mut activation_ns_builder = UInt64Array::builder(data.len());
+        let mut expiration_ns_builder = UInt64Array::builder(data.len());
+        let mut price_precision_builder = UInt8Array::builder(data.len());
+        let mut size_precision_builder = UInt8Array::builder(data.len());
+        let mut price_increment_builder = RawBuilder::<PriceRaw>::with_capacity(data.len());
+        let mut size_increment_builder = RawBuilder::<QuantityRaw>::with_capacity(data.len());
+        let mut outcome_builder = StringBuilder::new();
+        let mut description_builder = StringBuilder::new();
+        let mut max_quantity_builder = RawBuilder::<QuantityRaw>::with_capacity(data.len());
+        let mut min_quantity_builder = RawBuilder::<QuantityRaw>::with_capacity(data.len());
+        let mut max_price_builder = RawBuilder::<PriceRaw>::with_capacity(data.len());
+        let mut min_price_builder = RawBuilder::<PriceRaw>::with_capacity(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for instrument in data {
+            id_builder.append_value(instrument.id.to_string());
+            raw_symbol_builder.append_value(instrument.raw_symbol.value.as_str());
+            asset_class_builder.append_value(instrument.asset_class as u8);
+            currency_builder.append_value(instrument.currency.code.as_str());
+            activation_ns_builder.append_value(instrument.activation_ns);
+            expiration_ns_builder.append_value(instrument.expiration_ns);
+            price_precision_builder.append_value(instrument.price_precision);
+            size_precision_builder.append_value(instrument.size_precision);
+            price_increment_builder.append_value(instrument.price_increment.raw)?;
+            size_increment_builder.append_value(instrument.size_increment.raw)?;
+            outcome_builder.append_option(instrument.outcome.map(|s| s.as_str()));
+            description_builder.append_option(instrument.description.map(|s| s.as_str()));
+            max_quantity_builder.append_option(instrument.max_quantity.map(|q| q.raw))?;
+            min_quantity_builder.append_option(instrument.min_quantity.map(|q| q.raw))?;
+            max_price_builder.append_option(instrument.max_price.map(|p| p.raw))?;
+            min_price_builder.append_option(instrument.min_price.map(|p| p.raw))?;
+            ts_event_builder.append_value(instrument.ts_event);
+            ts_init_builder.append_value(instrument.ts_init);
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(id_builder.finish()),
+                Arc::new(raw_symbol_builder.finish()),
+                Arc::new(asset_class_builder.finish()),
+                Arc::new(currency_builder.finish()),
+                Arc::new(activation_ns_builder.finish()),
+                Arc::new(expiration_ns_builder.finish()),
+                Arc::new(price_precision_builder.finish()),
+                Arc::new(size_precision_builder.finish()),
+                Arc::new(price_increment_builder.finish()),
+                Arc::new(size_increment_builder.finish()),
+                Arc::new(outcome_builder.finish()),
+                Arc::new(description_builder.finish()),
+                Arc::new(max_quantity_builder.finish()),
+                Arc::new(min_quantity_builder.finish()),
+                Arc::new(max_price_builder.finish()),
+                Arc::new(min_price_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for BinaryOption {
+    fn decode_batch(
+        _metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let cols = record_batch.columns();
+
+        let id_values = extract_column::<StringArray>(cols, "id", 0, DataType::Utf8)?;
+        let raw_symbol_values =
+            extract_column::<StringArray>(cols, "raw_symbol", 1, DataType::Utf8)?;
+        let asset_class_values =
+            extract_column::<UInt8Array>(cols, "asset_class", 2, DataType::UInt8)?;
+        let currency_values = extract_column::<StringArray>(cols, "currency", 3, DataType::Utf8)?;
+        let activation_ns_values =
+            extract_column::<UInt64Array>(cols, "activation_ns", 4, DataType::UInt64)?;
+        let expiration_ns_values =
+            extract_column::<UInt64Array>(cols, "expiration_ns", 5, DataType::UInt64)?;
+        let price_precision_values =
+            extract_column::<UInt8Array>(cols, "price_precision", 6, DataType::UInt8)?;
+        let size_precision_values =
+            extract_column::<UInt8Array>(cols, "size_precision", 7, DataType::UInt8)?;
+        let price_increment_values = extract_raw_column::<PriceRaw>(cols, "price_increment", 8)?;
+        let size_increment_values = extract_raw_column::<QuantityRaw>(cols, "size_increment", 9)?;
+        let outcome_values = extract_column::<StringArray>(cols, "outcome", 10, DataType::Utf8)?;
+        let description_values =
+            extract_column::<StringArray>(cols, "description", 11, DataType::Utf8)?;
+        let max_quantity_values = extract_raw_column::<QuantityRaw>(cols, "max_quantity", 12)?;
+        let min_quantity_values = extract_raw_column::<QuantityRaw>(cols, "min_quantity", 13)?;
+        let max_price_values = extract_raw_column::<PriceRaw>(cols, "max_price", 14)?;
+        let min_price_values = extract_raw_column::<PriceRaw>(cols, "min_price", 15)?;
+        let ts_event_values =
+            extract_column::<UInt64Array>(cols, "ts_event", 16, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 17, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let id = InstrumentId::from_str(id_values.value(i))
+                    .map_err(|e| EncodingError::ParseError("id", e.to_string()))?;
+                let raw_symbol = Symbol::new(raw_symbol_values.value(i))
+                    .map_err(|e| EncodingError::ParseError("raw_symbol", e.to_string()))?;
+                let asset_class_value = asset_class_values.value(i);
+                let asset_class =
+                    AssetClass::from_repr(asset_class_value as usize).ok_or_else(|| {
+                        EncodingError::ParseError(
+                            stringify!(AssetClass),
+                            format!("Invalid enum value, was {asset_class_value}"),
+                        )
+                    })?;
+                let currency = Currency::from_str(currency_values.value(i))
+                    .map_err(|e| EncodingError::ParseError("currency", e.to_string()))?;
+                let price_precision = price_precision_values.value(i);
+                let size_precision = size_precision_values.value(i);
+                let price_increment =
+                    Price::from_raw(price_increment_values.value(i), price_precision)
+                        .map_err(|e| EncodingError::ParseError("price_increment", e.to_string()))?;
+                let size_increment =
+                    Quantity::from_raw(size_increment_values.value(i), size_precision)
+                        .map_err(|e| EncodingError::ParseError("size_increment", e.to_string()))?;
+                let outcome =
+                    (!outcome_values.is_null(i)).then(|| Ustr::from(outcome_values.value(i)));
+                let description = (!description_values.is_null(i))
+                    .then(|| Ustr::from(description_values.value(i)));
+                let max_quantity = (!max_quantity_values.is_null(i))
+                    .then(|| Quantity::from_raw(max_quantity_values.value(i), size_precision))
+                    .transpose()
+                    .map_err(|e| EncodingError::ParseError("max_quantity", e.to_string()))?;
+                let min_quantity = (!min_quantity_values.is_null(i))
+                    .then(|| Quantity::from_raw(min_quantity_values.value(i), size_precision))
+                    .transpose()
+                    .map_err(|e| EncodingError::ParseError("min_quantity", e.to_string()))?;
+                let max_price = (!max_price_values.is_null(i))
+                    .then(|| Price::from_raw(max_price_values.value(i), price_precision))
+                    .transpose()
+                    .map_err(|e| EncodingError::ParseError("max_price", e.to_string()))?;
+                let min_price = (!min_price_values.is_null(i))
+                    .then(|| Price::from_raw(min_price_values.value(i), price_precision))
+                    .transpose()
+                    .map_err(|e| EncodingError::ParseError("min_price", e.to_string()))?;
+
+                Self::new(
+                    id,
+                    raw_symbol,
+                    asset_class,
+                    currency,
+                    activation_ns_values.value(i),
+                    expiration_ns_values.value(i),
+                    price_precision,
+                    size_precision,
+                    price_increment,
+                    size_increment,
+                    outcome,
+                    description,
+                    max_quantity,
+                    min_quantity,
+                    max_price,
+                    min_price,
+                    ts_event_values.value(i),
+                    ts_init_values.value(i),
+                )
+                .map_err(|e| EncodingError::ParseError(stringify!(BinaryOption), e.to_string()))
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::instruments::stubs::binary_option;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_encode_decode_round_trip(binary_option: BinaryOption) {
+        let mut instrument2 = binary_option;
+        instrument2.id = InstrumentId::from("BTC-100K-2024-NO.POLYMARKET");
+        instrument2.raw_symbol = Symbol::from("BTC-100K-2024-NO");
+        instrument2.outcome = None;
+        instrument2.max_price = Some(Price::from("0.999"));
+        instrument2.min_quantity = Some(Quantity::from("5.00"));
+
+        let metadata = HashMap::new();
+        let data = vec![binary_option, instrument2];
+        let record_batch = BinaryOption::encode_batch(&metadata, &data).unwrap();
+
+        let columns = record_batch.columns();
+        let outcome_values = columns[10].as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(columns.len(), 18);
+        assert_eq!(outcome_values.value(0), "Yes");
+        assert!(outcome_values.is_null(1));
+
+        let decoded = BinaryOption::decode_batch(&metadata, record_batch).unwrap();
+        assert_eq!(decoded.len(), 2);
+        for (decoded, expected) in decoded.iter().zip(&data) {
+            assert_eq!(decoded.id, expected.id);
+            assert_eq!(decoded.raw_symbol, expected.raw_symbol);
+            assert_eq!(decoded.currency, expected.currency);
+            assert_eq!(decoded.price_increment, expected.price_increment);
+            assert_eq!(decoded.size_increment, expected.size_increment);
+            assert_eq!(decoded.outcome, expected.outcome);
+            assert_eq!(decoded.description, expected.description);
+            assert_eq!(decoded.max_price, expected.max_price);
+            assert_eq!(decoded.min_quantity, expected.min_quantity);
+            assert_eq!(decoded.expiration_ns, expected.expiration_ns);
+        }
+    }
+}
@@ -0,0 +1,315 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, sync::Arc};
+
+use datafusion::arrow::{
+    array::{
+        ArrayRef, BooleanArray, BooleanBuilder, Float64Array, Float64Builder, Int64Array,
+        Int64Builder, StringArray, StringBuilder, UInt64Array, UInt64Builder,
+    },
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use indexmap::IndexMap;
+use nautilus_model::data::custom::{
+    get_custom_data_schema, CustomData, CustomDataSchema, CustomFieldType, CustomValue,
+};
+
+use super::{extract_column, EncodingError, KEY_DATA_TYPE};
+use crate::arrow::{ArrowSchemaProvider, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+fn field_type_to_arrow(field_type: CustomFieldType) -> DataType {
+    match field_type {
+        CustomFieldType::Boolean => DataType::Boolean,
+        CustomFieldType::Int64 => DataType::Int64,
+        CustomFieldType::UInt64 => DataType::UInt64,
+        CustomFieldType::Float64 => DataType::Float64,
+        CustomFieldType::Utf8 => DataType::Utf8,
+    }
+}
+
+impl ArrowSchemaProvider for CustomData {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let mut fields = Vec::new();
+
+        // The value fields are only known once the data type is given in the metadata
+        if let Some(schema) = metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(KEY_DATA_TYPE))
+            .and_then(|data_type| get_custom_data_schema(data_type))
+        {
+            for (name, field_type) in schema {
+                fields.push(Field::new(name, field_type_to_arrow(field_type), false));
+            }
+        }
+
+        fields.push(Field::new("ts_event", DataType::UInt64, false));
+        fields.push(Field::new("ts_init", DataType::UInt64, false));
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(metadata: &HashMap<String, String>) -> Result<CustomDataSchema, EncodingError> {
+    let data_type = metadata
+        .get(KEY_DATA_TYPE)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_DATA_TYPE))?;
+
+    get_custom_data_schema(data_type).ok_or_else(|| {
+        EncodingError::ParseError(
+            KEY_DATA_TYPE,
+            format!("no schema registered for custom data type `{data_type}`"),
+        )
+    })
+}
+
+impl EncodeToRecordBatch for CustomData {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let schema =
+            parse_metadata(metadata).map_err(|e| ArrowError::SchemaError(e.to_string()))?;
+        let mut columns: Vec<ArrayRef> = Vec::with_capacity(schema.len() + 2);
+
+        for (name, field_type) in &schema {
+            let values = data.iter().map(|custom| {
+                custom.get(name).ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!("Missing value for field `{name}`"))
+                })
+            });
+            let type_error = |value: &CustomValue| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Invalid value type for field `{name}`: expected {field_type}, was {}",
+                    value.field_type()
+                ))
+            };
+
+            let column: ArrayRef = match field_type {
+                CustomFieldType::Boolean => {
+                    let mut builder = BooleanBuilder::with_capacity(data.len());
+                    for value in values {
+                        match value? {
+                            CustomValue::Boolean(v) => builder.append_value(*v),
+                            other => return Err(type_error(other)),
+                        }
+                    }
+                    Arc::new(builder.finish())
+                }
+                CustomFieldType::Int64 => {
+                    let mut builder = Int64Builder::with_capacity(data.len());
+                    for value in values {
+                        match value? {
+                            CustomValue::Int64(v) => builder.append_value(*v),
+                            other => return Err(type_error(other)),
+                        }
+                    }
+                    Arc::new(builder.finish())
+                }
+                CustomFieldType::UInt64 => {
+                    let mut builder = UInt64Builder::with_capacity(data.len());
+                    for value in values {
+                        match value? {
+                            CustomValue::UInt64(v) => builder.append_value(*v),
+                            other => return Err(type_error(other)),
+                        }
+                    }
+                    Arc::new(builder.finish())
+                }
+                CustomFieldType::Float64 => {
+                    let mut builder = Float64Builder::with_capacity(data.len());
+                    for value in values {
+                        match value? {
+                            CustomValue::Float64(v) => builder.append_value(*v),
+                            other => return Err(type_error(other)),
+                        }
+                    }
+                    Arc::new(builder.finish())
+                }
+                CustomFieldType::Utf8 => {
+                    let mut builder = StringBuilder::new();
+                    for value in values {
+                        match value? {
+                            CustomValue::Utf8(v) => builder.append_value(v),
+                            other => return Err(type_error(other)),
+                        }
+                    }
+                    Arc::new(builder.finish())
+                }
+            };
+            columns.push(column);
+        }
+
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for custom in data {
+            ts_event_builder.append_value(custom.ts_event);
+            ts_init_builder.append_value(custom.ts_init);
+        }
+
+        columns.push(Arc::new(ts_event_builder.finish()));
+        columns.push(Arc::new(ts_init_builder.finish()));
+
+        RecordBatch::try_new(Self::get_schema(Some(metadata.clone())).into(), columns)
+    }
+}
+
+impl DecodeFromRecordBatch for CustomData {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let schema = parse_metadata(metadata)?;
+        // SAFETY: Key presence already checked when parsing the metadata
+        let data_type = metadata.get(KEY_DATA_TYPE).unwrap();
+        let cols = record_batch.columns();
+        let num_rows = record_batch.num_rows();
+
+        let mut rows: Vec<IndexMap<String, CustomValue>> =
+            vec![IndexMap::with_capacity(schema.len()); num_rows];
+
+        for (index, (name, field_type)) in schema.iter().enumerate() {
+            // Error keys are static, so custom field columns share a single label
+            let key = "custom_field";
+            let arrow_type = field_type_to_arrow(*field_type);
+            match field_type {
+                CustomFieldType::Boolean => {
+                    let values = extract_column::<BooleanArray>(cols, key, index, arrow_type)?;
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row.insert(name.clone(), CustomValue::Boolean(values.value(i)));
+                    }
+                }
+                CustomFieldType::Int64 => {
+                    let values = extract_column::<Int64Array>(cols, key, index, arrow_type)?;
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row.insert(name.clone(), CustomValue::Int64(values.value(i)));
+                    }
+                }
+                CustomFieldType::UInt64 => {
+                    let values = extract_column::<UInt64Array>(cols, key, index, arrow_type)?;
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row.insert(name.clone(), CustomValue::UInt64(values.value(i)));
+                    }
+                }
+                CustomFieldType::Float64 => {
+                    let values = extract_column::<Float64Array>(cols, key, index, arrow_type)?;
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row.insert(name.clone(), CustomValue::Float64(values.value(i)));
+                    }
+                }
+                CustomFieldType::Utf8 => {
+                    let values = extract_column::<StringArray>(cols, key, index, arrow_type)?;
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        row.insert(name.clone(), CustomValue::Utf8(values.value(i).to_string()));
+                    }
+                }
+            }
+        }
+
+        let ts_index = schema.len();
+        let ts_event_values =
+            extract_column::<UInt64Array>(cols, "ts_event", ts_index, DataType::UInt64)?;
+        let ts_init_values =
+            extract_column::<UInt64Array>(cols, "ts_init", ts_index + 1, DataType::UInt64)?;
+
+        rows.into_iter()
+            .enumerate()
+            .map(|(i, values)| {
+                CustomData::new(
+                    data_type,
+                    values,
+                    ts_event_values.value(i),
+                    ts_init_values.value(i),
+                )
+                .map_err(|e| EncodingError::ParseError(KEY_DATA_TYPE, e.to_string()))
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::array::Array;
+    use nautilus_model::data::custom::stubs::*;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema(stub_custom_data: CustomData) {
+        let metadata = CustomData::get_metadata(stub_custom_data.data_type.as_str());
+        let schema = CustomData::get_schema(Some(metadata));
+        let names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+
+        assert_eq!(
+            names,
+            vec![
+                "token",
+                "inflow",
+                "transfers",
+                "is_exchange",
+                "ts_event",
+                "ts_init"
+            ]
+        );
+        assert_eq!(schema.field(1).data_type(), &DataType::Float64);
+    }
+
+    #[rstest]
+    fn test_encode_batch(stub_custom_data: CustomData) {
+        let metadata = CustomData::get_metadata(stub_custom_data.data_type.as_str());
+        let data = vec![stub_custom_data.clone(), stub_custom_data];
+
+        let record_batch = CustomData::encode_batch(&metadata, &data).unwrap();
+
+        let columns = record_batch.columns();
+        let token_values = columns[0].as_any().downcast_ref::<StringArray>().unwrap();
+        let inflow_values = columns[1].as_any().downcast_ref::<Float64Array>().unwrap();
+        let ts_init_values = columns[5].as_any().downcast_ref::<UInt64Array>().unwrap();
+
+        assert_eq!(columns.len(), 6);
+        assert_eq!(token_values.len(), 2);
+        assert_eq!(token_values.value(0), "ETH");
+        assert_eq!(inflow_values.value(1), 1250.5);
+        assert_eq!(ts_init_values.value(1), 2);
+    }
+
+    #[rstest]
+    fn test_encode_batch_when_unregistered_type() {
+        let metadata = CustomData::get_metadata("UnregisteredType");
+        let result = CustomData::encode_batch(&metadata, &[]);
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_decode_batch(stub_custom_data: CustomData) {
+        let metadata = CustomData::get_metadata(stub_custom_data.data_type.as_str());
+        let data = vec![stub_custom_data];
+
+        let record_batch = CustomData::encode_batch(&metadata, &data).unwrap();
+        let decoded = CustomData::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, data);
+    }
+}
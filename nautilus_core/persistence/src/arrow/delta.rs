@@ -16,7 +16,7 @@
 use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use datafusion::arrow::{
-    array::{Int64Array, UInt64Array, UInt8Array},
+    array::{UInt64Array, UInt8Array},
     datatypes::{DataType, Field, Schema},
     error::ArrowError,
     record_batch::RecordBatch,
@@ -25,12 +25,15 @@ use nautilus_model::{
     data::{delta::OrderBookDelta, order::BookOrder},
     enums::{BookAction, FromU8, OrderSide},
     identifiers::instrument_id::InstrumentId,
-    types::{price::Price, quantity::Quantity},
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 use super::{
-    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
-    KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+    extract_column, extract_raw_column, DecodeDataFromRecordBatch, EncodingError, FixedRaw,
+    RawBuilder, KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
@@ -39,8 +42,8 @@ impl ArrowSchemaProvider for OrderBookDelta {
         let fields = vec![
             Field::new("action", DataType::UInt8, false),
             Field::new("side", DataType::UInt8, false),
-            Field::new("price", DataType::Int64, false),
-            Field::new("size", DataType::UInt64, false),
+            Field::new("price", PriceRaw::DATA_TYPE, false),
+            Field::new("size", QuantityRaw::DATA_TYPE, false),
             Field::new("order_id", DataType::UInt64, false),
             Field::new("flags", DataType::UInt8, false),
             Field::new("sequence", DataType::UInt64, false),
@@ -86,8 +89,8 @@ impl EncodeToRecordBatch for OrderBookDelta {
     ) -> Result<RecordBatch, ArrowError> {
         let mut action_builder = UInt8Array::builder(data.len());
         let mut side_builder = UInt8Array::builder(data.len());
-        let mut price_builder = Int64Array::builder(data.len());
-        let mut size_builder = UInt64Array::builder(data.len());
+        let mut price_builder = RawBuilder::<PriceRaw>::with_capacity(data.len());
+        let mut size_builder = RawBuilder::<QuantityRaw>::with_capacity(data.len());
         let mut order_id_builder = UInt64Array::builder(data.len());
         let mut flags_builder = UInt8Array::builder(data.len());
         let mut sequence_builder = UInt64Array::builder(data.len());
@@ -97,8 +100,8 @@ impl EncodeToRecordBatch for OrderBookDelta {
         for delta in data {
             action_builder.append_value(delta.action as u8);
             side_builder.append_value(delta.order.side as u8);
-            price_builder.append_value(delta.order.price.raw);
-            size_builder.append_value(delta.order.size.raw);
+            price_builder.append_value(delta.order.price.raw)?;
+            size_builder.append_value(delta.order.size.raw)?;
             order_id_builder.append_value(delta.order.order_id);
             flags_builder.append_value(delta.flags);
             sequence_builder.append_value(delta.sequence);
@@ -143,8 +146,8 @@ impl DecodeFromRecordBatch for OrderBookDelta {
 
         let action_values = extract_column::<UInt8Array>(cols, "action", 0, DataType::UInt8)?;
         let side_values = extract_column::<UInt8Array>(cols, "side", 1, DataType::UInt8)?;
-        let price_values = extract_column::<Int64Array>(cols, "price", 2, DataType::Int64)?;
-        let size_values = extract_column::<UInt64Array>(cols, "size", 3, DataType::UInt64)?;
+        let price_values = extract_raw_column::<PriceRaw>(cols, "price", 2)?;
+        let size_values = extract_raw_column::<QuantityRaw>(cols, "size", 3)?;
         let order_id_values = extract_column::<UInt64Array>(cols, "order_id", 4, DataType::UInt64)?;
         let flags_values = extract_column::<UInt8Array>(cols, "flags", 5, DataType::UInt8)?;
         let sequence_values = extract_column::<UInt64Array>(cols, "sequence", 6, DataType::UInt64)?;
@@ -213,7 +216,7 @@ impl DecodeDataFromRecordBatch for OrderBookDelta {
 mod tests {
     use std::sync::Arc;
 
-    use datafusion::arrow::record_batch::RecordBatch;
+    use datafusion::arrow::{array::Int64Array, record_batch::RecordBatch};
     use rstest::rstest;
 
     use super::*;
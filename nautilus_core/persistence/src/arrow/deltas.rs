@@ -0,0 +1,156 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use datafusion::arrow::{datatypes::Schema, error::ArrowError, record_batch::RecordBatch};
+use nautilus_model::{
+    data::{delta::OrderBookDelta, deltas::OrderBookDeltas},
+    enums::RecordFlag,
+};
+
+use super::{DecodeDataFromRecordBatch, EncodingError};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+// Batches are persisted as plain `OrderBookDelta` rows, with the end of each batch marked by
+// the `F_LAST` flag on its final delta. Boundaries therefore survive across record batches and
+// files, and the data can also be read back as individual deltas.
+
+impl ArrowSchemaProvider for OrderBookDeltas {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        OrderBookDelta::get_schema(metadata)
+    }
+}
+
+impl EncodeToRecordBatch for OrderBookDeltas {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let len = data.iter().map(|batch| batch.deltas.len()).sum();
+        let mut deltas: Vec<OrderBookDelta> = Vec::with_capacity(len);
+
+        for batch in data {
+            deltas.extend_from_slice(&batch.deltas);
+            if let Some(last) = deltas.last_mut() {
+                last.flags |= RecordFlag::F_LAST as u8;
+            }
+        }
+
+        OrderBookDelta::encode_batch(metadata, &deltas)
+    }
+}
+
+impl DecodeFromRecordBatch for OrderBookDeltas {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let deltas = OrderBookDelta::decode_batch(metadata, record_batch)?;
+
+        // Any trailing deltas without `F_LAST` are returned as a final (partial) batch
+        Ok(deltas
+            .split_inclusive(|delta| RecordFlag::F_LAST.matches(delta.flags))
+            .map(|chunk| Self::new(chunk[0].instrument_id, chunk.to_vec()))
+            .collect())
+    }
+}
+
+impl DecodeDataFromRecordBatch for OrderBookDeltas {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let deltas: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(deltas.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::{array::UInt8Array, compute::concat_batches};
+    use nautilus_model::data::deltas::stubs::stub_deltas;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = OrderBookDeltas::get_schema_map();
+        assert_eq!(schema_map, OrderBookDelta::get_schema_map());
+    }
+
+    #[rstest]
+    fn test_encode_batch_marks_batch_boundaries(stub_deltas: OrderBookDeltas) {
+        let deltas = stub_deltas;
+        let metadata = OrderBookDeltas::get_metadata(&deltas.instrument_id, 2, 0);
+        let batches = deltas.split_by_size(4);
+
+        let record_batch = OrderBookDeltas::encode_batch(&metadata, &batches).unwrap();
+
+        let columns = record_batch.columns();
+        let flags_values = columns[5].as_any().downcast_ref::<UInt8Array>().unwrap();
+        let last_flags: Vec<bool> = flags_values
+            .values()
+            .iter()
+            .map(|flags| RecordFlag::F_LAST.matches(*flags))
+            .collect();
+
+        assert_eq!(columns.len(), 9);
+        assert_eq!(record_batch.num_rows(), 7);
+        assert_eq!(
+            last_flags,
+            vec![false, false, false, true, false, false, true]
+        );
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip(stub_deltas: OrderBookDeltas) {
+        let mut deltas = stub_deltas;
+        deltas.deltas[6].sequence = 1;
+        let metadata = OrderBookDeltas::get_metadata(&deltas.instrument_id, 2, 0);
+        let batches = deltas.split_by_size(4);
+
+        let record_batch = OrderBookDeltas::encode_batch(&metadata, &batches).unwrap();
+        let decoded = OrderBookDeltas::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].deltas.len(), 4);
+        assert_eq!(decoded[1].deltas.len(), 3);
+        assert_eq!(decoded[0].deltas[..3], batches[0].deltas[..3]);
+        assert!(RecordFlag::F_LAST.matches(decoded[0].flags));
+        assert!(RecordFlag::F_LAST.matches(decoded[1].flags));
+        assert_eq!(decoded[1].sequence, 1);
+    }
+
+    #[rstest]
+    fn test_decode_single_batch_record_batches_are_not_merged(stub_deltas: OrderBookDeltas) {
+        let deltas = stub_deltas;
+        let metadata = OrderBookDeltas::get_metadata(&deltas.instrument_id, 2, 0);
+        let batches = deltas.split_by_size(4);
+
+        let first = OrderBookDeltas::encode_batch(&metadata, &batches[..1]).unwrap();
+        let second = OrderBookDeltas::encode_batch(&metadata, &batches[1..]).unwrap();
+        let combined = concat_batches(&first.schema(), &[first, second]).unwrap();
+        let decoded = OrderBookDeltas::decode_batch(&metadata, combined).unwrap();
+
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].deltas.len(), 4);
+        assert_eq!(decoded[1].deltas.len(), 3);
+    }
+}
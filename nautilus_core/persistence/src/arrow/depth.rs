@@ -16,70 +16,73 @@
 use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use datafusion::arrow::{
-    array::{Array, Int64Array, UInt32Array, UInt64Array, UInt8Array},
+    array::{Array, UInt32Array, UInt64Array, UInt8Array},
     datatypes::{DataType, Field, Schema},
     error::ArrowError,
     record_batch::RecordBatch,
 };
 use nautilus_model::{
     data::{
-        depth::{OrderBookDepth10, DEPTH10_LEN},
+        depth::{OrderBookDepth10, OrderBookDepth25, DEPTH10_LEN, DEPTH25_LEN},
         order::BookOrder,
     },
     enums::OrderSide,
     identifiers::instrument_id::InstrumentId,
-    types::{price::Price, quantity::Quantity},
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 use super::{
-    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
-    KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+    extract_column, extract_raw_column, DecodeDataFromRecordBatch, EncodingError, FixedRaw,
+    RawBuilder, KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
 impl ArrowSchemaProvider for OrderBookDepth10 {
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
         let fields = vec![
-            Field::new("bid_price_0", DataType::Int64, false),
-            Field::new("bid_price_1", DataType::Int64, false),
-            Field::new("bid_price_2", DataType::Int64, false),
-            Field::new("bid_price_3", DataType::Int64, false),
-            Field::new("bid_price_4", DataType::Int64, false),
-            Field::new("bid_price_5", DataType::Int64, false),
-            Field::new("bid_price_6", DataType::Int64, false),
-            Field::new("bid_price_7", DataType::Int64, false),
-            Field::new("bid_price_8", DataType::Int64, false),
-            Field::new("bid_price_9", DataType::Int64, false),
-            Field::new("ask_price_0", DataType::Int64, false),
-            Field::new("ask_price_1", DataType::Int64, false),
-            Field::new("ask_price_2", DataType::Int64, false),
-            Field::new("ask_price_3", DataType::Int64, false),
-            Field::new("ask_price_4", DataType::Int64, false),
-            Field::new("ask_price_5", DataType::Int64, false),
-            Field::new("ask_price_6", DataType::Int64, false),
-            Field::new("ask_price_7", DataType::Int64, false),
-            Field::new("ask_price_8", DataType::Int64, false),
-            Field::new("ask_price_9", DataType::Int64, false),
-            Field::new("bid_size_0", DataType::UInt64, false),
-            Field::new("bid_size_1", DataType::UInt64, false),
-            Field::new("bid_size_2", DataType::UInt64, false),
-            Field::new("bid_size_3", DataType::UInt64, false),
-            Field::new("bid_size_4", DataType::UInt64, false),
-            Field::new("bid_size_5", DataType::UInt64, false),
-            Field::new("bid_size_6", DataType::UInt64, false),
-            Field::new("bid_size_7", DataType::UInt64, false),
-            Field::new("bid_size_8", DataType::UInt64, false),
-            Field::new("bid_size_9", DataType::UInt64, false),
-            Field::new("ask_size_0", DataType::UInt64, false),
-            Field::new("ask_size_1", DataType::UInt64, false),
-            Field::new("ask_size_2", DataType::UInt64, false),
-            Field::new("ask_size_3", DataType::UInt64, false),
-            Field::new("ask_size_4", DataType::UInt64, false),
-            Field::new("ask_size_5", DataType::UInt64, false),
-            Field::new("ask_size_6", DataType::UInt64, false),
-            Field::new("ask_size_7", DataType::UInt64, false),
-            Field::new("ask_size_8", DataType::UInt64, false),
-            Field::new("ask_size_9", DataType::UInt64, false),
+            Field::new("bid_price_0", PriceRaw::DATA_TYPE, false),
+            Field::new("bid_price_1", PriceRaw::DATA_TYPE, false),
+            Field::new("bid_price_2", PriceRaw::DATA_TYPE, false),
+            Field::new("bid_price_3", PriceRaw::DATA_TYPE, false),
+            Field::new("bid_price_4", PriceRaw::DATA_TYPE, false),
+            Field::new("bid_price_5", PriceRaw::DATA_TYPE, false),
+            Field::new("bid_price_6", PriceRaw::DATA_TYPE, false),
+            Field::new("bid_price_7", PriceRaw::DATA_TYPE, false),
+            Field::new("bid_price_8", PriceRaw::DATA_TYPE, false),
+            Field::new("bid_price_9", PriceRaw::DATA_TYPE, false),
+            Field::new("ask_price_0", PriceRaw::DATA_TYPE, false),
+            Field::new("ask_price_1", PriceRaw::DATA_TYPE, false),
+            Field::new("ask_price_2", PriceRaw::DATA_TYPE, false),
+            Field::new("ask_price_3", PriceRaw::DATA_TYPE, false),
+            Field::new("ask_price_4", PriceRaw::DATA_TYPE, false),
+            Field::new("ask_price_5", PriceRaw::DATA_TYPE, false),
+            Field::new("ask_price_6", PriceRaw::DATA_TYPE, false),
+            Field::new("ask_price_7", PriceRaw::DATA_TYPE, false),
+            Field::new("ask_price_8", PriceRaw::DATA_TYPE, false),
+            Field::new("ask_price_9", PriceRaw::DATA_TYPE, false),
+            Field::new("bid_size_0", QuantityRaw::DATA_TYPE, false),
+            Field::new("bid_size_1", QuantityRaw::DATA_TYPE, false),
+            Field::new("bid_size_2", QuantityRaw::DATA_TYPE, false),
+            Field::new("bid_size_3", QuantityRaw::DATA_TYPE, false),
+            Field::new("bid_size_4", QuantityRaw::DATA_TYPE, false),
+            Field::new("bid_size_5", QuantityRaw::DATA_TYPE, false),
+            Field::new("bid_size_6", QuantityRaw::DATA_TYPE, false),
+            Field::new("bid_size_7", QuantityRaw::DATA_TYPE, false),
+            Field::new("bid_size_8", QuantityRaw::DATA_TYPE, false),
+            Field::new("bid_size_9", QuantityRaw::DATA_TYPE, false),
+            Field::new("ask_size_0", QuantityRaw::DATA_TYPE, false),
+            Field::new("ask_size_1", QuantityRaw::DATA_TYPE, false),
+            Field::new("ask_size_2", QuantityRaw::DATA_TYPE, false),
+            Field::new("ask_size_3", QuantityRaw::DATA_TYPE, false),
+            Field::new("ask_size_4", QuantityRaw::DATA_TYPE, false),
+            Field::new("ask_size_5", QuantityRaw::DATA_TYPE, false),
+            Field::new("ask_size_6", QuantityRaw::DATA_TYPE, false),
+            Field::new("ask_size_7", QuantityRaw::DATA_TYPE, false),
+            Field::new("ask_size_8", QuantityRaw::DATA_TYPE, false),
+            Field::new("ask_size_9", QuantityRaw::DATA_TYPE, false),
             Field::new("bid_count_0", DataType::UInt32, false),
             Field::new("bid_count_1", DataType::UInt32, false),
             Field::new("bid_count_2", DataType::UInt32, false),
@@ -150,10 +153,10 @@ impl EncodeToRecordBatch for OrderBookDepth10 {
         let mut ask_count_builders = Vec::with_capacity(DEPTH10_LEN);
 
         for _ in 0..DEPTH10_LEN {
-            bid_price_builders.push(Int64Array::builder(data.len()));
-            ask_price_builders.push(Int64Array::builder(data.len()));
-            bid_size_builders.push(UInt64Array::builder(data.len()));
-            ask_size_builders.push(UInt64Array::builder(data.len()));
+            bid_price_builders.push(RawBuilder::<PriceRaw>::with_capacity(data.len()));
+            ask_price_builders.push(RawBuilder::<PriceRaw>::with_capacity(data.len()));
+            bid_size_builders.push(RawBuilder::<QuantityRaw>::with_capacity(data.len()));
+            ask_size_builders.push(RawBuilder::<QuantityRaw>::with_capacity(data.len()));
             bid_count_builders.push(UInt32Array::builder(data.len()));
             ask_count_builders.push(UInt32Array::builder(data.len()));
         }
@@ -165,10 +168,10 @@ impl EncodeToRecordBatch for OrderBookDepth10 {
 
         for depth in data {
             for i in 0..DEPTH10_LEN {
-                bid_price_builders[i].append_value(depth.bids[i].price.raw);
-                ask_price_builders[i].append_value(depth.asks[i].price.raw);
-                bid_size_builders[i].append_value(depth.bids[i].size.raw);
-                ask_size_builders[i].append_value(depth.asks[i].size.raw);
+                bid_price_builders[i].append_value(depth.bids[i].price.raw)?;
+                ask_price_builders[i].append_value(depth.asks[i].price.raw)?;
+                bid_size_builders[i].append_value(depth.bids[i].size.raw)?;
+                ask_size_builders[i].append_value(depth.asks[i].size.raw)?;
                 bid_count_builders[i].append_value(depth.bid_counts[i]);
                 ask_count_builders[i].append_value(depth.ask_counts[i]);
             }
@@ -319,29 +322,25 @@ impl DecodeFromRecordBatch for OrderBookDepth10 {
         let mut ask_counts = Vec::with_capacity(DEPTH10_LEN);
 
         for i in 0..DEPTH10_LEN {
-            bid_prices.push(extract_column::<Int64Array>(
+            bid_prices.push(extract_raw_column::<PriceRaw>(
                 cols,
                 bid_price_col_names[i],
                 i,
-                DataType::Int64,
             )?);
-            ask_prices.push(extract_column::<Int64Array>(
+            ask_prices.push(extract_raw_column::<PriceRaw>(
                 cols,
                 ask_price_col_names[i],
                 DEPTH10_LEN + i,
-                DataType::Int64,
             )?);
-            bid_sizes.push(extract_column::<UInt64Array>(
+            bid_sizes.push(extract_raw_column::<QuantityRaw>(
                 cols,
                 bid_size_col_names[i],
                 2 * DEPTH10_LEN + i,
-                DataType::UInt64,
             )?);
-            ask_sizes.push(extract_column::<UInt64Array>(
+            ask_sizes.push(extract_raw_column::<QuantityRaw>(
                 cols,
                 ask_size_col_names[i],
                 3 * DEPTH10_LEN + i,
-                DataType::UInt64,
             )?);
             bid_counts.push(extract_column::<UInt32Array>(
                 cols,
@@ -419,14 +418,227 @@ impl DecodeDataFromRecordBatch for OrderBookDepth10 {
     }
 }
 
+impl ArrowSchemaProvider for OrderBookDepth25 {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let mut fields = Vec::with_capacity(6 * DEPTH25_LEN + 4);
+        for (prefix, data_type) in [
+            ("bid_price", PriceRaw::DATA_TYPE),
+            ("ask_price", PriceRaw::DATA_TYPE),
+            ("bid_size", QuantityRaw::DATA_TYPE),
+            ("ask_size", QuantityRaw::DATA_TYPE),
+            ("bid_count", DataType::UInt32),
+            ("ask_count", DataType::UInt32),
+        ] {
+            for i in 0..DEPTH25_LEN {
+                fields.push(Field::new(
+                    format!("{prefix}_{i}"),
+                    data_type.clone(),
+                    false,
+                ));
+            }
+        }
+        fields.push(Field::new("flags", DataType::UInt8, false));
+        fields.push(Field::new("sequence", DataType::UInt64, false));
+        fields.push(Field::new("ts_event", DataType::UInt64, false));
+        fields.push(Field::new("ts_init", DataType::UInt64, false));
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for OrderBookDepth25 {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut bid_price_builders = Vec::with_capacity(DEPTH25_LEN);
+        let mut ask_price_builders = Vec::with_capacity(DEPTH25_LEN);
+        let mut bid_size_builders = Vec::with_capacity(DEPTH25_LEN);
+        let mut ask_size_builders = Vec::with_capacity(DEPTH25_LEN);
+        let mut bid_count_builders = Vec::with_capacity(DEPTH25_LEN);
+        let mut ask_count_builders = Vec::with_capacity(DEPTH25_LEN);
+
+        for _ in 0..DEPTH25_LEN {
+            bid_price_builders.push(RawBuilder::<PriceRaw>::with_capacity(data.len()));
+            ask_price_builders.push(RawBuilder::<PriceRaw>::with_capacity(data.len()));
+            bid_size_builders.push(RawBuilder::<QuantityRaw>::with_capacity(data.len()));
+            ask_size_builders.push(RawBuilder::<QuantityRaw>::with_capacity(data.len()));
+            bid_count_builders.push(UInt32Array::builder(data.len()));
+            ask_count_builders.push(UInt32Array::builder(data.len()));
+        }
+
+        let mut flags_builder = UInt8Array::builder(data.len());
+        let mut sequence_builder = UInt64Array::builder(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for depth in data {
+            for i in 0..DEPTH25_LEN {
+                bid_price_builders[i].append_value(depth.bids[i].price.raw)?;
+                ask_price_builders[i].append_value(depth.asks[i].price.raw)?;
+                bid_size_builders[i].append_value(depth.bids[i].size.raw)?;
+                ask_size_builders[i].append_value(depth.asks[i].size.raw)?;
+                bid_count_builders[i].append_value(depth.bid_counts[i]);
+                ask_count_builders[i].append_value(depth.ask_counts[i]);
+            }
+
+            flags_builder.append_value(depth.flags);
+            sequence_builder.append_value(depth.sequence);
+            ts_event_builder.append_value(depth.ts_event);
+            ts_init_builder.append_value(depth.ts_init);
+        }
+
+        let mut columns: Vec<Arc<dyn Array>> = Vec::with_capacity(6 * DEPTH25_LEN + 4);
+        columns.extend(
+            bid_price_builders
+                .iter_mut()
+                .chain(ask_price_builders.iter_mut())
+                .map(|b| Arc::new(b.finish()) as Arc<dyn Array>),
+        );
+        columns.extend(
+            bid_size_builders
+                .iter_mut()
+                .chain(ask_size_builders.iter_mut())
+                .map(|b| Arc::new(b.finish()) as Arc<dyn Array>),
+        );
+        columns.extend(
+            bid_count_builders
+                .iter_mut()
+                .chain(ask_count_builders.iter_mut())
+                .map(|b| Arc::new(b.finish()) as Arc<dyn Array>),
+        );
+        columns.push(Arc::new(flags_builder.finish()));
+        columns.push(Arc::new(sequence_builder.finish()));
+        columns.push(Arc::new(ts_event_builder.finish()));
+        columns.push(Arc::new(ts_init_builder.finish()));
+
+        RecordBatch::try_new(Self::get_schema(Some(metadata.clone())).into(), columns)
+    }
+}
+
+impl DecodeFromRecordBatch for OrderBookDepth25 {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let mut bid_prices = Vec::with_capacity(DEPTH25_LEN);
+        let mut ask_prices = Vec::with_capacity(DEPTH25_LEN);
+        let mut bid_sizes = Vec::with_capacity(DEPTH25_LEN);
+        let mut ask_sizes = Vec::with_capacity(DEPTH25_LEN);
+        let mut bid_counts = Vec::with_capacity(DEPTH25_LEN);
+        let mut ask_counts = Vec::with_capacity(DEPTH25_LEN);
+
+        // Column keys are reported per group, the index identifies the level
+        for i in 0..DEPTH25_LEN {
+            bid_prices.push(extract_raw_column::<PriceRaw>(cols, "bid_price", i)?);
+            ask_prices.push(extract_raw_column::<PriceRaw>(
+                cols,
+                "ask_price",
+                DEPTH25_LEN + i,
+            )?);
+            bid_sizes.push(extract_raw_column::<QuantityRaw>(
+                cols,
+                "bid_size",
+                2 * DEPTH25_LEN + i,
+            )?);
+            ask_sizes.push(extract_raw_column::<QuantityRaw>(
+                cols,
+                "ask_size",
+                3 * DEPTH25_LEN + i,
+            )?);
+            bid_counts.push(extract_column::<UInt32Array>(
+                cols,
+                "bid_count",
+                4 * DEPTH25_LEN + i,
+                DataType::UInt32,
+            )?);
+            ask_counts.push(extract_column::<UInt32Array>(
+                cols,
+                "ask_count",
+                5 * DEPTH25_LEN + i,
+                DataType::UInt32,
+            )?);
+        }
+
+        let flags = extract_column::<UInt8Array>(cols, "flags", 6 * DEPTH25_LEN, DataType::UInt8)?;
+        let sequence =
+            extract_column::<UInt64Array>(cols, "sequence", 6 * DEPTH25_LEN + 1, DataType::UInt64)?;
+        let ts_event =
+            extract_column::<UInt64Array>(cols, "ts_event", 6 * DEPTH25_LEN + 2, DataType::UInt64)?;
+        let ts_init =
+            extract_column::<UInt64Array>(cols, "ts_init", 6 * DEPTH25_LEN + 3, DataType::UInt64)?;
+
+        // Map record batch rows to vector of OrderBookDepth25
+        let result: Result<Vec<Self>, EncodingError> = (0..record_batch.num_rows())
+            .map(|i| {
+                let mut bids = [BookOrder::default(); DEPTH25_LEN];
+                let mut asks = [BookOrder::default(); DEPTH25_LEN];
+                let mut bid_count_arr = [0u32; DEPTH25_LEN];
+                let mut ask_count_arr = [0u32; DEPTH25_LEN];
+
+                for j in 0..DEPTH25_LEN {
+                    bids[j] = BookOrder::new(
+                        OrderSide::Buy,
+                        Price::from_raw(bid_prices[j].value(i), price_precision).unwrap(),
+                        Quantity::from_raw(bid_sizes[j].value(i), size_precision).unwrap(),
+                        0, // Order ID always zero
+                    );
+
+                    asks[j] = BookOrder::new(
+                        OrderSide::Sell,
+                        Price::from_raw(ask_prices[j].value(i), price_precision).unwrap(),
+                        Quantity::from_raw(ask_sizes[j].value(i), size_precision).unwrap(),
+                        0, // Order ID always zero
+                    );
+                    bid_count_arr[j] = bid_counts[j].value(i);
+                    ask_count_arr[j] = ask_counts[j].value(i);
+                }
+
+                Ok(Self {
+                    instrument_id,
+                    bids,
+                    asks,
+                    bid_counts: bid_count_arr,
+                    ask_counts: ask_count_arr,
+                    flags: flags.value(i),
+                    sequence: sequence.value(i),
+                    ts_event: ts_event.value(i),
+                    ts_init: ts_init.value(i),
+                })
+            })
+            .collect();
+
+        result
+    }
+}
+
+impl DecodeDataFromRecordBatch for OrderBookDepth25 {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let depths: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(depths.into_iter().map(Data::from).collect())
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
 
-    use datafusion::arrow::datatypes::{DataType, Field, Schema};
-    use nautilus_model::data::depth::stubs::stub_depth10;
+    use datafusion::arrow::{
+        array::Int64Array,
+        datatypes::{DataType, Field, Schema},
+    };
+    use nautilus_model::data::depth::stubs::{stub_depth10, stub_depth25};
     use rstest::rstest;
 
     use super::*;
@@ -813,4 +1025,26 @@ mod tests {
 
         assert_eq!(decoded_data.len(), 1);
     }
+
+    #[rstest]
+    fn test_depth25_encode_decode_batch(stub_depth25: OrderBookDepth25) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = OrderBookDepth25::get_metadata(&instrument_id, 2, 0);
+
+        let data = vec![stub_depth25];
+        let record_batch = OrderBookDepth25::encode_batch(&metadata, &data).unwrap();
+
+        assert_eq!(record_batch.num_columns(), 6 * DEPTH25_LEN + 4);
+        assert_eq!(
+            record_batch.schema().field(DEPTH25_LEN).name(),
+            &"ask_price_0".to_string()
+        );
+
+        let decoded_data = OrderBookDepth25::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded_data.len(), 1);
+        assert_eq!(decoded_data[0].bids[24].price, data[0].bids[24].price);
+        assert_eq!(decoded_data[0].asks[24].size, data[0].asks[24].size);
+        assert_eq!(decoded_data[0].ts_init, data[0].ts_init);
+    }
 }
@@ -0,0 +1,231 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use datafusion::arrow::{
+    array::{UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::liquidation::LiquidationTick,
+    enums::{FromU8, OrderSide},
+    identifiers::instrument_id::InstrumentId,
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
+};
+
+use super::{
+    extract_column, extract_raw_column, EncodingError, FixedRaw, RawBuilder, KEY_INSTRUMENT_ID,
+    KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+};
+use crate::arrow::{ArrowSchemaProvider, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for LiquidationTick {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("side", DataType::UInt8, false),
+            Field::new("price", PriceRaw::DATA_TYPE, false),
+            Field::new("size", QuantityRaw::DATA_TYPE, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(
+    metadata: &HashMap<String, String>,
+) -> Result<(InstrumentId, u8, u8), EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    let price_precision = metadata
+        .get(KEY_PRICE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_PRICE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_PRICE_PRECISION, e.to_string()))?;
+
+    let size_precision = metadata
+        .get(KEY_SIZE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_SIZE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_SIZE_PRECISION, e.to_string()))?;
+
+    Ok((instrument_id, price_precision, size_precision))
+}
+
+impl EncodeToRecordBatch for LiquidationTick {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut side_builder = UInt8Array::builder(data.len());
+        let mut price_builder = RawBuilder::<PriceRaw>::with_capacity(data.len());
+        let mut size_builder = RawBuilder::<QuantityRaw>::with_capacity(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for tick in data {
+            side_builder.append_value(tick.side as u8);
+            price_builder.append_value(tick.price.raw)?;
+            size_builder.append_value(tick.size.raw)?;
+            ts_event_builder.append_value(tick.ts_event);
+            ts_init_builder.append_value(tick.ts_init);
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(side_builder.finish()),
+                Arc::new(price_builder.finish()),
+                Arc::new(size_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for LiquidationTick {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let side_values = extract_column::<UInt8Array>(cols, "side", 0, DataType::UInt8)?;
+        let price_values = extract_raw_column::<PriceRaw>(cols, "price", 1)?;
+        let size_values = extract_raw_column::<QuantityRaw>(cols, "size", 2)?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 3, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 4, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let side_value = side_values.value(i);
+                let side = OrderSide::from_u8(side_value).ok_or_else(|| {
+                    EncodingError::ParseError(
+                        stringify!(OrderSide),
+                        format!("Invalid enum value, was {side_value}"),
+                    )
+                })?;
+                let price = Price::from_raw(price_values.value(i), price_precision)
+                    .map_err(|e| EncodingError::ParseError("price", e.to_string()))?;
+                let size = Quantity::from_raw(size_values.value(i), size_precision)
+                    .map_err(|e| EncodingError::ParseError("size", e.to_string()))?;
+
+                Ok(Self::new(
+                    instrument_id,
+                    side,
+                    price,
+                    size,
+                    ts_event_values.value(i),
+                    ts_init_values.value(i),
+                ))
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::array::Array;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = LiquidationTick::get_metadata(&instrument_id, 2, 3);
+        let schema = LiquidationTick::get_schema(Some(metadata.clone()));
+        let expected_fields = vec![
+            Field::new("side", DataType::UInt8, false),
+            Field::new("price", DataType::Int64, false),
+            Field::new("size", DataType::UInt64, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+        let expected_schema = Schema::new_with_metadata(expected_fields, metadata);
+        assert_eq!(schema, expected_schema);
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = LiquidationTick::get_metadata(&instrument_id, 2, 3);
+
+        let tick1 = LiquidationTick::new(
+            instrument_id,
+            OrderSide::Sell,
+            Price::from("50100.10"),
+            Quantity::from("0.014"),
+            1,
+            3,
+        );
+        let tick2 = LiquidationTick::new(
+            instrument_id,
+            OrderSide::Buy,
+            Price::from("50200.20"),
+            Quantity::from("1.250"),
+            2,
+            4,
+        );
+
+        let data = vec![tick1, tick2];
+        let record_batch = LiquidationTick::encode_batch(&metadata, &data).unwrap();
+
+        let columns = record_batch.columns();
+        let side_values = columns[0].as_any().downcast_ref::<UInt8Array>().unwrap();
+        assert_eq!(columns.len(), 5);
+        assert_eq!(side_values.len(), 2);
+        assert_eq!(side_values.value(0), OrderSide::Sell as u8);
+        assert_eq!(side_values.value(1), OrderSide::Buy as u8);
+
+        let decoded = LiquidationTick::decode_batch(&metadata, record_batch).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[rstest]
+    fn test_decode_batch_with_missing_metadata() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = LiquidationTick::get_metadata(&instrument_id, 2, 3);
+        let record_batch = LiquidationTick::encode_batch(&metadata, &[]).unwrap();
+
+        let mut metadata = metadata;
+        metadata.remove(KEY_SIZE_PRECISION);
+
+        let result = LiquidationTick::decode_batch(&metadata, record_batch);
+        assert!(matches!(
+            result,
+            Err(EncodingError::MissingMetadata(KEY_SIZE_PRECISION))
+        ));
+    }
+}
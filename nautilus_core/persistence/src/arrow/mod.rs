@@ -14,10 +14,17 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod bar;
+pub mod binary_option;
+pub mod custom;
 pub mod delta;
+pub mod deltas;
 pub mod depth;
+pub mod liquidation;
+pub mod open_interest;
+pub mod prices;
 pub mod quote;
 pub mod trade;
+pub mod trades;
 
 use std::{
     collections::HashMap,
@@ -25,7 +32,10 @@ use std::{
 };
 
 use datafusion::arrow::{
-    array::{Array, ArrayRef},
+    array::{
+        Array, ArrayRef, FixedSizeBinaryArray, FixedSizeBinaryBuilder, Int64Array, Int64Builder,
+        UInt64Array, UInt64Builder,
+    },
     datatypes::{DataType, Schema},
     error::ArrowError,
     ipc::writer::StreamWriter,
@@ -35,8 +45,9 @@ use nautilus_model::data::Data;
 use pyo3::prelude::*;
 
 // Define metadata key constants constants
-const KEY_BAR_TYPE: &str = "bar_type";
-const KEY_INSTRUMENT_ID: &str = "instrument_id";
+pub(crate) const KEY_BAR_TYPE: &str = "bar_type";
+const KEY_DATA_TYPE: &str = "data_type";
+pub(crate) const KEY_INSTRUMENT_ID: &str = "instrument_id";
 const KEY_PRICE_PRECISION: &str = "price_precision";
 const KEY_SIZE_PRECISION: &str = "size_precision";
 
@@ -92,7 +103,7 @@ where
 
 pub trait DecodeFromRecordBatch
 where
-    Self: Sized + Into<Data> + ArrowSchemaProvider,
+    Self: Sized + ArrowSchemaProvider,
 {
     fn decode_batch(
         metadata: &HashMap<String, String>,
@@ -144,3 +155,229 @@ pub fn extract_column<'a, T: Array + 'static>(
             ))?;
     Ok(downcasted_values)
 }
+
+/// Arrow encoding for the raw values of fixed-point types.
+///
+/// 64-bit raw values map to the native integer arrays, 128-bit raw values (with the
+/// `high-precision` feature of `nautilus-model`) are stored as 16 byte little-endian
+/// fixed size binary values, as no native Arrow integer type is wide enough.
+pub trait FixedRaw: Copy {
+    const DATA_TYPE: DataType;
+    type Array: Array + 'static;
+    type Builder;
+
+    fn new_builder(capacity: usize) -> Self::Builder;
+    fn append(builder: &mut Self::Builder, value: Option<Self>) -> Result<(), ArrowError>;
+    fn finish(builder: &mut Self::Builder) -> Self::Array;
+    fn value(array: &Self::Array, index: usize) -> Self;
+}
+
+macro_rules! impl_fixed_raw_primitive {
+    ($raw:ty, $data_type:expr, $array:ty, $builder:ty) => {
+        impl FixedRaw for $raw {
+            const DATA_TYPE: DataType = $data_type;
+            type Array = $array;
+            type Builder = $builder;
+
+            fn new_builder(capacity: usize) -> Self::Builder {
+                <$builder>::with_capacity(capacity)
+            }
+
+            fn append(builder: &mut Self::Builder, value: Option<Self>) -> Result<(), ArrowError> {
+                builder.append_option(value);
+                Ok(())
+            }
+
+            fn finish(builder: &mut Self::Builder) -> Self::Array {
+                builder.finish()
+            }
+
+            fn value(array: &Self::Array, index: usize) -> Self {
+                array.value(index)
+            }
+        }
+    };
+}
+
+macro_rules! impl_fixed_raw_binary {
+    ($raw:ty) => {
+        impl FixedRaw for $raw {
+            const DATA_TYPE: DataType =
+                DataType::FixedSizeBinary(std::mem::size_of::<$raw>() as i32);
+            type Array = FixedSizeBinaryArray;
+            type Builder = FixedSizeBinaryBuilder;
+
+            fn new_builder(capacity: usize) -> Self::Builder {
+                FixedSizeBinaryBuilder::with_capacity(capacity, std::mem::size_of::<$raw>() as i32)
+            }
+
+            fn append(builder: &mut Self::Builder, value: Option<Self>) -> Result<(), ArrowError> {
+                match value {
+                    Some(value) => builder.append_value(value.to_le_bytes()),
+                    None => {
+                        builder.append_null();
+                        Ok(())
+                    }
+                }
+            }
+
+            fn finish(builder: &mut Self::Builder) -> Self::Array {
+                builder.finish()
+            }
+
+            fn value(array: &Self::Array, index: usize) -> Self {
+                // Width is checked against `DATA_TYPE` when the column is extracted
+                let mut bytes = [0; std::mem::size_of::<$raw>()];
+                bytes.copy_from_slice(array.value(index));
+                Self::from_le_bytes(bytes)
+            }
+        }
+    };
+}
+
+impl_fixed_raw_primitive!(i64, DataType::Int64, Int64Array, Int64Builder);
+impl_fixed_raw_primitive!(u64, DataType::UInt64, UInt64Array, UInt64Builder);
+impl_fixed_raw_binary!(i128);
+impl_fixed_raw_binary!(u128);
+
+/// Builds a column of fixed-point raw values, e.g. `RawBuilder::<PriceRaw>`.
+pub struct RawBuilder<T: FixedRaw>(T::Builder);
+
+impl<T: FixedRaw> RawBuilder<T> {
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(T::new_builder(capacity))
+    }
+
+    pub fn append_value(&mut self, value: T) -> Result<(), ArrowError> {
+        T::append(&mut self.0, Some(value))
+    }
+
+    pub fn append_option(&mut self, value: Option<T>) -> Result<(), ArrowError> {
+        T::append(&mut self.0, value)
+    }
+
+    pub fn finish(&mut self) -> T::Array {
+        T::finish(&mut self.0)
+    }
+}
+
+/// A column of fixed-point raw values extracted with [`extract_raw_column`].
+pub struct RawColumn<'a, T: FixedRaw>(&'a T::Array);
+
+impl<'a, T: FixedRaw> RawColumn<'a, T> {
+    #[must_use]
+    pub fn value(&self, index: usize) -> T {
+        T::value(self.0, index)
+    }
+
+    #[must_use]
+    pub fn is_null(&self, index: usize) -> bool {
+        self.0.is_null(index)
+    }
+}
+
+pub fn extract_raw_column<'a, T: FixedRaw>(
+    cols: &'a [ArrayRef],
+    column_key: &'static str,
+    column_index: usize,
+) -> Result<RawColumn<'a, T>, EncodingError> {
+    let values = extract_column::<T::Array>(cols, column_key, column_index, T::DATA_TYPE)?;
+    if values.data_type() != &T::DATA_TYPE {
+        return Err(EncodingError::InvalidColumnType(
+            column_key,
+            column_index,
+            T::DATA_TYPE,
+            values.data_type().clone(),
+        ));
+    }
+    Ok(RawColumn(values))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use datafusion::arrow::array::FixedSizeBinaryArray;
+    use nautilus_model::types::{price::PriceRaw, quantity::QuantityRaw};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_raw_column_round_trip() {
+        let mut price_builder = RawBuilder::<PriceRaw>::with_capacity(3);
+        price_builder.append_value(PriceRaw::MIN).unwrap();
+        price_builder.append_option(None).unwrap();
+        price_builder.append_value(PriceRaw::MAX).unwrap();
+        let mut size_builder = RawBuilder::<QuantityRaw>::with_capacity(3);
+        size_builder.append_value(0).unwrap();
+        size_builder.append_option(None).unwrap();
+        size_builder.append_value(QuantityRaw::MAX).unwrap();
+        let cols: Vec<ArrayRef> = vec![
+            Arc::new(price_builder.finish()),
+            Arc::new(size_builder.finish()),
+        ];
+
+        let prices = extract_raw_column::<PriceRaw>(&cols, "price", 0).unwrap();
+        let sizes = extract_raw_column::<QuantityRaw>(&cols, "size", 1).unwrap();
+
+        assert_eq!(cols[0].data_type(), &PriceRaw::DATA_TYPE);
+        assert_eq!(cols[1].data_type(), &QuantityRaw::DATA_TYPE);
+        assert_eq!(prices.value(0), PriceRaw::MIN);
+        assert!(prices.is_null(1));
+        assert_eq!(prices.value(2), PriceRaw::MAX);
+        assert_eq!(sizes.value(0), 0);
+        assert!(sizes.is_null(1));
+        assert_eq!(sizes.value(2), QuantityRaw::MAX);
+    }
+
+    #[rstest]
+    fn test_raw_column_round_trip_128_bit() {
+        let mut price_builder = RawBuilder::<i128>::with_capacity(2);
+        price_builder.append_value(i128::MIN).unwrap();
+        price_builder.append_value(-1).unwrap();
+        let mut size_builder = RawBuilder::<u128>::with_capacity(1);
+        size_builder.append_value(u128::MAX).unwrap();
+        let cols: Vec<ArrayRef> = vec![
+            Arc::new(price_builder.finish()),
+            Arc::new(size_builder.finish()),
+        ];
+
+        let prices = extract_raw_column::<i128>(&cols, "price", 0).unwrap();
+        let sizes = extract_raw_column::<u128>(&cols, "size", 1).unwrap();
+
+        assert_eq!(cols[0].data_type(), &DataType::FixedSizeBinary(16));
+        assert_eq!(prices.value(0), i128::MIN);
+        assert_eq!(prices.value(1), -1);
+        assert_eq!(sizes.value(0), u128::MAX);
+    }
+
+    #[rstest]
+    fn test_extract_raw_column_with_invalid_type() {
+        let cols: Vec<ArrayRef> = vec![Arc::new(UInt64Array::from(vec![1]))];
+
+        let result = extract_raw_column::<i64>(&cols, "price", 0);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::InvalidColumnType("price", 0, _, _))
+        ));
+    }
+
+    #[rstest]
+    fn test_extract_raw_column_with_invalid_width() {
+        let array = FixedSizeBinaryArray::try_from_iter(vec![[0u8; 8]].into_iter()).unwrap();
+        let cols: Vec<ArrayRef> = vec![Arc::new(array)];
+
+        let result = extract_raw_column::<i128>(&cols, "price", 0);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::InvalidColumnType("price", 0, _, _))
+        ));
+    }
+}
@@ -0,0 +1,188 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use datafusion::arrow::{
+    array::UInt64Array,
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::open_interest::OpenInterestUpdate,
+    identifiers::instrument_id::InstrumentId,
+    types::quantity::{Quantity, QuantityRaw},
+};
+
+use super::{
+    extract_column, extract_raw_column, DecodeDataFromRecordBatch, EncodingError, FixedRaw,
+    RawBuilder, KEY_INSTRUMENT_ID, KEY_SIZE_PRECISION,
+};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for OpenInterestUpdate {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("value", QuantityRaw::DATA_TYPE, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(metadata: &HashMap<String, String>) -> Result<(InstrumentId, u8), EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    let size_precision = metadata
+        .get(KEY_SIZE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_SIZE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_SIZE_PRECISION, e.to_string()))?;
+
+    Ok((instrument_id, size_precision))
+}
+
+impl EncodeToRecordBatch for OpenInterestUpdate {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut value_builder = RawBuilder::<QuantityRaw>::with_capacity(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for update in data {
+            value_builder.append_value(update.value.raw)?;
+            ts_event_builder.append_value(update.ts_event);
+            ts_init_builder.append_value(update.ts_init);
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(value_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for OpenInterestUpdate {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, size_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let value_values = extract_raw_column::<QuantityRaw>(cols, "value", 0)?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 1, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 2, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let value = Quantity::from_raw(value_values.value(i), size_precision)
+                    .map_err(|e| EncodingError::ParseError("value", e.to_string()))?;
+                Ok(Self::new(
+                    instrument_id,
+                    value,
+                    ts_event_values.value(i),
+                    ts_init_values.value(i),
+                ))
+            })
+            .collect()
+    }
+}
+
+impl DecodeDataFromRecordBatch for OpenInterestUpdate {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let updates: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(updates.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::array::Array;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = OpenInterestUpdate::get_schema_map();
+        let mut expected_map = HashMap::new();
+        expected_map.insert("value".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_event".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        assert_eq!(schema_map, expected_map);
+    }
+
+    #[rstest]
+    fn test_encode_batch() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = OpenInterestUpdate::get_metadata(&instrument_id, 3);
+        let data = vec![
+            OpenInterestUpdate::new(instrument_id, Quantity::from("84125.317"), 1, 3),
+            OpenInterestUpdate::new(instrument_id, Quantity::from("84130.000"), 2, 4),
+        ];
+
+        let record_batch = OpenInterestUpdate::encode_batch(&metadata, &data).unwrap();
+
+        let columns = record_batch.columns();
+        let value_values = columns[0].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let ts_init_values = columns[2].as_any().downcast_ref::<UInt64Array>().unwrap();
+
+        assert_eq!(columns.len(), 3);
+        assert_eq!(value_values.len(), 2);
+        assert_eq!(value_values.value(0), 84_125_317_000_000);
+        assert_eq!(value_values.value(1), 84_130_000_000_000);
+        assert_eq!(ts_init_values.value(1), 4);
+    }
+
+    #[rstest]
+    fn test_decode_data_batch() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = OpenInterestUpdate::get_metadata(&instrument_id, 3);
+        let data = vec![OpenInterestUpdate::new(
+            instrument_id,
+            Quantity::from("84125.317"),
+            1,
+            3,
+        )];
+
+        let record_batch = OpenInterestUpdate::encode_batch(&metadata, &data).unwrap();
+        let decoded = OpenInterestUpdate::decode_data_batch(&metadata, record_batch).unwrap();
+
+        assert!(matches!(decoded[0], Data::OpenInterest(update) if update == data[0]));
+    }
+}
@@ -0,0 +1,266 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use datafusion::arrow::{
+    array::UInt64Array,
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::prices::{IndexPriceUpdate, MarkPriceUpdate},
+    identifiers::instrument_id::InstrumentId,
+    types::price::{Price, PriceRaw},
+};
+
+use super::{
+    extract_column, extract_raw_column, DecodeDataFromRecordBatch, EncodingError, FixedRaw,
+    RawBuilder, KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION,
+};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+fn get_price_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+    let fields = vec![
+        Field::new("value", PriceRaw::DATA_TYPE, false),
+        Field::new("ts_event", DataType::UInt64, false),
+        Field::new("ts_init", DataType::UInt64, false),
+    ];
+
+    match metadata {
+        Some(metadata) => Schema::new_with_metadata(fields, metadata),
+        None => Schema::new(fields),
+    }
+}
+
+fn parse_metadata(metadata: &HashMap<String, String>) -> Result<(InstrumentId, u8), EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    let price_precision = metadata
+        .get(KEY_PRICE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_PRICE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_PRICE_PRECISION, e.to_string()))?;
+
+    Ok((instrument_id, price_precision))
+}
+
+fn encode_price_batch(
+    metadata: &HashMap<String, String>,
+    rows: &[(Price, UnixNanos, UnixNanos)],
+) -> Result<RecordBatch, ArrowError> {
+    let mut value_builder = RawBuilder::<PriceRaw>::with_capacity(rows.len());
+    let mut ts_event_builder = UInt64Array::builder(rows.len());
+    let mut ts_init_builder = UInt64Array::builder(rows.len());
+
+    for (value, ts_event, ts_init) in rows {
+        value_builder.append_value(value.raw)?;
+        ts_event_builder.append_value(*ts_event);
+        ts_init_builder.append_value(*ts_init);
+    }
+
+    RecordBatch::try_new(
+        get_price_schema(Some(metadata.clone())).into(),
+        vec![
+            Arc::new(value_builder.finish()),
+            Arc::new(ts_event_builder.finish()),
+            Arc::new(ts_init_builder.finish()),
+        ],
+    )
+}
+
+fn decode_price_batch<T>(
+    metadata: &HashMap<String, String>,
+    record_batch: &RecordBatch,
+    new: fn(InstrumentId, Price, UnixNanos, UnixNanos) -> T,
+) -> Result<Vec<T>, EncodingError> {
+    let (instrument_id, price_precision) = parse_metadata(metadata)?;
+    let cols = record_batch.columns();
+
+    let value_values = extract_raw_column::<PriceRaw>(cols, "value", 0)?;
+    let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 1, DataType::UInt64)?;
+    let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 2, DataType::UInt64)?;
+
+    (0..record_batch.num_rows())
+        .map(|i| {
+            let value = Price::from_raw(value_values.value(i), price_precision)
+                .map_err(|e| EncodingError::ParseError("value", e.to_string()))?;
+            Ok(new(
+                instrument_id,
+                value,
+                ts_event_values.value(i),
+                ts_init_values.value(i),
+            ))
+        })
+        .collect()
+}
+
+impl ArrowSchemaProvider for MarkPriceUpdate {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        get_price_schema(metadata)
+    }
+}
+
+impl EncodeToRecordBatch for MarkPriceUpdate {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let rows: Vec<_> = data
+            .iter()
+            .map(|update| (update.value, update.ts_event, update.ts_init))
+            .collect();
+        encode_price_batch(metadata, &rows)
+    }
+}
+
+impl DecodeFromRecordBatch for MarkPriceUpdate {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        decode_price_batch(metadata, &record_batch, Self::new)
+    }
+}
+
+impl DecodeDataFromRecordBatch for MarkPriceUpdate {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let updates: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(updates.into_iter().map(Data::from).collect())
+    }
+}
+
+impl ArrowSchemaProvider for IndexPriceUpdate {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        get_price_schema(metadata)
+    }
+}
+
+impl EncodeToRecordBatch for IndexPriceUpdate {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let rows: Vec<_> = data
+            .iter()
+            .map(|update| (update.value, update.ts_event, update.ts_init))
+            .collect();
+        encode_price_batch(metadata, &rows)
+    }
+}
+
+impl DecodeFromRecordBatch for IndexPriceUpdate {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        decode_price_batch(metadata, &record_batch, Self::new)
+    }
+}
+
+impl DecodeDataFromRecordBatch for IndexPriceUpdate {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let updates: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(updates.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::array::{Array, Int64Array};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = MarkPriceUpdate::get_schema_map();
+        let mut expected_map = HashMap::new();
+        expected_map.insert("value".to_string(), "Int64".to_string());
+        expected_map.insert("ts_event".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        assert_eq!(schema_map, expected_map);
+        assert_eq!(IndexPriceUpdate::get_schema_map(), expected_map);
+    }
+
+    #[rstest]
+    fn test_encode_batch() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = MarkPriceUpdate::get_metadata(&instrument_id, 2);
+        let data = vec![
+            MarkPriceUpdate::new(instrument_id, Price::from("50200.00"), 1, 3),
+            MarkPriceUpdate::new(instrument_id, Price::from("50210.50"), 2, 4),
+        ];
+
+        let record_batch = MarkPriceUpdate::encode_batch(&metadata, &data).unwrap();
+
+        let columns = record_batch.columns();
+        let value_values = columns[0].as_any().downcast_ref::<Int64Array>().unwrap();
+        let ts_init_values = columns[2].as_any().downcast_ref::<UInt64Array>().unwrap();
+
+        assert_eq!(columns.len(), 3);
+        assert_eq!(value_values.len(), 2);
+        assert_eq!(value_values.value(0), 50_200_000_000_000);
+        assert_eq!(value_values.value(1), 50_210_500_000_000);
+        assert_eq!(ts_init_values.value(1), 4);
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = IndexPriceUpdate::get_metadata(&instrument_id, 2);
+        let data = vec![
+            IndexPriceUpdate::new(instrument_id, Price::from("50100.00"), 1, 3),
+            IndexPriceUpdate::new(instrument_id, Price::from("50105.25"), 2, 4),
+        ];
+
+        let record_batch = IndexPriceUpdate::encode_batch(&metadata, &data).unwrap();
+        let decoded = IndexPriceUpdate::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, data);
+    }
+
+    #[rstest]
+    fn test_decode_data_batch() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = MarkPriceUpdate::get_metadata(&instrument_id, 2);
+        let data = vec![MarkPriceUpdate::new(
+            instrument_id,
+            Price::from("50200.00"),
+            1,
+            3,
+        )];
+
+        let record_batch = MarkPriceUpdate::encode_batch(&metadata, &data).unwrap();
+        let decoded = MarkPriceUpdate::decode_data_batch(&metadata, record_batch).unwrap();
+
+        assert!(matches!(decoded[0], Data::MarkPrice(update) if update == data[0]));
+    }
+}
@@ -16,7 +16,7 @@
 use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use datafusion::arrow::{
-    array::{Int64Array, UInt64Array},
+    array::UInt64Array,
     datatypes::{DataType, Field, Schema},
     error::ArrowError,
     record_batch::RecordBatch,
@@ -24,22 +24,25 @@ use datafusion::arrow::{
 use nautilus_model::{
     data::quote::QuoteTick,
     identifiers::instrument_id::InstrumentId,
-    types::{price::Price, quantity::Quantity},
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 use super::{
-    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
-    KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+    extract_column, extract_raw_column, DecodeDataFromRecordBatch, EncodingError, FixedRaw,
+    RawBuilder, KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
 impl ArrowSchemaProvider for QuoteTick {
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
         let fields = vec![
-            Field::new("bid_price", DataType::Int64, false),
-            Field::new("ask_price", DataType::Int64, false),
-            Field::new("bid_size", DataType::UInt64, false),
-            Field::new("ask_size", DataType::UInt64, false),
+            Field::new("bid_price", PriceRaw::DATA_TYPE, false),
+            Field::new("ask_price", PriceRaw::DATA_TYPE, false),
+            Field::new("bid_size", QuantityRaw::DATA_TYPE, false),
+            Field::new("ask_size", QuantityRaw::DATA_TYPE, false),
             Field::new("ts_event", DataType::UInt64, false),
             Field::new("ts_init", DataType::UInt64, false),
         ];
@@ -80,18 +83,18 @@ impl EncodeToRecordBatch for QuoteTick {
         metadata: &HashMap<String, String>,
         data: &[Self],
     ) -> Result<RecordBatch, ArrowError> {
-        let mut bid_price_builder = Int64Array::builder(data.len());
-        let mut ask_price_builder = Int64Array::builder(data.len());
-        let mut bid_size_builder = UInt64Array::builder(data.len());
-        let mut ask_size_builder = UInt64Array::builder(data.len());
+        let mut bid_price_builder = RawBuilder::<PriceRaw>::with_capacity(data.len());
+        let mut ask_price_builder = RawBuilder::<PriceRaw>::with_capacity(data.len());
+        let mut bid_size_builder = RawBuilder::<QuantityRaw>::with_capacity(data.len());
+        let mut ask_size_builder = RawBuilder::<QuantityRaw>::with_capacity(data.len());
         let mut ts_event_builder = UInt64Array::builder(data.len());
         let mut ts_init_builder = UInt64Array::builder(data.len());
 
         for quote in data {
-            bid_price_builder.append_value(quote.bid_price.raw);
-            ask_price_builder.append_value(quote.ask_price.raw);
-            bid_size_builder.append_value(quote.bid_size.raw);
-            ask_size_builder.append_value(quote.ask_size.raw);
+            bid_price_builder.append_value(quote.bid_price.raw)?;
+            ask_price_builder.append_value(quote.ask_price.raw)?;
+            bid_size_builder.append_value(quote.bid_size.raw)?;
+            ask_size_builder.append_value(quote.ask_size.raw)?;
             ts_event_builder.append_value(quote.ts_event);
             ts_init_builder.append_value(quote.ts_init);
         }
@@ -125,10 +128,10 @@ impl DecodeFromRecordBatch for QuoteTick {
         let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
         let cols = record_batch.columns();
 
-        let bid_price_values = extract_column::<Int64Array>(cols, "bid_price", 0, DataType::Int64)?;
-        let ask_price_values = extract_column::<Int64Array>(cols, "ask_price", 1, DataType::Int64)?;
-        let bid_size_values = extract_column::<UInt64Array>(cols, "bid_size", 2, DataType::UInt64)?;
-        let ask_size_values = extract_column::<UInt64Array>(cols, "ask_size", 3, DataType::UInt64)?;
+        let bid_price_values = extract_raw_column::<PriceRaw>(cols, "bid_price", 0)?;
+        let ask_price_values = extract_raw_column::<PriceRaw>(cols, "ask_price", 1)?;
+        let bid_size_values = extract_raw_column::<QuantityRaw>(cols, "bid_size", 2)?;
+        let ask_size_values = extract_raw_column::<QuantityRaw>(cols, "ask_size", 3)?;
         let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 4, DataType::UInt64)?;
         let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 5, DataType::UInt64)?;
 
@@ -178,7 +181,7 @@ impl DecodeDataFromRecordBatch for QuoteTick {
 mod tests {
     use std::{collections::HashMap, sync::Arc};
 
-    use datafusion::arrow::record_batch::RecordBatch;
+    use datafusion::arrow::{array::Int64Array, record_batch::RecordBatch};
     use rstest::rstest;
 
     use super::*;
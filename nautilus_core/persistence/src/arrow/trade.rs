@@ -16,7 +16,7 @@
 use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use datafusion::arrow::{
-    array::{Int64Array, StringArray, StringBuilder, UInt64Array, UInt8Array},
+    array::{StringArray, StringBuilder, UInt64Array, UInt8Array},
     datatypes::{DataType, Field, Schema},
     error::ArrowError,
     record_batch::RecordBatch,
@@ -25,20 +25,23 @@ use nautilus_model::{
     data::trade::TradeTick,
     enums::AggressorSide,
     identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
-    types::{price::Price, quantity::Quantity},
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
 };
 
 use super::{
-    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
-    KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+    extract_column, extract_raw_column, DecodeDataFromRecordBatch, EncodingError, FixedRaw,
+    RawBuilder, KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
 use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
 
 impl ArrowSchemaProvider for TradeTick {
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
         let fields = vec![
-            Field::new("price", DataType::Int64, false),
-            Field::new("size", DataType::UInt64, false),
+            Field::new("price", PriceRaw::DATA_TYPE, false),
+            Field::new("size", QuantityRaw::DATA_TYPE, false),
             Field::new("aggressor_side", DataType::UInt8, false),
             Field::new("trade_id", DataType::Utf8, false),
             Field::new("ts_event", DataType::UInt64, false),
@@ -81,16 +84,16 @@ impl EncodeToRecordBatch for TradeTick {
         metadata: &HashMap<String, String>,
         data: &[Self],
     ) -> Result<RecordBatch, ArrowError> {
-        let mut price_builder = Int64Array::builder(data.len());
-        let mut size_builder = UInt64Array::builder(data.len());
+        let mut price_builder = RawBuilder::<PriceRaw>::with_capacity(data.len());
+        let mut size_builder = RawBuilder::<QuantityRaw>::with_capacity(data.len());
         let mut aggressor_side_builder = UInt8Array::builder(data.len());
         let mut trade_id_builder = StringBuilder::new();
         let mut ts_event_builder = UInt64Array::builder(data.len());
         let mut ts_init_builder = UInt64Array::builder(data.len());
 
         for tick in data {
-            price_builder.append_value(tick.price.raw);
-            size_builder.append_value(tick.size.raw);
+            price_builder.append_value(tick.price.raw)?;
+            size_builder.append_value(tick.size.raw)?;
             aggressor_side_builder.append_value(tick.aggressor_side as u8);
             trade_id_builder.append_value(tick.trade_id.to_string());
             ts_event_builder.append_value(tick.ts_event);
@@ -126,8 +129,8 @@ impl DecodeFromRecordBatch for TradeTick {
         let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
         let cols = record_batch.columns();
 
-        let price_values = extract_column::<Int64Array>(cols, "price", 0, DataType::Int64)?;
-        let size_values = extract_column::<UInt64Array>(cols, "size", 1, DataType::UInt64)?;
+        let price_values = extract_raw_column::<PriceRaw>(cols, "price", 0)?;
+        let size_values = extract_raw_column::<QuantityRaw>(cols, "size", 1)?;
         let aggressor_side_values =
             extract_column::<UInt8Array>(cols, "aggressor_side", 2, DataType::UInt8)?;
         let trade_id_values = extract_column::<StringArray>(cols, "trade_id", 3, DataType::Utf8)?;
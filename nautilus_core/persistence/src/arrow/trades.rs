@@ -0,0 +1,268 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use datafusion::arrow::{
+    array::{StringArray, StringBuilder, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::{trade::TradeTick, trades::TradeTicks},
+    enums::AggressorSide,
+    identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    types::{
+        price::{Price, PriceRaw},
+        quantity::{Quantity, QuantityRaw},
+    },
+};
+
+use super::{
+    extract_column, extract_raw_column, DecodeDataFromRecordBatch, EncodingError, RawBuilder,
+    KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for TradeTicks {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let mut fields = TradeTick::get_schema(None).fields().to_vec();
+        fields.push(Field::new("flags", DataType::UInt8, false).into());
+        fields.push(Field::new("sequence", DataType::UInt64, false).into());
+        fields.push(Field::new("batch_id", DataType::UInt64, false).into());
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(
+    metadata: &HashMap<String, String>,
+) -> Result<(InstrumentId, u8, u8), EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    let price_precision = metadata
+        .get(KEY_PRICE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_PRICE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_PRICE_PRECISION, e.to_string()))?;
+
+    let size_precision = metadata
+        .get(KEY_SIZE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_SIZE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_SIZE_PRECISION, e.to_string()))?;
+
+    Ok((instrument_id, price_precision, size_precision))
+}
+
+impl EncodeToRecordBatch for TradeTicks {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let len = data.iter().map(|batch| batch.trades.len()).sum();
+
+        let mut price_builder = RawBuilder::<PriceRaw>::with_capacity(len);
+        let mut size_builder = RawBuilder::<QuantityRaw>::with_capacity(len);
+        let mut aggressor_side_builder = UInt8Array::builder(len);
+        let mut trade_id_builder = StringBuilder::new();
+        let mut ts_event_builder = UInt64Array::builder(len);
+        let mut ts_init_builder = UInt64Array::builder(len);
+        let mut flags_builder = UInt8Array::builder(len);
+        let mut sequence_builder = UInt64Array::builder(len);
+        let mut batch_id_builder = UInt64Array::builder(len);
+
+        for (batch_id, batch) in data.iter().enumerate() {
+            for tick in &batch.trades {
+                price_builder.append_value(tick.price.raw)?;
+                size_builder.append_value(tick.size.raw)?;
+                aggressor_side_builder.append_value(tick.aggressor_side as u8);
+                trade_id_builder.append_value(tick.trade_id.to_string());
+                ts_event_builder.append_value(tick.ts_event);
+                ts_init_builder.append_value(tick.ts_init);
+                flags_builder.append_value(batch.flags);
+                sequence_builder.append_value(batch.sequence);
+                batch_id_builder.append_value(batch_id as u64);
+            }
+        }
+
+        let price_array = price_builder.finish();
+        let size_array = size_builder.finish();
+        let aggressor_side_array = aggressor_side_builder.finish();
+        let trade_id_array = trade_id_builder.finish();
+        let ts_event_array = ts_event_builder.finish();
+        let ts_init_array = ts_init_builder.finish();
+        let flags_array = flags_builder.finish();
+        let sequence_array = sequence_builder.finish();
+        let batch_id_array = batch_id_builder.finish();
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(price_array),
+                Arc::new(size_array),
+                Arc::new(aggressor_side_array),
+                Arc::new(trade_id_array),
+                Arc::new(ts_event_array),
+                Arc::new(ts_init_array),
+                Arc::new(flags_array),
+                Arc::new(sequence_array),
+                Arc::new(batch_id_array),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for TradeTicks {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let price_values = extract_raw_column::<PriceRaw>(cols, "price", 0)?;
+        let size_values = extract_raw_column::<QuantityRaw>(cols, "size", 1)?;
+        let aggressor_side_values =
+            extract_column::<UInt8Array>(cols, "aggressor_side", 2, DataType::UInt8)?;
+        let trade_id_values = extract_column::<StringArray>(cols, "trade_id", 3, DataType::Utf8)?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 4, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 5, DataType::UInt64)?;
+        let flags_values = extract_column::<UInt8Array>(cols, "flags", 6, DataType::UInt8)?;
+        let sequence_values = extract_column::<UInt64Array>(cols, "sequence", 7, DataType::UInt64)?;
+        let batch_id_values = extract_column::<UInt64Array>(cols, "batch_id", 8, DataType::UInt64)?;
+
+        let mut result: Vec<Self> = Vec::new();
+        let mut trades: Vec<TradeTick> = Vec::new();
+        let mut current_batch: Option<(u64, u8, u64)> = None;
+
+        for i in 0..record_batch.num_rows() {
+            let batch_id = batch_id_values.value(i);
+            if let Some((current_id, flags, sequence)) = current_batch {
+                if current_id != batch_id {
+                    let batch = std::mem::take(&mut trades);
+                    result.push(Self::new(instrument_id, batch, flags, sequence));
+                }
+            }
+            current_batch = Some((batch_id, flags_values.value(i), sequence_values.value(i)));
+
+            let price = Price::from_raw(price_values.value(i), price_precision).unwrap();
+            let size = Quantity::from_raw(size_values.value(i), size_precision).unwrap();
+            let aggressor_side_value = aggressor_side_values.value(i);
+            let aggressor_side = AggressorSide::from_repr(aggressor_side_value as usize)
+                .ok_or_else(|| {
+                    EncodingError::ParseError(
+                        stringify!(AggressorSide),
+                        format!("Invalid enum value, was {aggressor_side_value}"),
+                    )
+                })?;
+
+            trades.push(TradeTick {
+                instrument_id,
+                price,
+                size,
+                aggressor_side,
+                trade_id: TradeId::from(trade_id_values.value(i)),
+                ts_event: ts_event_values.value(i),
+                ts_init: ts_init_values.value(i),
+            });
+        }
+
+        if let Some((_, flags, sequence)) = current_batch {
+            result.push(Self::new(instrument_id, trades, flags, sequence));
+        }
+
+        Ok(result)
+    }
+}
+
+impl DecodeDataFromRecordBatch for TradeTicks {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let trades: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(trades.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::trades::stubs::stub_trade_ticks;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = TradeTicks::get_schema_map();
+        let mut expected_map = HashMap::new();
+        expected_map.insert("price".to_string(), "Int64".to_string());
+        expected_map.insert("size".to_string(), "UInt64".to_string());
+        expected_map.insert("aggressor_side".to_string(), "UInt8".to_string());
+        expected_map.insert("trade_id".to_string(), "Utf8".to_string());
+        expected_map.insert("ts_event".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        expected_map.insert("flags".to_string(), "UInt8".to_string());
+        expected_map.insert("sequence".to_string(), "UInt64".to_string());
+        expected_map.insert("batch_id".to_string(), "UInt64".to_string());
+        assert_eq!(schema_map, expected_map);
+    }
+
+    #[rstest]
+    fn test_encode_batch(stub_trade_ticks: TradeTicks) {
+        let trades = stub_trade_ticks;
+        let metadata = TradeTicks::get_metadata(&trades.instrument_id, 4, 8);
+        let batches = vec![trades.clone(), trades];
+
+        let record_batch = TradeTicks::encode_batch(&metadata, &batches).unwrap();
+
+        let columns = record_batch.columns();
+        let batch_id_values = columns[8].as_any().downcast_ref::<UInt64Array>().unwrap();
+
+        assert_eq!(columns.len(), 9);
+        assert_eq!(record_batch.num_rows(), 6);
+        assert_eq!(batch_id_values.values().to_vec(), vec![0, 0, 0, 1, 1, 1]);
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip(stub_trade_ticks: TradeTicks) {
+        let trades = stub_trade_ticks;
+        let metadata = TradeTicks::get_metadata(&trades.instrument_id, 4, 8);
+        let mut second = trades.clone();
+        second.flags = 128;
+        second.sequence = 2;
+        let batches = vec![trades, second];
+
+        let record_batch = TradeTicks::encode_batch(&metadata, &batches).unwrap();
+        let decoded = TradeTicks::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, batches);
+        assert_eq!(decoded[1].trades, batches[1].trades);
+        assert_eq!(decoded[1].flags, 128);
+        assert_eq!(decoded[1].sequence, 2);
+    }
+}
@@ -0,0 +1,215 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Query filters pushed down into Parquet scans.
+//!
+//! Files are pruned up front using the instrument metadata and the `ts_init` row group
+//! statistics in the Parquet footer, then the `ts_init` range is pushed into the query
+//! so DataFusion can prune row groups and pages within the remaining files.
+
+use std::{collections::HashMap, fs::File};
+
+use datafusion::{
+    error::Result,
+    parquet::{
+        arrow::parquet_to_arrow_schema,
+        file::{footer::parse_metadata, metadata::ParquetMetaData, statistics::Statistics},
+    },
+};
+use nautilus_core::time::UnixNanos;
+
+use crate::arrow::{KEY_BAR_TYPE, KEY_INSTRUMENT_ID};
+
+const TS_INIT_COLUMN: &str = "ts_init";
+
+/// Provides instrument and `ts_init` range filters for a query.
+///
+/// The `instrument_ids` match either the instrument ID or the bar type (or its
+/// instrument ID) stored in the file metadata, and the `start` and `end` bounds are inclusive.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueryFilter {
+    pub instrument_ids: Option<Vec<String>>,
+    pub start: Option<UnixNanos>,
+    pub end: Option<UnixNanos>,
+    pub where_clause: Option<String>,
+}
+
+impl QueryFilter {
+    /// Returns the SQL query for the table with the filters applied.
+    #[must_use]
+    pub fn to_sql(&self, table_name: &str) -> String {
+        let mut conditions = Vec::new();
+        if let Some(where_clause) = &self.where_clause {
+            conditions.push(format!("({where_clause})"));
+        }
+        if let Some(start) = self.start {
+            conditions.push(format!("{TS_INIT_COLUMN} >= {start}"));
+        }
+        if let Some(end) = self.end {
+            conditions.push(format!("{TS_INIT_COLUMN} <= {end}"));
+        }
+
+        let mut query = format!("SELECT * FROM {table_name}");
+        if !conditions.is_empty() {
+            query.push_str(" WHERE ");
+            query.push_str(&conditions.join(" AND "));
+        }
+        query
+    }
+
+    /// Returns whether the file metadata matches the instrument filter.
+    ///
+    /// Files without instrument metadata cannot be pruned and always match.
+    #[must_use]
+    pub fn matches_metadata(&self, metadata: &HashMap<String, String>) -> bool {
+        let Some(instrument_ids) = &self.instrument_ids else {
+            return true;
+        };
+        let instrument_id = metadata.get(KEY_INSTRUMENT_ID);
+        let bar_type = metadata.get(KEY_BAR_TYPE);
+        if instrument_id.is_none() && bar_type.is_none() {
+            return true;
+        }
+
+        instrument_ids.iter().any(|id| {
+            instrument_id == Some(id)
+                || bar_type.is_some_and(|bar_type| {
+                    bar_type == id
+                        || bar_type
+                            .strip_prefix(id.as_str())
+                            .is_some_and(|rest| rest.starts_with('-'))
+                })
+        })
+    }
+
+    /// Returns whether the inclusive `ts_init` range overlaps the filter range.
+    #[must_use]
+    pub fn overlaps(&self, min_ts: UnixNanos, max_ts: UnixNanos) -> bool {
+        self.start.map_or(true, |start| max_ts >= start)
+            && self.end.map_or(true, |end| min_ts <= end)
+    }
+
+    /// Returns whether the Parquet file at `file_path` may contain matching records,
+    /// reading only the file footer.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the file footer cannot be read.
+    pub fn matches_file(&self, file_path: &str) -> Result<bool> {
+        if self.instrument_ids.is_none() && self.start.is_none() && self.end.is_none() {
+            return Ok(true);
+        }
+
+        let metadata = parse_metadata(&File::open(file_path)?)?;
+        let file_metadata = metadata.file_metadata();
+        let schema = parquet_to_arrow_schema(
+            file_metadata.schema_descr(),
+            file_metadata.key_value_metadata(),
+        )?;
+
+        Ok(self.matches_metadata(schema.metadata()) && self.matches_row_groups(&metadata))
+    }
+
+    fn matches_row_groups(&self, metadata: &ParquetMetaData) -> bool {
+        if self.start.is_none() && self.end.is_none() {
+            return true;
+        }
+        let Some(column) = metadata
+            .file_metadata()
+            .schema_descr()
+            .columns()
+            .iter()
+            .position(|column| column.name() == TS_INIT_COLUMN)
+        else {
+            return true;
+        };
+
+        metadata.row_groups().iter().any(|row_group| {
+            match row_group.column(column).statistics() {
+                // Unsigned values are stored with the `Int64` physical type
+                Some(Statistics::Int64(stats)) if stats.has_min_max_set() => {
+                    self.overlaps(*stats.min() as UnixNanos, *stats.max() as UnixNanos)
+                }
+                _ => true,
+            }
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_to_sql_no_filters() {
+        let filter = QueryFilter::default();
+        assert_eq!(filter.to_sql("quotes"), "SELECT * FROM quotes");
+    }
+
+    #[rstest]
+    fn test_to_sql_with_filters() {
+        let filter = QueryFilter {
+            start: Some(1),
+            end: Some(2),
+            where_clause: Some("bid_size > 0".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_sql("quotes"),
+            "SELECT * FROM quotes WHERE (bid_size > 0) AND ts_init >= 1 AND ts_init <= 2"
+        );
+    }
+
+    #[rstest]
+    #[case(None, None, true)]
+    #[case(Some(10), None, true)]
+    #[case(Some(21), None, false)]
+    #[case(None, Some(10), true)]
+    #[case(None, Some(9), false)]
+    #[case(Some(12), Some(15), true)]
+    #[case(Some(0), Some(100), true)]
+    fn test_overlaps(
+        #[case] start: Option<UnixNanos>,
+        #[case] end: Option<UnixNanos>,
+        #[case] expected: bool,
+    ) {
+        let filter = QueryFilter {
+            start,
+            end,
+            ..Default::default()
+        };
+        assert_eq!(filter.overlaps(10, 20), expected);
+    }
+
+    #[rstest]
+    #[case(KEY_INSTRUMENT_ID, "EUR/USD.SIM", true)]
+    #[case(KEY_INSTRUMENT_ID, "AUD/USD.SIM", false)]
+    #[case(KEY_BAR_TYPE, "EUR/USD.SIM-1-MINUTE-LAST-EXTERNAL", true)]
+    #[case(KEY_BAR_TYPE, "EUR/USD.SIMX-1-MINUTE-LAST-EXTERNAL", false)]
+    #[case("data_type", "Custom", true)]
+    fn test_matches_metadata(#[case] key: &str, #[case] value: &str, #[case] expected: bool) {
+        let filter = QueryFilter {
+            instrument_ids: Some(vec!["EUR/USD.SIM".to_string()]),
+            ..Default::default()
+        };
+        let metadata = HashMap::from([(key.to_string(), value.to_string())]);
+        assert_eq!(filter.matches_metadata(&metadata), expected);
+    }
+}
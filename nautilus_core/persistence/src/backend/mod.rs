@@ -13,5 +13,6 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod filter;
 pub mod kmerge_batch;
 pub mod session;
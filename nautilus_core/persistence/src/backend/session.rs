@@ -13,7 +13,11 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, sync::Arc, vec::IntoIter};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    vec::IntoIter,
+};
 
 use compare::Compare;
 use datafusion::{
@@ -21,9 +25,18 @@ use datafusion::{
 };
 use futures::StreamExt;
 use nautilus_core::ffi::cvec::CVec;
-use nautilus_model::data::{Data, HasTsInit};
+use nautilus_model::{
+    data::{
+        deltas::{OrderBookDeltas, OrderBookDeltas_API},
+        Data, HasTsInit,
+    },
+    enums::RecordFlag,
+};
 
-use super::kmerge_batch::{EagerStream, ElementBatchIter, KMerge};
+use super::{
+    filter::QueryFilter,
+    kmerge_batch::{EagerStream, ElementBatchIter, KMerge},
+};
 use crate::arrow::{
     DataStreamingError, DecodeDataFromRecordBatch, EncodeToRecordBatch, WriteStream,
 };
@@ -47,6 +60,53 @@ where
 
 pub type QueryResult = KMerge<EagerStream<std::vec::IntoIter<Data>>, Data, TsInitComparator>;
 
+/// Rejoins [`OrderBookDeltas`] batches which were split across decoded record batches.
+///
+/// A trailing deltas batch without the `F_LAST` flag is held back and prepended to the
+/// first deltas batch of the next chunk for the same instrument.
+#[derive(Debug, Default)]
+pub struct DeltasBatchJoiner {
+    pending: Option<OrderBookDeltas>,
+}
+
+impl DeltasBatchJoiner {
+    #[must_use]
+    pub fn join(&mut self, mut data: Vec<Data>) -> Vec<Data> {
+        if let Some(pending) = self.pending.take() {
+            match data.first_mut() {
+                Some(Data::Deltas(next)) if next.instrument_id == pending.instrument_id => {
+                    let mut deltas = pending.deltas;
+                    deltas.extend_from_slice(&next.deltas);
+                    *next = OrderBookDeltas_API::new(OrderBookDeltas::new(
+                        pending.instrument_id,
+                        deltas,
+                    ));
+                }
+                _ => data.insert(0, Data::Deltas(OrderBookDeltas_API::new(pending))),
+            }
+        }
+
+        if let Some(Data::Deltas(last)) = data.last() {
+            if !RecordFlag::F_LAST.matches(last.flags) {
+                self.pending = Some((**last).clone());
+                data.pop();
+            }
+        }
+
+        data
+    }
+
+    /// Returns any held back partial batch once the stream is exhausted.
+    #[must_use]
+    pub fn finish(&mut self) -> Vec<Data> {
+        self.pending
+            .take()
+            .map(|deltas| Data::Deltas(OrderBookDeltas_API::new(deltas)))
+            .into_iter()
+            .collect()
+    }
+}
+
 /// Provides a DataFusion session and registers DataFusion queries.
 ///
 /// The session is used to register data sources and make queries on them. A
@@ -70,8 +130,16 @@ impl DataBackendSession {
             .enable_all()
             .build()
             .unwrap();
+
+        // Push filters down into the Parquet scan, pruning row groups and pages
+        let mut config = SessionConfig::new();
+        config.options_mut().execution.parquet.pruning = true;
+        config.options_mut().execution.parquet.enable_page_index = true;
+        config.options_mut().execution.parquet.pushdown_filters = true;
+        config.options_mut().execution.parquet.reorder_filters = true;
+
         Self {
-            session_ctx: SessionContext::default(),
+            session_ctx: SessionContext::new_with_config(config),
             batch_streams: Vec::default(),
             chunk_size,
             runtime: Arc::new(runtime),
@@ -135,16 +203,51 @@ impl DataBackendSession {
         Ok(())
     }
 
+    /// Query a file for its records matching the `filter`. The file is skipped
+    /// without being scanned when its footer metadata shows it cannot match,
+    /// otherwise the `ts_init` range is used to prune row groups and pages.
+    ///
+    /// Returns whether the file was added to the session.
+    ///
+    /// # Safety
+    ///
+    /// The file data must be ordered by the `ts_init` in ascending order for this
+    /// to work correctly.
+    pub fn add_file_with_filter<T>(
+        &mut self,
+        table_name: &str,
+        file_path: &str,
+        filter: &QueryFilter,
+    ) -> Result<bool>
+    where
+        T: DecodeDataFromRecordBatch + Into<Data>,
+    {
+        if !filter.matches_file(file_path)? {
+            return Ok(false);
+        }
+
+        let sql_query = filter.to_sql(table_name);
+        self.add_file::<T>(table_name, file_path, Some(&sql_query))?;
+        Ok(true)
+    }
+
     fn add_batch_stream<T>(&mut self, stream: SendableRecordBatchStream)
     where
         T: DecodeDataFromRecordBatch + Into<Data>,
     {
-        let transform = stream.map(|result| match result {
-            Ok(batch) => T::decode_data_batch(batch.schema().metadata(), batch)
-                .unwrap()
-                .into_iter(),
-            Err(_err) => panic!("Error getting next batch from RecordBatchStream"),
-        });
+        let joiner = Arc::new(Mutex::new(DeltasBatchJoiner::default()));
+        let remainder = joiner.clone();
+        let transform = stream
+            .map(move |result| match result {
+                Ok(batch) => {
+                    let data = T::decode_data_batch(batch.schema().metadata(), batch).unwrap();
+                    joiner.lock().unwrap().join(data).into_iter()
+                }
+                Err(_err) => panic!("Error getting next batch from RecordBatchStream"),
+            })
+            .chain(futures::stream::once(async move {
+                remainder.lock().unwrap().finish().into_iter()
+            }));
 
         self.batch_streams
             .push(EagerStream::from_stream_with_runtime(
@@ -243,3 +346,53 @@ impl Drop for DataQueryResult {
 
 // Note: Intended to be used on a single Python thread
 unsafe impl Send for DataQueryResult {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::deltas::stubs::stub_deltas;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn deltas_data(deltas: &OrderBookDeltas) -> Data {
+        Data::Deltas(OrderBookDeltas_API::new(deltas.clone()))
+    }
+
+    fn deltas_lens(data: &[Data]) -> Vec<usize> {
+        data.iter()
+            .map(|d| match d {
+                Data::Deltas(deltas) => deltas.deltas.len(),
+                _ => panic!("expected `Data::Deltas`"),
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_deltas_batch_joiner_rejoins_split_batch(stub_deltas: OrderBookDeltas) {
+        let mut deltas = stub_deltas;
+        deltas.deltas[6].flags |= RecordFlag::F_LAST as u8;
+        let parts = deltas.split_by_size(4);
+        let mut joiner = DeltasBatchJoiner::default();
+
+        let first = joiner.join(vec![deltas_data(&parts[0])]);
+        let second = joiner.join(vec![deltas_data(&parts[1])]);
+
+        assert!(first.is_empty());
+        assert_eq!(deltas_lens(&second), vec![7]);
+        assert!(joiner.finish().is_empty());
+    }
+
+    #[rstest]
+    fn test_deltas_batch_joiner_flushes_partial_batch_on_finish(stub_deltas: OrderBookDeltas) {
+        let deltas = stub_deltas;
+        let mut joiner = DeltasBatchJoiner::default();
+
+        let joined = joiner.join(vec![deltas_data(&deltas)]);
+
+        assert!(joined.is_empty());
+        assert_eq!(deltas_lens(&joiner.finish()), vec![7]);
+    }
+}
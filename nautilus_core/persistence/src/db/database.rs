@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{path::Path, str::FromStr};
+use std::{path::PathBuf, str::FromStr};
 
 use anyhow::Result;
 use sqlx::{
@@ -132,20 +132,22 @@ pub async fn init_db_schema(db: &Database, schema_dir: &str) -> Result<()> {
 }
 
 pub async fn setup_test_database() -> Database {
-    // check if test_db.sqlite exists,if not, create it
-    let db_path = std::env::var("TEST_DB_PATH").unwrap_or("test_db.sqlite".to_string());
-    let db_file_path = Path::new(db_path.as_str());
-    let exists = db_file_path.exists();
-    if !exists {
+    // Create the test database in the temp directory (unless `TEST_DB_PATH` is set)
+    let db_path = std::env::var("TEST_DB_PATH").map_or_else(
+        |_| std::env::temp_dir().join("nautilus_test_db.sqlite"),
+        PathBuf::from,
+    );
+    if !db_path.exists() {
         SqlitePool::connect_with(
             SqliteConnectOptions::new()
-                .filename(db_file_path)
+                .filename(&db_path)
                 .create_if_missing(true),
         )
         .await
-        .expect("Failed to create test_db.sqlite");
+        .expect("Failed to create test database");
     }
-    Database::new(Some(DatabaseEngine::SQLITE), Some("sqlite:test_db.sqlite")).await
+    let conn_string = format!("sqlite:{}", db_path.display());
+    Database::new(Some(DatabaseEngine::SQLITE), Some(&conn_string)).await
 }
 
 #[cfg(test)]
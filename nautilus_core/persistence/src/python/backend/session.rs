@@ -13,13 +13,23 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::{ffi::cvec::CVec, python::to_pyruntime_err};
+use nautilus_core::{ffi::cvec::CVec, python::to_pyruntime_err, time::UnixNanos};
 use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick, trade::TradeTick,
+    bar::Bar,
+    delta::OrderBookDelta,
+    deltas::OrderBookDeltas,
+    depth::{OrderBookDepth10, OrderBookDepth25},
+    open_interest::OpenInterestUpdate,
+    prices::{IndexPriceUpdate, MarkPriceUpdate},
+    quote::QuoteTick,
+    trade::TradeTick,
 };
 use pyo3::{prelude::*, types::PyCapsule};
 
-use crate::backend::session::{DataBackendSession, DataQueryResult};
+use crate::backend::{
+    filter::QueryFilter,
+    session::{DataBackendSession, DataQueryResult},
+};
 
 #[repr(C)]
 #[pyclass]
@@ -31,6 +41,11 @@ pub enum NautilusDataType {
     QuoteTick = 3,
     TradeTick = 4,
     Bar = 5,
+    OpenInterestUpdate = 6,
+    OrderBookDeltas = 7,
+    OrderBookDepth25 = 8,
+    MarkPriceUpdate = 9,
+    IndexPriceUpdate = 10,
 }
 
 #[pymethods]
@@ -80,6 +95,84 @@ impl DataBackendSession {
             NautilusDataType::Bar => slf
                 .add_file::<Bar>(table_name, file_path, sql_query)
                 .map_err(to_pyruntime_err),
+            NautilusDataType::OpenInterestUpdate => slf
+                .add_file::<OpenInterestUpdate>(table_name, file_path, sql_query)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::OrderBookDeltas => slf
+                .add_file::<OrderBookDeltas>(table_name, file_path, sql_query)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::OrderBookDepth25 => slf
+                .add_file::<OrderBookDepth25>(table_name, file_path, sql_query)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::MarkPriceUpdate => slf
+                .add_file::<MarkPriceUpdate>(table_name, file_path, sql_query)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::IndexPriceUpdate => slf
+                .add_file::<IndexPriceUpdate>(table_name, file_path, sql_query)
+                .map_err(to_pyruntime_err),
+        }
+    }
+
+    /// Query a file for its records matching the filters, skipping the file when
+    /// its metadata and `ts_init` statistics show it cannot match. Returns whether
+    /// the file was added.
+    ///
+    /// instrument_ids: The instrument IDs (or bar types) to match against the file metadata.
+    /// start: The inclusive lower bound of the `ts_init` range.
+    /// end: The inclusive upper bound of the `ts_init` range.
+    /// where_clause: An additional SQL condition for the records.
+    #[pyo3(name = "add_file_with_filter")]
+    #[pyo3(signature = (data_type, table_name, file_path, instrument_ids=None, start=None, end=None, where_clause=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn add_file_with_filter_py(
+        mut slf: PyRefMut<'_, Self>,
+        data_type: NautilusDataType,
+        table_name: &str,
+        file_path: &str,
+        instrument_ids: Option<Vec<String>>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        where_clause: Option<String>,
+    ) -> PyResult<bool> {
+        let _guard = slf.runtime.enter();
+        let filter = QueryFilter {
+            instrument_ids,
+            start,
+            end,
+            where_clause,
+        };
+
+        match data_type {
+            NautilusDataType::OrderBookDelta => slf
+                .add_file_with_filter::<OrderBookDelta>(table_name, file_path, &filter)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::OrderBookDepth10 => slf
+                .add_file_with_filter::<OrderBookDepth10>(table_name, file_path, &filter)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::QuoteTick => slf
+                .add_file_with_filter::<QuoteTick>(table_name, file_path, &filter)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::TradeTick => slf
+                .add_file_with_filter::<TradeTick>(table_name, file_path, &filter)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::Bar => slf
+                .add_file_with_filter::<Bar>(table_name, file_path, &filter)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::OpenInterestUpdate => slf
+                .add_file_with_filter::<OpenInterestUpdate>(table_name, file_path, &filter)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::OrderBookDeltas => slf
+                .add_file_with_filter::<OrderBookDeltas>(table_name, file_path, &filter)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::OrderBookDepth25 => slf
+                .add_file_with_filter::<OrderBookDepth25>(table_name, file_path, &filter)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::MarkPriceUpdate => slf
+                .add_file_with_filter::<MarkPriceUpdate>(table_name, file_path, &filter)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::IndexPriceUpdate => slf
+                .add_file_with_filter::<IndexPriceUpdate>(table_name, file_path, &filter)
+                .map_err(to_pyruntime_err),
         }
     }
 
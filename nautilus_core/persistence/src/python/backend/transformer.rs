@@ -13,15 +13,30 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::io::Cursor;
+use std::{collections::HashMap, io::Cursor};
 
 use datafusion::arrow::{
-    datatypes::Schema, error::ArrowError, ipc::writer::StreamWriter, record_batch::RecordBatch,
+    datatypes::Schema,
+    error::ArrowError,
+    ipc::{reader::StreamReader, writer::StreamWriter},
+    record_batch::RecordBatch,
 };
 use nautilus_core::python::to_pyvalue_err;
-use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, is_monotonically_increasing_by_init,
-    quote::QuoteTick, trade::TradeTick,
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        custom::CustomData,
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::{OrderBookDepth10, OrderBookDepth25},
+        is_monotonically_increasing_by_init,
+        open_interest::OpenInterestUpdate,
+        prices::{IndexPriceUpdate, MarkPriceUpdate},
+        quote::QuoteTick,
+        trade::TradeTick,
+    },
+    enums::BookAction,
+    instruments::binary_option::BinaryOption,
 };
 use pyo3::{
     exceptions::{PyRuntimeError, PyTypeError, PyValueError},
@@ -29,7 +44,7 @@ use pyo3::{
     types::{IntoPyDict, PyBytes, PyDict, PyType},
 };
 
-use crate::arrow::{ArrowSchemaProvider, EncodeToRecordBatch};
+use crate::arrow::{ArrowSchemaProvider, DecodeFromRecordBatch, EncodeToRecordBatch};
 
 const ERROR_EMPTY_DATA: &str = "`data` was empty";
 const ERROR_MONOTONICITY: &str = "`data` was not monotonically increasing by the `ts_init` field";
@@ -136,10 +151,16 @@ impl DataTransformer {
         let cls_str: &str = cls.getattr("__name__")?.extract()?;
         let result_map = match cls_str {
             stringify!(OrderBookDelta) => OrderBookDelta::get_schema_map(),
+            stringify!(OrderBookDeltas) => OrderBookDeltas::get_schema_map(),
             stringify!(OrderBookDepth10) => OrderBookDepth10::get_schema_map(),
+            stringify!(OrderBookDepth25) => OrderBookDepth25::get_schema_map(),
             stringify!(QuoteTick) => QuoteTick::get_schema_map(),
             stringify!(TradeTick) => TradeTick::get_schema_map(),
             stringify!(Bar) => Bar::get_schema_map(),
+            stringify!(OpenInterestUpdate) => OpenInterestUpdate::get_schema_map(),
+            stringify!(MarkPriceUpdate) => MarkPriceUpdate::get_schema_map(),
+            stringify!(IndexPriceUpdate) => IndexPriceUpdate::get_schema_map(),
+            stringify!(BinaryOption) => BinaryOption::get_schema_map(),
             _ => {
                 return Err(PyTypeError::new_err(format!(
                     "Arrow schema for `{cls_str}` is not currently implemented in Rust."
@@ -236,6 +257,45 @@ impl DataTransformer {
         }
     }
 
+    #[staticmethod]
+    pub fn pyo3_order_book_deltas_batches_to_record_batch_bytes(
+        py: Python<'_>,
+        data: Vec<OrderBookDeltas>,
+    ) -> PyResult<Py<PyBytes>> {
+        if data.is_empty() {
+            return Err(PyValueError::new_err(ERROR_EMPTY_DATA));
+        }
+
+        // Validate monotonically increasing
+        if !is_monotonically_increasing_by_init(&data) {
+            return Err(PyValueError::new_err(ERROR_MONOTONICITY));
+        }
+
+        // Take first element and extract metadata, with precisions from the first non-clear delta
+        // SAFETY: Unwrap safe as already checked that `data` not empty
+        let first = data.first().unwrap();
+        let (price_precision, size_precision) = data
+            .iter()
+            .flat_map(|batch| batch.deltas.iter())
+            .find(|delta| delta.action != BookAction::Clear)
+            .map_or((0, 0), |delta| {
+                (delta.order.price.precision, delta.order.size.precision)
+            });
+        let metadata =
+            OrderBookDeltas::get_metadata(&first.instrument_id, price_precision, size_precision);
+
+        let result: Result<RecordBatch, ArrowError> =
+            OrderBookDeltas::encode_batch(&metadata, &data);
+
+        match result {
+            Ok(batch) => {
+                let schema = OrderBookDeltas::get_schema(Some(metadata));
+                Self::record_batch_to_pybytes(py, batch, schema)
+            }
+            Err(e) => Err(to_pyvalue_err(e)),
+        }
+    }
+
     #[staticmethod]
     pub fn pyo3_order_book_depth10_to_record_batch_bytes(
         py: Python<'_>,
@@ -266,6 +326,36 @@ impl DataTransformer {
         }
     }
 
+    #[staticmethod]
+    pub fn pyo3_order_book_depth25_to_record_batch_bytes(
+        py: Python<'_>,
+        data: Vec<OrderBookDepth25>,
+    ) -> PyResult<Py<PyBytes>> {
+        if data.is_empty() {
+            return Err(PyValueError::new_err(ERROR_EMPTY_DATA));
+        }
+
+        // Take first element and extract metadata
+        // SAFETY: Unwrap safe as already checked that `data` not empty
+        let first = data.first().unwrap();
+        let metadata = OrderBookDepth25::get_metadata(
+            &first.instrument_id,
+            first.bids[0].price.precision,
+            first.bids[0].size.precision,
+        );
+
+        let result: Result<RecordBatch, ArrowError> =
+            OrderBookDepth25::encode_batch(&metadata, &data);
+
+        match result {
+            Ok(batch) => {
+                let schema = OrderBookDepth25::get_schema(Some(metadata));
+                Self::record_batch_to_pybytes(py, batch, schema)
+            }
+            Err(e) => Err(to_pyvalue_err(e)),
+        }
+    }
+
     #[staticmethod]
     pub fn pyo3_quote_ticks_to_record_batch_bytes(
         py: Python<'_>,
@@ -352,4 +442,188 @@ impl DataTransformer {
             Err(e) => Err(to_pyvalue_err(e)),
         }
     }
+
+    #[staticmethod]
+    pub fn pyo3_open_interest_to_record_batch_bytes(
+        py: Python<'_>,
+        data: Vec<OpenInterestUpdate>,
+    ) -> PyResult<Py<PyBytes>> {
+        if data.is_empty() {
+            return Err(to_pyvalue_err(ERROR_EMPTY_DATA));
+        }
+
+        // Take first element and extract metadata
+        // SAFETY: Unwrap safe as already checked that `data` not empty
+        let first = data.first().unwrap();
+        let metadata =
+            OpenInterestUpdate::get_metadata(&first.instrument_id, first.value.precision);
+
+        let result: Result<RecordBatch, ArrowError> =
+            OpenInterestUpdate::encode_batch(&metadata, &data);
+
+        match result {
+            Ok(batch) => {
+                let schema = OpenInterestUpdate::get_schema(Some(metadata));
+                Self::record_batch_to_pybytes(py, batch, schema)
+            }
+            Err(e) => Err(to_pyvalue_err(e)),
+        }
+    }
+
+    #[staticmethod]
+    pub fn pyo3_mark_prices_to_record_batch_bytes(
+        py: Python<'_>,
+        data: Vec<MarkPriceUpdate>,
+    ) -> PyResult<Py<PyBytes>> {
+        if data.is_empty() {
+            return Err(to_pyvalue_err(ERROR_EMPTY_DATA));
+        }
+
+        // Take first element and extract metadata
+        // SAFETY: Unwrap safe as already checked that `data` not empty
+        let first = data.first().unwrap();
+        let metadata = MarkPriceUpdate::get_metadata(&first.instrument_id, first.value.precision);
+
+        let result: Result<RecordBatch, ArrowError> =
+            MarkPriceUpdate::encode_batch(&metadata, &data);
+
+        match result {
+            Ok(batch) => {
+                let schema = MarkPriceUpdate::get_schema(Some(metadata));
+                Self::record_batch_to_pybytes(py, batch, schema)
+            }
+            Err(e) => Err(to_pyvalue_err(e)),
+        }
+    }
+
+    #[staticmethod]
+    pub fn pyo3_index_prices_to_record_batch_bytes(
+        py: Python<'_>,
+        data: Vec<IndexPriceUpdate>,
+    ) -> PyResult<Py<PyBytes>> {
+        if data.is_empty() {
+            return Err(to_pyvalue_err(ERROR_EMPTY_DATA));
+        }
+
+        // Take first element and extract metadata
+        // SAFETY: Unwrap safe as already checked that `data` not empty
+        let first = data.first().unwrap();
+        let metadata = IndexPriceUpdate::get_metadata(&first.instrument_id, first.value.precision);
+
+        let result: Result<RecordBatch, ArrowError> =
+            IndexPriceUpdate::encode_batch(&metadata, &data);
+
+        match result {
+            Ok(batch) => {
+                let schema = IndexPriceUpdate::get_schema(Some(metadata));
+                Self::record_batch_to_pybytes(py, batch, schema)
+            }
+            Err(e) => Err(to_pyvalue_err(e)),
+        }
+    }
+
+    #[staticmethod]
+    pub fn pyo3_custom_data_to_record_batch_bytes(
+        py: Python<'_>,
+        data: Vec<CustomData>,
+    ) -> PyResult<Py<PyBytes>> {
+        if data.is_empty() {
+            return Err(to_pyvalue_err(ERROR_EMPTY_DATA));
+        }
+
+        // Take first element and extract metadata
+        // SAFETY: Unwrap safe as already checked that `data` not empty
+        let first = data.first().unwrap();
+        if data.iter().any(|d| d.data_type != first.data_type) {
+            return Err(to_pyvalue_err(format!(
+                "`data` contained mixed custom data types, expected all `{}`",
+                first.data_type
+            )));
+        }
+
+        // Validate monotonically increasing
+        if !is_monotonically_increasing_by_init(&data) {
+            return Err(to_pyvalue_err(ERROR_MONOTONICITY));
+        }
+
+        let metadata = CustomData::get_metadata(first.data_type.as_str());
+
+        let result: Result<RecordBatch, ArrowError> = CustomData::encode_batch(&metadata, &data);
+
+        match result {
+            Ok(batch) => {
+                let schema = CustomData::get_schema(Some(metadata));
+                Self::record_batch_to_pybytes(py, batch, schema)
+            }
+            Err(e) => Err(to_pyvalue_err(e)),
+        }
+    }
+
+    #[staticmethod]
+    pub fn record_batch_bytes_to_pyo3_custom_data(
+        data_type: &str,
+        data: &[u8],
+    ) -> PyResult<Vec<CustomData>> {
+        let metadata = CustomData::get_metadata(data_type);
+
+        // Create a StreamReader (from Arrow IPC)
+        let cursor = Cursor::new(data);
+        let reader = StreamReader::try_new(cursor, None).map_err(to_pyvalue_err)?;
+
+        let mut custom_data = Vec::new();
+
+        // Read the record batches
+        for maybe_batch in reader {
+            let record_batch = maybe_batch.map_err(to_pyvalue_err)?;
+            let decoded =
+                CustomData::decode_batch(&metadata, record_batch).map_err(to_pyvalue_err)?;
+            custom_data.extend(decoded);
+        }
+
+        Ok(custom_data)
+    }
+
+    #[staticmethod]
+    pub fn pyo3_binary_options_to_record_batch_bytes(
+        py: Python<'_>,
+        data: Vec<BinaryOption>,
+    ) -> PyResult<Py<PyBytes>> {
+        if data.is_empty() {
+            return Err(to_pyvalue_err(ERROR_EMPTY_DATA));
+        }
+
+        // Instruments are self-describing so no metadata is required
+        let metadata = HashMap::new();
+
+        let result: Result<RecordBatch, ArrowError> = BinaryOption::encode_batch(&metadata, &data);
+
+        match result {
+            Ok(batch) => {
+                let schema = BinaryOption::get_schema(Some(metadata));
+                Self::record_batch_to_pybytes(py, batch, schema)
+            }
+            Err(e) => Err(to_pyvalue_err(e)),
+        }
+    }
+
+    #[staticmethod]
+    pub fn record_batch_bytes_to_pyo3_binary_options(data: &[u8]) -> PyResult<Vec<BinaryOption>> {
+        let metadata = HashMap::new();
+
+        // Create a StreamReader (from Arrow IPC)
+        let cursor = Cursor::new(data);
+        let reader = StreamReader::try_new(cursor, None).map_err(to_pyvalue_err)?;
+
+        let mut instruments = Vec::new();
+
+        // Read the record batches
+        for maybe_batch in reader {
+            let record_batch = maybe_batch.map_err(to_pyvalue_err)?;
+            let decoded =
+                BinaryOption::decode_batch(&metadata, record_batch).map_err(to_pyvalue_err)?;
+            instruments.extend(decoded);
+        }
+
+        Ok(instruments)
+    }
 }
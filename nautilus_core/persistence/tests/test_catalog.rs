@@ -16,10 +16,13 @@
 use nautilus_core::ffi::cvec::CVec;
 use nautilus_model::data::{
     bar::Bar, delta::OrderBookDelta, is_monotonically_increasing_by_init, quote::QuoteTick,
-    trade::TradeTick, Data,
+    trade::TradeTick, Data, HasTsInit,
 };
 use nautilus_persistence::{
-    backend::session::{DataBackendSession, DataQueryResult, QueryResult},
+    backend::{
+        filter::QueryFilter,
+        session::{DataBackendSession, DataQueryResult, QueryResult},
+    },
     python::backend::session::NautilusDataType,
 };
 #[cfg(target_os = "linux")]
@@ -299,3 +302,77 @@ fn test_bar_query() {
     assert_eq!(ticks.len(), expected_length);
     assert!(is_monotonically_increasing_by_init(&ticks));
 }
+
+#[rstest]
+fn test_quote_tick_query_with_ts_init_filter() {
+    let file_path = "../../tests/test_data/nautilus/quotes.parquet";
+    let mut catalog = DataBackendSession::new(10_000);
+    catalog
+        .add_file::<QuoteTick>("quotes_all", file_path, None)
+        .unwrap();
+    let all_ticks: Vec<Data> = catalog.get_query_result().collect();
+    let start = all_ticks[100].get_ts_init();
+    let end = all_ticks[199].get_ts_init();
+    let expected_length = all_ticks
+        .iter()
+        .filter(|data| (start..=end).contains(&data.get_ts_init()))
+        .count();
+
+    let filter = QueryFilter {
+        start: Some(start),
+        end: Some(end),
+        ..Default::default()
+    };
+    let added = catalog
+        .add_file_with_filter::<QuoteTick>("quotes_filtered", file_path, &filter)
+        .unwrap();
+    let ticks: Vec<Data> = catalog.get_query_result().collect();
+
+    assert!(added);
+    assert_eq!(ticks.len(), expected_length);
+    assert!(ticks
+        .iter()
+        .all(|data| (start..=end).contains(&data.get_ts_init())));
+    assert!(is_monotonically_increasing_by_init(&ticks));
+}
+
+#[rstest]
+fn test_query_with_ts_init_filter_outside_file_range_skips_file() {
+    let file_path = "../../tests/test_data/nautilus/trades.parquet";
+    let mut catalog = DataBackendSession::new(10_000);
+    let filter = QueryFilter {
+        start: Some(u64::MAX - 1),
+        ..Default::default()
+    };
+    let added = catalog
+        .add_file_with_filter::<TradeTick>("trades", file_path, &filter)
+        .unwrap();
+    let ticks: Vec<Data> = catalog.get_query_result().collect();
+
+    assert!(!added);
+    assert!(ticks.is_empty());
+}
+
+#[rstest]
+#[case("ADABTC.BINANCE", true, 10)]
+#[case("ADABTC.BINANCE-1-MINUTE-LAST-EXTERNAL", true, 10)]
+#[case("ETHUSDT.BINANCE", false, 0)]
+fn test_bar_query_with_instrument_filter(
+    #[case] instrument_id: &str,
+    #[case] expected_added: bool,
+    #[case] expected_length: usize,
+) {
+    let file_path = "../../tests/test_data/nautilus/bars.parquet";
+    let mut catalog = DataBackendSession::new(10_000);
+    let filter = QueryFilter {
+        instrument_ids: Some(vec![instrument_id.to_string()]),
+        ..Default::default()
+    };
+    let added = catalog
+        .add_file_with_filter::<Bar>("bars", file_path, &filter)
+        .unwrap();
+    let bars: Vec<Data> = catalog.get_query_result().collect();
+
+    assert_eq!(added, expected_added);
+    assert_eq!(bars.len(), expected_length);
+}
@@ -38,6 +38,12 @@ fn nautilus_pyo3(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     sys_modules.set_item(format!("{module_name}.{n}"), m.getattr(n)?)?;
     re_export_module_attributes(m, n)?;
 
+    let n = "analysis";
+    let submodule = pyo3::wrap_pymodule!(nautilus_analysis::python::analysis);
+    m.add_wrapped(submodule)?;
+    sys_modules.set_item(format!("{module_name}.{n}"), m.getattr(n)?)?;
+    re_export_module_attributes(m, n)?;
+
     let n = "databento";
     let submodule = pyo3::wrap_pymodule!(nautilus_adapters::databento::python::databento);
     m.add_wrapped(submodule)?;
@@ -16,6 +16,7 @@
 from nautilus_trader.adapters.betfair.providers import BetfairInstrumentProviderConfig
 from nautilus_trader.config import LiveDataClientConfig
 from nautilus_trader.config import LiveExecClientConfig
+from nautilus_trader.config import SocketConnectionConfig
 
 
 class BetfairDataClientConfig(LiveDataClientConfig, kw_only=True, frozen=True):
@@ -32,6 +33,8 @@ class BetfairDataClientConfig(LiveDataClientConfig, kw_only=True, frozen=True):
         The betfair application key.
     cert_dir : str, optional
         The local directory that contains the betfair certificates.
+    stream_connection : SocketConnectionConfig, optional
+        The proxy and TLS options for the stream connection.
 
     """
 
@@ -41,6 +44,7 @@ class BetfairDataClientConfig(LiveDataClientConfig, kw_only=True, frozen=True):
     app_key: str | None = None
     cert_dir: str | None = None
     instrument_config: BetfairInstrumentProviderConfig | None = None
+    stream_connection: SocketConnectionConfig | None = None
 
 
 class BetfairExecClientConfig(LiveExecClientConfig, kw_only=True, frozen=True):
@@ -57,6 +61,8 @@ class BetfairExecClientConfig(LiveExecClientConfig, kw_only=True, frozen=True):
         The betfair application key.
     cert_dir : str, optional
         The local directory that contains the betfair certificates.
+    stream_connection : SocketConnectionConfig, optional
+        The proxy and TLS options for the stream connection.
 
     """
 
@@ -67,3 +73,4 @@ class BetfairExecClientConfig(LiveExecClientConfig, kw_only=True, frozen=True):
     cert_dir: str | None = None
     instrument_config: BetfairInstrumentProviderConfig | None = None
     request_account_state_period: int | None = None
+    stream_connection: SocketConnectionConfig | None = None
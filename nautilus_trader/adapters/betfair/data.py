@@ -34,6 +34,7 @@ from nautilus_trader.cache.cache import Cache
 from nautilus_trader.common.component import LiveClock
 from nautilus_trader.common.component import MessageBus
 from nautilus_trader.common.enums import LogColor
+from nautilus_trader.config import SocketConnectionConfig
 from nautilus_trader.core.correctness import PyCondition
 from nautilus_trader.core.data import Data
 from nautilus_trader.live.data_client import LiveMarketDataClient
@@ -62,6 +63,8 @@ class BetfairDataClient(LiveMarketDataClient):
         The clock for the client.
     instrument_provider : BetfairInstrumentProvider, optional
         The instrument provider.
+    stream_connection : SocketConnectionConfig, optional
+        The proxy and TLS options for the stream connection.
 
     """
 
@@ -76,6 +79,7 @@ class BetfairDataClient(LiveMarketDataClient):
         clock: LiveClock,
         instrument_provider: BetfairInstrumentProvider,
         account_currency: Currency,
+        stream_connection: SocketConnectionConfig | None = None,
     ):
         super().__init__(
             loop=loop,
@@ -92,6 +96,7 @@ class BetfairDataClient(LiveMarketDataClient):
         self._stream = BetfairMarketStreamClient(
             http_client=self._client,
             message_handler=self.on_market_update,
+            connection_config=stream_connection,
         )
         self.parser = BetfairParser(currency=account_currency.code)
         self.subscription_status = SubscriptionStatus.UNSUBSCRIBED
@@ -57,6 +57,7 @@ from nautilus_trader.cache.cache import Cache
 from nautilus_trader.common.component import LiveClock
 from nautilus_trader.common.component import MessageBus
 from nautilus_trader.common.enums import LogColor
+from nautilus_trader.config import SocketConnectionConfig
 from nautilus_trader.core.correctness import PyCondition
 from nautilus_trader.core.datetime import millis_to_nanos
 from nautilus_trader.core.datetime import nanos_to_micros
@@ -108,6 +109,8 @@ class BetfairExecutionClient(LiveExecutionClient):
         The clock for the client.
     instrument_provider : BetfairInstrumentProvider
         The instrument provider.
+    stream_connection : SocketConnectionConfig, optional
+        The proxy and TLS options for the stream connection.
 
     """
 
@@ -121,6 +124,7 @@ class BetfairExecutionClient(LiveExecutionClient):
         clock: LiveClock,
         instrument_provider: BetfairInstrumentProvider,
         request_account_state_period: int = 300,
+        stream_connection: SocketConnectionConfig | None = None,
     ) -> None:
         super().__init__(
             loop=loop,
@@ -141,6 +145,7 @@ class BetfairExecutionClient(LiveExecutionClient):
         self.stream = BetfairOrderStreamClient(
             http_client=self._client,
             message_handler=self.handle_order_stream_update,
+            connection_config=stream_connection,
         )
         self.venue_order_id_to_client_order_id: dict[VenueOrderId, ClientOrderId] = {}
         self.pending_update_order_client_ids: set[tuple[ClientOrderId, VenueOrderId]] = set()
@@ -175,6 +175,7 @@ class BetfairLiveDataClientFactory(LiveDataClientFactory):
             clock=clock,
             instrument_provider=provider,
             account_currency=Currency.from_str(config.account_currency),
+            stream_connection=config.stream_connection,
         )
         return data_client
 
@@ -236,5 +237,6 @@ class BetfairLiveExecClientFactory(LiveExecClientFactory):
             clock=clock,
             instrument_provider=provider,
             request_account_state_period=config.request_account_state_period,
+            stream_connection=config.stream_connection,
         )
         return exec_client
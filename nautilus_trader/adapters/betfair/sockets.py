@@ -21,6 +21,7 @@ import msgspec
 
 from nautilus_trader.adapters.betfair.client import BetfairHttpClient
 from nautilus_trader.common.component import Logger
+from nautilus_trader.config import SocketConnectionConfig
 from nautilus_trader.core.nautilus_pyo3 import SocketClient
 from nautilus_trader.core.nautilus_pyo3 import SocketConfig
 
@@ -47,6 +48,7 @@ class BetfairStreamClient:
         port: int | None = None,
         crlf: bytes | None = None,
         encoding: str | None = None,
+        connection_config: SocketConnectionConfig | None = None,
     ) -> None:
         self._http_client = http_client
         self._log = Logger(type(self).__name__)
@@ -56,6 +58,7 @@ class BetfairStreamClient:
         self.crlf = crlf or CRLF
         self.use_ssl = USE_SSL
         self.encoding = encoding or ENCODING
+        self.connection_config = connection_config or SocketConnectionConfig()
         self._client: SocketClient | None = None
         self.unique_id = next(UNIQUE_ID)
         self.is_connected: bool = False
@@ -76,6 +79,10 @@ class BetfairStreamClient:
             handler=self.handler,
             ssl=self.use_ssl,
             suffix=self.crlf,
+            proxy_url=self.connection_config.proxy_url,
+            client_cert_path=self.connection_config.client_cert_path,
+            client_key_path=self.connection_config.client_key_path,
+            ca_cert_path=self.connection_config.ca_cert_path,
         )
         self._client = await SocketClient.connect(
             config,
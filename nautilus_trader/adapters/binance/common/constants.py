@@ -19,3 +19,8 @@ from nautilus_trader.model.identifiers import Venue
 
 
 BINANCE_VENUE: Final[Venue] = Venue("BINANCE")
+
+# The rate limiter key for the request weight budget, which is reported by the venue
+# in the `X-MBX-USED-WEIGHT-1M` response header
+BINANCE_REQUEST_WEIGHT_KEY: Final[str] = "request_weight"
+BINANCE_USED_WEIGHT_HEADER: Final[str] = "X-MBX-USED-WEIGHT-1M"
@@ -15,6 +15,7 @@
 
 
 from nautilus_trader.adapters.binance.common.enums import BinanceAccountType
+from nautilus_trader.config import HttpRetryConfig
 from nautilus_trader.config import LiveDataClientConfig
 from nautilus_trader.config import LiveExecClientConfig
 from nautilus_trader.config import PositiveFloat
@@ -48,6 +49,10 @@ class BinanceDataClientConfig(LiveDataClientConfig, frozen=True):
     use_agg_trade_ticks : bool, default False
         Whether to use aggregated trade tick endpoints instead of raw trade ticks.
         TradeId of ticks will be the Aggregate tradeId returned by Binance.
+    http_retry : HttpRetryConfig, optional
+        The retry configuration for failed HTTP requests.
+        The HTTP client is shared between data and execution clients with the same API key,
+        so the configuration of whichever client is created first applies.
 
     """
 
@@ -59,6 +64,7 @@ class BinanceDataClientConfig(LiveDataClientConfig, frozen=True):
     us: bool = False
     testnet: bool = False
     use_agg_trade_ticks: bool = False
+    http_retry: HttpRetryConfig | None = None
 
 
 class BinanceExecClientConfig(LiveExecClientConfig, frozen=True):
@@ -104,6 +110,10 @@ class BinanceExecClientConfig(LiveExecClientConfig, frozen=True):
         The maximum number of times a submit or cancel order request will be retried.
     retry_delay : PositiveFloat, optional
         The delay (seconds) between retries.
+    http_retry : HttpRetryConfig, optional
+        The retry configuration for failed HTTP requests.
+        The HTTP client is shared between data and execution clients with the same API key,
+        so the configuration of whichever client is created first applies.
 
     """
 
@@ -121,3 +131,4 @@ class BinanceExecClientConfig(LiveExecClientConfig, frozen=True):
     treat_expired_as_canceled: bool = False
     max_retries: PositiveInt | None = None
     retry_delay: PositiveFloat | None = None
+    http_retry: HttpRetryConfig | None = None
@@ -16,6 +16,7 @@
 import asyncio
 from functools import lru_cache
 
+from nautilus_trader.adapters.binance.common.constants import BINANCE_REQUEST_WEIGHT_KEY
 from nautilus_trader.adapters.binance.common.enums import BinanceAccountType
 from nautilus_trader.adapters.binance.config import BinanceDataClientConfig
 from nautilus_trader.adapters.binance.config import BinanceExecClientConfig
@@ -30,8 +31,10 @@ from nautilus_trader.adapters.env import get_env_key
 from nautilus_trader.cache.cache import Cache
 from nautilus_trader.common.component import LiveClock
 from nautilus_trader.common.component import MessageBus
+from nautilus_trader.config import HttpRetryConfig
 from nautilus_trader.config import InstrumentProviderConfig
 from nautilus_trader.core.nautilus_pyo3 import Quota
+from nautilus_trader.core.nautilus_pyo3 import RetryPolicy
 from nautilus_trader.live.factories import LiveDataClientFactory
 from nautilus_trader.live.factories import LiveExecClientFactory
 
@@ -48,6 +51,7 @@ def get_cached_binance_http_client(
     base_url: str | None = None,
     is_testnet: bool = False,
     is_us: bool = False,
+    retry_config: HttpRetryConfig | None = None,
 ) -> BinanceHttpClient:
     """
     Cache and return a Binance HTTP client with the given key and secret.
@@ -71,6 +75,8 @@ def get_cached_binance_http_client(
         If the client is connecting to the testnet API.
     is_us : bool, default False
         If the client is connecting to Binance US.
+    retry_config : HttpRetryConfig, optional
+        The retry configuration for failed requests (no retries if ``None``).
 
     Returns
     -------
@@ -88,6 +94,7 @@ def get_cached_binance_http_client(
         # Spot
         ratelimiter_default_quota = Quota.rate_per_minute(6000)
         ratelimiter_quotas: list[tuple[str, Quota]] = [
+            (BINANCE_REQUEST_WEIGHT_KEY, Quota.rate_per_minute(6000)),
             ("order", Quota.rate_per_minute(3000)),
             ("allOrders", Quota.rate_per_minute(int(3000 / 20))),
         ]
@@ -95,6 +102,7 @@ def get_cached_binance_http_client(
         # Futures
         ratelimiter_default_quota = Quota.rate_per_minute(2400)
         ratelimiter_quotas = [
+            (BINANCE_REQUEST_WEIGHT_KEY, Quota.rate_per_minute(2400)),
             ("order", Quota.rate_per_minute(1200)),
             ("allOrders", Quota.rate_per_minute(int(1200 / 20))),
         ]
@@ -108,11 +116,28 @@ def get_cached_binance_http_client(
             base_url=base_url or default_http_base_url,
             ratelimiter_quotas=ratelimiter_quotas,
             ratelimiter_default_quota=ratelimiter_default_quota,
+            retry_policy=_create_retry_policy(retry_config),
         )
         BINANCE_HTTP_CLIENTS[client_key] = client
     return BINANCE_HTTP_CLIENTS[client_key]
 
 
+def _create_retry_policy(config: HttpRetryConfig | None) -> RetryPolicy | None:
+    if config is None:
+        return None
+    return RetryPolicy(
+        max_retries=config.max_retries,
+        delay_initial_ms=config.delay_initial_ms,
+        delay_max_ms=config.delay_max_ms,
+        backoff_factor=config.backoff_factor,
+        jitter_ms=config.jitter_ms,
+        max_elapsed_ms=config.max_elapsed_ms,
+        retry_statuses=list(config.retry_statuses),
+        respect_retry_after=config.respect_retry_after,
+        idempotency_header=config.idempotency_header,
+    )
+
+
 @lru_cache(1)
 def get_cached_binance_spot_instrument_provider(
     client: BinanceHttpClient,
@@ -240,6 +265,7 @@ class BinanceLiveDataClientFactory(LiveDataClientFactory):
             base_url=config.base_url_http,
             is_testnet=config.testnet,
             is_us=config.us,
+            retry_config=config.http_retry,
         )
 
         default_base_url_ws: str = _get_ws_base_url(
@@ -343,6 +369,7 @@ class BinanceLiveExecClientFactory(LiveExecClientFactory):
             base_url=config.base_url_http,
             is_testnet=config.testnet,
             is_us=config.us,
+            retry_config=config.http_retry,
         )
 
         default_base_url_ws: str = _get_ws_base_url(
@@ -22,6 +22,7 @@ from nautilus_trader.adapters.binance.common.enums import BinanceAccountType
 from nautilus_trader.adapters.binance.config import BinanceDataClientConfig
 from nautilus_trader.adapters.binance.futures.enums import BinanceFuturesEnumParser
 from nautilus_trader.adapters.binance.futures.http.market import BinanceFuturesMarketHttpAPI
+from nautilus_trader.adapters.binance.futures.schemas.market import BinanceFuturesForceOrderMsg
 from nautilus_trader.adapters.binance.futures.schemas.market import BinanceFuturesMarkPriceMsg
 from nautilus_trader.adapters.binance.futures.schemas.market import BinanceFuturesTradeMsg
 from nautilus_trader.adapters.binance.futures.types import BinanceFuturesMarkPriceUpdate
@@ -106,10 +107,20 @@ class BinanceFuturesDataClient(BinanceCommonDataClient):
 
         # Register additional futures websocket handlers
         self._ws_handlers["@markPrice"] = self._handle_mark_price
+        self._ws_handlers["@forceOrder"] = self._handle_force_order
 
         # Websocket msgspec decoders
         self._decoder_futures_trade_msg = msgspec.json.Decoder(BinanceFuturesTradeMsg)
         self._decoder_futures_mark_price_msg = msgspec.json.Decoder(BinanceFuturesMarkPriceMsg)
+        self._decoder_futures_force_order_msg = msgspec.json.Decoder(BinanceFuturesForceOrderMsg)
+
+    # -- SUBSCRIPTIONS ----------------------------------------------------------------------------
+
+    async def _subscribe_liquidations(self, instrument_id: InstrumentId) -> None:
+        await self._ws_client.subscribe_force_orders(instrument_id.symbol.value)
+
+    async def _unsubscribe_liquidations(self, instrument_id: InstrumentId) -> None:
+        await self._ws_client.unsubscribe_force_orders(instrument_id.symbol.value)
 
     # -- WEBSOCKET HANDLERS ---------------------------------------------------------------------------------
 
@@ -152,3 +163,12 @@ class BinanceFuturesDataClient(BinanceCommonDataClient):
         )
         generic = CustomData(data_type=data_type, data=data)
         self._handle_data(generic)
+
+    def _handle_force_order(self, raw: bytes) -> None:
+        msg = self._decoder_futures_force_order_msg.decode(raw)
+        instrument_id: InstrumentId = self._get_cached_instrument_id(msg.data.o.s)
+        liquidation = msg.data.parse_to_liquidation_tick(
+            instrument_id=instrument_id,
+            ts_init=self._clock.timestamp_ns(),
+        )
+        self._handle_liquidation(liquidation)
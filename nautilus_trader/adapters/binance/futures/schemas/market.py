@@ -17,6 +17,7 @@ from decimal import Decimal
 
 import msgspec
 
+from nautilus_trader.adapters.binance.common.enums import BinanceOrderSide
 from nautilus_trader.adapters.binance.common.enums import BinanceOrderType
 from nautilus_trader.adapters.binance.common.enums import BinanceTimeInForce
 from nautilus_trader.adapters.binance.common.schemas.market import BinanceExchangeFilter
@@ -24,6 +25,7 @@ from nautilus_trader.adapters.binance.common.schemas.market import BinanceRateLi
 from nautilus_trader.adapters.binance.common.schemas.market import BinanceSymbolFilter
 from nautilus_trader.adapters.binance.futures.enums import BinanceFuturesContractStatus
 from nautilus_trader.adapters.binance.futures.types import BinanceFuturesMarkPriceUpdate
+from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.core.datetime import millis_to_nanos
 from nautilus_trader.model.data import TradeTick
 from nautilus_trader.model.enums import AggressorSide
@@ -236,3 +238,59 @@ class BinanceFuturesMarkPriceMsg(msgspec.Struct, frozen=True):
 
     stream: str
     data: BinanceFuturesMarkPriceData
+
+
+class BinanceFuturesForceOrder(msgspec.Struct, frozen=True):
+    """
+    WebSocket message 'inner inner struct' for `Binance Futures` Liquidation Order events.
+    """
+
+    s: str  # Symbol
+    S: BinanceOrderSide  # Side
+    o: BinanceOrderType  # Order type
+    f: BinanceTimeInForce  # Time in force
+    q: str  # Original quantity
+    p: str  # Price
+    ap: str  # Average price
+    X: str  # Order status
+    l: str  # Order last filled quantity
+    z: str  # Order filled accumulated quantity
+    T: int  # Order trade time
+
+
+class BinanceFuturesForceOrderData(msgspec.Struct, frozen=True):
+    """
+    WebSocket message 'inner struct' for `Binance Futures` Liquidation Order events.
+    """
+
+    e: str  # Event type
+    E: int  # Event time
+    o: BinanceFuturesForceOrder
+
+    def parse_to_liquidation_tick(
+        self,
+        instrument_id: InstrumentId,
+        ts_init: int,
+    ) -> nautilus_pyo3.LiquidationTick:
+        # Use the average fill price and accumulated filled quantity of the liquidation order
+        return nautilus_pyo3.LiquidationTick(
+            instrument_id=nautilus_pyo3.InstrumentId.from_str(instrument_id.value),
+            side=(
+                nautilus_pyo3.OrderSide.BUY
+                if self.o.S == BinanceOrderSide.BUY
+                else nautilus_pyo3.OrderSide.SELL
+            ),
+            price=nautilus_pyo3.Price.from_str(self.o.ap),
+            size=nautilus_pyo3.Quantity.from_str(self.o.z),
+            ts_event=millis_to_nanos(self.o.T),
+            ts_init=ts_init,
+        )
+
+
+class BinanceFuturesForceOrderMsg(msgspec.Struct, frozen=True):
+    """
+    WebSocket message from `Binance Futures` Liquidation Order events.
+    """
+
+    stream: str
+    data: BinanceFuturesForceOrderData
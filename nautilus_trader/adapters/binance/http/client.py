@@ -21,6 +21,8 @@ from typing import Any
 import msgspec
 
 import nautilus_trader
+from nautilus_trader.adapters.binance.common.constants import BINANCE_REQUEST_WEIGHT_KEY
+from nautilus_trader.adapters.binance.common.constants import BINANCE_USED_WEIGHT_HEADER
 from nautilus_trader.adapters.binance.http.error import BinanceClientError
 from nautilus_trader.adapters.binance.http.error import BinanceServerError
 from nautilus_trader.common.component import LiveClock
@@ -29,6 +31,7 @@ from nautilus_trader.core.nautilus_pyo3 import HttpClient
 from nautilus_trader.core.nautilus_pyo3 import HttpMethod
 from nautilus_trader.core.nautilus_pyo3 import HttpResponse
 from nautilus_trader.core.nautilus_pyo3 import Quota
+from nautilus_trader.core.nautilus_pyo3 import RetryPolicy
 
 
 class BinanceHttpClient:
@@ -49,6 +52,8 @@ class BinanceHttpClient:
         The keyed rate limiter quotas for the client.
     ratelimiter_quota : Quota, optional
         The default rate limiter quota for the client.
+    retry_policy : RetryPolicy, optional
+        The policy for retrying failed requests (no retries if ``None``).
 
     """
 
@@ -60,6 +65,7 @@ class BinanceHttpClient:
         base_url: str,
         ratelimiter_quotas: list[tuple[str, Quota]] | None = None,
         ratelimiter_default_quota: Quota | None = None,
+        retry_policy: RetryPolicy | None = None,
     ) -> None:
         self._clock: LiveClock = clock
         self._log: Logger = Logger(type(self).__name__)
@@ -75,6 +81,8 @@ class BinanceHttpClient:
         self._client = HttpClient(
             keyed_quotas=ratelimiter_quotas or [],
             default_quota=ratelimiter_default_quota,
+            retry_policy=retry_policy,
+            used_weight_headers=[(BINANCE_USED_WEIGHT_HEADER, BINANCE_REQUEST_WEIGHT_KEY)],
         )
 
     @property
@@ -127,6 +135,7 @@ class BinanceHttpClient:
         url_path: str,
         payload: dict[str, str] | None = None,
         ratelimiter_keys: list[str] | None = None,
+        ratelimiter_weight: int | None = None,
     ) -> Any:
         if payload is None:
             payload = {}
@@ -138,6 +147,7 @@ class BinanceHttpClient:
             url_path,
             payload=payload,
             ratelimiter_keys=ratelimiter_keys,
+            ratelimiter_weight=ratelimiter_weight,
         )
 
     async def send_request(
@@ -146,6 +156,7 @@ class BinanceHttpClient:
         url_path: str,
         payload: dict[str, str] | None = None,
         ratelimiter_keys: list[str] | None = None,
+        ratelimiter_weight: int | None = None,
     ) -> bytes:
         if payload:
             url_path += "?" + urllib.parse.urlencode(payload)
@@ -157,6 +168,7 @@ class BinanceHttpClient:
             headers=self._headers,
             body=msgspec.json.encode(payload) if payload else None,
             keys=ratelimiter_keys,
+            weight=ratelimiter_weight,
         )
 
         if 400 <= response.status < 500:
@@ -17,6 +17,7 @@ from typing import Any
 
 import msgspec
 
+from nautilus_trader.adapters.binance.common.constants import BINANCE_REQUEST_WEIGHT_KEY
 from nautilus_trader.adapters.binance.common.enums import BinanceSecurityType
 from nautilus_trader.adapters.binance.common.schemas.symbol import BinanceSymbol
 from nautilus_trader.adapters.binance.common.schemas.symbol import BinanceSymbols
@@ -41,6 +42,8 @@ class BinanceHttpEndpoint:
     --------
     This class should not be used directly, but through a concrete subclass.
 
+    Each request counts its weight (default 1) against the request weight rate limit.
+
     """
 
     def __init__(
@@ -48,10 +51,12 @@ class BinanceHttpEndpoint:
         client: BinanceHttpClient,
         methods_desc: dict[HttpMethod, BinanceSecurityType],
         url_path: str,
+        weights: dict[HttpMethod, int] | None = None,
     ):
         self.client = client
         self.methods_desc = methods_desc
         self.url_path = url_path
+        self.weights = weights or {}
 
         self.decoder = msgspec.json.Decoder()
         self.encoder = msgspec.json.Encoder(enc_hook=enc_hook)
@@ -80,6 +85,7 @@ class BinanceHttpEndpoint:
             http_method=method_type,
             url_path=self.url_path,
             payload=payload,
-            ratelimiter_keys=ratelimiter_keys,
+            ratelimiter_keys=[BINANCE_REQUEST_WEIGHT_KEY, *(ratelimiter_keys or [])],
+            ratelimiter_weight=self.weights.get(method_type, 1),
         )
         return raw
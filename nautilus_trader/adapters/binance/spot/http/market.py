@@ -54,6 +54,7 @@ class BinanceSpotExchangeInfoHttp(BinanceHttpEndpoint):
             client,
             methods,
             url_path,
+            weights={HttpMethod.GET: 20},
         )
         self._get_resp_decoder = msgspec.json.Decoder(BinanceSpotExchangeInfo)
 
@@ -461,6 +461,32 @@ class BinanceWebSocketClient:
             stream = f"{BinanceSymbol(symbol).lower()}@markPrice@{int(speed / 1000)}s"
         await self._unsubscribe(stream)
 
+    async def subscribe_force_orders(self, symbol: str | None = None) -> None:
+        """
+        Subscribe to liquidation order stream.
+
+        The Liquidation Order Snapshot Streams push force liquidation order information
+        for specific symbol, or for all symbols when `symbol` is None.
+        Stream Name: <symbol>@forceOrder
+        Update Speed: 1000ms
+
+        """
+        if symbol is None:
+            stream = "!forceOrder@arr"
+        else:
+            stream = f"{BinanceSymbol(symbol).lower()}@forceOrder"
+        await self._subscribe(stream)
+
+    async def unsubscribe_force_orders(self, symbol: str | None = None) -> None:
+        """
+        Unsubscribe from liquidation order stream.
+        """
+        if symbol is None:
+            stream = "!forceOrder@arr"
+        else:
+            stream = f"{BinanceSymbol(symbol).lower()}@forceOrder"
+        await self._unsubscribe(stream)
+
     async def _subscribe(self, stream: str) -> None:
         if stream in self._streams:
             self._log.warning(f"Cannot subscribe to {stream}: already subscribed.")
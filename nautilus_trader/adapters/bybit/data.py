@@ -27,6 +27,7 @@ from nautilus_trader.adapters.bybit.http.market import BybitMarketHttpAPI
 from nautilus_trader.adapters.bybit.schemas.market.ticker import BybitTickerData
 from nautilus_trader.adapters.bybit.schemas.symbol import BybitSymbol
 from nautilus_trader.adapters.bybit.schemas.ws import BybitWsMessageGeneral
+from nautilus_trader.adapters.bybit.schemas.ws import decoder_ws_liquidation
 from nautilus_trader.adapters.bybit.schemas.ws import decoder_ws_ticker
 from nautilus_trader.adapters.bybit.schemas.ws import decoder_ws_trade
 from nautilus_trader.adapters.bybit.utils import get_api_key
@@ -103,6 +104,7 @@ class BybitDataClient(LiveMarketDataClient):
             self._decoders = {
                 "trade": decoder_ws_trade(),
                 "ticker": decoder_ws_ticker(instrument_type),
+                "liquidation": decoder_ws_liquidation(),
             }
             self._decoder_ws_msg_general = msgspec.json.Decoder(BybitWsMessageGeneral)
 
@@ -191,6 +193,12 @@ class BybitDataClient(LiveMarketDataClient):
         await ws_client.subscribe_trades(symbol.raw_symbol)
         self._log.info(f"Subscribed to trade ticks for {instrument_id}.")
 
+    async def _subscribe_liquidations(self, instrument_id: InstrumentId) -> None:
+        symbol = BybitSymbol(instrument_id.symbol.value)
+        ws_client = self._ws_clients[symbol.instrument_type]
+        await ws_client.subscribe_liquidations(symbol.raw_symbol)
+        self._log.info(f"Subscribed to liquidations for {instrument_id}.")
+
     # async def _subscribe_ticker(self, instrument_id: InstrumentId) -> None:
     #     symbol = BybitSymbol(instrument_id.symbol.value)
     #     ws_client = self._ws_clients[symbol.instrument_type]
@@ -226,6 +234,20 @@ class BybitDataClient(LiveMarketDataClient):
             decoded_raw = raw.decode("utf-8")
             self._log.error(f"Failed to parse trade tick: {decoded_raw}")
 
+    def _handle_ws_liquidation(self, instrument_type: BybitInstrumentType, raw: bytes) -> None:
+        try:
+            msg = self._decoders["liquidation"].decode(raw)
+            symbol = msg.data.symbol + f"-{instrument_type.value.upper()}"
+            instrument_id: InstrumentId = self._get_cached_instrument_id(symbol)
+            liquidation = msg.data.parse_to_liquidation_tick(
+                instrument_id,
+                self._clock.timestamp_ns(),
+            )
+            self._handle_liquidation(liquidation)
+        except Exception:
+            decoded_raw = raw.decode("utf-8")
+            self._log.error(f"Failed to parse liquidation: {decoded_raw}")
+
     def _handle_ticker(self, instrument_type: BybitInstrumentType, raw: bytes) -> None:
         try:
             self._decoders["ticker"].decode(raw)
@@ -237,6 +259,8 @@ class BybitDataClient(LiveMarketDataClient):
             self._handle_trade(instrument_type, raw)
         elif "tickers" in topic:
             self._handle_ticker(instrument_type, raw)
+        elif "liquidation" in topic:
+            self._handle_ws_liquidation(instrument_type, raw)
         else:
             self._log.error(f"Unknown websocket message topic: {topic} in Bybit")
 
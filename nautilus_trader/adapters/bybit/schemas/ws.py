@@ -23,6 +23,7 @@ from nautilus_trader.adapters.bybit.common.enums import BybitOrderStatus
 from nautilus_trader.adapters.bybit.common.enums import BybitOrderType
 from nautilus_trader.adapters.bybit.common.enums import BybitPositionIdx
 from nautilus_trader.adapters.bybit.common.enums import BybitTimeInForce
+from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.core.datetime import millis_to_nanos
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.execution.reports import OrderStatusReport
@@ -83,6 +84,25 @@ class BybitWsLiquidation(msgspec.Struct):
     symbol: str
     updatedTime: int
 
+    def parse_to_liquidation_tick(
+        self,
+        instrument_id: InstrumentId,
+        ts_init: int,
+    ) -> nautilus_pyo3.LiquidationTick:
+        # A `Buy` liquidation closes out a short position, and vice versa
+        return nautilus_pyo3.LiquidationTick(
+            instrument_id=nautilus_pyo3.InstrumentId.from_str(instrument_id.value),
+            side=(
+                nautilus_pyo3.OrderSide.BUY
+                if self.side == BybitOrderSide.BUY
+                else nautilus_pyo3.OrderSide.SELL
+            ),
+            price=nautilus_pyo3.Price.from_str(self.price),
+            size=nautilus_pyo3.Quantity.from_str(self.size),
+            ts_event=millis_to_nanos(self.updatedTime),
+            ts_init=ts_init,
+        )
+
 
 class BybitWsLiquidationMsg(msgspec.Struct):
     topic: str
@@ -91,6 +111,10 @@ class BybitWsLiquidationMsg(msgspec.Struct):
     data: BybitWsLiquidation
 
 
+def decoder_ws_liquidation():
+    return msgspec.json.Decoder(BybitWsLiquidationMsg)
+
+
 ################################################################################
 # Public - Orderbook Delta
 ################################################################################
@@ -88,6 +88,16 @@ class BybitWebsocketClient:
         await self._client.send_text(json.dumps(sub))
         self._subscriptions.append(subscription)
 
+    async def subscribe_liquidations(self, symbol: str) -> None:
+        if self._client is None:
+            self._log.warning("Cannot subscribe: not connected.")
+            return
+
+        subscription = f"liquidation.{symbol}"
+        sub = {"op": "subscribe", "args": [subscription]}
+        await self._client.send_text(json.dumps(sub))
+        self._subscriptions.append(subscription)
+
     ################################################################################
     # Private
     ################################################################################
@@ -35,6 +35,7 @@ from nautilus_trader.adapters.interactive_brokers.parsing.execution import MAP_O
 from nautilus_trader.adapters.interactive_brokers.parsing.execution import MAP_ORDER_FIELDS
 from nautilus_trader.adapters.interactive_brokers.parsing.execution import MAP_ORDER_STATUS
 from nautilus_trader.adapters.interactive_brokers.parsing.execution import MAP_ORDER_TYPE
+from nautilus_trader.adapters.interactive_brokers.parsing.execution import MAP_PEG_ORDER_TYPE
 from nautilus_trader.adapters.interactive_brokers.parsing.execution import MAP_TIME_IN_FORCE
 from nautilus_trader.adapters.interactive_brokers.parsing.execution import MAP_TRIGGER_METHOD
 from nautilus_trader.adapters.interactive_brokers.parsing.execution import ORDER_SIDE_TO_ORDER_ACTION
@@ -62,6 +63,7 @@ from nautilus_trader.model.enums import OmsType
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.enums import OrderStatus
 from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import PegPriceType
 from nautilus_trader.model.enums import PositionSide
 from nautilus_trader.model.enums import TimeInForce
 from nautilus_trader.model.enums import TrailingOffsetType
@@ -79,6 +81,7 @@ from nautilus_trader.model.objects import Money
 from nautilus_trader.model.objects import Price
 from nautilus_trader.model.objects import Quantity
 from nautilus_trader.model.orders.base import Order
+from nautilus_trader.model.orders.limit import LimitOrder
 from nautilus_trader.model.orders.limit_if_touched import LimitIfTouchedOrder
 from nautilus_trader.model.orders.market_if_touched import MarketIfTouchedOrder
 from nautilus_trader.model.orders.stop_limit import StopLimitOrder
@@ -514,6 +517,10 @@ class InteractiveBrokersExecutionClient(LiveExecutionClient):
             )
         ) and order.trigger_price:
             ib_order.auxPrice = order.trigger_price.as_double()
+        elif isinstance(order, LimitOrder) and order.peg_price_type != PegPriceType.NO_PEG:
+            ib_order.orderType = MAP_PEG_ORDER_TYPE[order.peg_price_type]
+            if order.peg_offset is not None:
+                ib_order.auxPrice = order.peg_offset.as_double()
 
         details = self.instrument_provider.contract_details[order.instrument_id.value]
         ib_order.contract = details.contract
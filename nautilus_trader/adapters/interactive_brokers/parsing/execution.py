@@ -20,6 +20,7 @@ import pandas as pd
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.enums import OrderStatus
 from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import PegPriceType
 from nautilus_trader.model.enums import TimeInForce
 from nautilus_trader.model.enums import TriggerType
 
@@ -66,6 +67,11 @@ MAP_ORDER_TYPE: dict[int, str] = {
     OrderType.TRAILING_STOP_MARKET: "TRAIL",
 }
 
+MAP_PEG_ORDER_TYPE: dict[int, str] = {
+    PegPriceType.MID_POINT: "PEG MID",
+    PegPriceType.PRIMARY: "REL",
+}
+
 
 MAP_ORDER_FIELDS: set[tuple[str, str, Callable]] = {
     # ref: (nautilus_order_field, ib_order_field, value_fn)
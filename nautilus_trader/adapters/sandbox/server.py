@@ -0,0 +1,324 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import asyncio
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.common.actor import Actor
+from nautilus_trader.common.config import ActorConfig
+from nautilus_trader.common.factories import OrderFactory
+from nautilus_trader.core.nautilus_pyo3 import WebSocketServer
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.messages import CancelOrder
+from nautilus_trader.execution.messages import SubmitOrder
+from nautilus_trader.model.book import OrderBook
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import BookType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import order_side_from_str
+from nautilus_trader.model.enums import time_in_force_from_str
+from nautilus_trader.model.events import OrderEvent
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+class SandboxVenueServerConfig(ActorConfig, frozen=True):
+    """
+    Configuration for ``SandboxVenueServer`` instances.
+
+    Parameters
+    ----------
+    host : str, default '127.0.0.1'
+        The host address to listen on.
+    port : int, default 0
+        The port to listen on (0 to have one assigned by the OS).
+    book_depth : int, default 10
+        The number of levels per side in published order book snapshots.
+    book_interval_ms : int, default 1000
+        The interval between published order book snapshots.
+
+    """
+
+    host: str = "127.0.0.1"
+    port: int = 0
+    book_depth: int = 10
+    book_interval_ms: int = 1000
+
+
+class SandboxVenueServer(Actor):
+    """
+    Provides a WebSocket server exposing the sandbox (paper) venue to external
+    processes, which can submit and cancel orders, and stream order events,
+    order book snapshots and trades.
+
+    Messages are JSON objects with an ``op`` field, and an optional ``id`` field
+    which is echoed in the response:
+
+    - ``{"op": "submit_order", "instrument_id": ..., "side": "BUY", "quantity": "1",
+      "price": "100.0", "time_in_force": "GTC"}`` (a limit order when ``price`` is
+      given, otherwise a market order).
+    - ``{"op": "cancel_order", "instrument_id": ..., "client_order_id": ...}``.
+    - ``{"op": "subscribe", "topic": "book.<instrument_id>"}`` or
+      ``"trades.<instrument_id>"`` (and ``unsubscribe``).
+
+    Each client trades under its own strategy ID ``EXTERNAL-<client_id>``, and
+    receives the order events for its orders as ``{"type": "order_event", ...}``.
+
+    Parameters
+    ----------
+    config : SandboxVenueServerConfig
+        The configuration for the instance.
+
+    """
+
+    def __init__(self, config: SandboxVenueServerConfig) -> None:
+        super().__init__(config)
+        self._host = config.host
+        self._port = config.port
+        self._book_depth = config.book_depth
+        self._book_interval_ms = config.book_interval_ms
+        self._loop: asyncio.AbstractEventLoop | None = None
+        self._server: WebSocketServer | None = None
+        self._order_factories: dict[int, OrderFactory] = {}
+        self._subscribers: dict[str, set[int]] = {}
+
+    @property
+    def local_addr(self) -> str | None:
+        """
+        Return the address the server is listening on (if started).
+
+        Returns
+        -------
+        str or ``None``
+
+        """
+        return self._server.local_addr if self._server is not None else None
+
+    def on_start(self) -> None:
+        self._loop = asyncio.get_event_loop()
+        self._loop.create_task(self._bind())
+
+    def on_stop(self) -> None:
+        if self._server is not None:
+            self._server.shutdown()
+            self._server = None
+        for client_id in list(self._order_factories):
+            self._remove_client(client_id)
+
+    async def _bind(self) -> None:
+        self._server = await WebSocketServer.bind(
+            f"{self._host}:{self._port}",
+            handler=self._handle_threadsafe,
+            on_connect=self._on_connect_threadsafe,
+            on_disconnect=self._on_disconnect_threadsafe,
+        )
+        self.log.info(f"Sandbox venue server listening on {self._server.local_addr}.")
+
+    # -- SERVER CALLBACKS (called on the network thread) -------------------------------------
+
+    def _handle_threadsafe(self, client_id: int, message: str) -> None:
+        self._loop.call_soon_threadsafe(self._handle_message, client_id, message)
+
+    def _on_connect_threadsafe(self, client_id: int, peer: str) -> None:
+        self._loop.call_soon_threadsafe(self._add_client, client_id, peer)
+
+    def _on_disconnect_threadsafe(self, client_id: int) -> None:
+        self._loop.call_soon_threadsafe(self._remove_client, client_id)
+
+    # -- CLIENTS -----------------------------------------------------------------------------
+
+    def _add_client(self, client_id: int, peer: str) -> None:
+        strategy_id = StrategyId(f"EXTERNAL-{client_id}")
+        self._order_factories[client_id] = OrderFactory(
+            trader_id=self.trader_id,
+            strategy_id=strategy_id,
+            clock=self.clock,
+        )
+        self.msgbus.subscribe(
+            topic=f"events.order.{strategy_id}",
+            handler=self._handle_order_event,
+        )
+        self.log.info(f"Client {client_id} connected from {peer}.")
+
+    def _remove_client(self, client_id: int) -> None:
+        factory = self._order_factories.pop(client_id, None)
+        if factory is None:
+            return
+        self.msgbus.unsubscribe(
+            topic=f"events.order.{factory.strategy_id}",
+            handler=self._handle_order_event,
+        )
+        for topic in list(self._subscribers):
+            self._unsubscribe(client_id, topic)
+        self.log.info(f"Client {client_id} disconnected.")
+
+    def _send(self, client_id: int, message: dict[str, Any]) -> None:
+        if self._server is not None:
+            self._server.send(client_id, msgspec.json.encode(message).decode())
+
+    def _publish(self, topic: str, message: dict[str, Any]) -> None:
+        if self._server is not None:
+            self._server.publish(topic, msgspec.json.encode(message).decode())
+
+    # -- REQUESTS ----------------------------------------------------------------------------
+
+    def _handle_message(self, client_id: int, message: str) -> None:
+        request: dict[str, Any] = {}
+        try:
+            request = msgspec.json.decode(message)
+            op = request["op"]
+            if op == "submit_order":
+                result = self._submit_order(client_id, request)
+            elif op == "cancel_order":
+                result = self._cancel_order(client_id, request)
+            elif op == "subscribe":
+                result = self._subscribe(client_id, request["topic"])
+            elif op == "unsubscribe":
+                result = self._unsubscribe(client_id, request["topic"])
+            else:
+                raise ValueError(f"unrecognized op '{op}'")
+        except Exception as e:
+            self.log.warning(f"Invalid request from client {client_id}: {e!r}.")
+            self._send(client_id, {"type": "error", "id": request.get("id"), "error": str(e)})
+            return
+
+        self._send(client_id, {"type": "response", "id": request.get("id"), **result})
+
+    def _submit_order(self, client_id: int, request: dict[str, Any]) -> dict[str, Any]:
+        factory = self._order_factories[client_id]
+        instrument_id = InstrumentId.from_str(request["instrument_id"])
+        side: OrderSide = order_side_from_str(request["side"])
+        quantity = Quantity.from_str(str(request["quantity"]))
+        price = request.get("price")
+
+        if price is None:
+            order = factory.market(
+                instrument_id=instrument_id,
+                order_side=side,
+                quantity=quantity,
+            )
+        else:
+            tif: TimeInForce = time_in_force_from_str(request.get("time_in_force", "GTC"))
+            order = factory.limit(
+                instrument_id=instrument_id,
+                order_side=side,
+                quantity=quantity,
+                price=Price.from_str(str(price)),
+                time_in_force=tif,
+                post_only=request.get("post_only", False),
+            )
+
+        command = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=factory.strategy_id,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+        self.msgbus.send(endpoint="RiskEngine.execute", msg=command)
+        return {"client_order_id": order.client_order_id.value}
+
+    def _cancel_order(self, client_id: int, request: dict[str, Any]) -> dict[str, Any]:
+        factory = self._order_factories[client_id]
+        client_order_id = ClientOrderId(request["client_order_id"])
+        order = self.cache.order(client_order_id)
+        if order is None or order.strategy_id != factory.strategy_id:
+            raise ValueError(f"order {client_order_id!r} not found")
+
+        command = CancelOrder(
+            trader_id=self.trader_id,
+            strategy_id=factory.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=client_order_id,
+            venue_order_id=order.venue_order_id,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+        self.msgbus.send(endpoint="ExecEngine.execute", msg=command)
+        return {"client_order_id": client_order_id.value}
+
+    def _subscribe(self, client_id: int, topic: str) -> dict[str, Any]:
+        kind, instrument_id = self._parse_topic(topic)
+        subscribers = self._subscribers.setdefault(topic, set())
+        if not subscribers:
+            if kind == "book":
+                self.subscribe_order_book_snapshots(
+                    instrument_id=instrument_id,
+                    book_type=BookType.L2_MBP,
+                    depth=self._book_depth,
+                    interval_ms=self._book_interval_ms,
+                )
+            else:
+                self.subscribe_trade_ticks(instrument_id)
+        subscribers.add(client_id)
+        self._server.subscribe(client_id, topic)
+        return {"topic": topic}
+
+    def _unsubscribe(self, client_id: int, topic: str) -> dict[str, Any]:
+        kind, instrument_id = self._parse_topic(topic)
+        subscribers = self._subscribers.get(topic)
+        if subscribers is not None and client_id in subscribers:
+            subscribers.discard(client_id)
+            if not subscribers:
+                del self._subscribers[topic]
+                if kind == "book":
+                    self.unsubscribe_order_book_snapshots(
+                        instrument_id=instrument_id,
+                        interval_ms=self._book_interval_ms,
+                    )
+                else:
+                    self.unsubscribe_trade_ticks(instrument_id)
+        if self._server is not None:
+            self._server.unsubscribe(client_id, topic)
+        return {"topic": topic}
+
+    def _parse_topic(self, topic: str) -> tuple[str, InstrumentId]:
+        kind, _, instrument_id = topic.partition(".")
+        if kind not in ("book", "trades"):
+            raise ValueError(f"unrecognized topic '{topic}'")
+        return kind, InstrumentId.from_str(instrument_id)
+
+    # -- EVENTS ------------------------------------------------------------------------------
+
+    def _handle_order_event(self, event: OrderEvent) -> None:
+        client_id = int(event.strategy_id.get_tag())
+        self._send(client_id, {"type": "order_event", "event": type(event).to_dict(event)})
+
+    def on_order_book(self, order_book: OrderBook) -> None:
+        self._publish(
+            f"book.{order_book.instrument_id}",
+            {
+                "type": "book",
+                "instrument_id": order_book.instrument_id.value,
+                "bids": [
+                    [str(level.price), str(level.size())]
+                    for level in order_book.bids()[: self._book_depth]
+                ],
+                "asks": [
+                    [str(level.price), str(level.size())]
+                    for level in order_book.asks()[: self._book_depth]
+                ],
+                "ts_event": order_book.ts_last,
+            },
+        )
+
+    def on_trade_tick(self, tick: TradeTick) -> None:
+        self._publish(f"trades.{tick.instrument_id}", {**TradeTick.to_dict(tick), "type": "trade"})
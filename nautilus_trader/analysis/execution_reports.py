@@ -0,0 +1,339 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from collections.abc import Iterable
+from typing import Any
+
+import pandas as pd
+
+from nautilus_trader.core.datetime import unix_nanos_to_dt
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import liquidity_side_to_str
+from nautilus_trader.model.enums import order_side_to_str
+from nautilus_trader.model.enums import order_type_to_str
+from nautilus_trader.model.enums import position_side_to_str
+from nautilus_trader.model.events import OrderFilled
+from nautilus_trader.model.events import OrderInitialized
+from nautilus_trader.model.events import PositionEvent
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.persistence.catalog.parquet import ParquetDataCatalog
+
+
+SOH = "\x01"
+
+FIX_SIDE: dict[OrderSide, str] = {
+    OrderSide.BUY: "1",
+    OrderSide.SELL: "2",
+}
+
+FIX_ORD_TYPE: dict[OrderType, str] = {
+    OrderType.MARKET: "1",
+    OrderType.LIMIT: "2",
+    OrderType.STOP_MARKET: "3",
+    OrderType.STOP_LIMIT: "4",
+    OrderType.MARKET_TO_LIMIT: "K",
+}
+
+FIX_LAST_LIQUIDITY_IND: dict[LiquiditySide, str] = {
+    LiquiditySide.MAKER: "1",
+    LiquiditySide.TAKER: "2",
+}
+
+
+class ExecutionReportExporter:
+    """
+    Provides an exporter of order fill events to standardized execution reports.
+
+    Each `OrderFilled` event becomes one execution report, enriched with the
+    resulting position state from the matching `PositionEvent` (if any). The
+    reports can be written as CSV, or as FIX 4.4 ExecutionReport (35=8) messages
+    for compliance archiving.
+
+    Parameters
+    ----------
+    sender_comp_id : str, default 'NAUTILUS'
+        The FIX SenderCompID (49) for generated messages.
+    target_comp_id : str, default 'ARCHIVE'
+        The FIX TargetCompID (56) for generated messages.
+
+    """
+
+    def __init__(
+        self,
+        sender_comp_id: str = "NAUTILUS",
+        target_comp_id: str = "ARCHIVE",
+    ) -> None:
+        self.sender_comp_id = sender_comp_id
+        self.target_comp_id = target_comp_id
+
+    @staticmethod
+    def load_events(
+        catalog: ParquetDataCatalog,
+        instance_id: str,
+        kind: str = "backtest",
+    ) -> list[Any]:
+        """
+        Load the recorded events for a run from the given catalog.
+
+        Parameters
+        ----------
+        catalog : ParquetDataCatalog
+            The catalog containing the recorded run.
+        instance_id : str
+            The instance ID of the run.
+        kind : str, {'backtest', 'live'}
+            The kind of run.
+
+        Returns
+        -------
+        list[Any]
+
+        """
+        if kind == "backtest":
+            return catalog.read_backtest(instance_id=instance_id)
+        elif kind == "live":
+            return catalog.read_live_run(instance_id=instance_id)
+        else:
+            raise ValueError(f"Invalid `kind`, was '{kind}'")
+
+    def generate_report(self, events: Iterable[Any]) -> pd.DataFrame:
+        """
+        Generate an execution reports dataframe from the given events.
+
+        This report provides a row per order fill. Events other than
+        `OrderInitialized`, `OrderFilled` and `PositionEvent` are ignored.
+
+        Parameters
+        ----------
+        events : Iterable[Any]
+            The events to generate the report from.
+
+        Returns
+        -------
+        pd.DataFrame
+
+        """
+        reports = [r.to_dict() for r in self._generate_reports(events)]
+        if not reports:
+            return pd.DataFrame()
+
+        return pd.DataFrame(data=reports).set_index("exec_id")
+
+    def generate_fix_messages(self, events: Iterable[Any]) -> list[str]:
+        """
+        Generate FIX 4.4 ExecutionReport (35=8) messages from the given events.
+
+        Messages are sequenced from 1 in fill order, with SOH field delimiters.
+
+        Parameters
+        ----------
+        events : Iterable[Any]
+            The events to generate the messages from.
+
+        Returns
+        -------
+        list[str]
+
+        """
+        return [
+            self._encode_fix(seq_num, report)
+            for seq_num, report in enumerate(self._generate_reports(events), start=1)
+        ]
+
+    def write_csv(self, events: Iterable[Any], path: str) -> None:
+        """
+        Write the execution reports for the given events to a CSV file.
+
+        Parameters
+        ----------
+        events : Iterable[Any]
+            The events to export.
+        path : str
+            The path of the CSV file to write.
+
+        """
+        self.generate_report(events).to_csv(path)
+
+    def write_fix(self, events: Iterable[Any], path: str) -> None:
+        """
+        Write the FIX 4.4 ExecutionReport messages for the given events to a file
+        (one message per line).
+
+        Parameters
+        ----------
+        events : Iterable[Any]
+            The events to export.
+        path : str
+            The path of the file to write.
+
+        """
+        with open(path, "w", encoding="utf-8") as f:
+            for message in self.generate_fix_messages(events):
+                f.write(message + "\n")
+
+    def _generate_reports(self, events: Iterable[Any]) -> list[_ExecutionReport]:
+        order_qtys: dict[ClientOrderId, Quantity] = {}
+        fills: list[OrderFilled] = []
+        position_events: dict[tuple, PositionEvent] = {}
+
+        for event in events:
+            if isinstance(event, OrderInitialized):
+                order_qtys[event.client_order_id] = event.quantity
+            elif isinstance(event, OrderFilled):
+                fills.append(event)
+            elif isinstance(event, PositionEvent):
+                key = (event.position_id, event.ts_event, event.last_qty, event.last_px)
+                position_events[key] = event
+
+        fills.sort(key=lambda f: f.ts_event)
+
+        reports: list[_ExecutionReport] = []
+        cum_qtys: dict[ClientOrderId, float] = {}
+        cum_notionals: dict[ClientOrderId, float] = {}
+        for fill in fills:
+            last_qty = fill.last_qty.as_double()
+            cum_qty = cum_qtys.get(fill.client_order_id, 0.0) + last_qty
+            cum_notional = cum_notionals.get(fill.client_order_id, 0.0)
+            cum_notional += last_qty * fill.last_px.as_double()
+            cum_qtys[fill.client_order_id] = cum_qty
+            cum_notionals[fill.client_order_id] = cum_notional
+
+            reports.append(
+                _ExecutionReport(
+                    fill=fill,
+                    order_qty=order_qtys.get(fill.client_order_id),
+                    cum_qty=cum_qty,
+                    avg_px=cum_notional / cum_qty,
+                    position=position_events.get(
+                        (fill.position_id, fill.ts_event, fill.last_qty, fill.last_px),
+                    ),
+                ),
+            )
+
+        return reports
+
+    def _encode_fix(self, seq_num: int, report: _ExecutionReport) -> str:
+        fill = report.fill
+        size_precision = fill.last_qty.precision
+        transact_time = _fix_timestamp(fill.ts_event)
+
+        fields: list[tuple[int, Any]] = [
+            (35, "8"),
+            (49, self.sender_comp_id),
+            (56, self.target_comp_id),
+            (34, seq_num),
+            (52, transact_time),
+            (1, fill.account_id),
+            (37, fill.venue_order_id),
+            (11, fill.client_order_id),
+            (17, fill.trade_id),
+            (150, "F"),  # Trade
+            (39, "2" if report.is_order_filled else "1"),  # Filled / Partially filled
+            (55, fill.instrument_id.symbol),
+            (207, fill.instrument_id.venue),
+            (54, FIX_SIDE[fill.order_side]),
+        ]
+        if fill.order_type in FIX_ORD_TYPE:
+            fields.append((40, FIX_ORD_TYPE[fill.order_type]))
+        if report.order_qty is not None:
+            fields.append((38, report.order_qty))
+        fields += [
+            (32, fill.last_qty),
+            (31, fill.last_px),
+            (151, f"{report.leaves_qty or 0.0:.{size_precision}f}"),
+            (14, f"{report.cum_qty:.{size_precision}f}"),
+            (6, f"{report.avg_px:.{fill.last_px.precision + 1}f}"),
+            (15, fill.currency.code),
+            (12, fill.commission.as_decimal()),
+            (13, "3"),  # Absolute commission
+            (479, fill.commission.currency.code),
+            (60, transact_time),
+        ]
+        if fill.liquidity_side in FIX_LAST_LIQUIDITY_IND:
+            fields.append((851, FIX_LAST_LIQUIDITY_IND[fill.liquidity_side]))
+        if fill.position_id is not None:
+            fields.append((58, f"position_id={fill.position_id}"))
+
+        body = "".join(f"{tag}={value}{SOH}" for tag, value in fields)
+        header = f"8=FIX.4.4{SOH}9={len(body)}{SOH}"
+        checksum = sum((header + body).encode()) % 256
+        return f"{header}{body}10={checksum:03d}{SOH}"
+
+
+class _ExecutionReport:
+    def __init__(
+        self,
+        fill: OrderFilled,
+        order_qty: Quantity | None,
+        cum_qty: float,
+        avg_px: float,
+        position: PositionEvent | None,
+    ) -> None:
+        self.fill = fill
+        self.order_qty = order_qty
+        self.cum_qty = cum_qty
+        self.avg_px = avg_px
+        self.position = position
+
+    @property
+    def leaves_qty(self) -> float | None:
+        if self.order_qty is None:
+            return None
+        return max(self.order_qty.as_double() - self.cum_qty, 0.0)
+
+    @property
+    def is_order_filled(self) -> bool:
+        return self.order_qty is not None and self.leaves_qty == 0.0
+
+    def to_dict(self) -> dict[str, Any]:
+        fill = self.fill
+        position = self.position
+        return {
+            "exec_id": fill.trade_id.value,
+            "client_order_id": fill.client_order_id.value,
+            "venue_order_id": fill.venue_order_id.value,
+            "account_id": fill.account_id.value,
+            "strategy_id": fill.strategy_id.value,
+            "instrument_id": fill.instrument_id.value,
+            "side": order_side_to_str(fill.order_side),
+            "order_type": order_type_to_str(fill.order_type),
+            "order_qty": self.order_qty.as_double() if self.order_qty is not None else None,
+            "last_qty": fill.last_qty.as_double(),
+            "last_px": fill.last_px.as_double(),
+            "cum_qty": self.cum_qty,
+            "leaves_qty": self.leaves_qty,
+            "avg_px": self.avg_px,
+            "currency": fill.currency.code,
+            "commission": fill.commission.as_double(),
+            "commission_currency": fill.commission.currency.code,
+            "liquidity_side": liquidity_side_to_str(fill.liquidity_side),
+            "position_id": fill.position_id.value if fill.position_id is not None else None,
+            "position_side": position_side_to_str(position.side) if position else None,
+            "position_signed_qty": position.signed_qty if position else None,
+            "position_avg_px_open": position.avg_px_open if position else None,
+            "position_realized_pnl": position.realized_pnl.as_double() if position else None,
+            "transact_time": unix_nanos_to_dt(fill.ts_event),
+            "ts_event": fill.ts_event,
+            "ts_init": fill.ts_init,
+        }
+
+
+def _fix_timestamp(nanos: int) -> str:
+    return unix_nanos_to_dt(nanos).strftime("%Y%m%d-%H:%M:%S.%f")[:-3]
@@ -13,6 +13,125 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from decimal import Decimal
+from typing import Any
+
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.objects import Price
+
+
+def calculate_uncrossing_price(
+    bids: list[tuple[Price | None, Decimal]],
+    asks: list[tuple[Price | None, Decimal]],
+    reference_price: Price | None = None,
+) -> tuple[Price, Decimal] | None:
+    """
+    Calculate the auction uncrossing (clearing) price and executable volume.
+
+    The clearing price is the candidate limit price which maximizes executable
+    volume. Ties are broken by the minimum surplus (imbalance), then by the price
+    closest to the `reference_price` (if given), then by the lowest price.
+
+    Parameters
+    ----------
+    bids : list[tuple[Price | None, Decimal]]
+        The bid side auction interest as (price, size), where a ``None`` price is a market order.
+    asks : list[tuple[Price | None, Decimal]]
+        The ask side auction interest as (price, size), where a ``None`` price is a market order.
+    reference_price : Price, optional
+        The reference price for tie-breaking, and for clearing only market orders.
+
+    Returns
+    -------
+    tuple[Price, Decimal] or ``None``
+        ``None`` if the auction does not cross.
+
+    """
+    candidates = sorted({price for price, _ in bids + asks if price is not None})
+    if not candidates and reference_price is not None:
+        candidates = [reference_price]
+
+    best: tuple[Price, Decimal] | None = None
+    best_key: tuple | None = None
+    for price in candidates:
+        demand = sum(
+            (size for bid_px, size in bids if is_auction_executable(bid_px, OrderSide.BUY, price)),
+            Decimal(0),
+        )
+        supply = sum(
+            (size for ask_px, size in asks if is_auction_executable(ask_px, OrderSide.SELL, price)),
+            Decimal(0),
+        )
+        volume = min(demand, supply)
+        if volume <= 0:
+            continue
+
+        distance = abs(price - reference_price) if reference_price is not None else Decimal(0)
+        key = (-volume, abs(demand - supply), distance)
+        if best_key is None or key < best_key:  # Candidates ascending so lowest price wins ties
+            best = (price, volume)
+            best_key = key
+
+    return best
+
+
+def allocate_auction_volume(
+    orders: list[tuple[Any, Price | None, Decimal]],
+    side: OrderSide,
+    price: Price,
+    volume: Decimal,
+) -> list[tuple[Any, Decimal]]:
+    """
+    Allocate the uncrossed auction `volume` to one side of the auction in priority order.
+
+    Market orders have priority, followed by limit orders in price priority, with the
+    given order of `orders` used as time priority within each price.
+
+    Parameters
+    ----------
+    orders : list[tuple[Any, Price | None, Decimal]]
+        The auction interest as (key, price, size), where a ``None`` price is a market order.
+    side : OrderSide
+        The side of the auction interest.
+    price : Price
+        The clearing price for the auction.
+    volume : Decimal
+        The executable volume to allocate.
+
+    Returns
+    -------
+    list[tuple[Any, Decimal]]
+        The allocated (key, size) for each executed order.
+
+    """
+    executable = [o for o in orders if is_auction_executable(o[1], side, price)]
+    if side == OrderSide.BUY:
+        executable.sort(key=lambda o: (o[1] is not None, -o[1] if o[1] is not None else 0))
+    else:
+        executable.sort(key=lambda o: (o[1] is not None, o[1] if o[1] is not None else 0))
+
+    allocations: list[tuple[Any, Decimal]] = []
+    remaining = volume
+    for key, _, size in executable:
+        if remaining <= 0:
+            break
+        fill_size = min(size, remaining)
+        allocations.append((key, fill_size))
+        remaining -= fill_size
+
+    return allocations
+
+
+def is_auction_executable(order_price: Price | None, side: OrderSide, price: Price) -> bool:
+    """
+    Return whether auction interest at `order_price` executes at the clearing `price`.
+    """
+    if order_price is None:
+        return True  # Market order
+    if side == OrderSide.BUY:
+        return order_price >= price
+    return order_price <= price
+
 
 # def default_auction_match(left: Ladder, right: Ladder) -> tuple[list, list]:
 #     """Match bid/ask Ladders as default auction match function."""
@@ -24,6 +24,8 @@ from nautilus_trader.common import Environment
 from nautilus_trader.common.config import ActorConfig
 from nautilus_trader.common.config import ImportableActorConfig
 from nautilus_trader.common.config import NautilusConfig
+from nautilus_trader.common.config import NonNegativeInt
+from nautilus_trader.common.config import PositiveInt
 from nautilus_trader.common.config import resolve_path
 from nautilus_trader.core.datetime import dt_to_unix_nanos
 from nautilus_trader.data.config import DataEngineConfig
@@ -221,6 +223,10 @@ class BacktestEngineConfig(NautilusKernelConfig, frozen=True):
         If logging should be bypassed.
     run_analysis : bool, default True
         If post backtest performance analysis should be run.
+    random_seed : int, optional
+        The single random seed for all stochastic simulation components of the engine.
+        If set then each venue fill model is reseeded from an independent stream derived
+        from this seed, guaranteeing bit-identical runs for the same seed.
 
     """
 
@@ -230,6 +236,7 @@ class BacktestEngineConfig(NautilusKernelConfig, frozen=True):
     risk_engine: RiskEngineConfig = RiskEngineConfig()
     exec_engine: ExecEngineConfig = ExecEngineConfig()
     run_analysis: bool = True
+    random_seed: NonNegativeInt | None = None
 
 
 class BacktestRunConfig(NautilusConfig, frozen=True):
@@ -278,3 +285,24 @@ class FXRolloverInterestConfig(SimulationModuleConfig, frozen=True):
     """
 
     rate_data: pd.DataFrame  # TODO(cs): This could probably just become JSON data
+
+
+class FundingPaymentConfig(SimulationModuleConfig, frozen=True):
+    """
+    Provides a perpetual swap funding payment simulation module.
+
+    Parameters
+    ----------
+    rate_data : pd.DataFrame, optional
+        The recorded funding rates, indexed by timestamp (UTC) with columns
+        'instrument_id' and 'rate'. The latest rate at each funding time is applied.
+    default_rate : float, default 0.0
+        The funding rate applied when no recorded rate is available.
+    funding_interval_hours : PositiveInt, default 8
+        The interval between funding payments (aligned to UTC midnight).
+
+    """
+
+    rate_data: pd.DataFrame | None = None
+    default_rate: float = 0.0
+    funding_interval_hours: PositiveInt = 8
@@ -232,6 +232,45 @@ cdef class BacktestMarketDataClient(MarketDataClient):
         self._add_subscription_trade_ticks(instrument_id)
         # Do nothing else for backtest
 
+    cpdef void subscribe_funding_rates(self, InstrumentId instrument_id):
+        Condition.not_none(instrument_id, "instrument_id")
+
+        if not self._cache.instrument(instrument_id):
+            self._log.error(
+                f"Cannot find instrument {instrument_id} to subscribe for `FundingRateUpdate` data. "
+                "No data has been loaded for this instrument.",
+            )
+            return
+
+        self._add_subscription_funding_rates(instrument_id)
+        # Do nothing else for backtest
+
+    cpdef void subscribe_liquidations(self, InstrumentId instrument_id):
+        Condition.not_none(instrument_id, "instrument_id")
+
+        if not self._cache.instrument(instrument_id):
+            self._log.error(
+                f"Cannot find instrument {instrument_id} to subscribe for `LiquidationTick` data. "
+                "No data has been loaded for this instrument.",
+            )
+            return
+
+        self._add_subscription_liquidations(instrument_id)
+        # Do nothing else for backtest
+
+    cpdef void subscribe_open_interest(self, InstrumentId instrument_id):
+        Condition.not_none(instrument_id, "instrument_id")
+
+        if not self._cache.instrument(instrument_id):
+            self._log.error(
+                f"Cannot find instrument {instrument_id} to subscribe for `OpenInterestUpdate` data. "
+                "No data has been loaded for this instrument.",
+            )
+            return
+
+        self._add_subscription_open_interest(instrument_id)
+        # Do nothing else for backtest
+
     cpdef void subscribe_bars(self, BarType bar_type):
         Condition.not_none(bar_type, "bar_type")
 
@@ -297,6 +336,24 @@ cdef class BacktestMarketDataClient(MarketDataClient):
         self._remove_subscription_trade_ticks(instrument_id)
         # Do nothing else for backtest
 
+    cpdef void unsubscribe_funding_rates(self, InstrumentId instrument_id):
+        Condition.not_none(instrument_id, "instrument_id")
+
+        self._remove_subscription_funding_rates(instrument_id)
+        # Do nothing else for backtest
+
+    cpdef void unsubscribe_liquidations(self, InstrumentId instrument_id):
+        Condition.not_none(instrument_id, "instrument_id")
+
+        self._remove_subscription_liquidations(instrument_id)
+        # Do nothing else for backtest
+
+    cpdef void unsubscribe_open_interest(self, InstrumentId instrument_id):
+        Condition.not_none(instrument_id, "instrument_id")
+
+        self._remove_subscription_open_interest(instrument_id)
+        # Do nothing else for backtest
+
     cpdef void unsubscribe_bars(self, BarType bar_type):
         Condition.not_none(bar_type, "bar_type")
 
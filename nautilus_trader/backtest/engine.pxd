@@ -17,13 +17,17 @@ from cpython.datetime cimport datetime
 from libc.stdint cimport uint64_t
 
 from nautilus_trader.backtest.exchange cimport SimulatedExchange
+from nautilus_trader.backtest.models cimport FillModel
+from nautilus_trader.backtest.models cimport LatencyModel
 from nautilus_trader.common.component cimport Clock
 from nautilus_trader.common.component cimport Logger
+from nautilus_trader.common.component cimport RandomGenerator
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.rust.backtest cimport TimeEventAccumulatorAPI
 from nautilus_trader.core.rust.core cimport CVec
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.data.engine cimport DataEngine
+from nautilus_trader.model.identifiers cimport Venue
 
 
 cdef class BacktestEngine:
@@ -31,6 +35,7 @@ cdef class BacktestEngine:
     cdef Clock _clock
     cdef Logger _log
     cdef TimeEventAccumulatorAPI _accumulator
+    cdef RandomGenerator _rng
 
     cdef object _kernel
     cdef UUID4 _instance_id
@@ -49,6 +54,8 @@ cdef class BacktestEngine:
     cdef uint64_t _iteration
 
     cdef Data _next(self)
+    cdef void _seed_fill_model(self, Venue venue, FillModel fill_model)
+    cdef void _seed_latency_model(self, Venue venue, LatencyModel latency_model)
     cdef CVec _advance_time(self, uint64_t ts_now)
     cdef void _process_raw_time_event_handlers(
         self,
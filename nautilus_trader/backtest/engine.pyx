@@ -41,13 +41,16 @@ from nautilus_trader.backtest.data_client cimport BacktestDataClient
 from nautilus_trader.backtest.data_client cimport BacktestMarketDataClient
 from nautilus_trader.backtest.exchange cimport SimulatedExchange
 from nautilus_trader.backtest.execution_client cimport BacktestExecClient
+from nautilus_trader.backtest.models cimport FeeModel
 from nautilus_trader.backtest.models cimport FillModel
 from nautilus_trader.backtest.models cimport LatencyModel
+from nautilus_trader.backtest.models cimport ShortableSharesModel
 from nautilus_trader.backtest.modules cimport SimulationModule
 from nautilus_trader.cache.base cimport CacheFacade
 from nautilus_trader.common.actor cimport Actor
 from nautilus_trader.common.component cimport LiveClock
 from nautilus_trader.common.component cimport Logger
+from nautilus_trader.common.component cimport RandomGenerator
 from nautilus_trader.common.component cimport TestClock
 from nautilus_trader.common.component cimport TimeEvent
 from nautilus_trader.common.component cimport TimeEventHandler
@@ -121,6 +124,7 @@ cdef class BacktestEngine:
 
         # Setup components
         self._accumulator = <TimeEventAccumulatorAPI>time_event_accumulator_new()
+        self._rng = RandomGenerator(config.random_seed) if config.random_seed is not None else None
 
         # Run IDs
         self._run_config_id: str | None = None
@@ -367,6 +371,8 @@ cdef class BacktestEngine:
         modules: list[SimulationModule] | None = None,
         fill_model: FillModel | None = None,
         latency_model: LatencyModel | None = None,
+        fee_model: FeeModel | None = None,
+        shortable_shares_model: ShortableSharesModel | None = None,
         book_type: BookType = BookType.L1_MBP,
         routing: bool = False,
         frozen_account: bool = False,
@@ -404,6 +410,11 @@ cdef class BacktestEngine:
             The fill model for the exchange.
         latency_model : LatencyModel, optional
             The latency model for the exchange.
+        fee_model : FeeModel, optional
+            The fee model for the exchange (custom models can subclass `FeeModel`).
+            If ``None`` then a `MakerTakerFeeModel` is used.
+        shortable_shares_model : ShortableSharesModel, optional
+            The model for short-sell locates and borrow fees of equities.
         book_type : BookType, default ``BookType.L1_MBP``
             The default order book type for fill modelling.
         routing : bool, default False
@@ -441,6 +452,12 @@ cdef class BacktestEngine:
         Condition.not_empty(starting_balances, "starting_balances")
         Condition.list_type(modules, SimulationModule, "modules")
         Condition.type_or_none(fill_model, FillModel, "fill_model")
+        Condition.type_or_none(fee_model, FeeModel, "fee_model")
+        Condition.type_or_none(shortable_shares_model, ShortableSharesModel, "shortable_shares_model")
+
+        self._seed_fill_model(venue, fill_model)
+        if latency_model is not None:
+            self._seed_latency_model(venue, latency_model)
 
         if default_leverage is None:
             if account_type == AccountType.MARGIN:
@@ -464,6 +481,8 @@ cdef class BacktestEngine:
             cache=self.kernel.cache,
             fill_model=fill_model,
             latency_model=latency_model,
+            fee_model=fee_model,
+            shortable_shares_model=shortable_shares_model,
             book_type=book_type,
             clock=self.kernel.clock,
             frozen_account=frozen_account,
@@ -509,8 +528,27 @@ cdef class BacktestEngine:
         Condition.not_none(model, "model")
         Condition.is_in(venue, self._venues, "venue", "self._venues")
 
+        self._seed_fill_model(venue, model)
         self._venues[venue].set_fill_model(model)
 
+    def change_fee_model(self, Venue venue, FeeModel model) -> None:
+        """
+        Change the fee model for the exchange of the given venue.
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue of the simulated exchange.
+        model : FeeModel
+            The fee model to change to.
+
+        """
+        Condition.not_none(venue, "venue")
+        Condition.not_none(model, "model")
+        Condition.is_in(venue, self._venues, "venue", "self._venues")
+
+        self._venues[venue].set_fee_model(model)
+
     def add_instrument(self, Instrument instrument) -> None:
         """
         Add the instrument to the backtest engine.
@@ -814,6 +852,9 @@ cdef class BacktestEngine:
 
         for exchange in self._venues.values():
             exchange.reset()
+            self._seed_fill_model(exchange.id, exchange.fill_model)
+            if exchange.latency_model is not None:
+                self._seed_latency_model(exchange.id, exchange.latency_model)
 
         # Reset run IDs
         self._run_config_id = None
@@ -1146,6 +1187,19 @@ cdef class BacktestEngine:
             )
             vec_time_event_handlers_drop(raw_handlers)
 
+    cdef void _seed_fill_model(self, Venue venue, FillModel fill_model):
+        if self._rng is None:
+            return  # No engine level seed configured
+
+        # Derive an independent stream per venue, so results do not depend on venue order
+        fill_model.reseed(self._rng.fork(venue.value).seed)
+
+    cdef void _seed_latency_model(self, Venue venue, LatencyModel latency_model):
+        if self._rng is None:
+            return  # No engine level seed configured
+
+        latency_model.reseed(self._rng.fork(f"{venue.value}-latency").seed)
+
     cdef Data _next(self):
         cdef uint64_t cursor = self._index
         self._index += 1
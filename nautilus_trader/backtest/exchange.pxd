@@ -18,8 +18,10 @@ from libc.stdint cimport uint64_t
 from nautilus_trader.accounting.accounts.base cimport Account
 from nautilus_trader.backtest.execution_client cimport BacktestExecClient
 from nautilus_trader.backtest.matching_engine cimport OrderMatchingEngine
+from nautilus_trader.backtest.models cimport FeeModel
 from nautilus_trader.backtest.models cimport FillModel
 from nautilus_trader.backtest.models cimport LatencyModel
+from nautilus_trader.backtest.models cimport ShortableSharesModel
 from nautilus_trader.cache.cache cimport Cache
 from nautilus_trader.common.component cimport Clock
 from nautilus_trader.common.component cimport Logger
@@ -78,6 +80,10 @@ cdef class SimulatedExchange:
     """The latency model for the exchange.\n\n:returns: `LatencyModel`"""
     cdef readonly FillModel fill_model
     """The fill model for the exchange.\n\n:returns: `FillModel`"""
+    cdef readonly FeeModel fee_model
+    """The fee model for the exchange.\n\n:returns: `FeeModel`"""
+    cdef readonly ShortableSharesModel shortable_shares_model
+    """The shortable shares model for the exchange.\n\n:returns: `ShortableSharesModel` or ``None``"""
     cdef readonly bint bar_execution
     """If bars should be processed by the matching engine(s) (and move the market).\n\n:returns: `bool`"""
     cdef readonly bint reject_stop_orders
@@ -101,11 +107,14 @@ cdef class SimulatedExchange:
     cdef object _message_queue
     cdef list _inflight_queue
     cdef dict _inflight_counter
+    cdef uint64_t _last_borrow_fee_ns
 
 # -- REGISTRATION ---------------------------------------------------------------------------------
 
     cpdef void register_client(self, BacktestExecClient client)
     cpdef void set_fill_model(self, FillModel fill_model)
+    cpdef void set_fee_model(self, FeeModel fee_model)
+    cpdef void set_shortable_shares_model(self, ShortableSharesModel shortable_shares_model)
     cpdef void set_latency_model(self, LatencyModel latency_model)
     cpdef void initialize_account(self)
     cpdef void add_instrument(self, Instrument instrument)
@@ -136,6 +145,7 @@ cdef class SimulatedExchange:
     cpdef void process_venue_status(self, VenueStatus data)
     cpdef void process_instrument_status(self, InstrumentStatus data)
     cpdef void process(self, uint64_t ts_now)
+    cdef void _accrue_borrow_fees(self, uint64_t ts_now)
     cpdef void reset(self)
 
 # -- EVENT GENERATORS -----------------------------------------------------------------------------
@@ -24,16 +24,21 @@ from libc.stdint cimport uint64_t
 from nautilus_trader.accounting.accounts.base cimport Account
 from nautilus_trader.backtest.execution_client cimport BacktestExecClient
 from nautilus_trader.backtest.matching_engine cimport OrderMatchingEngine
+from nautilus_trader.backtest.models cimport FeeModel
 from nautilus_trader.backtest.models cimport FillModel
 from nautilus_trader.backtest.models cimport LatencyModel
+from nautilus_trader.backtest.models cimport MakerTakerFeeModel
+from nautilus_trader.backtest.models cimport ShortableSharesModel
 from nautilus_trader.backtest.modules cimport SimulationModule
 from nautilus_trader.cache.base cimport CacheFacade
 from nautilus_trader.common.component cimport Logger
 from nautilus_trader.common.component cimport TestClock
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.rust.core cimport NANOSECONDS_IN_SECOND
 from nautilus_trader.core.rust.model cimport AccountType
 from nautilus_trader.core.rust.model cimport BookType
 from nautilus_trader.core.rust.model cimport OmsType
+from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.execution.messages cimport BatchCancelOrders
 from nautilus_trader.execution.messages cimport CancelAllOrders
 from nautilus_trader.execution.messages cimport CancelOrder
@@ -53,9 +58,11 @@ from nautilus_trader.model.identifiers cimport Venue
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.instruments.crypto_future cimport CryptoFuture
 from nautilus_trader.model.instruments.crypto_perpetual cimport CryptoPerpetual
+from nautilus_trader.model.instruments.equity cimport Equity
 from nautilus_trader.model.objects cimport AccountBalance
 from nautilus_trader.model.objects cimport Money
 from nautilus_trader.model.objects cimport Price
+from nautilus_trader.model.position cimport Position
 from nautilus_trader.model.orders.base cimport Order
 from nautilus_trader.portfolio.base cimport PortfolioFacade
 
@@ -90,6 +97,12 @@ cdef class SimulatedExchange:
         The fill model for the exchange.
     latency_model : LatencyModel, optional
         The latency model for the exchange.
+    fee_model : FeeModel, optional
+        The fee model for calculating fill commissions.
+        If ``None`` then a `MakerTakerFeeModel` is used.
+    shortable_shares_model : ShortableSharesModel, optional
+        The model for short-sell locates and borrow fees of equities.
+        If ``None`` then short sells are not constrained by locates and accrue no borrow fees.
     clock : TestClock
         The clock for the exchange.
     book_type : BookType
@@ -145,6 +158,8 @@ cdef class SimulatedExchange:
         TestClock clock not None,
         FillModel fill_model not None,
         LatencyModel latency_model = None,
+        FeeModel fee_model = None,
+        ShortableSharesModel shortable_shares_model = None,
         BookType book_type = BookType.L1_MBP,
         bint frozen_account = False,
         bint bar_execution = True,
@@ -194,6 +209,9 @@ cdef class SimulatedExchange:
         self.use_reduce_only = use_reduce_only
         self.fill_model = fill_model
         self.latency_model = latency_model
+        self.fee_model = fee_model or MakerTakerFeeModel()
+        self.shortable_shares_model = shortable_shares_model
+        self._last_borrow_fee_ns = 0
 
         # Load modules
         self.modules = []
@@ -269,6 +287,50 @@ cdef class SimulatedExchange:
                 f"to {self.fill_model}.",
             )
 
+    cpdef void set_fee_model(self, FeeModel fee_model):
+        """
+        Set the fee model for all matching engines.
+
+        Parameters
+        ----------
+        fee_model : FeeModel
+            The fee model to set.
+
+        """
+        Condition.not_none(fee_model, "fee_model")
+
+        self.fee_model = fee_model
+
+        cdef OrderMatchingEngine matching_engine
+        for matching_engine in self._matching_engines.values():
+            matching_engine.set_fee_model(fee_model)
+            self._log.info(
+                f"Changed `FeeModel` for {matching_engine.venue} "
+                f"to {self.fee_model}.",
+            )
+
+    cpdef void set_shortable_shares_model(self, ShortableSharesModel shortable_shares_model):
+        """
+        Set the shortable shares model for all matching engines.
+
+        Parameters
+        ----------
+        shortable_shares_model : ShortableSharesModel
+            The shortable shares model to set.
+
+        """
+        Condition.not_none(shortable_shares_model, "shortable_shares_model")
+
+        self.shortable_shares_model = shortable_shares_model
+
+        cdef OrderMatchingEngine matching_engine
+        for matching_engine in self._matching_engines.values():
+            matching_engine.set_shortable_shares_model(shortable_shares_model)
+            self._log.info(
+                f"Changed `ShortableSharesModel` for {matching_engine.venue} "
+                f"to {self.shortable_shares_model}.",
+            )
+
     cpdef void set_latency_model(self, LatencyModel latency_model):
         """
         Change the latency model for this exchange.
@@ -341,6 +403,8 @@ cdef class SimulatedExchange:
             use_position_ids=self.use_position_ids,
             use_random_ids=self.use_random_ids,
             use_reduce_only=self.use_reduce_only,
+            fee_model=self.fee_model,
+            shortable_shares_model=self.shortable_shares_model,
         )
 
         self._matching_engines[instrument.id] = matching_engine
@@ -620,13 +684,15 @@ cdef class SimulatedExchange:
             heappush(self._inflight_queue, self.generate_inflight_command(command))
 
     cdef tuple generate_inflight_command(self, TradingCommand command):
-        cdef uint64_t ts
+        # Strategies react to market data as it arrives in the backtest, so the market data
+        # latency is modeled by delaying the commands generated in response to that data
+        cdef uint64_t ts = command.ts_init + self.latency_model.data_latency()
         if isinstance(command, (SubmitOrder, SubmitOrderList)):
-            ts = command.ts_init + self.latency_model.insert_latency_nanos
+            ts += self.latency_model.insert_latency()
         elif isinstance(command, ModifyOrder):
-            ts = command.ts_init + self.latency_model.update_latency_nanos
+            ts += self.latency_model.update_latency()
         elif isinstance(command, (CancelOrder, CancelAllOrders, BatchCancelOrders)):
-            ts = command.ts_init + self.latency_model.cancel_latency_nanos
+            ts += self.latency_model.cancel_latency()
         else:
             raise ValueError(f"invalid `TradingCommand`, was {command}")  # pragma: no cover (design-time error)
         if ts not in self._inflight_counter:
@@ -791,7 +857,7 @@ cdef class SimulatedExchange:
         if matching_engine is None:
             raise RuntimeError(f"No matching engine found for {data.instrument_id}")
 
-        matching_engine.process_status(data.status)
+        matching_engine.process_instrument_status(data)
 
     cpdef void process(self, uint64_t ts_now):
         """
@@ -844,6 +910,42 @@ cdef class SimulatedExchange:
         for module in self.modules:
             module.process(ts_now)
 
+        if self.shortable_shares_model is not None:
+            self._accrue_borrow_fees(ts_now)
+
+    cdef void _accrue_borrow_fees(self, uint64_t ts_now):
+        if self._last_borrow_fee_ns == 0:
+            self._last_borrow_fee_ns = ts_now
+            return
+
+        cdef uint64_t nanos_in_day = 86_400 * NANOSECONDS_IN_SECOND
+        cdef uint64_t days = (ts_now - self._last_borrow_fee_ns) // nanos_in_day
+        if days == 0:
+            return  # Borrow fees accrue daily
+
+        self._last_borrow_fee_ns += days * nanos_in_day
+
+        cdef:
+            Position position
+            Instrument instrument
+            Price price
+            Money fee
+        for position in self.cache.positions_open(venue=self.id):
+            if not position.is_short_c():
+                continue
+            instrument = self.instruments.get(position.instrument_id)
+            if not isinstance(instrument, Equity):
+                continue  # Only applicable to equities
+
+            price = self.cache.price(instrument.id, PriceType.MID) or self.cache.price(instrument.id, PriceType.LAST)
+            if price is None:
+                self._log.warning(f"Cannot accrue borrow fee for {instrument.id}, no market prices")
+                continue
+
+            fee = self.shortable_shares_model.calculate_borrow_fee(instrument, position.quantity, price, days)
+            if fee.as_f64_c() > 0.0:
+                self.adjust_account(Money(-fee.as_f64_c(), fee.currency))
+
     cpdef void reset(self):
         """
         Reset the simulated exchange.
@@ -855,6 +957,10 @@ cdef class SimulatedExchange:
         for module in self.modules:
             module.reset()
 
+        if self.shortable_shares_model is not None:
+            self.shortable_shares_model.reset()
+        self._last_borrow_fee_ns = 0
+
         self._generate_fresh_account_state()
 
         for matching_engine in self._matching_engines.values():
@@ -17,26 +17,34 @@ from libc.stdint cimport int64_t
 from libc.stdint cimport uint32_t
 from libc.stdint cimport uint64_t
 
+from nautilus_trader.backtest.models cimport FeeModel
 from nautilus_trader.backtest.models cimport FillModel
+from nautilus_trader.backtest.models cimport ShortableSharesModel
+from nautilus_trader.backtest.models cimport TradeDrivenFillModel
 from nautilus_trader.cache.base cimport CacheFacade
 from nautilus_trader.common.component cimport Clock
 from nautilus_trader.common.component cimport Logger
 from nautilus_trader.common.component cimport MessageBus
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.rust.model cimport AccountType
+from nautilus_trader.core.rust.model cimport AuctionPhase
 from nautilus_trader.core.rust.model cimport BookType
+from nautilus_trader.core.rust.model cimport HaltReason
 from nautilus_trader.core.rust.model cimport LiquiditySide
 from nautilus_trader.core.rust.model cimport MarketStatus
 from nautilus_trader.core.rust.model cimport OmsType
+from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport TimeInForce
 from nautilus_trader.execution.matching_core cimport MatchingCore
 from nautilus_trader.execution.messages cimport BatchCancelOrders
 from nautilus_trader.execution.messages cimport CancelAllOrders
 from nautilus_trader.execution.messages cimport CancelOrder
 from nautilus_trader.execution.messages cimport ModifyOrder
+from nautilus_trader.model.book cimport Level
 from nautilus_trader.model.book cimport OrderBook
 from nautilus_trader.model.data cimport Bar
 from nautilus_trader.model.data cimport BookOrder
+from nautilus_trader.model.data cimport InstrumentStatus
 from nautilus_trader.model.data cimport OrderBookDelta
 from nautilus_trader.model.data cimport OrderBookDeltas
 from nautilus_trader.model.data cimport QuoteTick
@@ -76,6 +84,9 @@ cdef class OrderMatchingEngine:
     cdef OrderBook _opening_auction_book
     cdef OrderBook _closing_auction_book
     cdef FillModel _fill_model
+    cdef FeeModel _fee_model
+    cdef ShortableSharesModel _shortable_model
+    cdef TradeDrivenFillModel _trade_fill_model
     # cdef object _auction_match_algo
     cdef bint _bar_execution
     cdef bint _reject_stop_orders
@@ -85,6 +96,7 @@ cdef class OrderMatchingEngine:
     cdef bint _use_random_ids
     cdef bint _use_reduce_only
     cdef dict _account_ids
+    cdef list _auction_market_orders
 
     cdef readonly Venue venue
     """The venue for the matching engine.\n\n:returns: `Venue`"""
@@ -100,6 +112,14 @@ cdef class OrderMatchingEngine:
     """The account type for the matching engine.\n\n:returns: `AccountType`"""
     cdef readonly MarketStatus market_status
     """The market status for the matching engine.\n\n:returns: `MarketStatus`"""
+    cdef readonly HaltReason halt_reason
+    """The halt reason for the matching engine.\n\n:returns: `HaltReason`"""
+    cdef readonly AuctionPhase auction_phase
+    """The auction call phase for the matching engine.\n\n:returns: `AuctionPhase`"""
+    cdef readonly Price limit_up
+    """The limit-up price band for the matching engine.\n\n:returns: `Price` or ``None``"""
+    cdef readonly Price limit_down
+    """The limit-down price band for the matching engine.\n\n:returns: `Price` or ``None``"""
     cdef readonly CacheFacade cache
     """The cache for the matching engine.\n\n:returns: `CacheFacade`"""
     cdef readonly MessageBus msgbus
@@ -119,6 +139,8 @@ cdef class OrderMatchingEngine:
 
     cpdef void reset(self)
     cpdef void set_fill_model(self, FillModel fill_model)
+    cpdef void set_fee_model(self, FeeModel fee_model)
+    cpdef void set_shortable_shares_model(self, ShortableSharesModel shortable_shares_model)
 
 # -- QUERIES --------------------------------------------------------------------------------------
 
@@ -138,6 +160,12 @@ cdef class OrderMatchingEngine:
     cpdef void process_trade_tick(self, TradeTick tick)
     cpdef void process_bar(self, Bar bar)
     cpdef void process_status(self, MarketStatus status)
+    cpdef void process_instrument_status(self, InstrumentStatus status)
+    cdef bint _is_trading_suspended(self)
+    cdef void _uncross_auction(self)
+    cdef object _short_qty_change(self, OrderSide side, quantity)
+    cdef object _level_size(self, Level level)
+    cdef void _check_price_bands(self, Price last)
     cpdef void process_auction_book(self, OrderBook book)
     cdef void _process_trade_ticks_from_bar(self, Bar bar)
     cdef void _process_quote_ticks_from_bar(self)
@@ -167,6 +195,11 @@ cdef class OrderMatchingEngine:
     cdef void _update_market_if_touched_order(self, Order order, Quantity qty, Price trigger_price)
     cdef void _update_limit_if_touched_order(self, Order order, Quantity qty, Price price, Price trigger_price)
     cdef void _update_trailing_stop_order(self, Order order)
+    cdef double _get_level_size(self, OrderSide side, Price price)
+    cdef void _update_trade_driven_orders(self)
+    cdef void _fill_trade_driven_orders(self, TradeTick tick)
+    cdef void _update_pegged_order(self, LimitOrder order)
+    cdef Price _calculate_pegged_price(self, LimitOrder order)
 
 # -- ORDER PROCESSING -----------------------------------------------------------------------------
 
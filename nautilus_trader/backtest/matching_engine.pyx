@@ -14,13 +14,20 @@
 # -------------------------------------------------------------------------------------------------
 
 import uuid
+from decimal import Decimal
 
 
+from nautilus_trader.backtest.auction import allocate_auction_volume
+from nautilus_trader.backtest.auction import calculate_uncrossing_price
 # from nautilus_trader.backtest.auction import default_auction_match
 
 from libc.stdint cimport uint64_t
 
+from nautilus_trader.backtest.models cimport FeeModel
 from nautilus_trader.backtest.models cimport FillModel
+from nautilus_trader.backtest.models cimport MakerTakerFeeModel
+from nautilus_trader.backtest.models cimport ShortableSharesModel
+from nautilus_trader.backtest.models cimport TradeDrivenFillModel
 from nautilus_trader.cache.base cimport CacheFacade
 from nautilus_trader.common.component cimport LogColor
 from nautilus_trader.common.component cimport Logger
@@ -38,6 +45,7 @@ from nautilus_trader.core.rust.model cimport OmsType
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport OrderStatus
 from nautilus_trader.core.rust.model cimport OrderType
+from nautilus_trader.core.rust.model cimport PegPriceType
 from nautilus_trader.core.rust.model cimport Price_t
 from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.core.rust.model cimport TimeInForce
@@ -55,8 +63,10 @@ from nautilus_trader.execution.messages cimport CancelAllOrders
 from nautilus_trader.execution.messages cimport CancelOrder
 from nautilus_trader.execution.messages cimport ModifyOrder
 from nautilus_trader.execution.trailing cimport TrailingStopCalculator
+from nautilus_trader.model.book cimport Level
 from nautilus_trader.model.book cimport OrderBook
 from nautilus_trader.model.data cimport BookOrder
+from nautilus_trader.model.data cimport InstrumentStatus
 from nautilus_trader.model.data cimport QuoteTick
 from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.events.order cimport OrderAccepted
@@ -68,7 +78,7 @@ from nautilus_trader.model.events.order cimport OrderModifyRejected
 from nautilus_trader.model.events.order cimport OrderRejected
 from nautilus_trader.model.events.order cimport OrderTriggered
 from nautilus_trader.model.events.order cimport OrderUpdated
-from nautilus_trader.model.functions cimport liquidity_side_to_str
+from nautilus_trader.model.functions cimport market_status_to_str
 from nautilus_trader.model.functions cimport order_type_to_str
 from nautilus_trader.model.identifiers cimport AccountId
 from nautilus_trader.model.identifiers cimport ClientOrderId
@@ -139,6 +149,12 @@ cdef class OrderMatchingEngine:
         If all venue generated identifiers will be random UUID4's.
     use_reduce_only : bool, default True
         If the `reduce_only` execution instruction on orders will be honored.
+    fee_model : FeeModel, optional
+        The fee model for calculating fill commissions.
+        If ``None`` then a `MakerTakerFeeModel` is used.
+    shortable_shares_model : ShortableSharesModel, optional
+        The model for short-sell locate availability of equities.
+        If ``None`` then short sells are not constrained by locates.
     auction_match_algo : Callable[[Ladder, Ladder], Tuple[List, List], optional
         The auction matching algorithm.
     """
@@ -161,6 +177,8 @@ cdef class OrderMatchingEngine:
         bint use_position_ids = True,
         bint use_random_ids = False,
         bint use_reduce_only = True,
+        FeeModel fee_model = None,
+        ShortableSharesModel shortable_shares_model = None,
         # auction_match_algo = default_auction_match
     ) -> None:
         self._clock = clock
@@ -175,6 +193,10 @@ cdef class OrderMatchingEngine:
         self.oms_type = oms_type
         self.account_type = account_type
         self.market_status = MarketStatus.OPEN
+        self.halt_reason = HaltReason.NOT_HALTED
+        self.auction_phase = AuctionPhase.NO_AUCTION
+        self.limit_up = None
+        self.limit_down = None
 
         self._bar_execution = bar_execution
         self._reject_stop_orders = reject_stop_orders
@@ -185,6 +207,9 @@ cdef class OrderMatchingEngine:
         self._use_reduce_only = use_reduce_only
         # self._auction_match_algo = auction_match_algo
         self._fill_model = fill_model
+        self._trade_fill_model = fill_model if isinstance(fill_model, TradeDrivenFillModel) else None
+        self._fee_model = fee_model or MakerTakerFeeModel()
+        self._shortable_model = shortable_shares_model
         self._book = OrderBook(
             instrument_id=instrument.id,
             book_type=book_type,
@@ -199,6 +224,7 @@ cdef class OrderMatchingEngine:
         )
 
         self._account_ids: dict[TraderId, AccountId]  = {}
+        self._auction_market_orders: list[MarketOrder] = []
 
         # Market
         self._core = MatchingCore(
@@ -233,6 +259,7 @@ cdef class OrderMatchingEngine:
 
         self._book.clear(0, 0)
         self._account_ids.clear()
+        self._auction_market_orders.clear()
         self._core.reset()
         self._target_bid = 0
         self._target_ask = 0
@@ -240,6 +267,11 @@ cdef class OrderMatchingEngine:
         self._has_targets = False
         self._last_bid_bar = None
         self._last_ask_bar = None
+        self.market_status = MarketStatus.OPEN
+        self.halt_reason = HaltReason.NOT_HALTED
+        self.auction_phase = AuctionPhase.NO_AUCTION
+        self.limit_up = None
+        self.limit_down = None
 
         self._position_count = 0
         self._order_count = 0
@@ -260,9 +292,42 @@ cdef class OrderMatchingEngine:
         Condition.not_none(fill_model, "fill_model")
 
         self._fill_model = fill_model
+        self._trade_fill_model = fill_model if isinstance(fill_model, TradeDrivenFillModel) else None
 
         self._log.debug(f"Changed `FillModel` to {self._fill_model}.")
 
+    cpdef void set_fee_model(self, FeeModel fee_model):
+        """
+        Set the fee model to the given model.
+
+        Parameters
+        ----------
+        fee_model : FeeModel
+            The fee model to set.
+
+        """
+        Condition.not_none(fee_model, "fee_model")
+
+        self._fee_model = fee_model
+
+        self._log.debug(f"Changed `FeeModel` to {self._fee_model}.")
+
+    cpdef void set_shortable_shares_model(self, ShortableSharesModel shortable_shares_model):
+        """
+        Set the shortable shares model to the given model.
+
+        Parameters
+        ----------
+        shortable_shares_model : ShortableSharesModel
+            The shortable shares model to set.
+
+        """
+        Condition.not_none(shortable_shares_model, "shortable_shares_model")
+
+        self._shortable_model = shortable_shares_model
+
+        self._log.debug(f"Changed `ShortableSharesModel` to {self._shortable_model}.")
+
 # -- QUERIES --------------------------------------------------------------------------------------
 
     cpdef Price best_bid_price(self):
@@ -438,7 +503,11 @@ cdef class OrderMatchingEngine:
 
         self._core.set_last_raw(tick._mem.price.raw)
 
+        if self._trade_fill_model is not None:
+            self._fill_trade_driven_orders(tick)
+
         self.iterate(tick.ts_init)
+        self._check_price_bands(tick.price)
 
     cpdef void process_bar(self, Bar bar):
         """
@@ -487,6 +556,8 @@ cdef class OrderMatchingEngine:
             The status to process.
 
         """
+        cdef MarketStatus previous_status = self.market_status
+
         if (self.market_status, status) == (MarketStatus.CLOSED, MarketStatus.OPEN):
             self.market_status = status
         elif (self.market_status, status) == (MarketStatus.CLOSED, MarketStatus.PRE_OPEN):
@@ -507,6 +578,145 @@ cdef class OrderMatchingEngine:
             # Market closed - nothing to do for now
             # TODO - should we implement some sort of closing price message here?
             self.market_status = status
+        elif status == MarketStatus.HALT or status == MarketStatus.AUCTION:
+            # Trading halt or auction call phase, continuous matching is suspended
+            self.market_status = status
+        elif status == MarketStatus.REOPEN or (self._is_trading_suspended() and status == MarketStatus.OPEN):
+            # Continuous matching resumes
+            self.market_status = status
+
+        if self.market_status != MarketStatus.HALT:
+            self.halt_reason = HaltReason.NOT_HALTED
+        if self.market_status != MarketStatus.AUCTION:
+            self.auction_phase = AuctionPhase.NO_AUCTION
+
+        if previous_status == MarketStatus.AUCTION and self.market_status != MarketStatus.AUCTION:
+            # Auction call phase ended, uncross at a single clearing price
+            self._uncross_auction()
+
+    cpdef void process_instrument_status(self, InstrumentStatus status):
+        """
+        Process the given instrument status update.
+
+        Any limit-up/limit-down price bands on the update replace the current bands.
+
+        Parameters
+        ----------
+        status : InstrumentStatus
+            The status update to process.
+
+        """
+        Condition.not_none(status, "status")
+
+        if status.limit_up is not None:
+            self.limit_up = status.limit_up
+        if status.limit_down is not None:
+            self.limit_down = status.limit_down
+
+        self.process_status(status.status)
+
+        if self.market_status == status.status:
+            self.halt_reason = status.halt_reason
+            self.auction_phase = status.auction_phase
+
+    cdef bint _is_trading_suspended(self):
+        return self.market_status == MarketStatus.HALT or self.market_status == MarketStatus.AUCTION
+
+    cdef void _uncross_auction(self):
+        cdef list auction_orders = self._auction_market_orders
+        self._auction_market_orders = []
+
+        # Book interest is ahead of own orders at the same price (time priority)
+        cdef list bids = [(None, level.price, self._level_size(level)) for level in self._book.bids()]
+        cdef list asks = [(None, level.price, self._level_size(level)) for level in self._book.asks()]
+
+        cdef Order order
+        for order in auction_orders:
+            if order.is_open_c():
+                (bids if order.side == OrderSide.BUY else asks).append((order, None, order.leaves_qty.as_decimal()))
+        for order in self._core.get_orders_bid():
+            if order.order_type == OrderType.LIMIT:
+                bids.append((order, order.price, order.leaves_qty.as_decimal()))
+        for order in self._core.get_orders_ask():
+            if order.order_type == OrderType.LIMIT:
+                asks.append((order, order.price, order.leaves_qty.as_decimal()))
+
+        result = calculate_uncrossing_price(
+            bids=[(price, size) for _, price, size in bids],
+            asks=[(price, size) for _, price, size in asks],
+            reference_price=self._core.last,
+        )
+
+        cdef Price clearing_px
+        cdef PositionId venue_position_id
+        cdef Position position
+        if result is not None:
+            clearing_px, volume = result
+            self._log.info(f"Uncrossing auction for {self.instrument.id} at {clearing_px} for {volume}.")
+            self._core.set_last_raw(clearing_px._mem.raw)
+            allocations = (
+                allocate_auction_volume(bids, OrderSide.BUY, clearing_px, volume)
+                + allocate_auction_volume(asks, OrderSide.SELL, clearing_px, volume)
+            )
+            for order, size in allocations:
+                if order is None:
+                    continue  # Book interest
+                venue_position_id = self._get_position_id(order)
+                position = None
+                if venue_position_id is not None:
+                    position = self.cache.position(venue_position_id)
+                self.fill_order(
+                    order=order,
+                    last_px=clearing_px,
+                    last_qty=Quantity(size, self.instrument.size_precision),
+                    liquidity_side=LiquiditySide.TAKER,
+                    venue_position_id=venue_position_id,
+                    position=position,
+                )
+
+        # Market orders do not carry over from the auction
+        for order in auction_orders:
+            if order.is_open_c():
+                self.cancel_order(order)
+
+    cdef object _short_qty_change(self, OrderSide side, quantity):
+        # Returns the change in the net short quantity if `quantity` were filled on `side`
+        net_qty = sum(
+            [p.signed_decimal_qty() for p in self.cache.positions_open(venue=None, instrument_id=self.instrument.id)],
+            Decimal(0),
+        )
+        cdef object filled_net_qty = net_qty + quantity if side == OrderSide.BUY else net_qty - quantity
+        return max(-filled_net_qty, Decimal(0)) - max(-net_qty, Decimal(0))
+
+    cdef object _level_size(self, Level level):
+        return Quantity(level.size(), self.instrument.size_precision).as_decimal()
+
+    cdef void _check_price_bands(self, Price last):
+        if self._is_trading_suspended():
+            return  # Already suspended
+
+        if (
+            (self.limit_up is None or last < self.limit_up)
+            and (self.limit_down is None or last > self.limit_down)
+        ):
+            return  # Within price bands
+
+        self._log.warning(
+            f"Halting {self.instrument.id}: last price {last} reached price band "
+            f"[{self.limit_down}, {self.limit_up}].",
+        )
+
+        cdef InstrumentStatus status = InstrumentStatus(
+            instrument_id=self.instrument.id,
+            status=MarketStatus.HALT,
+            ts_event=self._clock.timestamp_ns(),
+            ts_init=self._clock.timestamp_ns(),
+            halt_reason=HaltReason.LIMIT_UP_LIMIT_DOWN,
+            limit_up=self.limit_up,
+            limit_down=self.limit_down,
+        )
+        self.process_instrument_status(status)
+        self.msgbus.send(endpoint="DataEngine.process", msg=status)
 
     cpdef void process_auction_book(self, OrderBook book):
         Condition.not_none(book, "book")
@@ -706,6 +916,34 @@ cdef class OrderMatchingEngine:
                 )
                 return  # Invalid order
 
+        # Check order price within limit-up/limit-down bands
+        if order.has_price_c() and (
+            (self.limit_up is not None and price > self.limit_up)
+            or (self.limit_down is not None and price < self.limit_down)
+        ):
+            self._generate_order_rejected(
+                order,
+                f"Price {price} for order {order.client_order_id} outside price band "
+                f"[{self.limit_down}, {self.limit_up}]",
+            )
+            return  # Invalid price
+
+        # Check market orders while trading is suspended (market orders may join an auction call)
+        if (
+            (
+                (self.market_status == MarketStatus.HALT and order.order_type == OrderType.MARKET)
+                or (self._is_trading_suspended() and order.order_type == OrderType.MARKET_TO_LIMIT)
+            )
+            and order.time_in_force != TimeInForce.AT_THE_OPEN
+            and order.time_in_force != TimeInForce.AT_THE_CLOSE
+        ):
+            self._generate_order_rejected(
+                order,
+                f"{order.type_string_c()} order for {self.instrument.id} "
+                f"not permitted while market status is {market_status_to_str(self.market_status)}",
+            )
+            return  # Trading suspended
+
         cdef Position position = self.cache.position_for_order(order.client_order_id)
 
         # Check not shorting an equity without a MARGIN account
@@ -721,6 +959,22 @@ cdef class OrderMatchingEngine:
             )
             return  # Cannot short sell
 
+        # Check short sell locate is available
+        cdef object locate_qty
+        if (
+            self._shortable_model is not None
+            and order.side == OrderSide.SELL
+            and isinstance(self.instrument, Equity)
+        ):
+            locate_qty = self._short_qty_change(order.side, order.leaves_qty.as_decimal())
+            if locate_qty > 0 and not self._shortable_model.is_shortable(self.instrument.id, locate_qty):
+                self._generate_order_rejected(
+                    order,
+                    f"SHORT SELL order for {locate_qty} {self.instrument.id} shares has no locate, "
+                    f"available {self._shortable_model.available(self.instrument.id)}",
+                )
+                return  # Cannot short sell
+
         # Check reduce-only instruction
         if self._use_reduce_only and order.is_reduce_only and not order.is_closed_c():
             if (
@@ -815,6 +1069,12 @@ cdef class OrderMatchingEngine:
             self._process_auction_market_order(order)
             return
 
+        # Check auction call phase
+        if self.market_status == MarketStatus.AUCTION:
+            self._generate_order_accepted(order)
+            self._auction_market_orders.append(order)
+            return  # Executes at the auction uncross
+
         # Check market exists
         if order.side == OrderSide.BUY and not self._core.is_ask_initialized:
             self._generate_order_rejected(order, f"no market for {order.instrument_id}")
@@ -861,7 +1121,7 @@ cdef class OrderMatchingEngine:
         self.accept_order(order)
 
         # Check for immediate fill
-        if self._core.is_limit_matched(order.side, order.price):
+        if not self._is_trading_suspended() and self._core.is_limit_matched(order.side, order.price):
             # Filling as liquidity taker
             if order.liquidity_side == LiquiditySide.NO_LIQUIDITY_SIDE:
                 order.liquidity_side = LiquiditySide.TAKER
@@ -1199,6 +1459,102 @@ cdef class OrderMatchingEngine:
             trigger_price=new_trigger_price,
         )
 
+    cdef double _get_level_size(self, OrderSide side, Price price):
+        cdef list levels = self._book.bids() if side == OrderSide.BUY else self._book.asks()
+        for level in levels:
+            if level.price == price:
+                return level.size()
+        return 0.0
+
+    cdef void _update_trade_driven_orders(self):
+        cdef ClientOrderId client_order_id
+        cdef Order order
+        for client_order_id in self._trade_fill_model.client_order_ids():
+            if not self._core.order_exists(client_order_id):
+                self._trade_fill_model.remove_order(client_order_id)
+                continue
+
+            if self.book_type == BookType.L1_MBP:
+                continue  # Level sizes away from the top of book are unknown
+
+            order = self._core.get_order(client_order_id)
+            self._trade_fill_model.update_level_size(
+                client_order_id,
+                self._get_level_size(order.side, order.price),
+            )
+
+    cdef void _fill_trade_driven_orders(self, TradeTick tick):
+        cdef list trade_fills = self._trade_fill_model.process_trade(
+            tick.price,
+            tick.size.as_f64_c(),
+            tick.aggressor_side,
+        )
+
+        cdef:
+            ClientOrderId client_order_id
+            double volume
+            Order order
+            Quantity fill_qty
+            PositionId venue_position_id
+            Position position
+        for client_order_id, volume in trade_fills:
+            order = self._core.get_order(client_order_id)
+            if order is None or order.is_closed_c():
+                continue
+
+            fill_qty = self.instrument.make_qty(min(volume, order.leaves_qty.as_f64_c()))
+            if fill_qty._mem.raw == 0:
+                continue
+
+            venue_position_id = self._get_position_id(order)
+            position = None
+            if venue_position_id is not None:
+                position = self.cache.position(venue_position_id)
+
+            self.apply_fills(
+                order=order,
+                fills=[(order.price, fill_qty)],
+                liquidity_side=LiquiditySide.MAKER,
+                venue_position_id=venue_position_id,
+                position=position,
+            )
+
+    cdef void _update_pegged_order(self, LimitOrder order):
+        cdef Price new_price = self._calculate_pegged_price(order)
+        if new_price is None or new_price == order.price:
+            return  # No updates
+
+        self._generate_order_updated(
+            order=order,
+            quantity=order.quantity,
+            price=new_price,
+            trigger_price=None,
+        )
+
+    cdef Price _calculate_pegged_price(self, LimitOrder order):
+        cdef Price bid = self._core.bid
+        cdef Price ask = self._core.ask
+        if bid is None or ask is None:
+            return None  # Cannot peg without a two-sided market
+
+        cdef double offset = order.peg_offset.as_f64_c() if order.peg_offset is not None else 0.0
+        cdef double value
+        if order.peg_price_type == PegPriceType.MID_POINT:
+            value = (bid.as_f64_c() + ask.as_f64_c()) / 2.0
+            if order.side == OrderSide.BUY:
+                value -= offset
+            else:
+                value += offset
+        elif order.peg_price_type == PegPriceType.PRIMARY:
+            if order.side == OrderSide.BUY:
+                value = bid.as_f64_c() + offset
+            else:
+                value = ask.as_f64_c() - offset
+        else:
+            return None
+
+        return self.instrument.make_price(value)
+
 # -- ORDER PROCESSING -----------------------------------------------------------------------------
 
     cpdef void iterate(self, uint64_t timestamp_ns):
@@ -1224,6 +1580,12 @@ cdef class OrderMatchingEngine:
             ask = orderbook_best_ask_price(&self._book._mem)
             self._core.set_ask_raw(ask.raw)
 
+        if self._is_trading_suspended():
+            return  # No continuous matching while halted or in an auction call phase
+
+        if self._trade_fill_model is not None:
+            self._update_trade_driven_orders()
+
         self._core.iterate(timestamp_ns)
 
         cdef list orders = self._core.get_orders()
@@ -1243,6 +1605,10 @@ cdef class OrderMatchingEngine:
             if order.order_type == OrderType.TRAILING_STOP_MARKET or order.order_type == OrderType.TRAILING_STOP_LIMIT:
                 self._update_trailing_stop_order(order)
 
+            # Manage pegged limit
+            if order.order_type == OrderType.LIMIT and (<LimitOrder>order).peg_price_type != PegPriceType.NO_PEG:
+                self._update_pegged_order(<LimitOrder>order)
+
             # Move market back to targets
             if self._has_targets:
                 self._core.set_bid_raw(self._target_bid)
@@ -1481,7 +1847,16 @@ cdef class OrderMatchingEngine:
         Condition.true(order.has_price_c(), "order has no limit `price`")
 
         cdef Price price = order.price
-        if order.liquidity_side == LiquiditySide.MAKER and self._fill_model:
+        cdef bint at_touch
+        if order.liquidity_side == LiquiditySide.MAKER and self._trade_fill_model is not None:
+            # Fills driven by trade prints (e.g. traded volume consuming the queue)
+            at_touch = (
+                (order.side == OrderSide.BUY and self._core.bid_raw == price._mem.raw)
+                or (order.side == OrderSide.SELL and self._core.ask_raw == price._mem.raw)
+            )
+            if not self._trade_fill_model.is_book_fill_allowed(at_touch):
+                return  # Not filled
+        elif order.liquidity_side == LiquiditySide.MAKER and self._fill_model:
             if order.side == OrderSide.BUY and self._core.bid_raw == price._mem.raw and not self._fill_model.is_limit_filled():
                 return  # Not filled
             elif order.side == OrderSide.SELL and self._core.ask_raw == price._mem.raw and not self._fill_model.is_limit_filled():
@@ -1734,28 +2109,22 @@ cdef class OrderMatchingEngine:
 
         order.liquidity_side = liquidity_side
 
-        # Calculate commission
-        cdef double notional = self.instrument.notional_value(
-            quantity=last_qty,
-            price=last_px,
-            use_quote_for_inverse=False,
-        ).as_f64_c()
-
-        cdef double commission_f64
-        if order.liquidity_side == LiquiditySide.MAKER:
-            commission_f64 = notional * float(self.instrument.maker_fee)
-        elif order.liquidity_side == LiquiditySide.TAKER:
-            commission_f64 = notional * float(self.instrument.taker_fee)
-        else:
-            raise ValueError(
-                f"invalid `LiquiditySide`, was {liquidity_side_to_str(order.liquidity_side)}"
-            )
+        # Borrow or return located shares (prior to the fill updating positions)
+        cdef object short_qty_change
+        if self._shortable_model is not None and isinstance(self.instrument, Equity):
+            short_qty_change = self._short_qty_change(order.side, last_qty.as_decimal())
+            if short_qty_change > 0:
+                self._shortable_model.borrow(self.instrument.id, short_qty_change)
+            elif short_qty_change < 0:
+                self._shortable_model.return_shares(self.instrument.id, -short_qty_change)
 
-        cdef Money commission
-        if self.instrument.is_inverse:  # Not using quote for inverse (see above):
-            commission = Money(commission_f64, self.instrument.base_currency)
-        else:
-            commission = Money(commission_f64, self.instrument.quote_currency)
+        # Calculate commission
+        cdef Money commission = self._fee_model.get_commission(
+            order=order,
+            fill_qty=last_qty,
+            fill_px=last_px,
+            instrument=self.instrument,
+        )
 
         self._generate_order_filled(
             order=order,
@@ -1914,9 +2283,14 @@ cdef class OrderMatchingEngine:
             ):
                 if order.trigger_price is None:
                     self._update_trailing_stop_order(order)
+            elif order.order_type == OrderType.LIMIT and (<LimitOrder>order).peg_price_type != PegPriceType.NO_PEG:
+                self._update_pegged_order(<LimitOrder>order)
 
         self._core.add_order(order)
 
+        if self._trade_fill_model is not None and order.order_type == OrderType.LIMIT:
+            self._trade_fill_model.add_order(order, self._get_level_size(order.side, order.price))
+
     cpdef void expire_order(self, Order order):
         if self._support_contingent_orders and order.contingency_type != ContingencyType.NO_CONTINGENCY:
             self._cancel_contingent_orders(order)
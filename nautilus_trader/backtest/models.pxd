@@ -15,6 +15,16 @@
 
 from libc.stdint cimport uint64_t
 
+from nautilus_trader.common.component cimport RandomGenerator
+from nautilus_trader.core.rust.model cimport AggressorSide
+from nautilus_trader.model.identifiers cimport ClientOrderId
+from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.instruments.base cimport Instrument
+from nautilus_trader.model.objects cimport Money
+from nautilus_trader.model.objects cimport Price
+from nautilus_trader.model.objects cimport Quantity
+from nautilus_trader.model.orders.base cimport Order
+
 
 cdef class FillModel:
     cdef readonly double prob_fill_on_limit
@@ -23,6 +33,9 @@ cdef class FillModel:
     """The probability of stop orders filling on the stop price.\n\n:returns: `bool`"""
     cdef readonly double prob_slippage
     """The probability of aggressive order execution slipping.\n\n:returns: `bool`"""
+    cdef RandomGenerator _rng
+
+    cpdef void reseed(self, uint64_t seed)
 
     cpdef bint is_limit_filled(self)
     cpdef bint is_stop_filled(self)
@@ -31,6 +44,54 @@ cdef class FillModel:
     cdef bint _event_success(self, double probability)
 
 
+cdef class TradeDrivenFillModel(FillModel):
+    cdef dict _orders
+
+    cpdef void add_order(self, Order order, double queue_ahead)
+    cpdef void remove_order(self, ClientOrderId client_order_id)
+    cpdef list client_order_ids(self)
+    cpdef void update_level_size(self, ClientOrderId client_order_id, double level_size)
+    cpdef bint is_book_fill_allowed(self, bint at_touch)
+    cpdef list process_trade(self, Price price, double size, AggressorSide aggressor_side)
+
+
+cdef class QueuePositionFillModel(TradeDrivenFillModel):
+    cpdef double queue_ahead(self, ClientOrderId client_order_id)
+
+
+cdef class VolumeParticipationFillModel(TradeDrivenFillModel):
+    cdef readonly double participation_rate
+    """The maximum fraction of each trade print which can fill an order.\n\n:returns: `float`"""
+
+
+cdef class LatencyDistribution:
+    cpdef uint64_t sample(self, RandomGenerator rng)
+
+
+cdef class FixedLatency(LatencyDistribution):
+    cdef readonly uint64_t latency_nanos
+    """The latency (nanoseconds).\n\n:returns: `int`"""
+
+
+cdef class NormalLatency(LatencyDistribution):
+    cdef readonly double mean_nanos
+    """The mean latency (nanoseconds).\n\n:returns: `float`"""
+    cdef readonly double std_nanos
+    """The standard deviation (nanoseconds).\n\n:returns: `float`"""
+
+
+cdef class LogNormalLatency(LatencyDistribution):
+    cdef readonly double median_nanos
+    """The median latency (nanoseconds).\n\n:returns: `float`"""
+    cdef readonly double sigma
+    """The standard deviation of the latency logarithm.\n\n:returns: `float`"""
+
+
+cdef class EmpiricalLatency(LatencyDistribution):
+    cdef readonly list samples
+    """The observed latencies (nanoseconds).\n\n:returns: `list[int]`"""
+
+
 cdef class LatencyModel:
     cdef readonly uint64_t base_latency_nanos
     """The default latency to the exchange.\n\n:returns: `int`"""
@@ -40,3 +101,69 @@ cdef class LatencyModel:
     """The latency (nanoseconds) for order update messages to reach the exchange.\n\n:returns: `int`"""
     cdef readonly uint64_t cancel_latency_nanos
     """The latency (nanoseconds) for order cancel messages to reach the exchange.\n\n:returns: `int`"""
+    cdef readonly uint64_t data_latency_nanos
+    """The market data latency (nanoseconds).\n\n:returns: `int`"""
+    cdef readonly LatencyDistribution insert_distribution
+    """The jitter distribution for order insert messages.\n\n:returns: `LatencyDistribution` or ``None``"""
+    cdef readonly LatencyDistribution update_distribution
+    """The jitter distribution for order update messages.\n\n:returns: `LatencyDistribution` or ``None``"""
+    cdef readonly LatencyDistribution cancel_distribution
+    """The jitter distribution for order cancel messages.\n\n:returns: `LatencyDistribution` or ``None``"""
+    cdef readonly LatencyDistribution data_distribution
+    """The jitter distribution for market data.\n\n:returns: `LatencyDistribution` or ``None``"""
+    cdef RandomGenerator _rng
+
+    cpdef void reseed(self, uint64_t seed)
+    cpdef uint64_t insert_latency(self)
+    cpdef uint64_t update_latency(self)
+    cpdef uint64_t cancel_latency(self)
+    cpdef uint64_t data_latency(self)
+
+    cdef uint64_t _sample(self, LatencyDistribution distribution)
+
+
+cdef class FeeModel:
+    cpdef Money get_commission(self, Order order, Quantity fill_qty, Price fill_px, Instrument instrument)
+
+
+cdef class MakerTakerFeeModel(FeeModel):
+    pass
+
+
+cdef class TieredFeeModel(FeeModel):
+    cdef readonly list tiers
+    """The fee tiers as (min_volume, maker_fee, taker_fee) tuples.\n\n:returns: `list[tuple[float, Decimal, Decimal]]`"""
+    cdef readonly double cumulative_volume
+    """The cumulative notional volume charged by the model.\n\n:returns: `float`"""
+
+    cpdef tuple current_tier(self)
+    cpdef void reset(self)
+
+
+cdef class PerContractFeeModel(FeeModel):
+    cdef readonly Money maker_commission
+    """The commission charged per contract for maker fills.\n\n:returns: `Money`"""
+    cdef readonly Money taker_commission
+    """The commission charged per contract for taker fills.\n\n:returns: `Money`"""
+    cdef readonly Money min_commission
+    """The minimum commission charged per fill.\n\n:returns: `Money` or ``None``"""
+
+
+cdef class ShortableSharesModel:
+    cdef readonly dict borrow_rates
+    """The annualized borrow fee rate per instrument.\n\n:returns: `dict[InstrumentId, float]`"""
+    cdef readonly double default_borrow_rate
+    """The annualized borrow fee rate for instruments without a specific rate.\n\n:returns: `float`"""
+    cdef readonly int day_count
+    """The day count basis for accruing borrow fees.\n\n:returns: `int`"""
+    cdef dict _initial_locates
+    cdef dict _available
+
+    cpdef void set_locate(self, InstrumentId instrument_id, Quantity quantity)
+    cpdef object available(self, InstrumentId instrument_id)
+    cpdef bint is_shortable(self, InstrumentId instrument_id, quantity)
+    cpdef void borrow(self, InstrumentId instrument_id, quantity)
+    cpdef void return_shares(self, InstrumentId instrument_id, quantity)
+    cpdef double borrow_rate(self, InstrumentId instrument_id)
+    cpdef Money calculate_borrow_fee(self, Instrument instrument, Quantity quantity, Price price, double days)
+    cpdef void reset(self)
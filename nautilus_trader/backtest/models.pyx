@@ -13,11 +13,24 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
-import random
+from decimal import Decimal
 
+from libc.math cimport exp
 from libc.stdint cimport uint64_t
 
+from nautilus_trader.common.component cimport RandomGenerator
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.rust.model cimport AggressorSide
+from nautilus_trader.core.rust.model cimport LiquiditySide
+from nautilus_trader.core.rust.model cimport OrderSide
+from nautilus_trader.model.functions cimport liquidity_side_to_str
+from nautilus_trader.model.identifiers cimport ClientOrderId
+from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.instruments.base cimport Instrument
+from nautilus_trader.model.objects cimport Money
+from nautilus_trader.model.objects cimport Price
+from nautilus_trader.model.objects cimport Quantity
+from nautilus_trader.model.orders.base cimport Order
 
 
 cdef uint64_t NANOSECONDS_IN_MILLISECOND = 1_000_000
@@ -37,7 +50,9 @@ cdef class FillModel:
     prob_slippage : double
         The probability of order fill prices slipping by one tick.
     random_seed : int, optional
-        The random seed (if None then no random seed).
+        The random seed (if None then a seed is taken from OS entropy).
+        The model draws from its own generator, so results are reproducible
+        for a given seed regardless of any other use of random numbers.
 
     Raises
     ------
@@ -59,14 +74,25 @@ cdef class FillModel:
         Condition.in_range(prob_slippage, 0.0, 1.0, "prob_slippage")
         if random_seed is not None:
             Condition.type(random_seed, int, "random_seed")
-            random.seed(random_seed)
-        else:
-            random.seed()
+
+        self._rng = RandomGenerator(random_seed)
 
         self.prob_fill_on_limit = prob_fill_on_limit
         self.prob_fill_on_stop = prob_fill_on_stop
         self.prob_slippage = prob_slippage
 
+    cpdef void reseed(self, uint64_t seed):
+        """
+        Reseed the models random number generator.
+
+        Parameters
+        ----------
+        seed : uint64_t
+            The random seed.
+
+        """
+        self._rng.reseed(seed)
+
     cpdef bint is_limit_filled(self):
         """
         Return a value indicating whether a ``LIMIT`` order filled.
@@ -103,18 +129,511 @@ cdef class FillModel:
     cdef bint _event_success(self, double probability):
         # Return a result indicating whether an event occurred based on the
         # given probability of the event occurring [0, 1].
-        if probability == 0:
-            return False
-        elif probability == 1:
-            return True
-        else:
-            return probability >= random.random()
+        return self._rng.gen_bool(probability)
+
+
+cdef class TradeDrivenFillModel(FillModel):
+    """
+    The base class for fill models which fill resting limit orders from the
+    recorded trade prints, rather than instantly when the market touches the
+    order price.
+
+    Parameters
+    ----------
+    prob_fill_on_stop : double
+        The probability of stop orders filling if the market rests on its price.
+    prob_slippage : double
+        The probability of order fill prices slipping by one tick.
+    random_seed : int, optional
+        The random seed (if None then a seed is taken from OS entropy).
+
+    Raises
+    ------
+    ValueError
+        If any probability argument is not within range [0, 1].
+    TypeError
+        If `random_seed` is not None and not of type `int`.
+
+    Warnings
+    --------
+    This class should not be used directly, but through a concrete subclass.
+    """
+
+    def __init__(
+        self,
+        double prob_fill_on_stop = 1.0,
+        double prob_slippage = 0.0,
+        random_seed: int | None = None,
+    ):
+        super().__init__(
+            prob_fill_on_limit=1.0,
+            prob_fill_on_stop=prob_fill_on_stop,
+            prob_slippage=prob_slippage,
+            random_seed=random_seed,
+        )
+
+        self._orders = {}
+
+    cpdef void add_order(self, Order order, double queue_ahead):
+        """
+        Start tracking the given resting limit order.
+
+        Parameters
+        ----------
+        order : Order
+            The resting limit order.
+        queue_ahead : double
+            The volume ahead of the order at its price level.
+
+        """
+        Condition.not_none(order, "order")
+        Condition.true(order.has_price_c(), "order has no limit `price`")
+
+        self._orders[order.client_order_id] = [order.side, order.price, max(queue_ahead, 0.0)]
+
+    cpdef void remove_order(self, ClientOrderId client_order_id):
+        """
+        Stop tracking the order for the given client order ID.
+
+        Parameters
+        ----------
+        client_order_id : ClientOrderId
+            The client order ID to remove.
+
+        """
+        self._orders.pop(client_order_id, None)
+
+    cpdef list client_order_ids(self):
+        """
+        Return the tracked client order IDs.
+
+        Returns
+        -------
+        list[ClientOrderId]
+
+        """
+        return list(self._orders.keys())
+
+    cpdef void update_level_size(self, ClientOrderId client_order_id, double level_size):
+        """
+        Update the tracked order for the current size of its price level.
+
+        Parameters
+        ----------
+        client_order_id : ClientOrderId
+            The client order ID to update.
+        level_size : double
+            The current size of the price level.
+
+        """
+        pass  # Optionally override in subclass
+
+    cpdef bint is_book_fill_allowed(self, bint at_touch):
+        """
+        Return whether a resting limit order may fill from the order book
+        (rather than from trade prints) when the market reaches its price.
+
+        Parameters
+        ----------
+        at_touch : bool
+            If the market is at the order price (rather than through it).
+
+        Returns
+        -------
+        bool
+
+        """
+        return not at_touch
+
+    cpdef list process_trade(self, Price price, double size, AggressorSide aggressor_side):
+        """
+        Process the given trade print for the tracked orders.
+
+        Parameters
+        ----------
+        price : Price
+            The trade price.
+        size : double
+            The trade size.
+        aggressor_side : AggressorSide
+            The trade aggressor side (``NO_AGGRESSOR`` applies to both sides).
+
+        Returns
+        -------
+        list[tuple[ClientOrderId, double]]
+            The orders reached by the trade with the volume available to fill each of them.
+
+        """
+        raise NotImplementedError("method `process_trade` must be implemented in the subclass")  # pragma: no cover
+
+
+cdef class QueuePositionFillModel(TradeDrivenFillModel):
+    """
+    Provides a fill model which estimates the queue position of resting limit
+    orders at their price level, only filling once enough traded volume ahead
+    has been consumed.
+
+    The volume ahead is taken from the size of the level at the order price when
+    the order is accepted, reduced by trades at that price and by cancellations
+    (the level size falling below the volume ahead). A trade through the order
+    price is assumed to have consumed the entire queue.
+
+    Parameters
+    ----------
+    prob_fill_on_stop : double
+        The probability of stop orders filling if the market rests on its price.
+    prob_slippage : double
+        The probability of order fill prices slipping by one tick.
+    random_seed : int, optional
+        The random seed (if None then a seed is taken from OS entropy).
+
+    Raises
+    ------
+    ValueError
+        If any probability argument is not within range [0, 1].
+    TypeError
+        If `random_seed` is not None and not of type `int`.
+    """
+
+    cpdef double queue_ahead(self, ClientOrderId client_order_id):
+        """
+        Return the estimated volume ahead of the given order in its queue.
+
+        Returns ``0.0`` if the order is not tracked.
+
+        Parameters
+        ----------
+        client_order_id : ClientOrderId
+            The client order ID for the query.
+
+        Returns
+        -------
+        double
+
+        """
+        cdef list queue = self._orders.get(client_order_id)
+        if queue is None:
+            return 0.0
+        return queue[2]
+
+    cpdef void update_level_size(self, ClientOrderId client_order_id, double level_size):
+        # Volume removed from the level is assumed to have been ahead of the order
+        cdef list queue = self._orders.get(client_order_id)
+        if queue is None:
+            return
+        queue[2] = min(queue[2], max(level_size, 0.0))
+
+    cpdef list process_trade(self, Price price, double size, AggressorSide aggressor_side):
+        Condition.not_none(price, "price")
+
+        cdef list fills = []
+        cdef:
+            ClientOrderId client_order_id
+            list queue
+            OrderSide side
+            Price order_price
+        for client_order_id, queue in self._orders.items():
+            side = queue[0]
+            order_price = queue[1]
+            if side == OrderSide.BUY:
+                if aggressor_side == AggressorSide.BUYER:
+                    continue  # Trade did not hit the bids
+                if price < order_price:
+                    queue[2] = 0.0  # Traded through
+                    continue
+            elif side == OrderSide.SELL:
+                if aggressor_side == AggressorSide.SELLER:
+                    continue  # Trade did not lift the asks
+                if price > order_price:
+                    queue[2] = 0.0  # Traded through
+                    continue
+
+            if price != order_price:
+                continue
+
+            if size > queue[2]:
+                fills.append((client_order_id, size - queue[2]))
+            queue[2] = max(queue[2] - size, 0.0)
+
+        return fills
+
+
+cdef class VolumeParticipationFillModel(TradeDrivenFillModel):
+    """
+    Provides a fill model which fills resting limit orders from trade prints at
+    or through their price, taking at most a fraction of each print.
+
+    The volume available from each eligible print is shared between the tracked
+    orders in priority order (the most aggressive price first, then the earliest
+    arrival), so resting orders only fill as fast as the recorded volume allows
+    and never fill from the order book alone.
+
+    Parameters
+    ----------
+    participation_rate : double, default 1.0
+        The maximum fraction of each trade print which can fill an order.
+    prob_fill_on_stop : double
+        The probability of stop orders filling if the market rests on its price.
+    prob_slippage : double
+        The probability of order fill prices slipping by one tick.
+    random_seed : int, optional
+        The random seed (if None then a seed is taken from OS entropy).
+
+    Raises
+    ------
+    ValueError
+        If `participation_rate` is not within range (0, 1].
+    ValueError
+        If any probability argument is not within range [0, 1].
+    TypeError
+        If `random_seed` is not None and not of type `int`.
+    """
+
+    def __init__(
+        self,
+        double participation_rate = 1.0,
+        double prob_fill_on_stop = 1.0,
+        double prob_slippage = 0.0,
+        random_seed: int | None = None,
+    ):
+        Condition.positive(participation_rate, "participation_rate")
+        Condition.in_range(participation_rate, 0.0, 1.0, "participation_rate")
+
+        super().__init__(
+            prob_fill_on_stop=prob_fill_on_stop,
+            prob_slippage=prob_slippage,
+            random_seed=random_seed,
+        )
+
+        self.participation_rate = participation_rate
+
+    cpdef bint is_book_fill_allowed(self, bint at_touch):
+        return False
+
+    cpdef void add_order(self, Order order, double queue_ahead):
+        TradeDrivenFillModel.add_order(self, order, queue_ahead)
+
+        # Hold the order to cap its allocation by the remaining quantity
+        self._orders[order.client_order_id].append(order)
+
+    cpdef list process_trade(self, Price price, double size, AggressorSide aggressor_side):
+        Condition.not_none(price, "price")
+
+        cdef list eligible = []
+        cdef:
+            ClientOrderId client_order_id
+            list state
+            OrderSide side
+            Price order_price
+            Order order
+            int seq
+        for seq, (client_order_id, state) in enumerate(self._orders.items()):
+            side = state[0]
+            order_price = state[1]
+            if side == OrderSide.BUY:
+                if aggressor_side == AggressorSide.BUYER or price > order_price:
+                    continue
+                # Higher bids have priority
+                eligible.append((price._mem.raw - order_price._mem.raw, seq, client_order_id))
+            elif side == OrderSide.SELL:
+                if aggressor_side == AggressorSide.SELLER or price < order_price:
+                    continue
+                # Lower asks have priority
+                eligible.append((order_price._mem.raw - price._mem.raw, seq, client_order_id))
+
+        eligible.sort()
+
+        cdef list fills = []
+        cdef double remaining = size * self.participation_rate
+        cdef double volume
+        for _, _, client_order_id in eligible:
+            if remaining <= 0.0:
+                break
+            order = self._orders[client_order_id][3]
+            volume = min(remaining, order.leaves_qty.as_f64_c())
+            if volume <= 0.0:
+                continue
+            fills.append((client_order_id, volume))
+            remaining -= volume
+
+        return fills
+
+
+cdef class LatencyDistribution:
+    """
+    The base class for all latency distributions, which sample the jitter
+    (nanoseconds) added to a fixed latency.
+
+    Warnings
+    --------
+    This class should not be used directly, but through a concrete subclass.
+    """
+
+    cpdef uint64_t sample(self, RandomGenerator rng):
+        """
+        Return a latency (nanoseconds) sampled from the distribution.
+
+        Parameters
+        ----------
+        rng : RandomGenerator
+            The random generator to sample with.
+
+        Returns
+        -------
+        uint64_t
+
+        """
+        raise NotImplementedError("method `sample` must be implemented in the subclass")  # pragma: no cover
+
+
+cdef class FixedLatency(LatencyDistribution):
+    """
+    Provides a latency distribution which always returns the same latency.
+
+    Parameters
+    ----------
+    latency_nanos : int
+        The latency (nanoseconds).
+
+    Raises
+    ------
+    ValueError
+        If `latency_nanos` is negative (< 0).
+    """
+
+    def __init__(self, uint64_t latency_nanos):
+        Condition.not_negative_int(latency_nanos, "latency_nanos")
+
+        self.latency_nanos = latency_nanos
+
+    cpdef uint64_t sample(self, RandomGenerator rng):
+        return self.latency_nanos
+
+
+cdef class NormalLatency(LatencyDistribution):
+    """
+    Provides a normally distributed latency, truncated at zero.
+
+    Parameters
+    ----------
+    mean_nanos : double
+        The mean latency (nanoseconds).
+    std_nanos : double
+        The standard deviation (nanoseconds).
+
+    Raises
+    ------
+    ValueError
+        If `mean_nanos` is negative (< 0).
+    ValueError
+        If `std_nanos` is negative (< 0).
+    """
+
+    def __init__(self, double mean_nanos, double std_nanos):
+        Condition.not_negative(mean_nanos, "mean_nanos")
+        Condition.not_negative(std_nanos, "std_nanos")
+
+        self.mean_nanos = mean_nanos
+        self.std_nanos = std_nanos
+
+    cpdef uint64_t sample(self, RandomGenerator rng):
+        return <uint64_t>max(round(rng.gen_normal(self.mean_nanos, self.std_nanos)), 0)
+
+
+cdef class LogNormalLatency(LatencyDistribution):
+    """
+    Provides a lognormally distributed latency, which models the long right tail
+    typical of network latencies.
+
+    Parameters
+    ----------
+    median_nanos : double
+        The median latency (nanoseconds).
+    sigma : double
+        The standard deviation of the latency logarithm.
+
+    Raises
+    ------
+    ValueError
+        If `median_nanos` is not positive (> 0).
+    ValueError
+        If `sigma` is negative (< 0).
+    """
+
+    def __init__(self, double median_nanos, double sigma):
+        Condition.positive(median_nanos, "median_nanos")
+        Condition.not_negative(sigma, "sigma")
+
+        self.median_nanos = median_nanos
+        self.sigma = sigma
+
+    cpdef uint64_t sample(self, RandomGenerator rng):
+        return <uint64_t>round(self.median_nanos * exp(rng.gen_normal(0.0, self.sigma)))
+
+
+cdef class EmpiricalLatency(LatencyDistribution):
+    """
+    Provides a latency distribution which resamples observed latencies.
+
+    Parameters
+    ----------
+    samples : list[int]
+        The observed latencies (nanoseconds).
+
+    Raises
+    ------
+    ValueError
+        If `samples` is empty.
+    ValueError
+        If `samples` contains a negative latency.
+    """
+
+    def __init__(self, list samples not None):
+        Condition.not_empty(samples, "samples")
+        for sample in samples:
+            Condition.not_negative_int(sample, "sample")
+
+        self.samples = samples
+
+    @staticmethod
+    def from_timestamps(list sent_ns not None, list received_ns not None) -> EmpiricalLatency:
+        """
+        Return an empirical latency distribution from recorded timestamps.
+
+        Parameters
+        ----------
+        sent_ns : list[int]
+            The UNIX timestamps (nanoseconds) when each message was sent.
+        received_ns : list[int]
+            The UNIX timestamps (nanoseconds) when each message was received.
+
+        Returns
+        -------
+        EmpiricalLatency
+
+        Raises
+        ------
+        ValueError
+            If `sent_ns` and `received_ns` are not the same length.
+        ValueError
+            If any message was received before it was sent.
+
+        """
+        Condition.equal(len(sent_ns), len(received_ns), "len(sent_ns)", "len(received_ns)")
+
+        return EmpiricalLatency([received - sent for sent, received in zip(sent_ns, received_ns)])
+
+    cpdef uint64_t sample(self, RandomGenerator rng):
+        return self.samples[rng.gen_range(0, len(self.samples))]
 
 
 cdef class LatencyModel:
     """
     Provides a latency model for simulated exchange message I/O.
 
+    Each latency path is a fixed latency plus an optional jitter sampled from
+    a `LatencyDistribution`. Latency models are configured per venue.
+
     Parameters
     ----------
     base_latency_nanos : int, default 1_000_000_000
@@ -125,6 +644,19 @@ cdef class LatencyModel:
         The order update latency (nanoseconds) for the model.
     cancel_latency_nanos : int, default 0
         The order cancel latency (nanoseconds) for the model.
+    data_latency_nanos : int, default 0
+        The market data latency (nanoseconds) for the model, being how far the
+        strategies view of the market lags the venue (not including the base latency).
+    insert_distribution : LatencyDistribution, optional
+        The jitter distribution for order insert messages.
+    update_distribution : LatencyDistribution, optional
+        The jitter distribution for order update messages.
+    cancel_distribution : LatencyDistribution, optional
+        The jitter distribution for order cancel messages.
+    data_distribution : LatencyDistribution, optional
+        The jitter distribution for market data.
+    random_seed : int, optional
+        The random seed (if None then a seed is taken from OS entropy).
 
     Raises
     ------
@@ -136,6 +668,10 @@ cdef class LatencyModel:
         If `update_latency_nanos` is negative (< 0).
     ValueError
         If `cancel_latency_nanos` is negative (< 0).
+    ValueError
+        If `data_latency_nanos` is negative (< 0).
+    TypeError
+        If `random_seed` is not None and not of type `int`.
     """
 
     def __init__(
@@ -144,13 +680,511 @@ cdef class LatencyModel:
         uint64_t insert_latency_nanos = 0,
         uint64_t update_latency_nanos = 0,
         uint64_t cancel_latency_nanos = 0,
+        uint64_t data_latency_nanos = 0,
+        LatencyDistribution insert_distribution = None,
+        LatencyDistribution update_distribution = None,
+        LatencyDistribution cancel_distribution = None,
+        LatencyDistribution data_distribution = None,
+        random_seed: int | None = None,
     ):
         Condition.not_negative_int(base_latency_nanos, "base_latency_nanos")
         Condition.not_negative_int(insert_latency_nanos, "insert_latency_nanos")
         Condition.not_negative_int(update_latency_nanos, "update_latency_nanos")
         Condition.not_negative_int(cancel_latency_nanos, "cancel_latency_nanos")
+        Condition.not_negative_int(data_latency_nanos, "data_latency_nanos")
+        if random_seed is not None:
+            Condition.type(random_seed, int, "random_seed")
+
+        self._rng = RandomGenerator(random_seed)
 
         self.base_latency_nanos = base_latency_nanos
         self.insert_latency_nanos = base_latency_nanos + insert_latency_nanos
         self.update_latency_nanos = base_latency_nanos + update_latency_nanos
         self.cancel_latency_nanos = base_latency_nanos + cancel_latency_nanos
+        self.data_latency_nanos = data_latency_nanos
+        self.insert_distribution = insert_distribution
+        self.update_distribution = update_distribution
+        self.cancel_distribution = cancel_distribution
+        self.data_distribution = data_distribution
+
+    cpdef void reseed(self, uint64_t seed):
+        """
+        Reseed the models random number generator.
+
+        Parameters
+        ----------
+        seed : uint64_t
+            The random seed.
+
+        """
+        self._rng.reseed(seed)
+
+    cpdef uint64_t insert_latency(self):
+        """
+        Return a sampled latency (nanoseconds) for an order insert message.
+
+        Returns
+        -------
+        uint64_t
+
+        """
+        return self.insert_latency_nanos + self._sample(self.insert_distribution)
+
+    cpdef uint64_t update_latency(self):
+        """
+        Return a sampled latency (nanoseconds) for an order update message.
+
+        Returns
+        -------
+        uint64_t
+
+        """
+        return self.update_latency_nanos + self._sample(self.update_distribution)
+
+    cpdef uint64_t cancel_latency(self):
+        """
+        Return a sampled latency (nanoseconds) for an order cancel message.
+
+        Returns
+        -------
+        uint64_t
+
+        """
+        return self.cancel_latency_nanos + self._sample(self.cancel_distribution)
+
+    cpdef uint64_t data_latency(self):
+        """
+        Return a sampled latency (nanoseconds) for market data.
+
+        Returns
+        -------
+        uint64_t
+
+        """
+        return self.data_latency_nanos + self._sample(self.data_distribution)
+
+    cdef uint64_t _sample(self, LatencyDistribution distribution):
+        if distribution is None:
+            return 0
+        return distribution.sample(self._rng)
+
+
+cdef class FeeModel:
+    """
+    The base class for all fee models, which calculate the commission for a fill.
+
+    Custom models can be implemented in Python by subclassing and overriding
+    `get_commission`.
+
+    Warnings
+    --------
+    This class should not be used directly, but through a concrete subclass.
+    """
+
+    cpdef Money get_commission(
+        self,
+        Order order,
+        Quantity fill_qty,
+        Price fill_px,
+        Instrument instrument,
+    ):
+        """
+        Return the commission for a fill of the given order.
+
+        The `order.liquidity_side` is set for the fill prior to this call.
+
+        Parameters
+        ----------
+        order : Order
+            The order being filled.
+        fill_qty : Quantity
+            The fill quantity.
+        fill_px : Price
+            The fill price.
+        instrument : Instrument
+            The instrument for the order.
+
+        Returns
+        -------
+        Money
+
+        """
+        raise NotImplementedError("method `get_commission` must be implemented in the subclass")  # pragma: no cover
+
+
+cdef class MakerTakerFeeModel(FeeModel):
+    """
+    Provides a fee model which applies the instruments `maker_fee` and `taker_fee`
+    rates to the notional value of each fill.
+    """
+
+    cpdef Money get_commission(
+        self,
+        Order order,
+        Quantity fill_qty,
+        Price fill_px,
+        Instrument instrument,
+    ):
+        return _notional_commission(
+            instrument,
+            fill_qty,
+            fill_px,
+            _fee_rate(order.liquidity_side, instrument.maker_fee, instrument.taker_fee),
+        )
+
+
+cdef class TieredFeeModel(FeeModel):
+    """
+    Provides a fee model which applies maker and taker fee rates from a tiered
+    volume schedule.
+
+    The tier is selected from the cumulative notional volume traded prior to
+    each fill, so a fill which crosses a tier boundary is charged entirely at
+    the lower tier.
+
+    Parameters
+    ----------
+    tiers : list[tuple[float, Decimal, Decimal]]
+        The fee tiers as (min_volume, maker_fee, taker_fee) tuples, ordered by
+        strictly increasing `min_volume` with the first tier starting from zero.
+
+    Raises
+    ------
+    ValueError
+        If `tiers` is empty.
+    ValueError
+        If the first tier `min_volume` is not zero.
+    ValueError
+        If the tier `min_volume` values are not strictly increasing.
+    """
+
+    def __init__(self, list tiers not None):
+        Condition.not_empty(tiers, "tiers")
+        Condition.true(tiers[0][0] == 0, "first tier `min_volume` was not zero")
+        for i in range(1, len(tiers)):
+            Condition.true(
+                tiers[i - 1][0] < tiers[i][0],
+                "tier `min_volume` values were not strictly increasing",
+            )
+
+        self.tiers = [(float(t[0]), Decimal(t[1]), Decimal(t[2])) for t in tiers]
+        self.cumulative_volume = 0.0
+
+    cpdef tuple current_tier(self):
+        """
+        Return the tier applicable for the current cumulative volume.
+
+        Returns
+        -------
+        tuple[float, Decimal, Decimal]
+
+        """
+        cdef tuple tier
+        for tier in reversed(self.tiers):
+            if self.cumulative_volume >= tier[0]:
+                return tier
+        return self.tiers[0]
+
+    cpdef void reset(self):
+        """
+        Reset the cumulative volume (for example at the start of a new fee period).
+        """
+        self.cumulative_volume = 0.0
+
+    cpdef Money get_commission(
+        self,
+        Order order,
+        Quantity fill_qty,
+        Price fill_px,
+        Instrument instrument,
+    ):
+        cdef tuple tier = self.current_tier()
+        cdef Money commission = _notional_commission(
+            instrument,
+            fill_qty,
+            fill_px,
+            _fee_rate(order.liquidity_side, tier[1], tier[2]),
+        )
+        self.cumulative_volume += instrument.notional_value(fill_qty, fill_px).as_f64_c()
+        return commission
+
+
+cdef class PerContractFeeModel(FeeModel):
+    """
+    Provides a fee model which charges a fixed commission per contract (or
+    share), subject to an optional minimum per fill.
+
+    Parameters
+    ----------
+    maker_commission : Money
+        The commission charged per contract for ``MAKER`` fills.
+    taker_commission : Money
+        The commission charged per contract for ``TAKER`` fills.
+    min_commission : Money, optional
+        The minimum commission charged per fill.
+
+    Raises
+    ------
+    ValueError
+        If the commissions are not all denominated in the same currency.
+    """
+
+    def __init__(
+        self,
+        Money maker_commission not None,
+        Money taker_commission not None,
+        Money min_commission = None,
+    ):
+        Condition.equal(
+            maker_commission.currency,
+            taker_commission.currency,
+            "maker_commission.currency",
+            "taker_commission.currency",
+        )
+        if min_commission is not None:
+            Condition.equal(
+                maker_commission.currency,
+                min_commission.currency,
+                "maker_commission.currency",
+                "min_commission.currency",
+            )
+
+        self.maker_commission = maker_commission
+        self.taker_commission = taker_commission
+        self.min_commission = min_commission
+
+    cpdef Money get_commission(
+        self,
+        Order order,
+        Quantity fill_qty,
+        Price fill_px,
+        Instrument instrument,
+    ):
+        cdef Money per_contract
+        if order.liquidity_side == LiquiditySide.MAKER:
+            per_contract = self.maker_commission
+        elif order.liquidity_side == LiquiditySide.TAKER:
+            per_contract = self.taker_commission
+        else:
+            raise ValueError(
+                f"invalid `LiquiditySide`, was {liquidity_side_to_str(order.liquidity_side)}"
+            )
+
+        cdef double amount = per_contract.as_f64_c() * fill_qty.as_f64_c()
+        if self.min_commission is not None:
+            amount = max(amount, self.min_commission.as_f64_c())
+
+        return Money(amount, per_contract.currency)
+
+
+cdef class ShortableSharesModel:
+    """
+    Provides a model of short-sell locate availability and borrow fees for equities.
+
+    Instruments without a locate entry are treated as easy-to-borrow, with unlimited
+    shares available to short.
+
+    Parameters
+    ----------
+    locates : dict[InstrumentId, Quantity], optional
+        The shares available to borrow (locate) per instrument.
+    borrow_rates : dict[InstrumentId, float], optional
+        The annualized borrow fee rate per instrument.
+    default_borrow_rate : float, default 0.0
+        The annualized borrow fee rate for instruments without a specific rate.
+    day_count : int, default 360
+        The day count basis for accruing borrow fees.
+
+    Raises
+    ------
+    ValueError
+        If `default_borrow_rate` is negative.
+    ValueError
+        If `day_count` is not positive (> 0).
+    """
+
+    def __init__(
+        self,
+        dict locates = None,
+        dict borrow_rates = None,
+        double default_borrow_rate = 0.0,
+        int day_count = 360,
+    ):
+        Condition.not_negative(default_borrow_rate, "default_borrow_rate")
+        Condition.positive_int(day_count, "day_count")
+
+        self.borrow_rates = borrow_rates or {}
+        self.default_borrow_rate = default_borrow_rate
+        self.day_count = day_count
+        self._initial_locates = {
+            instrument_id: quantity.as_decimal()
+            for instrument_id, quantity in (locates or {}).items()
+        }
+        self._available = dict(self._initial_locates)
+
+    cpdef void set_locate(self, InstrumentId instrument_id, Quantity quantity):
+        """
+        Set the shares available to borrow for the given instrument.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the locate.
+        quantity : Quantity
+            The shares available to borrow.
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+        Condition.not_none(quantity, "quantity")
+
+        self._available[instrument_id] = quantity.as_decimal()
+
+    cpdef object available(self, InstrumentId instrument_id):
+        """
+        Return the shares currently available to borrow for the given instrument.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the query.
+
+        Returns
+        -------
+        Decimal or ``None``
+            ``None`` if the instrument is easy-to-borrow.
+
+        """
+        return self._available.get(instrument_id)
+
+    cpdef bint is_shortable(self, InstrumentId instrument_id, quantity):
+        """
+        Return whether the given quantity of shares can be borrowed to sell short.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the query.
+        quantity : Decimal
+            The quantity of shares to borrow.
+
+        Returns
+        -------
+        bool
+
+        """
+        available = self._available.get(instrument_id)
+        return available is None or quantity <= available
+
+    cpdef void borrow(self, InstrumentId instrument_id, quantity):
+        """
+        Borrow the given quantity of shares against the locate for the instrument.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the borrow.
+        quantity : Decimal
+            The quantity of shares borrowed.
+
+        """
+        available = self._available.get(instrument_id)
+        if available is not None:
+            self._available[instrument_id] = max(available - quantity, Decimal(0))
+
+    cpdef void return_shares(self, InstrumentId instrument_id, quantity):
+        """
+        Return the given quantity of borrowed shares to the locate for the instrument.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the return.
+        quantity : Decimal
+            The quantity of shares returned (on covering a short).
+
+        """
+        available = self._available.get(instrument_id)
+        if available is not None:
+            self._available[instrument_id] = available + quantity
+
+    cpdef double borrow_rate(self, InstrumentId instrument_id):
+        """
+        Return the annualized borrow fee rate for the given instrument.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the query.
+
+        Returns
+        -------
+        double
+
+        """
+        return self.borrow_rates.get(instrument_id, self.default_borrow_rate)
+
+    cpdef Money calculate_borrow_fee(
+        self,
+        Instrument instrument,
+        Quantity quantity,
+        Price price,
+        double days,
+    ):
+        """
+        Return the borrow fee accrued on a short position over the given days.
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The instrument for the short position.
+        quantity : Quantity
+            The short position quantity.
+        price : Price
+            The price for valuing the short position.
+        days : double
+            The number of days accrued.
+
+        Returns
+        -------
+        Money
+
+        """
+        Condition.not_none(instrument, "instrument")
+        Condition.not_none(quantity, "quantity")
+        Condition.not_none(price, "price")
+
+        cdef Money notional = instrument.notional_value(quantity, price)
+        cdef double rate = self.borrow_rate(instrument.id)
+        return Money(notional.as_f64_c() * rate * days / self.day_count, notional.currency)
+
+    cpdef void reset(self):
+        """
+        Reset the model, restoring the initial locates.
+        """
+        self._available = dict(self._initial_locates)
+
+
+cdef double _fee_rate(LiquiditySide liquidity_side, maker_fee, taker_fee):
+    if liquidity_side == LiquiditySide.MAKER:
+        return float(maker_fee)
+    elif liquidity_side == LiquiditySide.TAKER:
+        return float(taker_fee)
+    else:
+        raise ValueError(
+            f"invalid `LiquiditySide`, was {liquidity_side_to_str(liquidity_side)}"
+        )
+
+
+cdef Money _notional_commission(
+    Instrument instrument,
+    Quantity fill_qty,
+    Price fill_px,
+    double rate,
+):
+    # Inverse instruments are charged in the base currency of the notional value
+    cdef Money notional = instrument.notional_value(
+        quantity=fill_qty,
+        price=fill_px,
+        use_quote_for_inverse=False,
+    )
+    return Money(notional.as_f64_c() * rate, notional.currency)
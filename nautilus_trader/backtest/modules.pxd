@@ -21,6 +21,7 @@ from nautilus_trader.backtest.exchange cimport SimulatedExchange
 from nautilus_trader.common.actor cimport Actor
 from nautilus_trader.common.component cimport Logger
 from nautilus_trader.core.data cimport Data
+from nautilus_trader.model.identifiers cimport InstrumentId
 
 
 cdef class SimulationModule(Actor):
@@ -42,3 +43,14 @@ cdef class FXRolloverInterestModule(SimulationModule):
     cdef int _day_number
 
     cdef void _apply_rollover_interest(self, datetime timestamp, int iso_week_day)
+
+
+cdef class FundingPaymentModule(SimulationModule):
+    cdef dict _rates
+    cdef double _default_rate
+    cdef uint64_t _interval_ns
+    cdef uint64_t _next_funding_ns
+    cdef dict _funding_totals
+
+    cpdef double funding_rate(self, InstrumentId instrument_id, uint64_t ts_funding)
+    cdef void _apply_funding_payments(self, uint64_t ts_funding)
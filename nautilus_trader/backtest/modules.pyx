@@ -17,6 +17,7 @@ import msgspec
 import pandas as pd
 import pytz
 
+from nautilus_trader.backtest.config import FundingPaymentConfig
 from nautilus_trader.backtest.config import FXRolloverInterestConfig
 from nautilus_trader.backtest.config import SimulationModuleConfig
 from nautilus_trader.common.config import ActorConfig
@@ -29,10 +30,12 @@ from nautilus_trader.backtest.exchange cimport SimulatedExchange
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.rust.model cimport AssetClass
+from nautilus_trader.core.rust.core cimport NANOSECONDS_IN_SECOND
 from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.model.book cimport OrderBook
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.instruments.base cimport Instrument
+from nautilus_trader.model.instruments.crypto_perpetual cimport CryptoPerpetual
 from nautilus_trader.model.objects cimport Currency
 from nautilus_trader.model.objects cimport Money
 from nautilus_trader.model.objects cimport Price
@@ -219,3 +222,125 @@ cdef class FXRolloverInterestModule(SimulationModule):
         self._rollover_applied = False
         self._rollover_totals = {}
         self._day_number = 0
+
+
+cdef class FundingPaymentModule(SimulationModule):
+    """
+    Provides a perpetual swap funding payment simulation module.
+
+    At each funding time the funding rate is applied to the notional value of open
+    perpetual positions, with long positions paying (and short positions receiving)
+    a positive rate. The funding rate is taken from the configured `rate_data`,
+    otherwise the latest `FundingRateUpdate` in the cache, otherwise the `default_rate`.
+
+    Parameters
+    ----------
+    config  : FundingPaymentConfig
+    """
+
+    def __init__(self, config: FundingPaymentConfig):
+        super().__init__(config)
+
+        self._rates = {}
+        if config.rate_data is not None:
+            for instrument_id, rates in config.rate_data.groupby("instrument_id"):
+                self._rates[InstrumentId.from_str_c(str(instrument_id))] = rates["rate"].sort_index()
+
+        self._default_rate = config.default_rate
+        self._interval_ns = config.funding_interval_hours * 60 * 60 * NANOSECONDS_IN_SECOND
+        self._next_funding_ns = 0  # Initialized on first process
+        self._funding_totals = {}
+
+    cpdef void process(self, uint64_t ts_now):
+        """
+        Process the given tick through the module.
+
+        Parameters
+        ----------
+        ts_now : uint64_t
+            The current UNIX time (nanoseconds) in the simulated exchange.
+
+        """
+        if self._next_funding_ns == 0:
+            self._next_funding_ns = (ts_now // self._interval_ns + 1) * self._interval_ns
+            return
+
+        while ts_now >= self._next_funding_ns:
+            self._apply_funding_payments(self._next_funding_ns)
+            self._next_funding_ns += self._interval_ns
+
+    cpdef double funding_rate(self, InstrumentId instrument_id, uint64_t ts_funding):
+        """
+        Return the funding rate for the given instrument at the funding time.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the funding rate.
+        ts_funding : uint64_t
+            The UNIX timestamp (nanoseconds) of the funding time.
+
+        Returns
+        -------
+        double
+
+        """
+        rates = self._rates.get(instrument_id)
+        if rates is not None:
+            rate = rates.asof(pd.Timestamp(ts_funding, tz="UTC"))
+            if not pd.isna(rate):
+                return float(rate)
+
+        update = self.exchange.cache.funding_rate(instrument_id)
+        if update is not None:
+            return float(update.rate)
+
+        return self._default_rate
+
+    cdef void _apply_funding_payments(self, uint64_t ts_funding):
+        cdef:
+            Position position
+            Instrument instrument
+            Price price
+            Money notional
+            double payment
+        for position in self.exchange.cache.positions_open(venue=self.exchange.id):
+            instrument = self.exchange.instruments.get(position.instrument_id)
+            if not isinstance(instrument, CryptoPerpetual):
+                continue  # Only applicable to perpetual swaps
+
+            price = (
+                self.exchange.cache.price(instrument.id, PriceType.MID)
+                or self.exchange.cache.price(instrument.id, PriceType.LAST)
+            )
+            if price is None:
+                self._log.warning(f"Cannot apply funding payment for {instrument.id}, no market prices")
+                continue
+
+            notional = instrument.notional_value(position.quantity, price)
+            payment = notional.as_f64_c() * self.funding_rate(instrument.id, ts_funding)
+            if position.is_long_c():
+                payment = -payment  # Longs pay a positive funding rate
+
+            self._funding_totals[notional.currency] = Money(
+                self._funding_totals.get(notional.currency, 0.0) + payment,
+                notional.currency,
+            )
+            self.exchange.adjust_account(Money(payment, notional.currency))
+
+    cpdef void log_diagnostics(self, Logger logger):
+        """
+        Log diagnostics out to the `BacktestEngine` logger.
+
+        Parameters
+        ----------
+        logger : Logger
+            The logger to log to.
+
+        """
+        funding_totals = ', '.join([b.to_str() for b in self._funding_totals.values()])
+        logger.info(f"Funding payments (totals): {funding_totals}")
+
+    cpdef void reset(self):
+        self._next_funding_ns = 0  # Initialized on first process
+        self._funding_totals = {}
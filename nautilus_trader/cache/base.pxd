@@ -59,6 +59,8 @@ cdef class CacheFacade:
     cpdef QuoteTick quote_tick(self, InstrumentId instrument_id, int index=*)
     cpdef TradeTick trade_tick(self, InstrumentId instrument_id, int index=*)
     cpdef Bar bar(self, BarType bar_type, int index=*)
+    cpdef funding_rate(self, InstrumentId instrument_id)
+    cpdef greeks(self, InstrumentId instrument_id)
     cpdef int book_update_count(self, InstrumentId instrument_id)
     cpdef int quote_tick_count(self, InstrumentId instrument_id)
     cpdef int trade_tick_count(self, InstrumentId instrument_id)
@@ -67,6 +69,8 @@ cdef class CacheFacade:
     cpdef bint has_quote_ticks(self, InstrumentId instrument_id)
     cpdef bint has_trade_ticks(self, InstrumentId instrument_id)
     cpdef bint has_bars(self, BarType bar_type)
+    cpdef bint has_funding_rate(self, InstrumentId instrument_id)
+    cpdef bint has_greeks(self, InstrumentId instrument_id)
 
     cpdef double get_xrate(
         self,
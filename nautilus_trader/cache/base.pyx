@@ -83,6 +83,14 @@ cdef class CacheFacade:
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `bar` must be implemented in the subclass")  # pragma: no cover
 
+    cpdef funding_rate(self, InstrumentId instrument_id):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `funding_rate` must be implemented in the subclass")  # pragma: no cover
+
+    cpdef greeks(self, InstrumentId instrument_id):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `greeks` must be implemented in the subclass")  # pragma: no cover
+
     cpdef int book_update_count(self, InstrumentId instrument_id):
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `book_update_count` must be implemented in the subclass")  # pragma: no cover
@@ -115,6 +123,14 @@ cdef class CacheFacade:
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `has_bars` must be implemented in the subclass")  # pragma: no cover
 
+    cpdef bint has_funding_rate(self, InstrumentId instrument_id):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `has_funding_rate` must be implemented in the subclass")  # pragma: no cover
+
+    cpdef bint has_greeks(self, InstrumentId instrument_id):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `has_greeks` must be implemented in the subclass")  # pragma: no cover
+
     cpdef double get_xrate(
         self,
         Venue venue,
@@ -63,6 +63,8 @@ cdef class Cache(CacheFacade):
     cdef dict _bars
     cdef dict _bars_bid
     cdef dict _bars_ask
+    cdef dict _funding_rates
+    cdef dict _greeks
     cdef dict _currencies
     cdef dict _instruments
     cdef dict _synthetics
@@ -152,6 +154,8 @@ cdef class Cache(CacheFacade):
     cpdef void add_quote_ticks(self, list ticks)
     cpdef void add_trade_ticks(self, list ticks)
     cpdef void add_bars(self, list bars)
+    cpdef void add_funding_rate(self, funding_rate)
+    cpdef void add_greeks(self, greeks)
     cpdef void add_currency(self, Currency currency)
     cpdef void add_instrument(self, Instrument instrument)
     cpdef void add_synthetic(self, SyntheticInstrument synthetic)
@@ -21,6 +21,7 @@ from collections import deque
 from decimal import Decimal
 
 from nautilus_trader.cache.config import CacheConfig
+from nautilus_trader.core import nautilus_pyo3
 
 from cpython.datetime cimport datetime
 from libc.stdint cimport uint8_t
@@ -37,6 +38,7 @@ from nautilus_trader.core.rust.model cimport OmsType
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport PositionSide
 from nautilus_trader.core.rust.model cimport PriceType
+from nautilus_trader.core.rust.model cimport QuantityRaw
 from nautilus_trader.core.rust.model cimport TriggerType
 from nautilus_trader.execution.messages cimport SubmitOrder
 from nautilus_trader.model.data cimport Bar
@@ -119,6 +121,8 @@ cdef class Cache(CacheFacade):
         self._bars: dict[BarType, deque[Bar]] = {}
         self._bars_bid: dict[InstrumentId, Bar] = {}
         self._bars_ask: dict[InstrumentId, Bar] = {}
+        self._funding_rates: dict[InstrumentId, nautilus_pyo3.FundingRateUpdate] = {}
+        self._greeks: dict[InstrumentId, nautilus_pyo3.GreeksData] = {}
         self._currencies: dict[str, Currency] = {}
         self._instruments: dict[InstrumentId, Instrument] = {}
         self._synthetics: dict[InstrumentId, SyntheticInstrument] = {}
@@ -723,6 +727,8 @@ cdef class Cache(CacheFacade):
         self._bars.clear()
         self._bars_bid.clear()
         self._bars_ask.clear()
+        self._funding_rates.clear()
+        self._greeks.clear()
         self._currencies.clear()
         self._synthetics.clear()
         self._accounts.clear()
@@ -1178,6 +1184,40 @@ cdef class Cache(CacheFacade):
         elif price_type == PriceType.ASK:
             self._bars_ask[bar.bar_type.instrument_id] = bar
 
+    cpdef void add_funding_rate(self, funding_rate):
+        """
+        Add the given funding rate update to the cache.
+
+        Only the latest funding rate per instrument is retained.
+
+        Parameters
+        ----------
+        funding_rate : nautilus_pyo3.FundingRateUpdate
+            The funding rate update to add.
+
+        """
+        Condition.not_none(funding_rate, "funding_rate")
+
+        cdef InstrumentId instrument_id = InstrumentId.from_str_c(funding_rate.instrument_id.value)
+        self._funding_rates[instrument_id] = funding_rate
+
+    cpdef void add_greeks(self, greeks):
+        """
+        Add the given greeks to the cache.
+
+        Only the latest greeks per instrument are retained.
+
+        Parameters
+        ----------
+        greeks : nautilus_pyo3.GreeksData
+            The greeks to add.
+
+        """
+        Condition.not_none(greeks, "greeks")
+
+        cdef InstrumentId instrument_id = InstrumentId.from_str_c(greeks.instrument_id.value)
+        self._greeks[instrument_id] = greeks
+
     cpdef void add_quote_ticks(self, list ticks):
         """
         Add the given quote ticks to the cache.
@@ -2147,6 +2187,42 @@ cdef class Cache(CacheFacade):
         except IndexError:
             return None
 
+    cpdef funding_rate(self, InstrumentId instrument_id):
+        """
+        Return the latest funding rate update for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the funding rate to get.
+
+        Returns
+        -------
+        nautilus_pyo3.FundingRateUpdate or ``None``
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return self._funding_rates.get(instrument_id)
+
+    cpdef greeks(self, InstrumentId instrument_id):
+        """
+        Return the latest greeks for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the greeks to get.
+
+        Returns
+        -------
+        nautilus_pyo3.GreeksData or ``None``
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return self._greeks.get(instrument_id)
+
     cpdef int book_update_count(self, InstrumentId instrument_id):
         """
         The count of order book updates for the given instrument ID.
@@ -2299,6 +2375,44 @@ cdef class Cache(CacheFacade):
 
         return self.bar_count(bar_type) > 0
 
+    cpdef bint has_funding_rate(self, InstrumentId instrument_id):
+        """
+        Return a value indicating whether the cache has a funding rate for the
+        given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the funding rate.
+
+        Returns
+        -------
+        bool
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return instrument_id in self._funding_rates
+
+    cpdef bint has_greeks(self, InstrumentId instrument_id):
+        """
+        Return a value indicating whether the cache has greeks for the given
+        instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the greeks.
+
+        Returns
+        -------
+        bool
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return instrument_id in self._greeks
+
     cpdef double get_xrate(
         self,
         Venue venue,
@@ -3570,7 +3684,7 @@ cdef class Cache(CacheFacade):
         cdef:
             Order spawn_order
             uint8_t precision = 0
-            uint64_t raw_total_quantity = 0
+            QuantityRaw raw_total_quantity = 0
         for spawn_order in exec_spawn_orders:
             precision = spawn_order.quantity._mem.precision
             if not active_only or not spawn_order.is_closed_c():
@@ -3610,7 +3724,7 @@ cdef class Cache(CacheFacade):
         cdef:
             Order spawn_order
             uint8_t precision = 0
-            uint64_t raw_filled_qty = 0
+            QuantityRaw raw_filled_qty = 0
         for spawn_order in exec_spawn_orders:
             precision = spawn_order.filled_qty._mem.precision
             if not active_only or not spawn_order.is_closed_c():
@@ -3650,7 +3764,7 @@ cdef class Cache(CacheFacade):
         cdef:
             Order spawn_order
             uint8_t precision = 0
-            uint64_t raw_leaves_qty = 0
+            QuantityRaw raw_leaves_qty = 0
         for spawn_order in exec_spawn_orders:
             precision = spawn_order.leaves_qty._mem.precision
             if not active_only or not spawn_order.is_closed_c():
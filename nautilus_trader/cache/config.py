@@ -34,9 +34,13 @@ class CacheConfig(NautilusConfig, frozen=True):
         If timestamps should be persisted as ISO 8601 strings.
         If `False` then will persit as UNIX nanoseconds.
     buffer_interval_ms : PositiveInt, optional
-        The buffer interval (milliseconds) between pipelined/batched transactions.
+        The flush interval (milliseconds) between pipelined/batched transactions.
         The recommended range if using buffered pipeling is [10, 1000] milliseconds,
-        with a good compromise being 100 milliseconds.
+        with a good compromise being 100 milliseconds. If ``None`` then all commands
+        pending at each write are flushed together as a single pipelined transaction.
+    max_batch_size : PositiveInt, default 1000
+        The maximum number of buffered commands per pipelined/batched transaction,
+        a full batch is flushed without waiting for the buffer interval.
     use_trader_prefix : bool, default True
         If a 'trader-' prefix is used for keys.
     use_instance_id : bool, default False
@@ -56,6 +60,7 @@ class CacheConfig(NautilusConfig, frozen=True):
     encoding: str = "msgpack"
     timestamps_as_iso8601: bool = False
     buffer_interval_ms: PositiveInt | None = None
+    max_batch_size: PositiveInt = 1_000
     use_trader_prefix: bool = True
     use_instance_id: bool = False
     flush_on_start: bool = False
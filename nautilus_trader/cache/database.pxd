@@ -13,7 +13,10 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from nautilus_trader.accounting.accounts.base cimport Account
 from nautilus_trader.cache.facade cimport CacheDatabaseFacade
+from nautilus_trader.model.orders.base cimport Order
+from nautilus_trader.model.position cimport Position
 from nautilus_trader.serialization.base cimport Serializer
 
 
@@ -47,3 +50,8 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
 
     cdef Serializer _serializer
     cdef object _backing
+    cdef bint _bulk_load
+
+    cdef Account _unpack_account(self, list result)
+    cdef Order _unpack_order(self, list result)
+    cdef Position _unpack_position(self, list result)
@@ -94,6 +94,12 @@ cdef str _SNAPSHOTS_ORDERS = "snapshots:orders"
 cdef str _SNAPSHOTS_POSITIONS = "snapshots:positions"
 cdef str _HEARTBEAT = "health:heartbeat"
 
+# State index flags for atomic order and position updates (mirrors the Rust backing)
+cdef int _INDEX_FLAG_INFLIGHT = 1 << 0
+cdef int _INDEX_FLAG_OPEN = 1 << 1
+cdef int _INDEX_FLAG_CLOSED = 1 << 2
+cdef int _INDEX_FLAG_EMULATED = 1 << 3
+
 
 cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
     """
@@ -150,6 +156,7 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
         self._log.info(f"{config.encoding=}", LogColor.BLUE)
         self._log.info(f"{config.timestamps_as_iso8601=}", LogColor.BLUE)
         self._log.info(f"{config.buffer_interval_ms=}", LogColor.BLUE)
+        self._log.info(f"{config.max_batch_size=}", LogColor.BLUE)
         self._log.info(f"{config.flush_on_start=}", LogColor.BLUE)
         self._log.info(f"{config.use_trader_prefix=}", LogColor.BLUE)
         self._log.info(f"{config.use_instance_id=}", LogColor.BLUE)
@@ -184,11 +191,27 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
 
         self._serializer = serializer
 
-        self._backing = nautilus_pyo3.RedisCacheDatabase(
-            trader_id=nautilus_pyo3.TraderId(trader_id.value),
-            instance_id=nautilus_pyo3.UUID4(instance_id.value),
-            config_json=msgspec.json.encode(config),
-        )
+        if config.database is not None and config.database.type == "postgres":
+            self._backing = nautilus_pyo3.PostgresCacheDatabase(
+                trader_id=nautilus_pyo3.TraderId(trader_id.value),
+                instance_id=nautilus_pyo3.UUID4(instance_id.value),
+                config_json=msgspec.json.encode(config),
+            )
+            self._bulk_load = True
+        elif config.database is not None and config.database.type == "sqlite":
+            self._backing = nautilus_pyo3.SqliteCacheDatabase(
+                trader_id=nautilus_pyo3.TraderId(trader_id.value),
+                instance_id=nautilus_pyo3.UUID4(instance_id.value),
+                config_json=msgspec.json.encode(config),
+            )
+            self._bulk_load = True
+        else:
+            self._backing = nautilus_pyo3.RedisCacheDatabase(
+                trader_id=nautilus_pyo3.TraderId(trader_id.value),
+                instance_id=nautilus_pyo3.UUID4(instance_id.value),
+                config_json=msgspec.json.encode(config),
+            )
+            self._bulk_load = False
 
 # -- COMMANDS -------------------------------------------------------------------------------------
 
@@ -355,6 +378,16 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
         """
         cdef dict accounts = {}
 
+        cdef:
+            list result
+            Account account
+        if self._bulk_load:
+            for result in self._backing.load_collection(_ACCOUNTS).values():
+                account = self._unpack_account(result)
+                if account is not None:
+                    accounts[account.id] = account
+            return accounts
+
         cdef list account_keys = self._backing.keys(f"*:{_ACCOUNTS}*")
         if not account_keys:
             return accounts
@@ -363,7 +396,6 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
             str key
             str account_str
             AccountId account_id
-            Account account
         for key in account_keys:
             account_id = AccountId(key.rsplit(':', maxsplit=1)[1])
             account = self.load_account(account_id)
@@ -384,6 +416,16 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
         """
         cdef dict orders = {}
 
+        cdef:
+            list result
+            Order order
+        if self._bulk_load:
+            for result in self._backing.load_collection(_ORDERS).values():
+                order = self._unpack_order(result)
+                if order is not None:
+                    orders[order.client_order_id] = order
+            return orders
+
         cdef list order_keys = self._backing.keys(f"*:{_ORDERS}*")
         if not order_keys:
             return orders
@@ -391,7 +433,6 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
         cdef:
             str key
             ClientOrderId client_order_id
-            Order order
         for key in order_keys:
             client_order_id = ClientOrderId(key.rsplit(':', maxsplit=1)[1])
             order = self.load_order(client_order_id)
@@ -412,6 +453,16 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
         """
         cdef dict positions = {}
 
+        cdef:
+            list result
+            Position position
+        if self._bulk_load:
+            for result in self._backing.load_collection(_POSITIONS).values():
+                position = self._unpack_position(result)
+                if position is not None:
+                    positions[position.id] = position
+            return positions
+
         cdef list position_keys = self._backing.keys(f"*:{_POSITIONS}*")
         if not position_keys:
             return positions
@@ -419,7 +470,6 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
         cdef:
             str key
             PositionId position_id
-            Position position
         for key in position_keys:
             position_id = PositionId(key.rsplit(':', maxsplit=1)[1])
             position = self.load_position(position_id)
@@ -568,7 +618,9 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
         Condition.not_none(account_id, "account_id")
 
         cdef str key = f"{_ACCOUNTS}:{account_id.to_str()}"
-        cdef list result = self._backing.read(key)
+        return self._unpack_account(self._backing.read(key))
+
+    cdef Account _unpack_account(self, list result):
         if not result:
             return None
 
@@ -598,8 +650,9 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
         Condition.not_none(client_order_id, "client_order_id")
 
         cdef str key = f"{_ORDERS}:{client_order_id.to_str()}"
-        cdef list result = self._backing.read(key)
+        return self._unpack_order(self._backing.read(key))
 
+    cdef Order _unpack_order(self, list result):
         # Check there is at least one event to pop
         if not result:
             return None
@@ -650,8 +703,9 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
         Condition.not_none(position_id, "position_id")
 
         cdef str key = f"{_POSITIONS}:{position_id.to_str()}"
-        cdef list result = self._backing.read(key)
+        return self._unpack_position(self._backing.read(key))
 
+    cdef Position _unpack_position(self, list result):
         # Check there is at least one event to pop
         if not result:
             return None
@@ -1027,36 +1081,25 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
         """
         Condition.not_none(order, "order")
 
-        cdef str client_order_id_str = order.client_order_id.to_str()
-        cdef str key = f"{_ORDERS}:{client_order_id_str}"
-        cdef list payload = [self._serializer.serialize(order.last_event_c())]
-        self._backing.update(key, payload)
+        cdef str key = f"{_ORDERS}:{order.client_order_id.to_str()}"
+        cdef bytes event = self._serializer.serialize(order.last_event_c())
 
         if order.venue_order_id is not None:
             # Assumes order_id does not change
             self.index_venue_order_id(order.client_order_id, order.venue_order_id)
 
-        payload = [client_order_id_str.encode()]
-
-        # Update in-flight state
+        # The event and in-flight/open/closed/emulation index updates are applied atomically
+        cdef int flags = 0
         if order.is_inflight_c():
-            self._backing.insert(_INDEX_ORDERS_INFLIGHT, payload)
-        else:
-            self._backing.delete(_INDEX_ORDERS_INFLIGHT, payload)
-
-        # Update open/closed state
+            flags |= _INDEX_FLAG_INFLIGHT
         if order.is_open_c():
-            self._backing.delete(_INDEX_ORDERS_CLOSED, payload)
-            self._backing.insert(_INDEX_ORDERS_OPEN, payload)
+            flags |= _INDEX_FLAG_OPEN
         elif order.is_closed_c():
-            self._backing.delete(_INDEX_ORDERS_OPEN, payload)
-            self._backing.insert(_INDEX_ORDERS_CLOSED, payload)
+            flags |= _INDEX_FLAG_CLOSED
+        if order.emulation_trigger != TriggerType.NO_TRIGGER:
+            flags |= _INDEX_FLAG_EMULATED
 
-        # Update emulation state
-        if order.emulation_trigger == TriggerType.NO_TRIGGER:
-            self._backing.delete(_INDEX_ORDERS_EMULATED, payload)
-        else:
-            self._backing.insert(_INDEX_ORDERS_EMULATED, payload)
+        self._backing.update_order(key, event, flags)
 
         self._log.debug(f"Updated {order}.")
 
@@ -1072,17 +1115,17 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
         """
         Condition.not_none(position, "position")
 
-        cdef str position_id_str = position.id.to_str()
         cdef str key = f"{_POSITIONS}:{position.id.to_str()}"
-        cdef list payload = [self._serializer.serialize(position.last_event_c())]
-        self._backing.update(key, payload)
+        cdef bytes event = self._serializer.serialize(position.last_event_c())
 
+        # The event and open/closed index updates are applied atomically
+        cdef int flags = 0
         if position.is_open_c():
-            self._backing.insert(_INDEX_POSITIONS_OPEN, payload)
-            self._backing.delete(_INDEX_POSITIONS_CLOSED, payload)
+            flags |= _INDEX_FLAG_OPEN
         elif position.is_closed_c():
-            self._backing.insert(_INDEX_POSITIONS_CLOSED, payload)
-            self._backing.delete(_INDEX_POSITIONS_OPEN, payload)
+            flags |= _INDEX_FLAG_CLOSED
+
+        self._backing.update_position(key, event, flags)
 
         self._log.debug(f"Updated {position}.")
 
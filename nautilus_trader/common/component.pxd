@@ -31,12 +31,14 @@ from nautilus_trader.core.rust.common cimport LiveClock_API
 from nautilus_trader.core.rust.common cimport LogColor
 from nautilus_trader.core.rust.common cimport LogLevel
 from nautilus_trader.core.rust.common cimport MessageBus_API
+from nautilus_trader.core.rust.common cimport RandomGenerator_API
 from nautilus_trader.core.rust.common cimport TestClock_API
 from nautilus_trader.core.rust.common cimport TimeEvent_t
 from nautilus_trader.core.rust.core cimport CVec
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.model.identifiers cimport Identifier
 from nautilus_trader.model.identifiers cimport TraderId
+from nautilus_trader.model.identifiers cimport Venue
 from nautilus_trader.serialization.base cimport Serializer
 
 
@@ -98,6 +100,16 @@ cdef class TestClock(Clock):
 cdef class LiveClock(Clock):
     cdef LiveClock_API _mem
 
+    cpdef void record_venue_time(
+        self,
+        Venue venue,
+        uint64_t ts_sent,
+        uint64_t ts_server,
+        uint64_t ts_recv,
+    )
+    cpdef venue_offset_ns(self, Venue venue)
+    cpdef venue_latency_ns(self, Venue venue)
+
 
 cdef class TimeEvent(Event):
     cdef TimeEvent_t _mem
@@ -116,6 +128,18 @@ cdef class TimeEventHandler:
     cpdef void handle(self)
 
 
+cdef class RandomGenerator:
+    cdef RandomGenerator_API _mem
+
+    cpdef void reseed(self, uint64_t seed)
+    cpdef RandomGenerator fork(self, str stream)
+    cpdef uint64_t next_u64(self)
+    cpdef double random(self)
+    cpdef bint gen_bool(self, double probability)
+    cpdef uint64_t gen_range(self, uint64_t low, uint64_t high)
+    cpdef double gen_normal(self, double mean, double std_dev)
+
+
 cdef str RECV
 cdef str SENT
 cdef str CMD
@@ -145,12 +169,17 @@ cpdef void init_logging(
     str directory=*,
     str file_name=*,
     str file_format=*,
+    dict file_rotation=*,
     dict component_levels=*,
     bint colors=*,
+    bint json=*,
     bint bypass=*,
     bint print_config=*,
 )
 
+cpdef void set_component_log_level(str component, LogLevel level)
+cpdef void clear_component_log_level(str component)
+
 # Global static to flag if pyo3 based logging is initialized
 cdef bint LOGGING_PYO3
 cpdef bint is_logging_initialized()
@@ -249,6 +278,8 @@ cdef class MessageBus:
     cdef dict[str, object] _endpoints
     cdef dict[UUID4, object] _correlation_index
     cdef bint _has_backing
+    cdef object _database
+    cdef bint _key_by_instrument
     cdef tuple[type] _publishable_types
 
     cdef readonly TraderId trader_id
@@ -334,3 +365,25 @@ cdef class Throttler:
     cpdef void _process(self, TimeEvent event)
     cpdef void _resume(self, TimeEvent event)
     cdef void _send_msg(self, msg)
+
+
+cdef class TokenBucket:
+    cdef uint64_t _interval_ns
+    cdef double _tokens
+    cdef uint64_t _last_ns
+    cdef bint _initialized
+
+    cdef readonly str name
+    """The name of the token bucket.\n\n:returns: `str`"""
+    cdef readonly int limit
+    """The sustained limit of tokens per interval.\n\n:returns: `int`"""
+    cdef readonly timedelta interval
+    """The interval for the sustained limit.\n\n:returns: `timedelta`"""
+    cdef readonly int burst
+    """The capacity of the bucket.\n\n:returns: `int`"""
+
+    cpdef void reset(self)
+    cpdef double available(self, uint64_t ts_now)
+    cpdef bint try_consume(self, uint64_t ts_now)
+    cpdef uint64_t retry_after_ns(self, uint64_t ts_now)
+    cdef void _refill(self, uint64_t ts_now)
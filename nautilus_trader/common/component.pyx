@@ -45,6 +45,7 @@ from cpython.datetime cimport tzinfo
 from cpython.object cimport PyCallable_Check
 from cpython.object cimport PyObject
 from cpython.pycapsule cimport PyCapsule_GetPointer
+from libc.math cimport ceil
 from libc.stdint cimport int64_t
 from libc.stdint cimport uint64_t
 from libc.stdio cimport printf
@@ -67,8 +68,10 @@ from nautilus_trader.core.rust.common cimport component_trigger_from_cstr
 from nautilus_trader.core.rust.common cimport component_trigger_to_cstr
 from nautilus_trader.core.rust.common cimport live_clock_cancel_timer
 from nautilus_trader.core.rust.common cimport live_clock_drop
+from nautilus_trader.core.rust.common cimport live_clock_has_venue_offset
 from nautilus_trader.core.rust.common cimport live_clock_new
 from nautilus_trader.core.rust.common cimport live_clock_next_time
+from nautilus_trader.core.rust.common cimport live_clock_record_venue_time
 from nautilus_trader.core.rust.common cimport live_clock_register_default_handler
 from nautilus_trader.core.rust.common cimport live_clock_set_time_alert
 from nautilus_trader.core.rust.common cimport live_clock_set_timer
@@ -77,6 +80,8 @@ from nautilus_trader.core.rust.common cimport live_clock_timer_names
 from nautilus_trader.core.rust.common cimport live_clock_timestamp
 from nautilus_trader.core.rust.common cimport live_clock_timestamp_ms
 from nautilus_trader.core.rust.common cimport live_clock_timestamp_ns
+from nautilus_trader.core.rust.common cimport live_clock_venue_latency_ns
+from nautilus_trader.core.rust.common cimport live_clock_venue_offset_ns
 from nautilus_trader.core.rust.common cimport live_clock_timestamp_us
 from nautilus_trader.core.rust.common cimport log_color_from_cstr
 from nautilus_trader.core.rust.common cimport log_color_to_cstr
@@ -86,16 +91,30 @@ from nautilus_trader.core.rust.common cimport logger_flush
 from nautilus_trader.core.rust.common cimport logger_log
 from nautilus_trader.core.rust.common cimport logging_clock_set_realtime_mode
 from nautilus_trader.core.rust.common cimport logging_clock_set_static_mode
+from nautilus_trader.core.rust.common cimport logging_clear_component_level
 from nautilus_trader.core.rust.common cimport logging_clock_set_static_time
 from nautilus_trader.core.rust.common cimport logging_init
 from nautilus_trader.core.rust.common cimport logging_is_colored
 from nautilus_trader.core.rust.common cimport logging_is_initialized
 from nautilus_trader.core.rust.common cimport logging_log_header
 from nautilus_trader.core.rust.common cimport logging_log_sysinfo
+from nautilus_trader.core.rust.common cimport logging_set_component_level
 from nautilus_trader.core.rust.common cimport logging_shutdown
 from nautilus_trader.core.rust.common cimport msgbus_drop
+from nautilus_trader.core.rust.common cimport msgbus_is_matching
 from nautilus_trader.core.rust.common cimport msgbus_new
 from nautilus_trader.core.rust.common cimport msgbus_publish_external
+from nautilus_trader.core.rust.common cimport random_generator_drop
+from nautilus_trader.core.rust.common cimport random_generator_fork
+from nautilus_trader.core.rust.common cimport random_generator_from_entropy
+from nautilus_trader.core.rust.common cimport random_generator_gen_bool
+from nautilus_trader.core.rust.common cimport random_generator_gen_normal
+from nautilus_trader.core.rust.common cimport random_generator_gen_range
+from nautilus_trader.core.rust.common cimport random_generator_new
+from nautilus_trader.core.rust.common cimport random_generator_next_f64
+from nautilus_trader.core.rust.common cimport random_generator_next_u64
+from nautilus_trader.core.rust.common cimport random_generator_reseed
+from nautilus_trader.core.rust.common cimport random_generator_seed
 from nautilus_trader.core.rust.common cimport test_clock_advance_time
 from nautilus_trader.core.rust.common cimport test_clock_cancel_timer
 from nautilus_trader.core.rust.common cimport test_clock_cancel_timers
@@ -128,6 +147,7 @@ from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.model.identifiers cimport ComponentId
 from nautilus_trader.model.identifiers cimport Identifier
 from nautilus_trader.model.identifiers cimport TraderId
+from nautilus_trader.model.identifiers cimport Venue
 from nautilus_trader.serialization.base cimport _EXTERNAL_PUBLISHABLE_TYPES
 from nautilus_trader.serialization.base cimport Serializer
 
@@ -812,6 +832,85 @@ cdef class LiveClock(Clock):
             # and timer.
             self.cancel_timer(name)
 
+    cpdef void record_venue_time(
+        self,
+        Venue venue,
+        uint64_t ts_sent,
+        uint64_t ts_server,
+        uint64_t ts_recv,
+    ):
+        """
+        Record a venue server time observation for clock offset estimation.
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue for the observation.
+        ts_sent : uint64_t
+            UNIX timestamp (nanoseconds) when the request was sent.
+        ts_server : uint64_t
+            UNIX timestamp (nanoseconds) of the venue server time in the response.
+        ts_recv : uint64_t
+            UNIX timestamp (nanoseconds) when the response was received.
+
+        """
+        Condition.not_none(venue, "venue")
+        Condition.true(ts_recv >= ts_sent, "ts_recv was < ts_sent")
+
+        live_clock_record_venue_time(
+            &self._mem,
+            pystr_to_cstr(venue.to_str()),
+            ts_sent,
+            ts_server,
+            ts_recv,
+        )
+
+    cpdef venue_offset_ns(self, Venue venue):
+        """
+        Return the estimated offset of the venue clock relative to this clock.
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue for the offset.
+
+        Returns
+        -------
+        int or ``None``
+            ``None`` if no observations have been recorded for the venue.
+
+        """
+        Condition.not_none(venue, "venue")
+
+        cdef str venue_str = venue.to_str()
+        cdef const char* venue_ptr = pystr_to_cstr(venue_str)
+        if not live_clock_has_venue_offset(&self._mem, venue_ptr):
+            return None
+        return live_clock_venue_offset_ns(&self._mem, venue_ptr)
+
+    cpdef venue_latency_ns(self, Venue venue):
+        """
+        Return the estimated one-way latency to the venue.
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue for the latency.
+
+        Returns
+        -------
+        int or ``None``
+            ``None`` if no observations have been recorded for the venue.
+
+        """
+        Condition.not_none(venue, "venue")
+
+        cdef str venue_str = venue.to_str()
+        cdef const char* venue_ptr = pystr_to_cstr(venue_str)
+        if not live_clock_has_venue_offset(&self._mem, venue_ptr):
+            return None
+        return live_clock_venue_latency_ns(&self._mem, venue_ptr)
+
 
 def create_pyo3_conversion_wrapper(callback) -> Callable:
     def wrapper(capsule):
@@ -985,6 +1084,167 @@ cdef class TimeEventHandler:
         )
 
 
+cdef class RandomGenerator:
+    """
+    Provides a deterministic, seedable source of random numbers for stochastic
+    simulation components (such as fill and latency models).
+
+    The same seed always produces a bit-identical sequence, independent of the
+    platform and of the Python ``random`` module global state.
+
+    Parameters
+    ----------
+    seed : int, optional
+        The random seed. If ``None`` then a seed is taken from OS entropy.
+
+    """
+
+    def __init__(self, seed: int | None = None):
+        if seed is None:
+            self._mem = random_generator_from_entropy()
+        else:
+            Condition.not_negative_int(seed, "seed")
+            self._mem = random_generator_new(seed)
+
+    def __del__(self) -> None:
+        if self._mem._0 != NULL:
+            random_generator_drop(self._mem)
+
+    def __repr__(self) -> str:
+        return f"{type(self).__name__}(seed={self.seed})"
+
+    @property
+    def seed(self) -> int:
+        """
+        Return the seed the generator was initialized with.
+
+        Returns
+        -------
+        int
+
+        """
+        return random_generator_seed(&self._mem)
+
+    cpdef void reseed(self, uint64_t seed):
+        """
+        Reset the generator to the start of the sequence for the given seed.
+
+        Parameters
+        ----------
+        seed : uint64_t
+            The random seed.
+
+        """
+        random_generator_reseed(&self._mem, seed)
+
+    cpdef RandomGenerator fork(self, str stream):
+        """
+        Return a new independent generator derived from this generator's seed
+        and the given stream name.
+
+        The result does not depend on how many values have already been drawn,
+        so components may be forked in any order.
+
+        Parameters
+        ----------
+        stream : str
+            The stream name (e.g. a venue or component ID).
+
+        Returns
+        -------
+        RandomGenerator
+
+        """
+        Condition.valid_string(stream, "stream")
+
+        cdef RandomGenerator rng = RandomGenerator.__new__(RandomGenerator)
+        rng._mem = random_generator_fork(&self._mem, pystr_to_cstr(stream))
+        return rng
+
+    cpdef uint64_t next_u64(self):
+        """
+        Return the next uniformly distributed 64-bit unsigned integer.
+
+        Returns
+        -------
+        uint64_t
+
+        """
+        return random_generator_next_u64(&self._mem)
+
+    cpdef double random(self):
+        """
+        Return the next uniformly distributed float in the range [0, 1).
+
+        Returns
+        -------
+        double
+
+        """
+        return random_generator_next_f64(&self._mem)
+
+    cpdef bint gen_bool(self, double probability):
+        """
+        Return ``True`` with the given probability.
+
+        Probabilities of exactly 0 or 1 do not advance the sequence.
+
+        Parameters
+        ----------
+        probability : double
+            The probability of returning ``True`` [0, 1].
+
+        Returns
+        -------
+        bool
+
+        """
+        return <bint>random_generator_gen_bool(&self._mem, probability)
+
+    cpdef uint64_t gen_range(self, uint64_t low, uint64_t high):
+        """
+        Return a uniformly distributed integer in the range [low, high).
+
+        Parameters
+        ----------
+        low : uint64_t
+            The inclusive lower bound.
+        high : uint64_t
+            The exclusive upper bound.
+
+        Returns
+        -------
+        uint64_t
+
+        Raises
+        ------
+        ValueError
+            If `low` is not less than `high`.
+
+        """
+        Condition.true(low < high, "`low` was not less than `high`")
+
+        return random_generator_gen_range(&self._mem, low, high)
+
+    cpdef double gen_normal(self, double mean, double std_dev):
+        """
+        Return a normally distributed float.
+
+        Parameters
+        ----------
+        mean : double
+            The distribution mean.
+        std_dev : double
+            The distribution standard deviation.
+
+        Returns
+        -------
+        double
+
+        """
+        return random_generator_gen_normal(&self._mem, mean, std_dev)
+
+
 RECV = "<--"
 SENT = "-->"
 CMD = "[CMD]"
@@ -1032,8 +1292,10 @@ cpdef void init_logging(
     str directory = None,
     str file_name = None,
     str file_format = None,
+    dict file_rotation: dict[str, Any] = None,
     dict component_levels: dict[ComponentId, LogLevel] = None,
     bint colors = True,
+    bint json = False,
     bint bypass = False,
     bint print_config = False,
 ):
@@ -1066,11 +1328,17 @@ cpdef void init_logging(
     file_format : str { 'JSON' }, optional
         The log file format. If ``None`` (default) then will log in plain text.
         If set to 'JSON' then logs will be in JSON format.
+    file_rotation : dict[str, Any], optional
+        The log file rotation and retention policy, with any of the keys 'max_file_size' (bytes),
+        'max_file_age_secs', 'compression' ('gzip' or 'zstd'), 'max_backup_count' and
+        'max_backup_age_days'. If ``None`` then only daily rotation will occur.
     component_levels : dict[ComponentId, LogLevel]
         The additional per component log level filters, where keys are component
         IDs (e.g. actor/strategy IDs) and values are log levels.
     colors : bool, default True
         If ANSI codes should be used to produce colored log lines.
+    json : bool, default False
+        If log lines written to stdout and stderr should be structured JSON (one object per line).
     bypass : bool, default False
         If the output for the core logging system is bypassed (useful for logging tests).
     print_config : bool, default False
@@ -1093,13 +1361,50 @@ cpdef void init_logging(
             pystr_to_cstr(directory) if directory else NULL,
             pystr_to_cstr(file_name) if file_name else NULL,
             pystr_to_cstr(file_format) if file_format else NULL,
+            pybytes_to_cstr(msgspec.json.encode(file_rotation)) if file_rotation else NULL,
             pybytes_to_cstr(msgspec.json.encode(component_levels)) if component_levels else NULL,
             colors,
+            json,
             bypass,
             print_config,
         )
 
 
+cpdef void set_component_log_level(str component, LogLevel level):
+    """
+    Set the runtime log level override for the given component.
+
+    Log lines from the component will then be written up to `level`, regardless
+    of the stdout and file log levels, without restarting the logging system.
+
+    Parameters
+    ----------
+    component : str
+        The component name (as it appears within each log line).
+    level : LogLevel
+        The log level for the component.
+
+    """
+    Condition.valid_string(component, "component")
+
+    logging_set_component_level(pystr_to_cstr(component), level)
+
+
+cpdef void clear_component_log_level(str component):
+    """
+    Clear any runtime log level override for the given component.
+
+    Parameters
+    ----------
+    component : str
+        The component name (as it appears within each log line).
+
+    """
+    Condition.valid_string(component, "component")
+
+    logging_clear_component_level(pystr_to_cstr(component))
+
+
 LOGGING_PYO3 = False
 
 
@@ -1932,6 +2237,8 @@ cdef class MessageBus:
     Pub/Sub wildcard patterns for hierarchical topics are possible:
      - `*` asterisk represents one or more characters in a pattern.
      - `?` question mark represents a single character in a pattern.
+     - `+` plus sign (as a whole level) represents exactly one topic level.
+     - `#` hash sign (as a whole level) represents zero or more topic levels.
 
     Given a topic and pattern potentially containing wildcard characters, i.e.
     `*` and `?`, where `?` can match any single character in the topic, and `*`
@@ -1945,6 +2252,14 @@ cdef class MessageBus:
     `camp` and `comp`. The question mark can also be used more than once.
     For example, `c??p` would match both of the above examples and `coop`.
 
+    Topic levels are separated by `.`. For example, `data.quotes.+` matches
+    `data.quotes.BINANCE` but not `data.quotes.BINANCE.ETHUSDT`, whereas
+    `data.quotes.#` matches both (as well as `data.quotes` itself). When a pattern
+    contains a level wildcard, `*` and `?` only match within a single level.
+
+    Subscribers receive messages in descending priority order, and subscribers
+    with equal priority receive messages in the order they subscribed.
+
     Parameters
     ----------
     trader_id : TraderId
@@ -2029,13 +2344,31 @@ cdef class MessageBus:
         if config.types_filter is not None:
             config.types_filter.clear()
 
+        cdef bytes config_json = msgspec.json.encode(config)
         self._mem = msgbus_new(
             pystr_to_cstr(trader_id.value),
             pystr_to_cstr(name) if name else NULL,
             pystr_to_cstr(instance_id.to_str()),
-            pybytes_to_cstr(msgspec.json.encode(config)),
+            pybytes_to_cstr(config_json),
         )
 
+        # External backings other than Redis are provided by the infrastructure crate
+        self._database = None
+        self._key_by_instrument = False
+        if config.database is not None and config.database.type == "nats":
+            self._database = nautilus_pyo3.NatsMessageBusDatabase(
+                nautilus_pyo3.TraderId(trader_id.value),
+                nautilus_pyo3.UUID4(instance_id.to_str()),
+                config_json,
+            )
+        elif config.database is not None and config.database.type == "kafka":
+            self._database = nautilus_pyo3.KafkaMessageBusDatabase(
+                nautilus_pyo3.TraderId(trader_id.value),
+                nautilus_pyo3.UUID4(instance_id.to_str()),
+                config_json,
+            )
+            self._key_by_instrument = True
+
         self._endpoints: dict[str, Callable[[Any], None]] = {}
         self._patterns: dict[str, Subscription[:]] = {}
         self._subscriptions: dict[Subscription, list[str]] = {}
@@ -2052,6 +2385,8 @@ cdef class MessageBus:
         self.pub_count = 0
 
     def __del__(self) -> None:
+        if self._database is not None:
+            self._database.close()  # Flushes any buffered messages
         if self._mem._0 != NULL:
             msgbus_drop(self._mem)
 
@@ -2084,7 +2419,7 @@ cdef class MessageBus:
         Parameters
         ----------
         pattern : str, optional
-            The topic pattern filter. May include wildcard characters `*` and `?`.
+            The topic pattern filter. May include wildcard characters `*`, `?`, `+` and `#`.
             If ``None`` then query is for **all** topics.
 
         Returns
@@ -2105,7 +2440,7 @@ cdef class MessageBus:
         Parameters
         ----------
         pattern : str, optional
-            The topic filter. May include wildcard characters `*` and `?`.
+            The topic filter. May include wildcard characters `*`, `?`, `+` and `#`.
             If ``None`` then query is for **all** topics.
 
         Returns
@@ -2322,7 +2657,7 @@ cdef class MessageBus:
         ----------
         topic : str
             The topic for the subscription. May include wildcard characters
-            `*` and `?`.
+            `*`, `?`, `+` and `#`.
         handler : Callable[[Any], None]
             The handler for the subscription.
         priority : int, optional
@@ -2368,7 +2703,7 @@ cdef class MessageBus:
         cdef str pattern
         cdef list subs
         for pattern in patterns:
-            if is_matching(topic, pattern):
+            if is_matching(pattern, topic):
                 subs = list(self._patterns[pattern])
                 subs.append(sub)
                 subs = sorted(subs, reverse=True)
@@ -2386,8 +2721,8 @@ cdef class MessageBus:
         Parameters
         ----------
         topic : str, optional
-            The topic to unsubscribe from. May include wildcard characters `*`
-            and `?`.
+            The topic to unsubscribe from. May include wildcard characters `*`,
+            `?`, `+` and `#`.
         handler : Callable[[Any], None]
             The handler for the subscription.
 
@@ -2467,11 +2802,22 @@ cdef class MessageBus:
                     payload_bytes = msg
                 else:
                     payload_bytes = self.serializer.serialize(msg)
-                msgbus_publish_external(
-                    &self._mem,
-                    pystr_to_cstr(topic),
-                    pybytes_to_cstr(payload_bytes),
-                )
+                if self._key_by_instrument:
+                    # Partition by instrument where the message has one (otherwise by topic)
+                    instrument_id = getattr(msg, "instrument_id", None)
+                    self._database.publish(
+                        topic,
+                        payload_bytes,
+                        instrument_id.value if instrument_id is not None else None,
+                    )
+                elif self._database is not None:
+                    self._database.publish(topic, payload_bytes)
+                else:
+                    msgbus_publish_external(
+                        &self._mem,
+                        pystr_to_cstr(topic),
+                        pybytes_to_cstr(payload_bytes),
+                    )
 
         self.pub_count += 1
 
@@ -2498,33 +2844,8 @@ cdef class MessageBus:
 
 
 cdef inline bint is_matching(str topic, str pattern):
-    # Get length of string and wildcard pattern
-    cdef int n = len(topic)
-    cdef int m = len(pattern)
-
-    # Create a DP lookup table
-    cdef np.ndarray[np.int8_t, ndim=2] t = np.empty((n + 1, m + 1), dtype=np.int8)
-    t.fill(False)
-
-    # If both pattern and string are empty: match
-    t[0, 0] = True
-
-    # Handle empty string case (i == 0)
-    cdef int j
-    for j in range(1, m + 1):
-        if pattern[j - 1] == '*':
-            t[0, j] = t[0, j - 1]
-
-    # Build a matrix in a bottom-up manner
-    cdef int i
-    for i in range(1, n + 1):
-        for j in range(1, m + 1):
-            if pattern[j - 1] == '*':
-                t[i, j] = t[i - 1, j] or t[i, j - 1]
-            elif pattern[j - 1] == '?' or topic[i - 1] == pattern[j - 1]:
-                t[i, j] = t[i - 1, j - 1]
-
-    return t[n, m]
+    # Uses the compiled topic pattern matcher (supports `*`, `?`, `+` and `#` wildcards)
+    return <bint>msgbus_is_matching(pystr_to_cstr(topic), pystr_to_cstr(pattern))
 
 
 # Python wrapper for test access
@@ -2542,7 +2863,7 @@ cdef class Subscription:
     Parameters
     ----------
     topic : str
-        The topic for the subscription. May include wildcard characters `*` and `?`.
+        The topic for the subscription. May include wildcard characters `*`, `?`, `+` and `#`.
     handler : Callable[[Message], None]
         The handler for the subscription.
     priority : int
@@ -2822,6 +3143,145 @@ cdef class Throttler:
         self.sent_count += 1
 
 
+
+cdef class TokenBucket:
+    """
+    Provides a token bucket rate limiter.
+
+    The bucket holds up to `burst` tokens and is refilled continuously at the
+    sustained rate of `limit` tokens per `interval`. Each permitted message
+    consumes one token, so short bursts are allowed while the long run rate is
+    bounded by the sustained rate.
+
+    Parameters
+    ----------
+    name : str
+        The unique name of the token bucket.
+    limit : int
+        The sustained limit of tokens per interval.
+    interval : timedelta
+        The interval for the sustained limit.
+    burst : int, optional
+        The capacity of the bucket. If ``None`` then will equal `limit`.
+
+    Raises
+    ------
+    ValueError
+        If `name` is not a valid string.
+    ValueError
+        If `limit` is not positive (> 0).
+    ValueError
+        If `interval` is not positive (> 0).
+    ValueError
+        If `burst` is not positive (> 0).
+
+    """
+
+    def __init__(
+        self,
+        str name,
+        int limit,
+        timedelta interval not None,
+        burst: int | None = None,
+    ) -> None:
+        if burst is None:
+            burst = limit
+        Condition.valid_string(name, "name")
+        Condition.positive_int(limit, "limit")
+        Condition.positive(interval.total_seconds(), "interval.total_seconds()")
+        Condition.positive_int(burst, "burst")
+
+        self._interval_ns = secs_to_nanos(interval.total_seconds())
+        self._tokens = burst
+        self._last_ns = 0
+        self._initialized = False
+
+        self.name = name
+        self.limit = limit
+        self.interval = interval
+        self.burst = burst
+
+    def __repr__(self) -> str:
+        return f"{type(self).__name__}(name={self.name}, limit={self.limit}, interval={self.interval}, burst={self.burst})"
+
+    cpdef void reset(self):
+        """
+        Reset the bucket to full capacity.
+
+        """
+        self._tokens = self.burst
+        self._last_ns = 0
+        self._initialized = False
+
+    cpdef double available(self, uint64_t ts_now):
+        """
+        Return the tokens available at the given time.
+
+        Parameters
+        ----------
+        ts_now : uint64_t
+            UNIX timestamp (nanoseconds) of the current time.
+
+        Returns
+        -------
+        double
+
+        """
+        self._refill(ts_now)
+        return self._tokens
+
+    cpdef bint try_consume(self, uint64_t ts_now):
+        """
+        Try to consume a token at the given time.
+
+        Parameters
+        ----------
+        ts_now : uint64_t
+            UNIX timestamp (nanoseconds) of the current time.
+
+        Returns
+        -------
+        bool
+            True if a token was available and consumed, else False.
+
+        """
+        self._refill(ts_now)
+        if self._tokens < 1.0:
+            return False
+        self._tokens -= 1.0
+        return True
+
+    cpdef uint64_t retry_after_ns(self, uint64_t ts_now):
+        """
+        Return the duration until a token will be available.
+
+        Parameters
+        ----------
+        ts_now : uint64_t
+            UNIX timestamp (nanoseconds) of the current time.
+
+        Returns
+        -------
+        uint64_t
+            The duration (nanoseconds), zero if a token is available now.
+
+        """
+        self._refill(ts_now)
+        if self._tokens >= 1.0:
+            return 0
+        return <uint64_t>ceil((1.0 - self._tokens) * self._interval_ns / self.limit)
+
+    cdef void _refill(self, uint64_t ts_now):
+        if not self._initialized:
+            self._last_ns = ts_now
+            self._initialized = True
+            return
+        if ts_now <= self._last_ns:
+            return
+        cdef double refill = <double>(ts_now - self._last_ns) * self.limit / self._interval_ns
+        self._tokens = min(<double>self.burst, self._tokens + refill)
+        self._last_ns = ts_now
+
 cdef inline uint64_t max_uint64(uint64_t a, uint64_t b):
     if a > b:
         return a
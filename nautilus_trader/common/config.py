@@ -237,8 +237,9 @@ class DatabaseConfig(NautilusConfig, frozen=True):
 
     Parameters
     ----------
-    type : str, {'redis'}, default 'redis'
-        The database type.
+    type : str, {'redis', 'postgres', 'sqlite', 'nats', 'kafka'}, default 'redis'
+        The database type ('postgres' and 'sqlite' are only supported as cache backings, and
+        'nats' and 'kafka' are only supported as message bus backings).
     host : str, optional
         The database host address. If `None` then should use the typical default.
     port : int, optional
@@ -251,10 +252,20 @@ class DatabaseConfig(NautilusConfig, frozen=True):
         If database should use an SSL enabled connection.
     timeout : int, default 20
         The timeout (seconds) to wait for a new connection.
+    name : str, optional
+        The database name for 'postgres' (default 'nautilus'), or the database file path
+        for 'sqlite' (default 'nautilus.db').
 
     Notes
     -----
     If `type` is 'redis' then requires Redis version 6.2.0 and above for correct operation.
+    If `type` is 'nats' then requires a NATS server with JetStream enabled (default port 4222).
+    If `type` is 'kafka' then `host` and `port` give the bootstrap server (default port 9092),
+    and a `username` and `password` will authenticate with SASL PLAIN.
+    If `type` is 'postgres' then the default port is 5432 and the schema is created (and migrated)
+    on connection, which requires PostgreSQL version 12 and above.
+    If `type` is 'sqlite' then the database is embedded (no server required) and opened in
+    WAL mode, the connection options other than `name` and `timeout` are ignored.
 
     """
 
@@ -265,6 +276,7 @@ class DatabaseConfig(NautilusConfig, frozen=True):
     password: str | None = None
     ssl: bool = False
     timeout: int | None = 20
+    name: str | None = None
 
 
 class MessageBusConfig(NautilusConfig, frozen=True):
@@ -289,7 +301,8 @@ class MessageBusConfig(NautilusConfig, frozen=True):
         The actual window may extend up to one minute beyond the specified value since streams are
         trimmed at most once every minute.
         Note that this feature requires Redis version 6.2.0 or higher; otherwise it will result
-        in a command syntax error.
+        in a command syntax error. For a NATS backing this sets the JetStream stream max age.
+        For a Kafka backing retention is controlled by the broker topic configuration.
     use_trader_prefix : bool, default True
         If a 'trader-' prefix is used for stream names.
     use_trader_id : bool, default True
@@ -298,6 +311,10 @@ class MessageBusConfig(NautilusConfig, frozen=True):
         If the traders instance ID is used for stream names.
     streams_prefix : str, default 'streams'
         The prefix for externally published stream names (must have a `database` config).
+        For a NATS backing, messages are published on the subject `{prefix}.{topic}` with
+        the prefix levels separated by `.` rather than `:`.
+        For a Kafka backing, messages are sent to a topic per message type `{prefix}.{type}`
+        (e.g. `{prefix}.events.order`), keyed by instrument ID where the message has one.
         If `use_trader_id` and `use_instance_id` are *both* false, then it becomes possible for
         many traders to be configured to write to the same streams.
     types_filter : list[type], optional
@@ -457,11 +474,25 @@ class LoggingConfig(NautilusConfig, frozen=True):
         This will override automatic naming, and no daily file rotation will occur.
     log_file_format : str { 'JSON' }, optional
         The log file format. If ``None`` (default) then will log in plain text.
+    log_file_max_size : PositiveInt, optional
+        The maximum size (bytes) of a log file before it is rotated.
+    log_file_max_age_secs : PositiveInt, optional
+        The maximum age (seconds) of a log file before it is rotated.
+    log_file_compression : str { 'gzip', 'zstd' }, optional
+        The compression for rotated log files. If ``None`` then rotated files are not compressed.
+    log_file_max_backup_count : PositiveInt, optional
+        The maximum number of rotated log files to retain (oldest are removed first).
+    log_file_max_backup_age_days : PositiveInt, optional
+        The maximum age (days) of rotated log files to retain.
     log_colors : bool, default True
         If ANSI codes should be used to produce colored log lines.
+    log_json : bool, default False
+        If log lines written to stdout and stderr should be structured JSON (one object per line),
+        with fields for the trader ID, component and instrument ID (where available).
     log_component_levels : dict[str, LogLevel]
         The additional per component log level filters, where keys are component
         IDs (e.g. actor/strategy IDs) and values are log levels.
+        These can be changed at runtime with `set_component_log_level`.
     bypass_logging : bool, default False
         If all logging should be bypassed.
     print_config : bool, default False
@@ -478,13 +509,39 @@ class LoggingConfig(NautilusConfig, frozen=True):
     log_directory: str | None = None
     log_file_name: str | None = None
     log_file_format: str | None = None
+    log_file_max_size: PositiveInt | None = None
+    log_file_max_age_secs: PositiveInt | None = None
+    log_file_compression: str | None = None
+    log_file_max_backup_count: PositiveInt | None = None
+    log_file_max_backup_age_days: PositiveInt | None = None
     log_colors: bool = True
+    log_json: bool = False
     log_component_levels: dict[str, str] | None = None
     bypass_logging: bool = False
     print_config: bool = False
     use_pyo3: bool = False
 
 
+class MetricsConfig(NautilusConfig, frozen=True):
+    """
+    Configuration for the Prometheus metrics endpoint of a node.
+
+    When configured, metrics (messages processed, order latency, order book apply time
+    and WebSocket reconnects) are collected and served over HTTP at `/metrics`.
+
+    Parameters
+    ----------
+    host : str, default "127.0.0.1"
+        The host address to serve the metrics endpoint on.
+    port : PositiveInt, default 9090
+        The port to serve the metrics endpoint on.
+
+    """
+
+    host: str = "127.0.0.1"
+    port: PositiveInt = 9090
+
+
 class ImportableFactoryConfig(NautilusConfig, frozen=True):
     """
     Represents an importable (JSON) factory config.
@@ -24,6 +24,7 @@ from nautilus_trader.common.generators cimport OrderListIdGenerator
 from nautilus_trader.core.rust.model cimport ContingencyType
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport OrderType
+from nautilus_trader.core.rust.model cimport PegPriceType
 from nautilus_trader.core.rust.model cimport TimeInForce
 from nautilus_trader.core.rust.model cimport TrailingOffsetType
 from nautilus_trader.core.rust.model cimport TriggerType
@@ -96,6 +97,8 @@ cdef class OrderFactory:
         ExecAlgorithmId exec_algorithm_id=*,
         dict exec_algorithm_params=*,
         str tags=*,
+        PegPriceType peg_price_type=*,
+        Price peg_offset=*,
     )
 
     cpdef StopMarketOrder stop_market(
@@ -24,6 +24,7 @@ from nautilus_trader.core.datetime cimport dt_to_unix_nanos
 from nautilus_trader.core.rust.model cimport ContingencyType
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport OrderType
+from nautilus_trader.core.rust.model cimport PegPriceType
 from nautilus_trader.core.rust.model cimport TimeInForce
 from nautilus_trader.core.rust.model cimport TrailingOffsetType
 from nautilus_trader.core.rust.model cimport TriggerType
@@ -299,6 +300,8 @@ cdef class OrderFactory:
         ExecAlgorithmId exec_algorithm_id = None,
         dict exec_algorithm_params = None,
         str tags = None,
+        PegPriceType peg_price_type = PegPriceType.NO_PEG,
+        Price peg_offset = None,
     ):
         """
         Create a new ``LIMIT`` order.
@@ -336,6 +339,11 @@ cdef class OrderFactory:
         tags : str, optional
             The custom user tags for the order. These are optional and can
             contain any arbitrary delimiter if required.
+        peg_price_type : PegPriceType, default ``NO_PEG``
+            The reference price the order price is pegged to (pegged orders are
+            repriced as the market moves).
+        peg_offset : Price, optional
+            The offset from the peg reference price.
 
         Returns
         -------
@@ -349,6 +357,8 @@ cdef class OrderFactory:
             If `time_in_force` is ``GTD`` and `expire_time` <= UNIX epoch.
         ValueError
             If `display_qty` is negative (< 0) or greater than `quantity`.
+        ValueError
+            If `peg_offset` is not ``None`` when `peg_price_type` is ``NO_PEG``.
 
         """
         cdef ClientOrderId client_order_id = self._order_id_generator.generate()
@@ -378,6 +388,8 @@ cdef class OrderFactory:
             exec_algorithm_params=exec_algorithm_params,
             exec_spawn_id=client_order_id if exec_algorithm_id is not None else None,
             tags=tags,
+            peg_price_type=peg_price_type,
+            peg_offset=peg_offset,
         )
 
     cpdef StopMarketOrder stop_market(
@@ -23,6 +23,7 @@ from nautilus_trader.backtest.config import BacktestDataConfig
 from nautilus_trader.backtest.config import BacktestEngineConfig
 from nautilus_trader.backtest.config import BacktestRunConfig
 from nautilus_trader.backtest.config import BacktestVenueConfig
+from nautilus_trader.backtest.config import FundingPaymentConfig
 from nautilus_trader.backtest.config import FXRolloverInterestConfig
 from nautilus_trader.backtest.config import SimulationModuleConfig
 from nautilus_trader.cache.config import CacheConfig
@@ -35,6 +36,7 @@ from nautilus_trader.common.config import InstrumentProviderConfig
 from nautilus_trader.common.config import InvalidConfiguration
 from nautilus_trader.common.config import LoggingConfig
 from nautilus_trader.common.config import MessageBusConfig
+from nautilus_trader.common.config import MetricsConfig
 from nautilus_trader.common.config import NautilusConfig
 from nautilus_trader.common.config import NonNegativeFloat
 from nautilus_trader.common.config import NonNegativeInt
@@ -55,6 +57,7 @@ from nautilus_trader.execution.config import ExecEngineConfig
 from nautilus_trader.execution.config import ImportableExecAlgorithmConfig
 from nautilus_trader.live.config import ControllerConfig
 from nautilus_trader.live.config import ControllerFactory
+from nautilus_trader.live.config import HttpRetryConfig
 from nautilus_trader.live.config import ImportableControllerConfig
 from nautilus_trader.live.config import LiveDataClientConfig
 from nautilus_trader.live.config import LiveDataEngineConfig
@@ -62,9 +65,13 @@ from nautilus_trader.live.config import LiveExecClientConfig
 from nautilus_trader.live.config import LiveExecEngineConfig
 from nautilus_trader.live.config import LiveRiskEngineConfig
 from nautilus_trader.live.config import RoutingConfig
+from nautilus_trader.live.config import SocketConnectionConfig
 from nautilus_trader.live.config import TradingNodeConfig
 from nautilus_trader.persistence.config import DataCatalogConfig
 from nautilus_trader.persistence.config import StreamingConfig
+from nautilus_trader.risk.config import LossLimitConfig
+from nautilus_trader.risk.config import OrderRateLimitConfig
+from nautilus_trader.risk.config import PriceCollarConfig
 from nautilus_trader.risk.config import RiskEngineConfig
 from nautilus_trader.system.config import NautilusKernelConfig
 from nautilus_trader.trading.config import ImportableStrategyConfig
@@ -88,7 +95,9 @@ __all__ = [
     "ExecAlgorithmConfig",
     "ExecAlgorithmFactory",
     "ExecEngineConfig",
+    "FundingPaymentConfig",
     "FXRolloverInterestConfig",
+    "HttpRetryConfig",
     "ImportableActorConfig",
     "ImportableControllerConfig",
     "ImportableExecAlgorithmConfig",
@@ -96,14 +105,18 @@ __all__ = [
     "InstrumentProviderConfig",
     "InvalidConfiguration",
     "LoggingConfig",
+    "LossLimitConfig",
     "MessageBusConfig",
+    "MetricsConfig",
     "NautilusConfig",
     "NautilusKernelConfig",
     "NonNegativeInt",
     "NonNegativeFloat",
     "OrderEmulatorConfig",
+    "OrderRateLimitConfig",
     "PositiveInt",
     "PositiveFloat",
+    "PriceCollarConfig",
     "RiskEngineConfig",
     "StrategyConfig",
     "StrategyFactory",
@@ -116,6 +129,7 @@ __all__ = [
     "LiveExecEngineConfig",
     "LiveRiskEngineConfig",
     "RoutingConfig",
+    "SocketConnectionConfig",
     "TradingNodeConfig",
     "msgspec_encoding_hook",
     "msgspec_decoding_hook",
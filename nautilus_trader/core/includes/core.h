@@ -120,6 +120,15 @@ void cstr_drop(const char *ptr);
 
 struct UUID4_t uuid4_new(void);
 
+/**
+ * Sets the UUID version generated by `uuid4_new` for the process.
+ *
+ * # Panics
+ *
+ * - If `version` is not a supported UUID version (4 or 7).
+ */
+void uuid4_set_version(uint8_t version);
+
 /**
  * Returns a [`UUID4`] from C string pointer.
  *
@@ -5,21 +5,71 @@
 #include <stdint.h>
 #include <Python.h>
 
+#if defined(HIGH_PRECISION)
+#define PriceRaw_MAX ((__int128)(((unsigned __int128)1 << 127) - 1))
+#else
+#define PriceRaw_MAX INT64_MAX
+#endif
+
 #define DEPTH10_LEN 10
 
+#define DEPTH25_LEN 25
+
+#if !defined(HIGH_PRECISION)
 #define FIXED_PRECISION 9
+#endif
 
+#if defined(HIGH_PRECISION)
+#define FIXED_PRECISION 16
+#endif
+
+#if !defined(HIGH_PRECISION)
 #define FIXED_SCALAR 1000000000.0
+#endif
+
+#if defined(HIGH_PRECISION)
+#define FIXED_SCALAR 10000000000000000.0
+#endif
 
+#if !defined(HIGH_PRECISION)
 #define MONEY_MAX 9223372036.0
+#endif
 
+#if defined(HIGH_PRECISION)
+#define MONEY_MAX 17014118346046.0
+#endif
+
+#if !defined(HIGH_PRECISION)
 #define MONEY_MIN -9223372036.0
+#endif
+
+#if defined(HIGH_PRECISION)
+#define MONEY_MIN -17014118346046.0
+#endif
 
+#if !defined(HIGH_PRECISION)
 #define PRICE_MAX 9223372036.0
+#endif
+
+#if defined(HIGH_PRECISION)
+#define PRICE_MAX 17014118346046.0
+#endif
 
+#if !defined(HIGH_PRECISION)
 #define PRICE_MIN -9223372036.0
+#endif
 
+#if defined(HIGH_PRECISION)
+#define PRICE_MIN -17014118346046.0
+#endif
+
+#if !defined(HIGH_PRECISION)
 #define QUANTITY_MAX 18446744073.0
+#endif
+
+#if defined(HIGH_PRECISION)
+#define QUANTITY_MAX 34028236692093.0
+#endif
 
 #define QUANTITY_MIN 0.0
 
@@ -107,6 +157,32 @@ typedef enum AssetClass {
     ALTERNATIVE = 7,
 } AssetClass;
 
+/**
+ * The auction call phase for a market session.
+ */
+typedef enum AuctionPhase {
+    /**
+     * The market session is not in an auction call phase.
+     */
+    NO_AUCTION = 1,
+    /**
+     * The opening auction call phase before continuous trading.
+     */
+    OPENING = 2,
+    /**
+     * The closing auction call phase after continuous trading.
+     */
+    CLOSING = 3,
+    /**
+     * A scheduled intraday auction call phase.
+     */
+    INTRADAY = 4,
+    /**
+     * An unscheduled auction call phase triggered by a price breaching the volatility bands.
+     */
+    VOLATILITY_INTERRUPTION = 5,
+} AuctionPhase;
+
 /**
  * The type of order book action for an order book event.
  */
@@ -205,6 +281,14 @@ typedef enum HaltReason {
      * Trading halt is imposed by the venue to protect against extreme volatility.
      */
     VOLATILITY = 3,
+    /**
+     * Trading halt is imposed after the price reached a limit-up or limit-down band.
+     */
+    LIMIT_UP_LIMIT_DOWN = 4,
+    /**
+     * Trading halt is imposed by a market-wide circuit breaker.
+     */
+    CIRCUIT_BREAKER = 5,
 } HaltReason;
 
 /**
@@ -255,6 +339,10 @@ typedef enum InstrumentClass {
      * A warrant instrument class. A derivative that gives the holder the right, but not the obligation, to buy or sell a security—most commonly an equity—at a certain price before expiration.
      */
     SPORTS_BETTING = 11,
+    /**
+     * A binary option instrument class. A contract which settles at either a fixed amount or nothing depending on the outcome of an event, such as a prediction market contract.
+     */
+    BINARY_OPTION = 12,
 } InstrumentClass;
 
 /**
@@ -321,6 +409,10 @@ typedef enum MarketStatus {
      * The market session is closed.
      */
     CLOSED = 7,
+    /**
+     * The market session is in an auction call phase.
+     */
+    AUCTION = 8,
 } MarketStatus;
 
 /**
@@ -498,6 +590,24 @@ typedef enum OrderType {
     TRAILING_STOP_LIMIT = 9,
 } OrderType;
 
+/**
+ * The reference price which the limit price of a pegged order is pegged to.
+ */
+typedef enum PegPriceType {
+    /**
+     * No peg is specified (the order is not pegged).
+     */
+    NO_PEG = 0,
+    /**
+     * Pegged to the mid-point of the best bid and ask, offset away from the market.
+     */
+    MID_POINT = 1,
+    /**
+     * Pegged to the same side of the market (best bid for BUY, best ask for SELL), offset towards the market.
+     */
+    PRIMARY = 2,
+} PegPriceType;
+
 /**
  * The market side for a specific position, or action related to positions.
  */
@@ -542,6 +652,40 @@ typedef enum PriceType {
     LAST = 4,
 } PriceType;
 
+/**
+ * The rounding mode for fixed-point division and rescaling of value types.
+ */
+typedef enum RoundingMode {
+    /**
+     * Rounds away from zero.
+     */
+    UP = 1,
+    /**
+     * Rounds towards zero (truncation).
+     */
+    DOWN = 2,
+    /**
+     * Rounds towards positive infinity.
+     */
+    CEILING = 3,
+    /**
+     * Rounds towards negative infinity.
+     */
+    FLOOR = 4,
+    /**
+     * Rounds to the nearest value, with ties rounded away from zero.
+     */
+    HALF_UP = 5,
+    /**
+     * Rounds to the nearest value, with ties rounded towards zero.
+     */
+    HALF_DOWN = 6,
+    /**
+     * Rounds to the nearest value, with ties rounded to the nearest even value (banker's rounding).
+     */
+    HALF_EVEN = 7,
+} RoundingMode;
+
 /**
  * The 'Time in Force' instruction for an order in the financial market.
  */
@@ -689,6 +833,14 @@ typedef struct OrderBookDeltas_t OrderBookDeltas_t;
  */
 typedef struct SyntheticInstrument SyntheticInstrument;
 
+/**
+ * Represents a grouped batch of `TradeTick` events for a single instrument, such as a
+ * burst of trades delivered in one venue message.
+ *
+ * This type cannot be `repr(C)` due to the `trades` vec.
+ */
+typedef struct TradeTicks_t TradeTicks_t;
+
 /**
  * Represents a valid ticker symbol ID for a tradable financial market instrument.
  */
@@ -725,13 +877,41 @@ typedef struct InstrumentId_t {
     struct Venue_t venue;
 } InstrumentId_t;
 
+#if !defined(HIGH_PRECISION)
+/**
+ * The raw fixed-point representation of a [`Price`].
+ */
+typedef int64_t PriceRaw;
+#endif
+
+#if defined(HIGH_PRECISION)
+/**
+ * The raw fixed-point representation of a [`Price`].
+ */
+typedef __int128 PriceRaw;
+#endif
+
 typedef struct Price_t {
-    int64_t raw;
+    PriceRaw raw;
     uint8_t precision;
 } Price_t;
 
+#if !defined(HIGH_PRECISION)
+/**
+ * The raw fixed-point representation of a [`Quantity`].
+ */
+typedef uint64_t QuantityRaw;
+#endif
+
+#if defined(HIGH_PRECISION)
+/**
+ * The raw fixed-point representation of a [`Quantity`].
+ */
+typedef unsigned __int128 QuantityRaw;
+#endif
+
 typedef struct Quantity_t {
-    uint64_t raw;
+    QuantityRaw raw;
     uint8_t precision;
 } Quantity_t;
 
@@ -855,6 +1035,52 @@ typedef struct OrderBookDepth10_t {
     uint64_t ts_init;
 } OrderBookDepth10_t;
 
+/**
+ * Represents a self-contained order book update with a fixed depth of 25 levels per side.
+ *
+ * This is the 25 level equivalent of `OrderBookDepth10`, for venues which publish deeper
+ * fixed-depth books (e.g. OKX and Kraken). Unused levels are padded with default (zero size)
+ * orders.
+ */
+typedef struct OrderBookDepth25_t {
+    /**
+     * The instrument ID for the book.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The bid orders for the depth update.
+     */
+    struct BookOrder_t bids[DEPTH25_LEN];
+    /**
+     * The ask orders for the depth update.
+     */
+    struct BookOrder_t asks[DEPTH25_LEN];
+    /**
+     * The count of bid orders per level for the depth update.
+     */
+    uint32_t bid_counts[DEPTH25_LEN];
+    /**
+     * The count of ask orders per level for the depth update.
+     */
+    uint32_t ask_counts[DEPTH25_LEN];
+    /**
+     * A combination of packet end with matching engine status.
+     */
+    uint8_t flags;
+    /**
+     * The message sequence number assigned at the venue.
+     */
+    uint64_t sequence;
+    /**
+     * The UNIX timestamp (nanoseconds) when the data event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * The UNIX timestamp (nanoseconds) when the data object was initialized.
+     */
+    uint64_t ts_init;
+} OrderBookDepth25_t;
+
 /**
  * Represents a single quote tick in a financial market.
  */
@@ -939,6 +1165,16 @@ typedef struct TradeTick_t {
     uint64_t ts_init;
 } TradeTick_t;
 
+/**
+ * Provides a C compatible Foreign Function Interface (FFI) for an underlying [`TradeTicks`].
+ *
+ * This struct wraps `TradeTicks` in a way that makes it compatible with C function
+ * calls, enabling interaction with `TradeTicks` in a C environment.
+ */
+typedef struct TradeTicks_API {
+    struct TradeTicks_t *_0;
+} TradeTicks_API;
+
 /**
  * Represents a bar aggregation specification including a step, aggregation
  * method/rule and price type.
@@ -1015,13 +1251,84 @@ typedef struct Bar_t {
     uint64_t ts_init;
 } Bar_t;
 
+/**
+ * Represents a mark price update for a derivatives instrument, as published by the venue.
+ */
+typedef struct MarkPriceUpdate_t {
+    /**
+     * The instrument ID for the mark price.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The mark price value.
+     */
+    struct Price_t value;
+    /**
+     * The UNIX timestamp (nanoseconds) when the price event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * The UNIX timestamp (nanoseconds) when the data object was initialized.
+     */
+    uint64_t ts_init;
+} MarkPriceUpdate_t;
+
+/**
+ * Represents an index price update for a derivatives instrument, as published by the venue.
+ */
+typedef struct IndexPriceUpdate_t {
+    /**
+     * The instrument ID for the index price.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The index price value.
+     */
+    struct Price_t value;
+    /**
+     * The UNIX timestamp (nanoseconds) when the price event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * The UNIX timestamp (nanoseconds) when the data object was initialized.
+     */
+    uint64_t ts_init;
+} IndexPriceUpdate_t;
+
+/**
+ * Represents an open interest update for a futures or perpetual swap instrument.
+ */
+typedef struct OpenInterestUpdate_t {
+    /**
+     * The instrument ID for the open interest.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The total number of outstanding contracts.
+     */
+    struct Quantity_t value;
+    /**
+     * The UNIX timestamp (nanoseconds) when the open interest event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * The UNIX timestamp (nanoseconds) when the data object was initialized.
+     */
+    uint64_t ts_init;
+} OpenInterestUpdate_t;
+
 typedef enum Data_t_Tag {
     DELTA,
     DELTAS,
     DEPTH10,
+    DEPTH25,
     QUOTE,
     TRADE,
+    TRADES,
     BAR,
+    MARK_PRICE,
+    INDEX_PRICE,
+    OPEN_INTEREST,
 } Data_t_Tag;
 
 typedef struct Data_t {
@@ -1036,15 +1343,30 @@ typedef struct Data_t {
         struct {
             struct OrderBookDepth10_t depth10;
         };
+        struct {
+            struct OrderBookDepth25_t *depth25;
+        };
         struct {
             struct QuoteTick_t quote;
         };
         struct {
             struct TradeTick_t trade;
         };
+        struct {
+            struct TradeTicks_API trades;
+        };
         struct {
             struct Bar_t bar;
         };
+        struct {
+            struct MarkPriceUpdate_t mark_price;
+        };
+        struct {
+            struct IndexPriceUpdate_t index_price;
+        };
+        struct {
+            struct OpenInterestUpdate_t open_interest;
+        };
     };
 } Data_t;
 
@@ -1291,8 +1613,22 @@ typedef struct Currency_t {
     enum CurrencyType currency_type;
 } Currency_t;
 
+#if !defined(HIGH_PRECISION)
+/**
+ * The raw fixed-point representation of a [`Money`] amount.
+ */
+typedef int64_t MoneyRaw;
+#endif
+
+#if defined(HIGH_PRECISION)
+/**
+ * The raw fixed-point representation of a [`Money`] amount.
+ */
+typedef __int128 MoneyRaw;
+#endif
+
 typedef struct Money_t {
-    int64_t raw;
+    MoneyRaw raw;
     struct Currency_t currency;
 } Money_t;
 
@@ -1301,7 +1637,7 @@ typedef struct Money_t {
 /**
  * Sentinel Price for errors.
  */
-#define ERROR_PRICE (Price_t){ .raw = INT64_MAX, .precision = 0 }
+#define ERROR_PRICE (Price_t){ .raw = PriceRaw_MAX, .precision = 0 }
 
 struct Data_t data_clone(const struct Data_t *data);
 
@@ -1382,12 +1718,12 @@ struct Bar_t bar_new(struct BarType_t bar_type,
                      uint64_t ts_init);
 
 struct Bar_t bar_new_from_raw(struct BarType_t bar_type,
-                              int64_t open,
-                              int64_t high,
-                              int64_t low,
-                              int64_t close,
+                              PriceRaw open,
+                              PriceRaw high,
+                              PriceRaw low,
+                              PriceRaw close,
                               uint8_t price_prec,
-                              uint64_t volume,
+                              QuantityRaw volume,
                               uint8_t size_prec,
                               uint64_t ts_event,
                               uint64_t ts_init);
@@ -1474,9 +1810,9 @@ const uint32_t *orderbook_depth10_bid_counts_array(const struct OrderBookDepth10
 const uint32_t *orderbook_depth10_ask_counts_array(const struct OrderBookDepth10_t *depth);
 
 struct BookOrder_t book_order_from_raw(enum OrderSide order_side,
-                                       int64_t price_raw,
+                                       PriceRaw price_raw,
                                        uint8_t price_prec,
-                                       uint64_t size_raw,
+                                       QuantityRaw size_raw,
                                        uint8_t size_prec,
                                        uint64_t order_id);
 
@@ -1499,12 +1835,12 @@ const char *book_order_display_to_cstr(const struct BookOrder_t *order);
 const char *book_order_debug_to_cstr(const struct BookOrder_t *order);
 
 struct QuoteTick_t quote_tick_new(struct InstrumentId_t instrument_id,
-                                  int64_t bid_price_raw,
-                                  int64_t ask_price_raw,
+                                  PriceRaw bid_price_raw,
+                                  PriceRaw ask_price_raw,
                                   uint8_t bid_price_prec,
                                   uint8_t ask_price_prec,
-                                  uint64_t bid_size_raw,
-                                  uint64_t ask_size_raw,
+                                  QuantityRaw bid_size_raw,
+                                  QuantityRaw ask_size_raw,
                                   uint8_t bid_size_prec,
                                   uint8_t ask_size_prec,
                                   uint64_t ts_event,
@@ -1520,9 +1856,9 @@ uint64_t quote_tick_hash(const struct QuoteTick_t *delta);
 const char *quote_tick_to_cstr(const struct QuoteTick_t *tick);
 
 struct TradeTick_t trade_tick_new(struct InstrumentId_t instrument_id,
-                                  int64_t price_raw,
+                                  PriceRaw price_raw,
                                   uint8_t price_prec,
-                                  uint64_t size_raw,
+                                  QuantityRaw size_raw,
                                   uint8_t size_prec,
                                   enum AggressorSide aggressor_side,
                                   struct TradeId_t trade_id,
@@ -1538,6 +1874,37 @@ uint64_t trade_tick_hash(const struct TradeTick_t *delta);
  */
 const char *trade_tick_to_cstr(const struct TradeTick_t *tick);
 
+/**
+ * Creates a new `TradeTicks` object from a `CVec` of `TradeTick`.
+ *
+ * # Safety
+ * - The `trades` must be a valid pointer to a `CVec` containing `TradeTick` objects
+ * - This function clones the data pointed to by `trades` into Rust-managed memory, then forgets the original `Vec` to prevent Rust from auto-deallocating it
+ * - The caller is responsible for managing the memory of `trades` (including its deallocation) to avoid memory leaks
+ */
+struct TradeTicks_API trade_ticks_new(struct InstrumentId_t instrument_id,
+                                      const CVec *trades,
+                                      uint8_t flags,
+                                      uint64_t sequence);
+
+void trade_ticks_drop(struct TradeTicks_API trades);
+
+struct TradeTicks_API trade_ticks_clone(const struct TradeTicks_API *trades);
+
+struct InstrumentId_t trade_ticks_instrument_id(const struct TradeTicks_API *trades);
+
+CVec trade_ticks_vec_trades(const struct TradeTicks_API *trades);
+
+uint8_t trade_ticks_flags(const struct TradeTicks_API *trades);
+
+uint64_t trade_ticks_sequence(const struct TradeTicks_API *trades);
+
+uint64_t trade_ticks_ts_event(const struct TradeTicks_API *trades);
+
+uint64_t trade_ticks_ts_init(const struct TradeTicks_API *trades);
+
+void trade_ticks_vec_drop(CVec v);
+
 const char *account_type_to_cstr(enum AccountType value);
 
 /**
@@ -1692,6 +2059,17 @@ const char *halt_reason_to_cstr(enum HaltReason value);
  */
 enum HaltReason halt_reason_from_cstr(const char *ptr);
 
+const char *auction_phase_to_cstr(enum AuctionPhase value);
+
+/**
+ * Returns an enum from a Python string.
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ */
+enum AuctionPhase auction_phase_from_cstr(const char *ptr);
+
 const char *oms_type_to_cstr(enum OmsType value);
 
 /**
@@ -1747,6 +2125,17 @@ const char *order_type_to_cstr(enum OrderType value);
  */
 enum OrderType order_type_from_cstr(const char *ptr);
 
+const char *peg_price_type_to_cstr(enum PegPriceType value);
+
+/**
+ * Returns an enum from a Python string.
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ */
+enum PegPriceType peg_price_type_from_cstr(const char *ptr);
+
 const char *position_side_to_cstr(enum PositionSide value);
 
 /**
@@ -1769,6 +2158,17 @@ const char *price_type_to_cstr(enum PriceType value);
  */
 enum PriceType price_type_from_cstr(const char *ptr);
 
+const char *rounding_mode_to_cstr(enum RoundingMode value);
+
+/**
+ * Returns an enum from a Python string.
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ */
+enum RoundingMode rounding_mode_from_cstr(const char *ptr);
+
 const char *time_in_force_to_cstr(enum TimeInForce value);
 
 /**
@@ -2267,7 +2667,7 @@ struct Currency_t currency_from_cstr(const char *code_ptr);
 
 struct Money_t money_new(double amount, struct Currency_t currency);
 
-struct Money_t money_from_raw(int64_t raw, struct Currency_t currency);
+struct Money_t money_from_raw(MoneyRaw raw, struct Currency_t currency);
 
 double money_as_f64(const struct Money_t *money);
 
@@ -2277,7 +2677,7 @@ void money_sub_assign(struct Money_t a, struct Money_t b);
 
 struct Price_t price_new(double value, uint8_t precision);
 
-struct Price_t price_from_raw(int64_t raw, uint8_t precision);
+struct Price_t price_from_raw(PriceRaw raw, uint8_t precision);
 
 double price_as_f64(const struct Price_t *price);
 
@@ -2287,7 +2687,7 @@ void price_sub_assign(struct Price_t a, struct Price_t b);
 
 struct Quantity_t quantity_new(double value, uint8_t precision);
 
-struct Quantity_t quantity_from_raw(uint64_t raw, uint8_t precision);
+struct Quantity_t quantity_from_raw(QuantityRaw raw, uint8_t precision);
 
 double quantity_as_f64(const struct Quantity_t *qty);
 
@@ -4,6 +4,7 @@
 import datetime as dt
 from collections.abc import Awaitable
 from collections.abc import Callable
+from collections.abc import Iterator
 from decimal import Decimal
 from enum import Enum
 from os import PathLike
@@ -173,6 +174,99 @@ def is_within_last_24_hours(timestamp_ns: int) -> bool:
     """
 
 
+def iso8601_to_unix_nanos(value: str) -> int:
+    """
+    Parse the given ISO 8601 / RFC 3339 string into UNIX nanoseconds.
+
+    Timestamps without an offset are interpreted as UTC.
+
+    Parameters
+    ----------
+    value : str
+        The ISO 8601 string to parse.
+
+    Returns
+    -------
+    int
+
+    Raises
+    ------
+    ValueError
+        If `value` is not a valid ISO 8601 timestamp.
+
+    """
+
+
+def local_iso8601_to_unix_nanos(value: str, tz: str) -> int:
+    """
+    Parse the given ISO 8601 string into UNIX nanoseconds.
+
+    Timestamps without an offset are interpreted as local time in the time zone `tz`.
+
+    Parameters
+    ----------
+    value : str
+        The ISO 8601 string to parse.
+    tz : str
+        The IANA time zone name (e.g. 'America/New_York').
+
+    Returns
+    -------
+    int
+
+    Raises
+    ------
+    ValueError
+        If `value` is not a valid ISO 8601 timestamp.
+    ValueError
+        If `tz` is not a valid time zone, or the local time does not exist in it.
+
+    """
+
+
+def format_iso8601(timestamp_ns: int, precision: int = 9) -> str:
+    """
+    Format the given UNIX nanoseconds timestamp as an ISO 8601 UTC string.
+
+    Parameters
+    ----------
+    timestamp_ns : int
+        The UNIX nanoseconds timestamp to format.
+    precision : int, default 9
+        The number of fractional second digits (maximum 9).
+
+    Returns
+    -------
+    str
+
+    """
+
+
+def format_iso8601_local(timestamp_ns: int, tz: str, precision: int = 9) -> str:
+    """
+    Format the given UNIX nanoseconds timestamp as an ISO 8601 string in the time zone `tz`.
+
+    Parameters
+    ----------
+    timestamp_ns : int
+        The UNIX nanoseconds timestamp to format.
+    tz : str
+        The IANA time zone name (e.g. 'America/New_York').
+    precision : int, default 9
+        The number of fractional second digits (maximum 9).
+
+    Returns
+    -------
+    str
+
+    Raises
+    ------
+    ValueError
+        If `tz` is not a valid time zone.
+
+    """
+
+
 def convert_to_snake_case(s: str) -> str:
     """
     Convert the given string from any common case (PascalCase, camelCase, kebab-case, etc.)
@@ -200,6 +294,9 @@ def convert_to_snake_case(s: str) -> str:
 def init_tracing() -> None:
     ...
 
+def shutdown_tracing() -> None:
+    ...
+
 def init_logging(
     trader_id: TraderId,
     instance_id: UUID4,
@@ -209,11 +306,20 @@ def init_logging(
     directory: str | None = None,
     file_name: str | None = None,
     file_format: str | None = None,
+    max_file_size: int | None = None,
+    max_file_age_secs: int | None = None,
+    compression: str | None = None,
+    max_backup_count: int | None = None,
+    max_backup_age_days: int | None = None,
     is_colored: bool | None = None,
+    is_json: bool | None = None,
     is_bypassed: bool | None = None,
     print_config: bool | None = None,
 ) -> None: ...
 
+def logging_set_component_level(component: str, level: LogLevel) -> None: ...
+def logging_clear_component_level(component: str) -> None: ...
+
 def log_header(
     trader_id: TraderId,
     machine_id: str,
@@ -223,6 +329,32 @@ def log_header(
 
 def log_sysinfo(component: str) -> None: ...
 
+### Metrics
+
+def init_metrics(host: str, port: int) -> None: ...
+def metrics_is_enabled() -> bool: ...
+def encode_metrics() -> str: ...
+
+### Aggregation
+
+class BarAggregator:
+    def __init__(
+        self,
+        instrument: Instrument,
+        bar_type: BarType,
+        build_with_no_updates: bool = True,
+        timestamp_on_close: bool = True,
+    ) -> None: ...
+    @property
+    def bar_type(self) -> BarType: ...
+    def set_await_partial(self, value: bool) -> None: ...
+    def set_partial(self, partial_bar: Bar) -> None: ...
+    def handle_quote_tick(self, quote: QuoteTick) -> list[Bar]: ...
+    def handle_trade_tick(self, trade: TradeTick) -> list[Bar]: ...
+    def handle_quote_ticks(self, quotes: list[QuoteTick]) -> list[Bar]: ...
+    def handle_trade_ticks(self, trades: list[TradeTick]) -> list[Bar]: ...
+    def build_until(self, ts_now: int) -> list[Bar]: ...
+
 ###################################################################################################
 # Model
 ###################################################################################################
@@ -405,6 +537,10 @@ def margin_account_from_account_events(events: list[dict],calculate_account_stat
 
 def drop_cvec_pycapsule(capsule: object) -> None: ...
 
+def register_custom_data_type(data_type: str, fields: dict[str, str]) -> None: ...
+
+def custom_data_schema(data_type: str) -> dict[str, str] | None: ...
+
 class BarSpecification:
     def __init__(
         self,
@@ -521,11 +657,16 @@ class OrderBookDeltas:
         instrument_id: InstrumentId,
         deltas: list[OrderBookDelta],
     ) -> None: ...
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> OrderBookDelta: ...
+    def __iter__(self) -> Iterator[OrderBookDelta]: ...
     @property
     def instrument_id(self) -> InstrumentId: ...
     @property
     def deltas(self) -> list[OrderBookDelta]: ...
     @property
+    def deltas_count(self) -> int: ...
+    @property
     def flags(self) -> int: ...
     @property
     def sequence(self) -> int: ...
@@ -533,6 +674,12 @@ class OrderBookDeltas:
     def ts_event(self) -> int: ...
     @property
     def ts_init(self) -> int: ...
+    @staticmethod
+    def from_pycapsule_list(instrument_id: InstrumentId, capsule: object) -> OrderBookDeltas: ...
+    @staticmethod
+    def flatten(batches: list[OrderBookDeltas]) -> OrderBookDeltas: ...
+    def split_by_flags(self) -> list[OrderBookDeltas]: ...
+    def split_by_size(self, max_size: int) -> list[OrderBookDeltas]: ...
 
 class OrderBookDepth10:
     def __init__(
@@ -556,6 +703,26 @@ class OrderBookDepth10:
     @staticmethod
     def get_stub() -> OrderBookDepth10: ...
 
+class OrderBookDepth25:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        bids: list[BookOrder],
+        asks: list[BookOrder],
+        bid_counts: list[int],
+        ask_counts: list[int],
+        flags: int,
+        sequence: int,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @staticmethod
+    def get_fields() -> dict[str, str]: ...
+
 class QuoteTick:
     def __init__(
         self,
@@ -594,6 +761,226 @@ class TradeTick:
     @classmethod
     def from_dict(cls, values: dict[str, str]) -> TradeTick: ...
 
+class TradeTicks:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        trades: list[TradeTick],
+        flags: int = 0,
+        sequence: int = 0,
+    ) -> None: ...
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> TradeTick: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def trades(self) -> list[TradeTick]: ...
+    @property
+    def flags(self) -> int: ...
+    @property
+    def sequence(self) -> int: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @staticmethod
+    def from_pycapsule(capsule: object) -> TradeTicks: ...
+    def as_pycapsule(self) -> object: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> TradeTicks: ...
+
+class MarkPriceUpdate:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        value: Price,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def value(self) -> Price: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @staticmethod
+    def get_metadata(instrument_id: InstrumentId, price_precision: int) -> dict[str, str]: ...
+    @staticmethod
+    def get_fields() -> dict[str, str]: ...
+    def as_pycapsule(self) -> object: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> MarkPriceUpdate: ...
+
+class IndexPriceUpdate:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        value: Price,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def value(self) -> Price: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @staticmethod
+    def get_metadata(instrument_id: InstrumentId, price_precision: int) -> dict[str, str]: ...
+    @staticmethod
+    def get_fields() -> dict[str, str]: ...
+    def as_pycapsule(self) -> object: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> IndexPriceUpdate: ...
+
+class OpenInterestUpdate:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        value: Quantity,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def value(self) -> Quantity: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @staticmethod
+    def get_metadata(instrument_id: InstrumentId, size_precision: int) -> dict[str, str]: ...
+    @staticmethod
+    def get_fields() -> dict[str, str]: ...
+    def as_pycapsule(self) -> object: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> OpenInterestUpdate: ...
+
+class FundingRateUpdate:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        rate: Decimal,
+        next_funding_ns: int | None,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def rate(self) -> Decimal: ...
+    @property
+    def next_funding_ns(self) -> int | None: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    def time_until_next_funding(self, ts_now: int) -> int | None: ...
+    @staticmethod
+    def get_metadata(instrument_id: InstrumentId) -> dict[str, str]: ...
+    @staticmethod
+    def get_fields() -> dict[str, str]: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> FundingRateUpdate: ...
+
+class CustomData:
+    def __init__(
+        self,
+        data_type: str,
+        values: dict[str, bool | int | float | str],
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    def __getitem__(self, name: str) -> bool | int | float | str: ...
+    @property
+    def data_type(self) -> str: ...
+    @property
+    def values(self) -> dict[str, bool | int | float | str]: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @staticmethod
+    def get_metadata(data_type: str) -> dict[str, str]: ...
+    @staticmethod
+    def get_fields(data_type: str) -> dict[str, str]: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> CustomData: ...
+
+class GreeksData:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        delta: float,
+        gamma: float,
+        vega: float,
+        theta: float,
+        implied_vol: float,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def delta(self) -> float: ...
+    @property
+    def gamma(self) -> float: ...
+    @property
+    def vega(self) -> float: ...
+    @property
+    def theta(self) -> float: ...
+    @property
+    def implied_vol(self) -> float: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    def scaled(self, factor: float) -> GreeksData: ...
+    @staticmethod
+    def get_metadata(instrument_id: InstrumentId) -> dict[str, str]: ...
+    @staticmethod
+    def get_fields() -> dict[str, str]: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> GreeksData: ...
+
+class LiquidationTick:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        side: OrderSide,
+        price: Price,
+        size: Quantity,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def side(self) -> OrderSide: ...
+    @property
+    def price(self) -> Price: ...
+    @property
+    def size(self) -> Quantity: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @staticmethod
+    def get_metadata(
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+    ) -> dict[str, str]: ...
+    @staticmethod
+    def get_fields() -> dict[str, str]: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> LiquidationTick: ...
+
 ### Enums
 
 class AccountType(Enum):
@@ -630,6 +1017,7 @@ class InstrumentClass(Enum):
     OPTION_SPREAD = "OPTION_SPEAD"
     WARRANT = "WARRANT"
     SPORTS_BETTING = "SPORTS_BETTING"
+    BINARY_OPTION = "BINARY_OPTION"
 
 class BarAggregation(Enum):
     TICK = "TICK"
@@ -648,6 +1036,8 @@ class BarAggregation(Enum):
     DAY = "DAY"
     WEEK = "WEEK"
     MONTH = "MONTH"
+    RENKO = "RENKO"
+    RANGE = "RANGE"
 
 class BookAction(Enum):
     ADD = "ADD"
@@ -687,11 +1077,21 @@ class MarketStatus(Enum):
     REOPEN = "REOPEN"
     PRE_CLOSE = "PRE_CLOSE"
     CLOSED = "CLOSED"
+    AUCTION = "AUCTION"
 
 class HaltReason(Enum):
     NOT_HALTED = "NOT_HALTED"
     GENERAL = "GENERAL"
     VOLATILITY = "VOLATILITY"
+    LIMIT_UP_LIMIT_DOWN = "LIMIT_UP_LIMIT_DOWN"
+    CIRCUIT_BREAKER = "CIRCUIT_BREAKER"
+
+class AuctionPhase(Enum):
+    NO_AUCTION = "NO_AUCTION"
+    OPENING = "OPENING"
+    CLOSING = "CLOSING"
+    INTRADAY = "INTRADAY"
+    VOLATILITY_INTERRUPTION = "VOLATILITY_INTERRUPTION"
 
 class OmsType(Enum):
     UNSPECIFIED = "UNSPECIFIED"
@@ -734,6 +1134,11 @@ class OrderType(Enum):
     TRAILING_STOP_MARKET = "TRAILING_STOP_MARKET"
     TRAILING_STOP_LIMIT = "TRAILING_STOP_LIMIT"
 
+class PegPriceType(Enum):
+    NO_PEG = "NO_PEG"
+    MID_POINT = "MID_POINT"
+    PRIMARY = "PRIMARY"
+
 class PositionSide(Enum):
     FLAT = "FLAT"
     LONG = "LONG"
@@ -745,6 +1150,15 @@ class PriceType(Enum):
     MID = "MID"
     LAST = "LAST"
 
+class RoundingMode(Enum):
+    UP = "UP"
+    DOWN = "DOWN"
+    CEILING = "CEILING"
+    FLOOR = "FLOOR"
+    HALF_UP = "HALF_UP"
+    HALF_DOWN = "HALF_DOWN"
+    HALF_EVEN = "HALF_EVEN"
+
 class TimeInForce(Enum):
     GTC = "GTC"
     IOC = "IOC"
@@ -962,6 +1376,18 @@ class Currency:
     def from_str(value: str, strict: bool = False) -> Currency: ...
     @staticmethod
     def register(currency: Currency, overwrite: bool = False) -> None: ...
+    @staticmethod
+    def try_register(currency: Currency) -> None: ...
+    @staticmethod
+    def register_new(
+        code: str,
+        precision: int,
+        currency_type: CurrencyType = CurrencyType.CRYPTO,
+        iso4217: int = 0,
+        name: str | None = None,
+    ) -> Currency: ...
+    @staticmethod
+    def is_registered(code: str) -> bool: ...
 
 class Money:
     def __init__(self, value: float, currency: Currency) -> None: ...
@@ -999,6 +1425,12 @@ class Price:
     def as_double(self) -> float: ...
     def as_decimal(self) -> Decimal: ...
     def to_formatted_str(self) -> str: ...
+    def checked_add(self, other: Price) -> Price | None: ...
+    def checked_sub(self, other: Price) -> Price | None: ...
+    def checked_mul(self, other: Price) -> Price | None: ...
+    def checked_div(self, other: Price) -> Price | None: ...
+    def checked_div_round(self, other: Price, precision: int, mode: RoundingMode = RoundingMode.HALF_EVEN) -> Price | None: ...
+    def checked_rem(self, other: Price) -> Price | None: ...
 
 class Quantity:
     def __init__(self, value: float, precision: int) -> None: ...
@@ -1019,6 +1451,12 @@ class Quantity:
     def as_decimal(self) -> Decimal: ...
     def as_double(self) -> float: ...
     def to_formatted_str(self) -> str: ...
+    def checked_add(self, other: Quantity) -> Quantity | None: ...
+    def checked_sub(self, other: Quantity) -> Quantity | None: ...
+    def checked_mul(self, other: Quantity) -> Quantity | None: ...
+    def checked_div(self, other: Quantity) -> Quantity | None: ...
+    def checked_div_round(self, other: Quantity, precision: int, mode: RoundingMode = RoundingMode.HALF_EVEN) -> Quantity | None: ...
+    def checked_rem(self, other: Quantity) -> Quantity | None: ...
 
 class AccountBalance:
     def __init__(self, total: Money, locked: Money, free: Money): ...
@@ -1394,6 +1832,109 @@ class OptionsSpread:
     def size_increment(self) -> Quantity: ...
     def to_dict(self) -> dict[str, Any]: ...
 
+class BinaryOption:
+    def __init__(
+        self,
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        asset_class: AssetClass,
+        currency: Currency,
+        activation_ns: int,
+        expiration_ns: int,
+        price_precision: int,
+        size_precision: int,
+        price_increment: Price,
+        size_increment: Quantity,
+        ts_event: int,
+        ts_init: int,
+        outcome: str | None = None,
+        description: str | None = None,
+        max_quantity: Quantity | None = None,
+        min_quantity: Quantity | None = None,
+        max_price: Price | None = None,
+        min_price: Price | None = None,
+    ) -> None : ...
+    @property
+    def id(self) -> InstrumentId: ...
+    @property
+    def raw_symbol(self) -> Symbol: ...
+    @property
+    def currency(self) -> Currency: ...
+    @property
+    def activation_ns(self) -> int: ...
+    @property
+    def expiration_ns(self) -> int: ...
+    @property
+    def price_precision(self) -> int: ...
+    @property
+    def size_precision(self) -> int: ...
+    @property
+    def price_increment(self) -> Price: ...
+    @property
+    def size_increment(self) -> Quantity: ...
+    @property
+    def outcome(self) -> str | None: ...
+    @property
+    def description(self) -> str | None: ...
+    def settlement_price(self, outcome_occurred: bool) -> Price: ...
+    def settlement_value(self, quantity: Quantity, outcome_occurred: bool) -> Money: ...
+    def is_active(self, ts: int) -> bool: ...
+    def check_price(self, price: Price) -> None: ...
+    @staticmethod
+    def from_dict(values: dict[str, Any]) -> BinaryOption: ...
+    def to_dict(self) -> dict[str, Any]: ...
+
+class ComboLeg:
+    def __init__(self, instrument_id: InstrumentId, ratio: int) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def ratio(self) -> int: ...
+    def side_for(self, side: OrderSide) -> OrderSide: ...
+
+class ComboInstrument:
+    def __init__(
+        self,
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        asset_class: AssetClass,
+        instrument_class: InstrumentClass,
+        legs: list[ComboLeg],
+        currency: Currency,
+        price_precision: int,
+        price_increment: Price,
+        multiplier: Quantity,
+        lot_size: Quantity,
+        ts_event: int,
+        ts_init: int,
+        max_quantity: Quantity | None = None,
+        min_quantity: Quantity | None = None,
+        max_price: Price | None = None,
+        min_price: Price | None = None,
+        exchange: str | None = None,
+    ) -> None : ...
+    @property
+    def id(self) -> InstrumentId: ...
+    @property
+    def raw_symbol(self) -> Symbol: ...
+    @property
+    def legs(self) -> list[ComboLeg]: ...
+    @property
+    def currency(self) -> Currency: ...
+    @property
+    def price_precision(self) -> int: ...
+    @property
+    def price_increment(self) -> Price: ...
+    @property
+    def multiplier(self) -> Quantity: ...
+    def leg_ids(self) -> list[InstrumentId]: ...
+    def calculate_price(self, leg_prices: dict[InstrumentId, Price]) -> Price: ...
+    def calculate_quote(self, leg_quotes: dict[InstrumentId, QuoteTick]) -> tuple[Price, Price]: ...
+    def leg_orders(self, side: OrderSide, quantity: Quantity) -> list[tuple[InstrumentId, OrderSide, Quantity]]: ...
+    @staticmethod
+    def from_dict(values: dict[str, Any]) -> ComboInstrument: ...
+    def to_dict(self) -> dict[str, Any]: ...
+
 class SyntheticInstrument:
     @property
     def id(self) -> InstrumentId: ...
@@ -1412,6 +1953,8 @@ class SyntheticInstrument:
     def to_dict(self) -> dict[str, Any]: ...
 
 Instrument: TypeAlias = Union[
+    BinaryOption,
+    ComboInstrument,
     CryptoFuture,
     CryptoPerpetual,
     CurrencyPair,
@@ -1762,6 +2305,14 @@ class Level:
     def first(self) -> BookOrder | None: ...
     def get_orders(self) -> list[BookOrder]: ...
 
+class BookIntegrityConfig:
+    def __init__(
+        self,
+        on_crossed: str = "RAISE",
+        on_locked: str = "RAISE",
+        on_invalid_order: str = "RAISE",
+    ) -> None: ...
+
 class OrderBookMbo:
     def __init__(self, instrument_id: InstrumentId) -> None: ...
     @property
@@ -1786,8 +2337,12 @@ class OrderBookMbo:
     def clear_asks(self, ts_event: int, sequence: int = 0) -> None: ...
     def apply_delta(self, delta: OrderBookDelta) -> None: ...
     def apply_deltas(self, deltas: OrderBookDeltas) -> None: ...
+    def apply_deltas_with_quote(self, deltas: OrderBookDeltas) -> tuple[bool, QuoteTick | None]: ...
+    def top_of_book_quote(self, ts_init: int) -> QuoteTick | None: ...
     def apply_depth(self, depth: OrderBookDepth10) -> None: ...
+    def apply_depth25(self, depth: OrderBookDepth25) -> None: ...
     def check_integrity(self) -> None: ...
+    def enforce_integrity(self, config: BookIntegrityConfig) -> None: ...
     def bids(self) -> list[Level]: ...
     def asks(self) -> list[Level]: ...
     def best_bid_price(self) -> Price | None: ...
@@ -1796,8 +2351,19 @@ class OrderBookMbo:
     def best_ask_size(self) -> Quantity | None: ...
     def spread(self) -> float | None: ...
     def midpoint(self) -> float | None: ...
+    def spread_ticks(self, tick_size: Price) -> float | None: ...
+    def volume_imbalance(self, depth: int) -> float | None: ...
+    def microprice(self) -> float | None: ...
+    def get_cumulative_depth(self, side: OrderSide, offset: Price) -> float: ...
+    def group_levels(self, side: OrderSide, tick_interval: Price) -> list[tuple[Price, float]]: ...
     def get_avg_px_for_quantity(self, qty: Quantity, order_side: OrderSide) -> float: ...
+    def get_sweep_for_quantity(
+        self,
+        qty: Quantity,
+        order_side: OrderSide,
+    ) -> tuple[float, Quantity, list[tuple[Price, Quantity]]]: ...
     def get_quantity_for_price(self, price: Price, order_side: OrderSide) -> float: ...
+    def get_queue_position(self, order_id: int) -> tuple[Price, int, Quantity] | None: ...
     def simulate_fills(self, order: BookOrder) -> list[tuple[Price, Quantity]]: ...
     def pprint(self, num_levels: int) -> str: ...
 
@@ -1831,8 +2397,13 @@ class OrderBookMbp:
     def clear_asks(self, ts_event: int, sequence: int = 0) -> None: ...
     def apply_delta(self, delta: OrderBookDelta) -> None: ...
     def apply_deltas(self, deltas: OrderBookDeltas) -> None: ...
+    def apply_deltas_with_quote(self, deltas: OrderBookDeltas) -> tuple[bool, QuoteTick | None]: ...
+    def top_of_book_quote(self, ts_init: int) -> QuoteTick | None: ...
     def apply_depth(self, depth: OrderBookDepth10) -> None: ...
+    def apply_depth25(self, depth: OrderBookDepth25) -> None: ...
+    def apply_depth25_incremental(self, depth: OrderBookDepth25) -> list[OrderBookDelta]: ...
     def check_integrity(self) -> None: ...
+    def enforce_integrity(self, config: BookIntegrityConfig) -> None: ...
     def bids(self) -> list[Level]: ...
     def asks(self) -> list[Level]: ...
     def best_bid_price(self) -> Price | None: ...
@@ -1841,7 +2412,17 @@ class OrderBookMbp:
     def best_ask_size(self) -> Quantity | None: ...
     def spread(self) -> float | None: ...
     def midpoint(self) -> float | None: ...
+    def spread_ticks(self, tick_size: Price) -> float | None: ...
+    def volume_imbalance(self, depth: int) -> float | None: ...
+    def microprice(self) -> float | None: ...
+    def get_cumulative_depth(self, side: OrderSide, offset: Price) -> float: ...
+    def group_levels(self, side: OrderSide, tick_interval: Price) -> list[tuple[Price, float]]: ...
     def get_avg_px_for_quantity(self, qty: Quantity, order_side: OrderSide) -> float: ...
+    def get_sweep_for_quantity(
+        self,
+        qty: Quantity,
+        order_side: OrderSide,
+    ) -> tuple[float, Quantity, list[tuple[Price, Quantity]]]: ...
     def get_quantity_for_price(self, price: Price, order_side: OrderSide) -> float: ...
     def simulate_fills(self, order: BookOrder) -> list[tuple[Price, Quantity]]: ...
     def pprint(self, num_levels: int) -> str: ...
@@ -1854,19 +2435,141 @@ class RedisCacheDatabase:
     def __init__(
         self,
         trader_id: TraderId,
-        config: dict[str, Any],
+        instance_id: UUID4,
+        config_json: bytes,
+    ) -> None: ...
+    def flushdb(self) -> None: ...
+    def keys(self, pattern: str) -> list[str]: ...
+    def read(self, key: str) -> list[bytes]: ...
+    def insert(self, key: str, payload: list[bytes]) -> None: ...
+    def update(self, key: str, payload: list[bytes]) -> None: ...
+    def delete(self, key: str, payload: list[bytes] | None = None) -> None: ...
+    def update_order(self, key: str, event: bytes, flags: int) -> None: ...
+    def update_position(self, key: str, event: bytes, flags: int) -> None: ...
+    def export_archive(self, path: PathLike[str] | str) -> int: ...
+    def restore_archive(self, path: PathLike[str] | str) -> int: ...
+
+class PostgresCacheDatabase:
+    def __init__(
+        self,
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config_json: bytes,
+    ) -> None: ...
+    def flushdb(self) -> None: ...
+    def keys(self, pattern: str) -> list[str]: ...
+    def read(self, key: str) -> list[bytes]: ...
+    def load_collection(self, collection: str) -> dict[str, list[bytes]]: ...
+    def insert(self, key: str, payload: list[bytes]) -> None: ...
+    def update(self, key: str, payload: list[bytes]) -> None: ...
+    def delete(self, key: str, payload: list[bytes] | None = None) -> None: ...
+    def update_order(self, key: str, event: bytes, flags: int) -> None: ...
+    def update_position(self, key: str, event: bytes, flags: int) -> None: ...
+    def export_archive(self, path: PathLike[str] | str) -> int: ...
+    def restore_archive(self, path: PathLike[str] | str) -> int: ...
+
+class SqliteCacheDatabase:
+    def __init__(
+        self,
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config_json: bytes,
+    ) -> None: ...
+    def flushdb(self) -> None: ...
+    def keys(self, pattern: str) -> list[str]: ...
+    def read(self, key: str) -> list[bytes]: ...
+    def load_collection(self, collection: str) -> dict[str, list[bytes]]: ...
+    def insert(self, key: str, payload: list[bytes]) -> None: ...
+    def update(self, key: str, payload: list[bytes]) -> None: ...
+    def delete(self, key: str, payload: list[bytes] | None = None) -> None: ...
+    def update_order(self, key: str, event: bytes, flags: int) -> None: ...
+    def update_position(self, key: str, event: bytes, flags: int) -> None: ...
+    def export_archive(self, path: PathLike[str] | str) -> int: ...
+    def restore_archive(self, path: PathLike[str] | str) -> int: ...
+
+class NatsMessageBusDatabase:
+    def __init__(
+        self,
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config_json: bytes,
+    ) -> None: ...
+    def is_closed(self) -> bool: ...
+    def publish(self, topic: str, payload: bytes) -> None: ...
+    def close(self) -> None: ...
+
+class NatsMessageBusConsumer:
+    def __init__(
+        self,
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config_json: bytes,
+        durable_name: str,
+        topic_filter: str | None = None,
+    ) -> None: ...
+    def fetch(self, max_messages: int, timeout_ms: int) -> list[tuple[str, bytes]]: ...
+
+class KafkaMessageBusDatabase:
+    def __init__(
+        self,
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config_json: bytes,
     ) -> None: ...
+    def is_closed(self) -> bool: ...
+    def publish(self, topic: str, payload: bytes, key: str | None = None) -> None: ...
+    def close(self) -> None: ...
+
+class KafkaMessageBusConsumer:
+    def __init__(
+        self,
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config_json: bytes,
+        group_id: str,
+        types: list[str] | None = None,
+    ) -> None: ...
+    def poll(self, max_messages: int, timeout_ms: int) -> list[tuple[str, bytes]]: ...
 
 ###################################################################################################
 # Network
 ###################################################################################################
 
+class GrpcClient:
+    def __init__(
+        self,
+        url: str,
+        keyed_quotas: list[tuple[str, Quota]] = [],
+        default_quota: Quota | None = None,
+        retry_policy: RetryPolicy | None = None,
+        timeout_secs: int | None = None,
+    ) -> None: ...
+    async def unary(
+        self,
+        path: str,
+        request: bytes,
+        metadata: dict[str, str] | None = None,
+        keys: list[str] | None = None,
+        weight: int | None = None,
+        policy: RateLimitPolicy | None = None,
+        idempotent: bool = False,
+    ) -> GrpcResponse: ...
+
+class GrpcResponse:
+    @property
+    def body(self) -> bytes: ...
+    @property
+    def metadata(self) -> dict[str, str]: ...
+
 class HttpClient:
     def __init__(
         self,
         header_keys: list[str] = [],
         keyed_quotas: list[tuple[str, Quota]] = [],
         default_quota: Quota | None = None,
+        retry_policy: RetryPolicy | None = None,
+        used_weight_headers: list[tuple[str, str]] = [],
+        remaining_weight_headers: list[tuple[str, str]] = [],
     ) -> None: ...
     async def request(
         self,
@@ -1875,6 +2578,8 @@ class HttpClient:
         headers: dict[str, str] | None = None,
         body: bytes | None = None,
         keys: list[str] | None = None,
+        weight: int | None = None,
+        policy: RateLimitPolicy | None = None,
     ) -> HttpResponse: ...
 
 class HttpMethod(Enum):
@@ -1892,6 +2597,10 @@ class HttpResponse:
     @property
     def headers(self) -> dict[str, str]: ...
 
+class RateLimitPolicy(Enum):
+    QUEUE = "QUEUE"
+    REJECT = "REJECT"
+
 class Quota:
     @classmethod
     def rate_per_second(cls, max_burst: int) -> Quota: ...
@@ -1900,6 +2609,22 @@ class Quota:
     @classmethod
     def rate_per_hour(cls, max_burst: int) -> Quota: ...
 
+class RetryPolicy:
+    def __init__(
+        self,
+        max_retries: int = 3,
+        delay_initial_ms: int = 500,
+        delay_max_ms: int = 30_000,
+        backoff_factor: float = 2.0,
+        jitter_ms: int = 100,
+        max_elapsed_ms: int | None = None,
+        retry_statuses: list[str] | None = None,
+        respect_retry_after: bool = True,
+        idempotency_header: str | None = None,
+    ) -> None: ...
+    @property
+    def max_retries(self) -> int: ...
+
 class WebSocketConfig:
     def __init__(
         self,
@@ -1909,6 +2634,13 @@ class WebSocketConfig:
         heartbeat: int | None = None,
         heartbeat_msg: str | None = None,
         ping_handler: Callable[..., Any] | None = None,
+        state_handler: Callable[[str], None] | None = None,
+        reconnect_delay_initial_ms: int | None = None,
+        reconnect_delay_max_ms: int | None = None,
+        reconnect_backoff_factor: float | None = None,
+        reconnect_jitter_ms: int | None = None,
+        reconnect_max_attempts: int | None = None,
+        compression: str | None = None,
     ) -> None: ...
 
 class WebSocketClient:
@@ -1926,6 +2658,8 @@ class WebSocketClient:
     def send(self, data: bytes) -> Awaitable[None]: ...
     def send_text(self, data: str) -> Awaitable[None]: ...
     def send_pong(self, data: bytes) -> Awaitable[None]: ...
+    def add_subscription(self, data: str) -> Awaitable[None]: ...
+    def remove_subscription(self, data: str) -> Awaitable[None]: ...
 
 class SocketClient:
     @classmethod
@@ -1949,8 +2683,33 @@ class SocketConfig:
         suffix: bytes,
         handler: Callable[..., Any],
         heartbeat: tuple[int, list[int]] | None = None,
+        proxy_url: str | None = None,
+        client_cert_path: str | None = None,
+        client_key_path: str | None = None,
+        ca_cert_path: str | None = None,
     ) -> None: ...
 
+class WebSocketServer:
+    @staticmethod
+    def bind(
+        addr: str,
+        handler: Callable[[int, str], None],
+        on_connect: Callable[[int, str], None] | None = None,
+        on_disconnect: Callable[[int], None] | None = None,
+    ) -> Awaitable[WebSocketServer]: ...
+    @property
+    def local_addr(self) -> str: ...
+    @property
+    def client_count(self) -> int: ...
+    def send(self, client_id: int, message: str) -> bool: ...
+    def broadcast(self, message: str) -> int: ...
+    def subscribe(self, client_id: int, topic: str) -> None: ...
+    def unsubscribe(self, client_id: int, topic: str) -> None: ...
+    def subscriber_count(self, topic: str) -> int: ...
+    def publish(self, topic: str, message: str) -> int: ...
+    def close_client(self, client_id: int) -> bool: ...
+    def shutdown(self) -> None: ...
+
 ###################################################################################################
 # Persistence
 ###################################################################################################
@@ -1961,6 +2720,11 @@ class NautilusDataType(Enum):
     QuoteTick = 3
     TradeTick = 4
     Bar = 5
+    OpenInterestUpdate = 6
+    OrderBookDeltas = 7
+    OrderBookDepth25 = 8
+    MarkPriceUpdate = 9
+    IndexPriceUpdate = 10
 
 class DataBackendSession:
     def __init__(self, chunk_size: int = 5000) -> None: ...
@@ -1971,6 +2735,16 @@ class DataBackendSession:
         file_path: str,
         sql_query: str | None = None,
     ) -> None: ...
+    def add_file_with_filter(
+        self,
+        data_type: NautilusDataType,
+        table_name: str,
+        file_path: str,
+        instrument_ids: list[str] | None = None,
+        start: int | None = None,
+        end: int | None = None,
+        where_clause: str | None = None,
+    ) -> bool: ...
     def to_query_result(self) -> DataQueryResult: ...
 
 class QueryResult:
@@ -1990,13 +2764,31 @@ class DataTransformer:
     @staticmethod
     def pyo3_order_book_deltas_to_record_batch_bytes(data: list[OrderBookDelta]) -> bytes: ...
     @staticmethod
+    def pyo3_order_book_deltas_batches_to_record_batch_bytes(data: list[OrderBookDeltas]) -> bytes: ...
+    @staticmethod
     def pyo3_order_book_depth10_to_record_batch_bytes(data: list[OrderBookDepth10]) -> bytes: ...
     @staticmethod
+    def pyo3_order_book_depth25_to_record_batch_bytes(data: list[OrderBookDepth25]) -> bytes: ...
+    @staticmethod
     def pyo3_quote_ticks_to_record_batch_bytes(data: list[QuoteTick]) -> bytes: ...
     @staticmethod
     def pyo3_trade_ticks_to_record_batch_bytes(data: list[TradeTick]) -> bytes: ...
     @staticmethod
     def pyo3_bars_to_record_batch_bytes(data: list[Bar]) -> bytes: ...
+    @staticmethod
+    def pyo3_open_interest_to_record_batch_bytes(data: list[OpenInterestUpdate]) -> bytes: ...
+    @staticmethod
+    def pyo3_mark_prices_to_record_batch_bytes(data: list[MarkPriceUpdate]) -> bytes: ...
+    @staticmethod
+    def pyo3_index_prices_to_record_batch_bytes(data: list[IndexPriceUpdate]) -> bytes: ...
+    @staticmethod
+    def pyo3_custom_data_to_record_batch_bytes(data: list[CustomData]) -> bytes: ...
+    @staticmethod
+    def record_batch_bytes_to_pyo3_custom_data(data_type: str, data: bytes) -> list[CustomData]: ...
+    @staticmethod
+    def pyo3_binary_options_to_record_batch_bytes(data: list[BinaryOption]) -> bytes: ...
+    @staticmethod
+    def record_batch_bytes_to_pyo3_binary_options(data: bytes) -> list[BinaryOption]: ...
 
 class OrderBookDeltaDataWrangler:
     def __init__(
@@ -2307,6 +3099,62 @@ class BookImbalanceRatio:
     def update(self, best_bid: Quantity | None, best_ask: Quantity) -> None: ...
     def reset(self) -> None: ...
 
+###################################################################################################
+# Analysis
+###################################################################################################
+
+class MaxDrawdown:
+    def __init__(self, currency: Currency) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def currency(self) -> Currency: ...
+    @property
+    def peak(self) -> float: ...
+    @property
+    def drawdown(self) -> float: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, value: float) -> None: ...
+    def handle_account_state(self, state: AccountState) -> None: ...
+    def reset(self) -> None: ...
+
+class RollingSharpeRatio:
+    def __init__(self, window: int, period: int | None = None) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def window(self) -> int: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, value: float) -> None: ...
+    def handle_return(self, ts: int, value: float) -> None: ...
+    def handle_position(self, position: Position) -> None: ...
+    def reset(self) -> None: ...
+
+class RollingSortinoRatio:
+    def __init__(self, window: int, period: int | None = None) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def window(self) -> int: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, value: float) -> None: ...
+    def handle_return(self, ts: int, value: float) -> None: ...
+    def handle_position(self, position: Position) -> None: ...
+    def reset(self) -> None: ...
+
 ###################################################################################################
 # Adapters
 ###################################################################################################
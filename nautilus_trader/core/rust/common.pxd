@@ -1,7 +1,7 @@
 # Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
 
 from cpython.object cimport PyObject
-from libc.stdint cimport uint8_t, uint64_t, uintptr_t
+from libc.stdint cimport int64_t, uint8_t, uint64_t, uintptr_t
 from nautilus_trader.core.rust.core cimport CVec, UUID4_t
 from nautilus_trader.core.rust.model cimport TraderId_t
 
@@ -112,6 +112,8 @@ cdef extern from "../includes/common.h":
     # Pub/Sub wildcard patterns for hierarchical topics are possible:
     #  - `*` asterisk represents one or more characters in a pattern.
     #  - `?` question mark represents a single character in a pattern.
+    #  - `+` as a whole level represents exactly one topic level.
+    #  - `#` as a whole level represents zero or more topic levels.
     #
     # Given a topic and pattern potentially containing wildcard characters, i.e.
     # `*` and `?`, where `?` can match any single character in the topic, and `*`
@@ -124,9 +126,22 @@ cdef extern from "../includes/common.h":
     # A question mark matches a single character once. For example, `c?mp` matches
     # `camp` and `comp`. The question mark can also be used more than once.
     # For example, `c??p` would match both of the above examples and `coop`.
+    #
+    # Topic levels are separated by `.` and the MQTT-style level wildcards never
+    # match across a level boundary except as described. For example,
+    # `data.quotes.+` matches `data.quotes.BINANCE` but not `data.quotes.BINANCE.ETHUSDT`,
+    # whereas `data.quotes.#` matches both (as well as `data.quotes` itself).
+    # When a pattern contains a level wildcard, `*` and `?` only match within a single level.
+    #
+    # Subscribers are delivered messages in descending priority order, and subscribers
+    # with equal priority are delivered in the order they subscribed.
     cdef struct MessageBus:
         pass
 
+    # Provides a deterministic, seedable source of random numbers.
+    cdef struct RandomGenerator:
+        pass
+
     cdef struct TestClock:
         pass
 
@@ -164,6 +179,17 @@ cdef extern from "../includes/common.h":
     cdef struct MessageBus_API:
         MessageBus *_0;
 
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`RandomGenerator`].
+    #
+    # This struct wraps `RandomGenerator` in a way that makes it compatible with C function
+    # calls, enabling interaction with `RandomGenerator` in a C environment.
+    #
+    # It implements the `Deref` and `DerefMut` traits, allowing instances of `RandomGenerator_API`
+    # to be dereferenced to `RandomGenerator`, providing access to `RandomGenerator`'s methods
+    # without having to manually access the underlying `RandomGenerator` instance.
+    cdef struct RandomGenerator_API:
+        RandomGenerator *_0;
+
     # Represents a time event occurring at the event timestamp.
     cdef struct TimeEvent_t:
         # The event name.
@@ -226,6 +252,9 @@ cdef extern from "../includes/common.h":
 
     uintptr_t test_clock_timer_count(TestClock_API *clock);
 
+    # Returns the earliest time any timer is scheduled to alert (zero if no timers).
+    uint64_t test_clock_next_timer_ns(const TestClock_API *clock);
+
     # # Safety
     #
     # - Assumes `name_ptr` is a valid C string pointer.
@@ -318,6 +347,34 @@ cdef extern from "../includes/common.h":
 
     void live_clock_cancel_timers(LiveClock_API *clock);
 
+    # # Safety
+    #
+    # - Assumes `venue_ptr` is a valid C string pointer.
+    void live_clock_record_venue_time(LiveClock_API *clock,
+                                      const char *venue_ptr,
+                                      uint64_t ts_sent,
+                                      uint64_t ts_server,
+                                      uint64_t ts_recv);
+
+    # # Safety
+    #
+    # - Assumes `venue_ptr` is a valid C string pointer.
+    uint8_t live_clock_has_venue_offset(const LiveClock_API *clock, const char *venue_ptr);
+
+    # Returns the estimated venue clock offset (nanoseconds), or zero if no observations recorded.
+    #
+    # # Safety
+    #
+    # - Assumes `venue_ptr` is a valid C string pointer.
+    int64_t live_clock_venue_offset_ns(const LiveClock_API *clock, const char *venue_ptr);
+
+    # Returns the estimated one-way venue latency (nanoseconds), or zero if no observations recorded.
+    #
+    # # Safety
+    #
+    # - Assumes `venue_ptr` is a valid C string pointer.
+    uint64_t live_clock_venue_latency_ns(const LiveClock_API *clock, const char *venue_ptr);
+
     const char *component_state_to_cstr(ComponentState value);
 
     # Returns an enum from a Python string.
@@ -370,6 +427,7 @@ cdef extern from "../includes/common.h":
     # - Assume `file_name_ptr` is either NULL or a valid C string pointer.
     # - Assume `file_format_ptr` is either NULL or a valid C string pointer.
     # - Assume `component_level_ptr` is either NULL or a valid C string pointer.
+    # - Assume `file_rotation_ptr` is either NULL or a valid C string pointer.
     void logging_init(TraderId_t trader_id,
                       UUID4_t instance_id,
                       LogLevel level_stdout,
@@ -377,8 +435,10 @@ cdef extern from "../includes/common.h":
                       const char *directory_ptr,
                       const char *file_name_ptr,
                       const char *file_format_ptr,
+                      const char *file_rotation_ptr,
                       const char *component_levels_ptr,
                       uint8_t is_colored,
+                      uint8_t is_json,
                       uint8_t is_bypassed,
                       uint8_t print_config);
 
@@ -411,9 +471,48 @@ cdef extern from "../includes/common.h":
     # - Assumes `component_ptr` is a valid C string pointer.
     void logging_log_sysinfo(const char *component_ptr);
 
+    # Sets the runtime log level override for the given component.
+    #
+    # # Safety
+    #
+    # - Assumes `component_ptr` is a valid C string pointer.
+    void logging_set_component_level(const char *component_ptr, LogLevel level);
+
+    # Clears any runtime log level override for the given component.
+    #
+    # # Safety
+    #
+    # - Assumes `component_ptr` is a valid C string pointer.
+    void logging_clear_component_level(const char *component_ptr);
+
     # Flushes global logger buffers.
     void logger_flush();
 
+    # Returns whether metrics collection is enabled.
+    uint8_t metrics_is_enabled();
+
+    # Increments the count of messages of `kind` processed by `component`.
+    #
+    # # Safety
+    #
+    # - Assumes `component_ptr` is a valid C string pointer.
+    # - Assumes `kind_ptr` is a valid C string pointer.
+    void metrics_inc_messages_processed(const char *component_ptr, const char *kind_ptr);
+
+    # Records the time from order initialization to the venue response `event`.
+    #
+    # # Safety
+    #
+    # - Assumes `event_ptr` is a valid C string pointer.
+    void metrics_observe_order_latency(const char *event_ptr, uint64_t latency_ns);
+
+    # Records the time taken to apply an update to an order book of `book_type`.
+    #
+    # # Safety
+    #
+    # - Assumes `book_type_ptr` is a valid C string pointer.
+    void metrics_observe_book_apply_time(const char *book_type_ptr, uint64_t duration_ns);
+
     # # Safety
     #
     # - Assumes `trader_id_ptr` is a valid C string pointer.
@@ -545,6 +644,32 @@ cdef extern from "../includes/common.h":
                                  const char *topic_ptr,
                                  const char *payload_ptr);
 
+    RandomGenerator_API random_generator_new(uint64_t seed);
+
+    RandomGenerator_API random_generator_from_entropy();
+
+    void random_generator_drop(RandomGenerator_API rng);
+
+    uint64_t random_generator_seed(const RandomGenerator_API *rng);
+
+    void random_generator_reseed(RandomGenerator_API *rng, uint64_t seed);
+
+    # # Safety
+    #
+    # - Assumes `stream_ptr` is a valid C string pointer.
+    RandomGenerator_API random_generator_fork(const RandomGenerator_API *rng,
+                                              const char *stream_ptr);
+
+    uint64_t random_generator_next_u64(RandomGenerator_API *rng);
+
+    double random_generator_next_f64(RandomGenerator_API *rng);
+
+    uint8_t random_generator_gen_bool(RandomGenerator_API *rng, double probability);
+
+    uint64_t random_generator_gen_range(RandomGenerator_API *rng, uint64_t low, uint64_t high);
+
+    double random_generator_gen_normal(RandomGenerator_API *rng, double mean, double std_dev);
+
     # # Safety
     #
     # - Assumes `name_ptr` is borrowed from a valid Python UTF-8 `str`.
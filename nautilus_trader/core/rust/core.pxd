@@ -85,6 +85,13 @@ cdef extern from "../includes/core.h":
 
     UUID4_t uuid4_new();
 
+    # Sets the UUID version generated by `uuid4_new` for the process.
+    #
+    # # Panics
+    #
+    # - If `version` is not a supported UUID version (4 or 7).
+    void uuid4_set_version(uint8_t version);
+
     # Returns a [`UUID4`] from C string pointer.
     #
     # # Safety
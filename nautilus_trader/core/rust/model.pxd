@@ -7,6 +7,8 @@ cdef extern from "../includes/model.h":
 
     const uintptr_t DEPTH10_LEN # = 10
 
+    const uintptr_t DEPTH25_LEN # = 25
+
     const uint8_t FIXED_PRECISION # = 9
 
     const double FIXED_SCALAR # = 1000000000.0
@@ -65,6 +67,19 @@ cdef extern from "../includes/model.h":
         # Alternative assets.
         ALTERNATIVE # = 7,
 
+    # The auction call phase for a market session.
+    cpdef enum AuctionPhase:
+        # The market session is not in an auction call phase.
+        NO_AUCTION # = 1,
+        # The opening auction call phase before continuous trading.
+        OPENING # = 2,
+        # The closing auction call phase after continuous trading.
+        CLOSING # = 3,
+        # A scheduled intraday auction call phase.
+        INTRADAY # = 4,
+        # An unscheduled auction call phase triggered by a price breaching the volatility bands.
+        VOLATILITY_INTERRUPTION # = 5,
+
     # The type of order book action for an order book event.
     cpdef enum BookAction:
         # An order is added to the book.
@@ -115,6 +130,10 @@ cdef extern from "../includes/model.h":
         GENERAL # = 2,
         # Trading halt is imposed by the venue to protect against extreme volatility.
         VOLATILITY # = 3,
+        # Trading halt is imposed after the price reached a limit-up or limit-down band.
+        LIMIT_UP_LIMIT_DOWN # = 4,
+        # Trading halt is imposed by a market-wide circuit breaker.
+        CIRCUIT_BREAKER # = 5,
 
     # The asset type for a financial market product.
     cpdef enum InstrumentClass:
@@ -140,6 +159,8 @@ cdef extern from "../includes/model.h":
         WARRANT # = 10,
         # A warrant instrument class. A derivative that gives the holder the right, but not the obligation, to buy or sell a security—most commonly an equity—at a certain price before expiration.
         SPORTS_BETTING # = 11,
+        # A binary option instrument class. A contract which settles at either a fixed amount or nothing depending on the outcome of an event, such as a prediction market contract.
+        BINARY_OPTION # = 12,
 
     # The type of event for an instrument close.
     cpdef enum InstrumentCloseType:
@@ -173,6 +194,8 @@ cdef extern from "../includes/model.h":
         PRE_CLOSE # = 6,
         # The market session is closed.
         CLOSED # = 7,
+        # The market session is in an auction call phase.
+        AUCTION # = 8,
 
     # The order management system (OMS) type for a trading venue or trading strategy.
     cpdef enum OmsType:
@@ -272,6 +295,15 @@ cdef extern from "../includes/model.h":
         # A trailing stop limit order combines the features of a trailing stop order with those of a limit order.
         TRAILING_STOP_LIMIT # = 9,
 
+    # The reference price which the limit price of a pegged order is pegged to.
+    cpdef enum PegPriceType:
+        # No peg is specified (the order is not pegged).
+        NO_PEG # = 0,
+        # Pegged to the mid-point of the best bid and ask, offset away from the market.
+        MID_POINT # = 1,
+        # Pegged to the same side of the market (best bid for BUY, best ask for SELL), offset towards the market.
+        PRIMARY # = 2,
+
     # The market side for a specific position, or action related to positions.
     cpdef enum PositionSide:
         # No position side is specified (only valid in the context of a filter for actions involving positions).
@@ -294,6 +326,23 @@ cdef extern from "../includes/model.h":
         # The last price at which a trade was made for an instrument.
         LAST # = 4,
 
+    # The rounding mode for fixed-point division and rescaling of value types.
+    cpdef enum RoundingMode:
+        # Rounds away from zero.
+        UP # = 1,
+        # Rounds towards zero (truncation).
+        DOWN # = 2,
+        # Rounds towards positive infinity.
+        CEILING # = 3,
+        # Rounds towards negative infinity.
+        FLOOR # = 4,
+        # Rounds to the nearest value, with ties rounded away from zero.
+        HALF_UP # = 5,
+        # Rounds to the nearest value, with ties rounded towards zero.
+        HALF_DOWN # = 6,
+        # Rounds to the nearest value, with ties rounded to the nearest even value (banker's rounding).
+        HALF_EVEN # = 7,
+
     # The 'Time in Force' instruction for an order in the financial market.
     cpdef enum TimeInForce:
         # Good Till Canceled (GTC) - the order remains active until canceled.
@@ -377,6 +426,13 @@ cdef extern from "../includes/model.h":
     cdef struct SyntheticInstrument:
         pass
 
+    # Represents a grouped batch of `TradeTick` events for a single instrument, such as a
+    # burst of trades delivered in one venue message.
+    #
+    # This type cannot be `repr(C)` due to the `trades` vec.
+    cdef struct TradeTicks_t:
+        pass
+
     # Represents a valid ticker symbol ID for a tradable financial market instrument.
     cdef struct Symbol_t:
         # The ticker symbol ID value.
@@ -396,12 +452,18 @@ cdef extern from "../includes/model.h":
         # The instruments trading venue.
         Venue_t venue;
 
+    # The raw fixed-point representation of a [`Price`].
+    ctypedef int64_t PriceRaw;
+
     cdef struct Price_t:
-        int64_t raw;
+        PriceRaw raw;
         uint8_t precision;
 
+    # The raw fixed-point representation of a [`Quantity`].
+    ctypedef uint64_t QuantityRaw;
+
     cdef struct Quantity_t:
-        uint64_t raw;
+        QuantityRaw raw;
         uint8_t precision;
 
     # Represents an order in a book.
@@ -472,6 +534,31 @@ cdef extern from "../includes/model.h":
         # The UNIX timestamp (nanoseconds) when the data object was initialized.
         uint64_t ts_init;
 
+    # Represents a self-contained order book update with a fixed depth of 25 levels per side.
+    #
+    # This is the 25 level equivalent of `OrderBookDepth10`, for venues which publish deeper
+    # fixed-depth books (e.g. OKX and Kraken). Unused levels are padded with default (zero size)
+    # orders.
+    cdef struct OrderBookDepth25_t:
+        # The instrument ID for the book.
+        InstrumentId_t instrument_id;
+        # The bid orders for the depth update.
+        BookOrder_t bids[DEPTH25_LEN];
+        # The ask orders for the depth update.
+        BookOrder_t asks[DEPTH25_LEN];
+        # The count of bid orders per level for the depth update.
+        uint32_t bid_counts[DEPTH25_LEN];
+        # The count of ask orders per level for the depth update.
+        uint32_t ask_counts[DEPTH25_LEN];
+        # A combination of packet end with matching engine status.
+        uint8_t flags;
+        # The message sequence number assigned at the venue.
+        uint64_t sequence;
+        # The UNIX timestamp (nanoseconds) when the data event occurred.
+        uint64_t ts_event;
+        # The UNIX timestamp (nanoseconds) when the data object was initialized.
+        uint64_t ts_init;
+
     # Represents a single quote tick in a financial market.
     cdef struct QuoteTick_t:
         # The quotes instrument ID.
@@ -517,6 +604,13 @@ cdef extern from "../includes/model.h":
         #  The UNIX timestamp (nanoseconds) when the data object was initialized.
         uint64_t ts_init;
 
+    # Provides a C compatible Foreign Function Interface (FFI) for an underlying [`TradeTicks`].
+    #
+    # This struct wraps `TradeTicks` in a way that makes it compatible with C function
+    # calls, enabling interaction with `TradeTicks` in a C environment.
+    cdef struct TradeTicks_API:
+        TradeTicks_t *_0;
+
     # Represents a bar aggregation specification including a step, aggregation
     # method/rule and price type.
     cdef struct BarSpecification_t:
@@ -556,22 +650,65 @@ cdef extern from "../includes/model.h":
         # The UNIX timestamp (nanoseconds) when the data object was initialized.
         uint64_t ts_init;
 
+    # Represents a mark price update for a derivatives instrument, as published by the venue.
+    cdef struct MarkPriceUpdate_t:
+        # The instrument ID for the mark price.
+        InstrumentId_t instrument_id;
+        # The mark price value.
+        Price_t value;
+        # The UNIX timestamp (nanoseconds) when the price event occurred.
+        uint64_t ts_event;
+        # The UNIX timestamp (nanoseconds) when the data object was initialized.
+        uint64_t ts_init;
+
+    # Represents an index price update for a derivatives instrument, as published by the venue.
+    cdef struct IndexPriceUpdate_t:
+        # The instrument ID for the index price.
+        InstrumentId_t instrument_id;
+        # The index price value.
+        Price_t value;
+        # The UNIX timestamp (nanoseconds) when the price event occurred.
+        uint64_t ts_event;
+        # The UNIX timestamp (nanoseconds) when the data object was initialized.
+        uint64_t ts_init;
+
+    # Represents an open interest update for a futures or perpetual swap instrument.
+    cdef struct OpenInterestUpdate_t:
+        # The instrument ID for the open interest.
+        InstrumentId_t instrument_id;
+        # The total number of outstanding contracts.
+        Quantity_t value;
+        # The UNIX timestamp (nanoseconds) when the open interest event occurred.
+        uint64_t ts_event;
+        # The UNIX timestamp (nanoseconds) when the data object was initialized.
+        uint64_t ts_init;
+
     cpdef enum Data_t_Tag:
         DELTA,
         DELTAS,
         DEPTH10,
+        DEPTH25,
         QUOTE,
         TRADE,
+        TRADES,
         BAR,
+        MARK_PRICE,
+        INDEX_PRICE,
+        OPEN_INTEREST,
 
     cdef struct Data_t:
         Data_t_Tag tag;
         OrderBookDelta_t delta;
         OrderBookDeltas_API deltas;
         OrderBookDepth10_t depth10;
+        OrderBookDepth25_t *depth25;
         QuoteTick_t quote;
         TradeTick_t trade;
+        TradeTicks_API trades;
         Bar_t bar;
+        MarkPriceUpdate_t mark_price;
+        IndexPriceUpdate_t index_price;
+        OpenInterestUpdate_t open_interest;
 
     # Represents a valid trader ID.
     #
@@ -750,14 +887,17 @@ cdef extern from "../includes/model.h":
         char* name;
         CurrencyType currency_type;
 
+    # The raw fixed-point representation of a [`Money`] amount.
+    ctypedef int64_t MoneyRaw;
+
     cdef struct Money_t:
-        int64_t raw;
+        MoneyRaw raw;
         Currency_t currency;
 
     const BookOrder_t NULL_ORDER # = <BookOrder_t>{ OrderSide_NoOrderSide, <Price_t>{ 0, 0 }, <Quantity_t>{ 0, 0 }, 0 }
 
     # Sentinel Price for errors.
-    const Price_t ERROR_PRICE # = <Price_t>{ INT64_MAX, 0 }
+    const Price_t ERROR_PRICE # = <Price_t>{ PriceRaw_MAX, 0 }
 
     Data_t data_clone(const Data_t *data);
 
@@ -825,12 +965,12 @@ cdef extern from "../includes/model.h":
                   uint64_t ts_init);
 
     Bar_t bar_new_from_raw(BarType_t bar_type,
-                           int64_t open,
-                           int64_t high,
-                           int64_t low,
-                           int64_t close,
+                           PriceRaw open,
+                           PriceRaw high,
+                           PriceRaw low,
+                           PriceRaw close,
                            uint8_t price_prec,
-                           uint64_t volume,
+                           QuantityRaw volume,
                            uint8_t size_prec,
                            uint64_t ts_event,
                            uint64_t ts_init);
@@ -910,9 +1050,9 @@ cdef extern from "../includes/model.h":
     const uint32_t *orderbook_depth10_ask_counts_array(const OrderBookDepth10_t *depth);
 
     BookOrder_t book_order_from_raw(OrderSide order_side,
-                                    int64_t price_raw,
+                                    PriceRaw price_raw,
                                     uint8_t price_prec,
-                                    uint64_t size_raw,
+                                    QuantityRaw size_raw,
                                     uint8_t size_prec,
                                     uint64_t order_id);
 
@@ -931,12 +1071,12 @@ cdef extern from "../includes/model.h":
     const char *book_order_debug_to_cstr(const BookOrder_t *order);
 
     QuoteTick_t quote_tick_new(InstrumentId_t instrument_id,
-                               int64_t bid_price_raw,
-                               int64_t ask_price_raw,
+                               PriceRaw bid_price_raw,
+                               PriceRaw ask_price_raw,
                                uint8_t bid_price_prec,
                                uint8_t ask_price_prec,
-                               uint64_t bid_size_raw,
-                               uint64_t ask_size_raw,
+                               QuantityRaw bid_size_raw,
+                               QuantityRaw ask_size_raw,
                                uint8_t bid_size_prec,
                                uint8_t ask_size_prec,
                                uint64_t ts_event,
@@ -950,9 +1090,9 @@ cdef extern from "../includes/model.h":
     const char *quote_tick_to_cstr(const QuoteTick_t *tick);
 
     TradeTick_t trade_tick_new(InstrumentId_t instrument_id,
-                               int64_t price_raw,
+                               PriceRaw price_raw,
                                uint8_t price_prec,
-                               uint64_t size_raw,
+                               QuantityRaw size_raw,
                                uint8_t size_prec,
                                AggressorSide aggressor_side,
                                TradeId_t trade_id,
@@ -966,6 +1106,35 @@ cdef extern from "../includes/model.h":
     # Returns a [`TradeTick`] as a C string pointer.
     const char *trade_tick_to_cstr(const TradeTick_t *tick);
 
+    # Creates a new `TradeTicks` object from a `CVec` of `TradeTick`.
+    #
+    # # Safety
+    # - The `trades` must be a valid pointer to a `CVec` containing `TradeTick` objects
+    # - This function clones the data pointed to by `trades` into Rust-managed memory, then forgets the original `Vec` to prevent Rust from auto-deallocating it
+    # - The caller is responsible for managing the memory of `trades` (including its deallocation) to avoid memory leaks
+    TradeTicks_API trade_ticks_new(InstrumentId_t instrument_id,
+                                   const CVec *trades,
+                                   uint8_t flags,
+                                   uint64_t sequence);
+
+    void trade_ticks_drop(TradeTicks_API trades);
+
+    TradeTicks_API trade_ticks_clone(const TradeTicks_API *trades);
+
+    InstrumentId_t trade_ticks_instrument_id(const TradeTicks_API *trades);
+
+    CVec trade_ticks_vec_trades(const TradeTicks_API *trades);
+
+    uint8_t trade_ticks_flags(const TradeTicks_API *trades);
+
+    uint64_t trade_ticks_sequence(const TradeTicks_API *trades);
+
+    uint64_t trade_ticks_ts_event(const TradeTicks_API *trades);
+
+    uint64_t trade_ticks_ts_init(const TradeTicks_API *trades);
+
+    void trade_ticks_vec_drop(CVec v);
+
     const char *account_type_to_cstr(AccountType value);
 
     # Returns an enum from a Python string.
@@ -1092,6 +1261,15 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     HaltReason halt_reason_from_cstr(const char *ptr);
 
+    const char *auction_phase_to_cstr(AuctionPhase value);
+
+    # Returns an enum from a Python string.
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    AuctionPhase auction_phase_from_cstr(const char *ptr);
+
     const char *oms_type_to_cstr(OmsType value);
 
     # Returns an enum from a Python string.
@@ -1137,6 +1315,15 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     OrderType order_type_from_cstr(const char *ptr);
 
+    const char *peg_price_type_to_cstr(PegPriceType value);
+
+    # Returns an enum from a Python string.
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    PegPriceType peg_price_type_from_cstr(const char *ptr);
+
     const char *position_side_to_cstr(PositionSide value);
 
     # Returns an enum from a Python string.
@@ -1155,6 +1342,15 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     PriceType price_type_from_cstr(const char *ptr);
 
+    const char *rounding_mode_to_cstr(RoundingMode value);
+
+    # Returns an enum from a Python string.
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    RoundingMode rounding_mode_from_cstr(const char *ptr);
+
     const char *time_in_force_to_cstr(TimeInForce value);
 
     # Returns an enum from a Python string.
@@ -1590,7 +1786,7 @@ cdef extern from "../includes/model.h":
 
     Money_t money_new(double amount, Currency_t currency);
 
-    Money_t money_from_raw(int64_t raw, Currency_t currency);
+    Money_t money_from_raw(MoneyRaw raw, Currency_t currency);
 
     double money_as_f64(const Money_t *money);
 
@@ -1600,7 +1796,7 @@ cdef extern from "../includes/model.h":
 
     Price_t price_new(double value, uint8_t precision);
 
-    Price_t price_from_raw(int64_t raw, uint8_t precision);
+    Price_t price_from_raw(PriceRaw raw, uint8_t precision);
 
     double price_as_f64(const Price_t *price);
 
@@ -1610,7 +1806,7 @@ cdef extern from "../includes/model.h":
 
     Quantity_t quantity_new(double value, uint8_t precision);
 
-    Quantity_t quantity_from_raw(uint64_t raw, uint8_t precision);
+    Quantity_t quantity_from_raw(QuantityRaw raw, uint8_t precision);
 
     double quantity_as_f64(const Quantity_t *qty);
 
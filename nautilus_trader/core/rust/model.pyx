@@ -19,6 +19,7 @@ from nautilus_trader.core.rust.model cimport AccountType  # type: ignore
 from nautilus_trader.core.rust.model cimport AggregationSource  # type: ignore
 from nautilus_trader.core.rust.model cimport AggressorSide  # type: ignore
 from nautilus_trader.core.rust.model cimport AssetClass  # type: ignore
+from nautilus_trader.core.rust.model cimport AuctionPhase  # type: ignore
 from nautilus_trader.core.rust.model cimport BookAction  # type: ignore
 from nautilus_trader.core.rust.model cimport BookType  # type: ignore
 from nautilus_trader.core.rust.model cimport ContingencyType  # type: ignore
@@ -34,8 +35,10 @@ from nautilus_trader.core.rust.model cimport OptionKind  # type: ignore
 from nautilus_trader.core.rust.model cimport OrderSide  # type: ignore
 from nautilus_trader.core.rust.model cimport OrderStatus  # type: ignore
 from nautilus_trader.core.rust.model cimport OrderType  # type: ignore
+from nautilus_trader.core.rust.model cimport PegPriceType  # type: ignore
 from nautilus_trader.core.rust.model cimport PositionSide  # type: ignore
 from nautilus_trader.core.rust.model cimport PriceType  # type: ignore
+from nautilus_trader.core.rust.model cimport RoundingMode  # type: ignore
 from nautilus_trader.core.rust.model cimport TimeInForce  # type: ignore
 from nautilus_trader.core.rust.model cimport TradingState  # type: ignore
 from nautilus_trader.core.rust.model cimport TrailingOffsetType  # type: ignore
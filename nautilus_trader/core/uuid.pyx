@@ -13,11 +13,13 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.rust.core cimport UUID4_t
 from nautilus_trader.core.rust.core cimport uuid4_eq
 from nautilus_trader.core.rust.core cimport uuid4_from_cstr
 from nautilus_trader.core.rust.core cimport uuid4_hash
 from nautilus_trader.core.rust.core cimport uuid4_new
+from nautilus_trader.core.rust.core cimport uuid4_set_version
 from nautilus_trader.core.rust.core cimport uuid4_to_cstr
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
@@ -31,7 +33,8 @@ cdef class UUID4:
     Parameters
     ----------
     value : str, optional
-        The UUID value. If ``None`` then a value will be generated.
+        The UUID value. If ``None`` then a value will be generated using the
+        globally configured UUID version (see `set_uuid_version`).
 
     Warnings
     --------
@@ -78,3 +81,26 @@ cdef class UUID4:
         cdef UUID4 uuid4 = UUID4.__new__(UUID4)
         uuid4._mem = mem
         return uuid4
+
+
+cpdef void set_uuid_version(int version):
+    """
+    Set the UUID version used when generating new UUID values.
+
+    Version 7 values embed a millisecond timestamp, so they sort
+    chronologically (useful for database and log locality).
+
+    Parameters
+    ----------
+    version : int, {4, 7}
+        The UUID version (4 random, or 7 time-ordered).
+
+    Raises
+    ------
+    KeyError
+        If `version` is not 4 or 7.
+
+    """
+    Condition.is_in(version, (4, 7), "version", "supported_versions")
+
+    uuid4_set_version(version)
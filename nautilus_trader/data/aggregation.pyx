@@ -27,6 +27,8 @@ from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.datetime cimport dt_to_unix_nanos
 from nautilus_trader.core.rust.core cimport millis_to_nanos
 from nautilus_trader.core.rust.core cimport secs_to_nanos
+from nautilus_trader.core.rust.model cimport FIXED_SCALAR
+from nautilus_trader.core.rust.model cimport QuantityRaw
 from nautilus_trader.model.data cimport Bar
 from nautilus_trader.model.data cimport BarAggregation
 from nautilus_trader.model.data cimport BarType
@@ -404,9 +406,9 @@ cdef class VolumeBarAggregator(BarAggregator):
         )
 
     cdef void _apply_update(self, Price price, Quantity size, uint64_t ts_event):
-        cdef uint64_t raw_size_update = size._mem.raw
-        cdef uint64_t raw_step = int(self.bar_type.spec.step * 1e9)
-        cdef uint64_t raw_size_diff = 0
+        cdef QuantityRaw raw_size_update = size._mem.raw
+        cdef QuantityRaw raw_step = int(self.bar_type.spec.step * FIXED_SCALAR)
+        cdef QuantityRaw raw_size_diff = 0
 
         while raw_size_update > 0:  # While there is size to apply
             if self._builder.volume._mem.raw + raw_size_update < raw_step:
@@ -64,6 +64,9 @@ cdef class MarketDataClient(DataClient):
     cdef set _subscriptions_order_book_snapshot
     cdef set _subscriptions_quote_tick
     cdef set _subscriptions_trade_tick
+    cdef set _subscriptions_funding_rate
+    cdef set _subscriptions_liquidation
+    cdef set _subscriptions_open_interest
     cdef set _subscriptions_bar
     cdef set _subscriptions_venue_status
     cdef set _subscriptions_instrument_status
@@ -79,6 +82,9 @@ cdef class MarketDataClient(DataClient):
     cpdef list subscribed_order_book_snapshots(self)
     cpdef list subscribed_quote_ticks(self)
     cpdef list subscribed_trade_ticks(self)
+    cpdef list subscribed_funding_rates(self)
+    cpdef list subscribed_liquidations(self)
+    cpdef list subscribed_open_interest(self)
     cpdef list subscribed_bars(self)
     cpdef list subscribed_venue_status(self)
     cpdef list subscribed_instrument_status(self)
@@ -90,6 +96,9 @@ cdef class MarketDataClient(DataClient):
     cpdef void subscribe_order_book_snapshots(self, InstrumentId instrument_id, BookType book_type, int depth=*, dict kwargs=*)
     cpdef void subscribe_quote_ticks(self, InstrumentId instrument_id)
     cpdef void subscribe_trade_ticks(self, InstrumentId instrument_id)
+    cpdef void subscribe_funding_rates(self, InstrumentId instrument_id)
+    cpdef void subscribe_liquidations(self, InstrumentId instrument_id)
+    cpdef void subscribe_open_interest(self, InstrumentId instrument_id)
     cpdef void subscribe_bars(self, BarType bar_type)
     cpdef void subscribe_venue_status(self, Venue venue)
     cpdef void subscribe_instrument_status(self, InstrumentId instrument_id)
@@ -100,6 +109,9 @@ cdef class MarketDataClient(DataClient):
     cpdef void unsubscribe_order_book_snapshots(self, InstrumentId instrument_id)
     cpdef void unsubscribe_quote_ticks(self, InstrumentId instrument_id)
     cpdef void unsubscribe_trade_ticks(self, InstrumentId instrument_id)
+    cpdef void unsubscribe_funding_rates(self, InstrumentId instrument_id)
+    cpdef void unsubscribe_liquidations(self, InstrumentId instrument_id)
+    cpdef void unsubscribe_open_interest(self, InstrumentId instrument_id)
     cpdef void unsubscribe_bars(self, BarType bar_type)
     cpdef void unsubscribe_instrument_status(self, InstrumentId instrument_id)
     cpdef void unsubscribe_venue_status(self, Venue venue)
@@ -110,6 +122,9 @@ cdef class MarketDataClient(DataClient):
     cpdef void _add_subscription_order_book_snapshots(self, InstrumentId instrument_id)
     cpdef void _add_subscription_quote_ticks(self, InstrumentId instrument_id)
     cpdef void _add_subscription_trade_ticks(self, InstrumentId instrument_id)
+    cpdef void _add_subscription_funding_rates(self, InstrumentId instrument_id)
+    cpdef void _add_subscription_liquidations(self, InstrumentId instrument_id)
+    cpdef void _add_subscription_open_interest(self, InstrumentId instrument_id)
     cpdef void _add_subscription_bars(self, BarType bar_type)
     cpdef void _add_subscription_venue_status(self, Venue venue)
     cpdef void _add_subscription_instrument_status(self, InstrumentId instrument_id)
@@ -119,6 +134,9 @@ cdef class MarketDataClient(DataClient):
     cpdef void _remove_subscription_order_book_snapshots(self, InstrumentId instrument_id)
     cpdef void _remove_subscription_quote_ticks(self, InstrumentId instrument_id)
     cpdef void _remove_subscription_trade_ticks(self, InstrumentId instrument_id)
+    cpdef void _remove_subscription_funding_rates(self, InstrumentId instrument_id)
+    cpdef void _remove_subscription_liquidations(self, InstrumentId instrument_id)
+    cpdef void _remove_subscription_open_interest(self, InstrumentId instrument_id)
     cpdef void _remove_subscription_bars(self, BarType bar_type)
     cpdef void _remove_subscription_venue_status(self, Venue venue)
     cpdef void _remove_subscription_instrument_status(self, InstrumentId instrument_id)
@@ -164,11 +182,24 @@ cdef class MarketDataClient(DataClient):
         datetime start=*,
         datetime end=*,
     )
+    cpdef void request_open_interest(
+        self,
+        InstrumentId instrument_id,
+        int limit,
+        UUID4 correlation_id,
+        datetime start=*,
+        datetime end=*,
+    )
 
 # -- DATA HANDLERS --------------------------------------------------------------------------------
 
+    cpdef void _handle_funding_rate(self, funding_rate)
+    cpdef void _handle_greeks(self, greeks)
+    cpdef void _handle_liquidation(self, liquidation)
+    cpdef void _handle_open_interest(self, open_interest)
     cpdef void _handle_instrument(self, Instrument instrument, UUID4 correlation_id)
     cpdef void _handle_instruments(self, Venue venue, list instruments, UUID4 correlation_id)
     cpdef void _handle_quote_ticks(self, InstrumentId instrument_id, list ticks, UUID4 correlation_id)
     cpdef void _handle_trade_ticks(self, InstrumentId instrument_id, list ticks, UUID4 correlation_id)
     cpdef void _handle_bars(self, BarType bar_type, list bars, Bar partial, UUID4 correlation_id)
+    cpdef void _handle_open_interest_updates(self, InstrumentId instrument_id, list updates, UUID4 correlation_id)
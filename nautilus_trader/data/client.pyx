@@ -14,6 +14,7 @@
 # -------------------------------------------------------------------------------------------------
 
 from nautilus_trader.common.config import NautilusConfig
+from nautilus_trader.core import nautilus_pyo3
 
 from cpython.datetime cimport datetime
 
@@ -243,6 +244,9 @@ cdef class MarketDataClient(DataClient):
         self._subscriptions_order_book_snapshot = set()  # type: set[InstrumentId]
         self._subscriptions_quote_tick = set()           # type: set[InstrumentId]
         self._subscriptions_trade_tick = set()           # type: set[InstrumentId]
+        self._subscriptions_funding_rate = set()         # type: set[InstrumentId]
+        self._subscriptions_liquidation = set()          # type: set[InstrumentId]
+        self._subscriptions_open_interest = set()        # type: set[InstrumentId]
         self._subscriptions_bar = set()                  # type: set[BarType]
         self._subscriptions_venue_status = set()         # type: set[Venue]
         self._subscriptions_instrument_status = set()    # type: set[InstrumentId]
@@ -320,6 +324,39 @@ cdef class MarketDataClient(DataClient):
         """
         return sorted(list(self._subscriptions_trade_tick))
 
+    cpdef list subscribed_funding_rates(self):
+        """
+        Return the funding rate instruments subscribed to.
+
+        Returns
+        -------
+        list[InstrumentId]
+
+        """
+        return sorted(list(self._subscriptions_funding_rate))
+
+    cpdef list subscribed_liquidations(self):
+        """
+        Return the liquidation instruments subscribed to.
+
+        Returns
+        -------
+        list[InstrumentId]
+
+        """
+        return sorted(list(self._subscriptions_liquidation))
+
+    cpdef list subscribed_open_interest(self):
+        """
+        Return the open interest instruments subscribed to.
+
+        Returns
+        -------
+        list[InstrumentId]
+
+        """
+        return sorted(list(self._subscriptions_open_interest))
+
     cpdef list subscribed_bars(self):
         """
         Return the bar types subscribed to.
@@ -478,6 +515,54 @@ cdef class MarketDataClient(DataClient):
         )
         raise NotImplementedError("method `subscribe_trade_ticks` must be implemented in the subclass")
 
+    cpdef void subscribe_funding_rates(self, InstrumentId instrument_id):
+        """
+        Subscribe to `FundingRateUpdate` data for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The perpetual instrument to subscribe to.
+
+        """
+        self._log.error(  # pragma: no cover
+            f"Cannot subscribe to `FundingRateUpdate` data for {instrument_id}: not implemented. "  # pragma: no cover
+            f"You can implement by overriding the `subscribe_funding_rates` method for this client.",  # pragma: no cover
+        )
+        raise NotImplementedError("method `subscribe_funding_rates` must be implemented in the subclass")
+
+    cpdef void subscribe_liquidations(self, InstrumentId instrument_id):
+        """
+        Subscribe to `LiquidationTick` data for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The derivatives instrument to subscribe to.
+
+        """
+        self._log.error(  # pragma: no cover
+            f"Cannot subscribe to `LiquidationTick` data for {instrument_id}: not implemented. "  # pragma: no cover
+            f"You can implement by overriding the `subscribe_liquidations` method for this client.",  # pragma: no cover
+        )
+        raise NotImplementedError("method `subscribe_liquidations` must be implemented in the subclass")
+
+    cpdef void subscribe_open_interest(self, InstrumentId instrument_id):
+        """
+        Subscribe to `OpenInterestUpdate` data for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The futures instrument to subscribe to.
+
+        """
+        self._log.error(  # pragma: no cover
+            f"Cannot subscribe to `OpenInterestUpdate` data for {instrument_id}: not implemented. "  # pragma: no cover
+            f"You can implement by overriding the `subscribe_open_interest` method for this client.",  # pragma: no cover
+        )
+        raise NotImplementedError("method `subscribe_open_interest` must be implemented in the subclass")
+
     cpdef void subscribe_venue_status(self, Venue venue):
         """
         Subscribe to `InstrumentStatus` data for the venue.
@@ -648,6 +733,54 @@ cdef class MarketDataClient(DataClient):
         )
         raise NotImplementedError("method `unsubscribe_trade_ticks` must be implemented in the subclass")
 
+    cpdef void unsubscribe_funding_rates(self, InstrumentId instrument_id):
+        """
+        Unsubscribe from `FundingRateUpdate` data for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The perpetual instrument to unsubscribe from.
+
+        """
+        self._log.error(  # pragma: no cover
+            f"Cannot unsubscribe from `FundingRateUpdate` data for {instrument_id}: not implemented. "  # pragma: no cover
+            f"You can implement by overriding the `unsubscribe_funding_rates` method for this client.",  # pragma: no cover
+        )
+        raise NotImplementedError("method `unsubscribe_funding_rates` must be implemented in the subclass")
+
+    cpdef void unsubscribe_liquidations(self, InstrumentId instrument_id):
+        """
+        Unsubscribe from `LiquidationTick` data for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The derivatives instrument to unsubscribe from.
+
+        """
+        self._log.error(  # pragma: no cover
+            f"Cannot unsubscribe from `LiquidationTick` data for {instrument_id}: not implemented. "  # pragma: no cover
+            f"You can implement by overriding the `unsubscribe_liquidations` method for this client.",  # pragma: no cover
+        )
+        raise NotImplementedError("method `unsubscribe_liquidations` must be implemented in the subclass")
+
+    cpdef void unsubscribe_open_interest(self, InstrumentId instrument_id):
+        """
+        Unsubscribe from `OpenInterestUpdate` data for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The futures instrument to unsubscribe from.
+
+        """
+        self._log.error(  # pragma: no cover
+            f"Cannot unsubscribe from `OpenInterestUpdate` data for {instrument_id}: not implemented. "  # pragma: no cover
+            f"You can implement by overriding the `unsubscribe_open_interest` method for this client.",  # pragma: no cover
+        )
+        raise NotImplementedError("method `unsubscribe_open_interest` must be implemented in the subclass")
+
     cpdef void unsubscribe_bars(self, BarType bar_type):
         """
         Unsubscribe from `Bar` data for the given bar type.
@@ -742,6 +875,21 @@ cdef class MarketDataClient(DataClient):
 
         self._subscriptions_trade_tick.add(instrument_id)
 
+    cpdef void _add_subscription_funding_rates(self, InstrumentId instrument_id):
+        Condition.not_none(instrument_id, "instrument_id")
+
+        self._subscriptions_funding_rate.add(instrument_id)
+
+    cpdef void _add_subscription_liquidations(self, InstrumentId instrument_id):
+        Condition.not_none(instrument_id, "instrument_id")
+
+        self._subscriptions_liquidation.add(instrument_id)
+
+    cpdef void _add_subscription_open_interest(self, InstrumentId instrument_id):
+        Condition.not_none(instrument_id, "instrument_id")
+
+        self._subscriptions_open_interest.add(instrument_id)
+
     cpdef void _add_subscription_bars(self, BarType bar_type):
         Condition.not_none(bar_type, "bar_type")
 
@@ -792,6 +940,21 @@ cdef class MarketDataClient(DataClient):
 
         self._subscriptions_trade_tick.discard(instrument_id)
 
+    cpdef void _remove_subscription_funding_rates(self, InstrumentId instrument_id):
+        Condition.not_none(instrument_id, "instrument_id")
+
+        self._subscriptions_funding_rate.discard(instrument_id)
+
+    cpdef void _remove_subscription_liquidations(self, InstrumentId instrument_id):
+        Condition.not_none(instrument_id, "instrument_id")
+
+        self._subscriptions_liquidation.discard(instrument_id)
+
+    cpdef void _remove_subscription_open_interest(self, InstrumentId instrument_id):
+        Condition.not_none(instrument_id, "instrument_id")
+
+        self._subscriptions_open_interest.discard(instrument_id)
+
     cpdef void _remove_subscription_bars(self, BarType bar_type):
         Condition.not_none(bar_type, "bar_type")
 
@@ -963,6 +1126,37 @@ cdef class MarketDataClient(DataClient):
             f"You can implement by overriding the `request_bars` method for this client.",  # pragma: no cover  # noqa
         )
 
+    cpdef void request_open_interest(
+        self,
+        InstrumentId instrument_id,
+        int limit,
+        UUID4 correlation_id,
+        datetime start = None,
+        datetime end = None,
+    ):
+        """
+        Request historical `OpenInterestUpdate` data.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the request.
+        limit : int
+            The limit for the number of returned updates.
+        correlation_id : UUID4
+            The correlation ID for the request.
+        start : datetime, optional
+            The start datetime (UTC) of request time range (inclusive).
+        end : datetime, optional
+            The end datetime (UTC) of request time range.
+            The inclusiveness depends on individual data client implementation.
+
+        """
+        self._log.error(  # pragma: no cover
+            f"Cannot request `OpenInterestUpdate` data for {instrument_id}: not implemented. "  # pragma: no cover
+            f"You can implement by overriding the `request_open_interest` method for this client.",  # pragma: no cover  # noqa
+        )
+
 # -- PYTHON WRAPPERS ------------------------------------------------------------------------------
 
     # Convenient Python wrappers for the data handlers. Often Python methods
@@ -986,6 +1180,9 @@ cdef class MarketDataClient(DataClient):
     def _handle_bars_py(self, BarType bar_type, list bars, Bar partial, UUID4 correlation_id):
         self._handle_bars(bar_type, bars, partial, correlation_id)
 
+    def _handle_open_interest_updates_py(self, InstrumentId instrument_id, list updates, UUID4 correlation_id):
+        self._handle_open_interest_updates(instrument_id, updates, correlation_id)
+
     def _handle_data_response_py(self, DataType data_type, data, UUID4 correlation_id):
         self._handle_data_response(data_type, data, correlation_id)
 
@@ -994,6 +1191,18 @@ cdef class MarketDataClient(DataClient):
     cpdef void _handle_data(self, Data data):
         self._msgbus.send(endpoint="DataEngine.process", msg=data)
 
+    cpdef void _handle_funding_rate(self, funding_rate):
+        self._msgbus.send(endpoint="DataEngine.process_funding_rate", msg=funding_rate)
+
+    cpdef void _handle_greeks(self, greeks):
+        self._msgbus.send(endpoint="DataEngine.process_greeks", msg=greeks)
+
+    cpdef void _handle_liquidation(self, liquidation):
+        self._msgbus.send(endpoint="DataEngine.process_liquidation", msg=liquidation)
+
+    cpdef void _handle_open_interest(self, open_interest):
+        self._msgbus.send(endpoint="DataEngine.process_open_interest", msg=open_interest)
+
     cpdef void _handle_instrument(self, Instrument instrument, UUID4 correlation_id):
         cdef DataResponse response = DataResponse(
             client_id=self.id,
@@ -1059,6 +1268,19 @@ cdef class MarketDataClient(DataClient):
 
         self._msgbus.send(endpoint="DataEngine.response", msg=response)
 
+    cpdef void _handle_open_interest_updates(self, InstrumentId instrument_id, list updates, UUID4 correlation_id):
+        cdef DataResponse response = DataResponse(
+            client_id=self.id,
+            venue=instrument_id.venue,
+            data_type=DataType(nautilus_pyo3.OpenInterestUpdate, metadata={"instrument_id": instrument_id}),
+            data=updates,
+            correlation_id=correlation_id,
+            response_id=UUID4(),
+            ts_init=self._clock.timestamp_ns(),
+        )
+
+        self._msgbus.send(endpoint="DataEngine.response", msg=response)
+
     cpdef void _handle_data_response(self, DataType data_type, data, UUID4 correlation_id):
         cdef DataResponse response = DataResponse(
             client_id=self.id,
@@ -96,6 +96,9 @@ cdef class DataEngine(Component):
     cpdef list subscribed_order_book_snapshots(self)
     cpdef list subscribed_quote_ticks(self)
     cpdef list subscribed_trade_ticks(self)
+    cpdef list subscribed_funding_rates(self)
+    cpdef list subscribed_liquidations(self)
+    cpdef list subscribed_open_interest(self)
     cpdef list subscribed_bars(self)
     cpdef list subscribed_instrument_status(self)
     cpdef list subscribed_instrument_close(self)
@@ -106,6 +109,10 @@ cdef class DataEngine(Component):
 
     cpdef void execute(self, DataCommand command)
     cpdef void process(self, Data data)
+    cpdef void process_funding_rate(self, funding_rate)
+    cpdef void process_greeks(self, greeks)
+    cpdef void process_liquidation(self, liquidation)
+    cpdef void process_open_interest(self, open_interest)
     cpdef void request(self, DataRequest request)
     cpdef void response(self, DataResponse response)
 
@@ -121,6 +128,9 @@ cdef class DataEngine(Component):
     cpdef void _handle_subscribe_quote_ticks(self, MarketDataClient client, InstrumentId instrument_id)
     cpdef void _handle_subscribe_synthetic_quote_ticks(self, InstrumentId instrument_id)
     cpdef void _handle_subscribe_trade_ticks(self, MarketDataClient client, InstrumentId instrument_id)
+    cpdef void _handle_subscribe_funding_rates(self, MarketDataClient client, InstrumentId instrument_id)
+    cpdef void _handle_subscribe_liquidations(self, MarketDataClient client, InstrumentId instrument_id)
+    cpdef void _handle_subscribe_open_interest(self, MarketDataClient client, InstrumentId instrument_id)
     cpdef void _handle_subscribe_synthetic_trade_ticks(self, InstrumentId instrument_id)
     cpdef void _handle_subscribe_bars(self, MarketDataClient client, BarType bar_type, bint await_partial)
     cpdef void _handle_subscribe_data(self, DataClient client, DataType data_type)
@@ -132,6 +142,9 @@ cdef class DataEngine(Component):
     cpdef void _handle_unsubscribe_order_book_snapshots(self, MarketDataClient client, InstrumentId instrument_id, dict metadata)  # noqa
     cpdef void _handle_unsubscribe_quote_ticks(self, MarketDataClient client, InstrumentId instrument_id)
     cpdef void _handle_unsubscribe_trade_ticks(self, MarketDataClient client, InstrumentId instrument_id)
+    cpdef void _handle_unsubscribe_funding_rates(self, MarketDataClient client, InstrumentId instrument_id)
+    cpdef void _handle_unsubscribe_liquidations(self, MarketDataClient client, InstrumentId instrument_id)
+    cpdef void _handle_unsubscribe_open_interest(self, MarketDataClient client, InstrumentId instrument_id)
     cpdef void _handle_unsubscribe_bars(self, MarketDataClient client, BarType bar_type)
     cpdef void _handle_unsubscribe_data(self, DataClient client, DataType data_type)
     cpdef void _handle_request(self, DataRequest request)
@@ -146,6 +159,10 @@ cdef class DataEngine(Component):
     cpdef void _handle_order_book_depth(self, OrderBookDepth10 depth)
     cpdef void _handle_quote_tick(self, QuoteTick tick)
     cpdef void _handle_trade_tick(self, TradeTick tick)
+    cpdef void _handle_funding_rate(self, funding_rate)
+    cpdef void _handle_greeks(self, greeks)
+    cpdef void _handle_liquidation(self, liquidation)
+    cpdef void _handle_open_interest(self, open_interest)
     cpdef void _handle_bar(self, Bar bar)
     cpdef void _handle_custom_data(self, CustomData data)
     cpdef void _handle_venue_status(self, VenueStatus data)
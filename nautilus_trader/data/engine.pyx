@@ -30,8 +30,10 @@ just need to override the `execute`, `process`, `send` and `receive` methods.
 """
 
 from typing import Callable
+from time import perf_counter_ns
 
 from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.data.config import DataEngineConfig
 from nautilus_trader.persistence.catalog import ParquetDataCatalog
 
@@ -52,10 +54,14 @@ from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.datetime cimport dt_to_unix_nanos
 from nautilus_trader.core.datetime cimport unix_nanos_to_dt
 from nautilus_trader.core.rust.common cimport ComponentState
+from nautilus_trader.core.rust.common cimport metrics_inc_messages_processed
+from nautilus_trader.core.rust.common cimport metrics_is_enabled
+from nautilus_trader.core.rust.common cimport metrics_observe_book_apply_time
 from nautilus_trader.core.rust.core cimport NANOSECONDS_IN_MILLISECOND
 from nautilus_trader.core.rust.core cimport NANOSECONDS_IN_SECOND
 from nautilus_trader.core.rust.core cimport millis_to_nanos
 from nautilus_trader.core.rust.model cimport PriceType
+from nautilus_trader.core.string cimport pystr_to_cstr
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.data.aggregation cimport BarAggregator
 from nautilus_trader.data.aggregation cimport TickBarAggregator
@@ -82,6 +88,7 @@ from nautilus_trader.model.data cimport OrderBookDepth10
 from nautilus_trader.model.data cimport QuoteTick
 from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.data cimport VenueStatus
+from nautilus_trader.model.functions cimport book_type_to_str
 from nautilus_trader.model.identifiers cimport ClientId
 from nautilus_trader.model.identifiers cimport ComponentId
 from nautilus_trader.model.identifiers cimport InstrumentId
@@ -154,6 +161,10 @@ cdef class DataEngine(Component):
         # Register endpoints
         self._msgbus.register(endpoint="DataEngine.execute", handler=self.execute)
         self._msgbus.register(endpoint="DataEngine.process", handler=self.process)
+        self._msgbus.register(endpoint="DataEngine.process_funding_rate", handler=self.process_funding_rate)
+        self._msgbus.register(endpoint="DataEngine.process_greeks", handler=self.process_greeks)
+        self._msgbus.register(endpoint="DataEngine.process_liquidation", handler=self.process_liquidation)
+        self._msgbus.register(endpoint="DataEngine.process_open_interest", handler=self.process_open_interest)
         self._msgbus.register(endpoint="DataEngine.request", handler=self.request)
         self._msgbus.register(endpoint="DataEngine.response", handler=self.response)
 
@@ -425,6 +436,51 @@ cdef class DataEngine(Component):
             subscriptions += client.subscribed_trade_ticks()
         return subscriptions
 
+    cpdef list subscribed_funding_rates(self):
+        """
+        Return the funding rate instruments subscribed to.
+
+        Returns
+        -------
+        list[InstrumentId]
+
+        """
+        cdef list subscriptions = []
+        cdef MarketDataClient client
+        for client in [c for c in self._clients.values() if isinstance(c, MarketDataClient)]:
+            subscriptions += client.subscribed_funding_rates()
+        return subscriptions
+
+    cpdef list subscribed_liquidations(self):
+        """
+        Return the liquidation instruments subscribed to.
+
+        Returns
+        -------
+        list[InstrumentId]
+
+        """
+        cdef list subscriptions = []
+        cdef MarketDataClient client
+        for client in [c for c in self._clients.values() if isinstance(c, MarketDataClient)]:
+            subscriptions += client.subscribed_liquidations()
+        return subscriptions
+
+    cpdef list subscribed_open_interest(self):
+        """
+        Return the open interest instruments subscribed to.
+
+        Returns
+        -------
+        list[InstrumentId]
+
+        """
+        cdef list subscriptions = []
+        cdef MarketDataClient client
+        for client in [c for c in self._clients.values() if isinstance(c, MarketDataClient)]:
+            subscriptions += client.subscribed_open_interest()
+        return subscriptions
+
     cpdef list subscribed_bars(self):
         """
         Return the bar types subscribed to.
@@ -575,6 +631,62 @@ cdef class DataEngine(Component):
 
         self._handle_data(data)
 
+    cpdef void process_funding_rate(self, funding_rate):
+        """
+        Process the given funding rate update.
+
+        Parameters
+        ----------
+        funding_rate : nautilus_pyo3.FundingRateUpdate
+            The funding rate update to process.
+
+        """
+        Condition.not_none(funding_rate, "funding_rate")
+
+        self._handle_funding_rate(funding_rate)
+
+    cpdef void process_greeks(self, greeks):
+        """
+        Process the given option greeks.
+
+        Parameters
+        ----------
+        greeks : nautilus_pyo3.GreeksData
+            The greeks to process.
+
+        """
+        Condition.not_none(greeks, "greeks")
+
+        self._handle_greeks(greeks)
+
+    cpdef void process_liquidation(self, liquidation):
+        """
+        Process the given liquidation tick.
+
+        Parameters
+        ----------
+        liquidation : nautilus_pyo3.LiquidationTick
+            The liquidation tick to process.
+
+        """
+        Condition.not_none(liquidation, "liquidation")
+
+        self._handle_liquidation(liquidation)
+
+    cpdef void process_open_interest(self, open_interest):
+        """
+        Process the given open interest update.
+
+        Parameters
+        ----------
+        open_interest : nautilus_pyo3.OpenInterestUpdate
+            The open interest update to process.
+
+        """
+        Condition.not_none(open_interest, "open_interest")
+
+        self._handle_open_interest(open_interest)
+
     cpdef void request(self, DataRequest request):
         """
         Handle the given request.
@@ -609,6 +721,8 @@ cdef class DataEngine(Component):
         if self.debug:
             self._log.debug(f"{RECV}{CMD} {command}.")
         self.command_count += 1
+        if metrics_is_enabled():
+            metrics_inc_messages_processed(pystr_to_cstr(self.id.to_str()), b"command")
 
         cdef Venue venue = command.venue
         cdef DataClient client = self._clients.get(command.client_id)
@@ -660,6 +774,21 @@ cdef class DataEngine(Component):
                 client,
                 command.data_type.metadata.get("instrument_id"),
             )
+        elif command.data_type.type == nautilus_pyo3.FundingRateUpdate:
+            self._handle_subscribe_funding_rates(
+                client,
+                command.data_type.metadata.get("instrument_id"),
+            )
+        elif command.data_type.type == nautilus_pyo3.LiquidationTick:
+            self._handle_subscribe_liquidations(
+                client,
+                command.data_type.metadata.get("instrument_id"),
+            )
+        elif command.data_type.type == nautilus_pyo3.OpenInterestUpdate:
+            self._handle_subscribe_open_interest(
+                client,
+                command.data_type.metadata.get("instrument_id"),
+            )
         elif command.data_type.type == Bar:
             self._handle_subscribe_bars(
                 client,
@@ -712,6 +841,21 @@ cdef class DataEngine(Component):
                 client,
                 command.data_type.metadata.get("instrument_id"),
             )
+        elif command.data_type.type == nautilus_pyo3.FundingRateUpdate:
+            self._handle_unsubscribe_funding_rates(
+                client,
+                command.data_type.metadata.get("instrument_id"),
+            )
+        elif command.data_type.type == nautilus_pyo3.LiquidationTick:
+            self._handle_unsubscribe_liquidations(
+                client,
+                command.data_type.metadata.get("instrument_id"),
+            )
+        elif command.data_type.type == nautilus_pyo3.OpenInterestUpdate:
+            self._handle_unsubscribe_open_interest(
+                client,
+                command.data_type.metadata.get("instrument_id"),
+            )
         elif command.data_type.type == Bar:
             self._handle_unsubscribe_bars(
                 client,
@@ -961,6 +1105,39 @@ cdef class DataEngine(Component):
 
         self._subscribed_synthetic_trades.append(instrument_id)
 
+    cpdef void _handle_subscribe_funding_rates(
+        self,
+        MarketDataClient client,
+        InstrumentId instrument_id,
+    ):
+        Condition.not_none(client, "client")
+        Condition.not_none(instrument_id, "instrument_id")
+
+        if instrument_id not in client.subscribed_funding_rates():
+            client.subscribe_funding_rates(instrument_id)
+
+    cpdef void _handle_subscribe_liquidations(
+        self,
+        MarketDataClient client,
+        InstrumentId instrument_id,
+    ):
+        Condition.not_none(client, "client")
+        Condition.not_none(instrument_id, "instrument_id")
+
+        if instrument_id not in client.subscribed_liquidations():
+            client.subscribe_liquidations(instrument_id)
+
+    cpdef void _handle_subscribe_open_interest(
+        self,
+        MarketDataClient client,
+        InstrumentId instrument_id,
+    ):
+        Condition.not_none(client, "client")
+        Condition.not_none(instrument_id, "instrument_id")
+
+        if instrument_id not in client.subscribed_open_interest():
+            client.subscribe_open_interest(instrument_id)
+
     cpdef void _handle_subscribe_bars(
         self,
         MarketDataClient client,
@@ -1141,6 +1318,51 @@ cdef class DataEngine(Component):
         ):
             client.unsubscribe_trade_ticks(instrument_id)
 
+    cpdef void _handle_unsubscribe_funding_rates(
+        self,
+        MarketDataClient client,
+        InstrumentId instrument_id,
+    ):
+        Condition.not_none(client, "client")
+        Condition.not_none(instrument_id, "instrument_id")
+
+        if not self._msgbus.has_subscribers(
+            f"data.funding_rates"
+            f".{instrument_id.venue}"
+            f".{instrument_id.symbol}",
+        ):
+            client.unsubscribe_funding_rates(instrument_id)
+
+    cpdef void _handle_unsubscribe_liquidations(
+        self,
+        MarketDataClient client,
+        InstrumentId instrument_id,
+    ):
+        Condition.not_none(client, "client")
+        Condition.not_none(instrument_id, "instrument_id")
+
+        if not self._msgbus.has_subscribers(
+            f"data.liquidations"
+            f".{instrument_id.venue}"
+            f".{instrument_id.symbol}",
+        ):
+            client.unsubscribe_liquidations(instrument_id)
+
+    cpdef void _handle_unsubscribe_open_interest(
+        self,
+        MarketDataClient client,
+        InstrumentId instrument_id,
+    ):
+        Condition.not_none(client, "client")
+        Condition.not_none(instrument_id, "instrument_id")
+
+        if not self._msgbus.has_subscribers(
+            f"data.open_interest"
+            f".{instrument_id.venue}"
+            f".{instrument_id.symbol}",
+        ):
+            client.unsubscribe_open_interest(instrument_id)
+
     cpdef void _handle_unsubscribe_bars(
         self,
         MarketDataClient client,
@@ -1185,6 +1407,8 @@ cdef class DataEngine(Component):
         if self.debug:
             self._log.debug(f"{RECV}{REQ} {request}.", LogColor.MAGENTA)
         self.request_count += 1
+        if metrics_is_enabled():
+            metrics_inc_messages_processed(pystr_to_cstr(self.id.to_str()), b"request")
 
         # Query data catalog
         if self._catalog:
@@ -1249,6 +1473,15 @@ cdef class DataEngine(Component):
                 request.data_type.metadata.get("start"),
                 request.data_type.metadata.get("end"),
             )
+        elif request.data_type.type == nautilus_pyo3.OpenInterestUpdate:
+            Condition.true(isinstance(client, MarketDataClient), "client was not a MarketDataClient")
+            client.request_open_interest(
+                request.data_type.metadata.get("instrument_id"),
+                request.data_type.metadata.get("limit", 0),
+                request.id,
+                request.data_type.metadata.get("start"),
+                request.data_type.metadata.get("end"),
+            )
         else:
             try:
                 client.request(request.data_type, request.id)
@@ -1308,6 +1541,12 @@ cdef class DataEngine(Component):
                 start=ts_start,
                 end=ts_end,
             )
+        elif request.data_type.type == nautilus_pyo3.OpenInterestUpdate:
+            data = self._catalog.open_interest(
+                instrument_ids=[str(request.data_type.metadata.get("instrument_id"))],
+                start=ts_start,
+                end=ts_end,
+            )
         else:
             data = self._catalog.custom_data(
                 cls=request.data_type.type,
@@ -1338,6 +1577,8 @@ cdef class DataEngine(Component):
 
     cpdef void _handle_data(self, Data data):
         self.data_count += 1
+        if metrics_is_enabled():
+            metrics_inc_messages_processed(pystr_to_cstr(self.id.to_str()), b"data")
 
         if isinstance(data, OrderBookDelta):
             self._handle_order_book_delta(data)
@@ -1431,6 +1672,54 @@ cdef class DataEngine(Component):
             msg=tick,
         )
 
+    cpdef void _handle_funding_rate(self, funding_rate):
+        self.data_count += 1
+
+        self._cache.add_funding_rate(funding_rate)
+
+        cdef InstrumentId instrument_id = InstrumentId.from_str_c(funding_rate.instrument_id.value)
+        self._msgbus.publish_c(
+            topic=f"data.funding_rates"
+                  f".{instrument_id.venue}"
+                  f".{instrument_id.symbol}",
+            msg=funding_rate,
+        )
+
+    cpdef void _handle_greeks(self, greeks):
+        self.data_count += 1
+
+        self._cache.add_greeks(greeks)
+
+        cdef InstrumentId instrument_id = InstrumentId.from_str_c(greeks.instrument_id.value)
+        self._msgbus.publish_c(
+            topic=f"data.greeks"
+                  f".{instrument_id.venue}"
+                  f".{instrument_id.symbol}",
+            msg=greeks,
+        )
+
+    cpdef void _handle_liquidation(self, liquidation):
+        self.data_count += 1
+
+        cdef InstrumentId instrument_id = InstrumentId.from_str_c(liquidation.instrument_id.value)
+        self._msgbus.publish_c(
+            topic=f"data.liquidations"
+                  f".{instrument_id.venue}"
+                  f".{instrument_id.symbol}",
+            msg=liquidation,
+        )
+
+    cpdef void _handle_open_interest(self, open_interest):
+        self.data_count += 1
+
+        cdef InstrumentId instrument_id = InstrumentId.from_str_c(open_interest.instrument_id.value)
+        self._msgbus.publish_c(
+            topic=f"data.open_interest"
+                  f".{instrument_id.venue}"
+                  f".{instrument_id.symbol}",
+            msg=open_interest,
+        )
+
     cpdef void _handle_bar(self, Bar bar):
         cdef BarType bar_type = bar.bar_type
 
@@ -1488,6 +1777,8 @@ cdef class DataEngine(Component):
         if self.debug:
             self._log.debug(f"{RECV}{RES} {response}.", LogColor.MAGENTA)
         self.response_count += 1
+        if metrics_is_enabled():
+            metrics_inc_messages_processed(pystr_to_cstr(self.id.to_str()), b"response")
 
         if response.data_type.type == Instrument:
             if isinstance(response.data, list):
@@ -1552,7 +1843,16 @@ cdef class DataEngine(Component):
             # )
             return
 
-        order_book.apply(data)
+        cdef uint64_t ts_start
+        if metrics_is_enabled():
+            ts_start = perf_counter_ns()
+            order_book.apply(data)
+            metrics_observe_book_apply_time(
+                pystr_to_cstr(book_type_to_str(order_book.book_type)),
+                perf_counter_ns() - ts_start,
+            )
+        else:
+            order_book.apply(data)
 
     cpdef void _snapshot_order_book(self, TimeEvent snap_event):
         cdef tuple[str] parts = snap_event.name.partition('|')[2].rpartition('|')
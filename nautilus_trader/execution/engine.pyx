@@ -52,9 +52,13 @@ from nautilus_trader.common.component cimport MessageBus
 from nautilus_trader.common.generators cimport PositionIdGenerator
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.fsm cimport InvalidStateTrigger
+from nautilus_trader.core.rust.common cimport metrics_inc_messages_processed
+from nautilus_trader.core.rust.common cimport metrics_is_enabled
+from nautilus_trader.core.rust.common cimport metrics_observe_order_latency
 from nautilus_trader.core.rust.model cimport ContingencyType
 from nautilus_trader.core.rust.model cimport OmsType
 from nautilus_trader.core.rust.model cimport PositionSide
+from nautilus_trader.core.string cimport pystr_to_cstr
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.execution.algorithm cimport ExecAlgorithm
 from nautilus_trader.execution.client cimport ExecutionClient
@@ -67,6 +71,7 @@ from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.data cimport QuoteTick
 from nautilus_trader.model.data cimport TradeTick
+from nautilus_trader.model.events.order cimport OrderAccepted
 from nautilus_trader.model.events.order cimport OrderDenied
 from nautilus_trader.model.events.order cimport OrderEvent
 from nautilus_trader.model.events.order cimport OrderFilled
@@ -721,6 +726,8 @@ cdef class ExecutionEngine(Component):
         if self.debug:
             self._log.debug(f"{RECV}{CMD} {command}.", LogColor.MAGENTA)
         self.command_count += 1
+        if metrics_is_enabled():
+            metrics_inc_messages_processed(pystr_to_cstr(self.id.to_str()), b"command")
 
         cdef ExecutionClient client = self._clients.get(command.client_id)
         if client is None:
@@ -844,6 +851,8 @@ cdef class ExecutionEngine(Component):
         if self.debug:
             self._log.debug(f"{RECV}{EVT} {event}.", LogColor.MAGENTA)
         self.event_count += 1
+        if metrics_is_enabled():
+            metrics_inc_messages_processed(pystr_to_cstr(self.id.to_str()), b"event")
 
         # Fetch Order from cache
         cdef ClientOrderId client_order_id = event.client_order_id
@@ -889,6 +898,12 @@ cdef class ExecutionEngine(Component):
                 color=LogColor.GREEN,
             )
 
+        if metrics_is_enabled():
+            if isinstance(event, OrderAccepted):
+                metrics_observe_order_latency(b"accepted", event.ts_init - order.ts_init)
+            elif isinstance(event, OrderFilled) and order.filled_qty.is_zero():
+                metrics_observe_order_latency(b"filled", event.ts_init - order.ts_init)
+
         cdef OmsType oms_type
         if isinstance(event, OrderFilled):
             oms_type = self._determine_oms_type(event)
@@ -491,6 +491,20 @@ class LiveMarketDataClient(MarketDataClient):
             actions=lambda: self._add_subscription_trade_ticks(instrument_id),
         )
 
+    def subscribe_liquidations(self, instrument_id: InstrumentId) -> None:
+        self.create_task(
+            self._subscribe_liquidations(instrument_id),
+            log_msg=f"subscribe: liquidations {instrument_id}",
+            actions=lambda: self._add_subscription_liquidations(instrument_id),
+        )
+
+    def subscribe_open_interest(self, instrument_id: InstrumentId) -> None:
+        self.create_task(
+            self._subscribe_open_interest(instrument_id),
+            log_msg=f"subscribe: open_interest {instrument_id}",
+            actions=lambda: self._add_subscription_open_interest(instrument_id),
+        )
+
     def subscribe_bars(self, bar_type: BarType) -> None:
         PyCondition.true(bar_type.is_externally_aggregated(), "aggregation_source is not EXTERNAL")
 
@@ -563,6 +577,20 @@ class LiveMarketDataClient(MarketDataClient):
             actions=lambda: self._remove_subscription_trade_ticks(instrument_id),
         )
 
+    def unsubscribe_liquidations(self, instrument_id: InstrumentId) -> None:
+        self.create_task(
+            self._unsubscribe_liquidations(instrument_id),
+            log_msg=f"unsubscribe: liquidations {instrument_id}",
+            actions=lambda: self._remove_subscription_liquidations(instrument_id),
+        )
+
+    def unsubscribe_open_interest(self, instrument_id: InstrumentId) -> None:
+        self.create_task(
+            self._unsubscribe_open_interest(instrument_id),
+            log_msg=f"unsubscribe: open_interest {instrument_id}",
+            actions=lambda: self._remove_subscription_open_interest(instrument_id),
+        )
+
     def unsubscribe_bars(self, bar_type: BarType) -> None:
         self.create_task(
             self._unsubscribe_bars(bar_type),
@@ -689,6 +717,26 @@ class LiveMarketDataClient(MarketDataClient):
             log_msg=f"request: bars {bar_type}",
         )
 
+    def request_open_interest(
+        self,
+        instrument_id: InstrumentId,
+        limit: int,
+        correlation_id: UUID4,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> None:
+        self._log.debug(f"Request open interest {instrument_id}.")
+        self.create_task(
+            self._request_open_interest(
+                instrument_id=instrument_id,
+                limit=limit,
+                correlation_id=correlation_id,
+                start=start,
+                end=end,
+            ),
+            log_msg=f"request: open interest {instrument_id}",
+        )
+
     ############################################################################
     # Coroutines to implement
     ############################################################################
@@ -749,6 +797,16 @@ class LiveMarketDataClient(MarketDataClient):
             "implement the `_subscribe_trade_ticks` coroutine",  # pragma: no cover
         )
 
+    async def _subscribe_liquidations(self, instrument_id: InstrumentId) -> None:
+        raise NotImplementedError(  # pragma: no cover
+            "implement the `_subscribe_liquidations` coroutine",  # pragma: no cover
+        )
+
+    async def _subscribe_open_interest(self, instrument_id: InstrumentId) -> None:
+        raise NotImplementedError(  # pragma: no cover
+            "implement the `_subscribe_open_interest` coroutine",  # pragma: no cover
+        )
+
     async def _subscribe_bars(self, bar_type: BarType) -> None:
         raise NotImplementedError(  # pragma: no cover
             "implement the `_subscribe_bars` coroutine",  # pragma: no cover
@@ -799,6 +857,16 @@ class LiveMarketDataClient(MarketDataClient):
             "implement the `_unsubscribe_trade_ticks` coroutine",  # pragma: no cover
         )
 
+    async def _unsubscribe_liquidations(self, instrument_id: InstrumentId) -> None:
+        raise NotImplementedError(  # pragma: no cover
+            "implement the `_unsubscribe_liquidations` coroutine",  # pragma: no cover
+        )
+
+    async def _unsubscribe_open_interest(self, instrument_id: InstrumentId) -> None:
+        raise NotImplementedError(  # pragma: no cover
+            "implement the `_unsubscribe_open_interest` coroutine",  # pragma: no cover
+        )
+
     async def _unsubscribe_bars(self, bar_type: BarType) -> None:
         raise NotImplementedError(  # pragma: no cover
             "implement the `_unsubscribe_bars` coroutine",  # pragma: no cover
@@ -876,3 +944,15 @@ class LiveMarketDataClient(MarketDataClient):
         raise NotImplementedError(  # pragma: no cover
             "implement the `_request_bars` coroutine",  # pragma: no cover
         )
+
+    async def _request_open_interest(
+        self,
+        instrument_id: InstrumentId,
+        limit: int,
+        correlation_id: UUID4,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> None:
+        raise NotImplementedError(  # pragma: no cover
+            "implement the `_request_open_interest` coroutine",  # pragma: no cover
+        )
@@ -14,8 +14,8 @@
 # -------------------------------------------------------------------------------------------------
 
 import asyncio
-from asyncio import Queue
-from typing import Final
+from collections.abc import Hashable
+from typing import Any, Final
 
 from nautilus_trader.cache.cache import Cache
 from nautilus_trader.common.component import LiveClock
@@ -27,6 +27,9 @@ from nautilus_trader.data.engine import DataEngine
 from nautilus_trader.data.messages import DataCommand
 from nautilus_trader.data.messages import DataRequest
 from nautilus_trader.data.messages import DataResponse
+from nautilus_trader.live.queue import BoundedQueue
+from nautilus_trader.model.data import OrderBookDepth10
+from nautilus_trader.model.data import QuoteTick
 
 
 class LiveDataEngine(DataEngine):
@@ -50,6 +53,8 @@ class LiveDataEngine(DataEngine):
     ------
     TypeError
         If `config` is not of type `LiveDataEngineConfig`.
+    KeyError
+        If `config.data_queue_policy` is not a valid queue policy.
 
     """
 
@@ -74,10 +79,14 @@ class LiveDataEngine(DataEngine):
         )
 
         self._loop: asyncio.AbstractEventLoop = loop
-        self._cmd_queue: asyncio.Queue = Queue(maxsize=config.qsize)
-        self._req_queue: asyncio.Queue = Queue(maxsize=config.qsize)
-        self._res_queue: asyncio.Queue = Queue(maxsize=config.qsize)
-        self._data_queue: asyncio.Queue = Queue(maxsize=config.qsize)
+        self._cmd_queue: BoundedQueue = BoundedQueue(maxsize=config.qsize)
+        self._req_queue: BoundedQueue = BoundedQueue(maxsize=config.qsize)
+        self._res_queue: BoundedQueue = BoundedQueue(maxsize=config.qsize)
+        self._data_queue: BoundedQueue = BoundedQueue(
+            maxsize=config.qsize,
+            policy=config.data_queue_policy,
+            key=_data_conflation_key,
+        )
 
         # Async tasks
         self._cmd_queue_task: asyncio.Task | None = None
@@ -200,6 +209,28 @@ class LiveDataEngine(DataEngine):
         """
         return self._data_queue.qsize()
 
+    def data_dropped_count(self) -> int:
+        """
+        Return the number of `Data` objects dropped from the full internal queue.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._data_queue.dropped_count
+
+    def data_conflated_count(self) -> int:
+        """
+        Return the number of `Data` objects conflated on the internal queue.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._data_queue.conflated_count
+
     def kill(self) -> None:
         """
         Kill the engine by abruptly canceling the queue tasks and calling stop.
@@ -245,15 +276,7 @@ class LiveDataEngine(DataEngine):
         PyCondition.not_none(command, "command")
         # Do not allow None through (None is a sentinel value which stops the queue)
 
-        try:
-            self._loop.call_soon_threadsafe(self._cmd_queue.put_nowait, command)
-        except asyncio.QueueFull:
-            self._log.warning(
-                f"Blocking on `_cmd_queue.put` as queue full at "
-                f"{self._cmd_queue.qsize()} items.",
-            )
-            # Schedule the `put` operation to be executed once there is space in the queue
-            self._loop.create_task(self._cmd_queue.put(command))
+        self._loop.call_soon_threadsafe(self._enqueue, self._cmd_queue, "_cmd_queue", command)
 
     def request(self, request: DataRequest) -> None:
         """
@@ -276,15 +299,7 @@ class LiveDataEngine(DataEngine):
         PyCondition.not_none(request, "request")
         # Do not allow None through (None is a sentinel value which stops the queue)
 
-        try:
-            self._loop.call_soon_threadsafe(self._req_queue.put_nowait, request)
-        except asyncio.QueueFull:
-            self._log.warning(
-                f"Blocking on `_req_queue.put` as queue full at "
-                f"{self._req_queue.qsize()} items.",
-            )
-            # Schedule the `put` operation to be executed once there is space in the queue
-            self._loop.create_task(self._req_queue.put(request))
+        self._loop.call_soon_threadsafe(self._enqueue, self._req_queue, "_req_queue", request)
 
     def response(self, response: DataResponse) -> None:
         """
@@ -307,22 +322,14 @@ class LiveDataEngine(DataEngine):
         PyCondition.not_none(response, "response")
         # Do not allow None through (None is a sentinel value which stops the queue)
 
-        try:
-            self._loop.call_soon_threadsafe(self._res_queue.put_nowait, response)
-        except asyncio.QueueFull:
-            self._log.warning(
-                f"Blocking on `_res_queue.put` as queue full at "
-                f"{self._res_queue.qsize():_} items.",
-            )
-            # Schedule the `put` operation to be executed once there is space in the queue
-            self._loop.create_task(self._res_queue.put(response))
+        self._loop.call_soon_threadsafe(self._enqueue, self._res_queue, "_res_queue", response)
 
     def process(self, data: Data) -> None:
         """
         Process the given data.
 
-        If the internal queue is already full then will log a warning and block
-        until queue size reduces.
+        If the internal queue is already full then will apply the configured
+        `data_queue_policy` (by default will log a warning and block until queue size reduces).
 
         Parameters
         ----------
@@ -338,17 +345,27 @@ class LiveDataEngine(DataEngine):
         PyCondition.not_none(data, "data")
         # Do not allow None through (None is a sentinel value which stops the queue)
 
-        try:
-            self._loop.call_soon_threadsafe(self._data_queue.put_nowait, data)
-        except asyncio.QueueFull:
+        self._loop.call_soon_threadsafe(self._enqueue, self._data_queue, "_data_queue", data)
+
+    # -- INTERNAL -------------------------------------------------------------------------------------
+
+    def _enqueue(self, queue: BoundedQueue, name: str, item: Any) -> None:
+        dropped_count = queue.dropped_count
+        conflated_count = queue.conflated_count
+
+        if not queue.enqueue(item):
             self._log.warning(
-                f"Blocking on `_data_queue.put` as queue full at "
-                f"{self._data_queue.qsize():_} items.",
+                f"Blocking on `{name}.put` as queue full at {queue.qsize():_} items.",
             )
             # Schedule the `put` operation to be executed once there is space in the queue
-            self._loop.create_task(self._data_queue.put(data))
-
-    # -- INTERNAL -------------------------------------------------------------------------------------
+            self._loop.create_task(queue.put(item))
+        elif dropped_count == 0 and queue.dropped_count > 0:
+            self._log.warning(
+                f"Dropping oldest items from `{name}` as queue full at {queue.qsize():_} items "
+                "(further drops are counted without warning).",
+            )
+        elif conflated_count == 0 and queue.conflated_count > 0:
+            self._log.info(f"Conflating items on `{name}` (queue at {queue.qsize():_} items).")
 
     def _enqueue_sentinels(self) -> None:
         self._loop.call_soon_threadsafe(self._cmd_queue.put_nowait, self._sentinel)
@@ -459,3 +476,10 @@ class LiveDataEngine(DataEngine):
                 self._log.warning(f"{stopped_msg} with {self.data_qsize()} message(s) on queue.")
             else:
                 self._log.debug(stopped_msg + ".")
+
+
+def _data_conflation_key(data: Data) -> Hashable | None:
+    # Only data which fully supersedes the previous value for an instrument may be conflated
+    if isinstance(data, QuoteTick | OrderBookDepth10):
+        return type(data), data.instrument_id
+    return None
@@ -0,0 +1,147 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import asyncio
+from collections import deque
+from collections.abc import Callable
+from collections.abc import Hashable
+from typing import Any, Final
+
+from nautilus_trader.core.correctness import PyCondition
+
+
+QUEUE_POLICIES: Final[tuple[str, ...]] = ("block", "drop_oldest", "conflate")
+
+
+class BoundedQueue(asyncio.Queue):
+    """
+    Provides a bounded asyncio queue with a policy for putting items when full.
+
+    Parameters
+    ----------
+    maxsize : int
+        The maximum number of items on the queue.
+    policy : str, {'block', 'drop_oldest', 'conflate'}, default 'block'
+        The policy for putting items when the queue is full:
+         - 'block': the caller must wait for space on the queue.
+         - 'drop_oldest': the oldest item is dropped to make space.
+         - 'conflate': an item with the same key as an item already on the queue replaces
+           that item in place (regardless of queue size), otherwise the caller must wait.
+    key : Callable[[Any], Hashable | None], optional
+        The function returning the conflation key for an item, or ``None`` if the item
+        should never be conflated. Must be provided for the 'conflate' policy.
+
+    Raises
+    ------
+    KeyError
+        If `policy` is not a valid queue policy.
+    TypeError
+        If `policy` is 'conflate' and `key` is ``None``.
+
+    Warnings
+    --------
+    ``None`` items are treated as sentinels, and are never dropped or conflated.
+
+    """
+
+    def __init__(
+        self,
+        maxsize: int,
+        policy: str = "block",
+        key: Callable[[Any], Hashable | None] | None = None,
+    ) -> None:
+        PyCondition.is_in(policy, QUEUE_POLICIES, "policy", "QUEUE_POLICIES")
+        if policy == "conflate":
+            PyCondition.callable(key, "key")
+
+        self._policy = policy
+        self._key = key
+        super().__init__(maxsize=maxsize)
+
+        # Metrics
+        self.dropped_count = 0
+        self.conflated_count = 0
+
+    @property
+    def policy(self) -> str:
+        """
+        Return the queue policy.
+
+        Returns
+        -------
+        str
+
+        """
+        return self._policy
+
+    def enqueue(self, item: Any) -> bool:
+        """
+        Put the given item on the queue without blocking, applying the queue policy.
+
+        Parameters
+        ----------
+        item : Any
+            The item to put.
+
+        Returns
+        -------
+        bool
+            True if the item was put (or conflated), False if the queue is full and the
+            caller must wait for space.
+
+        """
+        if self._policy == "conflate" and item is not None:
+            key = self._key(item)
+            if key is not None:
+                slot = self._pending.get(key)
+                if slot is not None:
+                    slot[0] = item  # Replace in place to retain the queue position
+                    self.conflated_count += 1
+                    return True
+
+        if not self.full():
+            self.put_nowait(item)
+            return True
+
+        if self._policy == "drop_oldest" and self._queue[0][0] is not None:
+            self.get_nowait()
+            self.dropped_count += 1
+            self.put_nowait(item)
+            return True
+
+        return False
+
+    # -- INTERNAL -------------------------------------------------------------------------------------
+
+    def _init(self, maxsize: int) -> None:
+        # Items are held in mutable slots of [item, key] so they can be conflated in place
+        self._queue: deque[list] = deque()
+        self._pending: dict[Hashable, list] = {}
+
+    def _put(self, item: Any) -> None:
+        key = None
+        if self._policy == "conflate" and item is not None:
+            key = self._key(item)
+        slot = [item, key]
+        if key is not None:
+            self._pending[key] = slot
+        self._queue.append(slot)
+
+    def _get(self) -> Any:
+        slot = self._queue.popleft()
+        item, key = slot
+        if key is not None and self._pending.get(key) is slot:
+            del self._pending[key]
+        return item
@@ -29,15 +29,23 @@ from nautilus_trader.core import nautilus_pyo3
 NAUTILUS_PYO3_DATA_TYPES: tuple[type, ...] = (
     nautilus_pyo3.OrderBookDelta,
     nautilus_pyo3.OrderBookDepth10,
+    nautilus_pyo3.OrderBookDepth25,
     nautilus_pyo3.QuoteTick,
     nautilus_pyo3.TradeTick,
     nautilus_pyo3.Bar,
+    nautilus_pyo3.MarkPriceUpdate,
+    nautilus_pyo3.IndexPriceUpdate,
+    nautilus_pyo3.OpenInterestUpdate,
 )
 
 NautilusRustDataType = Union[  # noqa: UP007 (mypy does not like pipe operators)
     nautilus_pyo3.OrderBookDelta,
     nautilus_pyo3.OrderBookDepth10,
+    nautilus_pyo3.OrderBookDepth25,
     nautilus_pyo3.QuoteTick,
     nautilus_pyo3.TradeTick,
     nautilus_pyo3.Bar,
+    nautilus_pyo3.MarkPriceUpdate,
+    nautilus_pyo3.IndexPriceUpdate,
+    nautilus_pyo3.OpenInterestUpdate,
 ]
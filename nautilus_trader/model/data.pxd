@@ -22,6 +22,7 @@ from libc.stdint cimport uint64_t
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.rust.core cimport CVec
 from nautilus_trader.core.rust.model cimport AggressorSide
+from nautilus_trader.core.rust.model cimport AuctionPhase
 from nautilus_trader.core.rust.model cimport Bar_t
 from nautilus_trader.core.rust.model cimport BarSpecification_t
 from nautilus_trader.core.rust.model cimport BarType_t
@@ -38,6 +39,7 @@ from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.core.rust.model cimport QuoteTick_t
 from nautilus_trader.core.rust.model cimport TradeTick_t
+from nautilus_trader.core.rust.model cimport TradeTicks_API
 from nautilus_trader.model.data cimport BarAggregation
 from nautilus_trader.model.data cimport OrderBookDelta
 from nautilus_trader.model.data cimport OrderBookDeltas
@@ -99,6 +101,8 @@ cpdef enum BarAggregation:
     DAY = 14
     WEEK = 15
     MONTH = 16
+    RENKO = 17
+    RANGE = 18
 
 
 cdef class BarSpecification:
@@ -301,6 +305,12 @@ cdef class InstrumentStatus(Data):
     """The instrument market status.\n\n:returns: `MarketStatus`"""
     cdef readonly HaltReason halt_reason
     """The halt reason.\n\n:returns: `HaltReason`"""
+    cdef readonly AuctionPhase auction_phase
+    """The auction call phase.\n\n:returns: `AuctionPhase`"""
+    cdef readonly Price limit_up
+    """The limit-up price band.\n\n:returns: `Price` or ``None``"""
+    cdef readonly Price limit_down
+    """The limit-down price band.\n\n:returns: `Price` or ``None``"""
     cdef readonly uint64_t ts_event
     """The UNIX timestamp (nanoseconds) when the data event occurred.\n\n:returns: `uint64_t`"""
     cdef readonly uint64_t ts_init
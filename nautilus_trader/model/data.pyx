@@ -31,8 +31,10 @@ from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.rust.core cimport CVec
 from nautilus_trader.core.rust.model cimport DEPTH10_LEN
+from nautilus_trader.core.rust.model cimport DEPTH25_LEN
 from nautilus_trader.core.rust.model cimport AggregationSource
 from nautilus_trader.core.rust.model cimport AggressorSide
+from nautilus_trader.core.rust.model cimport AuctionPhase
 from nautilus_trader.core.rust.model cimport Bar_t
 from nautilus_trader.core.rust.model cimport BarSpecification_t
 from nautilus_trader.core.rust.model cimport BarType_t
@@ -41,8 +43,12 @@ from nautilus_trader.core.rust.model cimport BookOrder_t
 from nautilus_trader.core.rust.model cimport Data_t
 from nautilus_trader.core.rust.model cimport Data_t_Tag
 from nautilus_trader.core.rust.model cimport HaltReason
+from nautilus_trader.core.rust.model cimport IndexPriceUpdate_t
 from nautilus_trader.core.rust.model cimport InstrumentCloseType
+from nautilus_trader.core.rust.model cimport MarkPriceUpdate_t
 from nautilus_trader.core.rust.model cimport MarketStatus
+from nautilus_trader.core.rust.model cimport OpenInterestUpdate_t
+from nautilus_trader.core.rust.model cimport OrderBookDepth25_t
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.core.rust.model cimport bar_eq
@@ -106,6 +112,8 @@ from nautilus_trader.core.rust.model cimport trade_tick_eq
 from nautilus_trader.core.rust.model cimport trade_tick_hash
 from nautilus_trader.core.rust.model cimport trade_tick_new
 from nautilus_trader.core.rust.model cimport trade_tick_to_cstr
+from nautilus_trader.core.rust.model cimport trade_ticks_vec_drop
+from nautilus_trader.core.rust.model cimport trade_ticks_vec_trades
 from nautilus_trader.core.rust.model cimport venue_new
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
@@ -114,6 +122,8 @@ from nautilus_trader.model.data cimport BarAggregation
 from nautilus_trader.model.functions cimport aggregation_source_from_str
 from nautilus_trader.model.functions cimport aggressor_side_from_str
 from nautilus_trader.model.functions cimport aggressor_side_to_str
+from nautilus_trader.model.functions cimport auction_phase_from_str
+from nautilus_trader.model.functions cimport auction_phase_to_str
 from nautilus_trader.model.functions cimport bar_aggregation_from_str
 from nautilus_trader.model.functions cimport bar_aggregation_to_str
 from nautilus_trader.model.functions cimport book_action_from_str
@@ -159,6 +169,31 @@ cdef inline OrderBookDepth10 depth10_from_mem_c(OrderBookDepth10_t mem):
     return depth10
 
 
+cdef inline object book_order_to_pyo3_c(BookOrder_t order):
+    return nautilus_pyo3.BookOrder(
+        nautilus_pyo3.OrderSide(order_side_to_str(order.side)),
+        nautilus_pyo3.Price.from_raw(order.price.raw, order.price.precision),
+        nautilus_pyo3.Quantity.from_raw(order.size.raw, order.size.precision),
+        order.order_id,
+    )
+
+
+cdef inline object depth25_from_mem_c(OrderBookDepth25_t* mem):
+    # There is no Cython `OrderBookDepth25`, so convert to the pyo3 object
+    cdef uint64_t i
+    return nautilus_pyo3.OrderBookDepth25(
+        nautilus_pyo3.InstrumentId.from_str(InstrumentId.from_mem_c(mem.instrument_id).value),
+        [book_order_to_pyo3_c(mem.bids[i]) for i in range(DEPTH25_LEN)],
+        [book_order_to_pyo3_c(mem.asks[i]) for i in range(DEPTH25_LEN)],
+        [mem.bid_counts[i] for i in range(DEPTH25_LEN)],
+        [mem.ask_counts[i] for i in range(DEPTH25_LEN)],
+        mem.flags,
+        mem.sequence,
+        mem.ts_event,
+        mem.ts_init,
+    )
+
+
 cdef inline QuoteTick quote_from_mem_c(QuoteTick_t mem):
     cdef QuoteTick quote = QuoteTick.__new__(QuoteTick)
     quote._mem = mem
@@ -171,12 +206,58 @@ cdef inline TradeTick trade_from_mem_c(TradeTick_t mem):
     return trade
 
 
+cdef inline list trades_from_mem_c(TradeTicks_API mem):
+    cdef CVec raw_trades_vec = trade_ticks_vec_trades(&mem)
+    cdef TradeTick_t* raw_trades = <TradeTick_t*>raw_trades_vec.ptr
+
+    cdef list[TradeTick] trades = []
+
+    cdef:
+        uint64_t i
+    for i in range(raw_trades_vec.len):
+        trades.append(trade_from_mem_c(raw_trades[i]))
+
+    trade_ticks_vec_drop(raw_trades_vec)
+
+    return trades
+
+
 cdef inline Bar bar_from_mem_c(Bar_t mem):
     cdef Bar bar = Bar.__new__(Bar)
     bar._mem = mem
     return bar
 
 
+cdef inline object mark_price_from_mem_c(MarkPriceUpdate_t mem):
+    # There is no Cython `MarkPriceUpdate`, so convert to the pyo3 object
+    return nautilus_pyo3.MarkPriceUpdate(
+        nautilus_pyo3.InstrumentId.from_str(InstrumentId.from_mem_c(mem.instrument_id).value),
+        nautilus_pyo3.Price.from_raw(mem.value.raw, mem.value.precision),
+        mem.ts_event,
+        mem.ts_init,
+    )
+
+
+cdef inline object index_price_from_mem_c(IndexPriceUpdate_t mem):
+    # There is no Cython `IndexPriceUpdate`, so convert to the pyo3 object
+    return nautilus_pyo3.IndexPriceUpdate(
+        nautilus_pyo3.InstrumentId.from_str(InstrumentId.from_mem_c(mem.instrument_id).value),
+        nautilus_pyo3.Price.from_raw(mem.value.raw, mem.value.precision),
+        mem.ts_event,
+        mem.ts_init,
+    )
+
+
+cdef inline object open_interest_from_mem_c(OpenInterestUpdate_t mem):
+    # There is no Cython `OpenInterestUpdate`, so convert to the pyo3 object
+    return nautilus_pyo3.OpenInterestUpdate(
+        nautilus_pyo3.InstrumentId.from_str(InstrumentId.from_mem_c(mem.instrument_id).value),
+        nautilus_pyo3.Quantity.from_raw(mem.value.raw, mem.value.precision),
+        mem.ts_event,
+        mem.ts_init,
+    )
+
+
 # SAFETY: Do NOT deallocate the capsule here
 cpdef list capsule_to_list(capsule):
     cdef CVec* data = <CVec*>PyCapsule_GetPointer(capsule, NULL)
@@ -191,18 +272,28 @@ cpdef list capsule_to_list(capsule):
             objects.append(deltas_from_mem_c(ptr[i].deltas))
         elif ptr[i].tag == Data_t_Tag.DEPTH10:
             objects.append(depth10_from_mem_c(ptr[i].depth10))
+        elif ptr[i].tag == Data_t_Tag.DEPTH25:
+            objects.append(depth25_from_mem_c(ptr[i].depth25))
         elif ptr[i].tag == Data_t_Tag.QUOTE:
             objects.append(quote_from_mem_c(ptr[i].quote))
         elif ptr[i].tag == Data_t_Tag.TRADE:
             objects.append(trade_from_mem_c(ptr[i].trade))
+        elif ptr[i].tag == Data_t_Tag.TRADES:
+            objects.extend(trades_from_mem_c(ptr[i].trades))
         elif ptr[i].tag == Data_t_Tag.BAR:
             objects.append(bar_from_mem_c(ptr[i].bar))
+        elif ptr[i].tag == Data_t_Tag.MARK_PRICE:
+            objects.append(mark_price_from_mem_c(ptr[i].mark_price))
+        elif ptr[i].tag == Data_t_Tag.INDEX_PRICE:
+            objects.append(index_price_from_mem_c(ptr[i].index_price))
+        elif ptr[i].tag == Data_t_Tag.OPEN_INTEREST:
+            objects.append(open_interest_from_mem_c(ptr[i].open_interest))
 
     return objects
 
 
 # SAFETY: Do NOT deallocate the capsule here
-cpdef Data capsule_to_data(capsule):
+cpdef object capsule_to_data(capsule):
     cdef Data_t* ptr = <Data_t*>PyCapsule_GetPointer(capsule, NULL)
 
     if ptr.tag == Data_t_Tag.DELTA:
@@ -211,12 +302,22 @@ cpdef Data capsule_to_data(capsule):
         return deltas_from_mem_c(ptr.deltas)
     elif ptr.tag == Data_t_Tag.DEPTH10:
         return depth10_from_mem_c(ptr.depth10)
+    elif ptr.tag == Data_t_Tag.DEPTH25:
+        return depth25_from_mem_c(ptr.depth25)
     elif ptr.tag == Data_t_Tag.QUOTE:
         return quote_from_mem_c(ptr.quote)
     elif ptr.tag == Data_t_Tag.TRADE:
         return trade_from_mem_c(ptr.trade)
+    elif ptr.tag == Data_t_Tag.TRADES:
+        return trades_from_mem_c(ptr.trades)
     elif ptr.tag == Data_t_Tag.BAR:
         return bar_from_mem_c(ptr.bar)
+    elif ptr.tag == Data_t_Tag.MARK_PRICE:
+        return mark_price_from_mem_c(ptr.mark_price)
+    elif ptr.tag == Data_t_Tag.INDEX_PRICE:
+        return index_price_from_mem_c(ptr.index_price)
+    elif ptr.tag == Data_t_Tag.OPEN_INTEREST:
+        return open_interest_from_mem_c(ptr.open_interest)
     else:
         raise RuntimeError("Invalid data element to convert from `PyCapsule`")
 
@@ -416,6 +517,8 @@ cdef class BarSpecification:
             or aggregation == BarAggregation.VOLUME_IMBALANCE
             or aggregation == BarAggregation.VALUE
             or aggregation == BarAggregation.VALUE_IMBALANCE
+            or aggregation == BarAggregation.RENKO
+            or aggregation == BarAggregation.RANGE
         ):
             return True
         else:
@@ -2983,11 +3086,21 @@ cdef class InstrumentStatus(Data):
         The name of the trading session.
     halt_reason : HaltReason, default ``NOT_HALTED``
         The halt reason (only applicable for ``HALT`` status).
+    auction_phase : AuctionPhase, default ``NO_AUCTION``
+        The auction call phase (only applicable for ``AUCTION`` status).
+    limit_up : Price, optional
+        The limit-up price band, above which trading is halted.
+    limit_down : Price, optional
+        The limit-down price band, below which trading is halted.
 
     Raises
     ------
     ValueError
         If `status` is not equal to ``HALT`` and `halt_reason` is other than ``NOT_HALTED``.
+    ValueError
+        If `status` is not equal to ``AUCTION`` and `auction_phase` is other than ``NO_AUCTION``.
+    ValueError
+        If both `limit_up` and `limit_down` are given and `limit_down` is not less than `limit_up`.
 
     """
 
@@ -2999,14 +3112,24 @@ cdef class InstrumentStatus(Data):
         uint64_t ts_init,
         str trading_session = "Regular",
         HaltReason halt_reason = HaltReason.NOT_HALTED,
+        AuctionPhase auction_phase = AuctionPhase.NO_AUCTION,
+        Price limit_up = None,
+        Price limit_down = None,
     ) -> None:
         if status != MarketStatus.HALT:
             Condition.equal(halt_reason, HaltReason.NOT_HALTED, "halt_reason", "NO_HALT")
+        if status != MarketStatus.AUCTION:
+            Condition.equal(auction_phase, AuctionPhase.NO_AUCTION, "auction_phase", "NO_AUCTION")
+        if limit_up is not None and limit_down is not None:
+            Condition.true(limit_down < limit_up, "`limit_down` was not less than `limit_up`")
 
         self.instrument_id = instrument_id
         self.trading_session = trading_session
         self.status = status
         self.halt_reason = halt_reason
+        self.auction_phase = auction_phase
+        self.limit_up = limit_up
+        self.limit_down = limit_down
         self.ts_event = ts_event
         self.ts_init = ts_init
 
@@ -3023,17 +3146,25 @@ cdef class InstrumentStatus(Data):
             f"trading_session={self.trading_session}, "
             f"status={market_status_to_str(self.status)}, "
             f"halt_reason={halt_reason_to_str(self.halt_reason)}, "
+            f"auction_phase={auction_phase_to_str(self.auction_phase)}, "
+            f"limit_up={self.limit_up}, "
+            f"limit_down={self.limit_down}, "
             f"ts_event={self.ts_event})"
         )
 
     @staticmethod
     cdef InstrumentStatus from_dict_c(dict values):
         Condition.not_none(values, "values")
+        limit_up = values.get("limit_up")
+        limit_down = values.get("limit_down")
         return InstrumentStatus(
             instrument_id=InstrumentId.from_str_c(values["instrument_id"]),
             trading_session=values.get("trading_session", "Regular"),
             status=market_status_from_str(values["status"]),
             halt_reason=halt_reason_from_str(values.get("halt_reason", "NOT_HALTED")),
+            auction_phase=auction_phase_from_str(values.get("auction_phase", "NO_AUCTION")),
+            limit_up=Price.from_str_c(limit_up) if limit_up is not None else None,
+            limit_down=Price.from_str_c(limit_down) if limit_down is not None else None,
             ts_event=values["ts_event"],
             ts_init=values["ts_init"],
         )
@@ -3047,6 +3178,9 @@ cdef class InstrumentStatus(Data):
             "trading_session": obj.trading_session,
             "status": market_status_to_str(obj.status),
             "halt_reason": halt_reason_to_str(obj.halt_reason),
+            "auction_phase": auction_phase_to_str(obj.auction_phase),
+            "limit_up": str(obj.limit_up) if obj.limit_up is not None else None,
+            "limit_down": str(obj.limit_down) if obj.limit_down is not None else None,
             "ts_event": obj.ts_event,
             "ts_init": obj.ts_init,
         }
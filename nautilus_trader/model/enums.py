@@ -17,6 +17,7 @@ from nautilus_trader.core.rust.model import AccountType
 from nautilus_trader.core.rust.model import AggregationSource
 from nautilus_trader.core.rust.model import AggressorSide
 from nautilus_trader.core.rust.model import AssetClass
+from nautilus_trader.core.rust.model import AuctionPhase
 from nautilus_trader.core.rust.model import BookAction
 from nautilus_trader.core.rust.model import BookType
 from nautilus_trader.core.rust.model import ContingencyType
@@ -31,8 +32,10 @@ from nautilus_trader.core.rust.model import OptionKind
 from nautilus_trader.core.rust.model import OrderSide
 from nautilus_trader.core.rust.model import OrderStatus
 from nautilus_trader.core.rust.model import OrderType
+from nautilus_trader.core.rust.model import PegPriceType
 from nautilus_trader.core.rust.model import PositionSide
 from nautilus_trader.core.rust.model import PriceType
+from nautilus_trader.core.rust.model import RoundingMode
 from nautilus_trader.core.rust.model import TimeInForce
 from nautilus_trader.core.rust.model import TradingState
 from nautilus_trader.core.rust.model import TrailingOffsetType
@@ -46,6 +49,8 @@ from nautilus_trader.model.functions import aggressor_side_from_str
 from nautilus_trader.model.functions import aggressor_side_to_str
 from nautilus_trader.model.functions import asset_class_from_str
 from nautilus_trader.model.functions import asset_class_to_str
+from nautilus_trader.model.functions import auction_phase_from_str
+from nautilus_trader.model.functions import auction_phase_to_str
 from nautilus_trader.model.functions import bar_aggregation_from_str
 from nautilus_trader.model.functions import bar_aggregation_to_str
 from nautilus_trader.model.functions import book_action_from_str
@@ -76,10 +81,14 @@ from nautilus_trader.model.functions import order_status_from_str
 from nautilus_trader.model.functions import order_status_to_str
 from nautilus_trader.model.functions import order_type_from_str
 from nautilus_trader.model.functions import order_type_to_str
+from nautilus_trader.model.functions import peg_price_type_from_str
+from nautilus_trader.model.functions import peg_price_type_to_str
 from nautilus_trader.model.functions import position_side_from_str
 from nautilus_trader.model.functions import position_side_to_str
 from nautilus_trader.model.functions import price_type_from_str
 from nautilus_trader.model.functions import price_type_to_str
+from nautilus_trader.model.functions import rounding_mode_from_str
+from nautilus_trader.model.functions import rounding_mode_to_str
 from nautilus_trader.model.functions import time_in_force_from_str
 from nautilus_trader.model.functions import time_in_force_to_str
 from nautilus_trader.model.functions import trading_state_from_str
@@ -95,6 +104,7 @@ __all__ = [
     "AggregationSource",
     "AggressorSide",
     "AssetClass",
+    "AuctionPhase",
     "InstrumentClass",
     "BarAggregation",
     "BookAction",
@@ -110,8 +120,10 @@ __all__ = [
     "OrderSide",
     "OrderStatus",
     "OrderType",
+    "PegPriceType",
     "PositionSide",
     "PriceType",
+    "RoundingMode",
     "TimeInForce",
     "TradingState",
     "TrailingOffsetType",
@@ -138,6 +150,8 @@ __all__ = [
     "currency_type_from_str",
     "halt_reason_to_str",
     "halt_reason_from_str",
+    "auction_phase_to_str",
+    "auction_phase_from_str",
     "instrument_close_type_to_str",
     "instrument_close_type_from_str",
     "liquidity_side_to_str",
@@ -154,10 +168,14 @@ __all__ = [
     "order_status_from_str",
     "order_type_to_str",
     "order_type_from_str",
+    "peg_price_type_to_str",
+    "peg_price_type_from_str",
     "position_side_to_str",
     "position_side_from_str",
     "price_type_to_str",
     "price_type_from_str",
+    "rounding_mode_to_str",
+    "rounding_mode_from_str",
     "time_in_force_to_str",
     "time_in_force_from_str",
     "trading_state_to_str",
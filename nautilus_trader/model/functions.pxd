@@ -17,6 +17,7 @@ from nautilus_trader.core.rust.model cimport AccountType
 from nautilus_trader.core.rust.model cimport AggregationSource
 from nautilus_trader.core.rust.model cimport AggressorSide
 from nautilus_trader.core.rust.model cimport AssetClass
+from nautilus_trader.core.rust.model cimport AuctionPhase
 from nautilus_trader.core.rust.model cimport BookAction
 from nautilus_trader.core.rust.model cimport BookType
 from nautilus_trader.core.rust.model cimport ContingencyType
@@ -31,8 +32,10 @@ from nautilus_trader.core.rust.model cimport OptionKind
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport OrderStatus
 from nautilus_trader.core.rust.model cimport OrderType
+from nautilus_trader.core.rust.model cimport PegPriceType
 from nautilus_trader.core.rust.model cimport PositionSide
 from nautilus_trader.core.rust.model cimport PriceType
+from nautilus_trader.core.rust.model cimport RoundingMode
 from nautilus_trader.core.rust.model cimport TimeInForce
 from nautilus_trader.core.rust.model cimport TradingState
 from nautilus_trader.core.rust.model cimport TrailingOffsetType
@@ -83,6 +86,9 @@ cpdef str market_status_to_str(MarketStatus value)
 cpdef HaltReason halt_reason_from_str(str value)
 cpdef str halt_reason_to_str(HaltReason value)
 
+cpdef AuctionPhase auction_phase_from_str(str value)
+cpdef str auction_phase_to_str(AuctionPhase value)
+
 cpdef OmsType oms_type_from_str(str value)
 cpdef str oms_type_to_str(OmsType value)
 
@@ -98,12 +104,18 @@ cpdef str order_status_to_str(OrderStatus value)
 cpdef OrderType order_type_from_str(str value)
 cpdef str order_type_to_str(OrderType value)
 
+cpdef PegPriceType peg_price_type_from_str(str value)
+cpdef str peg_price_type_to_str(PegPriceType value)
+
 cpdef PositionSide position_side_from_str(str value)
 cpdef str position_side_to_str(PositionSide value)
 
 cpdef PriceType price_type_from_str(str value)
 cpdef str price_type_to_str(PriceType value)
 
+cpdef RoundingMode rounding_mode_from_str(str value)
+cpdef str rounding_mode_to_str(RoundingMode value)
+
 cpdef TimeInForce time_in_force_from_str(str value)
 cpdef str time_in_force_to_str(TimeInForce value)
 
@@ -23,6 +23,8 @@ from nautilus_trader.core.rust.model cimport aggressor_side_from_cstr
 from nautilus_trader.core.rust.model cimport aggressor_side_to_cstr
 from nautilus_trader.core.rust.model cimport asset_class_from_cstr
 from nautilus_trader.core.rust.model cimport asset_class_to_cstr
+from nautilus_trader.core.rust.model cimport auction_phase_from_cstr
+from nautilus_trader.core.rust.model cimport auction_phase_to_cstr
 from nautilus_trader.core.rust.model cimport bar_aggregation_from_cstr
 from nautilus_trader.core.rust.model cimport bar_aggregation_to_cstr
 from nautilus_trader.core.rust.model cimport book_action_from_cstr
@@ -53,10 +55,14 @@ from nautilus_trader.core.rust.model cimport order_status_from_cstr
 from nautilus_trader.core.rust.model cimport order_status_to_cstr
 from nautilus_trader.core.rust.model cimport order_type_from_cstr
 from nautilus_trader.core.rust.model cimport order_type_to_cstr
+from nautilus_trader.core.rust.model cimport peg_price_type_from_cstr
+from nautilus_trader.core.rust.model cimport peg_price_type_to_cstr
 from nautilus_trader.core.rust.model cimport position_side_from_cstr
 from nautilus_trader.core.rust.model cimport position_side_to_cstr
 from nautilus_trader.core.rust.model cimport price_type_from_cstr
 from nautilus_trader.core.rust.model cimport price_type_to_cstr
+from nautilus_trader.core.rust.model cimport rounding_mode_from_cstr
+from nautilus_trader.core.rust.model cimport rounding_mode_to_cstr
 from nautilus_trader.core.rust.model cimport time_in_force_from_cstr
 from nautilus_trader.core.rust.model cimport time_in_force_to_cstr
 from nautilus_trader.core.rust.model cimport trading_state_from_cstr
@@ -181,6 +187,14 @@ cpdef str halt_reason_to_str(HaltReason value):
     return cstr_to_pystr(halt_reason_to_cstr(value))
 
 
+cpdef AuctionPhase auction_phase_from_str(str value):
+    return auction_phase_from_cstr(pystr_to_cstr(value))
+
+
+cpdef str auction_phase_to_str(AuctionPhase value):
+    return cstr_to_pystr(auction_phase_to_cstr(value))
+
+
 cpdef OmsType oms_type_from_str(str value):
     return oms_type_from_cstr(pystr_to_cstr(value))
 
@@ -221,6 +235,14 @@ cpdef str order_type_to_str(OrderType value):
     return cstr_to_pystr(order_type_to_cstr(value))
 
 
+cpdef PegPriceType peg_price_type_from_str(str value):
+    return peg_price_type_from_cstr(pystr_to_cstr(value))
+
+
+cpdef str peg_price_type_to_str(PegPriceType value):
+    return cstr_to_pystr(peg_price_type_to_cstr(value))
+
+
 cpdef PositionSide position_side_from_str(str value):
     return position_side_from_cstr(pystr_to_cstr(value))
 
@@ -237,6 +259,14 @@ cpdef str price_type_to_str(PriceType value):
     return cstr_to_pystr(price_type_to_cstr(value))
 
 
+cpdef RoundingMode rounding_mode_from_str(str value):
+    return rounding_mode_from_cstr(pystr_to_cstr(value))
+
+
+cpdef str rounding_mode_to_str(RoundingMode value):
+    return cstr_to_pystr(rounding_mode_to_cstr(value))
+
+
 cpdef TimeInForce time_in_force_from_str(str value):
     return time_in_force_from_cstr(pystr_to_cstr(value))
 
@@ -13,15 +13,16 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
-from libc.stdint cimport int64_t
 from libc.stdint cimport uint8_t
-from libc.stdint cimport uint64_t
 
 from nautilus_trader.core.rust.model cimport Currency_t
 from nautilus_trader.core.rust.model cimport CurrencyType
 from nautilus_trader.core.rust.model cimport Money_t
+from nautilus_trader.core.rust.model cimport MoneyRaw
 from nautilus_trader.core.rust.model cimport Price_t
+from nautilus_trader.core.rust.model cimport PriceRaw
 from nautilus_trader.core.rust.model cimport Quantity_t
+from nautilus_trader.core.rust.model cimport QuantityRaw
 from nautilus_trader.model.identifiers cimport InstrumentId
 
 
@@ -37,7 +38,7 @@ cdef class Quantity:
     cdef bint is_zero(self)
     cdef bint is_negative(self)
     cdef bint is_positive(self)
-    cdef uint64_t raw_uint64_c(self)
+    cdef QuantityRaw raw_uint64_c(self)
     cdef double as_f64_c(self)
 
     cdef Quantity add(self, Quantity other)
@@ -52,13 +53,13 @@ cdef class Quantity:
     cdef bint _compare(a, b, int op)
 
     @staticmethod
-    cdef double raw_to_f64_c(uint64_t raw)
+    cdef double raw_to_f64_c(QuantityRaw raw)
 
     @staticmethod
     cdef Quantity from_mem_c(Quantity_t mem)
 
     @staticmethod
-    cdef Quantity from_raw_c(uint64_t raw, uint8_t precision)
+    cdef Quantity from_raw_c(QuantityRaw raw, uint8_t precision)
 
     @staticmethod
     cdef Quantity zero_c(uint8_t precision)
@@ -86,7 +87,7 @@ cdef class Price:
     cdef bint is_zero(self)
     cdef bint is_negative(self)
     cdef bint is_positive(self)
-    cdef int64_t raw_int64_c(self)
+    cdef PriceRaw raw_int64_c(self)
     cdef double as_f64_c(self)
 
     cdef Price add(self, Price other)
@@ -101,13 +102,13 @@ cdef class Price:
     cdef bint _compare(a, b, int op)
 
     @staticmethod
-    cdef double raw_to_f64_c(uint64_t raw)
+    cdef double raw_to_f64_c(PriceRaw raw)
 
     @staticmethod
     cdef Price from_mem_c(Price_t mem)
 
     @staticmethod
-    cdef Price from_raw_c(int64_t raw, uint8_t precision)
+    cdef Price from_raw_c(PriceRaw raw, uint8_t precision)
 
     @staticmethod
     cdef Price from_str_c(str value)
@@ -126,14 +127,14 @@ cdef class Money:
     cdef bint is_zero(self)
     cdef bint is_negative(self)
     cdef bint is_positive(self)
-    cdef int64_t raw_int64_c(self)
+    cdef MoneyRaw raw_int64_c(self)
     cdef double as_f64_c(self)
 
     @staticmethod
-    cdef double raw_to_f64_c(uint64_t raw)
+    cdef double raw_to_f64_c(MoneyRaw raw)
 
     @staticmethod
-    cdef Money from_raw_c(uint64_t raw, Currency currency)
+    cdef Money from_raw_c(MoneyRaw raw, Currency currency)
 
     @staticmethod
     cdef Money from_str_c(str value)
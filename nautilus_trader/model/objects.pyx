@@ -26,10 +26,8 @@ from cpython.object cimport Py_LE
 from cpython.object cimport Py_LT
 from cpython.object cimport PyObject_RichCompareBool
 from libc.math cimport isnan
-from libc.stdint cimport int64_t
 from libc.stdint cimport uint8_t
 from libc.stdint cimport uint16_t
-from libc.stdint cimport uint64_t
 
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.rust.core cimport precision_from_cstr
@@ -41,6 +39,9 @@ from nautilus_trader.core.rust.model cimport PRICE_MAX as RUST_PRICE_MAX
 from nautilus_trader.core.rust.model cimport PRICE_MIN as RUST_PRICE_MIN
 from nautilus_trader.core.rust.model cimport QUANTITY_MAX as RUST_QUANTITY_MAX
 from nautilus_trader.core.rust.model cimport QUANTITY_MIN as RUST_QUANTITY_MIN
+from nautilus_trader.core.rust.model cimport MoneyRaw
+from nautilus_trader.core.rust.model cimport PriceRaw
+from nautilus_trader.core.rust.model cimport QuantityRaw
 from nautilus_trader.core.rust.model cimport currency_code_to_cstr
 from nautilus_trader.core.rust.model cimport currency_exists
 from nautilus_trader.core.rust.model cimport currency_from_cstr
@@ -235,13 +236,13 @@ cdef class Quantity:
         return f"{type(self).__name__}('{self}')"
 
     @property
-    def raw(self) -> uint64_t:
+    def raw(self) -> QuantityRaw:
         """
         Return the raw memory representation of the quantity value.
 
         Returns
         -------
-        uint64_t
+        QuantityRaw
 
         """
         return self._mem.raw
@@ -301,14 +302,14 @@ cdef class Quantity:
         if self._mem.precision == 0:
             self._mem.precision = other.precision
 
-    cdef uint64_t raw_uint64_c(self):
+    cdef QuantityRaw raw_uint64_c(self):
         return self._mem.raw
 
     cdef double as_f64_c(self):
         return self._mem.raw / RUST_FIXED_SCALAR
 
     @staticmethod
-    cdef double raw_to_f64_c(uint64_t raw):
+    cdef double raw_to_f64_c(QuantityRaw raw):
         return raw / RUST_FIXED_SCALAR
 
     @staticmethod
@@ -322,7 +323,7 @@ cdef class Quantity:
         return quantity
 
     @staticmethod
-    cdef Quantity from_raw_c(uint64_t raw, uint8_t precision):
+    cdef Quantity from_raw_c(QuantityRaw raw, uint8_t precision):
         cdef Quantity quantity = Quantity.__new__(Quantity)
         quantity._mem = quantity_from_raw(raw, precision)
         return quantity
@@ -362,7 +363,7 @@ cdef class Quantity:
         return Quantity(value, precision=0)
 
     @staticmethod
-    def from_raw(uint64_t raw, uint8_t precision):
+    def from_raw(QuantityRaw raw, uint8_t precision):
         return Quantity.from_raw_c(raw, precision)
 
     @staticmethod
@@ -392,7 +393,7 @@ cdef class Quantity:
         return Quantity.zero_c(precision)
 
     @staticmethod
-    def from_raw(int64_t raw, uint8_t precision) -> Quantity:
+    def from_raw(QuantityRaw raw, uint8_t precision) -> Quantity:
         """
         Return a quantity from the given `raw` fixed precision integer and `precision`.
 
@@ -400,7 +401,7 @@ cdef class Quantity:
 
         Parameters
         ----------
-        raw : int64_t
+        raw : QuantityRaw
             The raw fixed precision quantity value.
         precision : uint8_t
             The precision for the quantity. Use a precision of 0 for whole numbers
@@ -677,13 +678,13 @@ cdef class Price:
         return f"{type(self).__name__}('{self}')"
 
     @property
-    def raw(self) -> int64_t:
+    def raw(self) -> PriceRaw:
         """
         Return the raw memory representation of the price value.
 
         Returns
         -------
-        int64_t
+        PriceRaw
 
         """
         return self._mem.raw
@@ -707,7 +708,7 @@ cdef class Price:
         return price
 
     @staticmethod
-    cdef Price from_raw_c(int64_t raw, uint8_t precision):
+    cdef Price from_raw_c(PriceRaw raw, uint8_t precision):
         cdef Price price = Price.__new__(Price)
         price._mem = price_from_raw(raw, precision)
         return price
@@ -735,7 +736,7 @@ cdef class Price:
         return PyObject_RichCompareBool(a, b, op)
 
     @staticmethod
-    cdef double raw_to_f64_c(uint64_t raw):
+    cdef double raw_to_f64_c(PriceRaw raw):
         return raw / RUST_FIXED_SCALAR
 
     @staticmethod
@@ -785,14 +786,14 @@ cdef class Price:
     cdef void sub_assign(self, Price other):
         self._mem.raw -= other._mem.raw
 
-    cdef int64_t raw_int64_c(self):
+    cdef PriceRaw raw_int64_c(self):
         return self._mem.raw
 
     cdef double as_f64_c(self):
         return self._mem.raw / RUST_FIXED_SCALAR
 
     @staticmethod
-    def from_raw(int64_t raw, uint8_t precision) -> Price:
+    def from_raw(PriceRaw raw, uint8_t precision) -> Price:
         """
         Return a price from the given `raw` fixed precision integer and `precision`.
 
@@ -800,7 +801,7 @@ cdef class Price:
 
         Parameters
         ----------
-        raw : int64_t
+        raw : PriceRaw
             The raw fixed precision price value.
         precision : uint8_t
             The precision for the price. Use a precision of 0 for whole numbers
@@ -1057,13 +1058,13 @@ cdef class Money:
         return f"{type(self).__name__}('{str(self)}', {self.currency_code_c()})"
 
     @property
-    def raw(self) -> int64_t:
+    def raw(self) -> MoneyRaw:
         """
         Return the raw memory representation of the money amount.
 
         Returns
         -------
-        int64_t
+        MoneyRaw
 
         """
         return self._mem.raw
@@ -1081,11 +1082,11 @@ cdef class Money:
         return Currency.from_str_c(self.currency_code_c())
 
     @staticmethod
-    cdef double raw_to_f64_c(uint64_t raw):
+    cdef double raw_to_f64_c(MoneyRaw raw):
         return raw / RUST_FIXED_SCALAR
 
     @staticmethod
-    cdef Money from_raw_c(uint64_t raw, Currency currency):
+    cdef Money from_raw_c(MoneyRaw raw, Currency currency):
         cdef Money money = Money.__new__(Money)
         money._mem = money_from_raw(raw, currency._mem)
         return money
@@ -1135,20 +1136,20 @@ cdef class Money:
         Condition.true(self._mem.currency.code == other._mem.currency.code, "currency != other.currency")
         self._mem.raw -= other._mem.raw
 
-    cdef int64_t raw_int64_c(self):
+    cdef MoneyRaw raw_int64_c(self):
         return self._mem.raw
 
     cdef double as_f64_c(self):
         return self._mem.raw / RUST_FIXED_SCALAR
 
     @staticmethod
-    def from_raw(int64_t raw, Currency currency) -> Money:
+    def from_raw(MoneyRaw raw, Currency currency) -> Money:
         """
         Return money from the given `raw` fixed precision integer and `currency`.
 
         Parameters
         ----------
-        raw : int64_t
+        raw : MoneyRaw
             The raw fixed precision money amount.
         currency : Currency
             The currency of the money.
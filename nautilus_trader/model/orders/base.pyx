@@ -18,9 +18,6 @@ from decimal import Decimal
 # This needs to be a Python import so it can used in the FSM
 from nautilus_trader.model.enums import order_status_to_str
 
-from libc.stdint cimport int64_t
-from libc.stdint cimport uint64_t
-
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.rust.model cimport ContingencyType
 from nautilus_trader.core.rust.model cimport LiquiditySide
@@ -28,6 +25,7 @@ from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport OrderStatus
 from nautilus_trader.core.rust.model cimport OrderType
 from nautilus_trader.core.rust.model cimport PositionSide
+from nautilus_trader.core.rust.model cimport QuantityRaw
 from nautilus_trader.model.events.order cimport OrderAccepted
 from nautilus_trader.model.events.order cimport OrderCanceled
 from nautilus_trader.model.events.order cimport OrderCancelRejected
@@ -1057,18 +1055,20 @@ cdef class Order:
         self.strategy_id = fill.strategy_id
         self._trade_ids.append(fill.trade_id)
         self.last_trade_id = fill.trade_id
-        cdef uint64_t raw_filled_qty = self.filled_qty._mem.raw + fill.last_qty._mem.raw
-        cdef int64_t raw_leaves_qty = self.quantity._mem.raw - raw_filled_qty
-        if raw_leaves_qty < 0:
+        cdef QuantityRaw raw_filled_qty = self.filled_qty._mem.raw + fill.last_qty._mem.raw
+        if raw_filled_qty > self.quantity._mem.raw:
             raise ValueError(
-                f"invalid order.leaves_qty: was {<uint64_t>raw_leaves_qty / 1e9}, "
+                f"invalid order.leaves_qty: was {self.quantity.as_f64_c() - Quantity.raw_to_f64_c(raw_filled_qty)}, "
                 f"order.quantity={self.quantity}, "
                 f"order.filled_qty={self.filled_qty}, "
                 f"fill.last_qty={fill.last_qty}, "
                 f"fill={fill}",
             )
         self.filled_qty.add_assign(fill.last_qty)
-        self.leaves_qty = Quantity.from_raw_c(<uint64_t>raw_leaves_qty, fill.last_qty._mem.precision)
+        self.leaves_qty = Quantity.from_raw_c(
+            self.quantity._mem.raw - raw_filled_qty,
+            fill.last_qty._mem.precision,
+        )
         self.avg_px = self._calculate_avg_px(fill.last_qty.as_f64_c(), fill.last_px.as_f64_c())
         self.liquidity_side = fill.liquidity_side
         self._set_slippage()
@@ -15,6 +15,7 @@
 
 from libc.stdint cimport uint64_t
 
+from nautilus_trader.core.rust.model cimport PegPriceType
 from nautilus_trader.model.events.order cimport OrderInitialized
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
@@ -28,6 +29,10 @@ cdef class LimitOrder(Order):
     """The order expiration (UNIX epoch nanoseconds), zero for no expiration.\n\n:returns: `uint64_t`"""
     cdef readonly Quantity display_qty
     """The quantity of the order to display on the public book (iceberg).\n\n:returns: `Quantity` or ``None``"""
+    cdef readonly PegPriceType peg_price_type
+    """The reference price the order price is pegged to.\n\n:returns: `PegPriceType`"""
+    cdef readonly Price peg_offset
+    """The offset from the peg reference price.\n\n:returns: `Price` or ``None``"""
 
     @staticmethod
     cdef LimitOrder create(OrderInitialized init)
@@ -21,6 +21,7 @@ from nautilus_trader.core.datetime cimport unix_nanos_to_dt
 from nautilus_trader.core.rust.model cimport ContingencyType
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport OrderType
+from nautilus_trader.core.rust.model cimport PegPriceType
 from nautilus_trader.core.rust.model cimport TimeInForce
 from nautilus_trader.core.rust.model cimport TriggerType
 from nautilus_trader.core.uuid cimport UUID4
@@ -30,6 +31,8 @@ from nautilus_trader.model.functions cimport contingency_type_to_str
 from nautilus_trader.model.functions cimport liquidity_side_to_str
 from nautilus_trader.model.functions cimport order_side_to_str
 from nautilus_trader.model.functions cimport order_type_to_str
+from nautilus_trader.model.functions cimport peg_price_type_from_str
+from nautilus_trader.model.functions cimport peg_price_type_to_str
 from nautilus_trader.model.functions cimport time_in_force_to_str
 from nautilus_trader.model.functions cimport trigger_type_to_str
 from nautilus_trader.model.identifiers cimport ClientOrderId
@@ -107,6 +110,12 @@ cdef class LimitOrder(Order):
     tags : str, optional
         The custom user tags for the order. These are optional and can
         contain any arbitrary delimiter if required.
+    peg_price_type : PegPriceType, default ``NO_PEG``
+        The reference price the order price is pegged to. A pegged order is
+        repriced as the market moves, with `price` holding the current pegged price.
+    peg_offset : Price, optional
+        The offset from the peg reference price (``MID_POINT`` pegs are offset
+        away from the market, ``PRIMARY`` pegs are offset towards the market).
 
     Raises
     ------
@@ -118,6 +127,10 @@ cdef class LimitOrder(Order):
         If `time_in_force` is ``GTD`` and `expire_time_ns` <= UNIX epoch.
     ValueError
         If `display_qty` is negative (< 0) or greater than `quantity`.
+    ValueError
+        If `peg_offset` is not ``None`` when `peg_price_type` is ``NO_PEG``.
+    ValueError
+        If `peg_offset` is negative (< 0).
 
     References
     ----------
@@ -151,6 +164,8 @@ cdef class LimitOrder(Order):
         dict exec_algorithm_params = None,
         ClientOrderId exec_spawn_id = None,
         str tags = None,
+        PegPriceType peg_price_type = PegPriceType.NO_PEG,
+        Price peg_offset = None,
     ):
         Condition.not_equal(order_side, OrderSide.NO_ORDER_SIDE, "order_side", "NO_ORDER_SIDE")
         if time_in_force == TimeInForce.GTD:
@@ -163,11 +178,17 @@ cdef class LimitOrder(Order):
             display_qty is None or 0 <= display_qty <= quantity,
             fail_msg="display_qty was negative or greater than order quantity",
         )
+        if peg_price_type == PegPriceType.NO_PEG:
+            Condition.true(peg_offset is None, "`peg_offset` was set when `peg_price_type` not pegged.")
+        elif peg_offset is not None:
+            Condition.not_negative(peg_offset.as_f64_c(), "peg_offset")
 
         # Set options
         cdef dict options = {
             "price": str(price),
             "display_qty": str(display_qty) if display_qty is not None else None,
+            "peg_price_type": peg_price_type_to_str(peg_price_type) if peg_price_type != PegPriceType.NO_PEG else None,
+            "peg_offset": str(peg_offset) if peg_offset is not None else None,
             "expire_time_ns": expire_time_ns,
         }
 
@@ -203,6 +224,8 @@ cdef class LimitOrder(Order):
         self.price = price
         self.expire_time_ns = expire_time_ns
         self.display_qty = display_qty
+        self.peg_price_type = peg_price_type
+        self.peg_offset = peg_offset
 
     cdef void _updated(self, OrderUpdated event):
         if self.venue_order_id is not None and event.venue_order_id is not None and self.venue_order_id != event.venue_order_id:
@@ -250,11 +273,13 @@ cdef class LimitOrder(Order):
         """
         cdef str expiration_str = "" if self.expire_time_ns == 0 else f" {format_iso8601(unix_nanos_to_dt(self.expire_time_ns))}"
         cdef str emulation_str = "" if self.emulation_trigger == TriggerType.NO_TRIGGER else f" EMULATED[{trigger_type_to_str(self.emulation_trigger)}]"
+        cdef str peg_offset_str = "" if self.peg_offset is None else f"-{self.peg_offset}"
+        cdef str peg_str = "" if self.peg_price_type == PegPriceType.NO_PEG else f" PEG[{peg_price_type_to_str(self.peg_price_type)}{peg_offset_str}]"
         return (
             f"{order_side_to_str(self.side)} {self.quantity.to_str()} {self.instrument_id} "
             f"{order_type_to_str(self.order_type)} @ {self.price} "
             f"{time_in_force_to_str(self.time_in_force)}{expiration_str}"
-            f"{emulation_str}"
+            f"{emulation_str}{peg_str}"
         )
 
     cpdef dict to_dict(self):
@@ -292,6 +317,8 @@ cdef class LimitOrder(Order):
             "is_reduce_only": self.is_reduce_only,
             "is_quote_quantity": self.is_quote_quantity,
             "display_qty": str(self.display_qty) if self.display_qty is not None else None,
+            "peg_price_type": peg_price_type_to_str(self.peg_price_type),
+            "peg_offset": str(self.peg_offset) if self.peg_offset is not None else None,
             "emulation_trigger": trigger_type_to_str(self.emulation_trigger),
             "trigger_instrument_id": self.trigger_instrument_id.to_str() if self.trigger_instrument_id is not None else None,
             "contingency_type": contingency_type_to_str(self.contingency_type),
@@ -330,6 +357,8 @@ cdef class LimitOrder(Order):
         Condition.equal(init.order_type, OrderType.LIMIT, "init.order_type", "OrderType")
 
         cdef str display_qty_str = init.options.get("display_qty")
+        cdef str peg_price_type_str = init.options.get("peg_price_type")
+        cdef str peg_offset_str = init.options.get("peg_offset")
 
         return LimitOrder(
             trader_id=init.trader_id,
@@ -357,6 +386,8 @@ cdef class LimitOrder(Order):
             exec_algorithm_params=init.exec_algorithm_params,
             exec_spawn_id=init.exec_spawn_id,
             tags=init.tags,
+            peg_price_type=peg_price_type_from_str(peg_price_type_str) if peg_price_type_str is not None else PegPriceType.NO_PEG,
+            peg_offset=Price.from_str_c(peg_offset_str) if peg_offset_str is not None else None,
         )
 
     @staticmethod
@@ -20,6 +20,7 @@ from abc import ABCMeta
 from abc import abstractmethod
 from typing import Any
 
+from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.core.data import Data
 from nautilus_trader.model.data import Bar
 from nautilus_trader.model.data import CustomData
@@ -151,6 +152,17 @@ class BaseDataCatalog(ABC, metaclass=_CombinedMeta):
     ) -> list[Bar]:
         return self.query(data_cls=Bar, bar_types=bar_types, **kwargs)
 
+    def open_interest(
+        self,
+        instrument_ids: list[str] | None = None,
+        **kwargs: Any,
+    ) -> list[nautilus_pyo3.OpenInterestUpdate]:
+        return self.query(
+            data_cls=nautilus_pyo3.OpenInterestUpdate,
+            instrument_ids=instrument_ids,
+            **kwargs,
+        )
+
     def custom_data(
         self,
         cls: type,
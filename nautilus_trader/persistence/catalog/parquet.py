@@ -37,9 +37,10 @@ from fsspec.implementations.memory import MemoryFileSystem
 from fsspec.utils import infer_storage_options
 from pyarrow import ArrowInvalid
 
+from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.core.correctness import PyCondition
 from nautilus_trader.core.data import Data
-from nautilus_trader.core.datetime import dt_to_unix_nanos
+from nautilus_trader.core.datetime import maybe_dt_to_unix_nanos
 from nautilus_trader.core.inspect import is_nautilus_class
 from nautilus_trader.core.message import Event
 from nautilus_trader.core.nautilus_pyo3 import DataBackendSession
@@ -363,7 +364,17 @@ class ParquetDataCatalog(BaseDataCatalog):
         where: str | None = None,
         **kwargs: Any,
     ) -> list[Data | CustomData]:
-        if data_cls in (OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick, Bar):
+        if data_cls in (
+            OrderBookDelta,
+            OrderBookDepth10,
+            nautilus_pyo3.OrderBookDepth25,
+            QuoteTick,
+            TradeTick,
+            Bar,
+            nautilus_pyo3.MarkPriceUpdate,
+            nautilus_pyo3.IndexPriceUpdate,
+            nautilus_pyo3.OpenInterestUpdate,
+        ):
             data = self.query_rust(
                 data_cls=data_cls,
                 instrument_ids=instrument_ids,
@@ -410,6 +421,10 @@ class ParquetDataCatalog(BaseDataCatalog):
         if session is None:
             raise ValueError("`session` was `None` when a value was expected")
 
+        filter_ids = [str(x) for x in (instrument_ids or []) + (bar_types or [])] or None
+        start_ns = maybe_dt_to_unix_nanos(start)
+        end_ns = maybe_dt_to_unix_nanos(end)
+
         file_prefix = class_to_filename(data_cls)
         glob_path = f"{self.path}/data/{file_prefix}/**/*"
         dirs = self.fs.glob(glob_path)
@@ -423,16 +438,18 @@ class ParquetDataCatalog(BaseDataCatalog):
             if bar_types and not any(urisafe_instrument_id(x) in path for x in bar_types):
                 continue
             table = f"{file_prefix}_{idx}"
-            query = self._build_query(
+            # Files which cannot match are pruned using their footer metadata,
+            # the `ts_init` range is pushed down to row groups and pages.
+            session.add_file_with_filter(
+                data_type,
                 table,
-                # instrument_ids=None, # Filtering by filename for now
-                start=start,
-                end=end,
-                where=where,
+                str(path),
+                instrument_ids=filter_ids,
+                start=start_ns,
+                end=end_ns,
+                where_clause=where,
             )
 
-            session.add_file(data_type, table, str(path), query)
-
         return session
 
     def query_rust(
@@ -528,40 +545,26 @@ class ParquetDataCatalog(BaseDataCatalog):
             filter_ = None
         return dataset.to_table(filter=filter_)
 
-    def _build_query(
-        self,
-        table: str,
-        start: TimestampLike | None = None,
-        end: TimestampLike | None = None,
-        where: str | None = None,
-    ) -> str:
-        # Build datafusion SQL query
-        query = f"SELECT * FROM {table}"  # noqa (possible SQL injection)
-        conditions: list[str] = [] + ([where] if where else [])
-
-        if start:
-            start_ts = dt_to_unix_nanos(start)
-            conditions.append(f"ts_init >= {start_ts}")
-        if end:
-            end_ts = dt_to_unix_nanos(end)
-            conditions.append(f"ts_init <= {end_ts}")
-        if conditions:
-            query += f" WHERE {' AND '.join(conditions)}"
-
-        return query
-
     @staticmethod
     def _nautilus_data_cls_to_data_type(data_cls: type) -> NautilusDataType:
         if data_cls in (OrderBookDelta, OrderBookDeltas):
             return NautilusDataType.OrderBookDelta
         elif data_cls == OrderBookDepth10:
             return NautilusDataType.OrderBookDepth10
+        elif data_cls == nautilus_pyo3.OrderBookDepth25:
+            return NautilusDataType.OrderBookDepth25
         elif data_cls == QuoteTick:
             return NautilusDataType.QuoteTick
         elif data_cls == TradeTick:
             return NautilusDataType.TradeTick
         elif data_cls == Bar:
             return NautilusDataType.Bar
+        elif data_cls == nautilus_pyo3.MarkPriceUpdate:
+            return NautilusDataType.MarkPriceUpdate
+        elif data_cls == nautilus_pyo3.IndexPriceUpdate:
+            return NautilusDataType.IndexPriceUpdate
+        elif data_cls == nautilus_pyo3.OpenInterestUpdate:
+            return NautilusDataType.OpenInterestUpdate
         else:
             raise RuntimeError(f"unsupported `data_cls` for Rust parquet, was {data_cls.__name__}")
 
@@ -22,7 +22,6 @@ import pandas as pd
 from nautilus_trader.model.enums import book_action_from_str
 from nautilus_trader.model.enums import order_side_from_str
 
-from libc.stdint cimport int64_t
 from libc.stdint cimport uint8_t
 from libc.stdint cimport uint64_t
 
@@ -32,9 +31,12 @@ from nautilus_trader.core.datetime cimport as_utc_index
 from nautilus_trader.core.datetime cimport dt_to_unix_nanos
 from nautilus_trader.core.rust.core cimport CVec
 from nautilus_trader.core.rust.core cimport secs_to_nanos
+from nautilus_trader.core.rust.model cimport FIXED_SCALAR
 from nautilus_trader.core.rust.model cimport AggressorSide
 from nautilus_trader.core.rust.model cimport BookAction
 from nautilus_trader.core.rust.model cimport OrderSide
+from nautilus_trader.core.rust.model cimport PriceRaw
+from nautilus_trader.core.rust.model cimport QuantityRaw
 from nautilus_trader.model.data cimport Bar
 from nautilus_trader.model.data cimport BarType
 from nautilus_trader.model.data cimport OrderBookDelta
@@ -120,8 +122,8 @@ cdef class OrderBookDeltaDataWrangler:
         self,
         BookAction action,
         OrderSide side,
-        int64_t price_raw,
-        uint64_t size_raw,
+        PriceRaw price_raw,
+        QuantityRaw size_raw,
         uint64_t order_id,
         uint8_t flags,
         uint64_t sequence,
@@ -160,9 +162,9 @@ cdef class OrderBookDeltaDataWrangler:
             self.instrument.id,
             action,
             side,
-            int(price * 1e9),
+            int(price * FIXED_SCALAR),
             self.instrument.price_precision,
-            int(size * 1e9),
+            int(size * FIXED_SCALAR),
             self.instrument.size_precision,
             order_id,
             flags,
@@ -391,10 +393,10 @@ cdef class QuoteTickDataWrangler:
     # cpdef method for Python wrap() (called with map)
     cpdef QuoteTick _build_tick_from_raw(
         self,
-        int64_t bid_price_raw,
-        int64_t ask_price_raw,
-        uint64_t bid_size_raw,
-        uint64_t ask_size_raw,
+        PriceRaw bid_price_raw,
+        PriceRaw ask_price_raw,
+        QuantityRaw bid_size_raw,
+        QuantityRaw ask_size_raw,
         uint64_t ts_event,
         uint64_t ts_init,
     ):
@@ -426,12 +428,12 @@ cdef class QuoteTickDataWrangler:
         # be an ndarray with 4 elements [bid, ask, bid_size, ask_size] of type double.
         return QuoteTick.from_raw_c(
             self.instrument.id,
-            int(bid * 1e9),
-            int(ask * 1e9),
+            int(bid * FIXED_SCALAR),
+            int(ask * FIXED_SCALAR),
             self.instrument.price_precision,
             self.instrument.price_precision,
-            int(bid_size * 1e9),
-            int(ask_size * 1e9),
+            int(bid_size * FIXED_SCALAR),
+            int(ask_size * FIXED_SCALAR),
             self.instrument.size_precision,
             self.instrument.size_precision,
             ts_event,
@@ -510,8 +512,8 @@ cdef class TradeTickDataWrangler:
     # cpdef method for Python wrap() (called with map)
     cpdef TradeTick _build_tick_from_raw(
         self,
-        int64_t price_raw,
-        uint64_t size_raw,
+        PriceRaw price_raw,
+        QuantityRaw size_raw,
         AggressorSide aggressor_side,
         str trade_id,
         uint64_t ts_event,
@@ -543,9 +545,9 @@ cdef class TradeTickDataWrangler:
         # be an ndarray with 4 elements [bid, ask, bid_size, ask_size] of type double.
         return TradeTick.from_raw_c(
             self.instrument.id,
-            int(price * 1e9),
+            int(price * FIXED_SCALAR),
             self.instrument.price_precision,
-            int(size * 1e9),
+            int(size * FIXED_SCALAR),
             self.instrument.size_precision,
             aggressor_side,
             TradeId(trade_id),
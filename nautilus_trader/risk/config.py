@@ -16,6 +16,78 @@
 from __future__ import annotations
 
 from nautilus_trader.common.config import NautilusConfig
+from nautilus_trader.common.config import PositiveFloat
+from nautilus_trader.common.config import PositiveInt
+
+
+class OrderRateLimitConfig(NautilusConfig, frozen=True):
+    """
+    Configuration for a token bucket order rate limit.
+
+    Parameters
+    ----------
+    rate : str
+        The sustained rate of submit order commands per timedelta, e.g. '10/00:00:01'.
+    burst : PositiveInt, optional
+        The maximum burst of submit order commands. If ``None`` then will equal
+        the sustained rate limit.
+
+    """
+
+    rate: str
+    burst: PositiveInt | None = None
+
+
+class PriceCollarConfig(NautilusConfig, frozen=True):
+    """
+    Configuration for a pre-trade price collar (fat-finger) check.
+
+    Order prices are checked against the mid of the last quote, or the last trade
+    price if no quote is cached. No check is made without a reference price.
+
+    Parameters
+    ----------
+    max_deviation_pct : PositiveFloat, optional
+        The maximum deviation from the reference price in percent (5.0 is 5%).
+    max_deviation_ticks : PositiveInt, optional
+        The maximum deviation from the reference price in price increments (ticks).
+    reject : bool, default True
+        If True then orders outside the collar are denied, otherwise a warning is logged.
+
+    """
+
+    max_deviation_pct: PositiveFloat | None = None
+    max_deviation_ticks: PositiveInt | None = None
+    reject: bool = True
+
+
+class LossLimitConfig(NautilusConfig, frozen=True):
+    """
+    Configuration for account-level loss limits.
+
+    Equity is the total account balance plus the unrealized PnL for the venue,
+    in the account base currency (or the currency of `max_daily_loss` for
+    multi-currency accounts). On breach the trading state is moved to
+    `breach_trading_state` until the next session boundary, when the prior
+    trading state is restored (unless the state was changed in the meantime).
+
+    Parameters
+    ----------
+    max_daily_loss : str, optional
+        The maximum loss of equity since the start of the session, e.g. '10000.00 USD'.
+    max_drawdown : PositiveFloat, optional
+        The maximum trailing drawdown from the session peak equity as a fraction (0.1 is 10%).
+    breach_trading_state : str, default 'HALTED'
+        The trading state on breach, either 'REDUCING' or 'HALTED'.
+    session_reset_time : str, default '00:00:00'
+        The UTC time of day at which the session (and any breach) is reset.
+
+    """
+
+    max_daily_loss: str | None = None
+    max_drawdown: PositiveFloat | None = None
+    breach_trading_state: str = "HALTED"
+    session_reset_time: str = "00:00:00"
 
 
 class RiskEngineConfig(NautilusConfig, frozen=True):
@@ -33,6 +105,24 @@ class RiskEngineConfig(NautilusConfig, frozen=True):
     max_notional_per_order : dict[str, int], default empty dict
         The maximum notional value of an order per instrument ID.
         The value should be a valid decimal format.
+    max_position_qty_per_instrument : dict[str, str], default empty dict
+        The maximum absolute net position quantity per instrument ID.
+    max_open_orders_per_instrument : dict[str, PositiveInt], default empty dict
+        The maximum number of open orders per instrument ID.
+    max_order_submit_rate_per_strategy : dict[str, OrderRateLimitConfig], default empty dict
+        The token bucket order rate limits per strategy ID.
+    max_order_submit_rate_per_venue : dict[str, OrderRateLimitConfig], default empty dict
+        The token bucket order rate limits per venue.
+    price_collars : dict[str, PriceCollarConfig], default empty dict
+        The price collar checks per instrument class (e.g. 'SPOT', 'FUTURE').
+    loss_limits : dict[str, LossLimitConfig], default empty dict
+        The account-level loss limits per venue.
+    max_net_exposure_per_underlying : dict[str, str], default empty dict
+        The maximum aggregate net exposure across all instruments per underlying
+        (e.g. {'BTC': '1000000.00 USD'}).
+    max_net_exposure_per_asset_class : dict[str, str], default empty dict
+        The maximum aggregate net exposure across all instruments per asset class
+        (e.g. {'CRYPTOCURRENCY': '5000000.00 USD'}).
     debug : bool, default False
         If debug mode is active (will provide extra debug logging).
 
@@ -42,4 +132,12 @@ class RiskEngineConfig(NautilusConfig, frozen=True):
     max_order_submit_rate: str = "100/00:00:01"
     max_order_modify_rate: str = "100/00:00:01"
     max_notional_per_order: dict[str, int] = {}
+    max_position_qty_per_instrument: dict[str, str] = {}
+    max_open_orders_per_instrument: dict[str, PositiveInt] = {}
+    max_order_submit_rate_per_strategy: dict[str, OrderRateLimitConfig] = {}
+    max_order_submit_rate_per_venue: dict[str, OrderRateLimitConfig] = {}
+    price_collars: dict[str, PriceCollarConfig] = {}
+    loss_limits: dict[str, LossLimitConfig] = {}
+    max_net_exposure_per_underlying: dict[str, str] = {}
+    max_net_exposure_per_asset_class: dict[str, str] = {}
     debug: bool = False
@@ -15,9 +15,13 @@
 
 from decimal import Decimal
 
+from libc.stdint cimport uint64_t
+
 from nautilus_trader.cache.cache cimport Cache
 from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.component cimport Throttler
+from nautilus_trader.common.component cimport TimeEvent
+from nautilus_trader.common.component cimport TokenBucket
 from nautilus_trader.core.message cimport Command
 from nautilus_trader.core.message cimport Event
 from nautilus_trader.core.rust.model cimport TradingState
@@ -28,20 +32,36 @@ from nautilus_trader.execution.messages cimport SubmitOrder
 from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport Venue
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
 from nautilus_trader.model.orders.base cimport Order
 from nautilus_trader.model.orders.list cimport OrderList
 from nautilus_trader.portfolio.base cimport PortfolioFacade
+from nautilus_trader.risk.messages cimport UpdateRiskLimits
 
 
 cdef class RiskEngine(Component):
     cdef readonly PortfolioFacade _portfolio
     cdef readonly Cache _cache
     cdef readonly dict _max_notional_per_order
+    cdef readonly dict _max_position_qty
+    cdef readonly dict _max_open_orders
     cdef readonly Throttler _order_submit_throttler
     cdef readonly Throttler _order_modify_throttler
+    cdef readonly dict _strategy_rate_limiters
+    cdef readonly dict _venue_rate_limiters
+    cdef readonly dict _price_collars
+    cdef readonly object _exposure
+    cdef readonly dict _loss_limits
+    cdef readonly dict _loss_limit_sessions
+    cdef readonly dict _session_start_equity
+    cdef readonly dict _peak_equity
+    cdef readonly set _loss_limit_breaches
+    cdef bint _loss_limit_state_set
+    cdef TradingState _loss_limit_state
+    cdef TradingState _loss_limit_prior_state
 
     cdef readonly TradingState trading_state
     """The current trading state for the engine.\n\n:returns: `TradingState`"""
@@ -60,6 +80,8 @@ cdef class RiskEngine(Component):
     cpdef void process(self, Event event)
     cpdef void set_trading_state(self, TradingState state)
     cpdef void set_max_notional_per_order(self, InstrumentId instrument_id, new_value: Decimal)
+    cpdef void set_max_position_qty(self, InstrumentId instrument_id, Quantity new_value)
+    cpdef void set_max_open_orders(self, InstrumentId instrument_id, new_value)
     cpdef void _log_state(self)
 
 # -- RISK SETTINGS --------------------------------------------------------------------------------
@@ -68,6 +90,8 @@ cdef class RiskEngine(Component):
     cpdef tuple max_order_modify_rate(self)
     cpdef dict max_notionals_per_order(self)
     cpdef object max_notional_per_order(self, InstrumentId instrument_id)
+    cpdef Quantity max_position_qty(self, InstrumentId instrument_id)
+    cpdef object max_open_orders(self, InstrumentId instrument_id)
 
 # -- ABSTRACT METHODS -----------------------------------------------------------------------------
 
@@ -80,6 +104,7 @@ cdef class RiskEngine(Component):
     cpdef void _handle_submit_order(self, SubmitOrder command)
     cpdef void _handle_submit_order_list(self, SubmitOrderList command)
     cpdef void _handle_modify_order(self, ModifyOrder command)
+    cpdef void _handle_update_risk_limits(self, UpdateRiskLimits command)
 
 # -- PRE-TRADE CHECKS -----------------------------------------------------------------------------
 
@@ -87,8 +112,19 @@ cdef class RiskEngine(Component):
     cpdef bint _check_order_price(self, Instrument instrument, Order order)
     cpdef bint _check_order_quantity(self, Instrument instrument, Order order)
     cpdef bint _check_orders_risk(self, Instrument instrument, list orders)
+    cpdef bint _check_orders_limits(self, Instrument instrument, list orders)
     cpdef str _check_price(self, Instrument instrument, Price price)
     cpdef str _check_quantity(self, Instrument instrument, Quantity quantity)
+    cpdef str _check_price_collar(self, Instrument instrument, Price price)
+    cpdef str _check_order_rate_limits(self, TradingCommand command)
+
+# -- POST-TRADE CHECKS ----------------------------------------------------------------------------
+
+    cpdef void _check_loss_limits(self)
+    cdef str _check_venue_loss_limit(self, Venue venue, config, uint64_t ts_now)
+    cdef object _loss_limit_session_start(self, config, uint64_t ts_now)
+    cdef void _set_loss_limit_reset_alert(self, Venue venue, config, uint64_t ts_now)
+    cpdef void _on_loss_limit_session_reset(self, TimeEvent event)
 
 # -- DENIALS --------------------------------------------------------------------------------------
 
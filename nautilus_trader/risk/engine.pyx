@@ -17,7 +17,11 @@ from decimal import Decimal
 
 import pandas as pd
 
+from nautilus_trader.risk.config import LossLimitConfig
+from nautilus_trader.risk.config import OrderRateLimitConfig
+from nautilus_trader.risk.config import PriceCollarConfig
 from nautilus_trader.risk.config import RiskEngineConfig
+from nautilus_trader.risk.exposure import ExposureAggregator
 
 from libc.stdint cimport uint64_t
 
@@ -31,16 +35,21 @@ from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.component cimport LogColor
 from nautilus_trader.common.component cimport MessageBus
 from nautilus_trader.common.component cimport Throttler
+from nautilus_trader.common.component cimport TimeEvent
+from nautilus_trader.common.component cimport TokenBucket
 from nautilus_trader.common.messages cimport TradingStateChanged
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.message cimport Command
 from nautilus_trader.core.message cimport Event
+from nautilus_trader.core.rust.common cimport metrics_inc_messages_processed
+from nautilus_trader.core.rust.common cimport metrics_is_enabled
 from nautilus_trader.core.rust.model cimport InstrumentClass
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport OrderStatus
 from nautilus_trader.core.rust.model cimport OrderType
 from nautilus_trader.core.rust.model cimport TradingState
 from nautilus_trader.core.rust.model cimport TriggerType
+from nautilus_trader.core.string cimport pystr_to_cstr
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.execution.messages cimport CancelAllOrders
 from nautilus_trader.execution.messages cimport CancelOrder
@@ -50,13 +59,20 @@ from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.data cimport QuoteTick
 from nautilus_trader.model.data cimport TradeTick
+from nautilus_trader.model.events.account cimport AccountState
 from nautilus_trader.model.events.order cimport OrderCancelRejected
 from nautilus_trader.model.events.order cimport OrderDenied
 from nautilus_trader.model.events.order cimport OrderModifyRejected
+from nautilus_trader.model.events.position cimport PositionEvent
+from nautilus_trader.model.functions cimport asset_class_from_str
+from nautilus_trader.model.functions cimport instrument_class_from_str
 from nautilus_trader.model.functions cimport order_type_to_str
+from nautilus_trader.model.functions cimport trading_state_from_str
 from nautilus_trader.model.functions cimport trading_state_to_str
 from nautilus_trader.model.identifiers cimport ComponentId
 from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport StrategyId
+from nautilus_trader.model.identifiers cimport Venue
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.instruments.currency_pair cimport CurrencyPair
 from nautilus_trader.model.objects cimport Currency
@@ -67,6 +83,7 @@ from nautilus_trader.model.orders.base cimport Order
 from nautilus_trader.model.orders.list cimport OrderList
 from nautilus_trader.model.position cimport Position
 from nautilus_trader.portfolio.base cimport PortfolioFacade
+from nautilus_trader.risk.messages cimport UpdateRiskLimits
 
 
 cdef class RiskEngine(Component):
@@ -169,8 +186,37 @@ cdef class RiskEngine(Component):
             color=LogColor.BLUE,
         )
 
+        # Token bucket order rate limits
+        self._strategy_rate_limiters: dict[StrategyId, TokenBucket] = {
+            StrategyId(strategy_id): self._create_rate_limiter(f"STRATEGY-{strategy_id}", rate_limit)
+            for strategy_id, rate_limit in config.max_order_submit_rate_per_strategy.items()
+        }
+        self._venue_rate_limiters: dict[Venue, TokenBucket] = {
+            Venue(venue): self._create_rate_limiter(f"VENUE-{venue}", rate_limit)
+            for venue, rate_limit in config.max_order_submit_rate_per_venue.items()
+        }
+
         # Risk settings
         self._max_notional_per_order: dict[InstrumentId, Decimal] = {}
+        self._max_position_qty: dict[InstrumentId, Quantity] = {}
+        self._max_open_orders: dict[InstrumentId, int] = {}
+        self._price_collars: dict[InstrumentClass, PriceCollarConfig] = {
+            instrument_class_from_str(instrument_class): collar
+            for instrument_class, collar in config.price_collars.items()
+        }
+        self._exposure = ExposureAggregator(cache=cache, log=self._log)
+
+        # Loss limits
+        self._loss_limits: dict[Venue, LossLimitConfig] = {
+            Venue(venue): loss_limit for venue, loss_limit in config.loss_limits.items()
+        }
+        self._loss_limit_sessions: dict[Venue, int] = {}
+        self._session_start_equity: dict[Venue, float] = {}
+        self._peak_equity: dict[Venue, float] = {}
+        self._loss_limit_breaches: set[Venue] = set()
+        self._loss_limit_state_set = False
+        self._loss_limit_state = TradingState.ACTIVE
+        self._loss_limit_prior_state = TradingState.ACTIVE
 
         # Configure
         self._initialize_risk_checks(config)
@@ -182,12 +228,46 @@ cdef class RiskEngine(Component):
         # Required subscriptions
         self._msgbus.subscribe(topic="events.order.*", handler=self._handle_event, priority=10)
         self._msgbus.subscribe(topic="events.position.*", handler=self._handle_event, priority=10)
+        if self._loss_limits:
+            self._msgbus.subscribe(topic="events.account.*", handler=self._handle_event, priority=10)
+
+    def _create_rate_limiter(self, str name, rate_limit: OrderRateLimitConfig) -> TokenBucket:
+        pieces = rate_limit.rate.split("/")
+        bucket = TokenBucket(
+            name=name,
+            limit=int(pieces[0]),
+            interval=pd.to_timedelta(pieces[1]),
+            burst=rate_limit.burst,
+        )
+        self._log.info(
+            f"Set ORDER_SUBMIT_RATE_LIMIT[{name}]: "
+            f"{bucket.limit}/{str(bucket.interval).replace('0 days ', '')} burst={bucket.burst}.",
+            color=LogColor.BLUE,
+        )
+        return bucket
 
     def _initialize_risk_checks(self, config: RiskEngineConfig):
         cdef dict max_notional_config = config.max_notional_per_order
         for instrument_id, value in max_notional_config.items():
             self.set_max_notional_per_order(InstrumentId.from_str_c(instrument_id), Decimal(value))
 
+        for instrument_id, value in config.max_position_qty_per_instrument.items():
+            self.set_max_position_qty(InstrumentId.from_str_c(instrument_id), Quantity.from_str_c(value))
+
+        for instrument_id, value in config.max_open_orders_per_instrument.items():
+            self.set_max_open_orders(InstrumentId.from_str_c(instrument_id), value)
+
+        for underlying, value in config.max_net_exposure_per_underlying.items():
+            self._exposure.set_max_net_exposure_per_underlying(underlying, Money.from_str_c(value))
+            self._log.info(f"Set MAX_NET_EXPOSURE[UNDERLYING={underlying}]: {value}.", color=LogColor.BLUE)
+
+        for asset_class, value in config.max_net_exposure_per_asset_class.items():
+            self._exposure.set_max_net_exposure_per_asset_class(
+                asset_class_from_str(asset_class),
+                Money.from_str_c(value),
+            )
+            self._log.info(f"Set MAX_NET_EXPOSURE[ASSET_CLASS={asset_class}]: {value}.", color=LogColor.BLUE)
+
 # -- COMMANDS -------------------------------------------------------------------------------------
 
     cpdef void execute(self, Command command):
@@ -303,6 +383,62 @@ cdef class RiskEngine(Component):
             color=LogColor.BLUE,
         )
 
+    cpdef void set_max_position_qty(self, InstrumentId instrument_id, Quantity new_value):
+        """
+        Set the maximum absolute net position quantity for the given instrument ID.
+
+        Passing a new_value of ``None`` will disable the pre-trade risk max
+        position quantity check.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the max position quantity.
+        new_value : Quantity, optional
+            The max position quantity to set.
+
+        """
+        if new_value is None:
+            self._max_position_qty.pop(instrument_id, None)
+        else:
+            self._max_position_qty[instrument_id] = new_value
+
+        self._log.info(
+            f"Set MAX_POSITION_QTY: {instrument_id} {new_value}.",
+            color=LogColor.BLUE,
+        )
+
+    cpdef void set_max_open_orders(self, InstrumentId instrument_id, new_value):
+        """
+        Set the maximum number of open orders for the given instrument ID.
+
+        Passing a new_value of ``None`` will disable the pre-trade risk max
+        open orders check.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the max open orders.
+        new_value : int, optional
+            The max open orders to set.
+
+        Raises
+        ------
+        ValueError
+            If `new_value` is not ``None`` and not positive.
+
+        """
+        if new_value is None:
+            self._max_open_orders.pop(instrument_id, None)
+        else:
+            Condition.positive_int(new_value, "new_value")
+            self._max_open_orders[instrument_id] = new_value
+
+        self._log.info(
+            f"Set MAX_OPEN_ORDERS: {instrument_id} {new_value}.",
+            color=LogColor.BLUE,
+        )
+
 # -- RISK SETTINGS --------------------------------------------------------------------------------
 
     cpdef tuple max_order_submit_rate(self):
@@ -357,6 +493,28 @@ cdef class RiskEngine(Component):
         """
         return self._max_notional_per_order.get(instrument_id)
 
+    cpdef Quantity max_position_qty(self, InstrumentId instrument_id):
+        """
+        Return the current maximum absolute net position quantity for the given instrument ID.
+
+        Returns
+        -------
+        Quantity or ``None``
+
+        """
+        return self._max_position_qty.get(instrument_id)
+
+    cpdef object max_open_orders(self, InstrumentId instrument_id):
+        """
+        Return the current maximum number of open orders for the given instrument ID.
+
+        Returns
+        -------
+        int or ``None``
+
+        """
+        return self._max_open_orders.get(instrument_id)
+
 # -- ABSTRACT METHODS -----------------------------------------------------------------------------
 
     cpdef void _on_start(self):
@@ -368,11 +526,21 @@ cdef class RiskEngine(Component):
 # -- ACTION IMPLEMENTATIONS -----------------------------------------------------------------------
 
     cpdef void _start(self):
-        # Do nothing else for now
+        # Reset loss limit sessions on the clock, so that a halted engine (which
+        # may receive no further account events) still resumes at the boundary
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+        cdef Venue venue
+        for venue, config in self._loss_limits.items():
+            self._set_loss_limit_reset_alert(venue, config, ts_now)
+
         self._on_start()
 
     cpdef void _stop(self):
-        # Do nothing else for now
+        cdef str timer_name
+        for timer_name in self._clock.timer_names:
+            if timer_name.startswith("RiskEngine|LossLimit|"):
+                self._clock.cancel_timer(timer_name)
+
         self._on_stop()
 
     cpdef void _reset(self):
@@ -381,6 +549,18 @@ cdef class RiskEngine(Component):
         self._order_submit_throttler.reset()
         self._order_modify_throttler.reset()
 
+        cdef TokenBucket bucket
+        for bucket in self._strategy_rate_limiters.values():
+            bucket.reset()
+        for bucket in self._venue_rate_limiters.values():
+            bucket.reset()
+
+        self._loss_limit_sessions.clear()
+        self._session_start_equity.clear()
+        self._peak_equity.clear()
+        self._loss_limit_breaches.clear()
+        self._loss_limit_state_set = False
+
     cpdef void _dispose(self):
         pass
         # Nothing to dispose for now
@@ -391,6 +571,8 @@ cdef class RiskEngine(Component):
         if self.debug:
             self._log.debug(f"{RECV}{CMD} {command}.", LogColor.MAGENTA)
         self.command_count += 1
+        if metrics_is_enabled():
+            metrics_inc_messages_processed(pystr_to_cstr(self.id.to_str()), b"command")
 
         if isinstance(command, SubmitOrder):
             self._handle_submit_order(command)
@@ -398,6 +580,8 @@ cdef class RiskEngine(Component):
             self._handle_submit_order_list(command)
         elif isinstance(command, ModifyOrder):
             self._handle_modify_order(command)
+        elif isinstance(command, UpdateRiskLimits):
+            self._handle_update_risk_limits(command)
         else:
             self._log.error(f"Cannot handle command: {command}.")
 
@@ -470,6 +654,11 @@ cdef class RiskEngine(Component):
 
         self._execution_gateway(instrument, command)
 
+    cpdef void _handle_update_risk_limits(self, UpdateRiskLimits command):
+        self.set_max_notional_per_order(command.instrument_id, command.max_notional_per_order)
+        self.set_max_position_qty(command.instrument_id, command.max_position_qty)
+        self.set_max_open_orders(command.instrument_id, command.max_open_orders)
+
     cpdef void _handle_modify_order(self, ModifyOrder command):
         ########################################################################
         # VALIDATE COMMAND
@@ -506,6 +695,8 @@ cdef class RiskEngine(Component):
 
         # Check price
         risk_msg = self._check_price(instrument, command.price)
+        if not risk_msg:
+            risk_msg = self._check_price_collar(instrument, command.price)
         if risk_msg:
             self._reject_modify_order(order=order, reason=risk_msg)
             return  # Denied
@@ -566,6 +757,8 @@ cdef class RiskEngine(Component):
         cdef str risk_msg = None
         if order.has_price_c():
             risk_msg = self._check_price(instrument, order.price)
+            if not risk_msg:
+                risk_msg = self._check_price_collar(instrument, order.price)
             if risk_msg:
                 self._deny_order(order=order, reason=risk_msg)
                 return False  # Denied
@@ -575,6 +768,8 @@ cdef class RiskEngine(Component):
         ########################################################################
         if order.has_trigger_price_c():
             risk_msg = self._check_price(instrument, order.trigger_price)
+            if not risk_msg:
+                risk_msg = self._check_price_collar(instrument, order.trigger_price)
             if risk_msg:
                 self._deny_order(order=order, reason=f"trigger {risk_msg}")
                 return False  # Denied
@@ -605,6 +800,16 @@ cdef class RiskEngine(Component):
             # TODO(cs): Improve efficiency of this
             max_notional = Money(float(max_notional_setting), instrument.quote_currency)
 
+        # Check aggregate exposure limits (across correlated instruments)
+        if self._exposure.has_limits:
+            denied = self._exposure.check_orders(instrument, orders)
+            if denied is not None:
+                self._deny_order(order=denied[0], reason=denied[1])
+                return False  # Denied
+
+        if not self._check_orders_limits(instrument, orders):
+            return False  # Denied
+
         # Get account for risk checks
         cdef Account account = self._cache.account_for_venue(instrument.id.venue)
         if account is None:
@@ -745,6 +950,47 @@ cdef class RiskEngine(Component):
         # Finally
         return True  # Passed
 
+    cpdef bint _check_orders_limits(self, Instrument instrument, list orders):
+        cdef Quantity max_position_qty = self._max_position_qty.get(instrument.id)
+        max_open_orders: int | None = self._max_open_orders.get(instrument.id)
+        if max_position_qty is None and max_open_orders is None:
+            return True  # No limits for instrument
+
+        cdef double position_qty = float(self._portfolio.net_position(instrument.id))
+        cdef int open_orders = self._cache.orders_open_count(instrument_id=instrument.id)
+
+        cdef:
+            Order order
+            double projected_qty
+        for order in orders:
+            open_orders += 1
+            if max_open_orders is not None and open_orders > max_open_orders:
+                self._deny_order(
+                    order=order,
+                    reason=f"OPEN_ORDERS_EXCEEDS_MAX: max_open_orders={max_open_orders}, open_orders={open_orders}",
+                )
+                return False  # Denied
+
+            if max_position_qty is None:
+                continue
+
+            # Orders which reduce the absolute position are always allowed
+            if order.side == OrderSide.BUY:
+                projected_qty = position_qty + order.quantity.as_f64_c()
+            else:
+                projected_qty = position_qty - order.quantity.as_f64_c()
+            if abs(projected_qty) > abs(position_qty) and abs(projected_qty) > max_position_qty.as_f64_c():
+                self._deny_order(
+                    order=order,
+                    reason=(
+                        f"POSITION_EXCEEDS_MAX_QUANTITY: max_position_qty={max_position_qty.to_str()}, "
+                        f"position_qty={instrument.make_qty(abs(projected_qty)).to_str()}"
+                    ),
+                )
+                return False  # Denied
+
+        return True  # Passed
+
     cpdef str _check_price(self, Instrument instrument, Price price):
         if price is None:
             # Nothing to check
@@ -771,6 +1017,179 @@ cdef class RiskEngine(Component):
             # Check failed
             return f"quantity {quantity.to_str()} invalid (< minimum trade size of {instrument.min_quantity})"
 
+    cpdef str _check_price_collar(self, Instrument instrument, Price price):
+        if price is None:
+            return None  # No price to check
+        collar: PriceCollarConfig | None = self._price_collars.get(instrument.instrument_class)
+        if collar is None:
+            return None  # No collar for instrument class
+
+        # Determine reference price
+        cdef double reference_px
+        cdef QuoteTick last_quote = self._cache.quote_tick(instrument.id)
+        cdef TradeTick last_trade
+        if last_quote is not None:
+            reference_px = (last_quote.bid_price.as_f64_c() + last_quote.ask_price.as_f64_c()) / 2.0
+        else:
+            last_trade = self._cache.trade_tick(instrument.id)
+            if last_trade is None:
+                self._log.warning(f"Cannot check price collar: no prices for {instrument.id}.")
+                return None  # Cannot check
+            reference_px = last_trade.price.as_f64_c()
+
+        cdef double deviation = abs(price.as_f64_c() - reference_px)
+        cdef str risk_msg = None
+        cdef double deviation_ticks
+        if collar.max_deviation_pct is not None and reference_px != 0.0:
+            if deviation / abs(reference_px) * 100.0 > collar.max_deviation_pct:
+                risk_msg = (
+                    f"PRICE_OUTSIDE_COLLAR: price={price}, reference={reference_px}, "
+                    f"max_deviation_pct={collar.max_deviation_pct}"
+                )
+        if risk_msg is None and collar.max_deviation_ticks is not None:
+            deviation_ticks = deviation / instrument.price_increment.as_f64_c()
+            # Allow for floating point error in the tick count
+            if deviation_ticks - collar.max_deviation_ticks > 1e-9:
+                risk_msg = (
+                    f"PRICE_OUTSIDE_COLLAR: price={price}, reference={reference_px}, "
+                    f"max_deviation_ticks={collar.max_deviation_ticks}"
+                )
+
+        if risk_msg is not None and not collar.reject:
+            self._log.warning(risk_msg)
+            return None  # Warn only
+
+        return risk_msg
+
+    cpdef str _check_order_rate_limits(self, TradingCommand command):
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+        cdef list buckets = []
+        cdef TokenBucket bucket = self._strategy_rate_limiters.get(command.strategy_id)
+        if bucket is not None:
+            buckets.append(bucket)
+        bucket = self._venue_rate_limiters.get(command.instrument_id.venue)
+        if bucket is not None:
+            buckets.append(bucket)
+
+        # Check all limits before consuming, so a denial consumes no tokens
+        cdef uint64_t retry_after_ns
+        for bucket in buckets:
+            retry_after_ns = bucket.retry_after_ns(ts_now)
+            if retry_after_ns > 0:
+                return (
+                    f"Exceeded ORDER_SUBMIT_RATE_LIMIT[{bucket.name}]: "
+                    f"retry_after_ms={(retry_after_ns + 999_999) // 1_000_000}"
+                )
+
+        for bucket in buckets:
+            bucket.try_consume(ts_now)
+
+        return None  # Check passed
+
+# -- POST-TRADE CHECKS ----------------------------------------------------------------------------
+
+    cpdef void _check_loss_limits(self):
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+        cdef bint had_breaches = len(self._loss_limit_breaches) > 0
+        cdef TradingState breach_state
+        cdef str breach_msg
+        cdef Venue venue
+        for venue, config in self._loss_limits.items():
+            breach_msg = self._check_venue_loss_limit(venue, config, ts_now)
+            if breach_msg is None or venue in self._loss_limit_breaches:
+                continue
+
+            self._loss_limit_breaches.add(venue)
+            self._log.warning(f"Loss limit breached for {venue}: {breach_msg}.")
+
+            breach_state = trading_state_from_str(config.breach_trading_state)
+            if self.trading_state != TradingState.HALTED and self.trading_state != breach_state:
+                if not self._loss_limit_state_set:
+                    self._loss_limit_prior_state = self.trading_state
+                self._loss_limit_state_set = True
+                self._loss_limit_state = breach_state
+                self.set_trading_state(breach_state)
+
+        if had_breaches and not self._loss_limit_breaches:
+            self._log.info("Loss limits reset at session boundary.", LogColor.BLUE)
+            # Only restore the state if it is still the one set by the loss limit,
+            # a change made since (e.g. by an operator) takes precedence
+            if self._loss_limit_state_set and self.trading_state == self._loss_limit_state:
+                self.set_trading_state(self._loss_limit_prior_state)
+            self._loss_limit_state_set = False
+
+    cdef void _set_loss_limit_reset_alert(self, Venue venue, config: LossLimitConfig, uint64_t ts_now):
+        session_end = self._loss_limit_session_start(config, ts_now) + pd.Timedelta(days=1)
+        self._clock.set_time_alert(
+            name=f"RiskEngine|LossLimit|{venue}|{session_end.value}",
+            alert_time=session_end,
+            callback=self._on_loss_limit_session_reset,
+        )
+
+    cpdef void _on_loss_limit_session_reset(self, TimeEvent event):
+        self._check_loss_limits()
+
+        cdef Venue venue = Venue(event.name.split("|")[2])
+        self._set_loss_limit_reset_alert(venue, self._loss_limits[venue], event.ts_event)
+
+    cdef object _loss_limit_session_start(self, config: LossLimitConfig, uint64_t ts_now):
+        now = pd.Timestamp(ts_now, tz="UTC")
+        session_start = now.normalize() + pd.Timedelta(config.session_reset_time)
+        if session_start > now:
+            session_start -= pd.Timedelta(days=1)
+        return session_start
+
+    cdef str _check_venue_loss_limit(self, Venue venue, config: LossLimitConfig, uint64_t ts_now):
+        cdef Account account = self._cache.account_for_venue(venue)
+        if account is None:
+            return None  # Nothing to check yet
+
+        cdef Money max_daily_loss = None
+        if config.max_daily_loss is not None:
+            max_daily_loss = Money.from_str_c(config.max_daily_loss)
+
+        cdef Currency currency = account.base_currency
+        if currency is None and max_daily_loss is not None:
+            currency = max_daily_loss.currency
+        if currency is None:
+            self._log.warning(f"Cannot check loss limits for {venue}: no currency for multi-currency account.")
+            return None
+
+        cdef Money balance = account.balance_total(currency)
+        if balance is None:
+            return None  # No balance in currency
+
+        cdef double equity = balance.as_f64_c()
+        cdef Money unrealized_pnl = self._portfolio.unrealized_pnls(venue).get(currency)
+        if unrealized_pnl is not None:
+            equity += unrealized_pnl.as_f64_c()
+
+        # Start a new session at the session boundary
+        session_start = self._loss_limit_session_start(config, ts_now)
+        if self._loss_limit_sessions.get(venue) != session_start.value:
+            self._loss_limit_sessions[venue] = session_start.value
+            self._session_start_equity[venue] = equity
+            self._peak_equity[venue] = equity
+            self._loss_limit_breaches.discard(venue)
+
+        cdef double daily_loss = self._session_start_equity[venue] - equity
+        if max_daily_loss is not None and daily_loss >= max_daily_loss.as_f64_c():
+            return (
+                f"MAX_DAILY_LOSS: loss={Money(daily_loss, currency)}, "
+                f"max_daily_loss={max_daily_loss}"
+            )
+
+        cdef double peak_equity = max(self._peak_equity[venue], equity)
+        self._peak_equity[venue] = peak_equity
+
+        cdef double drawdown
+        if config.max_drawdown is not None and peak_equity > 0:
+            drawdown = (peak_equity - equity) / peak_equity
+            if drawdown > config.max_drawdown:
+                return f"MAX_DRAWDOWN: drawdown={drawdown:.4f}, max_drawdown={config.max_drawdown:.4f}"
+
+        return None  # No breach
+
 # -- DENIALS --------------------------------------------------------------------------------------
 
     cpdef void _deny_command(self, TradingCommand command, str reason):
@@ -877,6 +1296,12 @@ cdef class RiskEngine(Component):
                         )
                         return  # Denied
 
+        # Check per strategy and per venue order rate limits
+        cdef str rate_limit_msg = self._check_order_rate_limits(command)
+        if rate_limit_msg is not None:
+            self._deny_command(command=command, reason=rate_limit_msg)
+            return  # Denied
+
         # All checks passed: send to ORDER_RATE throttler
         self._order_submit_throttler.send(command)
 
@@ -908,3 +1333,8 @@ cdef class RiskEngine(Component):
         if self.debug:
             self._log.debug(f"{RECV}{EVT} {event}.", LogColor.MAGENTA)
         self.event_count += 1
+        if metrics_is_enabled():
+            metrics_inc_messages_processed(pystr_to_cstr(self.id.to_str()), b"event")
+
+        if self._loss_limits and isinstance(event, (AccountState, PositionEvent)):
+            self._check_loss_limits()
@@ -0,0 +1,303 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+"""
+Provides net exposure aggregation across instruments for correlated-risk limits.
+"""
+
+from __future__ import annotations
+
+from nautilus_trader.cache.base import CacheFacade
+from nautilus_trader.common.component import Logger
+from nautilus_trader.model.enums import AssetClass
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import asset_class_to_str
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.model.orders import Order
+
+
+def instrument_underlying(instrument: Instrument) -> str:
+    """
+    Return the underlying for the given instrument.
+
+    This is the instruments underlying (for derivatives), otherwise the base
+    currency (e.g. BTC for both BTCUSDT spot and perpetuals), otherwise the
+    instruments symbol.
+
+    Parameters
+    ----------
+    instrument : Instrument
+        The instrument for the underlying.
+
+    Returns
+    -------
+    str
+
+    """
+    underlying = getattr(instrument, "underlying", None)
+    if underlying is not None:
+        return underlying if isinstance(underlying, str) else underlying.code
+
+    base_currency = instrument.get_base_currency()
+    if base_currency is not None:
+        return base_currency.code
+
+    return instrument.id.symbol.value
+
+
+class ExposureAggregator:
+    """
+    Provides net exposure aggregation across all instruments sharing an
+    underlying or asset class, and checks orders against limits on the aggregate.
+
+    Net exposure is the signed notional value of all open positions (across all
+    venues), valued at the quote mid (or last trade, or average open price) and
+    converted into the currency of the limit.
+
+    Parameters
+    ----------
+    cache : CacheFacade
+        The cache for the aggregator.
+    log : Logger
+        The logger for the aggregator.
+
+    """
+
+    def __init__(self, cache: CacheFacade, log: Logger) -> None:
+        self._cache = cache
+        self._log = log
+        self._max_net_exposure_per_underlying: dict[str, Money] = {}
+        self._max_net_exposure_per_asset_class: dict[AssetClass, Money] = {}
+
+    @property
+    def has_limits(self) -> bool:
+        """
+        Return whether any aggregate exposure limits are set.
+
+        Returns
+        -------
+        bool
+
+        """
+        return bool(self._max_net_exposure_per_underlying or self._max_net_exposure_per_asset_class)
+
+    def set_max_net_exposure_per_underlying(
+        self,
+        underlying: str,
+        max_net_exposure: Money | None,
+    ) -> None:
+        """
+        Set the maximum net exposure for the given underlying.
+
+        Parameters
+        ----------
+        underlying : str
+            The underlying for the limit.
+        max_net_exposure : Money, optional
+            The maximum net exposure (absolute). If ``None`` then removes the limit.
+
+        """
+        if max_net_exposure is None:
+            self._max_net_exposure_per_underlying.pop(underlying, None)
+        else:
+            self._max_net_exposure_per_underlying[underlying] = max_net_exposure
+
+    def set_max_net_exposure_per_asset_class(
+        self,
+        asset_class: AssetClass,
+        max_net_exposure: Money | None,
+    ) -> None:
+        """
+        Set the maximum net exposure for the given asset class.
+
+        Parameters
+        ----------
+        asset_class : AssetClass
+            The asset class for the limit.
+        max_net_exposure : Money, optional
+            The maximum net exposure (absolute). If ``None`` then removes the limit.
+
+        """
+        if max_net_exposure is None:
+            self._max_net_exposure_per_asset_class.pop(asset_class, None)
+        else:
+            self._max_net_exposure_per_asset_class[asset_class] = max_net_exposure
+
+    def net_exposure_for_underlying(self, underlying: str, currency: Currency) -> Money:
+        """
+        Return the aggregate net exposure for the given underlying.
+
+        Parameters
+        ----------
+        underlying : str
+            The underlying to aggregate.
+        currency : Currency
+            The currency for the exposure.
+
+        Returns
+        -------
+        Money
+
+        """
+        return self._net_exposure(lambda i: instrument_underlying(i) == underlying, currency)
+
+    def net_exposure_for_asset_class(self, asset_class: AssetClass, currency: Currency) -> Money:
+        """
+        Return the aggregate net exposure for the given asset class.
+
+        Parameters
+        ----------
+        asset_class : AssetClass
+            The asset class to aggregate.
+        currency : Currency
+            The currency for the exposure.
+
+        Returns
+        -------
+        Money
+
+        """
+        return self._net_exposure(lambda i: i.asset_class == asset_class, currency)
+
+    def check_orders(self, instrument: Instrument, orders: list[Order]) -> tuple[Order, str] | None:
+        """
+        Check the given orders against the aggregate exposure limits.
+
+        Orders which reduce the absolute aggregate exposure always pass.
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The instrument for the orders.
+        orders : list[Order]
+            The orders to check (cumulatively).
+
+        Returns
+        -------
+        tuple[Order, str] or ``None``
+            The first order breaching a limit with the reason, otherwise ``None``.
+
+        """
+        limits: list[tuple[str, Money, Money]] = []
+
+        underlying = instrument_underlying(instrument)
+        max_net_exposure = self._max_net_exposure_per_underlying.get(underlying)
+        if max_net_exposure is not None:
+            limits.append(
+                (
+                    f"UNDERLYING[{underlying}]",
+                    max_net_exposure,
+                    self.net_exposure_for_underlying(underlying, max_net_exposure.currency),
+                ),
+            )
+
+        max_net_exposure = self._max_net_exposure_per_asset_class.get(instrument.asset_class)
+        if max_net_exposure is not None:
+            limits.append(
+                (
+                    f"ASSET_CLASS[{asset_class_to_str(instrument.asset_class)}]",
+                    max_net_exposure,
+                    self.net_exposure_for_asset_class(
+                        instrument.asset_class,
+                        max_net_exposure.currency,
+                    ),
+                ),
+            )
+
+        for label, max_net_exposure, net_exposure in limits:
+            currency = max_net_exposure.currency
+            exposure = net_exposure.as_double()
+            for order in orders:
+                price = self._order_price(instrument, order)
+                if price is None:
+                    self._log.warning(f"Cannot check {label} exposure: no prices for {instrument.id}.")
+                    continue
+
+                notional = self._notional(instrument, order.quantity, price, currency)
+                if notional is None:
+                    continue
+
+                projected = exposure + notional if order.side == OrderSide.BUY else exposure - notional
+                if abs(projected) > abs(exposure) and abs(projected) > max_net_exposure.as_double():
+                    return (
+                        order,
+                        f"NET_EXPOSURE_EXCEEDS_MAX_FOR_{label}: "
+                        f"max_net_exposure={max_net_exposure.to_str()}, "
+                        f"net_exposure={Money(projected, currency).to_str()}",
+                    )
+                exposure = projected
+
+        return None  # Passed
+
+    def _net_exposure(self, predicate, currency: Currency) -> Money:
+        exposure = 0.0
+        for position in self._cache.positions_open():
+            instrument = self._cache.instrument(position.instrument_id)
+            if instrument is None or not predicate(instrument):
+                continue
+
+            price = self._reference_price(instrument)
+            if price is None:
+                price = instrument.make_price(position.avg_px_open)
+
+            notional = self._notional(instrument, position.quantity, price, currency)
+            if notional is None:
+                continue
+
+            exposure += notional if position.signed_qty > 0 else -notional
+
+        return Money(exposure, currency)
+
+    def _notional(
+        self,
+        instrument: Instrument,
+        quantity: Quantity,
+        price: Price,
+        currency: Currency,
+    ) -> float | None:
+        notional = instrument.notional_value(quantity, price)
+        if notional.currency == currency:
+            return notional.as_double()
+
+        xrate = self._cache.get_xrate(instrument.id.venue, notional.currency, currency)
+        if not xrate:
+            self._log.warning(
+                f"Cannot convert {instrument.id} exposure: "
+                f"no {notional.currency}/{currency} exchange rate.",
+            )
+            return None
+
+        return notional.as_double() * float(xrate)
+
+    def _reference_price(self, instrument: Instrument) -> Price | None:
+        quote = self._cache.quote_tick(instrument.id)
+        if quote is not None:
+            return instrument.make_price((quote.bid_price.as_double() + quote.ask_price.as_double()) / 2)
+
+        trade = self._cache.trade_tick(instrument.id)
+        if trade is not None:
+            return trade.price
+
+        return None
+
+    def _order_price(self, instrument: Instrument, order: Order) -> Price | None:
+        if order.has_price and order.price is not None:
+            return order.price
+        if order.has_trigger_price and order.trigger_price is not None:
+            return order.trigger_price
+        return self._reference_price(instrument)
@@ -0,0 +1,33 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+
+from nautilus_trader.core.message cimport Command
+from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport TraderId
+from nautilus_trader.model.objects cimport Quantity
+
+
+cdef class UpdateRiskLimits(Command):
+    cdef readonly TraderId trader_id
+    """The trader ID associated with the command.\n\n:returns: `TraderId`"""
+    cdef readonly InstrumentId instrument_id
+    """The instrument ID for the limits.\n\n:returns: `InstrumentId`"""
+    cdef readonly object max_notional_per_order
+    """The maximum notional value per order (``None`` for no limit).\n\n:returns: `Decimal` or ``None``"""
+    cdef readonly Quantity max_position_qty
+    """The maximum absolute net position quantity (``None`` for no limit).\n\n:returns: `Quantity` or ``None``"""
+    cdef readonly object max_open_orders
+    """The maximum number of open orders (``None`` for no limit).\n\n:returns: `int` or ``None``"""
@@ -0,0 +1,104 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+
+from decimal import Decimal
+
+from libc.stdint cimport uint64_t
+
+from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.message cimport Command
+from nautilus_trader.core.uuid cimport UUID4
+from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport TraderId
+from nautilus_trader.model.objects cimport Quantity
+
+
+cdef class UpdateRiskLimits(Command):
+    """
+    Represents a command to update the per-instrument risk limits of the
+    `RiskEngine` at runtime.
+
+    The limits replace any existing limits for the instrument, a limit of
+    ``None`` removes that check.
+
+    Parameters
+    ----------
+    trader_id : TraderId
+        The trader ID for the command.
+    instrument_id : InstrumentId
+        The instrument ID for the limits.
+    command_id : UUID4
+        The commands ID.
+    ts_init : uint64_t
+        The UNIX timestamp (nanoseconds) when the object was initialized.
+    max_notional_per_order : Decimal, optional
+        The maximum notional value per order.
+    max_position_qty : Quantity, optional
+        The maximum absolute net position quantity.
+    max_open_orders : int, optional
+        The maximum number of open orders.
+
+    Raises
+    ------
+    ValueError
+        If `max_notional_per_order` is not ``None`` and not positive.
+    ValueError
+        If `max_open_orders` is not ``None`` and not positive.
+    """
+
+    def __init__(
+        self,
+        TraderId trader_id not None,
+        InstrumentId instrument_id not None,
+        UUID4 command_id not None,
+        uint64_t ts_init,
+        max_notional_per_order: Decimal | None = None,
+        Quantity max_position_qty: Quantity | None = None,
+        max_open_orders: int | None = None,
+    ):
+        if max_notional_per_order is not None:
+            max_notional_per_order = Decimal(max_notional_per_order)
+            Condition.positive(max_notional_per_order, "max_notional_per_order")
+        if max_open_orders is not None:
+            Condition.positive_int(max_open_orders, "max_open_orders")
+        super().__init__(command_id, ts_init)
+
+        self.trader_id = trader_id
+        self.instrument_id = instrument_id
+        self.max_notional_per_order = max_notional_per_order
+        self.max_position_qty = max_position_qty
+        self.max_open_orders = max_open_orders
+
+    def __str__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id.to_str()}, "
+            f"max_notional_per_order={self.max_notional_per_order}, "
+            f"max_position_qty={self.max_position_qty}, "
+            f"max_open_orders={self.max_open_orders})"
+        )
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"trader_id={self.trader_id.to_str()}, "
+            f"instrument_id={self.instrument_id.to_str()}, "
+            f"max_notional_per_order={self.max_notional_per_order}, "
+            f"max_position_qty={self.max_position_qty}, "
+            f"max_open_orders={self.max_open_orders}, "
+            f"command_id={self.id.to_str()}, "
+            f"ts_init={self.ts_init})"
+        )
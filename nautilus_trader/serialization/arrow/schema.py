@@ -100,6 +100,9 @@ NAUTILUS_ARROW_SCHEMA = {
             "status": pa.dictionary(pa.int8(), pa.string()),
             "trading_session": pa.string(),
             "halt_reason": pa.dictionary(pa.int8(), pa.string()),
+            "auction_phase": pa.dictionary(pa.int8(), pa.string()),
+            "limit_up": pa.string(),
+            "limit_down": pa.string(),
             "ts_event": pa.uint64(),
             "ts_init": pa.uint64(),
         },
@@ -150,6 +153,8 @@ NAUTILUS_ARROW_SCHEMA = {
             "trailing_offset_type": pa.dictionary(pa.int8(), pa.string()),
             "expire_time_ns": pa.uint64(),
             "display_qty": pa.string(),
+            "peg_price_type": pa.dictionary(pa.int8(), pa.string()),
+            "peg_offset": pa.string(),
             "quote_quantity": pa.bool_(),
             "options": pa.binary(),
             # --------------------- #
@@ -177,6 +182,8 @@ NAUTILUS_ARROW_SCHEMA = {
                     "trailing_offset",
                     "trailing_offset_type",
                     "display_qty",
+                    "peg_price_type",
+                    "peg_offset",
                     "expire_time_ns",
                 ],
             ),
@@ -122,12 +122,20 @@ class ArrowSerializer:
                 batch_bytes = DataTransformer.pyo3_order_book_deltas_to_record_batch_bytes(data)
             case nautilus_pyo3.OrderBookDepth10:
                 batch_bytes = DataTransformer.pyo3_order_book_depth10_to_record_batch_bytes(data)
+            case nautilus_pyo3.OrderBookDepth25:
+                batch_bytes = DataTransformer.pyo3_order_book_depth25_to_record_batch_bytes(data)
             case nautilus_pyo3.QuoteTick:
                 batch_bytes = DataTransformer.pyo3_quote_ticks_to_record_batch_bytes(data)
             case nautilus_pyo3.TradeTick:
                 batch_bytes = DataTransformer.pyo3_trade_ticks_to_record_batch_bytes(data)
             case nautilus_pyo3.Bar:
                 batch_bytes = DataTransformer.pyo3_bars_to_record_batch_bytes(data)
+            case nautilus_pyo3.MarkPriceUpdate:
+                batch_bytes = DataTransformer.pyo3_mark_prices_to_record_batch_bytes(data)
+            case nautilus_pyo3.IndexPriceUpdate:
+                batch_bytes = DataTransformer.pyo3_index_prices_to_record_batch_bytes(data)
+            case nautilus_pyo3.OpenInterestUpdate:
+                batch_bytes = DataTransformer.pyo3_open_interest_to_record_batch_bytes(data)
             case _:
                 if data_cls == OrderBookDelta or data_cls == OrderBookDeltas:
                     pyo3_deltas = OrderBookDelta.to_pyo3_list(data)
@@ -296,6 +304,10 @@ RUST_SERIALIZERS = {
     OrderBookDelta,
     OrderBookDeltas,
     OrderBookDepth10,
+    nautilus_pyo3.OrderBookDepth25,
+    nautilus_pyo3.MarkPriceUpdate,
+    nautilus_pyo3.IndexPriceUpdate,
+    nautilus_pyo3.OpenInterestUpdate,
 }
 RUST_STR_SERIALIZERS = {s.__name__ for s in RUST_SERIALIZERS}
 
@@ -20,6 +20,7 @@ from nautilus_trader.common import Environment
 from nautilus_trader.common.config import ImportableActorConfig
 from nautilus_trader.common.config import LoggingConfig
 from nautilus_trader.common.config import MessageBusConfig
+from nautilus_trader.common.config import MetricsConfig
 from nautilus_trader.common.config import NautilusConfig
 from nautilus_trader.common.config import OrderEmulatorConfig
 from nautilus_trader.common.config import PositiveFloat
@@ -45,6 +46,8 @@ class NautilusKernelConfig(NautilusConfig, frozen=True):
         The kernel environment context.
     trader_id : TraderId
         The trader ID for the kernel (must be a name and ID tag separated by a hyphen).
+    uuid_version : int, default 4
+        The UUID version for generated identifiers (4 random, or 7 time-ordered).
     cache : CacheConfig, optional
         The cache configuration.
     message_bus : MessageBusConfig, optional
@@ -77,6 +80,9 @@ class NautilusKernelConfig(NautilusConfig, frozen=True):
         If the asyncio event loop should be in debug mode.
     logging : LoggingConfig, optional
         The logging config for the kernel.
+    metrics : MetricsConfig, optional
+        The Prometheus metrics endpoint config for the kernel.
+        If ``None`` then metrics are not collected.
     snapshot_orders : bool, default False
         If order state snapshot lists should be persisted.
         Snapshots will be taken at every order state update (when events are applied).
@@ -106,6 +112,7 @@ class NautilusKernelConfig(NautilusConfig, frozen=True):
     environment: Environment
     trader_id: TraderId
     instance_id: UUID4 | None = None
+    uuid_version: int = 4
     cache: CacheConfig | None = None
     message_bus: MessageBusConfig | None = None
     data_engine: DataEngineConfig | None = None
@@ -122,6 +129,7 @@ class NautilusKernelConfig(NautilusConfig, frozen=True):
     save_state: bool = False
     loop_debug: bool = False
     logging: LoggingConfig | None = None
+    metrics: MetricsConfig | None = None
     snapshot_orders: bool = False
     snapshot_positions: bool = False
     snapshot_positions_interval: PositiveFloat | None = None
@@ -61,6 +61,7 @@ from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.core.correctness import PyCondition
 from nautilus_trader.core.datetime import nanos_to_millis
 from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.core.uuid import set_uuid_version
 from nautilus_trader.data.engine import DataEngine
 from nautilus_trader.execution.algorithm import ExecAlgorithm
 from nautilus_trader.execution.emulator import OrderEmulator
@@ -142,6 +143,7 @@ class NautilusKernel:
         self._name: str = name
         self._trader_id: TraderId = trader_id
         self._machine_id: str = socket.gethostname()
+        set_uuid_version(config.uuid_version)
         self._instance_id: UUID4 = config.instance_id or UUID4()
         self._ts_created: int = time.time_ns()
 
@@ -175,7 +177,13 @@ class NautilusKernel:
                         directory=logging.log_directory,
                         file_name=logging.log_file_name,
                         file_format=logging.log_file_format,
+                        max_file_size=logging.log_file_max_size,
+                        max_file_age_secs=logging.log_file_max_age_secs,
+                        compression=logging.log_file_compression,
+                        max_backup_count=logging.log_file_max_backup_count,
+                        max_backup_age_days=logging.log_file_max_backup_age_days,
                         is_colored=logging.log_colors,
+                        is_json=logging.log_json,
                         is_bypassed=logging.bypass_logging,
                         print_config=logging.print_config,
                     )
@@ -186,6 +194,14 @@ class NautilusKernel:
                         component=name,
                     )
                 else:
+                    file_rotation = {
+                        "max_file_size": logging.log_file_max_size,
+                        "max_file_age_secs": logging.log_file_max_age_secs,
+                        "compression": logging.log_file_compression,
+                        "max_backup_count": logging.log_file_max_backup_count,
+                        "max_backup_age_days": logging.log_file_max_backup_age_days,
+                    }
+
                     # Initialize logging for sync Rust and Python
                     init_logging(
                         trader_id=self._trader_id,
@@ -200,8 +216,10 @@ class NautilusKernel:
                         directory=logging.log_directory,
                         file_name=logging.log_file_name,
                         file_format=logging.log_file_format,
+                        file_rotation={k: v for k, v in file_rotation.items() if v is not None},
                         component_levels=logging.log_component_levels,
                         colors=logging.log_colors,
+                        json=logging.log_json,
                         bypass=logging.bypass_logging,
                         print_config=logging.print_config,
                     )
@@ -221,6 +239,10 @@ class NautilusKernel:
 
         self._log.info("Building system kernel...")
 
+        # Setup metrics
+        if config.metrics is not None and not nautilus_pyo3.metrics_is_enabled():
+            nautilus_pyo3.init_metrics(config.metrics.host, config.metrics.port)
+
         # Setup loop (if sandbox live)
         self._loop: asyncio.AbstractEventLoop | None = None
         if self._environment != Environment.BACKTEST:
@@ -237,7 +259,7 @@ class NautilusKernel:
 
         if not config.cache or not config.cache.database:
             cache_db = None
-        elif config.cache.database.type == "redis":
+        elif config.cache.database.type in ("redis", "postgres", "sqlite"):
             encoding = config.cache.encoding.lower()
             cache_db = CacheDatabaseAdapter(
                 trader_id=self._trader_id,
@@ -252,7 +274,7 @@ class NautilusKernel:
         else:
             raise ValueError(
                 f"Unrecognized `config.cache.database.type`, was '{config.cache.database.type}'. "
-                "The only database type currently supported is 'redis', if you don't want a cache database backing "
+                "The database types currently supported are 'redis', 'postgres' and 'sqlite', if you don't want a cache database backing "
                 "then you can pass `None` for the `cache.database` ('in-memory' is no longer valid)",
             )
 
@@ -262,11 +284,11 @@ class NautilusKernel:
         if (
             config.message_bus
             and config.message_bus.database
-            and config.message_bus.database.type != "redis"
+            and config.message_bus.database.type not in ("redis", "nats", "kafka")
         ):
             raise ValueError(
                 f"Unrecognized `config.message_bus.type`, was '{config.message_bus.database.type}'. "
-                "The only database type currently supported is 'redis', if you don't want a message bus database backing "
+                "The database types currently supported are 'redis', 'nats' and 'kafka', if you don't want a message bus database backing "
                 "then you can pass `None` for the `message_bus.database`",
             )
 
@@ -923,6 +945,11 @@ class NautilusKernel:
         if self._writer:
             self._writer.close()
 
+        # Flush any tracing spans pending export
+        logging: LoggingConfig = self._config.logging or LoggingConfig()
+        if logging.use_pyo3:
+            nautilus_pyo3.shutdown_tracing()
+
     def cancel_all_tasks(self) -> None:
         """
         Cancel all tasks currently running for the Nautilus kernel.
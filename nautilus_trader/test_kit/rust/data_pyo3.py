@@ -20,9 +20,12 @@ from nautilus_trader.core.nautilus_pyo3 import BarSpecification
 from nautilus_trader.core.nautilus_pyo3 import BarType
 from nautilus_trader.core.nautilus_pyo3 import BookAction
 from nautilus_trader.core.nautilus_pyo3 import BookOrder
+from nautilus_trader.core.nautilus_pyo3 import IndexPriceUpdate
 from nautilus_trader.core.nautilus_pyo3 import InstrumentId
+from nautilus_trader.core.nautilus_pyo3 import MarkPriceUpdate
 from nautilus_trader.core.nautilus_pyo3 import OrderBookDelta
 from nautilus_trader.core.nautilus_pyo3 import OrderBookDepth10
+from nautilus_trader.core.nautilus_pyo3 import OrderBookDepth25
 from nautilus_trader.core.nautilus_pyo3 import OrderSide
 from nautilus_trader.core.nautilus_pyo3 import Price
 from nautilus_trader.core.nautilus_pyo3 import PriceType
@@ -120,6 +123,70 @@ class TestDataProviderPyo3:
             ts_init=ts_init,
         )
 
+    @staticmethod
+    def order_book_depth25(
+        instrument_id: InstrumentId | None = None,
+        flags: int = 0,
+        sequence: int = 0,
+        ts_event: int = 0,
+        ts_init: int = 0,
+    ) -> OrderBookDepth25:
+        bids: list[BookOrder] = []
+        asks: list[BookOrder] = []
+
+        # Create bids
+        price = 99.00
+        quantity = 100.0
+        order_id = 1
+
+        for _ in range(25):
+            order = BookOrder(
+                OrderSide.BUY,
+                Price(price, 2),
+                Quantity(quantity, 0),
+                order_id,
+            )
+
+            bids.append(order)
+
+            price -= 1.0
+            quantity += 100.0
+            order_id += 1
+
+        # Create asks
+        price = 100.00
+        quantity = 100.0
+        order_id = 26
+
+        for _ in range(25):
+            order = BookOrder(
+                OrderSide.SELL,
+                Price(price, 2),
+                Quantity(quantity, 0),
+                order_id,
+            )
+
+            asks.append(order)
+
+            price += 1.0
+            quantity += 100.0
+            order_id += 1
+
+        bid_counts = [1] * 25
+        ask_counts = [1] * 25
+
+        return OrderBookDepth25(
+            instrument_id=instrument_id or TestIdProviderPyo3.aapl_xnas_id(),
+            bids=bids,
+            asks=asks,
+            bid_counts=bid_counts,
+            ask_counts=ask_counts,
+            flags=flags,
+            sequence=sequence,
+            ts_event=ts_event,
+            ts_init=ts_init,
+        )
+
     @staticmethod
     def quote_tick(
         instrument_id: InstrumentId | None = None,
@@ -158,6 +225,34 @@ class TestDataProviderPyo3:
             ts_event=ts_event,
         )
 
+    @staticmethod
+    def mark_price(
+        instrument_id: InstrumentId | None = None,
+        value: float = 1987.5,
+        ts_event: int = 0,
+        ts_init: int = 0,
+    ) -> MarkPriceUpdate:
+        return MarkPriceUpdate(
+            instrument_id=instrument_id or TestIdProviderPyo3.ethusdt_binance_id(),
+            value=Price.from_str(str(value)),
+            ts_event=ts_event,
+            ts_init=ts_init,
+        )
+
+    @staticmethod
+    def index_price(
+        instrument_id: InstrumentId | None = None,
+        value: float = 1986.5,
+        ts_event: int = 0,
+        ts_init: int = 0,
+    ) -> IndexPriceUpdate:
+        return IndexPriceUpdate(
+            instrument_id=instrument_id or TestIdProviderPyo3.ethusdt_binance_id(),
+            value=Price.from_str(str(value)),
+            ts_event=ts_event,
+            ts_init=ts_init,
+        )
+
     @staticmethod
     def bar_spec_1min_bid() -> BarSpecification:
         return BarSpecification(1, BarAggregation.MINUTE, PriceType.BID)
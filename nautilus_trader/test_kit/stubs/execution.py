@@ -21,6 +21,7 @@ from nautilus_trader.core.datetime import dt_to_unix_nanos
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.model.enums import ContingencyType
 from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import PegPriceType
 from nautilus_trader.model.enums import TimeInForce
 from nautilus_trader.model.enums import TriggerType
 from nautilus_trader.model.identifiers import AccountId
@@ -74,6 +75,8 @@ class TestExecStubs:
         client_order_id: ClientOrderId | None = None,
         expire_time=None,
         tags=None,
+        peg_price_type=None,
+        peg_offset=None,
     ) -> LimitOrder:
         instrument = instrument or _AUDUSD_SIM
         return LimitOrder(
@@ -96,6 +99,8 @@ class TestExecStubs:
             linked_order_ids=None,
             parent_order_id=None,
             tags=tags,
+            peg_price_type=peg_price_type or PegPriceType.NO_PEG,
+            peg_offset=peg_offset,
         )
 
     @staticmethod
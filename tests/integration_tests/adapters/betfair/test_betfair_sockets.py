@@ -23,6 +23,7 @@ import pytest
 from nautilus_trader.adapters.betfair.sockets import BetfairMarketStreamClient
 from nautilus_trader.adapters.betfair.sockets import BetfairOrderStreamClient
 from nautilus_trader.adapters.betfair.sockets import BetfairStreamClient
+from nautilus_trader.config import SocketConnectionConfig
 from tests.integration_tests.adapters.betfair.test_kit import BetfairTestStubs
 
 
@@ -97,3 +98,19 @@ class TestBetfairSockets:
         # Assert
         assert client.is_connected
         await client.disconnect()
+
+    @pytest.mark.asyncio
+    async def test_socket_client_connect_with_invalid_proxy_raises(self, socket_server):
+        # Arrange
+        host, port = socket_server
+        client = BetfairStreamClient(
+            http_client=self.client,
+            message_handler=len,
+            host=host,
+            port=port,
+            connection_config=SocketConnectionConfig(proxy_url="ftp://proxy:21"),
+        )
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            await client.connect()
@@ -18,10 +18,13 @@ import pkgutil
 import msgspec
 
 from nautilus_trader.adapters.binance.common.schemas.market import BinanceTickerData
+from nautilus_trader.adapters.binance.futures.schemas.market import BinanceFuturesForceOrderMsg
+from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
 
 
 ETHUSDT = TestInstrumentProvider.ethusdt_binance()
+BTCUSDT_PERP = TestInstrumentProvider.btcusdt_perp_binance()
 
 
 class TestBinanceWebSocketParsing:
@@ -42,3 +45,26 @@ class TestBinanceWebSocketParsing:
 
         # Assert
         assert result.instrument_id == ETHUSDT.id
+
+    def test_parse_force_order(self):
+        # Arrange
+        raw = pkgutil.get_data(
+            package="tests.integration_tests.adapters.binance.resources.ws_messages",
+            resource="ws_futures_force_order.json",
+        )
+
+        # Act
+        decoder = msgspec.json.Decoder(BinanceFuturesForceOrderMsg)
+        msg = decoder.decode(raw)
+        result = msg.data.parse_to_liquidation_tick(
+            instrument_id=BTCUSDT_PERP.id,
+            ts_init=9999999999999991,
+        )
+
+        # Assert
+        assert result.instrument_id.value == BTCUSDT_PERP.id.value
+        assert result.side == nautilus_pyo3.OrderSide.SELL
+        assert result.price == nautilus_pyo3.Price.from_str("9910")
+        assert result.size == nautilus_pyo3.Quantity.from_str("0.014")
+        assert result.ts_event == 1568014460893000000
+        assert result.ts_init == 9999999999999991
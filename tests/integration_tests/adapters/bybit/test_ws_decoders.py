@@ -48,6 +48,8 @@ from nautilus_trader.adapters.bybit.schemas.ws import BybitWsTickerSpot
 from nautilus_trader.adapters.bybit.schemas.ws import BybitWsTickerSpotMsg
 from nautilus_trader.adapters.bybit.schemas.ws import BybitWsTrade
 from nautilus_trader.adapters.bybit.schemas.ws import BybitWsTradeMsg
+from nautilus_trader.core import nautilus_pyo3
+from nautilus_trader.model.identifiers import InstrumentId
 
 
 class TestBybitWsDecoders:
@@ -97,6 +99,23 @@ class TestBybitWsDecoders:
         assert result.ts == 1673251091822
         assert result.type == "snapshot"
 
+    def test_ws_public_liquidation_parse_to_liquidation_tick(self):
+        item = pkgutil.get_data(
+            "tests.integration_tests.adapters.bybit.resources.ws_messages.public",
+            "ws_liquidation.json",
+        )
+        assert item is not None
+        decoder = msgspec.json.Decoder(BybitWsLiquidationMsg)
+        result = decoder.decode(item)
+        instrument_id = InstrumentId.from_str("GALAUSDT-LINEAR.BYBIT")
+        liquidation = result.data.parse_to_liquidation_tick(instrument_id, ts_init=1)
+        assert liquidation.instrument_id.value == "GALAUSDT-LINEAR.BYBIT"
+        assert liquidation.side == nautilus_pyo3.OrderSide.BUY
+        assert liquidation.price == nautilus_pyo3.Price.from_str("0.03803")
+        assert liquidation.size == nautilus_pyo3.Quantity.from_str("1637")
+        assert liquidation.ts_event == 1673251091822000000
+        assert liquidation.ts_init == 1
+
     def test_ws_public_orderbook_delta(self):
         item = pkgutil.get_data(
             "tests.integration_tests.adapters.bybit.resources.ws_messages.public",
@@ -15,7 +15,9 @@
 
 import asyncio
 import sys
+import tempfile
 import time
+from pathlib import Path
 from decimal import Decimal
 
 import msgspec
@@ -71,7 +73,7 @@ from nautilus_trader.trading.strategy import Strategy
 AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
 
 # Requirements:
-# - A Redis instance listening on the default port 6379
+# - A Redis instance listening on the default port 6379 (SQLite tests are embedded)
 
 pytestmark = pytest.mark.skipif(
     sys.platform == "win32",
@@ -80,6 +82,9 @@ pytestmark = pytest.mark.skipif(
 
 
 class TestCacheDatabaseAdapter:
+    def database_config(self) -> DatabaseConfig:
+        return DatabaseConfig()  # default redis
+
     def setup(self):
         # Fixture Setup
         self.clock = TestClock()
@@ -130,7 +135,7 @@ class TestCacheDatabaseAdapter:
             trader_id=self.trader_id,
             instance_id=UUID4(),
             serializer=MsgSpecSerializer(encoding=msgspec.msgpack, timestamps_as_str=True),
-            config=CacheConfig(database=DatabaseConfig()),
+            config=CacheConfig(database=self.database_config()),
         )
 
     def teardown(self):
@@ -1055,6 +1060,12 @@ class TestCacheDatabaseAdapter:
         assert result == {}
 
 
+class TestSqliteCacheDatabaseAdapter(TestCacheDatabaseAdapter):
+    def database_config(self) -> DatabaseConfig:
+        self.tmp_dir = tempfile.TemporaryDirectory()
+        return DatabaseConfig(type="sqlite", name=str(Path(self.tmp_dir.name) / "cache.db"))
+
+
 class TestRedisCacheDatabaseIntegrity:
     def setup(self):
         # Fixture Setup
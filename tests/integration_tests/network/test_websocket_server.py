@@ -0,0 +1,50 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.core.nautilus_pyo3 import WebSocketClient
+from nautilus_trader.core.nautilus_pyo3 import WebSocketConfig
+from nautilus_trader.core.nautilus_pyo3 import WebSocketServer
+from nautilus_trader.test_kit.functions import eventually
+
+
+@pytest.mark.asyncio()
+async def test_server_send_and_publish():
+    # Arrange
+    received = []
+    server = await WebSocketServer.bind(
+        "127.0.0.1:0",
+        handler=lambda client_id, message: received.append((client_id, message)),
+    )
+    store = []
+    config = WebSocketConfig(f"ws://{server.local_addr}", store.append, [])
+    client = await WebSocketClient.connect(config)
+    await eventually(lambda: server.client_count == 1)
+
+    # Act
+    await client.send_text("hello")
+    await eventually(lambda: received == [(1, "hello")])
+    server.send(1, "direct")
+    server.subscribe(1, "book")
+    server.publish("book", "update")
+    server.publish("trades", "ignored")
+
+    # Assert
+    await eventually(lambda: store == [b"direct", b"update"])
+    await client.disconnect()
+    await eventually(lambda: server.client_count == 0)
+    assert server.subscriber_count("book") == 0
+    server.shutdown()
@@ -0,0 +1,152 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.analysis.execution_reports import SOH
+from nautilus_trader.analysis.execution_reports import ExecutionReportExporter
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.common.factories import OrderFactory
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.identifiers import PositionId
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import TraderId
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.model.position import Position
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.events import TestEventStubs
+
+
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+
+
+class TestExecutionReportExporter:
+    def setup(self):
+        # Fixture Setup
+        self.exporter = ExecutionReportExporter(sender_comp_id="NAUTILUS", target_comp_id="ARCHIVE")
+        self.order_factory = OrderFactory(
+            trader_id=TraderId("TESTER-000"),
+            strategy_id=StrategyId("S-001"),
+            clock=TestClock(),
+        )
+
+    def _events(self) -> list:
+        order = self.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("0.80010"),
+        )
+        order.apply(TestEventStubs.order_submitted(order))
+        order.apply(TestEventStubs.order_accepted(order))
+
+        fill1 = TestEventStubs.order_filled(
+            order,
+            instrument=AUDUSD_SIM,
+            trade_id=TradeId("E-1"),
+            position_id=PositionId("P-1"),
+            last_qty=Quantity.from_int(40_000),
+            last_px=Price.from_str("0.80000"),
+            ts_filled_ns=1_000_000_000,
+        )
+        order.apply(fill1)
+        position = Position(instrument=AUDUSD_SIM, fill=fill1)
+        opened = TestEventStubs.position_opened(position)
+
+        fill2 = TestEventStubs.order_filled(
+            order,
+            instrument=AUDUSD_SIM,
+            trade_id=TradeId("E-2"),
+            position_id=PositionId("P-1"),
+            last_qty=Quantity.from_int(60_000),
+            last_px=Price.from_str("0.80010"),
+            ts_filled_ns=2_000_000_000,
+        )
+        order.apply(fill2)
+        position.apply(fill2)
+        changed = TestEventStubs.position_changed(position)
+
+        # Position events may be recorded before their fills
+        return [order.init_event, opened, fill1, changed, fill2]
+
+    def test_generate_report_with_no_events_returns_empty_dataframe(self):
+        # Arrange, Act
+        report = self.exporter.generate_report([])
+
+        # Assert
+        assert report.empty
+
+    def test_generate_report(self):
+        # Arrange, Act
+        report = self.exporter.generate_report(self._events())
+
+        # Assert
+        assert len(report) == 2
+        assert list(report.index) == ["E-1", "E-2"]
+        assert report.loc["E-1"]["cum_qty"] == 40_000
+        assert report.loc["E-1"]["leaves_qty"] == 60_000
+        assert report.loc["E-2"]["cum_qty"] == 100_000
+        assert report.loc["E-2"]["leaves_qty"] == 0
+        assert report.loc["E-2"]["avg_px"] == pytest.approx(0.80006)
+        assert report.loc["E-1"]["position_signed_qty"] == 40_000
+        assert report.loc["E-2"]["position_signed_qty"] == 100_000
+        assert report.loc["E-2"]["position_side"] == "LONG"
+
+    def test_generate_fix_messages(self):
+        # Arrange, Act
+        messages = self.exporter.generate_fix_messages(self._events())
+
+        # Assert
+        assert len(messages) == 2
+        fields1 = dict(f.split("=", 1) for f in messages[0].split(SOH) if f)
+        fields2 = dict(f.split("=", 1) for f in messages[1].split(SOH) if f)
+        assert fields1["8"] == "FIX.4.4"
+        assert fields1["35"] == "8"
+        assert fields1["34"] == "1"
+        assert fields1["17"] == "E-1"
+        assert fields1["39"] == "1"  # Partially filled
+        assert fields1["54"] == "1"
+        assert fields1["40"] == "2"
+        assert fields1["32"] == "40000"
+        assert fields1["151"] == "60000"
+        assert fields1["52"] == "19700101-00:00:01.000"
+        assert fields2["34"] == "2"
+        assert fields2["39"] == "2"  # Filled
+        assert fields2["14"] == "100000"
+        assert fields2["6"] == "0.800060"
+
+    def test_fix_message_length_and_checksum(self):
+        # Arrange, Act
+        message = self.exporter.generate_fix_messages(self._events())[0]
+
+        # Assert
+        head, checksum = message[:-1].rsplit(f"{SOH}10=", 1)
+        header_end = message.index(SOH, message.index("9=")) + 1
+        assert int(checksum) == sum((head + SOH).encode()) % 256
+        assert int(message.split(SOH)[1][2:]) == len(head + SOH) - header_end
+
+    def test_write_csv_and_fix(self, tmp_path):
+        # Arrange
+        events = self._events()
+
+        # Act
+        self.exporter.write_csv(events, str(tmp_path / "fills.csv"))
+        self.exporter.write_fix(events, str(tmp_path / "fills.fix"))
+
+        # Assert
+        assert (tmp_path / "fills.csv").read_text().startswith("exec_id,")
+        assert len((tmp_path / "fills.fix").read_text().splitlines()) == 2
@@ -0,0 +1,98 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+from nautilus_trader.backtest.auction import allocate_auction_volume
+from nautilus_trader.backtest.auction import calculate_uncrossing_price
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.objects import Price
+
+
+class TestAuction:
+    def test_calculate_uncrossing_price_maximizes_volume(self) -> None:
+        # Arrange
+        bids = [(Price.from_str("102.00"), Decimal(100)), (Price.from_str("101.00"), Decimal(200))]
+        asks = [(Price.from_str("100.00"), Decimal(150)), (Price.from_str("101.00"), Decimal(100))]
+
+        # Act
+        result = calculate_uncrossing_price(bids, asks)
+
+        # Assert
+        assert result == (Price.from_str("101.00"), Decimal(250))
+
+    def test_calculate_uncrossing_price_when_not_crossed_returns_none(self) -> None:
+        # Arrange
+        bids = [(Price.from_str("100.00"), Decimal(100))]
+        asks = [(Price.from_str("101.00"), Decimal(100))]
+
+        # Act
+        result = calculate_uncrossing_price(bids, asks)
+
+        # Assert
+        assert result is None
+
+    def test_calculate_uncrossing_price_tie_breaks_on_minimum_surplus(self) -> None:
+        # Arrange
+        bids = [(Price.from_str("102.00"), Decimal(100)), (Price.from_str("101.00"), Decimal(50))]
+        asks = [(Price.from_str("100.00"), Decimal(100))]
+
+        # Act
+        result = calculate_uncrossing_price(bids, asks)
+
+        # Assert
+        assert result == (Price.from_str("102.00"), Decimal(100))
+
+    def test_calculate_uncrossing_price_tie_breaks_on_reference_price(self) -> None:
+        # Arrange
+        bids = [(Price.from_str("102.00"), Decimal(100))]
+        asks = [(Price.from_str("100.00"), Decimal(100))]
+
+        # Act
+        result = calculate_uncrossing_price(bids, asks, reference_price=Price.from_str("101.90"))
+
+        # Assert
+        assert result == (Price.from_str("102.00"), Decimal(100))
+
+    def test_calculate_uncrossing_price_with_only_market_orders_uses_reference_price(self) -> None:
+        # Arrange
+        bids = [(None, Decimal(100))]
+        asks = [(None, Decimal(60))]
+
+        # Act
+        result = calculate_uncrossing_price(bids, asks, reference_price=Price.from_str("101.00"))
+
+        # Assert
+        assert result == (Price.from_str("101.00"), Decimal(60))
+
+    def test_allocate_auction_volume_in_priority_order(self) -> None:
+        # Arrange
+        orders = [
+            ("A", Price.from_str("101.00"), Decimal(100)),
+            ("B", Price.from_str("102.00"), Decimal(50)),
+            ("C", None, Decimal(30)),
+            ("D", Price.from_str("100.00"), Decimal(100)),
+        ]
+
+        # Act
+        allocations = allocate_auction_volume(
+            orders,
+            OrderSide.BUY,
+            Price.from_str("101.00"),
+            Decimal(120),
+        )
+
+        # Assert
+        assert allocations == [("C", Decimal(30)), ("B", Decimal(50)), ("A", Decimal(40))]
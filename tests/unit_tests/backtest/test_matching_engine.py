@@ -19,15 +19,29 @@ import pytest
 
 from nautilus_trader.backtest.matching_engine import OrderMatchingEngine
 from nautilus_trader.backtest.models import FillModel
+from nautilus_trader.backtest.models import QueuePositionFillModel
+from nautilus_trader.backtest.models import ShortableSharesModel
+from nautilus_trader.backtest.models import VolumeParticipationFillModel
 from nautilus_trader.common.component import MessageBus
 from nautilus_trader.common.component import TestClock
+from nautilus_trader.model.data import InstrumentStatus
 from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import AuctionPhase
 from nautilus_trader.model.enums import BookType
+from nautilus_trader.model.enums import HaltReason
 from nautilus_trader.model.enums import MarketStatus
 from nautilus_trader.model.enums import OmsType
 from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import PegPriceType
 from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.events import OrderAccepted
 from nautilus_trader.model.events import OrderFilled
+from nautilus_trader.model.events import OrderRejected
+from nautilus_trader.model.events import OrderUpdated
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.model.orders import LimitOrder
 from nautilus_trader.model.orders import MarketOrder
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
 from nautilus_trader.test_kit.stubs.component import TestComponentStubs
@@ -91,6 +105,360 @@ class TestOrderMatchingEngine:
         self.matching_engine.process_status(MarketStatus.PAUSE)
         self.matching_engine.process_status(MarketStatus.OPEN)
 
+    def test_process_instrument_status_auction(self) -> None:
+        # Arrange
+        status = InstrumentStatus(
+            instrument_id=self.instrument_id,
+            status=MarketStatus.AUCTION,
+            ts_event=0,
+            ts_init=0,
+            auction_phase=AuctionPhase.VOLATILITY_INTERRUPTION,
+        )
+
+        # Act
+        self.matching_engine.process_instrument_status(status)
+
+        # Assert
+        assert self.matching_engine.market_status == MarketStatus.AUCTION
+        assert self.matching_engine.auction_phase == AuctionPhase.VOLATILITY_INTERRUPTION
+        assert self.matching_engine.halt_reason == HaltReason.NOT_HALTED
+
+    def test_process_instrument_status_reopen_clears_halt(self) -> None:
+        # Arrange
+        self.matching_engine.process_instrument_status(
+            InstrumentStatus(
+                instrument_id=self.instrument_id,
+                status=MarketStatus.HALT,
+                ts_event=0,
+                ts_init=0,
+                halt_reason=HaltReason.CIRCUIT_BREAKER,
+            ),
+        )
+
+        # Act
+        self.matching_engine.process_status(MarketStatus.REOPEN)
+
+        # Assert
+        assert self.matching_engine.market_status == MarketStatus.REOPEN
+        assert self.matching_engine.halt_reason == HaltReason.NOT_HALTED
+
+    def test_process_market_order_when_halted_rejects(self) -> None:
+        # Arrange
+        messages: list[Any] = []
+        self.msgbus.register("ExecEngine.process", messages.append)
+        self.matching_engine.process_status(MarketStatus.HALT)
+
+        order: MarketOrder = TestExecStubs.market_order(instrument=self.instrument)
+        self.cache.add_order(order)
+
+        # Act
+        self.matching_engine.process_order(order, self.account_id)
+
+        # Assert
+        assert len(messages) == 1
+        assert isinstance(messages[0], OrderRejected)
+
+    def test_process_limit_order_outside_price_band_rejects(self) -> None:
+        # Arrange
+        messages: list[Any] = []
+        self.msgbus.register("ExecEngine.process", messages.append)
+        self.matching_engine.process_instrument_status(
+            InstrumentStatus(
+                instrument_id=self.instrument_id,
+                status=MarketStatus.OPEN,
+                ts_event=0,
+                ts_init=0,
+                limit_up=Price.from_str("1100.00"),
+                limit_down=Price.from_str("900.00"),
+            ),
+        )
+
+        order: LimitOrder = TestExecStubs.limit_order(
+            instrument=self.instrument,
+            price=Price.from_str("1200.00"),
+        )
+        self.cache.add_order(order)
+
+        # Act
+        self.matching_engine.process_order(order, self.account_id)
+
+        # Assert
+        assert len(messages) == 1
+        assert isinstance(messages[0], OrderRejected)
+
+    def test_process_trade_tick_reaching_price_band_halts_market(self) -> None:
+        # Arrange
+        statuses: list[Any] = []
+        self.msgbus.register("DataEngine.process", statuses.append)
+        self.matching_engine.process_instrument_status(
+            InstrumentStatus(
+                instrument_id=self.instrument_id,
+                status=MarketStatus.OPEN,
+                ts_event=0,
+                ts_init=0,
+                limit_up=Price.from_str("1100.00"),
+                limit_down=Price.from_str("900.00"),
+            ),
+        )
+        tick = TestDataStubs.trade_tick(instrument=self.instrument, price=1100.00)
+
+        # Act
+        self.matching_engine.process_trade_tick(tick)
+
+        # Assert
+        assert self.matching_engine.market_status == MarketStatus.HALT
+        assert self.matching_engine.halt_reason == HaltReason.LIMIT_UP_LIMIT_DOWN
+        assert len(statuses) == 1
+        assert isinstance(statuses[0], InstrumentStatus)
+        assert statuses[0].status == MarketStatus.HALT
+        assert statuses[0].halt_reason == HaltReason.LIMIT_UP_LIMIT_DOWN
+        assert statuses[0].limit_up == Price.from_str("1100.00")
+
+    @pytest.mark.parametrize(
+        ("peg_price_type", "order_side", "expected"),
+        [
+            [PegPriceType.MID_POINT, OrderSide.BUY, Price.from_str("1000.50")],
+            [PegPriceType.MID_POINT, OrderSide.SELL, Price.from_str("1001.50")],
+            [PegPriceType.PRIMARY, OrderSide.BUY, Price.from_str("1000.50")],
+            [PegPriceType.PRIMARY, OrderSide.SELL, Price.from_str("1001.50")],
+        ],
+    )
+    def test_process_pegged_limit_order_reprices_to_peg(
+        self,
+        peg_price_type: PegPriceType,
+        order_side: OrderSide,
+        expected: Price,
+    ) -> None:
+        # Arrange
+        messages: list[Any] = []
+        self.msgbus.register("ExecEngine.process", messages.append)
+        quote = TestDataStubs.quote_tick(
+            instrument=self.instrument,
+            bid_price=1000.00,
+            ask_price=1002.00,
+        )
+        self.matching_engine.process_quote_tick(quote)
+
+        order: LimitOrder = TestExecStubs.limit_order(
+            instrument=self.instrument,
+            order_side=order_side,
+            price=Price.from_str("900.00" if order_side == OrderSide.BUY else "1100.00"),
+            peg_price_type=peg_price_type,
+            peg_offset=Price.from_str("0.50"),
+        )
+        self.cache.add_order(order)
+
+        # Act
+        self.matching_engine.process_order(order, self.account_id)
+
+        # Assert
+        assert len(messages) == 2
+        assert isinstance(messages[1], OrderUpdated)
+        assert messages[1].price == expected
+
+    def test_queue_position_fill_model_fills_after_volume_ahead_consumed(self) -> None:
+        # Arrange
+        messages: list[Any] = []
+        self.msgbus.register("ExecEngine.process", messages.append)
+        self.matching_engine.set_fill_model(QueuePositionFillModel())
+        self.matching_engine.process_quote_tick(
+            TestDataStubs.quote_tick(
+                instrument=self.instrument,
+                bid_price=1000.00,
+                ask_price=1002.00,
+                bid_size=100.0,
+                ask_size=100.0,
+            ),
+        )
+
+        order: LimitOrder = TestExecStubs.limit_order(
+            instrument=self.instrument,
+            order_side=OrderSide.BUY,
+            price=Price.from_str("1000.00"),
+            quantity=Quantity.from_str("10.000"),
+        )
+        self.cache.add_order(order)
+        self.matching_engine.process_order(order, self.account_id)
+
+        # Act
+        self.matching_engine.process_trade_tick(
+            TestDataStubs.trade_tick(
+                instrument=self.instrument,
+                price=1000.00,
+                size=60.0,
+                aggressor_side=AggressorSide.SELLER,
+            ),
+        )
+        messages_after_first_trade = list(messages)
+        self.matching_engine.process_trade_tick(
+            TestDataStubs.trade_tick(
+                instrument=self.instrument,
+                price=1000.00,
+                size=50.0,
+                aggressor_side=AggressorSide.SELLER,
+            ),
+        )
+
+        # Assert
+        assert len(messages_after_first_trade) == 1
+        assert isinstance(messages_after_first_trade[0], OrderAccepted)
+        assert len(messages) == 2
+        assert isinstance(messages[1], OrderFilled)
+        assert messages[1].last_qty == Quantity.from_str("10.000")
+        assert messages[1].last_px == Price.from_str("1000.00")
+
+    def test_volume_participation_fill_model_partially_fills_from_trade_prints(self) -> None:
+        # Arrange
+        messages: list[Any] = []
+        self.msgbus.register("ExecEngine.process", messages.append)
+        self.matching_engine.set_fill_model(VolumeParticipationFillModel(participation_rate=0.1))
+        self.matching_engine.process_quote_tick(
+            TestDataStubs.quote_tick(
+                instrument=self.instrument,
+                bid_price=1000.00,
+                ask_price=1002.00,
+            ),
+        )
+
+        order: LimitOrder = TestExecStubs.limit_order(
+            instrument=self.instrument,
+            order_side=OrderSide.BUY,
+            price=Price.from_str("1001.00"),
+            quantity=Quantity.from_str("10.000"),
+        )
+        self.cache.add_order(order)
+        self.matching_engine.process_order(order, self.account_id)
+
+        # Act
+        self.matching_engine.process_trade_tick(
+            TestDataStubs.trade_tick(
+                instrument=self.instrument,
+                price=1000.50,
+                size=20.0,
+                aggressor_side=AggressorSide.SELLER,
+            ),
+        )
+
+        # Assert
+        assert len(messages) == 2
+        assert isinstance(messages[0], OrderAccepted)
+        assert isinstance(messages[1], OrderFilled)
+        assert messages[1].last_qty == Quantity.from_str("2.000")
+        assert messages[1].last_px == Price.from_str("1001.00")
+
+    def test_auction_uncrosses_limit_order_at_clearing_price(self) -> None:
+        # Arrange
+        messages: list[Any] = []
+        self.msgbus.register("ExecEngine.process", messages.append)
+        self.matching_engine.process_quote_tick(
+            TestDataStubs.quote_tick(
+                instrument=self.instrument,
+                bid_price=1000.00,
+                ask_price=1002.00,
+                bid_size=100.0,
+                ask_size=100.0,
+            ),
+        )
+        self.matching_engine.process_status(MarketStatus.AUCTION)
+
+        order: LimitOrder = TestExecStubs.limit_order(
+            instrument=self.instrument,
+            order_side=OrderSide.BUY,
+            price=Price.from_str("1003.00"),
+            quantity=Quantity.from_str("10.000"),
+        )
+        self.cache.add_order(order)
+        self.matching_engine.process_order(order, self.account_id)
+        messages_during_auction = list(messages)
+
+        # Act
+        self.matching_engine.process_status(MarketStatus.OPEN)
+
+        # Assert
+        assert len(messages_during_auction) == 1
+        assert isinstance(messages_during_auction[0], OrderAccepted)
+        assert len(messages) == 2
+        assert isinstance(messages[1], OrderFilled)
+        assert messages[1].last_qty == Quantity.from_str("10.000")
+        assert messages[1].last_px == Price.from_str("1002.00")
+
+    def test_auction_accepts_market_order_and_fills_at_uncross(self) -> None:
+        # Arrange
+        messages: list[Any] = []
+        self.msgbus.register("ExecEngine.process", messages.append)
+        self.matching_engine.process_quote_tick(
+            TestDataStubs.quote_tick(
+                instrument=self.instrument,
+                bid_price=1000.00,
+                ask_price=1002.00,
+                bid_size=100.0,
+                ask_size=100.0,
+            ),
+        )
+        self.matching_engine.process_instrument_status(
+            InstrumentStatus(
+                instrument_id=self.instrument_id,
+                status=MarketStatus.AUCTION,
+                ts_event=0,
+                ts_init=0,
+                auction_phase=AuctionPhase.OPENING,
+            ),
+        )
+
+        order: MarketOrder = TestExecStubs.market_order(
+            instrument=self.instrument,
+            order_side=OrderSide.BUY,
+            quantity=Quantity.from_str("5.000"),
+        )
+        self.cache.add_order(order)
+        self.matching_engine.process_order(order, self.account_id)
+
+        # Act
+        self.matching_engine.process_status(MarketStatus.OPEN)
+
+        # Assert
+        assert len(messages) == 2
+        assert isinstance(messages[0], OrderAccepted)
+        assert isinstance(messages[1], OrderFilled)
+        assert messages[1].last_qty == Quantity.from_str("5.000")
+        assert messages[1].last_px == Price.from_str("1002.00")
+
+    def test_process_short_sell_without_locate_rejects(self) -> None:
+        # Arrange
+        messages: list[Any] = []
+        self.msgbus.register("ExecEngine.process", messages.append)
+        instrument = TestInstrumentProvider.equity()
+        self.cache.add_instrument(instrument)
+        matching_engine = OrderMatchingEngine(
+            instrument=instrument,
+            raw_id=1,
+            fill_model=FillModel(),
+            book_type=BookType.L1_MBP,
+            oms_type=OmsType.NETTING,
+            account_type=AccountType.MARGIN,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            shortable_shares_model=ShortableSharesModel(
+                locates={instrument.id: Quantity.from_int(50)},
+            ),
+        )
+
+        order: MarketOrder = TestExecStubs.market_order(
+            instrument=instrument,
+            order_side=OrderSide.SELL,
+            quantity=Quantity.from_int(100),
+        )
+        self.cache.add_order(order)
+
+        # Act
+        matching_engine.process_order(order, self.account_id)
+
+        # Assert
+        assert len(messages) == 1
+        assert isinstance(messages[0], OrderRejected)
+        assert "no locate" in messages[0].reason
+
     def test_process_market_on_close_order(self) -> None:
         order: MarketOrder = TestExecStubs.market_order(
             instrument=self.instrument,
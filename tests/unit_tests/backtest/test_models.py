@@ -13,8 +13,56 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from decimal import Decimal
+
+import pytest
+
+from nautilus_trader.backtest.models import EmpiricalLatency
+from nautilus_trader.backtest.models import FeeModel
 from nautilus_trader.backtest.models import FillModel
+from nautilus_trader.backtest.models import FixedLatency
 from nautilus_trader.backtest.models import LatencyModel
+from nautilus_trader.backtest.models import LogNormalLatency
+from nautilus_trader.backtest.models import MakerTakerFeeModel
+from nautilus_trader.backtest.models import NormalLatency
+from nautilus_trader.backtest.models import PerContractFeeModel
+from nautilus_trader.backtest.models import QueuePositionFillModel
+from nautilus_trader.backtest.models import ShortableSharesModel
+from nautilus_trader.backtest.models import TieredFeeModel
+from nautilus_trader.backtest.models import VolumeParticipationFillModel
+from nautilus_trader.model.currencies import EUR
+from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.events import TestEventStubs
+from nautilus_trader.test_kit.stubs.execution import TestExecStubs
+
+
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+AAPL_XNAS = TestInstrumentProvider.equity()
+
+
+def _partially_filled_order(instrument, liquidity_side, quantity=1_000_000):
+    order = TestExecStubs.make_accepted_order(
+        instrument=instrument,
+        quantity=instrument.make_qty(quantity),
+        price=instrument.make_price(1.0),
+    )
+    order.apply(
+        TestEventStubs.order_filled(
+            order=order,
+            instrument=instrument,
+            last_qty=instrument.make_qty(1),
+            liquidity_side=liquidity_side,
+        ),
+    )
+    return order
 
 
 class TestFillModel:
@@ -44,7 +92,7 @@ class TestFillModel:
         )
 
         # Act, Assert
-        assert not fill_model.is_stop_filled()
+        assert fill_model.is_stop_filled()
 
     def test_is_limit_filled_with_random_seed(self):
         # Arrange
@@ -54,7 +102,7 @@ class TestFillModel:
         )
 
         # Act, Assert
-        assert not fill_model.is_limit_filled()
+        assert fill_model.is_limit_filled()
 
     def test_is_slipped_with_random_seed(self):
         # Arrange
@@ -64,7 +112,189 @@ class TestFillModel:
         )
 
         # Act, Assert
-        assert not fill_model.is_slipped()
+        assert fill_model.is_slipped()
+
+    def test_same_random_seed_produces_identical_fills(self):
+        # Arrange
+        fill_model1 = FillModel(prob_fill_on_limit=0.5, random_seed=1)
+        fill_model2 = FillModel(prob_fill_on_limit=0.5, random_seed=1)
+
+        # Act
+        result1 = [fill_model1.is_limit_filled() for _ in range(100)]
+        result2 = [fill_model2.is_limit_filled() for _ in range(100)]
+
+        # Assert
+        assert result1 == result2
+        assert True in result1
+        assert False in result1
+
+    def test_reseed_restarts_sequence(self):
+        # Arrange
+        fill_model = FillModel(prob_slippage=0.5, random_seed=1)
+        expected = [fill_model.is_slipped() for _ in range(10)]
+
+        # Act
+        fill_model.reseed(1)
+
+        # Assert
+        assert [fill_model.is_slipped() for _ in range(10)] == expected
+
+
+class TestQueuePositionFillModel:
+    def setup(self):
+        # Fixture Setup
+        self.fill_model = QueuePositionFillModel()
+        self.order = TestExecStubs.limit_order(
+            instrument=AUDUSD_SIM,
+            order_side=OrderSide.BUY,
+            price=Price.from_str("1.00000"),
+        )
+        self.fill_model.add_order(self.order, 100_000)
+
+    def test_untracked_order_has_no_queue_ahead(self):
+        # Arrange
+        self.fill_model.remove_order(self.order.client_order_id)
+
+        # Act, Assert
+        assert self.fill_model.queue_ahead(self.order.client_order_id) == 0
+        assert self.fill_model.client_order_ids() == []
+
+    def test_trade_at_price_consumes_queue_ahead(self):
+        # Arrange, Act
+        fills = self.fill_model.process_trade(
+            Price.from_str("1.00000"),
+            60_000,
+            AggressorSide.SELLER,
+        )
+
+        # Assert
+        assert fills == []
+        assert self.fill_model.queue_ahead(self.order.client_order_id) == 40_000
+
+    def test_trade_at_price_beyond_queue_returns_available_volume(self):
+        # Arrange, Act
+        fills = self.fill_model.process_trade(
+            Price.from_str("1.00000"),
+            150_000,
+            AggressorSide.NO_AGGRESSOR,
+        )
+
+        # Assert
+        assert fills == [(self.order.client_order_id, 50_000)]
+        assert self.fill_model.queue_ahead(self.order.client_order_id) == 0
+
+    def test_trade_lifting_asks_does_not_consume_bid_queue(self):
+        # Arrange, Act
+        fills = self.fill_model.process_trade(
+            Price.from_str("1.00000"),
+            150_000,
+            AggressorSide.BUYER,
+        )
+
+        # Assert
+        assert fills == []
+        assert self.fill_model.queue_ahead(self.order.client_order_id) == 100_000
+
+    def test_trade_through_price_consumes_entire_queue(self):
+        # Arrange, Act
+        fills = self.fill_model.process_trade(
+            Price.from_str("0.99990"),
+            1_000,
+            AggressorSide.SELLER,
+        )
+
+        # Assert
+        assert fills == []
+        assert self.fill_model.queue_ahead(self.order.client_order_id) == 0
+
+    def test_update_level_size_reduces_queue_ahead_for_cancels(self):
+        # Arrange, Act
+        self.fill_model.update_level_size(self.order.client_order_id, 30_000)
+        self.fill_model.update_level_size(self.order.client_order_id, 80_000)
+
+        # Assert
+        assert self.fill_model.queue_ahead(self.order.client_order_id) == 30_000
+
+
+class TestVolumeParticipationFillModel:
+    def setup(self):
+        # Fixture Setup
+        self.fill_model = VolumeParticipationFillModel(participation_rate=0.25)
+        self.order = TestExecStubs.limit_order(
+            instrument=AUDUSD_SIM,
+            order_side=OrderSide.SELL,
+            price=Price.from_str("1.00000"),
+            quantity=Quantity.from_int(100_000),
+        )
+        self.fill_model.add_order(self.order, 0)
+
+    @pytest.mark.parametrize("participation_rate", [0.0, -0.5, 1.5])
+    def test_invalid_participation_rate_raises_value_error(self, participation_rate: float):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            VolumeParticipationFillModel(participation_rate=participation_rate)
+
+    def test_book_fills_are_never_allowed(self):
+        # Arrange, Act, Assert
+        assert not self.fill_model.is_book_fill_allowed(True)
+        assert not self.fill_model.is_book_fill_allowed(False)
+
+    @pytest.mark.parametrize(
+        ("price", "aggressor_side", "expected"),
+        [
+            ["1.00000", AggressorSide.BUYER, 25_000],
+            ["1.00010", AggressorSide.BUYER, 25_000],
+            ["1.00000", AggressorSide.NO_AGGRESSOR, 25_000],
+            ["0.99990", AggressorSide.BUYER, None],
+            ["1.00000", AggressorSide.SELLER, None],
+        ],
+    )
+    def test_process_trade_caps_fill_volume_by_participation(
+        self,
+        price: str,
+        aggressor_side: AggressorSide,
+        expected: float | None,
+    ):
+        # Arrange, Act
+        fills = self.fill_model.process_trade(Price.from_str(price), 100_000, aggressor_side)
+
+        # Assert
+        if expected is None:
+            assert fills == []
+        else:
+            assert fills == [(self.order.client_order_id, expected)]
+
+    def test_process_trade_allocates_one_budget_per_print_in_priority_order(self):
+        # Arrange
+        order2 = TestExecStubs.limit_order(
+            instrument=AUDUSD_SIM,
+            order_side=OrderSide.SELL,
+            price=Price.from_str("1.00000"),
+            quantity=Quantity.from_int(10_000),
+            client_order_id=ClientOrderId("O-2"),
+        )
+        order3 = TestExecStubs.limit_order(
+            instrument=AUDUSD_SIM,
+            order_side=OrderSide.SELL,
+            price=Price.from_str("0.99990"),
+            quantity=Quantity.from_int(5_000),
+            client_order_id=ClientOrderId("O-3"),
+        )
+        self.fill_model.add_order(order2, 0)
+        self.fill_model.add_order(order3, 0)
+
+        # Act
+        fills = self.fill_model.process_trade(
+            Price.from_str("1.00000"),
+            100_000,
+            AggressorSide.BUYER,
+        )
+
+        # Assert
+        assert fills == [
+            (order3.client_order_id, 5_000),
+            (self.order.client_order_id, 20_000),
+        ]
 
 
 class TestExchangeLatency:
@@ -76,3 +306,281 @@ class TestExchangeLatency:
         assert latency.insert_latency_nanos == self.NANOSECONDS_IN_MILLISECOND
         assert latency.update_latency_nanos == self.NANOSECONDS_IN_MILLISECOND
         assert latency.cancel_latency_nanos == self.NANOSECONDS_IN_MILLISECOND
+
+
+    def test_latency_without_distributions_is_fixed(self):
+        # Arrange
+        latency = LatencyModel(
+            base_latency_nanos=1_000,
+            insert_latency_nanos=100,
+            update_latency_nanos=200,
+            cancel_latency_nanos=300,
+            data_latency_nanos=50,
+        )
+
+        # Act, Assert
+        assert latency.insert_latency() == 1_100
+        assert latency.update_latency() == 1_200
+        assert latency.cancel_latency() == 1_300
+        assert latency.data_latency() == 50
+
+    def test_latency_with_fixed_distribution_adds_jitter(self):
+        # Arrange
+        latency = LatencyModel(
+            base_latency_nanos=1_000,
+            insert_distribution=FixedLatency(500),
+            data_distribution=FixedLatency(250),
+        )
+
+        # Act, Assert
+        assert latency.insert_latency() == 1_500
+        assert latency.update_latency() == 1_000
+        assert latency.data_latency() == 250
+
+    def test_same_random_seed_produces_identical_latencies(self):
+        # Arrange
+        latency1 = LatencyModel(
+            insert_distribution=NormalLatency(1_000_000, 100_000),
+            cancel_distribution=LogNormalLatency(1_000_000, 0.5),
+            random_seed=42,
+        )
+        latency2 = LatencyModel(
+            insert_distribution=NormalLatency(1_000_000, 100_000),
+            cancel_distribution=LogNormalLatency(1_000_000, 0.5),
+            random_seed=42,
+        )
+
+        # Act
+        result1 = [(latency1.insert_latency(), latency1.cancel_latency()) for _ in range(10)]
+        result2 = [(latency2.insert_latency(), latency2.cancel_latency()) for _ in range(10)]
+
+        # Assert
+        assert result1 == result2
+        assert len({insert for insert, _ in result1}) > 1
+
+    def test_normal_latency_is_truncated_at_zero(self):
+        # Arrange
+        latency = LatencyModel(
+            base_latency_nanos=0,
+            insert_distribution=NormalLatency(0, 1_000_000),
+            random_seed=1,
+        )
+
+        # Act, Assert
+        assert all(latency.insert_latency() >= 0 for _ in range(100))
+
+    def test_empirical_latency_samples_from_observations(self):
+        # Arrange
+        distribution = EmpiricalLatency.from_timestamps(
+            sent_ns=[0, 1_000, 2_000],
+            received_ns=[500, 1_700, 2_900],
+        )
+        latency = LatencyModel(
+            base_latency_nanos=0,
+            update_distribution=distribution,
+            random_seed=7,
+        )
+
+        # Act
+        samples = {latency.update_latency() for _ in range(100)}
+
+        # Assert
+        assert distribution.samples == [500, 700, 900]
+        assert samples <= {500, 700, 900}
+
+    def test_empirical_latency_with_received_before_sent_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            EmpiricalLatency.from_timestamps(sent_ns=[1_000], received_ns=[500])
+
+
+class TestFeeModels:
+    @pytest.mark.parametrize("liquidity_side", [LiquiditySide.MAKER, LiquiditySide.TAKER])
+    def test_maker_taker_fee_model(self, liquidity_side: LiquiditySide) -> None:
+        # Arrange
+        fee_model = MakerTakerFeeModel()
+        order = _partially_filled_order(AUDUSD_SIM, liquidity_side)
+
+        # Act
+        commission = fee_model.get_commission(
+            order,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00000"),
+            AUDUSD_SIM,
+        )
+
+        # Assert
+        assert commission == Money(2.00, USD)
+
+    def test_tiered_fee_model_with_invalid_tiers_raises_value_error(self) -> None:
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            TieredFeeModel([])
+        with pytest.raises(ValueError):
+            TieredFeeModel([(1_000_000, Decimal("0.0001"), Decimal("0.0002"))])
+        with pytest.raises(ValueError):
+            TieredFeeModel(
+                [
+                    (0, Decimal("0.0002"), Decimal("0.0004")),
+                    (0, Decimal("0.0001"), Decimal("0.0002")),
+                ],
+            )
+
+    def test_tiered_fee_model_moves_up_tiers_with_volume(self) -> None:
+        # Arrange
+        fee_model = TieredFeeModel(
+            [
+                (0, Decimal("0.0002"), Decimal("0.0004")),
+                (1_000_000, Decimal("0.0001"), Decimal("0.0002")),
+            ],
+        )
+        order = _partially_filled_order(AUDUSD_SIM, LiquiditySide.TAKER)
+        qty = Quantity.from_int(1_000_000)
+        px = Price.from_str("1.00000")
+
+        # Act
+        first = fee_model.get_commission(order, qty, px, AUDUSD_SIM)
+        second = fee_model.get_commission(order, qty, px, AUDUSD_SIM)
+
+        # Assert
+        assert first == Money(400.00, USD)
+        assert second == Money(200.00, USD)
+        assert fee_model.cumulative_volume == 2_000_000
+        assert fee_model.current_tier()[0] == 1_000_000
+
+    def test_tiered_fee_model_reset(self) -> None:
+        # Arrange
+        fee_model = TieredFeeModel(
+            [
+                (0, Decimal("0.0002"), Decimal("0.0004")),
+                (1_000_000, Decimal("0.0001"), Decimal("0.0002")),
+            ],
+        )
+        order = _partially_filled_order(AUDUSD_SIM, LiquiditySide.MAKER)
+        fee_model.get_commission(
+            order,
+            Quantity.from_int(2_000_000),
+            Price.from_str("1.00000"),
+            AUDUSD_SIM,
+        )
+
+        # Act
+        fee_model.reset()
+
+        # Assert
+        assert fee_model.cumulative_volume == 0
+        assert fee_model.current_tier()[0] == 0
+
+    @pytest.mark.parametrize(
+        ("liquidity_side", "fill_qty", "expected"),
+        [
+            [LiquiditySide.MAKER, 100, Money(1.00, USD)],
+            [LiquiditySide.TAKER, 100, Money(1.50, USD)],
+            [LiquiditySide.TAKER, 10, Money(1.00, USD)],
+        ],
+    )
+    def test_per_contract_fee_model(
+        self,
+        liquidity_side: LiquiditySide,
+        fill_qty: int,
+        expected: Money,
+    ) -> None:
+        # Arrange
+        fee_model = PerContractFeeModel(
+            maker_commission=Money(0.01, USD),
+            taker_commission=Money(0.015, USD),
+            min_commission=Money(1.00, USD),
+        )
+        order = _partially_filled_order(AAPL_XNAS, liquidity_side, quantity=1_000)
+
+        # Act
+        commission = fee_model.get_commission(
+            order,
+            Quantity.from_int(fill_qty),
+            Price.from_str("150.00"),
+            AAPL_XNAS,
+        )
+
+        # Assert
+        assert commission == expected
+
+    def test_per_contract_fee_model_with_mismatched_currencies_raises_value_error(self) -> None:
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            PerContractFeeModel(
+                maker_commission=Money(0.01, USD),
+                taker_commission=Money(0.01, EUR),
+            )
+
+    def test_custom_fee_model_from_python(self) -> None:
+        # Arrange
+        class FlatFeeModel(FeeModel):
+            def get_commission(self, order, fill_qty, fill_px, instrument):
+                return Money(5.00, USD)
+
+        order = _partially_filled_order(AUDUSD_SIM, LiquiditySide.TAKER)
+
+        # Act
+        commission = FlatFeeModel().get_commission(
+            order,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00000"),
+            AUDUSD_SIM,
+        )
+
+        # Assert
+        assert commission == Money(5.00, USD)
+
+
+class TestShortableSharesModel:
+    def test_instrument_without_locate_is_easy_to_borrow(self) -> None:
+        # Arrange
+        model = ShortableSharesModel()
+
+        # Act, Assert
+        assert model.available(AAPL_XNAS.id) is None
+        assert model.is_shortable(AAPL_XNAS.id, Decimal(1_000_000))
+
+    def test_borrow_and_return_shares_update_availability(self) -> None:
+        # Arrange
+        model = ShortableSharesModel(locates={AAPL_XNAS.id: Quantity.from_int(100)})
+
+        # Act
+        model.borrow(AAPL_XNAS.id, Decimal(60))
+        shortable_after_borrow = model.is_shortable(AAPL_XNAS.id, Decimal(50))
+        model.return_shares(AAPL_XNAS.id, Decimal(20))
+
+        # Assert
+        assert not shortable_after_borrow
+        assert model.available(AAPL_XNAS.id) == Decimal(60)
+        assert model.is_shortable(AAPL_XNAS.id, Decimal(50))
+
+    def test_reset_restores_initial_locates(self) -> None:
+        # Arrange
+        model = ShortableSharesModel(locates={AAPL_XNAS.id: Quantity.from_int(100)})
+        model.borrow(AAPL_XNAS.id, Decimal(100))
+
+        # Act
+        model.reset()
+
+        # Assert
+        assert model.available(AAPL_XNAS.id) == Decimal(100)
+
+    def test_calculate_borrow_fee(self) -> None:
+        # Arrange
+        model = ShortableSharesModel(
+            borrow_rates={AAPL_XNAS.id: 0.36},
+            default_borrow_rate=0.01,
+        )
+
+        # Act
+        fee = model.calculate_borrow_fee(
+            AAPL_XNAS,
+            Quantity.from_int(100),
+            Price.from_str("100.00"),
+            days=2,
+        )
+
+        # Assert
+        assert model.borrow_rate(AAPL_XNAS.id) == 0.36
+        assert fee == Money(20.00, USD)
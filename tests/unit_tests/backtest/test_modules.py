@@ -17,6 +17,8 @@ import pandas as pd
 
 from nautilus_trader.backtest.engine import BacktestEngine
 from nautilus_trader.backtest.modules import FXRolloverInterestConfig
+from nautilus_trader.backtest.modules import FundingPaymentConfig
+from nautilus_trader.backtest.modules import FundingPaymentModule
 from nautilus_trader.backtest.modules import FXRolloverInterestModule
 from nautilus_trader.backtest.modules import SimulationModule
 from nautilus_trader.common.component import Logger
@@ -35,6 +37,7 @@ from nautilus_trader.test_kit.providers import TestInstrumentProvider
 
 
 USDJPY_SIM = TestInstrumentProvider.default_fx_ccy("USD/JPY")
+BTCUSDT_PERP_BINANCE = TestInstrumentProvider.btcusdt_perp_binance()
 
 
 class TestSimulationModules:
@@ -68,6 +71,28 @@ class TestSimulationModules:
         [venue] = engine.list_venues()
         assert venue
 
+    def test_funding_payment_module_uses_recorded_rates(self):
+        # Arrange
+        rate_data = pd.DataFrame(
+            {
+                "instrument_id": [BTCUSDT_PERP_BINANCE.id.value] * 2,
+                "rate": [0.0001, 0.0003],
+            },
+            index=pd.to_datetime(["2024-01-01 00:00", "2024-01-01 08:00"], utc=True),
+        )
+        module = FundingPaymentModule(FundingPaymentConfig(rate_data=rate_data, default_rate=0.0002))
+        engine = self.create_engine(modules=[module])
+        ts_funding = pd.Timestamp("2024-01-01 12:00", tz="UTC").value
+
+        # Act
+        rate = module.funding_rate(BTCUSDT_PERP_BINANCE.id, ts_funding)
+        default_rate = module.funding_rate(USDJPY_SIM.id, ts_funding)
+
+        # Assert
+        assert engine.list_venues()
+        assert rate == 0.0003
+        assert default_rate == 0.0002
+
     def test_python_module(self):
         # Arrange
         class PythonModule(SimulationModule):
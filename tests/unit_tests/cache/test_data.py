@@ -17,6 +17,7 @@ from decimal import Decimal
 
 import pytest
 
+from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.model.currencies import AUD
 from nautilus_trader.model.currencies import JPY
 from nautilus_trader.model.currencies import USD
@@ -93,6 +94,74 @@ class TestCache:
         # Arrange, Act, Assert
         assert self.cache.bar(TestDataStubs.bartype_gbpusd_1sec_mid()) is None
 
+    def test_funding_rate_when_no_funding_rates_returns_none(self):
+        # Arrange, Act, Assert
+        assert self.cache.funding_rate(ETHUSDT_BINANCE.id) is None
+        assert not self.cache.has_funding_rate(ETHUSDT_BINANCE.id)
+
+    def test_add_funding_rate_retains_latest_update(self):
+        # Arrange
+        instrument_id = nautilus_pyo3.InstrumentId.from_str(ETHUSDT_BINANCE.id.value)
+        update1 = nautilus_pyo3.FundingRateUpdate(
+            instrument_id=instrument_id,
+            rate=Decimal("0.0001"),
+            next_funding_ns=28_800_000_000_000,
+            ts_event=1,
+            ts_init=1,
+        )
+        update2 = nautilus_pyo3.FundingRateUpdate(
+            instrument_id=instrument_id,
+            rate=Decimal("0.0002"),
+            next_funding_ns=28_800_000_000_000,
+            ts_event=2,
+            ts_init=2,
+        )
+
+        # Act
+        self.cache.add_funding_rate(update1)
+        self.cache.add_funding_rate(update2)
+
+        # Assert
+        assert self.cache.has_funding_rate(ETHUSDT_BINANCE.id)
+        assert self.cache.funding_rate(ETHUSDT_BINANCE.id) == update2
+
+    def test_greeks_when_no_greeks_returns_none(self):
+        # Arrange, Act, Assert
+        assert self.cache.greeks(ETHUSDT_BINANCE.id) is None
+        assert not self.cache.has_greeks(ETHUSDT_BINANCE.id)
+
+    def test_add_greeks_retains_latest_greeks(self):
+        # Arrange
+        instrument_id = nautilus_pyo3.InstrumentId.from_str(ETHUSDT_BINANCE.id.value)
+        greeks1 = nautilus_pyo3.GreeksData(
+            instrument_id=instrument_id,
+            delta=0.5,
+            gamma=0.25,
+            vega=12.5,
+            theta=-3.75,
+            implied_vol=0.625,
+            ts_event=1,
+            ts_init=1,
+        )
+        greeks2 = nautilus_pyo3.GreeksData(
+            instrument_id=instrument_id,
+            delta=0.55,
+            gamma=0.2,
+            vega=12.0,
+            theta=-3.5,
+            implied_vol=0.6,
+            ts_event=2,
+            ts_init=2,
+        )
+
+        # Act
+        self.cache.add_greeks(greeks1)
+        self.cache.add_greeks(greeks2)
+
+        # Assert
+        assert self.cache.has_greeks(ETHUSDT_BINANCE.id)
+        assert self.cache.greeks(ETHUSDT_BINANCE.id) == greeks2
+
     def test_quote_tick_count_for_unknown_instrument_returns_zero(self):
         # Arrange, Act, Assert
         assert self.cache.quote_tick_count(AUDUSD_SIM.id) == 0
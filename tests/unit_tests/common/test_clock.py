@@ -26,6 +26,7 @@ from nautilus_trader.common.component import TestClock
 from nautilus_trader.common.component import TimeEvent
 from nautilus_trader.common.component import TimeEventHandler
 from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.model.identifiers import Venue
 from nautilus_trader.test_kit.stubs.data import UNIX_EPOCH
 
 
@@ -624,6 +625,23 @@ class TestLiveClock:
         assert isinstance(result, datetime)
         assert result.tzinfo == pytz.utc
 
+    def test_venue_offset_when_no_observations_returns_none(self):
+        # Arrange, Act, Assert
+        assert self.clock.venue_offset_ns(Venue("BINANCE")) is None
+        assert self.clock.venue_latency_ns(Venue("BINANCE")) is None
+
+    def test_record_venue_time_estimates_offset_and_latency(self):
+        # Arrange
+        venue = Venue("BINANCE")
+
+        # Act
+        self.clock.record_venue_time(venue, 1_000, 1_800, 1_400)  # Higher latency
+        self.clock.record_venue_time(venue, 2_000, 2_550, 2_100)
+
+        # Assert
+        assert self.clock.venue_offset_ns(venue) == 500
+        assert self.clock.venue_latency_ns(venue) == 50
+
     def test_local_now(self):
         # Arrange, Act
         result = self.clock.local_now(pytz.timezone("Australia/Sydney"))
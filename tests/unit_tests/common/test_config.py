@@ -15,6 +15,8 @@
 
 from nautilus_trader.config import ActorFactory
 from nautilus_trader.config import ImportableActorConfig
+from nautilus_trader.config import MetricsConfig
+from nautilus_trader.config import TradingNodeConfig
 from nautilus_trader.test_kit.mocks.actors import MockActor
 
 
@@ -36,3 +38,28 @@ class TestActorFactory:
         # Assert
         assert isinstance(actor, MockActor)
         assert repr(actor.config) == "MockActorConfig(component_id='MyActor')"
+
+
+class TestMetricsConfig:
+    def test_defaults(self):
+        # Arrange, Act
+        config = MetricsConfig()
+
+        # Assert
+        assert config.host == "127.0.0.1"
+        assert config.port == 9090
+
+    def test_node_config_metrics_disabled_by_default(self):
+        # Arrange, Act
+        config = TradingNodeConfig()
+
+        # Assert
+        assert config.metrics is None
+
+    def test_node_config_with_metrics(self):
+        # Arrange, Act
+        config = TradingNodeConfig(metrics=MetricsConfig(host="0.0.0.0", port=9100))
+
+        # Assert
+        assert config.metrics.host == "0.0.0.0"
+        assert config.metrics.port == 9100
@@ -16,6 +16,8 @@
 import pytest
 
 from nautilus_trader.common.component import Logger
+from nautilus_trader.common.component import clear_component_log_level
+from nautilus_trader.common.component import set_component_log_level
 from nautilus_trader.common.enums import LogColor
 from nautilus_trader.common.enums import LogLevel
 from nautilus_trader.common.enums import log_level_from_str
@@ -135,3 +137,20 @@ class TestLoggerTests:
 
         # Assert
         assert True  # No exceptions raised
+
+    def test_set_and_clear_component_log_level(self):
+        # Arrange
+        logger = Logger(name="TEST_LOGGER")
+
+        # Act
+        set_component_log_level("TEST_LOGGER", LogLevel.DEBUG)
+        logger.debug("This is a log message.")
+        clear_component_log_level("TEST_LOGGER")
+
+        # Assert
+        assert True  # No exceptions raised
+
+    def test_set_component_log_level_with_empty_component_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            set_component_log_level("", LogLevel.DEBUG)
@@ -0,0 +1,122 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.common.component import RandomGenerator
+
+
+class TestRandomGenerator:
+    def test_instantiate_with_seed(self):
+        # Arrange, Act
+        rng = RandomGenerator(42)
+
+        # Assert
+        assert rng.seed == 42
+        assert repr(rng) == "RandomGenerator(seed=42)"
+
+    def test_same_seed_produces_identical_sequence(self):
+        # Arrange
+        rng1 = RandomGenerator(42)
+        rng2 = RandomGenerator(42)
+
+        # Act
+        values1 = [rng1.next_u64() for _ in range(100)]
+        values2 = [rng2.next_u64() for _ in range(100)]
+
+        # Assert
+        assert values1 == values2
+
+    def test_sequence_is_stable(self):
+        # Arrange
+        rng = RandomGenerator(0)
+
+        # Act
+        values = [rng.next_u64() for _ in range(3)]
+
+        # Assert
+        assert values == [
+            11_091_344_671_253_066_420,
+            13_793_997_310_169_335_082,
+            1_900_383_378_846_508_768,
+        ]
+
+    def test_reseed_restarts_sequence(self):
+        # Arrange
+        rng = RandomGenerator(7)
+        expected = rng.random()
+        rng.random()
+
+        # Act
+        rng.reseed(7)
+
+        # Assert
+        assert rng.random() == expected
+
+    def test_fork_is_independent_of_draw_count(self):
+        # Arrange
+        rng = RandomGenerator(42)
+        fork1 = rng.fork("SIM")
+        rng.next_u64()
+
+        # Act
+        fork2 = rng.fork("SIM")
+
+        # Assert
+        assert fork1.seed == fork2.seed
+        assert fork1.seed != rng.fork("BINANCE").seed
+
+    def test_random_in_unit_range(self):
+        # Arrange
+        rng = RandomGenerator(42)
+
+        # Act
+        values = [rng.random() for _ in range(1_000)]
+
+        # Assert
+        assert all(0.0 <= value < 1.0 for value in values)
+
+    @pytest.mark.parametrize(
+        ("probability", "expected"),
+        [
+            [0.0, False],
+            [1.0, True],
+        ],
+    )
+    def test_gen_bool_edge_probabilities(self, probability: float, expected: bool):
+        # Arrange
+        rng = RandomGenerator(42)
+
+        # Act, Assert
+        assert all(rng.gen_bool(probability) == expected for _ in range(100))
+
+    def test_gen_range(self):
+        # Arrange
+        rng = RandomGenerator(42)
+
+        # Act
+        values = [rng.gen_range(10, 20) for _ in range(1_000)]
+
+        # Assert
+        assert min(values) >= 10
+        assert max(values) < 20
+
+    def test_gen_range_with_empty_range_raises(self):
+        # Arrange
+        rng = RandomGenerator(42)
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            rng.gen_range(5, 5)
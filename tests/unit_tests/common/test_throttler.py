@@ -17,6 +17,7 @@ from datetime import timedelta
 
 from nautilus_trader.common.component import TestClock
 from nautilus_trader.common.component import Throttler
+from nautilus_trader.common.component import TokenBucket
 
 
 class TestBufferingThrottler:
@@ -305,3 +306,59 @@ class TestDroppingThrottler:
         assert self.throttler.used() == 0
         assert self.throttler.recv_count == 7
         assert self.throttler.sent_count == 6
+
+
+class TestTokenBucket:
+    def setup(self):
+        # Fixture Setup
+        self.bucket = TokenBucket(
+            name="Bucket",
+            limit=2,
+            interval=timedelta(seconds=1),
+            burst=4,
+        )
+
+    def test_instantiation(self):
+        # Arrange, Act, Assert
+        assert self.bucket.name == "Bucket"
+        assert self.bucket.limit == 2
+        assert self.bucket.interval == timedelta(seconds=1)
+        assert self.bucket.burst == 4
+        assert self.bucket.available(0) == 4
+
+    def test_burst_defaults_to_limit(self):
+        # Arrange, Act
+        bucket = TokenBucket(name="Bucket", limit=3, interval=timedelta(seconds=1))
+
+        # Assert
+        assert bucket.burst == 3
+
+    def test_consume_up_to_burst_then_limits(self):
+        # Arrange, Act
+        results = [self.bucket.try_consume(0) for _ in range(5)]
+
+        # Assert
+        assert results == [True, True, True, True, False]
+        assert self.bucket.retry_after_ns(0) == 500_000_000
+
+    def test_refills_at_sustained_rate(self):
+        # Arrange
+        for _ in range(4):
+            self.bucket.try_consume(0)
+
+        # Act, Assert
+        assert not self.bucket.try_consume(250_000_000)
+        assert self.bucket.retry_after_ns(250_000_000) == 250_000_000
+        assert self.bucket.try_consume(500_000_000)
+        assert self.bucket.available(10_000_000_000) == 4  # Capped at burst
+
+    def test_reset(self):
+        # Arrange
+        for _ in range(4):
+            self.bucket.try_consume(0)
+
+        # Act
+        self.bucket.reset()
+
+        # Assert
+        assert self.bucket.available(0) == 4
@@ -13,9 +13,14 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+import pandas as pd
 import pytest
 
 from nautilus_trader.core.nautilus_pyo3 import convert_to_snake_case
+from nautilus_trader.core.nautilus_pyo3 import format_iso8601
+from nautilus_trader.core.nautilus_pyo3 import format_iso8601_local
+from nautilus_trader.core.nautilus_pyo3 import iso8601_to_unix_nanos
+from nautilus_trader.core.nautilus_pyo3 import local_iso8601_to_unix_nanos
 
 
 @pytest.mark.parametrize(
@@ -51,3 +56,67 @@ def test_convert_to_snake_case(input: str, expected: str) -> None:
 
     # Assert
     assert result == expected
+
+
+@pytest.mark.parametrize(
+    "value",
+    [
+        "1970-01-01",
+        "2024-01-02T03:04:05Z",
+        "2024-01-02 03:04:05",
+        "2024-01-02T03:04:05.123456789Z",
+        "2024-01-02T03:04:05+01:00",
+        "2024-01-02T03:04:05-05:30",
+    ],
+)
+def test_iso8601_to_unix_nanos_matches_pandas(value: str) -> None:
+    # Arrange
+    timestamp = pd.Timestamp(value)
+    if timestamp.tzinfo is None:
+        timestamp = timestamp.tz_localize("UTC")
+
+    # Act
+    result = iso8601_to_unix_nanos(value)
+
+    # Assert
+    assert result == timestamp.value
+
+
+@pytest.mark.parametrize("value", ["", "2024-13-01", "2024-01-02T24:00:00", "not a timestamp"])
+def test_iso8601_to_unix_nanos_with_invalid_value_raises(value: str) -> None:
+    # Arrange, Act, Assert
+    with pytest.raises(ValueError):
+        iso8601_to_unix_nanos(value)
+
+
+def test_local_iso8601_to_unix_nanos() -> None:
+    # Arrange, Act
+    result = local_iso8601_to_unix_nanos("2024-07-01T09:30:00", "America/New_York")
+
+    # Assert
+    assert result == pd.Timestamp("2024-07-01T09:30:00", tz="America/New_York").value
+
+
+@pytest.mark.parametrize(
+    ("precision", "expected"),
+    [
+        [0, "2024-01-02T03:04:05Z"],
+        [3, "2024-01-02T03:04:05.123Z"],
+        [6, "2024-01-02T03:04:05.123456Z"],
+        [9, "2024-01-02T03:04:05.123456789Z"],
+    ],
+)
+def test_format_iso8601(precision: int, expected: str) -> None:
+    # Arrange, Act
+    result = format_iso8601(1_704_164_645_123_456_789, precision)
+
+    # Assert
+    assert result == expected
+
+
+def test_format_iso8601_local() -> None:
+    # Arrange, Act
+    result = format_iso8601_local(1_704_164_645_000_000_000, "America/New_York", 3)
+
+    # Assert
+    assert result == "2024-01-01T22:04:05.000-05:00"
@@ -15,7 +15,10 @@
 
 import pickle
 
+import pytest
+
 from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.core.uuid import set_uuid_version
 
 
 class TestUUID:
@@ -67,3 +70,23 @@ class TestUUID:
         assert isinstance(result, UUID4)
         assert len(str(result)) == 36
         assert len(str(result).replace("-", "")) == 32
+
+    def test_set_uuid_version_with_invalid_version_raises(self):
+        # Arrange, Act, Assert
+        with pytest.raises(KeyError):
+            set_uuid_version(5)
+
+    def test_set_uuid_version_v7_generates_time_ordered_values(self):
+        # Arrange
+        set_uuid_version(7)
+
+        try:
+            # Act
+            uuids = [UUID4() for _ in range(100)]
+        finally:
+            set_uuid_version(4)
+
+        # Assert
+        assert all(uuid.value[14] == "7" for uuid in uuids)
+        assert [uuid.value for uuid in uuids] == sorted(uuid.value for uuid in uuids)
+        assert UUID4().value[14] == "4"
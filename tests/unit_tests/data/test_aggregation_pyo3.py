@@ -0,0 +1,89 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.core.nautilus_pyo3 import AggregationSource
+from nautilus_trader.core.nautilus_pyo3 import BarAggregation
+from nautilus_trader.core.nautilus_pyo3 import BarAggregator
+from nautilus_trader.core.nautilus_pyo3 import BarSpecification
+from nautilus_trader.core.nautilus_pyo3 import BarType
+from nautilus_trader.core.nautilus_pyo3 import PriceType
+from nautilus_trader.core.nautilus_pyo3 import Quantity
+from nautilus_trader.test_kit.rust.data_pyo3 import TestDataProviderPyo3
+from nautilus_trader.test_kit.rust.instruments_pyo3 import TestInstrumentProviderPyo3
+
+
+ETHUSDT_BINANCE = TestInstrumentProviderPyo3.ethusdt_binance()
+
+
+def _bar_type(step: int, aggregation: BarAggregation) -> BarType:
+    return BarType(
+        ETHUSDT_BINANCE.id,
+        BarSpecification(step, aggregation, PriceType.LAST),
+        AggregationSource.INTERNAL,
+    )
+
+
+class TestBarAggregatorPyo3:
+    def test_volume_bar_aggregator_splits_update(self):
+        # Arrange
+        aggregator = BarAggregator(ETHUSDT_BINANCE, _bar_type(1, BarAggregation.VOLUME))
+
+        # Act
+        bars1 = aggregator.handle_trade_tick(TestDataProviderPyo3.trade_tick(size=0.6, ts_event=1))
+        bars2 = aggregator.handle_trade_tick(TestDataProviderPyo3.trade_tick(size=0.6, ts_event=2))
+
+        # Assert
+        assert bars1 == []
+        assert len(bars2) == 1
+        assert bars2[0].volume == Quantity.from_str("1.00000")
+
+    def test_tick_bar_aggregator_replays_trades(self):
+        # Arrange
+        aggregator = BarAggregator(ETHUSDT_BINANCE, _bar_type(2, BarAggregation.TICK))
+        trades = [TestDataProviderPyo3.trade_tick(ts_event=i) for i in range(5)]
+
+        # Act
+        bars = aggregator.handle_trade_ticks(trades)
+
+        # Assert
+        assert len(bars) == 2
+        assert bars[0].bar_type == aggregator.bar_type
+
+    def test_time_bar_aggregator_build_until(self):
+        # Arrange
+        aggregator = BarAggregator(ETHUSDT_BINANCE, _bar_type(1, BarAggregation.SECOND))
+        aggregator.handle_trade_tick(TestDataProviderPyo3.trade_tick(ts_event=1))
+
+        # Act
+        bars = aggregator.build_until(1_000_000_000)
+
+        # Assert
+        assert len(bars) == 1
+        assert bars[0].ts_event == 1_000_000_000
+
+    def test_build_until_when_not_time_aggregation_raises(self):
+        # Arrange
+        aggregator = BarAggregator(ETHUSDT_BINANCE, _bar_type(1, BarAggregation.TICK))
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            aggregator.build_until(1_000_000_000)
+
+    def test_instantiate_with_zero_step_raises(self):
+        # Act, Assert
+        with pytest.raises(ValueError):
+            BarAggregator(ETHUSDT_BINANCE, _bar_type(0, BarAggregation.VOLUME))
@@ -20,6 +20,7 @@ import pytest
 from nautilus_trader.backtest.data_client import BacktestMarketDataClient
 from nautilus_trader.common.component import MessageBus
 from nautilus_trader.common.component import TestClock
+from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.core.data import Data
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.data.engine import DataEngine
@@ -1545,6 +1546,87 @@ class TestDataEngine:
         assert handler1 == [tick]
         assert handler2 == [tick]
 
+    def test_execute_subscribe_open_interest_then_adds_subscription(self):
+        # Arrange
+        self.data_engine.register_client(self.binance_client)
+        self.binance_client.start()
+
+        subscribe = Subscribe(
+            client_id=ClientId(BINANCE.value),
+            venue=BINANCE,
+            data_type=DataType(
+                nautilus_pyo3.OpenInterestUpdate,
+                metadata={"instrument_id": ETHUSDT_BINANCE.id},
+            ),
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.data_engine.execute(subscribe)
+
+        # Assert
+        assert self.data_engine.subscribed_open_interest() == [ETHUSDT_BINANCE.id]
+
+    def test_process_open_interest_when_subscriber_then_sends_to_registered_handler(self):
+        # Arrange
+        self.data_engine.register_client(self.binance_client)
+        self.binance_client.start()
+
+        handler = []
+        self.msgbus.subscribe(topic="data.open_interest.BINANCE.ETHUSDT", handler=handler.append)
+
+        subscribe = Subscribe(
+            client_id=ClientId(BINANCE.value),
+            venue=BINANCE,
+            data_type=DataType(
+                nautilus_pyo3.OpenInterestUpdate,
+                metadata={"instrument_id": ETHUSDT_BINANCE.id},
+            ),
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        self.data_engine.execute(subscribe)
+
+        update = nautilus_pyo3.OpenInterestUpdate(
+            nautilus_pyo3.InstrumentId.from_str(ETHUSDT_BINANCE.id.value),
+            nautilus_pyo3.Quantity.from_str("125000.500"),
+            0,
+            0,
+        )
+
+        # Act
+        self.data_engine.process_open_interest(update)
+
+        # Assert
+        assert handler == [update]
+        assert self.data_engine.data_count == 1
+
+    def test_process_greeks_then_caches_and_sends_to_registered_handler(self):
+        # Arrange
+        handler = []
+        self.msgbus.subscribe(topic="data.greeks.BINANCE.ETHUSDT", handler=handler.append)
+
+        greeks = nautilus_pyo3.GreeksData(
+            nautilus_pyo3.InstrumentId.from_str(ETHUSDT_BINANCE.id.value),
+            0.5,
+            0.25,
+            12.5,
+            -3.75,
+            0.625,
+            0,
+            0,
+        )
+
+        # Act
+        self.data_engine.process_greeks(greeks)
+
+        # Assert
+        assert handler == [greeks]
+        assert self.cache.greeks(ETHUSDT_BINANCE.id) == greeks
+        assert self.data_engine.data_count == 1
+
     def test_process_trade_tick_when_synthetic_then_sends_to_registered_handlers(
         self,
     ):
@@ -229,6 +229,81 @@ class TestLiveDataEngine:
         assert self.engine.data_qsize() == 1
         assert self.engine.data_count == 0
 
+    @pytest.mark.asyncio
+    async def test_data_qsize_at_max_with_drop_oldest_policy_drops_data(self):
+        # Arrange
+        self.msgbus.deregister(endpoint="DataEngine.execute", handler=self.engine.execute)
+        self.msgbus.deregister(endpoint="DataEngine.process", handler=self.engine.process)
+        self.msgbus.deregister(endpoint="DataEngine.request", handler=self.engine.request)
+        self.msgbus.deregister(endpoint="DataEngine.response", handler=self.engine.response)
+
+        self.engine = LiveDataEngine(
+            loop=self.loop,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=LiveDataEngineConfig(qsize=1, data_queue_policy="drop_oldest"),
+        )
+
+        data = Data(1_000_000_000, 1_000_000_000)
+
+        # Act
+        self.engine.process(data)
+        self.engine.process(data)  # Add over max size
+        self.engine.process(data)  # Add over max size
+        await asyncio.sleep(0.1)
+
+        # Assert
+        assert self.engine.data_qsize() == 1
+        assert self.engine.data_dropped_count() == 2
+        assert self.engine.data_count == 0
+
+    @pytest.mark.asyncio
+    async def test_process_with_conflate_policy_conflates_quotes_by_instrument(self):
+        # Arrange
+        self.msgbus.deregister(endpoint="DataEngine.execute", handler=self.engine.execute)
+        self.msgbus.deregister(endpoint="DataEngine.process", handler=self.engine.process)
+        self.msgbus.deregister(endpoint="DataEngine.request", handler=self.engine.request)
+        self.msgbus.deregister(endpoint="DataEngine.response", handler=self.engine.response)
+
+        self.engine = LiveDataEngine(
+            loop=self.loop,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=LiveDataEngineConfig(qsize=10, data_queue_policy="conflate"),
+        )
+
+        quote1 = TestDataStubs.quote_tick(
+            instrument=ETHUSDT_BINANCE,
+            bid_price=100.0,
+            ask_price=100.5,
+        )
+        quote2 = TestDataStubs.quote_tick(
+            instrument=ETHUSDT_BINANCE,
+            bid_price=101.0,
+            ask_price=101.5,
+        )
+        quote3 = TestDataStubs.quote_tick(
+            instrument=BTCUSDT_BINANCE,
+            bid_price=50_000.0,
+            ask_price=50_001.0,
+        )
+        trade = TestDataStubs.trade_tick(instrument=ETHUSDT_BINANCE)
+
+        # Act
+        self.engine.process(quote1)
+        self.engine.process(trade)
+        self.engine.process(trade)  # Trades are never conflated
+        self.engine.process(quote3)
+        self.engine.process(quote2)
+        await asyncio.sleep(0.1)
+
+        # Assert
+        assert self.engine.data_qsize() == 4
+        assert self.engine.data_conflated_count() == 1
+        assert self.engine._data_queue.get_nowait() == quote2
+
     @pytest.mark.asyncio
     async def test_start(self):
         # Arrange, Act
@@ -0,0 +1,125 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.live.queue import BoundedQueue
+
+
+def _key(item: tuple[str | None, int]) -> str | None:
+    return item[0]
+
+
+class TestBoundedQueue:
+    def test_instantiate_with_invalid_policy_raises_key_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(KeyError):
+            BoundedQueue(maxsize=1, policy="drop_newest")
+
+    def test_instantiate_conflate_without_key_raises_type_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(TypeError):
+            BoundedQueue(maxsize=1, policy="conflate")
+
+    @pytest.mark.asyncio
+    async def test_enqueue_when_full_with_block_policy_returns_false(self):
+        # Arrange
+        queue = BoundedQueue(maxsize=1)
+
+        # Act
+        first = queue.enqueue(1)
+        second = queue.enqueue(2)
+
+        # Assert
+        assert queue.policy == "block"
+        assert first
+        assert not second
+        assert queue.qsize() == 1
+        assert queue.dropped_count == 0
+
+    @pytest.mark.asyncio
+    async def test_enqueue_when_full_with_drop_oldest_policy_drops_oldest(self):
+        # Arrange
+        queue = BoundedQueue(maxsize=2, policy="drop_oldest")
+
+        # Act
+        for i in range(4):
+            assert queue.enqueue(i)
+
+        # Assert
+        assert queue.qsize() == 2
+        assert queue.dropped_count == 2
+        assert queue.get_nowait() == 2
+        assert queue.get_nowait() == 3
+
+    @pytest.mark.asyncio
+    async def test_enqueue_with_drop_oldest_policy_never_drops_sentinel(self):
+        # Arrange
+        queue = BoundedQueue(maxsize=1, policy="drop_oldest")
+        queue.enqueue(None)
+
+        # Act
+        result = queue.enqueue(1)
+
+        # Assert
+        assert not result
+        assert queue.dropped_count == 0
+        assert queue.get_nowait() is None
+
+    @pytest.mark.asyncio
+    async def test_enqueue_with_conflate_policy_replaces_pending_item_in_place(self):
+        # Arrange
+        queue = BoundedQueue(maxsize=2, policy="conflate", key=_key)
+
+        # Act
+        queue.enqueue(("A", 1))
+        queue.enqueue(("B", 1))
+        queue.enqueue(("A", 2))  # Conflates even when full
+
+        # Assert
+        assert queue.qsize() == 2
+        assert queue.conflated_count == 1
+        assert queue.get_nowait() == ("A", 2)
+        assert queue.get_nowait() == ("B", 1)
+
+    @pytest.mark.asyncio
+    async def test_enqueue_with_conflate_policy_after_get_puts_new_item(self):
+        # Arrange
+        queue = BoundedQueue(maxsize=2, policy="conflate", key=_key)
+        queue.enqueue(("A", 1))
+        queue.get_nowait()
+
+        # Act
+        queue.enqueue(("A", 2))
+        queue.enqueue(("A", 3))
+
+        # Assert
+        assert queue.qsize() == 1
+        assert queue.conflated_count == 1
+        assert queue.get_nowait() == ("A", 3)
+
+    @pytest.mark.asyncio
+    async def test_enqueue_with_conflate_policy_and_no_key_blocks_when_full(self):
+        # Arrange
+        queue = BoundedQueue(maxsize=1, policy="conflate", key=_key)
+        queue.enqueue((None, 1))
+
+        # Act
+        result = queue.enqueue((None, 2))
+
+        # Assert
+        assert not result
+        assert queue.conflated_count == 0
+        assert queue.get_nowait() == (None, 1)
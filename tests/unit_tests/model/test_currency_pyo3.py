@@ -184,6 +184,65 @@ class TestCurrency:
         assert result.precision == 2  # Correct precision from built-in currency
         assert result.currency_type == CurrencyType.FIAT
 
+    def test_try_register_adds_currency_to_internal_currency_map(self):
+        # Arrange
+        currency = Currency(
+            code="WIF",
+            precision=6,
+            iso4217=0,
+            name="dogwifhat",
+            currency_type=CurrencyType.CRYPTO,
+        )
+
+        # Act
+        Currency.try_register(currency)
+
+        # Assert
+        assert Currency.is_registered("WIF")
+        assert Currency.from_str("WIF", strict=True) == currency
+
+    def test_try_register_with_conflicting_currency_raises_value_error(self):
+        # Arrange
+        another_aud = Currency(
+            code="AUD",
+            precision=8,  # <-- Different precision
+            iso4217=0,
+            name="AUD",
+            currency_type=CurrencyType.CRYPTO,
+        )
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            Currency.try_register(another_aud)
+
+        assert Currency.from_str("AUD").precision == 2
+
+    def test_register_new_with_defaults(self):
+        # Arrange, Act
+        result = Currency.register_new("BONK", 5)
+
+        # Assert
+        assert result.code == "BONK"
+        assert result.precision == 5
+        assert result.iso4217 == 0
+        assert result.name == "BONK"
+        assert result.currency_type == CurrencyType.CRYPTO
+        assert Currency.from_str("BONK", strict=True) == result
+
+    def test_register_new_when_identical_is_idempotent(self):
+        # Arrange
+        first = Currency.register_new("POPCAT", 4, name="Popcat")
+
+        # Act
+        second = Currency.register_new("POPCAT", 4, name="Popcat")
+
+        # Assert
+        assert first == second
+
+    def test_is_registered_when_unknown(self):
+        # Arrange, Act, Assert
+        assert not Currency.is_registered("NOT_A_CURRENCY")
+
     def test_from_internal_map_when_unknown(self):
         # Arrange, Act, Assert
         result = Currency.from_str("SOME_CURRENCY")
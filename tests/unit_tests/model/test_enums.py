@@ -19,6 +19,7 @@ from nautilus_trader.model.enums import AccountType
 from nautilus_trader.model.enums import AggregationSource
 from nautilus_trader.model.enums import AggressorSide
 from nautilus_trader.model.enums import AssetClass
+from nautilus_trader.model.enums import AuctionPhase
 from nautilus_trader.model.enums import BarAggregation
 from nautilus_trader.model.enums import BookAction
 from nautilus_trader.model.enums import BookType
@@ -34,8 +35,10 @@ from nautilus_trader.model.enums import OptionKind
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.enums import OrderStatus
 from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import PegPriceType
 from nautilus_trader.model.enums import PositionSide
 from nautilus_trader.model.enums import PriceType
+from nautilus_trader.model.enums import RoundingMode
 from nautilus_trader.model.enums import TimeInForce
 from nautilus_trader.model.enums import TradingState
 from nautilus_trader.model.enums import TrailingOffsetType
@@ -48,6 +51,8 @@ from nautilus_trader.model.enums import aggressor_side_from_str
 from nautilus_trader.model.enums import aggressor_side_to_str
 from nautilus_trader.model.enums import asset_class_from_str
 from nautilus_trader.model.enums import asset_class_to_str
+from nautilus_trader.model.enums import auction_phase_from_str
+from nautilus_trader.model.enums import auction_phase_to_str
 from nautilus_trader.model.enums import bar_aggregation_from_str
 from nautilus_trader.model.enums import bar_aggregation_to_str
 from nautilus_trader.model.enums import book_action_from_str
@@ -78,10 +83,14 @@ from nautilus_trader.model.enums import order_status_from_str
 from nautilus_trader.model.enums import order_status_to_str
 from nautilus_trader.model.enums import order_type_from_str
 from nautilus_trader.model.enums import order_type_to_str
+from nautilus_trader.model.enums import peg_price_type_from_str
+from nautilus_trader.model.enums import peg_price_type_to_str
 from nautilus_trader.model.enums import position_side_from_str
 from nautilus_trader.model.enums import position_side_to_str
 from nautilus_trader.model.enums import price_type_from_str
 from nautilus_trader.model.enums import price_type_to_str
+from nautilus_trader.model.enums import rounding_mode_from_str
+from nautilus_trader.model.enums import rounding_mode_to_str
 from nautilus_trader.model.enums import time_in_force_from_str
 from nautilus_trader.model.enums import time_in_force_to_str
 from nautilus_trader.model.enums import trading_state_from_str
@@ -244,6 +253,7 @@ class TestInstrumentClass:
             [InstrumentClass.OPTION_SPREAD, "OPTION_SPREAD"],
             [InstrumentClass.WARRANT, "WARRANT"],
             [InstrumentClass.SPORTS_BETTING, "SPORTS_BETTING"],
+            [InstrumentClass.BINARY_OPTION, "BINARY_OPTION"],
         ],
     )
     def test_instrument_class_to_str(self, enum, expected):
@@ -267,6 +277,7 @@ class TestInstrumentClass:
             ["OPTION_SPREAD", InstrumentClass.OPTION_SPREAD],
             ["WARRANT", InstrumentClass.WARRANT],
             ["SPORTS_BETTING", InstrumentClass.SPORTS_BETTING],
+            ["BINARY_OPTION", InstrumentClass.BINARY_OPTION],
         ],
     )
     def test_instrument_class_from_str(self, string, expected):
@@ -297,6 +308,8 @@ class TestBarAggregation:
             [BarAggregation.DAY, "DAY"],
             [BarAggregation.WEEK, "WEEK"],
             [BarAggregation.MONTH, "MONTH"],
+            [BarAggregation.RENKO, "RENKO"],
+            [BarAggregation.RANGE, "RANGE"],
         ],
     )
     def test_bar_aggregation_to_str(self, enum, expected):
@@ -325,6 +338,8 @@ class TestBarAggregation:
             ["DAY", BarAggregation.DAY],
             ["WEEK", BarAggregation.WEEK],
             ["MONTH", BarAggregation.MONTH],
+            ["RENKO", BarAggregation.RENKO],
+            ["RANGE", BarAggregation.RANGE],
         ],
     )
     def test_bar_aggregation_from_str(self, string, expected):
@@ -509,6 +524,8 @@ class TestHaltReason:
             [HaltReason.NOT_HALTED, "NOT_HALTED"],
             [HaltReason.GENERAL, "GENERAL"],
             [HaltReason.VOLATILITY, "VOLATILITY"],
+            [HaltReason.LIMIT_UP_LIMIT_DOWN, "LIMIT_UP_LIMIT_DOWN"],
+            [HaltReason.CIRCUIT_BREAKER, "CIRCUIT_BREAKER"],
         ],
     )
     def test_halt_reason_to_str(self, enum, expected):
@@ -524,6 +541,8 @@ class TestHaltReason:
             ["NOT_HALTED", HaltReason.NOT_HALTED],
             ["GENERAL", HaltReason.GENERAL],
             ["VOLATILITY", HaltReason.VOLATILITY],
+            ["LIMIT_UP_LIMIT_DOWN", HaltReason.LIMIT_UP_LIMIT_DOWN],
+            ["CIRCUIT_BREAKER", HaltReason.CIRCUIT_BREAKER],
         ],
     )
     def test_halt_reason_from_str(self, string, expected):
@@ -534,6 +553,42 @@ class TestHaltReason:
         assert result == expected
 
 
+class TestAuctionPhase:
+    @pytest.mark.parametrize(
+        ("enum", "expected"),
+        [
+            [AuctionPhase.NO_AUCTION, "NO_AUCTION"],
+            [AuctionPhase.OPENING, "OPENING"],
+            [AuctionPhase.CLOSING, "CLOSING"],
+            [AuctionPhase.INTRADAY, "INTRADAY"],
+            [AuctionPhase.VOLATILITY_INTERRUPTION, "VOLATILITY_INTERRUPTION"],
+        ],
+    )
+    def test_auction_phase_to_str(self, enum, expected):
+        # Arrange, Act
+        result = auction_phase_to_str(enum)
+
+        # Assert
+        assert result == expected
+
+    @pytest.mark.parametrize(
+        ("string", "expected"),
+        [
+            ["NO_AUCTION", AuctionPhase.NO_AUCTION],
+            ["OPENING", AuctionPhase.OPENING],
+            ["CLOSING", AuctionPhase.CLOSING],
+            ["INTRADAY", AuctionPhase.INTRADAY],
+            ["VOLATILITY_INTERRUPTION", AuctionPhase.VOLATILITY_INTERRUPTION],
+        ],
+    )
+    def test_auction_phase_from_str(self, string, expected):
+        # Arrange, Act
+        result = auction_phase_from_str(string)
+
+        # Assert
+        assert result == expected
+
+
 class TestInstrumentCloseType:
     @pytest.mark.parametrize(
         ("enum", "expected"),
@@ -605,6 +660,7 @@ class TestMarketStatus:
             [MarketStatus.OPEN, "OPEN"],
             [MarketStatus.PAUSE, "PAUSE"],
             [MarketStatus.PRE_CLOSE, "PRE_CLOSE"],
+            [MarketStatus.AUCTION, "AUCTION"],
         ],
     )
     def test_market_status_to_str(self, enum, expected):
@@ -622,6 +678,7 @@ class TestMarketStatus:
             ["OPEN", MarketStatus.OPEN],
             ["PAUSE", MarketStatus.PAUSE],
             ["PRE_CLOSE", MarketStatus.PRE_CLOSE],
+            ["AUCTION", MarketStatus.AUCTION],
         ],
     )
     def test_market_status_from_str(self, string, expected):
@@ -790,6 +847,38 @@ class TestOrderType:
         assert result == expected
 
 
+class TestPegPriceType:
+    @pytest.mark.parametrize(
+        ("enum", "expected"),
+        [
+            [PegPriceType.NO_PEG, "NO_PEG"],
+            [PegPriceType.MID_POINT, "MID_POINT"],
+            [PegPriceType.PRIMARY, "PRIMARY"],
+        ],
+    )
+    def test_peg_price_type_to_str(self, enum, expected):
+        # Arrange, Act
+        result = peg_price_type_to_str(enum)
+
+        # Assert
+        assert result == expected
+
+    @pytest.mark.parametrize(
+        ("string", "expected"),
+        [
+            ["NO_PEG", PegPriceType.NO_PEG],
+            ["MID_POINT", PegPriceType.MID_POINT],
+            ["PRIMARY", PegPriceType.PRIMARY],
+        ],
+    )
+    def test_peg_price_type_from_str(self, string, expected):
+        # Arrange, Act
+        result = peg_price_type_from_str(string)
+
+        # Assert
+        assert result == expected
+
+
 class TestPositionSide:
     @pytest.mark.parametrize(
         ("enum", "expected"),
@@ -857,6 +946,46 @@ class TestPriceType:
         assert result == expected
 
 
+class TestRoundingMode:
+    @pytest.mark.parametrize(
+        ("enum", "expected"),
+        [
+            [RoundingMode.UP, "UP"],
+            [RoundingMode.DOWN, "DOWN"],
+            [RoundingMode.CEILING, "CEILING"],
+            [RoundingMode.FLOOR, "FLOOR"],
+            [RoundingMode.HALF_UP, "HALF_UP"],
+            [RoundingMode.HALF_DOWN, "HALF_DOWN"],
+            [RoundingMode.HALF_EVEN, "HALF_EVEN"],
+        ],
+    )
+    def test_rounding_mode_to_str(self, enum, expected):
+        # Arrange, Act
+        result = rounding_mode_to_str(enum)
+
+        # Assert
+        assert result == expected
+
+    @pytest.mark.parametrize(
+        ("string", "expected"),
+        [
+            ["UP", RoundingMode.UP],
+            ["DOWN", RoundingMode.DOWN],
+            ["CEILING", RoundingMode.CEILING],
+            ["FLOOR", RoundingMode.FLOOR],
+            ["HALF_UP", RoundingMode.HALF_UP],
+            ["HALF_DOWN", RoundingMode.HALF_DOWN],
+            ["HALF_EVEN", RoundingMode.HALF_EVEN],
+        ],
+    )
+    def test_rounding_mode_from_str(self, string, expected):
+        # Arrange, Act
+        result = rounding_mode_from_str(string)
+
+        # Assert
+        assert result == expected
+
+
 class TestTimeInForce:
     @pytest.mark.parametrize(
         ("enum", "expected"),
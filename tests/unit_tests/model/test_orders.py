@@ -27,6 +27,7 @@ from nautilus_trader.model.enums import LiquiditySide
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.enums import OrderStatus
 from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import PegPriceType
 from nautilus_trader.model.enums import PositionSide
 from nautilus_trader.model.enums import TimeInForce
 from nautilus_trader.model.enums import TrailingOffsetType
@@ -495,6 +496,8 @@ class TestOrders:
             "is_reduce_only": False,
             "is_quote_quantity": False,
             "display_qty": "20000",
+            "peg_price_type": "NO_PEG",
+            "peg_offset": None,
             "emulation_trigger": "NO_TRIGGER",
             "trigger_instrument_id": None,
             "contingency_type": "NO_CONTINGENCY",
@@ -509,6 +512,53 @@ class TestOrders:
             "ts_last": 0,
         }
 
+    def test_limit_order_with_peg_offset_and_no_peg_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            self.order_factory.limit(
+                AUDUSD_SIM.id,
+                OrderSide.BUY,
+                Quantity.from_int(100_000),
+                Price.from_str("1.00000"),
+                peg_offset=Price.from_str("0.00010"),
+            )
+
+    def test_initialize_pegged_limit_order(self):
+        # Arrange, Act
+        order = self.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00000"),
+            peg_price_type=PegPriceType.MID_POINT,
+            peg_offset=Price.from_str("0.00010"),
+        )
+
+        # Assert
+        assert order.peg_price_type == PegPriceType.MID_POINT
+        assert order.peg_offset == Price.from_str("0.00010")
+        assert order.info() == "BUY 100_000 AUD/USD.SIM LIMIT @ 1.00000 GTC PEG[MID_POINT-0.00010]"
+        assert order.to_dict()["peg_price_type"] == "MID_POINT"
+        assert order.to_dict()["peg_offset"] == "0.00010"
+
+    def test_pegged_limit_order_create_from_init_event(self):
+        # Arrange
+        order = self.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00000"),
+            peg_price_type=PegPriceType.PRIMARY,
+        )
+
+        # Act
+        result = LimitOrder.create(order.init_event)
+
+        # Assert
+        assert result.peg_price_type == PegPriceType.PRIMARY
+        assert result.peg_offset is None
+        assert result == order
+
     def test_initialize_limit_order_with_expiration(self):
         # Arrange, Act
         order = self.order_factory.limit(
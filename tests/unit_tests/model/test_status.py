@@ -13,9 +13,13 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+import pytest
+
 from nautilus_trader.model.data import InstrumentClose
 from nautilus_trader.model.data import InstrumentStatus
 from nautilus_trader.model.data import VenueStatus
+from nautilus_trader.model.enums import AuctionPhase
+from nautilus_trader.model.enums import HaltReason
 from nautilus_trader.model.enums import InstrumentCloseType
 from nautilus_trader.model.enums import MarketStatus
 from nautilus_trader.model.identifiers import InstrumentId
@@ -55,9 +59,73 @@ class TestVenue:
         assert InstrumentStatus.from_dict(InstrumentStatus.to_dict(update)) == update
         assert (
             repr(update)
-            == "InstrumentStatus(instrument_id=BTCUSDT.BINANCE, trading_session=Regular, status=OPEN, halt_reason=NOT_HALTED, ts_event=0)"
+            == "InstrumentStatus(instrument_id=BTCUSDT.BINANCE, trading_session=Regular, status=OPEN, halt_reason=NOT_HALTED, auction_phase=NO_AUCTION, limit_up=None, limit_down=None, ts_event=0)"
+        )
+
+    def test_instrument_status_with_auction_phase_and_price_bands(self):
+        # Arrange
+        update = InstrumentStatus(
+            instrument_id=InstrumentId(Symbol("BTCUSDT"), Venue("BINANCE")),
+            status=MarketStatus.AUCTION,
+            ts_event=0,
+            ts_init=0,
+            auction_phase=AuctionPhase.VOLATILITY_INTERRUPTION,
+            limit_up=Price.from_str("110.00"),
+            limit_down=Price.from_str("90.00"),
         )
 
+        # Act
+        result = InstrumentStatus.from_dict(InstrumentStatus.to_dict(update))
+
+        # Assert
+        assert result == update
+        assert result.auction_phase == AuctionPhase.VOLATILITY_INTERRUPTION
+        assert result.limit_up == Price.from_str("110.00")
+        assert result.limit_down == Price.from_str("90.00")
+        assert (
+            repr(update)
+            == "InstrumentStatus(instrument_id=BTCUSDT.BINANCE, trading_session=Regular, status=AUCTION, halt_reason=NOT_HALTED, auction_phase=VOLATILITY_INTERRUPTION, limit_up=110.00, limit_down=90.00, ts_event=0)"
+        )
+
+    def test_instrument_status_halted_by_limit_up_limit_down(self):
+        # Arrange, Act
+        update = InstrumentStatus(
+            instrument_id=InstrumentId(Symbol("BTCUSDT"), Venue("BINANCE")),
+            status=MarketStatus.HALT,
+            ts_event=0,
+            ts_init=0,
+            halt_reason=HaltReason.LIMIT_UP_LIMIT_DOWN,
+            limit_up=Price.from_str("110.00"),
+        )
+
+        # Assert
+        assert update.halt_reason == HaltReason.LIMIT_UP_LIMIT_DOWN
+        assert update.limit_down is None
+        assert InstrumentStatus.from_dict(InstrumentStatus.to_dict(update)) == update
+
+    def test_instrument_status_auction_phase_when_not_in_auction_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            InstrumentStatus(
+                instrument_id=InstrumentId(Symbol("BTCUSDT"), Venue("BINANCE")),
+                status=MarketStatus.OPEN,
+                ts_event=0,
+                ts_init=0,
+                auction_phase=AuctionPhase.OPENING,
+            )
+
+    def test_instrument_status_with_inverted_price_bands_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            InstrumentStatus(
+                instrument_id=InstrumentId(Symbol("BTCUSDT"), Venue("BINANCE")),
+                status=MarketStatus.OPEN,
+                ts_event=0,
+                ts_init=0,
+                limit_up=Price.from_str("90.00"),
+                limit_down=Price.from_str("110.00"),
+            )
+
     def test_instrument_close(self):
         # Arrange
         update = InstrumentClose(
@@ -440,6 +440,36 @@ class TestMessageBus:
         assert handler1 == ["message1"]
         assert handler2 == ["message1", "message2", "message3"]
 
+    def test_subscribe_with_level_wildcards_after_publish_then_receives_messages(self):
+        # Arrange
+        received = []
+        self.msgbus.publish("data.quotes.BINANCE", "message1")  # Caches the topic
+        self.msgbus.subscribe(topic="data.quotes.+", handler=received.append)
+
+        # Act
+        self.msgbus.publish("data.quotes.BINANCE", "message2")
+        self.msgbus.publish("data.quotes.BINANCE.ETHUSDT", "message3")
+
+        # Assert
+        assert received == ["message2"]
+
+    def test_publish_delivers_in_priority_then_subscription_order(self):
+        # Arrange
+        received = []
+        self.msgbus.subscribe(topic="data.#", handler=lambda m: received.append(("a", m)))
+        self.msgbus.subscribe(
+            topic="data.quotes.+",
+            handler=lambda m: received.append(("b", m)),
+            priority=5,
+        )
+        self.msgbus.subscribe(topic="data.quotes.*", handler=lambda m: received.append(("c", m)))
+
+        # Act
+        self.msgbus.publish("data.quotes.BINANCE", "message")
+
+        # Assert
+        assert received == [("b", "message"), ("a", "message"), ("c", "message")]
+
     def test_msgbus_for_system_events_using_component_id(self):
         # Arrange
         subscriber = []
@@ -469,6 +499,13 @@ class TestMessageBus:
         ["data.quotes.BINANCE", "data.*.BINANCE", True],
         ["data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.*", True],
         ["data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.ETH*", True],
+        ["data.quotes.BINANCE", "data.quotes.+", True],
+        ["data.quotes.BINANCE.ETHUSDT", "data.quotes.+", False],
+        ["data.quotes.BINANCE.ETHUSDT", "data.+.BINANCE.+", True],
+        ["data.quotes", "data.quotes.#", True],
+        ["data.quotes.BINANCE.ETHUSDT", "data.quotes.#", True],
+        ["data.quotes.BINANCE.ETHUSDT", "data.#.ETHUSDT", True],
+        ["data.quotesX", "data.quotes.#", False],
     ],
 )
 def test_is_matching_given_various_topic_pattern_combos(topic, pattern, expected):
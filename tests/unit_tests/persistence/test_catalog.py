@@ -277,6 +277,66 @@ def test_catalog_write_pyo3_order_book_depth10(catalog: ParquetDataCatalog) -> N
     assert len(all_depths) == 100
 
 
+def test_catalog_write_pyo3_order_book_depth25(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    instrument = TestInstrumentProvider.ethusdt_binance()
+    instrument_id = nautilus_pyo3.InstrumentId.from_str(instrument.id.value)
+    depth25 = TestDataProviderPyo3.order_book_depth25(instrument_id=instrument_id)
+
+    # Act
+    catalog.write_data([depth25] * 100)
+
+    # Assert
+    depths = catalog.query(
+        data_cls=nautilus_pyo3.OrderBookDepth25,
+        instrument_ids=[instrument.id.value],
+    )
+    assert len(depths) == 100
+    assert depths[0] == depth25
+
+
+def test_catalog_write_pyo3_mark_prices(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    instrument = TestInstrumentProvider.ethusdt_binance()
+    instrument_id = nautilus_pyo3.InstrumentId.from_str(instrument.id.value)
+    mark_prices = [
+        TestDataProviderPyo3.mark_price(instrument_id=instrument_id, ts_event=i, ts_init=i)
+        for i in range(100)
+    ]
+
+    # Act
+    catalog.write_data(mark_prices)
+
+    # Assert
+    result = catalog.query(
+        data_cls=nautilus_pyo3.MarkPriceUpdate,
+        instrument_ids=[instrument.id.value],
+    )
+    assert len(result) == 100
+    assert result == mark_prices
+
+
+def test_catalog_write_pyo3_index_prices(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    instrument = TestInstrumentProvider.ethusdt_binance()
+    instrument_id = nautilus_pyo3.InstrumentId.from_str(instrument.id.value)
+    index_prices = [
+        TestDataProviderPyo3.index_price(instrument_id=instrument_id, ts_event=i, ts_init=i)
+        for i in range(100)
+    ]
+
+    # Act
+    catalog.write_data(index_prices)
+
+    # Assert
+    result = catalog.query(
+        data_cls=nautilus_pyo3.IndexPriceUpdate,
+        instrument_ids=[instrument.id.value],
+    )
+    assert len(result) == 100
+    assert result == index_prices
+
+
 def test_catalog_write_pyo3_quote_ticks(catalog: ParquetDataCatalog) -> None:
     # Arrange
     path = TEST_DATA_DIR / "truefx" / "audusd-ticks.csv"
@@ -403,3 +463,24 @@ def test_list_live_runs(
 
     # Assert
     assert result == ["abc"]
+
+
+def test_catalog_query_rust_with_time_range(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    path = TEST_DATA_DIR / "binance" / "ethusdt-trades.csv"
+    df = pd.read_csv(path)
+    instrument = TestInstrumentProvider.ethusdt_binance()
+    wrangler = TradeTickDataWranglerV2.from_instrument(instrument)
+    catalog.write_data(wrangler.from_pandas(df))
+    all_trades = catalog.trade_ticks()
+    start = all_trades[1_000].ts_init
+    end = all_trades[1_999].ts_init
+
+    # Act
+    trades = catalog.trade_ticks(instrument_ids=[instrument.id], start=start, end=end)
+    other_trades = catalog.trade_ticks(instrument_ids=["BTCUSDT.BINANCE"], start=start, end=end)
+
+    # Assert
+    assert len(trades) == len([t for t in all_trades if start <= t.ts_init <= end])
+    assert all(start <= t.ts_init <= end for t in trades)
+    assert other_trades == []
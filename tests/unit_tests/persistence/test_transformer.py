@@ -19,6 +19,7 @@ import pandas as pd
 import pyarrow as pa
 import pytest
 
+from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.core.nautilus_pyo3 import DataTransformer
 from nautilus_trader.model.data import Bar
 from nautilus_trader.model.data import OrderBookDelta
@@ -51,6 +52,47 @@ def test_pyo3_quote_ticks_to_record_batch_reader() -> None:
     reader.close()
 
 
+def test_pyo3_custom_data_to_record_batch_round_trip() -> None:
+    # Arrange
+    nautilus_pyo3.register_custom_data_type(
+        "OnChainFlow",
+        {"token": "Utf8", "inflow": "Float64", "transfers": "UInt64", "is_exchange": "Boolean"},
+    )
+    data = [
+        nautilus_pyo3.CustomData(
+            "OnChainFlow",
+            {"token": "ETH", "inflow": 1250.5, "transfers": 42, "is_exchange": True},
+            ts_event=i,
+            ts_init=i,
+        )
+        for i in range(3)
+    ]
+
+    # Act
+    batch_bytes = DataTransformer.pyo3_custom_data_to_record_batch_bytes(data)
+    table = pa.ipc.open_stream(BytesIO(batch_bytes)).read_all()
+    decoded = DataTransformer.record_batch_bytes_to_pyo3_custom_data("OnChainFlow", batch_bytes)
+
+    # Assert
+    assert table.column_names == [
+        "token",
+        "inflow",
+        "transfers",
+        "is_exchange",
+        "ts_event",
+        "ts_init",
+    ]
+    assert nautilus_pyo3.custom_data_schema("OnChainFlow")["inflow"] == "Float64"
+    assert decoded == data
+    assert decoded[0]["token"] == "ETH"
+
+
+def test_custom_data_with_unregistered_type_raises_value_error() -> None:
+    # Arrange, Act, Assert
+    with pytest.raises(ValueError):
+        nautilus_pyo3.CustomData("UnregisteredType", {"value": 1.0}, ts_event=0, ts_init=0)
+
+
 def test_legacy_trade_ticks_to_record_batch_reader() -> None:
     # Arrange
     instrument = TestInstrumentProvider.ethusdt_binance()
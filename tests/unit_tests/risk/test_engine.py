@@ -16,12 +16,16 @@
 from datetime import timedelta
 from decimal import Decimal
 
+import pandas as pd
 import pytest
 
 from nautilus_trader.common.component import MessageBus
 from nautilus_trader.common.component import TestClock
 from nautilus_trader.common.messages import TradingStateChanged
 from nautilus_trader.config import ExecEngineConfig
+from nautilus_trader.config import LossLimitConfig
+from nautilus_trader.config import OrderRateLimitConfig
+from nautilus_trader.config import PriceCollarConfig
 from nautilus_trader.config import RiskEngineConfig
 from nautilus_trader.core.message import Event
 from nautilus_trader.core.uuid import UUID4
@@ -59,6 +63,7 @@ from nautilus_trader.model.objects import Quantity
 from nautilus_trader.model.orders.list import OrderList
 from nautilus_trader.portfolio.portfolio import Portfolio
 from nautilus_trader.risk.engine import RiskEngine
+from nautilus_trader.risk.messages import UpdateRiskLimits
 from nautilus_trader.test_kit.mocks.exec_clients import MockExecutionClient
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
 from nautilus_trader.test_kit.stubs.component import TestComponentStubs
@@ -243,6 +248,67 @@ class TestRiskEngineWithCashAccount:
         assert max_notionals == {_AUDUSD_SIM.id: Decimal("1000000")}
         assert max_notional == Decimal(1_000_000)
 
+    def test_update_risk_limits_command_changes_settings(self):
+        # Arrange
+        command = UpdateRiskLimits(
+            trader_id=self.trader_id,
+            instrument_id=_AUDUSD_SIM.id,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            max_notional_per_order=Decimal(1_000_000),
+            max_position_qty=Quantity.from_int(500_000),
+            max_open_orders=5,
+        )
+
+        # Act
+        self.risk_engine.execute(command)
+
+        # Assert
+        assert self.risk_engine.command_count == 1
+        assert self.risk_engine.max_notional_per_order(_AUDUSD_SIM.id) == Decimal(1_000_000)
+        assert self.risk_engine.max_position_qty(_AUDUSD_SIM.id) == Quantity.from_int(500_000)
+        assert self.risk_engine.max_open_orders(_AUDUSD_SIM.id) == 5
+
+    def test_update_risk_limits_command_with_no_limits_removes_settings(self):
+        # Arrange
+        self.risk_engine.set_max_position_qty(_AUDUSD_SIM.id, Quantity.from_int(500_000))
+        self.risk_engine.set_max_open_orders(_AUDUSD_SIM.id, 5)
+        command = UpdateRiskLimits(
+            trader_id=self.trader_id,
+            instrument_id=_AUDUSD_SIM.id,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(command)
+
+        # Assert
+        assert self.risk_engine.max_notional_per_order(_AUDUSD_SIM.id) is None
+        assert self.risk_engine.max_position_qty(_AUDUSD_SIM.id) is None
+        assert self.risk_engine.max_open_orders(_AUDUSD_SIM.id) is None
+
+    def test_risk_limits_per_instrument_from_config(self):
+        # Arrange
+        config = RiskEngineConfig(
+            max_position_qty_per_instrument={"AUD/USD.SIM": "500000"},
+            max_open_orders_per_instrument={"AUD/USD.SIM": 5},
+        )
+
+        # Act
+        risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=config,
+        )
+
+        # Assert
+        assert risk_engine.max_position_qty(_AUDUSD_SIM.id) == Quantity.from_int(500_000)
+        assert risk_engine.max_open_orders(_AUDUSD_SIM.id) == 5
+        assert risk_engine.max_position_qty(_GBPUSD_SIM.id) is None
+
     def test_given_random_command_then_logs_and_continues(self):
         # Arrange
         random = TradingCommand(
@@ -303,6 +369,365 @@ class TestRiskEngineWithCashAccount:
         assert self.exec_engine.command_count == 1
         assert self.exec_client.calls == ["_start", "submit_order"]
 
+    def test_submit_order_when_venue_rate_limit_exceeded_then_denies_with_retry_after(self):
+        # Arrange
+        self.exec_engine.start()
+
+        risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=RiskEngineConfig(
+                max_order_submit_rate_per_venue={
+                    "SIM": OrderRateLimitConfig(rate="1/00:00:01", burst=2),
+                },
+            ),
+        )
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        orders = [
+            strategy.order_factory.market(
+                _AUDUSD_SIM.id,
+                OrderSide.BUY,
+                Quantity.from_int(100_000),
+            )
+            for _ in range(3)
+        ]
+
+        # Act
+        for order in orders:
+            risk_engine.execute(
+                SubmitOrder(
+                    trader_id=self.trader_id,
+                    strategy_id=strategy.id,
+                    position_id=None,
+                    order=order,
+                    command_id=UUID4(),
+                    ts_init=self.clock.timestamp_ns(),
+                ),
+            )
+
+        # Assert
+        assert self.exec_engine.command_count == 2
+        assert orders[2].status == OrderStatus.DENIED
+        assert orders[2].last_event.reason == (
+            "Exceeded ORDER_SUBMIT_RATE_LIMIT[VENUE-SIM]: retry_after_ms=1000"
+        )
+
+    def test_submit_order_when_strategy_rate_limit_refilled_then_sends_to_client(self):
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=RiskEngineConfig(
+                max_order_submit_rate_per_strategy={
+                    strategy.id.value: OrderRateLimitConfig(rate="1/00:00:01"),
+                },
+            ),
+        )
+
+        orders = [
+            strategy.order_factory.market(
+                _AUDUSD_SIM.id,
+                OrderSide.BUY,
+                Quantity.from_int(100_000),
+            )
+            for _ in range(2)
+        ]
+
+        # Act
+        for order in orders:
+            risk_engine.execute(
+                SubmitOrder(
+                    trader_id=self.trader_id,
+                    strategy_id=strategy.id,
+                    position_id=None,
+                    order=order,
+                    command_id=UUID4(),
+                    ts_init=self.clock.timestamp_ns(),
+                ),
+            )
+            self.clock.advance_time(self.clock.timestamp_ns() + 1_000_000_000)
+
+        # Assert
+        assert self.exec_engine.command_count == 2
+        assert orders[1].status != OrderStatus.DENIED
+
+    def _price_collar_risk_engine(self, collar: PriceCollarConfig) -> RiskEngine:
+        self.cache.add_quote_tick(
+            TestDataStubs.quote_tick(_AUDUSD_SIM, bid_price=0.99990, ask_price=1.00010),
+        )
+        return RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=RiskEngineConfig(price_collars={"SPOT": collar}),
+        )
+
+    def _submit_limit_order(self, risk_engine: RiskEngine, price: str):
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.limit(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str(price),
+        )
+
+        risk_engine.execute(
+            SubmitOrder(
+                trader_id=self.trader_id,
+                strategy_id=strategy.id,
+                position_id=None,
+                order=order,
+                command_id=UUID4(),
+                ts_init=self.clock.timestamp_ns(),
+            ),
+        )
+        return order
+
+    def test_submit_order_when_price_outside_pct_collar_then_denies(self):
+        # Arrange
+        self.exec_engine.start()
+        risk_engine = self._price_collar_risk_engine(PriceCollarConfig(max_deviation_pct=5.0))
+
+        # Act
+        order = self._submit_limit_order(risk_engine, "1.10000")
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+        assert order.last_event.reason.startswith("PRICE_OUTSIDE_COLLAR")
+        assert self.exec_engine.command_count == 0
+
+    def test_submit_order_when_price_within_pct_collar_then_sends_to_client(self):
+        # Arrange
+        self.exec_engine.start()
+        risk_engine = self._price_collar_risk_engine(PriceCollarConfig(max_deviation_pct=5.0))
+
+        # Act
+        self._submit_limit_order(risk_engine, "1.04000")
+
+        # Assert
+        assert self.exec_engine.command_count == 1
+
+    def test_submit_order_when_price_outside_tick_collar_then_denies(self):
+        # Arrange
+        self.exec_engine.start()
+        risk_engine = self._price_collar_risk_engine(PriceCollarConfig(max_deviation_ticks=10))
+
+        # Act
+        order1 = self._submit_limit_order(risk_engine, "1.00010")
+        order2 = self._submit_limit_order(risk_engine, "1.00011")
+
+        # Assert
+        assert order1.status != OrderStatus.DENIED
+        assert order2.status == OrderStatus.DENIED
+        assert "max_deviation_ticks=10" in order2.last_event.reason
+
+    def test_submit_order_when_price_outside_collar_and_warn_only_then_sends_to_client(self):
+        # Arrange
+        self.exec_engine.start()
+        risk_engine = self._price_collar_risk_engine(
+            PriceCollarConfig(max_deviation_pct=5.0, reject=False),
+        )
+
+        # Act
+        order = self._submit_limit_order(risk_engine, "1.10000")
+
+        # Assert
+        assert order.status != OrderStatus.DENIED
+        assert self.exec_engine.command_count == 1
+
+    def _loss_limit_risk_engine(self, loss_limit: LossLimitConfig) -> RiskEngine:
+        return RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=RiskEngineConfig(loss_limits={"SIM": loss_limit}),
+        )
+
+    def _update_account_balance(self, risk_engine: RiskEngine, total: int) -> None:
+        state = AccountState(
+            account_id=self.account_id,
+            account_type=AccountType.CASH,
+            base_currency=USD,
+            reported=True,
+            balances=[AccountBalance(Money(total, USD), Money(0, USD), Money(total, USD))],
+            margins=[],
+            info={},
+            event_id=UUID4(),
+            ts_event=self.clock.timestamp_ns(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+        self.portfolio.update_account(state)
+        risk_engine.process(state)
+
+    def test_daily_loss_limit_breached_then_halts_trading(self):
+        # Arrange
+        self.exec_engine.start()
+        risk_engine = self._loss_limit_risk_engine(LossLimitConfig(max_daily_loss="1000.00 USD"))
+        self._update_account_balance(risk_engine, 1_000_000)
+
+        # Act
+        self._update_account_balance(risk_engine, 999_500)
+        state_within_limit = risk_engine.trading_state
+        self._update_account_balance(risk_engine, 999_000)
+        order = self._submit_limit_order(risk_engine, "1.00000")
+
+        # Assert
+        assert state_within_limit == TradingState.ACTIVE
+        assert risk_engine.trading_state == TradingState.HALTED
+        assert order.status == OrderStatus.DENIED
+        assert order.last_event.reason == "TradingState.HALTED"
+
+    def test_trailing_drawdown_breached_then_reducing(self):
+        # Arrange
+        risk_engine = self._loss_limit_risk_engine(
+            LossLimitConfig(max_drawdown=0.1, breach_trading_state="REDUCING"),
+        )
+        self._update_account_balance(risk_engine, 1_000_000)
+        self._update_account_balance(risk_engine, 1_200_000)
+
+        # Act
+        self._update_account_balance(risk_engine, 1_090_000)
+        state_within_limit = risk_engine.trading_state
+        self._update_account_balance(risk_engine, 1_070_000)
+
+        # Assert
+        assert state_within_limit == TradingState.ACTIVE
+        assert risk_engine.trading_state == TradingState.REDUCING
+
+    def test_loss_limit_breach_resets_at_session_boundary(self):
+        # Arrange
+        risk_engine = self._loss_limit_risk_engine(
+            LossLimitConfig(max_daily_loss="1000.00 USD", session_reset_time="22:00:00"),
+        )
+        self._update_account_balance(risk_engine, 1_000_000)
+        self._update_account_balance(risk_engine, 998_000)
+        assert risk_engine.trading_state == TradingState.HALTED
+
+        # Act
+        self.clock.advance_time(pd.Timedelta(hours=21, minutes=59).value)
+        self._update_account_balance(risk_engine, 998_000)
+        state_before_boundary = risk_engine.trading_state
+        self.clock.advance_time(pd.Timedelta(hours=22).value)
+        self._update_account_balance(risk_engine, 998_000)
+
+        # Assert
+        assert state_before_boundary == TradingState.HALTED
+        assert risk_engine.trading_state == TradingState.ACTIVE
+
+    def test_loss_limit_breach_resets_on_timer_without_account_events(self):
+        # Arrange
+        risk_engine = self._loss_limit_risk_engine(
+            LossLimitConfig(max_daily_loss="1000.00 USD", session_reset_time="22:00:00"),
+        )
+        risk_engine.start()
+        self._update_account_balance(risk_engine, 1_000_000)
+        self._update_account_balance(risk_engine, 998_000)
+        assert risk_engine.trading_state == TradingState.HALTED
+
+        # Act
+        for handler in self.clock.advance_time(pd.Timedelta(hours=21, minutes=59).value):
+            handler.handle()
+        state_before_boundary = risk_engine.trading_state
+        for handler in self.clock.advance_time(pd.Timedelta(hours=22).value):
+            handler.handle()
+
+        # Assert
+        assert state_before_boundary == TradingState.HALTED
+        assert risk_engine.trading_state == TradingState.ACTIVE
+
+    def test_loss_limit_reset_restores_prior_trading_state(self):
+        # Arrange
+        risk_engine = self._loss_limit_risk_engine(
+            LossLimitConfig(max_daily_loss="1000.00 USD", session_reset_time="22:00:00"),
+        )
+        risk_engine.start()
+        risk_engine.set_trading_state(TradingState.REDUCING)
+        self._update_account_balance(risk_engine, 1_000_000)
+        self._update_account_balance(risk_engine, 998_000)
+        assert risk_engine.trading_state == TradingState.HALTED
+
+        # Act
+        for handler in self.clock.advance_time(pd.Timedelta(hours=22).value):
+            handler.handle()
+
+        # Assert
+        assert risk_engine.trading_state == TradingState.REDUCING
+
+    def test_loss_limit_reset_does_not_override_state_changed_since_breach(self):
+        # Arrange
+        risk_engine = self._loss_limit_risk_engine(
+            LossLimitConfig(
+                max_daily_loss="1000.00 USD",
+                breach_trading_state="REDUCING",
+                session_reset_time="22:00:00",
+            ),
+        )
+        risk_engine.start()
+        self._update_account_balance(risk_engine, 1_000_000)
+        self._update_account_balance(risk_engine, 998_000)
+        assert risk_engine.trading_state == TradingState.REDUCING
+        risk_engine.set_trading_state(TradingState.HALTED)
+
+        # Act
+        for handler in self.clock.advance_time(pd.Timedelta(hours=22).value):
+            handler.handle()
+
+        # Assert
+        assert risk_engine.trading_state == TradingState.HALTED
+
+    def test_submit_order_when_aggregate_exposure_exceeds_max_for_asset_class_then_denies(self):
+        # Arrange
+        self.exec_engine.start()
+        risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=RiskEngineConfig(max_net_exposure_per_asset_class={"FX": "50000.00 USD"}),
+        )
+
+        # Act
+        order = self._submit_limit_order(risk_engine, "1.00000")
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+        assert order.last_event.reason.startswith("NET_EXPOSURE_EXCEEDS_MAX_FOR_ASSET_CLASS[FX]")
+        assert self.exec_engine.command_count == 0
+
     def test_submit_order_when_risk_bypassed_sends_to_execution_engine(self):
         # Arrange
         self.exec_engine.start()
@@ -921,6 +1346,129 @@ class TestRiskEngineWithCashAccount:
         assert order.status == OrderStatus.DENIED
         assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
 
+    def test_submit_order_when_open_orders_exceeds_max_then_denies(self):
+        # Arrange
+        self.risk_engine.set_max_open_orders(_AUDUSD_SIM.id, 1)
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order1 = strategy.order_factory.limit(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00000"),
+        )
+        order2 = strategy.order_factory.limit(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("0.99990"),
+        )
+
+        self.risk_engine.execute(
+            SubmitOrder(
+                trader_id=self.trader_id,
+                strategy_id=strategy.id,
+                position_id=None,
+                order=order1,
+                command_id=UUID4(),
+                ts_init=self.clock.timestamp_ns(),
+            ),
+        )
+        self.exec_engine.process(TestEventStubs.order_submitted(order1))
+        self.exec_engine.process(TestEventStubs.order_accepted(order1))
+
+        # Act
+        self.risk_engine.execute(
+            SubmitOrder(
+                trader_id=self.trader_id,
+                strategy_id=strategy.id,
+                position_id=None,
+                order=order2,
+                command_id=UUID4(),
+                ts_init=self.clock.timestamp_ns(),
+            ),
+        )
+
+        # Assert
+        assert order1.status == OrderStatus.ACCEPTED
+        assert order2.status == OrderStatus.DENIED
+        assert order2.last_event.reason == "OPEN_ORDERS_EXCEEDS_MAX: max_open_orders=1, open_orders=2"
+        assert self.exec_engine.command_count == 1
+
+    def test_submit_order_when_position_exceeds_max_qty_then_denies(self):
+        # Arrange
+        self.risk_engine.set_max_position_qty(_AUDUSD_SIM.id, Quantity.from_int(150_000))
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order1 = strategy.order_factory.market(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        order2 = strategy.order_factory.market(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        order3 = strategy.order_factory.market(
+            _AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+        )
+
+        self.risk_engine.execute(
+            SubmitOrder(
+                trader_id=self.trader_id,
+                strategy_id=strategy.id,
+                position_id=None,
+                order=order1,
+                command_id=UUID4(),
+                ts_init=self.clock.timestamp_ns(),
+            ),
+        )
+        self.exec_engine.process(TestEventStubs.order_submitted(order1))
+        self.exec_engine.process(TestEventStubs.order_accepted(order1))
+        self.exec_engine.process(TestEventStubs.order_filled(order1, _AUDUSD_SIM))
+
+        # Act
+        for order in (order2, order3):
+            self.risk_engine.execute(
+                SubmitOrder(
+                    trader_id=self.trader_id,
+                    strategy_id=strategy.id,
+                    position_id=None,
+                    order=order,
+                    command_id=UUID4(),
+                    ts_init=self.clock.timestamp_ns(),
+                ),
+            )
+
+        # Assert
+        assert order2.status == OrderStatus.DENIED
+        assert order2.last_event.reason == (
+            "POSITION_EXCEEDS_MAX_QUANTITY: max_position_qty=150000, position_qty=200000"
+        )
+        assert order3.status != OrderStatus.DENIED  # Reduces the absolute position
+        assert self.exec_engine.command_count == 2
+
     def test_submit_order_when_buy_market_order_and_over_max_notional_then_denies(self):
         # Arrange
         self.risk_engine.set_max_notional_per_order(_AUDUSD_SIM.id, 1_000_000)
@@ -0,0 +1,176 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.common.component import Logger
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.common.factories import OrderFactory
+from nautilus_trader.model.currencies import USDT
+from nautilus_trader.model.enums import AssetClass
+from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.identifiers import PositionId
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.model.position import Position
+from nautilus_trader.risk.exposure import ExposureAggregator
+from nautilus_trader.risk.exposure import instrument_underlying
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.component import TestComponentStubs
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+from nautilus_trader.test_kit.stubs.events import TestEventStubs
+from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
+
+
+BTCUSDT_BINANCE = TestInstrumentProvider.btcusdt_binance()
+BTCUSDT_PERP_BINANCE = TestInstrumentProvider.btcusdt_perp_binance()
+ETHUSDT_BINANCE = TestInstrumentProvider.ethusdt_binance()
+
+
+class TestExposureAggregator:
+    def setup(self):
+        # Fixture Setup
+        self.cache = TestComponentStubs.cache()
+        self.order_factory = OrderFactory(
+            trader_id=TestIdStubs.trader_id(),
+            strategy_id=StrategyId("S-001"),
+            clock=TestClock(),
+        )
+        self.aggregator = ExposureAggregator(cache=self.cache, log=Logger("ExposureAggregator"))
+
+        for instrument, price in [
+            (BTCUSDT_BINANCE, 50_000.0),
+            (BTCUSDT_PERP_BINANCE, 50_000.0),
+            (ETHUSDT_BINANCE, 3_000.0),
+        ]:
+            self.cache.add_instrument(instrument)
+            self.cache.add_quote_tick(
+                TestDataStubs.quote_tick(instrument, bid_price=price, ask_price=price),
+            )
+
+    def _open_position(
+        self,
+        instrument: Instrument,
+        side: OrderSide,
+        quantity: str,
+        position_id: str,
+    ) -> None:
+        order = self.order_factory.market(instrument.id, side, Quantity.from_str(quantity))
+        fill = TestEventStubs.order_filled(
+            order,
+            instrument=instrument,
+            position_id=PositionId(position_id),
+            last_px=instrument.make_price(1.0),
+        )
+        self.cache.add_position(Position(instrument=instrument, fill=fill), OmsType.HEDGING)
+
+    def test_instrument_underlying(self):
+        # Arrange, Act, Assert
+        assert instrument_underlying(BTCUSDT_BINANCE) == "BTC"
+        assert instrument_underlying(BTCUSDT_PERP_BINANCE) == "BTC"
+        assert instrument_underlying(TestInstrumentProvider.aapl_option()) == "AAPL"
+
+    def test_has_limits_when_no_limits_returns_false(self):
+        # Arrange, Act, Assert
+        assert not self.aggregator.has_limits
+
+    def test_net_exposure_for_underlying_aggregates_spot_and_perpetual(self):
+        # Arrange
+        self._open_position(BTCUSDT_BINANCE, OrderSide.BUY, "1.000000", "P-1")
+        self._open_position(BTCUSDT_PERP_BINANCE, OrderSide.BUY, "3.000", "P-2")
+        self._open_position(BTCUSDT_PERP_BINANCE, OrderSide.SELL, "1.000", "P-3")
+        self._open_position(ETHUSDT_BINANCE, OrderSide.BUY, "10.00000", "P-4")
+
+        # Act
+        exposure = self.aggregator.net_exposure_for_underlying("BTC", USDT)
+
+        # Assert
+        assert exposure == Money(150_000, USDT)
+
+    def test_net_exposure_for_asset_class_aggregates_all_underlyings(self):
+        # Arrange
+        self._open_position(BTCUSDT_BINANCE, OrderSide.BUY, "1.000000", "P-1")
+        self._open_position(ETHUSDT_BINANCE, OrderSide.SELL, "10.00000", "P-2")
+
+        # Act
+        exposure = self.aggregator.net_exposure_for_asset_class(AssetClass.CRYPTOCURRENCY, USDT)
+
+        # Assert
+        assert exposure == Money(20_000, USDT)
+
+    def test_check_orders_when_aggregate_exceeds_underlying_limit_returns_denial(self):
+        # Arrange
+        self.aggregator.set_max_net_exposure_per_underlying("BTC", Money(175_000, USDT))
+        self._open_position(BTCUSDT_BINANCE, OrderSide.BUY, "3.000000", "P-1")
+        order = self.order_factory.limit(
+            BTCUSDT_PERP_BINANCE.id,
+            OrderSide.BUY,
+            Quantity.from_str("1.000"),
+            Price.from_str("50000.0"),
+        )
+
+        # Act
+        result = self.aggregator.check_orders(BTCUSDT_PERP_BINANCE, [order])
+
+        # Assert
+        assert result is not None
+        assert result[0] == order
+        assert result[1] == (
+            "NET_EXPOSURE_EXCEEDS_MAX_FOR_UNDERLYING[BTC]: "
+            "max_net_exposure=175_000.00000000 USDT, net_exposure=200_000.00000000 USDT"
+        )
+
+    def test_check_orders_when_reducing_aggregate_exposure_passes(self):
+        # Arrange
+        self.aggregator.set_max_net_exposure_per_underlying("BTC", Money(100_000, USDT))
+        self._open_position(BTCUSDT_BINANCE, OrderSide.BUY, "3.000000", "P-1")
+        order = self.order_factory.market(
+            BTCUSDT_PERP_BINANCE.id,
+            OrderSide.SELL,
+            Quantity.from_str("1.000"),
+        )
+
+        # Act
+        result = self.aggregator.check_orders(BTCUSDT_PERP_BINANCE, [order])
+
+        # Assert
+        assert result is None
+
+    def test_check_orders_when_cumulative_orders_exceed_asset_class_limit_denies_second(self):
+        # Arrange
+        self.aggregator.set_max_net_exposure_per_asset_class(
+            AssetClass.CRYPTOCURRENCY,
+            Money(100_000, USDT),
+        )
+        order1 = self.order_factory.market(
+            BTCUSDT_BINANCE.id,
+            OrderSide.BUY,
+            Quantity.from_str("1.000000"),
+        )
+        order2 = self.order_factory.market(
+            BTCUSDT_BINANCE.id,
+            OrderSide.BUY,
+            Quantity.from_str("1.500000"),
+        )
+
+        # Act
+        result = self.aggregator.check_orders(BTCUSDT_BINANCE, [order1, order2])
+
+        # Assert
+        assert result is not None
+        assert result[0] == order2
+        assert result[1].startswith("NET_EXPOSURE_EXCEEDS_MAX_FOR_ASSET_CLASS[CRYPTOCURRENCY]")